@@ -0,0 +1,107 @@
+use std::path::Path;
+
+use qir_core::error::AppError;
+
+use crate::draft::{draft_section_with_llm, AiDraftResponse, AiDraftSectionRequest, SectionId};
+use crate::embeddings::hashed::HashedEmbedder;
+use crate::evidence::{
+    AiIndexBuildInput, AiIndexStatus, EvidenceAddSourceInput, EvidenceOrigin, EvidenceQueryStore, EvidenceSource,
+    EvidenceSourceType, EvidenceStore, IndexStore,
+};
+use crate::llm::Llm;
+
+/// Model names recorded for guided-tour artifacts so they are never mistaken for real output.
+pub const GUIDED_TOUR_EMBED_MODEL: &str = "guided-tour-hashed-v1";
+pub const GUIDED_TOUR_LLM_MODEL: &str = "guided-tour-offline";
+
+#[derive(Debug, Clone)]
+pub struct GuidedTourEvidence {
+    pub sources: Vec<EvidenceSource>,
+    pub chunk_ids: Vec<String>,
+    pub index: AiIndexStatus,
+    pub draft: AiDraftResponse,
+}
+
+/// Canned "model" for the tour: one cited bullet per evidence block in the prompt.
+struct GuidedTourLlm;
+
+impl Llm for GuidedTourLlm {
+    fn generate(&self, _model: &str, prompt: &str) -> Result<String, AppError> {
+        let lines: Vec<String> = prompt
+            .lines()
+            .filter_map(|line| line.strip_prefix("[[chunk:"))
+            .filter_map(|rest| rest.split_once("]] source_id="))
+            .map(|(id, _)| {
+                format!("- Example finding drafted offline for the guided tour; see the cited evidence. [[chunk:{id}]]")
+            })
+            .collect();
+        if lines.is_empty() {
+            return Err(AppError::new("AI_DRAFT_FAILED", "Guided tour prompt contained no evidence"));
+        }
+        Ok(lines.join("\n"))
+    }
+}
+
+/// Fills the AI store at `root` with `notes` (label, text) as paste sources, builds chunks and
+/// an index with `HashedEmbedder`, and drafts an exec summary citing every chunk. No Ollama
+/// calls are made.
+pub fn seed_guided_tour_evidence(
+    root: &Path,
+    notes: &[(String, String)],
+    quarter_label: &str,
+    created_at: &str,
+) -> Result<GuidedTourEvidence, AppError> {
+    let evidence = EvidenceStore::open(root.to_path_buf());
+    let mut sources = Vec::new();
+    for (i, (label, text)) in notes.iter().enumerate() {
+        sources.push(evidence.add_source(EvidenceAddSourceInput {
+            source_type: EvidenceSourceType::FreeformText,
+            // Paste source ids derive from type + origin; a tour path keeps each note distinct.
+            origin: EvidenceOrigin {
+                kind: "paste".to_string(),
+                path: Some(format!("guided-tour://note-{i}")),
+            },
+            label: label.clone(),
+            created_at: created_at.to_string(),
+            text: Some(text.clone()),
+        })?);
+    }
+    evidence.build_chunks(None, created_at)?;
+    let chunk_ids: Vec<String> = evidence
+        .list_chunks(EvidenceQueryStore {
+            include_text: false,
+            source_id: None,
+        })?
+        .into_iter()
+        .map(|c| c.chunk_id)
+        .collect();
+
+    let index = IndexStore::open(root.to_path_buf()).build_with_embedder(
+        &evidence,
+        &HashedEmbedder::default(),
+        AiIndexBuildInput {
+            model: GUIDED_TOUR_EMBED_MODEL.to_string(),
+            source_id: None,
+            updated_at: created_at.to_string(),
+        },
+    )?;
+
+    let draft = draft_section_with_llm(
+        &evidence,
+        &GuidedTourLlm,
+        GUIDED_TOUR_LLM_MODEL,
+        AiDraftSectionRequest {
+            section_id: SectionId::ExecSummary,
+            quarter_label: quarter_label.to_string(),
+            prompt: "Summarize the quarter's most important incidents.".to_string(),
+            citation_chunk_ids: chunk_ids.clone(),
+        },
+    )?;
+
+    Ok(GuidedTourEvidence {
+        sources,
+        chunk_ids,
+        index,
+        draft,
+    })
+}
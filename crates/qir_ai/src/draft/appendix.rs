@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use qir_core::ai_drafts::AiDraftArtifact;
+use qir_core::error::AppError;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::export::{section_title, split_markers};
+use crate::evidence::EvidenceStore;
+
+/// One cited chunk, reproduced verbatim. Numbers (`E1`, `E2`, ...) follow first appearance across
+/// the drafts in the order given.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EvidenceAppendixEntry {
+    pub number: u32,
+    pub chunk_id: String,
+    pub source_id: String,
+    pub source_label: String,
+    pub ordinal: u32,
+    pub text_sha256: String,
+    pub text: String,
+    pub cited_by_draft_ids: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EvidenceAppendixResult {
+    pub path: String,
+    pub bytes: u64,
+    pub draft_ids: Vec<i64>,
+    pub entries: Vec<EvidenceAppendixEntry>,
+}
+
+/// Resolves every `[[chunk:<id>]]` marker in `drafts` to its stored chunk. A cited chunk that
+/// no longer exists fails the export rather than leaving a gap in the audit trail.
+pub fn collect_appendix_entries(
+    evidence: &EvidenceStore,
+    drafts: &[AiDraftArtifact],
+) -> Result<Vec<EvidenceAppendixEntry>, AppError> {
+    let labels: BTreeMap<String, String> = evidence
+        .list_sources()?
+        .into_iter()
+        .map(|s| (s.source_id, s.label))
+        .collect();
+
+    let mut index: BTreeMap<String, usize> = BTreeMap::new();
+    let mut entries: Vec<EvidenceAppendixEntry> = Vec::new();
+    for draft in drafts {
+        for line in draft.draft_text.lines() {
+            for chunk_id in split_markers(line).into_iter().filter_map(Result::err) {
+                if let Some(&i) = index.get(&chunk_id) {
+                    let cited_by = &mut entries[i].cited_by_draft_ids;
+                    if !cited_by.contains(&draft.id) {
+                        cited_by.push(draft.id);
+                    }
+                    continue;
+                }
+                let chunk = evidence.get_chunk(&chunk_id).map_err(|e| {
+                    if e.code == "AI_EVIDENCE_NOT_FOUND" {
+                        AppError::new("AI_CITATION_INVALID", "Draft cites a chunk that no longer exists")
+                            .with_details(format!("draft_id={}; chunk_id={}", draft.id, chunk_id))
+                    } else {
+                        e
+                    }
+                })?;
+                index.insert(chunk_id.clone(), entries.len());
+                entries.push(EvidenceAppendixEntry {
+                    number: entries.len() as u32 + 1,
+                    source_label: labels
+                        .get(&chunk.source_id)
+                        .cloned()
+                        .unwrap_or_else(|| chunk.source_id.clone()),
+                    chunk_id,
+                    source_id: chunk.source_id,
+                    ordinal: chunk.ordinal,
+                    text_sha256: chunk.text_sha256,
+                    text: chunk.text,
+                    cited_by_draft_ids: vec![draft.id],
+                });
+            }
+        }
+    }
+    Ok(entries)
+}
+
+// A fence longer than any backtick run in the chunk, so chunk text can never close it early.
+fn fence_for(text: &str) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+    for c in text.chars() {
+        run = if c == '`' { run + 1 } else { 0 };
+        longest = longest.max(run);
+    }
+    "`".repeat(longest.max(2) + 1)
+}
+
+/// Renders the appendix: which drafts (and report) it covers, then every cited chunk with its
+/// locator, source label and exact text.
+pub fn render_evidence_appendix_markdown(
+    drafts: &[AiDraftArtifact],
+    entries: &[EvidenceAppendixEntry],
+    report_sha256: Option<&str>,
+) -> String {
+    let mut out = String::from("# Evidence appendix\n\n");
+    if let Some(sha) = report_sha256 {
+        out.push_str(&format!("Report sha256: `{sha}`\n\n"));
+    }
+    out.push_str("Drafts covered:\n\n");
+    for d in drafts {
+        out.push_str(&format!(
+            "- Draft {} — {} — {} (artifact `{}`)\n",
+            d.id,
+            d.quarter_label,
+            section_title(&d.section_type),
+            d.artifact_hash
+        ));
+    }
+    out.push_str(&format!("\n{} cited chunk(s).\n", entries.len()));
+
+    for e in entries {
+        let cited_by = e
+            .cited_by_draft_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let fence = fence_for(&e.text);
+        out.push_str(&format!(
+            "\n## E{} — {}, chunk {}\n\n- chunk_id: `{}`\n- source_id: `{}`\n- sha256: `{}`\n- cited by drafts: {}\n\n{fence}text\n{}\n{fence}\n",
+            e.number,
+            e.source_label,
+            e.ordinal,
+            e.chunk_id,
+            e.source_id,
+            e.text_sha256,
+            cited_by,
+            e.text.trim_end()
+        ));
+    }
+    out
+}
+
+fn file_name(drafts: &[AiDraftArtifact], report_sha256: Option<&str>) -> String {
+    match report_sha256 {
+        Some(sha) => format!(
+            "evidence_appendix_report_{}.md",
+            sha.chars().take(12).collect::<String>()
+        ),
+        None => {
+            let mut hasher = Sha256::new();
+            for d in drafts {
+                hasher.update(d.artifact_hash.as_bytes());
+                hasher.update(b"\n");
+            }
+            let digest = hex::encode(hasher.finalize());
+            format!("evidence_appendix_drafts_{}.md", &digest[..12])
+        }
+    }
+}
+
+/// Writes the cited-evidence appendix for `drafts` into `destination_dir`. Pass the report's
+/// sha256 when the drafts come from a published report's manifest; it names the file and is
+/// recorded in the header.
+pub fn export_evidence_appendix(
+    evidence: &EvidenceStore,
+    drafts: &[AiDraftArtifact],
+    report_sha256: Option<&str>,
+    destination_dir: &Path,
+) -> Result<EvidenceAppendixResult, AppError> {
+    if drafts.is_empty() {
+        return Err(AppError::new(
+            "AI_DRAFT_EXPORT_FAILED",
+            "Select at least one draft for the evidence appendix",
+        ));
+    }
+    if !destination_dir.is_dir() {
+        return Err(AppError::new(
+            "AI_DRAFT_EXPORT_FAILED",
+            "Appendix export destination must be an existing directory",
+        )
+        .with_details(format!("path={}", destination_dir.display())));
+    }
+
+    let entries = collect_appendix_entries(evidence, drafts)?;
+    if entries.is_empty() {
+        return Err(AppError::new("AI_CITATION_REQUIRED", "Drafts have no citation markers to export")
+            .with_details(format!("draft_ids={:?}", drafts.iter().map(|d| d.id).collect::<Vec<_>>())));
+    }
+    let bytes = render_evidence_appendix_markdown(drafts, &entries, report_sha256).into_bytes();
+
+    let path = destination_dir.join(file_name(drafts, report_sha256));
+    let tmp = path.with_extension("md.tmp");
+    fs::write(&tmp, &bytes)
+        .and_then(|_| fs::rename(&tmp, &path))
+        .map_err(|e| {
+            let _ = fs::remove_file(&tmp);
+            AppError::new("AI_DRAFT_EXPORT_FAILED", "Failed to write evidence appendix")
+                .with_details(format!("path={}; err={}", path.display(), e))
+        })?;
+
+    Ok(EvidenceAppendixResult {
+        path: path.display().to_string(),
+        bytes: bytes.len() as u64,
+        draft_ids: drafts.iter().map(|d| d.id).collect(),
+        entries,
+    })
+}
@@ -0,0 +1,400 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::Path;
+
+use qir_core::ai_drafts::{AiDraftArtifact, AiDraftSectionType};
+use qir_core::error::AppError;
+use serde::{Deserialize, Serialize};
+
+use crate::evidence::EvidenceStore;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DraftExportFormat {
+    Markdown,
+    Docx,
+}
+
+impl DraftExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DraftExportFormat::Markdown => "md",
+            DraftExportFormat::Docx => "docx",
+        }
+    }
+}
+
+/// One numbered footnote. Numbers follow first appearance in the draft; repeated markers
+/// for the same chunk reuse the number.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DraftFootnote {
+    pub number: u32,
+    pub chunk_id: String,
+    pub source_id: String,
+    pub source_label: String,
+    pub ordinal: u32,
+    pub text_sha256: String,
+}
+
+impl DraftFootnote {
+    fn text(&self) -> String {
+        format!(
+            "{}, chunk {}, sha256 {}",
+            self.source_label, self.ordinal, self.text_sha256
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DraftExportResult {
+    pub draft_id: i64,
+    pub format: DraftExportFormat,
+    pub path: String,
+    pub bytes: u64,
+    pub footnotes: Vec<DraftFootnote>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Inline {
+    Text(String),
+    Note(u32),
+}
+
+/// Draft text split into lines, with `[[chunk:<id>]]` markers replaced by footnote numbers.
+#[derive(Debug, Clone)]
+struct RenderedDraft {
+    title: String,
+    lines: Vec<Vec<Inline>>,
+    footnotes: Vec<DraftFootnote>,
+    /// Workspace AI usage policy disclaimer, rendered below the body.
+    disclaimer: Option<String>,
+}
+
+pub fn section_title(section_type: &AiDraftSectionType) -> &'static str {
+    match section_type {
+        AiDraftSectionType::ExecSummary => "Executive Summary",
+        AiDraftSectionType::IncidentHighlightsTopN => "Incident Highlights",
+        AiDraftSectionType::ThemeAnalysis => "Theme Analysis",
+        AiDraftSectionType::ActionPlanNextQuarter => "Action Plan (Next Quarter)",
+        AiDraftSectionType::QuarterNarrativeRecap => "Quarter Narrative Recap",
+    }
+}
+
+// Same marker grammar as `extract_cited_chunk_ids`: `[[chunk:` + id + `]]`. Malformed markers
+// are kept as literal text.
+pub(super) fn split_markers(line: &str) -> Vec<Result<String, String>> {
+    let mut out = Vec::new();
+    let mut rest = line;
+    while let Some(pos) = rest.find("[[chunk:") {
+        let start = pos + "[[chunk:".len();
+        let Some(end) = rest[start..].find("]]") else {
+            break;
+        };
+        let id = rest[start..start + end].trim();
+        if id.is_empty() || id.contains(']') {
+            out.push(Ok(rest[..start].to_string()));
+            rest = &rest[start..];
+            continue;
+        }
+        if pos > 0 {
+            out.push(Ok(rest[..pos].to_string()));
+        }
+        out.push(Err(id.to_string()));
+        rest = &rest[start + end + 2..];
+    }
+    if !rest.is_empty() {
+        out.push(Ok(rest.to_string()));
+    }
+    out
+}
+
+fn render(evidence: &EvidenceStore, draft: &AiDraftArtifact) -> Result<RenderedDraft, AppError> {
+    let labels: BTreeMap<String, String> = evidence
+        .list_sources()?
+        .into_iter()
+        .map(|s| (s.source_id, s.label))
+        .collect();
+
+    let mut numbers: BTreeMap<String, u32> = BTreeMap::new();
+    let mut footnotes: Vec<DraftFootnote> = Vec::new();
+    let mut lines = Vec::new();
+    for line in draft.draft_text.lines() {
+        let mut inlines: Vec<Inline> = Vec::new();
+        for part in split_markers(line) {
+            match part {
+                Ok(text) => match inlines.last_mut() {
+                    Some(Inline::Text(prev)) => prev.push_str(&text),
+                    _ => inlines.push(Inline::Text(text)),
+                },
+                Err(chunk_id) => {
+                    if let Some(n) = numbers.get(&chunk_id) {
+                        inlines.push(Inline::Note(*n));
+                        continue;
+                    }
+                    let chunk = evidence.get_chunk(&chunk_id).map_err(|e| {
+                        if e.code == "AI_EVIDENCE_NOT_FOUND" {
+                            AppError::new("AI_CITATION_INVALID", "Draft cites a chunk that no longer exists")
+                                .with_details(format!("draft_id={}; chunk_id={}", draft.id, chunk_id))
+                        } else {
+                            e
+                        }
+                    })?;
+                    let number = footnotes.len() as u32 + 1;
+                    numbers.insert(chunk_id.clone(), number);
+                    footnotes.push(DraftFootnote {
+                        number,
+                        source_label: labels
+                            .get(&chunk.source_id)
+                            .cloned()
+                            .unwrap_or_else(|| chunk.source_id.clone()),
+                        chunk_id,
+                        source_id: chunk.source_id,
+                        ordinal: chunk.ordinal,
+                        text_sha256: chunk.text_sha256,
+                    });
+                    inlines.push(Inline::Note(number));
+                }
+            }
+        }
+        lines.push(inlines);
+    }
+
+    if footnotes.is_empty() {
+        return Err(AppError::new("AI_CITATION_REQUIRED", "Draft has no citation markers to export")
+            .with_details(format!("draft_id={}", draft.id)));
+    }
+
+    Ok(RenderedDraft {
+        title: format!("{} — {}", draft.quarter_label, section_title(&draft.section_type)),
+        lines,
+        footnotes,
+        disclaimer: None,
+    })
+}
+
+fn to_markdown(doc: &RenderedDraft) -> String {
+    let mut out = format!("# {}\n\n", doc.title);
+    for line in &doc.lines {
+        for inline in line {
+            match inline {
+                Inline::Text(t) => out.push_str(t),
+                Inline::Note(n) => out.push_str(&format!("[^{n}]")),
+            }
+        }
+        out.push('\n');
+    }
+    // Footnote definitions must be separated from the body by a blank line.
+    if !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+    if let Some(disclaimer) = &doc.disclaimer {
+        out.push_str(&format!("_{disclaimer}_\n\n"));
+    }
+    for f in &doc.footnotes {
+        out.push_str(&format!("[^{}]: {}\n", f.number, f.text()));
+    }
+    out
+}
+
+/// Renders a stored draft as Markdown with `[^n]` footnotes.
+pub fn render_draft_markdown(
+    evidence: &EvidenceStore,
+    draft: &AiDraftArtifact,
+) -> Result<(String, Vec<DraftFootnote>), AppError> {
+    let doc = render(evidence, draft)?;
+    Ok((to_markdown(&doc), doc.footnotes))
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn text_run(text: &str, bold: bool) -> String {
+    let rpr = if bold { "<w:rPr><w:b/></w:rPr>" } else { "" };
+    format!(r#"<w:r>{rpr}<w:t xml:space="preserve">{}</w:t></w:r>"#, xml_escape(text))
+}
+
+const DOCX_CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/><Override PartName="/word/footnotes.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.footnotes+xml"/></Types>"#;
+
+const DOCX_ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+const DOCX_DOCUMENT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/footnotes" Target="footnotes.xml"/></Relationships>"#;
+
+const W_NS: &str = r#"xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main""#;
+
+fn docx_document_xml(doc: &RenderedDraft) -> String {
+    let mut body = format!(
+        r#"<w:p><w:r><w:rPr><w:b/><w:sz w:val="32"/></w:rPr><w:t xml:space="preserve">{}</w:t></w:r></w:p>"#,
+        xml_escape(&doc.title)
+    );
+    for line in &doc.lines {
+        // Light Markdown handling: headings become bold paragraphs, list markers become bullets.
+        let mut bold = false;
+        let mut runs = String::new();
+        for (idx, inline) in line.iter().enumerate() {
+            match inline {
+                Inline::Text(t) => {
+                    let mut text = t.as_str();
+                    let owned;
+                    if idx == 0 {
+                        let trimmed = text.trim_start();
+                        if trimmed.starts_with('#') {
+                            bold = true;
+                            text = trimmed.trim_start_matches('#').trim_start();
+                        } else if let Some(item) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+                            owned = format!("• {item}");
+                            text = owned.as_str();
+                        }
+                    }
+                    runs.push_str(&text_run(text, bold));
+                }
+                Inline::Note(n) => runs.push_str(&format!(
+                    r#"<w:r><w:rPr><w:vertAlign w:val="superscript"/></w:rPr><w:footnoteReference w:id="{n}"/></w:r>"#
+                )),
+            }
+        }
+        if runs.is_empty() {
+            continue;
+        }
+        body.push_str(&format!("<w:p>{runs}</w:p>"));
+    }
+    if let Some(disclaimer) = &doc.disclaimer {
+        body.push_str(&format!(
+            r#"<w:p><w:r><w:rPr><w:i/></w:rPr><w:t xml:space="preserve">{}</w:t></w:r></w:p>"#,
+            xml_escape(disclaimer)
+        ));
+    }
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document {W_NS}><w:body>{body}<w:sectPr/></w:body></w:document>"#
+    )
+}
+
+fn docx_footnotes_xml(doc: &RenderedDraft) -> String {
+    let mut notes = String::from(
+        r#"<w:footnote w:type="separator" w:id="-1"><w:p><w:r><w:separator/></w:r></w:p></w:footnote><w:footnote w:type="continuationSeparator" w:id="0"><w:p><w:r><w:continuationSeparator/></w:r></w:p></w:footnote>"#,
+    );
+    for f in &doc.footnotes {
+        notes.push_str(&format!(
+            r#"<w:footnote w:id="{}"><w:p><w:r><w:rPr><w:vertAlign w:val="superscript"/></w:rPr><w:footnoteRef/></w:r>{}</w:p></w:footnote>"#,
+            f.number,
+            text_run(&format!(" {}", f.text()), false)
+        ));
+    }
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:footnotes {W_NS}>{notes}</w:footnotes>"#
+    )
+}
+
+fn to_docx(doc: &RenderedDraft) -> Result<Vec<u8>, AppError> {
+    let parts: [(&str, String); 5] = [
+        ("[Content_Types].xml", DOCX_CONTENT_TYPES.to_string()),
+        ("_rels/.rels", DOCX_ROOT_RELS.to_string()),
+        ("word/_rels/document.xml.rels", DOCX_DOCUMENT_RELS.to_string()),
+        ("word/document.xml", docx_document_xml(doc)),
+        ("word/footnotes.xml", docx_footnotes_xml(doc)),
+    ];
+    // Fixed timestamps keep the archive byte-for-byte deterministic.
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .last_modified_time(zip::DateTime::default());
+    let mut zw = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, xml) in parts.iter() {
+        let part_err = |e: String| {
+            AppError::new("AI_DRAFT_EXPORT_FAILED", "Failed to build DOCX archive")
+                .with_details(format!("part={name}; err={e}"))
+        };
+        zw.start_file(*name, options).map_err(|e| part_err(e.to_string()))?;
+        zw.write_all(xml.as_bytes()).map_err(|e| part_err(e.to_string()))?;
+    }
+    let cursor = zw.finish().map_err(|e| {
+        AppError::new("AI_DRAFT_EXPORT_FAILED", "Failed to build DOCX archive").with_details(e.to_string())
+    })?;
+    Ok(cursor.into_inner())
+}
+
+/// Renders a stored draft as a minimal DOCX (document + native Word footnotes).
+pub fn render_draft_docx(
+    evidence: &EvidenceStore,
+    draft: &AiDraftArtifact,
+) -> Result<(Vec<u8>, Vec<DraftFootnote>), AppError> {
+    let doc = render(evidence, draft)?;
+    Ok((to_docx(&doc)?, doc.footnotes))
+}
+
+fn file_stem(draft: &AiDraftArtifact) -> String {
+    let quarter: String = draft
+        .quarter_label
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    format!("{}_{}_draft_{}", quarter, draft.section_type.as_str(), draft.id)
+}
+
+/// Writes `<quarter>_<section>_draft_<id>.<ext>` into `destination_dir`, replacing a
+/// previous export of the same draft.
+pub fn export_draft(
+    evidence: &EvidenceStore,
+    draft: &AiDraftArtifact,
+    format: DraftExportFormat,
+    destination_dir: &Path,
+) -> Result<DraftExportResult, AppError> {
+    export_draft_with_disclaimer(evidence, draft, format, destination_dir, None)
+}
+
+/// Same as `export_draft`, appending the AI usage policy disclaimer (italic) below the body.
+pub fn export_draft_with_disclaimer(
+    evidence: &EvidenceStore,
+    draft: &AiDraftArtifact,
+    format: DraftExportFormat,
+    destination_dir: &Path,
+    disclaimer: Option<&str>,
+) -> Result<DraftExportResult, AppError> {
+    if !destination_dir.is_dir() {
+        return Err(AppError::new(
+            "AI_DRAFT_EXPORT_FAILED",
+            "Draft export destination must be an existing directory",
+        )
+        .with_details(format!("path={}", destination_dir.display())));
+    }
+
+    let mut doc = render(evidence, draft)?;
+    doc.disclaimer = disclaimer.map(str::trim).filter(|d| !d.is_empty()).map(str::to_string);
+    let bytes = match format {
+        DraftExportFormat::Markdown => to_markdown(&doc).into_bytes(),
+        DraftExportFormat::Docx => to_docx(&doc)?,
+    };
+
+    let path = destination_dir.join(format!("{}.{}", file_stem(draft), format.extension()));
+    let tmp = path.with_extension(format!("{}.tmp", format.extension()));
+    fs::write(&tmp, &bytes)
+        .and_then(|_| fs::rename(&tmp, &path))
+        .map_err(|e| {
+            let _ = fs::remove_file(&tmp);
+            AppError::new("AI_DRAFT_EXPORT_FAILED", "Failed to write draft export")
+                .with_details(format!("path={}; err={}", path.display(), e))
+        })?;
+
+    Ok(DraftExportResult {
+        draft_id: draft.id,
+        format,
+        path: path.display().to_string(),
+        bytes: bytes.len() as u64,
+        footnotes: doc.footnotes,
+    })
+}
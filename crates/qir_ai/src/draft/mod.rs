@@ -1,11 +1,16 @@
+use qir_core::ai_drafts::AiDraftSectionType;
 use qir_core::error::AppError;
 use serde::{Deserialize, Serialize};
 
 use crate::evidence::{Citation, EvidenceStore};
 use crate::guardrails::enforce_citations;
-use crate::llm::Llm;
+use crate::llm::{Llm, STAGE_GENERATING, STAGE_MODEL_LOADING};
+use qir_core::progress::Progress;
 use sha2::{Digest, Sha256};
 
+pub mod appendix;
+pub mod export;
+pub mod preview;
 mod prompts;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -18,6 +23,19 @@ pub enum SectionId {
     QuarterNarrativeRecap,
 }
 
+impl SectionId {
+    /// The stored draft section type this section is saved as.
+    pub fn section_type(&self) -> AiDraftSectionType {
+        match self {
+            SectionId::ExecSummary => AiDraftSectionType::ExecSummary,
+            SectionId::IncidentHighlightsTopN => AiDraftSectionType::IncidentHighlightsTopN,
+            SectionId::ThemeAnalysis => AiDraftSectionType::ThemeAnalysis,
+            SectionId::ActionPlanNextQuarter => AiDraftSectionType::ActionPlanNextQuarter,
+            SectionId::QuarterNarrativeRecap => AiDraftSectionType::QuarterNarrativeRecap,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AiDraftSectionRequest {
     pub section_id: SectionId,
@@ -42,6 +60,29 @@ pub fn draft_section_with_llm(
     model: &str,
     req: AiDraftSectionRequest,
 ) -> Result<AiDraftResponse, AppError> {
+    draft_section_with_llm_progress(evidence, llm, model, req, &mut Progress::noop("ai_draft_section"))
+}
+
+/// Same as `draft_section_with_llm`, reporting whether the model is still loading or
+/// already generating so a slow first token does not look like a hang.
+pub fn draft_section_with_llm_progress(
+    evidence: &EvidenceStore,
+    llm: &dyn Llm,
+    model: &str,
+    req: AiDraftSectionRequest,
+    progress: &mut Progress<'_>,
+) -> Result<AiDraftResponse, AppError> {
+    progress.run(|p| draft_stages(evidence, llm, model, req, p))
+}
+
+fn draft_stages(
+    evidence: &EvidenceStore,
+    llm: &dyn Llm,
+    model: &str,
+    req: AiDraftSectionRequest,
+    progress: &mut Progress<'_>,
+) -> Result<AiDraftResponse, AppError> {
+    progress.stage("prompt", Some(5));
     if req.citation_chunk_ids.is_empty() {
         return Err(AppError::new(
             "AI_CITATION_REQUIRED",
@@ -64,7 +105,9 @@ pub fn draft_section_with_llm(
     }
     evidence.validate_citations(&citations)?;
 
-    let evidence_blocks = build_evidence_blocks(evidence, &req.citation_chunk_ids)?;
+    let (evidence_blocks, languages) = build_evidence_blocks(evidence, &req.citation_chunk_ids)?;
+    let languages: Vec<&str> = languages.iter().map(String::as_str).collect();
+    let preamble = prompts::language_preamble(&languages);
 
     let prompt = match req.section_id {
         SectionId::ExecSummary => prompts::exec_summary_prompt(
@@ -94,6 +137,11 @@ pub fn draft_section_with_llm(
         ),
     };
 
+    let prompt = match preamble {
+        Some((_, text)) => format!("{text}\n\n{prompt}"),
+        None => prompt,
+    };
+
     let prompt_template_version = match req.section_id {
         SectionId::ExecSummary => "exec_summary_v1".to_string(),
         SectionId::IncidentHighlightsTopN => "incident_highlights_top_n_v1".to_string(),
@@ -101,9 +149,19 @@ pub fn draft_section_with_llm(
         SectionId::ActionPlanNextQuarter => "action_plan_next_quarter_v1".to_string(),
         SectionId::QuarterNarrativeRecap => "quarter_narrative_recap_v1".to_string(),
     };
+    let prompt_template_version = match preamble {
+        Some((id, _)) => format!("{prompt_template_version}+{id}"),
+        None => prompt_template_version,
+    };
     let model_params_hash = compute_model_params_hash(model)?;
 
+    if llm.model_loaded(model) == Some(false) {
+        progress.stage(STAGE_MODEL_LOADING, Some(10));
+        llm.load_model(model)?;
+    }
+    progress.stage(STAGE_GENERATING, Some(20));
     let markdown = llm.generate(model, &prompt)?;
+    progress.stage("validate", Some(90));
 
     validate_section_citations(req.section_id.clone(), &markdown).map_err(|e| {
         AppError::new("AI_CITATION_REQUIRED", "Draft missing citations")
@@ -150,6 +208,43 @@ pub fn draft_section_with_llm(
     })
 }
 
+/// Re-runs the drafting guardrails on human-edited draft text, independent of section type:
+/// every paragraph and list item must cite, and every cited chunk must be in
+/// `allowed_chunk_ids` and still exist in the evidence store. Returns the canonical citations
+/// of the cited chunks (sorted by chunk id).
+pub fn validate_draft_text(
+    evidence: &EvidenceStore,
+    text: &str,
+    allowed_chunk_ids: &[String],
+) -> Result<Vec<Citation>, AppError> {
+    enforce_citations(text)?;
+    validate_each_list_item_has_citation(text)?;
+    validate_each_paragraph_has_citation(text)?;
+
+    let allowed: std::collections::BTreeSet<&str> = allowed_chunk_ids.iter().map(String::as_str).collect();
+    let mut citations = Vec::new();
+    for cid in extract_cited_chunk_ids(text) {
+        if !allowed.contains(cid.as_str()) {
+            return Err(AppError::new(
+                "AI_CITATION_INVALID",
+                "Draft cites a chunk_id outside the allowed set",
+            )
+            .with_details(format!("chunk_id={}", cid)));
+        }
+        let chunk = evidence.get_chunk(&cid).map_err(|e| {
+            if e.code == "AI_EVIDENCE_NOT_FOUND" {
+                AppError::new("AI_CITATION_INVALID", "Cited chunk no longer exists")
+                    .with_details(format!("chunk_id={}", cid))
+            } else {
+                e
+            }
+        })?;
+        citations.push(evidence.citation_for_chunk(&chunk));
+    }
+    evidence.validate_citations(&citations)?;
+    Ok(citations)
+}
+
 fn validate_section_citations(section_id: SectionId, markdown: &str) -> Result<(), AppError> {
     // Always require at least one citation marker somewhere.
     enforce_citations(markdown)?;
@@ -206,16 +301,24 @@ fn validate_each_paragraph_has_citation(markdown: &str) -> Result<(), AppError>
     .with_details(format!("missing_citation_paragraphs={missing:?}")))
 }
 
-fn build_evidence_blocks(evidence: &EvidenceStore, chunk_ids: &[String]) -> Result<String, AppError> {
+/// Returns the prompt evidence blocks and the detected languages of the cited chunks.
+fn build_evidence_blocks(
+    evidence: &EvidenceStore,
+    chunk_ids: &[String],
+) -> Result<(String, std::collections::BTreeSet<String>), AppError> {
     let mut blocks: Vec<String> = Vec::new();
+    let mut languages = std::collections::BTreeSet::new();
     for cid in chunk_ids {
         let chunk = evidence.get_chunk(cid)?;
+        if let Some(lang) = chunk.meta.language.clone() {
+            languages.insert(lang);
+        }
         blocks.push(format!(
             "[[chunk:{cid}]] source_id={} ordinal={} text_sha256={}\n{}",
             chunk.source_id, chunk.ordinal, chunk.text_sha256, chunk.text
         ));
     }
-    Ok(blocks.join("\n\n---\n\n"))
+    Ok((blocks.join("\n\n---\n\n"), languages))
 }
 
 fn extract_cited_chunk_ids(markdown: &str) -> std::collections::BTreeSet<String> {
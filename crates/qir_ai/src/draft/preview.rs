@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use qir_core::error::AppError;
+use serde::{Deserialize, Serialize};
+
+use super::extract_cited_chunk_ids;
+use crate::evidence::{CitationLocator, EvidenceStore};
+
+/// Hover preview for one `[[chunk:<id>]]` marker. Markers that do not resolve (typos, deleted
+/// evidence) come back with `found=false` so the editor can flag them instead of failing.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CitationPreview {
+    pub chunk_id: String,
+    pub found: bool,
+    pub snippet: Option<String>,
+    pub source_label: Option<String>,
+    pub locator: Option<CitationLocator>,
+}
+
+impl CitationPreview {
+    fn missing(chunk_id: &str) -> Self {
+        Self {
+            chunk_id: chunk_id.to_string(),
+            found: false,
+            snippet: None,
+            source_label: None,
+            locator: None,
+        }
+    }
+}
+
+// Chunk ids are hex digests; anything else never touches the filesystem.
+fn is_plausible_chunk_id(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Resolves every citation marker in `markdown` in one pass, keyed by chunk id.
+pub fn resolve_citation_previews(
+    evidence: &EvidenceStore,
+    markdown: &str,
+) -> Result<BTreeMap<String, CitationPreview>, AppError> {
+    let labels: BTreeMap<String, String> = evidence
+        .list_sources()?
+        .into_iter()
+        .map(|s| (s.source_id, s.label))
+        .collect();
+
+    let mut out = BTreeMap::new();
+    for chunk_id in extract_cited_chunk_ids(markdown) {
+        if !is_plausible_chunk_id(&chunk_id) {
+            out.insert(chunk_id.clone(), CitationPreview::missing(&chunk_id));
+            continue;
+        }
+        let summary = match evidence.get_chunk_summary(&chunk_id) {
+            Ok(s) => s,
+            Err(e) if e.code == "AI_EVIDENCE_NOT_FOUND" => {
+                out.insert(chunk_id.clone(), CitationPreview::missing(&chunk_id));
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        let snippet = evidence.get_chunk_snippet(&chunk_id)?;
+        let locator = evidence.citation_for_summary(&summary).locator;
+        out.insert(
+            chunk_id.clone(),
+            CitationPreview {
+                chunk_id,
+                found: true,
+                snippet: Some(snippet),
+                source_label: labels.get(&summary.source_id).cloned(),
+                locator: Some(locator),
+            },
+        );
+    }
+    Ok(out)
+}
@@ -1,3 +1,5 @@
+use crate::evidence::language::{LANG_EN, LANG_ES, LANG_MIXED};
+
 pub fn exec_summary_prompt(
     quarter_label: &str,
     user_prompt: &str,
@@ -136,3 +138,24 @@ Output:
 "#
     )
 }
+
+/// Preamble prepended to any section prompt when the cited evidence is not all English.
+/// Returns (id, text); the id is appended to the prompt template version.
+pub fn language_preamble(languages: &[&str]) -> Option<(&'static str, &'static str)> {
+    let has_es = languages.iter().any(|l| *l == LANG_ES || *l == LANG_MIXED);
+    let has_en = languages.iter().any(|l| *l == LANG_EN || *l == LANG_MIXED);
+    match (has_es, has_en) {
+        (false, _) => None,
+        (true, false) => Some((
+            "lang_es",
+            "Language note: the evidence chunks below are in Spanish. Write the draft in English, translating \
+             faithfully; do not add facts while translating. Keep citations on the chunk each fact came from.",
+        )),
+        (true, true) => Some((
+            "lang_mixed",
+            "Language note: the evidence chunks below mix English and Spanish. Write the draft in English, \
+             translating Spanish passages faithfully; do not add facts while translating. Keep citations on the \
+             chunk each fact came from.",
+        )),
+    }
+}
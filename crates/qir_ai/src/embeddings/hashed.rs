@@ -0,0 +1,48 @@
+use qir_core::error::AppError;
+
+use super::Embedder;
+use crate::evidence::store::sha256_hex;
+
+/// Offline, deterministic embedder: a hashed bag of lowercase ASCII words, L2-normalized.
+/// Texts sharing words land close together, so retrieval over it is meaningful, but it is no
+/// substitute for a real model. Used for the guided tour and tests where Ollama is absent.
+#[derive(Debug, Clone)]
+pub struct HashedEmbedder {
+    dims: usize,
+}
+
+impl Default for HashedEmbedder {
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+impl HashedEmbedder {
+    pub fn new(dims: usize) -> Self {
+        Self { dims: dims.max(1) }
+    }
+
+    pub fn embed_text(&self, input: &str) -> Vec<f32> {
+        let mut v = vec![0f32; self.dims];
+        for word in input
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+        {
+            // sha256 rather than std's hasher so buckets are identical across builds/platforms.
+            let digest = sha256_hex(word.to_ascii_lowercase().as_bytes());
+            let bucket = u64::from_str_radix(&digest[..16], 16).unwrap_or(0) % self.dims as u64;
+            v[bucket as usize] += 1.0;
+        }
+        let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            v.iter_mut().for_each(|x| *x /= norm);
+        }
+        v
+    }
+}
+
+impl Embedder for HashedEmbedder {
+    fn embed(&self, _model: &str, input: &str) -> Result<Vec<f32>, AppError> {
+        Ok(self.embed_text(input))
+    }
+}
@@ -2,7 +2,19 @@ use qir_core::error::AppError;
 
 pub trait Embedder {
     fn embed(&self, model: &str, input: &str) -> Result<Vec<f32>, AppError>;
+
+    /// Embeds several inputs, returning one vector per input in order. The default calls
+    /// `embed` per input; backends with a batch endpoint override it.
+    fn embed_batch(&self, model: &str, inputs: &[&str]) -> Result<Vec<Vec<f32>>, AppError> {
+        inputs.iter().map(|input| self.embed(model, input)).collect()
+    }
+
+    /// Whether `model` is already loaded by the backend; None when it cannot tell.
+    fn model_loaded(&self, _model: &str) -> Option<bool> {
+        None
+    }
 }
 
+pub mod hashed;
 pub mod ollama_embed;
 
@@ -26,10 +26,82 @@ struct EmbeddingsResponse {
     embedding: Vec<f32>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct EmbedBatchRequest<'a> {
+    model: &'a str,
+    input: Vec<&'a str>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct EmbedBatchResponse {
+    embeddings: Vec<Vec<f32>>,
+}
+
+// Keep requests bounded. Chunking enforces reasonable sizes, but guard anyway.
+fn bounded(input: &str) -> &str {
+    if input.len() <= 12_000 {
+        return input;
+    }
+    let mut end = 12_000;
+    while !input.is_char_boundary(end) {
+        end -= 1;
+    }
+    &input[..end]
+}
+
 impl Embedder for OllamaEmbedder {
+    fn model_loaded(&self, model: &str) -> Option<bool> {
+        self.client.is_model_loaded(model)
+    }
+
+    /// One `/api/embed` request per batch. Ollama builds without that endpoint answer 404, in
+    /// which case the inputs are embedded one by one via `/api/embeddings`.
+    fn embed_batch(&self, model: &str, inputs: &[&str]) -> Result<Vec<Vec<f32>>, AppError> {
+        if inputs.is_empty() {
+            return Ok(Vec::new());
+        }
+        let url = format!("{}/api/embed", self.client.base_url());
+        let req = EmbedBatchRequest {
+            model,
+            input: inputs.iter().map(|i| bounded(i)).collect(),
+        };
+        let resp = ureq::post(&url)
+            .timeout(std::time::Duration::from_secs(10 + 2 * inputs.len() as u64))
+            .send_json(serde_json::to_value(req).map_err(|e| {
+                AppError::new("AI_EMBEDDINGS_FAILED", "Failed to encode embeddings request")
+                    .with_details(e.to_string())
+            })?);
+
+        match resp {
+            Ok(r) if r.status() == 200 => {
+                let v: EmbedBatchResponse = r.into_json().map_err(|e| {
+                    AppError::new("AI_EMBEDDINGS_FAILED", "Failed to decode embeddings response")
+                        .with_details(e.to_string())
+                })?;
+                if v.embeddings.len() != inputs.len() || v.embeddings.iter().any(|e| e.is_empty()) {
+                    return Err(AppError::new(
+                        "AI_EMBEDDINGS_FAILED",
+                        "Batch embeddings response did not match the request",
+                    )
+                    .with_details(format!("inputs={}; embeddings={}", inputs.len(), v.embeddings.len())));
+                }
+                Ok(v.embeddings)
+            }
+            Err(ureq::Error::Status(404, _)) => inputs.iter().map(|input| self.embed(model, input)).collect(),
+            Ok(r) => Err(
+                AppError::new("AI_EMBEDDINGS_FAILED", "Embeddings request failed")
+                    .with_details(format!("status={}", r.status())),
+            ),
+            Err(e) => Err(
+                AppError::new("AI_EMBEDDINGS_FAILED", "Failed to call embeddings endpoint")
+                    .with_details(e.to_string())
+                    .with_retryable(true),
+            ),
+        }
+    }
+
     fn embed(&self, model: &str, input: &str) -> Result<Vec<f32>, AppError> {
-        // Keep requests bounded. Chunking enforces reasonable sizes, but guard anyway.
-        let prompt = if input.len() > 12_000 { &input[..12_000] } else { input };
+        let prompt = bounded(input);
 
         let url = format!("{}/api/embeddings", self.client.base_url());
         let req = EmbeddingsRequest { model, prompt };
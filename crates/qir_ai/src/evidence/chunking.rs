@@ -2,7 +2,9 @@ use std::fs;
 use std::path::PathBuf;
 
 use qir_core::error::AppError;
+use serde::{Deserialize, Serialize};
 
+use super::language::detect_language;
 use super::model::{EvidenceChunkMeta, EvidenceSourceType, EvidenceTimeRange};
 use super::store::{normalize_text, EvidenceSourceRecord, EvidenceStore};
 
@@ -14,7 +16,99 @@ pub struct ChunkDraft {
     pub meta: EvidenceChunkMeta,
 }
 
-fn chunk_text_by_paragraphs(text: &str, kind: &str, max_chars: usize) -> Vec<ChunkDraft> {
+/// Rough chars-per-token ratio used to turn token budgets into paragraph packing limits.
+pub const CHARS_PER_TOKEN_EST: u32 = 4;
+
+/// Paragraph chunking parameters. Chunks are packed with whole paragraphs up to
+/// `target_tokens`; `overlap_tokens` repeats trailing paragraphs of the previous chunk (whole
+/// paragraphs only, so a long paragraph may yield less overlap than requested).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChunkingParams {
+    pub target_tokens: u32,
+    pub overlap_tokens: u32,
+}
+
+impl Default for ChunkingParams {
+    // 400 tokens ~ 1600 chars, the original fixed chunk size, with no overlap.
+    fn default() -> Self {
+        Self {
+            target_tokens: 400,
+            overlap_tokens: 0,
+        }
+    }
+}
+
+impl ChunkingParams {
+    pub const MIN_TARGET_TOKENS: u32 = 50;
+    pub const MAX_TARGET_TOKENS: u32 = 8000;
+
+    pub fn validate(&self) -> Result<(), AppError> {
+        if !(Self::MIN_TARGET_TOKENS..=Self::MAX_TARGET_TOKENS).contains(&self.target_tokens) {
+            return Err(AppError::new("AI_CHUNKING_INVALID", "Chunk target tokens out of range").with_details(
+                format!(
+                    "target_tokens={}; min={}; max={}",
+                    self.target_tokens,
+                    Self::MIN_TARGET_TOKENS,
+                    Self::MAX_TARGET_TOKENS
+                ),
+            ));
+        }
+        if self.overlap_tokens > self.target_tokens / 2 {
+            return Err(AppError::new(
+                "AI_CHUNKING_INVALID",
+                "Chunk overlap must be at most half of the target size",
+            )
+            .with_details(format!(
+                "target_tokens={}; overlap_tokens={}",
+                self.target_tokens, self.overlap_tokens
+            )));
+        }
+        Ok(())
+    }
+
+    fn target_chars(&self) -> usize {
+        (self.target_tokens * CHARS_PER_TOKEN_EST) as usize
+    }
+
+    fn overlap_chars(&self) -> usize {
+        (self.overlap_tokens * CHARS_PER_TOKEN_EST) as usize
+    }
+}
+
+fn paragraph_chunk(ordinal: usize, paras: &[String], kind: &str, params: &ChunkingParams) -> ChunkDraft {
+    let text = paras.join("\n\n");
+    let language = detect_language(&text).map(str::to_string);
+    ChunkDraft {
+        ordinal: ordinal as u32,
+        token_count_est: text.len().min(u32::MAX as usize) as u32,
+        text,
+        meta: EvidenceChunkMeta {
+            kind: kind.to_string(),
+            incident_keys: None,
+            time_range: None,
+            language,
+            chunking: Some(*params),
+        },
+    }
+}
+
+/// Trailing paragraphs of `paras` fitting in `budget` chars; never the whole chunk.
+fn overlap_tail(paras: &[String], budget: usize) -> Vec<String> {
+    let mut used = 0usize;
+    let mut take = 0usize;
+    for p in paras.iter().rev().take(paras.len().saturating_sub(1)) {
+        let add = if take == 0 { p.len() } else { 2 + p.len() };
+        if used + add > budget {
+            break;
+        }
+        used += add;
+        take += 1;
+    }
+    paras[paras.len() - take..].to_vec()
+}
+
+fn chunk_text_by_paragraphs(text: &str, kind: &str, params: &ChunkingParams) -> Vec<ChunkDraft> {
+    let max_chars = params.target_chars();
     let normalized = normalize_text(text);
     let mut paras: Vec<String> = normalized
         .split("\n\n")
@@ -27,42 +121,23 @@ fn chunk_text_by_paragraphs(text: &str, kind: &str, max_chars: usize) -> Vec<Chu
     }
 
     let mut out = Vec::new();
-    let mut ordinal: u32 = 0;
-    let mut buf = String::new();
+    let mut buf: Vec<String> = Vec::new();
+    let mut buf_len = 0usize;
+    // Leading paragraphs of `buf` repeated from the previous chunk.
+    let mut carried = 0usize;
     for p in paras {
         let add_len = if buf.is_empty() { p.len() } else { 2 + p.len() };
-        if !buf.is_empty() && buf.len() + add_len > max_chars {
-            let text = buf.clone();
-            out.push(ChunkDraft {
-                ordinal,
-                token_count_est: text.len().min(u32::MAX as usize) as u32,
-                text,
-                meta: EvidenceChunkMeta {
-                    kind: kind.to_string(),
-                    incident_keys: None,
-                    time_range: None,
-                },
-            });
-            ordinal += 1;
-            buf.clear();
-        }
-        if !buf.is_empty() {
-            buf.push_str("\n\n");
+        if buf.len() > carried && buf_len + add_len > max_chars {
+            out.push(paragraph_chunk(out.len(), &buf, kind, params));
+            buf = overlap_tail(&buf, params.overlap_chars());
+            carried = buf.len();
+            buf_len = buf.iter().map(|b| b.len()).sum::<usize>() + 2 * carried.saturating_sub(1);
         }
-        buf.push_str(&p);
+        buf_len += if buf.is_empty() { p.len() } else { 2 + p.len() };
+        buf.push(p);
     }
-    if !buf.trim().is_empty() {
-        let text = buf;
-        out.push(ChunkDraft {
-            ordinal,
-            token_count_est: text.len().min(u32::MAX as usize) as u32,
-            text,
-            meta: EvidenceChunkMeta {
-                kind: kind.to_string(),
-                incident_keys: None,
-                time_range: None,
-            },
-        });
+    if buf.len() > carried && buf.iter().any(|p| !p.trim().is_empty()) {
+        out.push(paragraph_chunk(out.len(), &buf, kind, params));
     }
 
     out
@@ -71,11 +146,12 @@ fn chunk_text_by_paragraphs(text: &str, kind: &str, max_chars: usize) -> Vec<Chu
 pub(crate) fn build_chunks_for_source(
     store: &EvidenceStore,
     rec: &EvidenceSourceRecord,
+    params: &ChunkingParams,
 ) -> Result<Vec<ChunkDraft>, AppError> {
     match rec.source.source_type {
         EvidenceSourceType::FreeformText | EvidenceSourceType::SlackTranscript | EvidenceSourceType::IncidentReportMd => {
             let text = store.read_source_text_for_chunking(rec)?;
-            Ok(chunk_text_by_paragraphs(&text, "paragraph", 1600))
+            Ok(chunk_text_by_paragraphs(&text, "paragraph", params))
         }
         EvidenceSourceType::SanitizedExport => {
             let dir = rec
@@ -245,6 +321,9 @@ pub(crate) fn build_chunks_for_source(
                         start_ts: inc.start_ts.clone(),
                         end_ts: inc.resolve_ts.clone(),
                     }),
+                    // Structured bundle with redacted text: nothing to detect.
+                    language: None,
+                    chunking: None,
                 };
                 out.push(ChunkDraft {
                     ordinal: i as u32,
@@ -0,0 +1,215 @@
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::time::Duration;
+
+use qir_core::error::AppError;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::model::{EvidenceOrigin, EvidenceSource, EvidenceSourceType};
+use super::store::{canonical_json_string, sha256_hex, EvidenceSourceRecord, EvidenceStore};
+
+pub const MAX_COPY_ATTEMPTS: u32 = 10;
+
+/// How often a copy off a network share is retried before giving up.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CopyRetryPolicy {
+    pub max_attempts: u32,
+    pub retry_delay_ms: u64,
+}
+
+impl Default for CopyRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            retry_delay_ms: 500,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvidenceCopySourceInput {
+    #[serde(rename = "type")]
+    pub source_type: EvidenceSourceType,
+    /// File to copy, e.g. a UNC path such as `\\fileserver\ops\retro.md`.
+    pub origin_path: String,
+    pub label: String,
+    pub created_at: String,
+    /// When set, the copy must also match this sha256 (hex).
+    pub expected_sha256: Option<String>,
+    #[serde(default)]
+    pub retry: CopyRetryPolicy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EvidenceCopySourceResult {
+    pub source: EvidenceSource,
+    pub sha256: String,
+    pub bytes: u64,
+    pub attempts: u32,
+}
+
+enum AttemptFailure {
+    Io(String),
+    /// The copy differs from a second read of the origin or from `expected_sha256`.
+    Mismatch { expected: String, actual: String },
+}
+
+fn sha256_file(path: &Path) -> std::io::Result<(String, u64)> {
+    let mut f = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+    let mut total = 0u64;
+    loop {
+        let n = f.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        total += n as u64;
+        hasher.update(&buf[..n]);
+    }
+    Ok((hex::encode(hasher.finalize()), total))
+}
+
+/// One copy: write the staging file, then hash the origin again independently so a read
+/// that was cut short or changed underneath us does not verify against itself.
+fn copy_once(origin: &Path, staging: &Path, expected: Option<&str>) -> Result<(String, u64), AttemptFailure> {
+    let io = |stage: &str, e: std::io::Error| AttemptFailure::Io(format!("{stage}: {e}"));
+    fs::copy(origin, staging).map_err(|e| io("copy", e))?;
+    let (copied, bytes) = sha256_file(staging).map_err(|e| io("hash copy", e))?;
+    let (source, _) = sha256_file(origin).map_err(|e| io("hash origin", e))?;
+    let expected = expected.unwrap_or(&source);
+    if copied != source || copied != expected {
+        return Err(AttemptFailure::Mismatch {
+            expected: expected.to_string(),
+            actual: copied,
+        });
+    }
+    Ok((copied, bytes))
+}
+
+impl EvidenceStore {
+    /// Copies a file (typically from a network share) into the store and registers it as a
+    /// `copy` source whose `origin.path` keeps the original location. Each attempt is
+    /// verified by sha256; transient read errors and mismatches are retried per `retry`.
+    /// Chunking reads the stored copy, so the share need not stay reachable.
+    pub fn copy_file_source(&self, input: EvidenceCopySourceInput) -> Result<EvidenceCopySourceResult, AppError> {
+        self.ensure_dirs()?;
+
+        if input.label.trim().is_empty() {
+            return Err(AppError::new("AI_EVIDENCE_SOURCE_INVALID", "Evidence source label is required"));
+        }
+        if input.origin_path.trim().is_empty() {
+            return Err(AppError::new("AI_EVIDENCE_SOURCE_INVALID", "Evidence origin path is required"));
+        }
+        if input.source_type == EvidenceSourceType::SanitizedExport {
+            return Err(AppError::new(
+                "AI_EVIDENCE_SOURCE_INVALID",
+                "Sanitized exports are directories and cannot be copied as a single file",
+            ));
+        }
+        if !(1..=MAX_COPY_ATTEMPTS).contains(&input.retry.max_attempts) {
+            return Err(AppError::new("AI_EVIDENCE_SOURCE_INVALID", "Copy attempts are out of range").with_details(
+                format!("max_attempts={}; allowed=1..={}", input.retry.max_attempts, MAX_COPY_ATTEMPTS),
+            ));
+        }
+        let expected = match input.expected_sha256.as_deref().map(str::trim) {
+            Some(h) if h.len() == 64 && h.chars().all(|c| c.is_ascii_hexdigit()) => Some(h.to_ascii_lowercase()),
+            Some(h) => {
+                return Err(AppError::new(
+                    "AI_EVIDENCE_SOURCE_INVALID",
+                    "Expected checksum must be a 64-character sha256 hex string",
+                )
+                .with_details(format!("expected_sha256={h}")))
+            }
+            None => None,
+        };
+
+        let descriptor = canonical_json_string(&serde_json::json!({
+            "type": &input.source_type,
+            "origin": { "kind": "copy", "path": &input.origin_path },
+        }))?;
+        let source_id = sha256_hex(descriptor.as_bytes());
+        let origin = Path::new(&input.origin_path);
+        let ext = origin
+            .extension()
+            .map(|e| e.to_string_lossy().to_ascii_lowercase())
+            .filter(|e| !e.is_empty() && e.chars().all(|c| c.is_ascii_alphanumeric()))
+            .unwrap_or_else(|| "bin".to_string());
+        let rel = format!("sources/{source_id}.{ext}");
+        let staging = self.sources_dir().join(format!("{source_id}.partial"));
+
+        let mut attempts = 0u32;
+        let outcome = loop {
+            attempts += 1;
+            match copy_once(origin, &staging, expected.as_deref()) {
+                Ok(done) => break Ok(done),
+                Err(failure) if attempts >= input.retry.max_attempts => break Err(failure),
+                Err(_) => std::thread::sleep(Duration::from_millis(input.retry.retry_delay_ms)),
+            }
+        };
+        let (sha256, bytes) = match outcome {
+            Ok(done) => done,
+            Err(failure) => {
+                let _ = fs::remove_file(&staging);
+                return Err(match failure {
+                    AttemptFailure::Io(err) => {
+                        AppError::new("AI_EVIDENCE_COPY_FAILED", "Failed to copy evidence file").with_details(format!(
+                            "origin={}; attempts={attempts}; err={err}",
+                            input.origin_path
+                        ))
+                    }
+                    AttemptFailure::Mismatch { expected, actual } => AppError::new(
+                        "AI_EVIDENCE_COPY_HASH_MISMATCH",
+                        "Copied evidence file did not match its checksum",
+                    )
+                    .with_details(format!(
+                        "origin={}; attempts={attempts}; expected_sha256={expected}; actual_sha256={actual}",
+                        input.origin_path
+                    )),
+                });
+            }
+        };
+
+        let abs = self.root().join(&rel);
+        fs::rename(&staging, &abs).map_err(|e| {
+            let _ = fs::remove_file(&staging);
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to finalize copied evidence file")
+                .with_details(format!("path={}; err={}", abs.display(), e))
+        })?;
+
+        let source = EvidenceSource {
+            source_id: source_id.clone(),
+            source_type: input.source_type,
+            origin: EvidenceOrigin {
+                kind: "copy".to_string(),
+                path: Some(input.origin_path.clone()),
+            },
+            label: input.label,
+            created_at: input.created_at,
+        };
+        let mut records = self.read_sources()?;
+        if let Some(old) = records.iter().find(|r| r.source.source_id == source_id) {
+            // A re-copy with a different extension would otherwise leave the old file behind.
+            if let Some(old_rel) = old.content_rel_path.as_deref().filter(|r| *r != rel) {
+                let _ = fs::remove_file(self.root().join(old_rel));
+            }
+        }
+        records.retain(|r| r.source.source_id != source_id);
+        records.push(EvidenceSourceRecord {
+            source: source.clone(),
+            content_rel_path: Some(rel),
+            content_sha256: Some(sha256.clone()),
+        });
+        records.sort_by(|a, b| a.source.source_id.cmp(&b.source.source_id));
+        self.write_sources(&records)?;
+
+        Ok(EvidenceCopySourceResult {
+            source,
+            sha256,
+            bytes,
+            attempts,
+        })
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use qir_core::error::AppError;
+use serde::{Deserialize, Serialize};
+
+use super::model::{EvidenceChunk, EvidenceChunkSummary};
+use super::store::{sha256_hex, snippet_first_chars, EvidenceChunkSummaryRecord, EvidenceStore};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EvidenceFsckIssue {
+    /// source_unknown | chunk_missing | chunk_decode_failed | chunk_identity_mismatch |
+    /// chunk_hash_mismatch | summary_missing | summary_mismatch
+    pub kind: String,
+    pub source_id: Option<String>,
+    pub chunk_id: Option<String>,
+    pub details: Option<String>,
+}
+
+/// Result of `EvidenceStore::fsck`. `ok` and the issue lists describe the store as found;
+/// the `*_rewritten`/`*_dropped`/`files_deleted` counters are only non-zero in repair mode.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EvidenceFsckReport {
+    pub ok: bool,
+    pub sources_checked: u32,
+    pub chunks_checked: u32,
+    pub issues: Vec<EvidenceFsckIssue>,
+    /// Paths relative to the store root that nothing references (safe to delete).
+    pub unreferenced_files: Vec<String>,
+    pub repaired: bool,
+    pub summaries_rewritten: u32,
+    pub chunks_dropped: u32,
+    pub files_deleted: u32,
+}
+
+fn issue(kind: &str, source_id: &str, chunk_id: Option<&str>, details: Option<String>) -> EvidenceFsckIssue {
+    EvidenceFsckIssue {
+        kind: kind.to_string(),
+        source_id: Some(source_id.to_string()),
+        chunk_id: chunk_id.map(|s| s.to_string()),
+        details,
+    }
+}
+
+fn expected_summary(chunk: &EvidenceChunk) -> EvidenceChunkSummaryRecord {
+    EvidenceChunkSummaryRecord {
+        summary: EvidenceChunkSummary {
+            chunk_id: chunk.chunk_id.clone(),
+            source_id: chunk.source_id.clone(),
+            ordinal: chunk.ordinal,
+            text_sha256: chunk.text_sha256.clone(),
+            token_count_est: chunk.token_count_est,
+            meta: chunk.meta.clone(),
+        },
+        snippet: snippet_first_chars(&chunk.text, 280),
+    }
+}
+
+fn list_dir(dir: &Path) -> Result<Vec<String>, AppError> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let entries = fs::read_dir(dir).map_err(|e| {
+        AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to list evidence store directory")
+            .with_details(format!("path={}; err={}", dir.display(), e))
+    })?;
+    let mut names = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to list evidence store directory")
+                .with_details(format!("path={}; err={}", dir.display(), e))
+        })?;
+        if entry.path().is_file() {
+            names.push(entry.file_name().to_string_lossy().to_string());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+impl EvidenceStore {
+    /// Verifies that every chunk referenced by `chunks_by_source.json` exists, belongs to a
+    /// known source, matches its `text_sha256`, and has a consistent summary; lists files
+    /// nothing references. With `repair`, rewrites summaries from valid chunks, drops broken
+    /// chunks from the mapping (re-run build_chunks to regenerate them), and deletes
+    /// unreferenced files.
+    pub fn fsck(&self, repair: bool) -> Result<EvidenceFsckReport, AppError> {
+        self.ensure_dirs()?;
+        let sources = self.read_sources()?;
+        let known_sources: BTreeSet<&str> = sources.iter().map(|r| r.source.source_id.as_str()).collect();
+        let mut map = self.read_chunks_by_source()?;
+
+        let mut issues = Vec::new();
+        let mut chunks_checked = 0u32;
+        let mut referenced: BTreeSet<String> = BTreeSet::new();
+        let mut broken: Vec<(String, String)> = Vec::new();
+        let mut stale_summaries: Vec<EvidenceChunk> = Vec::new();
+
+        for (source_id, chunk_ids) in map.iter() {
+            let source_known = known_sources.contains(source_id.as_str());
+            if !source_known {
+                issues.push(issue("source_unknown", source_id, None, None));
+            }
+            for chunk_id in chunk_ids {
+                chunks_checked += 1;
+                if !source_known {
+                    broken.push((source_id.clone(), chunk_id.clone()));
+                    continue;
+                }
+                referenced.insert(chunk_id.clone());
+
+                let path = self.chunk_path(chunk_id);
+                let raw = match fs::read_to_string(&path) {
+                    Ok(raw) => raw,
+                    Err(e) => {
+                        issues.push(issue("chunk_missing", source_id, Some(chunk_id), Some(e.to_string())));
+                        broken.push((source_id.clone(), chunk_id.clone()));
+                        continue;
+                    }
+                };
+                let chunk: EvidenceChunk = match serde_json::from_str(&raw) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        issues.push(issue("chunk_decode_failed", source_id, Some(chunk_id), Some(e.to_string())));
+                        broken.push((source_id.clone(), chunk_id.clone()));
+                        continue;
+                    }
+                };
+                if &chunk.chunk_id != chunk_id || &chunk.source_id != source_id {
+                    issues.push(issue(
+                        "chunk_identity_mismatch",
+                        source_id,
+                        Some(chunk_id),
+                        Some(format!("file_chunk_id={}; file_source_id={}", chunk.chunk_id, chunk.source_id)),
+                    ));
+                    broken.push((source_id.clone(), chunk_id.clone()));
+                    continue;
+                }
+                let actual = sha256_hex(chunk.text.as_bytes());
+                if actual != chunk.text_sha256 {
+                    issues.push(issue(
+                        "chunk_hash_mismatch",
+                        source_id,
+                        Some(chunk_id),
+                        Some(format!("expected={}; actual={}", chunk.text_sha256, actual)),
+                    ));
+                    broken.push((source_id.clone(), chunk_id.clone()));
+                    continue;
+                }
+
+                let expected = expected_summary(&chunk);
+                match fs::read_to_string(self.chunk_summary_path(chunk_id)) {
+                    Err(_) => {
+                        issues.push(issue("summary_missing", source_id, Some(chunk_id), None));
+                        stale_summaries.push(chunk);
+                    }
+                    Ok(raw) => {
+                        let consistent = serde_json::from_str::<EvidenceChunkSummaryRecord>(&raw)
+                            .map(|r| r.summary == expected.summary && r.snippet == expected.snippet)
+                            .unwrap_or(false);
+                        if !consistent {
+                            issues.push(issue("summary_mismatch", source_id, Some(chunk_id), None));
+                            stale_summaries.push(chunk);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Anything on disk that the mapping/sources do not reference. Broken chunks are not
+        // listed here; they are reported as issues above.
+        let broken_ids: BTreeSet<&str> = broken.iter().map(|(_, c)| c.as_str()).collect();
+        let mut unreferenced_files = Vec::new();
+        for (dir, rel) in [
+            (self.chunks_dir(), "chunks"),
+            (self.chunk_summaries_dir(), "chunk_summaries"),
+        ] {
+            for name in list_dir(&dir)? {
+                let id = name.strip_suffix(".json").unwrap_or(&name);
+                if !referenced.contains(id) && !broken_ids.contains(id) {
+                    unreferenced_files.push(format!("{rel}/{name}"));
+                }
+            }
+        }
+        let source_content: BTreeSet<&str> = sources
+            .iter()
+            .filter_map(|r| r.content_rel_path.as_deref())
+            .collect();
+        for name in list_dir(&self.sources_dir())? {
+            let rel = format!("sources/{name}");
+            if !source_content.contains(rel.as_str()) {
+                unreferenced_files.push(rel);
+            }
+        }
+        // Leftovers from interrupted tmp->rename writes.
+        for name in list_dir(self.root())? {
+            if name.ends_with(".tmp") {
+                unreferenced_files.push(name);
+            }
+        }
+        unreferenced_files.sort();
+
+        let mut report = EvidenceFsckReport {
+            ok: issues.is_empty() && unreferenced_files.is_empty(),
+            sources_checked: sources.len() as u32,
+            chunks_checked,
+            issues,
+            unreferenced_files,
+            repaired: false,
+            summaries_rewritten: 0,
+            chunks_dropped: 0,
+            files_deleted: 0,
+        };
+        if !repair {
+            return Ok(report);
+        }
+
+        for chunk in &stale_summaries {
+            self.write_chunk_summary(chunk)?;
+            report.summaries_rewritten += 1;
+        }
+
+        if !broken.is_empty() {
+            for (source_id, chunk_id) in &broken {
+                if let Some(ids) = map.get_mut(source_id) {
+                    ids.retain(|c| c != chunk_id);
+                }
+            }
+            map.retain(|source_id, _| known_sources.contains(source_id.as_str()));
+            let ids: Vec<String> = broken.iter().map(|(_, c)| c.clone()).collect();
+            self.delete_chunks(&ids)?;
+            self.write_chunks_by_source(&map)?;
+            report.chunks_dropped = broken.len() as u32;
+        }
+
+        for rel in &report.unreferenced_files {
+            let path = self.root().join(rel);
+            fs::remove_file(&path).map_err(|e| {
+                AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to delete unreferenced evidence file")
+                    .with_details(format!("path={}; err={}", path.display(), e))
+            })?;
+            report.files_deleted += 1;
+        }
+
+        report.repaired = true;
+        Ok(report)
+    }
+}
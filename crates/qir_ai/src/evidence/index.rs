@@ -3,11 +3,13 @@ use std::fs;
 use std::path::PathBuf;
 
 use qir_core::error::AppError;
+use qir_core::progress::Progress;
 use serde::{Deserialize, Serialize};
 
 use crate::embeddings::Embedder;
+use crate::llm::STAGE_MODEL_LOADING;
 
-use super::store::EvidenceStore;
+use super::store::{EvidenceStore, RechunkSourceResult};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AiIndexStatus {
@@ -29,6 +31,37 @@ pub struct AiIndexBuildInput {
     pub updated_at: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiRechunkResult {
+    pub rechunk: RechunkSourceResult,
+    /// Index vectors dropped because their chunk (or section summary) no longer exists.
+    pub invalidated_vectors: u32,
+    pub index: AiIndexStatus,
+}
+
+/// Rebuilds `source_id`'s chunks with the store's chunking settings and drops only the index
+/// vectors of chunks that disappeared (plus the source's section summaries if they went
+/// stale). New chunks are embedded by the next index build.
+pub fn rechunk_source(
+    evidence: &EvidenceStore,
+    index: &IndexStore,
+    source_id: &str,
+    updated_at: &str,
+) -> Result<AiRechunkResult, AppError> {
+    let rechunk = evidence.rechunk_source(source_id, updated_at)?;
+    let mut stale = rechunk.removed_chunk_ids.clone();
+    // Section summaries survive only if every chunk they cover came out unchanged.
+    if evidence.read_source_summaries(source_id)?.is_none() {
+        stale.extend(evidence.delete_source_summaries(source_id)?);
+    }
+    let invalidated_vectors = index.invalidate_chunks(&stale)?;
+    Ok(AiRechunkResult {
+        rechunk,
+        invalidated_vectors,
+        index: index.status()?,
+    })
+}
+
 #[derive(Debug, Clone)]
 pub struct IndexStore {
     root: PathBuf,
@@ -175,13 +208,57 @@ impl IndexStore {
         })
     }
 
+    /// Drops vectors and hashes for `chunk_ids`, keeping the rest of the index intact.
+    /// Returns how many vectors were removed.
+    pub fn invalidate_chunks(&self, chunk_ids: &[String]) -> Result<u32, AppError> {
+        let mut vectors = self.read_vectors()?;
+        let mut hashes = self.read_hashes()?;
+        let before = vectors.len();
+        for id in chunk_ids {
+            vectors.remove(id);
+            hashes.remove(id);
+        }
+        let removed = (before - vectors.len()) as u32;
+        if removed == 0 {
+            return Ok(0);
+        }
+        self.write_vectors(&vectors)?;
+        self.write_hashes(&hashes)?;
+        let mut st = self.status()?;
+        st.chunk_count = vectors.len() as u32;
+        self.write_status(&st)?;
+        Ok(removed)
+    }
+
     pub fn build_with_embedder(
         &self,
         evidence: &EvidenceStore,
         embedder: &dyn Embedder,
         input: AiIndexBuildInput,
+    ) -> Result<AiIndexStatus, AppError> {
+        self.build_with_embedder_progress(evidence, embedder, input, &mut Progress::noop("ai_index_build"))
+    }
+
+    /// Same as `build_with_embedder`, reporting embedding progress once per batch.
+    pub fn build_with_embedder_progress(
+        &self,
+        evidence: &EvidenceStore,
+        embedder: &dyn Embedder,
+        input: AiIndexBuildInput,
+        progress: &mut Progress<'_>,
+    ) -> Result<AiIndexStatus, AppError> {
+        progress.run(|p| self.build_stages(evidence, embedder, input, p))
+    }
+
+    fn build_stages(
+        &self,
+        evidence: &EvidenceStore,
+        embedder: &dyn Embedder,
+        input: AiIndexBuildInput,
+        progress: &mut Progress<'_>,
     ) -> Result<AiIndexStatus, AppError> {
         self.ensure_dirs()?;
+        progress.stage("chunks", Some(2));
 
         let chunk_summaries = evidence.list_chunks(super::store::EvidenceQueryStore {
             include_text: false,
@@ -194,12 +271,29 @@ impl IndexStore {
             ));
         }
 
-        // Stable order: chunk_id asc (ties deterministic).
-        let mut ids = chunk_summaries
-            .iter()
-            .map(|c| c.chunk_id.clone())
-            .collect::<Vec<_>>();
-        ids.sort();
+        // Sources with current section summaries are indexed through those summaries
+        // (summarize-then-embed); every other source is indexed chunk by chunk.
+        let mut entry_hashes: BTreeMap<String, String> = BTreeMap::new();
+        let mut section_texts: BTreeMap<String, String> = BTreeMap::new();
+        let source_ids: std::collections::BTreeSet<&str> =
+            chunk_summaries.iter().map(|c| c.source_id.as_str()).collect();
+        let mut summarized: std::collections::BTreeSet<String> = std::collections::BTreeSet::new();
+        for sid in source_ids {
+            if let Some(sums) = evidence.read_source_summaries(sid)? {
+                for sec in sums.sections.iter() {
+                    let key = sums.section_key(sec);
+                    entry_hashes.insert(key.clone(), sec.summary_sha256.clone());
+                    section_texts.insert(key, sec.summary.clone());
+                }
+                summarized.insert(sid.to_string());
+            }
+        }
+        for c in chunk_summaries.iter().filter(|c| !summarized.contains(&c.source_id)) {
+            entry_hashes.insert(c.chunk_id.clone(), c.text_sha256.clone());
+        }
+
+        // Stable order: key asc (ties deterministic).
+        let ids = entry_hashes.keys().cloned().collect::<Vec<_>>();
 
         let mut current = self.status()?;
         let compatible = current.ready
@@ -223,46 +317,66 @@ impl IndexStore {
         hashes.retain(|k, _| wanted.contains(k));
 
         let mut to_embed: Vec<String> = Vec::new();
-        for s in chunk_summaries.iter() {
-            let existing_hash = hashes.get(&s.chunk_id);
-            let has_vec = vectors.contains_key(&s.chunk_id);
-            if existing_hash != Some(&s.text_sha256) || !has_vec {
-                to_embed.push(s.chunk_id.clone());
+        for (key, hash) in entry_hashes.iter() {
+            if hashes.get(key) != Some(hash) || !vectors.contains_key(key) {
+                to_embed.push(key.clone());
             }
         }
-        to_embed.sort();
-        to_embed.dedup();
 
         let mut dims: Option<u32> = if compatible { current.dims } else { None };
 
-        for chunk_id in to_embed.iter() {
-            let chunk = evidence.get_chunk(chunk_id)?;
-            let v = embedder.embed(&input.model, &chunk.text).map_err(|e| {
+        let batch_size = evidence.read_settings()?.embed_batch_size.max(1) as usize;
+        // The first batch also pays for loading the model; say so instead of sitting at 5%.
+        let loading = !to_embed.is_empty() && embedder.model_loaded(&input.model) == Some(false);
+        if loading {
+            progress.stage(STAGE_MODEL_LOADING, Some(5));
+        }
+        for (batch_no, batch) in to_embed.chunks(batch_size).enumerate() {
+            if !(loading && batch_no == 0) {
+                progress.step("embed", batch_no * batch_size, to_embed.len(), 5, 95);
+            }
+            let mut texts = Vec::with_capacity(batch.len());
+            for chunk_id in batch {
+                texts.push(match section_texts.get(chunk_id) {
+                    Some(summary) => summary.clone(),
+                    None => evidence.get_chunk(chunk_id)?.text,
+                });
+            }
+            let inputs: Vec<&str> = texts.iter().map(String::as_str).collect();
+            let batch_vectors = embedder.embed_batch(&input.model, &inputs).map_err(|e| {
                 AppError::new("AI_EMBEDDINGS_FAILED", "Failed to compute embeddings")
-                    .with_details(format!("chunk_id={}; err={}", chunk_id, e))
+                    .with_details(format!("first_chunk_id={}; batch_len={}; err={}", batch[0], batch.len(), e))
                     .with_retryable(e.retryable)
             })?;
-            let this_dims = v.len() as u32;
-            if let Some(d) = dims {
-                if d != this_dims {
-                    return Err(AppError::new(
-                        "AI_INDEX_BUILD_FAILED",
-                        "Embedding dimension mismatch across chunks",
-                    )
-                    .with_details(format!("expected={}; got={}; chunk_id={}", d, this_dims, chunk_id)));
+            if batch_vectors.len() != batch.len() {
+                return Err(AppError::new(
+                    "AI_EMBEDDINGS_FAILED",
+                    "Embeddings backend returned the wrong number of vectors",
+                )
+                .with_details(format!("expected={}; got={}", batch.len(), batch_vectors.len())));
+            }
+            for (chunk_id, v) in batch.iter().zip(batch_vectors) {
+                let this_dims = v.len() as u32;
+                if let Some(d) = dims {
+                    if d != this_dims {
+                        return Err(AppError::new(
+                            "AI_INDEX_BUILD_FAILED",
+                            "Embedding dimension mismatch across chunks",
+                        )
+                        .with_details(format!("expected={}; got={}; chunk_id={}", d, this_dims, chunk_id)));
+                    }
+                } else {
+                    dims = Some(this_dims);
                 }
-            } else {
-                dims = Some(this_dims);
+                vectors.insert(chunk_id.clone(), v);
             }
-            vectors.insert(chunk_id.clone(), v);
         }
 
-        // Update hashes from the current chunk summaries (stable).
-        for s in chunk_summaries.iter() {
-            hashes.insert(s.chunk_id.clone(), s.text_sha256.clone());
-        }
+        // Update hashes from the current entries (stable).
+        hashes.extend(entry_hashes);
 
         // Write results atomically (tmp->rename). Only after embeddings succeed.
+        progress.stage("write", Some(95));
         self.write_vectors(&vectors)?;
         self.write_hashes(&hashes)?;
 
@@ -0,0 +1,64 @@
+/// Language tags stored in chunk meta.
+pub const LANG_EN: &str = "en";
+pub const LANG_ES: &str = "es";
+/// Both languages contribute substantially to the chunk.
+pub const LANG_MIXED: &str = "mixed";
+
+// Short, high-frequency function words; words shared by both languages (e.g. "a", "no") are left out.
+const EN_WORDS: &[&str] = &[
+    "the", "and", "is", "are", "was", "were", "of", "to", "in", "on", "for", "with", "that", "this", "it", "we",
+    "they", "be", "have", "has", "not", "but", "from", "at", "by", "after", "before", "our", "will", "been",
+];
+const ES_WORDS: &[&str] = &[
+    "el", "la", "los", "las", "de", "del", "que", "y", "es", "en", "por", "para", "con", "una", "un", "se", "su",
+    "al", "lo", "como", "pero", "fue", "está", "están", "hay", "después", "antes", "nuestro", "ya", "muy",
+];
+
+/// Minimum number of function-word hits before a language is assigned.
+const MIN_HITS: usize = 3;
+/// Share of hits the minority language needs for the chunk to count as mixed.
+const MIXED_SHARE: f64 = 0.3;
+
+/// Detects English vs Spanish by counting common function words (plus Spanish-only
+/// punctuation/letters). Returns None when the text has too few signals to tell (code,
+/// timestamps, key/value bundles).
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    let mut en = 0usize;
+    let mut es = 0usize;
+    for word in text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+    {
+        let word = word.to_lowercase();
+        if EN_WORDS.contains(&word.as_str()) {
+            en += 1;
+        }
+        if ES_WORDS.contains(&word.as_str()) {
+            es += 1;
+        }
+    }
+    es += text.chars().filter(|c| matches!(c, 'ñ' | 'Ñ' | '¿' | '¡')).count();
+
+    let total = en + es;
+    if total < MIN_HITS {
+        return None;
+    }
+    let minority = en.min(es) as f64 / total as f64;
+    if minority >= MIXED_SHARE {
+        Some(LANG_MIXED)
+    } else if en > es {
+        Some(LANG_EN)
+    } else {
+        Some(LANG_ES)
+    }
+}
+
+/// Whether a chunk tagged `chunk_language` should be returned for a `wanted` language filter.
+/// Mixed chunks match either language; untagged chunks only match when no filter is set.
+pub fn language_matches(chunk_language: Option<&str>, wanted: Option<&str>) -> bool {
+    match (wanted, chunk_language) {
+        (None, _) => true,
+        (Some(w), Some(c)) => w == c || c == LANG_MIXED,
+        (Some(_), None) => false,
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+use qir_core::error::AppError;
+use serde::{Deserialize, Serialize};
+
+use super::store::{EvidenceQueryStore, EvidenceStore};
+
+/// Titles shorter than this are too generic ("Outage", "DNS issue") to link on.
+pub const MIN_TITLE_MATCH_CHARS: usize = 16;
+
+/// The workspace incident fields the linking pass matches on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentRef {
+    pub incident_id: i64,
+    pub external_id: Option<String>,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentLinkMatch {
+    /// The chunk mentions the external id as a whole word, or was chunked for that incident.
+    ExternalId,
+    /// The chunk contains the full incident title (case- and whitespace-insensitive).
+    Title,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentChunkLink {
+    pub incident_id: i64,
+    pub chunk_id: String,
+    pub source_id: String,
+    pub matched_on: IncidentLinkMatch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentLinks {
+    pub built_at: String,
+    pub incident_count: usize,
+    pub chunk_count: usize,
+    /// Sorted by incident id, then chunk id.
+    pub links: Vec<IncidentChunkLink>,
+}
+
+/// What incident detail shows: "N evidence chunks reference this incident".
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentEvidenceLinks {
+    pub incident_id: i64,
+    pub chunk_count: usize,
+    pub source_count: usize,
+    pub links: Vec<IncidentChunkLink>,
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Whole-word occurrence: the characters around the match must not be alphanumeric, so
+/// `INC-1` does not match inside `INC-10`.
+fn contains_word(haystack: &str, needle: &str) -> bool {
+    if needle.is_empty() {
+        return false;
+    }
+    haystack.match_indices(needle).any(|(pos, _)| {
+        let before = haystack[..pos].chars().next_back();
+        let after = haystack[pos + needle.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+impl EvidenceStore {
+    fn incident_links_path(&self) -> PathBuf {
+        self.root().join("incident_links.json")
+    }
+
+    /// Deterministic matching pass over every chunk; replaces the previous link set. Run it
+    /// after chunk builds so links always refer to current chunk ids.
+    pub fn rebuild_incident_links(&self, incidents: &[IncidentRef], built_at: &str) -> Result<IncidentLinks, AppError> {
+        let chunks = self.list_chunks(EvidenceQueryStore {
+            include_text: false,
+            source_id: None,
+        })?;
+        let needles: Vec<(i64, Option<String>, Option<String>)> = incidents
+            .iter()
+            .map(|i| {
+                let external_id = i.external_id.as_deref().map(normalize).filter(|e| !e.is_empty());
+                let title = Some(normalize(&i.title)).filter(|t| t.chars().count() >= MIN_TITLE_MATCH_CHARS);
+                (i.incident_id, external_id, title)
+            })
+            .collect();
+
+        let mut links = Vec::new();
+        for summary in &chunks {
+            let text = normalize(&self.get_chunk(&summary.chunk_id)?.text);
+            let keys: BTreeSet<String> = summary
+                .meta
+                .incident_keys
+                .iter()
+                .flatten()
+                .map(|k| normalize(k))
+                .collect();
+            for (incident_id, external_id, title) in &needles {
+                let by_id = external_id
+                    .as_ref()
+                    .is_some_and(|e| keys.contains(e) || contains_word(&text, e));
+                let matched_on = if by_id {
+                    IncidentLinkMatch::ExternalId
+                } else if title.as_ref().is_some_and(|t| text.contains(t.as_str())) {
+                    IncidentLinkMatch::Title
+                } else {
+                    continue;
+                };
+                links.push(IncidentChunkLink {
+                    incident_id: *incident_id,
+                    chunk_id: summary.chunk_id.clone(),
+                    source_id: summary.source_id.clone(),
+                    matched_on,
+                });
+            }
+        }
+        links.sort_by(|a, b| a.incident_id.cmp(&b.incident_id).then(a.chunk_id.cmp(&b.chunk_id)));
+
+        let out = IncidentLinks {
+            built_at: built_at.to_string(),
+            incident_count: incidents.len(),
+            chunk_count: chunks.len(),
+            links,
+        };
+        self.ensure_dirs()?;
+        let path = self.incident_links_path();
+        let tmp = path.with_extension("tmp");
+        let json = serde_json::to_string_pretty(&out).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to encode incident links").with_details(e.to_string())
+        })?;
+        fs::write(&tmp, json.as_bytes()).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to write incident links")
+                .with_details(format!("path={}; err={}", tmp.display(), e))
+        })?;
+        fs::rename(&tmp, &path).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to finalize incident links write")
+                .with_details(format!("tmp={}; dest={}; err={}", tmp.display(), path.display(), e))
+        })?;
+        Ok(out)
+    }
+
+    /// None until the first linking pass has run.
+    pub fn read_incident_links(&self) -> Result<Option<IncidentLinks>, AppError> {
+        let path = self.incident_links_path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        let bytes = fs::read(&path).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to read incident links")
+                .with_details(format!("path={}; err={}", path.display(), e))
+        })?;
+        serde_json::from_slice(&bytes).map(Some).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to decode incident links")
+                .with_details(format!("path={}; err={}", path.display(), e))
+        })
+    }
+
+    pub fn incident_evidence_links(&self, incident_id: i64) -> Result<IncidentEvidenceLinks, AppError> {
+        let links: Vec<IncidentChunkLink> = self
+            .read_incident_links()?
+            .map(|l| l.links)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|l| l.incident_id == incident_id)
+            .collect();
+        let source_count = links.iter().map(|l| l.source_id.as_str()).collect::<BTreeSet<_>>().len();
+        Ok(IncidentEvidenceLinks {
+            incident_id,
+            chunk_count: links.len(),
+            source_count,
+            links,
+        })
+    }
+}
@@ -1,11 +1,24 @@
 pub mod chunking;
+pub mod copy;
+pub mod fsck;
 pub mod index;
+pub mod language;
+pub mod links;
 pub mod model;
 pub mod store;
+pub mod summaries;
 
 pub use model::{
     Citation, CitationLocator, EvidenceChunk, EvidenceChunkMeta, EvidenceChunkSummary, EvidenceOrigin,
     EvidenceContextResponse, EvidenceSource, EvidenceSourceType, EvidenceTimeRange,
 };
-pub use store::{BuildChunksResult, EvidenceAddSourceInput, EvidenceQueryStore, EvidenceStore};
-pub use index::{AiIndexBuildInput, AiIndexStatus, IndexStore};
+pub use fsck::{EvidenceFsckIssue, EvidenceFsckReport};
+pub use chunking::ChunkingParams;
+pub use copy::{CopyRetryPolicy, EvidenceCopySourceInput, EvidenceCopySourceResult, MAX_COPY_ATTEMPTS};
+pub use store::{
+    AiStoreSettings, BuildChunksResult, EvidenceAddSourceInput, EvidenceQueryStore, EvidenceStore, RechunkSourceResult,
+    DEFAULT_EMBED_BATCH_SIZE,
+};
+pub use links::{IncidentChunkLink, IncidentEvidenceLinks, IncidentLinkMatch, IncidentLinks, IncidentRef};
+pub use summaries::{summarize_source, SectionSummary, SourceSummaries, SummarizeSourceInput};
+pub use index::{rechunk_source, AiIndexBuildInput, AiIndexStatus, AiRechunkResult, IndexStore};
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::chunking::ChunkingParams;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum EvidenceSourceType {
@@ -38,6 +40,12 @@ pub struct EvidenceChunkMeta {
     pub kind: String,
     pub incident_keys: Option<Vec<String>>,
     pub time_range: Option<EvidenceTimeRange>,
+    /// Detected language ("en", "es", "mixed"); absent when undetermined or chunked before detection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Parameters used to pack paragraph chunks; absent for structured chunks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunking: Option<ChunkingParams>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
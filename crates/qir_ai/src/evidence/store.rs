@@ -7,7 +7,7 @@ use qir_core::repo::{PaginationParams, PaginationResult};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
-use super::chunking::{build_chunks_for_source, ChunkDraft};
+use super::chunking::{build_chunks_for_source, ChunkDraft, ChunkingParams};
 use super::model::{
     Citation, CitationLocator, EvidenceChunk, EvidenceChunkSummary, EvidenceContextResponse,
     EvidenceOrigin, EvidenceSource, EvidenceSourceType,
@@ -31,6 +31,53 @@ pub struct BuildChunksResult {
     pub updated_at: String,
 }
 
+/// AI settings persisted in the store root (`ai_settings.json`), next to the data they shape.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AiStoreSettings {
+    #[serde(default)]
+    pub chunking: ChunkingParams,
+    /// Inputs sent per embeddings request during index builds.
+    #[serde(default = "default_embed_batch_size")]
+    pub embed_batch_size: u32,
+}
+
+pub const DEFAULT_EMBED_BATCH_SIZE: u32 = 32;
+pub const MAX_EMBED_BATCH_SIZE: u32 = 512;
+
+fn default_embed_batch_size() -> u32 {
+    DEFAULT_EMBED_BATCH_SIZE
+}
+
+impl Default for AiStoreSettings {
+    fn default() -> Self {
+        Self {
+            chunking: ChunkingParams::default(),
+            embed_batch_size: DEFAULT_EMBED_BATCH_SIZE,
+        }
+    }
+}
+
+impl AiStoreSettings {
+    pub fn validate(&self) -> Result<(), AppError> {
+        self.chunking.validate()?;
+        if !(1..=MAX_EMBED_BATCH_SIZE).contains(&self.embed_batch_size) {
+            return Err(AppError::new("AI_EMBED_BATCH_INVALID", "Embedding batch size is out of range")
+                .with_details(format!("embed_batch_size={}; allowed=1..={}", self.embed_batch_size, MAX_EMBED_BATCH_SIZE)));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RechunkSourceResult {
+    pub source_id: String,
+    pub params: ChunkingParams,
+    pub chunk_count: u32,
+    /// Chunk ids that existed before and were not reproduced (their index vectors are stale).
+    pub removed_chunk_ids: Vec<String>,
+    pub updated_at: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvidenceQueryStore {
     pub include_text: bool,
@@ -41,6 +88,9 @@ pub struct EvidenceQueryStore {
 pub(crate) struct EvidenceSourceRecord {
     pub source: EvidenceSource,
     pub content_rel_path: Option<String>,
+    /// Verified hash of a `copy` source's stored file; checked again before chunking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_sha256: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -49,7 +99,7 @@ pub struct EvidenceStore {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct EvidenceChunkSummaryRecord {
+pub(crate) struct EvidenceChunkSummaryRecord {
     pub summary: EvidenceChunkSummary,
     pub snippet: String,
 }
@@ -67,15 +117,15 @@ impl EvidenceStore {
         self.root.join("sources.json")
     }
 
-    fn sources_dir(&self) -> PathBuf {
+    pub(crate) fn sources_dir(&self) -> PathBuf {
         self.root.join("sources")
     }
 
-    fn chunks_dir(&self) -> PathBuf {
+    pub(crate) fn chunks_dir(&self) -> PathBuf {
         self.root.join("chunks")
     }
 
-    fn chunk_summaries_dir(&self) -> PathBuf {
+    pub(crate) fn chunk_summaries_dir(&self) -> PathBuf {
         self.root.join("chunk_summaries")
     }
 
@@ -83,6 +133,46 @@ impl EvidenceStore {
         self.root.join("chunks_by_source.json")
     }
 
+    fn settings_path(&self) -> PathBuf {
+        self.root.join("ai_settings.json")
+    }
+
+    pub fn read_settings(&self) -> Result<AiStoreSettings, AppError> {
+        let path = self.settings_path();
+        if !path.exists() {
+            return Ok(AiStoreSettings::default());
+        }
+        let bytes = fs::read(&path).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to read AI settings")
+                .with_details(format!("path={}; err={}", path.display(), e))
+        })?;
+        serde_json::from_slice(&bytes).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to decode AI settings")
+                .with_details(format!("path={}; err={}", path.display(), e))
+        })
+    }
+
+    /// Validates and saves settings. Existing chunks are not rebuilt; use `rechunk_source`.
+    pub fn write_settings(&self, settings: &AiStoreSettings) -> Result<(), AppError> {
+        settings.validate()?;
+        self.ensure_dirs()?;
+        let path = self.settings_path();
+        let tmp = path.with_extension("tmp");
+        let json = serde_json::to_string_pretty(settings).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to encode AI settings")
+                .with_details(e.to_string())
+        })?;
+        fs::write(&tmp, json.as_bytes()).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to write AI settings")
+                .with_details(format!("path={}; err={}", tmp.display(), e))
+        })?;
+        fs::rename(&tmp, &path).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to finalize AI settings write")
+                .with_details(format!("tmp={}; dest={}; err={}", tmp.display(), path.display(), e))
+        })?;
+        Ok(())
+    }
+
     pub fn ensure_dirs(&self) -> Result<(), AppError> {
         fs::create_dir_all(self.root.as_path()).map_err(|e| {
             AppError::new(
@@ -119,7 +209,7 @@ impl EvidenceStore {
         Ok(())
     }
 
-    fn read_sources(&self) -> Result<Vec<EvidenceSourceRecord>, AppError> {
+    pub(crate) fn read_sources(&self) -> Result<Vec<EvidenceSourceRecord>, AppError> {
         let path = self.sources_path();
         if !path.exists() {
             return Ok(Vec::new());
@@ -134,7 +224,7 @@ impl EvidenceStore {
         })
     }
 
-    fn write_sources(&self, records: &[EvidenceSourceRecord]) -> Result<(), AppError> {
+    pub(crate) fn write_sources(&self, records: &[EvidenceSourceRecord]) -> Result<(), AppError> {
         let path = self.sources_path();
         let tmp = path.with_extension("tmp");
         let json = serde_json::to_string_pretty(records).map_err(|e| {
@@ -237,6 +327,7 @@ impl EvidenceStore {
         records.push(EvidenceSourceRecord {
             source: source.clone(),
             content_rel_path,
+            content_sha256: None,
         });
 
         // Stable ordering for deterministic lists.
@@ -253,7 +344,7 @@ impl EvidenceStore {
         Ok(records.into_iter().map(|r| r.source).collect())
     }
 
-    fn read_chunks_by_source(&self) -> Result<BTreeMap<String, Vec<String>>, AppError> {
+    pub(crate) fn read_chunks_by_source(&self) -> Result<BTreeMap<String, Vec<String>>, AppError> {
         let path = self.chunks_by_source_path();
         if !path.exists() {
             return Ok(BTreeMap::new());
@@ -274,7 +365,7 @@ impl EvidenceStore {
         })
     }
 
-    fn write_chunks_by_source(&self, map: &BTreeMap<String, Vec<String>>) -> Result<(), AppError> {
+    pub(crate) fn write_chunks_by_source(&self, map: &BTreeMap<String, Vec<String>>) -> Result<(), AppError> {
         let path = self.chunks_by_source_path();
         let tmp = path.with_extension("tmp");
         let json = serde_json::to_string_pretty(map).map_err(|e| {
@@ -301,11 +392,11 @@ impl EvidenceStore {
         Ok(())
     }
 
-    fn chunk_path(&self, chunk_id: &str) -> PathBuf {
+    pub(crate) fn chunk_path(&self, chunk_id: &str) -> PathBuf {
         self.chunks_dir().join(format!("{chunk_id}.json"))
     }
 
-    fn chunk_summary_path(&self, chunk_id: &str) -> PathBuf {
+    pub(crate) fn chunk_summary_path(&self, chunk_id: &str) -> PathBuf {
         self.chunk_summaries_dir().join(format!("{chunk_id}.json"))
     }
 
@@ -322,7 +413,7 @@ impl EvidenceStore {
         Ok(())
     }
 
-    fn write_chunk_summary(&self, chunk: &EvidenceChunk) -> Result<(), AppError> {
+    pub(crate) fn write_chunk_summary(&self, chunk: &EvidenceChunk) -> Result<(), AppError> {
         let path = self.chunk_summary_path(&chunk.chunk_id);
         let summary = EvidenceChunkSummary {
             chunk_id: chunk.chunk_id.clone(),
@@ -455,7 +546,7 @@ impl EvidenceStore {
         })
     }
 
-    fn delete_chunks(&self, chunk_ids: &[String]) -> Result<(), AppError> {
+    pub(crate) fn delete_chunks(&self, chunk_ids: &[String]) -> Result<(), AppError> {
         for id in chunk_ids {
             let path = self.chunk_path(id);
             if path.exists() {
@@ -500,6 +591,33 @@ impl EvidenceStore {
         })
     }
 
+    fn load_copied_text(&self, rec: &EvidenceSourceRecord) -> Result<String, AppError> {
+        let rel = rec.content_rel_path.as_ref().ok_or_else(|| {
+            AppError::new("AI_EVIDENCE_SOURCE_INVALID", "Copied evidence missing stored content")
+        })?;
+        let abs = self.root.join(rel);
+        let bytes = fs::read(&abs).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to read copied evidence content")
+                .with_details(format!("path={}; err={}", abs.display(), e))
+        })?;
+        let actual = sha256_hex(&bytes);
+        if rec.content_sha256.as_deref() != Some(actual.as_str()) {
+            return Err(AppError::new(
+                "AI_EVIDENCE_COPY_HASH_MISMATCH",
+                "Copied evidence content no longer matches its recorded checksum",
+            )
+            .with_details(format!(
+                "path={}; expected_sha256={}; actual_sha256={actual}",
+                abs.display(),
+                rec.content_sha256.as_deref().unwrap_or("")
+            )));
+        }
+        String::from_utf8(bytes).map_err(|e| {
+            AppError::new("AI_EVIDENCE_SOURCE_INVALID", "Copied evidence file is not UTF-8 text")
+                .with_details(format!("path={}; err={}", abs.display(), e))
+        })
+    }
+
     pub fn build_chunks(
         &self,
         source_id: Option<String>,
@@ -519,25 +637,13 @@ impl EvidenceStore {
             ));
         }
 
+        let params = self.read_settings()?.chunking;
         let mut chunks_by_source = self.read_chunks_by_source()?;
         let mut total: u32 = 0;
 
         for rec in sources {
-            // Delete old chunks for this source to avoid stale data.
-            if let Some(old) = chunks_by_source.get(&rec.source.source_id) {
-                self.delete_chunks(old)?;
-            }
-
-            let drafts = build_chunks_for_source(self, &rec)?;
-            let mut chunk_ids = Vec::new();
-            for d in drafts {
-                let chunk = self.chunk_from_draft(&rec.source.source_id, d)?;
-                self.write_chunk(&chunk)?;
-                self.write_chunk_summary(&chunk)?;
-                chunk_ids.push(chunk.chunk_id);
-                total += 1;
-            }
-            chunks_by_source.insert(rec.source.source_id.clone(), chunk_ids);
+            let (_, chunk_ids) = self.replace_source_chunks(&rec, &params, &mut chunks_by_source)?;
+            total += chunk_ids.len() as u32;
         }
 
         self.write_chunks_by_source(&chunks_by_source)?;
@@ -549,6 +655,50 @@ impl EvidenceStore {
         })
     }
 
+    /// Rebuilds one source's chunks with the current chunking settings. Chunk ids are content
+    /// addressed, so chunks that come out identical keep their id (and their index vector).
+    pub fn rechunk_source(&self, source_id: &str, updated_at: &str) -> Result<RechunkSourceResult, AppError> {
+        self.ensure_dirs()?;
+        let rec = self.read_source_record(source_id)?;
+        let params = self.read_settings()?.chunking;
+        let mut chunks_by_source = self.read_chunks_by_source()?;
+        let (old_ids, new_ids) = self.replace_source_chunks(&rec, &params, &mut chunks_by_source)?;
+        self.write_chunks_by_source(&chunks_by_source)?;
+
+        let kept: std::collections::BTreeSet<&String> = new_ids.iter().collect();
+        Ok(RechunkSourceResult {
+            source_id: source_id.to_string(),
+            params,
+            chunk_count: new_ids.len() as u32,
+            removed_chunk_ids: old_ids.into_iter().filter(|id| !kept.contains(id)).collect(),
+            updated_at: updated_at.to_string(),
+        })
+    }
+
+    /// Replaces the chunks of `rec` in storage and in `chunks_by_source` (not persisted here).
+    /// Returns (previous ids, new ids).
+    fn replace_source_chunks(
+        &self,
+        rec: &EvidenceSourceRecord,
+        params: &ChunkingParams,
+        chunks_by_source: &mut BTreeMap<String, Vec<String>>,
+    ) -> Result<(Vec<String>, Vec<String>), AppError> {
+        // Delete old chunks for this source to avoid stale data.
+        let old_ids = chunks_by_source.get(&rec.source.source_id).cloned().unwrap_or_default();
+        self.delete_chunks(&old_ids)?;
+
+        let drafts = build_chunks_for_source(self, rec, params)?;
+        let mut chunk_ids = Vec::new();
+        for d in drafts {
+            let chunk = self.chunk_from_draft(&rec.source.source_id, d)?;
+            self.write_chunk(&chunk)?;
+            self.write_chunk_summary(&chunk)?;
+            chunk_ids.push(chunk.chunk_id);
+        }
+        chunks_by_source.insert(rec.source.source_id.clone(), chunk_ids.clone());
+        Ok((old_ids, chunk_ids))
+    }
+
     fn chunk_from_draft(&self, source_id: &str, d: ChunkDraft) -> Result<EvidenceChunk, AppError> {
         let text = normalize_text(&d.text);
         let text_sha256 = sha256_hex(text.as_bytes());
@@ -698,6 +848,9 @@ impl EvidenceStore {
         if rec.source.origin.kind == "paste" {
             return self.load_paste_text(rec);
         }
+        if rec.source.origin.kind == "copy" {
+            return self.load_copied_text(rec);
+        }
         let path = rec
             .source
             .origin
@@ -727,7 +880,7 @@ pub(crate) fn normalize_text(s: &str) -> String {
     s.replace("\r\n", "\n").replace('\r', "\n")
 }
 
-fn snippet_first_chars(text: &str, max_chars: usize) -> String {
+pub(crate) fn snippet_first_chars(text: &str, max_chars: usize) -> String {
     let t = text.trim();
     if t.len() <= max_chars {
         return t.to_string();
@@ -737,12 +890,12 @@ fn snippet_first_chars(text: &str, max_chars: usize) -> String {
     s
 }
 
-fn sha256_hex(bytes: &[u8]) -> String {
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
     let digest = Sha256::digest(bytes);
     hex::encode(digest)
 }
 
-fn canonical_json_string(v: &serde_json::Value) -> Result<String, AppError> {
+pub(crate) fn canonical_json_string(v: &serde_json::Value) -> Result<String, AppError> {
     let canon = canonicalize_json_value(v);
     serde_json::to_string(&canon).map_err(|e| {
         AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to canonicalize JSON")
@@ -0,0 +1,222 @@
+use std::fs;
+use std::path::PathBuf;
+
+use qir_core::error::AppError;
+use serde::{Deserialize, Serialize};
+
+use crate::llm::Llm;
+
+use super::store::{sha256_hex, EvidenceQueryStore, EvidenceStore};
+
+pub const SECTION_SUMMARY_PROMPT_VERSION: &str = "section_summary_v1";
+/// Index keys for section summaries; everything else in the index is a chunk id.
+pub const SECTION_KEY_PREFIX: &str = "section:";
+pub const DEFAULT_SECTION_CHUNKS: u32 = 8;
+
+/// One LLM summary standing in for a run of consecutive chunks at retrieval time. The summary
+/// is only embedded; hits resolve to `chunk_ids`, which remain the only citable text.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SectionSummary {
+    pub ordinal: u32,
+    pub chunk_ids: Vec<String>,
+    pub summary: String,
+    pub summary_sha256: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SourceSummaries {
+    pub source_id: String,
+    pub model: String,
+    pub prompt_version: String,
+    pub section_chunks: u32,
+    pub sections: Vec<SectionSummary>,
+    pub updated_at: String,
+}
+
+impl SourceSummaries {
+    pub fn section_key(&self, section: &SectionSummary) -> String {
+        section_key(&self.source_id, section.ordinal)
+    }
+}
+
+pub fn section_key(source_id: &str, ordinal: u32) -> String {
+    format!("{SECTION_KEY_PREFIX}{source_id}:{ordinal}")
+}
+
+/// Splits a `section:<source_id>:<ordinal>` index key.
+pub fn parse_section_key(key: &str) -> Option<(&str, u32)> {
+    let (source_id, ordinal) = key.strip_prefix(SECTION_KEY_PREFIX)?.rsplit_once(':')?;
+    Some((source_id, ordinal.parse().ok()?))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummarizeSourceInput {
+    pub source_id: String,
+    pub model: String,
+    /// Consecutive chunks per section; defaults to `DEFAULT_SECTION_CHUNKS`.
+    pub section_chunks: Option<u32>,
+    pub updated_at: String,
+}
+
+fn section_summary_prompt(label: &str, section: u32, total: u32, text: &str) -> String {
+    format!(
+        r#"You are summarizing section {section} of {total} of the document "{label}" so it can be found by search.
+
+Rules (non-negotiable):
+1) Use ONLY the text below. Do not invent facts.
+2) Keep system names, vendors, incident keys, timestamps and numbers exactly as written.
+3) Write 3-6 plain sentences. No headings, no lists, no citations.
+
+Section text:
+{text}
+"#
+    )
+}
+
+impl EvidenceStore {
+    fn summaries_dir(&self) -> PathBuf {
+        self.root().join("summaries")
+    }
+
+    fn summaries_path(&self, source_id: &str) -> PathBuf {
+        self.summaries_dir().join(format!("{source_id}.json"))
+    }
+
+    /// Stored summaries for `source_id`, or None when absent or stale (the source was re-chunked
+    /// since they were generated).
+    pub fn read_source_summaries(&self, source_id: &str) -> Result<Option<SourceSummaries>, AppError> {
+        let path = self.summaries_path(source_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let bytes = fs::read(&path).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to read section summaries")
+                .with_details(format!("path={}; err={}", path.display(), e))
+        })?;
+        let summaries: SourceSummaries = serde_json::from_slice(&bytes).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to decode section summaries")
+                .with_details(format!("path={}; err={}", path.display(), e))
+        })?;
+
+        let current: Vec<String> = self
+            .list_chunks(EvidenceQueryStore {
+                include_text: false,
+                source_id: Some(source_id.to_string()),
+            })?
+            .into_iter()
+            .map(|c| c.chunk_id)
+            .collect();
+        let covered: Vec<String> = summaries.sections.iter().flat_map(|s| s.chunk_ids.clone()).collect();
+        Ok((covered == current).then_some(summaries))
+    }
+
+    fn write_source_summaries(&self, summaries: &SourceSummaries) -> Result<(), AppError> {
+        let dir = self.summaries_dir();
+        fs::create_dir_all(&dir).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to create summaries directory")
+                .with_details(format!("path={}; err={}", dir.display(), e))
+        })?;
+        let path = self.summaries_path(&summaries.source_id);
+        let tmp = path.with_extension("tmp");
+        let json = serde_json::to_string_pretty(summaries).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to encode section summaries")
+                .with_details(e.to_string())
+        })?;
+        fs::write(&tmp, json.as_bytes()).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to write section summaries")
+                .with_details(format!("path={}; err={}", tmp.display(), e))
+        })?;
+        fs::rename(&tmp, &path).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to finalize section summaries write")
+                .with_details(format!("tmp={}; dest={}; err={}", tmp.display(), path.display(), e))
+        })?;
+        Ok(())
+    }
+
+    /// Removes a source's summaries, returning the index keys they occupied.
+    pub fn delete_source_summaries(&self, source_id: &str) -> Result<Vec<String>, AppError> {
+        let path = self.summaries_path(source_id);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let keys = match fs::read(&path)
+            .ok()
+            .and_then(|b| serde_json::from_slice::<SourceSummaries>(&b).ok())
+        {
+            Some(s) => s.sections.iter().map(|sec| s.section_key(sec)).collect(),
+            None => Vec::new(),
+        };
+        fs::remove_file(&path).map_err(|e| {
+            AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to delete section summaries")
+                .with_details(format!("path={}; err={}", path.display(), e))
+        })?;
+        Ok(keys)
+    }
+}
+
+/// Summarize-then-embed for very long sources: groups the source's chunks into sections of
+/// `section_chunks` consecutive chunks and asks the local LLM for one summary per section.
+/// The next index build embeds these summaries instead of the source's chunks.
+pub fn summarize_source(
+    evidence: &EvidenceStore,
+    llm: &dyn Llm,
+    input: SummarizeSourceInput,
+) -> Result<SourceSummaries, AppError> {
+    let section_chunks = input.section_chunks.unwrap_or(DEFAULT_SECTION_CHUNKS);
+    if section_chunks == 0 {
+        return Err(AppError::new("AI_CHUNKING_INVALID", "Section size must be at least one chunk"));
+    }
+    let label = evidence
+        .list_sources()?
+        .into_iter()
+        .find(|s| s.source_id == input.source_id)
+        .map(|s| s.label)
+        .ok_or_else(|| {
+            AppError::new("AI_EVIDENCE_SOURCE_INVALID", "Evidence source not found")
+                .with_details(format!("source_id={}", input.source_id))
+        })?;
+    let chunks = evidence.list_chunks(EvidenceQueryStore {
+        include_text: false,
+        source_id: Some(input.source_id.clone()),
+    })?;
+    if chunks.is_empty() {
+        return Err(AppError::new(
+            "AI_INDEX_NOT_READY",
+            "No chunks available; build chunks before summarizing",
+        )
+        .with_details(format!("source_id={}", input.source_id)));
+    }
+
+    let groups: Vec<_> = chunks.chunks(section_chunks as usize).collect();
+    let total = groups.len() as u32;
+    let mut sections = Vec::new();
+    for (i, group) in groups.into_iter().enumerate() {
+        let mut texts = Vec::new();
+        for c in group {
+            texts.push(evidence.get_chunk(&c.chunk_id)?.text);
+        }
+        let prompt = section_summary_prompt(&label, i as u32 + 1, total, &texts.join("\n\n"));
+        let summary = llm.generate(&input.model, &prompt)?.trim().to_string();
+        if summary.is_empty() {
+            return Err(AppError::new("AI_DRAFT_FAILED", "Section summary was empty")
+                .with_details(format!("source_id={}; section={}", input.source_id, i)));
+        }
+        sections.push(SectionSummary {
+            ordinal: i as u32,
+            chunk_ids: group.iter().map(|c| c.chunk_id.clone()).collect(),
+            summary_sha256: sha256_hex(summary.as_bytes()),
+            summary,
+        });
+    }
+
+    let out = SourceSummaries {
+        source_id: input.source_id,
+        model: input.model,
+        prompt_version: SECTION_SUMMARY_PROMPT_VERSION.to_string(),
+        section_chunks,
+        sections,
+        updated_at: input.updated_at,
+    };
+    evidence.write_source_summaries(&out)?;
+    Ok(out)
+}
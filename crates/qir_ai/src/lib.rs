@@ -1,10 +1,14 @@
 pub mod evidence;
 pub mod embeddings;
+pub mod demo;
 pub mod draft;
 pub mod guardrails;
 pub mod llm;
 pub mod ollama;
+pub mod queue;
 pub mod retrieve;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 #[cfg(test)]
 mod tests {
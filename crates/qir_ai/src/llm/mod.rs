@@ -1,7 +1,22 @@
 use qir_core::error::AppError;
 
+/// Progress stages for AI operations whose first response can be slow: the backend is
+/// loading model weights vs. the model is producing output.
+pub const STAGE_MODEL_LOADING: &str = "model_loading";
+pub const STAGE_GENERATING: &str = "generating";
+
 pub trait Llm {
     fn generate(&self, model: &str, prompt: &str) -> Result<String, AppError>;
+
+    /// Whether `model` is already loaded by the backend; None when it cannot tell.
+    fn model_loaded(&self, _model: &str) -> Option<bool> {
+        None
+    }
+
+    /// Loads `model` without generating, so load time can be reported separately.
+    fn load_model(&self, _model: &str) -> Result<(), AppError> {
+        Ok(())
+    }
 }
 
 pub mod ollama_llm;
@@ -27,7 +27,40 @@ struct GenerateResponse {
     response: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct LoadRequest<'a> {
+    model: &'a str,
+}
+
 impl Llm for OllamaLlm {
+    fn model_loaded(&self, model: &str) -> Option<bool> {
+        self.client.is_model_loaded(model)
+    }
+
+    /// `/api/generate` with no prompt only loads the model into memory.
+    fn load_model(&self, model: &str) -> Result<(), AppError> {
+        let url = format!("{}/api/generate", self.client.base_url());
+        let resp = ureq::post(&url)
+            .timeout(std::time::Duration::from_secs(120))
+            .send_json(serde_json::to_value(LoadRequest { model }).map_err(|e| {
+                AppError::new("AI_DRAFT_FAILED", "Failed to encode model load request")
+                    .with_details(e.to_string())
+            })?);
+
+        match resp {
+            Ok(r) if r.status() == 200 => Ok(()),
+            Ok(r) => Err(
+                AppError::new("AI_DRAFT_FAILED", "Model load request failed")
+                    .with_details(format!("model={}; status={}", model, r.status())),
+            ),
+            Err(e) => Err(
+                AppError::new("AI_DRAFT_FAILED", "Failed to load model")
+                    .with_details(format!("model={}; err={}", model, e))
+                    .with_retryable(true),
+            ),
+        }
+    }
+
     fn generate(&self, model: &str, prompt: &str) -> Result<String, AppError> {
         let url = format!("{}/api/generate", self.client.base_url());
         let req = GenerateRequest {
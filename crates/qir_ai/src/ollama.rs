@@ -20,6 +20,32 @@ pub struct OllamaModelInfo {
     pub digest: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct OllamaPsResponse {
+    pub models: Vec<OllamaLoadedModel>,
+}
+
+/// A model currently resident in Ollama's memory (`/api/ps`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct OllamaLoadedModel {
+    pub name: String,
+    pub model: Option<String>,
+    pub size_vram: Option<u64>,
+    pub expires_at: Option<String>,
+}
+
+impl OllamaLoadedModel {
+    /// Ollama reports tagged names; an untagged request name means `:latest`.
+    pub fn matches(&self, requested: &str) -> bool {
+        let requested = if requested.contains(':') {
+            requested.to_string()
+        } else {
+            format!("{requested}:latest")
+        };
+        self.name == requested || self.model.as_deref() == Some(requested.as_str())
+    }
+}
+
 impl OllamaClient {
     /// Create a client for Ollama. This is strictly limited to `127.0.0.1`.
     pub fn new(base_url: &str) -> Result<Self, AppError> {
@@ -115,6 +141,39 @@ impl OllamaClient {
         }
     }
 
+    pub fn loaded_models(&self) -> Result<Vec<OllamaLoadedModel>, AppError> {
+        let url = format!("{}/api/ps", self.base_url);
+        let resp = ureq::get(&url)
+            .timeout(std::time::Duration::from_millis(800))
+            .call();
+
+        match resp {
+            Ok(r) if r.status() == 200 => {
+                let v: OllamaPsResponse = r.into_json().map_err(|e| {
+                    AppError::new("AI_OLLAMA_UNHEALTHY", "Failed to decode Ollama ps response")
+                        .with_details(e.to_string())
+                })?;
+                Ok(v.models)
+            }
+            Ok(r) => Err(
+                AppError::new("AI_OLLAMA_UNHEALTHY", "Ollama ps request failed")
+                    .with_details(format!("status={}", r.status())),
+            ),
+            Err(e) => Err(
+                AppError::new("AI_OLLAMA_UNHEALTHY", "Failed to reach Ollama on 127.0.0.1")
+                    .with_details(e.to_string())
+                    .with_retryable(true),
+            ),
+        }
+    }
+
+    /// Best-effort residency probe: None when `/api/ps` is unavailable (older Ollama, errors).
+    pub fn is_model_loaded(&self, model: &str) -> Option<bool> {
+        self.loaded_models()
+            .ok()
+            .map(|models| models.iter().any(|m| m.matches(model)))
+    }
+
     pub fn base_url(&self) -> &str {
         self.base_url.as_str()
     }
@@ -0,0 +1,149 @@
+use std::collections::VecDeque;
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AiQueueEntry {
+    pub ticket: u64,
+    pub operation_id: String,
+    pub operation: String,
+    pub model: String,
+}
+
+/// Queue feedback for one waiting operation; `position` is 1-based among waiters.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AiQueuePosition {
+    pub operation_id: String,
+    pub operation: String,
+    pub model: String,
+    pub position: u32,
+    pub running_model: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AiQueueSnapshot {
+    pub running: Vec<AiQueueEntry>,
+    pub waiting: Vec<AiQueueEntry>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    next_ticket: u64,
+    running: Vec<AiQueueEntry>,
+    waiting: VecDeque<AiQueueEntry>,
+}
+
+impl QueueState {
+    fn running_model(&self) -> Option<&str> {
+        self.running.first().map(|e| e.model.as_str())
+    }
+
+    /// FIFO, one model at a time: an entry starts once every waiter ahead of it uses the same
+    /// model and whatever is running (if anything) uses that model too. Same-model requests
+    /// share the loaded weights, so they may overlap; a different model waits for the GPU.
+    fn can_start(&self, ticket: u64) -> bool {
+        let Some(pos) = self.waiting.iter().position(|e| e.ticket == ticket) else {
+            return false;
+        };
+        let model = &self.waiting[pos].model;
+        self.waiting.iter().take(pos).all(|e| &e.model == model)
+            && self.running_model().is_none_or(|m| m == model)
+    }
+
+    fn position(&self, ticket: u64) -> u32 {
+        self.waiting
+            .iter()
+            .position(|e| e.ticket == ticket)
+            .map_or(0, |p| p as u32 + 1)
+    }
+}
+
+/// Serializes local AI operations so an index build and a draft do not load two models onto
+/// the GPU at once. Held for the duration of an operation via `AiQueueGuard`.
+#[derive(Default)]
+pub struct AiOperationQueue {
+    state: Mutex<QueueState>,
+    changed: Condvar,
+}
+
+impl AiOperationQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        // A panicking operation must not wedge every later AI request.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Blocks until the operation may run. `on_wait` is called whenever the queue position
+    /// changes while waiting (never if the operation starts immediately).
+    pub fn acquire(
+        &self,
+        operation_id: &str,
+        operation: &str,
+        model: &str,
+        mut on_wait: impl FnMut(&AiQueuePosition),
+    ) -> AiQueueGuard<'_> {
+        let mut state = self.lock();
+        state.next_ticket += 1;
+        let ticket = state.next_ticket;
+        let entry = AiQueueEntry {
+            ticket,
+            operation_id: operation_id.to_string(),
+            operation: operation.to_string(),
+            model: model.to_string(),
+        };
+        state.waiting.push_back(entry.clone());
+
+        let mut last_reported: Option<(u32, Option<String>)> = None;
+        while !state.can_start(ticket) {
+            let position = state.position(ticket);
+            let running_model = state.running_model().map(str::to_string);
+            if last_reported.as_ref() != Some(&(position, running_model.clone())) {
+                on_wait(&AiQueuePosition {
+                    operation_id: entry.operation_id.clone(),
+                    operation: entry.operation.clone(),
+                    model: entry.model.clone(),
+                    position,
+                    running_model: running_model.clone(),
+                });
+                last_reported = Some((position, running_model));
+            }
+            state = self.changed.wait(state).unwrap_or_else(|e| e.into_inner());
+        }
+
+        state.waiting.retain(|e| e.ticket != ticket);
+        state.running.push(entry);
+        drop(state);
+        // Others behind us may now be able to start alongside (same model) or move up.
+        self.changed.notify_all();
+        AiQueueGuard { queue: self, ticket }
+    }
+
+    pub fn snapshot(&self) -> AiQueueSnapshot {
+        let state = self.lock();
+        AiQueueSnapshot {
+            running: state.running.clone(),
+            waiting: state.waiting.iter().cloned().collect(),
+        }
+    }
+
+    fn release(&self, ticket: u64) {
+        self.lock().running.retain(|e| e.ticket != ticket);
+        self.changed.notify_all();
+    }
+}
+
+/// Releases the operation's slot when dropped, including on error or panic.
+pub struct AiQueueGuard<'a> {
+    queue: &'a AiOperationQueue,
+    ticket: u64,
+}
+
+impl Drop for AiQueueGuard<'_> {
+    fn drop(&mut self) {
+        self.queue.release(self.ticket);
+    }
+}
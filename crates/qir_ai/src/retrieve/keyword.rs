@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+
+use qir_core::error::AppError;
+
+use crate::evidence::language::language_matches;
+use crate::evidence::{EvidenceQueryStore, EvidenceStore};
+
+use super::{EvidenceQueryHit, EvidenceQueryResponse, RetrievalMode};
+
+// Standard Okapi BM25 parameters.
+const K1: f32 = 1.2;
+const B: f32 = 0.75;
+
+fn tokens(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// BM25 keyword search over every stored chunk. Needs no embeddings index and no Ollama, so
+/// evidence search keeps working when semantic retrieval is unavailable. Ordering matches
+/// semantic retrieval: score desc, then chunk_id asc.
+pub fn query_keyword(
+    evidence: &EvidenceStore,
+    query: &str,
+    top_k: u32,
+    source_filter: Option<&[String]>,
+    language_filter: Option<&str>,
+) -> Result<EvidenceQueryResponse, AppError> {
+    let q = query.trim();
+    if q.is_empty() {
+        return Err(AppError::new("AI_RETRIEVAL_FAILED", "Query must not be empty"));
+    }
+    let top_k = top_k.clamp(1, 50) as usize;
+    let mut query_terms = tokens(q);
+    query_terms.sort();
+    query_terms.dedup();
+    if query_terms.is_empty() {
+        return Err(AppError::new("AI_RETRIEVAL_FAILED", "Query has no searchable words")
+            .with_details(format!("query={q}")));
+    }
+
+    let summaries = evidence.list_chunks(EvidenceQueryStore {
+        include_text: false,
+        source_id: None,
+    })?;
+    // (chunk summary, term frequencies for the query terms, document length)
+    let mut docs = Vec::new();
+    for summary in summaries {
+        if let Some(filter) = source_filter {
+            if !filter.iter().any(|sid| sid == &summary.source_id) {
+                continue;
+            }
+        }
+        if !language_matches(summary.meta.language.as_deref(), language_filter) {
+            continue;
+        }
+        let words = tokens(&evidence.get_chunk(&summary.chunk_id)?.text);
+        let mut tf: BTreeMap<&str, u32> = BTreeMap::new();
+        for w in &words {
+            if let Some(term) = query_terms.iter().find(|t| *t == w) {
+                *tf.entry(term.as_str()).or_default() += 1;
+            }
+        }
+        docs.push((summary, tf, words.len() as f32));
+    }
+    if docs.is_empty() {
+        return Ok(EvidenceQueryResponse {
+            hits: Vec::new(),
+            mode: RetrievalMode::Keyword,
+            fallback_reason: None,
+        });
+    }
+
+    let n = docs.len() as f32;
+    let avg_len = docs.iter().map(|(_, _, len)| len).sum::<f32>() / n;
+    let idf: BTreeMap<&str, f32> = query_terms
+        .iter()
+        .map(|t| {
+            let df = docs.iter().filter(|(_, tf, _)| tf.contains_key(t.as_str())).count() as f32;
+            (t.as_str(), ((n - df + 0.5) / (df + 0.5) + 1.0).ln())
+        })
+        .collect();
+
+    let mut scored: Vec<(f32, usize)> = docs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (_, tf, len))| {
+            let norm = K1 * (1.0 - B + B * len / avg_len.max(1.0));
+            let score: f32 = tf
+                .iter()
+                .map(|(term, f)| {
+                    let f = *f as f32;
+                    idf[term] * f * (K1 + 1.0) / (f + norm)
+                })
+                .sum();
+            (score > 0.0).then_some((score, i))
+        })
+        .collect();
+    scored.sort_by(|a, b| {
+        b.0.partial_cmp(&a.0)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(docs[a.1].0.chunk_id.cmp(&docs[b.1].0.chunk_id))
+    });
+
+    let mut hits = Vec::new();
+    for (score, i) in scored.into_iter().take(top_k) {
+        let summary = &docs[i].0;
+        hits.push(EvidenceQueryHit {
+            chunk_id: summary.chunk_id.clone(),
+            source_id: summary.source_id.clone(),
+            score,
+            snippet: evidence.get_chunk_snippet(&summary.chunk_id)?,
+            citation: evidence.citation_for_summary(summary),
+            via_section: None,
+        });
+    }
+    Ok(EvidenceQueryResponse {
+        hits,
+        mode: RetrievalMode::Keyword,
+        fallback_reason: None,
+    })
+}
@@ -1,11 +1,18 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use qir_core::error::AppError;
 use serde::{Deserialize, Serialize};
 
 use crate::embeddings::Embedder;
+use crate::evidence::language::language_matches;
+use crate::evidence::summaries::{parse_section_key, SourceSummaries};
 use crate::evidence::{Citation, EvidenceStore, IndexStore};
 
+mod keyword;
 mod similarity;
 
+pub use keyword::query_keyword;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvidenceQueryHit {
     pub chunk_id: String,
@@ -13,11 +20,78 @@ pub struct EvidenceQueryHit {
     pub score: f32,
     pub snippet: String,
     pub citation: Citation,
+    /// Set when the hit came from a section summary (summarize-then-embed); the chunk is one of
+    /// the verbatim chunks that summary covers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub via_section: Option<String>,
+}
+
+/// How hits were ranked. Keyword hits are BM25 word matches, not semantic similarity, and
+/// their scores are not comparable with cosine scores.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RetrievalMode {
+    #[default]
+    Semantic,
+    Keyword,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvidenceQueryResponse {
     pub hits: Vec<EvidenceQueryHit>,
+    #[serde(default)]
+    pub mode: RetrievalMode,
+    /// Error code that made semantic retrieval unavailable, when keyword search stood in for it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback_reason: Option<String>,
+}
+
+/// Semantic retrieval when possible, otherwise BM25 keyword search labeled as such. Falls back
+/// when no embedder is available (Ollama unreachable), the index is not built, or the query
+/// cannot be embedded; other errors (e.g. an empty query) are returned as-is.
+pub fn query_with_fallback(
+    evidence: &EvidenceStore,
+    index: &IndexStore,
+    embedder: Option<&dyn Embedder>,
+    query: &str,
+    top_k: u32,
+    source_filter: Option<&[String]>,
+    language_filter: Option<&str>,
+) -> Result<EvidenceQueryResponse, AppError> {
+    let reason = match embedder {
+        None => "AI_OLLAMA_UNHEALTHY".to_string(),
+        Some(embedder) => {
+            match query_with_embedder(evidence, index, embedder, query, top_k, source_filter, language_filter) {
+                Err(e) if e.code == "AI_INDEX_NOT_READY" || e.code == "AI_EMBEDDINGS_FAILED" => e.code,
+                other => return other,
+            }
+        }
+    };
+    let mut res = query_keyword(evidence, query, top_k, source_filter, language_filter)?;
+    res.fallback_reason = Some(reason);
+    Ok(res)
+}
+
+/// Score multiplier for chunks linked to the incident being investigated.
+pub const INCIDENT_LINK_BOOST: f32 = 1.25;
+
+/// Boosts hits whose chunk is linked to the incident (see `EvidenceStore::rebuild_incident_links`),
+/// re-sorts with the usual tie-break (score desc, then chunk_id asc) and keeps the top `top_k`.
+/// Query with a larger `top_k` first so linked chunks just below the cut can move up.
+pub fn boost_incident_linked_hits(res: &mut EvidenceQueryResponse, linked_chunk_ids: &BTreeSet<String>, top_k: u32) {
+    for hit in res.hits.iter_mut() {
+        if linked_chunk_ids.contains(&hit.chunk_id) {
+            // Scale by magnitude so negative cosine scores still move up.
+            hit.score += hit.score.abs() * (INCIDENT_LINK_BOOST - 1.0);
+        }
+    }
+    res.hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.chunk_id.cmp(&b.chunk_id))
+    });
+    res.hits.truncate(top_k.clamp(1, 50) as usize);
 }
 
 pub fn query_with_embedder(
@@ -27,6 +101,7 @@ pub fn query_with_embedder(
     query: &str,
     top_k: u32,
     source_filter: Option<&[String]>,
+    language_filter: Option<&str>,
 ) -> Result<EvidenceQueryResponse, AppError> {
     let q = query.trim();
     if q.is_empty() {
@@ -74,22 +149,58 @@ pub fn query_with_embedder(
         ));
     }
 
-    let mut hits: Vec<(String, f32)> = Vec::new();
+    // (index key, score, chunk ids the key resolves to). Section-summary keys resolve to the
+    // verbatim chunks they summarize; those chunks are what gets returned and cited.
+    let mut hits: Vec<(String, f32, Vec<String>)> = Vec::new();
+    let mut summaries_by_source: BTreeMap<String, Option<SourceSummaries>> = BTreeMap::new();
 
-    for (chunk_id, v) in vectors.iter() {
+    for (key, v) in vectors.iter() {
         if v.len() as u32 != dims {
             return Err(AppError::new(
                 "AI_RETRIEVAL_FAILED",
                 "Index vector dims mismatch",
             )
-            .with_details(format!("chunk_id={chunk_id}; expected={dims}; got={}", v.len())));
+            .with_details(format!("chunk_id={key}; expected={dims}; got={}", v.len())));
         }
 
-        if let Some(filter) = source_filter {
-            let summary = evidence.get_chunk_summary(chunk_id)?;
-            if !filter.iter().any(|sid| sid == &summary.source_id) {
-                continue;
+        let mut chunk_ids = match parse_section_key(key) {
+            Some((source_id, ordinal)) => {
+                if !summaries_by_source.contains_key(source_id) {
+                    let sums = evidence.read_source_summaries(source_id)?;
+                    summaries_by_source.insert(source_id.to_string(), sums);
+                }
+                match summaries_by_source
+                    .get(source_id)
+                    .and_then(|s| s.as_ref())
+                    .and_then(|s| s.sections.iter().find(|sec| sec.ordinal == ordinal))
+                {
+                    Some(sec) => sec.chunk_ids.clone(),
+                    // Stale section vector (summaries regenerated or removed); skip until rebuild.
+                    None => continue,
+                }
             }
+            None => vec![key.clone()],
+        };
+
+        if source_filter.is_some() || language_filter.is_some() {
+            let mut kept = Vec::new();
+            for chunk_id in chunk_ids {
+                let summary = evidence.get_chunk_summary(&chunk_id)?;
+                if let Some(filter) = source_filter {
+                    if !filter.iter().any(|sid| sid == &summary.source_id) {
+                        continue;
+                    }
+                }
+                // Mixed-language chunks match either language filter.
+                if !language_matches(summary.meta.language.as_deref(), language_filter) {
+                    continue;
+                }
+                kept.push(chunk_id);
+            }
+            chunk_ids = kept;
+        }
+        if chunk_ids.is_empty() {
+            continue;
         }
 
         let vnorm = similarity::l2_norm(v);
@@ -97,7 +208,7 @@ pub fn query_with_embedder(
             continue;
         }
         let score = similarity::cosine_similarity(&qv, v, qnorm, vnorm);
-        hits.push((chunk_id.clone(), score));
+        hits.push((key.clone(), score, chunk_ids));
     }
 
     hits.sort_by(|a, b| {
@@ -105,20 +216,34 @@ pub fn query_with_embedder(
             .unwrap_or(std::cmp::Ordering::Equal)
             .then(a.0.cmp(&b.0))
     });
-    hits.truncate(top_k as usize);
 
     let mut out: Vec<EvidenceQueryHit> = Vec::new();
-    for (chunk_id, score) in hits {
-        let summary = evidence.get_chunk_summary(&chunk_id)?;
-        let snippet = evidence.get_chunk_snippet(&chunk_id)?;
-        out.push(EvidenceQueryHit {
-            chunk_id: summary.chunk_id.clone(),
-            source_id: summary.source_id.clone(),
-            score,
-            snippet,
-            citation: evidence.citation_for_summary(&summary),
-        });
+    let mut seen: BTreeSet<String> = BTreeSet::new();
+    'hits: for (key, score, chunk_ids) in hits {
+        let via_section = parse_section_key(&key).map(|_| key.clone());
+        for chunk_id in chunk_ids {
+            if out.len() >= top_k as usize {
+                break 'hits;
+            }
+            if !seen.insert(chunk_id.clone()) {
+                continue;
+            }
+            let summary = evidence.get_chunk_summary(&chunk_id)?;
+            let snippet = evidence.get_chunk_snippet(&chunk_id)?;
+            out.push(EvidenceQueryHit {
+                chunk_id: summary.chunk_id.clone(),
+                source_id: summary.source_id.clone(),
+                score,
+                snippet,
+                citation: evidence.citation_for_summary(&summary),
+                via_section: via_section.clone(),
+            });
+        }
     }
 
-    Ok(EvidenceQueryResponse { hits: out })
+    Ok(EvidenceQueryResponse {
+        hits: out,
+        mode: RetrievalMode::Semantic,
+        fallback_reason: None,
+    })
 }
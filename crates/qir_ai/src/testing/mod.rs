@@ -0,0 +1,253 @@
+// Test support for qir_ai and the Tauri layer: deterministic synthetic evidence plus
+// mock embedder/LLM implementations so the draft pipeline runs without Ollama.
+// Compiled only with `--features testing`; never shipped in the app.
+
+use std::sync::Mutex;
+
+use qir_core::error::AppError;
+
+use crate::embeddings::hashed::HashedEmbedder;
+use crate::embeddings::Embedder;
+use crate::evidence::store::sha256_hex;
+use crate::evidence::{
+    EvidenceAddSourceInput, EvidenceChunkSummary, EvidenceOrigin, EvidenceQueryStore, EvidenceSource,
+    EvidenceSourceType, EvidenceStore,
+};
+use crate::llm::Llm;
+
+pub const SYNTHETIC_CREATED_AT: &str = "2026-01-01T00:00:00Z";
+
+const SERVICES: [&str; 8] = [
+    "checkout", "search", "login", "payments", "inventory", "notifications", "billing", "reporting",
+];
+const SYMPTOMS: [&str; 6] = [
+    "elevated error rates",
+    "slow responses",
+    "failed logins",
+    "stale dashboards",
+    "dropped messages",
+    "timeouts from an upstream vendor",
+];
+const ACTIONS: [&str; 6] = [
+    "rolled back the latest deploy",
+    "failed over to the secondary region",
+    "restarted the worker pool",
+    "raised the connection limit",
+    "disabled the feature flag",
+    "paged the vendor on-call",
+];
+const FOLLOW_UPS: [&str; 5] = [
+    "add an alert on queue depth",
+    "document the failover runbook",
+    "load test before the next release",
+    "review vendor timeout settings",
+    "automate the rollback step",
+];
+
+/// splitmix64: tiny, fast and identical on every platform, which is all the generator needs.
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform-enough value in `0..n` (`n` must be non-zero).
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+}
+
+fn synthetic_paragraph(rng: &mut SeededRng, source_idx: usize, para_idx: usize) -> String {
+    let service = rng.pick(&SERVICES);
+    let symptom = rng.pick(&SYMPTOMS);
+    let action = rng.pick(&ACTIONS);
+    let follow_up = rng.pick(&FOLLOW_UPS);
+    let minutes = 5 + rng.below(175);
+    let pct = 1 + rng.below(99);
+    format!(
+        "INC-{}{:02}: The {service} service showed {symptom} affecting about {pct}% of requests. \
+         Responders {action} and service recovered after {minutes} minutes. \
+         Follow-up: {follow_up}.",
+        100 + source_idx,
+        para_idx
+    )
+}
+
+/// `source_count` paste sources, each with `paragraphs_per_source` incident-style paragraphs.
+/// The same seed always yields byte-identical inputs, so source and chunk ids are stable too.
+pub fn synthetic_sources(seed: u64, source_count: usize, paragraphs_per_source: usize) -> Vec<EvidenceAddSourceInput> {
+    let mut rng = SeededRng::new(seed);
+    let types = [
+        EvidenceSourceType::FreeformText,
+        EvidenceSourceType::SlackTranscript,
+        EvidenceSourceType::IncidentReportMd,
+    ];
+    (0..source_count)
+        .map(|s| {
+            let paragraphs: Vec<String> = (0..paragraphs_per_source.max(1))
+                .map(|p| synthetic_paragraph(&mut rng, s, p))
+                .collect();
+            EvidenceAddSourceInput {
+                source_type: types[s % types.len()].clone(),
+                // Paste source ids derive from type + origin, so a synthetic path keeps them distinct.
+                origin: EvidenceOrigin {
+                    kind: "paste".to_string(),
+                    path: Some(format!("synthetic://seed-{seed}/source-{s}")),
+                },
+                label: format!("Synthetic source {s} (seed {seed})"),
+                created_at: SYNTHETIC_CREATED_AT.to_string(),
+                text: Some(paragraphs.join("\n\n")),
+            }
+        })
+        .collect()
+}
+
+/// Adds `synthetic_sources(..)` to `store`, chunks them, and returns every chunk in store order.
+pub fn seed_synthetic_evidence(
+    store: &EvidenceStore,
+    seed: u64,
+    source_count: usize,
+    paragraphs_per_source: usize,
+) -> Result<(Vec<EvidenceSource>, Vec<EvidenceChunkSummary>), AppError> {
+    let mut sources = Vec::new();
+    for input in synthetic_sources(seed, source_count, paragraphs_per_source) {
+        sources.push(store.add_source(input)?);
+    }
+    store.build_chunks(None, SYNTHETIC_CREATED_AT)?;
+    let chunks = store.list_chunks(EvidenceQueryStore {
+        include_text: false,
+        source_id: None,
+    })?;
+    Ok((sources, chunks))
+}
+
+/// One call made against a mock, in call order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedCall {
+    pub model: String,
+    pub input: String,
+    pub output_sha256: String,
+}
+
+fn record(calls: &Mutex<Vec<RecordedCall>>, model: &str, input: &str, output_sha256: String) {
+    if let Ok(mut calls) = calls.lock() {
+        calls.push(RecordedCall {
+            model: model.to_string(),
+            input: input.to_string(),
+            output_sha256,
+        });
+    }
+}
+
+fn recorded(calls: &Mutex<Vec<RecordedCall>>) -> Vec<RecordedCall> {
+    calls.lock().map(|c| c.clone()).unwrap_or_default()
+}
+
+/// `HashedEmbedder` that records every call.
+#[derive(Debug)]
+pub struct MockEmbedder {
+    inner: HashedEmbedder,
+    calls: Mutex<Vec<RecordedCall>>,
+}
+
+impl Default for MockEmbedder {
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+impl MockEmbedder {
+    pub fn new(dims: usize) -> Self {
+        Self {
+            inner: HashedEmbedder::new(dims),
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn calls(&self) -> Vec<RecordedCall> {
+        recorded(&self.calls)
+    }
+}
+
+impl Embedder for MockEmbedder {
+    fn embed(&self, model: &str, input: &str) -> Result<Vec<f32>, AppError> {
+        let v = self.inner.embed_text(input);
+        let bytes: Vec<u8> = v.iter().flat_map(|x| x.to_le_bytes()).collect();
+        record(&self.calls, model, input, sha256_hex(&bytes));
+        Ok(v)
+    }
+}
+
+/// Cites every evidence block in the prompt, one `- ` item per chunk separated by blank lines,
+/// which satisfies the citation rules of every section (list items and paragraphs alike).
+/// `with_response` replaces the generated text with a canned answer.
+#[derive(Debug, Default)]
+pub struct MockLlm {
+    response: Option<String>,
+    calls: Mutex<Vec<RecordedCall>>,
+}
+
+impl MockLlm {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_response(response: impl Into<String>) -> Self {
+        Self {
+            response: Some(response.into()),
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn calls(&self) -> Vec<RecordedCall> {
+        recorded(&self.calls)
+    }
+}
+
+/// Chunk ids of the evidence blocks in a draft prompt (`[[chunk:<id>]] source_id=...` headers).
+pub fn prompt_chunk_ids(prompt: &str) -> Vec<String> {
+    prompt
+        .lines()
+        .filter_map(|line| line.strip_prefix("[[chunk:"))
+        .filter_map(|rest| rest.split_once("]] source_id="))
+        .map(|(id, _)| id.to_string())
+        .collect()
+}
+
+impl Llm for MockLlm {
+    fn generate(&self, model: &str, prompt: &str) -> Result<String, AppError> {
+        let out = match &self.response {
+            Some(r) => r.clone(),
+            None => {
+                let ids = prompt_chunk_ids(prompt);
+                if ids.is_empty() {
+                    return Err(AppError::new("AI_DRAFT_FAILED", "Mock LLM prompt contained no evidence blocks"));
+                }
+                let digest = sha256_hex(prompt.as_bytes());
+                ids.iter()
+                    .enumerate()
+                    .map(|(i, id)| format!("- Finding {} ({}) [[chunk:{id}]]", i + 1, &digest[..8]))
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            }
+        };
+        record(&self.calls, model, prompt, sha256_hex(out.as_bytes()));
+        Ok(out)
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use qir_ai::queue::{AiOperationQueue, AiQueuePosition};
+
+fn wait_for_waiting(queue: &AiOperationQueue, n: usize) {
+    for _ in 0..500 {
+        if queue.snapshot().waiting.len() == n {
+            return;
+        }
+        thread::sleep(Duration::from_millis(2));
+    }
+    panic!("queue never reached {n} waiting operations");
+}
+
+/// Acquires on a background thread; sends each position update, then `None` once running.
+fn spawn_acquire(
+    queue: &Arc<AiOperationQueue>,
+    operation_id: &'static str,
+    model: &'static str,
+) -> (mpsc::Receiver<Option<AiQueuePosition>>, thread::JoinHandle<()>) {
+    let (tx, rx) = mpsc::channel();
+    let queue = Arc::clone(queue);
+    let handle = thread::spawn(move || {
+        let updates = tx.clone();
+        let _turn = queue.acquire(operation_id, "op", model, |pos| {
+            updates.send(Some(pos.clone())).unwrap();
+        });
+        tx.send(None).unwrap();
+    });
+    (rx, handle)
+}
+
+#[test]
+fn a_different_model_waits_with_position_feedback() {
+    let queue = Arc::new(AiOperationQueue::new());
+    let build = queue.acquire("build-1", "ai_index_build", "nomic-embed-text", |_| panic!("queue was empty"));
+
+    let (rx, handle) = spawn_acquire(&queue, "draft-1", "llama3.2");
+    let pos = rx.recv().unwrap().expect("queued");
+    assert_eq!(pos.position, 1);
+    assert_eq!(pos.running_model.as_deref(), Some("nomic-embed-text"));
+    let snap = queue.snapshot();
+    assert_eq!(snap.running.len(), 1);
+    assert_eq!(snap.waiting[0].operation_id, "draft-1");
+
+    drop(build);
+    assert_eq!(rx.recv().unwrap(), None, "runs once the GPU is free");
+    handle.join().unwrap();
+    assert!(queue.snapshot().running.is_empty());
+}
+
+#[test]
+fn same_model_operations_share_the_slot() {
+    let queue = Arc::new(AiOperationQueue::new());
+    let _first = queue.acquire("draft-1", "ai_draft_section", "llama3.2", |_| panic!("queue was empty"));
+    let (rx, handle) = spawn_acquire(&queue, "draft-2", "llama3.2");
+    assert_eq!(rx.recv().unwrap(), None, "starts without queueing");
+    handle.join().unwrap();
+}
+
+#[test]
+fn same_model_requests_do_not_jump_ahead_of_other_models() {
+    let queue = Arc::new(AiOperationQueue::new());
+    let build = queue.acquire("build-1", "ai_index_build", "nomic-embed-text", |_| panic!("queue was empty"));
+
+    let (draft_rx, draft) = spawn_acquire(&queue, "draft-1", "llama3.2");
+    wait_for_waiting(&queue, 1);
+    let (query_rx, query) = spawn_acquire(&queue, "query-1", "nomic-embed-text");
+    assert_eq!(query_rx.recv().unwrap().expect("queued").position, 2);
+
+    drop(build);
+    // The draft goes first; the query follows once the draft releases the GPU.
+    assert_eq!(draft_rx.recv_timeout(Duration::from_secs(5)).unwrap().map(|p| p.position), Some(1));
+    assert_eq!(draft_rx.recv_timeout(Duration::from_secs(5)).unwrap(), None);
+    draft.join().unwrap();
+    let mut last = query_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    while last.is_some() {
+        last = query_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    }
+    query.join().unwrap();
+}
@@ -0,0 +1,52 @@
+use qir_ai::draft::preview::resolve_citation_previews;
+use qir_ai::evidence::{EvidenceAddSourceInput, EvidenceOrigin, EvidenceQueryStore, EvidenceSourceType, EvidenceStore};
+use tempfile::tempdir;
+
+#[test]
+fn resolves_all_markers_in_one_call_and_flags_unknown_ids() {
+    let dir = tempdir().unwrap();
+    let store = EvidenceStore::open(dir.path().to_path_buf());
+    let source = store
+        .add_source(EvidenceAddSourceInput {
+            source_type: EvidenceSourceType::FreeformText,
+            origin: EvidenceOrigin {
+                kind: "paste".to_string(),
+                path: None,
+            },
+            label: "Bridge notes".to_string(),
+            created_at: "2026-02-10T00:00:00Z".to_string(),
+            text: Some(format!("{}\n\n{}", "a".repeat(900), "b".repeat(900))),
+        })
+        .unwrap();
+    store
+        .build_chunks(Some(source.source_id.clone()), "2026-02-10T00:00:00Z")
+        .unwrap();
+    let chunks = store
+        .list_chunks(EvidenceQueryStore {
+            include_text: false,
+            source_id: Some(source.source_id.clone()),
+        })
+        .unwrap();
+    let (a, b) = (&chunks[0].chunk_id, &chunks[1].chunk_id);
+
+    let markdown = format!(
+        "- one [[chunk:{a}]]\n- two [[chunk:{b}]] and again [[chunk:{a}]]\n- typo [[chunk:deadbeef]] [[chunk:../sources]]"
+    );
+    let previews = resolve_citation_previews(&store, &markdown).unwrap();
+
+    assert_eq!(previews.len(), 4);
+    let pa = &previews[a.as_str()];
+    assert!(pa.found);
+    assert_eq!(pa.source_label.as_deref(), Some("Bridge notes"));
+    assert!(pa.snippet.as_deref().unwrap().starts_with("aaa"));
+    let loc = pa.locator.as_ref().unwrap();
+    assert_eq!(loc.source_id, source.source_id);
+    assert_eq!(loc.ordinal, 0);
+    assert_eq!(previews[b.as_str()].locator.as_ref().unwrap().ordinal, 1);
+
+    for missing in ["deadbeef", "../sources"] {
+        let p = &previews[missing];
+        assert!(!p.found);
+        assert!(p.snippet.is_none() && p.locator.is_none());
+    }
+}
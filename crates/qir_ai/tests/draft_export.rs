@@ -0,0 +1,166 @@
+use std::io::Read;
+
+use qir_ai::draft::export::{export_draft, export_draft_with_disclaimer, render_draft_docx, render_draft_markdown, DraftExportFormat};
+use qir_ai::evidence::{EvidenceAddSourceInput, EvidenceOrigin, EvidenceQueryStore, EvidenceSourceType, EvidenceStore};
+use qir_core::ai_drafts::{AiDraftArtifact, AiDraftSectionType};
+use tempfile::tempdir;
+
+fn store_with_chunks(root: &std::path::Path) -> (EvidenceStore, Vec<String>) {
+    let store = EvidenceStore::open(root.to_path_buf());
+    let text = format!("{}\n\n{}", "a".repeat(900), "b".repeat(900));
+    let source = store
+        .add_source(EvidenceAddSourceInput {
+            source_type: EvidenceSourceType::FreeformText,
+            origin: EvidenceOrigin {
+                kind: "paste".to_string(),
+                path: None,
+            },
+            label: "Ops notes & timeline".to_string(),
+            created_at: "2026-02-10T00:00:00Z".to_string(),
+            text: Some(text),
+        })
+        .unwrap();
+    store
+        .build_chunks(Some(source.source_id.clone()), "2026-02-10T00:00:00Z")
+        .unwrap();
+    let ids = store
+        .list_chunks(EvidenceQueryStore {
+            include_text: false,
+            source_id: Some(source.source_id),
+        })
+        .unwrap()
+        .into_iter()
+        .map(|c| c.chunk_id)
+        .collect();
+    (store, ids)
+}
+
+fn draft(text: String, chunk_ids: &[String]) -> AiDraftArtifact {
+    AiDraftArtifact {
+        id: 7,
+        quarter_label: "Q1 2026".to_string(),
+        section_type: AiDraftSectionType::ThemeAnalysis,
+        draft_text: text,
+        citation_chunk_ids: chunk_ids.to_vec(),
+        model_name: "m".to_string(),
+        model_params_hash: "h".to_string(),
+        prompt_template_version: "theme_analysis_v1".to_string(),
+        created_at: "2026-02-10T00:00:00Z".to_string(),
+        artifact_hash: "x".to_string(),
+        parent_draft_id: None,
+        revision_number: 1,
+        revision_notes: None,
+        branch_label: None,
+    }
+}
+
+#[test]
+fn markdown_numbers_footnotes_by_first_appearance() {
+    let dir = tempdir().unwrap();
+    let (store, ids) = store_with_chunks(dir.path());
+    let text = format!(
+        "- Vendor outages dominated [[chunk:{b}]]\n- Paging gaps [[chunk:{a}]][[chunk:{b}]]",
+        a = ids[0],
+        b = ids[1]
+    );
+    let (md, notes) = render_draft_markdown(&store, &draft(text, &ids)).unwrap();
+
+    assert_eq!(notes.len(), 2);
+    assert_eq!(notes[0].chunk_id, ids[1]);
+    assert_eq!(notes[0].ordinal, 1);
+    assert_eq!(notes[1].chunk_id, ids[0]);
+    assert!(!md.contains("[[chunk:"));
+    assert!(md.starts_with("# Q1 2026 — Theme Analysis\n\n"));
+    assert!(md.contains("- Vendor outages dominated [^1]\n- Paging gaps [^2][^1]\n"));
+    assert!(md.contains(&format!(
+        "\n[^1]: Ops notes & timeline, chunk 1, sha256 {}\n",
+        notes[0].text_sha256
+    )));
+}
+
+#[test]
+fn unknown_chunk_and_uncited_drafts_are_rejected() {
+    let dir = tempdir().unwrap();
+    let (store, ids) = store_with_chunks(dir.path());
+
+    let err = render_draft_markdown(&store, &draft("x [[chunk:missing]]".to_string(), &ids)).unwrap_err();
+    assert_eq!(err.code, "AI_CITATION_INVALID");
+
+    let err = render_draft_markdown(&store, &draft("no markers".to_string(), &ids)).unwrap_err();
+    assert_eq!(err.code, "AI_CITATION_REQUIRED");
+}
+
+#[test]
+fn docx_contains_native_footnotes_and_is_deterministic() {
+    let dir = tempdir().unwrap();
+    let (store, ids) = store_with_chunks(dir.path());
+    let d = draft(format!("## Themes\nSaturation <db> [[chunk:{}]]", ids[0]), &ids);
+
+    let (bytes, notes) = render_draft_docx(&store, &d).unwrap();
+    let (again, _) = render_draft_docx(&store, &d).unwrap();
+    assert_eq!(bytes, again);
+    assert_eq!(notes.len(), 1);
+
+    let mut zip = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+    let mut document = String::new();
+    zip.by_name("word/document.xml").unwrap().read_to_string(&mut document).unwrap();
+    assert!(document.contains(r#"<w:footnoteReference w:id="1"/>"#));
+    assert!(document.contains("Saturation &lt;db&gt; "));
+    assert!(!document.contains("[[chunk:"));
+
+    let mut footnotes = String::new();
+    zip.by_name("word/footnotes.xml").unwrap().read_to_string(&mut footnotes).unwrap();
+    assert!(footnotes.contains("Ops notes &amp; timeline, chunk 0"));
+    assert!(footnotes.contains(&notes[0].text_sha256));
+}
+
+#[test]
+fn export_writes_named_file_into_destination() {
+    let dir = tempdir().unwrap();
+    let out = tempdir().unwrap();
+    let (store, ids) = store_with_chunks(dir.path());
+    let d = draft(format!("Summary [[chunk:{}]]", ids[0]), &ids);
+
+    let res = export_draft(&store, &d, DraftExportFormat::Markdown, out.path()).unwrap();
+    assert!(res.path.ends_with("Q1_2026_theme_analysis_draft_7.md"));
+    let written = std::fs::read_to_string(&res.path).unwrap();
+    assert_eq!(written.len() as u64, res.bytes);
+    assert!(written.contains("Summary [^1]"));
+
+    let res = export_draft(&store, &d, DraftExportFormat::Docx, out.path()).unwrap();
+    assert!(res.path.ends_with(".docx"));
+
+    let err = export_draft(&store, &d, DraftExportFormat::Docx, &out.path().join("missing")).unwrap_err();
+    assert_eq!(err.code, "AI_DRAFT_EXPORT_FAILED");
+}
+
+#[test]
+fn export_appends_policy_disclaimer() {
+    let dir = tempdir().unwrap();
+    let out = tempdir().unwrap();
+    let (store, ids) = store_with_chunks(dir.path());
+    let d = draft(format!("Summary [[chunk:{}]]", ids[0]), &ids);
+
+    let res = export_draft_with_disclaimer(
+        &store,
+        &d,
+        DraftExportFormat::Markdown,
+        out.path(),
+        Some(" AI-assisted; verify <sources> "),
+    )
+    .unwrap();
+    let written = std::fs::read_to_string(&res.path).unwrap();
+    assert!(written.contains("Summary [^1]\n\n_AI-assisted; verify <sources>_\n\n[^1]: "));
+
+    let res = export_draft_with_disclaimer(&store, &d, DraftExportFormat::Docx, out.path(), Some("AI-assisted")).unwrap();
+    let mut zip = zip::ZipArchive::new(std::fs::File::open(&res.path).unwrap()).unwrap();
+    let mut document = String::new();
+    zip.by_name("word/document.xml").unwrap().read_to_string(&mut document).unwrap();
+    assert!(document.contains(r#"<w:i/></w:rPr><w:t xml:space="preserve">AI-assisted</w:t>"#));
+
+    // A blank disclaimer renders exactly like a plain export.
+    let blank = export_draft_with_disclaimer(&store, &d, DraftExportFormat::Markdown, out.path(), Some("  ")).unwrap();
+    let blank = std::fs::read_to_string(&blank.path).unwrap();
+    let plain = export_draft(&store, &d, DraftExportFormat::Markdown, out.path()).unwrap();
+    assert_eq!(blank, std::fs::read_to_string(&plain.path).unwrap());
+}
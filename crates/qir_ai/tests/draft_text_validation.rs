@@ -0,0 +1,76 @@
+use qir_ai::draft::validate_draft_text;
+use qir_ai::evidence::{EvidenceAddSourceInput, EvidenceOrigin, EvidenceQueryStore, EvidenceSourceType, EvidenceStore};
+
+const TS: &str = "2026-02-10T00:00:00Z";
+const MISSING_CHUNK: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+fn setup(dir: &std::path::Path) -> (EvidenceStore, String) {
+    let evidence = EvidenceStore::open(dir.to_path_buf());
+    let source = evidence
+        .add_source(EvidenceAddSourceInput {
+            source_type: EvidenceSourceType::FreeformText,
+            origin: EvidenceOrigin {
+                kind: "paste".to_string(),
+                path: None,
+            },
+            label: "timeline".to_string(),
+            created_at: TS.to_string(),
+            text: Some("Checkout errors began at 10:02 and cleared after the rollback.".to_string()),
+        })
+        .expect("add_source");
+    evidence
+        .build_chunks(Some(source.source_id.clone()), TS)
+        .expect("build_chunks");
+    let chunks = evidence
+        .list_chunks(EvidenceQueryStore {
+            include_text: false,
+            source_id: Some(source.source_id),
+        })
+        .expect("list_chunks");
+    (evidence, chunks[0].chunk_id.clone())
+}
+
+#[test]
+fn edited_text_that_keeps_its_citations_is_accepted() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (evidence, chunk_id) = setup(dir.path());
+    let text = format!(
+        "Errors began at 10:02 [[chunk:{chunk_id}]].\n\n- Rollback cleared them [[chunk:{chunk_id}]]\n- Reviewed by on-call [[chunk:{chunk_id}]]"
+    );
+
+    let citations = validate_draft_text(&evidence, &text, std::slice::from_ref(&chunk_id)).expect("valid");
+    assert_eq!(citations.len(), 1, "repeated markers yield one citation per chunk");
+    assert_eq!(citations[0].chunk_id, chunk_id);
+}
+
+#[test]
+fn edits_that_drop_citations_are_rejected() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (evidence, chunk_id) = setup(dir.path());
+    let allowed = std::slice::from_ref(&chunk_id);
+
+    let err = validate_draft_text(&evidence, "No markers at all.", allowed).unwrap_err();
+    assert_eq!(err.code, "AI_CITATION_REQUIRED");
+
+    let uncited_paragraph = format!("Cited [[chunk:{chunk_id}]].\n\nAn added sentence without evidence.");
+    let err = validate_draft_text(&evidence, &uncited_paragraph, allowed).unwrap_err();
+    assert_eq!(err.code, "AI_CITATION_REQUIRED");
+
+    let uncited_item = format!("- Cited [[chunk:{chunk_id}]]\n- Added bullet");
+    let err = validate_draft_text(&evidence, &uncited_item, allowed).unwrap_err();
+    assert_eq!(err.code, "AI_CITATION_REQUIRED");
+}
+
+#[test]
+fn citations_outside_the_allowed_set_or_missing_chunks_are_invalid() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (evidence, chunk_id) = setup(dir.path());
+
+    let text = format!("Cited [[chunk:{chunk_id}]].");
+    let err = validate_draft_text(&evidence, &text, &[]).unwrap_err();
+    assert_eq!(err.code, "AI_CITATION_INVALID");
+
+    let text = format!("Cited [[chunk:{MISSING_CHUNK}]].");
+    let err = validate_draft_text(&evidence, &text, &[MISSING_CHUNK.to_string()]).unwrap_err();
+    assert_eq!(err.code, "AI_CITATION_INVALID");
+}
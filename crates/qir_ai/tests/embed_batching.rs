@@ -0,0 +1,114 @@
+use std::sync::Mutex;
+
+use qir_ai::embeddings::hashed::HashedEmbedder;
+use qir_ai::embeddings::Embedder;
+use qir_ai::evidence::{
+    AiIndexBuildInput, AiStoreSettings, EvidenceAddSourceInput, EvidenceOrigin, EvidenceSourceType, EvidenceStore,
+    IndexStore, DEFAULT_EMBED_BATCH_SIZE,
+};
+use qir_core::error::AppError;
+
+const TS: &str = "2026-02-10T00:00:00Z";
+
+/// Records the size of every batch request.
+#[derive(Default)]
+struct BatchRecordingEmbedder {
+    inner: HashedEmbedder,
+    batches: Mutex<Vec<usize>>,
+}
+
+impl Embedder for BatchRecordingEmbedder {
+    fn embed(&self, model: &str, input: &str) -> Result<Vec<f32>, AppError> {
+        self.inner.embed(model, input)
+    }
+
+    fn embed_batch(&self, model: &str, inputs: &[&str]) -> Result<Vec<Vec<f32>>, AppError> {
+        self.batches.lock().unwrap().push(inputs.len());
+        inputs.iter().map(|i| self.inner.embed(model, i)).collect()
+    }
+}
+
+fn seed(evidence: &EvidenceStore, count: usize) {
+    for i in 0..count {
+        evidence
+            .add_source(EvidenceAddSourceInput {
+                source_type: EvidenceSourceType::FreeformText,
+                origin: EvidenceOrigin {
+                    kind: "paste".to_string(),
+                    path: Some(format!("test://note-{i}")),
+                },
+                label: format!("note-{i}"),
+                created_at: TS.to_string(),
+                text: Some(format!("Note {i} about the checkout outage and rollback step {i}.")),
+            })
+            .expect("add_source");
+    }
+    evidence.build_chunks(None, TS).expect("build_chunks");
+}
+
+fn build(evidence: &EvidenceStore, index: &IndexStore, embedder: &dyn Embedder) {
+    index
+        .build_with_embedder(
+            evidence,
+            embedder,
+            AiIndexBuildInput {
+                model: "hashed".to_string(),
+                source_id: None,
+                updated_at: TS.to_string(),
+            },
+        )
+        .expect("index");
+}
+
+#[test]
+fn batch_size_is_validated_and_defaults() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    assert_eq!(evidence.read_settings().expect("read").embed_batch_size, DEFAULT_EMBED_BATCH_SIZE);
+
+    for embed_batch_size in [0, 513] {
+        let err = evidence
+            .write_settings(&AiStoreSettings {
+                embed_batch_size,
+                ..AiStoreSettings::default()
+            })
+            .unwrap_err();
+        assert_eq!(err.code, "AI_EMBED_BATCH_INVALID");
+    }
+
+    // Settings files written before the batch size existed still load.
+    std::fs::write(dir.path().join("ai_settings.json"), r#"{"chunking":{"target_tokens":400,"overlap_tokens":0}}"#)
+        .expect("write");
+    assert_eq!(evidence.read_settings().expect("read").embed_batch_size, DEFAULT_EMBED_BATCH_SIZE);
+}
+
+#[test]
+fn index_build_embeds_in_configured_batches_with_identical_vectors() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    seed(&evidence, 7);
+    evidence
+        .write_settings(&AiStoreSettings {
+            embed_batch_size: 3,
+            ..AiStoreSettings::default()
+        })
+        .expect("settings");
+
+    let batched = IndexStore::open(dir.path().to_path_buf());
+    let embedder = BatchRecordingEmbedder::default();
+    build(&evidence, &batched, &embedder);
+    assert_eq!(*embedder.batches.lock().unwrap(), vec![3, 3, 1]);
+
+    // The default per-input fallback produces the same index.
+    let other = tempfile::tempdir().expect("tempdir");
+    let plain_evidence = EvidenceStore::open(other.path().to_path_buf());
+    seed(&plain_evidence, 7);
+    let plain = IndexStore::open(other.path().to_path_buf());
+    build(&plain_evidence, &plain, &HashedEmbedder::default());
+    assert_eq!(batched.read_vectors().expect("vectors"), plain.read_vectors().expect("vectors"));
+
+    // Unchanged chunks are not re-sent.
+    let again = BatchRecordingEmbedder::default();
+    build(&evidence, &batched, &again);
+    assert!(again.batches.lock().unwrap().is_empty());
+}
@@ -0,0 +1,112 @@
+use qir_ai::draft::appendix::{collect_appendix_entries, export_evidence_appendix};
+use qir_ai::evidence::{EvidenceAddSourceInput, EvidenceOrigin, EvidenceQueryStore, EvidenceSourceType, EvidenceStore};
+use qir_core::ai_drafts::{AiDraftArtifact, AiDraftSectionType};
+use tempfile::tempdir;
+
+fn store_with_chunks(root: &std::path::Path) -> (EvidenceStore, Vec<String>) {
+    let store = EvidenceStore::open(root.to_path_buf());
+    let text = format!("{}\n\n{}", "a".repeat(900), "b ```code``` ".repeat(60));
+    let source = store
+        .add_source(EvidenceAddSourceInput {
+            source_type: EvidenceSourceType::FreeformText,
+            origin: EvidenceOrigin {
+                kind: "paste".to_string(),
+                path: None,
+            },
+            label: "Ops timeline".to_string(),
+            created_at: "2026-02-10T00:00:00Z".to_string(),
+            text: Some(text),
+        })
+        .unwrap();
+    store
+        .build_chunks(Some(source.source_id.clone()), "2026-02-10T00:00:00Z")
+        .unwrap();
+    let ids = store
+        .list_chunks(EvidenceQueryStore {
+            include_text: false,
+            source_id: Some(source.source_id),
+        })
+        .unwrap()
+        .into_iter()
+        .map(|c| c.chunk_id)
+        .collect();
+    (store, ids)
+}
+
+fn draft(id: i64, text: String) -> AiDraftArtifact {
+    AiDraftArtifact {
+        id,
+        quarter_label: "Q1 2026".to_string(),
+        section_type: AiDraftSectionType::ExecSummary,
+        draft_text: text,
+        citation_chunk_ids: Vec::new(),
+        model_name: "m".to_string(),
+        model_params_hash: "h".to_string(),
+        prompt_template_version: "exec_summary_v1".to_string(),
+        created_at: "2026-02-10T00:00:00Z".to_string(),
+        artifact_hash: format!("hash-{id}"),
+        parent_draft_id: None,
+        revision_number: 1,
+        revision_notes: None,
+        branch_label: None,
+    }
+}
+
+#[test]
+fn collects_cited_chunks_once_in_first_appearance_order() {
+    let dir = tempdir().unwrap();
+    let (store, ids) = store_with_chunks(dir.path());
+    let drafts = [
+        draft(3, format!("- Vendor outage [[chunk:{}]]", ids[1])),
+        draft(5, format!("Paging gaps [[chunk:{a}]] [[chunk:{b}]]", a = ids[0], b = ids[1])),
+    ];
+
+    let entries = collect_appendix_entries(&store, &drafts).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!((entries[0].number, entries[0].chunk_id.as_str()), (1, ids[1].as_str()));
+    assert_eq!(entries[0].cited_by_draft_ids, vec![3, 5]);
+    assert_eq!(entries[1].cited_by_draft_ids, vec![5]);
+    assert_eq!(entries[1].source_label, "Ops timeline");
+    assert!(entries[1].text.starts_with("aaa"));
+}
+
+#[test]
+fn export_writes_chunk_text_locators_and_labels() {
+    let dir = tempdir().unwrap();
+    let out = tempdir().unwrap();
+    let (store, ids) = store_with_chunks(dir.path());
+    let drafts = [draft(3, format!("Summary [[chunk:{}]]", ids[1]))];
+
+    let res = export_evidence_appendix(&store, &drafts, Some("abcdef0123456789"), out.path()).unwrap();
+    assert!(res.path.ends_with("evidence_appendix_report_abcdef012345.md"));
+    let md = std::fs::read_to_string(&res.path).unwrap();
+    assert_eq!(md.len() as u64, res.bytes);
+    assert!(md.contains("Report sha256: `abcdef0123456789`"));
+    assert!(md.contains("- Draft 3 — Q1 2026 — Executive Summary (artifact `hash-3`)"));
+    assert!(md.contains(&format!("## E1 — Ops timeline, chunk {}", res.entries[0].ordinal)));
+    assert!(md.contains(&format!("- sha256: `{}`", res.entries[0].text_sha256)));
+    // Chunk text containing backticks is fenced with a longer fence.
+    assert!(md.contains("````text\nb ```code```"));
+
+    // Deterministic: re-export overwrites the same file with identical bytes.
+    let again = export_evidence_appendix(&store, &drafts, Some("abcdef0123456789"), out.path()).unwrap();
+    assert_eq!(again.path, res.path);
+    assert_eq!(std::fs::read_to_string(&again.path).unwrap(), md);
+}
+
+#[test]
+fn missing_chunks_and_uncited_drafts_fail() {
+    let dir = tempdir().unwrap();
+    let out = tempdir().unwrap();
+    let (store, _) = store_with_chunks(dir.path());
+
+    let err = export_evidence_appendix(&store, &[draft(1, "Summary [[chunk:gone]]".to_string())], None, out.path())
+        .unwrap_err();
+    assert_eq!(err.code, "AI_CITATION_INVALID");
+
+    let err = export_evidence_appendix(&store, &[draft(1, "No markers".to_string())], None, out.path()).unwrap_err();
+    assert_eq!(err.code, "AI_CITATION_REQUIRED");
+
+    let err = export_evidence_appendix(&store, &[], None, out.path()).unwrap_err();
+    assert_eq!(err.code, "AI_DRAFT_EXPORT_FAILED");
+}
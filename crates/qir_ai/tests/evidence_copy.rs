@@ -0,0 +1,96 @@
+use std::fs;
+
+use qir_ai::evidence::{
+    CopyRetryPolicy, EvidenceCopySourceInput, EvidenceQueryStore, EvidenceSourceType, EvidenceStore,
+};
+
+const TS: &str = "2026-02-10T00:00:00Z";
+const TEXT: &str = "Retro notes from the share.\n\nDatabase failover took 14 minutes.";
+
+fn input(origin: &std::path::Path, expected_sha256: Option<String>) -> EvidenceCopySourceInput {
+    EvidenceCopySourceInput {
+        source_type: EvidenceSourceType::IncidentReportMd,
+        origin_path: origin.display().to_string(),
+        label: "Retro (share)".to_string(),
+        created_at: TS.to_string(),
+        expected_sha256,
+        retry: CopyRetryPolicy {
+            max_attempts: 2,
+            retry_delay_ms: 0,
+        },
+    }
+}
+
+#[test]
+fn copy_keeps_origin_path_and_chunks_from_stored_copy() {
+    let share = tempfile::tempdir().expect("share");
+    let origin = share.path().join("retro.md");
+    fs::write(&origin, TEXT).expect("write origin");
+
+    let root = tempfile::tempdir().expect("root");
+    let store = EvidenceStore::open(root.path().to_path_buf());
+    let res = store.copy_file_source(input(&origin, None)).expect("copy");
+
+    assert_eq!(res.attempts, 1);
+    assert_eq!(res.bytes, TEXT.len() as u64);
+    assert_eq!(res.source.origin.kind, "copy");
+    assert_eq!(res.source.origin.path.as_deref(), Some(origin.display().to_string().as_str()));
+
+    // The share going away must not matter once the copy is registered.
+    fs::remove_file(&origin).expect("remove origin");
+    store
+        .build_chunks(Some(res.source.source_id.clone()), TS)
+        .expect("build_chunks");
+    let chunks = store
+        .list_chunks(EvidenceQueryStore {
+            include_text: true,
+            source_id: Some(res.source.source_id.clone()),
+        })
+        .expect("list_chunks");
+    assert!(!chunks.is_empty());
+}
+
+#[test]
+fn copy_rejects_expected_checksum_mismatch_after_retries() {
+    let share = tempfile::tempdir().expect("share");
+    let origin = share.path().join("retro.md");
+    fs::write(&origin, TEXT).expect("write origin");
+
+    let root = tempfile::tempdir().expect("root");
+    let store = EvidenceStore::open(root.path().to_path_buf());
+    let err = store
+        .copy_file_source(input(&origin, Some("0".repeat(64))))
+        .expect_err("mismatch");
+    assert_eq!(err.code, "AI_EVIDENCE_COPY_HASH_MISMATCH");
+    assert!(err.details.as_deref().unwrap_or("").contains("attempts=2"));
+    assert!(store.list_sources().expect("list").is_empty());
+}
+
+#[test]
+fn copy_of_missing_origin_fails_with_copy_failed() {
+    let share = tempfile::tempdir().expect("share");
+    let root = tempfile::tempdir().expect("root");
+    let store = EvidenceStore::open(root.path().to_path_buf());
+    let err = store
+        .copy_file_source(input(&share.path().join("gone.md"), None))
+        .expect_err("missing");
+    assert_eq!(err.code, "AI_EVIDENCE_COPY_FAILED");
+}
+
+#[test]
+fn tampered_copy_is_refused_at_chunking() {
+    let share = tempfile::tempdir().expect("share");
+    let origin = share.path().join("retro.md");
+    fs::write(&origin, TEXT).expect("write origin");
+
+    let root = tempfile::tempdir().expect("root");
+    let store = EvidenceStore::open(root.path().to_path_buf());
+    let res = store.copy_file_source(input(&origin, None)).expect("copy");
+    let stored = root.path().join(format!("sources/{}.md", res.source.source_id));
+    fs::write(&stored, "edited locally").expect("tamper");
+
+    let err = store
+        .build_chunks(Some(res.source.source_id), TS)
+        .expect_err("tampered");
+    assert_eq!(err.code, "AI_EVIDENCE_COPY_HASH_MISMATCH");
+}
@@ -0,0 +1,93 @@
+use qir_ai::evidence::{EvidenceAddSourceInput, EvidenceOrigin, EvidenceQueryStore, EvidenceSourceType, EvidenceStore};
+use tempfile::tempdir;
+
+fn seeded_store(root: &std::path::Path) -> (EvidenceStore, Vec<String>) {
+    let store = EvidenceStore::open(root.to_path_buf());
+    let text = format!("{}\n\n{}\n\n{}", "a".repeat(900), "b".repeat(900), "c".repeat(900));
+    let source = store
+        .add_source(EvidenceAddSourceInput {
+            source_type: EvidenceSourceType::FreeformText,
+            origin: EvidenceOrigin {
+                kind: "paste".to_string(),
+                path: None,
+            },
+            label: "freeform".to_string(),
+            created_at: "2026-02-10T00:00:00Z".to_string(),
+            text: Some(text),
+        })
+        .unwrap();
+    store
+        .build_chunks(Some(source.source_id.clone()), "2026-02-10T00:00:00Z")
+        .unwrap();
+    let chunk_ids = store
+        .list_chunks(EvidenceQueryStore {
+            include_text: false,
+            source_id: Some(source.source_id),
+        })
+        .unwrap()
+        .into_iter()
+        .map(|c| c.chunk_id)
+        .collect();
+    (store, chunk_ids)
+}
+
+#[test]
+fn clean_store_passes() {
+    let dir = tempdir().unwrap();
+    let (store, chunk_ids) = seeded_store(dir.path());
+
+    let report = store.fsck(false).unwrap();
+    assert!(report.ok, "{report:?}");
+    assert_eq!(report.sources_checked, 1);
+    assert_eq!(report.chunks_checked as usize, chunk_ids.len());
+    assert!(report.issues.is_empty());
+    assert!(report.unreferenced_files.is_empty());
+    assert!(!report.repaired);
+}
+
+#[test]
+fn reports_damage_and_repair_restores_consistency() {
+    let dir = tempdir().unwrap();
+    let (store, chunk_ids) = seeded_store(dir.path());
+    assert_eq!(chunk_ids.len(), 3);
+    let root = dir.path();
+
+    // Stale summary: delete it. Corrupt chunk: change the text without updating the hash.
+    std::fs::remove_file(root.join("chunk_summaries").join(format!("{}.json", chunk_ids[0]))).unwrap();
+    let corrupt_path = root.join("chunks").join(format!("{}.json", chunk_ids[1]));
+    let mut chunk: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&corrupt_path).unwrap()).unwrap();
+    chunk["text"] = serde_json::Value::String("tampered".to_string());
+    std::fs::write(&corrupt_path, serde_json::to_string(&chunk).unwrap()).unwrap();
+    // Orphans.
+    std::fs::write(root.join("chunks").join("stray.json"), b"{}").unwrap();
+    std::fs::write(root.join("sources.json.tmp"), b"[]").unwrap();
+
+    let report = store.fsck(false).unwrap();
+    assert!(!report.ok);
+    let kinds: Vec<(&str, Option<&str>)> = report
+        .issues
+        .iter()
+        .map(|i| (i.kind.as_str(), i.chunk_id.as_deref()))
+        .collect();
+    assert!(kinds.contains(&("summary_missing", Some(chunk_ids[0].as_str()))), "{kinds:?}");
+    assert!(kinds.contains(&("chunk_hash_mismatch", Some(chunk_ids[1].as_str()))), "{kinds:?}");
+    assert_eq!(kinds.len(), 2);
+    assert_eq!(
+        report.unreferenced_files,
+        vec!["chunks/stray.json".to_string(), "sources.json.tmp".to_string()]
+    );
+    assert!(root.join("chunks").join("stray.json").exists(), "check-only mode must not delete");
+
+    let repaired = store.fsck(true).unwrap();
+    assert!(repaired.repaired);
+    assert_eq!(repaired.summaries_rewritten, 1);
+    assert_eq!(repaired.chunks_dropped, 1);
+    assert_eq!(repaired.files_deleted, 2);
+
+    let after = store.fsck(false).unwrap();
+    assert!(after.ok, "{after:?}");
+    assert_eq!(after.chunks_checked, 2);
+    assert!(!corrupt_path.exists());
+    assert!(store.get_chunk_summary(&chunk_ids[0]).is_ok());
+}
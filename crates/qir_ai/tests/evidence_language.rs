@@ -0,0 +1,159 @@
+use std::sync::Mutex;
+
+use qir_ai::draft::{draft_section_with_llm, AiDraftSectionRequest, SectionId};
+use qir_ai::embeddings::hashed::HashedEmbedder;
+use qir_ai::evidence::language::detect_language;
+use qir_ai::evidence::{
+    AiIndexBuildInput, EvidenceAddSourceInput, EvidenceOrigin, EvidenceQueryStore, EvidenceSourceType, EvidenceStore,
+    IndexStore,
+};
+use qir_ai::llm::Llm;
+use qir_ai::retrieve::query_with_embedder;
+use qir_core::error::AppError;
+
+const EN: &str = "The database failover was slow and the checkout service returned errors for our users.";
+const ES: &str = "La base de datos falló y el servicio de pagos está caído para los usuarios.";
+const MIXED: &str =
+    "The rollback was done and the alerts stopped. El equipo de pagos confirmó que el servicio está estable.";
+
+/// Records the prompt and cites every evidence block it was given.
+#[derive(Default)]
+struct CapturingLlm {
+    prompt: Mutex<String>,
+}
+
+impl Llm for CapturingLlm {
+    fn generate(&self, _model: &str, prompt: &str) -> Result<String, AppError> {
+        *self.prompt.lock().unwrap() = prompt.to_string();
+        let ids: Vec<String> = prompt
+            .lines()
+            .filter_map(|l| l.strip_prefix("[[chunk:"))
+            .filter_map(|rest| rest.split_once("]]"))
+            .map(|(id, _)| format!("[[chunk:{id}]]"))
+            .collect();
+        Ok(format!("Summary {}", ids.join(" ")))
+    }
+}
+
+fn seed(evidence: &EvidenceStore) -> Vec<(String, Option<String>)> {
+    for (i, text) in [EN, ES, MIXED].iter().enumerate() {
+        evidence
+            .add_source(EvidenceAddSourceInput {
+                source_type: EvidenceSourceType::FreeformText,
+                origin: EvidenceOrigin {
+                    kind: "paste".to_string(),
+                    path: Some(format!("test://lang-{i}")),
+                },
+                label: format!("lang-{i}"),
+                created_at: "2026-02-10T00:00:00Z".to_string(),
+                text: Some(text.to_string()),
+            })
+            .expect("add_source");
+    }
+    evidence.build_chunks(None, "2026-02-10T00:00:00Z").expect("build_chunks");
+    evidence
+        .list_chunks(EvidenceQueryStore {
+            include_text: true,
+            source_id: None,
+        })
+        .expect("list_chunks")
+        .into_iter()
+        .map(|c| (c.chunk_id, c.meta.language))
+        .collect()
+}
+
+#[test]
+fn detects_english_spanish_and_mixed_text() {
+    assert_eq!(detect_language(EN), Some("en"));
+    assert_eq!(detect_language(ES), Some("es"));
+    assert_eq!(detect_language(MIXED), Some("mixed"));
+    assert_eq!(detect_language("2026-01-05T12:00:00Z api-gw 503"), None);
+}
+
+#[test]
+fn chunk_meta_carries_language_and_retrieval_filters_by_it() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    let chunks = seed(&evidence);
+    let mut languages: Vec<String> = chunks.iter().filter_map(|(_, l)| l.clone()).collect();
+    languages.sort();
+    assert_eq!(languages, vec!["en", "es", "mixed"]);
+
+    let index = IndexStore::open(dir.path().to_path_buf());
+    let embedder = HashedEmbedder::default();
+    index
+        .build_with_embedder(
+            &evidence,
+            &embedder,
+            AiIndexBuildInput {
+                model: "hashed".to_string(),
+                source_id: None,
+                updated_at: "2026-02-10T00:00:00Z".to_string(),
+            },
+        )
+        .expect("index");
+
+    let language_of = |chunk_id: &str| {
+        chunks
+            .iter()
+            .find(|(id, _)| id == chunk_id)
+            .and_then(|(_, l)| l.clone())
+            .unwrap_or_default()
+    };
+    let hit_languages = |filter: Option<&str>| {
+        let mut out: Vec<String> = query_with_embedder(&evidence, &index, &embedder, "servicio service", 10, None, filter)
+            .expect("query")
+            .hits
+            .iter()
+            .map(|h| language_of(&h.chunk_id))
+            .collect();
+        out.sort();
+        out
+    };
+    assert_eq!(hit_languages(None), vec!["en", "es", "mixed"]);
+    assert_eq!(hit_languages(Some("es")), vec!["es", "mixed"]);
+    assert_eq!(hit_languages(Some("en")), vec!["en", "mixed"]);
+}
+
+#[test]
+fn drafting_adds_a_language_preamble_only_for_non_english_evidence() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    let chunks = seed(&evidence);
+    let ids_for = |wanted: &[&str]| -> Vec<String> {
+        chunks
+            .iter()
+            .filter(|(_, l)| l.as_deref().is_some_and(|l| wanted.contains(&l)))
+            .map(|(id, _)| id.clone())
+            .collect()
+    };
+
+    let draft = |ids: Vec<String>| {
+        let llm = CapturingLlm::default();
+        let res = draft_section_with_llm(
+            &evidence,
+            &llm,
+            "mock",
+            AiDraftSectionRequest {
+                section_id: SectionId::ExecSummary,
+                quarter_label: "Q1 2026".to_string(),
+                prompt: "Summarize".to_string(),
+                citation_chunk_ids: ids,
+            },
+        )
+        .expect("draft");
+        let prompt = llm.prompt.lock().unwrap().clone();
+        (res.prompt_template_version, prompt)
+    };
+
+    let (version, prompt) = draft(ids_for(&["en"]));
+    assert_eq!(version, "exec_summary_v1");
+    assert!(!prompt.contains("Language note"));
+
+    let (version, prompt) = draft(ids_for(&["es"]));
+    assert_eq!(version, "exec_summary_v1+lang_es");
+    assert!(prompt.starts_with("Language note: the evidence chunks below are in Spanish."));
+
+    let (version, _) = draft(ids_for(&["en", "es"]));
+    assert_eq!(version, "exec_summary_v1+lang_mixed");
+}
@@ -0,0 +1,22 @@
+use qir_ai::demo::{seed_guided_tour_evidence, GUIDED_TOUR_EMBED_MODEL, GUIDED_TOUR_LLM_MODEL};
+use qir_ai::evidence::IndexStore;
+
+#[test]
+fn seeds_evidence_index_and_cited_draft_without_ollama() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let notes = vec![
+        ("IR-001 Slack channel".to_string(), "2026-01-01T00:05:00Z - monitor: payments 502s".to_string()),
+        ("IR-001 review".to_string(), "What happened: payments 502s.\n\nFollow-up: add a check.".to_string()),
+    ];
+    let tour = seed_guided_tour_evidence(dir.path(), &notes, "Q1 2026", "2026-02-01T00:00:00Z").expect("seed");
+
+    assert_eq!(tour.sources.len(), 2);
+    assert_eq!(tour.chunk_ids.len(), 2);
+    assert!(tour.index.ready);
+    assert_eq!(tour.index.model.as_deref(), Some(GUIDED_TOUR_EMBED_MODEL));
+    assert_eq!(tour.draft.model_name, GUIDED_TOUR_LLM_MODEL);
+    assert_eq!(tour.draft.citations.len(), 2);
+
+    let status = IndexStore::open(dir.path().to_path_buf()).status().expect("status");
+    assert!(status.ready);
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "testing")]
+
+use std::collections::BTreeSet;
+
+use qir_ai::evidence::{
+    Citation, CitationLocator, EvidenceAddSourceInput, EvidenceOrigin, EvidenceSourceType,
+    EvidenceStore, IncidentLinkMatch, IncidentRef,
+};
+use qir_ai::retrieve::{boost_incident_linked_hits, EvidenceQueryHit, EvidenceQueryResponse, RetrievalMode};
+use qir_core::repo::list_incidents;
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use tempfile::tempdir;
+
+fn add_text(store: &EvidenceStore, label: &str, text: &str) -> String {
+    let source = store
+        .add_source(EvidenceAddSourceInput {
+            source_type: EvidenceSourceType::FreeformText,
+            origin: EvidenceOrigin {
+                kind: "paste".to_string(),
+                path: Some(format!("test://{label}")),
+            },
+            label: label.to_string(),
+            created_at: "2026-02-10T00:00:00Z".to_string(),
+            text: Some(text.to_string()),
+        })
+        .unwrap();
+    store
+        .build_chunks(Some(source.source_id.clone()), "2026-02-10T00:00:00Z")
+        .unwrap();
+    source.source_id
+}
+
+/// Incident refs read back from a workspace, the way the app builds them.
+fn incidents() -> Vec<IncidentRef> {
+    let conn = db_with_incidents(&[
+        IncidentBuilder::new(1, "Outage").external_id("INC-1").build(),
+        IncidentBuilder::new(2, "Checkout API returning 503s").build(),
+        IncidentBuilder::new(10, "DNS").external_id("INC-10").build(),
+    ])
+    .expect("db");
+    list_incidents(&conn)
+        .expect("list")
+        .into_iter()
+        .map(|i| IncidentRef {
+            incident_id: i.id,
+            external_id: i.external_id,
+            title: i.title,
+        })
+        .collect()
+}
+
+#[test]
+fn links_chunks_by_whole_word_external_id_or_long_title() {
+    let dir = tempdir().unwrap();
+    let store = EvidenceStore::open(dir.path().to_path_buf());
+    let slack = add_text(&store, "Slack", "Paged on inc-10 at 09:00; rollback started.");
+    let notes = add_text(&store, "Notes", "Postmortem: checkout   API returning 503S after the deploy. See INC-1.");
+    add_text(&store, "Unrelated", "Outage drill went fine.");
+
+    let links = store.rebuild_incident_links(&incidents(), "2026-02-11T00:00:00Z").unwrap();
+    assert_eq!(links.incident_count, 3);
+    assert_eq!(links.chunk_count, 3);
+    let got: Vec<(i64, &str, IncidentLinkMatch)> = links
+        .links
+        .iter()
+        .map(|l| (l.incident_id, l.source_id.as_str(), l.matched_on))
+        .collect();
+    // INC-1 does not match inside inc-10, and the short title "Outage" never links.
+    assert_eq!(
+        got,
+        vec![
+            (1, notes.as_str(), IncidentLinkMatch::ExternalId),
+            (2, notes.as_str(), IncidentLinkMatch::Title),
+            (10, slack.as_str(), IncidentLinkMatch::ExternalId),
+        ]
+    );
+
+    let detail = store.incident_evidence_links(2).unwrap();
+    assert_eq!((detail.chunk_count, detail.source_count), (1, 1));
+    assert_eq!(store.incident_evidence_links(99).unwrap().chunk_count, 0);
+
+    // Rebuilding is deterministic and replaces the stored set.
+    let again = store.rebuild_incident_links(&incidents(), "2026-02-11T00:00:00Z").unwrap();
+    assert_eq!(again, links);
+    store.rebuild_incident_links(&[], "2026-02-12T00:00:00Z").unwrap();
+    assert_eq!(store.incident_evidence_links(1).unwrap().chunk_count, 0);
+}
+
+#[test]
+fn evidence_links_are_empty_before_the_first_pass() {
+    let dir = tempdir().unwrap();
+    let store = EvidenceStore::open(dir.path().to_path_buf());
+    assert!(store.read_incident_links().unwrap().is_none());
+    let detail = store.incident_evidence_links(1).unwrap();
+    assert!(detail.links.is_empty());
+    assert_eq!(detail.source_count, 0);
+}
+
+fn hit(chunk_id: &str, score: f32) -> EvidenceQueryHit {
+    EvidenceQueryHit {
+        chunk_id: chunk_id.to_string(),
+        source_id: "s".to_string(),
+        score,
+        snippet: String::new(),
+        citation: Citation {
+            chunk_id: chunk_id.to_string(),
+            locator: CitationLocator {
+                source_id: "s".to_string(),
+                ordinal: 0,
+                text_sha256: String::new(),
+                char_range: None,
+            },
+        },
+        via_section: None,
+    }
+}
+
+#[test]
+fn linked_hits_are_boosted_then_truncated() {
+    let mut res = EvidenceQueryResponse {
+        hits: vec![hit("a", 0.9), hit("b", 0.8), hit("c", 0.5), hit("d", -0.2)],
+        mode: RetrievalMode::Semantic,
+        fallback_reason: None,
+    };
+    let linked: BTreeSet<String> = ["b", "d"].iter().map(|s| s.to_string()).collect();
+    boost_incident_linked_hits(&mut res, &linked, 3);
+
+    let order: Vec<&str> = res.hits.iter().map(|h| h.chunk_id.as_str()).collect();
+    assert_eq!(order, ["b", "a", "c"]);
+    assert!((res.hits[0].score - 1.0).abs() < 1e-6);
+}
@@ -0,0 +1,109 @@
+use qir_ai::embeddings::hashed::HashedEmbedder;
+use qir_ai::embeddings::Embedder;
+use qir_ai::evidence::{
+    AiIndexBuildInput, EvidenceAddSourceInput, EvidenceOrigin, EvidenceSource, EvidenceSourceType, EvidenceStore,
+    IndexStore,
+};
+use qir_ai::retrieve::{query_keyword, query_with_fallback, RetrievalMode};
+use qir_core::error::AppError;
+
+const TS: &str = "2026-02-10T00:00:00Z";
+
+struct DownEmbedder;
+
+impl Embedder for DownEmbedder {
+    fn embed(&self, _model: &str, _input: &str) -> Result<Vec<f32>, AppError> {
+        Err(AppError::new("AI_EMBEDDINGS_FAILED", "Failed to call embeddings endpoint").with_retryable(true))
+    }
+}
+
+fn add(evidence: &EvidenceStore, name: &str, text: &str) -> EvidenceSource {
+    evidence
+        .add_source(EvidenceAddSourceInput {
+            source_type: EvidenceSourceType::FreeformText,
+            origin: EvidenceOrigin {
+                kind: "paste".to_string(),
+                path: Some(format!("test://{name}")),
+            },
+            label: name.to_string(),
+            created_at: TS.to_string(),
+            text: Some(text.to_string()),
+        })
+        .expect("add_source")
+}
+
+fn seed(evidence: &EvidenceStore) -> (EvidenceSource, EvidenceSource) {
+    let dns = add(
+        evidence,
+        "dns",
+        "The DNS resolver cache expired and DNS lookups for the checkout service failed for twenty minutes.",
+    );
+    let disk = add(evidence, "disk", "The database disk filled up; the on-call engineer expanded the volume.");
+    add(evidence, "misc", "Weekly review notes with no incident details.");
+    evidence.build_chunks(None, TS).expect("build_chunks");
+    (dns, disk)
+}
+
+#[test]
+fn keyword_search_ranks_by_bm25_and_honors_filters() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    let (dns, disk) = seed(&evidence);
+
+    let res = query_keyword(&evidence, "DNS checkout", 10, None, None).expect("query");
+    assert_eq!(res.mode, RetrievalMode::Keyword);
+    assert_eq!(res.hits.len(), 1, "only chunks containing a query word are returned");
+    assert_eq!(res.hits[0].source_id, dns.source_id);
+    assert!(res.hits[0].score > 0.0);
+
+    let res = query_keyword(&evidence, "disk dns", 10, None, None).expect("query");
+    assert_eq!(res.hits.len(), 2);
+    assert_eq!(res.hits[0].source_id, dns.source_id, "two DNS mentions outrank one disk mention");
+
+    let only_disk = [disk.source_id.clone()];
+    let res = query_keyword(&evidence, "disk dns", 10, Some(&only_disk), None).expect("query");
+    assert_eq!(res.hits.len(), 1);
+    assert_eq!(res.hits[0].source_id, disk.source_id);
+
+    assert_eq!(query_keyword(&evidence, "  ", 10, None, None).unwrap_err().code, "AI_RETRIEVAL_FAILED");
+}
+
+#[test]
+fn fallback_is_used_without_index_or_embedder_and_labeled() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    let index = IndexStore::open(dir.path().to_path_buf());
+    let (dns, _) = seed(&evidence);
+    let hashed = HashedEmbedder::default();
+
+    let res = query_with_fallback(&evidence, &index, Some(&hashed), "dns", 5, None, None).expect("no index");
+    assert_eq!(res.mode, RetrievalMode::Keyword);
+    assert_eq!(res.fallback_reason.as_deref(), Some("AI_INDEX_NOT_READY"));
+    assert_eq!(res.hits[0].source_id, dns.source_id);
+
+    let res = query_with_fallback(&evidence, &index, None, "dns", 5, None, None).expect("no embedder");
+    assert_eq!(res.fallback_reason.as_deref(), Some("AI_OLLAMA_UNHEALTHY"));
+
+    index
+        .build_with_embedder(
+            &evidence,
+            &hashed,
+            AiIndexBuildInput {
+                model: "hashed".to_string(),
+                source_id: None,
+                updated_at: TS.to_string(),
+            },
+        )
+        .expect("index");
+    let res = query_with_fallback(&evidence, &index, Some(&hashed), "dns", 5, None, None).expect("semantic");
+    assert_eq!(res.mode, RetrievalMode::Semantic);
+    assert_eq!(res.fallback_reason, None);
+
+    let res = query_with_fallback(&evidence, &index, Some(&DownEmbedder), "dns", 5, None, None).expect("ollama down");
+    assert_eq!(res.mode, RetrievalMode::Keyword);
+    assert_eq!(res.fallback_reason.as_deref(), Some("AI_EMBEDDINGS_FAILED"));
+
+    // Non-availability errors are not masked.
+    let err = query_with_fallback(&evidence, &index, Some(&hashed), "", 5, None, None).unwrap_err();
+    assert_eq!(err.code, "AI_RETRIEVAL_FAILED");
+}
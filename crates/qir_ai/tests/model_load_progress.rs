@@ -0,0 +1,163 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use qir_ai::draft::{draft_section_with_llm_progress, AiDraftSectionRequest, SectionId};
+use qir_ai::embeddings::hashed::HashedEmbedder;
+use qir_ai::embeddings::Embedder;
+use qir_ai::evidence::{
+    AiIndexBuildInput, EvidenceAddSourceInput, EvidenceOrigin, EvidenceQueryStore, EvidenceSourceType, EvidenceStore,
+    IndexStore,
+};
+use qir_ai::llm::Llm;
+use qir_ai::ollama::OllamaLoadedModel;
+use qir_core::error::AppError;
+use qir_core::progress::{Progress, ProgressEvent};
+
+const TS: &str = "2026-02-10T00:00:00Z";
+
+/// Reports the model as unloaded until `load_model` is called.
+#[derive(Default)]
+struct ColdLlm {
+    loaded: AtomicBool,
+}
+
+impl Llm for ColdLlm {
+    fn generate(&self, _model: &str, prompt: &str) -> Result<String, AppError> {
+        assert!(self.loaded.load(Ordering::SeqCst), "generate runs after the load");
+        let id = prompt
+            .lines()
+            .find_map(|l| l.strip_prefix("[[chunk:"))
+            .and_then(|rest| rest.split_once("]]"))
+            .map(|(id, _)| id.to_string())
+            .unwrap_or_default();
+        Ok(format!("Summary [[chunk:{id}]]"))
+    }
+
+    fn model_loaded(&self, _model: &str) -> Option<bool> {
+        Some(self.loaded.load(Ordering::SeqCst))
+    }
+
+    fn load_model(&self, _model: &str) -> Result<(), AppError> {
+        self.loaded.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct ColdEmbedder {
+    inner: HashedEmbedder,
+}
+
+impl Embedder for ColdEmbedder {
+    fn embed(&self, model: &str, input: &str) -> Result<Vec<f32>, AppError> {
+        self.inner.embed(model, input)
+    }
+
+    fn model_loaded(&self, _model: &str) -> Option<bool> {
+        Some(false)
+    }
+}
+
+fn seed(evidence: &EvidenceStore) -> String {
+    evidence
+        .add_source(EvidenceAddSourceInput {
+            source_type: EvidenceSourceType::FreeformText,
+            origin: EvidenceOrigin {
+                kind: "paste".to_string(),
+                path: Some("test://note".to_string()),
+            },
+            label: "note".to_string(),
+            created_at: TS.to_string(),
+            text: Some("Checkout errors after the payments deploy; rolled back.".to_string()),
+        })
+        .expect("add_source");
+    evidence.build_chunks(None, TS).expect("build_chunks");
+    evidence
+        .list_chunks(EvidenceQueryStore {
+            include_text: false,
+            source_id: None,
+        })
+        .expect("list")[0]
+        .chunk_id
+        .clone()
+}
+
+fn stages(events: &Mutex<Vec<ProgressEvent>>) -> Vec<String> {
+    events.lock().unwrap().iter().map(|e| e.stage.clone()).collect()
+}
+
+#[test]
+fn draft_reports_model_loading_before_generating() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    let chunk_id = seed(&evidence);
+    let events = Mutex::new(Vec::new());
+    let sink = |ev: &ProgressEvent| events.lock().unwrap().push(ev.clone());
+
+    let llm = ColdLlm::default();
+    let draft = |llm: &ColdLlm| {
+        let mut progress = Progress::new(&sink, "op-1", "ai_draft_section");
+        draft_section_with_llm_progress(
+            &evidence,
+            llm,
+            "llama3.2",
+            AiDraftSectionRequest {
+                section_id: SectionId::ExecSummary,
+                quarter_label: "Q1 2026".to_string(),
+                prompt: "Summarize".to_string(),
+                citation_chunk_ids: vec![chunk_id.clone()],
+            },
+            &mut progress,
+        )
+        .expect("draft");
+    };
+
+    draft(&llm);
+    assert_eq!(
+        stages(&events),
+        vec!["started", "prompt", "model_loading", "generating", "validate", "completed"]
+    );
+
+    // Once resident, the loading stage is skipped.
+    events.lock().unwrap().clear();
+    draft(&llm);
+    assert_eq!(stages(&events), vec!["started", "prompt", "generating", "validate", "completed"]);
+}
+
+#[test]
+fn index_build_reports_model_loading_for_the_first_batch() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    seed(&evidence);
+    let index = IndexStore::open(dir.path().to_path_buf());
+    let events = Mutex::new(Vec::new());
+    let sink = |ev: &ProgressEvent| events.lock().unwrap().push(ev.clone());
+    let mut progress = Progress::new(&sink, "op-2", "ai_index_build");
+    index
+        .build_with_embedder_progress(
+            &evidence,
+            &ColdEmbedder::default(),
+            AiIndexBuildInput {
+                model: "nomic-embed-text".to_string(),
+                source_id: None,
+                updated_at: TS.to_string(),
+            },
+            &mut progress,
+        )
+        .expect("index");
+
+    let stages = stages(&events);
+    let loading = stages.iter().position(|s| s == "model_loading").expect("model_loading stage");
+    assert!(!stages[loading..].contains(&"embed".to_string()), "the single batch is covered by the loading stage");
+    assert_eq!(stages.last().map(String::as_str), Some("completed"));
+}
+
+#[test]
+fn loaded_model_names_match_untagged_requests() {
+    let m: OllamaLoadedModel =
+        serde_json::from_str(r#"{"name":"llama3.2:latest","model":"llama3.2:latest","size_vram":123}"#).expect("json");
+    assert!(m.matches("llama3.2"));
+    assert!(m.matches("llama3.2:latest"));
+    assert!(!m.matches("llama3.2:1b"));
+    assert!(!m.matches("mistral"));
+}
@@ -0,0 +1,180 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use qir_ai::embeddings::hashed::HashedEmbedder;
+use qir_ai::embeddings::Embedder;
+use qir_ai::evidence::{
+    rechunk_source, AiIndexBuildInput, AiStoreSettings, ChunkingParams, EvidenceAddSourceInput, EvidenceOrigin,
+    EvidenceQueryStore, EvidenceSource, EvidenceSourceType, EvidenceStore, IndexStore,
+};
+use qir_core::error::AppError;
+
+const TS: &str = "2026-02-10T00:00:00Z";
+
+#[derive(Default)]
+struct CountingEmbedder {
+    inner: HashedEmbedder,
+    calls: AtomicUsize,
+}
+
+impl Embedder for CountingEmbedder {
+    fn embed(&self, model: &str, input: &str) -> Result<Vec<f32>, AppError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.inner.embed(model, input)
+    }
+}
+
+/// `count` paragraphs of exactly 300 chars each.
+fn paragraphs(count: usize) -> String {
+    (0..count)
+        .map(|i| format!("p{i} {}", "x".repeat(297)))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn add(evidence: &EvidenceStore, name: &str, text: String) -> EvidenceSource {
+    evidence
+        .add_source(EvidenceAddSourceInput {
+            source_type: EvidenceSourceType::FreeformText,
+            origin: EvidenceOrigin {
+                kind: "paste".to_string(),
+                path: Some(format!("test://{name}")),
+            },
+            label: name.to_string(),
+            created_at: TS.to_string(),
+            text: Some(text),
+        })
+        .expect("add_source")
+}
+
+fn chunk_texts(evidence: &EvidenceStore, source_id: &str) -> Vec<String> {
+    evidence
+        .list_chunks(EvidenceQueryStore {
+            include_text: false,
+            source_id: Some(source_id.to_string()),
+        })
+        .expect("list_chunks")
+        .iter()
+        .map(|c| evidence.get_chunk(&c.chunk_id).expect("chunk").text)
+        .collect()
+}
+
+fn set_chunking(evidence: &EvidenceStore, target_tokens: u32, overlap_tokens: u32) {
+    evidence
+        .write_settings(&AiStoreSettings {
+            chunking: ChunkingParams {
+                target_tokens,
+                overlap_tokens,
+            },
+            ..AiStoreSettings::default()
+        })
+        .expect("write_settings");
+}
+
+#[test]
+fn chunking_params_are_validated() {
+    assert!(ChunkingParams::default().validate().is_ok());
+    for (target_tokens, overlap_tokens) in [(10, 0), (9000, 0), (200, 101)] {
+        let err = ChunkingParams {
+            target_tokens,
+            overlap_tokens,
+        }
+        .validate()
+        .unwrap_err();
+        assert_eq!(err.code, "AI_CHUNKING_INVALID");
+    }
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    assert_eq!(evidence.read_settings().expect("read").chunking, ChunkingParams::default());
+    let bad = AiStoreSettings {
+        chunking: ChunkingParams {
+            target_tokens: 200,
+            overlap_tokens: 150,
+        },
+        ..AiStoreSettings::default()
+    };
+    assert_eq!(evidence.write_settings(&bad).unwrap_err().code, "AI_CHUNKING_INVALID");
+}
+
+#[test]
+fn target_size_and_overlap_shape_chunks_and_are_recorded_in_meta() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    let source = add(&evidence, "six", paragraphs(6));
+
+    set_chunking(&evidence, 200, 0);
+    evidence.build_chunks(None, TS).expect("build");
+    let texts = chunk_texts(&evidence, &source.source_id);
+    assert_eq!(texts.len(), 3, "two 300-char paragraphs fit in 800 chars");
+    assert!(texts[0].starts_with("p0 ") && texts[1].starts_with("p2 "));
+
+    set_chunking(&evidence, 200, 80);
+    evidence.rechunk_source(&source.source_id, TS).expect("rechunk");
+    let texts = chunk_texts(&evidence, &source.source_id);
+    assert_eq!(texts.len(), 5);
+    for (i, text) in texts.iter().enumerate() {
+        assert!(text.starts_with(&format!("p{i} ")), "chunk {i} repeats the previous chunk's last paragraph");
+    }
+    let summaries = evidence
+        .list_chunks(EvidenceQueryStore {
+            include_text: false,
+            source_id: Some(source.source_id.clone()),
+        })
+        .expect("list");
+    assert_eq!(
+        summaries[0].meta.chunking,
+        Some(ChunkingParams {
+            target_tokens: 200,
+            overlap_tokens: 80
+        })
+    );
+}
+
+#[test]
+fn rechunk_invalidates_only_the_vectors_of_the_rechunked_source() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    let index = IndexStore::open(dir.path().to_path_buf());
+    let long = add(&evidence, "long", paragraphs(6));
+    let other = add(&evidence, "other", "Unrelated note about the payments rollback.".to_string());
+    evidence.build_chunks(None, TS).expect("build");
+
+    let embedder = CountingEmbedder::default();
+    let build = |embedder: &CountingEmbedder| {
+        index
+            .build_with_embedder(
+                &evidence,
+                embedder,
+                AiIndexBuildInput {
+                    model: "hashed".to_string(),
+                    source_id: None,
+                    updated_at: TS.to_string(),
+                },
+            )
+            .expect("index")
+    };
+    build(&embedder);
+    assert_eq!(embedder.calls.load(Ordering::SeqCst), 3, "default size packs five paragraphs, then one");
+
+    // Unchanged settings reproduce the same chunk ids: nothing to invalidate.
+    let same = rechunk_source(&evidence, &index, &long.source_id, TS).expect("rechunk");
+    assert!(same.rechunk.removed_chunk_ids.is_empty());
+    assert_eq!(same.invalidated_vectors, 0);
+
+    set_chunking(&evidence, 100, 0);
+    let res = rechunk_source(&evidence, &index, &long.source_id, TS).expect("rechunk");
+    assert_eq!(res.rechunk.chunk_count, 6);
+    assert_eq!(res.rechunk.removed_chunk_ids.len(), 2);
+    assert_eq!(res.invalidated_vectors, 2);
+    assert_eq!(res.index.chunk_count, 1);
+
+    let other_chunk = chunk_texts(&evidence, &other.source_id);
+    assert_eq!(other_chunk.len(), 1);
+    let vectors = index.read_vectors().expect("vectors");
+    assert_eq!(vectors.len(), 1, "the other source's vector survives");
+
+    let rebuild = CountingEmbedder::default();
+    let st = build(&rebuild);
+    assert_eq!(rebuild.calls.load(Ordering::SeqCst), 6, "only the new chunks are embedded");
+    assert_eq!(st.chunk_count, 7);
+}
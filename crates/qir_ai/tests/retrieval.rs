@@ -74,6 +74,7 @@ fn retrieval_returns_stable_topk_and_tie_breaks_by_chunk_id() {
         "aaaa",
         2,
         Some(&[source.source_id.clone()]),
+        None,
     )
     .expect("query");
     assert_eq!(res.hits.len(), 2);
@@ -88,6 +89,7 @@ fn retrieval_returns_stable_topk_and_tie_breaks_by_chunk_id() {
         "ab",
         2,
         Some(&[source.source_id]),
+        None,
     )
     .expect("query");
     assert_eq!(tie.hits.len(), 2);
@@ -0,0 +1,198 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use qir_ai::embeddings::hashed::HashedEmbedder;
+use qir_ai::embeddings::Embedder;
+use qir_ai::evidence::{
+    rechunk_source, summarize_source, AiIndexBuildInput, AiIndexStatus, AiStoreSettings, ChunkingParams,
+    EvidenceAddSourceInput, EvidenceOrigin, EvidenceQueryStore, EvidenceSource, EvidenceSourceType, EvidenceStore,
+    IndexStore, SummarizeSourceInput,
+};
+use qir_ai::llm::Llm;
+use qir_ai::retrieve::query_with_embedder;
+use qir_core::error::AppError;
+
+const TS: &str = "2026-02-10T00:00:00Z";
+
+struct CountingEmbedder {
+    inner: HashedEmbedder,
+    calls: AtomicUsize,
+}
+
+impl CountingEmbedder {
+    fn new() -> Self {
+        // Wide vectors keep hashed-word collisions out of the ranking assertions.
+        Self {
+            inner: HashedEmbedder::new(1024),
+            calls: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl Embedder for CountingEmbedder {
+    fn embed(&self, model: &str, input: &str) -> Result<Vec<f32>, AppError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.inner.embed(model, input)
+    }
+}
+
+/// Summarizes section 2 as being about a zeppelin; every other section is routine.
+struct SectionLlm;
+
+impl Llm for SectionLlm {
+    fn generate(&self, _model: &str, prompt: &str) -> Result<String, AppError> {
+        let n = prompt
+            .split_once("summarizing section ")
+            .and_then(|(_, rest)| rest.split(' ').next())
+            .unwrap_or("0");
+        let topic = if n == "2" { "zeppelin" } else { "routine" };
+        Ok(format!("Section {n} summary about {topic}."))
+    }
+}
+
+fn add(evidence: &EvidenceStore, name: &str, text: String) -> EvidenceSource {
+    evidence
+        .add_source(EvidenceAddSourceInput {
+            source_type: EvidenceSourceType::FreeformText,
+            origin: EvidenceOrigin {
+                kind: "paste".to_string(),
+                path: Some(format!("test://{name}")),
+            },
+            label: name.to_string(),
+            created_at: TS.to_string(),
+            text: Some(text),
+        })
+        .expect("add_source")
+}
+
+fn set_target_tokens(evidence: &EvidenceStore, target_tokens: u32) {
+    evidence
+        .write_settings(&AiStoreSettings {
+            chunking: ChunkingParams {
+                target_tokens,
+                overlap_tokens: 0,
+            },
+            ..AiStoreSettings::default()
+        })
+        .expect("settings");
+}
+
+fn chunk_ids(evidence: &EvidenceStore, source_id: &str) -> Vec<String> {
+    evidence
+        .list_chunks(EvidenceQueryStore {
+            include_text: false,
+            source_id: Some(source_id.to_string()),
+        })
+        .expect("list")
+        .into_iter()
+        .map(|c| c.chunk_id)
+        .collect()
+}
+
+fn build(evidence: &EvidenceStore, index: &IndexStore, embedder: &CountingEmbedder) -> AiIndexStatus {
+    index
+        .build_with_embedder(
+            evidence,
+            embedder,
+            AiIndexBuildInput {
+                model: "hashed".to_string(),
+                source_id: None,
+                updated_at: TS.to_string(),
+            },
+        )
+        .expect("index")
+}
+
+#[test]
+fn section_summaries_are_embedded_but_hits_cite_verbatim_chunks() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    let index = IndexStore::open(dir.path().to_path_buf());
+
+    // Twelve ~160-char paragraphs; a 200-char target keeps one paragraph per chunk.
+    let rca = (0..12)
+        .map(|i| format!("Paragraph {i} of the vendor RCA. {}", "detail ".repeat(18)))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let long = add(&evidence, "vendor-rca", rca);
+    let other = add(&evidence, "note", "Short note about a database restart.".to_string());
+    set_target_tokens(&evidence, 50);
+    evidence.build_chunks(None, TS).expect("chunks");
+    let long_chunks = chunk_ids(&evidence, &long.source_id);
+    assert_eq!(long_chunks.len(), 12);
+
+    let sums = summarize_source(
+        &evidence,
+        &SectionLlm,
+        SummarizeSourceInput {
+            source_id: long.source_id.clone(),
+            model: "mock".to_string(),
+            section_chunks: Some(4),
+            updated_at: TS.to_string(),
+        },
+    )
+    .expect("summarize");
+    assert_eq!(sums.sections.len(), 3);
+    let covered: Vec<String> = sums.sections.iter().flat_map(|s| s.chunk_ids.clone()).collect();
+    assert_eq!(covered, long_chunks);
+    assert_eq!(evidence.read_source_summaries(&long.source_id).expect("read"), Some(sums.clone()));
+
+    let embedder = CountingEmbedder::new();
+    let st = build(&evidence, &index, &embedder);
+    assert_eq!(embedder.calls.load(Ordering::SeqCst), 4, "three section summaries plus the short note");
+    assert_eq!(st.chunk_count, 4);
+
+    let hits = query_with_embedder(&evidence, &index, &embedder, "zeppelin", 2, None, None)
+        .expect("query")
+        .hits;
+    assert_eq!(hits.len(), 2);
+    for (hit, expected) in hits.iter().zip(&sums.sections[1].chunk_ids) {
+        assert_eq!(&hit.chunk_id, expected);
+        assert_eq!(hit.citation.chunk_id, *expected);
+        assert!(hit.via_section.as_deref().is_some_and(|k| k.ends_with(":1")));
+        evidence.get_chunk(&hit.chunk_id).expect("verbatim chunk");
+    }
+
+    // Plain chunk hits still come back without a section.
+    let note = query_with_embedder(&evidence, &index, &embedder, "database restart", 1, None, None)
+        .expect("query")
+        .hits;
+    assert_eq!(note[0].chunk_id, chunk_ids(&evidence, &other.source_id)[0]);
+    assert_eq!(note[0].via_section, None);
+}
+
+#[test]
+fn rechunking_a_summarized_source_drops_its_stale_sections() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let evidence = EvidenceStore::open(dir.path().to_path_buf());
+    let index = IndexStore::open(dir.path().to_path_buf());
+    let text = (0..12)
+        .map(|i| format!("Paragraph {i} of the vendor RCA. {}", "detail ".repeat(18)))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let long = add(&evidence, "vendor-rca", text);
+    set_target_tokens(&evidence, 50);
+    evidence.build_chunks(None, TS).expect("chunks");
+    summarize_source(
+        &evidence,
+        &SectionLlm,
+        SummarizeSourceInput {
+            source_id: long.source_id.clone(),
+            model: "mock".to_string(),
+            section_chunks: Some(4),
+            updated_at: TS.to_string(),
+        },
+    )
+    .expect("summarize");
+    build(&evidence, &index, &CountingEmbedder::new());
+
+    set_target_tokens(&evidence, 100);
+    let res = rechunk_source(&evidence, &index, &long.source_id, TS).expect("rechunk");
+    assert_eq!(res.rechunk.chunk_count, 6);
+    assert_eq!(res.invalidated_vectors, 3, "only the section vectors were indexed for this source");
+    assert_eq!(evidence.read_source_summaries(&long.source_id).expect("read"), None);
+
+    let embedder = CountingEmbedder::new();
+    let st = build(&evidence, &index, &embedder);
+    assert_eq!(embedder.calls.load(Ordering::SeqCst), 6, "falls back to chunk-level vectors");
+    assert_eq!(st.chunk_count, 6);
+}
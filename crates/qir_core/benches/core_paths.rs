@@ -0,0 +1,69 @@
+// Run with: cargo bench -p qir_core --features bench-data
+// Set QIR_BENCH_SIZES=1000,10000 to skip the 100k tier locally.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+
+use qir_core::analytics::build_dashboard_payload_v2;
+use qir_core::bench_data::{bench_mapping, generate_jira_csv, seed_bench_dataset};
+use qir_core::ingest::jira_csv::import_jira_csv;
+use qir_core::report::generate_qir_markdown;
+use qir_core::testing::empty_db;
+
+const SEED: u64 = 0x5152_0001;
+
+fn sizes() -> Vec<usize> {
+    std::env::var("QIR_BENCH_SIZES")
+        .ok()
+        .map(|s| s.split(',').filter_map(|x| x.trim().parse().ok()).collect())
+        .unwrap_or_else(|| vec![1_000, 10_000, 100_000])
+}
+
+fn seeded_db(n: usize) -> rusqlite::Connection {
+    let mut conn = empty_db().expect("db");
+    seed_bench_dataset(&mut conn, n, SEED).expect("seed");
+    conn
+}
+
+fn bench_import(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_jira_csv");
+    group.sample_size(10);
+    let mapping = bench_mapping();
+    for n in sizes() {
+        let csv = generate_jira_csv(n, SEED);
+        group.bench_with_input(BenchmarkId::from_parameter(n), &csv, |b, csv| {
+            b.iter_batched(
+                || empty_db().expect("db"),
+                |mut conn| import_jira_csv(&mut conn, csv, &mapping).expect("import"),
+                BatchSize::PerIteration,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_dashboard(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build_dashboard_payload_v2");
+    group.sample_size(10);
+    for n in sizes() {
+        let conn = seeded_db(n);
+        group.bench_function(BenchmarkId::from_parameter(n), |b| {
+            b.iter(|| build_dashboard_payload_v2(&conn).expect("dashboard"))
+        });
+    }
+    group.finish();
+}
+
+fn bench_report(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generate_qir_markdown");
+    group.sample_size(10);
+    for n in sizes() {
+        let conn = seeded_db(n);
+        group.bench_function(BenchmarkId::from_parameter(n), |b| {
+            b.iter(|| generate_qir_markdown(&conn).expect("report"))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_import, bench_dashboard, bench_report);
+criterion_main!(benches);
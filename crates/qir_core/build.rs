@@ -0,0 +1,76 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Fails the build when any workspace crate constructs `AppError::new("CODE", ...)` with a
+// code that is not registered in src/error/catalog.rs. Only literal codes are checked;
+// `#[cfg(test)]` sections are ignored.
+
+const CATALOG: &str = "src/error/catalog.rs";
+
+// Sources that construct AppError, relative to this crate.
+const SCANNED_DIRS: &[&str] = &["src", "../qir_ai/src", "../../src-tauri/src"];
+
+fn rust_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            rust_files(&path, out);
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            out.push(path);
+        }
+    }
+}
+
+/// Literal first arguments of every `needle(` call in `text`.
+fn literal_first_args(text: &str, needle: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut rest = text;
+    while let Some(idx) = rest.find(needle) {
+        rest = &rest[idx + needle.len()..];
+        let arg = rest.trim_start();
+        if let Some(body) = arg.strip_prefix('"') {
+            if let Some(end) = body.find('"') {
+                out.push(body[..end].to_string());
+            }
+        }
+    }
+    out
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR"));
+
+    let catalog_text = fs::read_to_string(manifest_dir.join(CATALOG)).expect("read error catalog");
+    let registered: BTreeSet<String> = literal_first_args(&catalog_text, "ErrorSpec::new(")
+        .into_iter()
+        .collect();
+
+    let mut missing: BTreeSet<String> = BTreeSet::new();
+    for dir in SCANNED_DIRS {
+        let dir = manifest_dir.join(dir);
+        if !dir.is_dir() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", dir.display());
+        let mut files = Vec::new();
+        rust_files(&dir, &mut files);
+        for file in files {
+            let Ok(text) = fs::read_to_string(&file) else { continue };
+            let text = text.split("#[cfg(test)]").next().unwrap_or_default();
+            for code in literal_first_args(text, "AppError::new(") {
+                if !registered.contains(&code) {
+                    missing.insert(format!("{code} ({})", file.display()));
+                }
+            }
+        }
+    }
+
+    if !missing.is_empty() {
+        panic!(
+            "unregistered AppError codes; add them to {CATALOG}:\n  {}",
+            missing.into_iter().collect::<Vec<_>>().join("\n  ")
+        );
+    }
+}
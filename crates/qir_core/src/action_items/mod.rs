@@ -0,0 +1,180 @@
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ActionItem {
+    pub id: i64,
+    pub incident_id: i64,
+    pub title: String,
+    pub owner: Option<String>,
+    pub done: bool,
+    pub created_at: String,
+    pub closed_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CreateActionItemInput {
+    pub incident_id: i64,
+    pub title: String,
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// RFC3339; defaults to now. Set it when recording items raised earlier.
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+fn invalid(message: &str, details: String) -> AppError {
+    AppError::new("VALIDATION_ACTION_ITEM_INVALID", message).with_details(details)
+}
+
+/// Canonical RFC3339 UTC, or the current time when `ts` is None.
+fn canonical_ts(field: &str, ts: Option<&str>) -> Result<String, AppError> {
+    let dt = match ts {
+        Some(t) => OffsetDateTime::parse(t.trim(), &Rfc3339)
+            .map_err(|e| invalid("Action item timestamps must be RFC3339", format!("{field}={t}; err={e}")))?,
+        None => OffsetDateTime::now_utc(),
+    };
+    let utc = dt.to_offset(UtcOffset::UTC);
+    utc.replace_nanosecond(0)
+        .unwrap_or(utc)
+        .format(&Rfc3339)
+        .map_err(|e| invalid("Failed to format action item timestamp", e.to_string()))
+}
+
+const SELECT_COLUMNS: &str =
+    "SELECT id, incident_id, title, owner, done, created_at, closed_at FROM action_items";
+
+fn map_row(row: &rusqlite::Row<'_>) -> Result<ActionItem, rusqlite::Error> {
+    let done: i64 = row.get(4)?;
+    Ok(ActionItem {
+        id: row.get(0)?,
+        incident_id: row.get(1)?,
+        title: row.get(2)?,
+        owner: row.get(3)?,
+        done: done != 0,
+        created_at: row.get(5)?,
+        closed_at: row.get(6)?,
+    })
+}
+
+pub fn get_action_item(conn: &Connection, id: i64) -> Result<Option<ActionItem>, AppError> {
+    conn.query_row(&format!("{SELECT_COLUMNS} WHERE id = ?1"), [id], map_row)
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query action item")
+                .with_details(format!("id={id}; err={e}"))
+        })
+}
+
+fn require_action_item(conn: &Connection, id: i64) -> Result<ActionItem, AppError> {
+    get_action_item(conn, id)?.ok_or_else(|| {
+        AppError::new("DB_NOT_FOUND", "Action item not found").with_details(format!("id={id}"))
+    })
+}
+
+pub fn create_action_item(conn: &Connection, input: CreateActionItemInput) -> Result<ActionItem, AppError> {
+    let exists: Option<i64> = conn
+        .query_row("SELECT id FROM incidents WHERE id = ?1", [input.incident_id], |row| row.get(0))
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to check incident exists").with_details(e.to_string())
+        })?;
+    if exists.is_none() {
+        return Err(AppError::new("DB_NOT_FOUND", "Incident not found for action item")
+            .with_details(format!("incident_id={}", input.incident_id)));
+    }
+    if input.title.trim().is_empty() {
+        return Err(invalid(
+            "Action item title is required",
+            format!("incident_id={}", input.incident_id),
+        ));
+    }
+    let owner = input.owner.as_deref().map(str::trim).filter(|o| !o.is_empty());
+    let created_at = canonical_ts("created_at", input.created_at.as_deref())?;
+
+    conn.execute(
+        "INSERT INTO action_items(incident_id, title, owner, done, created_at) VALUES (?1, ?2, ?3, 0, ?4)",
+        params![input.incident_id, input.title.trim(), owner, created_at],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to store action item")
+            .with_details(format!("incident_id={}; err={}", input.incident_id, e))
+    })?;
+    require_action_item(conn, conn.last_insert_rowid())
+}
+
+/// Action items, optionally for one incident, in creation order.
+pub fn list_action_items(
+    conn: &Connection,
+    incident_id: Option<i64>,
+    include_done: bool,
+) -> Result<Vec<ActionItem>, AppError> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "{SELECT_COLUMNS} WHERE (?1 IS NULL OR incident_id = ?1) AND (?2 OR done = 0) ORDER BY created_at ASC, id ASC"
+        ))
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare action item list query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt
+        .query_map(params![incident_id, include_done], map_row)
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query action items").with_details(e.to_string())
+        })?;
+    let mut out = Vec::new();
+    for r in rows {
+        out.push(r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode action item row").with_details(e.to_string())
+        })?);
+    }
+    Ok(out)
+}
+
+/// Closes (`closed_at` defaults to now) or reopens an action item.
+pub fn set_action_item_done(
+    conn: &Connection,
+    id: i64,
+    done: bool,
+    closed_at: Option<&str>,
+) -> Result<ActionItem, AppError> {
+    let existing = require_action_item(conn, id)?;
+    let closed_at = if done {
+        let ts = canonical_ts("closed_at", closed_at)?;
+        if ts < existing.created_at {
+            return Err(invalid(
+                "Action item cannot close before it was created",
+                format!("id={id}; created_at={}; closed_at={ts}", existing.created_at),
+            ));
+        }
+        Some(ts)
+    } else {
+        None
+    };
+    conn.execute(
+        "UPDATE action_items SET done = ?2, closed_at = ?3 WHERE id = ?1",
+        params![id, done, closed_at],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to update action item")
+            .with_details(format!("id={id}; err={e}"))
+    })?;
+    require_action_item(conn, id)
+}
+
+pub fn delete_action_item(conn: &Connection, id: i64) -> Result<(), AppError> {
+    let n = conn
+        .execute("DELETE FROM action_items WHERE id = ?1", [id])
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to delete action item")
+                .with_details(format!("id={id}; err={e}"))
+        })?;
+    if n == 0 {
+        return Err(AppError::new("DB_NOT_FOUND", "Action item not found").with_details(format!("id={id}")));
+    }
+    Ok(())
+}
@@ -0,0 +1,182 @@
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use super::get_ai_draft;
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DraftComment {
+    pub id: i64,
+    pub draft_id: i64,
+    /// 0-based index over the draft's non-empty, blank-line separated paragraphs.
+    pub anchor_paragraph: u32,
+    pub author: String,
+    pub text: String,
+    pub resolved: bool,
+    pub created_at: String,
+    pub updated_at: String,
+    pub resolved_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CreateDraftCommentInput {
+    pub draft_id: i64,
+    pub anchor_paragraph: u32,
+    pub author: String,
+    pub text: String,
+}
+
+/// Same paragraph split the drafting guardrails use for narrative sections.
+pub fn draft_paragraph_count(draft_text: &str) -> u32 {
+    draft_text.split("\n\n").filter(|p| !p.trim().is_empty()).count() as u32
+}
+
+fn invalid(message: &str, details: String) -> AppError {
+    AppError::new("VALIDATION_DRAFT_COMMENT_INVALID", message).with_details(details)
+}
+
+const SELECT_COLUMNS: &str =
+    "SELECT id, draft_id, anchor_paragraph, author, text, resolved, created_at, updated_at, resolved_at FROM draft_comments";
+
+fn map_row(row: &rusqlite::Row<'_>) -> Result<DraftComment, rusqlite::Error> {
+    let resolved: i64 = row.get(5)?;
+    Ok(DraftComment {
+        id: row.get(0)?,
+        draft_id: row.get(1)?,
+        anchor_paragraph: row.get(2)?,
+        author: row.get(3)?,
+        text: row.get(4)?,
+        resolved: resolved != 0,
+        created_at: row.get(6)?,
+        updated_at: row.get(7)?,
+        resolved_at: row.get(8)?,
+    })
+}
+
+pub fn get_draft_comment(conn: &Connection, id: i64) -> Result<Option<DraftComment>, AppError> {
+    conn.query_row(&format!("{SELECT_COLUMNS} WHERE id = ?1"), [id], map_row)
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query draft comment")
+                .with_details(format!("id={id}; err={e}"))
+        })
+}
+
+fn require_comment(conn: &Connection, id: i64) -> Result<DraftComment, AppError> {
+    get_draft_comment(conn, id)?.ok_or_else(|| {
+        AppError::new("DB_NOT_FOUND", "Draft comment not found").with_details(format!("id={id}"))
+    })
+}
+
+pub fn create_draft_comment(conn: &Connection, input: CreateDraftCommentInput) -> Result<DraftComment, AppError> {
+    let draft = get_ai_draft(conn, input.draft_id)?.ok_or_else(|| {
+        AppError::new("DB_NOT_FOUND", "Draft not found for comment")
+            .with_details(format!("draft_id={}", input.draft_id))
+    })?;
+    if input.author.trim().is_empty() {
+        return Err(invalid("Comment author is required", format!("draft_id={}", draft.id)));
+    }
+    if input.text.trim().is_empty() {
+        return Err(invalid("Comment text is required", format!("draft_id={}", draft.id)));
+    }
+    let paragraphs = draft_paragraph_count(&draft.draft_text);
+    if input.anchor_paragraph >= paragraphs {
+        return Err(invalid(
+            "Comment anchor is outside the draft",
+            format!(
+                "draft_id={}; anchor_paragraph={}; paragraphs={}",
+                draft.id, input.anchor_paragraph, paragraphs
+            ),
+        ));
+    }
+
+    conn.execute(
+        "INSERT INTO draft_comments(draft_id, anchor_paragraph, author, text, resolved, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, 0, strftime('%Y-%m-%dT%H:%M:%fZ','now'), strftime('%Y-%m-%dT%H:%M:%fZ','now'))",
+        params![draft.id, input.anchor_paragraph, input.author.trim(), input.text.trim()],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to store draft comment")
+            .with_details(format!("draft_id={}; err={}", draft.id, e))
+    })?;
+    require_comment(conn, conn.last_insert_rowid())
+}
+
+/// Comments for one draft in document order (paragraph, then creation).
+pub fn list_draft_comments(
+    conn: &Connection,
+    draft_id: i64,
+    include_resolved: bool,
+) -> Result<Vec<DraftComment>, AppError> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "{SELECT_COLUMNS} WHERE draft_id = ?1 AND (?2 OR resolved = 0) ORDER BY anchor_paragraph ASC, id ASC"
+        ))
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare draft comment list query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt
+        .query_map(params![draft_id, include_resolved], map_row)
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query draft comments")
+                .with_details(format!("draft_id={draft_id}; err={e}"))
+        })?;
+    let mut out = Vec::new();
+    for r in rows {
+        out.push(r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode draft comment row")
+                .with_details(e.to_string())
+        })?);
+    }
+    Ok(out)
+}
+
+pub fn update_draft_comment_text(conn: &Connection, id: i64, text: &str) -> Result<DraftComment, AppError> {
+    if text.trim().is_empty() {
+        return Err(invalid("Comment text is required", format!("id={id}")));
+    }
+    require_comment(conn, id)?;
+    conn.execute(
+        "UPDATE draft_comments SET text = ?2, updated_at = strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE id = ?1",
+        params![id, text.trim()],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to update draft comment")
+            .with_details(format!("id={id}; err={e}"))
+    })?;
+    require_comment(conn, id)
+}
+
+pub fn set_draft_comment_resolved(conn: &Connection, id: i64, resolved: bool) -> Result<DraftComment, AppError> {
+    let existing = require_comment(conn, id)?;
+    if existing.resolved == resolved {
+        return Ok(existing);
+    }
+    conn.execute(
+        "UPDATE draft_comments
+         SET resolved = ?2,
+             resolved_at = CASE WHEN ?2 THEN strftime('%Y-%m-%dT%H:%M:%fZ','now') ELSE NULL END,
+             updated_at = strftime('%Y-%m-%dT%H:%M:%fZ','now')
+         WHERE id = ?1",
+        params![id, resolved],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to update draft comment")
+            .with_details(format!("id={id}; err={e}"))
+    })?;
+    require_comment(conn, id)
+}
+
+pub fn delete_draft_comment(conn: &Connection, id: i64) -> Result<(), AppError> {
+    let n = conn
+        .execute("DELETE FROM draft_comments WHERE id = ?1", [id])
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to delete draft comment")
+                .with_details(format!("id={id}; err={e}"))
+        })?;
+    if n == 0 {
+        return Err(AppError::new("DB_NOT_FOUND", "Draft comment not found").with_details(format!("id={id}")));
+    }
+    Ok(())
+}
@@ -3,6 +3,10 @@ use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+pub mod comments;
+pub mod policy;
+pub mod staleness;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum AiDraftSectionType {
@@ -145,7 +149,11 @@ pub fn create_ai_draft(conn: &Connection, input: CreateAiDraftInput) -> Result<A
         ));
     }
 
+    crate::quarters::ensure_quarter_exists(conn, &input.quarter_label)?;
+
     let artifact_hash = compute_artifact_hash(&input)?;
+    let snapshot_json =
+        staleness::encode_snapshot(&staleness::quarter_snapshot(conn, &input.quarter_label)?)?;
     let citation_chunk_ids_json = serde_json::to_string(&input.citation_chunk_ids).map_err(|e| {
         AppError::new(
             "DB_AI_DRAFT_INVALID",
@@ -170,8 +178,8 @@ pub fn create_ai_draft(conn: &Connection, input: CreateAiDraftInput) -> Result<A
         INSERT INTO ai_drafts(
           quarter_label, section_type, draft_text, citation_chunk_ids_json,
           model_name, model_params_hash, prompt_template_version, created_at, artifact_hash,
-          parent_draft_id, revision_number, revision_notes, branch_label
-        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+          parent_draft_id, revision_number, revision_notes, branch_label, quarter_snapshot_json
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
         "#,
         params![
             input.quarter_label,
@@ -187,6 +195,7 @@ pub fn create_ai_draft(conn: &Connection, input: CreateAiDraftInput) -> Result<A
             revision_number,
             input.revision_notes,
             input.branch_label,
+            snapshot_json,
         ],
     )
     .map_err(|e| {
@@ -0,0 +1,177 @@
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use super::{get_ai_draft, AiDraftArtifact, AiDraftSectionType};
+use crate::error::AppError;
+use crate::settings::{get_setting_as, set_setting};
+
+pub const AI_USAGE_POLICY_SETTING: &str = "ai.usage_policy";
+pub const MAX_POLICY_CHUNKS_PER_DRAFT: u32 = 200;
+pub const MAX_POLICY_DISCLAIMER_CHARS: usize = 1000;
+
+/// Admin-configured limits on AI drafting. The default policy allows everything and adds no
+/// disclaimer, so workspaces without a policy behave as before.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AiUsagePolicy {
+    /// Upper bound on citation chunks selected for one draft; None means no limit.
+    #[serde(default)]
+    pub max_chunks_per_draft: Option<u32>,
+    /// Section types that may be drafted; None allows all.
+    #[serde(default)]
+    pub allowed_section_types: Option<Vec<AiDraftSectionType>>,
+    /// Drafts need an approval record before they are exported or included in a report.
+    #[serde(default)]
+    pub require_approval_before_export: bool,
+    /// Appended below every exported AI-generated section.
+    #[serde(default)]
+    pub disclaimer: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AiDraftApproval {
+    pub draft_id: i64,
+    pub approved_by: String,
+    pub approved_at: String,
+}
+
+impl AiUsagePolicy {
+    pub fn validate(&self) -> Result<(), AppError> {
+        let chunks_ok = self
+            .max_chunks_per_draft
+            .is_none_or(|n| (1..=MAX_POLICY_CHUNKS_PER_DRAFT).contains(&n));
+        let sections_ok = self.allowed_section_types.as_ref().is_none_or(|s| !s.is_empty());
+        let disclaimer_ok = self
+            .disclaimer
+            .as_ref()
+            .is_none_or(|d| d.chars().count() <= MAX_POLICY_DISCLAIMER_CHARS);
+        if !chunks_ok || !sections_ok || !disclaimer_ok {
+            return Err(AppError::new(
+                "VALIDATION_AI_USAGE_POLICY_INVALID",
+                "AI usage policy needs a chunk limit of 1-200, at least one allowed section type and a disclaimer of at most 1000 characters",
+            )
+            .with_details(format!(
+                "max_chunks_per_draft={:?}; allowed_section_types={:?}; disclaimer_chars={}",
+                self.max_chunks_per_draft,
+                self.allowed_section_types,
+                self.disclaimer.as_ref().map(|d| d.chars().count()).unwrap_or(0)
+            )));
+        }
+        Ok(())
+    }
+
+    /// Trimmed disclaimer text; None when unset or blank.
+    pub fn disclaimer_text(&self) -> Option<&str> {
+        self.disclaimer.as_deref().map(str::trim).filter(|d| !d.is_empty())
+    }
+
+    /// Checks a drafting request (or a draft being saved) against the section and chunk limits.
+    pub fn check_draft(&self, section_type: &AiDraftSectionType, citation_chunk_count: usize) -> Result<(), AppError> {
+        if let Some(allowed) = &self.allowed_section_types {
+            if !allowed.contains(section_type) {
+                return Err(AppError::new(
+                    "AI_POLICY_VIOLATION",
+                    "The AI usage policy does not allow drafting this section type",
+                )
+                .with_details(format!("section_type={}", section_type.as_str())));
+            }
+        }
+        if let Some(max) = self.max_chunks_per_draft {
+            if citation_chunk_count > max as usize {
+                return Err(AppError::new(
+                    "AI_POLICY_VIOLATION",
+                    "The AI usage policy limits how many evidence chunks a draft may cite",
+                )
+                .with_details(format!("citation_chunks={citation_chunk_count}; max_chunks_per_draft={max}")));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn load_ai_usage_policy(conn: &Connection) -> Result<AiUsagePolicy, AppError> {
+    let policy = get_setting_as::<AiUsagePolicy>(conn, AI_USAGE_POLICY_SETTING)?.unwrap_or_default();
+    policy.validate()?;
+    Ok(policy)
+}
+
+pub fn save_ai_usage_policy(conn: &Connection, policy: &AiUsagePolicy) -> Result<(), AppError> {
+    policy.validate()?;
+    let value = serde_json::to_value(policy).map_err(|e| {
+        AppError::new("DB_ENCODE_FAILED", "Failed to encode AI usage policy").with_details(e.to_string())
+    })?;
+    set_setting(conn, AI_USAGE_POLICY_SETTING, &value)
+}
+
+pub fn get_ai_draft_approval(conn: &Connection, draft_id: i64) -> Result<Option<AiDraftApproval>, AppError> {
+    conn.query_row(
+        "SELECT draft_id, approved_by, approved_at FROM ai_draft_approvals WHERE draft_id = ?1",
+        [draft_id],
+        |row| {
+            Ok(AiDraftApproval {
+                draft_id: row.get(0)?,
+                approved_by: row.get(1)?,
+                approved_at: row.get(2)?,
+            })
+        },
+    )
+    .optional()
+    .map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query draft approval")
+            .with_details(format!("draft_id={draft_id}; err={e}"))
+    })
+}
+
+/// Records sign-off on a draft; approving again replaces the previous approver and time.
+pub fn approve_ai_draft(
+    conn: &Connection,
+    draft_id: i64,
+    approved_by: &str,
+    approved_at: &str,
+) -> Result<AiDraftApproval, AppError> {
+    if get_ai_draft(conn, draft_id)?.is_none() {
+        return Err(AppError::new("DB_NOT_FOUND", "AI draft not found").with_details(format!("draft_id={draft_id}")));
+    }
+    if approved_by.trim().is_empty() {
+        return Err(AppError::new("DB_AI_DRAFT_INVALID", "Approver name is required")
+            .with_details(format!("draft_id={draft_id}")));
+    }
+    conn.execute(
+        "INSERT INTO ai_draft_approvals(draft_id, approved_by, approved_at) VALUES (?1, ?2, ?3)
+         ON CONFLICT(draft_id) DO UPDATE SET approved_by = excluded.approved_by, approved_at = excluded.approved_at",
+        params![draft_id, approved_by.trim(), approved_at],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to store draft approval")
+            .with_details(format!("draft_id={draft_id}; err={e}"))
+    })?;
+    Ok(AiDraftApproval {
+        draft_id,
+        approved_by: approved_by.trim().to_string(),
+        approved_at: approved_at.to_string(),
+    })
+}
+
+pub fn revoke_ai_draft_approval(conn: &Connection, draft_id: i64) -> Result<(), AppError> {
+    conn.execute("DELETE FROM ai_draft_approvals WHERE draft_id = ?1", [draft_id])
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to revoke draft approval")
+                .with_details(format!("draft_id={draft_id}; err={e}"))
+        })?;
+    Ok(())
+}
+
+/// Fails with `AI_POLICY_APPROVAL_REQUIRED` when the policy requires sign-off and `draft` has none.
+pub fn ensure_draft_exportable(
+    conn: &Connection,
+    policy: &AiUsagePolicy,
+    draft: &AiDraftArtifact,
+) -> Result<(), AppError> {
+    if policy.require_approval_before_export && get_ai_draft_approval(conn, draft.id)?.is_none() {
+        return Err(AppError::new(
+            "AI_POLICY_APPROVAL_REQUIRED",
+            "The AI usage policy requires approval before this draft is exported",
+        )
+        .with_details(format!("draft_id={}", draft.id)));
+    }
+    Ok(())
+}
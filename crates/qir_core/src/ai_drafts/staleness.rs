@@ -0,0 +1,189 @@
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{list_ai_drafts, AiDraftArtifact};
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::quarters::{
+    load_quarter_assignment_anchor, resolve_quarter_scope, QuarterAssignmentAnchor, QuarterScope,
+};
+use crate::repo;
+
+/// Per-incident content hashes for one quarter, recorded when a draft is created.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuarterSnapshot {
+    pub content_hash: String,
+    pub incidents: BTreeMap<i64, String>,
+}
+
+/// What changed in the draft's quarter since it was generated.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DraftDataChanges {
+    /// False for drafts created before snapshots were recorded; they are never flagged stale.
+    pub tracked: bool,
+    pub incident_count_at_draft: u32,
+    pub incident_count_now: u32,
+    pub new_incident_ids: Vec<i64>,
+    pub changed_incident_ids: Vec<i64>,
+    pub removed_incident_ids: Vec<i64>,
+}
+
+/// Drafts list entry: the stored artifact plus staleness against current data.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AiDraftListItem {
+    #[serde(flatten)]
+    pub draft: AiDraftArtifact,
+    pub stale: bool,
+    pub changes: DraftDataChanges,
+}
+
+fn incident_hash(incident: &Incident) -> String {
+    // Same serialization as compute_incidents_content_hash, so ingested_at is not included.
+    let json = serde_json::to_string(incident).unwrap_or_default();
+    hex::encode(Sha256::digest(json.as_bytes()))
+}
+
+fn snapshot_from(
+    incidents: &[Incident],
+    scope: Option<&QuarterScope>,
+    anchor: QuarterAssignmentAnchor,
+) -> QuarterSnapshot {
+    let mut map = BTreeMap::new();
+    for inc in incidents {
+        let in_scope = scope.map(|s| s.contains_assigned(anchor, inc)).unwrap_or(false);
+        if in_scope {
+            map.insert(inc.id, incident_hash(inc));
+        }
+    }
+    let mut hasher = Sha256::new();
+    for (id, h) in &map {
+        hasher.update(format!("{id}={h}\n").as_bytes());
+    }
+    QuarterSnapshot {
+        content_hash: hex::encode(hasher.finalize()),
+        incidents: map,
+    }
+}
+
+// Labels that no longer resolve (quarter definition deleted) snapshot as empty.
+fn scope_for(conn: &Connection, quarter_label: &str) -> Result<Option<QuarterScope>, AppError> {
+    match resolve_quarter_scope(conn, quarter_label) {
+        Ok(scope) => Ok(Some(scope)),
+        Err(e) if e.code == "QUARTER_NOT_FOUND" => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn quarter_snapshot(conn: &Connection, quarter_label: &str) -> Result<QuarterSnapshot, AppError> {
+    let scope = scope_for(conn, quarter_label)?;
+    let anchor = load_quarter_assignment_anchor(conn)?;
+    Ok(snapshot_from(&repo::list_incidents(conn)?, scope.as_ref(), anchor))
+}
+
+pub fn compare_snapshots(at_draft: Option<&QuarterSnapshot>, now: &QuarterSnapshot) -> DraftDataChanges {
+    let Some(then) = at_draft else {
+        return DraftDataChanges {
+            incident_count_now: now.incidents.len() as u32,
+            ..DraftDataChanges::default()
+        };
+    };
+    let mut changes = DraftDataChanges {
+        tracked: true,
+        incident_count_at_draft: then.incidents.len() as u32,
+        incident_count_now: now.incidents.len() as u32,
+        ..DraftDataChanges::default()
+    };
+    if then.content_hash == now.content_hash {
+        return changes;
+    }
+    for (id, h) in &now.incidents {
+        match then.incidents.get(id) {
+            None => changes.new_incident_ids.push(*id),
+            Some(prev) if prev != h => changes.changed_incident_ids.push(*id),
+            Some(_) => {}
+        }
+    }
+    for id in then.incidents.keys() {
+        if !now.incidents.contains_key(id) {
+            changes.removed_incident_ids.push(*id);
+        }
+    }
+    changes
+}
+
+impl DraftDataChanges {
+    pub fn is_stale(&self) -> bool {
+        !self.new_incident_ids.is_empty()
+            || !self.changed_incident_ids.is_empty()
+            || !self.removed_incident_ids.is_empty()
+    }
+}
+
+pub(crate) fn encode_snapshot(snapshot: &QuarterSnapshot) -> Result<String, AppError> {
+    serde_json::to_string(snapshot).map_err(|e| {
+        AppError::new("DB_AI_DRAFT_CREATE_FAILED", "Failed to encode draft quarter snapshot")
+            .with_details(e.to_string())
+    })
+}
+
+fn read_snapshots(conn: &Connection) -> Result<HashMap<i64, QuarterSnapshot>, AppError> {
+    let mut stmt = conn
+        .prepare("SELECT id, quarter_snapshot_json FROM ai_drafts WHERE quarter_snapshot_json IS NOT NULL")
+        .map_err(|e| {
+            AppError::new("DB_AI_DRAFT_QUERY_FAILED", "Failed to prepare draft snapshot query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt
+        .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))
+        .map_err(|e| {
+            AppError::new("DB_AI_DRAFT_QUERY_FAILED", "Failed to query draft snapshots")
+                .with_details(e.to_string())
+        })?;
+    let mut out = HashMap::new();
+    for r in rows {
+        let (id, json) = r.map_err(|e| {
+            AppError::new("DB_AI_DRAFT_QUERY_FAILED", "Failed to read draft snapshot row")
+                .with_details(e.to_string())
+        })?;
+        let snapshot: QuarterSnapshot = serde_json::from_str(&json).map_err(|e| {
+            AppError::new("DB_AI_DRAFT_QUERY_FAILED", "Failed to decode draft quarter snapshot")
+                .with_details(format!("draft_id={id}; err={e}"))
+        })?;
+        out.insert(id, snapshot);
+    }
+    Ok(out)
+}
+
+/// `list_ai_drafts` with each draft compared against the current incidents of its quarter.
+pub fn list_ai_drafts_with_staleness(
+    conn: &Connection,
+    quarter_label: Option<&str>,
+) -> Result<Vec<AiDraftListItem>, AppError> {
+    let drafts = list_ai_drafts(conn, quarter_label)?;
+    let snapshots = read_snapshots(conn)?;
+    let incidents = repo::list_incidents(conn)?;
+    let anchor = load_quarter_assignment_anchor(conn)?;
+    let mut current: HashMap<String, QuarterSnapshot> = HashMap::new();
+
+    let mut out = Vec::with_capacity(drafts.len());
+    for draft in drafts {
+        let now = match current.entry(draft.quarter_label.clone()) {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => {
+                let scope = scope_for(conn, &draft.quarter_label)?;
+                e.insert(snapshot_from(&incidents, scope.as_ref(), anchor))
+            }
+        };
+        let changes = compare_snapshots(snapshots.get(&draft.id), now);
+        out.push(AiDraftListItem {
+            stale: changes.is_stale(),
+            changes,
+            draft,
+        });
+    }
+    Ok(out)
+}
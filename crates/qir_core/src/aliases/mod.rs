@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::Incident;
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AliasKind {
+    Vendor,
+    Service,
+}
+
+impl AliasKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AliasKind::Vendor => "vendor",
+            AliasKind::Service => "service",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "vendor" => Some(AliasKind::Vendor),
+            "service" => Some(AliasKind::Service),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EntityAlias {
+    pub kind: AliasKind,
+    pub alias: String,
+    pub canonical: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EntityAliasInput {
+    pub kind: AliasKind,
+    pub alias: String,
+    pub canonical: String,
+}
+
+fn map_row(row: &rusqlite::Row<'_>) -> Result<(String, String, String, String, String), rusqlite::Error> {
+    Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?))
+}
+
+fn decode(row: (String, String, String, String, String)) -> Result<EntityAlias, AppError> {
+    let (kind, alias, canonical, created_at, updated_at) = row;
+    let kind = AliasKind::parse(&kind).ok_or_else(|| {
+        AppError::new("DB_QUERY_FAILED", "Unknown entity alias kind").with_details(format!("kind={kind}; alias={alias}"))
+    })?;
+    Ok(EntityAlias {
+        kind,
+        alias,
+        canonical,
+        created_at,
+        updated_at,
+    })
+}
+
+fn get_entity_alias(conn: &Connection, kind: AliasKind, alias: &str) -> Result<Option<EntityAlias>, AppError> {
+    conn.query_row(
+        "SELECT kind, alias, canonical, created_at, updated_at FROM entity_aliases WHERE kind = ?1 AND alias = ?2",
+        params![kind.as_str(), alias],
+        map_row,
+    )
+    .optional()
+    .map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query entity alias")
+            .with_details(format!("kind={}; alias={alias}; err={e}", kind.as_str()))
+    })?
+    .map(decode)
+    .transpose()
+}
+
+/// Aliases of one kind (or all), grouped by canonical name.
+pub fn list_entity_aliases(conn: &Connection, kind: Option<AliasKind>) -> Result<Vec<EntityAlias>, AppError> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT kind, alias, canonical, created_at, updated_at FROM entity_aliases
+             WHERE ?1 IS NULL OR kind = ?1
+             ORDER BY kind ASC, canonical ASC, alias ASC",
+        )
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare entity alias query").with_details(e.to_string())
+        })?;
+    let rows = stmt.query_map([kind.map(|k| k.as_str())], map_row).map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query entity aliases").with_details(e.to_string())
+    })?;
+    let mut out = Vec::new();
+    for r in rows {
+        out.push(decode(r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode entity alias row").with_details(e.to_string())
+        })?)?);
+    }
+    Ok(out)
+}
+
+/// Creates or retargets an alias. Aliases are one step: a canonical name may not itself be an
+/// alias, and a name other aliases resolve to may not become an alias.
+pub fn upsert_entity_alias(conn: &Connection, input: &EntityAliasInput) -> Result<EntityAlias, AppError> {
+    let kind = input.kind;
+    let alias = input.alias.trim();
+    let canonical = input.canonical.trim();
+    let invalid = |message: &str| {
+        AppError::new("VALIDATION_ENTITY_ALIAS_INVALID", message)
+            .with_details(format!("kind={}; alias={alias}; canonical={canonical}", kind.as_str()))
+    };
+    if alias.is_empty() || canonical.is_empty() {
+        return Err(invalid("Aliases need a non-blank alias and canonical name"));
+    }
+    if alias.eq_ignore_ascii_case(canonical) {
+        return Err(invalid("An alias must differ from its canonical name"));
+    }
+    if let Some(chained) = get_entity_alias(conn, kind, canonical)? {
+        return Err(invalid(&format!("{} is itself an alias of {}", chained.alias, chained.canonical)));
+    }
+    let targeted: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM entity_aliases WHERE kind = ?1 AND canonical = ?2 COLLATE NOCASE",
+            params![kind.as_str(), alias],
+            |row| row.get(0),
+        )
+        .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to check entity aliases").with_details(e.to_string()))?;
+    if targeted > 0 {
+        return Err(invalid("Other aliases resolve to this name; retarget them first"));
+    }
+    conn.execute(
+        "INSERT INTO entity_aliases(kind, alias, canonical, created_at, updated_at)
+         VALUES (?1, ?2, ?3, strftime('%Y-%m-%dT%H:%M:%fZ','now'), strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+         ON CONFLICT(kind, alias) DO UPDATE SET
+           alias = excluded.alias,
+           canonical = excluded.canonical,
+           updated_at = excluded.updated_at",
+        params![kind.as_str(), alias, canonical],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to store entity alias")
+            .with_details(format!("kind={}; alias={alias}; err={e}", kind.as_str()))
+    })?;
+    get_entity_alias(conn, kind, alias)?.ok_or_else(|| {
+        AppError::new("DB_NOT_FOUND", "Entity alias missing after write")
+            .with_details(format!("kind={}; alias={alias}", kind.as_str()))
+    })
+}
+
+pub fn delete_entity_alias(conn: &Connection, kind: AliasKind, alias: &str) -> Result<(), AppError> {
+    let alias = alias.trim();
+    let deleted = conn
+        .execute(
+            "DELETE FROM entity_aliases WHERE kind = ?1 AND alias = ?2",
+            params![kind.as_str(), alias],
+        )
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to delete entity alias")
+                .with_details(format!("kind={}; alias={alias}; err={e}", kind.as_str()))
+        })?;
+    if deleted == 0 {
+        return Err(AppError::new("DB_NOT_FOUND", "Entity alias not found")
+            .with_details(format!("kind={}; alias={alias}", kind.as_str())));
+    }
+    Ok(())
+}
+
+/// Copies every alias into `dest` (a scoped snapshot), replacing what it holds.
+pub(crate) fn copy_entity_aliases(conn: &Connection, dest: &Connection) -> Result<(), AppError> {
+    let write_failed = |e: rusqlite::Error| {
+        AppError::new("DB_WRITE_FAILED", "Failed to copy entity aliases").with_details(e.to_string())
+    };
+    dest.execute("DELETE FROM entity_aliases", []).map_err(write_failed)?;
+    for a in list_entity_aliases(conn, None)? {
+        dest.execute(
+            "INSERT INTO entity_aliases(kind, alias, canonical, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![a.kind.as_str(), a.alias, a.canonical, a.created_at, a.updated_at],
+        )
+        .map_err(write_failed)?;
+    }
+    Ok(())
+}
+
+/// The alias table loaded once for an import or a rollup. Unknown values pass through
+/// unchanged; a value matching a canonical name case-insensitively adopts its spelling.
+#[derive(Debug, Clone, Default)]
+pub struct AliasResolver {
+    by_key: HashMap<(AliasKind, String), String>,
+}
+
+impl AliasResolver {
+    pub fn load(conn: &Connection) -> Result<Self, AppError> {
+        let mut by_key = HashMap::new();
+        for a in list_entity_aliases(conn, None)? {
+            by_key
+                .entry((a.kind, a.canonical.to_lowercase()))
+                .or_insert_with(|| a.canonical.clone());
+            by_key.insert((a.kind, a.alias.to_lowercase()), a.canonical);
+        }
+        Ok(Self { by_key })
+    }
+
+    pub fn resolve(&self, kind: AliasKind, raw: &str) -> String {
+        self.by_key
+            .get(&(kind, raw.trim().to_lowercase()))
+            .cloned()
+            .unwrap_or_else(|| raw.to_string())
+    }
+
+    /// Rewrites vendor and service on each incident to their canonical names.
+    pub fn apply(&self, incidents: &mut [Incident]) {
+        if self.by_key.is_empty() {
+            return;
+        }
+        for inc in incidents {
+            inc.vendor = inc.vendor.take().map(|v| self.resolve(AliasKind::Vendor, &v));
+            inc.service = inc.service.take().map(|s| self.resolve(AliasKind::Service, &s));
+        }
+    }
+}
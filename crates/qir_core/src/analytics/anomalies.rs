@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use super::funnel::{incident_lifecycle_funnel, IncidentLifecycleFunnel};
+use super::{build_dashboard_payload_v2, DashboardPayloadV2, IncidentSummaryV2};
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::metrics::{format_duration_seconds, percentile};
+use crate::quarters::{QuarterAssignmentAnchor, QuarterScope};
+use crate::repo::list_incidents;
+
+/// Below this many known values a P90 is not worth calling out.
+const TAIL_MIN_KNOWN: usize = 5;
+/// A tail is "driven by" a few incidents only when this many or fewer sit at/above P90.
+const TAIL_MAX_DRIVERS: usize = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AnomalyCallout {
+    /// metric_tail | new_vendor | new_service | data_gap
+    pub kind: String,
+    pub message: String,
+    pub incident_ids: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AnomalySummary {
+    pub quarter_label: Option<String>,
+    pub callouts: Vec<AnomalyCallout>,
+}
+
+/// Vendors and services seen on incidents assigned before the quarter in scope.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PriorExposure {
+    pub incident_count: i64,
+    pub vendors: BTreeSet<String>,
+    pub services: BTreeSet<String>,
+}
+
+impl PriorExposure {
+    /// Incidents the assignment rule places strictly before `scope` starts.
+    pub fn before_scope(incidents: &[Incident], scope: &QuarterScope, anchor: QuarterAssignmentAnchor) -> Self {
+        let parse = |t: &str| OffsetDateTime::parse(t.trim(), &Rfc3339).ok();
+        let mut prior = Self::default();
+        let Some(start) = parse(&scope.start_ts) else {
+            return prior;
+        };
+        for inc in incidents {
+            if anchor.anchor_ts(inc).and_then(parse).is_some_and(|t| t < start) {
+                prior.incident_count += 1;
+                prior.vendors.extend(inc.vendor.clone());
+                prior.services.extend(inc.service.clone());
+            }
+        }
+        prior
+    }
+}
+
+fn incident_label(inc: &IncidentSummaryV2) -> String {
+    inc.external_id.clone().unwrap_or_else(|| format!("#{}", inc.id))
+}
+
+fn metric_tail(
+    dash: &DashboardPayloadV2,
+    name: &str,
+    value: fn(&IncidentSummaryV2) -> Option<i64>,
+) -> Option<AnomalyCallout> {
+    let mut known: Vec<i64> = dash.incidents.iter().filter_map(value).collect();
+    if known.len() < TAIL_MIN_KNOWN {
+        return None;
+    }
+    let p50 = percentile(&mut known.clone(), 50, 100)?;
+    let p90 = percentile(&mut known, 90, 100)?;
+    if p90 <= 0 || p90 < p50.saturating_mul(2) {
+        return None;
+    }
+    let mut drivers: Vec<(&IncidentSummaryV2, i64)> = dash
+        .incidents
+        .iter()
+        .filter_map(|i| value(i).filter(|v| *v >= p90).map(|v| (i, v)))
+        .collect();
+    if drivers.len() > TAIL_MAX_DRIVERS {
+        return None;
+    }
+    drivers.sort_by(|a, b| b.1.cmp(&a.1).then(incident_label(a.0).cmp(&incident_label(b.0))));
+    let listed: Vec<String> = drivers
+        .iter()
+        .map(|(i, v)| format!("{} ({})", incident_label(i), format_duration_seconds(Some(*v))))
+        .collect();
+    let mut incident_ids: Vec<i64> = drivers.iter().map(|(i, _)| i.id).collect();
+    incident_ids.sort();
+    Some(AnomalyCallout {
+        kind: "metric_tail".to_string(),
+        message: format!(
+            "{name} P90 ({}) driven by {} incident{}: {}",
+            format_duration_seconds(Some(p90)),
+            drivers.len(),
+            if drivers.len() == 1 { "" } else { "s" },
+            listed.join(", ")
+        ),
+        incident_ids,
+    })
+}
+
+fn first_appearances(
+    dash: &DashboardPayloadV2,
+    kind: &str,
+    noun: &str,
+    field: fn(&IncidentSummaryV2) -> Option<&String>,
+    seen: &BTreeSet<String>,
+) -> Vec<AnomalyCallout> {
+    let mut fresh: BTreeMap<&str, Vec<i64>> = BTreeMap::new();
+    for inc in &dash.incidents {
+        if let Some(name) = field(inc).filter(|n| !seen.contains(*n)) {
+            fresh.entry(name.as_str()).or_default().push(inc.id);
+        }
+    }
+    fresh
+        .into_iter()
+        .map(|(name, mut ids)| {
+            ids.sort();
+            AnomalyCallout {
+                kind: kind.to_string(),
+                message: format!(
+                    "{noun} {name} appeared for the first time this quarter ({} incident{})",
+                    ids.len(),
+                    if ids.len() == 1 { "" } else { "s" }
+                ),
+                incident_ids: ids,
+            }
+        })
+        .collect()
+}
+
+fn data_gap(funnel: &IncidentLifecycleFunnel) -> Option<AnomalyCallout> {
+    let stage = funnel
+        .stages
+        .iter()
+        .find(|s| Some(&s.stage) == funnel.weakest_stage.as_ref())?;
+    // Only worth a headline when a quarter or more of the timelines stop here.
+    if stage.drop_off_count < 2 || stage.drop_off_count * 4 < funnel.incident_count {
+        return None;
+    }
+    Some(AnomalyCallout {
+        kind: "data_gap".to_string(),
+        message: format!(
+            "Timeline capture breaks down at {} ({}): {} of {} incidents lose a complete timeline at this stage",
+            stage.label, stage.stage, stage.drop_off_count, funnel.incident_count
+        ),
+        incident_ids: stage.missing_incident_ids.clone(),
+    })
+}
+
+/// Rule-based callouts over dashboard data, in a fixed order: metric tails (MTTR, time to
+/// mitigation, IT awareness lag), first-time vendors, first-time services, then the largest
+/// timeline data gap. First-appearance rules need `prior` with at least one earlier incident.
+pub fn anomaly_callouts(
+    dash: &DashboardPayloadV2,
+    funnel: &IncidentLifecycleFunnel,
+    prior: Option<&PriorExposure>,
+) -> Vec<AnomalyCallout> {
+    let mut out = Vec::new();
+    out.extend(metric_tail(dash, "MTTR", |i| i.mttr_seconds));
+    out.extend(metric_tail(dash, "Time to mitigation", |i| i.time_to_mitigation_seconds));
+    out.extend(metric_tail(dash, "IT awareness lag", |i| i.it_awareness_lag_seconds));
+    if let Some(prior) = prior.filter(|p| p.incident_count > 0) {
+        out.extend(first_appearances(dash, "new_vendor", "Vendor", |i| i.vendor.as_ref(), &prior.vendors));
+        out.extend(first_appearances(dash, "new_service", "Service", |i| i.service.as_ref(), &prior.services));
+    }
+    out.extend(data_gap(funnel));
+    out
+}
+
+/// Callouts for the workspace, or for the incidents the assignment rule places in
+/// `quarter_label` (compared against everything assigned before that quarter).
+pub fn build_anomaly_summary(conn: &Connection, quarter_label: Option<&str>) -> Result<AnomalySummary, AppError> {
+    let callouts = match quarter_label {
+        None => {
+            let dash = build_dashboard_payload_v2(conn)?;
+            anomaly_callouts(&dash, &incident_lifecycle_funnel(&list_incidents(conn)?), None)
+        }
+        Some(q) => {
+            let mut all = list_incidents(conn)?;
+            crate::aliases::AliasResolver::load(conn)?.apply(&mut all);
+            let scope = crate::quarters::resolve_quarter_scope(conn, q)?;
+            let anchor = crate::quarters::load_quarter_assignment_anchor(conn)?;
+            let prior = PriorExposure::before_scope(&all, &scope, anchor);
+            let scoped: Vec<Incident> = all.into_iter().filter(|i| scope.contains_assigned(anchor, i)).collect();
+            let snapshot = super::scoped_snapshot(conn, &scoped)?;
+            let dash = build_dashboard_payload_v2(&snapshot)?;
+            anomaly_callouts(&dash, &incident_lifecycle_funnel(&scoped), Some(&prior))
+        }
+    };
+    Ok(AnomalySummary {
+        quarter_label: quarter_label.map(str::to_string),
+        callouts,
+    })
+}
@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::Path;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use super::drilldown::{bucket_drilldown, DrilldownIncident};
+use super::{build_dashboard_payload_v2, scoped_snapshot, DashboardPayloadV2};
+use crate::error::AppError;
+use crate::repo::list_incidents;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BucketCsvExportResult {
+    pub csv_path: String,
+    pub bucket_key: String,
+    pub bucket_label: String,
+    pub quarter_label: Option<String>,
+    pub incident_count: i64,
+    pub csv_bytes: u64,
+}
+
+const BUCKET_CSV_HEADER: [&str; 12] = [
+    "id",
+    "external_id",
+    "title",
+    "severity",
+    "vendor",
+    "service",
+    "start_ts",
+    "mtta_seconds",
+    "time_to_mitigation_seconds",
+    "mttr_seconds",
+    "pain_units",
+    "warning_count",
+];
+
+/// (label, incident_ids) of the bucket with `key`. Severity counts are addressed as
+/// `severity:<name>`. Vendor/service keys resolve to the by-count list; named buckets hold the
+/// same incidents in the by-pain list, only OTHER can differ.
+fn find_bucket(dash: &DashboardPayloadV2, key: &str) -> Option<(String, Vec<i64>)> {
+    if let Some(sev) = key.strip_prefix("severity:") {
+        return dash
+            .severity_counts
+            .iter()
+            .find(|s| s.severity == sev)
+            .map(|s| (s.severity.clone(), s.incident_ids.clone()));
+    }
+    let det = &dash.detection_story;
+    let vs = &dash.vendor_service_story;
+    let resp = &dash.response_story;
+    let categories = det
+        .detection_source_mix
+        .iter()
+        .chain(&vs.top_vendors_by_count)
+        .chain(&vs.top_services_by_count)
+        .chain(&resp.start_hours_buckets)
+        .map(|b| (&b.key, &b.label, &b.incident_ids));
+    let pain = vs
+        .top_vendors_by_pain
+        .iter()
+        .chain(&vs.top_services_by_pain)
+        .map(|b| (&b.key, &b.label, &b.incident_ids));
+    let durations = det
+        .it_awareness_lag_buckets
+        .iter()
+        .chain(&resp.time_to_mitigation_buckets)
+        .chain(&resp.time_to_resolve_buckets)
+        .chain(dash.computed_metrics.iter().flat_map(|m| &m.buckets))
+        .map(|b| (&b.key, &b.label, &b.incident_ids));
+    categories
+        .chain(pain)
+        .chain(durations)
+        .find(|(k, _, _)| k.as_str() == key)
+        .map(|(_, label, ids)| (label.clone(), ids.clone()))
+}
+
+fn csv_row(i: &DrilldownIncident) -> [String; 12] {
+    let opt = |v: Option<i64>| v.map(|v| v.to_string()).unwrap_or_default();
+    [
+        i.id.to_string(),
+        i.external_id.clone().unwrap_or_default(),
+        i.title.clone(),
+        i.severity.clone().unwrap_or_default(),
+        i.vendor.clone().unwrap_or_default(),
+        i.service.clone().unwrap_or_default(),
+        i.start_ts.clone().unwrap_or_default(),
+        opt(i.mtta_seconds),
+        opt(i.time_to_mitigation_seconds),
+        opt(i.mttr_seconds),
+        opt(i.pain_units),
+        i.warning_count.to_string(),
+    ]
+}
+
+fn filename_part(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect()
+}
+
+/// Writes the incidents of one dashboard bucket as `bucket_<key>[_<quarter>].csv` into
+/// `destination_dir`. With a quarter the bucket is looked up in that quarter's dashboard, so
+/// the file matches what the scoped view shows. Rows use the drill-down order and columns.
+pub fn export_bucket_csv(
+    conn: &Connection,
+    bucket_key: &str,
+    quarter_label: Option<&str>,
+    destination_dir: &Path,
+) -> Result<BucketCsvExportResult, AppError> {
+    if !destination_dir.is_dir() {
+        return Err(AppError::new(
+            "EXPORT_BUCKET_DEST_NOT_DIR",
+            "Bucket export destination must be an existing directory",
+        )
+        .with_details(destination_dir.display().to_string()));
+    }
+    let dash = match quarter_label {
+        Some(q) => {
+            let scope = crate::quarters::resolve_quarter_scope(conn, q)?;
+            let anchor = crate::quarters::load_quarter_assignment_anchor(conn)?;
+            let mut incidents = list_incidents(conn)?;
+            incidents.retain(|i| scope.contains_assigned(anchor, i));
+            build_dashboard_payload_v2(&scoped_snapshot(conn, &incidents)?)?
+        }
+        None => build_dashboard_payload_v2(conn)?,
+    };
+    let (bucket_label, ids) = find_bucket(&dash, bucket_key).ok_or_else(|| {
+        AppError::new("VALIDATION_BUCKET_KEY_UNKNOWN", "No dashboard bucket has this key")
+            .with_details(format!("bucket_key={bucket_key}; quarter={}", quarter_label.unwrap_or("all")))
+    })?;
+    let drill = bucket_drilldown(conn, &ids)?;
+
+    let mut name = format!("bucket_{}", filename_part(bucket_key));
+    if let Some(q) = quarter_label {
+        name.push_str(&format!("_{}", filename_part(q)));
+    }
+    let csv_path = destination_dir.join(format!("{name}.csv"));
+    if csv_path.exists() {
+        return Err(AppError::new(
+            "EXPORT_BUCKET_DEST_EXISTS",
+            "Bucket export destination file already exists",
+        )
+        .with_details(csv_path.display().to_string()));
+    }
+
+    let encode_failed =
+        |e: csv::Error| AppError::new("EXPORT_BUCKET_ENCODE_FAILED", "Failed to encode bucket CSV").with_details(e.to_string());
+    let mut out = csv::Writer::from_writer(Vec::new());
+    out.write_record(BUCKET_CSV_HEADER).map_err(encode_failed)?;
+    for inc in &drill.incidents {
+        out.write_record(csv_row(inc)).map_err(encode_failed)?;
+    }
+    let bytes = out.into_inner().map_err(|e| {
+        AppError::new("EXPORT_BUCKET_ENCODE_FAILED", "Failed to encode bucket CSV").with_details(e.to_string())
+    })?;
+    fs::write(&csv_path, &bytes).map_err(|e| {
+        AppError::new("EXPORT_BUCKET_WRITE_FAILED", "Failed to write bucket CSV")
+            .with_details(format!("path={}; err={}", csv_path.display(), e))
+    })?;
+
+    Ok(BucketCsvExportResult {
+        csv_path: csv_path.to_string_lossy().to_string(),
+        bucket_key: bucket_key.to_string(),
+        bucket_label,
+        quarter_label: quarter_label.map(str::to_string),
+        incident_count: drill.incidents.len() as i64,
+        csv_bytes: bytes.len() as u64,
+    })
+}
@@ -0,0 +1,125 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::error::AppError;
+use crate::settings::{get_setting_as, set_setting};
+
+pub const BUSINESS_HOURS_SETTING: &str = "calendar.business_hours";
+
+/// Workspace working hours: `start_hour..end_hour` local time on `workdays`
+/// (ISO numbering, 1 = Monday .. 7 = Sunday), at a fixed UTC offset.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BusinessHoursConfig {
+    pub utc_offset_minutes: i32,
+    pub start_hour: u8,
+    pub end_hour: u8,
+    pub workdays: Vec<u8>,
+}
+
+impl Default for BusinessHoursConfig {
+    fn default() -> Self {
+        Self {
+            utc_offset_minutes: 0,
+            start_hour: 9,
+            end_hour: 17,
+            workdays: vec![1, 2, 3, 4, 5],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum HoursClass {
+    BusinessHours,
+    AfterHours,
+    Weekend,
+}
+
+impl HoursClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HoursClass::BusinessHours => "business_hours",
+            HoursClass::AfterHours => "after_hours",
+            HoursClass::Weekend => "weekend",
+        }
+    }
+}
+
+impl BusinessHoursConfig {
+    pub fn validate(&self) -> Result<(), AppError> {
+        let offset_ok = self.utc_offset_minutes.abs() <= 14 * 60;
+        let hours_ok = self.start_hour < self.end_hour && self.end_hour <= 24;
+        let days_ok = !self.workdays.is_empty() && self.workdays.iter().all(|d| (1..=7).contains(d));
+        if !offset_ok || !hours_ok || !days_ok {
+            return Err(AppError::new(
+                "VALIDATION_BUSINESS_HOURS_INVALID",
+                "Business hours need start < end within 0-24, workdays 1-7 and a UTC offset within +/-14:00",
+            )
+            .with_details(format!(
+                "utc_offset_minutes={}; start_hour={}; end_hour={}; workdays={:?}",
+                self.utc_offset_minutes, self.start_hour, self.end_hour, self.workdays
+            )));
+        }
+        Ok(())
+    }
+
+    /// Classifies an RFC3339 timestamp in the workspace's local time. Non-workdays are
+    /// `Weekend` regardless of hour. None for unparseable input.
+    pub fn classify(&self, ts: &str) -> Option<HoursClass> {
+        let offset = UtcOffset::from_whole_seconds(self.utc_offset_minutes * 60).ok()?;
+        let local = OffsetDateTime::parse(ts.trim(), &Rfc3339).ok()?.to_offset(offset);
+        if !self.workdays.contains(&local.weekday().number_from_monday()) {
+            return Some(HoursClass::Weekend);
+        }
+        if (self.start_hour..self.end_hour).contains(&local.hour()) {
+            Some(HoursClass::BusinessHours)
+        } else {
+            Some(HoursClass::AfterHours)
+        }
+    }
+
+    /// Seconds of `start..end` (RFC3339) that fall inside working hours in the workspace's
+    /// local time. None for unparseable input or an end before the start.
+    pub fn business_seconds_between(&self, start: &str, end: &str) -> Option<i64> {
+        let offset = UtcOffset::from_whole_seconds(self.utc_offset_minutes * 60).ok()?;
+        let start = OffsetDateTime::parse(start.trim(), &Rfc3339).ok()?.to_offset(offset);
+        let end = OffsetDateTime::parse(end.trim(), &Rfc3339).ok()?.to_offset(offset);
+        if end < start {
+            return None;
+        }
+        let mut total = 0;
+        let mut day = start.date();
+        while day <= end.date() {
+            if self.workdays.contains(&day.weekday().number_from_monday()) {
+                let open = day.with_hms(self.start_hour, 0, 0).ok()?.assume_offset(offset);
+                let close = if self.end_hour == 24 {
+                    day.next_day()?.midnight().assume_offset(offset)
+                } else {
+                    day.with_hms(self.end_hour, 0, 0).ok()?.assume_offset(offset)
+                };
+                let (from, to) = (open.max(start), close.min(end));
+                if to > from {
+                    total += (to - from).whole_seconds();
+                }
+            }
+            day = day.next_day()?;
+        }
+        Some(total)
+    }
+}
+
+pub fn load_business_hours(conn: &Connection) -> Result<BusinessHoursConfig, AppError> {
+    let cfg = get_setting_as::<BusinessHoursConfig>(conn, BUSINESS_HOURS_SETTING)?.unwrap_or_default();
+    cfg.validate()?;
+    Ok(cfg)
+}
+
+pub fn save_business_hours(conn: &Connection, cfg: &BusinessHoursConfig) -> Result<(), AppError> {
+    cfg.validate()?;
+    let value = serde_json::to_value(cfg).map_err(|e| {
+        AppError::new("DB_ENCODE_FAILED", "Failed to encode business hours").with_details(e.to_string())
+    })?;
+    set_setting(conn, BUSINESS_HOURS_SETTING, &value)
+}
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::metrics::{compute_incident_metrics, percentile};
+use crate::repo::list_incidents;
+
+/// Below this many known values a per-source percentile is flagged as a small sample.
+pub const DETECTION_SOURCE_MIN_SAMPLE: i64 = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MetricSample {
+    pub known_count: i64,
+    pub p50_seconds: Option<i64>,
+    pub p90_seconds: Option<i64>,
+    /// known_count is below DETECTION_SOURCE_MIN_SAMPLE; treat percentiles as anecdotal.
+    pub small_sample: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DetectionSourceResponse {
+    pub detection_source: String,
+    pub incident_count: i64,
+    pub incident_ids: Vec<i64>,
+    pub mtta: MetricSample,
+    pub mttr: MetricSample,
+    /// This source's MTTR P50 minus the all-sources P50; negative = resolved faster.
+    pub mttr_p50_delta_seconds: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DetectionSourceComparison {
+    pub quarter_label: Option<String>,
+    pub overall: DetectionSourceResponse,
+    /// Most incidents first, then source name.
+    pub sources: Vec<DetectionSourceResponse>,
+    pub caveats: Vec<String>,
+}
+
+fn sample(values: &[Option<i64>]) -> MetricSample {
+    let known: Vec<i64> = values.iter().filter_map(|v| *v).collect();
+    let known_count = known.len() as i64;
+    MetricSample {
+        known_count,
+        p50_seconds: percentile(&mut known.clone(), 50, 100),
+        p90_seconds: percentile(&mut known.clone(), 90, 100),
+        small_sample: known_count < DETECTION_SOURCE_MIN_SAMPLE,
+    }
+}
+
+/// (incident id, MTTA seconds, MTTR seconds).
+type ResponseRow = (i64, Option<i64>, Option<i64>);
+
+fn response(source: String, rows: &[ResponseRow]) -> DetectionSourceResponse {
+    let mut incident_ids: Vec<i64> = rows.iter().map(|r| r.0).collect();
+    incident_ids.sort();
+    let mtta: Vec<Option<i64>> = rows.iter().map(|r| r.1).collect();
+    let mttr: Vec<Option<i64>> = rows.iter().map(|r| r.2).collect();
+    DetectionSourceResponse {
+        detection_source: source,
+        incident_count: rows.len() as i64,
+        incident_ids,
+        mtta: sample(&mtta),
+        mttr: sample(&mttr),
+        mttr_p50_delta_seconds: None,
+    }
+}
+
+/// MTTA/MTTR per detection source over an already-scoped incident list. Missing sources
+/// group under UNKNOWN, as in the detection source mix.
+pub fn detection_source_comparison(incidents: &[Incident]) -> DetectionSourceComparison {
+    let mut all = Vec::with_capacity(incidents.len());
+    let mut by_source: BTreeMap<String, Vec<ResponseRow>> = BTreeMap::new();
+    for inc in incidents {
+        let (m, _) = compute_incident_metrics(inc);
+        let row = (inc.id, m.mtta_seconds, m.mttr_seconds);
+        all.push(row);
+        let key = inc.detection_source.clone().unwrap_or_else(|| "UNKNOWN".to_string());
+        by_source.entry(key).or_default().push(row);
+    }
+
+    let overall = response("ALL".to_string(), &all);
+    let mut sources: Vec<DetectionSourceResponse> = by_source
+        .into_iter()
+        .map(|(source, rows)| {
+            let mut r = response(source, &rows);
+            r.mttr_p50_delta_seconds = r.mttr.p50_seconds.zip(overall.mttr.p50_seconds).map(|(s, o)| s - o);
+            r
+        })
+        .collect();
+    sources.sort_by(|a, b| {
+        b.incident_count
+            .cmp(&a.incident_count)
+            .then(a.detection_source.cmp(&b.detection_source))
+    });
+
+    let mut caveats = Vec::new();
+    let small: Vec<&str> = sources
+        .iter()
+        .filter(|s| s.mttr.small_sample)
+        .map(|s| s.detection_source.as_str())
+        .collect();
+    if !small.is_empty() {
+        caveats.push(format!(
+            "Fewer than {DETECTION_SOURCE_MIN_SAMPLE} known MTTR values for: {}; differences are not statistically meaningful.",
+            small.join(", ")
+        ));
+    }
+    if sources.len() < 2 {
+        caveats.push("Only one detection source in scope; nothing to compare.".to_string());
+    }
+    caveats.push("Percentiles are descriptive only; no significance test is applied.".to_string());
+
+    DetectionSourceComparison {
+        quarter_label: None,
+        overall,
+        sources,
+        caveats,
+    }
+}
+
+/// Comparison for the workspace, optionally limited to the incidents the assignment rule
+/// places in `quarter_label`.
+pub fn build_detection_source_comparison(
+    conn: &Connection,
+    quarter_label: Option<&str>,
+) -> Result<DetectionSourceComparison, AppError> {
+    let mut incidents = list_incidents(conn)?;
+    if let Some(q) = quarter_label {
+        let scope = crate::quarters::resolve_quarter_scope(conn, q)?;
+        let anchor = crate::quarters::load_quarter_assignment_anchor(conn)?;
+        incidents.retain(|i| scope.contains_assigned(anchor, i));
+    }
+    let mut comparison = detection_source_comparison(&incidents);
+    comparison.quarter_label = quarter_label.map(str::to_string);
+    Ok(comparison)
+}
@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::cache::{compute_dashboard_v2_hash, DashboardCache};
+use crate::error::AppError;
+
+use super::{
+    build_dashboard_payload_v2, CategoryBucket, ComputedMetricDistribution, DashboardPayloadV2, DurationBucket,
+    IncidentSummaryV2, PainBucket, SeverityCount,
+};
+
+trait KeyedBucket {
+    fn bucket_key(&self) -> &str;
+}
+
+impl KeyedBucket for CategoryBucket {
+    fn bucket_key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl KeyedBucket for DurationBucket {
+    fn bucket_key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl KeyedBucket for PainBucket {
+    fn bucket_key(&self) -> &str {
+        &self.key
+    }
+}
+
+/// Changes to one bucket list. `order` is the full key order after applying the patch, so the
+/// client can rebuild the list from its previous buckets plus `upserted`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BucketListPatch<T> {
+    pub upserted: Vec<T>,
+    pub removed_keys: Vec<String>,
+    pub order: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DashboardPatchV2 {
+    pub incident_count: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_counts: Option<Vec<SeverityCount>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detection_source_mix: Option<BucketListPatch<CategoryBucket>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub it_awareness_lag_buckets: Option<BucketListPatch<DurationBucket>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_vendors_by_count: Option<BucketListPatch<CategoryBucket>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_services_by_count: Option<BucketListPatch<CategoryBucket>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_vendors_by_pain: Option<BucketListPatch<PainBucket>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_services_by_pain: Option<BucketListPatch<PainBucket>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_to_mitigation_buckets: Option<BucketListPatch<DurationBucket>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_to_resolve_buckets: Option<BucketListPatch<DurationBucket>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_hours_buckets: Option<BucketListPatch<CategoryBucket>>,
+    /// Full replacement of the computed metric distributions when any of them changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub computed_metrics: Option<Vec<ComputedMetricDistribution>>,
+    /// Present only when the pain formula changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pain_formula_version: Option<String>,
+    pub upserted_incidents: Vec<IncidentSummaryV2>,
+    pub removed_incident_ids: Vec<i64>,
+    /// Present only when the incident summary order changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incident_order: Option<Vec<i64>>,
+}
+
+/// Response of `dashboard_delta_v2`. `kind` is "unchanged", "patch", or "full"; "full" is
+/// returned when the client's `since_hash` is unknown (or expired from the cache).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DashboardDeltaV2 {
+    pub kind: String,
+    pub hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch: Option<DashboardPatchV2>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full: Option<DashboardPayloadV2>,
+}
+
+fn diff_buckets<T: KeyedBucket + Clone + PartialEq>(old: &[T], new: &[T]) -> Option<BucketListPatch<T>> {
+    if old == new {
+        return None;
+    }
+    let old_by_key: BTreeMap<&str, &T> = old.iter().map(|b| (b.bucket_key(), b)).collect();
+    let new_keys: Vec<&str> = new.iter().map(|b| b.bucket_key()).collect();
+    Some(BucketListPatch {
+        upserted: new
+            .iter()
+            .filter(|b| old_by_key.get(b.bucket_key()).copied() != Some(*b))
+            .cloned()
+            .collect(),
+        removed_keys: old
+            .iter()
+            .map(|b| b.bucket_key())
+            .filter(|k| !new_keys.contains(k))
+            .map(|k| k.to_string())
+            .collect(),
+        order: new_keys.into_iter().map(|k| k.to_string()).collect(),
+    })
+}
+
+/// Minimal patch that turns `old` into `new`. Pure and deterministic.
+pub fn diff_dashboard_v2(old: &DashboardPayloadV2, new: &DashboardPayloadV2) -> DashboardPatchV2 {
+    let old_incidents: BTreeMap<i64, &IncidentSummaryV2> = old.incidents.iter().map(|i| (i.id, i)).collect();
+    let new_ids: Vec<i64> = new.incidents.iter().map(|i| i.id).collect();
+    let old_ids: Vec<i64> = old.incidents.iter().map(|i| i.id).collect();
+
+    let mut removed_incident_ids: Vec<i64> = old_ids.iter().copied().filter(|id| !new_ids.contains(id)).collect();
+    removed_incident_ids.sort();
+
+    DashboardPatchV2 {
+        incident_count: new.incident_count,
+        severity_counts: (old.severity_counts != new.severity_counts).then(|| new.severity_counts.clone()),
+        detection_source_mix: diff_buckets(
+            &old.detection_story.detection_source_mix,
+            &new.detection_story.detection_source_mix,
+        ),
+        it_awareness_lag_buckets: diff_buckets(
+            &old.detection_story.it_awareness_lag_buckets,
+            &new.detection_story.it_awareness_lag_buckets,
+        ),
+        top_vendors_by_count: diff_buckets(
+            &old.vendor_service_story.top_vendors_by_count,
+            &new.vendor_service_story.top_vendors_by_count,
+        ),
+        top_services_by_count: diff_buckets(
+            &old.vendor_service_story.top_services_by_count,
+            &new.vendor_service_story.top_services_by_count,
+        ),
+        top_vendors_by_pain: diff_buckets(
+            &old.vendor_service_story.top_vendors_by_pain,
+            &new.vendor_service_story.top_vendors_by_pain,
+        ),
+        top_services_by_pain: diff_buckets(
+            &old.vendor_service_story.top_services_by_pain,
+            &new.vendor_service_story.top_services_by_pain,
+        ),
+        time_to_mitigation_buckets: diff_buckets(
+            &old.response_story.time_to_mitigation_buckets,
+            &new.response_story.time_to_mitigation_buckets,
+        ),
+        time_to_resolve_buckets: diff_buckets(
+            &old.response_story.time_to_resolve_buckets,
+            &new.response_story.time_to_resolve_buckets,
+        ),
+        start_hours_buckets: diff_buckets(
+            &old.response_story.start_hours_buckets,
+            &new.response_story.start_hours_buckets,
+        ),
+        computed_metrics: (old.computed_metrics != new.computed_metrics).then(|| new.computed_metrics.clone()),
+        pain_formula_version: (old.pain_formula_version != new.pain_formula_version)
+            .then(|| new.pain_formula_version.clone()),
+        upserted_incidents: new
+            .incidents
+            .iter()
+            .filter(|i| old_incidents.get(&i.id).copied() != Some(*i))
+            .cloned()
+            .collect(),
+        removed_incident_ids,
+        incident_order: (old_ids != new_ids).then_some(new_ids),
+    }
+}
+
+/// Returns "unchanged" when `since_hash` still matches, a patch against the cached payload
+/// for `since_hash` when available, otherwise the full payload. Always refreshes the cache.
+pub fn dashboard_delta_v2(
+    conn: &Connection,
+    cache: &DashboardCache,
+    since_hash: Option<&str>,
+) -> Result<DashboardDeltaV2, AppError> {
+    let hash = compute_dashboard_v2_hash(conn)?;
+    if since_hash == Some(hash.as_str()) {
+        return Ok(DashboardDeltaV2 {
+            kind: "unchanged".to_string(),
+            hash,
+            patch: None,
+            full: None,
+        });
+    }
+
+    let base = since_hash.and_then(|h| cache.get_v2(h));
+    let current = match cache.get_v2(&hash) {
+        Some(p) => p,
+        None => {
+            let p = build_dashboard_payload_v2(conn)?;
+            cache.set_v2(p.clone(), hash.clone());
+            p
+        }
+    };
+
+    Ok(match base {
+        Some(old) => DashboardDeltaV2 {
+            kind: "patch".to_string(),
+            hash,
+            patch: Some(diff_dashboard_v2(&old, &current)),
+            full: None,
+        },
+        None => DashboardDeltaV2 {
+            kind: "full".to_string(),
+            hash,
+            patch: None,
+            full: Some(current),
+        },
+    })
+}
@@ -0,0 +1,87 @@
+use std::collections::BTreeSet;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use super::business_hours::load_business_hours;
+use super::load_dashboard_config;
+use super::pain::load_pain_formula;
+use crate::error::AppError;
+use crate::metrics::compute_incident_metrics;
+use crate::repo::list_incidents;
+use crate::validate::validate_incident;
+
+/// One incident row of a bucket drill-down; enough for a list view without a detail call.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DrilldownIncident {
+    pub id: i64,
+    pub external_id: Option<String>,
+    pub title: String,
+    pub severity: Option<String>,
+    pub vendor: Option<String>,
+    pub service: Option<String>,
+    pub start_ts: Option<String>,
+    pub mtta_seconds: Option<i64>,
+    pub time_to_mitigation_seconds: Option<i64>,
+    pub mttr_seconds: Option<i64>,
+    /// Pain units as summed into the dashboard's pain buckets (workspace pain formula applied).
+    pub pain_units: Option<i64>,
+    pub warning_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BucketDrilldown {
+    /// Ordered like the dashboard incident list: external_id, then title, then id.
+    pub incidents: Vec<DrilldownIncident>,
+    /// Requested ids with no incident (deleted since the bucket was built), ascending.
+    pub missing_ids: Vec<i64>,
+}
+
+/// Compact summaries for the `incident_ids` of any dashboard bucket in one call. Duplicate ids
+/// are collapsed; the output order does not depend on the order of the request.
+pub fn bucket_drilldown(conn: &Connection, incident_ids: &[i64]) -> Result<BucketDrilldown, AppError> {
+    let mut wanted: BTreeSet<i64> = incident_ids.iter().copied().collect();
+    if wanted.is_empty() {
+        return Ok(BucketDrilldown {
+            incidents: Vec::new(),
+            missing_ids: Vec::new(),
+        });
+    }
+    let cfg = load_dashboard_config(conn)?;
+    let hours = load_business_hours(conn)?;
+    let formula = load_pain_formula(conn)?;
+
+    let mut all = list_incidents(conn)?;
+    crate::aliases::AliasResolver::load(conn)?.apply(&mut all);
+
+    let mut incidents = Vec::new();
+    for inc in all {
+        if !wanted.remove(&inc.id) {
+            continue;
+        }
+        let (metrics, metric_warnings) = compute_incident_metrics(&inc);
+        let warning_count = (metric_warnings.len() + validate_incident(&inc).len()) as i64;
+        incidents.push(DrilldownIncident {
+            pain_units: formula.incident_pain_units(&inc, metrics.mttr_seconds, &hours, cfg.after_hours_pain_multiplier_pct),
+            id: inc.id,
+            external_id: inc.external_id,
+            title: inc.title,
+            severity: inc.severity,
+            vendor: inc.vendor,
+            service: inc.service,
+            start_ts: inc.start_ts,
+            mtta_seconds: metrics.mtta_seconds,
+            time_to_mitigation_seconds: metrics.time_to_mitigation_seconds,
+            mttr_seconds: metrics.mttr_seconds,
+            warning_count,
+        });
+    }
+    incidents.sort_by(|a, b| {
+        (a.external_id.as_deref().unwrap_or_default(), a.title.as_str(), a.id)
+            .cmp(&(b.external_id.as_deref().unwrap_or_default(), b.title.as_str(), b.id))
+    });
+    Ok(BucketDrilldown {
+        incidents,
+        missing_ids: wanted.into_iter().collect(),
+    })
+}
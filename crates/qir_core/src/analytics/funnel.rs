@@ -0,0 +1,132 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::repo::list_incidents;
+
+/// Lifecycle order used by the funnel: (field, label).
+const FUNNEL_STAGES: [(&str, &str); 6] = [
+    ("start_ts", "Start"),
+    ("first_observed_ts", "Observed"),
+    ("it_awareness_ts", "IT aware"),
+    ("ack_ts", "Acknowledged"),
+    ("mitigate_ts", "Mitigated"),
+    ("resolve_ts", "Resolved"),
+];
+
+/// (canonical, raw) values of one lifecycle field.
+fn stage_values<'a>(inc: &'a Incident, stage: &str) -> (Option<&'a str>, Option<&'a str>) {
+    let (canonical, raw) = match stage {
+        "start_ts" => (&inc.start_ts, &inc.start_ts_raw),
+        "first_observed_ts" => (&inc.first_observed_ts, &inc.first_observed_ts_raw),
+        "it_awareness_ts" => (&inc.it_awareness_ts, &inc.it_awareness_ts_raw),
+        "ack_ts" => (&inc.ack_ts, &inc.ack_ts_raw),
+        "mitigate_ts" => (&inc.mitigate_ts, &inc.mitigate_ts_raw),
+        _ => (&inc.resolve_ts, &inc.resolve_ts_raw),
+    };
+    (canonical.as_deref(), raw.as_deref())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FunnelStage {
+    pub stage: String,
+    pub label: String,
+    /// Incidents with a parseable timestamp for this stage.
+    pub populated_count: i64,
+    /// Raw value captured at ingest but not parseable as RFC3339.
+    pub unparseable_count: i64,
+    pub missing_count: i64,
+    /// Populated here and at every earlier stage.
+    pub continuous_count: i64,
+    /// continuous_count lost relative to the previous stage.
+    pub drop_off_count: i64,
+    /// Incidents without a parseable timestamp for this stage, ascending.
+    pub missing_incident_ids: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentLifecycleFunnel {
+    pub quarter_label: Option<String>,
+    pub incident_count: i64,
+    pub stages: Vec<FunnelStage>,
+    /// Stage with the largest drop-off (earliest on ties); None when nothing drops.
+    pub weakest_stage: Option<String>,
+}
+
+fn parseable(ts: Option<&str>) -> bool {
+    ts.is_some_and(|t| OffsetDateTime::parse(t, &Rfc3339).is_ok())
+}
+
+/// Funnel over an already-scoped incident list.
+pub fn incident_lifecycle_funnel(incidents: &[Incident]) -> IncidentLifecycleFunnel {
+    let incident_count = incidents.len() as i64;
+    let mut continuous: Vec<bool> = vec![true; incidents.len()];
+    let mut previous_continuous = incident_count;
+    let mut stages = Vec::with_capacity(FUNNEL_STAGES.len());
+
+    for (stage, label) in FUNNEL_STAGES {
+        let mut populated_count = 0;
+        let mut unparseable_count = 0;
+        let mut missing_incident_ids = Vec::new();
+        for (idx, inc) in incidents.iter().enumerate() {
+            let (canonical, raw) = stage_values(inc, stage);
+            if parseable(canonical) {
+                populated_count += 1;
+            } else {
+                continuous[idx] = false;
+                missing_incident_ids.push(inc.id);
+                if raw.is_some_and(|r| !r.trim().is_empty()) {
+                    unparseable_count += 1;
+                }
+            }
+        }
+        missing_incident_ids.sort();
+        let continuous_count = continuous.iter().filter(|c| **c).count() as i64;
+        stages.push(FunnelStage {
+            stage: stage.to_string(),
+            label: label.to_string(),
+            populated_count,
+            unparseable_count,
+            missing_count: incident_count - populated_count,
+            continuous_count,
+            drop_off_count: previous_continuous - continuous_count,
+            missing_incident_ids,
+        });
+        previous_continuous = continuous_count;
+    }
+
+    let mut weakest: Option<&FunnelStage> = None;
+    for s in &stages {
+        if s.drop_off_count > weakest.map_or(0, |w| w.drop_off_count) {
+            weakest = Some(s);
+        }
+    }
+    let weakest_stage = weakest.map(|s| s.stage.clone());
+
+    IncidentLifecycleFunnel {
+        quarter_label: None,
+        incident_count,
+        stages,
+        weakest_stage,
+    }
+}
+
+/// Lifecycle funnel for the workspace, optionally limited to the incidents the assignment
+/// rule places in `quarter_label`.
+pub fn build_incident_lifecycle_funnel(
+    conn: &Connection,
+    quarter_label: Option<&str>,
+) -> Result<IncidentLifecycleFunnel, AppError> {
+    let mut incidents = list_incidents(conn)?;
+    if let Some(q) = quarter_label {
+        let scope = crate::quarters::resolve_quarter_scope(conn, q)?;
+        let anchor = crate::quarters::load_quarter_assignment_anchor(conn)?;
+        incidents.retain(|i| scope.contains_assigned(anchor, i));
+    }
+    let mut funnel = incident_lifecycle_funnel(&incidents);
+    funnel.quarter_label = quarter_label.map(str::to_string);
+    Ok(funnel)
+}
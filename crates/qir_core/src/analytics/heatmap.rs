@@ -0,0 +1,97 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::repo::list_incidents;
+
+pub const HEATMAP_WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const UTC_OFFSET_MAX_MINUTES: i32 = 14 * 60;
+
+/// 7×24 counts, rows Monday..Sunday, columns hour 0..23 in the requested offset.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WeekdayHourMatrix {
+    pub counts: Vec<Vec<i64>>,
+    pub total: i64,
+    /// Incidents without a parseable timestamp for this matrix.
+    pub unknown_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WeekdayHourHeatmap {
+    pub quarter_label: Option<String>,
+    pub utc_offset_minutes: i32,
+    pub weekdays: Vec<String>,
+    /// Keyed on start_ts.
+    pub starts: WeekdayHourMatrix,
+    /// Keyed on first_observed_ts (detection, as in MTTD).
+    pub detections: WeekdayHourMatrix,
+}
+
+fn slot(ts: Option<&str>, offset: UtcOffset) -> Option<(usize, usize)> {
+    let dt = OffsetDateTime::parse(ts?.trim(), &Rfc3339).ok()?.to_offset(offset);
+    Some((dt.weekday().number_days_from_monday() as usize, dt.hour() as usize))
+}
+
+fn matrix<'a>(timestamps: impl Iterator<Item = Option<&'a str>>, offset: UtcOffset) -> WeekdayHourMatrix {
+    let mut m = WeekdayHourMatrix {
+        counts: vec![vec![0; 24]; 7],
+        total: 0,
+        unknown_count: 0,
+    };
+    for ts in timestamps {
+        match slot(ts, offset) {
+            Some((day, hour)) => {
+                m.counts[day][hour] += 1;
+                m.total += 1;
+            }
+            None => m.unknown_count += 1,
+        }
+    }
+    m
+}
+
+/// Heatmap over an already-scoped incident list.
+pub fn weekday_hour_heatmap(
+    incidents: &[Incident],
+    utc_offset_minutes: i32,
+) -> Result<WeekdayHourHeatmap, AppError> {
+    let in_range = utc_offset_minutes.abs() <= UTC_OFFSET_MAX_MINUTES;
+    let offset = in_range
+        .then(|| UtcOffset::from_whole_seconds(utc_offset_minutes * 60).ok())
+        .flatten()
+        .ok_or_else(|| {
+            AppError::new(
+                "VALIDATION_UTC_OFFSET_INVALID",
+                "UTC offset must be between -14:00 and +14:00",
+            )
+            .with_details(format!("utc_offset_minutes={utc_offset_minutes}"))
+        })?;
+    Ok(WeekdayHourHeatmap {
+        quarter_label: None,
+        utc_offset_minutes,
+        weekdays: HEATMAP_WEEKDAYS.iter().map(|d| d.to_string()).collect(),
+        starts: matrix(incidents.iter().map(|i| i.start_ts.as_deref()), offset),
+        detections: matrix(incidents.iter().map(|i| i.first_observed_ts.as_deref()), offset),
+    })
+}
+
+/// Weekday × hour heatmap for the workspace, optionally limited to the incidents the
+/// assignment rule places in `quarter_label`.
+pub fn build_weekday_hour_heatmap(
+    conn: &Connection,
+    quarter_label: Option<&str>,
+    utc_offset_minutes: i32,
+) -> Result<WeekdayHourHeatmap, AppError> {
+    let mut incidents = list_incidents(conn)?;
+    if let Some(q) = quarter_label {
+        let scope = crate::quarters::resolve_quarter_scope(conn, q)?;
+        let anchor = crate::quarters::load_quarter_assignment_anchor(conn)?;
+        incidents.retain(|i| scope.contains_assigned(anchor, i));
+    }
+    let mut heatmap = weekday_hour_heatmap(&incidents, utc_offset_minutes)?;
+    heatmap.quarter_label = quarter_label.map(str::to_string);
+    Ok(heatmap)
+}
@@ -1,14 +1,62 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
 // Note: analytics uses only deterministic data from qir_core; no UI-side computation.
 
+use crate::domain::Incident;
 use crate::error::AppError;
-use crate::metrics::compute_incident_metrics;
+use crate::metrics::{
+    compute_computed_metrics, compute_incident_metrics, load_computed_metric_definitions,
+    save_computed_metric_definitions,
+};
 use crate::repo::list_incidents;
+use crate::settings::{get_setting_as, set_setting};
 use crate::validate::validate_incident;
 
+mod anomalies;
+mod bucket_export;
+mod business_hours;
+mod by_detection_source;
+mod delta;
+mod drilldown;
+mod funnel;
+mod heatmap;
+mod on_call;
+mod pain;
+mod severity_regrade;
+pub use anomalies::{
+    anomaly_callouts, build_anomaly_summary, AnomalyCallout, AnomalySummary, PriorExposure,
+};
+pub use bucket_export::{export_bucket_csv, BucketCsvExportResult};
+pub use business_hours::{
+    load_business_hours, save_business_hours, BusinessHoursConfig, HoursClass, BUSINESS_HOURS_SETTING,
+};
+pub use by_detection_source::{
+    build_detection_source_comparison, detection_source_comparison, DetectionSourceComparison,
+    DetectionSourceResponse, MetricSample, DETECTION_SOURCE_MIN_SAMPLE,
+};
+pub use delta::{
+    dashboard_delta_v2, diff_dashboard_v2, BucketListPatch, DashboardDeltaV2, DashboardPatchV2,
+};
+pub use drilldown::{bucket_drilldown, BucketDrilldown, DrilldownIncident};
+pub use funnel::{
+    build_incident_lifecycle_funnel, incident_lifecycle_funnel, FunnelStage, IncidentLifecycleFunnel,
+};
+pub use heatmap::{
+    build_weekday_hour_heatmap, weekday_hour_heatmap, WeekdayHourHeatmap, WeekdayHourMatrix,
+    HEATMAP_WEEKDAYS,
+};
+pub use on_call::{build_on_call_load, on_call_load, OnCallLoad, ResponderLoad};
+pub use pain::{
+    load_pain_formula, save_pain_formula, PainFormulaConfig, PainScheme, PAIN_FORMULA_DEFAULT_VERSION,
+    PAIN_FORMULA_SETTING,
+};
+pub use severity_regrade::{
+    build_severity_regrade_analysis, severity_regrade_analysis, IncidentRegrade, RegradeDirection,
+    SeverityRegradeAnalysis, SeverityTransition,
+};
+
 pub const DASHBOARD_PAYLOAD_VERSION: u32 = 1;
 pub const DASHBOARD_PAYLOAD_V2_VERSION: u32 = 2;
 
@@ -82,6 +130,19 @@ pub struct VendorServiceStoryV1 {
 pub struct ResponseStoryV1 {
     pub time_to_mitigation_buckets: Vec<DurationBucket>,
     pub time_to_resolve_buckets: Vec<DurationBucket>,
+    /// Incident starts split into business hours / after hours / weekend (+ UNKNOWN).
+    #[serde(default)]
+    pub start_hours_buckets: Vec<CategoryBucket>,
+}
+
+/// Distribution of one workspace-defined computed metric (see `metrics.computed_definitions`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ComputedMetricDistribution {
+    pub key: String,
+    pub label: String,
+    pub from_field: String,
+    pub to_field: String,
+    pub buckets: Vec<DurationBucket>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -97,6 +158,9 @@ pub struct IncidentSummaryV2 {
     pub time_to_mitigation_seconds: Option<i64>,
     pub mttr_seconds: Option<i64>,
     pub warning_count: i64,
+    /// Business-hours class of start_ts under the workspace business-hours config.
+    #[serde(default)]
+    pub start_hours_class: Option<HoursClass>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -108,6 +172,12 @@ pub struct DashboardPayloadV2 {
     pub detection_story: DetectionStoryV1,
     pub vendor_service_story: VendorServiceStoryV1,
     pub response_story: ResponseStoryV1,
+    /// One entry per computed metric definition, in definition order.
+    #[serde(default)]
+    pub computed_metrics: Vec<ComputedMetricDistribution>,
+    /// `PainFormulaConfig::version` of the formula behind the pain buckets.
+    #[serde(default)]
+    pub pain_formula_version: String,
 }
 
 pub fn build_dashboard_payload_v1(conn: &Connection) -> Result<DashboardPayloadV1, AppError> {
@@ -174,54 +244,252 @@ pub fn build_dashboard_payload_v1(conn: &Connection) -> Result<DashboardPayloadV
     })
 }
 
-fn bucket_label_for_duration(secs: Option<i64>) -> (&'static str, &'static str) {
-    // Deterministic, human-friendly buckets. Always include UNKNOWN to reconcile to incident totals.
-    match secs {
-        None => ("unknown", "UNKNOWN (unparseable/missing)"),
-        Some(s) if s <= 5 * 60 => ("le_5m", "0-5m"),
-        Some(s) if s <= 15 * 60 => ("le_15m", "5-15m"),
-        Some(s) if s <= 60 * 60 => ("le_1h", "15m-1h"),
-        Some(s) if s <= 4 * 60 * 60 => ("le_4h", "1h-4h"),
-        Some(s) if s <= 24 * 60 * 60 => ("le_24h", "4h-24h"),
-        Some(_) => ("gt_24h", ">24h"),
+pub const DASHBOARD_TOP_N_SETTING: &str = "dashboard.top_n";
+pub const DASHBOARD_DURATION_BOUNDS_SETTING: &str = "dashboard.duration_bucket_bounds_seconds";
+pub const DASHBOARD_AFTER_HOURS_PAIN_SETTING: &str = "dashboard.after_hours_pain_multiplier_pct";
+pub const DASHBOARD_PINNED_VENDORS_SETTING: &str = "dashboard.pinned_vendors";
+pub const DASHBOARD_PINNED_SERVICES_SETTING: &str = "dashboard.pinned_services";
+pub const DASHBOARD_OTHER_MIN_COUNT_SETTING: &str = "dashboard.other_min_count";
+pub const DASHBOARD_TOP_N_MAX: u32 = 50;
+pub const DASHBOARD_DURATION_BOUNDS_MAX: usize = 12;
+pub const DASHBOARD_AFTER_HOURS_PAIN_MAX_PCT: u32 = 1000;
+pub const DASHBOARD_PINNED_MAX: usize = 20;
+
+/// Workspace-tunable dashboard shape. Defaults reproduce the original fixed layout
+/// (top 8; 5m/15m/1h/4h/24h duration buckets; no after-hours weighting; no pins).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DashboardConfig {
+    pub top_n: u32,
+    /// Inclusive upper bounds in seconds, strictly increasing. Values above the last bound
+    /// land in a final ">last" bucket; missing/unparseable values land in UNKNOWN.
+    pub duration_bucket_bounds_seconds: Vec<i64>,
+    /// Pain multiplier, in percent, for incidents starting after hours or on a weekend.
+    /// 100 leaves the formula unchanged.
+    #[serde(default = "default_after_hours_pain_multiplier_pct")]
+    pub after_hours_pain_multiplier_pct: u32,
+    /// Vendors (exact names) always listed as their own bucket, never rolled into OTHER.
+    #[serde(default)]
+    pub pinned_vendors: Vec<String>,
+    /// Services (exact names) always listed as their own bucket, never rolled into OTHER.
+    #[serde(default)]
+    pub pinned_services: Vec<String>,
+    /// Unpinned vendors/services with fewer incidents than this roll into OTHER even when
+    /// they rank in the top N. 1 keeps every top-N entry.
+    #[serde(default = "default_other_min_count")]
+    pub other_min_count: u32,
+}
+
+fn default_after_hours_pain_multiplier_pct() -> u32 {
+    100
+}
+
+fn default_other_min_count() -> u32 {
+    1
+}
+
+impl Default for DashboardConfig {
+    fn default() -> Self {
+        Self {
+            top_n: 8,
+            duration_bucket_bounds_seconds: vec![5 * 60, 15 * 60, 60 * 60, 4 * 60 * 60, 24 * 60 * 60],
+            after_hours_pain_multiplier_pct: default_after_hours_pain_multiplier_pct(),
+            pinned_vendors: Vec::new(),
+            pinned_services: Vec::new(),
+            other_min_count: default_other_min_count(),
+        }
     }
 }
 
-fn duration_bucket_order_key(key: &str) -> i32 {
-    match key {
-        "le_5m" => 1,
-        "le_15m" => 2,
-        "le_1h" => 3,
-        "le_4h" => 4,
-        "le_24h" => 5,
-        "gt_24h" => 6,
-        "unknown" => 7,
-        _ => 999,
+impl DashboardConfig {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.top_n == 0 || self.top_n > DASHBOARD_TOP_N_MAX {
+            return Err(AppError::new(
+                "VALIDATION_DASHBOARD_CONFIG_INVALID",
+                format!("Dashboard top-N must be between 1 and {DASHBOARD_TOP_N_MAX}"),
+            )
+            .with_details(format!("top_n={}", self.top_n)));
+        }
+        let bounds = &self.duration_bucket_bounds_seconds;
+        let increasing = bounds.windows(2).all(|w| w[0] < w[1]);
+        if bounds.is_empty()
+            || bounds.len() > DASHBOARD_DURATION_BOUNDS_MAX
+            || bounds[0] <= 0
+            || !increasing
+        {
+            return Err(AppError::new(
+                "VALIDATION_DASHBOARD_CONFIG_INVALID",
+                format!(
+                    "Duration bucket bounds must be 1-{DASHBOARD_DURATION_BOUNDS_MAX} positive, strictly increasing values"
+                ),
+            )
+            .with_details(format!("duration_bucket_bounds_seconds={bounds:?}")));
+        }
+        if !(100..=DASHBOARD_AFTER_HOURS_PAIN_MAX_PCT).contains(&self.after_hours_pain_multiplier_pct) {
+            return Err(AppError::new(
+                "VALIDATION_DASHBOARD_CONFIG_INVALID",
+                format!("After-hours pain multiplier must be between 100% and {DASHBOARD_AFTER_HOURS_PAIN_MAX_PCT}%"),
+            )
+            .with_details(format!(
+                "after_hours_pain_multiplier_pct={}",
+                self.after_hours_pain_multiplier_pct
+            )));
+        }
+        if self.other_min_count == 0 {
+            return Err(AppError::new(
+                "VALIDATION_DASHBOARD_CONFIG_INVALID",
+                "OTHER threshold must be at least 1 incident",
+            )
+            .with_details("other_min_count=0"));
+        }
+        for (field, pinned) in [("pinned_vendors", &self.pinned_vendors), ("pinned_services", &self.pinned_services)] {
+            let mut seen = BTreeSet::new();
+            let bad = pinned.iter().find(|p| p.trim().is_empty() || p.trim() != p.as_str() || !seen.insert(p.as_str()));
+            if pinned.len() > DASHBOARD_PINNED_MAX || bad.is_some() {
+                return Err(AppError::new(
+                    "VALIDATION_DASHBOARD_CONFIG_INVALID",
+                    format!("Pinned names must be up to {DASHBOARD_PINNED_MAX} distinct, non-empty, trimmed values"),
+                )
+                .with_details(format!("{field}={pinned:?}")));
+            }
+        }
+        Ok(())
     }
 }
 
-fn category_key(raw: &Option<String>) -> String {
-    raw.clone().unwrap_or_else(|| "UNKNOWN".to_string())
+/// Reads `dashboard.*` workspace settings over the defaults. Invalid stored values are an
+/// error rather than a silent fallback.
+pub fn load_dashboard_config(conn: &Connection) -> Result<DashboardConfig, AppError> {
+    let mut cfg = DashboardConfig::default();
+    if let Some(n) = get_setting_as::<u32>(conn, DASHBOARD_TOP_N_SETTING)? {
+        cfg.top_n = n;
+    }
+    if let Some(b) = get_setting_as::<Vec<i64>>(conn, DASHBOARD_DURATION_BOUNDS_SETTING)? {
+        cfg.duration_bucket_bounds_seconds = b;
+    }
+    if let Some(pct) = get_setting_as::<u32>(conn, DASHBOARD_AFTER_HOURS_PAIN_SETTING)? {
+        cfg.after_hours_pain_multiplier_pct = pct;
+    }
+    if let Some(v) = get_setting_as::<Vec<String>>(conn, DASHBOARD_PINNED_VENDORS_SETTING)? {
+        cfg.pinned_vendors = v;
+    }
+    if let Some(v) = get_setting_as::<Vec<String>>(conn, DASHBOARD_PINNED_SERVICES_SETTING)? {
+        cfg.pinned_services = v;
+    }
+    if let Some(n) = get_setting_as::<u32>(conn, DASHBOARD_OTHER_MIN_COUNT_SETTING)? {
+        cfg.other_min_count = n;
+    }
+    cfg.validate()?;
+    Ok(cfg)
+}
+
+pub fn save_dashboard_config(conn: &Connection, cfg: &DashboardConfig) -> Result<(), AppError> {
+    cfg.validate()?;
+    set_setting(conn, DASHBOARD_TOP_N_SETTING, &serde_json::json!(cfg.top_n))?;
+    set_setting(
+        conn,
+        DASHBOARD_DURATION_BOUNDS_SETTING,
+        &serde_json::json!(cfg.duration_bucket_bounds_seconds),
+    )?;
+    set_setting(
+        conn,
+        DASHBOARD_AFTER_HOURS_PAIN_SETTING,
+        &serde_json::json!(cfg.after_hours_pain_multiplier_pct),
+    )?;
+    set_setting(conn, DASHBOARD_PINNED_VENDORS_SETTING, &serde_json::json!(cfg.pinned_vendors))?;
+    set_setting(conn, DASHBOARD_PINNED_SERVICES_SETTING, &serde_json::json!(cfg.pinned_services))?;
+    set_setting(conn, DASHBOARD_OTHER_MIN_COUNT_SETTING, &serde_json::json!(cfg.other_min_count))
 }
 
-fn compute_pain_units(
-    impact_pct: Option<i64>,
-    service_health_pct: Option<i64>,
-    duration_secs: Option<i64>,
-) -> Option<i64> {
-    let impact = impact_pct?;
-    let health = service_health_pct?;
-    let dur = duration_secs?;
-    if !(0..=100).contains(&impact) || !(0..=100).contains(&health) || dur < 0 {
-        return None;
+fn format_bound(secs: i64) -> String {
+    if secs % 3600 == 0 {
+        format!("{}h", secs / 3600)
+    } else if secs % 60 == 0 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{secs}s")
     }
-    let degradation = 100 - health;
+}
 
-    let p: i128 = (impact as i128) * (degradation as i128) * (dur as i128);
-    if p <= 0 {
-        return Some(0);
+/// Returns (order, key, label) for a duration. With the default bounds this yields
+/// le_5m/"0-5m", le_15m/"5-15m", ... gt_24h/">24h".
+fn bucket_for_duration(bounds: &[i64], secs: Option<i64>) -> (usize, String, String) {
+    // Deterministic, human-friendly buckets. Always include UNKNOWN to reconcile to incident totals.
+    let Some(s) = secs else {
+        return (
+            bounds.len() + 1,
+            "unknown".to_string(),
+            "UNKNOWN (unparseable/missing)".to_string(),
+        );
+    };
+    for (idx, b) in bounds.iter().enumerate() {
+        if s <= *b {
+            let lower = if idx == 0 {
+                "0".to_string()
+            } else {
+                format_bound(bounds[idx - 1])
+            };
+            return (
+                idx,
+                format!("le_{}", format_bound(*b)),
+                format!("{lower}-{}", format_bound(*b)),
+            );
+        }
     }
-    Some(std::cmp::min(p, i64::MAX as i128) as i64)
+    let last = format_bound(*bounds.last().unwrap_or(&0));
+    (bounds.len(), format!("gt_{last}"), format!(">{last}"))
+}
+
+/// Bucket key -> (sort order, label, incident ids).
+type DurationBucketMap = BTreeMap<String, (usize, String, Vec<i64>)>;
+
+fn duration_buckets(
+    prefix: &str,
+    map: DurationBucketMap,
+) -> Vec<DurationBucket> {
+    let mut items = map.into_iter().collect::<Vec<_>>();
+    items.sort_by_key(|(_, (order, _, _))| *order);
+    items
+        .into_iter()
+        .map(|(k, (_, label, mut ids))| {
+            ids.sort();
+            DurationBucket {
+                key: format!("{prefix}:{k}"),
+                label,
+                count: ids.len() as i64,
+                incident_ids: ids,
+            }
+        })
+        .collect()
+}
+
+/// Fixed business / after-hours / weekend order so the buckets line up across quarters;
+/// UNKNOWN (missing or unparseable start_ts) only when non-empty.
+fn start_hours_buckets(mut map: BTreeMap<Option<HoursClass>, Vec<i64>>) -> Vec<CategoryBucket> {
+    let mut out = Vec::new();
+    for class in [HoursClass::BusinessHours, HoursClass::AfterHours, HoursClass::Weekend] {
+        let mut ids = map.remove(&Some(class)).unwrap_or_default();
+        ids.sort();
+        out.push(CategoryBucket {
+            key: format!("start_hours:{}", class.as_str()),
+            label: class.as_str().to_string(),
+            count: ids.len() as i64,
+            incident_ids: ids,
+        });
+    }
+    if let Some(mut ids) = map.remove(&None) {
+        ids.sort();
+        out.push(CategoryBucket {
+            key: "start_hours:unknown".to_string(),
+            label: "UNKNOWN".to_string(),
+            count: ids.len() as i64,
+            incident_ids: ids,
+        });
+    }
+    out
+}
+
+fn category_key(raw: &Option<String>) -> String {
+    raw.clone().unwrap_or_else(|| "UNKNOWN".to_string())
 }
 
 fn stable_top_n_keys_by_count(map: &BTreeMap<String, Vec<i64>>, n: usize) -> Vec<String> {
@@ -242,13 +510,61 @@ fn stable_top_n_keys_by_pain(map: &BTreeMap<String, PainBucket>, n: usize) -> Ve
     items.into_iter().take(n).map(|(k, _, _)| k).collect()
 }
 
+/// Keys that get their own bucket: the top N at or above the OTHER threshold, plus every pinned
+/// key present in the data whatever its rank or count.
+fn named_bucket_keys(
+    top: Vec<String>,
+    pinned: &[String],
+    other_min_count: u32,
+    count_of: impl Fn(&str) -> Option<usize>,
+) -> Vec<String> {
+    let mut keys: Vec<String> = top
+        .into_iter()
+        .filter(|k| count_of(k).is_some_and(|c| c >= other_min_count as usize))
+        .collect();
+    for p in pinned {
+        if count_of(p).is_some() && !keys.contains(p) {
+            keys.push(p.clone());
+        }
+    }
+    keys
+}
+
+/// In-memory workspace holding only `incidents`, with the dashboard, business-hours and
+/// display-timezone settings and the entity aliases carried over so scoped payloads match the
+/// workspace layout.
+pub(crate) fn scoped_snapshot(conn: &Connection, incidents: &[Incident]) -> Result<Connection, AppError> {
+    let snapshot = crate::history::materialize_incidents(incidents)?;
+    save_dashboard_config(&snapshot, &load_dashboard_config(conn)?)?;
+    save_business_hours(&snapshot, &load_business_hours(conn)?)?;
+    save_pain_formula(&snapshot, &load_pain_formula(conn)?)?;
+    save_computed_metric_definitions(&snapshot, &load_computed_metric_definitions(conn)?)?;
+    crate::aliases::copy_entity_aliases(conn, &snapshot)?;
+    crate::report::save_display_timezone(&snapshot, crate::report::load_display_timezone(conn)?.as_ref())?;
+    Ok(snapshot)
+}
+
 pub fn build_dashboard_payload_v2(conn: &Connection) -> Result<DashboardPayloadV2, AppError> {
-    let incidents = list_incidents(conn)?;
+    let cfg = load_dashboard_config(conn)?;
+    build_dashboard_payload_v2_with_config(conn, &cfg)
+}
+
+pub fn build_dashboard_payload_v2_with_config(
+    conn: &Connection,
+    cfg: &DashboardConfig,
+) -> Result<DashboardPayloadV2, AppError> {
+    cfg.validate()?;
+    let bounds = cfg.duration_bucket_bounds_seconds.as_slice();
+    let hours = load_business_hours(conn)?;
+    let formula = load_pain_formula(conn)?;
+    let computed_defs = load_computed_metric_definitions(conn)?;
+    let mut incidents = list_incidents(conn)?;
+    crate::aliases::AliasResolver::load(conn)?.apply(&mut incidents);
     let incident_count = incidents.len() as i64;
 
     let mut severity_map: BTreeMap<String, Vec<i64>> = BTreeMap::new();
     let mut detection_source_map: BTreeMap<String, Vec<i64>> = BTreeMap::new();
-    let mut awareness_lag_map: BTreeMap<String, (String, Vec<i64>)> = BTreeMap::new();
+    let mut awareness_lag_map: DurationBucketMap = BTreeMap::new();
 
     let mut vendor_map: BTreeMap<String, Vec<i64>> = BTreeMap::new();
     let mut service_map: BTreeMap<String, Vec<i64>> = BTreeMap::new();
@@ -256,8 +572,10 @@ pub fn build_dashboard_payload_v2(conn: &Connection) -> Result<DashboardPayloadV
     let mut vendor_pain_map: BTreeMap<String, PainBucket> = BTreeMap::new();
     let mut service_pain_map: BTreeMap<String, PainBucket> = BTreeMap::new();
 
-    let mut ttm_map: BTreeMap<String, (String, Vec<i64>)> = BTreeMap::new();
-    let mut ttr_map: BTreeMap<String, (String, Vec<i64>)> = BTreeMap::new();
+    let mut ttm_map: DurationBucketMap = BTreeMap::new();
+    let mut ttr_map: DurationBucketMap = BTreeMap::new();
+    let mut hours_map: BTreeMap<Option<HoursClass>, Vec<i64>> = BTreeMap::new();
+    let mut computed_maps: Vec<DurationBucketMap> = vec![BTreeMap::new(); computed_defs.len()];
 
     let mut incident_summaries = Vec::new();
 
@@ -289,28 +607,39 @@ pub fn build_dashboard_payload_v2(conn: &Connection) -> Result<DashboardPayloadV
         let val_warnings = validate_incident(inc);
         let warning_count = (metric_warnings.len() + val_warnings.len()) as i64;
 
-        let (lag_key, lag_label) = bucket_label_for_duration(metrics.it_awareness_lag_seconds);
+        let (lag_order, lag_key, lag_label) = bucket_for_duration(bounds, metrics.it_awareness_lag_seconds);
         awareness_lag_map
-            .entry(lag_key.to_string())
-            .or_insert_with(|| (lag_label.to_string(), Vec::new()))
-            .1
+            .entry(lag_key)
+            .or_insert_with(|| (lag_order, lag_label, Vec::new()))
+            .2
             .push(inc.id);
 
-        let (ttm_key, ttm_label) = bucket_label_for_duration(metrics.time_to_mitigation_seconds);
+        let (ttm_order, ttm_key, ttm_label) = bucket_for_duration(bounds, metrics.time_to_mitigation_seconds);
         ttm_map
-            .entry(ttm_key.to_string())
-            .or_insert_with(|| (ttm_label.to_string(), Vec::new()))
-            .1
+            .entry(ttm_key)
+            .or_insert_with(|| (ttm_order, ttm_label, Vec::new()))
+            .2
             .push(inc.id);
 
-        let (ttr_key, ttr_label) = bucket_label_for_duration(metrics.mttr_seconds);
+        let (ttr_order, ttr_key, ttr_label) = bucket_for_duration(bounds, metrics.mttr_seconds);
         ttr_map
-            .entry(ttr_key.to_string())
-            .or_insert_with(|| (ttr_label.to_string(), Vec::new()))
-            .1
+            .entry(ttr_key)
+            .or_insert_with(|| (ttr_order, ttr_label, Vec::new()))
+            .2
             .push(inc.id);
 
-        let pain = compute_pain_units(inc.impact_pct, inc.service_health_pct, metrics.mttr_seconds);
+        for (map, secs) in computed_maps.iter_mut().zip(compute_computed_metrics(inc, &computed_defs)) {
+            let (order, key, label) = bucket_for_duration(bounds, secs);
+            map.entry(key)
+                .or_insert_with(|| (order, label, Vec::new()))
+                .2
+                .push(inc.id);
+        }
+
+        let start_hours_class = inc.start_ts.as_deref().and_then(|ts| hours.classify(ts));
+        hours_map.entry(start_hours_class).or_default().push(inc.id);
+
+        let pain = formula.incident_pain_units(inc, metrics.mttr_seconds, &hours, cfg.after_hours_pain_multiplier_pct);
         {
             let entry = vendor_pain_map
                 .entry(vendor_key.clone())
@@ -360,6 +689,7 @@ pub fn build_dashboard_payload_v2(conn: &Connection) -> Result<DashboardPayloadV
             time_to_mitigation_seconds: metrics.time_to_mitigation_seconds,
             mttr_seconds: metrics.mttr_seconds,
             warning_count,
+            start_hours_class,
         });
     }
 
@@ -401,64 +731,38 @@ pub fn build_dashboard_payload_v2(conn: &Connection) -> Result<DashboardPayloadV
     detection_source_mix
         .sort_by(|a, b| (-(a.count), a.label.clone()).cmp(&(-(b.count), b.label.clone())));
 
-    let mut it_awareness_lag_buckets = awareness_lag_map
-        .into_iter()
-        .map(|(k, (label, mut ids))| {
-            ids.sort();
-            DurationBucket {
-                key: format!("it_awareness_lag:{k}"),
-                label,
-                count: ids.len() as i64,
-                incident_ids: ids,
-            }
-        })
-        .collect::<Vec<_>>();
-    it_awareness_lag_buckets.sort_by(|a, b| {
-        duration_bucket_order_key(a.key.split(':').nth(1).unwrap_or("")).cmp(
-            &duration_bucket_order_key(b.key.split(':').nth(1).unwrap_or("")),
-        )
-    });
-
-    let mut time_to_mitigation_buckets = ttm_map
+    let it_awareness_lag_buckets = duration_buckets("it_awareness_lag", awareness_lag_map);
+    let time_to_mitigation_buckets = duration_buckets("time_to_mitigation", ttm_map);
+    let time_to_resolve_buckets = duration_buckets("time_to_resolve", ttr_map);
+    let start_hours_buckets = start_hours_buckets(hours_map);
+    let computed_metrics = computed_defs
         .into_iter()
-        .map(|(k, (label, mut ids))| {
-            ids.sort();
-            DurationBucket {
-                key: format!("time_to_mitigation:{k}"),
-                label,
-                count: ids.len() as i64,
-                incident_ids: ids,
-            }
+        .zip(computed_maps)
+        .map(|(d, map)| ComputedMetricDistribution {
+            buckets: duration_buckets(&format!("computed:{}", d.key), map),
+            key: d.key,
+            label: d.label,
+            from_field: d.from_field,
+            to_field: d.to_field,
         })
-        .collect::<Vec<_>>();
-    time_to_mitigation_buckets.sort_by(|a, b| {
-        duration_bucket_order_key(a.key.split(':').nth(1).unwrap_or("")).cmp(
-            &duration_bucket_order_key(b.key.split(':').nth(1).unwrap_or("")),
-        )
-    });
-
-    let mut time_to_resolve_buckets = ttr_map
-        .into_iter()
-        .map(|(k, (label, mut ids))| {
-            ids.sort();
-            DurationBucket {
-                key: format!("time_to_resolve:{k}"),
-                label,
-                count: ids.len() as i64,
-                incident_ids: ids,
-            }
-        })
-        .collect::<Vec<_>>();
-    time_to_resolve_buckets.sort_by(|a, b| {
-        duration_bucket_order_key(a.key.split(':').nth(1).unwrap_or("")).cmp(
-            &duration_bucket_order_key(b.key.split(':').nth(1).unwrap_or("")),
-        )
-    });
-
-    // Build top-N vendor/service by count with OTHER bucket for reconciliation.
-    let top_n = 8usize;
-    let top_vendor_keys = stable_top_n_keys_by_count(&vendor_map, top_n);
-    let top_service_keys = stable_top_n_keys_by_count(&service_map, top_n);
+        .collect();
+
+    // Build top-N vendor/service by count with OTHER bucket for reconciliation. Pinned names
+    // always keep their own bucket; small unpinned ones fall into OTHER.
+    let top_n = cfg.top_n as usize;
+    let min = cfg.other_min_count;
+    let top_vendor_keys = named_bucket_keys(
+        stable_top_n_keys_by_count(&vendor_map, top_n),
+        &cfg.pinned_vendors,
+        min,
+        |k| vendor_map.get(k).map(Vec::len),
+    );
+    let top_service_keys = named_bucket_keys(
+        stable_top_n_keys_by_count(&service_map, top_n),
+        &cfg.pinned_services,
+        min,
+        |k| service_map.get(k).map(Vec::len),
+    );
 
     let top_vendors_by_count = {
         let mut out = Vec::new();
@@ -520,8 +824,18 @@ pub fn build_dashboard_payload_v2(conn: &Connection) -> Result<DashboardPayloadV
         out
     };
 
-    let top_vendor_pain_keys = stable_top_n_keys_by_pain(&vendor_pain_map, top_n);
-    let top_service_pain_keys = stable_top_n_keys_by_pain(&service_pain_map, top_n);
+    let top_vendor_pain_keys = named_bucket_keys(
+        stable_top_n_keys_by_pain(&vendor_pain_map, top_n),
+        &cfg.pinned_vendors,
+        min,
+        |k| vendor_pain_map.get(k).map(|b| b.count as usize),
+    );
+    let top_service_pain_keys = named_bucket_keys(
+        stable_top_n_keys_by_pain(&service_pain_map, top_n),
+        &cfg.pinned_services,
+        min,
+        |k| service_pain_map.get(k).map(|b| b.count as usize),
+    );
 
     let top_vendors_by_pain = {
         let mut out = Vec::new();
@@ -617,6 +931,9 @@ pub fn build_dashboard_payload_v2(conn: &Connection) -> Result<DashboardPayloadV
         response_story: ResponseStoryV1 {
             time_to_mitigation_buckets,
             time_to_resolve_buckets,
+            start_hours_buckets,
         },
+        computed_metrics,
+        pain_formula_version: formula.version(),
     })
 }
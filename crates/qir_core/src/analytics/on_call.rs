@@ -0,0 +1,135 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use super::business_hours::{load_business_hours, BusinessHoursConfig, HoursClass};
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::repo::list_incidents;
+use crate::responders::{list_responders, IncidentResponder};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResponderLoad {
+    pub person: String,
+    /// Distinct incidents the person engaged on.
+    pub incident_count: i64,
+    pub engagement_count: i64,
+    /// Sum over engagements of (released_at, else incident resolve_ts) - engaged_at.
+    pub time_in_incident_seconds: i64,
+    /// Engagements with no release and an unresolved incident; excluded from time.
+    pub open_engagement_count: i64,
+    pub business_hours_engagements: i64,
+    pub after_hours_engagements: i64,
+    pub weekend_engagements: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OnCallLoad {
+    pub quarter_label: Option<String>,
+    pub anonymized: bool,
+    pub business_hours: BusinessHoursConfig,
+    /// Heaviest load first: incident count, then time in incident.
+    pub responders: Vec<ResponderLoad>,
+}
+
+fn parse(ts: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(ts.trim(), &Rfc3339).ok()
+}
+
+/// Per-person load over an already-scoped incident list. Engagements on incidents outside
+/// `incidents` are ignored. Anonymized output replaces names with `Responder N` by rank.
+pub fn on_call_load(
+    incidents: &[Incident],
+    responders: &[IncidentResponder],
+    hours: &BusinessHoursConfig,
+    anonymize: bool,
+) -> OnCallLoad {
+    let by_id: HashMap<i64, &Incident> = incidents.iter().map(|i| (i.id, i)).collect();
+    let mut per_person: BTreeMap<&str, (BTreeSet<i64>, ResponderLoad)> = BTreeMap::new();
+    for r in responders {
+        let Some(incident) = by_id.get(&r.incident_id) else {
+            continue;
+        };
+        let (seen, load) = per_person.entry(r.person.as_str()).or_insert_with(|| {
+            (
+                BTreeSet::new(),
+                ResponderLoad {
+                    person: r.person.clone(),
+                    incident_count: 0,
+                    engagement_count: 0,
+                    time_in_incident_seconds: 0,
+                    open_engagement_count: 0,
+                    business_hours_engagements: 0,
+                    after_hours_engagements: 0,
+                    weekend_engagements: 0,
+                },
+            )
+        });
+        seen.insert(r.incident_id);
+        load.engagement_count += 1;
+
+        let end = r.released_at.as_deref().or(incident.resolve_ts.as_deref());
+        match (parse(&r.engaged_at), end.and_then(parse)) {
+            (Some(start), Some(end)) if end >= start => {
+                load.time_in_incident_seconds += (end - start).whole_seconds();
+            }
+            (_, None) => load.open_engagement_count += 1,
+            _ => {}
+        }
+        match hours.classify(&r.engaged_at) {
+            Some(HoursClass::BusinessHours) => load.business_hours_engagements += 1,
+            Some(HoursClass::AfterHours) => load.after_hours_engagements += 1,
+            Some(HoursClass::Weekend) => load.weekend_engagements += 1,
+            None => {}
+        }
+    }
+
+    let mut out: Vec<ResponderLoad> = per_person
+        .into_values()
+        .map(|(seen, mut load)| {
+            load.incident_count = seen.len() as i64;
+            load
+        })
+        .collect();
+    out.sort_by(|a, b| {
+        b.incident_count
+            .cmp(&a.incident_count)
+            .then(b.time_in_incident_seconds.cmp(&a.time_in_incident_seconds))
+            .then(a.person.cmp(&b.person))
+    });
+    if anonymize {
+        for (idx, load) in out.iter_mut().enumerate() {
+            load.person = format!("Responder {}", idx + 1);
+        }
+    }
+
+    OnCallLoad {
+        quarter_label: None,
+        anonymized: anonymize,
+        business_hours: hours.clone(),
+        responders: out,
+    }
+}
+
+/// On-call load for the workspace, optionally limited to the incidents the assignment
+/// rule places in `quarter_label`.
+pub fn build_on_call_load(
+    conn: &Connection,
+    quarter_label: Option<&str>,
+    anonymize: bool,
+) -> Result<OnCallLoad, AppError> {
+    let mut incidents = list_incidents(conn)?;
+    if let Some(q) = quarter_label {
+        let scope = crate::quarters::resolve_quarter_scope(conn, q)?;
+        let anchor = crate::quarters::load_quarter_assignment_anchor(conn)?;
+        incidents.retain(|i| scope.contains_assigned(anchor, i));
+    }
+    let hours = load_business_hours(conn)?;
+    let responders = list_responders(conn, None)?;
+    let mut load = on_call_load(&incidents, &responders, &hours, anonymize);
+    load.quarter_label = quarter_label.map(str::to_string);
+    Ok(load)
+}
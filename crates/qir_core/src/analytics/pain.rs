@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::business_hours::{BusinessHoursConfig, HoursClass};
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::settings::{get_setting_as, set_setting};
+
+pub const PAIN_FORMULA_SETTING: &str = "analytics.pain_formula";
+/// Version of the built-in formula. Customized formulas are versioned `v2-<config hash>`.
+pub const PAIN_FORMULA_DEFAULT_VERSION: &str = "v1";
+pub const PAIN_WEIGHT_MAX_PCT: u32 = 1000;
+pub const PAIN_SEVERITY_MULTIPLIER_MAX_PCT: u32 = 1000;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PainScheme {
+    /// impact × degradation × duration.
+    #[default]
+    Product,
+    /// Weighted mean of impact and degradation, scaled to the product's range (x100) and
+    /// multiplied by duration. A full outage of either kind counts even when the other is 0.
+    WeightedSum,
+}
+
+impl PainScheme {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PainScheme::Product => "impact × degradation × duration",
+            PainScheme::WeightedSum => "weighted impact + degradation × duration",
+        }
+    }
+}
+
+/// Workspace pain function. The default reproduces the original formula exactly; the
+/// after-hours multiplier stays in `DashboardConfig` and applies on top of this.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PainFormulaConfig {
+    #[serde(default)]
+    pub scheme: PainScheme,
+    /// Relative weights of impact and degradation; only the weighted-sum scheme uses them.
+    #[serde(default = "default_weight_pct")]
+    pub impact_weight_pct: u32,
+    #[serde(default = "default_weight_pct")]
+    pub degradation_weight_pct: u32,
+    /// Multiplier, in percent, by exact severity label. Unlisted severities use 100.
+    #[serde(default)]
+    pub severity_multipliers_pct: BTreeMap<String, u32>,
+    /// Upper bound on one incident's pain units, applied after all multipliers.
+    #[serde(default)]
+    pub cap_per_incident: Option<i64>,
+    /// Count only the part of start -> resolve inside business hours as duration.
+    #[serde(default)]
+    pub business_hours_only_duration: bool,
+}
+
+fn default_weight_pct() -> u32 {
+    100
+}
+
+impl Default for PainFormulaConfig {
+    fn default() -> Self {
+        Self {
+            scheme: PainScheme::default(),
+            impact_weight_pct: default_weight_pct(),
+            degradation_weight_pct: default_weight_pct(),
+            severity_multipliers_pct: BTreeMap::new(),
+            cap_per_incident: None,
+            business_hours_only_duration: false,
+        }
+    }
+}
+
+fn format_pct(pct: u32) -> String {
+    format!("×{}.{:02}", pct / 100, pct % 100)
+}
+
+impl PainFormulaConfig {
+    pub fn validate(&self) -> Result<(), AppError> {
+        let invalid = |msg: String, details: String| {
+            Err(AppError::new("VALIDATION_PAIN_FORMULA_INVALID", msg).with_details(details))
+        };
+        let weights = [self.impact_weight_pct, self.degradation_weight_pct];
+        if weights.iter().any(|w| *w > PAIN_WEIGHT_MAX_PCT) || weights.iter().sum::<u32>() == 0 {
+            return invalid(
+                format!("Pain weights must be 0-{PAIN_WEIGHT_MAX_PCT}% and not both zero"),
+                format!(
+                    "impact_weight_pct={}; degradation_weight_pct={}",
+                    self.impact_weight_pct, self.degradation_weight_pct
+                ),
+            );
+        }
+        for (severity, pct) in &self.severity_multipliers_pct {
+            if severity.trim().is_empty() || severity.trim() != severity || *pct > PAIN_SEVERITY_MULTIPLIER_MAX_PCT {
+                return invalid(
+                    format!(
+                        "Severity multipliers need a trimmed, non-empty severity and a value of 0-{PAIN_SEVERITY_MULTIPLIER_MAX_PCT}%"
+                    ),
+                    format!("severity={severity:?}; pct={pct}"),
+                );
+            }
+        }
+        if let Some(cap) = self.cap_per_incident.filter(|c| *c <= 0) {
+            return invalid("Pain cap per incident must be positive".to_string(), format!("cap_per_incident={cap}"));
+        }
+        Ok(())
+    }
+
+    /// `v1` for the built-in formula, otherwise `v2-` plus the first 8 hex digits of the
+    /// config's SHA-256, so two reports with the same version used the same formula.
+    pub fn version(&self) -> String {
+        if *self == Self::default() {
+            return PAIN_FORMULA_DEFAULT_VERSION.to_string();
+        }
+        let json = serde_json::to_string(self).unwrap_or_default();
+        format!("v2-{}", &hex::encode(Sha256::digest(json.as_bytes()))[..8])
+    }
+
+    /// One-line human description for reports.
+    pub fn describe(&self) -> String {
+        let mut parts = vec![match self.scheme {
+            PainScheme::Product => self.scheme.label().to_string(),
+            PainScheme::WeightedSum => format!(
+                "(impact × {}% + degradation × {}%) × duration",
+                self.impact_weight_pct, self.degradation_weight_pct
+            ),
+        }];
+        if self.business_hours_only_duration {
+            parts.push("business-hours duration only".to_string());
+        }
+        if !self.severity_multipliers_pct.is_empty() {
+            let sev: Vec<String> =
+                self.severity_multipliers_pct.iter().map(|(s, p)| format!("{s} {}", format_pct(*p))).collect();
+            parts.push(format!("severity weights {}", sev.join(", ")));
+        }
+        if let Some(cap) = self.cap_per_incident {
+            parts.push(format!("capped at {cap} per incident"));
+        }
+        parts.join("; ")
+    }
+
+    /// Pain units of one incident; None when impact, service health or duration is missing or
+    /// out of range. `mttr_seconds` is the incident's computed MTTR; incidents starting after
+    /// hours or on a weekend are scaled by `after_hours_multiplier_pct`.
+    pub fn incident_pain_units(
+        &self,
+        incident: &Incident,
+        mttr_seconds: Option<i64>,
+        hours: &BusinessHoursConfig,
+        after_hours_multiplier_pct: u32,
+    ) -> Option<i64> {
+        let impact = incident.impact_pct?;
+        let health = incident.service_health_pct?;
+        if !(0..=100).contains(&impact) || !(0..=100).contains(&health) {
+            return None;
+        }
+        let degradation = 100 - health;
+        let mut duration = mttr_seconds?;
+        if self.business_hours_only_duration {
+            // Same span as MTTR: start (or first observed) -> resolve.
+            let start = incident.start_ts.as_deref().or(incident.first_observed_ts.as_deref())?;
+            duration = hours.business_seconds_between(start, incident.resolve_ts.as_deref()?)?;
+        }
+        if duration < 0 {
+            return None;
+        }
+        let start_class = incident.start_ts.as_deref().and_then(|ts| hours.classify(ts));
+        let after_hours_pct = match start_class {
+            Some(HoursClass::AfterHours | HoursClass::Weekend) => after_hours_multiplier_pct,
+            _ => 100,
+        };
+        let severity_pct = incident
+            .severity
+            .as_deref()
+            .and_then(|s| self.severity_multipliers_pct.get(s))
+            .copied()
+            .unwrap_or(100);
+
+        // Rate per second in 0..=10_000 units as numerator / denominator, kept exact until the
+        // single final division so the default matches the original integer formula.
+        let (rate, denom): (i128, i128) = match self.scheme {
+            PainScheme::Product => ((impact as i128) * (degradation as i128), 1),
+            PainScheme::WeightedSum => {
+                let (iw, dw) = (self.impact_weight_pct as i128, self.degradation_weight_pct as i128);
+                ((iw * impact as i128 + dw * degradation as i128) * 100, iw + dw)
+            }
+        };
+        let mut p = rate * (duration as i128) * (after_hours_pct as i128) * (severity_pct as i128) / (denom * 10_000);
+        if let Some(cap) = self.cap_per_incident {
+            p = p.min(cap as i128);
+        }
+        if p <= 0 {
+            return Some(0);
+        }
+        Some(std::cmp::min(p, i64::MAX as i128) as i64)
+    }
+}
+
+pub fn load_pain_formula(conn: &Connection) -> Result<PainFormulaConfig, AppError> {
+    let cfg = get_setting_as::<PainFormulaConfig>(conn, PAIN_FORMULA_SETTING)?.unwrap_or_default();
+    cfg.validate()?;
+    Ok(cfg)
+}
+
+pub fn save_pain_formula(conn: &Connection, cfg: &PainFormulaConfig) -> Result<(), AppError> {
+    cfg.validate()?;
+    let value = serde_json::to_value(cfg).map_err(|e| {
+        AppError::new("DB_ENCODE_FAILED", "Failed to encode pain formula").with_details(e.to_string())
+    })?;
+    set_setting(conn, PAIN_FORMULA_SETTING, &value)
+}
@@ -0,0 +1,150 @@
+use std::collections::{BTreeMap, HashMap};
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::repo::list_incidents;
+use crate::severity::{initial_severities, severity_rank, SeverityGrade, SeverityGradeSource};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum RegradeDirection {
+    /// Final severity is more severe than the initial one (e.g. SEV3 -> SEV1).
+    Upgraded,
+    Downgraded,
+    Unchanged,
+    /// Initial or final severity missing, or the labels differ but carry no comparable level.
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentRegrade {
+    pub incident_id: i64,
+    pub external_id: Option<String>,
+    pub initial_severity: Option<String>,
+    pub initial_source: Option<SeverityGradeSource>,
+    pub final_severity: Option<String>,
+    pub direction: RegradeDirection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SeverityTransition {
+    pub from: String,
+    pub to: String,
+    pub count: i64,
+    pub incident_ids: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SeverityRegradeAnalysis {
+    pub quarter_label: Option<String>,
+    pub incident_count: i64,
+    pub upgraded_count: i64,
+    pub downgraded_count: i64,
+    pub unchanged_count: i64,
+    pub unknown_count: i64,
+    /// Upgraded + downgraded as a rounded percentage of incidents with a known direction.
+    pub regrade_rate_pct: Option<i64>,
+    /// Changed grades only, most frequent first.
+    pub transitions: Vec<SeverityTransition>,
+    pub incidents: Vec<IncidentRegrade>,
+}
+
+fn direction(initial: Option<&str>, final_: Option<&str>) -> RegradeDirection {
+    let (Some(initial), Some(final_)) = (initial, final_) else {
+        return RegradeDirection::Unknown;
+    };
+    if initial.trim().eq_ignore_ascii_case(final_.trim()) {
+        return RegradeDirection::Unchanged;
+    }
+    match (severity_rank(initial), severity_rank(final_)) {
+        (Some(i), Some(f)) if f < i => RegradeDirection::Upgraded,
+        (Some(i), Some(f)) if f > i => RegradeDirection::Downgraded,
+        (Some(_), Some(_)) => RegradeDirection::Unchanged,
+        _ => RegradeDirection::Unknown,
+    }
+}
+
+/// Initial vs final severity over an already-scoped incident list. `initial` holds the
+/// initial grades known for the workspace; grades for incidents outside the list are ignored.
+pub fn severity_regrade_analysis(incidents: &[Incident], initial: &[SeverityGrade]) -> SeverityRegradeAnalysis {
+    let by_id: HashMap<i64, &SeverityGrade> = initial.iter().map(|g| (g.incident_id, g)).collect();
+    let mut out = SeverityRegradeAnalysis {
+        quarter_label: None,
+        incident_count: incidents.len() as i64,
+        upgraded_count: 0,
+        downgraded_count: 0,
+        unchanged_count: 0,
+        unknown_count: 0,
+        regrade_rate_pct: None,
+        transitions: Vec::new(),
+        incidents: Vec::with_capacity(incidents.len()),
+    };
+    let mut transitions: BTreeMap<(String, String), Vec<i64>> = BTreeMap::new();
+    for inc in incidents {
+        let grade = by_id.get(&inc.id);
+        let initial_severity = grade.map(|g| g.initial_severity.clone());
+        let dir = direction(initial_severity.as_deref(), inc.severity.as_deref());
+        match dir {
+            RegradeDirection::Upgraded => out.upgraded_count += 1,
+            RegradeDirection::Downgraded => out.downgraded_count += 1,
+            RegradeDirection::Unchanged => out.unchanged_count += 1,
+            RegradeDirection::Unknown => out.unknown_count += 1,
+        }
+        if matches!(dir, RegradeDirection::Upgraded | RegradeDirection::Downgraded) {
+            if let (Some(from), Some(to)) = (initial_severity.clone(), inc.severity.clone()) {
+                transitions.entry((from, to)).or_default().push(inc.id);
+            }
+        }
+        out.incidents.push(IncidentRegrade {
+            incident_id: inc.id,
+            external_id: inc.external_id.clone(),
+            initial_severity,
+            initial_source: grade.map(|g| g.source),
+            final_severity: inc.severity.clone(),
+            direction: dir,
+        });
+    }
+    out.incidents.sort_by_key(|r| r.incident_id);
+
+    let regraded = out.upgraded_count + out.downgraded_count;
+    let known = regraded + out.unchanged_count;
+    if known > 0 {
+        out.regrade_rate_pct = Some((regraded * 100 + known / 2) / known);
+    }
+
+    out.transitions = transitions
+        .into_iter()
+        .map(|((from, to), mut ids)| {
+            ids.sort();
+            SeverityTransition {
+                from,
+                to,
+                count: ids.len() as i64,
+                incident_ids: ids,
+            }
+        })
+        .collect();
+    out.transitions
+        .sort_by(|a, b| b.count.cmp(&a.count).then(a.from.cmp(&b.from)).then(a.to.cmp(&b.to)));
+    out
+}
+
+/// Re-grade analysis for the workspace, optionally limited to the incidents the assignment
+/// rule places in `quarter_label`.
+pub fn build_severity_regrade_analysis(
+    conn: &Connection,
+    quarter_label: Option<&str>,
+) -> Result<SeverityRegradeAnalysis, AppError> {
+    let mut incidents = list_incidents(conn)?;
+    if let Some(q) = quarter_label {
+        let scope = crate::quarters::resolve_quarter_scope(conn, q)?;
+        let anchor = crate::quarters::load_quarter_assignment_anchor(conn)?;
+        incidents.retain(|i| scope.contains_assigned(anchor, i));
+    }
+    let mut analysis = severity_regrade_analysis(&incidents, &initial_severities(conn)?);
+    analysis.quarter_label = quarter_label.map(str::to_string);
+    Ok(analysis)
+}
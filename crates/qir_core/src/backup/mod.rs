@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fs;
 use std::io::Read;
 use std::path::{Path, PathBuf};
@@ -7,12 +8,18 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
 use crate::error::AppError;
+use crate::progress::Progress;
+use crate::workspace::{init_portable_layout, is_portable_workspace, WorkspaceDataPaths, WorkspaceMetadata};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackupFileEntry {
     pub rel_path: String,
     pub sha256: String,
     pub bytes: u64,
+    /// Differential backups only: folder name of the sibling backup that holds this file,
+    /// unchanged since then. None when the bytes are in this backup.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stored_in: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,8 +51,17 @@ pub struct BackupManifest {
     pub counts: BackupCounts,
     pub db: BackupDbInfo,
     pub artifacts: BackupArtifactsInfo,
+    /// Differential backups only: folder name of the sibling backup this one was diffed against.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_backup: Option<String>,
 }
 
+/// Self-contained backup: the DB and every artifact are in the folder.
+pub const BACKUP_MANIFEST_VERSION_FULL: u32 = 1;
+/// Artifacts unchanged since the base backup are referenced, not copied. Restoring needs the
+/// referenced sibling folders, so older app versions (which only know v1) refuse it.
+pub const BACKUP_MANIFEST_VERSION_DIFFERENTIAL: u32 = 2;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackupCreateResult {
     pub backup_dir: String,
@@ -120,8 +136,9 @@ fn query_counts(conn: &Connection) -> Result<BackupCounts, AppError> {
     })
 }
 
-fn filename_safe_timestamp(export_time: &str) -> String {
-    // Keep backup dir names human-friendly but filesystem-safe and deterministic.
+/// Shared by every export that names a file or folder after its timestamp.
+pub(crate) fn filename_safe_timestamp(export_time: &str) -> String {
+    // Keep export names human-friendly but filesystem-safe and deterministic.
     // We do not attempt fuzzy parsing; this is just a deterministic normalization.
     export_time
         .chars()
@@ -176,7 +193,44 @@ fn list_files_recursive_sorted(root: &Path) -> Result<Vec<PathBuf>, AppError> {
     Ok(out)
 }
 
-fn copy_artifacts_dir(src: &Path, dst: &Path) -> Result<Vec<BackupFileEntry>, AppError> {
+/// Artifact hashes of the backup a differential backup is taken against, keyed by rel_path,
+/// with the sibling folder that actually holds each file.
+struct BaseArtifacts {
+    backups_root: PathBuf,
+    files: HashMap<String, (String, String)>,
+}
+
+fn load_base_artifacts(base_backup_dir: &Path) -> Result<(String, BaseArtifacts), AppError> {
+    let invalid = |message: &str| {
+        AppError::new("DB_BACKUP_BASE_INVALID", message).with_details(base_backup_dir.display().to_string())
+    };
+    if !base_backup_dir.is_dir() {
+        return Err(invalid("Base backup folder does not exist"));
+    }
+    let name = base_backup_dir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| invalid("Base backup path has no folder name"))?;
+    let backups_root = base_backup_dir
+        .parent()
+        .filter(|p| p.is_dir())
+        .ok_or_else(|| invalid("Base backup must sit in an existing backups folder"))?
+        .to_path_buf();
+    let manifest = read_manifest(base_backup_dir)?;
+    ensure_supported_manifest(&manifest)?;
+    let files = manifest
+        .artifacts
+        .files
+        .into_iter()
+        .map(|f| {
+            let holder = f.stored_in.unwrap_or_else(|| name.clone());
+            (f.rel_path, (f.sha256, holder))
+        })
+        .collect();
+    Ok((name, BaseArtifacts { backups_root, files }))
+}
+
+fn copy_artifacts_dir(src: &Path, dst: &Path, base: Option<&BaseArtifacts>) -> Result<Vec<BackupFileEntry>, AppError> {
     let files = list_files_recursive_sorted(src)?;
     let mut manifest_files = Vec::new();
 
@@ -191,6 +245,23 @@ fn copy_artifacts_dir(src: &Path, dst: &Path) -> Result<Vec<BackupFileEntry>, Ap
         let rel_str = rel.to_string_lossy().to_string();
         let target = dst.join(rel);
 
+        // Unchanged since the base (and still present where the base says it is): reference it.
+        if let Some((sha, holder)) = base.and_then(|b| {
+            let (sha, holder) = b.files.get(&rel_str)?;
+            b.backups_root.join(holder).join("artifacts").join(rel).is_file().then_some((sha, holder))
+        }) {
+            let (actual, bytes) = sha256_file_hex(&abs)?;
+            if actual == *sha {
+                manifest_files.push(BackupFileEntry {
+                    rel_path: rel_str,
+                    sha256: actual,
+                    bytes,
+                    stored_in: Some(holder.clone()),
+                });
+                continue;
+            }
+        }
+
         if let Some(parent) = target.parent() {
             fs::create_dir_all(parent).map_err(|e| {
                 AppError::new(
@@ -214,6 +285,7 @@ fn copy_artifacts_dir(src: &Path, dst: &Path) -> Result<Vec<BackupFileEntry>, Ap
             rel_path: rel_str,
             sha256: sha,
             bytes,
+            stored_in: None,
         });
     }
 
@@ -229,6 +301,74 @@ pub fn create_backup(
     export_time: &str,
     app_version: &str,
 ) -> Result<BackupCreateResult, AppError> {
+    create_backup_with_progress(
+        conn,
+        db_path,
+        artifacts_dir,
+        destination_dir,
+        export_time,
+        app_version,
+        &mut Progress::noop("backup_create"),
+    )
+}
+
+pub fn create_backup_with_progress(
+    conn: &Connection,
+    db_path: &Path,
+    artifacts_dir: Option<&Path>,
+    destination_dir: &Path,
+    export_time: &str,
+    app_version: &str,
+    progress: &mut Progress<'_>,
+) -> Result<BackupCreateResult, AppError> {
+    let target = BackupTarget {
+        destination_dir,
+        base_backup_dir: None,
+    };
+    progress.run(|p| create_backup_stages(conn, db_path, artifacts_dir, &target, export_time, app_version, p))
+}
+
+/// Differential backup written next to `base_backup_dir`: the DB is snapshotted in full, but
+/// artifacts whose sha256 matches the base manifest are recorded as references to the backup
+/// holding them instead of being copied. Chains collapse, so each reference names the folder
+/// with the bytes. Restore needs those sibling folders to be present.
+pub fn create_differential_backup_with_progress(
+    conn: &Connection,
+    db_path: &Path,
+    artifacts_dir: Option<&Path>,
+    base_backup_dir: &Path,
+    export_time: &str,
+    app_version: &str,
+    progress: &mut Progress<'_>,
+) -> Result<BackupCreateResult, AppError> {
+    let destination_dir = base_backup_dir.parent().ok_or_else(|| {
+        AppError::new("DB_BACKUP_BASE_INVALID", "Base backup must sit in an existing backups folder")
+            .with_details(base_backup_dir.display().to_string())
+    })?;
+    let target = BackupTarget {
+        destination_dir,
+        base_backup_dir: Some(base_backup_dir),
+    };
+    progress.run(|p| create_backup_stages(conn, db_path, artifacts_dir, &target, export_time, app_version, p))
+}
+
+struct BackupTarget<'a> {
+    destination_dir: &'a Path,
+    base_backup_dir: Option<&'a Path>,
+}
+
+fn create_backup_stages(
+    conn: &Connection,
+    db_path: &Path,
+    artifacts_dir: Option<&Path>,
+    target: &BackupTarget<'_>,
+    export_time: &str,
+    app_version: &str,
+    progress: &mut Progress<'_>,
+) -> Result<BackupCreateResult, AppError> {
+    let destination_dir = target.destination_dir;
+    // Read the base first so a bad base fails before anything is written.
+    let base = target.base_backup_dir.map(load_base_artifacts).transpose()?;
     if !destination_dir.is_dir() {
         return Err(AppError::new(
             "DB_BACKUP_DEST_NOT_DIR",
@@ -255,6 +395,7 @@ pub fn create_backup(
     })?;
 
     // Create a consistent SQLite snapshot via the backup API (avoids unsafe file copying).
+    progress.stage("snapshot", Some(5));
     let dest_db_path = backup_dir.join("incidentreview.sqlite");
     let mut dest_conn = Connection::open(&dest_db_path).map_err(|e| {
         AppError::new("DB_BACKUP_DB_CREATE_FAILED", "Failed to create backup SQLite file")
@@ -276,9 +417,11 @@ pub fn create_backup(
             })?;
     }
 
+    progress.stage("hash", Some(40));
     let (db_sha, db_bytes) = sha256_file_hex(&dest_db_path)?;
 
     // Copy artifacts directory if present.
+    progress.stage("artifacts", Some(50));
     let artifacts_dst = backup_dir.join("artifacts");
     let (artifacts_included, artifacts_files) = match artifacts_dir {
         Some(dir) if dir.is_dir() => {
@@ -289,16 +432,21 @@ pub fn create_backup(
                 )
                 .with_details(format!("path={}: {}", artifacts_dst.display(), e))
             })?;
-            (true, copy_artifacts_dir(dir, &artifacts_dst)?)
+            (true, copy_artifacts_dir(dir, &artifacts_dst, base.as_ref().map(|(_, b)| b))?)
         }
         _ => (false, Vec::new()),
     };
 
+    progress.stage("manifest", Some(90));
     let migrations = list_migration_names(conn)?;
     let counts = query_counts(conn)?;
 
     let manifest = BackupManifest {
-        manifest_version: 1,
+        manifest_version: if base.is_some() {
+            BACKUP_MANIFEST_VERSION_DIFFERENTIAL
+        } else {
+            BACKUP_MANIFEST_VERSION_FULL
+        },
         app_version: app_version.to_string(),
         export_time: export_time.to_string(),
         schema_migrations: migrations,
@@ -312,6 +460,7 @@ pub fn create_backup(
             included: artifacts_included,
             files: artifacts_files,
         },
+        base_backup: base.map(|(name, _)| name),
     };
 
     let manifest_path = backup_dir.join("manifest.json");
@@ -363,6 +512,41 @@ pub fn read_manifest(backup_dir: &Path) -> Result<BackupManifest, AppError> {
     })
 }
 
+/// Where each manifest artifact's bytes live: this backup's artifacts folder, or for files a
+/// differential backup references, the sibling backup holding them.
+fn artifact_sources(backup_dir: &Path, manifest: &BackupManifest) -> Result<Vec<PathBuf>, AppError> {
+    manifest
+        .artifacts
+        .files
+        .iter()
+        .map(|f| match &f.stored_in {
+            None => Ok(backup_dir.join("artifacts").join(&f.rel_path)),
+            Some(holder) => backup_dir
+                .parent()
+                .map(|root| root.join(holder).join("artifacts").join(&f.rel_path))
+                .filter(|p| p.is_file())
+                .ok_or_else(|| {
+                    AppError::new(
+                        "DB_RESTORE_BASE_MISSING",
+                        "A backup this differential backup depends on is missing",
+                    )
+                    .with_details(format!("file={}; stored_in={}", f.rel_path, holder))
+                }),
+        })
+        .collect()
+}
+
+fn ensure_supported_manifest(manifest: &BackupManifest) -> Result<(), AppError> {
+    if ![BACKUP_MANIFEST_VERSION_FULL, BACKUP_MANIFEST_VERSION_DIFFERENTIAL].contains(&manifest.manifest_version) {
+        return Err(AppError::new(
+            "DB_RESTORE_UNSUPPORTED_MANIFEST",
+            "Unsupported backup manifest version",
+        )
+        .with_details(format!("version={}", manifest.manifest_version)));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RestoreResult {
     pub ok: bool,
@@ -377,13 +561,7 @@ pub fn restore_from_backup(
     allow_overwrite: bool,
 ) -> Result<RestoreResult, AppError> {
     let manifest = read_manifest(backup_dir)?;
-    if manifest.manifest_version != 1 {
-        return Err(AppError::new(
-            "DB_RESTORE_UNSUPPORTED_MANIFEST",
-            "Unsupported backup manifest version",
-        )
-        .with_details(format!("version={}", manifest.manifest_version)));
-    }
+    ensure_supported_manifest(&manifest)?;
 
     let backup_db_path = backup_dir.join(&manifest.db.filename);
     if !backup_db_path.is_file() {
@@ -406,6 +584,10 @@ pub fn restore_from_backup(
         )));
     }
 
+    // Resolve every artifact's source up front so a broken differential chain fails before
+    // the workspace is touched.
+    let artifact_sources = artifact_sources(backup_dir, &manifest)?;
+
     if target_db_path.exists() && !allow_overwrite {
         return Err(AppError::new(
             "DB_RESTORE_CONFIRM_REQUIRED",
@@ -485,8 +667,7 @@ pub fn restore_from_backup(
             })?;
 
             // Copy artifacts according to manifest ordering and validate hashes.
-            for f in &manifest.artifacts.files {
-                let src = backup_artifacts.join(&f.rel_path);
+            for (f, src) in manifest.artifacts.files.iter().zip(&artifact_sources) {
                 let dst = tmp_artifacts.join(&f.rel_path);
                 if let Some(parent) = dst.parent() {
                     fs::create_dir_all(parent).map_err(|e| {
@@ -497,7 +678,7 @@ pub fn restore_from_backup(
                         .with_details(format!("path={}: {}", parent.display(), e))
                     })?;
                 }
-                fs::copy(&src, &dst).map_err(|e| {
+                fs::copy(src, &dst).map_err(|e| {
                     AppError::new(
                         "DB_RESTORE_COPY_FAILED",
                         "Failed to restore artifact file",
@@ -555,3 +736,61 @@ pub fn restore_from_backup(
         restored_artifacts,
     })
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreAsNewWorkspaceResult {
+    pub restore: RestoreResult,
+    pub workspace: WorkspaceMetadata,
+    pub data_paths: WorkspaceDataPaths,
+}
+
+/// Materializes a backup into a fresh workspace at `dest_db_path` so it can be inspected next
+/// to the current one, which is never touched. The new workspace uses the portable layout:
+/// the backup's artifacts land next to its DB instead of in the machine-level folder. Nothing
+/// at the destination is overwritten, and a failed restore removes what it created.
+pub fn restore_as_new_workspace(backup_dir: &Path, dest_db_path: &Path) -> Result<RestoreAsNewWorkspaceResult, AppError> {
+    if dest_db_path.exists() || is_portable_workspace(dest_db_path) {
+        return Err(AppError::new(
+            "DB_RESTORE_DEST_EXISTS",
+            "Restore destination already holds a workspace",
+        )
+        .with_details(dest_db_path.display().to_string()));
+    }
+    let data_paths = init_portable_layout(dest_db_path)?;
+    let artifacts_dir = PathBuf::from(&data_paths.artifacts_dir);
+
+    let restore = || -> Result<(RestoreResult, WorkspaceMetadata), AppError> {
+        // The layout's empty artifacts folder would count as an existing restore target.
+        fs::remove_dir(&artifacts_dir).map_err(|e| {
+            AppError::new("DB_RESTORE_MKDIR_FAILED", "Failed to prepare artifacts dir")
+                .with_details(format!("path={}: {}", artifacts_dir.display(), e))
+        })?;
+        let restored = restore_from_backup(backup_dir, dest_db_path, Some(&artifacts_dir), false)?;
+        fs::create_dir_all(&artifacts_dir).map_err(|e| {
+            AppError::new("DB_RESTORE_MKDIR_FAILED", "Failed to create artifacts dir")
+                .with_details(format!("path={}: {}", artifacts_dir.display(), e))
+        })?;
+        // Older backups are brought up to the current schema here.
+        let workspace = crate::workspace::open_workspace(dest_db_path)?;
+        Ok((restored, workspace))
+    };
+
+    match restore() {
+        Ok((restore, workspace)) => Ok(RestoreAsNewWorkspaceResult {
+            restore,
+            workspace,
+            data_paths,
+        }),
+        Err(e) => {
+            let _ = fs::remove_file(dest_db_path);
+            let _ = fs::remove_file(dest_db_path.with_extension("restore_tmp"));
+            let _ = fs::remove_dir_all(&artifacts_dir);
+            let _ = fs::remove_dir_all(artifacts_dir.with_extension("restore_tmp"));
+            let _ = fs::remove_dir(&data_paths.ai_store_root);
+            if let Some(dir) = dest_db_path.parent() {
+                let _ = fs::remove_file(dir.join(crate::workspace::PORTABLE_MARKER_FILENAME));
+            }
+            Err(e)
+        }
+    }
+}
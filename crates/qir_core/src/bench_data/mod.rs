@@ -0,0 +1,95 @@
+// Deterministic synthetic datasets for benchmarks and perf budgets.
+// Compiled only with `--features bench-data`; never shipped in the app.
+
+use rusqlite::Connection;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::error::AppError;
+use crate::ingest::jira_csv::{import_jira_csv, JiraCsvMapping, JiraImportSummary};
+
+/// 2025-01-01T00:00:00Z
+const BASE_UNIX: i64 = 1_735_689_600;
+
+/// splitmix64: tiny, stable across platforms and Rust versions.
+fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+fn ts(unix: i64) -> String {
+    OffsetDateTime::from_unix_timestamp(unix)
+        .ok()
+        .and_then(|dt| dt.format(&Rfc3339).ok())
+        .unwrap_or_default()
+}
+
+pub fn bench_mapping() -> JiraCsvMapping {
+    JiraCsvMapping {
+        external_id: Some("Key".to_string()),
+        title: "Summary".to_string(),
+        description: None,
+        severity: Some("Severity".to_string()),
+        initial_severity: None,
+        detection_source: Some("DetectionSource".to_string()),
+        vendor: Some("Vendor".to_string()),
+        service: Some("Service".to_string()),
+        impact_pct: Some("ImpactPct".to_string()),
+        service_health_pct: Some("ServiceHealthPct".to_string()),
+        start_ts: Some("StartTs".to_string()),
+        first_observed_ts: Some("FirstObservedTs".to_string()),
+        it_awareness_ts: Some("ItAwarenessTs".to_string()),
+        ack_ts: Some("AckTs".to_string()),
+        mitigate_ts: Some("MitigateTs".to_string()),
+        resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
+    }
+}
+
+/// Jira-style CSV with `n` incidents spread over a year. Same (n, seed) => identical bytes.
+/// Roughly 2% of rows omit ack/resolve so UNKNOWN buckets and warnings are exercised.
+pub fn generate_jira_csv(n: usize, seed: u64) -> String {
+    let severities = ["SEV0", "SEV1", "SEV2", "SEV3", "SEV4"];
+    let detections = ["monitoring", "customer", "vendor", "internal_test", "synthetic"];
+    let mut out = String::with_capacity(n * 220);
+    out.push_str("Key,Summary,Severity,DetectionSource,Vendor,Service,ImpactPct,ServiceHealthPct,StartTs,FirstObservedTs,ItAwarenessTs,AckTs,MitigateTs,ResolveTs\n");
+
+    for i in 0..n {
+        let r = mix(seed ^ (i as u64));
+        let r2 = mix(r);
+        let sev = severities[(r % severities.len() as u64) as usize];
+        let det = detections[((r >> 8) % detections.len() as u64) as usize];
+        let vendor = format!("Vendor{:02}", (r >> 16) % 40);
+        let service = format!("svc-{:03}", (r >> 24) % 150);
+        let impact = (r >> 32) % 101;
+        let health = 100 - ((r >> 40) % 101);
+
+        let start = BASE_UNIX + ((r2 % (365 * 86_400)) as i64);
+        let observed = start + ((r2 >> 20) % 1_800) as i64;
+        let aware = observed + ((r2 >> 30) % 3_600) as i64;
+        let ack = aware + ((r2 >> 40) % 7_200) as i64;
+        let mitigate = ack + ((r2 >> 48) % 14_400) as i64;
+        let resolve = mitigate + ((r >> 48) % 86_400) as i64;
+        let partial = r2.is_multiple_of(50);
+
+        out.push_str(&format!(
+            "BENCH-{i:06},\"Synthetic incident {i}\",{sev},{det},{vendor},{service},{impact},{health},{},{},{},{},{},{}\n",
+            ts(start),
+            ts(observed),
+            ts(aware),
+            if partial { String::new() } else { ts(ack) },
+            ts(mitigate),
+            if partial { String::new() } else { ts(resolve) },
+        ));
+    }
+    out
+}
+
+/// Imports `generate_jira_csv(n, seed)` into `conn` (expected to be migrated and empty).
+pub fn seed_bench_dataset(conn: &mut Connection, n: usize, seed: u64) -> Result<JiraImportSummary, AppError> {
+    import_jira_csv(conn, &generate_jira_csv(n, seed), &bench_mapping())
+}
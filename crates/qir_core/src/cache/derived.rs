@@ -0,0 +1,139 @@
+use std::time::Instant;
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::analytics::{build_dashboard_payload_v2, DashboardPayloadV2};
+use crate::error::AppError;
+use crate::validate::{validate_all_incidents, IncidentValidationReportItem};
+
+use super::{compute_dashboard_v2_hash, compute_incidents_content_hash, DashboardCache};
+
+pub const DERIVED_KIND_DASHBOARD_V2: &str = "dashboard_v2";
+/// Bumped when validators change so persisted reports from older rules are not served.
+pub const DERIVED_KIND_VALIDATION_REPORT: &str = "validation_report_v2";
+
+/// Where a derived payload came from on this call.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DerivedSource {
+    Memory,
+    Persisted,
+    Computed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WarmStartSummary {
+    pub dashboard_hash: String,
+    pub dashboard_source: DerivedSource,
+    pub validation_hash: String,
+    pub validation_source: DerivedSource,
+    pub incident_count: i64,
+    pub elapsed_ms: u64,
+}
+
+pub fn load_derived<T: DeserializeOwned>(
+    conn: &Connection,
+    kind: &str,
+    content_hash: &str,
+) -> Result<Option<T>, AppError> {
+    let payload: Option<String> = conn
+        .query_row(
+            "SELECT payload_json FROM derived_cache WHERE kind = ?1 AND content_hash = ?2",
+            [kind, content_hash],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query derived cache")
+                .with_details(format!("kind={kind}; err={e}"))
+        })?;
+    match payload {
+        None => Ok(None),
+        // A row that no longer decodes (payload shape changed) is treated as a miss; it will
+        // be overwritten by the next store.
+        Some(json) => Ok(serde_json::from_str(&json).ok()),
+    }
+}
+
+pub fn store_derived<T: Serialize>(
+    conn: &Connection,
+    kind: &str,
+    content_hash: &str,
+    payload: &T,
+) -> Result<(), AppError> {
+    let json = serde_json::to_string(payload).map_err(|e| {
+        AppError::new("DB_ENCODE_FAILED", "Failed to encode derived payload")
+            .with_details(format!("kind={kind}; err={e}"))
+    })?;
+    conn.execute(
+        "INSERT INTO derived_cache(kind, content_hash, payload_json, computed_at) VALUES (?1, ?2, ?3, strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+         ON CONFLICT(kind) DO UPDATE SET content_hash = excluded.content_hash, payload_json = excluded.payload_json, computed_at = excluded.computed_at",
+        rusqlite::params![kind, content_hash, json],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to persist derived payload")
+            .with_details(format!("kind={kind}; err={e}"))
+    })?;
+    Ok(())
+}
+
+pub fn clear_derived_cache(conn: &Connection) -> Result<(), AppError> {
+    conn.execute("DELETE FROM derived_cache", []).map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to clear derived cache")
+            .with_details(e.to_string())
+    })?;
+    Ok(())
+}
+
+/// Dashboard V2 via memory cache, then the persisted `derived_cache` row, then a fresh build
+/// (which is stored in both). Always consistent with the current data because every tier
+/// is keyed by `compute_dashboard_v2_hash`.
+pub fn cached_dashboard_v2(
+    conn: &Connection,
+    cache: &DashboardCache,
+) -> Result<(DashboardPayloadV2, String, DerivedSource), AppError> {
+    let hash = compute_dashboard_v2_hash(conn)?;
+    if let Some(p) = cache.get_v2(&hash) {
+        return Ok((p, hash, DerivedSource::Memory));
+    }
+    if let Some(p) = load_derived::<DashboardPayloadV2>(conn, DERIVED_KIND_DASHBOARD_V2, &hash)? {
+        cache.set_v2(p.clone(), hash.clone());
+        return Ok((p, hash, DerivedSource::Persisted));
+    }
+    let p = build_dashboard_payload_v2(conn)?;
+    store_derived(conn, DERIVED_KIND_DASHBOARD_V2, &hash, &p)?;
+    cache.set_v2(p.clone(), hash.clone());
+    Ok((p, hash, DerivedSource::Computed))
+}
+
+/// Validation report via the persisted row (keyed by incident content hash) or a fresh run.
+pub fn cached_validation_report(
+    conn: &Connection,
+) -> Result<(Vec<IncidentValidationReportItem>, String, DerivedSource), AppError> {
+    let incidents = crate::repo::list_incidents(conn)?;
+    let hash = compute_incidents_content_hash(&incidents);
+    if let Some(r) = load_derived(conn, DERIVED_KIND_VALIDATION_REPORT, &hash)? {
+        return Ok((r, hash, DerivedSource::Persisted));
+    }
+    let r = validate_all_incidents(conn)?;
+    store_derived(conn, DERIVED_KIND_VALIDATION_REPORT, &hash, &r)?;
+    Ok((r, hash, DerivedSource::Computed))
+}
+
+/// Precomputes the dashboard and validation report for a freshly opened workspace. Meant to
+/// run off the UI thread on its own connection.
+pub fn warm_start_precompute(conn: &Connection, cache: &DashboardCache) -> Result<WarmStartSummary, AppError> {
+    let started = Instant::now();
+    let (dash, dashboard_hash, dashboard_source) = cached_dashboard_v2(conn, cache)?;
+    let (_, validation_hash, validation_source) = cached_validation_report(conn)?;
+    Ok(WarmStartSummary {
+        dashboard_hash,
+        dashboard_source,
+        validation_hash,
+        validation_source,
+        incident_count: dash.incident_count,
+        elapsed_ms: started.elapsed().as_millis() as u64,
+    })
+}
@@ -10,6 +10,13 @@ use std::sync::Mutex;
 use std::time::{SystemTime, Duration};
 use crate::analytics::{DashboardPayloadV1, DashboardPayloadV2};
 
+mod derived;
+pub use derived::{
+    cached_dashboard_v2, cached_validation_report, clear_derived_cache, load_derived, store_derived,
+    warm_start_precompute, DerivedSource, WarmStartSummary, DERIVED_KIND_DASHBOARD_V2,
+    DERIVED_KIND_VALIDATION_REPORT,
+};
+
 /// Cached dashboard payload with timestamp and hash
 struct CachedDashboard<T> {
     data: T,
@@ -160,6 +167,116 @@ pub fn compute_incidents_hash(incident_ids: &[String]) -> String {
     encode(hasher.finalize())
 }
 
+/// Deterministic content hash of the incident rows that drive metrics, dashboards and
+/// reports. Operational columns (ingested_at) are excluded so re-importing identical data
+/// keeps the hash stable. Incidents are hashed in id order.
+pub fn compute_incidents_content_hash(incidents: &[crate::domain::Incident]) -> String {
+    use sha2::{Digest, Sha256};
+
+    let mut sorted: Vec<&crate::domain::Incident> = incidents.iter().collect();
+    sorted.sort_by_key(|i| i.id);
+    let mut hasher = Sha256::new();
+    for inc in sorted {
+        // Serialization of the domain struct is field-ordered and cannot fail for plain data.
+        let json = serde_json::to_string(inc).unwrap_or_default();
+        hasher.update(json.as_bytes());
+        hasher.update(b"\n");
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Content hash of the workspace: incidents plus timeline events (excluding created_at).
+pub fn compute_workspace_content_hash(
+    conn: &rusqlite::Connection,
+) -> Result<String, crate::error::AppError> {
+    use sha2::{Digest, Sha256};
+
+    let incidents = crate::repo::list_incidents(conn)?;
+    let mut events = crate::repo::list_timeline_events(conn)?;
+    events.sort_by_key(|e| e.id);
+
+    let mut hasher = Sha256::new();
+    hasher.update(b"incidents=");
+    hasher.update(compute_incidents_content_hash(&incidents).as_bytes());
+    hasher.update(b"|timeline_events=");
+    for e in events {
+        let json = serde_json::to_string(&(
+            e.id,
+            e.incident_id,
+            &e.source,
+            &e.ts,
+            &e.author,
+            &e.kind,
+            &e.text,
+            &e.raw_json,
+        ))
+        .map_err(|err| {
+            crate::error::AppError::new("DB_ENCODE_FAILED", "Failed to encode timeline event for hashing")
+                .with_details(format!("event_id={}; err={}", e.id, err))
+        })?;
+        hasher.update(json.as_bytes());
+        hasher.update(b"\n");
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Hash of everything `build_dashboard_payload_v2` depends on: incident content, the
+/// workspace dashboard config, business hours, pain formula, computed metric definitions, and
+/// the payload version. Used as the V2 cache key and as the `since_hash` token for dashboard deltas.
+pub fn compute_dashboard_v2_hash(
+    conn: &rusqlite::Connection,
+) -> Result<String, crate::error::AppError> {
+    use sha2::{Digest, Sha256};
+
+    let incidents = crate::repo::list_incidents(conn)?;
+    let cfg = crate::analytics::load_dashboard_config(conn)?;
+    let cfg_json = serde_json::to_string(&cfg).map_err(|e| {
+        crate::error::AppError::new("DB_ENCODE_FAILED", "Failed to encode dashboard config for hashing")
+            .with_details(e.to_string())
+    })?;
+    let hours_json = serde_json::to_string(&crate::analytics::load_business_hours(conn)?).map_err(|e| {
+        crate::error::AppError::new("DB_ENCODE_FAILED", "Failed to encode business hours for hashing")
+            .with_details(e.to_string())
+    })?;
+
+    let pain_json = serde_json::to_string(&crate::analytics::load_pain_formula(conn)?).map_err(|e| {
+        crate::error::AppError::new("DB_ENCODE_FAILED", "Failed to encode pain formula for hashing")
+            .with_details(e.to_string())
+    })?;
+
+    let computed_json = serde_json::to_string(&crate::metrics::load_computed_metric_definitions(conn)?)
+        .map_err(|e| {
+            crate::error::AppError::new("DB_ENCODE_FAILED", "Failed to encode computed metrics for hashing")
+                .with_details(e.to_string())
+        })?;
+
+    let aliases_json = serde_json::to_string(
+        &crate::aliases::list_entity_aliases(conn, None)?
+            .into_iter()
+            .map(|a| (a.kind, a.alias, a.canonical))
+            .collect::<Vec<_>>(),
+    )
+    .map_err(|e| {
+        crate::error::AppError::new("DB_ENCODE_FAILED", "Failed to encode entity aliases for hashing")
+            .with_details(e.to_string())
+    })?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(format!("dashboard_v{}|", crate::analytics::DASHBOARD_PAYLOAD_V2_VERSION).as_bytes());
+    hasher.update(cfg_json.as_bytes());
+    hasher.update(b"|business_hours=");
+    hasher.update(hours_json.as_bytes());
+    hasher.update(b"|pain_formula=");
+    hasher.update(pain_json.as_bytes());
+    hasher.update(b"|computed_metrics=");
+    hasher.update(computed_json.as_bytes());
+    hasher.update(b"|entity_aliases=");
+    hasher.update(aliases_json.as_bytes());
+    hasher.update(b"|incidents=");
+    hasher.update(compute_incidents_content_hash(&incidents).as_bytes());
+    Ok(hex::encode(hasher.finalize()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,7 +374,10 @@ mod tests {
             response_story: crate::analytics::ResponseStoryV1 {
                 time_to_mitigation_buckets: vec![],
                 time_to_resolve_buckets: vec![],
+                start_hours_buckets: vec![],
             },
+            computed_metrics: vec![],
+            pain_formula_version: String::new(),
         };
 
         cache.set_v1(v1, hash.clone());
@@ -0,0 +1,304 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::domain::{Incident, ValidationWarning};
+use crate::backup::filename_safe_timestamp;
+use crate::error::AppError;
+use crate::metrics;
+use crate::repo;
+use crate::validate;
+
+/// Bump when a column is added, removed, renamed, or changes type.
+/// v2: per-workspace `computed_<key>_seconds` columns follow `mttr_seconds`.
+pub const PARQUET_SCHEMA_VERSION: u32 = 2;
+pub const PARQUET_DESCRIPTOR_FILENAME: &str = "schema.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ParquetColumnDescriptor {
+    pub name: String,
+    /// Arrow logical type: "int64" or "utf8".
+    pub data_type: String,
+    pub nullable: bool,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ParquetTableDescriptor {
+    pub name: String,
+    pub filename: String,
+    pub row_count: i64,
+    pub bytes: u64,
+    pub sha256: String,
+    pub columns: Vec<ParquetColumnDescriptor>,
+}
+
+/// JSON descriptor written next to the Parquet files so consumers can check the schema
+/// version before loading.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ParquetExportDescriptor {
+    pub schema_version: u32,
+    pub app_version: String,
+    pub export_time: String,
+    pub tables: Vec<ParquetTableDescriptor>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ParquetExportResult {
+    pub export_dir: String,
+    pub incident_count: i64,
+    pub warning_count: i64,
+    pub descriptor: ParquetExportDescriptor,
+}
+
+/// A column's schema and its data, built together so the descriptor can never drift from
+/// what is actually written.
+struct Column {
+    name: String,
+    nullable: bool,
+    description: String,
+    array: ArrayRef,
+}
+
+fn int_col(name: impl Into<String>, nullable: bool, description: impl Into<String>, values: Vec<Option<i64>>) -> Column {
+    Column {
+        name: name.into(),
+        nullable,
+        description: description.into(),
+        array: Arc::new(Int64Array::from(values)),
+    }
+}
+
+fn str_col(name: impl Into<String>, nullable: bool, description: impl Into<String>, values: Vec<Option<String>>) -> Column {
+    Column {
+        name: name.into(),
+        nullable,
+        description: description.into(),
+        array: Arc::new(StringArray::from(values)),
+    }
+}
+
+fn type_name(dt: &DataType) -> &'static str {
+    match dt {
+        DataType::Int64 => "int64",
+        DataType::Utf8 => "utf8",
+        _ => "unsupported",
+    }
+}
+
+fn write_table(dir: &Path, table: &str, row_count: i64, columns: Vec<Column>) -> Result<ParquetTableDescriptor, AppError> {
+    let fields: Vec<Field> = columns
+        .iter()
+        .map(|c| Field::new(c.name.clone(), c.array.data_type().clone(), c.nullable))
+        .collect();
+    let descriptors: Vec<ParquetColumnDescriptor> = columns
+        .iter()
+        .map(|c| ParquetColumnDescriptor {
+            name: c.name.clone(),
+            data_type: type_name(c.array.data_type()).to_string(),
+            nullable: c.nullable,
+            description: c.description.clone(),
+        })
+        .collect();
+    let schema = Arc::new(Schema::new(fields));
+    let batch = RecordBatch::try_new(schema.clone(), columns.into_iter().map(|c| c.array).collect())
+        .map_err(|e| {
+            AppError::new("EXPORT_PARQUET_ENCODE_FAILED", "Failed to build Parquet record batch")
+                .with_details(format!("table={table}; err={e}"))
+        })?;
+
+    let filename = format!("{table}.parquet");
+    let path = dir.join(&filename);
+    let file = fs::File::create(&path).map_err(|e| {
+        AppError::new("EXPORT_PARQUET_WRITE_FAILED", "Failed to create Parquet file")
+            .with_details(format!("path={}: {}", path.display(), e))
+    })?;
+    let props = WriterProperties::builder()
+        .set_created_by(format!("IncidentReview parquet_schema_v{PARQUET_SCHEMA_VERSION}"))
+        .build();
+    let mut writer = ArrowWriter::try_new(file, schema, Some(props)).map_err(|e| {
+        AppError::new("EXPORT_PARQUET_WRITE_FAILED", "Failed to open Parquet writer")
+            .with_details(format!("path={}: {}", path.display(), e))
+    })?;
+    writer.write(&batch).map_err(|e| {
+        AppError::new("EXPORT_PARQUET_WRITE_FAILED", "Failed to write Parquet rows")
+            .with_details(format!("path={}: {}", path.display(), e))
+    })?;
+    writer.close().map_err(|e| {
+        AppError::new("EXPORT_PARQUET_WRITE_FAILED", "Failed to finalize Parquet file")
+            .with_details(format!("path={}: {}", path.display(), e))
+    })?;
+
+    let bytes = fs::read(&path).map_err(|e| {
+        AppError::new("EXPORT_PARQUET_WRITE_FAILED", "Failed to read back Parquet file")
+            .with_details(format!("path={}: {}", path.display(), e))
+    })?;
+    Ok(ParquetTableDescriptor {
+        name: table.to_string(),
+        filename,
+        row_count,
+        bytes: bytes.len() as u64,
+        sha256: hex::encode(Sha256::digest(&bytes)),
+        columns: descriptors,
+    })
+}
+
+/// Exports incidents (with computed metrics) and validation warnings as Parquet files plus a
+/// versioned JSON schema descriptor, into `IncidentReviewParquet_<ts>/` under `destination_dir`.
+/// Unlike the sanitized export this is NOT pseudonymized; it is meant for internal analysis.
+pub fn export_parquet_dataset(
+    conn: &Connection,
+    destination_dir: &Path,
+    export_time: &str,
+    app_version: &str,
+) -> Result<ParquetExportResult, AppError> {
+    if !destination_dir.is_dir() {
+        return Err(AppError::new(
+            "EXPORT_PARQUET_DEST_NOT_DIR",
+            "Parquet export destination must be an existing directory",
+        )
+        .with_details(destination_dir.display().to_string()));
+    }
+    let export_dir = destination_dir.join(format!(
+        "IncidentReviewParquet_{}",
+        filename_safe_timestamp(export_time)
+    ));
+    if export_dir.exists() {
+        return Err(AppError::new(
+            "EXPORT_PARQUET_DEST_EXISTS",
+            "Parquet export destination folder already exists",
+        )
+        .with_details(export_dir.display().to_string()));
+    }
+    fs::create_dir_all(&export_dir).map_err(|e| {
+        AppError::new("EXPORT_PARQUET_MKDIR_FAILED", "Failed to create Parquet export directory")
+            .with_details(format!("path={}: {}", export_dir.display(), e))
+    })?;
+
+    let mut incidents = repo::list_incidents(conn)?;
+    incidents.sort_by_key(|i| i.id);
+
+    let mut validation = validate::validate_all_incidents(conn)?;
+    validation.sort_by_key(|v| v.id);
+    let warning_count_for = |id: i64| -> i64 {
+        validation
+            .iter()
+            .find(|v| v.id == id)
+            .map(|v| v.warnings.len() as i64)
+            .unwrap_or(0)
+    };
+
+    let computed_defs = metrics::load_computed_metric_definitions(conn)?;
+    let computed_values: Vec<Vec<Option<i64>>> = incidents
+        .iter()
+        .map(|i| metrics::compute_computed_metrics(i, &computed_defs))
+        .collect();
+
+    let computed: Vec<metrics::IncidentMetrics> = incidents
+        .iter()
+        .map(|i| metrics::compute_incident_metrics(i).0)
+        .collect();
+    let strs = |f: &dyn Fn(&Incident) -> Option<String>| -> Vec<Option<String>> {
+        incidents.iter().map(f).collect()
+    };
+    let ints = |f: &dyn Fn(&Incident) -> Option<i64>| -> Vec<Option<i64>> {
+        incidents.iter().map(f).collect()
+    };
+    let metric = |f: &dyn Fn(&metrics::IncidentMetrics) -> Option<i64>| -> Vec<Option<i64>> {
+        computed.iter().map(f).collect()
+    };
+
+    let mut incident_columns = vec![
+        int_col("incident_id", false, "Workspace incident id", ints(&|i| Some(i.id))),
+        str_col("external_id", true, "Source system key (e.g. Jira issue key)", strs(&|i| i.external_id.clone())),
+        str_col("title", false, "Incident title", strs(&|i| Some(i.title.clone()))),
+        str_col("severity", true, "Severity as imported", strs(&|i| i.severity.clone())),
+        str_col("detection_source", true, "How the incident was detected", strs(&|i| i.detection_source.clone())),
+        str_col("vendor", true, "Vendor attribution", strs(&|i| i.vendor.clone())),
+        str_col("service", true, "Affected service", strs(&|i| i.service.clone())),
+        int_col("impact_pct", true, "Customer impact percentage (0-100)", ints(&|i| i.impact_pct)),
+        int_col("service_health_pct", true, "Service health percentage (0-100)", ints(&|i| i.service_health_pct)),
+        str_col("start_ts", true, "Canonical RFC3339 start", strs(&|i| i.start_ts.clone())),
+        str_col("first_observed_ts", true, "Canonical RFC3339 first observed", strs(&|i| i.first_observed_ts.clone())),
+        str_col("it_awareness_ts", true, "Canonical RFC3339 IT awareness", strs(&|i| i.it_awareness_ts.clone())),
+        str_col("ack_ts", true, "Canonical RFC3339 acknowledgement", strs(&|i| i.ack_ts.clone())),
+        str_col("mitigate_ts", true, "Canonical RFC3339 mitigation", strs(&|i| i.mitigate_ts.clone())),
+        str_col("resolve_ts", true, "Canonical RFC3339 resolution", strs(&|i| i.resolve_ts.clone())),
+        int_col("mttd_seconds", true, "start -> first observed", metric(&|m| m.mttd_seconds)),
+        int_col("it_awareness_lag_seconds", true, "first observed -> IT awareness", metric(&|m| m.it_awareness_lag_seconds)),
+        int_col("mtta_seconds", true, "IT awareness -> acknowledgement", metric(&|m| m.mtta_seconds)),
+        int_col("time_to_mitigation_seconds", true, "IT awareness -> mitigation", metric(&|m| m.time_to_mitigation_seconds)),
+        int_col("mttr_seconds", true, "start (or first observed) -> resolution", metric(&|m| m.mttr_seconds)),
+    ];
+    for (idx, d) in computed_defs.iter().enumerate() {
+        incident_columns.push(int_col(
+            format!("computed_{}_seconds", d.key),
+            true,
+            format!("{}: {} -> {}", d.label, d.from_field, d.to_field),
+            computed_values.iter().map(|v| v[idx]).collect(),
+        ));
+    }
+    incident_columns.push(int_col(
+        "warning_count",
+        false,
+        "Validation warnings for this incident",
+        ints(&|i| Some(warning_count_for(i.id))),
+    ));
+
+    let flat: Vec<(i64, &ValidationWarning)> = validation
+        .iter()
+        .flat_map(|v| v.warnings.iter().map(move |w| (v.id, w)))
+        .collect();
+    let warning_columns = vec![
+        int_col("incident_id", false, "Workspace incident id", flat.iter().map(|(id, _)| Some(*id)).collect()),
+        str_col("code", false, "Stable warning code", flat.iter().map(|(_, w)| Some(w.code.clone())).collect()),
+        str_col("message", false, "Human-readable warning", flat.iter().map(|(_, w)| Some(w.message.clone())).collect()),
+        str_col("details", true, "Warning details (may contain raw values)", flat.iter().map(|(_, w)| w.details.clone()).collect()),
+    ];
+
+    let write_all = || -> Result<ParquetExportDescriptor, AppError> {
+        let tables = vec![
+            write_table(&export_dir, "incidents", incidents.len() as i64, incident_columns)?,
+            write_table(&export_dir, "warnings", flat.len() as i64, warning_columns)?,
+        ];
+        let descriptor = ParquetExportDescriptor {
+            schema_version: PARQUET_SCHEMA_VERSION,
+            app_version: app_version.to_string(),
+            export_time: export_time.to_string(),
+            tables,
+        };
+        let path = export_dir.join(PARQUET_DESCRIPTOR_FILENAME);
+        let json = serde_json::to_string_pretty(&descriptor).map_err(|e| {
+            AppError::new("EXPORT_PARQUET_ENCODE_FAILED", "Failed to encode Parquet schema descriptor")
+                .with_details(e.to_string())
+        })?;
+        fs::write(&path, json.as_bytes()).map_err(|e| {
+            AppError::new("EXPORT_PARQUET_WRITE_FAILED", "Failed to write Parquet schema descriptor")
+                .with_details(format!("path={}: {}", path.display(), e))
+        })?;
+        Ok(descriptor)
+    };
+
+    let descriptor = match write_all() {
+        Ok(d) => d,
+        Err(e) => {
+            let _ = fs::remove_dir_all(&export_dir);
+            return Err(e);
+        }
+    };
+
+    Ok(ParquetExportResult {
+        export_dir: export_dir.to_string_lossy().to_string(),
+        incident_count: incidents.len() as i64,
+        warning_count: flat.len() as i64,
+        descriptor,
+    })
+}
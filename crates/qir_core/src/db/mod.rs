@@ -54,6 +54,182 @@ const MIGRATION_0006: (&str, &str) = (
     )),
 );
 
+const MIGRATION_0007: (&str, &str) = (
+    "0007_add_workspace_settings.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0007_add_workspace_settings.sql"
+    )),
+);
+
+const MIGRATION_0008: (&str, &str) = (
+    "0008_add_quarter_freezes.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0008_add_quarter_freezes.sql"
+    )),
+);
+
+const MIGRATION_0009: (&str, &str) = (
+    "0009_add_workspace_lineage.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0009_add_workspace_lineage.sql"
+    )),
+);
+
+const MIGRATION_0010: (&str, &str) = (
+    "0010_add_incident_samples.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0010_add_incident_samples.sql"
+    )),
+);
+
+const MIGRATION_0011: (&str, &str) = (
+    "0011_add_incident_field_history.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0011_add_incident_field_history.sql"
+    )),
+);
+
+const MIGRATION_0012: (&str, &str) = (
+    "0012_add_derived_cache.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0012_add_derived_cache.sql"
+    )),
+);
+
+const MIGRATION_0013: (&str, &str) = (
+    "0013_add_draft_comments.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0013_add_draft_comments.sql"
+    )),
+);
+
+const MIGRATION_0014: (&str, &str) = (
+    "0014_add_draft_quarter_snapshot.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0014_add_draft_quarter_snapshot.sql"
+    )),
+);
+
+const MIGRATION_0015: (&str, &str) = (
+    "0015_add_quarters.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0015_add_quarters.sql"
+    )),
+);
+
+const MIGRATION_0016: (&str, &str) = (
+    "0016_add_incident_assigned_quarter.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0016_add_incident_assigned_quarter.sql"
+    )),
+);
+
+const MIGRATION_0017: (&str, &str) = (
+    "0017_add_action_items.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0017_add_action_items.sql"
+    )),
+);
+
+const MIGRATION_0018: (&str, &str) = (
+    "0018_add_incident_responders.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0018_add_incident_responders.sql"
+    )),
+);
+
+const MIGRATION_0019: (&str, &str) = (
+    "0019_add_incident_severity_grades.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0019_add_incident_severity_grades.sql"
+    )),
+);
+
+const MIGRATION_0020: (&str, &str) = (
+    "0020_add_raw_markup_columns.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0020_add_raw_markup_columns.sql"
+    )),
+);
+
+const MIGRATION_0021: (&str, &str) = (
+    "0021_add_ai_draft_approvals.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0021_add_ai_draft_approvals.sql"
+    )),
+);
+
+const MIGRATION_0022: (&str, &str) = (
+    "0022_add_import_history.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0022_add_import_history.sql"
+    )),
+);
+
+const MIGRATION_0023: (&str, &str) = (
+    "0023_add_timeline_superseded_by.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0023_add_timeline_superseded_by.sql"
+    )),
+);
+
+const MIGRATION_0024: (&str, &str) = (
+    "0024_add_import_batches.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0024_add_import_batches.sql"
+    )),
+);
+
+const MIGRATION_0025: (&str, &str) = (
+    "0025_add_incident_diagrams.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0025_add_incident_diagrams.sql"
+    )),
+);
+
+const MIGRATION_0026: (&str, &str) = (
+    "0026_add_fingerprint_strategy.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0026_add_fingerprint_strategy.sql"
+    )),
+);
+
+const MIGRATION_0027: (&str, &str) = (
+    "0027_add_severity_mappings.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0027_add_severity_mappings.sql"
+    )),
+);
+
+const MIGRATION_0028: (&str, &str) = (
+    "0028_add_entity_aliases.sql",
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../migrations/0028_add_entity_aliases.sql"
+    )),
+);
+
 fn migrations() -> Vec<(&'static str, &'static str)> {
     vec![
         MIGRATION_0001,
@@ -62,6 +238,28 @@ fn migrations() -> Vec<(&'static str, &'static str)> {
         MIGRATION_0004,
         MIGRATION_0005,
         MIGRATION_0006,
+        MIGRATION_0007,
+        MIGRATION_0008,
+        MIGRATION_0009,
+        MIGRATION_0010,
+        MIGRATION_0011,
+        MIGRATION_0012,
+        MIGRATION_0013,
+        MIGRATION_0014,
+        MIGRATION_0015,
+        MIGRATION_0016,
+        MIGRATION_0017,
+        MIGRATION_0018,
+        MIGRATION_0019,
+        MIGRATION_0020,
+        MIGRATION_0021,
+        MIGRATION_0022,
+        MIGRATION_0023,
+        MIGRATION_0024,
+        MIGRATION_0025,
+        MIGRATION_0026,
+        MIGRATION_0027,
+        MIGRATION_0028,
     ]
 }
 
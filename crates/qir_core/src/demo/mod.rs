@@ -1,6 +1,11 @@
 use crate::error::AppError;
 use crate::ingest::jira_csv::{import_jira_csv, JiraCsvMapping, JiraImportSummary};
-use rusqlite::Connection;
+use crate::ingest::slack_transcript::ingest_slack_transcript_text;
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// Quarter the demo dataset falls into under the default calendar.
+pub const GUIDED_TOUR_QUARTER_LABEL: &str = "Q1 2026";
 
 fn demo_csv() -> String {
     // Sanitized, deterministic dataset large enough to make dashboards/reports meaningful.
@@ -53,6 +58,7 @@ pub fn seed_demo_dataset(conn: &mut Connection) -> Result<JiraImportSummary, App
         title: "Summary".to_string(),
         description: None,
         severity: Some("Severity".to_string()),
+        initial_severity: None,
         detection_source: Some("DetectionSource".to_string()),
         vendor: Some("Vendor".to_string()),
         service: Some("Service".to_string()),
@@ -64,8 +70,88 @@ pub fn seed_demo_dataset(conn: &mut Connection) -> Result<JiraImportSummary, App
         ack_ts: Some("AckTs".to_string()),
         mitigate_ts: Some("MitigateTs".to_string()),
         resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
     };
 
     import_jira_csv(conn, &demo_csv(), &mapping)
 }
 
+
+/// Free text for the guided tour's evidence store (transcripts and short write-ups).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DemoEvidenceNote {
+    pub label: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuidedTourDataset {
+    pub import: JiraImportSummary,
+    pub timeline_events: usize,
+    pub evidence_notes: Vec<DemoEvidenceNote>,
+}
+
+/// (demo key, what happened, how it was mitigated, follow-up) for incidents that get a
+/// Slack timeline and evidence in the guided tour. Hours match `demo_csv()`.
+const GUIDED_TOUR_STORIES: [(&str, &str, &str, &str); 4] = [
+    ("IR-001", "payments API returning 502s from AcmeCloud load balancers", "failed over to the secondary region", "Add a synthetic check on the payments load balancer."),
+    ("IR-002", "auth token refresh failing after a ContosoNet certificate rotation", "pinned the previous intermediate certificate", "Track vendor certificate rotations on the change calendar."),
+    ("IR-003", "api latency spike caused by an ExampleVendor rate limit change", "raised the client-side backoff and throttled batch jobs", "Alert on vendor rate-limit headers before requests fail."),
+    ("IR-004", "search index lagging after a WidgetCo storage incident", "paused reindexing and served results from the warm replica", "Document the warm replica failover runbook."),
+];
+
+fn guided_tour_transcript(key: &str, what: &str, mitigation: &str) -> String {
+    // IR-00n starts on day 1 + (n-1)/2 at hour ((n-1)%2)*6, matching demo_csv().
+    let n: usize = key.trim_start_matches("IR-").parse().unwrap_or(1);
+    let prefix = format!("2026-01-{:02}T{:02}", 1 + (n - 1) / 2, ((n - 1) % 2) * 6);
+    [
+        format!("{prefix}:05:00Z - monitor: Alert fired: {what}."),
+        format!("{prefix}:10:00Z - oncall: IT aware, opening incident channel."),
+        format!("{prefix}:15:00Z - oncall: Acked. Investigating impact."),
+        format!("{prefix}:45:00Z - lead: Mitigated: {mitigation}."),
+        format!("{prefix}:55:00Z - lead: Resolved. Monitoring for recurrence."),
+    ]
+    .join("\n")
+}
+
+/// Demo dataset plus Slack-derived timelines and evidence notes for a few incidents, so every
+/// screen has something to show. Run on an empty workspace.
+pub fn seed_guided_tour_dataset(conn: &mut Connection) -> Result<GuidedTourDataset, AppError> {
+    let import = seed_demo_dataset(conn)?;
+    let mut timeline_events = 0usize;
+    let mut evidence_notes = Vec::new();
+
+    for (key, what, mitigation, follow_up) in GUIDED_TOUR_STORIES {
+        let incident_id: i64 = conn
+            .query_row("SELECT id FROM incidents WHERE external_id = ?1", [key], |r| r.get(0))
+            .optional()
+            .map_err(|e| {
+                AppError::new("DB_QUERY_FAILED", "Failed to look up demo incident").with_details(e.to_string())
+            })?
+            .ok_or_else(|| {
+                AppError::new("DB_NOT_FOUND", "Demo incident missing after seeding").with_details(key.to_string())
+            })?;
+        let transcript = guided_tour_transcript(key, what, mitigation);
+        let summary = ingest_slack_transcript_text(conn, Some(incident_id), None, &transcript)?;
+        timeline_events += summary.inserted_events;
+
+        evidence_notes.push(DemoEvidenceNote {
+            label: format!("{key} Slack channel"),
+            text: transcript,
+        });
+        evidence_notes.push(DemoEvidenceNote {
+            label: format!("{key} post-incident review"),
+            text: format!(
+                "{key} post-incident review\n\nWhat happened: {what}.\n\nMitigation: responders {mitigation}.\n\nFollow-up: {follow_up}"
+            ),
+        });
+    }
+
+    Ok(GuidedTourDataset {
+        import,
+        timeline_events,
+        evidence_notes,
+    })
+}
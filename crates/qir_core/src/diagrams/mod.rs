@@ -0,0 +1,285 @@
+use std::fs;
+use std::path::Path;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::AppError;
+
+/// Largest diagram accepted on attach and embedded on export.
+pub const MAX_DIAGRAM_BYTES: u64 = 5 * 1024 * 1024;
+
+/// `artifacts.kind` of images attached through `attach_incident_diagram`.
+pub const DIAGRAM_ARTIFACT_KIND: &str = "diagram";
+
+/// Subfolder of the workspace artifacts dir holding diagram images by content hash.
+pub const DIAGRAMS_DIRNAME: &str = "diagrams";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentDiagram {
+    pub incident_id: i64,
+    pub artifact_id: i64,
+    pub sha256: String,
+    pub filename: Option<String>,
+    pub mime_type: String,
+    pub caption: Option<String>,
+    pub byte_len: i64,
+    pub set_at: String,
+}
+
+impl IncidentDiagram {
+    /// Path of the image relative to the artifacts dir (and to an export bundle's root).
+    pub fn rel_path(&self) -> String {
+        format!("{DIAGRAMS_DIRNAME}/{}.{}", self.sha256, extension_for_mime(&self.mime_type))
+    }
+}
+
+fn invalid(message: &str, details: String) -> AppError {
+    AppError::new("VALIDATION_DIAGRAM_INVALID", message).with_details(details)
+}
+
+/// Raster formats browsers render inline. SVG is left out on purpose: it can carry script.
+fn sniff_image_mime(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else {
+        None
+    }
+}
+
+fn extension_for_mime(mime: &str) -> &'static str {
+    match mime {
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        _ => "bin",
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+const SELECT_DIAGRAM: &str = "SELECT d.incident_id, d.artifact_id, a.sha256, a.filename, a.mime_type, d.caption, d.byte_len, d.set_at
+  FROM incident_diagrams d JOIN artifacts a ON a.id = d.artifact_id";
+
+fn map_row(row: &rusqlite::Row<'_>) -> Result<IncidentDiagram, rusqlite::Error> {
+    Ok(IncidentDiagram {
+        incident_id: row.get(0)?,
+        artifact_id: row.get(1)?,
+        sha256: row.get(2)?,
+        filename: row.get(3)?,
+        mime_type: row.get::<_, Option<String>>(4)?.unwrap_or_default(),
+        caption: row.get(5)?,
+        byte_len: row.get(6)?,
+        set_at: row.get(7)?,
+    })
+}
+
+pub fn get_incident_diagram(conn: &Connection, incident_id: i64) -> Result<Option<IncidentDiagram>, AppError> {
+    conn.query_row(&format!("{SELECT_DIAGRAM} WHERE d.incident_id = ?1"), [incident_id], map_row)
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query incident diagram")
+                .with_details(format!("incident_id={incident_id}; err={e}"))
+        })
+}
+
+fn ensure_incident(conn: &Connection, incident_id: i64) -> Result<(), AppError> {
+    let exists: Option<i64> = conn
+        .query_row("SELECT id FROM incidents WHERE id = ?1", [incident_id], |row| row.get(0))
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to check incident exists").with_details(e.to_string())
+        })?;
+    if exists.is_none() {
+        return Err(AppError::new("DB_NOT_FOUND", "Incident not found for diagram")
+            .with_details(format!("incident_id={incident_id}")));
+    }
+    crate::freeze::ensure_incident_mutable(conn, incident_id)
+}
+
+/// Marks an existing image artifact as the incident's diagram, replacing any previous one.
+/// The image must already be stored under `artifacts_dir` (see `attach_incident_diagram`).
+pub fn set_incident_diagram(
+    conn: &Connection,
+    artifacts_dir: &Path,
+    incident_id: i64,
+    artifact_id: i64,
+    caption: Option<&str>,
+    set_at: &str,
+) -> Result<IncidentDiagram, AppError> {
+    ensure_incident(conn, incident_id)?;
+    let (sha256, mime_type): (String, Option<String>) = conn
+        .query_row("SELECT sha256, mime_type FROM artifacts WHERE id = ?1", [artifact_id], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .optional()
+        .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to query artifact").with_details(e.to_string()))?
+        .ok_or_else(|| {
+            AppError::new("DB_NOT_FOUND", "Artifact not found").with_details(format!("artifact_id={artifact_id}"))
+        })?;
+    let mime_type = mime_type
+        .filter(|m| extension_for_mime(m) != "bin")
+        .ok_or_else(|| {
+            invalid(
+                "Only PNG, JPEG, GIF or WebP artifacts can be an incident diagram",
+                format!("artifact_id={artifact_id}"),
+            )
+        })?;
+
+    let diagram = IncidentDiagram {
+        incident_id,
+        artifact_id,
+        sha256,
+        filename: None,
+        mime_type,
+        caption: None,
+        byte_len: 0,
+        set_at: set_at.to_string(),
+    };
+    let bytes = read_checked(artifacts_dir, &diagram, None, "VALIDATION_DIAGRAM_INVALID")?;
+
+    let caption = caption.map(str::trim).filter(|c| !c.is_empty());
+    conn.execute(
+        "INSERT INTO incident_diagrams(incident_id, artifact_id, caption, byte_len, set_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(incident_id) DO UPDATE SET
+           artifact_id = excluded.artifact_id, caption = excluded.caption,
+           byte_len = excluded.byte_len, set_at = excluded.set_at",
+        params![incident_id, artifact_id, caption, bytes.len() as i64, set_at],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to store incident diagram")
+            .with_details(format!("incident_id={incident_id}; artifact_id={artifact_id}; err={e}"))
+    })?;
+    get_incident_diagram(conn, incident_id)?
+        .ok_or_else(|| AppError::new("DB_NOT_FOUND", "Incident diagram not found after update"))
+}
+
+/// Copies an image file into `artifacts_dir/diagrams/<sha256>.<ext>`, records it as a
+/// `diagram` artifact of the incident (deduplicated by hash) and marks it as the diagram.
+pub fn attach_incident_diagram(
+    conn: &Connection,
+    artifacts_dir: &Path,
+    incident_id: i64,
+    source_path: &Path,
+    caption: Option<&str>,
+    created_at: &str,
+) -> Result<IncidentDiagram, AppError> {
+    ensure_incident(conn, incident_id)?;
+    let len = fs::metadata(source_path)
+        .map_err(|e| {
+            AppError::new("INGEST_DIAGRAM_READ_FAILED", "Failed to read diagram file")
+                .with_details(format!("path={}: {}", source_path.display(), e))
+        })?
+        .len();
+    if len > MAX_DIAGRAM_BYTES {
+        return Err(invalid(
+            "Diagram images must be at most 5 MiB",
+            format!("path={}; bytes={len}; max_bytes={MAX_DIAGRAM_BYTES}", source_path.display()),
+        ));
+    }
+    let bytes = fs::read(source_path).map_err(|e| {
+        AppError::new("INGEST_DIAGRAM_READ_FAILED", "Failed to read diagram file")
+            .with_details(format!("path={}: {}", source_path.display(), e))
+    })?;
+    let mime_type = sniff_image_mime(&bytes).ok_or_else(|| {
+        invalid(
+            "Diagram must be a PNG, JPEG, GIF or WebP image",
+            format!("path={}", source_path.display()),
+        )
+    })?;
+    let sha256 = sha256_hex(&bytes);
+
+    let dir = artifacts_dir.join(DIAGRAMS_DIRNAME);
+    let store_failed = |path: &Path, e: std::io::Error| {
+        AppError::new("INGEST_DIAGRAM_STORE_FAILED", "Failed to store diagram in the artifacts folder")
+            .with_details(format!("path={}: {}", path.display(), e))
+    };
+    fs::create_dir_all(&dir).map_err(|e| store_failed(&dir, e))?;
+    let dest = dir.join(format!("{sha256}.{}", extension_for_mime(mime_type)));
+    // Content addressed: an intact file under this name already holds these bytes.
+    let intact = fs::read(&dest).map(|b| sha256_hex(&b) == sha256).unwrap_or(false);
+    if !intact {
+        let tmp = dest.with_extension("tmp");
+        fs::write(&tmp, &bytes).map_err(|e| store_failed(&tmp, e))?;
+        fs::rename(&tmp, &dest).map_err(|e| {
+            let _ = fs::remove_file(&tmp);
+            store_failed(&dest, e)
+        })?;
+    }
+
+    let filename = source_path.file_name().map(|n| n.to_string_lossy().to_string());
+    conn.execute(
+        "INSERT OR IGNORE INTO artifacts(incident_id, kind, sha256, filename, mime_type, text, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, NULL, ?6)",
+        params![incident_id, DIAGRAM_ARTIFACT_KIND, sha256, filename, mime_type, created_at],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to store diagram artifact")
+            .with_details(format!("incident_id={incident_id}; err={e}"))
+    })?;
+    let artifact_id: i64 = conn
+        .query_row("SELECT id FROM artifacts WHERE sha256 = ?1", [&sha256], |row| row.get(0))
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query diagram artifact").with_details(e.to_string())
+        })?;
+    set_incident_diagram(conn, artifacts_dir, incident_id, artifact_id, caption, created_at)
+}
+
+/// Unmarks the incident's diagram. The artifact and its file are kept.
+pub fn clear_incident_diagram(conn: &Connection, incident_id: i64) -> Result<(), AppError> {
+    ensure_incident(conn, incident_id)?;
+    conn.execute("DELETE FROM incident_diagrams WHERE incident_id = ?1", [incident_id])
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to clear incident diagram")
+                .with_details(format!("incident_id={incident_id}; err={e}"))
+        })?;
+    Ok(())
+}
+
+/// Reads the stored image and checks it against the size limit, the recorded byte length and
+/// the artifact's sha256, so an export never embeds a swapped or truncated file.
+pub fn read_verified_diagram_bytes(artifacts_dir: &Path, diagram: &IncidentDiagram) -> Result<Vec<u8>, AppError> {
+    read_checked(artifacts_dir, diagram, Some(diagram.byte_len), "EXPORT_DIAGRAM_INVALID")
+}
+
+fn read_checked(
+    artifacts_dir: &Path,
+    diagram: &IncidentDiagram,
+    expected_len: Option<i64>,
+    code: &str,
+) -> Result<Vec<u8>, AppError> {
+    let path = artifacts_dir.join(diagram.rel_path());
+    let failed = |message: &str, details: String| {
+        AppError::new(code, message)
+            .with_details(format!("incident_id={}; path={}; {details}", diagram.incident_id, path.display()))
+    };
+    let len = fs::metadata(&path)
+        .map_err(|e| failed("Incident diagram file is missing", format!("err={e}")))?
+        .len();
+    if len > MAX_DIAGRAM_BYTES || expected_len.is_some_and(|n| n != len as i64) {
+        return Err(failed(
+            "Incident diagram file has an unexpected size",
+            format!("bytes={len}; expected_bytes={}", expected_len.unwrap_or(len as i64)),
+        ));
+    }
+    let bytes = fs::read(&path).map_err(|e| failed("Failed to read incident diagram file", format!("err={e}")))?;
+    let actual = sha256_hex(&bytes);
+    if actual != diagram.sha256 {
+        return Err(failed(
+            "Incident diagram file does not match its checksum",
+            format!("expected_sha256={}; actual_sha256={actual}", diagram.sha256),
+        ));
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,334 @@
+use serde::Serialize;
+
+/// Coarse grouping used by the UI to pick an icon/section for an error.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    Ai,
+    Backup,
+    Database,
+    Export,
+    Ingest,
+    Quarter,
+    Query,
+    Report,
+    Settings,
+    Validation,
+    Workspace,
+}
+
+/// Registry entry for one `AppError` code. `retryable` is the default applied by
+/// `AppError::new`; call sites may still override it with `with_retryable`.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct ErrorSpec {
+    pub code: &'static str,
+    pub category: ErrorCategory,
+    pub retryable: bool,
+    pub remediation: &'static str,
+}
+
+impl ErrorSpec {
+    const fn new(
+        code: &'static str,
+        category: ErrorCategory,
+        retryable: bool,
+        remediation: &'static str,
+    ) -> Self {
+        Self {
+            code,
+            category,
+            retryable,
+            remediation,
+        }
+    }
+}
+
+use ErrorCategory::*;
+
+const HINT_DB_IO: &str = "The workspace database could not be read or written. Make sure no other app holds it open and the disk is writable, then retry.";
+const HINT_DB_BUG: &str = "Stored data could not be decoded. If this persists, restore from a recent backup and report the details.";
+const HINT_DEST_EXISTS: &str = "The destination already exists. Choose an empty folder or remove the previous output first.";
+const HINT_DEST_NOT_DIR: &str = "The destination must be a folder. Pick a directory, not a file.";
+const HINT_FS_WRITE: &str = "Writing to the destination failed. Choose a writable folder with enough free space and retry.";
+const HINT_FS_READ: &str = "A file could not be read. Confirm the path exists and is readable, then retry.";
+const HINT_BACKUP_CORRUPT: &str = "The backup folder failed integrity checks. Do not edit backup files in place; pick another backup or create a new one.";
+const HINT_SANITIZED_CORRUPT: &str = "This sanitized dataset folder failed integrity checks (hash/size mismatch). Re-export and try again; do not edit files in-place.";
+const HINT_SANITIZED_UNREDACTED: &str = "The sanitized dataset contains unredacted content. Re-export it with the current app version instead of editing files by hand.";
+const HINT_INPUT: &str = "Check the highlighted input and try again.";
+
+/// Every code constructed via `AppError::new` across the workspace crates must be listed
+/// here; `build.rs` fails the build otherwise. Keep entries sorted by code.
+#[rustfmt::skip]
+pub const ERROR_CATALOG: &[ErrorSpec] = &[
+    ErrorSpec::new("AI_CHUNKING_INVALID", Ai, false, "Chunking settings are out of range. Use 50-8000 target tokens and an overlap of at most half the target."),
+    ErrorSpec::new("AI_CITATION_INVALID", Ai, false, "Citations are invalid. Ensure cited chunk IDs exist and match the selected citation set."),
+    ErrorSpec::new("AI_CITATION_REQUIRED", Ai, false, "Citations are required. Select at least one evidence chunk and ensure the draft includes citation markers [[chunk:<chunk_id>]]."),
+    ErrorSpec::new("AI_DRAFT_EXPORT_FAILED", Ai, false, HINT_FS_WRITE),
+    ErrorSpec::new("AI_DRAFT_FAILED", Ai, false, "Drafting failed. Ensure Ollama is healthy and a local model is installed."),
+    ErrorSpec::new("AI_EMBEDDINGS_FAILED", Ai, false, "Embeddings failed. Ensure your Ollama instance is healthy and supports the embeddings model you selected."),
+    ErrorSpec::new("AI_EMBED_BATCH_INVALID", Ai, false, "Embedding batch size is out of range. Use 1-512 inputs per request."),
+    ErrorSpec::new("AI_EVIDENCE_CONTEXT_INVALID", Ai, false, "The evidence context is invalid. Check the selected incident/quarter and retry."),
+    ErrorSpec::new("AI_EVIDENCE_COPY_FAILED", Ai, true, "The file could not be read from its share after several attempts. Check the share is reachable and the file is not locked, then retry."),
+    ErrorSpec::new("AI_EVIDENCE_COPY_HASH_MISMATCH", Ai, false, "The copied file does not match its checksum. It may still be changing on the share or differ from the expected version; confirm the file, then copy it again."),
+    ErrorSpec::new("AI_EVIDENCE_EMPTY", Ai, false, "No evidence sources are available. Add at least one evidence source before drafting."),
+    ErrorSpec::new("AI_EVIDENCE_NOT_FOUND", Ai, false, "The evidence source or chunk no longer exists. Refresh the evidence list and reselect."),
+    ErrorSpec::new("AI_EVIDENCE_SOURCE_INVALID", Ai, false, "The evidence source is invalid. Check the selected path exists and matches the chosen source type."),
+    ErrorSpec::new("AI_EVIDENCE_STORE_FAILED", Ai, false, "The local evidence store could not be read or written. Confirm the app data directory is writable."),
+    ErrorSpec::new("AI_INDEX_BUILD_FAILED", Ai, false, "Index build failed. Confirm evidence chunks exist and the app data directory is writable."),
+    ErrorSpec::new("AI_INDEX_NOT_READY", Ai, false, "The AI index is not ready. Build evidence chunks first, then build the embeddings index."),
+    ErrorSpec::new("AI_OLLAMA_UNHEALTHY", Ai, true, "Ollama is not reachable on 127.0.0.1. Start Ollama locally, then retry the health check."),
+    ErrorSpec::new("AI_POLICY_APPROVAL_REQUIRED", Ai, false, "The workspace AI usage policy requires sign-off. Approve the draft, then export again."),
+    ErrorSpec::new("AI_POLICY_VIOLATION", Ai, false, "The workspace AI usage policy does not allow this draft. Use an allowed section type and fewer cited chunks, or ask an admin to change the policy."),
+    ErrorSpec::new("AI_REMOTE_NOT_ALLOWED", Ai, false, "Only a local Ollama endpoint on 127.0.0.1 is allowed. Update the endpoint setting."),
+    ErrorSpec::new("AI_RETRIEVAL_FAILED", Ai, false, "Retrieval failed. Ensure the index is built and the query is non-empty, then retry."),
+    ErrorSpec::new("DB_AI_DRAFT_CREATE_FAILED", Ai, false, HINT_DB_IO),
+    ErrorSpec::new("DB_AI_DRAFT_HASH_FAILED", Ai, false, HINT_DB_BUG),
+    ErrorSpec::new("DB_AI_DRAFT_INVALID", Ai, false, "The draft is missing required fields. Regenerate the draft and save again."),
+    ErrorSpec::new("DB_AI_DRAFT_QUERY_FAILED", Ai, false, HINT_DB_IO),
+    ErrorSpec::new("DB_BACKUP_ARTIFACTS_COPY_FAILED", Backup, false, HINT_FS_WRITE),
+    ErrorSpec::new("DB_BACKUP_ARTIFACTS_MKDIR_FAILED", Backup, false, HINT_FS_WRITE),
+    ErrorSpec::new("DB_BACKUP_ARTIFACTS_PATH_FAILED", Backup, false, "An artifact path could not be resolved inside the backup. Create a fresh backup."),
+    ErrorSpec::new("DB_BACKUP_ARTIFACTS_READDIR_FAILED", Backup, false, HINT_FS_READ),
+    ErrorSpec::new("DB_BACKUP_ARTIFACTS_STAT_FAILED", Backup, false, HINT_FS_READ),
+    ErrorSpec::new("DB_BACKUP_BASE_INVALID", Backup, false, "Differential backups are written next to their base backup. Pick an existing backup folder inside your backups folder."),
+    ErrorSpec::new("DB_BACKUP_COUNTS_FAILED", Backup, false, HINT_DB_IO),
+    ErrorSpec::new("DB_BACKUP_DB_CREATE_FAILED", Backup, false, HINT_FS_WRITE),
+    ErrorSpec::new("DB_BACKUP_DB_SNAPSHOT_FAILED", Backup, true, "Snapshotting the workspace database failed. Pause other activity in the app and retry."),
+    ErrorSpec::new("DB_BACKUP_DEST_EXISTS", Backup, false, HINT_DEST_EXISTS),
+    ErrorSpec::new("DB_BACKUP_DEST_NOT_DIR", Backup, false, HINT_DEST_NOT_DIR),
+    ErrorSpec::new("DB_BACKUP_FILE_OPEN_FAILED", Backup, false, HINT_FS_READ),
+    ErrorSpec::new("DB_BACKUP_FILE_READ_FAILED", Backup, false, HINT_FS_READ),
+    ErrorSpec::new("DB_BACKUP_MANIFEST_DECODE_FAILED", Backup, false, HINT_BACKUP_CORRUPT),
+    ErrorSpec::new("DB_BACKUP_MANIFEST_ENCODE_FAILED", Backup, false, HINT_DB_BUG),
+    ErrorSpec::new("DB_BACKUP_MANIFEST_READ_FAILED", Backup, false, "The backup manifest could not be read. Pick the backup folder that contains manifest.json."),
+    ErrorSpec::new("DB_BACKUP_MANIFEST_WRITE_FAILED", Backup, false, HINT_FS_WRITE),
+    ErrorSpec::new("DB_BACKUP_MIGRATIONS_QUERY_FAILED", Backup, false, HINT_DB_IO),
+    ErrorSpec::new("DB_BACKUP_MKDIR_FAILED", Backup, false, HINT_FS_WRITE),
+    ErrorSpec::new("DB_BACKUP_SOURCE_DB_MISSING", Backup, false, "The workspace database file is missing. Reopen the workspace and retry the backup."),
+    ErrorSpec::new("DB_BACKUP_TIME_FAILED", Backup, false, "The system clock could not be read. Check the system date/time settings and retry."),
+    ErrorSpec::new("DB_DECODE_FAILED", Database, false, HINT_DB_BUG),
+    ErrorSpec::new("DB_ENCODE_FAILED", Database, false, HINT_DB_BUG),
+    ErrorSpec::new("DB_IMPORT_BATCH_QUERY_FAILED", Database, false, HINT_DB_IO),
+    ErrorSpec::new("DB_IMPORT_BATCH_WRITE_FAILED", Database, false, HINT_DB_IO),
+    ErrorSpec::new("DB_IMPORT_HISTORY_QUERY_FAILED", Database, false, HINT_DB_IO),
+    ErrorSpec::new("DB_IMPORT_HISTORY_WRITE_FAILED", Database, false, HINT_DB_IO),
+    ErrorSpec::new("DB_MERGE_WRITE_FAILED", Database, false, HINT_DB_IO),
+    ErrorSpec::new("DB_MIGRATIONS_QUERY_FAILED", Database, false, HINT_DB_IO),
+    ErrorSpec::new("DB_MIGRATIONS_TABLE_FAILED", Database, false, HINT_DB_IO),
+    ErrorSpec::new("DB_MIGRATION_FAILED", Database, false, "Upgrading the database schema failed. Restore from a backup or create a fresh workspace with this app version."),
+    ErrorSpec::new("DB_NOT_FOUND", Database, false, "The requested record no longer exists. Refresh the view and try again."),
+    ErrorSpec::new("DB_OPEN_FAILED", Database, true, "The database could not be opened. If it is in use, close other apps using it and retry."),
+    ErrorSpec::new("DB_PATH_FAILED", Database, false, "The app data directory could not be resolved. Check the user profile directory is accessible."),
+    ErrorSpec::new("DB_QUERY_FAILED", Database, false, HINT_DB_IO),
+    ErrorSpec::new("DB_RESTORE_ARTIFACTS_CLEAN_FAILED", Backup, false, HINT_FS_WRITE),
+    ErrorSpec::new("DB_RESTORE_ARTIFACTS_MISSING", Backup, false, HINT_BACKUP_CORRUPT),
+    ErrorSpec::new("DB_RESTORE_ARTIFACT_HASH_MISMATCH", Backup, false, HINT_BACKUP_CORRUPT),
+    ErrorSpec::new("DB_RESTORE_BASE_MISSING", Backup, false, "This differential backup reads unchanged artifacts from earlier backups in the same folder. Put the earlier backups back next to it and retry."),
+    ErrorSpec::new("DB_RESTORE_CONFIRM_REQUIRED", Backup, false, "Restoring overwrites the current workspace. Confirm the restore to continue."),
+    ErrorSpec::new("DB_RESTORE_COPY_FAILED", Backup, false, HINT_FS_WRITE),
+    ErrorSpec::new("DB_RESTORE_DB_HASH_MISMATCH", Backup, false, HINT_BACKUP_CORRUPT),
+    ErrorSpec::new("DB_RESTORE_DB_MISSING", Backup, false, HINT_BACKUP_CORRUPT),
+    ErrorSpec::new("DB_RESTORE_DEST_EXISTS", Backup, false, "Restoring as a new workspace needs a DB path that does not exist yet, in a folder that is not already a portable workspace."),
+    ErrorSpec::new("DB_RESTORE_MKDIR_FAILED", Backup, false, HINT_FS_WRITE),
+    ErrorSpec::new("DB_RESTORE_SWAP_FAILED", Backup, true, "Swapping in the restored database failed. Close other apps using the workspace file and retry."),
+    ErrorSpec::new("DB_RESTORE_UNSUPPORTED_MANIFEST", Backup, false, "This backup was created by an incompatible app version. Restore it with a compatible version."),
+    ErrorSpec::new("DB_TX_FAILED", Database, true, "A database transaction could not complete. Wait for other operations to finish and retry."),
+    ErrorSpec::new("DB_WRITE_FAILED", Database, false, HINT_DB_IO),
+    ErrorSpec::new("EXPORT_BUCKET_DEST_EXISTS", Export, false, HINT_DEST_EXISTS),
+    ErrorSpec::new("EXPORT_BUCKET_DEST_NOT_DIR", Export, false, HINT_DEST_NOT_DIR),
+    ErrorSpec::new("EXPORT_BUCKET_ENCODE_FAILED", Export, false, HINT_DB_BUG),
+    ErrorSpec::new("EXPORT_BUCKET_WRITE_FAILED", Export, false, HINT_FS_WRITE),
+    ErrorSpec::new("EXPORT_DIAGRAM_INVALID", Export, false, "An incident diagram is missing or no longer matches its checksum in the artifacts folder. Attach the image again or clear the diagram, then export."),
+    ErrorSpec::new("EXPORT_INTERCHANGE_DEST_EXISTS", Export, false, HINT_DEST_EXISTS),
+    ErrorSpec::new("EXPORT_INTERCHANGE_DEST_NOT_DIR", Export, false, HINT_DEST_NOT_DIR),
+    ErrorSpec::new("EXPORT_INTERCHANGE_ENCODE_FAILED", Export, false, HINT_DB_BUG),
+    ErrorSpec::new("EXPORT_INTERCHANGE_VERSION_UNSUPPORTED", Export, false, "The other tool accepts no interchange version this app writes. Update whichever side is older."),
+    ErrorSpec::new("EXPORT_INTERCHANGE_WRITE_FAILED", Export, false, HINT_FS_WRITE),
+    ErrorSpec::new("EXPORT_PARQUET_DEST_EXISTS", Export, false, HINT_DEST_EXISTS),
+    ErrorSpec::new("EXPORT_PARQUET_DEST_NOT_DIR", Export, false, HINT_DEST_NOT_DIR),
+    ErrorSpec::new("EXPORT_PARQUET_ENCODE_FAILED", Export, false, HINT_DB_BUG),
+    ErrorSpec::new("EXPORT_PARQUET_MKDIR_FAILED", Export, false, HINT_FS_WRITE),
+    ErrorSpec::new("EXPORT_PARQUET_WRITE_FAILED", Export, false, HINT_FS_WRITE),
+    ErrorSpec::new("EXPORT_SANITIZED_DEST_EXISTS", Export, false, HINT_DEST_EXISTS),
+    ErrorSpec::new("EXPORT_SANITIZED_DEST_NOT_DIR", Export, false, HINT_DEST_NOT_DIR),
+    ErrorSpec::new("EXPORT_SANITIZED_ENCODE_FAILED", Export, false, HINT_DB_BUG),
+    ErrorSpec::new("EXPORT_SANITIZED_FILE_OPEN_FAILED", Export, false, HINT_FS_READ),
+    ErrorSpec::new("EXPORT_SANITIZED_FILE_READ_FAILED", Export, false, HINT_FS_READ),
+    ErrorSpec::new("EXPORT_SANITIZED_FINALIZE_FAILED", Export, false, HINT_FS_WRITE),
+    ErrorSpec::new("EXPORT_SANITIZED_MKDIR_FAILED", Export, false, HINT_FS_WRITE),
+    ErrorSpec::new("EXPORT_SANITIZED_STAGING_CLEAN_FAILED", Export, false, HINT_FS_WRITE),
+    ErrorSpec::new("EXPORT_SANITIZED_WRITE_FAILED", Export, false, HINT_FS_WRITE),
+    ErrorSpec::new("EXPORT_SITE_DEST_EXISTS", Export, false, HINT_DEST_EXISTS),
+    ErrorSpec::new("EXPORT_SITE_DEST_NOT_DIR", Export, false, HINT_DEST_NOT_DIR),
+    ErrorSpec::new("EXPORT_SITE_FINALIZE_FAILED", Export, false, HINT_FS_WRITE),
+    ErrorSpec::new("EXPORT_SITE_MKDIR_FAILED", Export, false, HINT_FS_WRITE),
+    ErrorSpec::new("EXPORT_SITE_WRITE_FAILED", Export, false, HINT_FS_WRITE),
+    ErrorSpec::new("INGEST_ARTIFACTS_DIR_READ_FAILED", Ingest, false, HINT_FS_READ),
+    ErrorSpec::new("INGEST_ARTIFACTS_DIR_STORE_FAILED", Ingest, false, "A file could not be copied into the workspace artifacts folder. Check the folder is writable with enough free space, then retry."),
+    ErrorSpec::new("INGEST_CONFLUENCE_EMPTY", Ingest, false, "No summary, timeline table or action items were found. Export the postmortem page as HTML or storage-format XML and check it uses Summary, Timeline and Action items headings."),
+    ErrorSpec::new("INGEST_CONFLUENCE_INSERT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_CSV_DECODE_FAILED", Ingest, false, "The file could not be decoded as text. Save it as UTF-8 CSV and import again."),
+    ErrorSpec::new("INGEST_CSV_HEADERS_FAILED", Ingest, false, "The CSV header row could not be read. Export the CSV again with a header row and UTF-8 encoding."),
+    ErrorSpec::new("INGEST_CSV_INSERT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_CSV_PARSE_FAILED", Ingest, false, "The CSV could not be parsed. Check for unbalanced quotes or a non-CSV file and export again."),
+    ErrorSpec::new("INGEST_CSV_UPDATE_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_DIAGRAM_READ_FAILED", Ingest, false, HINT_FS_READ),
+    ErrorSpec::new("INGEST_DIAGRAM_STORE_FAILED", Ingest, false, "The image could not be copied into the workspace artifacts folder. Check the folder is writable with enough free space, then retry."),
+    ErrorSpec::new("INGEST_EMAIL_EMPTY", Ingest, false, "No messages were found. Pick an mbox file, a single .eml file, or a folder containing .eml files."),
+    ErrorSpec::new("INGEST_EMAIL_INSERT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_EMAIL_READ_FAILED", Ingest, false, HINT_FS_READ),
+    ErrorSpec::new("INGEST_FILE_BATCH_DUPLICATE_FILE", Ingest, false, "Files in one batch are reported by file name. Rename or remove one of the files that share a name and retry."),
+    ErrorSpec::new("INGEST_FILE_BATCH_EMPTY", Ingest, false, "Pick at least one CSV or JSON export to import."),
+    ErrorSpec::new("INGEST_FILE_BATCH_FORMAT_UNKNOWN", Ingest, false, "The file was not recognised as a Jira, PagerDuty, ServiceNow, incident.io, FireHydrant or Rootly export. Import it from its own import section instead."),
+    ErrorSpec::new("INGEST_FILE_BATCH_READ_FAILED", Ingest, false, HINT_FS_READ),
+    ErrorSpec::new("INGEST_IMPORT_BATCH_ALREADY_UNDONE", Ingest, false, "This import was already undone. Refresh the import list."),
+    ErrorSpec::new("INGEST_IMPORT_BATCH_NOT_FOUND", Ingest, false, "The import batch no longer exists. Refresh the import list and pick another."),
+    ErrorSpec::new("INGEST_IMPORT_CANCELLED", Ingest, true, "The import was cancelled and nothing was written. Start it again when ready."),
+    ErrorSpec::new("INGEST_IMPORT_UNDO_CONFLICT", Ingest, false, "Incidents from this import were edited since, changed by a later import, or are in a frozen quarter. Undo later imports first or unfreeze the quarter; nothing was changed."),
+    ErrorSpec::new("INGEST_INCIDENT_IO_CSV_HEADERS_FAILED", Ingest, false, "The CSV header row could not be read. Export the incidents from incident.io again with its header row."),
+    ErrorSpec::new("INGEST_INCIDENT_IO_PARSE_FAILED", Ingest, false, "The file is not a readable incident.io export. Use the CSV export or the incidents API JSON response."),
+    ErrorSpec::new("INGEST_INCIDENT_IO_RESPONDER_INSERT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_INTERCHANGE_DECODE_FAILED", Ingest, false, "The interchange file does not match its declared schema version. Re-export it from the producing tool."),
+    ErrorSpec::new("INGEST_INTERCHANGE_INSERT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_INTERCHANGE_INVALID", Ingest, false, "An incident in the interchange file is invalid. Fix the listed incident in the producing tool and export again."),
+    ErrorSpec::new("INGEST_INTERCHANGE_READ_FAILED", Ingest, false, HINT_FS_READ),
+    ErrorSpec::new("INGEST_INTERCHANGE_SCHEMA_MISMATCH", Ingest, false, "This file is not an IncidentReview interchange document. Pick a file exported with the interchange format."),
+    ErrorSpec::new("INGEST_INTERCHANGE_VERSION_UNSUPPORTED", Ingest, false, "This interchange file uses a schema version this app cannot read. Export it again requesting a supported version."),
+    ErrorSpec::new("INGEST_JIRA_API_AUTH_FAILED", Ingest, false, "Jira rejected the stored token. Create a new API token (Cloud: with the account email; Data Center: a personal access token) and save the connection again."),
+    ErrorSpec::new("INGEST_JIRA_API_CONFIG_INVALID", Ingest, false, "Check the Jira base URL (https://), token and JQL, then retry."),
+    ErrorSpec::new("INGEST_JIRA_API_CREDENTIALS_FAILED", Ingest, false, "The local Jira connection file could not be read or written. Check app data permissions, or clear and save the connection again."),
+    ErrorSpec::new("INGEST_JIRA_API_DECODE_FAILED", Ingest, false, "Jira returned an unexpected response. Check that the base URL points at the Jira site itself, not a proxy or login page."),
+    ErrorSpec::new("INGEST_JIRA_API_REQUEST_FAILED", Ingest, true, "Jira could not be reached or returned an error. Check network access to the Jira site and retry."),
+    ErrorSpec::new("INGEST_JIRA_JSON_INSERT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_JIRA_JSON_PARSE_FAILED", Ingest, false, "The file is not a Jira JSON export. Export issues as JSON (an \"issues\" array, optionally with the changelog expanded) and retry."),
+    ErrorSpec::new("INGEST_JIRA_SESSION_DUPLICATE_FILE", Ingest, false, "Each file can only be added to an import session once. Remove the duplicate and retry."),
+    ErrorSpec::new("INGEST_JIRA_SESSION_EMPTY", Ingest, false, "Add at least one CSV file to the import session."),
+    ErrorSpec::new("INGEST_MARKDOWN_EMPTY", Ingest, false, "No Summary, Impact, Timeline or Action items section was found. Use # headings with those names and bullets for the timeline and action items."),
+    ErrorSpec::new("INGEST_MARKDOWN_INSERT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_MARKDOWN_READ_FAILED", Ingest, false, HINT_FS_READ),
+    ErrorSpec::new("INGEST_PAGERDUTY_CSV_HEADERS_FAILED", Ingest, false, "The CSV header row could not be read. Export the incidents CSV from PagerDuty again with its header row."),
+    ErrorSpec::new("INGEST_PAGERDUTY_CSV_PARSE_FAILED", Ingest, false, "The CSV could not be parsed. Check for unbalanced quotes or a non-CSV file and export again."),
+    ErrorSpec::new("INGEST_RETRO_TOOL_PARSE_FAILED", Ingest, false, "The file is not a readable FireHydrant or Rootly incident export. Use the incidents API JSON response."),
+    ErrorSpec::new("INGEST_SANITIZED_DB_NOT_EMPTY", Ingest, false, "This import refuses to run on a non-empty DB. Restore or seed into a fresh DB first, then retry."),
+    ErrorSpec::new("INGEST_SANITIZED_DECODE_FAILED", Ingest, false, HINT_SANITIZED_CORRUPT),
+    ErrorSpec::new("INGEST_SANITIZED_EVENT_INCIDENT_UNKNOWN", Ingest, false, HINT_SANITIZED_CORRUPT),
+    ErrorSpec::new("INGEST_SANITIZED_EVENT_NOT_REDACTED", Ingest, false, HINT_SANITIZED_UNREDACTED),
+    ErrorSpec::new("INGEST_SANITIZED_FILE_MISSING", Ingest, false, HINT_SANITIZED_CORRUPT),
+    ErrorSpec::new("INGEST_SANITIZED_FILE_OPEN_FAILED", Ingest, false, HINT_FS_READ),
+    ErrorSpec::new("INGEST_SANITIZED_FILE_READ_FAILED", Ingest, false, HINT_FS_READ),
+    ErrorSpec::new("INGEST_SANITIZED_INCIDENT_COUNT_MISMATCH", Ingest, false, HINT_SANITIZED_CORRUPT),
+    ErrorSpec::new("INGEST_SANITIZED_INSERT_EVENT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_SANITIZED_INSERT_INCIDENT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_SANITIZED_MANIFEST_BYTES_MISMATCH", Ingest, false, HINT_SANITIZED_CORRUPT),
+    ErrorSpec::new("INGEST_SANITIZED_MANIFEST_HASH_MISMATCH", Ingest, false, HINT_SANITIZED_CORRUPT),
+    ErrorSpec::new("INGEST_SANITIZED_MANIFEST_MISSING_FILE", Ingest, false, HINT_SANITIZED_CORRUPT),
+    ErrorSpec::new("INGEST_SANITIZED_MANIFEST_VERSION_MISMATCH", Ingest, false, "This sanitized dataset export is from an incompatible version. Re-export the sanitized dataset using a compatible app version."),
+    ErrorSpec::new("INGEST_SANITIZED_METRICS_MISMATCH", Ingest, false, "This sanitized dataset failed deterministic metrics verification. Re-export and try again; if it persists, treat the dataset as corrupted."),
+    ErrorSpec::new("INGEST_SANITIZED_NOT_DIR", Ingest, false, "Pick the sanitized export folder (the one containing manifest.json), not a file."),
+    ErrorSpec::new("INGEST_SANITIZED_READ_FAILED", Ingest, false, HINT_FS_READ),
+    ErrorSpec::new("INGEST_SERVICENOW_CSV_HEADERS_FAILED", Ingest, false, "The CSV header row could not be read. Export the incident list from ServiceNow again with its header row."),
+    ErrorSpec::new("INGEST_SERVICENOW_CSV_PARSE_FAILED", Ingest, false, "The CSV could not be parsed. Check for unbalanced quotes or a non-CSV file and export again."),
+    ErrorSpec::new("INGEST_SLACK_EXPORT_CHANNEL_NOT_FOUND", Ingest, false, "The selected channel is not in this export. Preview the export again and pick from the listed channels."),
+    ErrorSpec::new("INGEST_SLACK_EXPORT_INVALID", Ingest, false, "This does not look like a Slack workspace export. Select the export .zip or its extracted folder containing users.json and channel folders."),
+    ErrorSpec::new("INGEST_SLACK_EXPORT_READ_FAILED", Ingest, false, HINT_FS_READ),
+    ErrorSpec::new("INGEST_SLACK_INCIDENT_CREATE_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_SLACK_INCIDENT_NOT_FOUND", Ingest, false, "The target incident no longer exists. Pick another incident or create a new one from the transcript."),
+    ErrorSpec::new("INGEST_SLACK_INSERT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_SLACK_JSON_INVALID", Ingest, false, "The Slack JSON does not look like a message export. Export the channel again or paste plain text."),
+    ErrorSpec::new("INGEST_SLACK_JSON_PARSE_FAILED", Ingest, false, "The Slack JSON could not be parsed. Make sure the whole file was pasted or selected."),
+    ErrorSpec::new("INGEST_SLACK_TARGET_AMBIGUOUS", Ingest, false, "Choose either an existing incident or a new incident title, not both."),
+    ErrorSpec::new("INGEST_SLACK_TARGET_REQUIRED", Ingest, false, "Choose an existing incident or enter a title for a new one."),
+    ErrorSpec::new("INGEST_SLACK_TITLE_REQUIRED", Ingest, false, "Enter a non-empty title for the new incident."),
+    ErrorSpec::new("INGEST_STATUSPAGE_INCIDENT_NOT_FOUND", Ingest, false, "That Statuspage incident is not in this export. Preview the export again and pick from the listed incidents."),
+    ErrorSpec::new("INGEST_STATUSPAGE_INSERT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_STATUSPAGE_PARSE_FAILED", Ingest, false, "The file is not a Statuspage incident history. Use incidents.json from the status page or the incidents API response."),
+    ErrorSpec::new("INGEST_XLSX_PARSE_FAILED", Ingest, false, "The spreadsheet could not be read. Save it as .xlsx (or .xls/.ods) with a header row in the first non-empty row and retry."),
+    ErrorSpec::new("INGEST_XLSX_READ_FAILED", Ingest, false, HINT_FS_READ),
+    ErrorSpec::new("INGEST_XLSX_SHEET_NOT_FOUND", Ingest, false, "That worksheet is not in the workbook. Preview again and pick one of the listed sheets."),
+    ErrorSpec::new("INGEST_ZOOM_VTT_CALL_START_INVALID", Ingest, false, "Enter the call start as an RFC3339 timestamp with a zone, e.g. 2026-01-05T14:00:00Z."),
+    ErrorSpec::new("INGEST_ZOOM_VTT_INSERT_FAILED", Ingest, false, HINT_DB_IO),
+    ErrorSpec::new("INGEST_ZOOM_VTT_PARSE_FAILED", Ingest, false, "The file is not a WebVTT transcript. Download the recording transcript (.vtt) from Zoom and try again."),
+    ErrorSpec::new("INVALID_PAGINATION", Validation, false, "Page size must be between 1 and 100."),
+    ErrorSpec::new("QUARTER_ALREADY_EXISTS", Quarter, false, "A quarter with this label is already defined. Edit it instead of creating a new one."),
+    ErrorSpec::new("QUARTER_ALREADY_FROZEN", Quarter, false, "This quarter is already frozen. Unfreeze it first if you need to change it."),
+    ErrorSpec::new("QUARTER_FROZEN", Quarter, false, "The incident belongs to a frozen quarter. Unfreeze the quarter (with a reason) before editing."),
+    ErrorSpec::new("QUARTER_NOT_FOUND", Quarter, false, "The quarter is not defined. Create it under Quarters, or use a label from the workspace calendar (Q1 2026, or FY2027 Q1 with a fiscal year configured)."),
+    ErrorSpec::new("QUARTER_NOT_FROZEN", Quarter, false, "This quarter is not frozen, so there is nothing to unfreeze."),
+    ErrorSpec::new("QUARTER_OVERLAP", Quarter, false, "Quarter date ranges may not overlap. Adjust the start or end so each incident falls in one quarter."),
+    ErrorSpec::new("QUERY_EXECUTE_FAILED", Query, false, "The query failed while running. Check column and table names and retry."),
+    ErrorSpec::new("QUERY_PREPARE_FAILED", Query, false, "The query has a syntax error. Check the SQL and retry."),
+    ErrorSpec::new("QUERY_STATEMENT_REJECTED", Query, false, "Only a single read-only SELECT (optionally starting with WITH) without comments is allowed."),
+    ErrorSpec::new("QUERY_TIMEOUT", Query, false, "The query ran past its time limit. Narrow it with filters or a LIMIT, or raise timeout_ms."),
+    ErrorSpec::new("REPORT_DETERMINISM_COPY_FAILED", Report, false, HINT_DB_IO),
+    ErrorSpec::new("REPORT_DIGEST_TIME_FAILED", Report, false, HINT_DB_BUG),
+    ErrorSpec::new("REPORT_DRAFT_NOT_FOUND", Report, false, "A selected AI draft no longer exists. Deselect it and export again."),
+    ErrorSpec::new("REPORT_EXPORT_DEST_EXISTS", Report, false, HINT_DEST_EXISTS),
+    ErrorSpec::new("REPORT_EXPORT_DEST_NOT_DIR", Report, false, HINT_DEST_NOT_DIR),
+    ErrorSpec::new("REPORT_EXPORT_WRITE_FAILED", Report, false, HINT_FS_WRITE),
+    ErrorSpec::new("REPORT_MANIFEST_ENCODE_FAILED", Report, false, HINT_DB_BUG),
+    ErrorSpec::new("REPORT_MANIFEST_INVALID", Report, false, "The report manifest is not valid JSON. Pick the .manifest.json written next to the report."),
+    ErrorSpec::new("REPORT_MANIFEST_READ_FAILED", Report, false, HINT_FS_READ),
+    ErrorSpec::new("REPORT_MANIFEST_UNSUPPORTED_VERSION", Report, false, "This report manifest was written by an incompatible app version."),
+    ErrorSpec::new("SETTINGS_BUNDLE_ENCODE_FAILED", Settings, false, HINT_DB_BUG),
+    ErrorSpec::new("SETTINGS_BUNDLE_INVALID", Settings, false, "The settings bundle is malformed. Export it again from the source workspace."),
+    ErrorSpec::new("SETTINGS_BUNDLE_READ_FAILED", Settings, false, HINT_FS_READ),
+    ErrorSpec::new("SETTINGS_BUNDLE_SIGNATURE_INVALID", Settings, false, "The bundle signature does not match. It was modified or signed with a different key; do not import it."),
+    ErrorSpec::new("SETTINGS_BUNDLE_SIGNING_KEY_REQUIRED", Settings, false, "This bundle is signed. Provide the signing key to verify it."),
+    ErrorSpec::new("SETTINGS_BUNDLE_UNSUPPORTED_VERSION", Settings, false, "This settings bundle was written by an incompatible app version."),
+    ErrorSpec::new("SETTINGS_EXPORT_DEST_EXISTS", Settings, false, HINT_DEST_EXISTS),
+    ErrorSpec::new("SETTINGS_EXPORT_DEST_NOT_DIR", Settings, false, HINT_DEST_NOT_DIR),
+    ErrorSpec::new("SETTINGS_EXPORT_WRITE_FAILED", Settings, false, HINT_FS_WRITE),
+    ErrorSpec::new("VALIDATION_ACTION_ITEM_INVALID", Validation, false, "Action items need a title and RFC3339 timestamps, and cannot close before they were created."),
+    ErrorSpec::new("VALIDATION_AI_USAGE_POLICY_INVALID", Validation, false, "The chunk limit must be 1-200, at least one section type must stay allowed, and the disclaimer must be at most 1000 characters."),
+    ErrorSpec::new("VALIDATION_ARTIFACTS_DIR_INVALID", Validation, false, "Pick a folder, not a single file, to add its files as artifacts."),
+    ErrorSpec::new("VALIDATION_AS_OF_INVALID", Validation, false, "Enter the as-of time as an RFC3339 timestamp, for example 2026-01-31T00:00:00Z."),
+    ErrorSpec::new("VALIDATION_BENCHMARK_DATASETS_INVALID", Validation, false, "Pick 1-26 different sanitized dataset folders for the benchmark."),
+    ErrorSpec::new("VALIDATION_BUCKET_KEY_UNKNOWN", Validation, false, "The dashboard changed since this bucket was shown, or the quarter scope differs. Refresh the dashboard and export again."),
+    ErrorSpec::new("VALIDATION_BUSINESS_HOURS_INVALID", Validation, false, "Business hours need a start hour before the end hour, workdays numbered 1 (Monday) to 7 (Sunday), and a UTC offset within +/-14:00."),
+    ErrorSpec::new("VALIDATION_COMPUTED_METRIC_INVALID", Validation, false, "Computed metrics need a unique snake_case key, a label, and two different incident timestamp fields (start_ts, first_observed_ts, it_awareness_ts, ack_ts, mitigate_ts, resolve_ts)."),
+    ErrorSpec::new("VALIDATION_DASHBOARD_CONFIG_INVALID", Validation, false, "Top-N must be positive, duration bucket bounds must be positive and strictly increasing, and the after-hours pain multiplier must be 100-1000%."),
+    ErrorSpec::new("VALIDATION_DIAGRAM_INVALID", Validation, false, "Diagrams must be PNG, JPEG, GIF or WebP images of at most 5 MiB."),
+    ErrorSpec::new("VALIDATION_DIGEST_INVALID", Validation, false, "Give the digest a sender and at least one recipient as plain email addresses, and a week ending as an RFC3339 timestamp."),
+    ErrorSpec::new("VALIDATION_DISPLAY_TIMEZONE_INVALID", Validation, false, "Give the display timezone a short label (up to 64 characters, no '|') and a UTC offset within +/-14:00, or clear it to show UTC only."),
+    ErrorSpec::new("VALIDATION_DRAFT_COMMENT_INVALID", Validation, false, "Comments need an author, some text, and a paragraph that exists in the draft."),
+    ErrorSpec::new("VALIDATION_ENTITY_ALIAS_INVALID", Validation, false, "Map a non-blank alias (for example AWS) to a different canonical name that is not itself an alias."),
+    ErrorSpec::new("VALIDATION_FINGERPRINT_STRATEGY_INVALID", Validation, false, "Pick at least one fingerprint field and list each field once."),
+    ErrorSpec::new("VALIDATION_FISCAL_CALENDAR_INVALID", Validation, false, "Pick the month your fiscal year starts in (1 = January for calendar quarters)."),
+    ErrorSpec::new("VALIDATION_MAPPING_PROFILE_INVALID", Validation, false, "The mapping profile is incomplete. Give it a name and map at least the title column."),
+    ErrorSpec::new("VALIDATION_MERGE_SAME_INCIDENT", Validation, false, "Pick two different incidents to merge."),
+    ErrorSpec::new("VALIDATION_PAIN_FORMULA_INVALID", Validation, false, "Pain weights must be 0-1000% and not both zero, severity multipliers 0-1000% on trimmed severity names, and the per-incident cap positive."),
+    ErrorSpec::new("VALIDATION_QUARTER_INVALID", Validation, false, "Give the quarter a label and RFC3339 start/end times with the start before the end."),
+    ErrorSpec::new("VALIDATION_QUARTER_LABEL_INVALID", Validation, false, "Use a defined quarter, a label like Q1 2026, or FY2027 Q1 with a fiscal calendar."),
+    ErrorSpec::new("VALIDATION_QUERY_LIMIT_INVALID", Validation, false, HINT_INPUT),
+    ErrorSpec::new("VALIDATION_QUICK_CAPTURE_INVALID", Validation, false, "Quick capture needs a title and live notes need text; a start time, when given, must be RFC3339."),
+    ErrorSpec::new("VALIDATION_RESPONDER_INVALID", Validation, false, "Responders need a name and RFC3339 engaged/released times, released no earlier than engaged."),
+    ErrorSpec::new("VALIDATION_SAMPLE_SEED_REQUIRED", Validation, false, "Enter a seed so the sample can be reproduced."),
+    ErrorSpec::new("VALIDATION_SAMPLE_SIZE_INVALID", Validation, false, HINT_INPUT),
+    ErrorSpec::new("VALIDATION_SETTING_INVALID", Validation, false, HINT_INPUT),
+    ErrorSpec::new("VALIDATION_SETTING_KEY_INVALID", Validation, false, "This setting key is not recognized by this app version."),
+    ErrorSpec::new("VALIDATION_SEVERITY_GRADE_INVALID", Validation, false, "Enter the severity the incident was first declared with, for example SEV2."),
+    ErrorSpec::new("VALIDATION_SEVERITY_MAPPING_INVALID", Validation, false, "Map a non-blank source value (for example P1) to a severity that is not itself mapped elsewhere."),
+    ErrorSpec::new("VALIDATION_TS_FORMAT_INVALID", Validation, false, "Use strftime-style codes such as %d/%b/%y %I:%M %p; supported codes are %Y %y %m %b %B %d %e %H %I %M %S %f %p %a %A %z %T %R %F."),
+    ErrorSpec::new("VALIDATION_UNLOCK_REASON_REQUIRED", Validation, false, "Enter a reason for unfreezing; it is recorded in the audit log."),
+    ErrorSpec::new("VALIDATION_UTC_OFFSET_INVALID", Validation, false, "Use a UTC offset in minutes between -840 and 840, for example 60 for UTC+01:00."),
+    ErrorSpec::new("WORKSPACE_BRANCH_FAILED", Workspace, false, HINT_FS_WRITE),
+    ErrorSpec::new("WORKSPACE_BRANCH_LABEL_REQUIRED", Workspace, false, "Enter a label for the new workspace branch."),
+    ErrorSpec::new("WORKSPACE_CONFIG_STALE", Workspace, false, "The last workspace DB was moved or deleted. Pick one of the suggested locations, open another workspace, or continue with the default one."),
+    ErrorSpec::new("WORKSPACE_CREATE_FAILED", Workspace, false, "Failed to create the workspace DB. Choose a writable folder and a non-existing filename."),
+    ErrorSpec::new("WORKSPACE_DB_NOT_FOUND", Workspace, false, "Workspace DB file not found. Create a new workspace or pick an existing DB file."),
+    ErrorSpec::new("WORKSPACE_INVALID_PATH", Workspace, false, "Pick a valid workspace path. For create: choose an existing folder. For open: choose an existing SQLite DB file."),
+    ErrorSpec::new("WORKSPACE_MIGRATION_FAILED", Workspace, false, "Failed to migrate the workspace DB schema. If this DB was created by an incompatible version, create a fresh workspace."),
+    ErrorSpec::new("WORKSPACE_OPEN_FAILED", Workspace, true, "Failed to open the workspace DB. If it is in use, close other apps using it and retry."),
+    ErrorSpec::new("WORKSPACE_PATHS_INVALID", Workspace, false, "Pick an absolute folder path for app data. The destination must be empty and must not contain the current folder."),
+    ErrorSpec::new("WORKSPACE_PATHS_RELOCATE_FAILED", Workspace, false, "Moving app data failed and the original folder was kept. Check the destination is writable with enough free space, then retry."),
+    ErrorSpec::new("WORKSPACE_PERSIST_FAILED", Workspace, false, "Failed to persist workspace selection locally. The app may not remember the last workspace."),
+];
+
+pub fn lookup_error(code: &str) -> Option<&'static ErrorSpec> {
+    ERROR_CATALOG.iter().find(|s| s.code == code)
+}
+
+/// The full catalog, sorted by code, for the `errors_catalog` command.
+pub fn error_catalog() -> Vec<ErrorSpec> {
+    ERROR_CATALOG.to_vec()
+}
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+mod catalog;
+
+pub use catalog::{error_catalog, lookup_error, ErrorCategory, ErrorSpec, ERROR_CATALOG};
+
 /// Single structured error shape used across backend layers and exposed over RPC.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct AppError {
@@ -11,12 +15,15 @@ pub struct AppError {
 }
 
 impl AppError {
+    /// `retryable` starts from the catalog default for `code` (false for unknown codes).
     pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        let code = code.into();
+        let retryable = lookup_error(&code).is_some_and(|s| s.retryable);
         Self {
-            code: code.into(),
+            code,
             message: message.into(),
             details: None,
-            retryable: false,
+            retryable,
         }
     }
 
@@ -29,6 +36,11 @@ impl AppError {
         self.retryable = retryable;
         self
     }
+
+    /// Catalog entry for this error's code, if registered.
+    pub fn spec(&self) -> Option<&'static ErrorSpec> {
+        lookup_error(&self.code)
+    }
 }
 
 impl fmt::Display for AppError {
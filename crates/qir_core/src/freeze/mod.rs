@@ -0,0 +1,340 @@
+use std::collections::HashSet;
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::error::AppError;
+use crate::quarters::assignment::QuarterAssigner;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuarterFreeze {
+    pub quarter_label: String,
+    pub frozen_at: String,
+    pub frozen_by: Option<String>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuarterFreezeAuditEntry {
+    pub id: i64,
+    pub quarter_label: String,
+    pub action: String,
+    pub actor: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: String,
+}
+
+/// Calendar quarter label ("Q1 2026") for a canonical RFC3339 timestamp, evaluated in UTC.
+/// Returns None for unparseable input; callers treat that as "no quarter" (never frozen).
+pub fn calendar_quarter_label(ts: &str) -> Option<String> {
+    let dt = OffsetDateTime::parse(ts, &Rfc3339).ok()?.to_offset(UtcOffset::UTC);
+    let q = (u8::from(dt.month()) - 1) / 3 + 1;
+    Some(format!("Q{} {}", q, dt.year()))
+}
+
+/// The quarter an incident is reported in: start_ts, falling back to first_observed_ts
+/// (the same anchor order used for MTTR).
+pub fn incident_quarter_label(start_ts: Option<&str>, first_observed_ts: Option<&str>) -> Option<String> {
+    start_ts
+        .and_then(calendar_quarter_label)
+        .or_else(|| first_observed_ts.and_then(calendar_quarter_label))
+}
+
+/// Accepts calendar labels ("Q<1-4> <YYYY>") only. Freezes go through `freezable_quarter_label`,
+/// which also admits fiscal and defined quarter labels.
+pub fn validate_quarter_label(label: &str) -> Result<(), AppError> {
+    let invalid = || {
+        AppError::new(
+            "VALIDATION_QUARTER_LABEL_INVALID",
+            "Quarter label must look like \"Q1 2026\"",
+        )
+        .with_details(format!("quarter_label={label}"))
+    };
+    let (q, year) = label.split_once(' ').ok_or_else(invalid)?;
+    let q_ok = matches!(q, "Q1" | "Q2" | "Q3" | "Q4");
+    let year_ok = year.len() == 4 && year.chars().all(|c| c.is_ascii_digit());
+    if !q_ok || !year_ok {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+/// A freeze label must name a quarter incidents can be assigned to: a defined quarter, a calendar
+/// quarter, or a fiscal quarter of the configured calendar ("FY2027 Q1").
+fn freezable_quarter_label(conn: &Connection, label: &str) -> Result<(), AppError> {
+    crate::quarters::resolve_quarter_scope(conn, label).map(|_| ()).map_err(|e| {
+        if e.code != "QUARTER_NOT_FOUND" {
+            return e;
+        }
+        AppError::new(
+            "VALIDATION_QUARTER_LABEL_INVALID",
+            "Quarter label must be a defined, calendar (\"Q1 2026\") or fiscal (\"FY2027 Q1\") quarter",
+        )
+        .with_details(format!("quarter_label={label}"))
+    })
+}
+
+fn insert_audit(
+    conn: &Connection,
+    quarter_label: &str,
+    action: &str,
+    actor: Option<&str>,
+    reason: Option<&str>,
+) -> Result<(), AppError> {
+    conn.execute(
+        "INSERT INTO quarter_freeze_audit(quarter_label, action, actor, reason, created_at) VALUES (?1, ?2, ?3, ?4, strftime('%Y-%m-%dT%H:%M:%fZ','now'))",
+        rusqlite::params![quarter_label, action, actor, reason],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to record quarter freeze audit entry")
+            .with_details(format!("quarter_label={quarter_label}; action={action}; err={e}"))
+    })?;
+    Ok(())
+}
+
+pub fn get_quarter_freeze(conn: &Connection, quarter_label: &str) -> Result<Option<QuarterFreeze>, AppError> {
+    conn.query_row(
+        "SELECT quarter_label, frozen_at, frozen_by, note FROM quarter_freezes WHERE quarter_label = ?1",
+        [quarter_label],
+        |row| {
+            Ok(QuarterFreeze {
+                quarter_label: row.get(0)?,
+                frozen_at: row.get(1)?,
+                frozen_by: row.get(2)?,
+                note: row.get(3)?,
+            })
+        },
+    )
+    .optional()
+    .map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query quarter freeze")
+            .with_details(format!("quarter_label={quarter_label}; err={e}"))
+    })
+}
+
+pub fn list_quarter_freezes(conn: &Connection) -> Result<Vec<QuarterFreeze>, AppError> {
+    let mut stmt = conn
+        .prepare("SELECT quarter_label, frozen_at, frozen_by, note FROM quarter_freezes ORDER BY quarter_label ASC")
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare quarter freeze list query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(QuarterFreeze {
+                quarter_label: row.get(0)?,
+                frozen_at: row.get(1)?,
+                frozen_by: row.get(2)?,
+                note: row.get(3)?,
+            })
+        })
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query quarter freezes")
+                .with_details(e.to_string())
+        })?;
+    let mut out = Vec::new();
+    for r in rows {
+        out.push(r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode quarter freeze row")
+                .with_details(e.to_string())
+        })?);
+    }
+    Ok(out)
+}
+
+pub fn frozen_quarter_labels(conn: &Connection) -> Result<HashSet<String>, AppError> {
+    Ok(list_quarter_freezes(conn)?
+        .into_iter()
+        .map(|f| f.quarter_label)
+        .collect())
+}
+
+pub fn list_quarter_freeze_audit(
+    conn: &Connection,
+    quarter_label: Option<&str>,
+) -> Result<Vec<QuarterFreezeAuditEntry>, AppError> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, quarter_label, action, actor, reason, created_at FROM quarter_freeze_audit
+             WHERE (?1 IS NULL OR quarter_label = ?1) ORDER BY id ASC",
+        )
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare quarter freeze audit query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt
+        .query_map([quarter_label], |row| {
+            Ok(QuarterFreezeAuditEntry {
+                id: row.get(0)?,
+                quarter_label: row.get(1)?,
+                action: row.get(2)?,
+                actor: row.get(3)?,
+                reason: row.get(4)?,
+                created_at: row.get(5)?,
+            })
+        })
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query quarter freeze audit")
+                .with_details(e.to_string())
+        })?;
+    let mut out = Vec::new();
+    for r in rows {
+        out.push(r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode quarter freeze audit row")
+                .with_details(e.to_string())
+        })?);
+    }
+    Ok(out)
+}
+
+pub fn freeze_quarter(
+    conn: &mut Connection,
+    quarter_label: &str,
+    actor: Option<&str>,
+    note: Option<&str>,
+) -> Result<QuarterFreeze, AppError> {
+    freezable_quarter_label(conn, quarter_label)?;
+    if get_quarter_freeze(conn, quarter_label)?.is_some() {
+        return Err(AppError::new("QUARTER_ALREADY_FROZEN", "Quarter is already frozen")
+            .with_details(format!("quarter_label={quarter_label}")));
+    }
+
+    let tx = conn.transaction().map_err(|e| {
+        AppError::new("DB_TX_FAILED", "Failed to start quarter freeze transaction")
+            .with_details(e.to_string())
+    })?;
+    tx.execute(
+        "INSERT INTO quarter_freezes(quarter_label, frozen_at, frozen_by, note) VALUES (?1, strftime('%Y-%m-%dT%H:%M:%fZ','now'), ?2, ?3)",
+        rusqlite::params![quarter_label, actor, note],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to freeze quarter")
+            .with_details(format!("quarter_label={quarter_label}; err={e}"))
+    })?;
+    insert_audit(&tx, quarter_label, "freeze", actor, note)?;
+    tx.commit().map_err(|e| {
+        AppError::new("DB_TX_FAILED", "Failed to commit quarter freeze")
+            .with_details(e.to_string())
+    })?;
+
+    get_quarter_freeze(conn, quarter_label)?.ok_or_else(|| {
+        AppError::new("DB_NOT_FOUND", "Quarter freeze not found after write")
+            .with_details(format!("quarter_label={quarter_label}"))
+    })
+}
+
+/// Explicit unlock. A non-empty reason is required and is kept in the audit log.
+pub fn unlock_quarter(
+    conn: &mut Connection,
+    quarter_label: &str,
+    actor: Option<&str>,
+    reason: &str,
+) -> Result<(), AppError> {
+    if reason.trim().is_empty() {
+        return Err(AppError::new(
+            "VALIDATION_UNLOCK_REASON_REQUIRED",
+            "Unlocking a frozen quarter requires a reason",
+        )
+        .with_details(format!("quarter_label={quarter_label}")));
+    }
+    if get_quarter_freeze(conn, quarter_label)?.is_none() {
+        return Err(AppError::new("QUARTER_NOT_FROZEN", "Quarter is not frozen")
+            .with_details(format!("quarter_label={quarter_label}")));
+    }
+
+    let tx = conn.transaction().map_err(|e| {
+        AppError::new("DB_TX_FAILED", "Failed to start quarter unlock transaction")
+            .with_details(e.to_string())
+    })?;
+    tx.execute(
+        "DELETE FROM quarter_freezes WHERE quarter_label = ?1",
+        [quarter_label],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to unlock quarter")
+            .with_details(format!("quarter_label={quarter_label}; err={e}"))
+    })?;
+    insert_audit(&tx, quarter_label, "unlock", actor, Some(reason.trim()))?;
+    tx.commit().map_err(|e| {
+        AppError::new("DB_TX_FAILED", "Failed to commit quarter unlock")
+            .with_details(e.to_string())
+    })?;
+    Ok(())
+}
+
+/// Frozen quarter labels plus the workspace quarter assignment rule, loaded once for checks
+/// over many incidents.
+pub(crate) struct FrozenQuarters {
+    labels: HashSet<String>,
+    assigner: QuarterAssigner,
+}
+
+impl FrozenQuarters {
+    pub(crate) fn load(conn: &Connection) -> Result<Self, AppError> {
+        Ok(Self {
+            labels: frozen_quarter_labels(conn)?,
+            assigner: QuarterAssigner::load(conn)?,
+        })
+    }
+
+    /// The frozen quarter a stored incident is assigned to, if any.
+    pub(crate) fn for_incident(&self, conn: &Connection, incident_id: i64) -> Result<Option<String>, AppError> {
+        if self.labels.is_empty() {
+            return Ok(None);
+        }
+        Ok(assigned_quarter(conn, &self.assigner, incident_id)?.filter(|q| self.labels.contains(q)))
+    }
+
+    /// The frozen quarter the assignment rule places these timestamps in, for incidents that
+    /// are about to be written.
+    pub(crate) fn for_timestamps(
+        &self,
+        start_ts: Option<&str>,
+        first_observed_ts: Option<&str>,
+        resolve_ts: Option<&str>,
+    ) -> Option<String> {
+        self.assigner
+            .label_for_timestamps(start_ts, first_observed_ts, resolve_ts)
+            .filter(|q| self.labels.contains(q))
+    }
+}
+
+/// An incident's materialized `assigned_quarter_label`. Rows written since the last refresh
+/// (or before the column existed) have none yet and are labeled by the rule directly.
+fn assigned_quarter(conn: &Connection, assigner: &QuarterAssigner, incident_id: i64) -> Result<Option<String>, AppError> {
+    type Row = (Option<String>, Option<String>, Option<String>, Option<String>);
+    let row: Option<Row> = conn
+        .query_row(
+            "SELECT assigned_quarter_label, start_ts, first_observed_ts, resolve_ts FROM incidents WHERE id = ?1",
+            [incident_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to load incident for freeze check")
+                .with_details(format!("incident_id={incident_id}; err={e}"))
+        })?;
+    Ok(row.and_then(|(assigned, start, first_observed, resolve)| {
+        assigned.or_else(|| assigner.label_for_timestamps(start.as_deref(), first_observed.as_deref(), resolve.as_deref()))
+    }))
+}
+
+pub(crate) fn quarter_frozen_error(quarter_label: &str, context: &str) -> AppError {
+    AppError::new(
+        "QUARTER_FROZEN",
+        "Incident belongs to a frozen quarter; unlock the quarter before modifying it",
+    )
+    .with_details(format!("quarter_label={quarter_label}; {context}"))
+}
+
+/// Guard used by every mutating repo/ingest path that touches an existing incident. Checks
+/// the quarter the incident is assigned to, so it follows the fiscal calendar, defined
+/// quarters and the assignment anchor.
+pub fn ensure_incident_mutable(conn: &Connection, incident_id: i64) -> Result<(), AppError> {
+    if let Some(label) = FrozenQuarters::load(conn)?.for_incident(conn, incident_id)? {
+        return Err(quarter_frozen_error(&label, &format!("incident_id={incident_id}")));
+    }
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::domain::Incident;
+use crate::error::AppError;
+
+/// One field-level change recorded by the `incident_field_history` triggers.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentFieldChange {
+    pub id: i64,
+    pub incident_id: i64,
+    pub field: String,
+    pub change_kind: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub effective_at: String,
+}
+
+/// Canonicalizes an as-of timestamp to the millisecond UTC form used by `effective_at`
+/// (`YYYY-MM-DDTHH:MM:SS.mmmZ`) so that plain string comparison orders correctly.
+pub fn normalize_as_of(as_of: &str) -> Result<String, AppError> {
+    let dt = OffsetDateTime::parse(as_of.trim(), &Rfc3339).map_err(|e| {
+        AppError::new("VALIDATION_AS_OF_INVALID", "As-of timestamp must be RFC3339")
+            .with_details(format!("as_of={as_of}; err={e}"))
+    })?;
+    let utc = dt.to_offset(UtcOffset::UTC);
+    Ok(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        utc.year(),
+        u8::from(utc.month()),
+        utc.day(),
+        utc.hour(),
+        utc.minute(),
+        utc.second(),
+        utc.millisecond()
+    ))
+}
+
+fn map_change(row: &rusqlite::Row<'_>) -> rusqlite::Result<IncidentFieldChange> {
+    Ok(IncidentFieldChange {
+        id: row.get(0)?,
+        incident_id: row.get(1)?,
+        field: row.get(2)?,
+        change_kind: row.get(3)?,
+        old_value: row.get(4)?,
+        new_value: row.get(5)?,
+        effective_at: row.get(6)?,
+    })
+}
+
+/// Full field-level history for one incident, oldest first.
+pub fn incident_history(conn: &Connection, incident_id: i64) -> Result<Vec<IncidentFieldChange>, AppError> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, incident_id, field, change_kind, old_value, new_value, effective_at
+             FROM incident_field_history WHERE incident_id = ?1 ORDER BY effective_at ASC, id ASC",
+        )
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare incident history query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt.query_map([incident_id], map_change).map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query incident history")
+            .with_details(format!("incident_id={incident_id}; err={e}"))
+    })?;
+    let mut out = Vec::new();
+    for r in rows {
+        out.push(r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode incident history row")
+                .with_details(e.to_string())
+        })?);
+    }
+    Ok(out)
+}
+
+fn parse_int_field(incident_id: i64, field: &str, v: Option<&String>) -> Result<Option<i64>, AppError> {
+    match v {
+        None => Ok(None),
+        Some(s) => s.parse::<i64>().map(Some).map_err(|e| {
+            AppError::new("DB_DECODE_FAILED", "Failed to decode integer field from incident history")
+                .with_details(format!("incident_id={incident_id}; field={field}; value={s}; err={e}"))
+        }),
+    }
+}
+
+/// Reconstructs every incident as it was believed to be at `as_of` (latest change per field
+/// with effective_at <= as_of). Incidents created after, or deleted before, `as_of` are
+/// excluded. Ordered by id.
+pub fn incidents_as_of(conn: &Connection, as_of: &str) -> Result<Vec<Incident>, AppError> {
+    let as_of = normalize_as_of(as_of)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, incident_id, field, change_kind, old_value, new_value, effective_at
+             FROM incident_field_history WHERE effective_at <= ?1 ORDER BY incident_id ASC, effective_at ASC, id ASC",
+        )
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare as-of history query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt.query_map([&as_of], map_change).map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query incident history as of timestamp")
+            .with_details(format!("as_of={as_of}; err={e}"))
+    })?;
+
+    // incident_id -> (field -> latest change); later rows overwrite earlier ones.
+    let mut state: BTreeMap<i64, BTreeMap<String, IncidentFieldChange>> = BTreeMap::new();
+    for r in rows {
+        let change = r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode incident history row")
+                .with_details(e.to_string())
+        })?;
+        state
+            .entry(change.incident_id)
+            .or_default()
+            .insert(change.field.clone(), change);
+    }
+
+    let mut out = Vec::new();
+    for (id, fields) in state {
+        // title is NOT NULL, so its latest change tells us whether the row existed at as_of.
+        let Some(title_change) = fields.get("title") else {
+            continue;
+        };
+        if title_change.change_kind == "delete" {
+            continue;
+        }
+        let get = |f: &str| fields.get(f).and_then(|c| c.new_value.clone());
+        out.push(Incident {
+            id,
+            external_id: get("external_id"),
+            fingerprint: get("fingerprint").unwrap_or_default(),
+            title: get("title").unwrap_or_default(),
+            description: get("description"),
+            severity: get("severity"),
+            detection_source: get("detection_source"),
+            vendor: get("vendor"),
+            service: get("service"),
+            impact_pct: parse_int_field(id, "impact_pct", get("impact_pct").as_ref())?,
+            service_health_pct: parse_int_field(id, "service_health_pct", get("service_health_pct").as_ref())?,
+            start_ts: get("start_ts"),
+            first_observed_ts: get("first_observed_ts"),
+            it_awareness_ts: get("it_awareness_ts"),
+            ack_ts: get("ack_ts"),
+            mitigate_ts: get("mitigate_ts"),
+            resolve_ts: get("resolve_ts"),
+            start_ts_raw: get("start_ts_raw"),
+            first_observed_ts_raw: get("first_observed_ts_raw"),
+            it_awareness_ts_raw: get("it_awareness_ts_raw"),
+            ack_ts_raw: get("ack_ts_raw"),
+            mitigate_ts_raw: get("mitigate_ts_raw"),
+            resolve_ts_raw: get("resolve_ts_raw"),
+        });
+    }
+    Ok(out)
+}
+
+/// Builds an in-memory workspace holding the incidents as of `as_of`, so every existing
+/// deterministic generator (dashboards, validation, report) can run unchanged against it.
+pub fn materialize_as_of(conn: &Connection, as_of: &str) -> Result<Connection, AppError> {
+    let incidents = incidents_as_of(conn, as_of)?;
+    materialize_incidents(&incidents)
+}
+
+/// In-memory workspace containing exactly `incidents` (ids preserved).
+pub(crate) fn materialize_incidents(incidents: &[Incident]) -> Result<Connection, AppError> {
+    let mut snap = crate::db::open_in_memory()?;
+    crate::db::migrate(&mut snap)?;
+
+    let tx = snap.transaction().map_err(|e| {
+        AppError::new("DB_TX_FAILED", "Failed to start as-of snapshot transaction")
+            .with_details(e.to_string())
+    })?;
+    for i in incidents {
+        tx.execute(
+            r#"
+      INSERT INTO incidents(
+        id, external_id, fingerprint, title, description, severity,
+        detection_source, vendor, service,
+        impact_pct, service_health_pct,
+        start_ts, first_observed_ts, it_awareness_ts, ack_ts, mitigate_ts, resolve_ts,
+        start_ts_raw, first_observed_ts_raw, it_awareness_ts_raw, ack_ts_raw, mitigate_ts_raw, resolve_ts_raw,
+        ingested_at
+      ) VALUES (
+        ?1, ?2, ?3, ?4, ?5, ?6,
+        ?7, ?8, ?9,
+        ?10, ?11,
+        ?12, ?13, ?14, ?15, ?16, ?17,
+        ?18, ?19, ?20, ?21, ?22, ?23,
+        strftime('%Y-%m-%dT%H:%M:%fZ','now')
+      )
+      "#,
+            rusqlite::params![
+                i.id,
+                i.external_id,
+                i.fingerprint,
+                i.title,
+                i.description,
+                i.severity,
+                i.detection_source,
+                i.vendor,
+                i.service,
+                i.impact_pct,
+                i.service_health_pct,
+                i.start_ts,
+                i.first_observed_ts,
+                i.it_awareness_ts,
+                i.ack_ts,
+                i.mitigate_ts,
+                i.resolve_ts,
+                i.start_ts_raw,
+                i.first_observed_ts_raw,
+                i.it_awareness_ts_raw,
+                i.ack_ts_raw,
+                i.mitigate_ts_raw,
+                i.resolve_ts_raw,
+            ],
+        )
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to write as-of incident snapshot")
+                .with_details(format!("incident_id={}; err={}", i.id, e))
+        })?;
+    }
+    tx.commit().map_err(|e| {
+        AppError::new("DB_TX_FAILED", "Failed to commit as-of snapshot")
+            .with_details(e.to_string())
+    })?;
+    Ok(snap)
+}
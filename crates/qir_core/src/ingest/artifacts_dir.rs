@@ -0,0 +1,235 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+
+/// `artifacts.kind` of files copied in by `ingest_artifacts_dir`.
+pub const FILE_ARTIFACT_KIND: &str = "file";
+
+/// Subfolder of the workspace artifacts dir holding ingested files by content hash.
+pub const FILES_DIRNAME: &str = "files";
+
+/// Larger files are skipped with a warning; response folders are screenshots and logs, not
+/// disk images.
+pub const MAX_ARTIFACT_FILE_BYTES: u64 = 50 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IngestedArtifact {
+    pub artifact_id: i64,
+    /// Path inside the ingested folder, `/`-separated.
+    pub source_rel_path: String,
+    pub sha256: String,
+    pub mime_type: String,
+    pub byte_len: u64,
+    /// Path of the stored copy relative to the artifacts dir.
+    pub stored_rel_path: String,
+    /// True when a file with the same content was already stored; the existing row is reused
+    /// and keeps its original incident link.
+    pub duplicate: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArtifactsDirIngestSummary {
+    pub incident_id: i64,
+    pub inserted: usize,
+    pub duplicates: usize,
+    /// Files left out (too large or unreadable); each has a warning.
+    pub skipped: usize,
+    pub artifacts: Vec<IngestedArtifact>,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+fn read_failed(path: &Path, e: impl ToString) -> AppError {
+    AppError::new("INGEST_ARTIFACTS_DIR_READ_FAILED", "Failed to read artifacts folder")
+        .with_details(format!("path={}: {}", path.display(), e.to_string()))
+}
+
+fn store_failed(path: &Path, e: impl ToString) -> AppError {
+    AppError::new("INGEST_ARTIFACTS_DIR_STORE_FAILED", "Failed to copy file into the artifacts folder")
+        .with_details(format!("path={}: {}", path.display(), e.to_string()))
+}
+
+fn mime_for_extension(path: &Path) -> &'static str {
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_ascii_lowercase();
+    match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "pdf" => "application/pdf",
+        "json" => "application/json",
+        "csv" => "text/csv",
+        "md" => "text/markdown",
+        "html" | "htm" => "text/html",
+        "txt" | "log" | "out" => "text/plain",
+        "eml" => "message/rfc822",
+        "zip" => "application/zip",
+        "gz" => "application/gzip",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Regular files under `dir`, sorted, skipping hidden entries (`.DS_Store`, `.git`) and
+/// symlinks so the walk stays inside the selected folder.
+fn collect_files(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), AppError> {
+    let mut entries = fs::read_dir(dir)
+        .map_err(|e| read_failed(dir, e))?
+        .map(|e| e.map_err(|e| read_failed(dir, e)))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|e| e.file_name());
+    for entry in entries {
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let file_type = entry.file_type().map_err(|e| read_failed(&entry.path(), e))?;
+        if file_type.is_dir() {
+            collect_files(&entry.path(), out)?;
+        } else if file_type.is_file() {
+            out.push(entry.path());
+        }
+    }
+    Ok(())
+}
+
+fn ensure_incident(conn: &Connection, incident_id: i64) -> Result<(), AppError> {
+    let exists: Option<i64> = conn
+        .query_row("SELECT id FROM incidents WHERE id = ?1", [incident_id], |row| row.get(0))
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to check incident exists").with_details(e.to_string())
+        })?;
+    if exists.is_none() {
+        return Err(AppError::new("DB_NOT_FOUND", "Incident not found for artifacts")
+            .with_details(format!("incident_id={incident_id}")));
+    }
+    crate::freeze::ensure_incident_mutable(conn, incident_id)
+}
+
+/// Writes `bytes` to `dest` unless an intact copy is already there (the name is the hash).
+fn store_copy(dest: &Path, bytes: &[u8], sha256: &str) -> Result<(), AppError> {
+    let intact = fs::read(dest).map(|b| hex::encode(Sha256::digest(&b)) == sha256).unwrap_or(false);
+    if intact {
+        return Ok(());
+    }
+    let tmp = dest.with_extension("tmp");
+    fs::write(&tmp, bytes).map_err(|e| store_failed(&tmp, e))?;
+    fs::rename(&tmp, dest).map_err(|e| {
+        let _ = fs::remove_file(&tmp);
+        store_failed(dest, e)
+    })
+}
+
+/// Copies every file under `source_dir` into `artifacts_dir/files/<sha256>[.ext]` and records
+/// one `file` artifact per distinct content, linked to the incident. Rows are written in one
+/// transaction; files whose content is already stored reuse the existing artifact.
+pub fn ingest_artifacts_dir(
+    conn: &mut Connection,
+    artifacts_dir: &Path,
+    incident_id: i64,
+    source_dir: &Path,
+    created_at: &str,
+) -> Result<ArtifactsDirIngestSummary, AppError> {
+    if !source_dir.is_dir() {
+        return Err(AppError::new("VALIDATION_ARTIFACTS_DIR_INVALID", "Selected path is not a folder")
+            .with_details(format!("path={}", source_dir.display())));
+    }
+    ensure_incident(conn, incident_id)?;
+    let mut files = Vec::new();
+    collect_files(source_dir, &mut files)?;
+
+    let store_dir = artifacts_dir.join(FILES_DIRNAME);
+    fs::create_dir_all(&store_dir).map_err(|e| store_failed(&store_dir, e))?;
+
+    let mut summary = ArtifactsDirIngestSummary {
+        incident_id,
+        inserted: 0,
+        duplicates: 0,
+        skipped: 0,
+        artifacts: Vec::new(),
+        warnings: Vec::new(),
+    };
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to start transaction").with_details(e.to_string()))?;
+    for path in files {
+        let rel = path
+            .strip_prefix(source_dir)
+            .unwrap_or(&path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let bytes = match fs::metadata(&path).map(|m| m.len()) {
+            Ok(len) if len > MAX_ARTIFACT_FILE_BYTES => {
+                summary.skipped += 1;
+                summary.warnings.push(
+                    ValidationWarning::new("INGEST_ARTIFACT_FILE_TOO_LARGE", "File is larger than the artifact limit; skipped")
+                        .with_details(format!("file={rel}; bytes={len}; max_bytes={MAX_ARTIFACT_FILE_BYTES}")),
+                );
+                continue;
+            }
+            Ok(_) => fs::read(&path),
+            Err(e) => Err(e),
+        };
+        let bytes = match bytes {
+            Ok(b) => b,
+            Err(e) => {
+                summary.skipped += 1;
+                summary.warnings.push(
+                    ValidationWarning::new("INGEST_ARTIFACT_FILE_UNREADABLE", "File could not be read; skipped")
+                        .with_details(format!("file={rel}; error={e}")),
+                );
+                continue;
+            }
+        };
+
+        let sha256 = hex::encode(Sha256::digest(&bytes));
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .filter(|e| !e.is_empty() && e.chars().all(|c| c.is_ascii_alphanumeric()))
+            .map(|e| format!(".{}", e.to_ascii_lowercase()))
+            .unwrap_or_default();
+        let stored_rel_path = format!("{FILES_DIRNAME}/{sha256}{ext}");
+        store_copy(&artifacts_dir.join(&stored_rel_path), &bytes, &sha256)?;
+
+        let mime_type = mime_for_extension(&path);
+        let filename = path.file_name().map(|n| n.to_string_lossy().to_string());
+        let inserted = tx
+            .execute(
+                "INSERT OR IGNORE INTO artifacts(incident_id, kind, sha256, filename, mime_type, text, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, NULL, ?6)",
+                params![incident_id, FILE_ARTIFACT_KIND, sha256, filename, mime_type, created_at],
+            )
+            .map_err(|e| {
+                AppError::new("DB_WRITE_FAILED", "Failed to store file artifact")
+                    .with_details(format!("file={rel}; err={e}"))
+            })?;
+        let artifact_id: i64 = tx
+            .query_row("SELECT id FROM artifacts WHERE sha256 = ?1", [&sha256], |row| row.get(0))
+            .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to query file artifact").with_details(e.to_string()))?;
+        let duplicate = inserted == 0;
+        if duplicate {
+            summary.duplicates += 1;
+        } else {
+            summary.inserted += 1;
+        }
+        summary.artifacts.push(IngestedArtifact {
+            artifact_id,
+            source_rel_path: rel,
+            sha256,
+            mime_type: mime_type.to_string(),
+            byte_len: bytes.len() as u64,
+            stored_rel_path,
+            duplicate,
+        });
+    }
+    tx.commit()
+        .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to commit transaction").with_details(e.to_string()))?;
+    Ok(summary)
+}
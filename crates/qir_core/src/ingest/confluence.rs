@@ -0,0 +1,567 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+
+use super::postmortem::{section_for, write_postmortem, ActionEntry, PostmortemRecord, Section, TimelineEntry};
+use super::slack_transcript::ensure_target_incident;
+
+/// `timeline_events.source` of rows read from a Confluence timeline table.
+pub const CONFLUENCE_EVENT_SOURCE: &str = "confluence";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConfluenceTimelineRow {
+    /// Time cell as written on the page.
+    pub time: String,
+    pub text: String,
+    pub author: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConfluenceActionItem {
+    pub title: String,
+    pub owner: Option<String>,
+    pub done: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConfluencePostmortem {
+    /// "storage" (the page's XML storage format) or "html" (an HTML export).
+    pub format: String,
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    pub timeline: Vec<ConfluenceTimelineRow>,
+    pub action_items: Vec<ConfluenceActionItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConfluenceIngestSummary {
+    pub incident_id: i64,
+    pub incident_created: bool,
+    /// True when the page summary was written to an empty incident description.
+    pub description_filled: bool,
+    pub inserted_events: usize,
+    pub inserted_action_items: usize,
+    pub skipped_duplicates: usize,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+// --- Minimal XHTML reader -------------------------------------------------------------------
+//
+// Both export flavours are XHTML-ish markup; the storage format adds `ac:`/`ri:` namespaced
+// elements. A lenient tag reader is enough: no scripts run and nothing is rendered.
+
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+#[derive(Debug)]
+enum Node {
+    Element(Element),
+    Text(String),
+}
+
+impl Element {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+
+    fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|c| match c {
+            Node::Element(e) => Some(e),
+            Node::Text(_) => None,
+        })
+    }
+
+    /// Descendants named `name`, in document order, not descending into matches.
+    fn find_all<'a>(&'a self, name: &str, out: &mut Vec<&'a Element>) {
+        for e in self.elements() {
+            if e.name == name {
+                out.push(e);
+            } else {
+                e.find_all(name, out);
+            }
+        }
+    }
+
+    /// Text content with whitespace collapsed. Macro parameters are configuration, not text.
+    fn text(&self) -> String {
+        let mut raw = String::new();
+        self.collect_text(&mut raw);
+        raw.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn collect_text(&self, out: &mut String) {
+        if self.name == "ac:parameter" || self.name == "ac:task-status" || self.name == "ac:task-id" {
+            return;
+        }
+        if self.name == "ri:user" {
+            if let Some(user) = self.attr("ri:username") {
+                out.push_str(&format!(" @{user} "));
+            }
+            return;
+        }
+        for c in &self.children {
+            match c {
+                Node::Text(t) => out.push_str(t),
+                Node::Element(e) => {
+                    if matches!(e.name.as_str(), "br" | "p" | "div" | "li" | "td" | "th" | "tr") {
+                        out.push(' ');
+                    }
+                    e.collect_text(out);
+                }
+            }
+        }
+    }
+}
+
+const VOID_ELEMENTS: [&str; 8] = ["br", "hr", "img", "meta", "link", "input", "col", "wbr"];
+
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(idx) = rest.find('&') {
+        out.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+        let Some(end) = rest.bytes().take(12).position(|b| b == b';') else {
+            out.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            "ndash" => Some('–'),
+            "mdash" => Some('—'),
+            "lsquo" => Some('‘'),
+            "rsquo" => Some('’'),
+            "ldquo" => Some('“'),
+            "rdquo" => Some('”'),
+            "hellip" => Some('…'),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|h| u32::from_str_radix(h, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|d| d.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn parse_attrs(s: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let mut rest = s.trim();
+    while !rest.is_empty() {
+        let name_end = rest.find(|c: char| c == '=' || c.is_whitespace()).unwrap_or(rest.len());
+        let name = rest[..name_end].to_lowercase();
+        rest = rest[name_end..].trim_start();
+        let mut value = String::new();
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            if let Some(q) = after.chars().next().filter(|c| *c == '"' || *c == '\'') {
+                let body = &after[1..];
+                let end = body.find(q).unwrap_or(body.len());
+                value = decode_entities(&body[..end]);
+                rest = body.get(end + 1..).unwrap_or("").trim_start();
+            } else {
+                let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                value = decode_entities(&after[..end]);
+                rest = after[end..].trim_start();
+            }
+        }
+        if !name.is_empty() {
+            attrs.push((name, value));
+        }
+    }
+    attrs
+}
+
+fn close_into_parent(stack: &mut Vec<Element>) {
+    if stack.len() > 1 {
+        let done = stack.pop().expect("non-empty stack");
+        stack.last_mut().expect("parent").children.push(Node::Element(done));
+    }
+}
+
+/// Builds a tree from the markup, closing unclosed `p`/`li`/`td`/`tr` the way browsers do and
+/// ignoring stray end tags. Comments, doctype, scripts and styles are dropped; CDATA is text.
+fn parse_markup(src: &str) -> Element {
+    let mut stack = vec![Element {
+        name: "#root".to_string(),
+        ..Element::default()
+    }];
+    let mut rest = src;
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            stack.last_mut().expect("root").children.push(Node::Text(decode_entities(rest)));
+            break;
+        };
+        if lt > 0 {
+            stack.last_mut().expect("root").children.push(Node::Text(decode_entities(&rest[..lt])));
+        }
+        rest = &rest[lt..];
+        if let Some(body) = rest.strip_prefix("<!--") {
+            rest = body.find("-->").map(|i| &body[i + 3..]).unwrap_or("");
+            continue;
+        }
+        if let Some(body) = rest.strip_prefix("<![CDATA[") {
+            let end = body.find("]]>").unwrap_or(body.len());
+            stack.last_mut().expect("root").children.push(Node::Text(body[..end].to_string()));
+            rest = body.get(end + 3..).unwrap_or("");
+            continue;
+        }
+        let Some(gt) = rest.find('>') else {
+            stack.last_mut().expect("root").children.push(Node::Text(decode_entities(rest)));
+            break;
+        };
+        let tag = &rest[1..gt];
+        rest = &rest[gt + 1..];
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_lowercase();
+            if stack.iter().skip(1).any(|e| e.name == name) {
+                while stack.last().is_some_and(|e| e.name != name) {
+                    close_into_parent(&mut stack);
+                }
+                close_into_parent(&mut stack);
+            }
+            continue;
+        }
+        let self_closing = tag.ends_with('/');
+        let tag = tag.trim_end_matches('/');
+        let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+        let name = tag[..name_end].to_lowercase();
+        if name == "script" || name == "style" {
+            let close = format!("</{name}");
+            // ASCII-only lowercasing keeps byte offsets valid for `rest`.
+            let end = rest.to_ascii_lowercase().find(&close).unwrap_or(rest.len());
+            rest = &rest[end..];
+            rest = rest.find('>').map(|i| &rest[i + 1..]).unwrap_or("");
+            continue;
+        }
+        // Implied end tags.
+        let implied: &[&str] = match name.as_str() {
+            "p" => &["p"],
+            "li" => &["li", "p"],
+            "td" | "th" => &["td", "th", "p"],
+            "tr" => &["tr", "td", "th", "p"],
+            _ => &[],
+        };
+        while stack.len() > 1 && implied.contains(&stack.last().expect("stack").name.as_str()) {
+            close_into_parent(&mut stack);
+        }
+        let el = Element {
+            attrs: parse_attrs(&tag[name_end..]),
+            name,
+            children: Vec::new(),
+        };
+        if self_closing || VOID_ELEMENTS.contains(&el.name.as_str()) {
+            stack.last_mut().expect("root").children.push(Node::Element(el));
+        } else {
+            stack.push(el);
+        }
+    }
+    while stack.len() > 1 {
+        close_into_parent(&mut stack);
+    }
+    stack.pop().expect("root")
+}
+
+// --- Page structure -------------------------------------------------------------------------
+
+enum Block<'a> {
+    Heading(String),
+    Paragraph(String),
+    List(&'a Element),
+    Table(&'a Element),
+}
+
+/// Headings, paragraphs, lists and tables in document order; containers (divs, panels, macro
+/// bodies) are walked through.
+fn blocks<'a>(el: &'a Element, out: &mut Vec<Block<'a>>) {
+    for c in &el.children {
+        match c {
+            Node::Text(t) if !t.trim().is_empty() && el.name != "#root" => {
+                out.push(Block::Paragraph(t.split_whitespace().collect::<Vec<_>>().join(" ")));
+            }
+            Node::Text(_) => {}
+            Node::Element(e) => match e.name.as_str() {
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => out.push(Block::Heading(e.text())),
+                "p" | "blockquote" | "pre" => {
+                    let text = e.text();
+                    if !text.is_empty() {
+                        out.push(Block::Paragraph(text));
+                    }
+                }
+                "ul" | "ol" | "ac:task-list" => out.push(Block::List(e)),
+                "table" => out.push(Block::Table(e)),
+                "head" | "ac:parameter" => {}
+                _ => blocks(e, out),
+            },
+        }
+    }
+}
+
+fn list_items(list: &Element) -> Vec<ConfluenceActionItem> {
+    let mut out = Vec::new();
+    for item in list.elements() {
+        let (title, done) = match item.name.as_str() {
+            "ac:task" => {
+                // The status is hidden from `text()`, so read its text nodes directly.
+                let status = item.elements().find(|e| e.name == "ac:task-status").map(|e| {
+                    e.children
+                        .iter()
+                        .filter_map(|c| match c {
+                            Node::Text(t) => Some(t.trim()),
+                            Node::Element(_) => None,
+                        })
+                        .collect::<String>()
+                });
+                let body = item.elements().find(|e| e.name == "ac:task-body").map(Element::text);
+                (body.unwrap_or_default(), status.is_some_and(|s| s.eq_ignore_ascii_case("complete")))
+            }
+            "li" => {
+                let checked = item.attr("class").is_some_and(|c| c.split_whitespace().any(|c| c == "checked"))
+                    || item.attr("data-inline-task-status").is_some_and(|s| s == "complete");
+                (item.text(), checked)
+            }
+            _ => continue,
+        };
+        if !title.is_empty() {
+            out.push(ConfluenceActionItem {
+                title,
+                owner: None,
+                done,
+            });
+        }
+    }
+    out
+}
+
+fn table_rows(table: &Element) -> Vec<Vec<String>> {
+    let mut trs = Vec::new();
+    table.find_all("tr", &mut trs);
+    trs.iter()
+        .map(|tr| tr.elements().filter(|c| c.name == "td" || c.name == "th").map(Element::text).collect())
+        .filter(|row: &Vec<String>| row.iter().any(|c| !c.is_empty()))
+        .collect()
+}
+
+fn column(header: &[String], names: &[&str], exclude: &[Option<usize>]) -> Option<usize> {
+    header.iter().enumerate().position(|(i, h)| {
+        let h = h.to_lowercase();
+        !exclude.contains(&Some(i)) && names.iter().any(|n| h.contains(n))
+    })
+}
+
+fn cell(row: &[String], idx: Option<usize>) -> Option<String> {
+    idx.and_then(|i| row.get(i)).map(|c| c.trim().to_string()).filter(|c| !c.is_empty())
+}
+
+/// Time/event(/who) columns of a timeline table; None when the header does not look like one.
+fn timeline_rows(table: &Element) -> Option<Vec<ConfluenceTimelineRow>> {
+    let rows = table_rows(table);
+    let (header, body) = rows.split_first()?;
+    let time = column(header, &["time", "date", "when"], &[])?;
+    let author = column(header, &["who", "owner", "author", "person", "by"], &[Some(time)]);
+    let text = column(header, &["event", "description", "what", "detail", "update", "action", "note"], &[Some(time), author])
+        .or_else(|| (0..header.len()).find(|i| *i != time && Some(*i) != author))?;
+    Some(
+        body.iter()
+            .filter_map(|row| {
+                Some(ConfluenceTimelineRow {
+                    time: cell(row, Some(time)).unwrap_or_default(),
+                    text: cell(row, Some(text))?,
+                    author: cell(row, author),
+                })
+            })
+            .collect(),
+    )
+}
+
+fn is_done(status: &str) -> bool {
+    matches!(
+        status.trim().to_lowercase().as_str(),
+        "done" | "complete" | "completed" | "closed" | "resolved" | "yes" | "✓" | "✔"
+    )
+}
+
+fn action_table_items(table: &Element) -> Vec<ConfluenceActionItem> {
+    let rows = table_rows(table);
+    let Some((header, body)) = rows.split_first() else {
+        return Vec::new();
+    };
+    let title = column(header, &["action", "item", "task", "description", "what"], &[]).unwrap_or(0);
+    let owner = column(header, &["owner", "assignee", "who"], &[Some(title)]);
+    let status = column(header, &["status", "done", "state"], &[Some(title), owner]);
+    body.iter()
+        .filter_map(|row| {
+            Some(ConfluenceActionItem {
+                title: cell(row, Some(title))?,
+                owner: cell(row, owner),
+                done: cell(row, status).is_some_and(|s| is_done(&s)),
+            })
+        })
+        .collect()
+}
+
+/// Reads the summary (paragraphs under a Summary/Overview heading), the timeline table (under
+/// a Timeline heading, or the first table with a time column) and the action items (task lists,
+/// lists or tables under an Action items/Follow-ups heading) of a postmortem page.
+pub fn parse_confluence_page(text: &str) -> Result<ConfluencePostmortem, AppError> {
+    let root = parse_markup(text);
+    let format = if text.contains("<ac:") || text.contains("<ri:") { "storage" } else { "html" };
+
+    let mut titles = Vec::new();
+    root.find_all("title", &mut titles);
+    let mut title = titles.first().map(|t| t.text()).filter(|t| !t.is_empty());
+
+    let mut all = Vec::new();
+    blocks(&root, &mut all);
+
+    let mut section = Section::Other;
+    let mut summary = Vec::new();
+    let mut timeline: Option<Vec<ConfluenceTimelineRow>> = None;
+    let mut fallback_timeline: Option<Vec<ConfluenceTimelineRow>> = None;
+    let mut action_items = Vec::new();
+    for block in all {
+        match block {
+            Block::Heading(h) => {
+                if title.is_none() && section == Section::Other && !h.is_empty() && section_for(&h) == Section::Other {
+                    title = Some(h.clone());
+                }
+                section = section_for(&h);
+            }
+            Block::Paragraph(p) if section == Section::Summary => summary.push(p),
+            Block::Paragraph(_) => {}
+            Block::List(list) => match section {
+                Section::Summary => summary.extend(list.elements().map(|li| format!("- {}", li.text()))),
+                Section::Actions => action_items.extend(list_items(list)),
+                _ => {}
+            },
+            Block::Table(table) => match section {
+                Section::Timeline if timeline.is_none() => timeline = timeline_rows(table),
+                Section::Actions => action_items.extend(action_table_items(table)),
+                _ if fallback_timeline.is_none() => fallback_timeline = timeline_rows(table),
+                _ => {}
+            },
+        }
+    }
+    let page = ConfluencePostmortem {
+        format: format.to_string(),
+        title,
+        summary: Some(summary.join("\n\n")).filter(|s| !s.is_empty()),
+        timeline: timeline.or(fallback_timeline).unwrap_or_default(),
+        action_items,
+    };
+    if page.summary.is_none() && page.timeline.is_empty() && page.action_items.is_empty() {
+        return Err(AppError::new(
+            "INGEST_CONFLUENCE_EMPTY",
+            "No summary, timeline table or action items found on the page",
+        )
+        .with_details(format!("format={format}")));
+    }
+    Ok(page)
+}
+
+pub fn preview_confluence_page(text: &str) -> Result<ConfluencePostmortem, AppError> {
+    parse_confluence_page(text)
+}
+
+// --- Ingest ---------------------------------------------------------------------------------
+
+/// Writes a postmortem page into an incident: the summary fills an empty description, timeline
+/// rows become `confluence` timeline events, and action items are added unless one with the same
+/// title exists. Re-ingesting the same page adds nothing.
+pub fn ingest_confluence_page(
+    conn: &mut Connection,
+    text: &str,
+    incident_id: Option<i64>,
+    new_incident_title: Option<&str>,
+    ingested_at: &str,
+) -> Result<ConfluenceIngestSummary, AppError> {
+    let page = parse_confluence_page(text)?;
+    // A new incident defaults to the page title.
+    let title = match incident_id {
+        None => new_incident_title.or(page.title.as_deref()),
+        Some(_) => new_incident_title,
+    };
+    let (target_incident_id, incident_created) = ensure_target_incident(conn, incident_id, title, text)?;
+
+    let mut summary = ConfluenceIngestSummary {
+        incident_id: target_incident_id,
+        incident_created,
+        description_filled: false,
+        inserted_events: 0,
+        inserted_action_items: 0,
+        skipped_duplicates: 0,
+        warnings: Vec::new(),
+    };
+    let mut raw_context = serde_json::Map::new();
+    raw_context.insert("page_title".to_string(), page.title.clone().into());
+    let record = PostmortemRecord {
+        source: CONFLUENCE_EVENT_SOURCE,
+        description: page.summary.as_deref(),
+        timeline: page
+            .timeline
+            .iter()
+            .map(|r| TimelineEntry {
+                time: &r.time,
+                text: &r.text,
+                author: r.author.as_deref(),
+            })
+            .collect(),
+        action_items: page
+            .action_items
+            .iter()
+            .map(|a| ActionEntry {
+                title: &a.title,
+                owner: a.owner.as_deref(),
+                done: a.done,
+            })
+            .collect(),
+        raw_context,
+    };
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to start transaction").with_details(e.to_string()))?;
+    let counts = write_postmortem(
+        &tx,
+        target_incident_id,
+        &record,
+        "INGEST_CONFLUENCE_TS_NO_DATE",
+        "INGEST_CONFLUENCE_INSERT_FAILED",
+        ingested_at,
+        &mut summary.warnings,
+    )?;
+    tx.commit()
+        .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to commit transaction").with_details(e.to_string()))?;
+    summary.description_filled = counts.description_filled;
+    summary.inserted_events = counts.inserted_events;
+    summary.inserted_action_items = counts.inserted_action_items;
+    summary.skipped_duplicates = counts.skipped_duplicates;
+    Ok(summary)
+}
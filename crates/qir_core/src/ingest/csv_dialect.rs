@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+
+/// Records sampled when guessing the delimiter.
+const SNIFF_RECORDS: usize = 20;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvDelimiter {
+    #[default]
+    Comma,
+    /// Excel's default in locales where the comma is the decimal separator.
+    Semicolon,
+    Tab,
+    Pipe,
+}
+
+impl CsvDelimiter {
+    /// Tie-break order for detection: the common case first.
+    const ALL: [CsvDelimiter; 4] = [
+        CsvDelimiter::Comma,
+        CsvDelimiter::Semicolon,
+        CsvDelimiter::Tab,
+        CsvDelimiter::Pipe,
+    ];
+
+    pub fn as_byte(self) -> u8 {
+        match self {
+            CsvDelimiter::Comma => b',',
+            CsvDelimiter::Semicolon => b';',
+            CsvDelimiter::Tab => b'\t',
+            CsvDelimiter::Pipe => b'|',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvEncoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Windows1252,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DecodedCsv {
+    pub text: String,
+    pub encoding: CsvEncoding,
+    /// True when a byte-order mark was present (and removed).
+    pub had_bom: bool,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+/// Code points for bytes 0x80..=0x9F in Windows-1252; the five undefined bytes keep their
+/// C1 control code point, as browsers do.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}', '\u{8F}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}', '\u{178}',
+];
+
+fn decode_windows_1252(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|&b| match b {
+            0x80..=0x9F => WINDOWS_1252_HIGH[usize::from(b - 0x80)],
+            _ => char::from(b),
+        })
+        .collect()
+}
+
+fn decode_utf16(bytes: &[u8], little_endian: bool) -> Result<String, AppError> {
+    if !bytes.len().is_multiple_of(2) {
+        return Err(AppError::new("INGEST_CSV_DECODE_FAILED", "UTF-16 CSV has an odd number of bytes")
+            .with_details(format!("len={}", bytes.len())));
+    }
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|c| if little_endian { u16::from_le_bytes([c[0], c[1]]) } else { u16::from_be_bytes([c[0], c[1]]) })
+        .collect();
+    String::from_utf16(&units)
+        .map_err(|e| AppError::new("INGEST_CSV_DECODE_FAILED", "Failed to decode UTF-16 CSV").with_details(e.to_string()))
+}
+
+/// Decodes a CSV file's raw bytes. UTF-8 (with or without BOM) and BOM-marked UTF-16 are
+/// read as-is; anything that is not valid UTF-8 is transcoded from Windows-1252, with an
+/// `INGEST_CSV_TRANSCODED` warning because the guess cannot be verified.
+pub fn decode_csv_bytes(bytes: &[u8]) -> Result<DecodedCsv, AppError> {
+    let decoded = |text: String, encoding: CsvEncoding, had_bom: bool| DecodedCsv {
+        text,
+        encoding,
+        had_bom,
+        warnings: Vec::new(),
+    };
+    if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        if let Ok(text) = std::str::from_utf8(rest) {
+            return Ok(decoded(text.to_string(), CsvEncoding::Utf8, true));
+        }
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        return Ok(decoded(decode_utf16(rest, true)?, CsvEncoding::Utf16Le, true));
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        return Ok(decoded(decode_utf16(rest, false)?, CsvEncoding::Utf16Be, true));
+    }
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return Ok(decoded(text.to_string(), CsvEncoding::Utf8, false));
+    }
+
+    let mut out = decoded(decode_windows_1252(bytes), CsvEncoding::Windows1252, false);
+    let first_invalid = std::str::from_utf8(bytes).err().map(|e| e.valid_up_to()).unwrap_or(0);
+    out.warnings.push(
+        ValidationWarning::new(
+            "INGEST_CSV_TRANSCODED",
+            "CSV is not valid UTF-8; transcoded from Windows-1252",
+        )
+        .with_details(format!("from=windows-1252; first_invalid_byte={first_invalid}")),
+    );
+    Ok(out)
+}
+
+/// Text with a leading BOM removed; text decoded by the webview keeps it.
+pub(crate) fn strip_bom(text: &str) -> &str {
+    text.strip_prefix('\u{FEFF}').unwrap_or(text)
+}
+
+/// Field count of every sampled record (header included) when split on `delimiter`, or None
+/// when the counts disagree or the text does not parse.
+fn consistent_width(text: &str, delimiter: CsvDelimiter) -> Option<usize> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter.as_byte())
+        .from_reader(text.as_bytes());
+    let mut width = None;
+    for record in rdr.records().take(SNIFF_RECORDS) {
+        let len = record.ok()?.len();
+        match width {
+            None => width = Some(len),
+            Some(w) if w != len => return None,
+            Some(_) => {}
+        }
+    }
+    width
+}
+
+/// Picks the delimiter that splits every sampled record (quoted newlines included) into the
+/// same, largest number of fields. Falls back to comma when nothing splits consistently.
+pub fn detect_delimiter(csv_text: &str) -> CsvDelimiter {
+    let text = strip_bom(csv_text);
+    CsvDelimiter::ALL
+        .iter()
+        .filter_map(|&d| consistent_width(text, d).filter(|w| *w > 1).map(|w| (d, w)))
+        // `max_by_key` keeps the last maximum; iterate in reverse so ties go to ALL's order.
+        .rev()
+        .max_by_key(|(_, w)| *w)
+        .map(|(d, _)| d)
+        .unwrap_or_default()
+}
+
+/// Reader over `csv_text` with the BOM removed and the given (or detected) delimiter.
+pub(crate) fn csv_reader(csv_text: &str, delimiter: Option<CsvDelimiter>) -> (csv::Reader<&[u8]>, CsvDelimiter) {
+    let delimiter = delimiter.unwrap_or_else(|| detect_delimiter(csv_text));
+    let rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .delimiter(delimiter.as_byte())
+        .from_reader(strip_bom(csv_text).as_bytes());
+    (rdr, delimiter)
+}
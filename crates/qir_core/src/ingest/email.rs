@@ -0,0 +1,427 @@
+use std::fs;
+use std::path::Path;
+
+use base64::Engine;
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use time::format_description::well_known::{Rfc2822, Rfc3339};
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+
+use super::slack_transcript::ensure_target_incident;
+
+/// The fields of one message that end up on the timeline.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ParsedEmail {
+    pub message_id: Option<String>,
+    pub from: Option<String>,
+    pub subject: Option<String>,
+    /// RFC3339 UTC; None when the Date header is missing or unreadable.
+    pub ts: Option<String>,
+    pub body: String,
+    /// True when the text part claims base64 encoding but does not decode; `body` is then empty.
+    pub body_undecodable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EmailPreview {
+    pub message_count: usize,
+    pub senders: Vec<String>,
+    pub first_ts: Option<String>,
+    pub last_ts: Option<String>,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EmailIngestSummary {
+    pub incident_id: i64,
+    pub incident_created: bool,
+    pub inserted_events: usize,
+    pub inserted_artifacts: usize,
+    pub skipped_duplicates: usize,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+/// One raw message and where it came from (file name, or `file#n` for mbox entries).
+struct RawEmail {
+    origin: String,
+    raw: String,
+}
+
+fn read_failed(path: &Path, e: impl ToString) -> AppError {
+    AppError::new("INGEST_EMAIL_READ_FAILED", "Failed to read email source")
+        .with_details(format!("path={}; err={}", path.display(), e.to_string()))
+}
+
+/// Splits an mbox file on its `From ` separator lines and undoes `>From ` quoting.
+fn split_mbox(text: &str, origin: &str) -> Vec<RawEmail> {
+    let mut out = Vec::new();
+    let mut current: Option<String> = None;
+    let mut prev_blank = true;
+    for line in text.lines() {
+        if line.starts_with("From ") && prev_blank {
+            if let Some(msg) = current.take() {
+                out.push(msg);
+            }
+            current = Some(String::new());
+        } else if let Some(msg) = current.as_mut() {
+            let line = line.strip_prefix('>').filter(|l| l.trim_start_matches('>').starts_with("From ")).unwrap_or(line);
+            msg.push_str(line);
+            msg.push('\n');
+        }
+        prev_blank = line.trim().is_empty();
+    }
+    out.extend(current);
+    out.into_iter()
+        .filter(|m| !m.trim().is_empty())
+        .enumerate()
+        .map(|(idx, raw)| RawEmail {
+            origin: format!("{origin}#{}", idx + 1),
+            raw,
+        })
+        .collect()
+}
+
+/// A folder of `.eml` files (sorted by name), a single `.eml`, or an mbox file.
+fn read_email_source(path: &Path) -> Result<Vec<RawEmail>, AppError> {
+    let read = |p: &Path| fs::read(p).map(|b| String::from_utf8_lossy(&b).into_owned()).map_err(|e| read_failed(p, e));
+    let name = |p: &Path| p.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let messages = if path.is_dir() {
+        let mut files = fs::read_dir(path)
+            .map_err(|e| read_failed(path, e))?
+            .map(|e| e.map(|e| e.path()).map_err(|e| read_failed(path, e)))
+            .collect::<Result<Vec<_>, _>>()?;
+        files.retain(|p| p.extension().and_then(|e| e.to_str()).is_some_and(|e| e.eq_ignore_ascii_case("eml")));
+        files.sort();
+        files
+            .iter()
+            .map(|p| Ok(RawEmail { origin: name(p), raw: read(p)? }))
+            .collect::<Result<Vec<_>, AppError>>()?
+    } else if path.extension().and_then(|e| e.to_str()).is_some_and(|e| e.eq_ignore_ascii_case("eml")) {
+        vec![RawEmail { origin: name(path), raw: read(path)? }]
+    } else {
+        split_mbox(&read(path)?, &name(path))
+    };
+    if messages.is_empty() {
+        return Err(AppError::new("INGEST_EMAIL_EMPTY", "No email messages found")
+            .with_details(format!("path={}", path.display())));
+    }
+    Ok(messages)
+}
+
+/// Header block (folded lines joined) and the remaining body.
+fn split_headers(raw: &str) -> (Vec<(String, String)>, &str) {
+    let raw = raw.trim_start_matches(['\r', '\n']);
+    let (head, body) = match raw.find("\r\n\r\n") {
+        Some(i) if raw.find("\n\n").is_none_or(|j| i < j) => (&raw[..i], &raw[i + 4..]),
+        _ => match raw.find("\n\n") {
+            Some(i) => (&raw[..i], &raw[i + 2..]),
+            None => (raw, ""),
+        },
+    };
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in head.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, v)) = headers.last_mut() {
+                v.push(' ');
+                v.push_str(line.trim());
+            }
+        } else if let Some((k, v)) = line.split_once(':') {
+            headers.push((k.trim().to_ascii_lowercase(), v.trim().to_string()));
+        }
+    }
+    (headers, body)
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+}
+
+fn decode_quoted_printable(s: &str, underscore_is_space: bool) -> Vec<u8> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'=' if bytes.get(i + 1) == Some(&b'\n') => i += 2,
+            b'=' if bytes.get(i + 1) == Some(&b'\r') && bytes.get(i + 2) == Some(&b'\n') => i += 3,
+            b'=' if i + 2 < bytes.len() && bytes[i + 1].is_ascii_hexdigit() && bytes[i + 2].is_ascii_hexdigit() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("00");
+                out.push(u8::from_str_radix(hex, 16).unwrap_or(0));
+                i += 3;
+            }
+            b'_' if underscore_is_space => {
+                out.push(b' ');
+                i += 1;
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let compact: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    base64::engine::general_purpose::STANDARD.decode(compact).ok()
+}
+
+/// Decodes RFC 2047 encoded words (`=?utf-8?B?...?=` / `?Q?`) in a header value.
+fn decode_header_words(value: &str) -> String {
+    let mut out = String::new();
+    let mut rest = value;
+    let mut after_word = false;
+    while let Some(start) = rest.find("=?") {
+        let decoded = rest[start + 2..].splitn(3, '?').collect::<Vec<_>>();
+        let (encoding, tail) = match decoded.as_slice() {
+            [_charset, enc, tail] => (enc.to_ascii_uppercase(), *tail),
+            _ => break,
+        };
+        let Some(end) = tail.find("?=") else {
+            break;
+        };
+        let text = &tail[..end];
+        let bytes = match encoding.as_str() {
+            "B" => decode_base64(text),
+            "Q" => Some(decode_quoted_printable(text, true)),
+            _ => None,
+        };
+        let Some(bytes) = bytes else {
+            break;
+        };
+        let before = &rest[..start];
+        // Whitespace between adjacent encoded words is not part of the text.
+        if !(after_word && before.trim().is_empty()) {
+            out.push_str(before);
+        }
+        out.push_str(&String::from_utf8_lossy(&bytes));
+        after_word = true;
+        let consumed = rest.len() - tail.len() + end + 2;
+        rest = &rest[consumed..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// None when a `base64` body fails to decode.
+fn decode_body(body: &str, headers: &[(String, String)]) -> Option<String> {
+    let encoding = header(headers, "content-transfer-encoding").unwrap_or("").to_ascii_lowercase();
+    match encoding.trim() {
+        "base64" => decode_base64(body).map(|b| String::from_utf8_lossy(&b).into_owned()),
+        "quoted-printable" => Some(String::from_utf8_lossy(&decode_quoted_printable(body, false)).into_owned()),
+        _ => Some(body.to_string()),
+    }
+}
+
+fn content_type(headers: &[(String, String)]) -> String {
+    header(headers, "content-type").unwrap_or("text/plain").to_ascii_lowercase()
+}
+
+fn boundary(content_type_raw: &str) -> Option<String> {
+    content_type_raw.split(';').find_map(|p| {
+        let (k, v) = p.trim().split_once('=')?;
+        k.trim().eq_ignore_ascii_case("boundary").then(|| v.trim().trim_matches('"').to_string())
+    })
+}
+
+/// The first `text/plain` part, searching nested multiparts; None when there is none, and
+/// `Some(None)` when that part does not decode.
+fn plain_text_body(headers: &[(String, String)], body: &str) -> Option<Option<String>> {
+    let ct = content_type(headers);
+    if ct.starts_with("multipart/") {
+        let boundary = boundary(header(headers, "content-type").unwrap_or(""))?;
+        let delimiter = format!("--{boundary}");
+        return body
+            .split(delimiter.as_str())
+            .skip(1)
+            .take_while(|part| !part.starts_with("--"))
+            .find_map(|part| {
+                let (part_headers, part_body) = split_headers(part);
+                plain_text_body(&part_headers, part_body)
+            });
+    }
+    ct.starts_with("text/plain").then(|| decode_body(body, headers))
+}
+
+/// Drops quoted reply lines and trailing signatures so each event holds only new text.
+fn strip_quoted(body: &str) -> String {
+    let mut lines = Vec::new();
+    for line in body.lines() {
+        let t = line.trim_end();
+        if t == "--" || t == "-- " {
+            break;
+        }
+        if t.starts_with('>') {
+            continue;
+        }
+        lines.push(t);
+    }
+    // Drop the "On <date>, <name> wrote:" line that introduces a removed quote.
+    while lines.last().is_some_and(|l| l.trim().is_empty() || l.trim_end().ends_with("wrote:")) {
+        lines.pop();
+    }
+    lines.join("\n").trim().to_string()
+}
+
+fn parse_email_date(value: &str) -> Option<String> {
+    // Drop trailing comments like "(UTC)" which RFC 2822 parsers reject.
+    let value = value.split('(').next().unwrap_or("").trim();
+    let dt = OffsetDateTime::parse(value, &Rfc2822).ok()?;
+    dt.to_offset(UtcOffset::UTC).format(&Rfc3339).ok()
+}
+
+pub fn parse_email_message(raw: &str) -> ParsedEmail {
+    let (headers, body) = split_headers(raw);
+    let text = plain_text_body(&headers, body);
+    let body_undecodable = matches!(text, Some(None));
+    let non_empty = |v: Option<&str>| v.map(decode_header_words).map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+    ParsedEmail {
+        message_id: non_empty(header(&headers, "message-id")),
+        from: non_empty(header(&headers, "from")),
+        subject: non_empty(header(&headers, "subject")),
+        ts: header(&headers, "date").and_then(parse_email_date),
+        body: text.flatten().map(|t| strip_quoted(&t)).unwrap_or_default(),
+        body_undecodable,
+    }
+}
+
+fn date_warning(origin: &str) -> ValidationWarning {
+    ValidationWarning::new("INGEST_EMAIL_DATE_UNKNOWN", "Email has no readable Date header")
+        .with_details(format!("message={origin}"))
+}
+
+fn body_warning(origin: &str) -> ValidationWarning {
+    ValidationWarning::new("INGEST_EMAIL_BODY_UNDECODABLE", "Email text body is not valid base64")
+        .with_details(format!("message={origin}"))
+}
+
+pub fn preview_email_source(path: &Path) -> Result<EmailPreview, AppError> {
+    let messages = read_email_source(path)?;
+    let mut warnings = Vec::new();
+    let mut senders = Vec::new();
+    let mut timestamps = Vec::new();
+    for m in &messages {
+        let parsed = parse_email_message(&m.raw);
+        match parsed.ts {
+            Some(ts) => timestamps.push(ts),
+            None => warnings.push(date_warning(&m.origin)),
+        }
+        if parsed.body_undecodable {
+            warnings.push(body_warning(&m.origin));
+        }
+        senders.extend(parsed.from);
+    }
+    senders.sort();
+    senders.dedup();
+    timestamps.sort();
+    Ok(EmailPreview {
+        message_count: messages.len(),
+        senders,
+        first_ts: timestamps.first().cloned(),
+        last_ts: timestamps.last().cloned(),
+        warnings,
+    })
+}
+
+/// Adds each message as an `email` timeline event and stores the raw message as an
+/// `email_message` artifact (deduplicated by content hash). Re-ingesting skips messages whose
+/// event already exists on the incident.
+pub fn ingest_email_source(
+    conn: &mut Connection,
+    path: &Path,
+    incident_id: Option<i64>,
+    new_incident_title: Option<&str>,
+) -> Result<EmailIngestSummary, AppError> {
+    let messages = read_email_source(path)?;
+    let all_raw: String = messages.iter().map(|m| m.raw.as_str()).collect();
+    let (target_incident_id, incident_created) =
+        ensure_target_incident(conn, incident_id, new_incident_title, &all_raw)?;
+
+    let mut summary = EmailIngestSummary {
+        incident_id: target_incident_id,
+        incident_created,
+        inserted_events: 0,
+        inserted_artifacts: 0,
+        skipped_duplicates: 0,
+        warnings: Vec::new(),
+    };
+    let insert_failed = |what: &str, origin: &str, e: rusqlite::Error| {
+        AppError::new("INGEST_EMAIL_INSERT_FAILED", format!("Failed to insert email {what}"))
+            .with_details(format!("message={origin}; err={e}"))
+    };
+    for m in &messages {
+        let parsed = parse_email_message(&m.raw);
+        if parsed.ts.is_none() {
+            summary.warnings.push(date_warning(&m.origin));
+        }
+        if parsed.body_undecodable {
+            summary.warnings.push(body_warning(&m.origin));
+        }
+        let text = match (parsed.subject.as_deref(), parsed.body.is_empty()) {
+            (Some(subject), false) => format!("{subject}\n\n{}", parsed.body),
+            (Some(subject), true) => subject.to_string(),
+            (None, _) => parsed.body.clone(),
+        };
+        if text.is_empty() {
+            summary.warnings.push(
+                ValidationWarning::new("INGEST_EMAIL_EMPTY_MESSAGE", "Skipped email without subject or text body")
+                    .with_details(format!("message={}", m.origin)),
+            );
+            continue;
+        }
+
+        let exists: Option<i64> = conn
+            .query_row(
+                "SELECT id FROM timeline_events
+                 WHERE incident_id = ?1 AND source = 'email' AND ts IS ?2 AND author IS ?3 AND text = ?4",
+                rusqlite::params![target_incident_id, parsed.ts, parsed.from, text],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| {
+                AppError::new("DB_QUERY_FAILED", "Failed to check existing email events").with_details(e.to_string())
+            })?;
+        if exists.is_some() {
+            summary.skipped_duplicates += 1;
+            continue;
+        }
+
+        let sha = hex::encode(Sha256::digest(m.raw.as_bytes()));
+        summary.inserted_artifacts += conn
+            .execute(
+                r#"
+          INSERT OR IGNORE INTO artifacts(incident_id, kind, sha256, filename, mime_type, text, created_at)
+          VALUES (?1, 'email_message', ?2, ?3, 'message/rfc822', ?4, strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+          "#,
+                rusqlite::params![target_incident_id, sha, m.origin, m.raw],
+            )
+            .map_err(|e| insert_failed("artifact", &m.origin, e))?;
+
+        let raw_json = serde_json::json!({
+            "message_id": parsed.message_id,
+            "subject": parsed.subject,
+            "source_file": m.origin,
+            "artifact_sha256": sha,
+        })
+        .to_string();
+        conn.execute(
+            r#"
+          INSERT INTO timeline_events(
+            incident_id, source, ts, author, kind, text, raw_json, created_at
+          ) VALUES (
+            ?1, 'email', ?2, ?3, 'email', ?4, ?5,
+            strftime('%Y-%m-%dT%H:%M:%fZ','now')
+          )
+          "#,
+            rusqlite::params![target_incident_id, parsed.ts, parsed.from, text, raw_json],
+        )
+        .map_err(|e| insert_failed("timeline event", &m.origin, e))?;
+        summary.inserted_events += 1;
+    }
+    Ok(summary)
+}
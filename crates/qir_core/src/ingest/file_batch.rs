@@ -0,0 +1,262 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::AppError;
+use crate::progress::Progress;
+use crate::profiles::suggest::suggest_jira_mapping;
+
+use super::csv_dialect::{csv_reader, decode_csv_bytes};
+use super::incident_io::{import_incident_io, IncidentIoMapping};
+use super::jira_api::default_jira_api_mapping;
+use super::jira_csv::{import_jira_csv, JiraCsvMapping, JiraImportSummary};
+use super::jira_json::import_jira_json;
+use super::pagerduty_csv::{import_pagerduty_csv, PagerDutyCsvMapping};
+use super::retro_tool::import_retro_tool_export;
+use super::servicenow_csv::{import_servicenow_csv, ServiceNowCsvMapping};
+
+/// Source a batch file was recognised as; decides which importer reads it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileBatchFormat {
+    JiraCsv,
+    JiraJson,
+    PagerdutyCsv,
+    ServicenowCsv,
+    IncidentIo,
+    RetroTool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileBatchFileResult {
+    pub path: String,
+    /// None when the file could not be read or recognised.
+    pub format: Option<FileBatchFormat>,
+    /// Incident import summary; None when the file failed. Source-level warnings (e.g. Jira
+    /// changelog or incident.io role warnings) are folded into its warnings.
+    pub summary: Option<JiraImportSummary>,
+    /// Jira JSON changelog entries written as timeline events.
+    #[serde(default)]
+    pub timeline_events_inserted: usize,
+    /// incident.io role assignments written as responders.
+    #[serde(default)]
+    pub responders_added: usize,
+    pub error: Option<AppError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileBatchImportSummary {
+    pub inserted: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    /// Files that could not be read, recognised or imported.
+    pub failed: usize,
+    /// Keyed by file name.
+    pub files: BTreeMap<String, FileBatchFileResult>,
+}
+
+fn is_json(name: &str, text: &str) -> bool {
+    name.to_lowercase().ends_with(".json") || matches!(text.trim_start().chars().next(), Some('{') | Some('['))
+}
+
+fn unknown_format(details: String) -> AppError {
+    AppError::new("INGEST_FILE_BATCH_FORMAT_UNKNOWN", "File does not look like a supported export").with_details(details)
+}
+
+/// The incident list of a JSON export: a bare array, or the first of the known envelope keys.
+fn json_items(root: &Value) -> Option<(&'static str, &[Value])> {
+    match root {
+        Value::Array(items) => Some(("", items)),
+        Value::Object(obj) => ["issues", "incidents", "data"]
+            .into_iter()
+            .find_map(|key| obj.get(key).and_then(Value::as_array).map(|items| (key, items.as_slice()))),
+        _ => None,
+    }
+}
+
+fn detect_json(name: &str, text: &str) -> Result<FileBatchFormat, AppError> {
+    let root: Value = serde_json::from_str(text).map_err(|e| unknown_format(format!("file={name}; json={e}")))?;
+    let (key, items) =
+        json_items(&root).ok_or_else(|| unknown_format(format!("file={name}; keys=issues,incidents,data")))?;
+    let any_has = |fields: &[&str]| items.iter().any(|i| fields.iter().any(|f| i.get(*f).is_some()));
+    if key == "issues" || any_has(&["fields"]) {
+        Ok(FileBatchFormat::JiraJson)
+    } else if any_has(&["reference", "incident_timestamp_values", "incident_role_assignments"]) {
+        Ok(FileBatchFormat::IncidentIo)
+    } else if key == "data" || any_has(&["attributes", "milestones"]) {
+        Ok(FileBatchFormat::RetroTool)
+    } else {
+        Err(unknown_format(format!("file={name}; incidents={}", items.len())))
+    }
+}
+
+/// Only an exact title synonym counts: the suggester also scores partial header matches,
+/// which would make any CSV look like a Jira export.
+fn has_exact_title_column(text: &str) -> Result<bool, AppError> {
+    Ok(suggest_jira_mapping(text)?
+        .fields
+        .iter()
+        .any(|f| f.field == "title" && f.column.is_some() && f.confidence >= 1.0))
+}
+
+fn detect_csv(name: &str, text: &str, jira_mapping: Option<&JiraCsvMapping>) -> Result<FileBatchFormat, AppError> {
+    let (mut rdr, _) = csv_reader(text, None);
+    let headers: HashSet<String> = rdr
+        .headers()
+        .map_err(|e| unknown_format(format!("file={name}; csv={e}")))?
+        .iter()
+        .map(|h| h.trim().to_string())
+        .collect();
+    let has = |h: &str| headers.contains(h);
+    let pagerduty = PagerDutyCsvMapping::default();
+    let servicenow = ServiceNowCsvMapping::default();
+    let incident_io = IncidentIoMapping::default();
+    if has(&servicenow.title) && (has("number") || has("sys_id")) {
+        Ok(FileBatchFormat::ServicenowCsv)
+    } else if has(&pagerduty.title) && has("incident_number") {
+        Ok(FileBatchFormat::PagerdutyCsv)
+    } else if has(&incident_io.name) && has("Reference") {
+        Ok(FileBatchFormat::IncidentIo)
+    } else if match jira_mapping {
+        Some(m) => has(&m.title),
+        None => has_exact_title_column(text)?,
+    } {
+        Ok(FileBatchFormat::JiraCsv)
+    } else {
+        let mut sorted: Vec<&str> = headers.iter().map(String::as_str).collect();
+        sorted.sort_unstable();
+        Err(unknown_format(format!("file={name}; headers={}", sorted.join(","))))
+    }
+}
+
+/// Picks the importer for one file from its extension and content: JSON by envelope and item
+/// shape, CSV by the stock export headers of each source. CSVs that match none of them are
+/// Jira CSVs when `jira_mapping`'s title column is present (or, without a mapping, when a
+/// header is a known title name such as "Summary").
+pub fn detect_file_batch_format(
+    name: &str,
+    text: &str,
+    jira_mapping: Option<&JiraCsvMapping>,
+) -> Result<FileBatchFormat, AppError> {
+    if is_json(name, text) {
+        detect_json(name, text)
+    } else {
+        detect_csv(name, text, jira_mapping)
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+fn read_text(path: &Path) -> Result<String, AppError> {
+    let bytes = std::fs::read(path).map_err(|e| {
+        AppError::new("INGEST_FILE_BATCH_READ_FAILED", "Failed to read import file")
+            .with_details(format!("path={}; error={e}", path.display()))
+    })?;
+    Ok(decode_csv_bytes(&bytes)?.text)
+}
+
+fn import_one(
+    conn: &mut Connection,
+    name: &str,
+    text: &str,
+    jira_mapping: Option<&JiraCsvMapping>,
+    result: &mut FileBatchFileResult,
+) -> Result<JiraImportSummary, AppError> {
+    let format = detect_file_batch_format(name, text, jira_mapping)?;
+    result.format = Some(format);
+    match format {
+        FileBatchFormat::JiraCsv => {
+            let mapping = match jira_mapping {
+                Some(m) => m.clone(),
+                None => suggest_jira_mapping(text)?.mapping,
+            };
+            import_jira_csv(conn, text, &mapping)
+        }
+        FileBatchFormat::JiraJson => {
+            // Profiles name CSV headers; JSON exports are read by field id.
+            let mut out = import_jira_json(conn, text, &default_jira_api_mapping())?;
+            result.timeline_events_inserted = out.timeline_events_inserted;
+            out.incidents.warnings.append(&mut out.warnings);
+            Ok(out.incidents)
+        }
+        FileBatchFormat::PagerdutyCsv => import_pagerduty_csv(conn, text, &PagerDutyCsvMapping::default()),
+        FileBatchFormat::ServicenowCsv => import_servicenow_csv(conn, text, &ServiceNowCsvMapping::default()),
+        FileBatchFormat::IncidentIo => {
+            let mut out = import_incident_io(conn, text, &IncidentIoMapping::default())?;
+            result.responders_added = out.responders_added;
+            out.incidents.warnings.append(&mut out.warnings);
+            Ok(out.incidents)
+        }
+        FileBatchFormat::RetroTool => import_retro_tool_export(conn, text, None),
+    }
+}
+
+/// Imports a mix of CSV and JSON exports one file after another, each with the importer its
+/// content calls for and stock mappings (Jira CSVs use `jira_mapping`, or a suggested mapping
+/// without one; Jira JSON is read by API field ids). Each file is committed by its
+/// importer before the next one starts, so a file that fails is reported in its result and
+/// leaves the files before it imported.
+pub fn import_file_batch(
+    conn: &mut Connection,
+    paths: &[PathBuf],
+    jira_mapping: Option<&JiraCsvMapping>,
+    progress: &mut Progress<'_>,
+) -> Result<FileBatchImportSummary, AppError> {
+    if paths.is_empty() {
+        return Err(AppError::new("INGEST_FILE_BATCH_EMPTY", "Import batch has no files"));
+    }
+    let mut names = HashSet::new();
+    for path in paths {
+        let name = file_name(path);
+        if !names.insert(name.clone()) {
+            return Err(AppError::new(
+                "INGEST_FILE_BATCH_DUPLICATE_FILE",
+                "Two files in the import batch share a file name",
+            )
+            .with_details(format!("file={name}")));
+        }
+    }
+
+    progress.run(|p| {
+        let mut out = FileBatchImportSummary {
+            inserted: 0,
+            updated: 0,
+            skipped: 0,
+            failed: 0,
+            files: BTreeMap::new(),
+        };
+        for (idx, path) in paths.iter().enumerate() {
+            p.step("files", idx, paths.len(), 1, 99);
+            let name = file_name(path);
+            let mut result = FileBatchFileResult {
+                path: path.display().to_string(),
+                format: None,
+                summary: None,
+                timeline_events_inserted: 0,
+                responders_added: 0,
+                error: None,
+            };
+            match read_text(path).and_then(|text| import_one(conn, &name, &text, jira_mapping, &mut result)) {
+                Ok(summary) => {
+                    out.inserted += summary.inserted;
+                    out.updated += summary.updated;
+                    out.skipped += summary.skipped;
+                    result.summary = Some(summary);
+                }
+                Err(e) => {
+                    out.failed += 1;
+                    result.error = Some(e);
+                }
+            }
+            out.files.insert(name, result);
+        }
+        Ok(out)
+    })
+}
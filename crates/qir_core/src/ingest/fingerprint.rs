@@ -0,0 +1,236 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::AppError;
+use crate::settings::{get_setting_as, set_setting};
+
+pub const FINGERPRINT_STRATEGY_SETTING: &str = "ingest.fingerprint_strategy";
+/// Version of the original title + primary timestamps fingerprint.
+pub const FINGERPRINT_DEFAULT_VERSION: &str = "v1";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum FingerprintComponent {
+    ExternalId,
+    /// Whitespace-collapsed, lowercased title.
+    Title,
+    Vendor,
+    Service,
+    /// YYYY-MM-DD of start (or first observed) when canonical; the raw value otherwise.
+    StartDate,
+    StartTs,
+    FirstObservedTs,
+    AckTs,
+    MitigateTs,
+    ResolveTs,
+}
+
+impl FingerprintComponent {
+    const ALL: [FingerprintComponent; 10] = [
+        FingerprintComponent::ExternalId,
+        FingerprintComponent::Title,
+        FingerprintComponent::Vendor,
+        FingerprintComponent::Service,
+        FingerprintComponent::StartDate,
+        FingerprintComponent::StartTs,
+        FingerprintComponent::FirstObservedTs,
+        FingerprintComponent::AckTs,
+        FingerprintComponent::MitigateTs,
+        FingerprintComponent::ResolveTs,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FingerprintComponent::ExternalId => "external_id",
+            FingerprintComponent::Title => "title",
+            FingerprintComponent::Vendor => "vendor",
+            FingerprintComponent::Service => "service",
+            FingerprintComponent::StartDate => "start_date",
+            FingerprintComponent::StartTs => "start_ts",
+            FingerprintComponent::FirstObservedTs => "first_observed_ts",
+            FingerprintComponent::AckTs => "ack_ts",
+            FingerprintComponent::MitigateTs => "mitigate_ts",
+            FingerprintComponent::ResolveTs => "resolve_ts",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == s)
+    }
+}
+
+/// Fields a fingerprint can be built from. Timestamps are the canonical value when there is
+/// one, otherwise the raw cell.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FingerprintInput<'a> {
+    pub external_id: Option<&'a str>,
+    pub title: &'a str,
+    pub vendor: Option<&'a str>,
+    pub service: Option<&'a str>,
+    pub start_ts: Option<&'a str>,
+    pub first_observed_ts: Option<&'a str>,
+    pub ack_ts: Option<&'a str>,
+    pub mitigate_ts: Option<&'a str>,
+    pub resolve_ts: Option<&'a str>,
+}
+
+fn normalize_title(title: &str) -> String {
+    title.split_whitespace().collect::<Vec<_>>().join(" ").trim().to_lowercase()
+}
+
+impl FingerprintInput<'_> {
+    fn value(&self, component: FingerprintComponent) -> String {
+        let opt = |v: Option<&str>| v.map(str::trim).unwrap_or("").to_string();
+        match component {
+            FingerprintComponent::ExternalId => opt(self.external_id),
+            FingerprintComponent::Title => normalize_title(self.title),
+            FingerprintComponent::Vendor => opt(self.vendor).to_lowercase(),
+            FingerprintComponent::Service => opt(self.service).to_lowercase(),
+            FingerprintComponent::StartDate => {
+                let start = opt(self.start_ts.or(self.first_observed_ts));
+                let canonical_date = start.len() >= 10
+                    && start.as_bytes()[4] == b'-'
+                    && start.as_bytes()[7] == b'-'
+                    && start.as_bytes()[..10].iter().enumerate().all(|(i, b)| i == 4 || i == 7 || b.is_ascii_digit());
+                if canonical_date {
+                    start[..10].to_string()
+                } else {
+                    start
+                }
+            }
+            FingerprintComponent::StartTs => opt(self.start_ts),
+            FingerprintComponent::FirstObservedTs => opt(self.first_observed_ts),
+            FingerprintComponent::AckTs => opt(self.ack_ts),
+            FingerprintComponent::MitigateTs => opt(self.mitigate_ts),
+            FingerprintComponent::ResolveTs => opt(self.resolve_ts),
+        }
+    }
+}
+
+/// Original fingerprint: normalized title + primary timestamps (nullable). If all timestamps
+/// are missing it is title-only and collisions are surfaced as conflicts.
+fn v1_fingerprint(input: &FingerprintInput<'_>) -> String {
+    let payload = format!(
+        "title={}|start={}|first_observed={}|ack={}|mitigate={}|resolve={}",
+        normalize_title(input.title),
+        input.start_ts.unwrap_or(""),
+        input.first_observed_ts.unwrap_or(""),
+        input.ack_ts.unwrap_or(""),
+        input.mitigate_ts.unwrap_or(""),
+        input.resolve_ts.unwrap_or("")
+    );
+    hex::encode(Sha256::digest(payload.as_bytes()))
+}
+
+/// Which fields identify an incident when it has no matching external_id. The default is the
+/// v1 fingerprint (title + start, first observed, ack, mitigate, resolve timestamps).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FingerprintStrategy {
+    pub components: Vec<FingerprintComponent>,
+}
+
+impl Default for FingerprintStrategy {
+    fn default() -> Self {
+        Self {
+            components: vec![
+                FingerprintComponent::Title,
+                FingerprintComponent::StartTs,
+                FingerprintComponent::FirstObservedTs,
+                FingerprintComponent::AckTs,
+                FingerprintComponent::MitigateTs,
+                FingerprintComponent::ResolveTs,
+            ],
+        }
+    }
+}
+
+impl FingerprintStrategy {
+    pub fn validate(&self) -> Result<(), AppError> {
+        let mut sorted = self.components.clone();
+        sorted.sort();
+        sorted.dedup();
+        if self.components.is_empty() || sorted.len() != self.components.len() {
+            return Err(AppError::new(
+                "VALIDATION_FINGERPRINT_STRATEGY_INVALID",
+                "A fingerprint strategy needs at least one component and no repeats",
+            )
+            .with_details(format!("components={:?}", self.components)));
+        }
+        Ok(())
+    }
+
+    /// `v1` for the default strategy, otherwise `v2:` + the component names joined by `+`
+    /// (e.g. `v2:title+service+start_date`). Stored next to each fingerprint.
+    pub fn version(&self) -> String {
+        if *self == Self::default() {
+            return FINGERPRINT_DEFAULT_VERSION.to_string();
+        }
+        let names: Vec<&str> = self.components.iter().map(|c| c.as_str()).collect();
+        format!("v2:{}", names.join("+"))
+    }
+
+    /// Inverse of `version`; None for unknown versions.
+    pub fn from_version(version: &str) -> Option<Self> {
+        if version == FINGERPRINT_DEFAULT_VERSION {
+            return Some(Self::default());
+        }
+        let components = version
+            .strip_prefix("v2:")?
+            .split('+')
+            .map(FingerprintComponent::parse)
+            .collect::<Option<Vec<_>>>()?;
+        let strategy = Self { components };
+        strategy.validate().ok().map(|_| strategy)
+    }
+
+    /// (fingerprint, strategy version). When every selected component is empty the row falls
+    /// back to the v1 fingerprint, so an external_id-only strategy does not collapse all rows
+    /// without an id into one.
+    pub fn fingerprint(&self, input: &FingerprintInput<'_>) -> (String, String) {
+        let version = self.version();
+        if version == FINGERPRINT_DEFAULT_VERSION {
+            return (v1_fingerprint(input), version);
+        }
+        let values: Vec<(&str, String)> = self.components.iter().map(|c| (c.as_str(), input.value(*c))).collect();
+        if values.iter().all(|(_, v)| v.is_empty()) {
+            return (v1_fingerprint(input), FINGERPRINT_DEFAULT_VERSION.to_string());
+        }
+        let fields: Vec<String> = values.iter().map(|(k, v)| format!("{k}={v}")).collect();
+        let payload = format!("strategy={version}|{}", fields.join("|"));
+        (hex::encode(Sha256::digest(payload.as_bytes())), version)
+    }
+}
+
+pub fn load_fingerprint_strategy(conn: &Connection) -> Result<FingerprintStrategy, AppError> {
+    let strategy = get_setting_as::<FingerprintStrategy>(conn, FINGERPRINT_STRATEGY_SETTING)?.unwrap_or_default();
+    strategy.validate()?;
+    Ok(strategy)
+}
+
+pub fn save_fingerprint_strategy(conn: &Connection, strategy: &FingerprintStrategy) -> Result<(), AppError> {
+    strategy.validate()?;
+    let value = serde_json::to_value(strategy).map_err(|e| {
+        AppError::new("DB_ENCODE_FAILED", "Failed to encode fingerprint strategy").with_details(e.to_string())
+    })?;
+    set_setting(conn, FINGERPRINT_STRATEGY_SETTING, &value)
+}
+
+/// The workspace strategy followed by every other strategy already stored on incidents
+/// (NULL counts as v1), so a lookup finds rows fingerprinted before the strategy changed.
+pub(crate) fn lookup_strategies(conn: &Connection, current: &FingerprintStrategy) -> Result<Vec<FingerprintStrategy>, AppError> {
+    let mut stmt = conn
+        .prepare("SELECT DISTINCT COALESCE(fingerprint_strategy, ?1) FROM incidents ORDER BY 1")
+        .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to prepare fingerprint strategy query").with_details(e.to_string()))?;
+    let versions: Vec<String> = stmt
+        .query_map([FINGERPRINT_DEFAULT_VERSION], |r| r.get(0))
+        .and_then(|rows| rows.collect())
+        .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to query fingerprint strategies").with_details(e.to_string()))?;
+    let mut out = vec![current.clone()];
+    for strategy in versions.iter().filter_map(|v| FingerprintStrategy::from_version(v)) {
+        if !out.contains(&strategy) {
+            out.push(strategy);
+        }
+    }
+    Ok(out)
+}
@@ -0,0 +1,1439 @@
+use crate::aliases::{AliasKind, AliasResolver};
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+use crate::freeze::FrozenQuarters;
+use crate::normalize::markup::{normalize_jira_description, NormalizedText};
+use crate::normalize::timestamps::{normalize_timestamp_with_format, CustomTimestampFormat, NaiveTimestampPolicy};
+use crate::progress::{Progress, ProgressCounts};
+use crate::severity::SeverityNormalizer;
+
+use super::csv_dialect::{csv_reader, CsvDelimiter};
+use super::fingerprint::{load_fingerprint_strategy, lookup_strategies, FingerprintInput, FingerprintStrategy};
+use super::import_batches::{record_batch, ImportBatchLog};
+use super::merge::{
+    merge_prefer_existing, merge_preserve_on_empty, ConflictStrategy, IncidentRecord, TimestampUpdate, TimestampUpdates,
+};
+
+use rusqlite::Connection;
+use rusqlite::OptionalExtension;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Rows between running-count progress events; percent updates stay per row.
+const COUNTS_EVERY_ROWS: usize = 500;
+
+/// How the description column is written, so markup can be converted to CommonMark.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvDescriptionFormat {
+    /// Stored as-is.
+    #[default]
+    Plain,
+    /// Jira wiki markup or Atlassian Document Format JSON.
+    JiraMarkup,
+}
+
+impl CsvDescriptionFormat {
+    fn normalize(self, input: &str) -> NormalizedText {
+        match self {
+            CsvDescriptionFormat::Plain => NormalizedText {
+                text: input.to_string(),
+                raw: None,
+            },
+            CsvDescriptionFormat::JiraMarkup => normalize_jira_description(input),
+        }
+    }
+}
+
+/// Maps arbitrary CSV column headers onto incident fields. Every field except `title` is an
+/// optional header name; unmapped fields are left empty.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GenericCsvMapping {
+    /// CSV column header for external ID (e.g. a ticket key). Optional.
+    pub external_id: Option<String>,
+    /// CSV column header for incident title/summary. Required.
+    pub title: String,
+    pub description: Option<String>,
+    pub severity: Option<String>,
+    /// CSV column with the severity first declared (e.g. a "Original Priority" field).
+    /// Stored as the ingest-sourced initial grade; never overwrites a manual grade.
+    #[serde(default)]
+    pub initial_severity: Option<String>,
+    pub detection_source: Option<String>,
+    pub vendor: Option<String>,
+    pub service: Option<String>,
+    pub impact_pct: Option<String>,
+    pub service_health_pct: Option<String>,
+
+    pub start_ts: Option<String>,
+    pub first_observed_ts: Option<String>,
+    pub it_awareness_ts: Option<String>,
+    pub ack_ts: Option<String>,
+    pub mitigate_ts: Option<String>,
+    pub resolve_ts: Option<String>,
+
+    #[serde(default)]
+    pub description_format: CsvDescriptionFormat,
+    /// Recorded as the import batch source, e.g. "pagerduty_csv". None records "jira_csv" for
+    /// Jira markup and "csv" otherwise.
+    #[serde(default)]
+    pub source: Option<String>,
+    /// None detects the delimiter from the text.
+    #[serde(default)]
+    pub delimiter: Option<CsvDelimiter>,
+    /// How rows that match an existing incident are merged into it.
+    #[serde(default)]
+    pub conflict_strategy: ConflictStrategy,
+    /// Minutes east of UTC assumed for timestamps written without a zone, such as
+    /// "2026-01-05 14:00". None assumes UTC.
+    #[serde(default)]
+    pub assumed_utc_offset_minutes: Option<i32>,
+    /// Per-column strftime-style patterns for timestamps in a non-standard format.
+    #[serde(default)]
+    pub timestamp_formats: CsvTimestampFormats,
+}
+
+impl GenericCsvMapping {
+    fn batch_source(&self) -> &str {
+        self.source.as_deref().unwrap_or(match self.description_format {
+            CsvDescriptionFormat::JiraMarkup => "jira_csv",
+            CsvDescriptionFormat::Plain => "csv",
+        })
+    }
+}
+
+/// One incident from an export whose layout is fixed by its tool (PagerDuty, ServiceNow,
+/// incident.io, FireHydrant, Rootly), already reduced to canonical fields. Empty strings are
+/// empty cells; the description is stored as written.
+#[derive(Debug, Clone, Default)]
+pub(super) struct SourceRow {
+    pub(super) external_id: String,
+    pub(super) title: String,
+    pub(super) description: String,
+    pub(super) severity: String,
+    pub(super) service: String,
+    pub(super) start_ts: String,
+    pub(super) first_observed_ts: String,
+    pub(super) ack_ts: String,
+    pub(super) mitigate_ts: String,
+    pub(super) resolve_ts: String,
+}
+
+const SOURCE_ROW_COLUMNS: [&str; 10] = [
+    "external_id",
+    "title",
+    "description",
+    "severity",
+    "service",
+    "start_ts",
+    "first_observed_ts",
+    "ack_ts",
+    "mitigate_ts",
+    "resolve_ts",
+];
+
+impl SourceRow {
+    fn record(&self) -> csv::StringRecord {
+        csv::StringRecord::from(vec![
+            self.external_id.as_str(),
+            self.title.as_str(),
+            self.description.as_str(),
+            self.severity.as_str(),
+            self.service.as_str(),
+            self.start_ts.as_str(),
+            self.first_observed_ts.as_str(),
+            self.ack_ts.as_str(),
+            self.mitigate_ts.as_str(),
+            self.resolve_ts.as_str(),
+        ])
+    }
+
+    /// Reads every `SourceRow` field by its column name; descriptions are kept verbatim.
+    fn mapping(source: &str) -> GenericCsvMapping {
+        let col = |name: &str| Some(name.to_string());
+        GenericCsvMapping {
+            external_id: col("external_id"),
+            title: "title".to_string(),
+            description: col("description"),
+            severity: col("severity"),
+            initial_severity: None,
+            detection_source: None,
+            vendor: None,
+            service: col("service"),
+            impact_pct: None,
+            service_health_pct: None,
+            start_ts: col("start_ts"),
+            first_observed_ts: col("first_observed_ts"),
+            it_awareness_ts: None,
+            ack_ts: col("ack_ts"),
+            mitigate_ts: col("mitigate_ts"),
+            resolve_ts: col("resolve_ts"),
+            description_format: CsvDescriptionFormat::Plain,
+            source: Some(source.to_string()),
+            delimiter: None,
+            conflict_strategy: ConflictStrategy::default(),
+            assumed_utc_offset_minutes: None,
+            timestamp_formats: CsvTimestampFormats::default(),
+        }
+    }
+}
+
+/// Rows fed to the import loop, each with its progress position out of `total` (a byte offset
+/// for CSV text, an index for source rows). `Ok(None)` is a row its source already reported as
+/// unreadable; it is counted as skipped.
+struct ImportRows<I> {
+    headers: csv::StringRecord,
+    total: usize,
+    rows: I,
+}
+
+/// Optional strftime-style pattern per timestamp field, e.g. "%d/%b/%y %I:%M %p" for
+/// "05/Jan/26 2:00 PM". Cells that do not match still fall back to the built-in formats.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct CsvTimestampFormats {
+    pub start_ts: Option<String>,
+    pub first_observed_ts: Option<String>,
+    pub it_awareness_ts: Option<String>,
+    pub ack_ts: Option<String>,
+    pub mitigate_ts: Option<String>,
+    pub resolve_ts: Option<String>,
+}
+
+impl CsvTimestampFormats {
+    fn compile(field: &str, pattern: Option<&str>) -> Result<Option<CustomTimestampFormat>, AppError> {
+        pattern
+            .filter(|p| !p.trim().is_empty())
+            .map(|p| CustomTimestampFormat::from_strftime(field, p))
+            .transpose()
+    }
+
+    /// Rejects unsupported patterns, so a typo fails the save or import instead of every row.
+    pub fn validate(&self) -> Result<(), AppError> {
+        self.compiled().map(|_| ())
+    }
+
+    fn compiled(&self) -> Result<CompiledTimestampFormats, AppError> {
+        Ok(CompiledTimestampFormats {
+            start_ts: Self::compile("start_ts", self.start_ts.as_deref())?,
+            first_observed_ts: Self::compile("first_observed_ts", self.first_observed_ts.as_deref())?,
+            it_awareness_ts: Self::compile("it_awareness_ts", self.it_awareness_ts.as_deref())?,
+            ack_ts: Self::compile("ack_ts", self.ack_ts.as_deref())?,
+            mitigate_ts: Self::compile("mitigate_ts", self.mitigate_ts.as_deref())?,
+            resolve_ts: Self::compile("resolve_ts", self.resolve_ts.as_deref())?,
+        })
+    }
+}
+
+struct CompiledTimestampFormats {
+    start_ts: Option<CustomTimestampFormat>,
+    first_observed_ts: Option<CustomTimestampFormat>,
+    it_awareness_ts: Option<CustomTimestampFormat>,
+    ack_ts: Option<CustomTimestampFormat>,
+    mitigate_ts: Option<CustomTimestampFormat>,
+    resolve_ts: Option<CustomTimestampFormat>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CsvPreview {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    #[serde(default)]
+    pub delimiter: CsvDelimiter,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CsvImportConflict {
+    pub row: usize,
+    pub reason: String,
+    pub external_id: Option<String>,
+    pub fingerprint: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvRowAction {
+    Insert,
+    Update,
+    Unchanged,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CsvFieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// A row whose values disagree with the incident it matched: `before` is stored, `after`
+/// is the incoming cell. Reported for every strategy; `strategy` says which side won.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CsvValueConflict {
+    pub row: usize,
+    pub incident_id: i64,
+    pub external_id: Option<String>,
+    pub strategy: ConflictStrategy,
+    pub fields: Vec<CsvFieldChange>,
+}
+
+/// What one row would do to the workspace. Rows that end up skipped or in conflict are
+/// reported through `warnings`/`conflicts` instead.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CsvRowDiff {
+    pub row: usize,
+    pub action: CsvRowAction,
+    /// The existing incident for updates and unchanged rows.
+    pub incident_id: Option<i64>,
+    pub external_id: Option<String>,
+    /// Changed fields only; every non-empty field for inserts.
+    pub changes: Vec<CsvFieldChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CsvImportSummary {
+    pub inserted: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub conflicts: Vec<CsvImportConflict>,
+    pub warnings: Vec<ValidationWarning>,
+    /// True when nothing was written; the counts are what a real import would do.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Per-row outcome, only filled in by a dry run.
+    #[serde(default)]
+    pub row_diffs: Vec<CsvRowDiff>,
+    /// Stored and incoming values that disagreed, and how the conflict strategy settled them.
+    #[serde(default)]
+    pub value_conflicts: Vec<CsvValueConflict>,
+    /// Import batch to pass to `undo_import_batch`; None for dry runs and for rows imported
+    /// as part of a larger operation that records its own batch.
+    #[serde(default)]
+    pub batch_id: Option<i64>,
+}
+
+/// The reviewable fields of a record, in display order. Fingerprints and raw timestamps are
+/// derived values and left out.
+fn reviewable_fields(r: &IncidentRecord) -> [(&'static str, Option<String>); 15] {
+    [
+        ("external_id", r.external_id.clone()),
+        ("title", Some(r.title.clone())),
+        ("description", r.description.clone()),
+        ("severity", r.severity.clone()),
+        ("detection_source", r.detection_source.clone()),
+        ("vendor", r.vendor.clone()),
+        ("service", r.service.clone()),
+        ("impact_pct", r.impact_pct.map(|v| v.to_string())),
+        ("service_health_pct", r.service_health_pct.map(|v| v.to_string())),
+        ("start_ts", r.start_ts.clone().or_else(|| r.start_ts_raw.clone())),
+        ("first_observed_ts", r.first_observed_ts.clone().or_else(|| r.first_observed_ts_raw.clone())),
+        ("it_awareness_ts", r.it_awareness_ts.clone().or_else(|| r.it_awareness_ts_raw.clone())),
+        ("ack_ts", r.ack_ts.clone().or_else(|| r.ack_ts_raw.clone())),
+        ("mitigate_ts", r.mitigate_ts.clone().or_else(|| r.mitigate_ts_raw.clone())),
+        ("resolve_ts", r.resolve_ts.clone().or_else(|| r.resolve_ts_raw.clone())),
+    ]
+}
+
+fn field_changes(before: Option<&IncidentRecord>, after: &IncidentRecord) -> Vec<CsvFieldChange> {
+    let before = before.map(reviewable_fields);
+    reviewable_fields(after)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, (field, after))| {
+            let before = before.as_ref().and_then(|b| b[idx].1.clone());
+            (before != after).then(|| CsvFieldChange {
+                field: field.to_string(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+/// Fields where both the stored incident and the incoming row have a value and they differ.
+/// Empty cells never conflict; they are what preserve-on-empty is for.
+fn value_conflicts(existing: &IncidentRecord, incoming: &IncidentRecord) -> Vec<CsvFieldChange> {
+    reviewable_fields(existing)
+        .into_iter()
+        .zip(reviewable_fields(incoming))
+        .filter_map(|((field, before), (_, after))| match (before, after) {
+            (Some(b), Some(a)) if b != a => Some(CsvFieldChange {
+                field: field.to_string(),
+                before: Some(b),
+                after: Some(a),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// `existing` with every field the mapping reads cleared, so a preserve-on-empty merge lets
+/// the row replace mapped fields (empty cells included) and keep the unmapped ones. The
+/// external ID is the row's identity and is never cleared.
+fn clear_mapped_fields(existing: &IncidentRecord, mapping: &GenericCsvMapping) -> IncidentRecord {
+    let mut r = existing.clone();
+    let clear = |mapped: &Option<String>, v: &mut Option<String>| {
+        if mapped.is_some() {
+            *v = None;
+        }
+    };
+    clear(&mapping.description, &mut r.description);
+    clear(&mapping.severity, &mut r.severity);
+    clear(&mapping.detection_source, &mut r.detection_source);
+    clear(&mapping.vendor, &mut r.vendor);
+    clear(&mapping.service, &mut r.service);
+    if mapping.impact_pct.is_some() {
+        r.impact_pct = None;
+    }
+    if mapping.service_health_pct.is_some() {
+        r.service_health_pct = None;
+    }
+    for (mapped, canonical, raw) in [
+        (&mapping.start_ts, &mut r.start_ts, &mut r.start_ts_raw),
+        (&mapping.first_observed_ts, &mut r.first_observed_ts, &mut r.first_observed_ts_raw),
+        (&mapping.it_awareness_ts, &mut r.it_awareness_ts, &mut r.it_awareness_ts_raw),
+        (&mapping.ack_ts, &mut r.ack_ts, &mut r.ack_ts_raw),
+        (&mapping.mitigate_ts, &mut r.mitigate_ts, &mut r.mitigate_ts_raw),
+        (&mapping.resolve_ts, &mut r.resolve_ts, &mut r.resolve_ts_raw),
+    ] {
+        if mapped.is_some() {
+            *canonical = None;
+            *raw = None;
+        }
+    }
+    r
+}
+
+fn get<'a>(
+    row: &'a csv::StringRecord,
+    headers: &'a csv::StringRecord,
+    header_name: &str,
+) -> Option<&'a str> {
+    headers
+        .iter()
+        .position(|h| h == header_name)
+        .and_then(|idx| row.get(idx))
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+}
+
+/// Applies the workspace severity mappings to a cell. Unmapped values are dropped, and tallied
+/// case-insensitively for one warning each, rather than adding a spelling to severity counts.
+fn normalize_severity_cell(
+    normalizer: &SeverityNormalizer,
+    raw: Option<&str>,
+    row_idx: usize,
+    unmapped: &mut BTreeMap<String, (String, usize, usize)>,
+) -> Option<String> {
+    match normalizer.normalize(raw?) {
+        Ok(severity) => Some(severity),
+        Err(value) => {
+            unmapped.entry(value.to_lowercase()).or_insert((value, row_idx, 0)).2 += 1;
+            None
+        }
+    }
+}
+
+fn parse_pct(raw: Option<&str>, field: &str, warnings: &mut Vec<ValidationWarning>) -> Option<i64> {
+    let s = raw?;
+    match s.parse::<i64>() {
+        Ok(v) if (0..=100).contains(&v) => Some(v),
+        Ok(v) => {
+            warnings.push(
+                ValidationWarning::new(
+                    "VALIDATION_PCT_OUT_OF_RANGE",
+                    format!("{field} out of range"),
+                )
+                .with_details(format!("value={v}")),
+            );
+            None
+        }
+        Err(e) => {
+            warnings.push(
+                ValidationWarning::new(
+                    "VALIDATION_PCT_PARSE_FAILED",
+                    format!("Failed to parse {field}"),
+                )
+                .with_details(format!("value={s}; err={e}")),
+            );
+            None
+        }
+    }
+}
+
+fn ts_update_from_cell(
+    row_idx: usize,
+    field: &str,
+    cell: Option<String>,
+    format: Option<&CustomTimestampFormat>,
+    policy: NaiveTimestampPolicy,
+    warnings: &mut Vec<ValidationWarning>,
+) -> TimestampUpdate {
+    let Some(v) = cell else {
+        return TimestampUpdate::not_provided();
+    };
+
+    let norm = normalize_timestamp_with_format(field, &v, format, policy, warnings);
+    if norm.raw.is_some() && norm.canonical_rfc3339_utc.is_none() {
+        warnings.push(
+            ValidationWarning::new(
+                "INGEST_TS_RAW_STORED",
+                format!("Stored raw timestamp for {field} (no canonical)"),
+            )
+            .with_details(format!("row={row_idx}")),
+        );
+    }
+
+    TimestampUpdate {
+        provided: true,
+        canonical: norm.canonical_rfc3339_utc,
+        raw: norm.raw,
+    }
+}
+
+/// Headers and the first `max_rows` rows, with the delimiter detected from the text.
+pub fn preview_generic_csv(csv_text: &str, max_rows: usize) -> Result<CsvPreview, AppError> {
+    let (mut rdr, delimiter) = csv_reader(csv_text, None);
+
+    let headers = rdr
+        .headers()
+        .map_err(|e| {
+            AppError::new(
+                "INGEST_CSV_HEADERS_FAILED",
+                "Failed to read CSV headers",
+            )
+            .with_details(e.to_string())
+        })?
+        .iter()
+        .map(|h| h.to_string())
+        .collect::<Vec<_>>();
+
+    let mut rows = Vec::new();
+    for result in rdr.records().take(max_rows) {
+        let row = result.map_err(|e| {
+            AppError::new(
+                "INGEST_CSV_PARSE_FAILED",
+                "Failed to parse CSV row",
+            )
+            .with_details(e.to_string())
+        })?;
+        rows.push(row.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+    }
+
+    Ok(CsvPreview {
+        headers,
+        rows,
+        delimiter,
+    })
+}
+
+fn is_unique_constraint_error(err: &rusqlite::Error) -> bool {
+    match err {
+        // rusqlite versions differ in the granularity of constraint error codes.
+        // We treat any constraint violation as a conflict that must be surfaced explicitly.
+        rusqlite::Error::SqliteFailure(e, _) => e.code == rusqlite::ErrorCode::ConstraintViolation,
+        _ => false,
+    }
+}
+
+/// Stores the original description markup next to the normalized description (NULL when unchanged).
+fn set_description_raw(conn: &Connection, id: i64, raw: Option<&str>) -> Result<(), AppError> {
+    conn.execute(
+        "UPDATE incidents SET description_raw = ?1 WHERE id = ?2",
+        rusqlite::params![raw, id],
+    )
+    .map_err(|e| {
+        AppError::new(
+            "INGEST_CSV_UPDATE_FAILED",
+            "Failed to store raw incident description",
+        )
+        .with_details(format!("id={id}; err={e}"))
+    })?;
+    Ok(())
+}
+
+fn find_existing_by_external_id(
+    conn: &Connection,
+    external_id: &str,
+) -> Result<Option<i64>, AppError> {
+    conn.query_row(
+        "SELECT id FROM incidents WHERE external_id = ?1",
+        [external_id],
+        |row| row.get::<_, i64>(0),
+    )
+    .optional()
+    .map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query incident by external_id")
+            .with_details(e.to_string())
+    })
+}
+
+/// Tries the row's fingerprint under each strategy in `strategies`, stopping at the first match.
+fn find_existing_by_fingerprint(
+    conn: &Connection,
+    strategies: &[FingerprintStrategy],
+    input: &FingerprintInput<'_>,
+) -> Result<Option<i64>, AppError> {
+    for strategy in strategies {
+        let (fp, version) = strategy.fingerprint(input);
+        let found = conn
+            .query_row(
+                "SELECT id FROM incidents WHERE fingerprint = ?1 AND COALESCE(fingerprint_strategy, 'v1') = ?2",
+                rusqlite::params![fp, version],
+                |row| row.get::<_, i64>(0),
+            )
+            .optional()
+            .map_err(|e| {
+                AppError::new("DB_QUERY_FAILED", "Failed to query incident by fingerprint")
+                    .with_details(e.to_string())
+            })?;
+        if found.is_some() {
+            return Ok(found);
+        }
+    }
+    Ok(None)
+}
+
+fn fingerprint_input(r: &IncidentRecord) -> FingerprintInput<'_> {
+    FingerprintInput {
+        external_id: r.external_id.as_deref(),
+        title: &r.title,
+        vendor: r.vendor.as_deref(),
+        service: r.service.as_deref(),
+        start_ts: r.start_ts.as_deref().or(r.start_ts_raw.as_deref()),
+        first_observed_ts: r.first_observed_ts.as_deref().or(r.first_observed_ts_raw.as_deref()),
+        ack_ts: r.ack_ts.as_deref().or(r.ack_ts_raw.as_deref()),
+        mitigate_ts: r.mitigate_ts.as_deref().or(r.mitigate_ts_raw.as_deref()),
+        resolve_ts: r.resolve_ts.as_deref().or(r.resolve_ts_raw.as_deref()),
+    }
+}
+
+/// Strategy an existing incident's fingerprint was computed with; NULL is v1.
+fn stored_fingerprint_strategy(conn: &Connection, id: i64) -> Result<Option<FingerprintStrategy>, AppError> {
+    let version: Option<String> = conn
+        .query_row("SELECT fingerprint_strategy FROM incidents WHERE id = ?1", [id], |row| row.get(0))
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to load incident fingerprint strategy").with_details(e.to_string())
+        })?;
+    Ok(FingerprintStrategy::from_version(version.as_deref().unwrap_or("v1")))
+}
+
+fn load_incident_record_for_compare(
+    conn: &Connection,
+    id: i64,
+) -> Result<IncidentRecord, AppError> {
+    conn.query_row(
+        r#"
+      SELECT external_id, fingerprint, title, description, severity,
+             detection_source, vendor, service,
+             impact_pct, service_health_pct,
+             start_ts, first_observed_ts, it_awareness_ts, ack_ts, mitigate_ts, resolve_ts,
+             start_ts_raw, first_observed_ts_raw, it_awareness_ts_raw, ack_ts_raw, mitigate_ts_raw, resolve_ts_raw
+      FROM incidents WHERE id = ?1
+      "#,
+        [id],
+        |row| {
+            Ok(IncidentRecord {
+                external_id: row.get(0)?,
+                fingerprint: row.get(1)?,
+                title: row.get(2)?,
+                description: row.get(3)?,
+                severity: row.get(4)?,
+                detection_source: row.get(5)?,
+                vendor: row.get(6)?,
+                service: row.get(7)?,
+                impact_pct: row.get(8)?,
+                service_health_pct: row.get(9)?,
+                start_ts: row.get(10)?,
+                first_observed_ts: row.get(11)?,
+                it_awareness_ts: row.get(12)?,
+                ack_ts: row.get(13)?,
+                mitigate_ts: row.get(14)?,
+                resolve_ts: row.get(15)?,
+                start_ts_raw: row.get(16)?,
+                first_observed_ts_raw: row.get(17)?,
+                it_awareness_ts_raw: row.get(18)?,
+                ack_ts_raw: row.get(19)?,
+                mitigate_ts_raw: row.get(20)?,
+                resolve_ts_raw: row.get(21)?,
+            })
+        },
+    )
+    .map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to load incident for compare")
+            .with_details(e.to_string())
+    })
+}
+
+pub fn import_generic_csv(
+    conn: &mut Connection,
+    csv_text: &str,
+    mapping: &GenericCsvMapping,
+) -> Result<CsvImportSummary, AppError> {
+    import_generic_csv_with_progress(conn, csv_text, mapping, &mut Progress::noop("import_generic_csv"))
+}
+
+/// Same as `import_generic_csv`, reporting per-row progress (by byte offset), running counts
+/// and warnings as they are produced. Runs in one transaction: a cancelled or failed import
+/// leaves the workspace untouched.
+pub fn import_generic_csv_with_progress(
+    conn: &mut Connection,
+    csv_text: &str,
+    mapping: &GenericCsvMapping,
+    progress: &mut Progress<'_>,
+) -> Result<CsvImportSummary, AppError> {
+    run_generic_csv_import(conn, csv_text, mapping, false, progress)
+}
+
+/// Runs the whole import (constraints, frozen quarters and merges included) in a transaction
+/// that is rolled back, returning the counts plus a per-row field diff against existing
+/// incidents. Nothing is written.
+pub fn dry_run_generic_csv_import(
+    conn: &mut Connection,
+    csv_text: &str,
+    mapping: &GenericCsvMapping,
+    progress: &mut Progress<'_>,
+) -> Result<CsvImportSummary, AppError> {
+    run_generic_csv_import(conn, csv_text, mapping, true, progress)
+}
+
+fn run_generic_csv_import(
+    conn: &mut Connection,
+    csv_text: &str,
+    mapping: &GenericCsvMapping,
+    dry_run: bool,
+    progress: &mut Progress<'_>,
+) -> Result<CsvImportSummary, AppError> {
+    run_import(conn, mapping, dry_run, progress, |tx, batch, p| {
+        import_generic_csv_rows(tx, csv_text, mapping, dry_run, batch, p)
+    })
+}
+
+/// Imports rows built by a tool-specific importer through the same dedupe, merge,
+/// frozen-quarter and conflict handling as CSV rows, recording the batch under `source`.
+/// `None` entries are rows the caller already reported as unreadable.
+pub(super) fn import_source_rows_with_progress(
+    conn: &mut Connection,
+    rows: &[Option<SourceRow>],
+    source: &str,
+    progress: &mut Progress<'_>,
+) -> Result<CsvImportSummary, AppError> {
+    let mapping = SourceRow::mapping(source);
+    run_import(conn, &mapping, false, progress, |tx, batch, p| {
+        let input = ImportRows {
+            headers: csv::StringRecord::from(SOURCE_ROW_COLUMNS.to_vec()),
+            total: rows.len(),
+            rows: rows.iter().enumerate().map(|(idx, row)| (Some(idx), Ok(row.as_ref().map(SourceRow::record)))),
+        };
+        import_rows(tx, input, &mapping, false, batch, p)
+    })
+}
+
+/// Runs `import` in a transaction, recording an import batch unless this is a dry run (which
+/// is rolled back).
+fn run_import(
+    conn: &mut Connection,
+    mapping: &GenericCsvMapping,
+    dry_run: bool,
+    progress: &mut Progress<'_>,
+    import: impl FnOnce(&Connection, Option<&mut ImportBatchLog>, &mut Progress<'_>) -> Result<CsvImportSummary, AppError>,
+) -> Result<CsvImportSummary, AppError> {
+    progress.run(|p| {
+        let tx = conn
+            .transaction()
+            .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to start transaction").with_details(e.to_string()))?;
+        let mut batch = (!dry_run).then(ImportBatchLog::default);
+        let mut summary = import(&tx, batch.as_mut(), p)?;
+        if let Some(batch) = &batch {
+            let row_count = summary.inserted + summary.updated + summary.skipped;
+            summary.batch_id = Some(record_batch(&tx, mapping.batch_source(), row_count, batch)?);
+        }
+        if dry_run {
+            tx.rollback()
+                .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to roll back transaction").with_details(e.to_string()))?;
+        } else {
+            tx.commit()
+                .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to commit transaction").with_details(e.to_string()))?;
+        }
+        Ok(summary)
+    })
+}
+
+pub(super) fn import_generic_csv_rows(
+    conn: &Connection,
+    csv_text: &str,
+    mapping: &GenericCsvMapping,
+    dry_run: bool,
+    batch: Option<&mut ImportBatchLog>,
+    progress: &mut Progress<'_>,
+) -> Result<CsvImportSummary, AppError> {
+    let (mut rdr, _) = csv_reader(csv_text, mapping.delimiter);
+
+    let headers = rdr
+        .headers()
+        .map_err(|e| {
+            AppError::new(
+                "INGEST_CSV_HEADERS_FAILED",
+                "Failed to read CSV headers",
+            )
+            .with_details(e.to_string())
+        })?
+        .clone();
+    let input = ImportRows {
+        headers,
+        total: csv_text.len(),
+        rows: rdr.into_records().map(|result| {
+            let pos = result.as_ref().ok().and_then(|r| r.position()).map(|p| p.byte() as usize);
+            (pos, result.map(Some))
+        }),
+    };
+    import_rows(conn, input, mapping, dry_run, batch, progress)
+}
+
+fn import_rows(
+    conn: &Connection,
+    input: ImportRows<impl Iterator<Item = (Option<usize>, Result<Option<csv::StringRecord>, csv::Error>)>>,
+    mapping: &GenericCsvMapping,
+    dry_run: bool,
+    mut batch: Option<&mut ImportBatchLog>,
+    progress: &mut Progress<'_>,
+) -> Result<CsvImportSummary, AppError> {
+    let mut warnings = Vec::new();
+    let mut row_diffs = Vec::new();
+    let mut value_conflict_rows = Vec::new();
+    let mut reported_warnings = 0usize;
+    let mut conflicts = Vec::new();
+    let mut inserted = 0usize;
+    let mut updated = 0usize;
+    let mut skipped = 0usize;
+
+    let ts_policy = NaiveTimestampPolicy::from_offset_minutes(mapping.assumed_utc_offset_minutes)?;
+    let ts_formats = mapping.timestamp_formats.compiled()?;
+
+    let mut seen_external_ids = std::collections::HashSet::<String>::new();
+    let mut seen_fps = std::collections::HashSet::<String>::new();
+    let fp_strategy = load_fingerprint_strategy(conn)?;
+    let fp_lookup = lookup_strategies(conn, &fp_strategy)?;
+    let severity_normalizer = SeverityNormalizer::load(conn)?;
+    let aliases = AliasResolver::load(conn)?;
+    let mut unmapped_severities = BTreeMap::<String, (String, usize, usize)>::new();
+    // Frozen quarters are closed books: rows that would touch them are surfaced as conflicts.
+    let frozen_quarters = FrozenQuarters::load(conn)?;
+
+    let headers = input.headers;
+
+    let mut processed = 0usize;
+    let counts = |inserted, updated, skipped, conflicts: &Vec<CsvImportConflict>, processed| ProgressCounts {
+        processed,
+        inserted,
+        updated,
+        skipped,
+        conflicts: conflicts.len(),
+    };
+
+    for (row_idx, (pos, result)) in input.rows.enumerate() {
+        for w in &warnings[reported_warnings..] {
+            progress.warning("rows", w);
+        }
+        reported_warnings = warnings.len();
+        if progress.is_cancelled() {
+            return Err(AppError::new("INGEST_IMPORT_CANCELLED", "CSV import was cancelled")
+                .with_details(format!("rows_processed={row_idx}")));
+        }
+        if row_idx > 0 && row_idx % COUNTS_EVERY_ROWS == 0 {
+            progress.counts("rows", counts(inserted, updated, skipped, &conflicts, row_idx));
+        }
+        processed = row_idx + 1;
+
+        let row = match result {
+            Ok(Some(r)) => r,
+            Ok(None) => {
+                skipped += 1;
+                continue;
+            }
+            Err(e) => {
+                warnings.push(
+                    ValidationWarning::new(
+                        "INGEST_CSV_PARSE_FAILED",
+                        "Failed to parse CSV row",
+                    )
+                    .with_details(format!("row={row_idx}; err={e}")),
+                );
+                skipped += 1;
+                continue;
+            }
+        };
+        if let Some(pos) = pos {
+            progress.step("rows", pos, input.total, 1, 99);
+        }
+
+        let title = get(&row, &headers, &mapping.title)
+            .unwrap_or("")
+            .to_string();
+        if title.trim().is_empty() {
+            warnings.push(
+                ValidationWarning::new("INGEST_MISSING_TITLE", "Row missing required title")
+                    .with_details(format!("row={row_idx}")),
+            );
+            skipped += 1;
+            continue;
+        }
+
+        let external_id = mapping
+            .external_id
+            .as_deref()
+            .and_then(|h| get(&row, &headers, h))
+            .map(|s| s.to_string());
+
+        if let Some(ref ext) = external_id {
+            if !seen_external_ids.insert(ext.clone()) {
+                conflicts.push(CsvImportConflict {
+                    row: row_idx,
+                    reason: "Duplicate external_id in import".to_string(),
+                    external_id: Some(ext.clone()),
+                    fingerprint: None,
+                });
+                skipped += 1;
+                continue;
+            }
+        }
+
+        let normalized_description = mapping
+            .description
+            .as_deref()
+            .and_then(|h| get(&row, &headers, h))
+            .map(|d| mapping.description_format.normalize(d));
+        let description = normalized_description.as_ref().map(|n| n.text.clone());
+        let description_raw = normalized_description.and_then(|n| n.raw);
+
+        let severity = normalize_severity_cell(
+            &severity_normalizer,
+            mapping.severity.as_deref().and_then(|h| get(&row, &headers, h)),
+            row_idx,
+            &mut unmapped_severities,
+        );
+
+        let initial_severity = normalize_severity_cell(
+            &severity_normalizer,
+            mapping.initial_severity.as_deref().and_then(|h| get(&row, &headers, h)),
+            row_idx,
+            &mut unmapped_severities,
+        );
+
+        let detection_source = mapping
+            .detection_source
+            .as_deref()
+            .and_then(|h| get(&row, &headers, h))
+            .map(|s| s.to_string());
+
+        let vendor = mapping
+            .vendor
+            .as_deref()
+            .and_then(|h| get(&row, &headers, h))
+            .map(|s| aliases.resolve(AliasKind::Vendor, s));
+
+        let service = mapping
+            .service
+            .as_deref()
+            .and_then(|h| get(&row, &headers, h))
+            .map(|s| aliases.resolve(AliasKind::Service, s));
+
+        let start_update = ts_update_from_cell(
+            row_idx,
+            "start_ts",
+            mapping
+                .start_ts
+                .as_deref()
+                .and_then(|h| get(&row, &headers, h))
+                .map(|s| s.to_string()),
+            ts_formats.start_ts.as_ref(),
+            ts_policy,
+            &mut warnings,
+        );
+        let first_observed_update = ts_update_from_cell(
+            row_idx,
+            "first_observed_ts",
+            mapping
+                .first_observed_ts
+                .as_deref()
+                .and_then(|h| get(&row, &headers, h))
+                .map(|s| s.to_string()),
+            ts_formats.first_observed_ts.as_ref(),
+            ts_policy,
+            &mut warnings,
+        );
+        let it_awareness_update = ts_update_from_cell(
+            row_idx,
+            "it_awareness_ts",
+            mapping
+                .it_awareness_ts
+                .as_deref()
+                .and_then(|h| get(&row, &headers, h))
+                .map(|s| s.to_string()),
+            ts_formats.it_awareness_ts.as_ref(),
+            ts_policy,
+            &mut warnings,
+        );
+        let ack_update = ts_update_from_cell(
+            row_idx,
+            "ack_ts",
+            mapping
+                .ack_ts
+                .as_deref()
+                .and_then(|h| get(&row, &headers, h))
+                .map(|s| s.to_string()),
+            ts_formats.ack_ts.as_ref(),
+            ts_policy,
+            &mut warnings,
+        );
+        let mitigate_update = ts_update_from_cell(
+            row_idx,
+            "mitigate_ts",
+            mapping
+                .mitigate_ts
+                .as_deref()
+                .and_then(|h| get(&row, &headers, h))
+                .map(|s| s.to_string()),
+            ts_formats.mitigate_ts.as_ref(),
+            ts_policy,
+            &mut warnings,
+        );
+        let resolve_update = ts_update_from_cell(
+            row_idx,
+            "resolve_ts",
+            mapping
+                .resolve_ts
+                .as_deref()
+                .and_then(|h| get(&row, &headers, h))
+                .map(|s| s.to_string()),
+            ts_formats.resolve_ts.as_ref(),
+            ts_policy,
+            &mut warnings,
+        );
+
+        let start_ts = start_update.canonical.clone();
+        let start_ts_raw = start_update.raw.clone();
+        let first_observed_ts = first_observed_update.canonical.clone();
+        let first_observed_ts_raw = first_observed_update.raw.clone();
+        let it_awareness_ts = it_awareness_update.canonical.clone();
+        let it_awareness_ts_raw = it_awareness_update.raw.clone();
+        let ack_ts = ack_update.canonical.clone();
+        let ack_ts_raw = ack_update.raw.clone();
+        let mitigate_ts = mitigate_update.canonical.clone();
+        let mitigate_ts_raw = mitigate_update.raw.clone();
+        let resolve_ts = resolve_update.canonical.clone();
+        let resolve_ts_raw = resolve_update.raw.clone();
+
+        let impact_pct = parse_pct(
+            mapping
+                .impact_pct
+                .as_deref()
+                .and_then(|h| get(&row, &headers, h)),
+            "impact_pct",
+            &mut warnings,
+        );
+        let service_health_pct = parse_pct(
+            mapping
+                .service_health_pct
+                .as_deref()
+                .and_then(|h| get(&row, &headers, h)),
+            "service_health_pct",
+            &mut warnings,
+        );
+
+        let fp_input = FingerprintInput {
+            external_id: external_id.as_deref(),
+            title: &title,
+            vendor: vendor.as_deref(),
+            service: service.as_deref(),
+            start_ts: start_ts.as_deref().or(start_ts_raw.as_deref()),
+            first_observed_ts: first_observed_ts.as_deref().or(first_observed_ts_raw.as_deref()),
+            ack_ts: ack_ts.as_deref().or(ack_ts_raw.as_deref()),
+            mitigate_ts: mitigate_ts.as_deref().or(mitigate_ts_raw.as_deref()),
+            resolve_ts: resolve_ts.as_deref().or(resolve_ts_raw.as_deref()),
+        };
+        let (fp, fp_version) = fp_strategy.fingerprint(&fp_input);
+
+        if !seen_fps.insert(fp.clone()) {
+            conflicts.push(CsvImportConflict {
+                row: row_idx,
+                reason: "Duplicate fingerprint in import".to_string(),
+                external_id: external_id.clone(),
+                fingerprint: Some(fp),
+            });
+            skipped += 1;
+            continue;
+        }
+
+        let existing_id = if let Some(ref ext) = external_id {
+            find_existing_by_external_id(conn, ext)?
+        } else {
+            find_existing_by_fingerprint(conn, &fp_lookup, &fp_input)?
+        };
+
+        if let Some(id) = existing_id {
+            let existing = load_incident_record_for_compare(conn, id)?;
+            let incoming = IncidentRecord {
+                external_id: external_id.clone(),
+                fingerprint: fp.clone(), // placeholder; recomputed after merge
+                title: title.clone(),
+                description: description.clone(),
+                severity: severity.clone(),
+                detection_source: detection_source.clone(),
+                vendor: vendor.clone(),
+                service: service.clone(),
+                impact_pct,
+                service_health_pct,
+                start_ts: start_ts.clone(),
+                first_observed_ts: first_observed_ts.clone(),
+                it_awareness_ts: it_awareness_ts.clone(),
+                ack_ts: ack_ts.clone(),
+                mitigate_ts: mitigate_ts.clone(),
+                resolve_ts: resolve_ts.clone(),
+                start_ts_raw: start_ts_raw.clone(),
+                first_observed_ts_raw: first_observed_ts_raw.clone(),
+                it_awareness_ts_raw: it_awareness_ts_raw.clone(),
+                ack_ts_raw: ack_ts_raw.clone(),
+                mitigate_ts_raw: mitigate_ts_raw.clone(),
+                resolve_ts_raw: resolve_ts_raw.clone(),
+            };
+
+            let strategy = mapping.conflict_strategy;
+            let disagreements = value_conflicts(&existing, &incoming);
+            if !disagreements.is_empty() {
+                value_conflict_rows.push(CsvValueConflict {
+                    row: row_idx,
+                    incident_id: id,
+                    external_id: existing.external_id.clone(),
+                    strategy,
+                    fields: disagreements.clone(),
+                });
+            }
+            if strategy == ConflictStrategy::SkipConflicts && !disagreements.is_empty() {
+                let fields: Vec<&str> = disagreements.iter().map(|c| c.field.as_str()).collect();
+                conflicts.push(CsvImportConflict {
+                    row: row_idx,
+                    reason: format!("Incoming values conflict with stored {}; row skipped", fields.join(", ")),
+                    external_id: existing.external_id.clone(),
+                    fingerprint: Some(existing.fingerprint.clone()),
+                });
+                skipped += 1;
+                continue;
+            }
+
+            let ts_updates = TimestampUpdates {
+                start: start_update.clone(),
+                first_observed: first_observed_update.clone(),
+                it_awareness: it_awareness_update.clone(),
+                ack: ack_update.clone(),
+                mitigate: mitigate_update.clone(),
+                resolve: resolve_update.clone(),
+            };
+            let mut desired = match strategy {
+                ConflictStrategy::PreferExisting => merge_prefer_existing(&existing, incoming, ts_updates),
+                ConflictStrategy::PreferIncoming => {
+                    merge_preserve_on_empty(&clear_mapped_fields(&existing, mapping), incoming, ts_updates)
+                }
+                ConflictStrategy::MergePreserveOnEmpty | ConflictStrategy::SkipConflicts => {
+                    merge_preserve_on_empty(&existing, incoming, ts_updates)
+                }
+            };
+            // Recompute the fingerprint from the merged record under the strategy the incident
+            // was first fingerprinted with, so dedupe stays stable across strategy changes.
+            let row_strategy = stored_fingerprint_strategy(conn, id)?.unwrap_or_else(|| fp_strategy.clone());
+            let (merged_fp, merged_fp_version) = row_strategy.fingerprint(&fingerprint_input(&desired));
+            desired.fingerprint = merged_fp;
+
+            if existing == desired {
+                let frozen = frozen_quarters.for_incident(conn, id)?;
+                if let (Some(sev), None) = (initial_severity.as_deref(), frozen) {
+                    crate::severity::record_ingest_severity(conn, id, sev)?;
+                }
+                if dry_run {
+                    row_diffs.push(CsvRowDiff {
+                        row: row_idx,
+                        action: CsvRowAction::Unchanged,
+                        incident_id: Some(id),
+                        external_id: existing.external_id.clone(),
+                        changes: Vec::new(),
+                    });
+                }
+                skipped += 1;
+                continue;
+            }
+
+            let frozen = frozen_quarters.for_incident(conn, id)?.or_else(|| {
+                frozen_quarters.for_timestamps(
+                    desired.start_ts.as_deref(),
+                    desired.first_observed_ts.as_deref(),
+                    desired.resolve_ts.as_deref(),
+                )
+            });
+            if let Some(q) = frozen {
+                conflicts.push(CsvImportConflict {
+                    row: row_idx,
+                    reason: format!("Incident belongs to frozen quarter {q}; update not applied"),
+                    external_id: desired.external_id.clone(),
+                    fingerprint: Some(desired.fingerprint.clone()),
+                });
+                skipped += 1;
+                continue;
+            }
+
+            let pending = batch
+                .is_some()
+                .then(|| ImportBatchLog::prepare_update(conn, id))
+                .transpose()?;
+            let res = conn.execute(
+                r#"
+        UPDATE incidents SET
+          external_id = ?1,
+          fingerprint = ?2,
+          title = ?3,
+          description = ?4,
+          severity = ?5,
+          detection_source = ?6,
+          vendor = ?7,
+          service = ?8,
+          impact_pct = ?9,
+          service_health_pct = ?10,
+          start_ts = ?11,
+          first_observed_ts = ?12,
+          it_awareness_ts = ?13,
+          ack_ts = ?14,
+          mitigate_ts = ?15,
+          resolve_ts = ?16,
+          start_ts_raw = ?17,
+          first_observed_ts_raw = ?18,
+          it_awareness_ts_raw = ?19,
+          ack_ts_raw = ?20,
+          mitigate_ts_raw = ?21,
+          resolve_ts_raw = ?22,
+          fingerprint_strategy = ?24,
+          ingested_at = strftime('%Y-%m-%dT%H:%M:%fZ','now')
+        WHERE id = ?23
+        "#,
+                rusqlite::params![
+                    desired.external_id,
+                    desired.fingerprint,
+                    desired.title,
+                    desired.description,
+                    desired.severity,
+                    desired.detection_source,
+                    desired.vendor,
+                    desired.service,
+                    desired.impact_pct,
+                    desired.service_health_pct,
+                    desired.start_ts,
+                    desired.first_observed_ts,
+                    desired.it_awareness_ts,
+                    desired.ack_ts,
+                    desired.mitigate_ts,
+                    desired.resolve_ts,
+                    desired.start_ts_raw,
+                    desired.first_observed_ts_raw,
+                    desired.it_awareness_ts_raw,
+                    desired.ack_ts_raw,
+                    desired.mitigate_ts_raw,
+                    desired.resolve_ts_raw,
+                    id,
+                    merged_fp_version
+                ],
+            );
+
+            match res {
+                Ok(_) => {
+                    // Only the incoming description carries its raw markup; a preserved one keeps its own.
+                    if desired.description == description && (description.is_some() || existing.description.is_some()) {
+                        set_description_raw(conn, id, description_raw.as_deref())?;
+                    }
+                    if let Some(sev) = initial_severity.as_deref() {
+                        crate::severity::record_ingest_severity(conn, id, sev)?;
+                    }
+                    if let (Some(log), Some(pending)) = (batch.as_deref_mut(), pending) {
+                        log.updated(pending);
+                    }
+                    if dry_run {
+                        row_diffs.push(CsvRowDiff {
+                            row: row_idx,
+                            action: CsvRowAction::Update,
+                            incident_id: Some(id),
+                            external_id: desired.external_id.clone(),
+                            changes: field_changes(Some(&existing), &desired),
+                        });
+                    }
+                    updated += 1;
+                }
+                Err(e) if is_unique_constraint_error(&e) => {
+                    conflicts.push(CsvImportConflict {
+                        row: row_idx,
+                        reason: "Uniqueness constraint conflict while updating incident"
+                            .to_string(),
+                        external_id: desired.external_id.clone(),
+                        fingerprint: Some(desired.fingerprint.clone()),
+                    });
+                    skipped += 1;
+                }
+                Err(e) => {
+                    return Err(AppError::new(
+                        "INGEST_CSV_UPDATE_FAILED",
+                        "Failed to update incident from CSV",
+                    )
+                    .with_details(format!("row={row_idx}; err={e}")));
+                }
+            }
+            continue;
+        }
+
+        if let Some(q) =
+            frozen_quarters.for_timestamps(start_ts.as_deref(), first_observed_ts.as_deref(), resolve_ts.as_deref())
+        {
+            conflicts.push(CsvImportConflict {
+                row: row_idx,
+                reason: format!("Incident falls in frozen quarter {q}; insert not applied"),
+                external_id: external_id.clone(),
+                fingerprint: Some(fp.clone()),
+            });
+            skipped += 1;
+            continue;
+        }
+
+        let res = conn.execute(
+            r#"
+      INSERT INTO incidents(
+        external_id, fingerprint, title, description, severity,
+        detection_source, vendor, service,
+        impact_pct, service_health_pct,
+        start_ts, first_observed_ts, it_awareness_ts, ack_ts, mitigate_ts, resolve_ts,
+        start_ts_raw, first_observed_ts_raw, it_awareness_ts_raw, ack_ts_raw, mitigate_ts_raw, resolve_ts_raw,
+        fingerprint_strategy, ingested_at
+      ) VALUES (
+        ?1, ?2, ?3, ?4, ?5,
+        ?6, ?7, ?8,
+        ?9, ?10,
+        ?11, ?12, ?13, ?14, ?15, ?16,
+        ?17, ?18, ?19, ?20, ?21, ?22,
+        ?23, strftime('%Y-%m-%dT%H:%M:%fZ','now')
+      )
+      "#,
+            rusqlite::params![
+                external_id,
+                fp,
+                title,
+                description,
+                severity,
+                detection_source,
+                vendor,
+                service,
+                impact_pct,
+                service_health_pct,
+                start_ts,
+                first_observed_ts,
+                it_awareness_ts,
+                ack_ts,
+                mitigate_ts,
+                resolve_ts,
+                start_ts_raw,
+                first_observed_ts_raw,
+                it_awareness_ts_raw,
+                ack_ts_raw,
+                mitigate_ts_raw,
+                resolve_ts_raw,
+                fp_version,
+            ],
+        );
+
+        match res {
+            Ok(_) => {
+                let id = conn.last_insert_rowid();
+                if description_raw.is_some() {
+                    set_description_raw(conn, id, description_raw.as_deref())?;
+                }
+                if let Some(sev) = initial_severity.as_deref() {
+                    crate::severity::record_ingest_severity(conn, id, sev)?;
+                }
+                if let Some(log) = batch.as_deref_mut() {
+                    log.inserted(id);
+                }
+                if dry_run {
+                    let record = IncidentRecord {
+                        external_id: external_id.clone(),
+                        fingerprint: fp.clone(),
+                        title: title.clone(),
+                        description: description.clone(),
+                        severity: severity.clone(),
+                        detection_source: detection_source.clone(),
+                        vendor: vendor.clone(),
+                        service: service.clone(),
+                        impact_pct,
+                        service_health_pct,
+                        start_ts: start_ts.clone(),
+                        first_observed_ts: first_observed_ts.clone(),
+                        it_awareness_ts: it_awareness_ts.clone(),
+                        ack_ts: ack_ts.clone(),
+                        mitigate_ts: mitigate_ts.clone(),
+                        resolve_ts: resolve_ts.clone(),
+                        start_ts_raw: start_ts_raw.clone(),
+                        first_observed_ts_raw: first_observed_ts_raw.clone(),
+                        it_awareness_ts_raw: it_awareness_ts_raw.clone(),
+                        ack_ts_raw: ack_ts_raw.clone(),
+                        mitigate_ts_raw: mitigate_ts_raw.clone(),
+                        resolve_ts_raw: resolve_ts_raw.clone(),
+                    };
+                    row_diffs.push(CsvRowDiff {
+                        row: row_idx,
+                        action: CsvRowAction::Insert,
+                        incident_id: None,
+                        external_id: external_id.clone(),
+                        changes: field_changes(None, &record),
+                    });
+                }
+                inserted += 1;
+            }
+            Err(e) if is_unique_constraint_error(&e) => {
+                conflicts.push(CsvImportConflict {
+                    row: row_idx,
+                    reason: "Uniqueness constraint conflict while inserting incident".to_string(),
+                    external_id: external_id.clone(),
+                    fingerprint: Some(fp.clone()),
+                });
+                skipped += 1;
+            }
+            Err(e) => {
+                return Err(AppError::new(
+                    "INGEST_CSV_INSERT_FAILED",
+                    "Failed to insert incident from CSV",
+                )
+                .with_details(format!("row={row_idx}; err={e}")));
+            }
+        }
+    }
+
+    for (value, first_row, rows) in unmapped_severities.into_values() {
+        warnings.push(
+            ValidationWarning::new(
+                "INGEST_SEVERITY_UNMAPPED",
+                "Severity has no workspace mapping; left blank",
+            )
+            .with_details(format!("severity={value}; rows={rows}; first_row={first_row}")),
+        );
+    }
+    for w in &warnings[reported_warnings..] {
+        progress.warning("rows", w);
+    }
+    progress.counts("rows", counts(inserted, updated, skipped, &conflicts, processed));
+    if inserted + updated > 0 {
+        crate::quarters::refresh_quarter_assignments(conn)?;
+    }
+
+    Ok(CsvImportSummary {
+        inserted,
+        updated,
+        skipped,
+        conflicts,
+        warnings,
+        dry_run,
+        row_diffs,
+        value_conflicts: value_conflict_rows,
+        batch_id: None,
+    })
+}
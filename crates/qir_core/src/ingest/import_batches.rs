@@ -0,0 +1,404 @@
+use std::collections::BTreeMap;
+
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::freeze::FrozenQuarters;
+
+/// Columns left out of snapshots: the key itself, and values recomputed after an undo.
+const UNSNAPSHOTTED_COLUMNS: [&str; 2] = ["id", "assigned_quarter_label"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImportBatch {
+    pub id: i64,
+    pub source: String,
+    pub row_count: usize,
+    /// Incidents the batch inserted or updated.
+    pub incident_ids: Vec<i64>,
+    pub created_at: String,
+    pub undone_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImportUndoSummary {
+    pub batch_id: i64,
+    /// Incidents the batch had inserted.
+    pub deleted: usize,
+    /// Incidents put back to their pre-import values.
+    pub restored: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct SeverityGradeRow {
+    initial_severity: String,
+    source: String,
+    recorded_at: String,
+}
+
+/// An incident row (minus `UNSNAPSHOTTED_COLUMNS`) plus its severity grade, which imports
+/// also write.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct IncidentSnapshot {
+    columns: BTreeMap<String, serde_json::Value>,
+    severity_grade: Option<SeverityGradeRow>,
+}
+
+impl IncidentSnapshot {
+    fn text(&self, column: &str) -> Option<&str> {
+        self.columns.get(column).and_then(|v| v.as_str())
+    }
+
+    fn frozen_quarter(&self, frozen: &FrozenQuarters) -> Option<String> {
+        frozen.for_timestamps(self.text("start_ts"), self.text("first_observed_ts"), self.text("resolve_ts"))
+    }
+}
+
+fn to_json(value: Value) -> serde_json::Value {
+    match value {
+        Value::Null => serde_json::Value::Null,
+        Value::Integer(i) => i.into(),
+        Value::Real(f) => serde_json::Number::from_f64(f).map_or(serde_json::Value::Null, Into::into),
+        Value::Text(s) => s.into(),
+        Value::Blob(b) => b.into(),
+    }
+}
+
+fn from_json(value: &serde_json::Value) -> Value {
+    match value {
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::Integer(i),
+            None => Value::Real(n.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(s) => Value::Text(s.clone()),
+        serde_json::Value::Array(bytes) => {
+            Value::Blob(bytes.iter().filter_map(|b| b.as_u64().map(|b| b as u8)).collect())
+        }
+        _ => Value::Null,
+    }
+}
+
+fn snapshot_failed(incident_id: i64) -> impl Fn(rusqlite::Error) -> AppError {
+    move |e| {
+        AppError::new("DB_IMPORT_BATCH_QUERY_FAILED", "Failed to snapshot incident for import undo")
+            .with_details(format!("incident_id={incident_id}; err={e}"))
+    }
+}
+
+fn snapshot_incident(conn: &Connection, incident_id: i64) -> Result<Option<IncidentSnapshot>, AppError> {
+    let mut stmt = conn
+        .prepare("SELECT * FROM incidents WHERE id = ?1")
+        .map_err(snapshot_failed(incident_id))?;
+    let names: Vec<String> = stmt.column_names().into_iter().map(str::to_string).collect();
+    let columns = stmt
+        .query_row([incident_id], |row| {
+            let mut out = BTreeMap::new();
+            for (idx, name) in names.iter().enumerate() {
+                if !UNSNAPSHOTTED_COLUMNS.contains(&name.as_str()) {
+                    out.insert(name.clone(), to_json(row.get::<_, Value>(idx)?));
+                }
+            }
+            Ok(out)
+        })
+        .optional()
+        .map_err(snapshot_failed(incident_id))?;
+    let Some(columns) = columns else {
+        return Ok(None);
+    };
+    let severity_grade = conn
+        .query_row(
+            "SELECT initial_severity, source, recorded_at FROM incident_severity_grades WHERE incident_id = ?1",
+            [incident_id],
+            |r| {
+                Ok(SeverityGradeRow {
+                    initial_severity: r.get(0)?,
+                    source: r.get(1)?,
+                    recorded_at: r.get(2)?,
+                })
+            },
+        )
+        .optional()
+        .map_err(snapshot_failed(incident_id))?;
+    Ok(Some(IncidentSnapshot {
+        columns,
+        severity_grade,
+    }))
+}
+
+fn encode(snapshot: &IncidentSnapshot) -> Result<String, AppError> {
+    serde_json::to_string(snapshot).map_err(|e| {
+        AppError::new("DB_IMPORT_BATCH_WRITE_FAILED", "Failed to encode incident snapshot").with_details(e.to_string())
+    })
+}
+
+fn decode(json: &str, incident_id: i64) -> Result<IncidentSnapshot, AppError> {
+    serde_json::from_str(json).map_err(|e| {
+        AppError::new("DB_DECODE_FAILED", "Failed to decode incident snapshot")
+            .with_details(format!("incident_id={incident_id}; err={e}"))
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BatchAction {
+    Insert,
+    Update,
+}
+
+impl BatchAction {
+    fn as_str(self) -> &'static str {
+        match self {
+            BatchAction::Insert => "insert",
+            BatchAction::Update => "update",
+        }
+    }
+}
+
+/// Incidents touched by an import in progress, with pre-update snapshots. Filled in by the
+/// importer as it writes, then persisted by `record_batch` in the same transaction.
+#[derive(Debug, Default)]
+pub(crate) struct ImportBatchLog {
+    touched: Vec<(i64, BatchAction, Option<String>)>,
+}
+
+impl ImportBatchLog {
+    pub(crate) fn inserted(&mut self, incident_id: i64) {
+        self.touched.push((incident_id, BatchAction::Insert, None));
+    }
+
+    /// Snapshot to take before writing an update; hand it to `updated` once the write lands.
+    pub(crate) fn prepare_update(conn: &Connection, incident_id: i64) -> Result<PendingUpdate, AppError> {
+        let before = snapshot_incident(conn, incident_id)?.map(|s| encode(&s)).transpose()?;
+        Ok(PendingUpdate { incident_id, before })
+    }
+
+    pub(crate) fn updated(&mut self, pending: PendingUpdate) {
+        self.touched.push((pending.incident_id, BatchAction::Update, pending.before));
+    }
+}
+
+/// Pre-update snapshot of one incident, see `ImportBatchLog::prepare_update`.
+#[derive(Debug)]
+pub(crate) struct PendingUpdate {
+    incident_id: i64,
+    before: Option<String>,
+}
+
+/// Persists `log` as a new batch, snapshotting every touched incident as the import left it.
+pub(crate) fn record_batch(
+    conn: &Connection,
+    source: &str,
+    row_count: usize,
+    log: &ImportBatchLog,
+) -> Result<i64, AppError> {
+    let write_failed = |e: rusqlite::Error| {
+        AppError::new("DB_IMPORT_BATCH_WRITE_FAILED", "Failed to record import batch").with_details(e.to_string())
+    };
+    conn.execute(
+        "INSERT INTO import_batches(source, row_count, created_at) VALUES (?1, ?2, strftime('%Y-%m-%dT%H:%M:%fZ','now'))",
+        params![source, row_count as i64],
+    )
+    .map_err(write_failed)?;
+    let batch_id = conn.last_insert_rowid();
+    for (incident_id, action, before) in &log.touched {
+        let Some(after) = snapshot_incident(conn, *incident_id)? else {
+            continue;
+        };
+        // The first touch wins: it holds the state from before this batch.
+        conn.execute(
+            "INSERT OR IGNORE INTO import_batch_incidents(batch_id, incident_id, action, before_json, after_json)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![batch_id, incident_id, action.as_str(), before, encode(&after)?],
+        )
+        .map_err(write_failed)?;
+    }
+    Ok(batch_id)
+}
+
+/// Most recent batches first.
+pub fn list_import_batches(conn: &Connection, limit: usize) -> Result<Vec<ImportBatch>, AppError> {
+    let query_failed = |e: rusqlite::Error| {
+        AppError::new("DB_IMPORT_BATCH_QUERY_FAILED", "Failed to load import batches").with_details(e.to_string())
+    };
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, source, row_count, created_at, undone_at FROM import_batches ORDER BY id DESC LIMIT ?1",
+        )
+        .map_err(query_failed)?;
+    let mut batches = stmt
+        .query_map([limit as i64], |r| {
+            Ok(ImportBatch {
+                id: r.get(0)?,
+                source: r.get(1)?,
+                row_count: r.get::<_, i64>(2)? as usize,
+                incident_ids: Vec::new(),
+                created_at: r.get(3)?,
+                undone_at: r.get(4)?,
+            })
+        })
+        .map_err(query_failed)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(query_failed)?;
+    let mut ids_stmt = conn
+        .prepare("SELECT incident_id FROM import_batch_incidents WHERE batch_id = ?1 ORDER BY incident_id")
+        .map_err(query_failed)?;
+    for batch in &mut batches {
+        batch.incident_ids = ids_stmt
+            .query_map([batch.id], |r| r.get(0))
+            .map_err(query_failed)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(query_failed)?;
+    }
+    Ok(batches)
+}
+
+fn restore_incident(conn: &Connection, incident_id: i64, snapshot: &IncidentSnapshot) -> Result<(), AppError> {
+    let write_failed = |e: rusqlite::Error| {
+        AppError::new("DB_IMPORT_BATCH_WRITE_FAILED", "Failed to restore incident from import snapshot")
+            .with_details(format!("incident_id={incident_id}; err={e}"))
+    };
+    // Column names come from the stored snapshot; only ones the table still has are written.
+    let current: Vec<String> = conn
+        .prepare("SELECT name FROM pragma_table_info('incidents')")
+        .and_then(|mut s| s.query_map([], |r| r.get(0))?.collect())
+        .map_err(write_failed)?;
+    let columns: Vec<(&String, &serde_json::Value)> =
+        snapshot.columns.iter().filter(|(name, _)| current.contains(name)).collect();
+    let assignments: Vec<String> = columns
+        .iter()
+        .enumerate()
+        .map(|(idx, (name, _))| format!("\"{name}\" = ?{}", idx + 1))
+        .collect();
+    let sql = format!(
+        "UPDATE incidents SET {} WHERE id = ?{}",
+        assignments.join(", "),
+        columns.len() + 1
+    );
+    let values = columns
+        .iter()
+        .map(|(_, v)| from_json(v))
+        .chain(std::iter::once(Value::Integer(incident_id)));
+    conn.execute(&sql, params_from_iter(values)).map_err(write_failed)?;
+
+    conn.execute("DELETE FROM incident_severity_grades WHERE incident_id = ?1", [incident_id])
+        .map_err(write_failed)?;
+    if let Some(g) = &snapshot.severity_grade {
+        conn.execute(
+            "INSERT INTO incident_severity_grades(incident_id, initial_severity, source, recorded_at) VALUES (?1, ?2, ?3, ?4)",
+            params![incident_id, g.initial_severity, g.source, g.recorded_at],
+        )
+        .map_err(write_failed)?;
+    }
+    Ok(())
+}
+
+/// Reverts an import batch: inserted incidents are deleted and updated ones get their
+/// pre-import values back. All or nothing — the undo is refused when any incident changed
+/// after the import (edited by hand or by a later import; undo that one first) or belongs
+/// to a frozen quarter.
+pub fn undo_import_batch(conn: &mut Connection, batch_id: i64) -> Result<ImportUndoSummary, AppError> {
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to start transaction").with_details(e.to_string()))?;
+    let query_failed = |e: rusqlite::Error| {
+        AppError::new("DB_IMPORT_BATCH_QUERY_FAILED", "Failed to load import batch")
+            .with_details(format!("batch_id={batch_id}; err={e}"))
+    };
+
+    let undone_at: Option<Option<String>> = tx
+        .query_row("SELECT undone_at FROM import_batches WHERE id = ?1", [batch_id], |r| r.get(0))
+        .optional()
+        .map_err(query_failed)?;
+    match undone_at {
+        None => {
+            return Err(AppError::new("INGEST_IMPORT_BATCH_NOT_FOUND", "Import batch not found")
+                .with_details(format!("batch_id={batch_id}")))
+        }
+        Some(Some(at)) => {
+            return Err(
+                AppError::new("INGEST_IMPORT_BATCH_ALREADY_UNDONE", "Import batch was already undone")
+                    .with_details(format!("batch_id={batch_id}; undone_at={at}")),
+            )
+        }
+        Some(None) => {}
+    }
+
+    let entries: Vec<(i64, String, Option<String>, String)> = tx
+        .prepare(
+            "SELECT incident_id, action, before_json, after_json FROM import_batch_incidents
+             WHERE batch_id = ?1 ORDER BY incident_id",
+        )
+        .and_then(|mut s| s.query_map([batch_id], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?)))?.collect())
+        .map_err(query_failed)?;
+
+    let frozen = FrozenQuarters::load(&tx)?;
+    let mut changed_since = Vec::new();
+    let mut in_frozen = Vec::new();
+    let mut plan = Vec::new();
+    for (incident_id, action, before_json, after_json) in entries {
+        let after = decode(&after_json, incident_id)?;
+        let current = snapshot_incident(&tx, incident_id)?;
+        let Some(current) = current else {
+            // Already deleted: an inserted incident is gone as undo wants; a deleted updated
+            // one cannot be restored without resurrecting it.
+            if action == "update" {
+                changed_since.push(incident_id);
+            }
+            continue;
+        };
+        if current != after {
+            changed_since.push(incident_id);
+            continue;
+        }
+        let before = before_json.as_deref().map(|j| decode(j, incident_id)).transpose()?;
+        // The current row is checked by its assigned quarter, the restored one by the rule.
+        let restores_frozen = before.as_ref().and_then(|b| b.frozen_quarter(&frozen)).is_some();
+        if frozen.for_incident(&tx, incident_id)?.is_some() || restores_frozen {
+            in_frozen.push(incident_id);
+            continue;
+        }
+        plan.push((incident_id, before));
+    }
+    if !changed_since.is_empty() || !in_frozen.is_empty() {
+        return Err(AppError::new(
+            "INGEST_IMPORT_UNDO_CONFLICT",
+            "Some incidents changed after this import or are in a frozen quarter; nothing was undone",
+        )
+        .with_details(format!(
+            "batch_id={batch_id}; changed_since={changed_since:?}; frozen={in_frozen:?}"
+        )));
+    }
+
+    let mut summary = ImportUndoSummary {
+        batch_id,
+        deleted: 0,
+        restored: 0,
+    };
+    for (incident_id, before) in &plan {
+        match before {
+            Some(before) => {
+                restore_incident(&tx, *incident_id, before)?;
+                summary.restored += 1;
+            }
+            None => {
+                tx.execute("DELETE FROM incidents WHERE id = ?1", [incident_id]).map_err(|e| {
+                    AppError::new("DB_IMPORT_BATCH_WRITE_FAILED", "Failed to delete imported incident")
+                        .with_details(format!("incident_id={incident_id}; err={e}"))
+                })?;
+                summary.deleted += 1;
+            }
+        }
+    }
+    tx.execute(
+        "UPDATE import_batches SET undone_at = strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE id = ?1",
+        [batch_id],
+    )
+    .map_err(|e| {
+        AppError::new("DB_IMPORT_BATCH_WRITE_FAILED", "Failed to mark import batch undone")
+            .with_details(format!("batch_id={batch_id}; err={e}"))
+    })?;
+    crate::quarters::refresh_quarter_assignments(&tx)?;
+    tx.commit()
+        .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to commit import undo").with_details(e.to_string()))?;
+    Ok(summary)
+}
@@ -0,0 +1,101 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Counts for one logical import; `summary_json` keeps the source-specific detail.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImportHistoryEntry {
+    pub id: i64,
+    pub source: String,
+    pub profile_id: Option<i64>,
+    pub file_names: Vec<String>,
+    pub inserted: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub conflicts: usize,
+    pub warnings: usize,
+    pub summary_json: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NewImportHistory<'a> {
+    pub source: &'a str,
+    pub profile_id: Option<i64>,
+    pub file_names: &'a [String],
+    pub inserted: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub conflicts: usize,
+    pub warnings: usize,
+    pub summary_json: String,
+}
+
+pub(crate) fn record_import(conn: &Connection, entry: &NewImportHistory<'_>) -> Result<i64, AppError> {
+    let file_names_json = serde_json::to_string(entry.file_names).map_err(|e| {
+        AppError::new("DB_IMPORT_HISTORY_WRITE_FAILED", "Failed to encode import file names")
+            .with_details(e.to_string())
+    })?;
+    conn.execute(
+        r#"
+      INSERT INTO import_history(
+        source, profile_id, file_names_json, inserted, updated, skipped, conflicts, warnings,
+        summary_json, created_at
+      ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+      "#,
+        rusqlite::params![
+            entry.source,
+            entry.profile_id,
+            file_names_json,
+            entry.inserted as i64,
+            entry.updated as i64,
+            entry.skipped as i64,
+            entry.conflicts as i64,
+            entry.warnings as i64,
+            entry.summary_json,
+        ],
+    )
+    .map_err(|e| {
+        AppError::new("DB_IMPORT_HISTORY_WRITE_FAILED", "Failed to record import history")
+            .with_details(e.to_string())
+    })?;
+    Ok(conn.last_insert_rowid())
+}
+
+/// Most recent imports first.
+pub fn list_import_history(conn: &Connection, limit: usize) -> Result<Vec<ImportHistoryEntry>, AppError> {
+    let query_failed = |e: rusqlite::Error| {
+        AppError::new("DB_IMPORT_HISTORY_QUERY_FAILED", "Failed to load import history").with_details(e.to_string())
+    };
+    let mut stmt = conn
+        .prepare(
+            r#"
+      SELECT id, source, profile_id, file_names_json, inserted, updated, skipped, conflicts,
+             warnings, summary_json, created_at
+      FROM import_history
+      ORDER BY id DESC
+      LIMIT ?1
+      "#,
+        )
+        .map_err(query_failed)?;
+    let rows = stmt
+        .query_map([limit as i64], |row| {
+            let file_names_json: String = row.get(3)?;
+            Ok(ImportHistoryEntry {
+                id: row.get(0)?,
+                source: row.get(1)?,
+                profile_id: row.get(2)?,
+                file_names: serde_json::from_str(&file_names_json).unwrap_or_default(),
+                inserted: row.get::<_, i64>(4)? as usize,
+                updated: row.get::<_, i64>(5)? as usize,
+                skipped: row.get::<_, i64>(6)? as usize,
+                conflicts: row.get::<_, i64>(7)? as usize,
+                warnings: row.get::<_, i64>(8)? as usize,
+                summary_json: row.get(9)?,
+                created_at: row.get(10)?,
+            })
+        })
+        .map_err(query_failed)?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(query_failed)
+}
@@ -0,0 +1,425 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+use crate::progress::Progress;
+
+use super::generic_csv::{import_source_rows_with_progress, SourceRow};
+use super::jira_csv::JiraImportSummary;
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+/// Prefix for external ids built from incident.io references (INC-123), so they never collide
+/// with Jira keys that use the same project key.
+pub const INCIDENT_IO_EXTERNAL_ID_PREFIX: &str = "IIO-";
+
+/// How an incident.io export maps onto canonical fields. Column names only apply to CSV
+/// exports; JSON exports are read by their API field names.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentIoMapping {
+    /// Severity recorded for each incident.io severity name (case-insensitive). incident.io
+    /// severities are workspace-defined; unlisted names are kept verbatim and warned about.
+    #[serde(default = "default_severity_map")]
+    pub severity_map: BTreeMap<String, String>,
+    pub reference: Option<String>,
+    pub name: String,
+    pub summary: Option<String>,
+    pub severity: Option<String>,
+    pub created_ts: Option<String>,
+    pub impact_started_ts: Option<String>,
+    pub acknowledged_ts: Option<String>,
+    pub mitigated_ts: Option<String>,
+    pub resolved_ts: Option<String>,
+    /// CSV columns holding role assignees, e.g. "Incident Lead"; the header is the role name.
+    #[serde(default = "default_role_columns")]
+    pub role_columns: Vec<String>,
+}
+
+fn default_severity_map() -> BTreeMap<String, String> {
+    BTreeMap::from([
+        ("critical".to_string(), "SEV1".to_string()),
+        ("major".to_string(), "SEV2".to_string()),
+        ("minor".to_string(), "SEV3".to_string()),
+    ])
+}
+
+fn default_role_columns() -> Vec<String> {
+    vec!["Incident Lead".to_string(), "Reporter".to_string()]
+}
+
+impl Default for IncidentIoMapping {
+    fn default() -> Self {
+        Self {
+            severity_map: default_severity_map(),
+            reference: Some("Reference".to_string()),
+            name: "Name".to_string(),
+            summary: Some("Summary".to_string()),
+            severity: Some("Severity".to_string()),
+            created_ts: Some("Reported at".to_string()),
+            impact_started_ts: Some("Impact started at".to_string()),
+            acknowledged_ts: Some("Accepted at".to_string()),
+            mitigated_ts: Some("Stabilised at".to_string()),
+            resolved_ts: Some("Resolved at".to_string()),
+            role_columns: default_role_columns(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentIoPreview {
+    /// "csv" or "json".
+    pub format: String,
+    pub incident_count: usize,
+    /// Distinct raw severity names, for building `severity_map`.
+    pub severities: Vec<String>,
+    pub roles: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentIoImportSummary {
+    pub incidents: JiraImportSummary,
+    pub responders_added: usize,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+/// One incident in source-neutral form.
+#[derive(Debug, Clone, Default)]
+struct IncidentIoRecord {
+    reference: String,
+    name: String,
+    summary: String,
+    severity: String,
+    created_ts: String,
+    impact_started_ts: String,
+    acknowledged_ts: String,
+    mitigated_ts: String,
+    resolved_ts: String,
+    /// (role, person)
+    roles: Vec<(String, String)>,
+}
+
+fn parse_failed(message: &str, details: String) -> AppError {
+    AppError::new("INGEST_INCIDENT_IO_PARSE_FAILED", message).with_details(details)
+}
+
+fn is_json(text: &str) -> bool {
+    matches!(text.trim_start().chars().next(), Some('{') | Some('['))
+}
+
+fn get<'a>(row: &'a csv::StringRecord, headers: &csv::StringRecord, header_name: Option<&str>) -> &'a str {
+    header_name
+        .and_then(|name| headers.iter().position(|h| h == name))
+        .and_then(|idx| row.get(idx))
+        .map(str::trim)
+        .unwrap_or("")
+}
+
+fn read_csv(text: &str, mapping: &IncidentIoMapping, warnings: &mut Vec<ValidationWarning>) -> Result<Vec<Option<IncidentIoRecord>>, AppError> {
+    let mut rdr = csv::ReaderBuilder::new().has_headers(true).from_reader(text.as_bytes());
+    let headers = rdr
+        .headers()
+        .map_err(|e| {
+            AppError::new("INGEST_INCIDENT_IO_CSV_HEADERS_FAILED", "Failed to read incident.io CSV headers")
+                .with_details(e.to_string())
+        })?
+        .clone();
+    let mut out = Vec::new();
+    for (row_idx, result) in rdr.records().enumerate() {
+        let row = match result {
+            Ok(r) => r,
+            Err(e) => {
+                warnings.push(
+                    ValidationWarning::new("INGEST_INCIDENT_IO_PARSE_FAILED", "Failed to parse incident.io CSV row")
+                        .with_details(format!("row={row_idx}; err={e}")),
+                );
+                out.push(None);
+                continue;
+            }
+        };
+        let col = |h: &Option<String>| get(&row, &headers, h.as_deref()).to_string();
+        out.push(Some(IncidentIoRecord {
+            reference: col(&mapping.reference),
+            name: get(&row, &headers, Some(mapping.name.as_str())).to_string(),
+            summary: col(&mapping.summary),
+            severity: col(&mapping.severity),
+            created_ts: col(&mapping.created_ts),
+            impact_started_ts: col(&mapping.impact_started_ts),
+            acknowledged_ts: col(&mapping.acknowledged_ts),
+            mitigated_ts: col(&mapping.mitigated_ts),
+            resolved_ts: col(&mapping.resolved_ts),
+            roles: mapping
+                .role_columns
+                .iter()
+                .flat_map(|role| {
+                    // Multi-assignee cells are comma separated.
+                    get(&row, &headers, Some(role.as_str()))
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|p| !p.is_empty())
+                        .map(|p| (role.clone(), p.to_string()))
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+        }));
+    }
+    Ok(out)
+}
+
+fn str_at<'a>(v: &'a Value, path: &[&str]) -> &'a str {
+    path.iter()
+        .try_fold(v, |v, key| v.get(key))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .unwrap_or("")
+}
+
+/// Normalized incident.io timestamp name: "Impact started at" and "impact_started" both
+/// become "impact started".
+fn timestamp_key(name: &str) -> String {
+    let name = name.trim().to_lowercase().replace('_', " ");
+    name.strip_suffix(" at").unwrap_or(&name).trim().to_string()
+}
+
+fn json_record(v: &Value) -> IncidentIoRecord {
+    let mut timestamps = BTreeMap::new();
+    for tv in v.get("incident_timestamp_values").and_then(Value::as_array).into_iter().flatten() {
+        let name = str_at(tv, &["incident_timestamp", "name"]);
+        let value = str_at(tv, &["value", "value"]);
+        if !name.is_empty() && !value.is_empty() {
+            timestamps.insert(timestamp_key(name), value.to_string());
+        }
+    }
+    let ts = |keys: &[&str]| keys.iter().find_map(|k| timestamps.get(*k).cloned()).unwrap_or_default();
+    let severity = match v.get("severity") {
+        Some(Value::String(s)) => s.trim().to_string(),
+        Some(s) => str_at(s, &["name"]).to_string(),
+        None => String::new(),
+    };
+    let roles = v
+        .get("incident_role_assignments")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|a| {
+            let role = str_at(a, &["role", "name"]);
+            let assignee = a.get("assignee")?;
+            let person = Some(str_at(assignee, &["name"]))
+                .filter(|p| !p.is_empty())
+                .unwrap_or_else(|| str_at(assignee, &["email"]));
+            (!role.is_empty() && !person.is_empty()).then(|| (role.to_string(), person.to_string()))
+        })
+        .collect();
+    IncidentIoRecord {
+        reference: str_at(v, &["reference"]).to_string(),
+        name: str_at(v, &["name"]).to_string(),
+        summary: str_at(v, &["summary"]).to_string(),
+        severity,
+        created_ts: Some(str_at(v, &["created_at"]).to_string())
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| ts(&["reported"])),
+        impact_started_ts: ts(&["impact started"]),
+        acknowledged_ts: ts(&["accepted", "acknowledged"]),
+        mitigated_ts: ts(&["stabilised", "stabilized", "mitigated"]),
+        resolved_ts: ts(&["resolved", "closed"]),
+        roles,
+    }
+}
+
+/// Accepts the API list shape (`{"incidents": [..]}`) or a bare array of incidents.
+fn read_json(text: &str) -> Result<Vec<Option<IncidentIoRecord>>, AppError> {
+    let root: Value =
+        serde_json::from_str(text).map_err(|e| parse_failed("Failed to parse incident.io JSON export", e.to_string()))?;
+    let incidents = match &root {
+        Value::Array(items) => items,
+        Value::Object(obj) => obj
+            .get("incidents")
+            .and_then(Value::as_array)
+            .ok_or_else(|| parse_failed("incident.io JSON export has no incidents array", "key=incidents".to_string()))?,
+        _ => return Err(parse_failed("incident.io JSON export must be an object or array", String::new())),
+    };
+    Ok(incidents.iter().map(|v| Some(json_record(v))).collect())
+}
+
+fn read_records(
+    text: &str,
+    mapping: &IncidentIoMapping,
+    warnings: &mut Vec<ValidationWarning>,
+) -> Result<Vec<Option<IncidentIoRecord>>, AppError> {
+    if is_json(text) {
+        read_json(text)
+    } else {
+        read_csv(text, mapping, warnings)
+    }
+}
+
+pub fn preview_incident_io(text: &str, mapping: Option<&IncidentIoMapping>) -> Result<IncidentIoPreview, AppError> {
+    let default_mapping = IncidentIoMapping::default();
+    let mapping = mapping.unwrap_or(&default_mapping);
+    let records = read_records(text, mapping, &mut Vec::new())?;
+    let severities: BTreeSet<String> = records
+        .iter()
+        .flatten()
+        .map(|r| r.severity.clone())
+        .filter(|s| !s.is_empty())
+        .collect();
+    let roles: BTreeSet<String> = records.iter().flatten().flat_map(|r| r.roles.iter().map(|(role, _)| role.clone())).collect();
+    Ok(IncidentIoPreview {
+        format: if is_json(text) { "json" } else { "csv" }.to_string(),
+        incident_count: records.len(),
+        severities: severities.into_iter().collect(),
+        roles: roles.into_iter().collect(),
+    })
+}
+
+fn external_id(reference: &str) -> String {
+    if reference.is_empty() {
+        String::new()
+    } else {
+        format!("{INCIDENT_IO_EXTERNAL_ID_PREFIX}{reference}")
+    }
+}
+
+fn severity_for(mapping: &IncidentIoMapping, raw: &str, unmapped: &mut BTreeSet<String>) -> String {
+    if raw.is_empty() {
+        return String::new();
+    }
+    match mapping.severity_map.iter().find(|(k, _)| k.eq_ignore_ascii_case(raw)) {
+        Some((_, v)) => v.clone(),
+        None => {
+            unmapped.insert(raw.to_string());
+            raw.to_string()
+        }
+    }
+}
+
+/// Canonical rows in record order (`None` for unreadable rows) so row numbers in warnings and
+/// conflicts match the original export.
+fn source_rows(
+    records: &[Option<IncidentIoRecord>],
+    mapping: &IncidentIoMapping,
+    warnings: &mut Vec<ValidationWarning>,
+) -> Vec<Option<SourceRow>> {
+    let mut unmapped = BTreeSet::new();
+    let rows = records
+        .iter()
+        .map(|record| {
+            let r = record.as_ref()?;
+            let start = if r.impact_started_ts.is_empty() { &r.created_ts } else { &r.impact_started_ts };
+            Some(SourceRow {
+                external_id: external_id(&r.reference),
+                title: r.name.clone(),
+                description: r.summary.clone(),
+                severity: severity_for(mapping, &r.severity, &mut unmapped),
+                start_ts: start.clone(),
+                ack_ts: r.acknowledged_ts.clone(),
+                mitigate_ts: r.mitigated_ts.clone(),
+                resolve_ts: r.resolved_ts.clone(),
+                ..SourceRow::default()
+            })
+        })
+        .collect();
+    for raw in unmapped {
+        warnings.push(
+            ValidationWarning::new(
+                "INGEST_INCIDENT_IO_SEVERITY_UNMAPPED",
+                "incident.io severity has no mapping; imported verbatim",
+            )
+            .with_details(format!("severity={raw}")),
+        );
+    }
+    rows
+}
+
+fn canonical_ts(ts: &str) -> Option<String> {
+    let dt = OffsetDateTime::parse(ts.trim(), &Rfc3339).ok()?.to_offset(UtcOffset::UTC);
+    dt.replace_nanosecond(0).unwrap_or(dt).format(&Rfc3339).ok()
+}
+
+/// Adds role assignees as responders engaged from the incident's start. Re-imports are
+/// idempotent through the (incident, person, engaged_at) uniqueness.
+fn store_role_assignments(conn: &Connection, records: &[Option<IncidentIoRecord>], warnings: &mut Vec<ValidationWarning>) -> Result<usize, AppError> {
+    let mut added = 0usize;
+    for r in records.iter().flatten() {
+        if r.roles.is_empty() || r.reference.is_empty() {
+            continue;
+        }
+        let ext = external_id(&r.reference);
+        let incident_id: Option<i64> = conn
+            .query_row("SELECT id FROM incidents WHERE external_id = ?1", [&ext], |row| row.get(0))
+            .optional()
+            .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to look up imported incident").with_details(e.to_string()))?;
+        // Skipped or conflicting rows have no incident to attach to.
+        let Some(incident_id) = incident_id else { continue };
+        if let Err(e) = crate::freeze::ensure_incident_mutable(conn, incident_id) {
+            if e.code != "QUARTER_FROZEN" {
+                return Err(e);
+            }
+            warnings.push(
+                ValidationWarning::new(
+                    "INGEST_INCIDENT_IO_ROLES_FROZEN",
+                    "Role assignments skipped because the incident belongs to a frozen quarter",
+                )
+                .with_details(format!("external_id={ext}; {}", e.details.unwrap_or_default())),
+            );
+            continue;
+        }
+        let start = if r.impact_started_ts.is_empty() { &r.created_ts } else { &r.impact_started_ts };
+        let Some(engaged_at) = canonical_ts(start) else {
+            warnings.push(
+                ValidationWarning::new(
+                    "INGEST_INCIDENT_IO_ROLES_NO_START",
+                    "Role assignments skipped because the incident has no start time",
+                )
+                .with_details(format!("external_id={ext}")),
+            );
+            continue;
+        };
+        for (role, person) in &r.roles {
+            added += conn
+                .execute(
+                    "INSERT OR IGNORE INTO incident_responders(incident_id, person, role, engaged_at, released_at, created_at)
+                     VALUES (?1, ?2, ?3, ?4, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now'))",
+                    rusqlite::params![incident_id, person, role, engaged_at],
+                )
+                .map_err(|e| {
+                    AppError::new("INGEST_INCIDENT_IO_RESPONDER_INSERT_FAILED", "Failed to store incident.io role assignment")
+                        .with_details(format!("external_id={ext}; person={person}; err={e}"))
+                })?;
+        }
+    }
+    Ok(added)
+}
+
+pub fn import_incident_io(
+    conn: &mut Connection,
+    text: &str,
+    mapping: &IncidentIoMapping,
+) -> Result<IncidentIoImportSummary, AppError> {
+    import_incident_io_with_progress(conn, text, mapping, &mut Progress::noop("import_incident_io"))
+}
+
+/// Imports an incident.io CSV or JSON export through the shared CSV import path (dedupe, merge,
+/// frozen-quarter and conflict handling match the other sources), then records role
+/// assignments as responders.
+pub fn import_incident_io_with_progress(
+    conn: &mut Connection,
+    text: &str,
+    mapping: &IncidentIoMapping,
+    progress: &mut Progress<'_>,
+) -> Result<IncidentIoImportSummary, AppError> {
+    let mut warnings = Vec::new();
+    let records = read_records(text, mapping, &mut warnings)?;
+    let rows = source_rows(&records, mapping, &mut warnings);
+    let incidents = import_source_rows_with_progress(conn, &rows, "incident_io", progress)?;
+
+    let responders_added = store_role_assignments(conn, &records, &mut warnings)?;
+    Ok(IncidentIoImportSummary {
+        incidents,
+        responders_added,
+        warnings,
+    })
+}
@@ -0,0 +1,427 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use base64::Engine;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::error::AppError;
+use crate::progress::Progress;
+
+use super::csv_dialect::CsvDelimiter;
+use super::jira_csv::{import_jira_csv_with_progress, JiraCsvMapping, JiraCsvPreview, JiraImportSummary};
+
+/// Issues requested per search page.
+pub const JIRA_API_PAGE_SIZE: u32 = 100;
+pub const DEFAULT_JIRA_API_MAX_ISSUES: u32 = 1000;
+pub const MAX_JIRA_API_MAX_ISSUES: u32 = 5000;
+
+/// Pseudo field id for the issue key, which the API returns outside `fields`.
+pub const JIRA_API_KEY_FIELD: &str = "key";
+
+/// Locally stored connection details. Kept in a file outside the workspace DB so tokens never
+/// land in backups, settings bundles or sanitized exports.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JiraApiCredentials {
+    /// e.g. `https://example.atlassian.net`
+    pub base_url: String,
+    /// Account email for Jira Cloud API tokens (basic auth). None sends the token as a bearer
+    /// personal access token (Jira Data Center).
+    pub email: Option<String>,
+    pub token: String,
+}
+
+/// What the UI may show about stored credentials; never includes the token.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JiraApiCredentialsStatus {
+    pub configured: bool,
+    pub base_url: Option<String>,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JiraApiIssue {
+    pub key: String,
+    #[serde(default)]
+    pub fields: Map<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchPage {
+    #[serde(default)]
+    issues: Vec<JiraApiIssue>,
+    // `/search/jql` pagination.
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+    // Legacy `/search` pagination.
+    total: Option<u32>,
+}
+
+/// Field ids for a stock Jira project. Custom fields (e.g. `customfield_10042`) can be mapped
+/// the same way as CSV headers.
+pub fn default_jira_api_mapping() -> JiraCsvMapping {
+    let field = |id: &str| Some(id.to_string());
+    JiraCsvMapping {
+        external_id: field(JIRA_API_KEY_FIELD),
+        title: "summary".to_string(),
+        description: field("description"),
+        severity: field("priority"),
+        initial_severity: None,
+        detection_source: None,
+        vendor: None,
+        service: field("components"),
+        impact_pct: None,
+        service_health_pct: None,
+        start_ts: field("created"),
+        first_observed_ts: None,
+        it_awareness_ts: None,
+        ack_ts: None,
+        mitigate_ts: None,
+        resolve_ts: field("resolutiondate"),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
+    }
+}
+
+fn validate_credentials(creds: &JiraApiCredentials) -> Result<JiraApiCredentials, AppError> {
+    let base_url = creds.base_url.trim().trim_end_matches('/').to_string();
+    let local_http = ["http://127.0.0.1", "http://localhost"]
+        .iter()
+        .any(|p| base_url == *p || base_url.starts_with(&format!("{p}:")) || base_url.starts_with(&format!("{p}/")));
+    if !base_url.starts_with("https://") && !local_http {
+        return Err(AppError::new(
+            "INGEST_JIRA_API_CONFIG_INVALID",
+            "Jira base URL must use https:// (http is only allowed for localhost)",
+        )
+        .with_details(format!("base_url={base_url}")));
+    }
+    let token = creds.token.trim().to_string();
+    if token.is_empty() {
+        return Err(AppError::new("INGEST_JIRA_API_CONFIG_INVALID", "Jira API token must not be empty"));
+    }
+    let email = creds
+        .email
+        .as_deref()
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .map(str::to_string);
+    Ok(JiraApiCredentials {
+        base_url,
+        email,
+        token,
+    })
+}
+
+fn status_of(creds: Option<&JiraApiCredentials>) -> JiraApiCredentialsStatus {
+    JiraApiCredentialsStatus {
+        configured: creds.is_some(),
+        base_url: creds.map(|c| c.base_url.clone()),
+        email: creds.and_then(|c| c.email.clone()),
+    }
+}
+
+/// Writes credentials owner-readable only (0600 on unix), replacing any previous file.
+pub fn save_jira_api_credentials(
+    path: &Path,
+    creds: &JiraApiCredentials,
+) -> Result<JiraApiCredentialsStatus, AppError> {
+    let creds = validate_credentials(creds)?;
+    let json = serde_json::to_vec_pretty(&creds).map_err(|e| {
+        AppError::new("INGEST_JIRA_API_CREDENTIALS_FAILED", "Failed to encode Jira credentials")
+            .with_details(e.to_string())
+    })?;
+    let io_err = |e: std::io::Error| {
+        AppError::new("INGEST_JIRA_API_CREDENTIALS_FAILED", "Failed to write Jira credentials")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_err)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    let mut opts = fs::OpenOptions::new();
+    opts.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        opts.mode(0o600);
+    }
+    opts.open(&tmp)
+        .and_then(|mut f| f.write_all(&json))
+        .and_then(|_| fs::rename(&tmp, path))
+        .map_err(|e| {
+            let _ = fs::remove_file(&tmp);
+            io_err(e)
+        })?;
+    Ok(status_of(Some(&creds)))
+}
+
+pub fn load_jira_api_credentials(path: &Path) -> Result<Option<JiraApiCredentials>, AppError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let bytes = fs::read(path).map_err(|e| {
+        AppError::new("INGEST_JIRA_API_CREDENTIALS_FAILED", "Failed to read Jira credentials")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    })?;
+    serde_json::from_slice(&bytes).map(Some).map_err(|e| {
+        AppError::new("INGEST_JIRA_API_CREDENTIALS_FAILED", "Failed to decode Jira credentials")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    })
+}
+
+pub fn jira_api_credentials_status(path: &Path) -> Result<JiraApiCredentialsStatus, AppError> {
+    Ok(status_of(load_jira_api_credentials(path)?.as_ref()))
+}
+
+pub fn clear_jira_api_credentials(path: &Path) -> Result<(), AppError> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(
+            AppError::new("INGEST_JIRA_API_CREDENTIALS_FAILED", "Failed to delete Jira credentials")
+                .with_details(format!("path={}; err={}", path.display(), e)),
+        ),
+    }
+}
+
+fn auth_header(creds: &JiraApiCredentials) -> String {
+    match &creds.email {
+        Some(email) => format!(
+            "Basic {}",
+            base64::engine::general_purpose::STANDARD.encode(format!("{email}:{}", creds.token))
+        ),
+        None => format!("Bearer {}", creds.token),
+    }
+}
+
+enum Cursor {
+    Token(Option<String>),
+    StartAt(u32),
+}
+
+/// One search request. `Ok(None)` means the first `/search/jql` call hit an instance without
+/// that endpoint (404), so the caller should switch to legacy pagination.
+fn search_page(
+    creds: &JiraApiCredentials,
+    jql: &str,
+    fields: &[String],
+    max_results: u32,
+    cursor: &Cursor,
+) -> Result<Option<SearchPage>, AppError> {
+    let (path, mut body) = match cursor {
+        Cursor::Token(_) => ("/rest/api/2/search/jql", serde_json::json!({})),
+        Cursor::StartAt(start_at) => ("/rest/api/2/search", serde_json::json!({ "startAt": start_at })),
+    };
+    body["jql"] = Value::from(jql);
+    body["fields"] = Value::from(fields.to_vec());
+    body["maxResults"] = Value::from(max_results);
+    if let Cursor::Token(Some(token)) = cursor {
+        body["nextPageToken"] = Value::from(token.as_str());
+    }
+    let resp = ureq::post(&format!("{}{}", creds.base_url, path))
+        .timeout(Duration::from_secs(30))
+        .set("Authorization", &auth_header(creds))
+        .set("Accept", "application/json")
+        .send_json(body);
+    match resp {
+        Ok(r) => r.into_json().map(Some).map_err(|e| {
+            AppError::new("INGEST_JIRA_API_DECODE_FAILED", "Failed to decode Jira search response")
+                .with_details(format!("base_url={}; err={}", creds.base_url, e))
+        }),
+        Err(ureq::Error::Status(404, _)) if matches!(cursor, Cursor::Token(None)) => Ok(None),
+        Err(e) => Err(request_error(creds, e)),
+    }
+}
+
+fn request_error(creds: &JiraApiCredentials, e: ureq::Error) -> AppError {
+    match e {
+        ureq::Error::Status(code @ (401 | 403), _) => AppError::new(
+            "INGEST_JIRA_API_AUTH_FAILED",
+            "Jira rejected the stored credentials",
+        )
+        .with_details(format!("base_url={}; status={code}", creds.base_url)),
+        ureq::Error::Status(code, resp) => {
+            let body = resp.into_string().unwrap_or_default();
+            AppError::new("INGEST_JIRA_API_REQUEST_FAILED", "Jira search request failed")
+                .with_details(format!(
+                    "base_url={}; status={code}; body={}",
+                    creds.base_url,
+                    body.chars().take(500).collect::<String>()
+                ))
+                .with_retryable(code >= 500 || code == 429)
+        }
+        ureq::Error::Transport(t) => AppError::new("INGEST_JIRA_API_REQUEST_FAILED", "Failed to reach Jira")
+            .with_details(format!("base_url={}; err={}", creds.base_url, t))
+            .with_retryable(true),
+    }
+}
+
+/// Runs `jql` and returns up to `max_issues` issues with the requested `fields`. Uses the
+/// token-paginated `/search/jql` endpoint and falls back to the legacy `/search` endpoint on
+/// instances that don't have it (404).
+pub fn fetch_jira_issues(
+    creds: &JiraApiCredentials,
+    jql: &str,
+    fields: &[String],
+    max_issues: u32,
+) -> Result<Vec<JiraApiIssue>, AppError> {
+    let creds = validate_credentials(creds)?;
+    let jql = jql.trim();
+    if jql.is_empty() {
+        return Err(AppError::new("INGEST_JIRA_API_CONFIG_INVALID", "JQL must not be empty"));
+    }
+    let max_issues = max_issues.clamp(1, MAX_JIRA_API_MAX_ISSUES) as usize;
+
+    let mut issues: Vec<JiraApiIssue> = Vec::new();
+    let mut cursor = Cursor::Token(None);
+    while issues.len() < max_issues {
+        let want = JIRA_API_PAGE_SIZE.min((max_issues - issues.len()) as u32);
+        let Some(page) = search_page(&creds, jql, fields, want, &cursor)? else {
+            cursor = Cursor::StartAt(0);
+            continue;
+        };
+        let got = page.issues.len();
+        issues.extend(page.issues);
+        cursor = match cursor {
+            Cursor::Token(_) => match page.next_page_token {
+                Some(token) if got > 0 => Cursor::Token(Some(token)),
+                _ => break,
+            },
+            Cursor::StartAt(start_at) => {
+                let next = start_at + got as u32;
+                if got == 0 || page.total.is_some_and(|total| next >= total) {
+                    break;
+                }
+                Cursor::StartAt(next)
+            }
+        };
+    }
+    issues.truncate(max_issues);
+    Ok(issues)
+}
+
+/// Jira renders offsets as `+0000`; RFC3339 needs `+00:00`.
+pub(super) fn jira_timestamp(s: &str) -> Option<String> {
+    let b = s.as_bytes();
+    let n = b.len();
+    let looks_like_ts = n >= 24 && b[4] == b'-' && b[7] == b'-' && b[10] == b'T';
+    let compact_offset = n >= 5 && matches!(b[n - 5], b'+' | b'-') && b[n - 4..].iter().all(u8::is_ascii_digit);
+    (looks_like_ts && compact_offset).then(|| format!("{}:{}", &s[..n - 2], &s[n - 2..]))
+}
+
+/// Flattens one Jira field value into a CSV cell: option/user/priority objects use their
+/// display name, arrays are joined with "; ".
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => jira_timestamp(s).unwrap_or_else(|| s.clone()),
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Array(items) => items
+            .iter()
+            .map(cell)
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("; "),
+        Value::Object(obj) => ["value", "name", "displayName", "key"]
+            .iter()
+            .find_map(|k| obj.get(*k).and_then(Value::as_str))
+            .map(str::to_string)
+            .unwrap_or_else(|| value.to_string()),
+    }
+}
+
+pub(super) fn issue_cell(issue: &JiraApiIssue, field: &str) -> String {
+    if field == JIRA_API_KEY_FIELD {
+        return issue.key.clone();
+    }
+    issue.fields.get(field).map(cell).unwrap_or_default()
+}
+
+/// Field ids the mapping reads, i.e. what to request from the search API.
+pub fn mapping_fields(mapping: &JiraCsvMapping) -> Vec<String> {
+    let mut fields: BTreeSet<String> = [
+        &mapping.external_id,
+        &mapping.description,
+        &mapping.severity,
+        &mapping.initial_severity,
+        &mapping.detection_source,
+        &mapping.vendor,
+        &mapping.service,
+        &mapping.impact_pct,
+        &mapping.service_health_pct,
+        &mapping.start_ts,
+        &mapping.first_observed_ts,
+        &mapping.it_awareness_ts,
+        &mapping.ack_ts,
+        &mapping.mitigate_ts,
+        &mapping.resolve_ts,
+    ]
+    .into_iter()
+    .flatten()
+    .cloned()
+    .collect();
+    fields.insert(mapping.title.clone());
+    fields.remove(JIRA_API_KEY_FIELD);
+    fields.into_iter().collect()
+}
+
+/// Tabulates issues like a CSV export: `key` first, then every returned field id, sorted.
+pub fn preview_jira_issues(issues: &[JiraApiIssue], max_rows: usize) -> JiraCsvPreview {
+    let field_ids: BTreeSet<&str> = issues
+        .iter()
+        .flat_map(|i| i.fields.keys().map(String::as_str))
+        .collect();
+    let mut headers = vec![JIRA_API_KEY_FIELD.to_string()];
+    headers.extend(field_ids.into_iter().map(str::to_string));
+    let rows = issues
+        .iter()
+        .take(max_rows)
+        .map(|issue| headers.iter().map(|h| issue_cell(issue, h)).collect())
+        .collect();
+    JiraCsvPreview {
+        headers,
+        rows,
+        delimiter: CsvDelimiter::Comma,
+    }
+}
+
+fn issues_to_csv(issues: &[JiraApiIssue], columns: &[String]) -> Result<String, AppError> {
+    let write_failed = |e: String| {
+        AppError::new("INGEST_JIRA_API_DECODE_FAILED", "Failed to prepare Jira issues for import").with_details(e)
+    };
+    let mut out = csv::Writer::from_writer(Vec::new());
+    out.write_record(columns).map_err(|e| write_failed(e.to_string()))?;
+    for issue in issues {
+        out.write_record(columns.iter().map(|c| issue_cell(issue, c)))
+            .map_err(|e| write_failed(e.to_string()))?;
+    }
+    let bytes = out.into_inner().map_err(|e| write_failed(e.to_string()))?;
+    String::from_utf8(bytes).map_err(|e| write_failed(e.to_string()))
+}
+
+/// Imports fetched issues through the Jira CSV pipeline; `mapping` names field ids instead of
+/// CSV headers. Dedupe, merge, conflicts and preserve-on-empty match CSV imports exactly.
+pub fn import_jira_issues_with_progress(
+    conn: &mut Connection,
+    issues: &[JiraApiIssue],
+    mapping: &JiraCsvMapping,
+    progress: &mut Progress<'_>,
+) -> Result<JiraImportSummary, AppError> {
+    let mut columns = vec![JIRA_API_KEY_FIELD.to_string()];
+    columns.extend(mapping_fields(mapping));
+    let csv_text = issues_to_csv(issues, &columns)?;
+    import_jira_csv_with_progress(conn, &csv_text, mapping, progress)
+}
+
+pub fn import_jira_issues(
+    conn: &mut Connection,
+    issues: &[JiraApiIssue],
+    mapping: &JiraCsvMapping,
+) -> Result<JiraImportSummary, AppError> {
+    import_jira_issues_with_progress(conn, issues, mapping, &mut Progress::noop("import_jira_api"))
+}
@@ -0,0 +1,270 @@
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+use crate::progress::Progress;
+
+use super::jira_api::{
+    import_jira_issues_with_progress, issue_cell, jira_timestamp, preview_jira_issues, JiraApiIssue,
+};
+use super::jira_csv::{JiraCsvMapping, JiraCsvPreview, JiraImportSummary};
+
+/// Pseudo field ids for changelog transitions: `changelog:status:Resolved` is the first time the
+/// issue moved to Resolved. Map them like any other field (e.g. onto `mitigate_ts`).
+pub const JIRA_CHANGELOG_FIELD_PREFIX: &str = "changelog:";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JiraChangelogItem {
+    pub field: String,
+    #[serde(default, rename = "fromString")]
+    pub from_text: Option<String>,
+    #[serde(default, rename = "toString")]
+    pub to_text: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JiraChangelogHistory {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub author: Option<Value>,
+    pub created: String,
+    #[serde(default)]
+    pub items: Vec<JiraChangelogItem>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct JiraChangelog {
+    #[serde(default)]
+    pub histories: Vec<JiraChangelogHistory>,
+}
+
+/// One issue from a Jira Cloud bulk export (`expand=changelog`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JiraExportIssue {
+    pub key: String,
+    #[serde(default)]
+    pub fields: Map<String, Value>,
+    #[serde(default)]
+    pub changelog: Option<JiraChangelog>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JiraJsonImportSummary {
+    pub incidents: JiraImportSummary,
+    pub timeline_events_inserted: usize,
+    pub timeline_events_skipped: usize,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ExportDocument {
+    Wrapped { issues: Vec<JiraExportIssue> },
+    Bare(Vec<JiraExportIssue>),
+}
+
+/// Accepts the export's `{"issues": [...]}` envelope or a bare array of issues.
+pub fn parse_jira_json_export(json_text: &str) -> Result<Vec<JiraExportIssue>, AppError> {
+    let doc: ExportDocument = serde_json::from_str(json_text.trim_start_matches('\u{feff}')).map_err(|e| {
+        AppError::new("INGEST_JIRA_JSON_PARSE_FAILED", "Failed to parse Jira JSON export").with_details(e.to_string())
+    })?;
+    Ok(match doc {
+        ExportDocument::Wrapped { issues } | ExportDocument::Bare(issues) => issues,
+    })
+}
+
+fn changelog_field_id(field: &str, to_text: &str) -> String {
+    format!("{JIRA_CHANGELOG_FIELD_PREFIX}{}:{}", field.to_lowercase(), to_text)
+}
+
+/// Histories sorted oldest first; Jira exports them newest first.
+fn histories_oldest_first(issue: &JiraExportIssue) -> Vec<&JiraChangelogHistory> {
+    let mut histories: Vec<&JiraChangelogHistory> = issue.changelog.iter().flat_map(|c| &c.histories).collect();
+    histories.sort_by(|a, b| {
+        let ts = |h: &JiraChangelogHistory| jira_timestamp(&h.created).unwrap_or_else(|| h.created.clone());
+        ts(a).cmp(&ts(b))
+    });
+    histories
+}
+
+/// Flattens an export issue into the API issue shape, adding one `changelog:<field>:<to>`
+/// pseudo field per transition target (first occurrence wins).
+fn to_api_issue(issue: &JiraExportIssue) -> JiraApiIssue {
+    let mut fields = issue.fields.clone();
+    for history in histories_oldest_first(issue) {
+        for item in &history.items {
+            let Some(to_text) = item.to_text.as_deref().filter(|t| !t.trim().is_empty()) else {
+                continue;
+            };
+            fields
+                .entry(changelog_field_id(&item.field, to_text.trim()))
+                .or_insert_with(|| Value::String(history.created.clone()));
+        }
+    }
+    JiraApiIssue {
+        key: issue.key.clone(),
+        fields,
+    }
+}
+
+pub fn preview_jira_json(json_text: &str, max_rows: usize) -> Result<JiraCsvPreview, AppError> {
+    let issues: Vec<JiraApiIssue> = parse_jira_json_export(json_text)?.iter().map(to_api_issue).collect();
+    Ok(preview_jira_issues(&issues, max_rows))
+}
+
+fn author_name(author: &Option<Value>) -> Option<String> {
+    let obj = author.as_ref()?.as_object()?;
+    ["displayName", "name", "emailAddress"]
+        .iter()
+        .find_map(|k| obj.get(*k).and_then(Value::as_str))
+        .map(str::to_string)
+}
+
+fn change_text(item: &JiraChangelogItem) -> String {
+    let from = item.from_text.as_deref().unwrap_or("").trim();
+    let to = item.to_text.as_deref().unwrap_or("").trim();
+    match (from.is_empty(), to.is_empty()) {
+        (true, true) => format!("{} cleared", item.field),
+        (true, false) => format!("{}: {}", item.field, to),
+        (false, true) => format!("{}: {} → (none)", item.field, from),
+        (false, false) => format!("{}: {} → {}", item.field, from, to),
+    }
+}
+
+/// Writes each changelog item as a `jira` timeline event on the issue's incident. Events already
+/// present (same incident, timestamp and text) are skipped, so re-importing an export is a no-op.
+fn insert_changelog_events(
+    conn: &mut Connection,
+    issues: &[JiraExportIssue],
+    mapping: &JiraCsvMapping,
+    summary: &mut JiraJsonImportSummary,
+) -> Result<(), AppError> {
+    let Some(external_id_field) = mapping.external_id.as_deref() else {
+        if issues.iter().any(|i| i.changelog.is_some()) {
+            summary.warnings.push(ValidationWarning::new(
+                "INGEST_JIRA_JSON_CHANGELOG_SKIPPED",
+                "Changelog not imported: map an external id so events can be attached to incidents",
+            ));
+        }
+        return Ok(());
+    };
+    let insert_failed = |key: &str, e: rusqlite::Error| {
+        AppError::new("INGEST_JIRA_JSON_INSERT_FAILED", "Failed to insert Jira changelog event")
+            .with_details(format!("key={key}; err={e}"))
+    };
+
+    let tx = conn.transaction().map_err(|e| insert_failed("-", e))?;
+    for issue in issues {
+        let api_issue = JiraApiIssue {
+            key: issue.key.clone(),
+            fields: issue.fields.clone(),
+        };
+        let external_id = issue_cell(&api_issue, external_id_field);
+        let incident_id: Option<i64> = tx
+            .query_row(
+                "SELECT id FROM incidents WHERE external_id = ?1",
+                [external_id.trim()],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| insert_failed(&issue.key, e))?;
+        let Some(incident_id) = incident_id else {
+            if issue.changelog.as_ref().is_some_and(|c| !c.histories.is_empty()) {
+                summary.warnings.push(
+                    ValidationWarning::new(
+                        "INGEST_JIRA_JSON_CHANGELOG_SKIPPED",
+                        "Changelog not imported: no incident with this external id",
+                    )
+                    .with_details(format!("key={}; external_id={}", issue.key, external_id)),
+                );
+            }
+            continue;
+        };
+        // Frozen quarters are closed books: their incidents get no new events on re-import.
+        if let Err(e) = crate::freeze::ensure_incident_mutable(&tx, incident_id) {
+            if e.code != "QUARTER_FROZEN" {
+                return Err(e);
+            }
+            if issue.changelog.as_ref().is_some_and(|c| !c.histories.is_empty()) {
+                summary.warnings.push(
+                    ValidationWarning::new(
+                        "INGEST_JIRA_JSON_CHANGELOG_SKIPPED",
+                        "Changelog not imported: incident belongs to a frozen quarter",
+                    )
+                    .with_details(format!("key={}; {}", issue.key, e.details.unwrap_or_default())),
+                );
+            }
+            continue;
+        }
+
+        for history in histories_oldest_first(issue) {
+            let ts = jira_timestamp(&history.created).unwrap_or_else(|| history.created.clone());
+            let author = author_name(&history.author);
+            for item in &history.items {
+                let text = change_text(item);
+                let exists: Option<i64> = tx
+                    .query_row(
+                        "SELECT id FROM timeline_events WHERE incident_id = ?1 AND source = 'jira' AND ts = ?2 AND text = ?3",
+                        rusqlite::params![incident_id, ts, text],
+                        |row| row.get(0),
+                    )
+                    .optional()
+                    .map_err(|e| insert_failed(&issue.key, e))?;
+                if exists.is_some() {
+                    summary.timeline_events_skipped += 1;
+                    continue;
+                }
+                let raw_json = serde_json::to_string(item).map_err(|e| {
+                    AppError::new("INGEST_JIRA_JSON_INSERT_FAILED", "Failed to encode Jira changelog item")
+                        .with_details(format!("key={}; err={e}", issue.key))
+                })?;
+                tx.execute(
+                    r#"
+              INSERT INTO timeline_events(
+                incident_id, source, ts, author, kind, text, raw_json, created_at
+              ) VALUES (
+                ?1, 'jira', ?2, ?3, 'field_change', ?4, ?5,
+                strftime('%Y-%m-%dT%H:%M:%fZ','now')
+              )
+              "#,
+                    rusqlite::params![incident_id, ts, author, text, raw_json],
+                )
+                .map_err(|e| insert_failed(&issue.key, e))?;
+                summary.timeline_events_inserted += 1;
+            }
+        }
+    }
+    tx.commit().map_err(|e| insert_failed("-", e))
+}
+
+/// Imports a bulk export: issues go through the Jira CSV pipeline (custom fields and
+/// `changelog:` pseudo fields are mappable by id), then changelog entries become timeline events.
+pub fn import_jira_json_with_progress(
+    conn: &mut Connection,
+    json_text: &str,
+    mapping: &JiraCsvMapping,
+    progress: &mut Progress<'_>,
+) -> Result<JiraJsonImportSummary, AppError> {
+    let issues = parse_jira_json_export(json_text)?;
+    let api_issues: Vec<JiraApiIssue> = issues.iter().map(to_api_issue).collect();
+    let incidents = import_jira_issues_with_progress(conn, &api_issues, mapping, progress)?;
+    let mut summary = JiraJsonImportSummary {
+        incidents,
+        timeline_events_inserted: 0,
+        timeline_events_skipped: 0,
+        warnings: Vec::new(),
+    };
+    insert_changelog_events(conn, &issues, mapping, &mut summary)?;
+    Ok(summary)
+}
+
+pub fn import_jira_json(
+    conn: &mut Connection,
+    json_text: &str,
+    mapping: &JiraCsvMapping,
+) -> Result<JiraJsonImportSummary, AppError> {
+    import_jira_json_with_progress(conn, json_text, mapping, &mut Progress::noop("import_jira_json"))
+}
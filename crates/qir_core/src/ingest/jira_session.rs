@@ -0,0 +1,186 @@
+use std::collections::HashSet;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+use crate::progress::Progress;
+use crate::profiles::jira::get_profile;
+
+use super::import_batches::{record_batch, ImportBatchLog};
+use super::import_history::{record_import, NewImportHistory};
+use super::jira_csv::{import_jira_csv_rows, preview_jira_csv, JiraImportConflict, JiraImportSummary};
+
+pub const JIRA_SESSION_IMPORT_SOURCE: &str = "jira_csv_session";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JiraImportSessionFile {
+    /// Display name (usually the file name); must be unique within the session.
+    pub name: String,
+    pub csv_text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JiraImportSessionFileSummary {
+    pub name: String,
+    pub summary: JiraImportSummary,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JiraImportSessionConflict {
+    pub file: String,
+    #[serde(flatten)]
+    pub conflict: JiraImportConflict,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JiraImportSessionSummary {
+    pub import_history_id: i64,
+    /// One import batch covers every file, so undo reverts the whole session.
+    #[serde(default)]
+    pub import_batch_id: Option<i64>,
+    pub profile_id: i64,
+    pub inserted: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub conflicts: Vec<JiraImportSessionConflict>,
+    /// Session-level warnings (e.g. header drift between files) followed by every file's
+    /// warnings, each tagged with `file=` in its details.
+    pub warnings: Vec<ValidationWarning>,
+    pub files: Vec<JiraImportSessionFileSummary>,
+}
+
+fn tag_warning(file: &str, w: &ValidationWarning) -> ValidationWarning {
+    let details = match w.details.as_deref() {
+        Some(d) => format!("file={file}; {d}"),
+        None => format!("file={file}"),
+    };
+    ValidationWarning::new(w.code.clone(), w.message.clone()).with_details(details)
+}
+
+/// Imports several CSV exports with one profile as a single operation: every file is imported
+/// inside one transaction (a hard failure in any file leaves the workspace untouched) and one
+/// `import_history` row covers the whole session.
+pub fn import_jira_csv_session(
+    conn: &mut Connection,
+    profile_id: i64,
+    files: &[JiraImportSessionFile],
+    progress: &mut Progress<'_>,
+) -> Result<JiraImportSessionSummary, AppError> {
+    if files.is_empty() {
+        return Err(AppError::new(
+            "INGEST_JIRA_SESSION_EMPTY",
+            "Import session has no files",
+        ));
+    }
+    let mut names = HashSet::new();
+    for f in files {
+        if !names.insert(f.name.as_str()) {
+            return Err(AppError::new(
+                "INGEST_JIRA_SESSION_DUPLICATE_FILE",
+                "The same file was added to the import session twice",
+            )
+            .with_details(format!("file={}", f.name)));
+        }
+    }
+    let profile = get_profile(conn, profile_id)?;
+
+    // Quarterly exports are expected to share a header row; drift is worth a look but the
+    // profile mapping decides what actually gets read.
+    let mut session_warnings = Vec::new();
+    let first_headers = preview_jira_csv(&files[0].csv_text, 0)?.headers;
+    for f in &files[1..] {
+        let headers = preview_jira_csv(&f.csv_text, 0)?.headers;
+        if headers != first_headers {
+            session_warnings.push(
+                ValidationWarning::new(
+                    "INGEST_JIRA_SESSION_HEADERS_DIFFER",
+                    "File headers differ from the first file in the session",
+                )
+                .with_details(format!("file={}; first_file={}", f.name, files[0].name)),
+            );
+        }
+    }
+
+    progress.run(|p| {
+        let tx = conn.transaction().map_err(|e| {
+            AppError::new("DB_TX_FAILED", "Failed to start import session transaction")
+                .with_details(e.to_string())
+        })?;
+
+        let mut out = JiraImportSessionSummary {
+            import_history_id: 0,
+            import_batch_id: None,
+            profile_id: profile.id,
+            inserted: 0,
+            updated: 0,
+            skipped: 0,
+            conflicts: Vec::new(),
+            warnings: session_warnings,
+            files: Vec::new(),
+        };
+        let mut batch = ImportBatchLog::default();
+        for (idx, f) in files.iter().enumerate() {
+            p.step("files", idx, files.len(), 1, 99);
+            let mut file_progress = Progress::noop("import_jira_csv");
+            let summary = import_jira_csv_rows(&tx, &f.csv_text, &profile.mapping, Some(&mut batch), &mut file_progress)
+                .map_err(|e| {
+                    let details = match e.details.as_deref() {
+                        Some(d) => format!("file={}; {d}", f.name),
+                        None => format!("file={}", f.name),
+                    };
+                    e.with_details(details)
+                })?;
+            for w in &summary.warnings {
+                let tagged = tag_warning(&f.name, w);
+                p.warning("files", &tagged);
+                out.warnings.push(tagged);
+            }
+            out.inserted += summary.inserted;
+            out.updated += summary.updated;
+            out.skipped += summary.skipped;
+            out.conflicts.extend(summary.conflicts.iter().map(|c| JiraImportSessionConflict {
+                file: f.name.clone(),
+                conflict: c.clone(),
+            }));
+            out.files.push(JiraImportSessionFileSummary {
+                name: f.name.clone(),
+                summary,
+            });
+        }
+
+        let file_names: Vec<String> = files.iter().map(|f| f.name.clone()).collect();
+        let summary_json = serde_json::to_string(&out.files).map_err(|e| {
+            AppError::new("DB_IMPORT_HISTORY_WRITE_FAILED", "Failed to encode import session summary")
+                .with_details(e.to_string())
+        })?;
+        out.import_history_id = record_import(
+            &tx,
+            &NewImportHistory {
+                source: JIRA_SESSION_IMPORT_SOURCE,
+                profile_id: Some(profile.id),
+                file_names: &file_names,
+                inserted: out.inserted,
+                updated: out.updated,
+                skipped: out.skipped,
+                conflicts: out.conflicts.len(),
+                warnings: out.warnings.len(),
+                summary_json,
+            },
+        )?;
+
+        out.import_batch_id = Some(record_batch(
+            &tx,
+            JIRA_SESSION_IMPORT_SOURCE,
+            out.inserted + out.updated + out.skipped,
+            &batch,
+        )?);
+
+        tx.commit().map_err(|e| {
+            AppError::new("DB_TX_FAILED", "Failed to commit import session")
+                .with_details(e.to_string())
+        })?;
+        Ok(out)
+    })
+}
@@ -0,0 +1,400 @@
+use std::fs;
+use std::path::Path;
+
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+use crate::normalize::timestamps::normalize_timestamp;
+
+use super::postmortem::{
+    section_for, time_of_day, write_postmortem, ActionEntry, PostmortemRecord, Section, TimelineEntry,
+};
+use super::slack_transcript::ensure_target_incident;
+
+/// `timeline_events.source` of bullets read from a Markdown postmortem.
+pub const MARKDOWN_EVENT_SOURCE: &str = "markdown_postmortem";
+
+/// `artifacts.kind` of the stored postmortem file.
+pub const MARKDOWN_ARTIFACT_KIND: &str = "postmortem_markdown";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MarkdownTimelineEntry {
+    /// Leading time of the bullet as written; empty when the bullet has none.
+    pub time: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MarkdownActionItem {
+    pub title: String,
+    /// First `@handle` in the bullet.
+    pub owner: Option<String>,
+    pub done: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MarkdownPostmortem {
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    pub impact: Option<String>,
+    pub timeline: Vec<MarkdownTimelineEntry>,
+    pub action_items: Vec<MarkdownActionItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MarkdownIngestSummary {
+    pub incident_id: i64,
+    pub incident_created: bool,
+    pub artifact_id: i64,
+    /// False when the same file content was already stored; the existing artifact is reused.
+    pub artifact_inserted: bool,
+    /// True when summary/impact were written to an empty incident description.
+    pub description_filled: bool,
+    pub inserted_events: usize,
+    pub inserted_action_items: usize,
+    pub skipped_duplicates: usize,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+/// Drops emphasis and code markers and keeps link text: `**[PR 12](http://x)**` -> `PR 12`.
+fn plain_inline(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(open) = rest.find('[') {
+        let link = rest[open + 1..]
+            .split_once("](")
+            .and_then(|(text, tail)| tail.find(')').map(|close| (text, &tail[close + 1..])));
+        match link {
+            Some((text, tail)) if !text.contains(']') => {
+                out.push_str(&rest[..open]);
+                out.push_str(text);
+                rest = tail;
+            }
+            _ => {
+                out.push_str(&rest[..=open]);
+                rest = &rest[open + 1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out.replace("**", "").replace("__", "").replace('`', "").split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn heading(line: &str) -> Option<(usize, String)> {
+    let trimmed = line.trim_start();
+    let level = trimmed.bytes().take_while(|b| *b == b'#').count();
+    let rest = &trimmed[level..];
+    if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with(' ')) {
+        return None;
+    }
+    Some((level, plain_inline(rest.trim().trim_end_matches('#'))))
+}
+
+/// Text of a list item line and its indent, for `-`, `*`, `+` and `1.`/`1)` markers.
+fn list_item(line: &str) -> Option<(usize, &str)> {
+    let indent = line.len() - line.trim_start().len();
+    let trimmed = line.trim_start();
+    if let Some(rest) = ["- ", "* ", "+ "].iter().find_map(|m| trimmed.strip_prefix(m)) {
+        return Some((indent, rest));
+    }
+    let digits = trimmed.bytes().take_while(u8::is_ascii_digit).count();
+    let rest = &trimmed[digits..];
+    (digits > 0)
+        .then(|| rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")))
+        .flatten()
+        .map(|rest| (indent, rest))
+}
+
+fn looks_like_time(candidate: &str) -> bool {
+    let mut scratch = Vec::new();
+    time_of_day(candidate).is_some()
+        || normalize_timestamp("timeline.time", candidate, &mut scratch).canonical_rfc3339_utc.is_some()
+}
+
+/// Splits "14:05 - Alerts fire", "2026-03-02 10:00 UTC: Paged" or "09:10 Backlog grows" into
+/// (time, text). Bullets without a leading time keep an empty time.
+fn split_time_prefix(s: &str) -> (String, String) {
+    for sep in [" - ", " – ", " — ", ": ", " | "] {
+        if let Some((head, tail)) = s.split_once(sep) {
+            if looks_like_time(head) && !tail.trim().is_empty() {
+                return (head.trim().to_string(), tail.trim().to_string());
+            }
+        }
+    }
+    let tokens: Vec<&str> = s.split_whitespace().collect();
+    for n in (1..=tokens.len().saturating_sub(1).min(3)).rev() {
+        let head = tokens[..n].join(" ");
+        if looks_like_time(&head) {
+            return (head, tokens[n..].join(" "));
+        }
+    }
+    (String::new(), s.to_string())
+}
+
+fn action_item(text: &str) -> MarkdownActionItem {
+    let (done, title) = match text.get(..4) {
+        Some("[x] " | "[X] ") => (true, &text[4..]),
+        Some("[ ] ") => (false, &text[4..]),
+        _ => (false, text),
+    };
+    let owner = title
+        .split_whitespace()
+        .find_map(|t| t.trim_start_matches(['(', '[']).strip_prefix('@'))
+        .map(|h| h.trim_end_matches(|c: char| !c.is_alphanumeric() && c != '_' && c != '-').to_string())
+        .filter(|h| !h.is_empty());
+    MarkdownActionItem {
+        title: title.trim().to_string(),
+        owner,
+        done,
+    }
+}
+
+/// Reads a postmortem written with conventional `#` headings: Summary/Overview paragraphs,
+/// Impact, Timeline bullets (leading times split off) and Action items bullets (`[x]`
+/// checkboxes, `@owner`). Front matter `title:` or the first level-1 heading is the title.
+/// Fenced code blocks are skipped.
+pub fn parse_markdown_postmortem(text: &str) -> Result<MarkdownPostmortem, AppError> {
+    let text = text.trim_start_matches('\u{feff}');
+    let mut lines = text.lines().peekable();
+    let mut title = None;
+    if lines.peek().is_some_and(|l| l.trim() == "---") {
+        lines.next();
+        for line in lines.by_ref() {
+            if line.trim() == "---" || line.trim() == "..." {
+                break;
+            }
+            if let Some(v) = line.strip_prefix("title:") {
+                title = Some(v.trim().trim_matches(|c| c == '"' || c == '\'').to_string()).filter(|t| !t.is_empty());
+            }
+        }
+    }
+
+    let mut section = Section::Other;
+    let mut summary: Vec<String> = Vec::new();
+    let mut impact: Vec<String> = Vec::new();
+    // (section, text) of bullets; nested bullets and continuation lines join their parent.
+    let mut items: Vec<(Section, String)> = Vec::new();
+    let mut paragraph: Vec<String> = Vec::new();
+    let mut in_item = false;
+    let mut fence: Option<&str> = None;
+
+    let flush = |section: Section, paragraph: &mut Vec<String>, summary: &mut Vec<String>, impact: &mut Vec<String>| {
+        if paragraph.is_empty() {
+            return;
+        }
+        let p = plain_inline(&paragraph.join(" "));
+        paragraph.clear();
+        match section {
+            Section::Summary => summary.push(p),
+            Section::Impact => impact.push(p),
+            _ => {}
+        }
+    };
+
+    for line in lines {
+        let trimmed = line.trim_start();
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            continue;
+        }
+        if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+            flush(section, &mut paragraph, &mut summary, &mut impact);
+            in_item = false;
+            fence = Some(marker);
+            continue;
+        }
+        if line.trim().is_empty() {
+            flush(section, &mut paragraph, &mut summary, &mut impact);
+            in_item = false;
+            continue;
+        }
+        if let Some((level, h)) = heading(line) {
+            flush(section, &mut paragraph, &mut summary, &mut impact);
+            in_item = false;
+            section = section_for(&h);
+            if title.is_none() && level == 1 && section == Section::Other && !h.is_empty() {
+                title = Some(h);
+            }
+            continue;
+        }
+        if let Some((indent, body)) = list_item(line) {
+            flush(section, &mut paragraph, &mut summary, &mut impact);
+            let body = body.trim();
+            // Prose sections keep bullets in document order with their paragraphs.
+            if let Section::Summary | Section::Impact = section {
+                let bullet = format!("- {}", plain_inline(body));
+                if section == Section::Summary {
+                    summary.push(bullet);
+                } else {
+                    impact.push(bullet);
+                }
+                in_item = false;
+                continue;
+            }
+            match items.last_mut() {
+                Some((_, parent)) if in_item && indent >= 2 => {
+                    parent.push_str("; ");
+                    parent.push_str(body);
+                }
+                _ => items.push((section, body.to_string())),
+            }
+            in_item = true;
+            continue;
+        }
+        if in_item && (line.starts_with(' ') || line.starts_with('\t')) {
+            if let Some((_, parent)) = items.last_mut() {
+                parent.push(' ');
+                parent.push_str(line.trim());
+            }
+            continue;
+        }
+        in_item = false;
+        paragraph.push(line.trim().to_string());
+    }
+    flush(section, &mut paragraph, &mut summary, &mut impact);
+
+    let mut timeline = Vec::new();
+    let mut action_items = Vec::new();
+    for (item_section, raw) in items {
+        match item_section {
+            Section::Timeline => {
+                let (time, text) = split_time_prefix(&plain_inline(&raw));
+                timeline.push(MarkdownTimelineEntry { time, text });
+            }
+            Section::Actions => action_items.push(action_item(&plain_inline(&raw))),
+            Section::Summary | Section::Impact | Section::Other => {}
+        }
+    }
+
+    let postmortem = MarkdownPostmortem {
+        title,
+        summary: Some(summary.join("\n\n")).filter(|s| !s.is_empty()),
+        impact: Some(impact.join("\n\n")).filter(|s| !s.is_empty()),
+        timeline,
+        action_items,
+    };
+    if postmortem.summary.is_none()
+        && postmortem.impact.is_none()
+        && postmortem.timeline.is_empty()
+        && postmortem.action_items.is_empty()
+    {
+        return Err(AppError::new(
+            "INGEST_MARKDOWN_EMPTY",
+            "No Summary, Impact, Timeline or Action items section found in the Markdown file",
+        ));
+    }
+    Ok(postmortem)
+}
+
+fn read_markdown(path: &Path) -> Result<String, AppError> {
+    fs::read_to_string(path).map_err(|e| {
+        AppError::new("INGEST_MARKDOWN_READ_FAILED", "Failed to read Markdown postmortem")
+            .with_details(format!("path={}: {}", path.display(), e))
+    })
+}
+
+pub fn preview_markdown_postmortem(path: &Path) -> Result<MarkdownPostmortem, AppError> {
+    parse_markdown_postmortem(&read_markdown(path)?)
+}
+
+/// Ingests one Markdown postmortem file into an incident: summary and impact fill an empty
+/// description, timeline bullets become timeline events, action items are added unless the
+/// title exists, and the file itself is stored as a `postmortem_markdown` artifact. Re-ingesting
+/// the same file adds nothing.
+pub fn ingest_markdown_postmortem(
+    conn: &mut Connection,
+    path: &Path,
+    incident_id: Option<i64>,
+    new_incident_title: Option<&str>,
+    ingested_at: &str,
+) -> Result<MarkdownIngestSummary, AppError> {
+    let text = read_markdown(path)?;
+    let doc = parse_markdown_postmortem(&text)?;
+    // A new incident defaults to the document title.
+    let title = match incident_id {
+        None => new_incident_title.or(doc.title.as_deref()),
+        Some(_) => new_incident_title,
+    };
+    let (target_incident_id, incident_created) = ensure_target_incident(conn, incident_id, title, &text)?;
+
+    let filename = path.file_name().map(|n| n.to_string_lossy().to_string());
+    let description = match (&doc.summary, &doc.impact) {
+        (Some(s), Some(i)) => Some(format!("{s}\n\nImpact: {i}")),
+        (Some(s), None) => Some(s.clone()),
+        (None, Some(i)) => Some(format!("Impact: {i}")),
+        (None, None) => None,
+    };
+    let mut raw_context = serde_json::Map::new();
+    raw_context.insert("source_file".to_string(), filename.clone().into());
+    let record = PostmortemRecord {
+        source: MARKDOWN_EVENT_SOURCE,
+        description: description.as_deref(),
+        timeline: doc
+            .timeline
+            .iter()
+            .map(|e| TimelineEntry {
+                time: &e.time,
+                text: &e.text,
+                author: None,
+            })
+            .collect(),
+        action_items: doc
+            .action_items
+            .iter()
+            .map(|a| ActionEntry {
+                title: &a.title,
+                owner: a.owner.as_deref(),
+                done: a.done,
+            })
+            .collect(),
+        raw_context,
+    };
+
+    let mut warnings = Vec::new();
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to start transaction").with_details(e.to_string()))?;
+    let sha256 = hex::encode(Sha256::digest(text.as_bytes()));
+    let artifact_inserted = tx
+        .execute(
+            "INSERT OR IGNORE INTO artifacts(incident_id, kind, sha256, filename, mime_type, text, created_at)
+             VALUES (?1, ?2, ?3, ?4, 'text/markdown', ?5, ?6)",
+            params![target_incident_id, MARKDOWN_ARTIFACT_KIND, sha256, filename, text, ingested_at],
+        )
+        .map_err(|e| {
+            AppError::new("INGEST_MARKDOWN_INSERT_FAILED", "Failed to store Markdown postmortem")
+                .with_details(format!("artifact; err={e}"))
+        })?
+        > 0;
+    let artifact_id: i64 = tx
+        .query_row("SELECT id FROM artifacts WHERE sha256 = ?1", [&sha256], |r| r.get(0))
+        .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to query postmortem artifact").with_details(e.to_string()))?;
+    let counts = write_postmortem(
+        &tx,
+        target_incident_id,
+        &record,
+        "INGEST_MARKDOWN_TS_NO_DATE",
+        "INGEST_MARKDOWN_INSERT_FAILED",
+        ingested_at,
+        &mut warnings,
+    )?;
+    tx.commit()
+        .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to commit transaction").with_details(e.to_string()))?;
+
+    Ok(MarkdownIngestSummary {
+        incident_id: target_incident_id,
+        incident_created,
+        artifact_id,
+        artifact_inserted,
+        description_filled: counts.description_filled,
+        inserted_events: counts.inserted_events,
+        inserted_action_items: counts.inserted_action_items,
+        skipped_duplicates: counts.skipped_duplicates,
+        warnings,
+    })
+}
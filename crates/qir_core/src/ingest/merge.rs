@@ -0,0 +1,186 @@
+// Pure merge rules for re-importing an incident that already exists. Kept free of DB and
+// parsing concerns so the invariants can be exercised in isolation (see tests/ingest_merge_props.rs).
+
+use serde::{Deserialize, Serialize};
+
+/// How a re-imported row is merged into the incident it matches when stored and incoming
+/// values disagree. Disagreements are reported whichever strategy is chosen.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// Stored values win; the row only fills fields that are empty in the workspace.
+    PreferExisting,
+    /// The row is the source of truth for every mapped field, so empty cells clear stored values.
+    PreferIncoming,
+    /// Non-empty cells win; empty cells keep stored values.
+    #[default]
+    MergePreserveOnEmpty,
+    /// Rows that disagree with a stored value are not applied; other rows merge preserve-on-empty.
+    SkipConflicts,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampUpdate {
+    // Preserve-on-empty semantics:
+    // - provided=false: do not update stored canonical/raw.
+    // - provided=true: overwrite stored canonical/raw exactly as computed, even if canonical is NULL.
+    pub provided: bool,
+    pub canonical: Option<String>,
+    pub raw: Option<String>,
+}
+
+impl TimestampUpdate {
+    pub fn not_provided() -> Self {
+        Self {
+            provided: false,
+            canonical: None,
+            raw: None,
+        }
+    }
+
+    fn apply(self, existing_canonical: &Option<String>, existing_raw: &Option<String>) -> (Option<String>, Option<String>) {
+        if self.provided {
+            (self.canonical, self.raw)
+        } else {
+            (existing_canonical.clone(), existing_raw.clone())
+        }
+    }
+}
+
+/// One update per canonical timestamp column of an incident.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampUpdates {
+    pub start: TimestampUpdate,
+    pub first_observed: TimestampUpdate,
+    pub it_awareness: TimestampUpdate,
+    pub ack: TimestampUpdate,
+    pub mitigate: TimestampUpdate,
+    pub resolve: TimestampUpdate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncidentRecord {
+    pub external_id: Option<String>,
+    pub fingerprint: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub severity: Option<String>,
+    pub detection_source: Option<String>,
+    pub vendor: Option<String>,
+    pub service: Option<String>,
+    pub impact_pct: Option<i64>,
+    pub service_health_pct: Option<i64>,
+    pub start_ts: Option<String>,
+    pub first_observed_ts: Option<String>,
+    pub it_awareness_ts: Option<String>,
+    pub ack_ts: Option<String>,
+    pub mitigate_ts: Option<String>,
+    pub resolve_ts: Option<String>,
+    pub start_ts_raw: Option<String>,
+    pub first_observed_ts_raw: Option<String>,
+    pub it_awareness_ts_raw: Option<String>,
+    pub ack_ts_raw: Option<String>,
+    pub mitigate_ts_raw: Option<String>,
+    pub resolve_ts_raw: Option<String>,
+}
+
+pub fn merge_preserve_on_empty(
+    existing: &IncidentRecord,
+    incoming: IncidentRecord,
+    ts: TimestampUpdates,
+) -> IncidentRecord {
+    // Preserve-on-empty semantics:
+    // - If the CSV cell is missing/empty, we do NOT overwrite the existing value with NULL.
+    // - For required fields (title), the incoming value is always present by construction.
+    //
+    // Timestamp fields require special handling (incoming timestamp fields are ignored):
+    // - provided=true with raw=None must clear any previously stored raw.
+    // - provided=true with canonical=None must overwrite canonical to NULL.
+    let (start_ts, start_ts_raw) = ts.start.apply(&existing.start_ts, &existing.start_ts_raw);
+    let (first_observed_ts, first_observed_ts_raw) = ts
+        .first_observed
+        .apply(&existing.first_observed_ts, &existing.first_observed_ts_raw);
+    let (it_awareness_ts, it_awareness_ts_raw) = ts
+        .it_awareness
+        .apply(&existing.it_awareness_ts, &existing.it_awareness_ts_raw);
+    let (ack_ts, ack_ts_raw) = ts.ack.apply(&existing.ack_ts, &existing.ack_ts_raw);
+    let (mitigate_ts, mitigate_ts_raw) = ts
+        .mitigate
+        .apply(&existing.mitigate_ts, &existing.mitigate_ts_raw);
+    let (resolve_ts, resolve_ts_raw) = ts.resolve.apply(&existing.resolve_ts, &existing.resolve_ts_raw);
+
+    IncidentRecord {
+        external_id: incoming
+            .external_id
+            .or_else(|| existing.external_id.clone()),
+        fingerprint: incoming.fingerprint, // recomputed by the caller after merge
+        title: incoming.title,
+        description: incoming
+            .description
+            .or_else(|| existing.description.clone()),
+        severity: incoming.severity.or_else(|| existing.severity.clone()),
+        detection_source: incoming
+            .detection_source
+            .or_else(|| existing.detection_source.clone()),
+        vendor: incoming.vendor.or_else(|| existing.vendor.clone()),
+        service: incoming.service.or_else(|| existing.service.clone()),
+        impact_pct: incoming.impact_pct.or(existing.impact_pct),
+        service_health_pct: incoming.service_health_pct.or(existing.service_health_pct),
+        start_ts,
+        first_observed_ts,
+        it_awareness_ts,
+        ack_ts,
+        mitigate_ts,
+        resolve_ts,
+        start_ts_raw,
+        first_observed_ts_raw,
+        it_awareness_ts_raw,
+        ack_ts_raw,
+        mitigate_ts_raw,
+        resolve_ts_raw,
+    }
+}
+
+/// Stored values win: incoming values (timestamps included) only fill fields that are empty.
+pub fn merge_prefer_existing(existing: &IncidentRecord, incoming: IncidentRecord, ts: TimestampUpdates) -> IncidentRecord {
+    let keep = |canonical: &Option<String>, raw: &Option<String>, update: TimestampUpdate| {
+        if canonical.is_some() || raw.is_some() {
+            (canonical.clone(), raw.clone())
+        } else {
+            update.apply(canonical, raw)
+        }
+    };
+    let (start_ts, start_ts_raw) = keep(&existing.start_ts, &existing.start_ts_raw, ts.start);
+    let (first_observed_ts, first_observed_ts_raw) =
+        keep(&existing.first_observed_ts, &existing.first_observed_ts_raw, ts.first_observed);
+    let (it_awareness_ts, it_awareness_ts_raw) =
+        keep(&existing.it_awareness_ts, &existing.it_awareness_ts_raw, ts.it_awareness);
+    let (ack_ts, ack_ts_raw) = keep(&existing.ack_ts, &existing.ack_ts_raw, ts.ack);
+    let (mitigate_ts, mitigate_ts_raw) = keep(&existing.mitigate_ts, &existing.mitigate_ts_raw, ts.mitigate);
+    let (resolve_ts, resolve_ts_raw) = keep(&existing.resolve_ts, &existing.resolve_ts_raw, ts.resolve);
+
+    IncidentRecord {
+        external_id: existing.external_id.clone().or(incoming.external_id),
+        fingerprint: incoming.fingerprint, // recomputed by the caller after merge
+        title: existing.title.clone(),
+        description: existing.description.clone().or(incoming.description),
+        severity: existing.severity.clone().or(incoming.severity),
+        detection_source: existing.detection_source.clone().or(incoming.detection_source),
+        vendor: existing.vendor.clone().or(incoming.vendor),
+        service: existing.service.clone().or(incoming.service),
+        impact_pct: existing.impact_pct.or(incoming.impact_pct),
+        service_health_pct: existing.service_health_pct.or(incoming.service_health_pct),
+        start_ts,
+        first_observed_ts,
+        it_awareness_ts,
+        ack_ts,
+        mitigate_ts,
+        resolve_ts,
+        start_ts_raw,
+        first_observed_ts_raw,
+        it_awareness_ts_raw,
+        ack_ts_raw,
+        mitigate_ts_raw,
+        resolve_ts_raw,
+    }
+}
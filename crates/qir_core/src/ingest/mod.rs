@@ -1,2 +1,25 @@
+pub mod artifacts_dir;
+pub mod confluence;
+pub mod csv_dialect;
+pub mod email;
+pub mod file_batch;
+pub mod fingerprint;
+pub mod generic_csv;
+pub mod import_batches;
+pub mod import_history;
+pub mod incident_io;
+pub mod jira_api;
 pub mod jira_csv;
+pub mod jira_json;
+pub mod jira_session;
+pub mod markdown_postmortem;
+pub mod merge;
+pub mod pagerduty_csv;
+mod postmortem;
+pub mod retro_tool;
+pub mod servicenow_csv;
+pub mod slack_export;
 pub mod slack_transcript;
+pub mod statuspage;
+pub mod xlsx;
+pub mod zoom_vtt;
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+use crate::progress::Progress;
+
+use super::csv_dialect::csv_reader;
+use super::generic_csv::{import_source_rows_with_progress, SourceRow};
+use super::jira_csv::{JiraCsvPreview, JiraImportSummary};
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+/// Prefix for external ids built from PagerDuty incident numbers, so they never collide with
+/// Jira keys in the same workspace.
+pub const PAGERDUTY_EXTERNAL_ID_PREFIX: &str = "PD-";
+
+/// Column headers of a PagerDuty incidents CSV export. Defaults match the stock export.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PagerDutyCsvMapping {
+    /// Incident number column; imported as external id `PD-<number>`. Optional.
+    pub incident_number: Option<String>,
+    /// PagerDuty's "description" column is the incident title. Required.
+    pub title: String,
+    pub description: Option<String>,
+    /// Priority column (e.g. P1). When present it wins over urgency for the severity.
+    pub priority: Option<String>,
+    pub urgency: Option<String>,
+    /// Severity recorded for each urgency value (case-insensitive); unlisted values are kept verbatim.
+    #[serde(default = "default_urgency_severity")]
+    pub urgency_severity: BTreeMap<String, String>,
+    pub service: Option<String>,
+    pub created_ts: Option<String>,
+    pub ack_ts: Option<String>,
+    pub resolved_ts: Option<String>,
+}
+
+fn default_urgency_severity() -> BTreeMap<String, String> {
+    BTreeMap::from([
+        ("high".to_string(), "SEV2".to_string()),
+        ("low".to_string(), "SEV4".to_string()),
+    ])
+}
+
+impl Default for PagerDutyCsvMapping {
+    fn default() -> Self {
+        Self {
+            incident_number: Some("incident_number".to_string()),
+            title: "description".to_string(),
+            description: None,
+            priority: Some("priority_name".to_string()),
+            urgency: Some("urgency".to_string()),
+            urgency_severity: default_urgency_severity(),
+            service: Some("service_name".to_string()),
+            created_ts: Some("created_on".to_string()),
+            ack_ts: None,
+            resolved_ts: Some("resolved_on".to_string()),
+        }
+    }
+}
+
+fn get<'a>(row: &'a csv::StringRecord, headers: &csv::StringRecord, header_name: Option<&str>) -> &'a str {
+    header_name
+        .and_then(|name| headers.iter().position(|h| h == name))
+        .and_then(|idx| row.get(idx))
+        .map(str::trim)
+        .unwrap_or("")
+}
+
+fn severity_for(mapping: &PagerDutyCsvMapping, priority: &str, urgency: &str) -> String {
+    if !priority.is_empty() {
+        return priority.to_string();
+    }
+    mapping
+        .urgency_severity
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(urgency))
+        .map(|(_, v)| v.clone())
+        .unwrap_or_else(|| urgency.to_string())
+}
+
+/// Reads the export into canonical rows, one per input row (`None` for unreadable ones) so row
+/// numbers in warnings and conflicts match the original file.
+fn read_rows(
+    csv_text: &str,
+    mapping: &PagerDutyCsvMapping,
+    warnings: &mut Vec<ValidationWarning>,
+) -> Result<Vec<Option<SourceRow>>, AppError> {
+    let (mut rdr, _) = csv_reader(csv_text, None);
+    let headers = rdr
+        .headers()
+        .map_err(|e| {
+            AppError::new(
+                "INGEST_PAGERDUTY_CSV_HEADERS_FAILED",
+                "Failed to read PagerDuty CSV headers",
+            )
+            .with_details(e.to_string())
+        })?
+        .clone();
+
+    let mut rows = Vec::new();
+    for (row_idx, result) in rdr.records().enumerate() {
+        let row = match result {
+            Ok(r) => r,
+            Err(e) => {
+                warnings.push(
+                    ValidationWarning::new(
+                        "INGEST_PAGERDUTY_CSV_PARSE_FAILED",
+                        "Failed to parse PagerDuty CSV row",
+                    )
+                    .with_details(format!("row={row_idx}; err={e}")),
+                );
+                rows.push(None);
+                continue;
+            }
+        };
+
+        let number = get(&row, &headers, mapping.incident_number.as_deref());
+        let external_id = if number.is_empty() {
+            String::new()
+        } else {
+            format!("{PAGERDUTY_EXTERNAL_ID_PREFIX}{number}")
+        };
+        let col = |header: &Option<String>| get(&row, &headers, header.as_deref()).to_string();
+        rows.push(Some(SourceRow {
+            external_id,
+            title: get(&row, &headers, Some(mapping.title.as_str())).to_string(),
+            description: col(&mapping.description),
+            severity: severity_for(
+                mapping,
+                get(&row, &headers, mapping.priority.as_deref()),
+                get(&row, &headers, mapping.urgency.as_deref()),
+            ),
+            service: col(&mapping.service),
+            start_ts: col(&mapping.created_ts),
+            ack_ts: col(&mapping.ack_ts),
+            resolve_ts: col(&mapping.resolved_ts),
+            ..SourceRow::default()
+        }));
+    }
+    Ok(rows)
+}
+
+pub fn preview_pagerduty_csv(csv_text: &str, max_rows: usize) -> Result<JiraCsvPreview, AppError> {
+    let (mut rdr, delimiter) = csv_reader(csv_text, None);
+
+    let headers = rdr
+        .headers()
+        .map_err(|e| {
+            AppError::new(
+                "INGEST_PAGERDUTY_CSV_HEADERS_FAILED",
+                "Failed to read PagerDuty CSV headers",
+            )
+            .with_details(e.to_string())
+        })?
+        .iter()
+        .map(|h| h.to_string())
+        .collect::<Vec<_>>();
+
+    let mut rows = Vec::new();
+    for result in rdr.records().take(max_rows) {
+        let row = result.map_err(|e| {
+            AppError::new(
+                "INGEST_PAGERDUTY_CSV_PARSE_FAILED",
+                "Failed to parse PagerDuty CSV row",
+            )
+            .with_details(e.to_string())
+        })?;
+        rows.push(row.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+    }
+
+    Ok(JiraCsvPreview {
+        headers,
+        rows,
+        delimiter,
+    })
+}
+
+pub fn import_pagerduty_csv(
+    conn: &mut Connection,
+    csv_text: &str,
+    mapping: &PagerDutyCsvMapping,
+) -> Result<JiraImportSummary, AppError> {
+    import_pagerduty_csv_with_progress(conn, csv_text, mapping, &mut Progress::noop("import_pagerduty_csv"))
+}
+
+/// Imports a PagerDuty export through the shared CSV import path, so dedupe, merge,
+/// frozen-quarter and conflict handling are identical for every source.
+pub fn import_pagerduty_csv_with_progress(
+    conn: &mut Connection,
+    csv_text: &str,
+    mapping: &PagerDutyCsvMapping,
+    progress: &mut Progress<'_>,
+) -> Result<JiraImportSummary, AppError> {
+    let mut warnings = Vec::new();
+    let rows = read_rows(csv_text, mapping, &mut warnings)?;
+    let mut summary = import_source_rows_with_progress(conn, &rows, "pagerduty_csv", progress)?;
+    warnings.append(&mut summary.warnings);
+    summary.warnings = warnings;
+    Ok(summary)
+}
@@ -0,0 +1,237 @@
+use rusqlite::{params, OptionalExtension, Transaction};
+use time::format_description::well_known::Rfc3339;
+use time::{Duration, OffsetDateTime};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+use crate::normalize::timestamps::normalize_timestamp;
+
+// Shared by the postmortem document importers (Confluence pages, Markdown files).
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Section {
+    Other,
+    Summary,
+    Impact,
+    Timeline,
+    Actions,
+}
+
+pub(super) fn section_for(heading: &str) -> Section {
+    let h = heading.to_lowercase();
+    if h.contains("timeline") || h.contains("chronology") {
+        Section::Timeline
+    } else if h.contains("action item") || h.contains("follow-up") || h.contains("follow up") || h.contains("next steps")
+    {
+        Section::Actions
+    } else if h.contains("summary") || h.contains("overview") || h == "description" {
+        Section::Summary
+    } else if h.contains("impact") {
+        Section::Impact
+    } else {
+        Section::Other
+    }
+}
+
+/// "14:05", "2:05 PM" style cells without a date: (hour, minute, second).
+pub(super) fn time_of_day(raw: &str) -> Option<(u8, u8, u8)> {
+    let s = raw.trim().trim_end_matches(" UTC").trim_end_matches(" GMT").trim_end_matches('Z').trim();
+    let (clock, pm) = match s.rsplit_once(' ') {
+        Some((c, ampm)) if ampm.eq_ignore_ascii_case("am") => (c, Some(false)),
+        Some((c, ampm)) if ampm.eq_ignore_ascii_case("pm") => (c, Some(true)),
+        _ => (s, None),
+    };
+    let parts: Vec<&str> = clock.split(':').collect();
+    if !(2..=3).contains(&parts.len()) || parts.iter().any(|p| p.is_empty() || p.len() > 2 || !p.bytes().all(|b| b.is_ascii_digit())) {
+        return None;
+    }
+    let mut hour: u8 = parts[0].parse().ok()?;
+    let minute: u8 = parts[1].parse().ok()?;
+    let second: u8 = parts.get(2).map_or(Some(0), |p| p.parse().ok())?;
+    if let Some(pm) = pm {
+        if !(1..=12).contains(&hour) {
+            return None;
+        }
+        hour = match (hour, pm) {
+            (12, false) => 0,
+            (12, true) => 12,
+            (h, true) => h + 12,
+            (h, false) => h,
+        };
+    }
+    (hour < 24 && minute < 60 && second < 60).then_some((hour, minute, second))
+}
+
+/// Canonicalizes timeline times in order. Full timestamps go through the normal allowlist;
+/// time-of-day values take the date of the previous dated row (initially the incident start)
+/// and roll over midnight when the clock goes backwards. Times read as UTC. Undated times with
+/// nothing to anchor them get `no_date_code`.
+pub(super) fn resolve_timeline_ts(
+    times: &[&str],
+    incident_start: Option<&str>,
+    no_date_code: &str,
+    warnings: &mut Vec<ValidationWarning>,
+) -> Vec<Option<String>> {
+    let parse = |s: &str| OffsetDateTime::parse(s, &Rfc3339).ok();
+    let mut last: Option<OffsetDateTime> = incident_start.and_then(parse);
+    // Rows logged before the recorded start are common, so only a previous row can roll the day.
+    let mut last_from_row = false;
+    let mut out = Vec::with_capacity(times.len());
+    for (idx, time) in times.iter().enumerate() {
+        if time.is_empty() {
+            out.push(None);
+            continue;
+        }
+        let ts = match time_of_day(time) {
+            Some((h, m, s)) => match last {
+                Some(prev) => {
+                    let mut at = prev.replace_time(time::Time::from_hms(h, m, s).expect("validated time"));
+                    if last_from_row && at < prev {
+                        at += Duration::days(1);
+                    }
+                    Some(at)
+                }
+                None => {
+                    warnings.push(
+                        ValidationWarning::new(no_date_code, "Timeline time has no date to anchor it")
+                            .with_details(format!("row={}; time={time}", idx + 1)),
+                    );
+                    None
+                }
+            },
+            None => normalize_timestamp("timeline.time", time, warnings)
+                .canonical_rfc3339_utc
+                .as_deref()
+                .and_then(parse),
+        };
+        if ts.is_some() {
+            last = ts;
+            last_from_row = true;
+        }
+        out.push(ts.and_then(|t| t.format(&Rfc3339).ok()));
+    }
+    out
+}
+
+pub(super) struct TimelineEntry<'a> {
+    pub time: &'a str,
+    pub text: &'a str,
+    pub author: Option<&'a str>,
+}
+
+pub(super) struct ActionEntry<'a> {
+    pub title: &'a str,
+    pub owner: Option<&'a str>,
+    pub done: bool,
+}
+
+/// What a postmortem document contributes to an incident.
+pub(super) struct PostmortemRecord<'a> {
+    /// `timeline_events.source` of the rows written.
+    pub source: &'a str,
+    pub description: Option<&'a str>,
+    pub timeline: Vec<TimelineEntry<'a>>,
+    pub action_items: Vec<ActionEntry<'a>>,
+    /// Document fields stored in each event's raw_json next to the raw time.
+    pub raw_context: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct PostmortemWriteCounts {
+    pub description_filled: bool,
+    pub inserted_events: usize,
+    pub inserted_action_items: usize,
+    pub skipped_duplicates: usize,
+}
+
+/// Fills an empty description, adds timeline events not already recorded from `source` (same
+/// ts and text) and action items whose title is new to the incident (case-insensitive). Done
+/// items are closed at `ingested_at`.
+pub(super) fn write_postmortem(
+    tx: &Transaction<'_>,
+    incident_id: i64,
+    record: &PostmortemRecord<'_>,
+    no_date_code: &str,
+    insert_failed_code: &str,
+    ingested_at: &str,
+    warnings: &mut Vec<ValidationWarning>,
+) -> Result<PostmortemWriteCounts, AppError> {
+    let insert_failed = |what: String, e: rusqlite::Error| {
+        AppError::new(insert_failed_code, "Failed to store postmortem content").with_details(format!("{what}; err={e}"))
+    };
+    let mut counts = PostmortemWriteCounts::default();
+
+    if let Some(text) = record.description {
+        counts.description_filled = tx
+            .execute(
+                "UPDATE incidents SET description = ?1
+                 WHERE id = ?2 AND (description IS NULL OR trim(description) = '')",
+                params![text, incident_id],
+            )
+            .map_err(|e| insert_failed("field=description".to_string(), e))?
+            > 0;
+    }
+
+    let start: Option<String> = tx
+        .query_row("SELECT start_ts FROM incidents WHERE id = ?1", [incident_id], |r| r.get(0))
+        .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to load incident start").with_details(e.to_string()))?;
+    let times: Vec<&str> = record.timeline.iter().map(|e| e.time).collect();
+    let stamps = resolve_timeline_ts(&times, start.as_deref(), no_date_code, warnings);
+    for (entry, ts) in record.timeline.iter().zip(stamps) {
+        let exists: Option<i64> = tx
+            .query_row(
+                "SELECT id FROM timeline_events
+                 WHERE incident_id = ?1 AND source = ?2 AND ts IS ?3 AND text = ?4",
+                params![incident_id, record.source, ts, entry.text],
+                |r| r.get(0),
+            )
+            .optional()
+            .map_err(|e| {
+                AppError::new("DB_QUERY_FAILED", "Failed to check existing postmortem events").with_details(e.to_string())
+            })?;
+        if exists.is_some() {
+            counts.skipped_duplicates += 1;
+            continue;
+        }
+        let mut raw = record.raw_context.clone();
+        raw.insert("time".to_string(), entry.time.into());
+        let raw_json = serde_json::Value::Object(raw).to_string();
+        tx.execute(
+            "INSERT INTO timeline_events(incident_id, source, ts, author, kind, text, raw_json, created_at)
+             VALUES (?1, ?2, ?3, ?4, 'postmortem_timeline', ?5, ?6, ?7)",
+            params![incident_id, record.source, ts, entry.author, entry.text, raw_json, ingested_at],
+        )
+        .map_err(|e| insert_failed(format!("time={}", entry.time), e))?;
+        counts.inserted_events += 1;
+    }
+
+    for item in &record.action_items {
+        let exists: Option<i64> = tx
+            .query_row(
+                "SELECT id FROM action_items WHERE incident_id = ?1 AND lower(title) = lower(?2)",
+                params![incident_id, item.title],
+                |r| r.get(0),
+            )
+            .optional()
+            .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to check existing action items").with_details(e.to_string()))?;
+        if exists.is_some() {
+            counts.skipped_duplicates += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO action_items(incident_id, title, owner, done, created_at, closed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                incident_id,
+                item.title,
+                item.owner,
+                item.done as i64,
+                ingested_at,
+                item.done.then_some(ingested_at)
+            ],
+        )
+        .map_err(|e| insert_failed(format!("action_item={}", item.title), e))?;
+        counts.inserted_action_items += 1;
+    }
+    Ok(counts)
+}
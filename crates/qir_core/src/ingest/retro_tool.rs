@@ -0,0 +1,305 @@
+use std::collections::BTreeSet;
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+use crate::progress::Progress;
+
+use super::generic_csv::{import_source_rows_with_progress, SourceRow};
+use super::jira_csv::JiraImportSummary;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Retrospective tools whose incident exports can be imported.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RetroTool {
+    FireHydrant,
+    Rootly,
+}
+
+impl RetroTool {
+    /// Prefix for external ids, so incident numbers never collide across tools.
+    pub fn external_id_prefix(self) -> &'static str {
+        match self {
+            RetroTool::FireHydrant => "FH-",
+            RetroTool::Rootly => "RL-",
+        }
+    }
+
+    /// Source recorded on import batches.
+    pub fn source_label(self) -> &'static str {
+        match self {
+            RetroTool::FireHydrant => "firehydrant",
+            RetroTool::Rootly => "rootly",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RetroToolPreview {
+    pub tool: RetroTool,
+    pub incident_count: usize,
+    /// Distinct milestone names found, canonical or not.
+    pub milestones: Vec<String>,
+}
+
+/// Canonical milestone a tool milestone maps onto.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Milestone {
+    Started,
+    Detected,
+    Acknowledged,
+    Mitigated,
+    Resolved,
+}
+
+fn milestone_for(name: &str) -> Option<Milestone> {
+    let name = name.trim().to_lowercase().replace(['-', ' '], "_");
+    let name = name.strip_suffix("_at").unwrap_or(&name);
+    match name {
+        "started" | "start" => Some(Milestone::Started),
+        "detected" => Some(Milestone::Detected),
+        "acknowledged" | "acknowledge" => Some(Milestone::Acknowledged),
+        "mitigated" | "stabilized" => Some(Milestone::Mitigated),
+        "resolved" => Some(Milestone::Resolved),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct RetroRecord {
+    number: String,
+    title: String,
+    description: String,
+    severity: String,
+    service: String,
+    started: String,
+    detected: String,
+    acknowledged: String,
+    mitigated: String,
+    resolved: String,
+    milestone_names: Vec<String>,
+}
+
+impl RetroRecord {
+    fn set(&mut self, name: &str, ts: &str) {
+        if ts.is_empty() {
+            return;
+        }
+        self.milestone_names.push(name.to_string());
+        let slot = match milestone_for(name) {
+            Some(Milestone::Started) => &mut self.started,
+            Some(Milestone::Detected) => &mut self.detected,
+            Some(Milestone::Acknowledged) => &mut self.acknowledged,
+            Some(Milestone::Mitigated) => &mut self.mitigated,
+            Some(Milestone::Resolved) => &mut self.resolved,
+            None => return,
+        };
+        // Tools repeat milestones after reopen; the earliest occurrence is the one that counts.
+        if slot.is_empty() || ts < slot.as_str() {
+            *slot = ts.to_string();
+        }
+    }
+}
+
+fn parse_failed(message: &str, details: String) -> AppError {
+    AppError::new("INGEST_RETRO_TOOL_PARSE_FAILED", message).with_details(details)
+}
+
+fn str_at<'a>(v: &'a Value, path: &[&str]) -> &'a str {
+    path.iter()
+        .try_fold(v, |v, key| v.get(key))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .unwrap_or("")
+}
+
+fn scalar_at(v: &Value, path: &[&str]) -> String {
+    match path.iter().try_fold(v, |v, key| v.get(key)) {
+        Some(Value::String(s)) => s.trim().to_string(),
+        Some(Value::Number(n)) => n.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// A severity is either a plain string or an object with a name/slug.
+fn named(v: Option<&Value>) -> String {
+    match v {
+        Some(Value::String(s)) => s.trim().to_string(),
+        Some(v @ Value::Object(_)) => {
+            let paths: [&[&str]; 4] = [&["name"], &["slug"], &["data", "attributes", "name"], &["data", "attributes", "slug"]];
+            paths
+                .iter()
+                .map(|p| str_at(v, p))
+                .find(|s| !s.is_empty())
+                .unwrap_or("")
+                .to_string()
+        }
+        _ => String::new(),
+    }
+}
+
+/// FireHydrant incident: milestones are a `[{type, occurred_at}]` list; `started_at` is also
+/// top-level on older exports.
+fn firehydrant_record(v: &Value) -> RetroRecord {
+    let mut r = RetroRecord {
+        number: scalar_at(v, &["number"]),
+        title: str_at(v, &["name"]).to_string(),
+        description: str_at(v, &["description"]).to_string(),
+        severity: named(v.get("severity")),
+        service: v
+            .get("services")
+            .and_then(Value::as_array)
+            .and_then(|s| s.first())
+            .map(|s| str_at(s, &["name"]).to_string())
+            .unwrap_or_default(),
+        ..RetroRecord::default()
+    };
+    if r.number.is_empty() {
+        r.number = str_at(v, &["id"]).to_string();
+    }
+    r.set("started", str_at(v, &["started_at"]));
+    for m in v.get("milestones").and_then(Value::as_array).into_iter().flatten() {
+        let name = Some(str_at(m, &["type"])).filter(|s| !s.is_empty()).unwrap_or_else(|| str_at(m, &["name"]));
+        r.set(name, str_at(m, &["occurred_at"]));
+    }
+    r
+}
+
+/// Rootly incident: JSON:API resource whose milestones are `<name>_at` attributes.
+fn rootly_record(v: &Value) -> RetroRecord {
+    let attrs = v.get("attributes").unwrap_or(v);
+    let mut r = RetroRecord {
+        number: scalar_at(attrs, &["sequential_id"]),
+        title: str_at(attrs, &["title"]).to_string(),
+        description: str_at(attrs, &["summary"]).to_string(),
+        severity: named(attrs.get("severity")),
+        service: attrs
+            .get("services")
+            .and_then(|s| s.get("data").unwrap_or(s).as_array())
+            .and_then(|s| s.first())
+            .map(|s| {
+                Some(str_at(s, &["attributes", "name"]))
+                    .filter(|n| !n.is_empty())
+                    .unwrap_or_else(|| str_at(s, &["name"]))
+                    .to_string()
+            })
+            .unwrap_or_default(),
+        ..RetroRecord::default()
+    };
+    if r.number.is_empty() {
+        r.number = str_at(v, &["id"]).to_string();
+    }
+    for name in ["started_at", "detected_at", "acknowledged_at", "mitigated_at", "resolved_at"] {
+        r.set(name, str_at(attrs, &[name]));
+    }
+    r
+}
+
+fn detect_tool(items: &[Value]) -> RetroTool {
+    if items.iter().any(|i| i.get("attributes").is_some()) {
+        RetroTool::Rootly
+    } else {
+        RetroTool::FireHydrant
+    }
+}
+
+/// Accepts `{"data": [..]}` (both tools' API list shape), `{"incidents": [..]}`, or a bare array.
+fn read_records(json_text: &str, tool: Option<RetroTool>) -> Result<(RetroTool, Vec<RetroRecord>), AppError> {
+    let root: Value =
+        serde_json::from_str(json_text).map_err(|e| parse_failed("Failed to parse retro tool export", e.to_string()))?;
+    let items = match &root {
+        Value::Array(items) => items,
+        Value::Object(obj) => obj
+            .get("data")
+            .or_else(|| obj.get("incidents"))
+            .and_then(Value::as_array)
+            .ok_or_else(|| parse_failed("Retro tool export has no incident list", "keys=data,incidents".to_string()))?,
+        _ => return Err(parse_failed("Retro tool export must be an object or array", String::new())),
+    };
+    let tool = tool.unwrap_or_else(|| detect_tool(items));
+    let records = items
+        .iter()
+        .map(|v| match tool {
+            RetroTool::FireHydrant => firehydrant_record(v),
+            RetroTool::Rootly => rootly_record(v),
+        })
+        .collect();
+    Ok((tool, records))
+}
+
+pub fn preview_retro_tool_export(json_text: &str, tool: Option<RetroTool>) -> Result<RetroToolPreview, AppError> {
+    let (tool, records) = read_records(json_text, tool)?;
+    let milestones: BTreeSet<String> = records.iter().flat_map(|r| r.milestone_names.iter().cloned()).collect();
+    Ok(RetroToolPreview {
+        tool,
+        incident_count: records.len(),
+        milestones: milestones.into_iter().collect(),
+    })
+}
+
+impl RetroRecord {
+    fn source_row(&self, tool: RetroTool) -> SourceRow {
+        let external_id = if self.number.is_empty() {
+            String::new()
+        } else {
+            format!("{}{}", tool.external_id_prefix(), self.number)
+        };
+        SourceRow {
+            external_id,
+            title: self.title.clone(),
+            description: self.description.clone(),
+            severity: self.severity.clone(),
+            service: self.service.clone(),
+            start_ts: self.started.clone(),
+            first_observed_ts: self.detected.clone(),
+            ack_ts: self.acknowledged.clone(),
+            mitigate_ts: self.mitigated.clone(),
+            resolve_ts: self.resolved.clone(),
+        }
+    }
+}
+
+pub fn import_retro_tool_export(
+    conn: &mut Connection,
+    json_text: &str,
+    tool: Option<RetroTool>,
+) -> Result<JiraImportSummary, AppError> {
+    import_retro_tool_export_with_progress(conn, json_text, tool, &mut Progress::noop("import_retro_tool"))
+}
+
+/// Imports a FireHydrant or Rootly incident export (detected from its shape when `tool` is
+/// None) through the shared CSV import path, recording the batch under the tool's name.
+/// Milestones map onto start/first observed/ack/mitigate/resolve; other milestones are
+/// reported once as a warning.
+pub fn import_retro_tool_export_with_progress(
+    conn: &mut Connection,
+    json_text: &str,
+    tool: Option<RetroTool>,
+    progress: &mut Progress<'_>,
+) -> Result<JiraImportSummary, AppError> {
+    let (tool, records) = read_records(json_text, tool)?;
+    let rows: Vec<_> = records.iter().map(|r| Some(r.source_row(tool))).collect();
+    let mut summary = import_source_rows_with_progress(conn, &rows, tool.source_label(), progress)?;
+
+    let unmapped: BTreeSet<&str> = records
+        .iter()
+        .flat_map(|r| r.milestone_names.iter().map(String::as_str))
+        .filter(|m| milestone_for(m).is_none())
+        .collect();
+    let mut warnings: Vec<ValidationWarning> = unmapped
+        .into_iter()
+        .map(|m| {
+            ValidationWarning::new(
+                "INGEST_RETRO_TOOL_MILESTONE_UNMAPPED",
+                "Milestone has no canonical timestamp column; not imported",
+            )
+            .with_details(format!("milestone={m}"))
+        })
+        .collect();
+    warnings.append(&mut summary.warnings);
+    summary.warnings = warnings;
+    Ok(summary)
+}
@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+use crate::progress::Progress;
+
+use super::csv_dialect::csv_reader;
+use super::generic_csv::{import_source_rows_with_progress, SourceRow};
+use super::jira_csv::{JiraCsvPreview, JiraImportSummary};
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+/// Prefix for external ids built from ServiceNow `sys_id`s, so they never collide with Jira keys
+/// or PagerDuty numbers in the same workspace.
+pub const SERVICENOW_EXTERNAL_ID_PREFIX: &str = "SN-";
+
+// ServiceNow incident state codes (and display names) that mean the incident is over.
+const RESOLVED_STATES: [&str; 4] = ["6", "7", "resolved", "closed"];
+
+/// Column headers of a ServiceNow incident list CSV export. Defaults match the stock export.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ServiceNowCsvMapping {
+    /// Stable record id; imported as external id `SN-<sys_id>`. Preferred over `number`.
+    pub sys_id: Option<String>,
+    /// Incident number (e.g. INC0012345); the external id for rows without a `sys_id`.
+    pub number: Option<String>,
+    /// ServiceNow's "short_description" column is the incident title. Required.
+    pub title: String,
+    pub description: Option<String>,
+    /// Priority column (e.g. "1 - Critical"). When empty, priority is derived from impact and urgency.
+    pub priority: Option<String>,
+    pub impact: Option<String>,
+    pub urgency: Option<String>,
+    /// Severity recorded for each priority code 1-5; unlisted codes are kept as-is.
+    #[serde(default = "default_priority_severity")]
+    pub priority_severity: BTreeMap<String, String>,
+    pub service: Option<String>,
+    /// Incident state column. When mapped, resolve/close times are only imported for resolved or
+    /// closed incidents.
+    pub state: Option<String>,
+    pub opened_ts: Option<String>,
+    pub ack_ts: Option<String>,
+    pub resolved_ts: Option<String>,
+    /// Used as the resolve time when `resolved_ts` is empty on a closed incident.
+    pub closed_ts: Option<String>,
+}
+
+fn default_priority_severity() -> BTreeMap<String, String> {
+    (1..=5).map(|p| (p.to_string(), format!("SEV{p}"))).collect()
+}
+
+impl Default for ServiceNowCsvMapping {
+    fn default() -> Self {
+        Self {
+            sys_id: Some("sys_id".to_string()),
+            number: Some("number".to_string()),
+            title: "short_description".to_string(),
+            description: Some("description".to_string()),
+            priority: Some("priority".to_string()),
+            impact: Some("impact".to_string()),
+            urgency: Some("urgency".to_string()),
+            priority_severity: default_priority_severity(),
+            service: Some("business_service".to_string()),
+            state: Some("state".to_string()),
+            opened_ts: Some("opened_at".to_string()),
+            ack_ts: None,
+            resolved_ts: Some("resolved_at".to_string()),
+            closed_ts: Some("closed_at".to_string()),
+        }
+    }
+}
+
+fn get<'a>(row: &'a csv::StringRecord, headers: &csv::StringRecord, header_name: Option<&str>) -> &'a str {
+    header_name
+        .and_then(|name| headers.iter().position(|h| h == name))
+        .and_then(|idx| row.get(idx))
+        .map(str::trim)
+        .unwrap_or("")
+}
+
+/// Leading numeric code of a ServiceNow choice value: "2 - High" and "2" both yield 2.
+fn choice_code(value: &str) -> Option<u32> {
+    let digits: String = value.trim().chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+/// ServiceNow's default priority lookup: impact 1 + urgency 1 is P1, each step down adds one.
+fn priority_from_matrix(impact: &str, urgency: &str) -> Option<u32> {
+    let (impact, urgency) = (choice_code(impact)?, choice_code(urgency)?);
+    Some((impact + urgency).saturating_sub(1).clamp(1, 5))
+}
+
+fn severity_for(mapping: &ServiceNowCsvMapping, priority: &str, impact: &str, urgency: &str) -> String {
+    let code = if priority.is_empty() {
+        priority_from_matrix(impact, urgency)
+    } else {
+        choice_code(priority)
+    };
+    match code {
+        Some(code) => mapping
+            .priority_severity
+            .get(&code.to_string())
+            .cloned()
+            .unwrap_or_else(|| code.to_string()),
+        None => priority.to_string(),
+    }
+}
+
+fn is_resolved_state(state: &str) -> bool {
+    let state = state.trim();
+    let code = choice_code(state).map(|c| c.to_string());
+    RESOLVED_STATES
+        .iter()
+        .any(|s| state.eq_ignore_ascii_case(s) || code.as_deref() == Some(*s))
+}
+
+/// Reads the export into canonical rows, one per input row (`None` for unreadable ones) so row
+/// numbers in warnings and conflicts match the original file.
+fn read_rows(
+    csv_text: &str,
+    mapping: &ServiceNowCsvMapping,
+    warnings: &mut Vec<ValidationWarning>,
+) -> Result<Vec<Option<SourceRow>>, AppError> {
+    let (mut rdr, _) = csv_reader(csv_text, None);
+    let headers = rdr
+        .headers()
+        .map_err(|e| {
+            AppError::new(
+                "INGEST_SERVICENOW_CSV_HEADERS_FAILED",
+                "Failed to read ServiceNow CSV headers",
+            )
+            .with_details(e.to_string())
+        })?
+        .clone();
+
+    let mut rows = Vec::new();
+    for (row_idx, result) in rdr.records().enumerate() {
+        let row = match result {
+            Ok(r) => r,
+            Err(e) => {
+                warnings.push(
+                    ValidationWarning::new(
+                        "INGEST_SERVICENOW_CSV_PARSE_FAILED",
+                        "Failed to parse ServiceNow CSV row",
+                    )
+                    .with_details(format!("row={row_idx}; err={e}")),
+                );
+                rows.push(None);
+                continue;
+            }
+        };
+
+        let sys_id = get(&row, &headers, mapping.sys_id.as_deref());
+        let external_id = if sys_id.is_empty() {
+            get(&row, &headers, mapping.number.as_deref()).to_string()
+        } else {
+            format!("{SERVICENOW_EXTERNAL_ID_PREFIX}{sys_id}")
+        };
+        let severity = severity_for(
+            mapping,
+            get(&row, &headers, mapping.priority.as_deref()),
+            get(&row, &headers, mapping.impact.as_deref()),
+            get(&row, &headers, mapping.urgency.as_deref()),
+        );
+
+        // An open (or reopened) incident leaves resolve_ts empty, which preserves any stored value.
+        let resolved =
+            mapping.state.is_none() || is_resolved_state(get(&row, &headers, mapping.state.as_deref()));
+        let resolve_ts = if resolved {
+            let resolved_at = get(&row, &headers, mapping.resolved_ts.as_deref());
+            if resolved_at.is_empty() {
+                get(&row, &headers, mapping.closed_ts.as_deref())
+            } else {
+                resolved_at
+            }
+        } else {
+            ""
+        };
+
+        let col = |header: &Option<String>| get(&row, &headers, header.as_deref()).to_string();
+        rows.push(Some(SourceRow {
+            external_id,
+            title: get(&row, &headers, Some(mapping.title.as_str())).to_string(),
+            description: col(&mapping.description),
+            severity,
+            service: col(&mapping.service),
+            start_ts: col(&mapping.opened_ts),
+            ack_ts: col(&mapping.ack_ts),
+            resolve_ts: resolve_ts.to_string(),
+            ..SourceRow::default()
+        }));
+    }
+    Ok(rows)
+}
+
+pub fn preview_servicenow_csv(csv_text: &str, max_rows: usize) -> Result<JiraCsvPreview, AppError> {
+    let (mut rdr, delimiter) = csv_reader(csv_text, None);
+
+    let headers = rdr
+        .headers()
+        .map_err(|e| {
+            AppError::new(
+                "INGEST_SERVICENOW_CSV_HEADERS_FAILED",
+                "Failed to read ServiceNow CSV headers",
+            )
+            .with_details(e.to_string())
+        })?
+        .iter()
+        .map(|h| h.to_string())
+        .collect::<Vec<_>>();
+
+    let mut rows = Vec::new();
+    for result in rdr.records().take(max_rows) {
+        let row = result.map_err(|e| {
+            AppError::new(
+                "INGEST_SERVICENOW_CSV_PARSE_FAILED",
+                "Failed to parse ServiceNow CSV row",
+            )
+            .with_details(e.to_string())
+        })?;
+        rows.push(row.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+    }
+
+    Ok(JiraCsvPreview {
+        headers,
+        rows,
+        delimiter,
+    })
+}
+
+pub fn import_servicenow_csv(
+    conn: &mut Connection,
+    csv_text: &str,
+    mapping: &ServiceNowCsvMapping,
+) -> Result<JiraImportSummary, AppError> {
+    import_servicenow_csv_with_progress(conn, csv_text, mapping, &mut Progress::noop("import_servicenow_csv"))
+}
+
+/// Imports a ServiceNow export through the shared CSV import path, so dedupe, merge,
+/// frozen-quarter, conflict and preserve-on-empty handling are identical for every source.
+pub fn import_servicenow_csv_with_progress(
+    conn: &mut Connection,
+    csv_text: &str,
+    mapping: &ServiceNowCsvMapping,
+    progress: &mut Progress<'_>,
+) -> Result<JiraImportSummary, AppError> {
+    let mut warnings = Vec::new();
+    let rows = read_rows(csv_text, mapping, &mut warnings)?;
+    let mut summary = import_source_rows_with_progress(conn, &rows, "servicenow_csv", progress)?;
+    warnings.append(&mut summary.warnings);
+    summary.warnings = warnings;
+    Ok(summary)
+}
@@ -0,0 +1,348 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+use crate::normalize::markup::normalize_slack_text;
+
+use super::slack_transcript::{ensure_target_incident, parse_slack_ts_seconds_to_rfc3339, SlackIngestSummary};
+
+pub const SLACK_WORKSPACE_EXPORT_FORMAT: &str = "slack_workspace_export";
+
+// Membership noise, not incident conversation.
+const SKIPPED_SUBTYPES: [&str; 4] = ["channel_join", "channel_leave", "group_join", "group_leave"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SlackExportChannelSummary {
+    pub name: String,
+    pub day_files: usize,
+    pub message_count: usize,
+    pub first_ts: Option<String>,
+    pub last_ts: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SlackExportPreview {
+    pub user_count: usize,
+    pub channels: Vec<SlackExportChannelSummary>,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+/// Channel -> day file name -> raw JSON, both sorted.
+type ChannelFiles = BTreeMap<String, BTreeMap<String, Vec<u8>>>;
+
+/// An official Slack export: `users.json` plus one directory per channel holding one
+/// `YYYY-MM-DD.json` message array per day.
+struct SlackExport {
+    /// User id -> display name.
+    users: BTreeMap<String, String>,
+    channels: ChannelFiles,
+}
+
+fn read_failed(path: &Path, e: impl ToString) -> AppError {
+    AppError::new("INGEST_SLACK_EXPORT_READ_FAILED", "Failed to read Slack export")
+        .with_details(format!("path={}; err={}", path.display(), e.to_string()))
+}
+
+fn invalid(message: &str, details: String) -> AppError {
+    AppError::new("INGEST_SLACK_EXPORT_INVALID", message).with_details(details)
+}
+
+/// Collects `users.json` and `<channel>/<day>.json` from an extracted export directory or the
+/// export `.zip` itself.
+fn read_export_files(path: &Path) -> Result<(Option<Vec<u8>>, ChannelFiles), AppError> {
+    let mut users = None;
+    let mut channels: ChannelFiles = BTreeMap::new();
+    if path.is_dir() {
+        let users_path = path.join("users.json");
+        if users_path.is_file() {
+            users = Some(fs::read(&users_path).map_err(|e| read_failed(&users_path, e))?);
+        }
+        for entry in fs::read_dir(path).map_err(|e| read_failed(path, e))? {
+            let dir = entry.map_err(|e| read_failed(path, e))?.path();
+            if !dir.is_dir() {
+                continue;
+            }
+            let channel = dir.file_name().unwrap_or_default().to_string_lossy().to_string();
+            for day in fs::read_dir(&dir).map_err(|e| read_failed(&dir, e))? {
+                let file = day.map_err(|e| read_failed(&dir, e))?.path();
+                if file.extension().and_then(|e| e.to_str()) != Some("json") {
+                    continue;
+                }
+                let name = file.file_name().unwrap_or_default().to_string_lossy().to_string();
+                let bytes = fs::read(&file).map_err(|e| read_failed(&file, e))?;
+                channels.entry(channel.clone()).or_default().insert(name, bytes);
+            }
+        }
+    } else {
+        let file = fs::File::open(path).map_err(|e| read_failed(path, e))?;
+        let mut archive = zip::ZipArchive::new(file).map_err(|e| read_failed(path, e))?;
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i).map_err(|e| read_failed(path, e))?;
+            if entry.is_dir() {
+                continue;
+            }
+            let name = entry.name().to_string();
+            let parts: Vec<&str> = name.split('/').filter(|p| !p.is_empty()).collect();
+            let wanted = match parts.as_slice() {
+                ["users.json"] => true,
+                [_, day] => day.ends_with(".json"),
+                _ => false,
+            };
+            if !wanted {
+                continue;
+            }
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes).map_err(|e| read_failed(path, e))?;
+            match parts.as_slice() {
+                ["users.json"] => users = Some(bytes),
+                [channel, day] => {
+                    channels.entry(channel.to_string()).or_default().insert(day.to_string(), bytes);
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok((users, channels))
+}
+
+fn user_display_name(user: &Map<String, Value>) -> Option<String> {
+    fn non_empty(v: Option<&Value>) -> Option<&str> {
+        v.and_then(Value::as_str).map(str::trim).filter(|s| !s.is_empty())
+    }
+    let profile = user.get("profile").and_then(Value::as_object);
+    non_empty(profile.and_then(|p| p.get("display_name")))
+        .or_else(|| non_empty(profile.and_then(|p| p.get("real_name"))))
+        .or_else(|| non_empty(user.get("real_name")))
+        .or_else(|| non_empty(user.get("name")))
+        .map(str::to_string)
+}
+
+fn load_export(path: &Path) -> Result<SlackExport, AppError> {
+    let (users_json, channels) = read_export_files(path)?;
+    if channels.is_empty() {
+        return Err(invalid(
+            "No channel directories found in Slack export",
+            format!("path={}", path.display()),
+        ));
+    }
+    let mut users = BTreeMap::new();
+    if let Some(bytes) = users_json {
+        let list: Vec<Map<String, Value>> = serde_json::from_slice(&bytes)
+            .map_err(|e| invalid("users.json is not a Slack user list", e.to_string()))?;
+        for user in &list {
+            if let (Some(id), Some(name)) = (user.get("id").and_then(Value::as_str), user_display_name(user)) {
+                users.insert(id.to_string(), name);
+            }
+        }
+    }
+    Ok(SlackExport { users, channels })
+}
+
+fn day_messages(channel: &str, day: &str, bytes: &[u8]) -> Result<Vec<Map<String, Value>>, AppError> {
+    let items: Vec<Value> = serde_json::from_slice(bytes)
+        .map_err(|e| invalid("Slack export day file is not a message array", format!("file={channel}/{day}; err={e}")))?;
+    Ok(items
+        .into_iter()
+        .filter_map(|v| match v {
+            Value::Object(o) => Some(o),
+            _ => None,
+        })
+        .collect())
+}
+
+fn is_conversation(msg: &Map<String, Value>) -> bool {
+    let subtype = msg.get("subtype").and_then(Value::as_str).unwrap_or("");
+    let text = msg.get("text").and_then(Value::as_str).unwrap_or("");
+    !SKIPPED_SUBTYPES.contains(&subtype) && !text.trim().is_empty()
+}
+
+pub fn preview_slack_export(path: &Path) -> Result<SlackExportPreview, AppError> {
+    let export = load_export(path)?;
+    let mut warnings = Vec::new();
+    if export.users.is_empty() {
+        warnings.push(ValidationWarning::new(
+            "INGEST_SLACK_EXPORT_USERS_MISSING",
+            "users.json missing or empty; authors will show as Slack user ids",
+        ));
+    }
+    let mut channels = Vec::new();
+    for (name, days) in &export.channels {
+        let mut summary = SlackExportChannelSummary {
+            name: name.clone(),
+            day_files: days.len(),
+            message_count: 0,
+            first_ts: None,
+            last_ts: None,
+        };
+        for (day, bytes) in days {
+            for msg in day_messages(name, day, bytes)?.iter().filter(|m| is_conversation(m)) {
+                summary.message_count += 1;
+                let ts = msg
+                    .get("ts")
+                    .and_then(Value::as_str)
+                    .and_then(parse_slack_ts_seconds_to_rfc3339);
+                if let Some(ts) = ts {
+                    if summary.first_ts.as_ref().is_none_or(|f| &ts < f) {
+                        summary.first_ts = Some(ts.clone());
+                    }
+                    if summary.last_ts.as_ref().is_none_or(|l| &ts > l) {
+                        summary.last_ts = Some(ts);
+                    }
+                }
+            }
+        }
+        channels.push(summary);
+    }
+    Ok(SlackExportPreview {
+        user_count: export.users.len(),
+        channels,
+        warnings,
+    })
+}
+
+/// Rewrites `<@U123>` mentions as `<@U123|Name>` so markup normalization renders `@Name`.
+fn label_mentions(text: &str, users: &BTreeMap<String, String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("<@") {
+        let Some(len) = rest[start..].find('>') else {
+            break;
+        };
+        let id = &rest[start + 2..start + len];
+        out.push_str(&rest[..start]);
+        match users.get(id) {
+            Some(name) if !id.contains('|') => out.push_str(&format!("<@{id}|{name}>")),
+            _ => out.push_str(&rest[start..=start + len]),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Imports messages of `channels` (every channel when empty) as `slack` timeline events on one
+/// incident. Thread parents and replies keep `thread_ts`/`parent_user_id` in `raw_json`;
+/// re-importing the same export skips messages already stored.
+pub fn ingest_slack_export(
+    conn: &mut Connection,
+    path: &Path,
+    channels: &[String],
+    incident_id: Option<i64>,
+    new_incident_title: Option<&str>,
+) -> Result<SlackIngestSummary, AppError> {
+    let export = load_export(path)?;
+    for wanted in channels {
+        if !export.channels.contains_key(wanted) {
+            return Err(AppError::new("INGEST_SLACK_EXPORT_CHANNEL_NOT_FOUND", "Channel not found in Slack export")
+                .with_details(format!("channel={wanted}; channels={:?}", export.channels.keys().collect::<Vec<_>>())));
+        }
+    }
+    let selected: Vec<(&String, &BTreeMap<String, Vec<u8>>)> = export
+        .channels
+        .iter()
+        .filter(|(name, _)| channels.is_empty() || channels.contains(name))
+        .collect();
+
+    // The shell fingerprint covers the selected channels' content, like the transcript hash.
+    let mut hasher = Sha256::new();
+    for (name, days) in &selected {
+        hasher.update(name.as_bytes());
+        for (day, bytes) in days.iter() {
+            hasher.update(day.as_bytes());
+            hasher.update(bytes);
+        }
+    }
+    let content_key = hex::encode(hasher.finalize());
+    let (target_incident_id, incident_created) =
+        ensure_target_incident(conn, incident_id, new_incident_title, &content_key)?;
+
+    let mut warnings = Vec::new();
+    let mut inserted_events = 0usize;
+    for (channel, days) in selected {
+        for (day, bytes) in days {
+            for msg in day_messages(channel, day, bytes)?.iter().filter(|m| is_conversation(m)) {
+                let ts_raw = msg.get("ts").and_then(Value::as_str).unwrap_or("");
+                let ts = parse_slack_ts_seconds_to_rfc3339(ts_raw);
+                if ts.is_none() {
+                    warnings.push(
+                        ValidationWarning::new("INGEST_SLACK_TS_UNKNOWN", "Slack export message missing/invalid timestamp")
+                            .with_details(format!("file={channel}/{day}")),
+                    );
+                }
+                let user_id = msg.get("user").and_then(Value::as_str);
+                let author = user_id
+                    .and_then(|id| export.users.get(id).cloned())
+                    .or_else(|| {
+                        msg.get("user_profile")
+                            .and_then(Value::as_object)
+                            .and_then(user_display_name)
+                    })
+                    .or_else(|| user_id.map(str::to_string));
+                let text = msg.get("text").and_then(Value::as_str).unwrap_or("").trim();
+                let text = normalize_slack_text(&label_mentions(text, &export.users));
+
+                let thread_ts = msg.get("thread_ts").and_then(Value::as_str);
+                let is_reply = thread_ts.is_some_and(|t| t != ts_raw);
+                let kind = if is_reply { "thread_reply" } else { "message" };
+                let raw_json = serde_json::json!({
+                    "channel": channel,
+                    "ts": ts_raw,
+                    "thread_ts": thread_ts,
+                    "parent_user_id": msg.get("parent_user_id"),
+                    "reply_count": msg.get("reply_count"),
+                    "is_thread_reply": is_reply,
+                    "message": msg,
+                })
+                .to_string();
+
+                let exists: Option<i64> = conn
+                    .query_row(
+                        "SELECT id FROM timeline_events
+                         WHERE incident_id = ?1 AND source = 'slack' AND ts IS ?2 AND author IS ?3 AND text = ?4",
+                        rusqlite::params![target_incident_id, ts, author, text.text],
+                        |row| row.get(0),
+                    )
+                    .optional()
+                    .map_err(|e| {
+                        AppError::new("DB_QUERY_FAILED", "Failed to check existing Slack events")
+                            .with_details(format!("file={channel}/{day}; err={e}"))
+                    })?;
+                if exists.is_some() {
+                    continue;
+                }
+                conn.execute(
+                    r#"
+              INSERT INTO timeline_events(
+                incident_id, source, ts, author, kind, text, text_raw, raw_json, created_at
+              ) VALUES (
+                ?1, 'slack', ?2, ?3, ?4, ?5, ?6, ?7,
+                strftime('%Y-%m-%dT%H:%M:%fZ','now')
+              )
+              "#,
+                    rusqlite::params![target_incident_id, ts, author, kind, text.text, text.raw, raw_json],
+                )
+                .map_err(|e| {
+                    AppError::new("INGEST_SLACK_INSERT_FAILED", "Failed to insert Slack timeline event")
+                        .with_details(format!("file={channel}/{day}; ts={ts_raw}; err={e}"))
+                })?;
+                inserted_events += 1;
+            }
+        }
+    }
+
+    Ok(SlackIngestSummary {
+        incident_id: target_incident_id,
+        incident_created,
+        detected_format: SLACK_WORKSPACE_EXPORT_FORMAT.to_string(),
+        inserted_events,
+        warnings,
+    })
+}
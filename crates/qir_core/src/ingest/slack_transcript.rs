@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use rusqlite::{Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
@@ -6,6 +8,7 @@ use time::{Duration, OffsetDateTime, UtcOffset};
 
 use crate::domain::ValidationWarning;
 use crate::error::AppError;
+use crate::normalize::markup::normalize_slack_text;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SlackIngestSummary {
@@ -68,7 +71,7 @@ fn split_line_rfc3339ish(line: &str) -> (Option<String>, Option<String>, String)
     }
 }
 
-fn parse_slack_ts_seconds_to_rfc3339(ts: &str) -> Option<String> {
+pub(super) fn parse_slack_ts_seconds_to_rfc3339(ts: &str) -> Option<String> {
     // Slack JSON export typically uses a string seconds-with-fraction, e.g. "1700000000.000100".
     // We parse deterministically without floats.
     let trimmed = ts.trim();
@@ -90,6 +93,34 @@ fn parse_slack_ts_seconds_to_rfc3339(ts: &str) -> Option<String> {
     canonicalize_rfc3339_utc(dt)
 }
 
+/// Event kinds reactions tag a message with, strongest first when several apply.
+const REACTION_KINDS: &[(&str, &[&str])] = &[
+    ("mitigated", &["white_check_mark", "heavy_check_mark", "ballot_box_with_check"]),
+    ("escalated", &["rotating_light", "sos"]),
+    ("acknowledged", &["eyes"]),
+];
+
+/// Reaction names in export order, without skin-tone modifiers ("+1::skin-tone-2" -> "+1").
+fn reaction_names(obj: &serde_json::Map<String, serde_json::Value>) -> Vec<String> {
+    obj.get("reactions")
+        .and_then(|r| r.as_array())
+        .map(|reactions| {
+            reactions
+                .iter()
+                .filter_map(|r| r.get("name").and_then(|n| n.as_str()))
+                .map(|n| n.split("::").next().unwrap_or(n).to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn reaction_kind(names: &[String]) -> Option<&'static str> {
+    REACTION_KINDS
+        .iter()
+        .find(|(_, emoji)| names.iter().any(|n| emoji.contains(&n.as_str())))
+        .map(|(kind, _)| *kind)
+}
+
 fn detect_format(transcript: &str) -> String {
     let s = transcript.trim_start();
     if s.starts_with('[') || s.starts_with('{') {
@@ -117,7 +148,7 @@ fn detect_format(transcript: &str) -> String {
     "raw_lines".to_string()
 }
 
-fn ensure_target_incident(
+pub(super) fn ensure_target_incident(
     conn: &mut Connection,
     incident_id: Option<i64>,
     new_incident_title: Option<&str>,
@@ -145,6 +176,7 @@ fn ensure_target_incident(
                 )
                 .with_details(format!("incident_id={id}")));
             }
+            crate::freeze::ensure_incident_mutable(conn, id)?;
             Ok((id, false))
         }
         (None, Some(title)) => {
@@ -230,6 +262,10 @@ pub fn preview_slack_transcript_text(transcript: &str) -> SlackPreview {
     }
 }
 
+/// Attaches a pasted Slack transcript to an incident. For JSON exports, thread replies are
+/// stored as `thread_reply` events whose `raw_json` references the parent (`thread_ts` and,
+/// when the parent is in the same transcript, `thread_parent_event_id`), and reactions listed
+/// in `REACTION_KINDS` (e.g. a check mark for mitigation) set the event `kind`.
 pub fn ingest_slack_transcript_text(
     conn: &mut Connection,
     incident_id: Option<i64>,
@@ -259,6 +295,9 @@ pub fn ingest_slack_transcript_text(
                 )
             })?;
 
+            // Parent ts -> event id, and replies to point at their parent once all are stored.
+            let mut event_ids: HashMap<String, i64> = HashMap::new();
+            let mut replies: Vec<(usize, i64, String)> = Vec::new();
             for (idx, item) in arr.iter().enumerate() {
                 let obj = match item.as_object() {
                     Some(o) => o,
@@ -304,18 +343,36 @@ pub fn ingest_slack_transcript_text(
                     );
                 }
 
-                let raw_json = serde_json::to_string(item).ok();
+                let ts_raw = obj.get("ts").and_then(|t| t.as_str()).unwrap_or("");
+                let thread_ts = obj.get("thread_ts").and_then(|t| t.as_str());
+                let is_reply = thread_ts.is_some_and(|t| t != ts_raw);
+                let reactions = reaction_names(obj);
+                let reaction_kind = reaction_kind(&reactions);
+                let kind = reaction_kind.unwrap_or(if is_reply { "thread_reply" } else { "message" });
+                let raw_json = serde_json::json!({
+                    "ts": ts_raw,
+                    "thread_ts": thread_ts,
+                    "parent_user_id": obj.get("parent_user_id"),
+                    "reply_count": obj.get("reply_count"),
+                    "is_thread_reply": is_reply,
+                    "thread_parent_event_id": null,
+                    "reactions": reactions,
+                    "reaction_kind": reaction_kind,
+                    "message": item,
+                })
+                .to_string();
+                let text = normalize_slack_text(text);
 
                 conn.execute(
                     r#"
               INSERT INTO timeline_events(
-                incident_id, source, ts, author, kind, text, raw_json, created_at
+                incident_id, source, ts, author, kind, text, text_raw, raw_json, created_at
               ) VALUES (
-                ?1, 'slack', ?2, ?3, 'message', ?4, ?5,
+                ?1, 'slack', ?2, ?3, ?4, ?5, ?6, ?7,
                 strftime('%Y-%m-%dT%H:%M:%fZ','now')
               )
               "#,
-                    rusqlite::params![target_incident_id, ts, author, text, raw_json],
+                    rusqlite::params![target_incident_id, ts, author, kind, text.text, text.raw, raw_json],
                 )
                 .map_err(|e| {
                     AppError::new(
@@ -325,6 +382,38 @@ pub fn ingest_slack_transcript_text(
                     .with_details(format!("index={idx}; err={e}"))
                 })?;
                 inserted_events += 1;
+                let event_id = conn.last_insert_rowid();
+                match thread_ts {
+                    Some(parent_ts) if is_reply => replies.push((idx, event_id, parent_ts.to_string())),
+                    _ => {
+                        event_ids.entry(ts_raw.to_string()).or_insert(event_id);
+                    }
+                }
+            }
+
+            // Replies may precede their parent in hand-assembled transcripts, so link afterwards.
+            for (idx, event_id, parent_ts) in replies {
+                let Some(parent_id) = event_ids.get(&parent_ts) else {
+                    warnings.push(
+                        ValidationWarning::new(
+                            "INGEST_SLACK_THREAD_PARENT_MISSING",
+                            "Slack thread reply's parent message is not in the transcript",
+                        )
+                        .with_details(format!("index={idx}; thread_ts={parent_ts}")),
+                    );
+                    continue;
+                };
+                conn.execute(
+                    "UPDATE timeline_events SET raw_json = json_set(raw_json, '$.thread_parent_event_id', ?1) WHERE id = ?2",
+                    rusqlite::params![parent_id, event_id],
+                )
+                .map_err(|e| {
+                    AppError::new(
+                        "INGEST_SLACK_INSERT_FAILED",
+                        "Failed to link Slack thread reply to its parent",
+                    )
+                    .with_details(format!("index={idx}; err={e}"))
+                })?;
             }
         }
         "line_rfc3339" => {
@@ -342,17 +431,18 @@ pub fn ingest_slack_transcript_text(
                         .with_details(format!("line={idx}")),
                     );
                 }
+                let text = normalize_slack_text(&text);
 
                 conn.execute(
                     r#"
               INSERT INTO timeline_events(
-                incident_id, source, ts, author, kind, text, raw_json, created_at
+                incident_id, source, ts, author, kind, text, text_raw, raw_json, created_at
               ) VALUES (
-                ?1, 'slack', ?2, ?3, 'message', ?4, NULL,
+                ?1, 'slack', ?2, ?3, 'message', ?4, ?5, NULL,
                 strftime('%Y-%m-%dT%H:%M:%fZ','now')
               )
               "#,
-                    rusqlite::params![target_incident_id, ts, author, text],
+                    rusqlite::params![target_incident_id, ts, author, text.text, text.raw],
                 )
                 .map_err(|e| {
                     AppError::new(
@@ -391,17 +481,18 @@ pub fn ingest_slack_transcript_text(
                 } else {
                     (None, t.to_string())
                 };
+                let text = normalize_slack_text(&text);
 
                 conn.execute(
                     r#"
               INSERT INTO timeline_events(
-                incident_id, source, ts, author, kind, text, raw_json, created_at
+                incident_id, source, ts, author, kind, text, text_raw, raw_json, created_at
               ) VALUES (
-                ?1, 'slack', NULL, ?2, 'message', ?3, NULL,
+                ?1, 'slack', NULL, ?2, 'message', ?3, ?4, NULL,
                 strftime('%Y-%m-%dT%H:%M:%fZ','now')
               )
               "#,
-                    rusqlite::params![target_incident_id, author, text],
+                    rusqlite::params![target_incident_id, author, text.text, text.raw],
                 )
                 .map_err(|e| {
                     AppError::new(
@@ -0,0 +1,229 @@
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+
+use super::slack_transcript::ensure_target_incident;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StatuspageUpdate {
+    #[serde(default)]
+    pub id: Option<String>,
+    /// investigating, identified, monitoring, resolved, ...
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// When the update was shown publicly; Statuspage allows backdating it.
+    #[serde(default)]
+    pub display_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StatuspageIncident {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub impact: Option<String>,
+    #[serde(default)]
+    pub shortlink: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub monitoring_at: Option<String>,
+    #[serde(default)]
+    pub resolved_at: Option<String>,
+    #[serde(default)]
+    pub incident_updates: Vec<StatuspageUpdate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StatuspageIncidentSummary {
+    pub id: String,
+    pub name: Option<String>,
+    pub impact: Option<String>,
+    pub created_at: Option<String>,
+    pub resolved_at: Option<String>,
+    pub update_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StatuspageIngestSummary {
+    pub incident_id: i64,
+    pub incident_created: bool,
+    pub inserted_events: usize,
+    pub skipped_duplicates: usize,
+    /// Canonical incident fields filled from Statuspage because they were empty.
+    pub backfilled_fields: Vec<String>,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StatuspageExport {
+    Wrapped { incidents: Vec<StatuspageIncident> },
+    Bare(Vec<StatuspageIncident>),
+}
+
+/// Parses an incident history export: the public `incidents.json` (`{"page":..,"incidents":[..]}`)
+/// or the management API's bare incident array.
+pub fn parse_statuspage_history(json_text: &str) -> Result<Vec<StatuspageIncident>, AppError> {
+    let export: StatuspageExport = serde_json::from_str(json_text).map_err(|e| {
+        AppError::new("INGEST_STATUSPAGE_PARSE_FAILED", "Failed to parse Statuspage incident history")
+            .with_details(e.to_string())
+    })?;
+    Ok(match export {
+        StatuspageExport::Wrapped { incidents } | StatuspageExport::Bare(incidents) => incidents,
+    })
+}
+
+fn canonical_ts(ts: Option<&str>) -> Option<String> {
+    let dt = OffsetDateTime::parse(ts?.trim(), &Rfc3339).ok()?;
+    dt.to_offset(UtcOffset::UTC).format(&Rfc3339).ok()
+}
+
+pub fn preview_statuspage_history(json_text: &str) -> Result<Vec<StatuspageIncidentSummary>, AppError> {
+    Ok(parse_statuspage_history(json_text)?
+        .into_iter()
+        .map(|i| StatuspageIncidentSummary {
+            created_at: canonical_ts(i.created_at.as_deref()),
+            resolved_at: canonical_ts(i.resolved_at.as_deref()),
+            update_count: i.incident_updates.len(),
+            id: i.id,
+            name: i.name,
+            impact: i.impact,
+        })
+        .collect())
+}
+
+/// Sets `column` to `value` only when it is currently NULL; returns whether it changed.
+fn backfill(conn: &Connection, incident_id: i64, column: &str, value: &str) -> Result<bool, AppError> {
+    let changed = conn
+        .execute(
+            &format!("UPDATE incidents SET {column} = ?1 WHERE id = ?2 AND {column} IS NULL"),
+            rusqlite::params![value, incident_id],
+        )
+        .map_err(|e| {
+            AppError::new("INGEST_STATUSPAGE_INSERT_FAILED", "Failed to backfill incident timestamp")
+                .with_details(format!("incident_id={incident_id}; field={column}; err={e}"))
+        })?;
+    Ok(changed > 0)
+}
+
+/// Adds the public updates of one Statuspage incident as `statuspage` timeline events and
+/// fills `start_ts`/`mitigate_ts`/`resolve_ts` from created/monitoring/resolved times when those
+/// are still empty, then re-materializes quarter assignments when an anchor was filled.
+/// Existing timestamps are never overwritten.
+pub fn ingest_statuspage_incident(
+    conn: &mut Connection,
+    json_text: &str,
+    statuspage_incident_id: &str,
+    incident_id: Option<i64>,
+    new_incident_title: Option<&str>,
+) -> Result<StatuspageIngestSummary, AppError> {
+    let source = parse_statuspage_history(json_text)?
+        .into_iter()
+        .find(|i| i.id == statuspage_incident_id)
+        .ok_or_else(|| {
+            AppError::new("INGEST_STATUSPAGE_INCIDENT_NOT_FOUND", "Statuspage incident not found in export")
+                .with_details(format!("statuspage_incident_id={statuspage_incident_id}"))
+        })?;
+    // A new incident defaults to the public Statuspage title.
+    let title = match incident_id {
+        None => new_incident_title.or(source.name.as_deref()),
+        Some(_) => new_incident_title,
+    };
+    let (target_incident_id, incident_created) = ensure_target_incident(
+        conn,
+        incident_id,
+        title,
+        &format!("statuspage|{}", source.id),
+    )?;
+
+    let mut summary = StatuspageIngestSummary {
+        incident_id: target_incident_id,
+        incident_created,
+        inserted_events: 0,
+        skipped_duplicates: 0,
+        backfilled_fields: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    let mut updates = source.incident_updates.clone();
+    updates.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    for u in &updates {
+        let ts = canonical_ts(u.display_at.as_deref().or(u.created_at.as_deref()));
+        if ts.is_none() {
+            summary.warnings.push(
+                ValidationWarning::new("INGEST_STATUSPAGE_TS_UNKNOWN", "Statuspage update has no readable timestamp")
+                    .with_details(format!("update_id={}", u.id.as_deref().unwrap_or("?"))),
+            );
+        }
+        let text = u.body.as_deref().unwrap_or("").trim().to_string();
+        let kind = u.status.as_deref().unwrap_or("update").to_string();
+        let exists: Option<i64> = conn
+            .query_row(
+                "SELECT id FROM timeline_events
+                 WHERE incident_id = ?1 AND source = 'statuspage' AND ts IS ?2 AND kind = ?3 AND text = ?4",
+                rusqlite::params![target_incident_id, ts, kind, text],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| {
+                AppError::new("DB_QUERY_FAILED", "Failed to check existing Statuspage events")
+                    .with_details(e.to_string())
+            })?;
+        if exists.is_some() {
+            summary.skipped_duplicates += 1;
+            continue;
+        }
+        let raw_json = serde_json::json!({
+            "statuspage_incident_id": source.id,
+            "shortlink": source.shortlink,
+            "impact": source.impact,
+            "update": u,
+        })
+        .to_string();
+        conn.execute(
+            r#"
+          INSERT INTO timeline_events(
+            incident_id, source, ts, author, kind, text, raw_json, created_at
+          ) VALUES (
+            ?1, 'statuspage', ?2, NULL, ?3, ?4, ?5,
+            strftime('%Y-%m-%dT%H:%M:%fZ','now')
+          )
+          "#,
+            rusqlite::params![target_incident_id, ts, kind, text, raw_json],
+        )
+        .map_err(|e| {
+            AppError::new("INGEST_STATUSPAGE_INSERT_FAILED", "Failed to insert Statuspage update")
+                .with_details(format!("update_id={}; err={e}", u.id.as_deref().unwrap_or("?")))
+        })?;
+        summary.inserted_events += 1;
+    }
+
+    for (column, value) in [
+        ("start_ts", &source.created_at),
+        ("mitigate_ts", &source.monitoring_at),
+        ("resolve_ts", &source.resolved_at),
+    ] {
+        if let Some(ts) = canonical_ts(value.as_deref()) {
+            if backfill(conn, target_incident_id, column, &ts)? {
+                summary.backfilled_fields.push(column.to_string());
+            }
+        }
+    }
+    // start_ts and resolve_ts are the quarter assignment anchors; mitigate_ts never moves an incident.
+    if summary.backfilled_fields.iter().any(|f| f == "start_ts" || f == "resolve_ts") {
+        crate::quarters::refresh_quarter_assignments(conn)?;
+    }
+    Ok(summary)
+}
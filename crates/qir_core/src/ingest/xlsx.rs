@@ -0,0 +1,148 @@
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+
+use calamine::{open_workbook_auto_from_rs, Data, Range, Reader};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::error::AppError;
+use crate::progress::Progress;
+
+use super::jira_csv::{import_jira_csv_with_progress, JiraCsvMapping, JiraImportSummary};
+
+// Days between Excel's 1900 date system epoch (1899-12-30) and the Unix epoch.
+const EXCEL_UNIX_EPOCH_DAYS: f64 = 25569.0;
+
+/// First rows of one worksheet, plus every sheet name so the UI can offer a sheet picker.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct XlsxPreview {
+    pub sheets: Vec<String>,
+    pub sheet: String,
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+fn parse_failed(e: impl ToString) -> AppError {
+    AppError::new("INGEST_XLSX_PARSE_FAILED", "Failed to read spreadsheet").with_details(e.to_string())
+}
+
+pub fn read_xlsx_file(path: &Path) -> Result<Vec<u8>, AppError> {
+    fs::read(path).map_err(|e| {
+        AppError::new("INGEST_XLSX_READ_FAILED", "Failed to read spreadsheet file")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    })
+}
+
+/// Excel stores dates as day serials without a zone; they are read as UTC.
+fn excel_serial_to_rfc3339(serial: f64) -> Option<String> {
+    let nanos = ((serial - EXCEL_UNIX_EPOCH_DAYS) * 86_400.0 * 1e9).round() as i128;
+    // Round to whole seconds; serials carry float noise in the sub-second digits.
+    let nanos = (nanos + 500_000_000).div_euclid(1_000_000_000) * 1_000_000_000;
+    OffsetDateTime::from_unix_timestamp_nanos(nanos)
+        .ok()?
+        .format(&Rfc3339)
+        .ok()
+}
+
+fn cell_text(cell: &Data) -> String {
+    match cell {
+        Data::Empty | Data::Error(_) => String::new(),
+        Data::String(s) | Data::DateTimeIso(s) | Data::DurationIso(s) => s.trim().to_string(),
+        Data::Int(i) => i.to_string(),
+        // Whole numbers typed into Excel come back as floats; render 12.0 as "12".
+        Data::Float(f) if f.fract() == 0.0 && f.abs() < 1e15 => format!("{}", *f as i64),
+        Data::Float(f) => f.to_string(),
+        Data::Bool(b) => b.to_string(),
+        Data::DateTime(dt) => excel_serial_to_rfc3339(dt.as_f64()).unwrap_or_else(|| dt.as_f64().to_string()),
+    }
+}
+
+struct SheetRows {
+    sheets: Vec<String>,
+    name: String,
+    rows: Vec<Vec<String>>,
+}
+
+/// Cell text of `sheet` (the first sheet when None), header row included. Fully empty rows are
+/// dropped.
+fn read_sheet(bytes: &[u8], sheet: Option<&str>) -> Result<SheetRows, AppError> {
+    let mut workbook = open_workbook_auto_from_rs(Cursor::new(bytes.to_vec())).map_err(parse_failed)?;
+    let sheets = workbook.sheet_names();
+    let name = match sheet.map(str::trim).filter(|s| !s.is_empty()) {
+        Some(s) => sheets.iter().find(|n| n.as_str() == s).cloned().ok_or_else(|| {
+            AppError::new("INGEST_XLSX_SHEET_NOT_FOUND", "Worksheet not found in workbook")
+                .with_details(format!("sheet={s}; sheets={sheets:?}"))
+        })?,
+        None => sheets.first().cloned().ok_or_else(|| parse_failed("workbook has no worksheets"))?,
+    };
+    let range: Range<Data> = workbook.worksheet_range(&name).map_err(parse_failed)?;
+    let rows = range
+        .rows()
+        .map(|r| r.iter().map(cell_text).collect::<Vec<_>>())
+        .filter(|r| r.iter().any(|c| !c.is_empty()))
+        .collect();
+    Ok(SheetRows { sheets, name, rows })
+}
+
+fn split_header(mut rows: Vec<Vec<String>>, sheet: &str) -> Result<(Vec<String>, Vec<Vec<String>>), AppError> {
+    if rows.is_empty() {
+        return Err(AppError::new("INGEST_XLSX_PARSE_FAILED", "Worksheet has no header row")
+            .with_details(format!("sheet={sheet}")));
+    }
+    let headers = rows.remove(0);
+    Ok((headers, rows))
+}
+
+pub fn preview_xlsx(bytes: &[u8], sheet: Option<&str>, max_rows: usize) -> Result<XlsxPreview, AppError> {
+    let sheet = read_sheet(bytes, sheet)?;
+    let (headers, mut rows) = split_header(sheet.rows, &sheet.name)?;
+    rows.truncate(max_rows);
+    Ok(XlsxPreview {
+        sheets: sheet.sheets,
+        sheet: sheet.name,
+        headers,
+        rows,
+    })
+}
+
+fn rows_to_csv(headers: &[String], rows: &[Vec<String>]) -> Result<String, AppError> {
+    let write_failed = |e: String| {
+        AppError::new("INGEST_XLSX_PARSE_FAILED", "Failed to prepare spreadsheet rows for import").with_details(e)
+    };
+    let mut out = csv::Writer::from_writer(Vec::new());
+    out.write_record(headers).map_err(|e| write_failed(e.to_string()))?;
+    for row in rows {
+        // Pad or cut ragged rows to the header width.
+        let cells = (0..headers.len()).map(|i| row.get(i).map(String::as_str).unwrap_or(""));
+        out.write_record(cells).map_err(|e| write_failed(e.to_string()))?;
+    }
+    let bytes = out.into_inner().map_err(|e| write_failed(e.to_string()))?;
+    String::from_utf8(bytes).map_err(|e| write_failed(e.to_string()))
+}
+
+/// Imports one worksheet through the Jira CSV pipeline; `mapping` names the sheet's header
+/// cells, so saved Jira mapping profiles work unchanged.
+pub fn import_xlsx_with_progress(
+    conn: &mut Connection,
+    bytes: &[u8],
+    sheet: Option<&str>,
+    mapping: &JiraCsvMapping,
+    progress: &mut Progress<'_>,
+) -> Result<JiraImportSummary, AppError> {
+    let sheet = read_sheet(bytes, sheet)?;
+    let (headers, rows) = split_header(sheet.rows, &sheet.name)?;
+    let csv_text = rows_to_csv(&headers, &rows)?;
+    import_jira_csv_with_progress(conn, &csv_text, mapping, progress)
+}
+
+pub fn import_xlsx(
+    conn: &mut Connection,
+    bytes: &[u8],
+    sheet: Option<&str>,
+    mapping: &JiraCsvMapping,
+) -> Result<JiraImportSummary, AppError> {
+    import_xlsx_with_progress(conn, bytes, sheet, mapping, &mut Progress::noop("import_xlsx"))
+}
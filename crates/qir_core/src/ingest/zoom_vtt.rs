@@ -0,0 +1,228 @@
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{Duration, OffsetDateTime, UtcOffset};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+
+use super::slack_transcript::ensure_target_incident;
+
+/// One caption cue; offsets are milliseconds from the start of the recording.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VttCue {
+    pub cue_id: Option<String>,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ZoomVttPreview {
+    pub cue_count: usize,
+    pub speakers: Vec<String>,
+    pub duration_ms: i64,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ZoomVttIngestSummary {
+    pub incident_id: i64,
+    pub incident_created: bool,
+    pub inserted_events: usize,
+    pub skipped_duplicates: usize,
+    pub warnings: Vec<ValidationWarning>,
+}
+
+fn parse_failed(message: &str, details: String) -> AppError {
+    AppError::new("INGEST_ZOOM_VTT_PARSE_FAILED", message).with_details(details)
+}
+
+/// `HH:MM:SS.mmm` or `MM:SS.mmm`.
+fn parse_vtt_timestamp(s: &str) -> Option<i64> {
+    let (clock, millis) = s.trim().split_once('.')?;
+    if millis.len() != 3 {
+        return None;
+    }
+    let millis: i64 = millis.parse().ok()?;
+    let parts = clock
+        .split(':')
+        .map(|p| p.parse::<i64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    let (h, m, sec) = match parts.as_slice() {
+        [h, m, s] => (*h, *m, *s),
+        [m, s] => (0, *m, *s),
+        _ => return None,
+    };
+    if m >= 60 || sec >= 60 {
+        return None;
+    }
+    Some(((h * 60 + m) * 60 + sec) * 1000 + millis)
+}
+
+/// Zoom writes the speaker as a `Name: ` prefix; generic WebVTT uses `<v Name>` voice tags.
+fn split_speaker(text: &str) -> (Option<String>, String) {
+    if let Some(rest) = text.strip_prefix("<v") {
+        if let Some((name, body)) = rest.split_once('>') {
+            let name = name.trim();
+            let body = body.replace("</v>", "");
+            let name = (!name.is_empty()).then(|| name.to_string());
+            return (name, body.trim().to_string());
+        }
+    }
+    match text.split_once(": ") {
+        // Guard against sentences that merely contain a colon.
+        Some((name, body)) if !name.is_empty() && name.chars().count() <= 64 && !name.contains("://") => {
+            (Some(name.trim().to_string()), body.trim().to_string())
+        }
+        _ => (None, text.trim().to_string()),
+    }
+}
+
+/// Parses a WebVTT file into cues. Cue settings after the end time and NOTE/STYLE blocks are
+/// ignored; multi-line cue text is joined with spaces.
+pub fn parse_zoom_vtt(vtt: &str) -> Result<(Vec<VttCue>, Vec<ValidationWarning>), AppError> {
+    let vtt = vtt.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+    let mut blocks = vtt.split("\n\n");
+    let header = blocks.next().unwrap_or("");
+    if !header.trim_start().starts_with("WEBVTT") {
+        return Err(parse_failed(
+            "Transcript is not a WebVTT file",
+            "first line must start with WEBVTT".to_string(),
+        ));
+    }
+
+    let mut cues = Vec::new();
+    let mut warnings = Vec::new();
+    for (idx, block) in blocks.enumerate() {
+        let lines: Vec<&str> = block.lines().map(str::trim_end).filter(|l| !l.trim().is_empty()).collect();
+        let Some(first) = lines.first() else {
+            continue;
+        };
+        if first.starts_with("NOTE") || first.starts_with("STYLE") || first.starts_with("REGION") {
+            continue;
+        }
+        let (cue_id, timing_idx) = if first.contains("-->") { (None, 0) } else { (Some(first.trim().to_string()), 1) };
+        let timing = lines.get(timing_idx).copied().unwrap_or("");
+        let parsed = timing.split_once("-->").and_then(|(start, rest)| {
+            let end = rest.split_whitespace().next().unwrap_or("");
+            Some((parse_vtt_timestamp(start)?, parse_vtt_timestamp(end)?))
+        });
+        let Some((start_ms, end_ms)) = parsed else {
+            warnings.push(
+                ValidationWarning::new("INGEST_ZOOM_VTT_CUE_SKIPPED", "Skipped cue with an unreadable timing line")
+                    .with_details(format!("block={}; timing={timing}", idx + 1)),
+            );
+            continue;
+        };
+        let text = lines[timing_idx + 1..].iter().map(|l| l.trim()).collect::<Vec<_>>().join(" ");
+        let (speaker, text) = split_speaker(&text);
+        if text.is_empty() {
+            continue;
+        }
+        if speaker.is_none() {
+            warnings.push(
+                ValidationWarning::new("INGEST_ZOOM_VTT_SPEAKER_UNKNOWN", "Cue has no speaker attribution")
+                    .with_details(format!("cue={}", cue_id.as_deref().unwrap_or("?"))),
+            );
+        }
+        cues.push(VttCue {
+            cue_id,
+            start_ms,
+            end_ms,
+            speaker,
+            text,
+        });
+    }
+    Ok((cues, warnings))
+}
+
+pub fn preview_zoom_vtt(vtt: &str) -> Result<ZoomVttPreview, AppError> {
+    let (cues, warnings) = parse_zoom_vtt(vtt)?;
+    let mut speakers: Vec<String> = cues.iter().filter_map(|c| c.speaker.clone()).collect();
+    speakers.sort();
+    speakers.dedup();
+    Ok(ZoomVttPreview {
+        cue_count: cues.len(),
+        speakers,
+        duration_ms: cues.iter().map(|c| c.end_ms).max().unwrap_or(0),
+        warnings,
+    })
+}
+
+fn parse_call_start(call_start: &str) -> Result<OffsetDateTime, AppError> {
+    OffsetDateTime::parse(call_start.trim(), &Rfc3339).map_err(|e| {
+        AppError::new("INGEST_ZOOM_VTT_CALL_START_INVALID", "Call start time must be an RFC3339 timestamp")
+            .with_details(format!("call_start={call_start}; err={e}"))
+    })
+}
+
+/// Inserts each cue as a `zoom` timeline event at `call_start + cue start`. Re-ingesting the
+/// same transcript with the same call start skips cues already stored.
+pub fn ingest_zoom_vtt(
+    conn: &mut Connection,
+    incident_id: Option<i64>,
+    new_incident_title: Option<&str>,
+    call_start: &str,
+    vtt: &str,
+) -> Result<ZoomVttIngestSummary, AppError> {
+    let start = parse_call_start(call_start)?;
+    let (cues, warnings) = parse_zoom_vtt(vtt)?;
+    let (target_incident_id, incident_created) = ensure_target_incident(conn, incident_id, new_incident_title, vtt)?;
+
+    let mut inserted_events = 0usize;
+    let mut skipped_duplicates = 0usize;
+    for cue in &cues {
+        let ts = (start + Duration::milliseconds(cue.start_ms))
+            .to_offset(UtcOffset::UTC)
+            .format(&Rfc3339)
+            .map_err(|e| parse_failed("Failed to format cue timestamp", e.to_string()))?;
+        let exists: Option<i64> = conn
+            .query_row(
+                "SELECT id FROM timeline_events
+                 WHERE incident_id = ?1 AND source = 'zoom' AND ts = ?2 AND author IS ?3 AND text = ?4",
+                rusqlite::params![target_incident_id, ts, cue.speaker, cue.text],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| {
+                AppError::new("DB_QUERY_FAILED", "Failed to check existing Zoom events").with_details(e.to_string())
+            })?;
+        if exists.is_some() {
+            skipped_duplicates += 1;
+            continue;
+        }
+        let raw_json = serde_json::json!({
+            "call_start": call_start.trim(),
+            "cue_id": cue.cue_id,
+            "start_ms": cue.start_ms,
+            "end_ms": cue.end_ms,
+        })
+        .to_string();
+        conn.execute(
+            r#"
+          INSERT INTO timeline_events(
+            incident_id, source, ts, author, kind, text, raw_json, created_at
+          ) VALUES (
+            ?1, 'zoom', ?2, ?3, 'transcript', ?4, ?5,
+            strftime('%Y-%m-%dT%H:%M:%fZ','now')
+          )
+          "#,
+            rusqlite::params![target_incident_id, ts, cue.speaker, cue.text, raw_json],
+        )
+        .map_err(|e| {
+            AppError::new("INGEST_ZOOM_VTT_INSERT_FAILED", "Failed to insert Zoom transcript event")
+                .with_details(format!("cue={}; err={e}", cue.cue_id.as_deref().unwrap_or("?")))
+        })?;
+        inserted_events += 1;
+    }
+
+    Ok(ZoomVttIngestSummary {
+        incident_id: target_incident_id,
+        incident_created,
+        inserted_events,
+        skipped_duplicates,
+        warnings,
+    })
+}
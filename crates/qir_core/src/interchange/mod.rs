@@ -0,0 +1,554 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::aliases::AliasResolver;
+use crate::domain::{Incident, ValidationWarning};
+use crate::backup::filename_safe_timestamp;
+use crate::error::AppError;
+use crate::freeze::FrozenQuarters;
+use crate::metrics::{compute_incident_metrics, IncidentMetrics};
+use crate::repo::{list_incidents, list_timeline_events_for_incident};
+use crate::severity::SeverityNormalizer;
+
+/// Identifies an interchange document regardless of version. Documented in
+/// `docs/architecture/interchange.md`.
+pub const INTERCHANGE_SCHEMA: &str = "incidentreview.interchange";
+pub const INTERCHANGE_SCHEMA_VERSION: u32 = 1;
+/// Versions this build can both write and read, oldest first.
+pub const INTERCHANGE_SUPPORTED_VERSIONS: [u32; 1] = [1];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InterchangeCapabilities {
+    pub schema: String,
+    pub current_version: u32,
+    pub supported_versions: Vec<u32>,
+}
+
+/// One interchange file: incidents with their timelines and derived metrics. Keyed by
+/// `external_id`/`fingerprint` only; workspace row ids never leave the database.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InterchangeDocument {
+    pub schema: String,
+    pub schema_version: u32,
+    pub producer: InterchangeProducer,
+    pub exported_at: String,
+    pub incidents: Vec<InterchangeIncident>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InterchangeProducer {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InterchangeIncident {
+    #[serde(default)]
+    pub external_id: Option<String>,
+    /// Optional on import; derived the same way CSV imports derive it when absent.
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub severity: Option<String>,
+    #[serde(default)]
+    pub detection_source: Option<String>,
+    #[serde(default)]
+    pub vendor: Option<String>,
+    #[serde(default)]
+    pub service: Option<String>,
+    #[serde(default)]
+    pub impact_pct: Option<i64>,
+    #[serde(default)]
+    pub service_health_pct: Option<i64>,
+    #[serde(default)]
+    pub timestamps: InterchangeTimestamps,
+    /// Informational on import: metrics are always recomputed from `timestamps`.
+    #[serde(default)]
+    pub metrics: Option<IncidentMetrics>,
+    #[serde(default)]
+    pub timeline: Vec<InterchangeTimelineEvent>,
+}
+
+/// RFC3339 UTC timestamps; `null` when unknown.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InterchangeTimestamps {
+    #[serde(default)]
+    pub start: Option<String>,
+    #[serde(default)]
+    pub first_observed: Option<String>,
+    #[serde(default)]
+    pub it_awareness: Option<String>,
+    #[serde(default)]
+    pub ack: Option<String>,
+    #[serde(default)]
+    pub mitigate: Option<String>,
+    #[serde(default)]
+    pub resolve: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InterchangeTimelineEvent {
+    pub source: String,
+    #[serde(default)]
+    pub ts: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub kind: Option<String>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterchangeExportResult {
+    pub path: String,
+    pub schema_version: u32,
+    pub incident_count: i64,
+    pub timeline_event_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InterchangeImportSummary {
+    pub schema_version: u32,
+    pub inserted_incidents: i64,
+    pub inserted_timeline_events: i64,
+    /// `external_id` (or fingerprint) of incidents already in the workspace; left untouched.
+    pub skipped_existing: Vec<String>,
+    /// `external_id` (or fingerprint) of new incidents that fall in a frozen quarter; not inserted.
+    pub skipped_frozen: Vec<String>,
+    pub import_warnings: Vec<ValidationWarning>,
+}
+
+/// Only the fields needed to decide whether the rest of the document can be read.
+#[derive(Deserialize)]
+struct InterchangeHeader {
+    schema: Option<String>,
+    schema_version: Option<u32>,
+}
+
+pub fn interchange_capabilities() -> InterchangeCapabilities {
+    InterchangeCapabilities {
+        schema: INTERCHANGE_SCHEMA.to_string(),
+        current_version: INTERCHANGE_SCHEMA_VERSION,
+        supported_versions: INTERCHANGE_SUPPORTED_VERSIONS.to_vec(),
+    }
+}
+
+/// Picks the newest version both sides understand. An empty `accepted` list means the
+/// consumer takes whatever this build writes by default.
+pub fn negotiate_interchange_version(accepted: &[u32]) -> Result<u32, AppError> {
+    if accepted.is_empty() {
+        return Ok(INTERCHANGE_SCHEMA_VERSION);
+    }
+    INTERCHANGE_SUPPORTED_VERSIONS
+        .iter()
+        .rev()
+        .find(|v| accepted.contains(v))
+        .copied()
+        .ok_or_else(|| {
+            AppError::new(
+                "EXPORT_INTERCHANGE_VERSION_UNSUPPORTED",
+                "No interchange schema version is supported by both tools",
+            )
+            .with_details(format!(
+                "accepted={accepted:?}; supported={:?}",
+                INTERCHANGE_SUPPORTED_VERSIONS
+            ))
+        })
+}
+
+fn interchange_incident(conn: &Connection, incident: &Incident) -> Result<InterchangeIncident, AppError> {
+    let (metrics, _) = compute_incident_metrics(incident);
+    let timeline = list_timeline_events_for_incident(conn, incident.id)?
+        .into_iter()
+        .map(|e| InterchangeTimelineEvent {
+            source: e.source,
+            ts: e.ts,
+            author: e.author,
+            kind: e.kind,
+            text: e.text,
+        })
+        .collect();
+    Ok(InterchangeIncident {
+        external_id: incident.external_id.clone(),
+        fingerprint: Some(incident.fingerprint.clone()),
+        title: incident.title.clone(),
+        description: incident.description.clone(),
+        severity: incident.severity.clone(),
+        detection_source: incident.detection_source.clone(),
+        vendor: incident.vendor.clone(),
+        service: incident.service.clone(),
+        impact_pct: incident.impact_pct,
+        service_health_pct: incident.service_health_pct,
+        timestamps: InterchangeTimestamps {
+            start: incident.start_ts.clone(),
+            first_observed: incident.first_observed_ts.clone(),
+            it_awareness: incident.it_awareness_ts.clone(),
+            ack: incident.ack_ts.clone(),
+            mitigate: incident.mitigate_ts.clone(),
+            resolve: incident.resolve_ts.clone(),
+        },
+        metrics: Some(metrics),
+        timeline,
+    })
+}
+
+/// Builds a document in the negotiated version. Deterministic for a given workspace state
+/// and `exported_at` (incidents by id, timeline events by timestamp).
+pub fn build_interchange_document(
+    conn: &Connection,
+    accepted_versions: &[u32],
+    exported_at: &str,
+    app_version: &str,
+) -> Result<InterchangeDocument, AppError> {
+    let schema_version = negotiate_interchange_version(accepted_versions)?;
+    let mut incidents = list_incidents(conn)?;
+    incidents.sort_by_key(|i| i.id);
+    let incidents = incidents
+        .iter()
+        .map(|i| interchange_incident(conn, i))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(InterchangeDocument {
+        schema: INTERCHANGE_SCHEMA.to_string(),
+        schema_version,
+        producer: InterchangeProducer {
+            name: "IncidentReview".to_string(),
+            version: app_version.to_string(),
+        },
+        exported_at: exported_at.to_string(),
+        incidents,
+    })
+}
+
+pub fn export_interchange(
+    conn: &Connection,
+    destination_dir: &Path,
+    accepted_versions: &[u32],
+    exported_at: &str,
+    app_version: &str,
+) -> Result<InterchangeExportResult, AppError> {
+    if !destination_dir.is_dir() {
+        return Err(AppError::new(
+            "EXPORT_INTERCHANGE_DEST_NOT_DIR",
+            "Interchange export destination must be an existing directory",
+        )
+        .with_details(destination_dir.display().to_string()));
+    }
+    let path = destination_dir.join(format!(
+        "IncidentReviewInterchange_{}.json",
+        filename_safe_timestamp(exported_at)
+    ));
+    if path.exists() {
+        return Err(AppError::new(
+            "EXPORT_INTERCHANGE_DEST_EXISTS",
+            "Interchange export destination file already exists",
+        )
+        .with_details(path.display().to_string()));
+    }
+
+    let doc = build_interchange_document(conn, accepted_versions, exported_at, app_version)?;
+    let json = serde_json::to_string_pretty(&doc).map_err(|e| {
+        AppError::new("EXPORT_INTERCHANGE_ENCODE_FAILED", "Failed to encode interchange document")
+            .with_details(e.to_string())
+    })?;
+    fs::write(&path, json.as_bytes()).map_err(|e| {
+        AppError::new("EXPORT_INTERCHANGE_WRITE_FAILED", "Failed to write interchange document")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    })?;
+
+    Ok(InterchangeExportResult {
+        path: path.to_string_lossy().to_string(),
+        schema_version: doc.schema_version,
+        incident_count: doc.incidents.len() as i64,
+        timeline_event_count: doc.incidents.iter().map(|i| i.timeline.len() as i64).sum(),
+    })
+}
+
+/// Checks the schema identifier and version before decoding the body, so a document from a
+/// newer producer fails with a version error rather than a field-level decode error.
+pub fn parse_interchange_document(json: &str) -> Result<InterchangeDocument, AppError> {
+    let header: InterchangeHeader = serde_json::from_str(json).map_err(|e| {
+        AppError::new("INGEST_INTERCHANGE_DECODE_FAILED", "Interchange document is not valid JSON")
+            .with_details(e.to_string())
+    })?;
+    if header.schema.as_deref() != Some(INTERCHANGE_SCHEMA) {
+        return Err(AppError::new(
+            "INGEST_INTERCHANGE_SCHEMA_MISMATCH",
+            "File is not an IncidentReview interchange document",
+        )
+        .with_details(format!("schema={:?}; expected={INTERCHANGE_SCHEMA}", header.schema)));
+    }
+    let version = header.schema_version.unwrap_or(0);
+    if !INTERCHANGE_SUPPORTED_VERSIONS.contains(&version) {
+        return Err(AppError::new(
+            "INGEST_INTERCHANGE_VERSION_UNSUPPORTED",
+            "Unsupported interchange schema version",
+        )
+        .with_details(format!(
+            "schema_version={version}; supported={:?}",
+            INTERCHANGE_SUPPORTED_VERSIONS
+        )));
+    }
+    serde_json::from_str(json).map_err(|e| {
+        AppError::new("INGEST_INTERCHANGE_DECODE_FAILED", "Failed to decode interchange document")
+            .with_details(format!("schema_version={version}; err={e}"))
+    })
+}
+
+pub fn read_interchange_document(path: &Path) -> Result<InterchangeDocument, AppError> {
+    let json = fs::read_to_string(path).map_err(|e| {
+        AppError::new("INGEST_INTERCHANGE_READ_FAILED", "Failed to read interchange document")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    })?;
+    parse_interchange_document(&json)
+}
+
+fn invalid(index: usize, message: &str, details: String) -> AppError {
+    AppError::new("INGEST_INTERCHANGE_INVALID", message).with_details(format!("incident={index}; {details}"))
+}
+
+fn check_ts(index: usize, field: &str, value: &Option<String>) -> Result<(), AppError> {
+    match value.as_deref() {
+        Some(v) if OffsetDateTime::parse(v, &Rfc3339).is_err() => Err(invalid(
+            index,
+            "Interchange timestamps must be RFC3339",
+            format!("field={field}; value={v}"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn to_incident(inc: &InterchangeIncident) -> Incident {
+    let t = &inc.timestamps;
+    let fingerprint = inc.fingerprint.clone().unwrap_or_else(|| {
+        let input = crate::ingest::fingerprint::FingerprintInput {
+            title: &inc.title,
+            start_ts: t.start.as_deref(),
+            first_observed_ts: t.first_observed.as_deref(),
+            ack_ts: t.ack.as_deref(),
+            mitigate_ts: t.mitigate.as_deref(),
+            resolve_ts: t.resolve.as_deref(),
+            ..Default::default()
+        };
+        crate::ingest::fingerprint::FingerprintStrategy::default().fingerprint(&input).0
+    });
+    Incident {
+        id: 0,
+        external_id: inc.external_id.clone(),
+        fingerprint,
+        title: inc.title.clone(),
+        description: inc.description.clone(),
+        severity: inc.severity.clone(),
+        detection_source: inc.detection_source.clone(),
+        vendor: inc.vendor.clone(),
+        service: inc.service.clone(),
+        impact_pct: inc.impact_pct,
+        service_health_pct: inc.service_health_pct,
+        start_ts: t.start.clone(),
+        first_observed_ts: t.first_observed.clone(),
+        it_awareness_ts: t.it_awareness.clone(),
+        ack_ts: t.ack.clone(),
+        mitigate_ts: t.mitigate.clone(),
+        resolve_ts: t.resolve.clone(),
+        start_ts_raw: None,
+        first_observed_ts_raw: None,
+        it_awareness_ts_raw: None,
+        ack_ts_raw: None,
+        mitigate_ts_raw: None,
+        resolve_ts_raw: None,
+    }
+}
+
+/// Validates the whole document up front so a bad incident never leaves a partial import.
+fn validate_document(doc: &InterchangeDocument) -> Result<Vec<Incident>, AppError> {
+    let mut keys = BTreeSet::new();
+    let mut out = Vec::with_capacity(doc.incidents.len());
+    for (index, inc) in doc.incidents.iter().enumerate() {
+        if inc.title.trim().is_empty() {
+            return Err(invalid(
+                index,
+                "Interchange incident is missing a title",
+                format!("external_id={:?}", inc.external_id),
+            ));
+        }
+        let t = &inc.timestamps;
+        for (field, value) in [
+            ("start", &t.start),
+            ("first_observed", &t.first_observed),
+            ("it_awareness", &t.it_awareness),
+            ("ack", &t.ack),
+            ("mitigate", &t.mitigate),
+            ("resolve", &t.resolve),
+        ] {
+            check_ts(index, field, value)?;
+        }
+        for e in &inc.timeline {
+            check_ts(index, "timeline.ts", &e.ts)?;
+        }
+        let incident = to_incident(inc);
+        let key = incident.external_id.clone().unwrap_or_else(|| incident.fingerprint.clone());
+        if !keys.insert(key.clone()) {
+            return Err(invalid(
+                index,
+                "Interchange document lists the same incident twice",
+                format!("key={key}"),
+            ));
+        }
+        out.push(incident);
+    }
+    Ok(out)
+}
+
+fn existing_incident(conn: &Connection, incident: &Incident) -> Result<bool, AppError> {
+    let n: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM incidents WHERE fingerprint = ?1 OR (?2 IS NOT NULL AND external_id = ?2)",
+            rusqlite::params![incident.fingerprint, incident.external_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to look up incident during interchange import")
+                .with_details(e.to_string())
+        })?;
+    Ok(n > 0)
+}
+
+/// Inserts incidents the workspace does not already have (matched by `external_id` or
+/// fingerprint) along with their timelines, in one transaction. Existing incidents are
+/// reported in `skipped_existing` and never modified; incidents in frozen quarters are
+/// reported in `skipped_frozen`. Workspace severity mappings and vendor/service aliases apply
+/// as in every other import.
+pub fn import_interchange(
+    conn: &mut Connection,
+    doc: &InterchangeDocument,
+    imported_at: &str,
+) -> Result<InterchangeImportSummary, AppError> {
+    let incidents = validate_document(doc)?;
+    let severity_normalizer = SeverityNormalizer::load(conn)?;
+    let aliases = AliasResolver::load(conn)?;
+    let frozen_quarters = FrozenQuarters::load(conn)?;
+
+    let tx = conn.transaction().map_err(|e| {
+        AppError::new("DB_TX_FAILED", "Failed to start interchange import transaction")
+            .with_details(e.to_string())
+    })?;
+
+    let mut summary = InterchangeImportSummary {
+        schema_version: doc.schema_version,
+        inserted_incidents: 0,
+        inserted_timeline_events: 0,
+        skipped_existing: Vec::new(),
+        skipped_frozen: Vec::new(),
+        import_warnings: Vec::new(),
+    };
+
+    for (inc, incident) in doc.incidents.iter().zip(&incidents) {
+        let key = incident.external_id.clone().unwrap_or_else(|| incident.fingerprint.clone());
+        if existing_incident(&tx, incident)? {
+            summary.skipped_existing.push(key);
+            continue;
+        }
+        if let Some(q) = frozen_quarters.for_timestamps(
+            incident.start_ts.as_deref(),
+            incident.first_observed_ts.as_deref(),
+            incident.resolve_ts.as_deref(),
+        ) {
+            summary.import_warnings.push(
+                ValidationWarning::new(
+                    "INGEST_INTERCHANGE_QUARTER_FROZEN",
+                    "Incident falls in a frozen quarter; not imported",
+                )
+                .with_details(format!("incident={key}; quarter_label={q}")),
+            );
+            summary.skipped_frozen.push(key);
+            continue;
+        }
+
+        let (metrics, _) = compute_incident_metrics(incident);
+        if let Some(claimed) = inc.metrics.as_ref().filter(|m| **m != metrics) {
+            summary.import_warnings.push(
+                ValidationWarning::new(
+                    "INGEST_INTERCHANGE_METRICS_MISMATCH",
+                    "Interchange metrics differ from recomputation; recomputed values are used",
+                )
+                .with_details(format!("incident={key}; document={claimed:?}; computed={metrics:?}")),
+            );
+        }
+
+        let mut incident = incident.clone();
+        if let Some(raw) = incident.severity.take() {
+            match severity_normalizer.normalize(&raw) {
+                Ok(severity) => incident.severity = Some(severity),
+                Err(value) => summary.import_warnings.push(
+                    ValidationWarning::new("INGEST_SEVERITY_UNMAPPED", "Severity has no workspace mapping; left blank")
+                        .with_details(format!("severity={value}; incident={key}")),
+                ),
+            }
+        }
+        aliases.apply(std::slice::from_mut(&mut incident));
+
+        tx.execute(
+            r#"
+            INSERT INTO incidents(
+              external_id, fingerprint, title, description, severity,
+              detection_source, vendor, service,
+              impact_pct, service_health_pct,
+              start_ts, first_observed_ts, it_awareness_ts, ack_ts, mitigate_ts, resolve_ts,
+              ingested_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
+            "#,
+            rusqlite::params![
+                incident.external_id,
+                incident.fingerprint,
+                incident.title,
+                incident.description,
+                incident.severity,
+                incident.detection_source,
+                incident.vendor,
+                incident.service,
+                incident.impact_pct,
+                incident.service_health_pct,
+                incident.start_ts,
+                incident.first_observed_ts,
+                incident.it_awareness_ts,
+                incident.ack_ts,
+                incident.mitigate_ts,
+                incident.resolve_ts,
+                imported_at,
+            ],
+        )
+        .map_err(|e| {
+            AppError::new("INGEST_INTERCHANGE_INSERT_FAILED", "Failed to insert interchange incident")
+                .with_details(format!("incident={key}; err={e}"))
+        })?;
+        let incident_id = tx.last_insert_rowid();
+        summary.inserted_incidents += 1;
+
+        for e in &inc.timeline {
+            tx.execute(
+                "INSERT INTO timeline_events(incident_id, source, ts, author, kind, text, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                rusqlite::params![incident_id, e.source, e.ts, e.author, e.kind, e.text, imported_at],
+            )
+            .map_err(|err| {
+                AppError::new("INGEST_INTERCHANGE_INSERT_FAILED", "Failed to insert interchange timeline event")
+                    .with_details(format!("incident={key}; ts={:?}; err={err}", e.ts))
+            })?;
+            summary.inserted_timeline_events += 1;
+        }
+    }
+
+    crate::quarters::refresh_quarter_assignments(&tx)?;
+    tx.commit().map_err(|e| {
+        AppError::new("DB_TX_FAILED", "Failed to commit interchange import").with_details(e.to_string())
+    })?;
+    Ok(summary)
+}
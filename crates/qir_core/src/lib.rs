@@ -1,18 +1,37 @@
+pub mod action_items;
+pub mod aliases;
 pub mod analytics;
 pub mod ai_drafts;
 pub mod backup;
+#[cfg(feature = "bench-data")]
+pub mod bench_data;
 pub mod cache;
+pub mod columnar;
 pub mod db;
 pub mod demo;
+pub mod diagrams;
 pub mod domain;
 pub mod error;
+pub mod freeze;
+pub mod history;
 pub mod ingest;
+pub mod interchange;
+pub mod live;
 pub mod metrics;
 pub mod normalize;
 pub mod profiles;
+pub mod progress;
+pub mod quarters;
+pub mod query;
 pub mod repo;
 pub mod report;
+pub mod responders;
+pub mod sampling;
 pub mod sanitize;
+pub mod settings;
+pub mod severity;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod validate;
 pub mod workspace;
 
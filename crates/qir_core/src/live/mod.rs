@@ -0,0 +1,262 @@
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::freeze::{ensure_incident_mutable, quarter_frozen_error, FrozenQuarters};
+use crate::repo::TimelineEvent;
+
+/// Timeline source for notes typed during a live incident.
+pub const LIVE_SOURCE: &str = "live";
+
+/// A live note and an imported event this far apart (either way) can be the same moment:
+/// people type the note a little before or after the message lands in Slack.
+pub const RECONCILE_WINDOW_SECONDS: i64 = 5 * 60;
+
+/// Share of the shorter text's words the two must have in common to count as one event.
+const RECONCILE_MIN_WORD_OVERLAP: f64 = 0.6;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuickCaptureInput {
+    pub title: String,
+    #[serde(default)]
+    pub severity: Option<String>,
+    #[serde(default)]
+    pub service: Option<String>,
+    /// RFC3339; defaults to now. Set it when the incident was noticed a few minutes ago.
+    #[serde(default)]
+    pub started_at: Option<String>,
+}
+
+fn invalid(message: &str, details: String) -> AppError {
+    AppError::new("VALIDATION_QUICK_CAPTURE_INVALID", message).with_details(details)
+}
+
+/// Canonical RFC3339 UTC to the second, or the current time when `ts` is None.
+pub(crate) fn canonical_now_or(field: &str, ts: Option<&str>) -> Result<String, AppError> {
+    let dt = match ts {
+        Some(t) => OffsetDateTime::parse(t.trim(), &Rfc3339)
+            .map_err(|e| invalid("Live timestamps must be RFC3339", format!("{field}={t}; err={e}")))?,
+        None => OffsetDateTime::now_utc(),
+    };
+    let utc = dt.to_offset(UtcOffset::UTC);
+    utc.replace_nanosecond(0)
+        .unwrap_or(utc)
+        .format(&Rfc3339)
+        .map_err(|e| invalid("Failed to format live timestamp", e.to_string()))
+}
+
+fn non_blank(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// Creates a minimal open incident while it is still happening: title, start (now unless
+/// given) and optionally severity and service. Pressing capture twice within the same second
+/// returns the incident already created instead of a duplicate.
+pub fn quick_capture(conn: &Connection, input: QuickCaptureInput) -> Result<Incident, AppError> {
+    let title = input.title.trim();
+    if title.is_empty() {
+        return Err(invalid("Quick capture needs a title", String::new()));
+    }
+    let start_ts = canonical_now_or("started_at", input.started_at.as_deref())?;
+    if let Some(label) = FrozenQuarters::load(conn)?.for_timestamps(Some(&start_ts), None, None) {
+        return Err(quarter_frozen_error(&label, &format!("start_ts={start_ts}")));
+    }
+    let severity = non_blank(input.severity.as_deref());
+    let service = non_blank(input.service.as_deref());
+
+    let payload = format!("quick_capture|title={}|start={start_ts}", title.to_lowercase());
+    let fingerprint = hex::encode(Sha256::digest(payload.as_bytes()));
+    let write_failed = |e: rusqlite::Error| {
+        AppError::new("DB_WRITE_FAILED", "Failed to capture incident").with_details(format!("title={title}; err={e}"))
+    };
+    let inserted = conn
+        .execute(
+            r#"
+      INSERT OR IGNORE INTO incidents(
+        external_id, fingerprint, title, severity, service, start_ts, ingested_at
+      ) VALUES (NULL, ?1, ?2, ?3, ?4, ?5, strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+      "#,
+            params![fingerprint, title, severity, service, start_ts],
+        )
+        .map_err(write_failed)?;
+    let id: i64 = conn
+        .query_row("SELECT id FROM incidents WHERE fingerprint = ?1", [&fingerprint], |r| r.get(0))
+        .optional()
+        .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to load captured incident").with_details(e.to_string()))?
+        .ok_or_else(|| AppError::new("DB_NOT_FOUND", "Captured incident not found").with_details(format!("fingerprint={fingerprint}")))?;
+
+    if inserted > 0 {
+        if let Some(severity) = severity {
+            // What was declared in the moment is the initial grade by definition.
+            crate::severity::set_initial_severity(conn, id, Some(severity))?;
+        }
+        crate::quarters::refresh_quarter_assignments(conn)?;
+    }
+    crate::repo::get_incident(conn, id)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LiveNoteDuplicate {
+    pub live_event_id: i64,
+    pub matched_event_id: i64,
+    pub matched_source: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LiveReconcileSummary {
+    pub incident_id: i64,
+    /// Live notes not yet superseded that were compared.
+    pub checked: usize,
+    pub suppressed: Vec<LiveNoteDuplicate>,
+}
+
+fn load_event(conn: &Connection, id: i64) -> Result<TimelineEvent, AppError> {
+    conn.query_row(
+        "SELECT id, incident_id, source, ts, author, kind, text, raw_json, created_at FROM timeline_events WHERE id = ?1",
+        [id],
+        |row| {
+            Ok(TimelineEvent {
+                id: row.get(0)?,
+                incident_id: row.get(1)?,
+                source: row.get(2)?,
+                ts: row.get(3)?,
+                author: row.get(4)?,
+                kind: row.get(5)?,
+                text: row.get(6)?,
+                raw_json: row.get(7)?,
+                created_at: row.get(8)?,
+            })
+        },
+    )
+    .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to load timeline event").with_details(format!("id={id}; err={e}")))
+}
+
+fn require_incident(conn: &Connection, incident_id: i64) -> Result<(), AppError> {
+    let exists: Option<i64> = conn
+        .query_row("SELECT id FROM incidents WHERE id = ?1", [incident_id], |row| row.get(0))
+        .optional()
+        .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to check incident exists").with_details(e.to_string()))?;
+    if exists.is_none() {
+        return Err(AppError::new("DB_NOT_FOUND", "Incident not found for live timeline")
+            .with_details(format!("incident_id={incident_id}")));
+    }
+    Ok(())
+}
+
+/// Appends a note to a live incident's timeline stamped with the current UTC time. `kind`
+/// defaults to "note" (e.g. "action", "decision", "status").
+pub fn timeline_append_now(
+    conn: &Connection,
+    incident_id: i64,
+    text: &str,
+    kind: Option<&str>,
+) -> Result<TimelineEvent, AppError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(invalid("Live timeline notes need some text", format!("incident_id={incident_id}")));
+    }
+    require_incident(conn, incident_id)?;
+    ensure_incident_mutable(conn, incident_id)?;
+    let kind = non_blank(kind).unwrap_or("note").to_lowercase();
+    let ts = canonical_now_or("ts", None)?;
+
+    conn.execute(
+        r#"
+      INSERT INTO timeline_events(incident_id, source, ts, author, kind, text, raw_json, created_at)
+      VALUES (?1, ?2, ?3, NULL, ?4, ?5, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+      "#,
+        params![incident_id, LIVE_SOURCE, ts, kind, text],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to append live timeline note")
+            .with_details(format!("incident_id={incident_id}; err={e}"))
+    })?;
+    load_event(conn, conn.last_insert_rowid())
+}
+
+fn words(text: &str) -> std::collections::BTreeSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn same_text(a: &str, b: &str) -> bool {
+    let (wa, wb) = (words(a), words(b));
+    let shorter = wa.len().min(wb.len());
+    if shorter == 0 {
+        return false;
+    }
+    wa.intersection(&wb).count() as f64 / shorter as f64 >= RECONCILE_MIN_WORD_OVERLAP
+}
+
+fn seconds_apart(a: &str, b: &str) -> Option<i64> {
+    let a = OffsetDateTime::parse(a, &Rfc3339).ok()?;
+    let b = OffsetDateTime::parse(b, &Rfc3339).ok()?;
+    Some((a - b).whole_seconds().abs())
+}
+
+/// Compares live notes with events imported later for the same incident. A note whose text
+/// largely matches an imported event within `RECONCILE_WINDOW_SECONDS` is marked superseded
+/// by the closest such event and drops out of the timeline. Safe to run repeatedly.
+pub fn reconcile_live_notes(conn: &Connection, incident_id: i64) -> Result<LiveReconcileSummary, AppError> {
+    require_incident(conn, incident_id)?;
+    ensure_incident_mutable(conn, incident_id)?;
+
+    let query_failed =
+        |e: rusqlite::Error| AppError::new("DB_QUERY_FAILED", "Failed to load timeline for reconcile").with_details(e.to_string());
+    let load = |sql: &str| -> Result<Vec<(i64, String, String, String)>, AppError> {
+        let mut stmt = conn.prepare(sql).map_err(query_failed)?;
+        let rows = stmt
+            .query_map(params![incident_id, LIVE_SOURCE], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?)))
+            .map_err(query_failed)?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(query_failed)
+    };
+    let live = load(
+        "SELECT id, source, ts, text FROM timeline_events
+         WHERE incident_id = ?1 AND source = ?2 AND superseded_by IS NULL AND ts IS NOT NULL
+         ORDER BY ts, id",
+    )?;
+    let imported = load(
+        "SELECT id, source, ts, text FROM timeline_events
+         WHERE incident_id = ?1 AND source <> ?2 AND superseded_by IS NULL AND ts IS NOT NULL
+         ORDER BY ts, id",
+    )?;
+
+    let mut suppressed = Vec::new();
+    for (live_id, _, live_ts, live_text) in &live {
+        let best = imported
+            .iter()
+            .filter_map(|(id, source, ts, text)| {
+                let apart = seconds_apart(live_ts, ts)?;
+                (apart <= RECONCILE_WINDOW_SECONDS && same_text(live_text, text)).then_some((apart, *id, source))
+            })
+            .min();
+        let Some((_, matched_id, source)) = best else {
+            continue;
+        };
+        conn.execute(
+            "UPDATE timeline_events SET superseded_by = ?1 WHERE id = ?2",
+            params![matched_id, live_id],
+        )
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to mark live note as duplicate")
+                .with_details(format!("id={live_id}; err={e}"))
+        })?;
+        suppressed.push(LiveNoteDuplicate {
+            live_event_id: *live_id,
+            matched_event_id: matched_id,
+            matched_source: source.clone(),
+        });
+    }
+
+    Ok(LiveReconcileSummary {
+        incident_id,
+        checked: live.len(),
+        suppressed,
+    })
+}
@@ -0,0 +1,136 @@
+use std::collections::BTreeSet;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use super::{compute_pair, parse_ts};
+use crate::domain::{Incident, ValidationWarning};
+use crate::error::AppError;
+use crate::settings::{get_setting_as, set_setting};
+
+pub const COMPUTED_METRICS_SETTING: &str = "metrics.computed_definitions";
+pub const COMPUTED_METRICS_MAX: usize = 12;
+
+/// Incident timestamp fields a computed metric may start or end at.
+pub const COMPUTED_METRIC_TIMESTAMP_FIELDS: [&str; 6] = [
+    "start_ts",
+    "first_observed_ts",
+    "it_awareness_ts",
+    "ack_ts",
+    "mitigate_ts",
+    "resolve_ts",
+];
+
+/// Keys of the built-in metrics; computed metrics may not shadow them.
+const BUILT_IN_METRIC_KEYS: [&str; 5] = [
+    "mttd",
+    "it_awareness_lag",
+    "mtta",
+    "time_to_mitigation",
+    "mttr",
+];
+
+/// A workspace-defined duration metric: seconds from `from_field` to `to_field`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ComputedMetricDefinition {
+    /// Stable snake_case key, used in bucket keys and export column names.
+    pub key: String,
+    pub label: String,
+    pub from_field: String,
+    pub to_field: String,
+}
+
+fn invalid(message: impl Into<String>, details: String) -> AppError {
+    AppError::new("VALIDATION_COMPUTED_METRIC_INVALID", message).with_details(details)
+}
+
+fn valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    key.len() <= 40
+        && chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+pub fn validate_computed_metric_definitions(defs: &[ComputedMetricDefinition]) -> Result<(), AppError> {
+    if defs.len() > COMPUTED_METRICS_MAX {
+        return Err(invalid(
+            format!("At most {COMPUTED_METRICS_MAX} computed metrics can be defined"),
+            format!("count={}", defs.len()),
+        ));
+    }
+    let mut seen = BTreeSet::new();
+    for d in defs {
+        if !valid_key(&d.key) || BUILT_IN_METRIC_KEYS.contains(&d.key.as_str()) {
+            return Err(invalid(
+                "Computed metric key must be snake_case (max 40 chars) and not a built-in metric",
+                format!("key={}", d.key),
+            ));
+        }
+        if !seen.insert(d.key.as_str()) {
+            return Err(invalid("Computed metric keys must be unique", format!("key={}", d.key)));
+        }
+        if d.label.trim().is_empty() {
+            return Err(invalid("Computed metric label is required", format!("key={}", d.key)));
+        }
+        for field in [&d.from_field, &d.to_field] {
+            if !COMPUTED_METRIC_TIMESTAMP_FIELDS.contains(&field.as_str()) {
+                return Err(invalid(
+                    "Computed metrics must use incident timestamp fields",
+                    format!("key={}; field={field}", d.key),
+                ));
+            }
+        }
+        if d.from_field == d.to_field {
+            return Err(invalid(
+                "Computed metric must span two different timestamps",
+                format!("key={}; field={}", d.key, d.from_field),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Definitions stored in workspace settings, in the order they were saved. Empty when none.
+pub fn load_computed_metric_definitions(conn: &Connection) -> Result<Vec<ComputedMetricDefinition>, AppError> {
+    let defs = get_setting_as::<Vec<ComputedMetricDefinition>>(conn, COMPUTED_METRICS_SETTING)?.unwrap_or_default();
+    validate_computed_metric_definitions(&defs)?;
+    Ok(defs)
+}
+
+pub fn save_computed_metric_definitions(
+    conn: &Connection,
+    defs: &[ComputedMetricDefinition],
+) -> Result<(), AppError> {
+    validate_computed_metric_definitions(defs)?;
+    set_setting(conn, COMPUTED_METRICS_SETTING, &serde_json::json!(defs))
+}
+
+fn timestamp_field<'a>(incident: &'a Incident, field: &str) -> &'a Option<String> {
+    match field {
+        "start_ts" => &incident.start_ts,
+        "first_observed_ts" => &incident.first_observed_ts,
+        "it_awareness_ts" => &incident.it_awareness_ts,
+        "ack_ts" => &incident.ack_ts,
+        "mitigate_ts" => &incident.mitigate_ts,
+        "resolve_ts" => &incident.resolve_ts,
+        _ => &None,
+    }
+}
+
+/// Same rules as the built-in metrics: missing or unparseable timestamps yield None, and an
+/// end before the start yields None with a METRICS_TS_ORDER_VIOLATION warning.
+pub fn compute_computed_metric(
+    incident: &Incident,
+    def: &ComputedMetricDefinition,
+) -> (Option<i64>, Vec<ValidationWarning>) {
+    let mut warnings = Vec::new();
+    let from = parse_ts(&def.from_field, timestamp_field(incident, &def.from_field), &mut warnings);
+    let to = parse_ts(&def.to_field, timestamp_field(incident, &def.to_field), &mut warnings);
+    let secs = compute_pair(&def.from_field, from, &def.to_field, to, &mut warnings);
+    (secs, warnings)
+}
+
+/// One value per definition, in definition order.
+pub fn compute_computed_metrics(incident: &Incident, defs: &[ComputedMetricDefinition]) -> Vec<Option<i64>> {
+    defs.iter().map(|d| compute_computed_metric(incident, d).0).collect()
+}
@@ -3,6 +3,13 @@ use time::OffsetDateTime;
 
 use crate::domain::{Incident, ValidationWarning};
 
+mod computed;
+pub use computed::{
+    compute_computed_metric, compute_computed_metrics, load_computed_metric_definitions,
+    save_computed_metric_definitions, validate_computed_metric_definitions, ComputedMetricDefinition,
+    COMPUTED_METRICS_MAX, COMPUTED_METRICS_SETTING, COMPUTED_METRIC_TIMESTAMP_FIELDS,
+};
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct IncidentMetrics {
     pub mttd_seconds: Option<i64>,
@@ -136,3 +143,37 @@ pub fn compute_incident_metrics(incident: &Incident) -> (IncidentMetrics, Vec<Va
         warnings,
     )
 }
+
+/// Human-readable duration used across reports and callouts ("1h 5m", "4m 10s", UNKNOWN).
+pub(crate) fn format_duration_seconds(secs: Option<i64>) -> String {
+    match secs {
+        None => "UNKNOWN".to_string(),
+        Some(s) => {
+            let minutes = s / 60;
+            let rem = s % 60;
+            if minutes >= 60 {
+                let hours = minutes / 60;
+                let m = minutes % 60;
+                format!("{hours}h {m}m")
+            } else if minutes > 0 {
+                format!("{minutes}m {rem}s")
+            } else {
+                format!("{rem}s")
+            }
+        }
+    }
+}
+
+pub(crate) fn percentile(values: &mut Vec<i64>, pct_num: i64, pct_den: i64) -> Option<i64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort();
+    let n = values.len() as i64;
+    if n == 1 {
+        return Some(values[0]);
+    }
+    // Deterministic "nearest-rank on 0..n-1": idx = floor((n-1) * pct).
+    let idx = ((n - 1) * pct_num) / pct_den;
+    values.get(idx as usize).copied()
+}
@@ -0,0 +1,393 @@
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedText {
+    /// CommonMark rendering of the input.
+    pub text: String,
+    /// Original input, preserved only when normalization changed it.
+    pub raw: Option<String>,
+}
+
+fn normalized(original: &str, text: String) -> NormalizedText {
+    let raw = (text != original).then(|| original.to_string());
+    NormalizedText { text, raw }
+}
+
+/// Jira descriptions arrive either as wiki markup (CSV exports, Server/DC) or as an Atlassian
+/// Document Format JSON document (Cloud API). Both become CommonMark.
+pub fn normalize_jira_description(input: &str) -> NormalizedText {
+    let text = adf_to_markdown(input).unwrap_or_else(|| jira_wiki_to_markdown(input));
+    normalized(input, text)
+}
+
+/// Slack message text (mrkdwn) to CommonMark.
+pub fn normalize_slack_text(input: &str) -> NormalizedText {
+    normalized(input, slack_mrkdwn_to_markdown(input))
+}
+
+fn boundary_before(chars: &[char], i: usize) -> bool {
+    i == 0 || !chars[i - 1].is_alphanumeric()
+}
+
+fn boundary_after(chars: &[char], j: usize) -> bool {
+    chars.get(j + 1).is_none_or(|c| !c.is_alphanumeric())
+}
+
+/// Rewrites `<delim>text<delim>` spans (word-bounded, no inner edge whitespace) as
+/// `open text close`. Backtick code spans are copied through untouched.
+fn convert_delimited(s: &str, delim: char, open: &str, close: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::with_capacity(s.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '`' {
+            if let Some(end) = (i + 1..chars.len()).find(|&j| chars[j] == '`') {
+                out.extend(&chars[i..=end]);
+                i = end + 1;
+                continue;
+            }
+        }
+        let opens = c == delim
+            && boundary_before(&chars, i)
+            && (i == 0 || chars[i - 1] != delim)
+            && chars.get(i + 1).is_some_and(|n| !n.is_whitespace() && *n != delim);
+        if opens {
+            let close_at = (i + 2..chars.len())
+                .find(|&j| {
+                    chars[j] == delim
+                        && !chars[j - 1].is_whitespace()
+                        && boundary_after(&chars, j)
+                        && chars.get(j + 1) != Some(&delim)
+                });
+            if let Some(j) = close_at {
+                out.push_str(open);
+                out.extend(&chars[i + 1..j]);
+                out.push_str(close);
+                i = j + 1;
+                continue;
+            }
+        }
+        out.push(c);
+        i += 1;
+    }
+    out
+}
+
+fn strip_tag(s: &str, tag: &str) -> String {
+    // Drops `{tag}` and `{tag:...}` markers, keeping the text between them.
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    let open = format!("{{{tag}");
+    while let Some(start) = rest.find(&open) {
+        let after = &rest[start + open.len()..];
+        if !(after.starts_with('}') || after.starts_with(':')) {
+            out.push_str(&rest[..start + open.len()]);
+            rest = after;
+            continue;
+        }
+        match after.find('}') {
+            Some(end) => {
+                out.push_str(&rest[..start]);
+                rest = &after[end + 1..];
+            }
+            None => break,
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn jira_links(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('[') {
+        let Some(len) = rest[start..].find(']') else {
+            break;
+        };
+        let inner = &rest[start + 1..start + len];
+        out.push_str(&rest[..start]);
+        if let Some(user) = inner.strip_prefix('~') {
+            out.push('@');
+            out.push_str(user);
+        } else if let Some((label, url)) = inner.split_once('|') {
+            out.push_str(&format!("[{label}]({url})"));
+        } else if inner.contains("://") || inner.starts_with("mailto:") {
+            out.push_str(&format!("<{inner}>"));
+        } else {
+            out.push_str(&rest[start..=start + len]);
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn jira_inline(s: &str) -> String {
+    let s = strip_tag(&strip_tag(s, "color"), "anchor");
+    let s = s.replace("{{", "`").replace("}}", "`");
+    let s = jira_links(&s);
+    let s = convert_delimited(&s, '*', "**", "**");
+    let s = convert_delimited(&s, '_', "*", "*");
+    let s = convert_delimited(&s, '-', "~~", "~~");
+    convert_delimited(&s, '+', "", "")
+}
+
+fn jira_list_prefix(line: &str) -> Option<(String, &str)> {
+    let markers: String = line.chars().take_while(|c| *c == '*' || *c == '#').collect();
+    let rest = line[markers.len()..].strip_prefix(' ')?;
+    if markers.is_empty() || rest.trim().is_empty() {
+        return None;
+    }
+    let mut indent = String::new();
+    for m in markers.chars().take(markers.chars().count() - 1) {
+        indent.push_str(if m == '#' { "   " } else { "  " });
+    }
+    let bullet = if markers.ends_with('#') { "1. " } else { "- " };
+    Some((format!("{indent}{bullet}"), rest))
+}
+
+fn jira_table_row(line: &str) -> Option<(String, bool)> {
+    let header = line.starts_with("||");
+    if !line.starts_with('|') || !line.ends_with('|') || line.len() < 2 {
+        return None;
+    }
+    let sep = if header { "||" } else { "|" };
+    let cells: Vec<String> = line
+        .trim_matches('|')
+        .split(sep)
+        .map(|c| jira_inline(c.trim()))
+        .collect();
+    Some((format!("| {} |", cells.join(" | ")), header))
+}
+
+/// Converts the common subset of Jira wiki markup: headings, bullet/numbered lists, bold,
+/// italic, strike, monospace, links, `{code}`/`{noformat}`, `{quote}`/`bq.`, tables and rules.
+/// Unknown macros are left as-is.
+pub fn jira_wiki_to_markdown(input: &str) -> String {
+    let text = input.replace("\r\n", "\n").replace('\r', "\n");
+    let mut out: Vec<String> = Vec::new();
+    let mut in_code = false;
+    let mut in_quote = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if in_code {
+            if trimmed == "{code}" || trimmed == "{noformat}" {
+                out.push("```".to_string());
+                in_code = false;
+            } else {
+                out.push(line.to_string());
+            }
+            continue;
+        }
+        if trimmed.starts_with("{code") || trimmed.starts_with("{noformat") {
+            let lang = trimmed
+                .strip_prefix("{code:")
+                .and_then(|rest| rest.strip_suffix('}'))
+                .filter(|l| !l.contains('='))
+                .unwrap_or("");
+            out.push(format!("```{lang}"));
+            in_code = true;
+            continue;
+        }
+        if trimmed == "{quote}" {
+            in_quote = !in_quote;
+            continue;
+        }
+        if trimmed.starts_with("{panel") {
+            continue;
+        }
+
+        let converted = if trimmed == "----" {
+            "---".to_string()
+        } else if let Some(rest) = trimmed.strip_prefix("bq. ") {
+            format!("> {}", jira_inline(rest))
+        } else if let Some((level, rest)) = trimmed
+            .strip_prefix('h')
+            .and_then(|r| r.split_once(". "))
+            .filter(|(l, _)| matches!(*l, "1" | "2" | "3" | "4" | "5" | "6"))
+        {
+            format!("{} {}", "#".repeat(level.parse().unwrap_or(1)), jira_inline(rest))
+        } else if let Some((prefix, rest)) = jira_list_prefix(trimmed) {
+            format!("{prefix}{}", jira_inline(rest))
+        } else if let Some((row, header)) = jira_table_row(trimmed) {
+            let cols = row.matches(" | ").count() + 1;
+            if header {
+                format!("{row}\n|{}", "---|".repeat(cols))
+            } else {
+                row
+            }
+        } else {
+            jira_inline(line)
+        };
+        out.push(if in_quote { format!("> {converted}") } else { converted });
+    }
+    if in_code {
+        out.push("```".to_string());
+    }
+    out.join("\n")
+}
+
+fn slack_angle_links(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('<') {
+        let Some(len) = rest[start..].find('>') else {
+            break;
+        };
+        let inner = &rest[start + 1..start + len];
+        out.push_str(&rest[..start]);
+        let (target, label) = match inner.split_once('|') {
+            Some((t, l)) => (t, Some(l)),
+            None => (inner, None),
+        };
+        if let Some(user) = target.strip_prefix('@') {
+            out.push('@');
+            out.push_str(label.unwrap_or(user));
+        } else if let Some(channel) = target.strip_prefix('#') {
+            out.push('#');
+            out.push_str(label.unwrap_or(channel));
+        } else if let Some(special) = target.strip_prefix('!') {
+            out.push('@');
+            out.push_str(label.unwrap_or(special));
+        } else if target.contains("://") || target.starts_with("mailto:") {
+            match label {
+                Some(l) => out.push_str(&format!("[{l}]({target})")),
+                None => out.push_str(&format!("<{target}>")),
+            }
+        } else {
+            out.push_str(&rest[start..=start + len]);
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Converts Slack mrkdwn: `*bold*`, `_italic_`, `~strike~`, `<url|label>` links, user/channel
+/// mentions, `•` bullets and HTML entities. Code spans and fences are already CommonMark.
+pub fn slack_mrkdwn_to_markdown(input: &str) -> String {
+    input
+        .lines()
+        .map(|line| {
+            let (indent, body) = line.split_at(line.len() - line.trim_start().len());
+            let body = match body.strip_prefix("• ").or_else(|| body.strip_prefix("◦ ")) {
+                Some(rest) => format!("- {rest}"),
+                None => body.to_string(),
+            };
+            let s = slack_angle_links(&body);
+            let s = convert_delimited(&s, '*', "**", "**");
+            let s = convert_delimited(&s, '_', "*", "*");
+            let s = convert_delimited(&s, '~', "~~", "~~");
+            let s = s.replace("&lt;", "<").replace("&gt;", ">").replace("&amp;", "&");
+            format!("{indent}{s}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn adf_inline(nodes: &[Value]) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node.get("type").and_then(Value::as_str).unwrap_or("") {
+            "text" => {
+                let mut text = node.get("text").and_then(Value::as_str).unwrap_or("").to_string();
+                for mark in node.get("marks").and_then(Value::as_array).into_iter().flatten() {
+                    text = match mark.get("type").and_then(Value::as_str).unwrap_or("") {
+                        "strong" => format!("**{text}**"),
+                        "em" => format!("*{text}*"),
+                        "code" => format!("`{text}`"),
+                        "strike" => format!("~~{text}~~"),
+                        "link" => match mark.pointer("/attrs/href").and_then(Value::as_str) {
+                            Some(href) => format!("[{text}]({href})"),
+                            None => text,
+                        },
+                        _ => text,
+                    };
+                }
+                out.push_str(&text);
+            }
+            "hardBreak" => out.push('\n'),
+            "mention" => {
+                let name = node.pointer("/attrs/text").and_then(Value::as_str).unwrap_or("@user");
+                if !name.starts_with('@') {
+                    out.push('@');
+                }
+                out.push_str(name);
+            }
+            "emoji" => out.push_str(node.pointer("/attrs/shortName").and_then(Value::as_str).unwrap_or("")),
+            "inlineCard" => {
+                if let Some(url) = node.pointer("/attrs/url").and_then(Value::as_str) {
+                    out.push_str(&format!("<{url}>"));
+                }
+            }
+            _ => out.push_str(&adf_inline(children(node))),
+        }
+    }
+    out
+}
+
+fn children(node: &Value) -> &[Value] {
+    node.get("content").and_then(Value::as_array).map(Vec::as_slice).unwrap_or(&[])
+}
+
+fn adf_blocks(nodes: &[Value], indent: &str, out: &mut Vec<String>) {
+    for node in nodes {
+        match node.get("type").and_then(Value::as_str).unwrap_or("") {
+            "paragraph" => out.push(format!("{indent}{}", adf_inline(children(node)))),
+            "heading" => {
+                let level = node.pointer("/attrs/level").and_then(Value::as_u64).unwrap_or(1).clamp(1, 6);
+                out.push(format!("{indent}{} {}", "#".repeat(level as usize), adf_inline(children(node))));
+            }
+            "bulletList" | "orderedList" => {
+                let bullet = if node["type"] == "orderedList" { "1. " } else { "- " };
+                let nested = format!("{indent}{}", " ".repeat(bullet.len()));
+                for item in children(node) {
+                    let mut item_lines = Vec::new();
+                    adf_blocks(children(item), &nested, &mut item_lines);
+                    if let Some(first) = item_lines.first_mut() {
+                        *first = format!("{indent}{bullet}{}", first.trim_start());
+                    }
+                    out.extend(item_lines);
+                }
+            }
+            "codeBlock" => {
+                let lang = node.pointer("/attrs/language").and_then(Value::as_str).unwrap_or("");
+                out.push(format!("{indent}```{lang}"));
+                out.push(adf_inline(children(node)));
+                out.push(format!("{indent}```"));
+            }
+            "blockquote" => {
+                let mut quoted = Vec::new();
+                adf_blocks(children(node), "", &mut quoted);
+                out.extend(quoted.into_iter().map(|l| format!("{indent}> {l}")));
+            }
+            "rule" => out.push(format!("{indent}---")),
+            _ => adf_blocks(children(node), indent, out),
+        }
+    }
+}
+
+/// Renders an Atlassian Document Format document. Returns None when `input` is not ADF JSON.
+pub fn adf_to_markdown(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    if !trimmed.starts_with('{') {
+        return None;
+    }
+    let doc: Value = serde_json::from_str(trimmed).ok()?;
+    if doc.get("type").and_then(Value::as_str) != Some("doc") {
+        return None;
+    }
+    // Top-level blocks are separated by a blank line, as CommonMark paragraphs need.
+    let blocks: Vec<String> = children(&doc)
+        .iter()
+        .map(|block| {
+            let mut lines = Vec::new();
+            adf_blocks(std::slice::from_ref(block), "", &mut lines);
+            lines.join("\n")
+        })
+        .filter(|b| !b.trim().is_empty())
+        .collect();
+    Some(blocks.join("\n\n"))
+}
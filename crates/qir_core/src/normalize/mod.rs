@@ -1 +1,2 @@
+pub mod markup;
 pub mod timestamps;
@@ -1,7 +1,147 @@
 use time::format_description::well_known::Rfc3339;
+use time::parsing::Parsed;
 use time::{format_description, OffsetDateTime, PrimitiveDateTime, UtcOffset};
 
 use crate::domain::ValidationWarning;
+use crate::error::AppError;
+
+/// Largest offset accepted for naive timestamps (UTC-14:00..=UTC+14:00).
+const ASSUMED_UTC_OFFSET_MAX_MINUTES: i32 = 14 * 60;
+
+/// Zone given to timestamps that carry none, such as "2026-01-05 14:00".
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NaiveTimestampPolicy {
+    #[default]
+    AssumeUtc,
+    AssumeOffset(UtcOffset),
+}
+
+impl NaiveTimestampPolicy {
+    /// `None` keeps the UTC assumption; an explicit offset, even zero, is recorded as such.
+    pub fn from_offset_minutes(minutes: Option<i32>) -> Result<Self, AppError> {
+        let Some(m) = minutes else {
+            return Ok(Self::AssumeUtc);
+        };
+        let in_range = m.abs() <= ASSUMED_UTC_OFFSET_MAX_MINUTES;
+        in_range
+            .then(|| UtcOffset::from_whole_seconds(m * 60).ok())
+            .flatten()
+            .map(Self::AssumeOffset)
+            .ok_or_else(|| {
+                AppError::new(
+                    "VALIDATION_UTC_OFFSET_INVALID",
+                    "Assumed UTC offset must be between -14:00 and +14:00",
+                )
+                .with_details(format!("assumed_utc_offset_minutes={m}"))
+            })
+    }
+}
+
+/// A strftime-style pattern for one timestamp column, such as "%d/%b/%y %I:%M %p" for
+/// "05/Jan/26 2:00 PM". Tried after RFC3339 and before the built-in allowlist.
+///
+/// Supported: %Y %y %m %b %h %B %d %e %H %I %l %M %S %f %p %P %a %A %z %:z %T %R %F %%,
+/// plus the `-` flag (%-d, %-m, %-H, %-I) for unpadded numbers. Two-digit years pivot
+/// like POSIX: 69-99 are 19xx, 00-68 are 20xx.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomTimestampFormat {
+    pattern: String,
+    description: String,
+}
+
+impl CustomTimestampFormat {
+    pub fn from_strftime(field: &str, pattern: &str) -> Result<Self, AppError> {
+        let invalid = |reason: String| {
+            AppError::new(
+                "VALIDATION_TS_FORMAT_INVALID",
+                format!("Timestamp format for {field} is not a supported strftime pattern"),
+            )
+            .with_details(format!("field={field}; format={pattern}; {reason}"))
+        };
+        if pattern.trim().is_empty() {
+            return Err(invalid("reason=empty".to_string()));
+        }
+
+        let mut description = String::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '%' => {}
+                '[' => {
+                    description.push_str("[[");
+                    continue;
+                }
+                other => {
+                    description.push(other);
+                    continue;
+                }
+            }
+            let mut spec = chars.next();
+            let unpadded = spec == Some('-');
+            if unpadded {
+                spec = chars.next();
+            }
+            let colon = spec == Some(':');
+            if colon {
+                spec = chars.next();
+            }
+            let item = match (spec, unpadded, colon) {
+                (Some('Y'), false, false) => "[year]",
+                (Some('y'), false, false) => "[year repr:last_two]",
+                (Some('m'), false, false) => "[month]",
+                (Some('m'), true, false) => "[month padding:none]",
+                (Some('b' | 'h'), false, false) => "[month repr:short]",
+                (Some('B'), false, false) => "[month repr:long]",
+                (Some('d'), false, false) => "[day]",
+                (Some('d'), true, false) => "[day padding:none]",
+                (Some('e'), false, false) => "[day padding:space]",
+                (Some('H'), false, false) => "[hour]",
+                (Some('H'), true, false) => "[hour padding:none]",
+                (Some('I'), false, false) => "[hour repr:12]",
+                (Some('I'), true, false) | (Some('l'), false, false) => "[hour repr:12 padding:none]",
+                (Some('M'), false, false) => "[minute]",
+                (Some('S'), false, false) => "[second]",
+                (Some('f'), false, false) => "[subsecond]",
+                (Some('p'), false, false) => "[period]",
+                (Some('P'), false, false) => "[period case:lower]",
+                (Some('a'), false, false) => "[weekday repr:short]",
+                (Some('A'), false, false) => "[weekday]",
+                (Some('z'), false, false) => "[offset_hour sign:mandatory][offset_minute]",
+                (Some('z'), false, true) => "[offset_hour sign:mandatory]:[offset_minute]",
+                (Some('T'), false, false) => "[hour]:[minute]:[second]",
+                (Some('R'), false, false) => "[hour]:[minute]",
+                (Some('F'), false, false) => "[year]-[month]-[day]",
+                (Some('%'), false, false) => "%",
+                _ => {
+                    let token = format!(
+                        "%{}{}{}",
+                        if unpadded { "-" } else { "" },
+                        if colon { ":" } else { "" },
+                        spec.map(String::from).unwrap_or_default()
+                    );
+                    return Err(invalid(format!("unsupported={token}")));
+                }
+            };
+            description.push_str(item);
+        }
+
+        format_description::parse(&description).map_err(|e| invalid(format!("err={e}")))?;
+        Ok(Self {
+            pattern: pattern.to_string(),
+            description,
+        })
+    }
+
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+}
+
+fn format_offset(offset: UtcOffset) -> String {
+    let (h, m, _) = offset.as_hms();
+    let sign = if offset.is_negative() { '-' } else { '+' };
+    format!("{sign}{:02}:{:02}", h.unsigned_abs(), m.unsigned_abs())
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NormalizedTimestamp {
@@ -16,10 +156,11 @@ fn canonicalize_rfc3339_utc(dt: OffsetDateTime) -> Option<String> {
     utc.format(&Rfc3339).ok()
 }
 
-fn parse_primitive_assume_utc(
+fn parse_primitive_naive(
     raw: &str,
     fmt: &str,
     field: &str,
+    policy: NaiveTimestampPolicy,
     warnings: &mut Vec<ValidationWarning>,
 ) -> Option<String> {
     let items = match format_description::parse(fmt) {
@@ -41,37 +182,91 @@ fn parse_primitive_assume_utc(
         Err(_) => return None,
     };
 
-    // This format is missing timezone. We assume UTC deterministically but MUST warn explicitly.
-    warnings.push(
-        ValidationWarning::new(
-            "INGEST_TS_TZ_ASSUMED_UTC",
-            format!("Assumed UTC timezone for {field}"),
-        )
-        .with_details(format!("value={raw}; fmt={fmt}")),
-    );
+    assume_zone(pdt, raw, fmt, field, policy, warnings)
+}
 
-    canonicalize_rfc3339_utc(pdt.assume_utc())
+/// Applies the import's policy to a timestamp parsed without a zone. Deterministic, but
+/// MUST warn explicitly.
+fn assume_zone(
+    pdt: PrimitiveDateTime,
+    raw: &str,
+    fmt: &str,
+    field: &str,
+    policy: NaiveTimestampPolicy,
+    warnings: &mut Vec<ValidationWarning>,
+) -> Option<String> {
+    match policy {
+        NaiveTimestampPolicy::AssumeUtc => {
+            warnings.push(
+                ValidationWarning::new(
+                    "INGEST_TS_TZ_ASSUMED_UTC",
+                    format!("Assumed UTC timezone for {field}"),
+                )
+                .with_details(format!("value={raw}; fmt={fmt}")),
+            );
+            canonicalize_rfc3339_utc(pdt.assume_utc())
+        }
+        NaiveTimestampPolicy::AssumeOffset(offset) => {
+            warnings.push(
+                ValidationWarning::new(
+                    "INGEST_TS_TZ_ASSUMED_OFFSET",
+                    format!("Assumed UTC{} for {field}", format_offset(offset)),
+                )
+                .with_details(format!("value={raw}; fmt={fmt}; offset={}", format_offset(offset))),
+            );
+            canonicalize_rfc3339_utc(pdt.assume_offset(offset))
+        }
+    }
+}
+
+fn parse_custom(
+    raw: &str,
+    format: &CustomTimestampFormat,
+    field: &str,
+    policy: NaiveTimestampPolicy,
+    warnings: &mut Vec<ValidationWarning>,
+) -> Option<String> {
+    let items = format_description::parse(&format.description).ok()?;
+    let mut parsed = Parsed::new();
+    let rest = parsed.parse_items(raw.as_bytes(), &items).ok()?;
+    if !rest.is_empty() {
+        return None;
+    }
+    if parsed.year().is_none() {
+        if let Some(last_two) = parsed.year_last_two() {
+            parsed.set_year_century(if last_two < 69 { 20 } else { 19 }, false)?;
+        }
+    }
+
+    // A pattern with %z carries its own zone; only zoneless patterns go through the policy.
+    if parsed.offset_hour().is_some() {
+        return OffsetDateTime::try_from(parsed).ok().and_then(canonicalize_rfc3339_utc);
+    }
+    let pdt = PrimitiveDateTime::try_from(parsed).ok()?;
+    assume_zone(pdt, raw, format.pattern(), field, policy, warnings)
 }
 
 fn parse_allowlist(
     raw: &str,
     field: &str,
+    policy: NaiveTimestampPolicy,
     warnings: &mut Vec<ValidationWarning>,
 ) -> Option<String> {
     // Deterministic allowlist only (no fuzzy parsing).
     //
     // Notes:
     // - We prefer formats that explicitly include a timezone.
-    // - For formats without timezone, we assume UTC but emit an explicit warning.
+    // - For formats without timezone, we apply `policy` (UTC by default) and emit an explicit
+    //   warning.
 
-    // ISO-like without timezone (assume UTC, warn).
+    // ISO-like without timezone (apply policy, warn).
     for fmt in [
         "[year]-[month]-[day] [hour]:[minute]:[second]",
         "[year]-[month]-[day] [hour]:[minute]",
         "[year]-[month]-[day]T[hour]:[minute]:[second]",
         "[year]-[month]-[day]T[hour]:[minute]",
     ] {
-        if let Some(canon) = parse_primitive_assume_utc(raw, fmt, field, warnings) {
+        if let Some(canon) = parse_primitive_naive(raw, fmt, field, policy, warnings) {
             return Some(canon);
         }
     }
@@ -90,6 +285,29 @@ pub fn normalize_timestamp(
     field: &str,
     raw_input: &str,
     warnings: &mut Vec<ValidationWarning>,
+) -> NormalizedTimestamp {
+    normalize_timestamp_with_policy(field, raw_input, NaiveTimestampPolicy::AssumeUtc, warnings)
+}
+
+/// Same contract as `normalize_timestamp`, with `policy` deciding the zone of inputs that
+/// carry none.
+pub fn normalize_timestamp_with_policy(
+    field: &str,
+    raw_input: &str,
+    policy: NaiveTimestampPolicy,
+    warnings: &mut Vec<ValidationWarning>,
+) -> NormalizedTimestamp {
+    normalize_timestamp_with_format(field, raw_input, None, policy, warnings)
+}
+
+/// Same contract as `normalize_timestamp_with_policy`, also accepting inputs that match the
+/// column's custom `format`.
+pub fn normalize_timestamp_with_format(
+    field: &str,
+    raw_input: &str,
+    format: Option<&CustomTimestampFormat>,
+    policy: NaiveTimestampPolicy,
+    warnings: &mut Vec<ValidationWarning>,
 ) -> NormalizedTimestamp {
     let trimmed = raw_input.trim();
     if trimmed.is_empty() {
@@ -106,7 +324,8 @@ pub fn normalize_timestamp(
         };
     }
 
-    if let Some(canon) = parse_allowlist(trimmed, field, warnings) {
+    let custom = format.and_then(|f| parse_custom(trimmed, f, field, policy, warnings));
+    if let Some(canon) = custom.or_else(|| parse_allowlist(trimmed, field, policy, warnings)) {
         warnings.push(
             ValidationWarning::new(
                 "INGEST_TS_NORMALIZED",
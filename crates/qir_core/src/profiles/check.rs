@@ -0,0 +1,185 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::ingest::jira_csv::{preview_jira_csv, JiraCsvMapping};
+
+use super::jira::get_profile;
+
+/// Suggestions below this similarity are too weak to offer.
+const MIN_SUGGESTION_SCORE: f64 = 0.6;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileColumnStatus {
+    /// The header exists exactly as mapped.
+    Ok,
+    /// Only case or spacing differs; the import would still miss it.
+    Renamed,
+    Missing,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProfileColumnCheck {
+    /// Mapping field name, e.g. "resolve_ts".
+    pub field: String,
+    /// Column header the profile expects.
+    pub column: String,
+    pub status: ProfileColumnStatus,
+    /// Closest live header when the expected one is not present.
+    pub suggestion: Option<String>,
+    pub score: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JiraProfileCheck {
+    pub profile_id: i64,
+    pub profile_name: String,
+    pub headers: Vec<String>,
+    /// True when every mapped column is present exactly.
+    pub ok: bool,
+    pub columns: Vec<ProfileColumnCheck>,
+    /// The profile's mapping with every suggestion applied; missing columns without a
+    /// suggestion are kept as-is.
+    pub suggested_mapping: JiraCsvMapping,
+}
+
+fn normalize(s: &str) -> String {
+    s.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+/// 0..1 similarity of normalized headers. A header that contains the expected name (Jira's
+/// `Custom field (Severity)` for `Severity`) scores high.
+pub(super) fn similarity(expected: &str, header: &str) -> f64 {
+    let (a, b) = (normalize(expected), normalize(header));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    if a == b {
+        return 1.0;
+    }
+    if b.contains(&a) || a.contains(&b) {
+        return 0.9;
+    }
+    let (ac, bc): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    1.0 - levenshtein(&ac, &bc) as f64 / ac.len().max(bc.len()) as f64
+}
+
+fn mapped_columns(mapping: &mut JiraCsvMapping) -> Vec<(&'static str, &mut Option<String>)> {
+    vec![
+        ("external_id", &mut mapping.external_id),
+        ("description", &mut mapping.description),
+        ("severity", &mut mapping.severity),
+        ("initial_severity", &mut mapping.initial_severity),
+        ("detection_source", &mut mapping.detection_source),
+        ("vendor", &mut mapping.vendor),
+        ("service", &mut mapping.service),
+        ("impact_pct", &mut mapping.impact_pct),
+        ("service_health_pct", &mut mapping.service_health_pct),
+        ("start_ts", &mut mapping.start_ts),
+        ("first_observed_ts", &mut mapping.first_observed_ts),
+        ("it_awareness_ts", &mut mapping.it_awareness_ts),
+        ("ack_ts", &mut mapping.ack_ts),
+        ("mitigate_ts", &mut mapping.mitigate_ts),
+        ("resolve_ts", &mut mapping.resolve_ts),
+    ]
+}
+
+fn check_column(field: &str, column: &str, headers: &[String], taken: &[&str]) -> ProfileColumnCheck {
+    if headers.iter().any(|h| h == column) {
+        return ProfileColumnCheck {
+            field: field.to_string(),
+            column: column.to_string(),
+            status: ProfileColumnStatus::Ok,
+            suggestion: None,
+            score: None,
+        };
+    }
+    // Headers another field already maps exactly are not offered again.
+    let best = headers
+        .iter()
+        .filter(|h| !taken.contains(&h.as_str()))
+        .map(|h| (h, similarity(column, h)))
+        .filter(|(_, s)| *s >= MIN_SUGGESTION_SCORE)
+        .fold(None::<(&String, f64)>, |best, cur| match best {
+            Some(b) if b.1 >= cur.1 => Some(b),
+            _ => Some(cur),
+        });
+    let status = match best {
+        Some((_, s)) if s >= 1.0 => ProfileColumnStatus::Renamed,
+        _ => ProfileColumnStatus::Missing,
+    };
+    ProfileColumnCheck {
+        field: field.to_string(),
+        column: column.to_string(),
+        status,
+        suggestion: best.map(|(h, _)| h.clone()),
+        score: best.map(|(_, s)| (s * 100.0).round() / 100.0),
+    }
+}
+
+/// Compares every column a mapping references with `headers`, suggesting the closest live
+/// header for each one that is gone.
+pub fn check_mapping_against_headers(mapping: &JiraCsvMapping, headers: &[String]) -> (Vec<ProfileColumnCheck>, JiraCsvMapping) {
+    let mut suggested = mapping.clone();
+    let mut expected: Vec<(&'static str, String)> = vec![("title", mapping.title.clone())];
+    for (field, value) in mapped_columns(&mut suggested) {
+        if let Some(v) = value.as_deref().filter(|v| !v.trim().is_empty()) {
+            expected.push((field, v.to_string()));
+        }
+    }
+    let taken: Vec<&str> = expected
+        .iter()
+        .filter(|(_, c)| headers.iter().any(|h| h == c))
+        .map(|(_, c)| c.as_str())
+        .collect();
+
+    let checks: Vec<ProfileColumnCheck> = expected
+        .iter()
+        .map(|(field, column)| check_column(field, column, headers, &taken))
+        .collect();
+
+    for check in &checks {
+        let Some(suggestion) = check.suggestion.clone() else {
+            continue;
+        };
+        if check.field == "title" {
+            suggested.title = suggestion;
+            continue;
+        }
+        for (field, value) in mapped_columns(&mut suggested) {
+            if field == check.field {
+                *value = Some(suggestion.clone());
+            }
+        }
+    }
+    (checks, suggested)
+}
+
+/// Checks a saved profile against the header row of `csv_text` before anything is imported.
+pub fn check_profile_against_csv(conn: &Connection, profile_id: i64, csv_text: &str) -> Result<JiraProfileCheck, AppError> {
+    let profile = get_profile(conn, profile_id)?;
+    let headers = preview_jira_csv(csv_text, 0)?.headers;
+    let (columns, suggested_mapping) = check_mapping_against_headers(&profile.mapping, &headers);
+    Ok(JiraProfileCheck {
+        profile_id: profile.id,
+        profile_name: profile.name,
+        ok: columns.iter().all(|c| c.status == ProfileColumnStatus::Ok),
+        headers,
+        columns,
+        suggested_mapping,
+    })
+}
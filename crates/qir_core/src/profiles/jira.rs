@@ -103,6 +103,7 @@ pub fn upsert_profile(
             "Mapping must include a title column",
         ));
     }
+    upsert.mapping.timestamp_formats.validate()?;
 
     let mapping_json = serde_json::to_string(&upsert.mapping).map_err(|e| {
         AppError::new(
@@ -1 +1,3 @@
+pub mod check;
 pub mod jira;
+pub mod suggest;
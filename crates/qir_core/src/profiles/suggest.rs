@@ -0,0 +1,235 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::ingest::jira_csv::{preview_jira_csv, JiraCsvMapping};
+use crate::normalize::timestamps::normalize_timestamp;
+
+use super::check::similarity;
+
+/// Header matches weaker than this are not suggested.
+const MIN_FIELD_CONFIDENCE: f64 = 0.7;
+
+/// Sample rows read to check what a column actually holds.
+const SAMPLE_ROWS: usize = 20;
+
+/// Known header names per mapping field, most specific first. Matching is case- and
+/// punctuation-insensitive, so "Resolved At" and "resolved_at" are one synonym.
+const SYNONYMS: [(&str, &[&str]); 16] = [
+    ("external_id", &["Issue key", "Key", "Ticket", "Incident ID", "Reference", "Number", "ID"]),
+    ("title", &["Summary", "Title", "Incident Name", "Name", "Subject", "Short description"]),
+    ("description", &["Description", "Details", "Notes", "Body"]),
+    ("severity", &["Severity", "Priority", "Sev", "Impact level", "Urgency"]),
+    ("initial_severity", &["Original Priority", "Initial Severity", "Original Severity"]),
+    ("detection_source", &["Detection Source", "Detected By", "Source", "Detection Method", "DetectionSource"]),
+    ("vendor", &["Vendor", "Third Party", "Provider"]),
+    ("service", &["Service", "Component", "Components", "Affected Service", "Application", "System"]),
+    ("impact_pct", &["Impact %", "Impact Percent", "Impact Pct", "ImpactPct", "Customer Impact"]),
+    ("service_health_pct", &["Service Health %", "Service Health", "Availability"]),
+    ("start_ts", &["Start", "Started", "Start Time", "Impact Start", "Began", "Incident Start"]),
+    ("first_observed_ts", &["First Observed", "Detected", "Detected At", "Created", "Opened"]),
+    ("it_awareness_ts", &["IT Awareness", "Aware", "Reported", "Reported At"]),
+    ("ack_ts", &["Acknowledged", "Ack", "Ack Time", "Accepted", "Responded"]),
+    ("mitigate_ts", &["Mitigated", "Mitigation", "Mitigate", "Stabilized", "Workaround", "Restored"]),
+    ("resolve_ts", &["Resolved", "Resolve", "Resolution Date", "Closed", "Closed At", "Ended", "End Time", "Done"]),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldMappingSuggestion {
+    /// Mapping field name, e.g. "resolve_ts".
+    pub field: String,
+    /// Suggested header; None when nothing matched well enough.
+    pub column: Option<String>,
+    /// 0..1; 1.0 is an exact synonym match.
+    pub confidence: f64,
+    /// Why the column was picked: the synonym it matched, or "values" when the sample
+    /// values decided.
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JiraMappingSuggestion {
+    pub headers: Vec<String>,
+    /// Ready-to-use mapping; `title` is empty when no header looked like a title.
+    pub mapping: JiraCsvMapping,
+    pub fields: Vec<FieldMappingSuggestion>,
+}
+
+fn is_timestamp_field(field: &str) -> bool {
+    field.ends_with("_ts")
+}
+
+/// Severity-looking values: "P1", "SEV 2", "Sev-3", or the common word scales.
+fn looks_like_severity(value: &str) -> bool {
+    let v = value.trim().to_lowercase();
+    let digits = v
+        .strip_prefix("sev")
+        .or_else(|| v.strip_prefix('p'))
+        .map(|rest| rest.trim_start_matches([' ', '-', '_']));
+    if let Some(d) = digits {
+        if !d.is_empty() && d.chars().all(|c| c.is_ascii_digit()) {
+            return true;
+        }
+    }
+    matches!(v.as_str(), "critical" | "major" | "minor" | "high" | "medium" | "low" | "blocker")
+}
+
+/// What the sampled values of one column look like.
+#[derive(Debug, Clone, Copy, Default)]
+struct ColumnProfile {
+    non_empty: usize,
+    timestamps: usize,
+    severities: usize,
+}
+
+impl ColumnProfile {
+    fn mostly(&self, count: usize) -> bool {
+        self.non_empty > 0 && count * 2 > self.non_empty
+    }
+}
+
+fn profile_columns(headers: &[String], rows: &[Vec<String>]) -> Vec<ColumnProfile> {
+    let mut profiles = vec![ColumnProfile::default(); headers.len()];
+    let mut scratch = Vec::new();
+    for row in rows {
+        for (idx, value) in row.iter().enumerate().take(headers.len()) {
+            if value.trim().is_empty() {
+                continue;
+            }
+            let p = &mut profiles[idx];
+            p.non_empty += 1;
+            if normalize_timestamp("suggest", value, &mut scratch).canonical_rfc3339_utc.is_some() {
+                p.timestamps += 1;
+            }
+            if looks_like_severity(value) {
+                p.severities += 1;
+            }
+            scratch.clear();
+        }
+    }
+    profiles
+}
+
+/// Best synonym score for `field` against `header`, adjusted by the sampled values: a
+/// timestamp field never takes a column of non-timestamps, and a severity field gets a
+/// floor when the values read like P1/P2 or SEV1.
+fn score(field: &str, synonyms: &[&str], header: &str, profile: ColumnProfile) -> (f64, Option<String>) {
+    let (mut best, mut reason) = synonyms
+        .iter()
+        .map(|s| (similarity(s, header), Some(s.to_string())))
+        .fold((0.0, None), |a, b| if b.0 > a.0 { b } else { a });
+
+    if is_timestamp_field(field) && profile.non_empty > 0 && !profile.mostly(profile.timestamps) {
+        return (0.0, None);
+    }
+    if !is_timestamp_field(field) && profile.mostly(profile.timestamps) {
+        best *= 0.5;
+    }
+    // Only the primary severity field is inferred from values alone.
+    if field == "severity" && profile.mostly(profile.severities) && best < 0.8 {
+        best = 0.8;
+        reason = Some("values".to_string());
+    }
+    (best, reason)
+}
+
+fn set_field(mapping: &mut JiraCsvMapping, field: &str, column: String) {
+    let slot = match field {
+        "title" => {
+            mapping.title = column;
+            return;
+        }
+        "external_id" => &mut mapping.external_id,
+        "description" => &mut mapping.description,
+        "severity" => &mut mapping.severity,
+        "initial_severity" => &mut mapping.initial_severity,
+        "detection_source" => &mut mapping.detection_source,
+        "vendor" => &mut mapping.vendor,
+        "service" => &mut mapping.service,
+        "impact_pct" => &mut mapping.impact_pct,
+        "service_health_pct" => &mut mapping.service_health_pct,
+        "start_ts" => &mut mapping.start_ts,
+        "first_observed_ts" => &mut mapping.first_observed_ts,
+        "it_awareness_ts" => &mut mapping.it_awareness_ts,
+        "ack_ts" => &mut mapping.ack_ts,
+        "mitigate_ts" => &mut mapping.mitigate_ts,
+        "resolve_ts" => &mut mapping.resolve_ts,
+        _ => return,
+    };
+    *slot = Some(column);
+}
+
+/// Suggests a mapping for a CSV nobody has mapped yet. Every (field, header) pair is scored
+/// against known synonyms and sampled values, then assigned greedily from the highest score
+/// down so each header is used for at most one field.
+pub fn suggest_jira_mapping(csv_text: &str) -> Result<JiraMappingSuggestion, AppError> {
+    let preview = preview_jira_csv(csv_text, SAMPLE_ROWS)?;
+    let profiles = profile_columns(&preview.headers, &preview.rows);
+
+    let mut candidates: Vec<(f64, usize, usize, Option<String>)> = Vec::new();
+    for (field_idx, (field, synonyms)) in SYNONYMS.iter().enumerate() {
+        for (header_idx, header) in preview.headers.iter().enumerate() {
+            let (confidence, reason) = score(field, synonyms, header, profiles[header_idx]);
+            if confidence >= MIN_FIELD_CONFIDENCE {
+                candidates.push((confidence, field_idx, header_idx, reason));
+            }
+        }
+    }
+    // Highest confidence first; ties go to the field listed first, then the leftmost header.
+    candidates.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+
+    let mut fields: Vec<FieldMappingSuggestion> = SYNONYMS
+        .iter()
+        .map(|(field, _)| FieldMappingSuggestion {
+            field: field.to_string(),
+            column: None,
+            confidence: 0.0,
+            reason: None,
+        })
+        .collect();
+    let mut header_used = vec![false; preview.headers.len()];
+    for (confidence, field_idx, header_idx, reason) in candidates {
+        if fields[field_idx].column.is_some() || header_used[header_idx] {
+            continue;
+        }
+        header_used[header_idx] = true;
+        fields[field_idx] = FieldMappingSuggestion {
+            field: SYNONYMS[field_idx].0.to_string(),
+            column: Some(preview.headers[header_idx].clone()),
+            confidence: (confidence * 100.0).round() / 100.0,
+            reason,
+        };
+    }
+
+    let mut mapping = JiraCsvMapping {
+        external_id: None,
+        title: String::new(),
+        description: None,
+        severity: None,
+        initial_severity: None,
+        detection_source: None,
+        vendor: None,
+        service: None,
+        impact_pct: None,
+        service_health_pct: None,
+        start_ts: None,
+        first_observed_ts: None,
+        it_awareness_ts: None,
+        ack_ts: None,
+        mitigate_ts: None,
+        resolve_ts: None,
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
+    };
+    for f in &fields {
+        if let Some(column) = &f.column {
+            set_field(&mut mapping, &f.field, column.clone());
+        }
+    }
+
+    Ok(JiraMappingSuggestion {
+        headers: preview.headers,
+        mapping,
+        fields,
+    })
+}
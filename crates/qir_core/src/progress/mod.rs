@@ -0,0 +1,183 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::ValidationWarning;
+use crate::error::AppError;
+
+pub const STAGE_STARTED: &str = "started";
+pub const STAGE_COMPLETED: &str = "completed";
+pub const STAGE_FAILED: &str = "failed";
+
+/// Running totals for row-based operations (imports), so the UI can show more than a percent.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProgressCounts {
+    pub processed: usize,
+    pub inserted: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub conflicts: usize,
+}
+
+/// Shared flag a caller sets to ask a running operation to stop. Operations poll it at safe
+/// points and roll back rather than leaving partial writes.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Envelope shared by every long-running operation. Exactly one of `warning`/`error` is set
+/// on warning and failure events; plain stage events carry neither.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProgressEvent {
+    pub operation_id: String,
+    /// Stable operation name, e.g. "import_jira_csv", "backup_create".
+    pub operation: String,
+    pub stage: String,
+    /// 0..=100 when the operation can estimate it.
+    pub percent: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<ValidationWarning>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<AppError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counts: Option<ProgressCounts>,
+}
+
+/// Receives progress events. Implemented for closures so callers (tests, the Tauri layer)
+/// can forward events without a dedicated type.
+pub trait ProgressSink: Send + Sync {
+    fn emit(&self, event: &ProgressEvent);
+}
+
+impl<F> ProgressSink for F
+where
+    F: Fn(&ProgressEvent) + Send + Sync,
+{
+    fn emit(&self, event: &ProgressEvent) {
+        self(event)
+    }
+}
+
+/// Sink for callers that do not observe progress.
+pub struct NoopProgress;
+
+impl ProgressSink for NoopProgress {
+    fn emit(&self, _event: &ProgressEvent) {}
+}
+
+/// Per-operation reporter. Suppresses repeated (stage, percent) pairs so tight loops can
+/// report on every iteration.
+pub struct Progress<'a> {
+    sink: &'a dyn ProgressSink,
+    operation_id: String,
+    operation: String,
+    last: Option<(String, Option<u8>)>,
+    cancel: Option<CancelToken>,
+}
+
+impl Progress<'static> {
+    /// Reporter that discards events; used by the non-`_with_progress` entry points.
+    pub fn noop(operation: impl Into<String>) -> Self {
+        Progress::new(&NoopProgress, "", operation)
+    }
+}
+
+impl<'a> Progress<'a> {
+    pub fn new(sink: &'a dyn ProgressSink, operation_id: impl Into<String>, operation: impl Into<String>) -> Self {
+        Self {
+            sink,
+            operation_id: operation_id.into(),
+            operation: operation.into(),
+            last: None,
+            cancel: None,
+        }
+    }
+
+    /// Lets the caller stop the operation through `token`.
+    pub fn with_cancel(mut self, token: CancelToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(CancelToken::is_cancelled)
+    }
+
+    pub fn operation_id(&self) -> &str {
+        &self.operation_id
+    }
+
+    fn event(&self, stage: &str, percent: Option<u8>) -> ProgressEvent {
+        ProgressEvent {
+            operation_id: self.operation_id.clone(),
+            operation: self.operation.clone(),
+            stage: stage.to_string(),
+            percent,
+            warning: None,
+            error: None,
+            counts: None,
+        }
+    }
+
+    pub fn stage(&mut self, stage: &str, percent: Option<u8>) {
+        let percent = percent.map(|p| p.min(100));
+        let key = (stage.to_string(), percent);
+        if self.last.as_ref() == Some(&key) {
+            return;
+        }
+        self.sink.emit(&self.event(stage, percent));
+        self.last = Some(key);
+    }
+
+    /// `done` of `total` units within `stage`, scaled into the `[from, to]` percent window.
+    pub fn step(&mut self, stage: &str, done: usize, total: usize, from: u8, to: u8) {
+        let span = to.saturating_sub(from) as usize;
+        // An empty stage (total == 0) is already complete.
+        let pct = (done.min(total) * span)
+            .checked_div(total)
+            .map_or(to, |scaled| from + scaled as u8);
+        self.stage(stage, Some(pct));
+    }
+
+    pub fn warning(&self, stage: &str, warning: &ValidationWarning) {
+        let mut ev = self.event(stage, self.last.as_ref().and_then(|(_, p)| *p));
+        ev.warning = Some(warning.clone());
+        self.sink.emit(&ev);
+    }
+
+    /// Running totals within `stage`; always emitted, so callers decide how often.
+    pub fn counts(&self, stage: &str, counts: ProgressCounts) {
+        let mut ev = self.event(stage, self.last.as_ref().and_then(|(_, p)| *p));
+        ev.counts = Some(counts);
+        self.sink.emit(&ev);
+    }
+
+    /// Emits `started`, runs `f`, then emits `completed` or `failed` (with the error).
+    pub fn run<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T, AppError>) -> Result<T, AppError> {
+        self.stage(STAGE_STARTED, Some(0));
+        let res = f(self);
+        match &res {
+            Ok(_) => self.stage(STAGE_COMPLETED, Some(100)),
+            Err(e) => {
+                let mut ev = self.event(STAGE_FAILED, self.last.as_ref().and_then(|(_, p)| *p));
+                ev.error = Some(e.clone());
+                self.sink.emit(&ev);
+            }
+        }
+        res
+    }
+}
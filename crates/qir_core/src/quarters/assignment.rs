@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    list_quarters, load_fiscal_calendar, parse_utc, FiscalCalendarConfig, Quarter, QuarterScope,
+};
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::repo;
+use crate::settings::{get_setting_as, set_setting};
+
+pub const QUARTER_ASSIGNMENT_ANCHOR_SETTING: &str = "calendar.quarter_assignment_anchor";
+
+/// Which timestamp decides an incident's quarter. `resolve_ts` falls back to the start anchor
+/// for incidents that are not resolved yet, so open incidents are still reviewed somewhere.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuarterAssignmentAnchor {
+    #[default]
+    StartTs,
+    ResolveTs,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuarterAssignmentSummary {
+    pub anchor: QuarterAssignmentAnchor,
+    pub assigned: u32,
+    pub unassigned: u32,
+    /// Rows whose stored `assigned_quarter_label` changed in this refresh.
+    pub changed: u32,
+}
+
+/// An incident whose start and resolution fall in different quarters.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BoundaryIncident {
+    pub incident_id: i64,
+    pub external_id: Option<String>,
+    pub title: String,
+    pub start_ts: String,
+    pub resolve_ts: String,
+    pub start_quarter: String,
+    pub resolve_quarter: String,
+    pub assigned_quarter: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuarterBoundaryReport {
+    pub anchor: QuarterAssignmentAnchor,
+    pub incidents: Vec<BoundaryIncident>,
+}
+
+/// Labels timestamps with the quarters table first, then the workspace calendar.
+pub(crate) struct QuarterAssigner {
+    quarters: Vec<Quarter>,
+    calendar: FiscalCalendarConfig,
+    anchor: QuarterAssignmentAnchor,
+}
+
+impl QuarterAssigner {
+    pub(crate) fn load(conn: &Connection) -> Result<Self, AppError> {
+        Ok(Self {
+            quarters: list_quarters(conn)?,
+            calendar: load_fiscal_calendar(conn)?,
+            anchor: load_quarter_assignment_anchor(conn)?,
+        })
+    }
+
+    pub(super) fn label_for_ts(&self, ts: &str) -> Option<String> {
+        let t = parse_utc(ts)?;
+        let defined = self.quarters.iter().find(|q| {
+            match (parse_utc(&q.start_ts), parse_utc(&q.end_ts)) {
+                (Some(start), Some(end)) => start <= t && t < end,
+                _ => false,
+            }
+        });
+        match defined {
+            Some(q) => Some(q.label.clone()),
+            None => self.calendar.quarter_label(ts),
+        }
+    }
+
+    fn assign(&self, incident: &Incident) -> Option<String> {
+        self.anchor.anchor_ts(incident).and_then(|t| self.label_for_ts(t))
+    }
+
+    /// The quarter the rule assigns to an incident with these timestamps; for incidents that
+    /// are not stored yet or are about to change, whose materialized label is not current.
+    pub(crate) fn label_for_timestamps(
+        &self,
+        start_ts: Option<&str>,
+        first_observed_ts: Option<&str>,
+        resolve_ts: Option<&str>,
+    ) -> Option<String> {
+        self.anchor
+            .anchor_of(start_ts, first_observed_ts, resolve_ts)
+            .and_then(|t| self.label_for_ts(t))
+    }
+}
+
+fn parseable(ts: Option<&str>) -> Option<&str> {
+    ts.filter(|t| parse_utc(t).is_some())
+}
+
+/// start_ts, falling back to first_observed_ts (the usual incident anchor order).
+pub(super) fn start_anchor(incident: &Incident) -> Option<&str> {
+    parseable(incident.start_ts.as_deref()).or_else(|| parseable(incident.first_observed_ts.as_deref()))
+}
+
+pub(super) fn resolve_anchor(incident: &Incident) -> Option<&str> {
+    parseable(incident.resolve_ts.as_deref())
+}
+
+impl QuarterAssignmentAnchor {
+    /// The timestamp this rule places the incident by, if it has a parseable one.
+    pub fn anchor_ts<'a>(&self, incident: &'a Incident) -> Option<&'a str> {
+        self.anchor_of(
+            incident.start_ts.as_deref(),
+            incident.first_observed_ts.as_deref(),
+            incident.resolve_ts.as_deref(),
+        )
+    }
+
+    fn anchor_of<'a>(
+        &self,
+        start_ts: Option<&'a str>,
+        first_observed_ts: Option<&'a str>,
+        resolve_ts: Option<&'a str>,
+    ) -> Option<&'a str> {
+        let start = || parseable(start_ts).or_else(|| parseable(first_observed_ts));
+        match self {
+            Self::StartTs => start(),
+            Self::ResolveTs => parseable(resolve_ts).or_else(start),
+        }
+    }
+}
+
+impl QuarterScope {
+    /// Whether the assignment rule places `incident` inside this scope.
+    pub fn contains_assigned(&self, anchor: QuarterAssignmentAnchor, incident: &Incident) -> bool {
+        anchor.anchor_ts(incident).map(|t| self.contains(t)).unwrap_or(false)
+    }
+}
+
+pub fn load_quarter_assignment_anchor(conn: &Connection) -> Result<QuarterAssignmentAnchor, AppError> {
+    Ok(get_setting_as::<QuarterAssignmentAnchor>(conn, QUARTER_ASSIGNMENT_ANCHOR_SETTING)?
+        .unwrap_or_default())
+}
+
+/// Stores the rule and re-materializes every incident's quarter under it.
+pub fn save_quarter_assignment_anchor(
+    conn: &Connection,
+    anchor: QuarterAssignmentAnchor,
+) -> Result<QuarterAssignmentSummary, AppError> {
+    let value = serde_json::to_value(anchor).map_err(|e| {
+        AppError::new("DB_ENCODE_FAILED", "Failed to encode quarter assignment rule")
+            .with_details(e.to_string())
+    })?;
+    set_setting(conn, QUARTER_ASSIGNMENT_ANCHOR_SETTING, &value)?;
+    refresh_quarter_assignments(conn)
+}
+
+/// Recomputes `incidents.assigned_quarter_label` for all incidents, writing only rows that
+/// changed. Called after imports and whenever quarters, the fiscal calendar or the rule change.
+pub fn refresh_quarter_assignments(conn: &Connection) -> Result<QuarterAssignmentSummary, AppError> {
+    let assigner = QuarterAssigner::load(conn)?;
+    let current = stored_assignments(conn)?;
+    let mut stmt = conn
+        .prepare("UPDATE incidents SET assigned_quarter_label = ?2 WHERE id = ?1")
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to prepare quarter assignment update")
+                .with_details(e.to_string())
+        })?;
+
+    let mut summary = QuarterAssignmentSummary {
+        anchor: assigner.anchor,
+        assigned: 0,
+        unassigned: 0,
+        changed: 0,
+    };
+    for incident in repo::list_incidents(conn)? {
+        let label = assigner.assign(&incident);
+        if label.is_some() {
+            summary.assigned += 1;
+        } else {
+            summary.unassigned += 1;
+        }
+        if current.get(&incident.id) != Some(&label) {
+            stmt.execute(rusqlite::params![incident.id, label]).map_err(|e| {
+                AppError::new("DB_WRITE_FAILED", "Failed to store incident quarter assignment")
+                    .with_details(format!("incident_id={}; err={e}", incident.id))
+            })?;
+            summary.changed += 1;
+        }
+    }
+    Ok(summary)
+}
+
+fn stored_assignments(conn: &Connection) -> Result<HashMap<i64, Option<String>>, AppError> {
+    let mut stmt = conn
+        .prepare("SELECT id, assigned_quarter_label FROM incidents")
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare quarter assignment query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt
+        .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, Option<String>>(1)?)))
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query quarter assignments")
+                .with_details(e.to_string())
+        })?;
+    let mut out = HashMap::new();
+    for r in rows {
+        let (id, label) = r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to read quarter assignment row")
+                .with_details(e.to_string())
+        })?;
+        out.insert(id, label);
+    }
+    Ok(out)
+}
+
+/// Incident ids materialized into `quarter_label`, ascending.
+pub fn assigned_incident_ids(conn: &Connection, quarter_label: &str) -> Result<Vec<i64>, AppError> {
+    let mut stmt = conn
+        .prepare("SELECT id FROM incidents WHERE assigned_quarter_label = ?1 ORDER BY id ASC")
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare assigned incidents query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt.query_map([quarter_label], |row| row.get::<_, i64>(0)).map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query assigned incidents")
+            .with_details(format!("quarter_label={quarter_label}; err={e}"))
+    })?;
+    let mut out = Vec::new();
+    for r in rows {
+        out.push(r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to read assigned incident row")
+                .with_details(e.to_string())
+        })?);
+    }
+    Ok(out)
+}
+
+/// Incidents that started in one quarter and resolved in another, optionally limited to those
+/// touching `quarter_label` on either side. Ordered by start time, then id.
+pub fn quarter_boundary_report(
+    conn: &Connection,
+    quarter_label: Option<&str>,
+) -> Result<QuarterBoundaryReport, AppError> {
+    let assigner = QuarterAssigner::load(conn)?;
+    let mut incidents = Vec::new();
+    for incident in repo::list_incidents(conn)? {
+        let (Some(start), Some(resolve)) =
+            (start_anchor(&incident), resolve_anchor(&incident))
+        else {
+            continue;
+        };
+        let (Some(start_quarter), Some(resolve_quarter)) =
+            (assigner.label_for_ts(start), assigner.label_for_ts(resolve))
+        else {
+            continue;
+        };
+        if start_quarter == resolve_quarter {
+            continue;
+        }
+        if let Some(q) = quarter_label {
+            if start_quarter != q && resolve_quarter != q {
+                continue;
+            }
+        }
+        let assigned_quarter = match assigner.anchor {
+            QuarterAssignmentAnchor::StartTs => start_quarter.clone(),
+            QuarterAssignmentAnchor::ResolveTs => resolve_quarter.clone(),
+        };
+        incidents.push(BoundaryIncident {
+            incident_id: incident.id,
+            external_id: incident.external_id.clone(),
+            title: incident.title.clone(),
+            start_ts: start.to_string(),
+            resolve_ts: resolve.to_string(),
+            start_quarter,
+            resolve_quarter,
+            assigned_quarter,
+        });
+    }
+    incidents.sort_by(|a, b| {
+        parse_utc(&a.start_ts)
+            .cmp(&parse_utc(&b.start_ts))
+            .then(a.incident_id.cmp(&b.incident_id))
+    });
+    Ok(QuarterBoundaryReport {
+        anchor: assigner.anchor,
+        incidents,
+    })
+}
@@ -0,0 +1,142 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use super::assignment::{resolve_anchor, start_anchor, QuarterAssigner};
+use super::{parse_utc, resolve_quarter_scope};
+use crate::action_items::list_action_items;
+use crate::error::AppError;
+use crate::repo;
+
+/// An incident still unresolved at a quarter boundary.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CarryOverIncident {
+    pub incident_id: i64,
+    pub external_id: Option<String>,
+    pub title: String,
+    pub start_ts: String,
+    pub resolve_ts: Option<String>,
+    /// Quarter the incident started in.
+    pub origin_quarter: Option<String>,
+    /// Whole days open at the quarter end (or at resolution, if it resolved inside the quarter).
+    pub age_days: i64,
+}
+
+/// An action item raised before the quarter that was still open when it began.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CarriedActionItem {
+    pub action_item_id: i64,
+    pub incident_id: i64,
+    pub title: String,
+    pub owner: Option<String>,
+    pub created_at: String,
+    pub closed_at: Option<String>,
+    /// Quarter the item was raised in.
+    pub origin_quarter: Option<String>,
+    /// Whole days open at the quarter end (or at closure, if it closed inside the quarter).
+    pub age_days: i64,
+    pub open_at_quarter_end: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuarterCarryOver {
+    pub quarter_label: String,
+    pub start_ts: String,
+    pub end_ts: String,
+    /// Started before the quarter and unresolved at its start.
+    pub open_at_start: Vec<CarryOverIncident>,
+    /// Started before the quarter end and unresolved at its end.
+    pub open_at_end: Vec<CarryOverIncident>,
+    pub carried_action_items: Vec<CarriedActionItem>,
+}
+
+/// Unresolved/unclosed means no timestamp yet, or one at or after the boundary.
+fn open_at(opened: OffsetDateTime, closed: Option<OffsetDateTime>, boundary: OffsetDateTime) -> bool {
+    opened < boundary && closed.map(|c| c >= boundary).unwrap_or(true)
+}
+
+fn age_days(opened: OffsetDateTime, closed: Option<OffsetDateTime>, end: OffsetDateTime) -> i64 {
+    let until = closed.filter(|c| *c < end).unwrap_or(end);
+    (until - opened).whole_days().max(0)
+}
+
+/// What is still dragging on in `quarter_label`: incidents open at either boundary and action
+/// items carried in from earlier quarters, oldest first.
+pub fn quarter_carry_over(conn: &Connection, quarter_label: &str) -> Result<QuarterCarryOver, AppError> {
+    let scope = resolve_quarter_scope(conn, quarter_label)?;
+    let (Some(start), Some(end)) = (parse_utc(&scope.start_ts), parse_utc(&scope.end_ts)) else {
+        return Err(AppError::new("DB_QUERY_FAILED", "Stored quarter bounds are not RFC3339")
+            .with_details(format!("label={quarter_label}")));
+    };
+    let assigner = QuarterAssigner::load(conn)?;
+
+    let mut open_at_start = Vec::new();
+    let mut open_at_end = Vec::new();
+    let incidents = repo::list_incidents(conn)?;
+    for incident in &incidents {
+        let Some(opened_ts) = start_anchor(incident) else {
+            continue;
+        };
+        let Some(opened) = parse_utc(opened_ts) else {
+            continue;
+        };
+        let resolved = resolve_anchor(incident).and_then(parse_utc);
+        let item = || CarryOverIncident {
+            incident_id: incident.id,
+            external_id: incident.external_id.clone(),
+            title: incident.title.clone(),
+            start_ts: opened_ts.to_string(),
+            resolve_ts: incident.resolve_ts.clone(),
+            origin_quarter: assigner.label_for_ts(opened_ts),
+            age_days: age_days(opened, resolved, end),
+        };
+        if open_at(opened, resolved, start) {
+            open_at_start.push(item());
+        }
+        if open_at(opened, resolved, end) {
+            open_at_end.push(item());
+        }
+    }
+
+    let mut carried_action_items = Vec::new();
+    for ai in list_action_items(conn, None, true)? {
+        let Some(opened) = parse_utc(&ai.created_at) else {
+            continue;
+        };
+        let closed = ai.closed_at.as_deref().and_then(parse_utc);
+        if !open_at(opened, closed, start) {
+            continue;
+        }
+        carried_action_items.push(CarriedActionItem {
+            origin_quarter: assigner.label_for_ts(&ai.created_at),
+            age_days: age_days(opened, closed, end),
+            open_at_quarter_end: open_at(opened, closed, end),
+            action_item_id: ai.id,
+            incident_id: ai.incident_id,
+            title: ai.title,
+            owner: ai.owner,
+            created_at: ai.created_at,
+            closed_at: ai.closed_at,
+        });
+    }
+
+    let oldest_first = |a: &CarryOverIncident, b: &CarryOverIncident| {
+        b.age_days.cmp(&a.age_days).then(a.incident_id.cmp(&b.incident_id))
+    };
+    open_at_start.sort_by(oldest_first);
+    open_at_end.sort_by(oldest_first);
+    carried_action_items.sort_by(|a, b| {
+        b.age_days
+            .cmp(&a.age_days)
+            .then(a.action_item_id.cmp(&b.action_item_id))
+    });
+
+    Ok(QuarterCarryOver {
+        quarter_label: scope.label,
+        start_ts: scope.start_ts,
+        end_ts: scope.end_ts,
+        open_at_start,
+        open_at_end,
+        carried_action_items,
+    })
+}
@@ -0,0 +1,141 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use super::{calendar_quarter_bounds, month_start, QuarterInput};
+use crate::error::AppError;
+use crate::freeze::calendar_quarter_label;
+use crate::settings::{get_setting_as, set_setting};
+
+pub const FISCAL_YEAR_START_MONTH_SETTING: &str = "calendar.fiscal_year_start_month";
+
+/// Workspace fiscal calendar. A start month of 1 is the calendar year and keeps the
+/// "Q1 2026" labels; any other month yields "FY2027 Q1" labels, where the fiscal year is
+/// named after the calendar year it ends in (Feb 2026 - Jan 2027 is FY2027).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FiscalCalendarConfig {
+    pub fiscal_year_start_month: u8,
+}
+
+impl Default for FiscalCalendarConfig {
+    fn default() -> Self {
+        Self {
+            fiscal_year_start_month: 1,
+        }
+    }
+}
+
+impl FiscalCalendarConfig {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if !(1..=12).contains(&self.fiscal_year_start_month) {
+            return Err(AppError::new(
+                "VALIDATION_FISCAL_CALENDAR_INVALID",
+                "Fiscal year start month must be between 1 and 12",
+            )
+            .with_details(format!("fiscal_year_start_month={}", self.fiscal_year_start_month)));
+        }
+        Ok(())
+    }
+
+    pub fn is_calendar_year(&self) -> bool {
+        self.fiscal_year_start_month == 1
+    }
+
+    /// Value stored in `quarters.fiscal_offset_months` for quarters of this calendar.
+    pub fn offset_months(&self) -> u8 {
+        self.fiscal_year_start_month.saturating_sub(1)
+    }
+
+    /// Quarter label ("Q1 2026" or "FY2027 Q1") for an RFC3339 timestamp, evaluated in UTC.
+    pub fn quarter_label(&self, ts: &str) -> Option<String> {
+        if self.is_calendar_year() {
+            return calendar_quarter_label(ts);
+        }
+        let dt = OffsetDateTime::parse(ts.trim(), &Rfc3339).ok()?.to_offset(UtcOffset::UTC);
+        let month = u8::from(dt.month());
+        let start = self.fiscal_year_start_month;
+        let fy_start_year = if month >= start { dt.year() } else { dt.year() - 1 };
+        let months_into_fy = (i32::from(month) - i32::from(start)).rem_euclid(12);
+        Some(format!("FY{} Q{}", fy_start_year + 1, months_into_fy / 3 + 1))
+    }
+
+    /// Bounds of a label under this calendar. Calendar labels always resolve; fiscal labels
+    /// only when a fiscal start month is configured.
+    pub fn quarter_bounds(&self, label: &str) -> Option<(String, String)> {
+        if let Some(bounds) = calendar_quarter_bounds(label) {
+            return Some(bounds);
+        }
+        if self.is_calendar_year() {
+            return None;
+        }
+        let (fy, q) = label.trim().split_once(' ')?;
+        let fy = fy.strip_prefix("FY").filter(|y| y.len() == 4)?;
+        let fy: i32 = fy.parse().ok()?;
+        let q: i32 = match q {
+            "Q1" => 1,
+            "Q2" => 2,
+            "Q3" => 3,
+            "Q4" => 4,
+            _ => return None,
+        };
+        let first = i32::from(self.offset_months()) + (q - 1) * 3;
+        Some((month_start(fy - 1, first)?, month_start(fy - 1, first + 3)?))
+    }
+
+    /// The quarter an incident is assigned to: start_ts, falling back to first_observed_ts
+    /// (the anchor order of `incident_quarter_label`).
+    pub fn incident_quarter_label(&self, start_ts: Option<&str>, first_observed_ts: Option<&str>) -> Option<String> {
+        start_ts
+            .and_then(|t| self.quarter_label(t))
+            .or_else(|| first_observed_ts.and_then(|t| self.quarter_label(t)))
+    }
+
+    /// Prefilled input for defining `label` in the quarters table.
+    pub fn default_quarter_input(&self, label: &str) -> Option<QuarterInput> {
+        let (start_ts, end_ts) = self.quarter_bounds(label)?;
+        let fiscal_offset_months = if calendar_quarter_bounds(label).is_some() {
+            0
+        } else {
+            self.offset_months()
+        };
+        Some(QuarterInput {
+            label: label.trim().to_string(),
+            start_ts,
+            end_ts,
+            fiscal_offset_months,
+        })
+    }
+}
+
+/// Reads `calendar.fiscal_year_start_month` over the calendar-year default.
+pub fn load_fiscal_calendar(conn: &Connection) -> Result<FiscalCalendarConfig, AppError> {
+    let mut cfg = FiscalCalendarConfig::default();
+    if let Some(m) = get_setting_as::<u8>(conn, FISCAL_YEAR_START_MONTH_SETTING)? {
+        cfg.fiscal_year_start_month = m;
+    }
+    cfg.validate()?;
+    Ok(cfg)
+}
+
+/// Stores the calendar and re-materializes incident quarter assignments under it.
+pub fn save_fiscal_calendar(conn: &Connection, cfg: &FiscalCalendarConfig) -> Result<(), AppError> {
+    cfg.validate()?;
+    set_setting(
+        conn,
+        FISCAL_YEAR_START_MONTH_SETTING,
+        &serde_json::json!(cfg.fiscal_year_start_month),
+    )?;
+    super::refresh_quarter_assignments(conn).map(|_| ())
+}
+
+/// Prefilled quarters-table input for `label` under the workspace fiscal calendar.
+pub fn default_quarter_input(conn: &Connection, label: &str) -> Result<QuarterInput, AppError> {
+    load_fiscal_calendar(conn)?.default_quarter_input(label).ok_or_else(|| {
+        AppError::new(
+            "QUARTER_NOT_FOUND",
+            "Quarter label does not match the workspace calendar",
+        )
+        .with_details(format!("label={label}"))
+    })
+}
@@ -0,0 +1,289 @@
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{Date, Month, OffsetDateTime, UtcOffset};
+
+use crate::error::AppError;
+use crate::freeze::{get_quarter_freeze, validate_quarter_label};
+
+pub mod assignment;
+pub mod carryover;
+pub mod fiscal;
+pub use assignment::{
+    assigned_incident_ids, load_quarter_assignment_anchor, quarter_boundary_report,
+    refresh_quarter_assignments, save_quarter_assignment_anchor, BoundaryIncident,
+    QuarterAssignmentAnchor, QuarterAssignmentSummary, QuarterBoundaryReport,
+    QUARTER_ASSIGNMENT_ANCHOR_SETTING,
+};
+pub use carryover::{quarter_carry_over, CarriedActionItem, CarryOverIncident, QuarterCarryOver};
+pub use fiscal::{
+    default_quarter_input, load_fiscal_calendar, save_fiscal_calendar, FiscalCalendarConfig,
+    FISCAL_YEAR_START_MONTH_SETTING,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Quarter {
+    pub label: String,
+    /// Inclusive, canonical RFC3339 UTC.
+    pub start_ts: String,
+    /// Exclusive, canonical RFC3339 UTC.
+    pub end_ts: String,
+    /// Months the fiscal year is shifted from the calendar year (0 = calendar quarters).
+    pub fiscal_offset_months: u8,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuarterInput {
+    pub label: String,
+    pub start_ts: String,
+    pub end_ts: String,
+    #[serde(default)]
+    pub fiscal_offset_months: u8,
+}
+
+/// Time window a quarter label resolves to. `defined=false` means no `quarters` row exists
+/// and the bounds come from the workspace calendar (calendar or fiscal quarter of the label).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuarterScope {
+    pub label: String,
+    pub start_ts: String,
+    pub end_ts: String,
+    pub defined: bool,
+}
+
+fn parse_utc(ts: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(ts.trim(), &Rfc3339)
+        .ok()
+        .map(|dt| dt.to_offset(UtcOffset::UTC))
+}
+
+impl QuarterScope {
+    pub fn contains(&self, ts: &str) -> bool {
+        match (parse_utc(ts), parse_utc(&self.start_ts), parse_utc(&self.end_ts)) {
+            (Some(t), Some(start), Some(end)) => start <= t && t < end,
+            _ => false,
+        }
+    }
+
+    /// Incidents are anchored on start_ts, falling back to first_observed_ts (the same anchor
+    /// order as `incident_quarter_label`).
+    pub fn contains_incident(&self, start_ts: Option<&str>, first_observed_ts: Option<&str>) -> bool {
+        start_ts
+            .filter(|t| parse_utc(t).is_some())
+            .or(first_observed_ts)
+            .map(|t| self.contains(t))
+            .unwrap_or(false)
+    }
+}
+
+pub(crate) fn month_start(year: i32, month0: i32) -> Option<String> {
+    let year = year + month0.div_euclid(12);
+    let month = Month::try_from((month0.rem_euclid(12) + 1) as u8).ok()?;
+    let date = Date::from_calendar_date(year, month, 1).ok()?;
+    date.midnight().assume_utc().format(&Rfc3339).ok()
+}
+
+/// Bounds of a calendar label ("Q1 2026"), or None if the label is not calendar-shaped.
+pub fn calendar_quarter_bounds(label: &str) -> Option<(String, String)> {
+    validate_quarter_label(label).ok()?;
+    let (q, year) = label.split_once(' ')?;
+    let q: i32 = q.trim_start_matches('Q').parse().ok()?;
+    let year: i32 = year.parse().ok()?;
+    let first = (q - 1) * 3;
+    Some((month_start(year, first)?, month_start(year, first + 3)?))
+}
+
+fn invalid(message: &str, details: String) -> AppError {
+    AppError::new("VALIDATION_QUARTER_INVALID", message).with_details(details)
+}
+
+/// Validates and canonicalizes an input; bounds are stored as RFC3339 UTC.
+fn normalize_input(input: &QuarterInput) -> Result<(String, String, String), AppError> {
+    let label = input.label.trim().to_string();
+    if label.is_empty() || label.len() > 64 {
+        return Err(invalid("Quarter label must be 1-64 characters", format!("label={}", input.label)));
+    }
+    let start = parse_utc(&input.start_ts)
+        .ok_or_else(|| invalid("Quarter start must be an RFC3339 timestamp", format!("start_ts={}", input.start_ts)))?;
+    let end = parse_utc(&input.end_ts)
+        .ok_or_else(|| invalid("Quarter end must be an RFC3339 timestamp", format!("end_ts={}", input.end_ts)))?;
+    if start >= end {
+        return Err(invalid(
+            "Quarter start must be before its end",
+            format!("start_ts={}; end_ts={}", input.start_ts, input.end_ts),
+        ));
+    }
+    if input.fiscal_offset_months > 11 {
+        return Err(invalid(
+            "Fiscal offset must be between 0 and 11 months",
+            format!("fiscal_offset_months={}", input.fiscal_offset_months),
+        ));
+    }
+    // Whole seconds keep stored bounds the same shape, so they order lexicographically.
+    let fmt = |dt: OffsetDateTime| {
+        dt.replace_nanosecond(0)
+            .unwrap_or(dt)
+            .format(&Rfc3339)
+            .map_err(|e| invalid("Failed to format quarter bound", e.to_string()))
+    };
+    Ok((label, fmt(start)?, fmt(end)?))
+}
+
+fn map_row(row: &rusqlite::Row<'_>) -> Result<Quarter, rusqlite::Error> {
+    Ok(Quarter {
+        label: row.get(0)?,
+        start_ts: row.get(1)?,
+        end_ts: row.get(2)?,
+        fiscal_offset_months: row.get(3)?,
+        created_at: row.get(4)?,
+        updated_at: row.get(5)?,
+    })
+}
+
+pub fn get_quarter(conn: &Connection, label: &str) -> Result<Option<Quarter>, AppError> {
+    conn.query_row(
+        "SELECT label, start_ts, end_ts, fiscal_offset_months, created_at, updated_at FROM quarters WHERE label = ?1",
+        [label],
+        map_row,
+    )
+    .optional()
+    .map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query quarter")
+            .with_details(format!("label={label}; err={e}"))
+    })
+}
+
+/// Defined quarters in chronological order.
+pub fn list_quarters(conn: &Connection) -> Result<Vec<Quarter>, AppError> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT label, start_ts, end_ts, fiscal_offset_months, created_at, updated_at FROM quarters ORDER BY start_ts ASC, label ASC",
+        )
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare quarter list query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt.query_map([], map_row).map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query quarters").with_details(e.to_string())
+    })?;
+    let mut out = Vec::new();
+    for r in rows {
+        out.push(r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode quarter row").with_details(e.to_string())
+        })?);
+    }
+    Ok(out)
+}
+
+fn ensure_no_overlap(conn: &Connection, label: &str, start: &str, end: &str) -> Result<(), AppError> {
+    let clash: Option<String> = conn
+        .query_row(
+            "SELECT label FROM quarters WHERE label <> ?1 AND start_ts < ?3 AND ?2 < end_ts ORDER BY start_ts LIMIT 1",
+            params![label, start, end],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to check quarter overlap").with_details(e.to_string())
+        })?;
+    match clash {
+        Some(other) => Err(AppError::new("QUARTER_OVERLAP", "Quarter overlaps an existing quarter")
+            .with_details(format!("label={label}; overlaps={other}"))),
+        None => Ok(()),
+    }
+}
+
+pub fn create_quarter(conn: &Connection, input: &QuarterInput) -> Result<Quarter, AppError> {
+    let (label, start, end) = normalize_input(input)?;
+    if get_quarter(conn, &label)?.is_some() {
+        return Err(AppError::new("QUARTER_ALREADY_EXISTS", "A quarter with this label already exists")
+            .with_details(format!("label={label}")));
+    }
+    ensure_no_overlap(conn, &label, &start, &end)?;
+    conn.execute(
+        "INSERT INTO quarters(label, start_ts, end_ts, fiscal_offset_months, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, strftime('%Y-%m-%dT%H:%M:%fZ','now'), strftime('%Y-%m-%dT%H:%M:%fZ','now'))",
+        params![label, start, end, input.fiscal_offset_months],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to create quarter")
+            .with_details(format!("label={label}; err={e}"))
+    })?;
+    refresh_quarter_assignments(conn)?;
+    require_quarter(conn, &label)
+}
+
+/// Updates bounds/offset of an existing quarter. The label is the key and cannot change.
+pub fn update_quarter(conn: &Connection, input: &QuarterInput) -> Result<Quarter, AppError> {
+    let (label, start, end) = normalize_input(input)?;
+    require_quarter(conn, &label)?;
+    if get_quarter_freeze(conn, &label)?.is_some() {
+        return Err(crate::freeze::quarter_frozen_error(&label, "action=update_quarter"));
+    }
+    ensure_no_overlap(conn, &label, &start, &end)?;
+    conn.execute(
+        "UPDATE quarters SET start_ts = ?2, end_ts = ?3, fiscal_offset_months = ?4,
+         updated_at = strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE label = ?1",
+        params![label, start, end, input.fiscal_offset_months],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to update quarter")
+            .with_details(format!("label={label}; err={e}"))
+    })?;
+    refresh_quarter_assignments(conn)?;
+    require_quarter(conn, &label)
+}
+
+pub fn delete_quarter(conn: &Connection, label: &str) -> Result<(), AppError> {
+    require_quarter(conn, label)?;
+    if get_quarter_freeze(conn, label)?.is_some() {
+        return Err(crate::freeze::quarter_frozen_error(label, "action=delete_quarter"));
+    }
+    conn.execute("DELETE FROM quarters WHERE label = ?1", [label])
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to delete quarter")
+                .with_details(format!("label={label}; err={e}"))
+        })?;
+    refresh_quarter_assignments(conn)?;
+    Ok(())
+}
+
+fn require_quarter(conn: &Connection, label: &str) -> Result<Quarter, AppError> {
+    get_quarter(conn, label)?.ok_or_else(|| {
+        AppError::new("QUARTER_NOT_FOUND", "Quarter is not defined").with_details(format!("label={label}"))
+    })
+}
+
+/// Resolves a label into its time window: a defined quarter wins, otherwise calendar labels
+/// ("Q1 2026") and, with a fiscal calendar configured, fiscal labels ("FY2027 Q1") resolve
+/// implicitly. Anything else is `QUARTER_NOT_FOUND`.
+pub fn resolve_quarter_scope(conn: &Connection, label: &str) -> Result<QuarterScope, AppError> {
+    if let Some(q) = get_quarter(conn, label)? {
+        return Ok(QuarterScope {
+            label: q.label,
+            start_ts: q.start_ts,
+            end_ts: q.end_ts,
+            defined: true,
+        });
+    }
+    match load_fiscal_calendar(conn)?.quarter_bounds(label) {
+        Some((start_ts, end_ts)) => Ok(QuarterScope {
+            label: label.to_string(),
+            start_ts,
+            end_ts,
+            defined: false,
+        }),
+        None => Err(AppError::new(
+            "QUARTER_NOT_FOUND",
+            "Quarter label is neither defined nor a quarter of the workspace calendar",
+        )
+        .with_details(format!("label={label}"))),
+    }
+}
+
+/// Validation hook for anything that stores a quarter label reference.
+pub fn ensure_quarter_exists(conn: &Connection, label: &str) -> Result<(), AppError> {
+    resolve_quarter_scope(conn, label).map(|_| ())
+}
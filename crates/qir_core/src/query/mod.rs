@@ -0,0 +1,275 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, ErrorCode, OpenFlags};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+pub const QUERY_DEFAULT_MAX_ROWS: u32 = 1_000;
+pub const QUERY_MAX_ROWS_LIMIT: u32 = 10_000;
+pub const QUERY_DEFAULT_MAX_BYTES: u64 = 1024 * 1024;
+pub const QUERY_MAX_BYTES_LIMIT: u64 = 16 * 1024 * 1024;
+pub const QUERY_DEFAULT_TIMEOUT_MS: u64 = 5_000;
+pub const QUERY_MAX_TIMEOUT_MS: u64 = 60_000;
+/// SQLite VM instructions between deadline checks.
+const QUERY_PROGRESS_STEPS: i32 = 1_000;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QueryLimits {
+    pub max_rows: u32,
+    /// Approximate cap on result payload size (sum of cell sizes).
+    pub max_bytes: u64,
+    /// Wall-clock budget for preparing and running the query; past it the query is interrupted.
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+fn default_timeout_ms() -> u64 {
+    QUERY_DEFAULT_TIMEOUT_MS
+}
+
+impl Default for QueryLimits {
+    fn default() -> Self {
+        Self {
+            max_rows: QUERY_DEFAULT_MAX_ROWS,
+            max_bytes: QUERY_DEFAULT_MAX_BYTES,
+            timeout_ms: QUERY_DEFAULT_TIMEOUT_MS,
+        }
+    }
+}
+
+impl QueryLimits {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.max_rows == 0 || self.max_rows > QUERY_MAX_ROWS_LIMIT {
+            return Err(AppError::new(
+                "VALIDATION_QUERY_LIMIT_INVALID",
+                format!("max_rows must be between 1 and {QUERY_MAX_ROWS_LIMIT}"),
+            )
+            .with_details(format!("max_rows={}", self.max_rows)));
+        }
+        if self.max_bytes == 0 || self.max_bytes > QUERY_MAX_BYTES_LIMIT {
+            return Err(AppError::new(
+                "VALIDATION_QUERY_LIMIT_INVALID",
+                format!("max_bytes must be between 1 and {QUERY_MAX_BYTES_LIMIT}"),
+            )
+            .with_details(format!("max_bytes={}", self.max_bytes)));
+        }
+        if self.timeout_ms == 0 || self.timeout_ms > QUERY_MAX_TIMEOUT_MS {
+            return Err(AppError::new(
+                "VALIDATION_QUERY_LIMIT_INVALID",
+                format!("timeout_ms must be between 1 and {QUERY_MAX_TIMEOUT_MS}"),
+            )
+            .with_details(format!("timeout_ms={}", self.timeout_ms)));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QueryResult {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<serde_json::Value>>,
+    pub row_count: u32,
+    pub bytes: u64,
+    /// True when max_rows or max_bytes stopped the scan early.
+    pub truncated: bool,
+}
+
+/// Opens an empty in-memory connection with the workspace DB attached read-only (`mode=ro`)
+/// and `query_only` enabled. Unqualified table names resolve to the attached workspace, so
+/// user SQL reads naturally while no write can reach the workspace file.
+pub fn open_readonly_query_connection(db_path: &Path) -> Result<Connection, AppError> {
+    if !db_path.is_file() {
+        return Err(AppError::new(
+            "WORKSPACE_DB_NOT_FOUND",
+            "Workspace database file not found",
+        )
+        .with_details(db_path.display().to_string()));
+    }
+    let conn = Connection::open_with_flags(
+        ":memory:",
+        OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE | OpenFlags::SQLITE_OPEN_URI,
+    )
+    .map_err(|e| {
+        AppError::new("DB_OPEN_FAILED", "Failed to open query connection")
+            .with_details(e.to_string())
+    })?;
+
+    let mut uri = String::from("file:");
+    for c in db_path.to_string_lossy().chars() {
+        match c {
+            '%' => uri.push_str("%25"),
+            '?' => uri.push_str("%3f"),
+            '#' => uri.push_str("%23"),
+            _ => uri.push(c),
+        }
+    }
+    uri.push_str("?mode=ro");
+    conn.execute("ATTACH DATABASE ?1 AS ws", [&uri]).map_err(|e| {
+        AppError::new("DB_OPEN_FAILED", "Failed to attach workspace read-only")
+            .with_details(format!("path={}; err={}", db_path.display(), e))
+    })?;
+    conn.execute_batch("PRAGMA query_only = ON;").map_err(|e| {
+        AppError::new("DB_OPEN_FAILED", "Failed to enable query_only mode")
+            .with_details(e.to_string())
+    })?;
+    Ok(conn)
+}
+
+fn rejected(reason: &str, sql: &str) -> AppError {
+    AppError::new("QUERY_STATEMENT_REJECTED", format!("Query rejected: {reason}"))
+        .with_details(format!("sql={}", sql.chars().take(200).collect::<String>()))
+}
+
+/// `sql` with the contents of string literals and quoted identifiers ('...', "...", `...`,
+/// [...]) blanked out, so the lexical checks only see SQL syntax. An unterminated quote blanks
+/// the rest; preparing the statement rejects it.
+fn outside_quotes(sql: &str) -> String {
+    let mut out = String::with_capacity(sql.len());
+    let mut close: Option<char> = None;
+    for c in sql.chars() {
+        match close {
+            // A doubled quote ('it''s') closes and reopens, which blanks the same way.
+            Some(q) if c == q => {
+                close = None;
+                out.push(c);
+            }
+            Some(_) => out.push(' '),
+            None => {
+                close = match c {
+                    '\'' | '"' | '`' => Some(c),
+                    '[' => Some(']'),
+                    _ => None,
+                };
+                out.push(c);
+            }
+        }
+    }
+    out
+}
+
+/// Only a single SELECT (optionally starting with WITH) is accepted. This is checked
+/// lexically first (quoted text is ignored) and then confirmed with `sqlite3_stmt_readonly`
+/// after preparing.
+fn check_statement(sql: &str) -> Result<&str, AppError> {
+    let trimmed = sql.trim();
+    let body = trimmed.strip_suffix(';').unwrap_or(trimmed).trim_end();
+    if body.is_empty() {
+        return Err(rejected("empty statement", sql));
+    }
+    let syntax = outside_quotes(body);
+    if syntax.contains(';') {
+        return Err(rejected("only a single statement is allowed", sql));
+    }
+    if syntax.contains("--") || syntax.contains("/*") {
+        return Err(rejected("comments are not allowed", sql));
+    }
+    let first = body
+        .split(|c: char| c.is_whitespace() || c == '(')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_uppercase();
+    if first != "SELECT" && first != "WITH" {
+        return Err(rejected("only SELECT statements are allowed", sql));
+    }
+    Ok(body)
+}
+
+fn cell_to_json(v: ValueRef<'_>) -> (serde_json::Value, u64) {
+    match v {
+        ValueRef::Null => (serde_json::Value::Null, 4),
+        ValueRef::Integer(i) => (serde_json::Value::from(i), 8),
+        ValueRef::Real(f) => (
+            serde_json::Number::from_f64(f)
+                .map(serde_json::Value::Number)
+                .unwrap_or(serde_json::Value::Null),
+            8,
+        ),
+        ValueRef::Text(t) => {
+            let s = String::from_utf8_lossy(t).to_string();
+            let n = s.len() as u64;
+            (serde_json::Value::String(s), n)
+        }
+        ValueRef::Blob(b) => (serde_json::Value::String(hex::encode(b)), (b.len() * 2) as u64),
+    }
+}
+
+fn execute_failed(message: &str, e: rusqlite::Error, limits: &QueryLimits) -> AppError {
+    match e {
+        rusqlite::Error::SqliteFailure(ref f, _) if f.code == ErrorCode::OperationInterrupted => {
+            AppError::new("QUERY_TIMEOUT", "Query stopped after exceeding its time limit")
+                .with_details(format!("timeout_ms={}", limits.timeout_ms))
+        }
+        e => AppError::new("QUERY_EXECUTE_FAILED", message).with_details(e.to_string()),
+    }
+}
+
+/// Runs a guarded ad-hoc SELECT. Pair with `open_readonly_query_connection`; the statement
+/// checks here are defense in depth, not the only barrier. A progress handler interrupts the
+/// query once `limits.timeout_ms` has passed (`QUERY_TIMEOUT`).
+pub fn run_readonly_query(conn: &Connection, sql: &str, limits: &QueryLimits) -> Result<QueryResult, AppError> {
+    limits.validate()?;
+    let body = check_statement(sql)?;
+
+    let deadline = Instant::now() + Duration::from_millis(limits.timeout_ms);
+    conn.progress_handler(QUERY_PROGRESS_STEPS, Some(move || Instant::now() >= deadline));
+    let result = read_rows(conn, sql, body, limits);
+    conn.progress_handler(0, None::<fn() -> bool>);
+    result
+}
+
+fn read_rows(conn: &Connection, sql: &str, body: &str, limits: &QueryLimits) -> Result<QueryResult, AppError> {
+    let mut stmt = conn.prepare(body).map_err(|e| {
+        AppError::new("QUERY_PREPARE_FAILED", "Failed to prepare query")
+            .with_details(e.to_string())
+    })?;
+    if !stmt.readonly() {
+        return Err(rejected("statement would modify the database", sql));
+    }
+    let columns: Vec<String> = stmt.column_names().into_iter().map(|s| s.to_string()).collect();
+    let column_count = columns.len();
+
+    let mut rows_iter = stmt
+        .query([])
+        .map_err(|e| execute_failed("Failed to execute query", e, limits))?;
+    let mut rows = Vec::new();
+    let mut bytes: u64 = 0;
+    let mut truncated = false;
+    loop {
+        let row = rows_iter
+            .next()
+            .map_err(|e| execute_failed("Failed to read query row", e, limits))?;
+        let Some(row) = row else { break };
+        if rows.len() as u32 >= limits.max_rows {
+            truncated = true;
+            break;
+        }
+        let mut out_row = Vec::with_capacity(column_count);
+        let mut row_bytes = 0u64;
+        for idx in 0..column_count {
+            let v = row.get_ref(idx).map_err(|e| {
+                AppError::new("QUERY_EXECUTE_FAILED", "Failed to decode query cell")
+                    .with_details(format!("column={idx}; err={e}"))
+            })?;
+            let (json, n) = cell_to_json(v);
+            row_bytes += n;
+            out_row.push(json);
+        }
+        if bytes + row_bytes > limits.max_bytes {
+            truncated = true;
+            break;
+        }
+        bytes += row_bytes;
+        rows.push(out_row);
+    }
+
+    Ok(QueryResult {
+        columns,
+        row_count: rows.len() as u32,
+        rows,
+        bytes,
+        truncated,
+    })
+}
@@ -0,0 +1,354 @@
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection};
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::domain::Incident;
+use crate::error::AppError;
+
+use super::{get_incident, list_incidents};
+
+/// Titles must be at least this similar (bigram Dice coefficient) to be considered.
+pub const DUPLICATE_MIN_TITLE_SIMILARITY: f64 = 0.6;
+
+/// Windows further apart than this are never duplicates, however similar the titles.
+pub const DUPLICATE_MAX_WINDOW_GAP_SECONDS: i64 = 2 * 60 * 60;
+
+/// Weight of title similarity in the score; the rest is time proximity.
+const TITLE_WEIGHT: f64 = 0.7;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DuplicateCandidate {
+    /// The earlier-starting incident (lower id on ties).
+    pub incident_id: i64,
+    pub other_incident_id: i64,
+    pub title: String,
+    pub other_title: String,
+    /// Bigram Dice coefficient of the normalized titles, rounded to two decimals.
+    pub title_similarity: f64,
+    /// Seconds between the end of the earlier window and the start of the later; 0 when the
+    /// windows overlap or touch.
+    pub window_gap_seconds: i64,
+    /// 0.7 x title similarity + 0.3 x time proximity, rounded to two decimals.
+    pub score: f64,
+}
+
+struct Window<'a> {
+    incident: &'a Incident,
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    bigrams: Vec<(char, char)>,
+}
+
+fn parse(ts: &Option<String>) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(ts.as_deref()?, &Rfc3339).ok()
+}
+
+/// Sorted character bigrams of the lowercased title with punctuation folded to single spaces.
+fn title_bigrams(title: &str) -> Vec<(char, char)> {
+    let normalized: String = title
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let chars: Vec<char> = normalized.chars().collect();
+    let mut bigrams: Vec<(char, char)> = chars.windows(2).map(|w| (w[0], w[1])).collect();
+    bigrams.sort_unstable();
+    bigrams
+}
+
+/// Sorensen-Dice over bigram multisets: 2 x shared / (len a + len b).
+fn dice(a: &[(char, char)], b: &[(char, char)]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+    let (mut i, mut j, mut shared) = (0, 0, 0usize);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                shared += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    (2 * shared) as f64 / (a.len() + b.len()) as f64
+}
+
+/// Window: start (or first observed) to resolve (or mitigate); a point at the start when the
+/// incident has no end yet. Incidents with no start are not compared.
+fn window(incident: &Incident) -> Option<Window<'_>> {
+    let start = parse(&incident.start_ts).or_else(|| parse(&incident.first_observed_ts))?;
+    let end = parse(&incident.resolve_ts).or_else(|| parse(&incident.mitigate_ts)).unwrap_or(start);
+    Some(Window {
+        incident,
+        start,
+        end: end.max(start),
+        bigrams: title_bigrams(&incident.title),
+    })
+}
+
+fn round2(v: f64) -> f64 {
+    (v * 100.0).round() / 100.0
+}
+
+/// Pairs with similar titles whose windows overlap or lie within
+/// `DUPLICATE_MAX_WINDOW_GAP_SECONDS` of each other, highest score first (then by ids).
+pub fn probable_duplicates(incidents: &[Incident]) -> Vec<DuplicateCandidate> {
+    let mut windows: Vec<Window<'_>> = incidents.iter().filter_map(window).collect();
+    windows.sort_by_key(|w| (w.start, w.incident.id));
+
+    let mut out = Vec::new();
+    for (i, a) in windows.iter().enumerate() {
+        for b in &windows[i + 1..] {
+            let gap = (b.start - a.end).whole_seconds().max(0);
+            if gap > DUPLICATE_MAX_WINDOW_GAP_SECONDS {
+                // Sorted by start: every later window is further away still.
+                break;
+            }
+            let similarity = dice(&a.bigrams, &b.bigrams);
+            if similarity < DUPLICATE_MIN_TITLE_SIMILARITY {
+                continue;
+            }
+            let proximity = 1.0 - gap as f64 / DUPLICATE_MAX_WINDOW_GAP_SECONDS as f64;
+            out.push(DuplicateCandidate {
+                incident_id: a.incident.id,
+                other_incident_id: b.incident.id,
+                title: a.incident.title.clone(),
+                other_title: b.incident.title.clone(),
+                title_similarity: round2(similarity),
+                window_gap_seconds: gap,
+                score: round2(TITLE_WEIGHT * similarity + (1.0 - TITLE_WEIGHT) * proximity),
+            });
+        }
+    }
+    out.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(a.incident_id.cmp(&b.incident_id))
+            .then(a.other_incident_id.cmp(&b.other_incident_id))
+    });
+    out
+}
+
+pub fn find_probable_duplicates(conn: &Connection) -> Result<Vec<DuplicateCandidate>, AppError> {
+    Ok(probable_duplicates(&list_incidents(conn)?))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MergeIncidentsSummary {
+    pub kept_incident_id: i64,
+    pub merged_incident_id: i64,
+    /// Fields empty on the kept incident and taken from the merged one.
+    pub filled_fields: Vec<String>,
+    /// Lifecycle timestamps moved earlier (or resolve_ts later) by the merged incident.
+    pub changed_timestamps: Vec<String>,
+    pub moved_timeline_events: i64,
+    /// Merged-incident events identical (source, ts, author, kind, text) to a kept one.
+    pub dropped_duplicate_events: i64,
+    pub moved_artifacts: i64,
+    pub moved_action_items: i64,
+    pub moved_responders: i64,
+}
+
+/// (column, value, raw value) chosen for one field of the merged incident.
+type Chosen = (&'static str, Option<String>, Option<String>);
+
+fn blank(v: &Option<String>) -> bool {
+    v.as_deref().is_none_or(|s| s.trim().is_empty())
+}
+
+/// Earliest (or latest) parseable timestamp of the two, with the raw value it came from. The
+/// kept value wins ties and when neither side parses.
+fn pick_ts(
+    keep: (&Option<String>, &Option<String>),
+    merged: (&Option<String>, &Option<String>),
+    latest: bool,
+) -> (Option<String>, Option<String>, bool) {
+    let take_merged = match (parse(keep.0), parse(merged.0)) {
+        (None, Some(_)) => true,
+        (Some(k), Some(m)) => {
+            if latest {
+                m > k
+            } else {
+                m < k
+            }
+        }
+        _ => false,
+    };
+    if take_merged {
+        (merged.0.clone(), merged.1.clone(), true)
+    } else {
+        (keep.0.clone(), keep.1.clone(), false)
+    }
+}
+
+fn write_failed(what: &str, e: rusqlite::Error) -> AppError {
+    AppError::new("DB_MERGE_WRITE_FAILED", "Failed to merge incidents").with_details(format!("{what}; err={e}"))
+}
+
+/// Folds `merge_id` into `keep_id` and deletes it. The kept incident's title and non-empty
+/// fields win; empty fields are filled from the merged one. Lifecycle timestamps take the
+/// earliest value (resolve_ts the latest). Timeline events, artifacts, action items and
+/// responders move over; events identical to one already on the kept incident are dropped.
+/// Runs in one transaction; both incidents must be outside frozen quarters.
+pub fn merge_incidents(conn: &mut Connection, keep_id: i64, merge_id: i64) -> Result<MergeIncidentsSummary, AppError> {
+    if keep_id == merge_id {
+        return Err(AppError::new("VALIDATION_MERGE_SAME_INCIDENT", "Cannot merge an incident into itself")
+            .with_details(format!("incident_id={keep_id}")));
+    }
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to start merge transaction").with_details(e.to_string()))?;
+    let keep = get_incident(&tx, keep_id)?;
+    let merged = get_incident(&tx, merge_id)?;
+    crate::freeze::ensure_incident_mutable(&tx, keep_id)?;
+    crate::freeze::ensure_incident_mutable(&tx, merge_id)?;
+
+    let mut summary = MergeIncidentsSummary {
+        kept_incident_id: keep_id,
+        merged_incident_id: merge_id,
+        filled_fields: Vec::new(),
+        changed_timestamps: Vec::new(),
+        moved_timeline_events: 0,
+        dropped_duplicate_events: 0,
+        moved_artifacts: 0,
+        moved_action_items: 0,
+        moved_responders: 0,
+    };
+
+    let description_raw: (Option<String>, Option<String>) = tx
+        .query_row(
+            "SELECT (SELECT description_raw FROM incidents WHERE id = ?1), (SELECT description_raw FROM incidents WHERE id = ?2)",
+            params![keep_id, merge_id],
+            |r| Ok((r.get(0)?, r.get(1)?)),
+        )
+        .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to load incident descriptions").with_details(e.to_string()))?;
+    let mut fields: Vec<Chosen> = Vec::new();
+    let mut fill = |column: &'static str, k: &Option<String>, m: &Option<String>, k_raw: Option<String>, m_raw: Option<String>| {
+        if blank(k) && !blank(m) {
+            summary.filled_fields.push(column.to_string());
+            fields.push((column, m.clone(), m_raw));
+        } else {
+            fields.push((column, k.clone(), k_raw));
+        }
+    };
+    fill("external_id", &keep.external_id, &merged.external_id, None, None);
+    fill("description", &keep.description, &merged.description, description_raw.0, description_raw.1);
+    fill("severity", &keep.severity, &merged.severity, None, None);
+    fill("detection_source", &keep.detection_source, &merged.detection_source, None, None);
+    fill("vendor", &keep.vendor, &merged.vendor, None, None);
+    fill("service", &keep.service, &merged.service, None, None);
+    let impact_pct = keep.impact_pct.or(merged.impact_pct);
+    let service_health_pct = keep.service_health_pct.or(merged.service_health_pct);
+    for (column, k, m) in [
+        ("impact_pct", keep.impact_pct, merged.impact_pct),
+        ("service_health_pct", keep.service_health_pct, merged.service_health_pct),
+    ] {
+        if k.is_none() && m.is_some() {
+            summary.filled_fields.push(column.to_string());
+        }
+    }
+
+    let ts_fields = [
+        ("start_ts", (&keep.start_ts, &keep.start_ts_raw), (&merged.start_ts, &merged.start_ts_raw), false),
+        (
+            "first_observed_ts",
+            (&keep.first_observed_ts, &keep.first_observed_ts_raw),
+            (&merged.first_observed_ts, &merged.first_observed_ts_raw),
+            false,
+        ),
+        (
+            "it_awareness_ts",
+            (&keep.it_awareness_ts, &keep.it_awareness_ts_raw),
+            (&merged.it_awareness_ts, &merged.it_awareness_ts_raw),
+            false,
+        ),
+        ("ack_ts", (&keep.ack_ts, &keep.ack_ts_raw), (&merged.ack_ts, &merged.ack_ts_raw), false),
+        ("mitigate_ts", (&keep.mitigate_ts, &keep.mitigate_ts_raw), (&merged.mitigate_ts, &merged.mitigate_ts_raw), false),
+        ("resolve_ts", (&keep.resolve_ts, &keep.resolve_ts_raw), (&merged.resolve_ts, &merged.resolve_ts_raw), true),
+    ];
+    let mut timestamps: Vec<Chosen> = Vec::new();
+    for (column, k, m, latest) in ts_fields {
+        let (value, raw, changed) = pick_ts(k, m, latest);
+        if changed {
+            summary.changed_timestamps.push(column.to_string());
+        }
+        timestamps.push((column, value, raw));
+    }
+
+    // Timeline: drop exact duplicates (repointing notes they superseded), then move the rest.
+    let dupes: Vec<(i64, i64)> = {
+        let mut stmt = tx
+            .prepare(
+                "SELECT m.id, MIN(k.id) FROM timeline_events m
+                 JOIN timeline_events k ON k.incident_id = ?1 AND k.source = m.source AND k.ts IS m.ts
+                   AND k.author IS m.author AND k.kind IS m.kind AND k.text = m.text
+                 WHERE m.incident_id = ?2
+                 GROUP BY m.id ORDER BY m.id",
+            )
+            .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to prepare duplicate event query").with_details(e.to_string()))?;
+        let rows = stmt
+            .query_map(params![keep_id, merge_id], |r| Ok((r.get(0)?, r.get(1)?)))
+            .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to query duplicate events").with_details(e.to_string()))?;
+        rows.collect::<Result<_, _>>()
+            .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to read duplicate events").with_details(e.to_string()))?
+    };
+    for (dup, kept) in &dupes {
+        tx.execute("UPDATE timeline_events SET superseded_by = ?1 WHERE superseded_by = ?2", params![kept, dup])
+            .map_err(|e| write_failed("timeline_events.superseded_by", e))?;
+        tx.execute("DELETE FROM timeline_events WHERE id = ?1", [dup])
+            .map_err(|e| write_failed("timeline_events", e))?;
+    }
+    summary.dropped_duplicate_events = dupes.len() as i64;
+
+    let move_rows = |sql: &str, what: &str| -> Result<i64, AppError> {
+        tx.execute(sql, params![keep_id, merge_id]).map(|n| n as i64).map_err(|e| write_failed(what, e))
+    };
+    summary.moved_timeline_events =
+        move_rows("UPDATE timeline_events SET incident_id = ?1 WHERE incident_id = ?2", "timeline_events")?;
+    summary.moved_artifacts = move_rows("UPDATE artifacts SET incident_id = ?1 WHERE incident_id = ?2", "artifacts")?;
+    summary.moved_action_items =
+        move_rows("UPDATE action_items SET incident_id = ?1 WHERE incident_id = ?2", "action_items")?;
+    // Engagements already recorded on the kept incident, an initial grade or a diagram it
+    // already has stay; the merged incident's copies go with it.
+    summary.moved_responders = move_rows(
+        "UPDATE OR IGNORE incident_responders SET incident_id = ?1 WHERE incident_id = ?2",
+        "incident_responders",
+    )?;
+    move_rows(
+        "UPDATE OR IGNORE incident_severity_grades SET incident_id = ?1 WHERE incident_id = ?2",
+        "incident_severity_grades",
+    )?;
+    move_rows("UPDATE OR IGNORE incident_diagrams SET incident_id = ?1 WHERE incident_id = ?2", "incident_diagrams")?;
+
+    // Delete first so a moved external_id does not collide with the unique index.
+    tx.execute("DELETE FROM incidents WHERE id = ?1", [merge_id])
+        .map_err(|e| write_failed("incidents delete", e))?;
+
+    let text = |v: Option<String>| v.map_or(Value::Null, Value::Text);
+    let mut sets = vec!["impact_pct = ?1".to_string(), "service_health_pct = ?2".to_string()];
+    let mut values: Vec<Value> = vec![impact_pct.map_or(Value::Null, Value::Integer), service_health_pct.map_or(Value::Null, Value::Integer)];
+    for (column, value, raw) in fields.into_iter().chain(timestamps) {
+        sets.push(format!("{column} = ?{}", values.len() + 1));
+        values.push(text(value));
+        if column == "description" || column.ends_with("_ts") {
+            sets.push(format!("{column}_raw = ?{}", values.len() + 1));
+            values.push(text(raw));
+        }
+    }
+    let sql = format!("UPDATE incidents SET {} WHERE id = ?{}", sets.join(", "), values.len() + 1);
+    values.push(Value::Integer(keep_id));
+    tx.execute(&sql, params_from_iter(values)).map_err(|e| write_failed("incidents update", e))?;
+
+    crate::quarters::refresh_quarter_assignments(&tx)?;
+    tx.commit()
+        .map_err(|e| AppError::new("DB_TX_FAILED", "Failed to commit incident merge").with_details(e.to_string()))?;
+    Ok(summary)
+}
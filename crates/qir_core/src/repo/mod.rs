@@ -6,6 +6,13 @@ use crate::error::AppError;
 use crate::metrics::{compute_incident_metrics, IncidentMetrics};
 use crate::validate::validate_incident;
 
+mod duplicates;
+
+pub use duplicates::{
+    find_probable_duplicates, merge_incidents, probable_duplicates, DuplicateCandidate, MergeIncidentsSummary,
+    DUPLICATE_MAX_WINDOW_GAP_SECONDS, DUPLICATE_MIN_TITLE_SIMILARITY,
+};
+
 /// Pagination parameters for list queries
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginationParams {
@@ -337,7 +344,7 @@ pub fn list_timeline_events_for_incident(
       SELECT
         id, incident_id, source, ts, author, kind, text, raw_json, created_at
       FROM timeline_events
-      WHERE incident_id = ?1
+      WHERE incident_id = ?1 AND superseded_by IS NULL
       ORDER BY (ts IS NULL) ASC, ts ASC, id ASC
       "#,
         )
@@ -383,6 +390,7 @@ pub fn list_timeline_events(conn: &Connection) -> Result<Vec<TimelineEvent>, App
       SELECT
         id, incident_id, source, ts, author, kind, text, raw_json, created_at
       FROM timeline_events
+      WHERE superseded_by IS NULL
       ORDER BY
         COALESCE(incident_id, 0) ASC,
         COALESCE(ts, '9999-12-31T23:59:59Z') ASC,
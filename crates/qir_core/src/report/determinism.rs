@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+
+use rusqlite::types::Value;
+use rusqlite::{params_from_iter, Connection};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::analytics::{build_dashboard_payload_v2, DashboardPayloadV2};
+use crate::error::AppError;
+
+use super::generate_qir_markdown;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeterminismMismatch {
+    /// 1-based line of the first difference.
+    pub line: usize,
+    pub first: String,
+    pub second: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeterminismCheck {
+    /// "dashboard_v2" or "report_markdown".
+    pub output: String,
+    pub identical: bool,
+    pub first_sha256: String,
+    pub second_sha256: String,
+    pub first_difference: Option<DeterminismMismatch>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeterminismReport {
+    pub deterministic: bool,
+    pub checks: Vec<DeterminismCheck>,
+}
+
+fn copy_failed(what: &str, e: rusqlite::Error) -> AppError {
+    AppError::new("REPORT_DETERMINISM_COPY_FAILED", "Failed to copy the workspace for the determinism check")
+        .with_details(format!("{what}; err={e}"))
+}
+
+/// Byte comparison of two renderings of the same output.
+pub fn compare_outputs(output: &str, first: &str, second: &str) -> DeterminismCheck {
+    let first_difference = (first != second).then(|| {
+        let (a, b): (Vec<&str>, Vec<&str>) = (first.lines().collect(), second.lines().collect());
+        // Equal line lists mean the only difference is the trailing newline.
+        let idx = (0..a.len().max(b.len())).find(|&i| a.get(i) != b.get(i)).unwrap_or(a.len());
+        DeterminismMismatch {
+            line: idx + 1,
+            first: a.get(idx).copied().unwrap_or("<end of output>").to_string(),
+            second: b.get(idx).copied().unwrap_or("<end of output>").to_string(),
+        }
+    });
+    DeterminismCheck {
+        output: output.to_string(),
+        identical: first_difference.is_none(),
+        first_sha256: hex::encode(Sha256::digest(first.as_bytes())),
+        second_sha256: hex::encode(Sha256::digest(second.as_bytes())),
+        first_difference,
+    }
+}
+
+/// Page-for-page copy on a fresh connection.
+fn fresh_copy(conn: &Connection) -> Result<Connection, AppError> {
+    let mut dest = Connection::open_in_memory().map_err(|e| copy_failed("open", e))?;
+    rusqlite::backup::Backup::new(conn, &mut dest)
+        .and_then(|b| b.run_to_completion(64, std::time::Duration::from_millis(0), None))
+        .map_err(|e| copy_failed("backup", e))?;
+    Ok(dest)
+}
+
+/// Columns that hold incident ids without a declared foreign key (the rows must outlive the
+/// incident), remapped alongside the declared ones.
+const UNDECLARED_REFERENCES: &[(&str, &str, &str)] = &[
+    ("incident_field_history", "incident_id", "incidents"),
+    ("import_batch_incidents", "incident_id", "incidents"),
+];
+
+/// Map from a copy's incident ids back to the workspace's. Renderers apply it to every id they
+/// print, so outputs compare equal when only the numbering differs.
+#[derive(Debug, Clone, Default)]
+pub struct IncidentIdMap(HashMap<i64, i64>);
+
+impl IncidentIdMap {
+    /// Workspace id of a copy-local incident id; ids the copy did not renumber map to themselves.
+    pub fn workspace_id(&self, id: i64) -> i64 {
+        self.0.get(&id).copied().unwrap_or(id)
+    }
+}
+
+struct TableRows {
+    name: String,
+    quoted: String,
+    width: usize,
+    rows: Vec<Vec<Value>>,
+    /// Row indexes in seeded pseudo-random order.
+    order: Vec<usize>,
+    /// Position and name of an `INTEGER PRIMARY KEY` (rowid alias) that gets fresh ids.
+    id_column: Option<(usize, String)>,
+    /// (column position, referenced table, referenced column; None = its primary key).
+    references: Vec<(usize, String, Option<String>)>,
+}
+
+fn read_table(conn: &Connection, name: &str) -> Result<TableRows, AppError> {
+    let quoted = format!("\"{}\"", name.replace('"', "\"\""));
+    let read_failed = |e| copy_failed(&format!("read {name}"), e);
+    let mut stmt = conn.prepare(&format!("SELECT * FROM {quoted}")).map_err(read_failed)?;
+    let columns: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+    let width = columns.len();
+    let rows: Vec<Vec<Value>> = stmt
+        .query_map([], |r| (0..width).map(|i| r.get::<_, Value>(i)).collect())
+        .and_then(|rows| rows.collect())
+        .map_err(read_failed)?;
+    let position = |column: &str| columns.iter().position(|c| c == column);
+
+    let mut references: Vec<(usize, String, Option<String>)> = {
+        let mut stmt = conn
+            .prepare("SELECT \"from\", \"table\", \"to\" FROM pragma_foreign_key_list(?1)")
+            .map_err(read_failed)?;
+        let declared = stmt
+            .query_map([name], |r| Ok((r.get::<_, String>(0)?, r.get::<_, String>(1)?, r.get(2)?)))
+            .and_then(|rows| rows.collect::<Result<Vec<(String, String, Option<String>)>, _>>())
+            .map_err(read_failed)?;
+        declared
+            .into_iter()
+            .filter_map(|(from, table, to)| Some((position(&from)?, table, to)))
+            .collect()
+    };
+    references.extend(
+        UNDECLARED_REFERENCES
+            .iter()
+            .filter(|(table, _, _)| *table == name)
+            .filter_map(|(_, column, target)| Some((position(column)?, target.to_string(), None))),
+    );
+
+    let primary_key: Vec<(String, String)> = {
+        let mut stmt = conn
+            .prepare("SELECT name, type FROM pragma_table_info(?1) WHERE pk > 0")
+            .map_err(read_failed)?;
+        let pk = stmt
+            .query_map([name], |r| Ok((r.get(0)?, r.get(1)?)))
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+            .map_err(read_failed)?;
+        pk
+    };
+    // A primary key that is itself a reference follows the referenced table's new ids instead.
+    let id_column = match primary_key.as_slice() {
+        [(column, kind)] if kind.eq_ignore_ascii_case("INTEGER") => position(column)
+            .filter(|&i| references.iter().all(|(r, _, _)| *r != i))
+            .map(|i| (i, column.clone())),
+        _ => None,
+    };
+
+    let mut order: Vec<(String, usize)> = (0..rows.len())
+        .map(|i| (hex::encode(Sha256::digest(format!("determinism_v1|{name}|{i}").as_bytes())), i))
+        .collect();
+    order.sort();
+    Ok(TableRows {
+        name: name.to_string(),
+        quoted,
+        width,
+        rows,
+        order: order.into_iter().map(|(_, i)| i).collect(),
+        id_column,
+        references,
+    })
+}
+
+/// A logical copy whose rows are stored in a seeded pseudo-random order.
+struct ShuffledCopy {
+    conn: Connection,
+    incident_ids: IncidentIdMap,
+}
+
+/// Logical copy with `reverse_unordered_selects` on and every table's rows inserted in a seeded
+/// pseudo-random order. Rowid tables keep their storage ordered by id, so their rows also get
+/// fresh ids permuted by that order (references are remapped to match); any output that leans
+/// on storage or id order then shifts.
+fn shuffled_copy(conn: &Connection) -> Result<ShuffledCopy, AppError> {
+    let dest = Connection::open_in_memory().map_err(|e| copy_failed("open", e))?;
+    dest.execute_batch("PRAGMA reverse_unordered_selects = ON;")
+        .map_err(|e| copy_failed("pragma", e))?;
+
+    let schema: Vec<(String, String, String)> = {
+        let mut stmt = conn
+            .prepare(
+                "SELECT type, name, sql FROM sqlite_master
+                 WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite_%' ORDER BY rowid",
+            )
+            .map_err(|e| copy_failed("schema", e))?;
+        let rows = stmt
+            .query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+            .map_err(|e| copy_failed("schema", e))?;
+        rows
+    };
+
+    // Tables first, then their rows, then indexes/triggers/views so triggers do not fire.
+    for (_, name, sql) in schema.iter().filter(|(kind, _, _)| kind == "table") {
+        dest.execute_batch(sql).map_err(|e| copy_failed(&format!("create {name}"), e))?;
+    }
+    let tables = schema
+        .iter()
+        .filter(|(kind, _, _)| kind == "table")
+        .map(|(_, name, _)| read_table(conn, name))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Old id -> new id per renumbered table: the sorted ids handed out in seeded row order.
+    let mut new_ids: HashMap<&str, (&str, HashMap<i64, i64>)> = HashMap::new();
+    for table in &tables {
+        let Some((i, column)) = &table.id_column else { continue };
+        let id_of = |row: usize| match table.rows[row][*i] {
+            Value::Integer(id) => Some(id),
+            _ => None,
+        };
+        let mut sorted: Vec<i64> = (0..table.rows.len()).filter_map(id_of).collect();
+        sorted.sort_unstable();
+        let ids = table.order.iter().filter_map(|&row| id_of(row)).zip(sorted).collect();
+        new_ids.insert(table.name.as_str(), (column.as_str(), ids));
+    }
+
+    for table in &tables {
+        let write_failed = |e| copy_failed(&format!("write {}", table.name), e);
+        let placeholders = vec!["?"; table.width].join(", ");
+        let mut insert = dest
+            .prepare(&format!("INSERT INTO {} VALUES ({placeholders})", table.quoted))
+            .map_err(write_failed)?;
+        let own = new_ids.get(table.name.as_str()).map(|(_, ids)| ids);
+        for &row in &table.order {
+            let mut values = table.rows[row].clone();
+            let remap = |value: &mut Value, ids: &HashMap<i64, i64>| {
+                if let Value::Integer(id) = value {
+                    *id = ids.get(id).copied().unwrap_or(*id);
+                }
+            };
+            if let (Some((i, _)), Some(ids)) = (&table.id_column, own) {
+                remap(&mut values[*i], ids);
+            }
+            for (i, target, to) in &table.references {
+                match new_ids.get(target.as_str()) {
+                    Some((column, ids)) if to.as_deref().is_none_or(|to| to == *column) => {
+                        remap(&mut values[*i], ids)
+                    }
+                    _ => {}
+                }
+            }
+            insert.execute(params_from_iter(values.iter())).map_err(write_failed)?;
+        }
+    }
+    for (_, name, sql) in schema.iter().filter(|(kind, _, _)| kind != "table") {
+        dest.execute_batch(sql).map_err(|e| copy_failed(&format!("create {name}"), e))?;
+    }
+
+    let incident_ids = new_ids
+        .get("incidents")
+        .map(|(_, ids)| ids.iter().map(|(&old, &new)| (new, old)).collect())
+        .unwrap_or_default();
+    Ok(ShuffledCopy {
+        conn: dest,
+        incident_ids: IncidentIdMap(incident_ids),
+    })
+}
+
+/// Maps the payload's incident ids back to the workspace. Id lists follow id order, so they
+/// are re-sorted by workspace id to compare membership rather than the copy's numbering.
+fn restore_incident_ids(payload: &mut DashboardPayloadV2, ids: &IncidentIdMap) {
+    let restore = |list: &mut Vec<i64>| {
+        list.iter_mut().for_each(|id| *id = ids.workspace_id(*id));
+        list.sort_unstable();
+    };
+    for inc in &mut payload.incidents {
+        inc.id = ids.workspace_id(inc.id);
+    }
+    payload.severity_counts.iter_mut().for_each(|b| restore(&mut b.incident_ids));
+    let detection = &mut payload.detection_story;
+    detection.detection_source_mix.iter_mut().for_each(|b| restore(&mut b.incident_ids));
+    detection.it_awareness_lag_buckets.iter_mut().for_each(|b| restore(&mut b.incident_ids));
+    let vendor_service = &mut payload.vendor_service_story;
+    for buckets in [&mut vendor_service.top_vendors_by_count, &mut vendor_service.top_services_by_count] {
+        buckets.iter_mut().for_each(|b| restore(&mut b.incident_ids));
+    }
+    for buckets in [&mut vendor_service.top_vendors_by_pain, &mut vendor_service.top_services_by_pain] {
+        buckets.iter_mut().for_each(|b| restore(&mut b.incident_ids));
+    }
+    let response = &mut payload.response_story;
+    for buckets in [&mut response.time_to_mitigation_buckets, &mut response.time_to_resolve_buckets] {
+        buckets.iter_mut().for_each(|b| restore(&mut b.incident_ids));
+    }
+    response.start_hours_buckets.iter_mut().for_each(|b| restore(&mut b.incident_ids));
+    for metric in &mut payload.computed_metrics {
+        metric.buckets.iter_mut().for_each(|b| restore(&mut b.incident_ids));
+    }
+}
+
+fn render(conn: &Connection, ids: &IncidentIdMap) -> Result<Vec<(String, String)>, AppError> {
+    let mut payload = build_dashboard_payload_v2(conn)?;
+    restore_incident_ids(&mut payload, ids);
+    let dashboard = serde_json::to_string_pretty(&payload).map_err(|e| {
+        AppError::new("DB_ENCODE_FAILED", "Failed to encode dashboard payload").with_details(e.to_string())
+    })?;
+    Ok(vec![
+        ("dashboard_v2".to_string(), dashboard),
+        ("report_markdown".to_string(), generate_qir_markdown(conn)?),
+    ])
+}
+
+/// Renders the dashboard payload and the QIR Markdown from two independent copies of the
+/// workspace (a fresh page copy, and a copy with shuffled rows, renumbered ids and reversed
+/// unordered scans) and byte-compares them. Any difference is an ordering bug that would make a
+/// published report irreproducible. The workspace itself is only read.
+pub fn verify_determinism(conn: &Connection) -> Result<DeterminismReport, AppError> {
+    verify_determinism_with(conn, render)
+}
+
+/// `verify_determinism` for any named outputs: `render` is called on both copies with the map
+/// from the copy's incident ids back to the workspace's, and must name its outputs identically.
+pub fn verify_determinism_with<F>(conn: &Connection, render: F) -> Result<DeterminismReport, AppError>
+where
+    F: Fn(&Connection, &IncidentIdMap) -> Result<Vec<(String, String)>, AppError>,
+{
+    let first = render(&fresh_copy(conn)?, &IncidentIdMap::default())?;
+    let shuffled = shuffled_copy(conn)?;
+    let second = render(&shuffled.conn, &shuffled.incident_ids)?;
+    let checks: Vec<DeterminismCheck> = first
+        .iter()
+        .zip(second.iter())
+        .map(|((output, a), (_, b))| compare_outputs(output, a, b))
+        .collect();
+    Ok(DeterminismReport {
+        deterministic: checks.iter().all(|c| c.identical),
+        checks,
+    })
+}
@@ -0,0 +1,315 @@
+use std::fs;
+use std::path::Path;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use time::format_description::well_known::{Rfc2822, Rfc3339};
+use time::{Duration, OffsetDateTime, UtcOffset};
+
+use super::display_time::with_local;
+use super::{escape_html, load_display_timezone};
+use crate::backup::filename_safe_timestamp;
+use crate::error::AppError;
+use crate::metrics::{compute_incident_metrics, format_duration_seconds, percentile};
+use crate::repo::list_incidents;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DigestEmailOptions {
+    pub from: String,
+    pub to: Vec<String>,
+    /// RFC3339 end of the digest window (exclusive); the window is the 7 days before it.
+    pub week_ending: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DigestIncident {
+    pub external_id: Option<String>,
+    pub title: String,
+    pub severity: Option<String>,
+    pub start_ts: Option<String>,
+    pub resolve_ts: Option<String>,
+    pub mttr_seconds: Option<i64>,
+}
+
+/// The weekly mini-report: what started in the window and what got resolved in it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WeeklyDigest {
+    pub week_start: String,
+    pub week_ending: String,
+    /// Incidents anchored (start, else first observed) in the window, oldest first.
+    pub new_incidents: Vec<DigestIncident>,
+    pub still_open_count: i64,
+    /// Incidents resolved in the window, whenever they started.
+    pub resolved_count: i64,
+    pub resolved_mttr_p50_seconds: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DigestEmlExportResult {
+    pub eml_path: String,
+    pub subject: String,
+    pub new_incident_count: i64,
+    pub eml_bytes: u64,
+}
+
+fn invalid(message: &str, details: String) -> AppError {
+    AppError::new("VALIDATION_DIGEST_INVALID", message).with_details(details)
+}
+
+fn parse_utc(ts: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(ts.trim(), &Rfc3339)
+        .ok()
+        .map(|t| t.to_offset(UtcOffset::UTC))
+}
+
+fn format_utc(t: OffsetDateTime) -> Result<String, AppError> {
+    t.format(&Rfc3339).map_err(|e| {
+        AppError::new("REPORT_DIGEST_TIME_FAILED", "Failed to format digest time").with_details(e.to_string())
+    })
+}
+
+pub fn build_weekly_digest(conn: &Connection, week_ending: &str) -> Result<WeeklyDigest, AppError> {
+    let end = parse_utc(week_ending).ok_or_else(|| {
+        invalid("Digest week ending must be an RFC3339 timestamp", format!("week_ending={week_ending}"))
+    })?;
+    let start = end - Duration::days(7);
+    let in_window = |ts: Option<&str>| ts.and_then(parse_utc).is_some_and(|t| start <= t && t < end);
+
+    let mut new_incidents = Vec::new();
+    let mut resolved_mttr = Vec::new();
+    let mut resolved_count = 0;
+    for inc in list_incidents(conn)? {
+        let (m, _) = compute_incident_metrics(&inc);
+        if in_window(inc.resolve_ts.as_deref()) {
+            resolved_count += 1;
+            if let Some(s) = m.mttr_seconds {
+                resolved_mttr.push(s);
+            }
+        }
+        let anchor = inc
+            .start_ts
+            .as_deref()
+            .filter(|t| parse_utc(t).is_some())
+            .or(inc.first_observed_ts.as_deref());
+        if in_window(anchor) {
+            new_incidents.push((
+                anchor.and_then(parse_utc),
+                inc.id,
+                DigestIncident {
+                    external_id: inc.external_id.clone(),
+                    title: inc.title.clone(),
+                    severity: inc.severity.clone(),
+                    start_ts: anchor.map(str::to_string),
+                    resolve_ts: inc.resolve_ts.clone(),
+                    mttr_seconds: m.mttr_seconds,
+                },
+            ));
+        }
+    }
+    new_incidents.sort_by_key(|(anchor, id, _)| (*anchor, *id));
+    let new_incidents: Vec<DigestIncident> = new_incidents.into_iter().map(|(_, _, i)| i).collect();
+
+    Ok(WeeklyDigest {
+        week_start: format_utc(start)?,
+        week_ending: format_utc(end)?,
+        still_open_count: new_incidents.iter().filter(|i| i.resolve_ts.is_none()).count() as i64,
+        new_incidents,
+        resolved_count,
+        resolved_mttr_p50_seconds: percentile(&mut resolved_mttr, 50, 100),
+    })
+}
+
+fn digest_subject(d: &WeeklyDigest) -> String {
+    format!(
+        "Incident digest: week ending {} ({} new, {} resolved)",
+        &d.week_ending[..10],
+        d.new_incidents.len(),
+        d.resolved_count
+    )
+}
+
+fn incident_rows(d: &WeeklyDigest, tz: Option<&super::DisplayTimezone>) -> Vec<[String; 5]> {
+    d.new_incidents
+        .iter()
+        .map(|i| {
+            [
+                i.external_id.clone().unwrap_or_else(|| "NO_EXTERNAL_ID".to_string()),
+                i.title.clone(),
+                i.severity.clone().unwrap_or_else(|| "UNKNOWN".to_string()),
+                i.start_ts
+                    .as_deref()
+                    .map(|ts| with_local(ts, tz))
+                    .unwrap_or_else(|| "UNKNOWN".to_string()),
+                match &i.resolve_ts {
+                    Some(_) => format!("resolved, MTTR {}", format_duration_seconds(i.mttr_seconds)),
+                    None => "open".to_string(),
+                },
+            ]
+        })
+        .collect()
+}
+
+fn render_text(d: &WeeklyDigest, rows: &[[String; 5]]) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("Incident digest for {} to {}\n\n", d.week_start, d.week_ending));
+    out.push_str(&format!("- New incidents: {}\n", d.new_incidents.len()));
+    out.push_str(&format!("- Still open: {}\n", d.still_open_count));
+    out.push_str(&format!("- Resolved this week: {}\n", d.resolved_count));
+    out.push_str(&format!(
+        "- Median time to resolve (resolved this week): {}\n\n",
+        format_duration_seconds(d.resolved_mttr_p50_seconds)
+    ));
+    out.push_str("New this week:\n");
+    if rows.is_empty() {
+        out.push_str("- None.\n");
+    }
+    for [external, title, sev, started, status] in rows {
+        out.push_str(&format!("- {external} [{sev}] {title}\n  started {started}; {status}\n"));
+    }
+    out.push_str("\nGenerated locally by IncidentReview from the incident database.\n");
+    out
+}
+
+fn render_html(d: &WeeklyDigest, rows: &[[String; 5]]) -> String {
+    let mut out = String::from("<!DOCTYPE html>\n<html>\n<body>\n");
+    out.push_str(&format!(
+        "<h2>Incident digest for {} to {}</h2>\n<ul>\n",
+        escape_html(&d.week_start),
+        escape_html(&d.week_ending)
+    ));
+    out.push_str(&format!("<li>New incidents: <b>{}</b></li>\n", d.new_incidents.len()));
+    out.push_str(&format!("<li>Still open: <b>{}</b></li>\n", d.still_open_count));
+    out.push_str(&format!("<li>Resolved this week: <b>{}</b></li>\n", d.resolved_count));
+    out.push_str(&format!(
+        "<li>Median time to resolve (resolved this week): <b>{}</b></li>\n</ul>\n",
+        escape_html(&format_duration_seconds(d.resolved_mttr_p50_seconds))
+    ));
+    out.push_str("<h3>New this week</h3>\n");
+    if rows.is_empty() {
+        out.push_str("<p>None.</p>\n");
+    } else {
+        out.push_str("<table border=\"1\" cellpadding=\"4\" cellspacing=\"0\">\n");
+        out.push_str("<tr><th>External ID</th><th>Title</th><th>Severity</th><th>Started</th><th>Status</th></tr>\n");
+        for row in rows {
+            out.push_str("<tr>");
+            for cell in row {
+                out.push_str(&format!("<td>{}</td>", escape_html(cell)));
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</table>\n");
+    }
+    out.push_str("<p><i>Generated locally by IncidentReview from the incident database.</i></p>\n</body>\n</html>\n");
+    out
+}
+
+fn validate_address(addr: &str) -> Result<(), AppError> {
+    let ok = addr.contains('@')
+        && addr.is_ascii()
+        && !addr.chars().any(|c| c.is_ascii_control() || c == ',');
+    if !ok {
+        return Err(invalid(
+            "Digest addresses must be single ASCII email addresses",
+            format!("address={}", addr.escape_debug()),
+        ));
+    }
+    Ok(())
+}
+
+fn crlf(s: &str) -> String {
+    s.replace('\n', "\r\n")
+}
+
+/// Renders the weekly digest as a complete RFC 5322 message with a plain-text part and an
+/// HTML alternative. Output is deterministic for a given workspace and `generated_at`.
+pub fn render_digest_eml(
+    conn: &Connection,
+    opts: &DigestEmailOptions,
+    generated_at: &str,
+) -> Result<(String, WeeklyDigest), AppError> {
+    validate_address(opts.from.trim())?;
+    if opts.to.is_empty() {
+        return Err(invalid("Digest needs at least one recipient", "to=[]".to_string()));
+    }
+    for to in &opts.to {
+        validate_address(to.trim())?;
+    }
+    let date = parse_utc(generated_at)
+        .ok_or_else(|| invalid("Digest generated_at must be RFC3339", format!("generated_at={generated_at}")))?
+        .format(&Rfc2822)
+        .map_err(|e| {
+            AppError::new("REPORT_DIGEST_TIME_FAILED", "Failed to format digest time").with_details(e.to_string())
+        })?;
+
+    let digest = build_weekly_digest(conn, &opts.week_ending)?;
+    let tz = load_display_timezone(conn)?;
+    let rows = incident_rows(&digest, tz.as_ref());
+    let text = render_text(&digest, &rows);
+    let html = render_html(&digest, &rows);
+
+    let mut hasher = Sha256::new();
+    hasher.update(generated_at.as_bytes());
+    hasher.update(text.as_bytes());
+    hasher.update(html.as_bytes());
+    let token = hex::encode(hasher.finalize())[..24].to_string();
+    let boundary = format!("qir-digest-{token}");
+    let to: Vec<&str> = opts.to.iter().map(|t| t.trim()).collect();
+
+    let mut eml = String::new();
+    eml.push_str(&format!("From: {}\n", opts.from.trim()));
+    eml.push_str(&format!("To: {}\n", to.join(", ")));
+    eml.push_str(&format!("Subject: {}\n", digest_subject(&digest)));
+    eml.push_str(&format!("Date: {date}\n"));
+    eml.push_str(&format!("Message-ID: <{token}@incidentreview.local>\n"));
+    eml.push_str("X-Unsent: 1\n");
+    eml.push_str("MIME-Version: 1.0\n");
+    eml.push_str(&format!("Content-Type: multipart/alternative; boundary=\"{boundary}\"\n\n"));
+    for (subtype, body) in [("plain", &text), ("html", &html)] {
+        eml.push_str(&format!("--{boundary}\n"));
+        eml.push_str(&format!("Content-Type: text/{subtype}; charset=utf-8\n"));
+        eml.push_str("Content-Transfer-Encoding: 8bit\n\n");
+        eml.push_str(body);
+        eml.push('\n');
+    }
+    eml.push_str(&format!("--{boundary}--\n"));
+    Ok((crlf(&eml), digest))
+}
+
+/// Writes `QIR_digest_<week_ending>.eml` into `destination_dir`. Nothing is sent.
+pub fn export_digest_eml(
+    conn: &Connection,
+    opts: &DigestEmailOptions,
+    destination_dir: &Path,
+    generated_at: &str,
+) -> Result<DigestEmlExportResult, AppError> {
+    if !destination_dir.is_dir() {
+        return Err(AppError::new(
+            "REPORT_EXPORT_DEST_NOT_DIR",
+            "Digest export destination must be an existing directory",
+        )
+        .with_details(destination_dir.display().to_string()));
+    }
+    let (eml, digest) = render_digest_eml(conn, opts, generated_at)?;
+    let eml_path = destination_dir.join(format!(
+        "QIR_digest_{}.eml",
+        filename_safe_timestamp(&digest.week_ending)
+    ));
+    if eml_path.exists() {
+        return Err(AppError::new(
+            "REPORT_EXPORT_DEST_EXISTS",
+            "Digest export destination file already exists",
+        )
+        .with_details(eml_path.display().to_string()));
+    }
+    fs::write(&eml_path, eml.as_bytes()).map_err(|e| {
+        AppError::new("REPORT_EXPORT_WRITE_FAILED", "Failed to write digest email")
+            .with_details(format!("path={}; err={}", eml_path.display(), e))
+    })?;
+    Ok(DigestEmlExportResult {
+        eml_path: eml_path.to_string_lossy().to_string(),
+        subject: digest_subject(&digest),
+        new_incident_count: digest.new_incidents.len() as i64,
+        eml_bytes: eml.len() as u64,
+    })
+}
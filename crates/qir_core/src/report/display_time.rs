@@ -0,0 +1,94 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::error::AppError;
+use crate::settings::{delete_setting, get_setting_as, set_setting};
+
+pub const DISPLAY_TIMEZONE_SETTING: &str = "report.display_timezone";
+
+/// Where the readers of this workspace's reports sit. Display only: storage, metrics and
+/// quarter assignment stay UTC. A fixed offset, like business hours, so rendering never
+/// depends on the machine's timezone database; it does not follow daylight saving, which is
+/// why the offset is printed next to every local time.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DisplayTimezone {
+    /// Shown next to local times after the offset, e.g. "New York office" or "Sydney on-call".
+    pub label: String,
+    pub utc_offset_minutes: i32,
+}
+
+impl DisplayTimezone {
+    pub fn validate(&self) -> Result<(), AppError> {
+        let label = self.label.trim();
+        let label_ok = !label.is_empty() && label.chars().count() <= 64 && !label.contains('|');
+        if !label_ok || self.utc_offset_minutes.abs() > 14 * 60 {
+            return Err(AppError::new(
+                "VALIDATION_DISPLAY_TIMEZONE_INVALID",
+                "Display timezone needs a label (up to 64 characters, no '|') and a UTC offset within +/-14:00",
+            )
+            .with_details(format!(
+                "label={}; utc_offset_minutes={}",
+                self.label, self.utc_offset_minutes
+            )));
+        }
+        Ok(())
+    }
+
+    /// "UTC+05:30" style suffix.
+    pub fn offset_label(&self) -> String {
+        let m = self.utc_offset_minutes;
+        format!("UTC{}{:02}:{:02}", if m < 0 { '-' } else { '+' }, m.abs() / 60, m.abs() % 60)
+    }
+
+    /// `ts` rendered as local wall-clock time ("2026-01-05 09:00 UTC-05:00 New York office").
+    /// None when `ts` is not RFC3339.
+    pub fn local(&self, ts: &str) -> Option<String> {
+        let offset = UtcOffset::from_whole_seconds(self.utc_offset_minutes * 60).ok()?;
+        let local = OffsetDateTime::parse(ts.trim(), &Rfc3339).ok()?.to_offset(offset);
+        Some(format!(
+            "{:04}-{:02}-{:02} {:02}:{:02} {} {}",
+            local.year(),
+            u8::from(local.month()),
+            local.day(),
+            local.hour(),
+            local.minute(),
+            self.offset_label(),
+            self.label.trim()
+        ))
+    }
+}
+
+/// `ts` as stored (UTC), followed by the local rendering when a display timezone is set.
+/// Unparseable values are shown as stored.
+pub(crate) fn with_local(ts: &str, tz: Option<&DisplayTimezone>) -> String {
+    match tz.and_then(|tz| tz.local(ts)) {
+        Some(local) => format!("{ts} ({local})"),
+        None => ts.to_string(),
+    }
+}
+
+pub fn load_display_timezone(conn: &Connection) -> Result<Option<DisplayTimezone>, AppError> {
+    let tz = get_setting_as::<DisplayTimezone>(conn, DISPLAY_TIMEZONE_SETTING)?;
+    if let Some(tz) = &tz {
+        tz.validate()?;
+    }
+    Ok(tz)
+}
+
+/// Sets the display timezone, or clears it (UTC only) with None. Clearing never decodes the
+/// stored value, so an unreadable setting can always be removed.
+pub fn save_display_timezone(conn: &Connection, tz: Option<&DisplayTimezone>) -> Result<(), AppError> {
+    let Some(tz) = tz else {
+        return match delete_setting(conn, DISPLAY_TIMEZONE_SETTING) {
+            Err(e) if e.code == "DB_NOT_FOUND" => Ok(()),
+            other => other,
+        };
+    };
+    tz.validate()?;
+    let value = serde_json::to_value(tz).map_err(|e| {
+        AppError::new("DB_ENCODE_FAILED", "Failed to encode display timezone").with_details(e.to_string())
+    })?;
+    set_setting(conn, DISPLAY_TIMEZONE_SETTING, &value)
+}
@@ -0,0 +1,268 @@
+use std::fs;
+use std::path::Path;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::backup::filename_safe_timestamp;
+use crate::error::AppError;
+
+/// Bump when the Markdown layout produced by `generate_qir_markdown` changes.
+pub const REPORT_TEMPLATE_VERSION: &str = "qir_md_v5";
+pub const REPORT_MANIFEST_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QirReportOptions {
+    /// Restrict the report to incidents in this quarter (defined or calendar label). None = all incidents.
+    pub quarter_label: Option<String>,
+    /// Render from incident field history as of this RFC3339 timestamp.
+    pub as_of: Option<String>,
+    /// AI draft artifacts appended as a clearly labeled, non-authoritative appendix.
+    pub draft_ids: Vec<i64>,
+    /// Append the per-person on-call load section. None = omitted.
+    #[serde(default)]
+    pub on_call: Option<OnCallReportMode>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OnCallReportMode {
+    Named,
+    /// Names replaced by `Responder N`, ranked by load.
+    Anonymized,
+}
+
+/// Sidecar manifest describing exactly what produced a report, so it can be re-verified.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReportManifest {
+    pub manifest_version: u32,
+    pub report_kind: String,
+    pub template_version: String,
+    pub app_version: String,
+    pub generated_at: String,
+    pub workspace_content_sha256: String,
+    pub applied_migrations: Vec<String>,
+    pub quarter_scope: Option<String>,
+    pub as_of: Option<String>,
+    pub draft_ids: Vec<i64>,
+    pub draft_artifact_hashes: Vec<String>,
+    #[serde(default)]
+    pub on_call: Option<OnCallReportMode>,
+    pub report_sha256: String,
+    pub report_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GeneratedReport {
+    pub markdown: String,
+    pub manifest: ReportManifest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReportExportResult {
+    pub report_path: String,
+    pub manifest_path: String,
+    pub manifest: ReportManifest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReportVerifyResult {
+    /// True when regenerating with the manifest's inputs yields byte-identical output.
+    pub reproducible: bool,
+    pub expected_report_sha256: String,
+    pub actual_report_sha256: String,
+    pub workspace_content_matches: bool,
+    pub migrations_match: bool,
+    pub template_matches: bool,
+    pub notes: Vec<String>,
+}
+
+fn render(conn: &Connection, opts: &QirReportOptions) -> Result<(String, Vec<String>), AppError> {
+    let mut md = super::render_scoped_markdown(conn, opts.quarter_label.as_deref(), opts.as_of.as_deref())?;
+    if let Some(mode) = opts.on_call {
+        let load = crate::analytics::build_on_call_load(
+            conn,
+            opts.quarter_label.as_deref(),
+            mode == OnCallReportMode::Anonymized,
+        )?;
+        md.push('\n');
+        md.push_str(&super::render_on_call_section(&load));
+    }
+
+    let mut draft_hashes = Vec::new();
+    if !opts.draft_ids.is_empty() {
+        md.push_str("\n## Appendix: AI drafts (non-authoritative)\n\n");
+        md.push_str("- Drafts are evidence-cited narrative only; all numbers above are deterministic.\n\n");
+        let policy = crate::ai_drafts::policy::load_ai_usage_policy(conn)?;
+        for id in &opts.draft_ids {
+            let draft = crate::ai_drafts::get_ai_draft(conn, *id)?.ok_or_else(|| {
+                AppError::new("REPORT_DRAFT_NOT_FOUND", "AI draft referenced by report not found")
+                    .with_details(format!("draft_id={id}"))
+            })?;
+            md.push_str(&format!(
+                "### {} ({}) — draft {}\n\n",
+                draft.section_type.as_str(),
+                draft.quarter_label,
+                draft.artifact_hash
+            ));
+            md.push_str(draft.draft_text.trim_end());
+            md.push_str("\n\n");
+            if let Some(disclaimer) = policy.disclaimer_text() {
+                md.push_str(&format!("_{disclaimer}_\n\n"));
+            }
+            draft_hashes.push(draft.artifact_hash);
+        }
+    }
+    Ok((md, draft_hashes))
+}
+
+/// Generates the QIR Markdown plus its reproducibility manifest.
+pub fn generate_qir_report(
+    conn: &Connection,
+    opts: &QirReportOptions,
+    generated_at: &str,
+    app_version: &str,
+) -> Result<GeneratedReport, AppError> {
+    if !opts.draft_ids.is_empty() {
+        let policy = crate::ai_drafts::policy::load_ai_usage_policy(conn)?;
+        for id in &opts.draft_ids {
+            if let Some(draft) = crate::ai_drafts::get_ai_draft(conn, *id)? {
+                crate::ai_drafts::policy::ensure_draft_exportable(conn, &policy, &draft)?;
+            }
+        }
+    }
+    let (markdown, draft_artifact_hashes) = render(conn, opts)?;
+    let manifest = ReportManifest {
+        manifest_version: REPORT_MANIFEST_VERSION,
+        report_kind: "qir_markdown".to_string(),
+        template_version: REPORT_TEMPLATE_VERSION.to_string(),
+        app_version: app_version.to_string(),
+        generated_at: generated_at.to_string(),
+        workspace_content_sha256: crate::cache::compute_workspace_content_hash(conn)?,
+        applied_migrations: crate::db::applied_migration_names(conn)?,
+        quarter_scope: opts.quarter_label.clone(),
+        as_of: opts
+            .as_of
+            .as_deref()
+            .map(crate::history::normalize_as_of)
+            .transpose()?,
+        draft_ids: opts.draft_ids.clone(),
+        draft_artifact_hashes,
+        on_call: opts.on_call,
+        report_sha256: hex::encode(Sha256::digest(markdown.as_bytes())),
+        report_bytes: markdown.len() as u64,
+    };
+    Ok(GeneratedReport { markdown, manifest })
+}
+
+/// Writes `QIR_<ts>.md` and its sidecar `QIR_<ts>.manifest.json` into `destination_dir`.
+pub fn export_qir_report(
+    conn: &Connection,
+    opts: &QirReportOptions,
+    destination_dir: &Path,
+    generated_at: &str,
+    app_version: &str,
+) -> Result<ReportExportResult, AppError> {
+    if !destination_dir.is_dir() {
+        return Err(AppError::new(
+            "REPORT_EXPORT_DEST_NOT_DIR",
+            "Report export destination must be an existing directory",
+        )
+        .with_details(destination_dir.display().to_string()));
+    }
+    let stem = format!("QIR_{}", filename_safe_timestamp(generated_at));
+    let report_path = destination_dir.join(format!("{stem}.md"));
+    let manifest_path = destination_dir.join(format!("{stem}.manifest.json"));
+    for p in [&report_path, &manifest_path] {
+        if p.exists() {
+            return Err(AppError::new(
+                "REPORT_EXPORT_DEST_EXISTS",
+                "Report export destination file already exists",
+            )
+            .with_details(p.display().to_string()));
+        }
+    }
+
+    let report = generate_qir_report(conn, opts, generated_at, app_version)?;
+    fs::write(&report_path, report.markdown.as_bytes()).map_err(|e| {
+        AppError::new("REPORT_EXPORT_WRITE_FAILED", "Failed to write report")
+            .with_details(format!("path={}; err={}", report_path.display(), e))
+    })?;
+    let manifest_json = serde_json::to_string_pretty(&report.manifest).map_err(|e| {
+        AppError::new("REPORT_MANIFEST_ENCODE_FAILED", "Failed to encode report manifest")
+            .with_details(e.to_string())
+    })?;
+    fs::write(&manifest_path, manifest_json.as_bytes()).map_err(|e| {
+        AppError::new("REPORT_EXPORT_WRITE_FAILED", "Failed to write report manifest")
+            .with_details(format!("path={}; err={}", manifest_path.display(), e))
+    })?;
+
+    Ok(ReportExportResult {
+        report_path: report_path.to_string_lossy().to_string(),
+        manifest_path: manifest_path.to_string_lossy().to_string(),
+        manifest: report.manifest,
+    })
+}
+
+pub fn read_report_manifest(path: &Path) -> Result<ReportManifest, AppError> {
+    let bytes = fs::read(path).map_err(|e| {
+        AppError::new("REPORT_MANIFEST_READ_FAILED", "Failed to read report manifest")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    })?;
+    let manifest: ReportManifest = serde_json::from_slice(&bytes).map_err(|e| {
+        AppError::new("REPORT_MANIFEST_INVALID", "Report manifest is not valid JSON")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    })?;
+    if manifest.manifest_version != REPORT_MANIFEST_VERSION {
+        return Err(AppError::new(
+            "REPORT_MANIFEST_UNSUPPORTED_VERSION",
+            "Unsupported report manifest version",
+        )
+        .with_details(format!("manifest_version={}", manifest.manifest_version)));
+    }
+    Ok(manifest)
+}
+
+/// Regenerates the report from the manifest's inputs against the current DB and compares.
+pub fn verify_qir_report(conn: &Connection, manifest: &ReportManifest) -> Result<ReportVerifyResult, AppError> {
+    let opts = QirReportOptions {
+        quarter_label: manifest.quarter_scope.clone(),
+        as_of: manifest.as_of.clone(),
+        draft_ids: manifest.draft_ids.clone(),
+        on_call: manifest.on_call,
+    };
+    let (markdown, draft_hashes) = render(conn, &opts)?;
+    let actual_report_sha256 = hex::encode(Sha256::digest(markdown.as_bytes()));
+    let workspace_content_matches =
+        crate::cache::compute_workspace_content_hash(conn)? == manifest.workspace_content_sha256;
+    let migrations_match = crate::db::applied_migration_names(conn)? == manifest.applied_migrations;
+    let template_matches = manifest.template_version == REPORT_TEMPLATE_VERSION;
+
+    let mut notes = Vec::new();
+    if !workspace_content_matches {
+        notes.push("Workspace content changed since the report was generated".to_string());
+    }
+    if !migrations_match {
+        notes.push("Applied migrations differ from the report manifest".to_string());
+    }
+    if !template_matches {
+        notes.push(format!(
+            "Report template changed: manifest={}; current={}",
+            manifest.template_version, REPORT_TEMPLATE_VERSION
+        ));
+    }
+    if draft_hashes != manifest.draft_artifact_hashes {
+        notes.push("Included AI draft artifacts differ from the report manifest".to_string());
+    }
+
+    Ok(ReportVerifyResult {
+        reproducible: actual_report_sha256 == manifest.report_sha256,
+        expected_report_sha256: manifest.report_sha256.clone(),
+        actual_report_sha256,
+        workspace_content_matches,
+        migrations_match,
+        template_matches,
+        notes,
+    })
+}
@@ -1,42 +1,56 @@
 use rusqlite::Connection;
 
-use crate::analytics::build_dashboard_payload_v2;
+use crate::analytics::{
+    anomaly_callouts, build_dashboard_payload_v2, detection_source_comparison, incident_lifecycle_funnel,
+    weekday_hour_heatmap, DashboardConfig, DetectionSourceComparison, PriorExposure, WeekdayHourMatrix, HEATMAP_WEEKDAYS,
+};
 use crate::error::AppError;
-use crate::metrics::compute_incident_metrics;
+use crate::metrics::{
+    compute_computed_metrics, compute_incident_metrics, format_duration_seconds, load_computed_metric_definitions,
+    percentile,
+};
 use crate::repo::list_incidents;
 use crate::validate::validate_incident;
 
-fn format_duration_seconds(secs: Option<i64>) -> String {
-    match secs {
-        None => "UNKNOWN".to_string(),
-        Some(s) => {
-            let minutes = s / 60;
-            let rem = s % 60;
-            if minutes >= 60 {
-                let hours = minutes / 60;
-                let m = minutes % 60;
-                format!("{hours}h {m}m")
-            } else if minutes > 0 {
-                format!("{minutes}m {rem}s")
-            } else {
-                format!("{rem}s")
-            }
-        }
-    }
-}
+mod determinism;
+pub use determinism::{
+    compare_outputs, verify_determinism, verify_determinism_with, DeterminismCheck, DeterminismMismatch,
+    DeterminismReport, IncidentIdMap,
+};
 
-fn percentile(values: &mut Vec<i64>, pct_num: i64, pct_den: i64) -> Option<i64> {
-    if values.is_empty() {
-        return None;
-    }
-    values.sort();
-    let n = values.len() as i64;
-    if n == 1 {
-        return Some(values[0]);
+mod digest;
+pub use digest::{
+    build_weekly_digest, export_digest_eml, render_digest_eml, DigestEmailOptions, DigestEmlExportResult,
+    DigestIncident, WeeklyDigest,
+};
+
+mod display_time;
+pub use display_time::{load_display_timezone, save_display_timezone, DisplayTimezone, DISPLAY_TIMEZONE_SETTING};
+use display_time::with_local;
+
+mod manifest;
+pub use manifest::{
+    export_qir_report, generate_qir_report, read_report_manifest, verify_qir_report,
+    GeneratedReport, OnCallReportMode, QirReportOptions, ReportExportResult, ReportManifest, ReportVerifyResult,
+    REPORT_MANIFEST_VERSION, REPORT_TEMPLATE_VERSION,
+};
+
+mod site;
+pub use site::{export_static_site, StaticSiteExportResult, StaticSiteOptions};
+
+/// Escapes text for HTML element content and double-quoted attributes.
+fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
     }
-    // Deterministic "nearest-rank on 0..n-1": idx = floor((n-1) * pct).
-    let idx = ((n - 1) * pct_num) / pct_den;
-    values.get(idx as usize).copied()
+    out
 }
 
 fn metric_summary_row(name: &str, vals: &[Option<i64>], total: i64) -> String {
@@ -56,10 +70,84 @@ fn metric_summary_row(name: &str, vals: &[Option<i64>], total: i64) -> String {
     )
 }
 
+/// States non-default vendor/service bucketing so readers know why a small name is listed or
+/// what OTHER holds. Prints nothing with the default config.
+fn render_bucket_rules(out: &mut String, cfg: &DashboardConfig) {
+    for (what, pinned) in [("vendors", &cfg.pinned_vendors), ("services", &cfg.pinned_services)] {
+        if !pinned.is_empty() {
+            out.push_str(&format!("_Always listed {what}: {}._\n", pinned.join(", ")));
+        }
+    }
+    if cfg.other_min_count > 1 {
+        out.push_str(&format!(
+            "_Unpinned vendors/services with fewer than {} incidents are grouped under OTHER._\n",
+            cfg.other_min_count
+        ));
+    }
+    if !cfg.pinned_vendors.is_empty() || !cfg.pinned_services.is_empty() || cfg.other_min_count > 1 {
+        out.push('\n');
+    }
+}
+
+fn render_detection_source_table(out: &mut String, cmp: &DetectionSourceComparison) {
+    out.push_str("### Response by detection source\n\n");
+    out.push_str("| Detection source | Incidents | MTTA known | MTTA P50 | MTTR known | MTTR P50 | MTTR P90 |\n");
+    out.push_str("|---|---:|---:|---:|---:|---:|---:|\n");
+    for s in &cmp.sources {
+        out.push_str(&format!(
+            "| {} | {} | {}/{} | {} | {}/{} | {} | {} |\n",
+            s.detection_source,
+            s.incident_count,
+            s.mtta.known_count,
+            s.incident_count,
+            format_duration_seconds(s.mtta.p50_seconds),
+            s.mttr.known_count,
+            s.incident_count,
+            format_duration_seconds(s.mttr.p50_seconds),
+            format_duration_seconds(s.mttr.p90_seconds)
+        ));
+    }
+    out.push('\n');
+    for c in &cmp.caveats {
+        out.push_str(&format!("- _{c}_\n"));
+    }
+    out.push('\n');
+}
+
+fn render_heatmap_table(out: &mut String, m: &WeekdayHourMatrix, what: &str) {
+    out.push_str("| Day |");
+    for h in 0..24 {
+        out.push_str(&format!(" {h:02} |"));
+    }
+    out.push_str(" Total |\n|---|");
+    out.push_str(&"---:|".repeat(25));
+    out.push('\n');
+    for (day, row) in HEATMAP_WEEKDAYS.iter().zip(&m.counts) {
+        out.push_str(&format!("| {day} |"));
+        for c in row {
+            if *c == 0 {
+                out.push_str(" - |");
+            } else {
+                out.push_str(&format!(" {c} |"));
+            }
+        }
+        out.push_str(&format!(" {} |\n", row.iter().sum::<i64>()));
+    }
+    out.push('\n');
+    if m.unknown_count > 0 {
+        out.push_str(&format!("- Unknown {what} time: {}\n\n", m.unknown_count));
+    }
+}
+
 /// Generate a deterministic QIR Markdown report from the current DB contents.
 ///
 /// Ordering rules are stable so outputs are snapshot-testable.
 pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
+    render_markdown(conn, None)
+}
+
+/// `prior` enables the first-appearance callouts for quarter-scoped reports.
+fn render_markdown(conn: &Connection, prior: Option<&PriorExposure>) -> Result<String, AppError> {
     let dash = build_dashboard_payload_v2(conn)?;
     let incidents = list_incidents(conn)?;
     let total = incidents.len() as i64;
@@ -84,6 +172,8 @@ pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
     let mut mtta = Vec::new();
     let mut ttm = Vec::new();
     let mut mttr = Vec::new();
+    let computed_defs = load_computed_metric_definitions(conn)?;
+    let mut computed: Vec<Vec<Option<i64>>> = vec![Vec::new(); computed_defs.len()];
 
     let mut warnings_by_incident: Vec<(i64, Vec<String>)> = Vec::new();
 
@@ -96,6 +186,9 @@ pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
         mtta.push(m.mtta_seconds);
         ttm.push(m.time_to_mitigation_seconds);
         mttr.push(m.mttr_seconds);
+        for (vals, secs) in computed.iter_mut().zip(compute_computed_metrics(inc, &computed_defs)) {
+            vals.push(secs);
+        }
 
         let mut codes: Vec<String> = metric_warnings
             .into_iter()
@@ -106,9 +199,18 @@ pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
         warnings_by_incident.push((inc.id, codes));
     }
 
+    let display_tz = load_display_timezone(conn)?;
+
     let mut out = String::new();
     out.push_str("# Quarterly Incident Review (QIR)\n\n");
     out.push_str(&format!("Incident count: **{}**\n\n", dash.incident_count));
+    if let Some(tz) = &display_tz {
+        out.push_str(&format!(
+            "_Timestamps are UTC; local times in parentheses are {} ({}). Metrics are computed in UTC._\n\n",
+            tz.label.trim(),
+            tz.offset_label()
+        ));
+    }
 
     out.push_str("## Executive summary\n\n");
     out.push_str(
@@ -124,6 +226,10 @@ pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
         "- Median time to resolve (P50 MTTR): **{}**\n",
         format_duration_seconds(mttr_p50)
     ));
+    let funnel = incident_lifecycle_funnel(&incidents);
+    for callout in anomaly_callouts(&dash, &funnel, prior) {
+        out.push_str(&format!("- {}\n", callout.message));
+    }
     out.push('\n');
 
     out.push_str("## Metrics summary (distributions)\n\n");
@@ -150,6 +256,10 @@ pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
         &mttr,
         total,
     ));
+    for (d, vals) in computed_defs.iter().zip(&computed) {
+        let name = format!("{} ({} → {})", d.label, d.from_field, d.to_field);
+        out.push_str(&metric_summary_row(&name, vals, total));
+    }
     out.push('\n');
 
     out.push_str("## Severity distribution\n\n");
@@ -169,8 +279,11 @@ pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
         out.push_str(&format!("- {}: {}\n", b.label, b.count));
     }
     out.push('\n');
+    render_detection_source_table(&mut out, &detection_source_comparison(&incidents));
 
     out.push_str("## Vendor/service reliability\n\n");
+    let dash_cfg = crate::analytics::load_dashboard_config(conn)?;
+    render_bucket_rules(&mut out, &dash_cfg);
     out.push_str("### Top vendors by incident count\n\n");
     for b in &dash.vendor_service_story.top_vendors_by_count {
         out.push_str(&format!("- {}: {}\n", b.label, b.count));
@@ -181,7 +294,17 @@ pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
         out.push_str(&format!("- {}: {}\n", b.label, b.count));
     }
     out.push('\n');
-    out.push_str("### Top vendors by weighted pain (impact × degradation × duration)\n\n");
+    let pain_multiplier_pct = dash_cfg.after_hours_pain_multiplier_pct;
+    if pain_multiplier_pct != 100 {
+        out.push_str(&format!(
+            "_Pain for incidents starting after hours or on weekends is weighted ×{}.{:02}._\n\n",
+            pain_multiplier_pct / 100,
+            pain_multiplier_pct % 100
+        ));
+    }
+    let formula = crate::analytics::load_pain_formula(conn)?;
+    out.push_str(&format!("_Pain formula {}: {}._\n\n", formula.version(), formula.describe()));
+    out.push_str(&format!("### Top vendors by weighted pain ({})\n\n", formula.scheme.label()));
     for b in &dash.vendor_service_story.top_vendors_by_pain {
         out.push_str(&format!(
             "- {}: pain_sum={}, incidents={}, pain_known={}\n",
@@ -189,7 +312,7 @@ pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
         ));
     }
     out.push('\n');
-    out.push_str("### Top services by weighted pain (impact × degradation × duration)\n\n");
+    out.push_str(&format!("### Top services by weighted pain ({})\n\n", formula.scheme.label()));
     for b in &dash.vendor_service_story.top_services_by_pain {
         out.push_str(&format!(
             "- {}: pain_sum={}, incidents={}, pain_known={}\n",
@@ -210,14 +333,34 @@ pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
     }
     out.push('\n');
 
+    let heatmap = weekday_hour_heatmap(&incidents, 0)?;
+    out.push_str("## Incident timing (weekday × hour, UTC)\n\n");
+    out.push_str("### Incident starts\n\n");
+    render_heatmap_table(&mut out, &heatmap.starts, "start");
+    out.push_str("### Detections (first observed)\n\n");
+    render_heatmap_table(&mut out, &heatmap.detections, "detection");
+
     out.push_str("## Incidents (stable ordering)\n\n");
     out.push_str(
         "_Sort keys:_ `external_id` (missing treated as empty), then `title`, then `id`.\n\n",
     );
-    out.push_str(
-        "| External ID | Title | Severity | Detection | Vendor | Service | MTTR | Warnings |\n",
-    );
-    out.push_str("|---|---|---|---|---|---|---:|---:|\n");
+    let start_by_id: std::collections::HashMap<i64, &str> = incidents
+        .iter()
+        .filter_map(|i| i.start_ts.as_deref().map(|ts| (i.id, ts)))
+        .collect();
+    // The Started column only appears with a display timezone, so UTC-only reports keep
+    // their layout.
+    if display_tz.is_some() {
+        out.push_str(
+            "| External ID | Title | Started | Severity | Detection | Vendor | Service | MTTR | Warnings |\n",
+        );
+        out.push_str("|---|---|---|---|---|---|---|---:|---:|\n");
+    } else {
+        out.push_str(
+            "| External ID | Title | Severity | Detection | Vendor | Service | MTTR | Warnings |\n",
+        );
+        out.push_str("|---|---|---|---|---|---|---:|---:|\n");
+    }
     for inc in &dash.incidents {
         let external = inc
             .external_id
@@ -233,10 +376,22 @@ pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
             .unwrap_or_else(|| "UNKNOWN".to_string());
         let vendor = inc.vendor.clone().unwrap_or_else(|| "UNKNOWN".to_string());
         let service = inc.service.clone().unwrap_or_else(|| "UNKNOWN".to_string());
+        // Started cell plus its separator; the column only exists with a display timezone.
+        let started = match &display_tz {
+            Some(tz) => format!(
+                " {} |",
+                start_by_id
+                    .get(&inc.id)
+                    .map(|ts| with_local(ts, Some(tz)))
+                    .unwrap_or_else(|| "UNKNOWN".to_string())
+            ),
+            None => String::new(),
+        };
         out.push_str(&format!(
-            "| {} | {} | {} | {} | {} | {} | {} | {} |\n",
+            "| {} | {} |{} {} | {} | {} | {} | {} | {} |\n",
             external,
             inc.title,
+            started,
             sev,
             det,
             vendor,
@@ -268,3 +423,190 @@ pub fn generate_qir_markdown(conn: &Connection) -> Result<String, AppError> {
 
     Ok(out)
 }
+
+/// Generate the QIR report as it would have been produced at `as_of`, using the incident
+/// field history ("what did we believe about these incidents at review time?").
+pub fn generate_qir_markdown_as_of(conn: &Connection, as_of: &str) -> Result<String, AppError> {
+    render_scoped_markdown(conn, None, Some(as_of))
+}
+
+/// Scoped rendering shared by as-of and quarter-scoped reports: the selected incidents are
+/// materialized into an in-memory workspace and the standard generator runs unchanged.
+pub(crate) fn render_scoped_markdown(
+    conn: &Connection,
+    quarter_label: Option<&str>,
+    as_of: Option<&str>,
+) -> Result<String, AppError> {
+    if quarter_label.is_none() && as_of.is_none() {
+        return generate_qir_markdown(conn);
+    }
+
+    let normalized_as_of = as_of.map(crate::history::normalize_as_of).transpose()?;
+    let mut incidents = match normalized_as_of.as_deref() {
+        Some(t) => crate::history::incidents_as_of(conn, t)?,
+        None => list_incidents(conn)?,
+    };
+    crate::aliases::AliasResolver::load(conn)?.apply(&mut incidents);
+    let mut prior = None;
+    if let Some(q) = quarter_label {
+        let scope = crate::quarters::resolve_quarter_scope(conn, q)?;
+        let anchor = crate::quarters::load_quarter_assignment_anchor(conn)?;
+        prior = Some(PriorExposure::before_scope(&incidents, &scope, anchor));
+        incidents.retain(|i| scope.contains_assigned(anchor, i));
+    }
+
+    let snapshot = crate::analytics::scoped_snapshot(conn, &incidents)?;
+    let md = render_markdown(&snapshot, prior.as_ref())?;
+
+    let mut header = String::from("# Quarterly Incident Review (QIR)\n\n");
+    if let Some(q) = quarter_label {
+        header.push_str(&format!("Quarter scope: **{q}**\n\n"));
+    }
+    if let Some(t) = normalized_as_of.as_deref() {
+        header.push_str(&format!(
+            "As of: **{t}** (reconstructed from incident field history)\n\n"
+        ));
+    }
+    let mut md = md.replacen("# Quarterly Incident Review (QIR)\n\n", &header, 1);
+    // Boundary placement is a property of current data, so as-of reports leave it out.
+    if let (Some(q), None) = (quarter_label, normalized_as_of.as_deref()) {
+        let display_tz = load_display_timezone(conn)?;
+        md.push('\n');
+        md.push_str(&render_boundary_section(
+            &crate::quarters::quarter_boundary_report(conn, Some(q))?,
+            display_tz.as_ref(),
+        ));
+        md.push('\n');
+        md.push_str(&render_carry_over_section(
+            &crate::quarters::quarter_carry_over(conn, q)?,
+            display_tz.as_ref(),
+        ));
+    }
+    Ok(md)
+}
+
+fn render_carry_over_incidents(
+    out: &mut String,
+    incidents: &[crate::quarters::CarryOverIncident],
+    tz: Option<&DisplayTimezone>,
+) {
+    if incidents.is_empty() {
+        out.push_str("- None.\n\n");
+        return;
+    }
+    out.push_str("| External ID | Title | Started | Origin quarter | Age (days) |\n");
+    out.push_str("|---|---|---|---|---:|\n");
+    for inc in incidents {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            inc.external_id.as_deref().unwrap_or("NO_EXTERNAL_ID"),
+            inc.title,
+            with_local(&inc.start_ts, tz),
+            inc.origin_quarter.as_deref().unwrap_or("UNKNOWN"),
+            inc.age_days
+        ));
+    }
+    out.push('\n');
+}
+
+fn render_carry_over_section(carry: &crate::quarters::QuarterCarryOver, tz: Option<&DisplayTimezone>) -> String {
+    let mut out = String::from("## Carry-over\n\n");
+    out.push_str("### Incidents open at quarter start\n\n");
+    render_carry_over_incidents(&mut out, &carry.open_at_start, tz);
+    out.push_str("### Incidents still open at quarter end\n\n");
+    render_carry_over_incidents(&mut out, &carry.open_at_end, tz);
+    out.push_str("### Action items carried over from previous quarters\n\n");
+    if carry.carried_action_items.is_empty() {
+        out.push_str("- None.\n");
+        return out;
+    }
+    out.push_str("| Action item | Owner | Raised | Origin quarter | Age (days) | Status at quarter end |\n");
+    out.push_str("|---|---|---|---|---:|---|\n");
+    for item in &carry.carried_action_items {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            item.title,
+            item.owner.as_deref().unwrap_or("UNASSIGNED"),
+            with_local(&item.created_at, tz),
+            item.origin_quarter.as_deref().unwrap_or("UNKNOWN"),
+            item.age_days,
+            if item.open_at_quarter_end { "open" } else { "closed" }
+        ));
+    }
+    out
+}
+
+fn render_on_call_section(load: &crate::analytics::OnCallLoad) -> String {
+    let hours = &load.business_hours;
+    let offset = hours.utc_offset_minutes;
+    let days: Vec<&str> = hours
+        .workdays
+        .iter()
+        .filter_map(|d| HEATMAP_WEEKDAYS.get(usize::from(*d).wrapping_sub(1)).copied())
+        .collect();
+    let mut out = String::from("## On-call load\n\n");
+    out.push_str(&format!(
+        "_Business hours {:02}:00-{:02}:00 UTC{}{:02}:{:02} on {}; engagements are classified by when they started._\n\n",
+        hours.start_hour,
+        hours.end_hour,
+        if offset < 0 { '-' } else { '+' },
+        offset.abs() / 60,
+        offset.abs() % 60,
+        days.join(", ")
+    ));
+    if load.anonymized {
+        out.push_str("- Names anonymized; responders are numbered by load.\n\n");
+    }
+    if load.responders.is_empty() {
+        out.push_str("- No responders recorded.\n");
+        return out;
+    }
+    out.push_str("| Responder | Incidents | Engagements | Time in incident | After hours | Weekend | Still engaged |\n");
+    out.push_str("|---|---:|---:|---:|---:|---:|---:|\n");
+    for r in &load.responders {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} |\n",
+            r.person,
+            r.incident_count,
+            r.engagement_count,
+            format_duration_seconds(Some(r.time_in_incident_seconds)),
+            r.after_hours_engagements,
+            r.weekend_engagements,
+            r.open_engagement_count
+        ));
+    }
+    out
+}
+
+fn render_boundary_section(
+    report: &crate::quarters::QuarterBoundaryReport,
+    tz: Option<&DisplayTimezone>,
+) -> String {
+    let rule = match report.anchor {
+        crate::quarters::QuarterAssignmentAnchor::StartTs => "start_ts",
+        crate::quarters::QuarterAssignmentAnchor::ResolveTs => "resolve_ts",
+    };
+    let mut out = String::from("## Quarter boundary incidents\n\n");
+    out.push_str(&format!(
+        "_Started and resolved in different quarters; assigned by `{rule}`._\n\n"
+    ));
+    if report.incidents.is_empty() {
+        out.push_str("- None.\n");
+        return out;
+    }
+    out.push_str("| External ID | Title | Started | Resolved | Assigned to |\n");
+    out.push_str("|---|---|---|---|---|\n");
+    for inc in &report.incidents {
+        out.push_str(&format!(
+            "| {} | {} | {} ({}) | {} ({}) | {} |\n",
+            inc.external_id.as_deref().unwrap_or("NO_EXTERNAL_ID"),
+            inc.title,
+            inc.start_quarter,
+            with_local(&inc.start_ts, tz),
+            inc.resolve_quarter,
+            with_local(&inc.resolve_ts, tz),
+            inc.assigned_quarter
+        ));
+    }
+    out
+}
@@ -0,0 +1,394 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use super::display_time::with_local;
+use crate::backup::filename_safe_timestamp;
+use super::{escape_html, load_display_timezone, DisplayTimezone};
+use crate::analytics::{build_dashboard_payload_v2, DashboardPayloadV2};
+use crate::diagrams::{get_incident_diagram, read_verified_diagram_bytes, IncidentDiagram};
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::metrics::{compute_incident_metrics, format_duration_seconds};
+use crate::progress::Progress;
+use crate::repo::{list_incidents, list_timeline_events_for_incident};
+
+const SITE_CSS: &str = "body{font-family:system-ui,sans-serif;margin:2rem auto;max-width:60rem;padding:0 1rem;color:#1d2430}\n\
+nav a{margin-right:1rem}\n\
+table{border-collapse:collapse;width:100%}\n\
+th,td{border-bottom:1px solid #d5dae1;padding:.35rem .5rem;text-align:left;vertical-align:top}\n\
+.num{text-align:right}\n\
+.muted{color:#667085}\n\
+.chart{margin:1rem 0 2rem}\n\
+figure{margin:1rem 0}\n\
+figure img{max-width:100%;height:auto;border:1px solid #d5dae1}\n\
+.highlights{display:grid;grid-template-columns:repeat(auto-fill,minmax(16rem,1fr));gap:1rem}\n";
+
+const CHART_BAR_HEIGHT: usize = 22;
+const CHART_LABEL_WIDTH: usize = 180;
+const CHART_BAR_WIDTH: usize = 360;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StaticSiteOptions {
+    /// Restrict the site to incidents in this quarter (defined or calendar label). None = all incidents.
+    pub quarter_label: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StaticSiteExportResult {
+    pub site_dir: String,
+    pub incident_count: i64,
+    /// Relative paths of every written file, sorted.
+    pub files: Vec<String>,
+}
+
+/// Incident page path relative to the site root; ids keep names unique and stable.
+fn incident_page(id: i64) -> String {
+    format!("incidents/incident-{id}.html")
+}
+
+fn page(title: &str, root: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+<link rel=\"stylesheet\" href=\"{root}style.css\">\n</head>\n<body>\n\
+<nav><a href=\"{root}index.html\">Incidents</a><a href=\"{root}dashboard.html\">Dashboard</a></nav>\n\
+{body}</body>\n</html>\n",
+        title = escape_html(title),
+    )
+}
+
+/// Horizontal bar chart as inline SVG, one bar per `(label, count)`, scaled to the largest count.
+fn svg_bar_chart(title: &str, bars: &[(String, i64)]) -> String {
+    let mut out = format!("<h2>{}</h2>\n", escape_html(title));
+    if bars.is_empty() {
+        out.push_str("<p class=\"muted\">No data.</p>\n");
+        return out;
+    }
+    let max = bars.iter().map(|(_, c)| *c).max().unwrap_or(0).max(1);
+    let width = CHART_LABEL_WIDTH + CHART_BAR_WIDTH + 60;
+    let height = bars.len() * CHART_BAR_HEIGHT;
+    out.push_str(&format!(
+        "<svg class=\"chart\" role=\"img\" aria-label=\"{}\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\" xmlns=\"http://www.w3.org/2000/svg\">\n",
+        escape_html(title)
+    ));
+    for (idx, (label, count)) in bars.iter().enumerate() {
+        let y = idx * CHART_BAR_HEIGHT;
+        let bar = (*count).max(0) as usize * CHART_BAR_WIDTH / max as usize;
+        out.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"end\" font-size=\"12\">{}</text>\
+<rect x=\"{CHART_LABEL_WIDTH}\" y=\"{}\" width=\"{bar}\" height=\"{}\" fill=\"#3b6fd8\"/>\
+<text x=\"{}\" y=\"{}\" font-size=\"12\">{count}</text>\n",
+            CHART_LABEL_WIDTH - 6,
+            y + 15,
+            escape_html(label),
+            y + 3,
+            CHART_BAR_HEIGHT - 6,
+            CHART_LABEL_WIDTH + bar + 6,
+            y + 15,
+        ));
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// `<figure>` for an incident diagram; `root` is the relative path back to the site root.
+fn diagram_figure(diagram: &IncidentDiagram, root: &str, alt: &str) -> String {
+    let caption = diagram
+        .caption
+        .as_deref()
+        .map(|c| format!("<figcaption>{}</figcaption>", escape_html(c)))
+        .unwrap_or_default();
+    format!(
+        "<figure><img src=\"{root}{}\" alt=\"{}\">{caption}</figure>\n",
+        diagram.rel_path(),
+        escape_html(alt)
+    )
+}
+
+fn render_index(
+    dash: &DashboardPayloadV2,
+    scope: Option<&str>,
+    generated_at: &str,
+    diagrams: &BTreeMap<i64, IncidentDiagram>,
+) -> String {
+    let mut body = String::from("<h1>Quarterly Incident Review</h1>\n");
+    if let Some(q) = scope {
+        body.push_str(&format!("<p>Quarter scope: <strong>{}</strong></p>\n", escape_html(q)));
+    }
+    body.push_str(&format!(
+        "<p>Incident count: <strong>{}</strong>. Generated {} from the incident database.</p>\n",
+        dash.incident_count,
+        escape_html(generated_at)
+    ));
+    body.push_str("<table>\n<tr><th>External ID</th><th>Title</th><th>Severity</th><th>Service</th><th class=\"num\">MTTR</th></tr>\n");
+    for inc in &dash.incidents {
+        body.push_str(&format!(
+            "<tr><td>{}</td><td><a href=\"{}\">{}</a></td><td>{}</td><td>{}</td><td class=\"num\">{}</td></tr>\n",
+            escape_html(inc.external_id.as_deref().unwrap_or("NO_EXTERNAL_ID")),
+            incident_page(inc.id),
+            escape_html(&inc.title),
+            escape_html(inc.severity.as_deref().unwrap_or("UNKNOWN")),
+            escape_html(inc.service.as_deref().unwrap_or("UNKNOWN")),
+            escape_html(&format_duration_seconds(inc.mttr_seconds)),
+        ));
+    }
+    body.push_str("</table>\n");
+
+    let highlighted: Vec<_> = dash
+        .incidents
+        .iter()
+        .filter_map(|inc| diagrams.get(&inc.id).map(|d| (inc, d)))
+        .collect();
+    if !highlighted.is_empty() {
+        body.push_str("<h2>Highlights</h2>\n<div class=\"highlights\">\n");
+        for (inc, diagram) in highlighted {
+            body.push_str(&format!(
+                "<div><a href=\"{}\">{}</a>\n{}</div>\n",
+                incident_page(inc.id),
+                escape_html(&inc.title),
+                diagram_figure(diagram, "", &format!("Diagram for {}", inc.title)),
+            ));
+        }
+        body.push_str("</div>\n");
+    }
+    page("Quarterly Incident Review", "", &body)
+}
+
+fn render_dashboard(dash: &DashboardPayloadV2) -> String {
+    let mut body = String::from("<h1>Dashboard</h1>\n");
+    let severity: Vec<(String, i64)> = dash.severity_counts.iter().map(|s| (s.severity.clone(), s.count)).collect();
+    body.push_str(&svg_bar_chart("Incidents by severity", &severity));
+    let category = |buckets: &[crate::analytics::CategoryBucket]| -> Vec<(String, i64)> {
+        buckets.iter().map(|b| (b.label.clone(), b.count)).collect()
+    };
+    let duration = |buckets: &[crate::analytics::DurationBucket]| -> Vec<(String, i64)> {
+        buckets.iter().map(|b| (b.label.clone(), b.count)).collect()
+    };
+    body.push_str(&svg_bar_chart(
+        "Detection source mix",
+        &category(&dash.detection_story.detection_source_mix),
+    ));
+    body.push_str(&svg_bar_chart(
+        "Time to resolve",
+        &duration(&dash.response_story.time_to_resolve_buckets),
+    ));
+    body.push_str(&svg_bar_chart(
+        "Top vendors by incident count",
+        &category(&dash.vendor_service_story.top_vendors_by_count),
+    ));
+    body.push_str(&svg_bar_chart(
+        "Top services by incident count",
+        &category(&dash.vendor_service_story.top_services_by_count),
+    ));
+    page("Dashboard", "", &body)
+}
+
+fn render_incident(
+    conn: &Connection,
+    inc: &Incident,
+    diagram: Option<&IncidentDiagram>,
+    tz: Option<&DisplayTimezone>,
+) -> Result<String, AppError> {
+    let (m, _) = compute_incident_metrics(inc);
+    let mut body = format!(
+        "<h1>{}: {}</h1>\n",
+        escape_html(inc.external_id.as_deref().unwrap_or("NO_EXTERNAL_ID")),
+        escape_html(&inc.title)
+    );
+    let text = |v: &Option<String>| escape_html(v.as_deref().unwrap_or("UNKNOWN"));
+    let ts = |v: &Option<String>| {
+        escape_html(
+            &v.as_deref()
+                .map(|t| with_local(t, tz))
+                .unwrap_or_else(|| "UNKNOWN".to_string()),
+        )
+    };
+    body.push_str("<table>\n");
+    for (label, value) in [
+        ("Severity", text(&inc.severity)),
+        ("Detection source", text(&inc.detection_source)),
+        ("Vendor", text(&inc.vendor)),
+        ("Service", text(&inc.service)),
+        ("Started", ts(&inc.start_ts)),
+        ("First observed", ts(&inc.first_observed_ts)),
+        ("IT aware", ts(&inc.it_awareness_ts)),
+        ("Acknowledged", ts(&inc.ack_ts)),
+        ("Mitigated", ts(&inc.mitigate_ts)),
+        ("Resolved", ts(&inc.resolve_ts)),
+        ("MTTD", escape_html(&format_duration_seconds(m.mttd_seconds))),
+        ("MTTA", escape_html(&format_duration_seconds(m.mtta_seconds))),
+        ("Time to mitigation", escape_html(&format_duration_seconds(m.time_to_mitigation_seconds))),
+        ("MTTR", escape_html(&format_duration_seconds(m.mttr_seconds))),
+    ] {
+        body.push_str(&format!("<tr><th>{label}</th><td>{value}</td></tr>\n"));
+    }
+    body.push_str("</table>\n");
+    if let Some(diagram) = diagram {
+        body.push_str("<h2>Diagram</h2>\n");
+        body.push_str(&diagram_figure(diagram, "../", &format!("Diagram for {}", inc.title)));
+    }
+    if let Some(d) = inc.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        body.push_str(&format!("<h2>Description</h2>\n<pre>{}</pre>\n", escape_html(d)));
+    }
+
+    body.push_str("<h2>Timeline</h2>\n");
+    let events = list_timeline_events_for_incident(conn, inc.id)?;
+    if events.is_empty() {
+        body.push_str("<p class=\"muted\">No timeline events.</p>\n");
+    } else {
+        body.push_str("<table>\n<tr><th>Time</th><th>Source</th><th>Author</th><th>Text</th></tr>\n");
+        for e in &events {
+            body.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                ts(&e.ts),
+                escape_html(&e.source),
+                escape_html(e.author.as_deref().unwrap_or("")),
+                escape_html(&e.text),
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+    let title = format!("{} {}", inc.external_id.as_deref().unwrap_or("NO_EXTERNAL_ID"), inc.title);
+    Ok(page(&title, "../", &body))
+}
+
+fn write_file(dir: &Path, rel: &str, contents: impl AsRef<[u8]>) -> Result<(), AppError> {
+    let path = dir.join(rel);
+    fs::write(&path, contents).map_err(|e| {
+        AppError::new("EXPORT_SITE_WRITE_FAILED", "Failed to write static site file")
+            .with_details(format!("path={}: {}", path.display(), e))
+    })
+}
+
+/// Writes the site into `site_dir` and returns the incident count and relative file paths.
+fn write_site_files(
+    conn: &Connection,
+    opts: &StaticSiteOptions,
+    artifacts_dir: Option<&Path>,
+    site_dir: &Path,
+    generated_at: &str,
+    progress: &mut Progress<'_>,
+) -> Result<(i64, Vec<String>), AppError> {
+    progress.stage("incidents", Some(5));
+    let mut incidents = list_incidents(conn)?;
+    let dash = match opts.quarter_label.as_deref() {
+        Some(q) => {
+            let scope = crate::quarters::resolve_quarter_scope(conn, q)?;
+            let anchor = crate::quarters::load_quarter_assignment_anchor(conn)?;
+            incidents.retain(|i| scope.contains_assigned(anchor, i));
+            build_dashboard_payload_v2(&crate::analytics::scoped_snapshot(conn, &incidents)?)?
+        }
+        None => build_dashboard_payload_v2(conn)?,
+    };
+    let tz = load_display_timezone(conn)?;
+
+    let incidents_dir = site_dir.join("incidents");
+    fs::create_dir_all(&incidents_dir).map_err(|e| {
+        AppError::new("EXPORT_SITE_MKDIR_FAILED", "Failed to create static site directory")
+            .with_details(format!("path={}: {}", incidents_dir.display(), e))
+    })?;
+
+    let mut files = Vec::new();
+    let mut diagrams = BTreeMap::new();
+    for (idx, inc) in incidents.iter().enumerate() {
+        progress.step("incidents", idx, incidents.len(), 10, 90);
+        let diagram = get_incident_diagram(conn, inc.id)?;
+        if let Some(d) = diagram.as_ref() {
+            let artifacts_dir = artifacts_dir.ok_or_else(|| {
+                AppError::new("EXPORT_DIAGRAM_INVALID", "Incident diagram cannot be read without an artifacts folder")
+                    .with_details(format!("incident_id={}", inc.id))
+            })?;
+            // Identical images are stored once; the copy is hash-checked before it is written.
+            let rel = d.rel_path();
+            if !files.contains(&rel) {
+                let bytes = read_verified_diagram_bytes(artifacts_dir, d)?;
+                fs::create_dir_all(site_dir.join(crate::diagrams::DIAGRAMS_DIRNAME)).map_err(|e| {
+                    AppError::new("EXPORT_SITE_MKDIR_FAILED", "Failed to create static site directory")
+                        .with_details(format!("path={}: {}", site_dir.display(), e))
+                })?;
+                write_file(site_dir, &rel, bytes)?;
+                files.push(rel);
+            }
+        }
+        let rel = incident_page(inc.id);
+        write_file(site_dir, &rel, render_incident(conn, inc, diagram.as_ref(), tz.as_ref())?)?;
+        files.push(rel);
+        if let Some(d) = diagram {
+            diagrams.insert(inc.id, d);
+        }
+    }
+
+    progress.stage("pages", Some(90));
+    write_file(site_dir, "style.css", SITE_CSS)?;
+    write_file(
+        site_dir,
+        "index.html",
+        render_index(&dash, opts.quarter_label.as_deref(), generated_at, &diagrams),
+    )?;
+    write_file(site_dir, "dashboard.html", render_dashboard(&dash))?;
+    files.extend(["dashboard.html", "index.html", "style.css"].map(str::to_string));
+    files.sort();
+    Ok((incidents.len() as i64, files))
+}
+
+/// Exports the review as a self-contained static site (index, dashboard with inline SVG charts,
+/// one page per incident) into a new `IncidentReviewSite_<ts>` folder under `destination_dir`.
+/// Pages only link to each other with relative paths, so the folder can be served from any
+/// static file share. Incident diagrams are copied from `artifacts_dir` into `diagrams/`.
+pub fn export_static_site(
+    conn: &Connection,
+    opts: &StaticSiteOptions,
+    artifacts_dir: Option<&Path>,
+    destination_dir: &Path,
+    generated_at: &str,
+    progress: &mut Progress<'_>,
+) -> Result<StaticSiteExportResult, AppError> {
+    progress.run(|progress| {
+        if !destination_dir.is_dir() {
+            return Err(AppError::new(
+                "EXPORT_SITE_DEST_NOT_DIR",
+                "Static site export destination must be an existing directory",
+            )
+            .with_details(destination_dir.display().to_string()));
+        }
+        let site_dir = destination_dir.join(format!("IncidentReviewSite_{}", filename_safe_timestamp(generated_at)));
+        if site_dir.exists() {
+            return Err(AppError::new(
+                "EXPORT_SITE_DEST_EXISTS",
+                "Static site export destination folder already exists",
+            )
+            .with_details(site_dir.display().to_string()));
+        }
+
+        // Staged next to the final folder and renamed at the end, like the sanitized export,
+        // so a failure never leaves half a site under the final name.
+        let staging_dir = site_dir.with_extension("export_tmp");
+        if staging_dir.exists() {
+            fs::remove_dir_all(&staging_dir).map_err(|e| {
+                AppError::new("EXPORT_SITE_MKDIR_FAILED", "Failed to remove leftover static site staging directory")
+                    .with_details(format!("path={}: {}", staging_dir.display(), e))
+            })?;
+        }
+        let (incident_count, files) = match write_site_files(conn, opts, artifacts_dir, &staging_dir, generated_at, progress) {
+            Ok(written) => written,
+            Err(e) => {
+                let _ = fs::remove_dir_all(&staging_dir);
+                return Err(e);
+            }
+        };
+        fs::rename(&staging_dir, &site_dir).map_err(|e| {
+            let _ = fs::remove_dir_all(&staging_dir);
+            AppError::new("EXPORT_SITE_FINALIZE_FAILED", "Failed to move staged static site into place")
+                .with_details(format!("src={} dst={}: {}", staging_dir.display(), site_dir.display(), e))
+        })?;
+
+        Ok(StaticSiteExportResult {
+            site_dir: site_dir.to_string_lossy().to_string(),
+            incident_count,
+            files,
+        })
+    })
+}
@@ -0,0 +1,149 @@
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentResponder {
+    pub id: i64,
+    pub incident_id: i64,
+    pub person: String,
+    pub role: Option<String>,
+    pub engaged_at: String,
+    pub released_at: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AddResponderInput {
+    pub incident_id: i64,
+    pub person: String,
+    #[serde(default)]
+    pub role: Option<String>,
+    pub engaged_at: String,
+    #[serde(default)]
+    pub released_at: Option<String>,
+}
+
+fn invalid(message: &str, details: String) -> AppError {
+    AppError::new("VALIDATION_RESPONDER_INVALID", message).with_details(details)
+}
+
+fn canonical_ts(field: &str, ts: &str) -> Result<String, AppError> {
+    let dt = OffsetDateTime::parse(ts.trim(), &Rfc3339)
+        .map_err(|e| invalid("Responder timestamps must be RFC3339", format!("{field}={ts}; err={e}")))?
+        .to_offset(UtcOffset::UTC);
+    dt.replace_nanosecond(0)
+        .unwrap_or(dt)
+        .format(&Rfc3339)
+        .map_err(|e| invalid("Failed to format responder timestamp", e.to_string()))
+}
+
+const SELECT_COLUMNS: &str =
+    "SELECT id, incident_id, person, role, engaged_at, released_at, created_at FROM incident_responders";
+
+fn map_row(row: &rusqlite::Row<'_>) -> Result<IncidentResponder, rusqlite::Error> {
+    Ok(IncidentResponder {
+        id: row.get(0)?,
+        incident_id: row.get(1)?,
+        person: row.get(2)?,
+        role: row.get(3)?,
+        engaged_at: row.get(4)?,
+        released_at: row.get(5)?,
+        created_at: row.get(6)?,
+    })
+}
+
+pub fn get_responder(conn: &Connection, id: i64) -> Result<Option<IncidentResponder>, AppError> {
+    conn.query_row(&format!("{SELECT_COLUMNS} WHERE id = ?1"), [id], map_row)
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query responder")
+                .with_details(format!("id={id}; err={e}"))
+        })
+}
+
+/// Records one engagement. The same person may engage several times on one incident
+/// (e.g. handed off and re-paged); each engagement is its own row.
+pub fn add_responder(conn: &Connection, input: AddResponderInput) -> Result<IncidentResponder, AppError> {
+    let exists: Option<i64> = conn
+        .query_row("SELECT id FROM incidents WHERE id = ?1", [input.incident_id], |row| row.get(0))
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to check incident exists").with_details(e.to_string())
+        })?;
+    if exists.is_none() {
+        return Err(AppError::new("DB_NOT_FOUND", "Incident not found for responder")
+            .with_details(format!("incident_id={}", input.incident_id)));
+    }
+    crate::freeze::ensure_incident_mutable(conn, input.incident_id)?;
+    let person = input.person.trim();
+    if person.is_empty() {
+        return Err(invalid("Responder name is required", format!("incident_id={}", input.incident_id)));
+    }
+    let role = input.role.as_deref().map(str::trim).filter(|r| !r.is_empty());
+    let engaged_at = canonical_ts("engaged_at", &input.engaged_at)?;
+    let released_at = input
+        .released_at
+        .as_deref()
+        .map(|t| canonical_ts("released_at", t))
+        .transpose()?;
+    if let Some(released) = released_at.as_deref() {
+        if released < engaged_at.as_str() {
+            return Err(invalid(
+                "Responder cannot be released before being engaged",
+                format!("engaged_at={engaged_at}; released_at={released}"),
+            ));
+        }
+    }
+
+    conn.execute(
+        "INSERT INTO incident_responders(incident_id, person, role, engaged_at, released_at, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, strftime('%Y-%m-%dT%H:%M:%fZ','now'))",
+        params![input.incident_id, person, role, engaged_at, released_at],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to store responder")
+            .with_details(format!("incident_id={}; person={person}; err={e}", input.incident_id))
+    })?;
+    get_responder(conn, conn.last_insert_rowid())?.ok_or_else(|| {
+        AppError::new("DB_NOT_FOUND", "Responder not found after insert")
+    })
+}
+
+/// Engagements, optionally for one incident, ordered by engagement time.
+pub fn list_responders(conn: &Connection, incident_id: Option<i64>) -> Result<Vec<IncidentResponder>, AppError> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "{SELECT_COLUMNS} WHERE (?1 IS NULL OR incident_id = ?1) ORDER BY engaged_at ASC, id ASC"
+        ))
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare responder list query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt.query_map([incident_id], map_row).map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query responders").with_details(e.to_string())
+    })?;
+    let mut out = Vec::new();
+    for r in rows {
+        out.push(r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode responder row").with_details(e.to_string())
+        })?);
+    }
+    Ok(out)
+}
+
+pub fn remove_responder(conn: &Connection, id: i64) -> Result<(), AppError> {
+    let existing = get_responder(conn, id)?.ok_or_else(|| {
+        AppError::new("DB_NOT_FOUND", "Responder not found").with_details(format!("id={id}"))
+    })?;
+    crate::freeze::ensure_incident_mutable(conn, existing.incident_id)?;
+    conn.execute("DELETE FROM incident_responders WHERE id = ?1", [id])
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to remove responder")
+                .with_details(format!("id={id}; err={e}"))
+        })?;
+    Ok(())
+}
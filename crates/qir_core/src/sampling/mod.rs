@@ -0,0 +1,273 @@
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::freeze::incident_quarter_label;
+use crate::quarters::{
+    load_quarter_assignment_anchor, resolve_quarter_scope, QuarterAssignmentAnchor, QuarterScope,
+};
+use crate::repo;
+
+pub const SAMPLE_MAX_N: u32 = 1000;
+
+/// Exact-match filter over the incident population. `None` means "any". Use the literal
+/// "UNKNOWN" to select incidents where the field is missing (matching dashboard buckets).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentSampleFilter {
+    pub severity: Option<String>,
+    pub detection_source: Option<String>,
+    pub vendor: Option<String>,
+    pub service: Option<String>,
+    pub quarter_label: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentSample {
+    pub id: i64,
+    pub seed: String,
+    pub requested_n: u32,
+    pub filter: IncidentSampleFilter,
+    pub population_count: i64,
+    /// Incident ids in sample order (ascending seeded rank).
+    pub incident_ids: Vec<i64>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentSampleReplay {
+    pub sample: IncidentSample,
+    pub current_population_count: i64,
+    pub current_incident_ids: Vec<i64>,
+    pub matches_recorded: bool,
+}
+
+fn field_matches(want: &Option<String>, have: &Option<String>) -> bool {
+    match want.as_deref() {
+        None => true,
+        Some("UNKNOWN") => have.as_deref().map(|s| s.trim().is_empty()).unwrap_or(true),
+        Some(w) => have.as_deref() == Some(w),
+    }
+}
+
+/// How the quarter part of a filter is evaluated: "UNKNOWN" selects incidents with no usable
+/// anchor timestamp, other labels resolve through the quarters table / workspace calendar.
+enum QuarterFilter {
+    Any,
+    Unknown,
+    Scope(Option<QuarterScope>, QuarterAssignmentAnchor),
+}
+
+fn quarter_filter(conn: &Connection, filter: &IncidentSampleFilter) -> Result<QuarterFilter, AppError> {
+    match filter.quarter_label.as_deref() {
+        None => Ok(QuarterFilter::Any),
+        Some("UNKNOWN") => Ok(QuarterFilter::Unknown),
+        // An unknown label matches nothing rather than failing the sample.
+        Some(label) => {
+            let anchor = load_quarter_assignment_anchor(conn)?;
+            match resolve_quarter_scope(conn, label) {
+                Ok(scope) => Ok(QuarterFilter::Scope(Some(scope), anchor)),
+                Err(e) if e.code == "QUARTER_NOT_FOUND" => Ok(QuarterFilter::Scope(None, anchor)),
+                Err(e) => Err(e),
+            }
+        }
+    }
+}
+
+fn incident_matches(filter: &IncidentSampleFilter, quarter: &QuarterFilter, incident: &Incident) -> bool {
+    let start = incident.start_ts.as_deref();
+    let first_observed = incident.first_observed_ts.as_deref();
+    let quarter_ok = match quarter {
+        QuarterFilter::Any => true,
+        QuarterFilter::Unknown => incident_quarter_label(start, first_observed).is_none(),
+        QuarterFilter::Scope(scope, anchor) => scope
+            .as_ref()
+            .map(|s| s.contains_assigned(*anchor, incident))
+            .unwrap_or(false),
+    };
+    field_matches(&filter.severity, &incident.severity)
+        && field_matches(&filter.detection_source, &incident.detection_source)
+        && field_matches(&filter.vendor, &incident.vendor)
+        && field_matches(&filter.service, &incident.service)
+        && quarter_ok
+}
+
+fn seeded_rank(seed: &str, incident_id: i64) -> String {
+    hex::encode(Sha256::digest(format!("sample_v1|seed={seed}|id={incident_id}").as_bytes()))
+}
+
+/// Pure selection: each incident gets a rank of sha256(seed, id) and the n lowest ranks win.
+/// Independent of row order, so the same seed + population always yields the same sample.
+fn select(conn: &Connection, n: u32, seed: &str, filter: &IncidentSampleFilter) -> Result<(i64, Vec<i64>), AppError> {
+    let quarter = quarter_filter(conn, filter)?;
+    let population: Vec<Incident> = repo::list_incidents(conn)?
+        .into_iter()
+        .filter(|i| incident_matches(filter, &quarter, i))
+        .collect();
+    let mut ranked: Vec<(String, i64)> = population
+        .iter()
+        .map(|i| (seeded_rank(seed, i.id), i.id))
+        .collect();
+    ranked.sort();
+    let ids = ranked
+        .into_iter()
+        .take(n as usize)
+        .map(|(_, id)| id)
+        .collect();
+    Ok((population.len() as i64, ids))
+}
+
+pub fn sample_incidents(
+    conn: &Connection,
+    n: u32,
+    seed: &str,
+    filter: &IncidentSampleFilter,
+    created_at: &str,
+) -> Result<IncidentSample, AppError> {
+    if n == 0 || n > SAMPLE_MAX_N {
+        return Err(AppError::new(
+            "VALIDATION_SAMPLE_SIZE_INVALID",
+            format!("Sample size must be between 1 and {SAMPLE_MAX_N}"),
+        )
+        .with_details(format!("n={n}")));
+    }
+    if seed.trim().is_empty() {
+        return Err(AppError::new(
+            "VALIDATION_SAMPLE_SEED_REQUIRED",
+            "A sample seed is required so the sample can be reproduced",
+        ));
+    }
+
+    let (population_count, incident_ids) = select(conn, n, seed, filter)?;
+
+    let filter_json = serde_json::to_string(filter).map_err(|e| {
+        AppError::new("DB_ENCODE_FAILED", "Failed to encode sample filter JSON")
+            .with_details(e.to_string())
+    })?;
+    let ids_json = serde_json::to_string(&incident_ids).map_err(|e| {
+        AppError::new("DB_ENCODE_FAILED", "Failed to encode sample incident ids JSON")
+            .with_details(e.to_string())
+    })?;
+    conn.execute(
+        "INSERT INTO incident_samples(seed, requested_n, filter_json, population_count, incident_ids_json, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        rusqlite::params![seed, n, filter_json, population_count, ids_json, created_at],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to record incident sample")
+            .with_details(e.to_string())
+    })?;
+
+    Ok(IncidentSample {
+        id: conn.last_insert_rowid(),
+        seed: seed.to_string(),
+        requested_n: n,
+        filter: filter.clone(),
+        population_count,
+        incident_ids,
+        created_at: created_at.to_string(),
+    })
+}
+
+type SampleRow = (i64, String, u32, String, i64, String, String);
+
+fn decode_sample(row: SampleRow) -> Result<IncidentSample, AppError> {
+    let (id, seed, requested_n, filter_json, population_count, ids_json, created_at) = row;
+    let filter = serde_json::from_str(&filter_json).map_err(|e| {
+        AppError::new("DB_DECODE_FAILED", "Failed to decode sample filter JSON")
+            .with_details(format!("sample_id={id}; err={e}"))
+    })?;
+    let incident_ids = serde_json::from_str(&ids_json).map_err(|e| {
+        AppError::new("DB_DECODE_FAILED", "Failed to decode sample incident ids JSON")
+            .with_details(format!("sample_id={id}; err={e}"))
+    })?;
+    Ok(IncidentSample {
+        id,
+        seed,
+        requested_n,
+        filter,
+        population_count,
+        incident_ids,
+        created_at,
+    })
+}
+
+pub fn get_incident_sample(conn: &Connection, sample_id: i64) -> Result<IncidentSample, AppError> {
+    let row: Option<SampleRow> = conn
+        .query_row(
+            "SELECT id, seed, requested_n, filter_json, population_count, incident_ids_json, created_at FROM incident_samples WHERE id = ?1",
+            [sample_id],
+            |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                    row.get(5)?,
+                    row.get(6)?,
+                ))
+            },
+        )
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query incident sample")
+                .with_details(e.to_string())
+        })?;
+    let row = row.ok_or_else(|| {
+        AppError::new("DB_NOT_FOUND", "Incident sample not found")
+            .with_details(format!("sample_id={sample_id}"))
+    })?;
+    decode_sample(row)
+}
+
+pub fn list_incident_samples(conn: &Connection) -> Result<Vec<IncidentSample>, AppError> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, seed, requested_n, filter_json, population_count, incident_ids_json, created_at FROM incident_samples ORDER BY id ASC",
+        )
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare incident sample list query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+                row.get(5)?,
+                row.get(6)?,
+            ))
+        })
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query incident samples")
+                .with_details(e.to_string())
+        })?;
+    let mut out = Vec::new();
+    for r in rows {
+        let row: SampleRow = r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode incident sample row")
+                .with_details(e.to_string())
+        })?;
+        out.push(decode_sample(row)?);
+    }
+    Ok(out)
+}
+
+/// Re-runs a recorded sample against the current data. `matches_recorded=false` means the
+/// population changed since the sample was drawn (new/edited/deleted incidents).
+pub fn replay_incident_sample(conn: &Connection, sample_id: i64) -> Result<IncidentSampleReplay, AppError> {
+    let sample = get_incident_sample(conn, sample_id)?;
+    let (current_population_count, current_incident_ids) =
+        select(conn, sample.requested_n, &sample.seed, &sample.filter)?;
+    let matches_recorded = current_incident_ids == sample.incident_ids;
+    Ok(IncidentSampleReplay {
+        sample,
+        current_population_count,
+        current_incident_ids,
+        matches_recorded,
+    })
+}
@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::metrics::{format_duration_seconds, percentile};
+
+use super::import::{inspect_sanitized_dataset, read_json_file};
+use super::SanitizedIncident;
+
+/// Datasets are labelled TEAM_A..TEAM_Z, so one benchmark covers at most 26 teams.
+pub const MAX_BENCHMARK_DATASETS: usize = 26;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BenchmarkDataset {
+    /// Anonymized label; dataset paths and export folder names are never reported.
+    pub label: String,
+    pub export_time: String,
+    pub incident_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BenchmarkDurationStats {
+    pub count: usize,
+    pub p50_seconds: Option<i64>,
+    pub p90_seconds: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BenchmarkDatasetMttr {
+    pub label: String,
+    pub mttr: BenchmarkDurationStats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BenchmarkSeverityMttr {
+    /// "UNKNOWN" when the incident had no severity.
+    pub severity: String,
+    pub incident_count: usize,
+    pub mttr: BenchmarkDurationStats,
+    pub by_dataset: Vec<BenchmarkDatasetMttr>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BenchmarkDetectionMix {
+    pub label: String,
+    pub incident_count: usize,
+    /// Share of incidents with a recorded detection source, in percent.
+    pub detection_known_pct: f64,
+    pub distinct_sources: usize,
+    /// Share held by the dataset's most common detection source, in percent.
+    pub top_source_pct: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SanitizedBenchmark {
+    pub datasets: Vec<BenchmarkDataset>,
+    pub incident_count: usize,
+    pub mttr_overall: BenchmarkDurationStats,
+    pub mttr_by_severity: Vec<BenchmarkSeverityMttr>,
+    pub detection_mix: Vec<BenchmarkDetectionMix>,
+}
+
+fn duration_stats(values: &[i64]) -> BenchmarkDurationStats {
+    let mut v = values.to_vec();
+    BenchmarkDurationStats {
+        count: v.len(),
+        p50_seconds: percentile(&mut v, 1, 2),
+        p90_seconds: percentile(&mut v, 9, 10),
+    }
+}
+
+fn pct(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    ((part as f64 / total as f64) * 1000.0).round() / 10.0
+}
+
+fn invalid(message: &str, details: String) -> AppError {
+    AppError::new("VALIDATION_BENCHMARK_DATASETS_INVALID", message).with_details(details)
+}
+
+/// Loads several verified sanitized datasets in memory and combines their MTTR distributions
+/// and detection mix. Detection sources are pseudonymized per dataset (DETECT_001 means
+/// something different in each), so the mix is compared by shape, not by source name.
+pub fn aggregate_sanitized_benchmark(dataset_dirs: &[PathBuf]) -> Result<SanitizedBenchmark, AppError> {
+    if dataset_dirs.is_empty() || dataset_dirs.len() > MAX_BENCHMARK_DATASETS {
+        return Err(invalid(
+            "Select between 1 and 26 sanitized datasets",
+            format!("datasets={}", dataset_dirs.len()),
+        ));
+    }
+
+    let mut datasets = Vec::new();
+    let mut loaded: Vec<(String, Vec<SanitizedIncident>)> = Vec::new();
+    let mut seen_manifests: Vec<String> = Vec::new();
+    for (idx, dir) in dataset_dirs.iter().enumerate() {
+        let manifest = inspect_sanitized_dataset(dir)?;
+        // The same dataset selected twice would double-count its incidents.
+        let identity = manifest
+            .files
+            .iter()
+            .map(|f| f.sha256.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+        if seen_manifests.contains(&identity) {
+            return Err(invalid(
+                "The same sanitized dataset was selected more than once",
+                format!("position={}", idx + 1),
+            ));
+        }
+        seen_manifests.push(identity);
+
+        let incidents = read_json_file::<Vec<SanitizedIncident>>(dir.join("incidents.json").as_path())?;
+        let label = format!("TEAM_{}", (b'A' + idx as u8) as char);
+        datasets.push(BenchmarkDataset {
+            label: label.clone(),
+            export_time: manifest.export_time.clone(),
+            incident_count: manifest.incident_count,
+        });
+        loaded.push((label, incidents));
+    }
+
+    let mut overall = Vec::new();
+    // severity -> dataset label -> MTTR samples
+    let mut by_severity: BTreeMap<String, BTreeMap<String, Vec<i64>>> = BTreeMap::new();
+    let mut severity_counts: BTreeMap<String, usize> = BTreeMap::new();
+    let mut detection_mix = Vec::new();
+    for (label, incidents) in &loaded {
+        let mut sources: BTreeMap<&str, usize> = BTreeMap::new();
+        for inc in incidents {
+            let severity = inc
+                .severity
+                .as_deref()
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .unwrap_or("UNKNOWN")
+                .to_string();
+            *severity_counts.entry(severity.clone()).or_default() += 1;
+            let samples = by_severity.entry(severity).or_default().entry(label.clone()).or_default();
+            if let Some(mttr) = inc.metrics.mttr_seconds {
+                samples.push(mttr);
+                overall.push(mttr);
+            }
+            if let Some(src) = inc.detection_source.as_deref() {
+                *sources.entry(src).or_default() += 1;
+            }
+        }
+        let known: usize = sources.values().sum();
+        detection_mix.push(BenchmarkDetectionMix {
+            label: label.clone(),
+            incident_count: incidents.len(),
+            detection_known_pct: pct(known, incidents.len()),
+            distinct_sources: sources.len(),
+            top_source_pct: pct(sources.values().copied().max().unwrap_or(0), incidents.len()),
+        });
+    }
+
+    let mttr_by_severity = by_severity
+        .into_iter()
+        .map(|(severity, per_dataset)| {
+            let combined: Vec<i64> = per_dataset.values().flatten().copied().collect();
+            BenchmarkSeverityMttr {
+                incident_count: severity_counts.get(&severity).copied().unwrap_or(0),
+                severity,
+                mttr: duration_stats(&combined),
+                by_dataset: per_dataset
+                    .iter()
+                    .map(|(label, v)| BenchmarkDatasetMttr {
+                        label: label.clone(),
+                        mttr: duration_stats(v),
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    Ok(SanitizedBenchmark {
+        incident_count: loaded.iter().map(|(_, i)| i.len()).sum(),
+        datasets,
+        mttr_overall: duration_stats(&overall),
+        mttr_by_severity,
+        detection_mix,
+    })
+}
+
+/// Markdown benchmark report; only anonymized labels and aggregate numbers appear.
+pub fn render_benchmark_markdown(b: &SanitizedBenchmark) -> String {
+    let mut out = String::new();
+    out.push_str("# Reliability Benchmark\n\n");
+    out.push_str(&format!(
+        "{} incidents across {} teams. MTTR p50 {}, p90 {}.\n\n",
+        b.incident_count,
+        b.datasets.len(),
+        format_duration_seconds(b.mttr_overall.p50_seconds),
+        format_duration_seconds(b.mttr_overall.p90_seconds)
+    ));
+
+    out.push_str("## Datasets\n\n| Team | Exported | Incidents |\n|---|---|---|\n");
+    for d in &b.datasets {
+        out.push_str(&format!("| {} | {} | {} |\n", d.label, d.export_time, d.incident_count));
+    }
+
+    out.push_str("\n## MTTR by severity\n\n| Severity | Team | Incidents with MTTR | p50 | p90 |\n|---|---|---|---|---|\n");
+    for s in &b.mttr_by_severity {
+        out.push_str(&format!(
+            "| {} | All | {} | {} | {} |\n",
+            s.severity,
+            s.mttr.count,
+            format_duration_seconds(s.mttr.p50_seconds),
+            format_duration_seconds(s.mttr.p90_seconds)
+        ));
+        for d in &s.by_dataset {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                s.severity,
+                d.label,
+                d.mttr.count,
+                format_duration_seconds(d.mttr.p50_seconds),
+                format_duration_seconds(d.mttr.p90_seconds)
+            ));
+        }
+    }
+
+    out.push_str("\n## Detection mix\n\n| Team | Detection recorded | Distinct sources | Top source share |\n|---|---|---|---|\n");
+    for m in &b.detection_mix {
+        out.push_str(&format!(
+            "| {} | {:.1}% | {} | {:.1}% |\n",
+            m.label, m.detection_known_pct, m.distinct_sources, m.top_source_pct
+        ));
+    }
+    out
+}
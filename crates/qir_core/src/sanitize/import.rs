@@ -49,7 +49,7 @@ fn sha256_file_hex_ingest(path: &Path) -> Result<(String, u64), AppError> {
     Ok((hex::encode(hasher.finalize()), total))
 }
 
-fn read_json_file<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T, AppError> {
+pub(super) fn read_json_file<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T, AppError> {
     let bytes = fs::read(path).map_err(|e| {
         AppError::new(
             "INGEST_SANITIZED_READ_FAILED",
@@ -519,6 +519,8 @@ pub fn import_sanitized_dataset(
         );
     }
 
+    crate::quarters::refresh_quarter_assignments(conn)?;
+
     Ok(SanitizedImportSummary {
         inserted_incidents: incidents.len() as i64,
         inserted_timeline_events: inserted_events,
@@ -4,12 +4,19 @@ use std::path::{Path, PathBuf};
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
 
+use crate::backup::filename_safe_timestamp;
 use crate::error::AppError;
 use crate::metrics;
+use crate::progress::Progress;
 use crate::repo;
 use crate::validate;
 
+mod benchmark;
 mod import;
+pub use benchmark::{
+    aggregate_sanitized_benchmark, render_benchmark_markdown, BenchmarkDataset, BenchmarkDatasetMttr,
+    BenchmarkDetectionMix, BenchmarkDurationStats, BenchmarkSeverityMttr, SanitizedBenchmark, MAX_BENCHMARK_DATASETS,
+};
 pub use import::{
     import_sanitized_dataset, inspect_sanitized_dataset, read_sanitized_manifest,
     SanitizedImportSummary,
@@ -80,16 +87,6 @@ pub struct SanitizedWarning {
     pub code: String,
 }
 
-fn filename_safe_timestamp(export_time: &str) -> String {
-    export_time
-        .chars()
-        .map(|c| match c {
-            'a'..='z' | 'A'..='Z' | '0'..='9' => c,
-            _ => '_',
-        })
-        .collect()
-}
-
 fn sha256_file_hex(path: &Path) -> Result<(String, u64), AppError> {
     use sha2::{Digest, Sha256};
     use std::io::Read;
@@ -140,6 +137,32 @@ pub fn export_sanitized_dataset(
     destination_dir: &Path,
     export_time: &str,
     app_version: &str,
+) -> Result<SanitizedExportResult, AppError> {
+    export_sanitized_dataset_with_progress(
+        conn,
+        destination_dir,
+        export_time,
+        app_version,
+        &mut Progress::noop("export_sanitized_dataset"),
+    )
+}
+
+pub fn export_sanitized_dataset_with_progress(
+    conn: &Connection,
+    destination_dir: &Path,
+    export_time: &str,
+    app_version: &str,
+    progress: &mut Progress<'_>,
+) -> Result<SanitizedExportResult, AppError> {
+    progress.run(|p| export_sanitized_stages(conn, destination_dir, export_time, app_version, p))
+}
+
+fn export_sanitized_stages(
+    conn: &Connection,
+    destination_dir: &Path,
+    export_time: &str,
+    app_version: &str,
+    progress: &mut Progress<'_>,
 ) -> Result<SanitizedExportResult, AppError> {
     if !destination_dir.is_dir() {
         return Err(AppError::new(
@@ -161,15 +184,60 @@ pub fn export_sanitized_dataset(
         )
         .with_details(export_dir.display().to_string()));
     }
-    fs::create_dir_all(&export_dir).map_err(|e| {
+
+    // Stage next to the final folder (same filesystem) and rename once the manifest is written,
+    // so a failure never leaves a partial dataset under the final name.
+    let staging_dir = export_dir.with_extension("export_tmp");
+    if staging_dir.exists() {
+        fs::remove_dir_all(&staging_dir).map_err(|e| {
+            AppError::new(
+                "EXPORT_SANITIZED_STAGING_CLEAN_FAILED",
+                "Failed to remove leftover sanitized export staging directory",
+            )
+            .with_details(format!("path={}: {}", staging_dir.display(), e))
+        })?;
+    }
+    fs::create_dir_all(&staging_dir).map_err(|e| {
         AppError::new(
             "EXPORT_SANITIZED_MKDIR_FAILED",
             "Failed to create sanitized export directory",
         )
-        .with_details(format!("path={}: {}", export_dir.display(), e))
+        .with_details(format!("path={}: {}", staging_dir.display(), e))
+    })?;
+
+    let incident_count = match write_sanitized_files(conn, &staging_dir, export_time, app_version, progress) {
+        Ok(n) => n,
+        Err(e) => {
+            let _ = fs::remove_dir_all(&staging_dir);
+            return Err(e);
+        }
+    };
+
+    fs::rename(&staging_dir, &export_dir).map_err(|e| {
+        let _ = fs::remove_dir_all(&staging_dir);
+        AppError::new(
+            "EXPORT_SANITIZED_FINALIZE_FAILED",
+            "Failed to move staged sanitized export into place",
+        )
+        .with_details(format!("src={} dst={}: {}", staging_dir.display(), export_dir.display(), e))
     })?;
 
+    Ok(SanitizedExportResult {
+        export_dir: export_dir.to_string_lossy().to_string(),
+        incident_count,
+    })
+}
+
+/// Writes the dataset files into `export_dir`, manifest last; returns the incident count.
+fn write_sanitized_files(
+    conn: &Connection,
+    export_dir: &Path,
+    export_time: &str,
+    app_version: &str,
+    progress: &mut Progress<'_>,
+) -> Result<i64, AppError> {
     // Pull incidents and build deterministic incident_key mapping.
+    progress.stage("incidents", Some(5));
     let incidents = repo::list_incidents(conn)?;
     let mut ids: Vec<i64> = incidents.iter().map(|i| i.id).collect();
     ids.sort();
@@ -226,6 +294,7 @@ pub fn export_sanitized_dataset(
     };
 
     // Validator payload (we export codes only to avoid leaking raw details).
+    progress.stage("validate", Some(20));
     let validation = validate::validate_all_incidents(conn)?;
     let mut warning_count_by_id: Vec<(i64, i64)> = validation
         .iter()
@@ -245,7 +314,8 @@ pub fn export_sanitized_dataset(
         .collect();
 
     let mut sanitized_incidents = Vec::new();
-    for i in &incidents {
+    for (idx, i) in incidents.iter().enumerate() {
+        progress.step("metrics", idx, incidents.len(), 40, 70);
         let (m, _metric_warnings) = metrics::compute_incident_metrics(i);
         let warning_count = warning_count_by_id
             .iter()
@@ -280,6 +350,7 @@ pub fn export_sanitized_dataset(
     sanitized_incidents.sort_by(|a, b| a.incident_key.cmp(&b.incident_key));
 
     // Timeline events, with text redacted.
+    progress.stage("timeline", Some(70));
     let events = repo::list_timeline_events(conn)?;
     let mut sanitized_events: Vec<SanitizedTimelineEvent> = events
         .into_iter()
@@ -312,9 +383,10 @@ pub fn export_sanitized_dataset(
         Ok(path)
     }
 
-    let incidents_path = write_json(&export_dir, "incidents.json", &sanitized_incidents)?;
-    let events_path = write_json(&export_dir, "timeline_events.json", &sanitized_events)?;
-    let warnings_path = write_json(&export_dir, "warnings.json", &warnings_flat)?;
+    progress.stage("write", Some(80));
+    let incidents_path = write_json(export_dir, "incidents.json", &sanitized_incidents)?;
+    let events_path = write_json(export_dir, "timeline_events.json", &sanitized_events)?;
+    let warnings_path = write_json(export_dir, "warnings.json", &warnings_flat)?;
 
     let mut files = Vec::new();
     for (name, path) in [
@@ -331,6 +403,7 @@ pub fn export_sanitized_dataset(
     }
     files.sort_by(|a, b| a.filename.cmp(&b.filename));
 
+    progress.stage("manifest", Some(95));
     let manifest = SanitizedExportManifest {
         manifest_version: 1,
         app_version: app_version.to_string(),
@@ -354,8 +427,5 @@ pub fn export_sanitized_dataset(
         .with_details(format!("path={}: {}", manifest_path.display(), e))
     })?;
 
-    Ok(SanitizedExportResult {
-        export_dir: export_dir.to_string_lossy().to_string(),
-        incident_count: sanitized_incidents.len() as i64,
-    })
+    Ok(sanitized_incidents.len() as i64)
 }
@@ -0,0 +1,386 @@
+use std::fs;
+use std::path::Path;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::backup::filename_safe_timestamp;
+use crate::error::AppError;
+use crate::ingest::jira_csv::JiraCsvMapping;
+use crate::profiles::jira::list_profiles;
+
+use super::{list_settings, validate_setting_key};
+
+pub const SETTINGS_BUNDLE_VERSION: u32 = 1;
+
+const SIGNATURE_ALG_SHA256: &str = "sha256";
+const SIGNATURE_ALG_HMAC_SHA256: &str = "hmac-sha256";
+
+/// Portable workspace configuration. Contains mapping profiles and workspace settings only;
+/// incidents, timeline events, artifacts and AI drafts are never included.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SettingsBundle {
+    pub bundle_version: u32,
+    pub app_version: String,
+    pub export_time: String,
+    pub jira_mapping_profiles: Vec<SettingsBundleProfile>,
+    pub settings: Vec<SettingsBundleEntry>,
+    pub signature: SettingsBundleSignature,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SettingsBundleProfile {
+    pub name: String,
+    pub mapping: JiraCsvMapping,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SettingsBundleEntry {
+    pub key: String,
+    pub value: serde_json::Value,
+}
+
+/// `sha256` detects corruption/tampering of an unkeyed bundle; `hmac-sha256` additionally
+/// proves the bundle was produced by someone holding the shared signing key.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SettingsBundleSignature {
+    pub algorithm: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsExportResult {
+    pub bundle_path: String,
+    pub profile_count: i64,
+    pub setting_count: i64,
+    pub signature: SettingsBundleSignature,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SettingsImportSummary {
+    pub profiles_created: i64,
+    pub profiles_updated: i64,
+    pub settings_written: i64,
+}
+
+#[derive(Serialize)]
+struct SignedPayload<'a> {
+    bundle_version: u32,
+    app_version: &'a str,
+    export_time: &'a str,
+    jira_mapping_profiles: &'a [SettingsBundleProfile],
+    settings: &'a [SettingsBundleEntry],
+}
+
+fn canonicalize_json_value(v: &serde_json::Value) -> serde_json::Value {
+    match v {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let mut out = serde_json::Map::new();
+            for k in keys {
+                out.insert(k.clone(), canonicalize_json_value(&map[k]));
+            }
+            serde_json::Value::Object(out)
+        }
+        serde_json::Value::Array(arr) => {
+            serde_json::Value::Array(arr.iter().map(canonicalize_json_value).collect())
+        }
+        _ => v.clone(),
+    }
+}
+
+fn signed_payload_bytes(
+    bundle_version: u32,
+    app_version: &str,
+    export_time: &str,
+    profiles: &[SettingsBundleProfile],
+    settings: &[SettingsBundleEntry],
+) -> Result<Vec<u8>, AppError> {
+    let payload = SignedPayload {
+        bundle_version,
+        app_version,
+        export_time,
+        jira_mapping_profiles: profiles,
+        settings,
+    };
+    let value = serde_json::to_value(&payload).map_err(|e| {
+        AppError::new("SETTINGS_BUNDLE_ENCODE_FAILED", "Failed to encode settings bundle payload")
+            .with_details(e.to_string())
+    })?;
+    serde_json::to_vec(&canonicalize_json_value(&value)).map_err(|e| {
+        AppError::new("SETTINGS_BUNDLE_ENCODE_FAILED", "Failed to encode settings bundle payload")
+            .with_details(e.to_string())
+    })
+}
+
+fn hmac_sha256_hex(key: &[u8], msg: &[u8]) -> String {
+    const BLOCK: usize = 64;
+    let mut k = if key.len() > BLOCK {
+        Sha256::digest(key).to_vec()
+    } else {
+        key.to_vec()
+    };
+    k.resize(BLOCK, 0);
+
+    let ipad: Vec<u8> = k.iter().map(|b| b ^ 0x36).collect();
+    let opad: Vec<u8> = k.iter().map(|b| b ^ 0x5c).collect();
+
+    let mut inner = Sha256::new();
+    inner.update(&ipad);
+    inner.update(msg);
+    let inner_hash = inner.finalize();
+
+    let mut outer = Sha256::new();
+    outer.update(&opad);
+    outer.update(inner_hash);
+    hex::encode(outer.finalize())
+}
+
+fn sign(payload: &[u8], signing_key: Option<&str>) -> SettingsBundleSignature {
+    match signing_key {
+        Some(key) => SettingsBundleSignature {
+            algorithm: SIGNATURE_ALG_HMAC_SHA256.to_string(),
+            value: hmac_sha256_hex(key.as_bytes(), payload),
+        },
+        None => SettingsBundleSignature {
+            algorithm: SIGNATURE_ALG_SHA256.to_string(),
+            value: hex::encode(Sha256::digest(payload)),
+        },
+    }
+}
+
+/// Builds a signed bundle from the current workspace. Output is deterministic for a given
+/// workspace state + export_time + app_version (profiles sorted by name, settings by key).
+pub fn build_settings_bundle(
+    conn: &Connection,
+    export_time: &str,
+    app_version: &str,
+    signing_key: Option<&str>,
+) -> Result<SettingsBundle, AppError> {
+    let mut profiles: Vec<SettingsBundleProfile> = list_profiles(conn)?
+        .into_iter()
+        .map(|p| SettingsBundleProfile {
+            name: p.name,
+            mapping: p.mapping,
+        })
+        .collect();
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let settings: Vec<SettingsBundleEntry> = list_settings(conn)?
+        .into_iter()
+        .map(|s| SettingsBundleEntry {
+            key: s.key,
+            value: s.value,
+        })
+        .collect();
+
+    let payload = signed_payload_bytes(
+        SETTINGS_BUNDLE_VERSION,
+        app_version,
+        export_time,
+        &profiles,
+        &settings,
+    )?;
+    let signature = sign(&payload, signing_key);
+
+    Ok(SettingsBundle {
+        bundle_version: SETTINGS_BUNDLE_VERSION,
+        app_version: app_version.to_string(),
+        export_time: export_time.to_string(),
+        jira_mapping_profiles: profiles,
+        settings,
+        signature,
+    })
+}
+
+/// Checks version and signature. A keyed (HMAC) bundle requires the same key; an unkeyed
+/// bundle is rejected when a key is supplied, so callers who expect signed bundles never
+/// silently accept an unsigned one.
+pub fn verify_settings_bundle(bundle: &SettingsBundle, signing_key: Option<&str>) -> Result<(), AppError> {
+    if bundle.bundle_version != SETTINGS_BUNDLE_VERSION {
+        return Err(AppError::new(
+            "SETTINGS_BUNDLE_UNSUPPORTED_VERSION",
+            "Unsupported settings bundle version",
+        )
+        .with_details(format!(
+            "bundle_version={}; supported={}",
+            bundle.bundle_version, SETTINGS_BUNDLE_VERSION
+        )));
+    }
+
+    let payload = signed_payload_bytes(
+        bundle.bundle_version,
+        &bundle.app_version,
+        &bundle.export_time,
+        &bundle.jira_mapping_profiles,
+        &bundle.settings,
+    )?;
+
+    let expected = match (bundle.signature.algorithm.as_str(), signing_key) {
+        (SIGNATURE_ALG_SHA256, None) => sign(&payload, None),
+        (SIGNATURE_ALG_HMAC_SHA256, Some(key)) => sign(&payload, Some(key)),
+        (SIGNATURE_ALG_HMAC_SHA256, None) => {
+            return Err(AppError::new(
+                "SETTINGS_BUNDLE_SIGNING_KEY_REQUIRED",
+                "Settings bundle is keyed; a signing key is required to import it",
+            ))
+        }
+        (SIGNATURE_ALG_SHA256, Some(_)) => {
+            return Err(AppError::new(
+                "SETTINGS_BUNDLE_SIGNATURE_INVALID",
+                "Settings bundle is not keyed but a signing key was supplied",
+            ))
+        }
+        (other, _) => {
+            return Err(AppError::new(
+                "SETTINGS_BUNDLE_SIGNATURE_INVALID",
+                "Unsupported settings bundle signature algorithm",
+            )
+            .with_details(format!("algorithm={other}")))
+        }
+    };
+
+    if expected.value != bundle.signature.value {
+        return Err(AppError::new(
+            "SETTINGS_BUNDLE_SIGNATURE_INVALID",
+            "Settings bundle signature does not match its contents",
+        )
+        .with_details(format!(
+            "algorithm={}; expected={}; actual={}",
+            bundle.signature.algorithm, expected.value, bundle.signature.value
+        )));
+    }
+    Ok(())
+}
+
+pub fn export_settings_bundle(
+    conn: &Connection,
+    destination_dir: &Path,
+    export_time: &str,
+    app_version: &str,
+    signing_key: Option<&str>,
+) -> Result<SettingsExportResult, AppError> {
+    if !destination_dir.is_dir() {
+        return Err(AppError::new(
+            "SETTINGS_EXPORT_DEST_NOT_DIR",
+            "Settings export destination must be an existing directory",
+        )
+        .with_details(destination_dir.display().to_string()));
+    }
+    let path = destination_dir.join(format!(
+        "IncidentReviewSettings_{}.json",
+        filename_safe_timestamp(export_time)
+    ));
+    if path.exists() {
+        return Err(AppError::new(
+            "SETTINGS_EXPORT_DEST_EXISTS",
+            "Settings bundle destination file already exists",
+        )
+        .with_details(path.display().to_string()));
+    }
+
+    let bundle = build_settings_bundle(conn, export_time, app_version, signing_key)?;
+    let json = serde_json::to_string_pretty(&bundle).map_err(|e| {
+        AppError::new("SETTINGS_BUNDLE_ENCODE_FAILED", "Failed to encode settings bundle")
+            .with_details(e.to_string())
+    })?;
+    fs::write(&path, json.as_bytes()).map_err(|e| {
+        AppError::new("SETTINGS_EXPORT_WRITE_FAILED", "Failed to write settings bundle")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    })?;
+
+    Ok(SettingsExportResult {
+        bundle_path: path.to_string_lossy().to_string(),
+        profile_count: bundle.jira_mapping_profiles.len() as i64,
+        setting_count: bundle.settings.len() as i64,
+        signature: bundle.signature,
+    })
+}
+
+pub fn read_settings_bundle(path: &Path) -> Result<SettingsBundle, AppError> {
+    let bytes = fs::read(path).map_err(|e| {
+        AppError::new("SETTINGS_BUNDLE_READ_FAILED", "Failed to read settings bundle")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    })?;
+    serde_json::from_slice(&bytes).map_err(|e| {
+        AppError::new("SETTINGS_BUNDLE_INVALID", "Settings bundle is not valid JSON")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    })
+}
+
+/// Applies a verified bundle atomically. Mapping profiles are matched by name (updated in
+/// place or created); settings keys are overwritten. Workspace-only settings that are not
+/// in the bundle are left untouched.
+pub fn import_settings_bundle(
+    conn: &mut Connection,
+    bundle: &SettingsBundle,
+    signing_key: Option<&str>,
+) -> Result<SettingsImportSummary, AppError> {
+    verify_settings_bundle(bundle, signing_key)?;
+
+    for p in &bundle.jira_mapping_profiles {
+        if p.name.trim().is_empty() || p.mapping.title.trim().is_empty() {
+            return Err(AppError::new(
+                "VALIDATION_MAPPING_PROFILE_INVALID",
+                "Settings bundle contains a mapping profile without a name or title column",
+            )
+            .with_details(format!("name={}", p.name)));
+        }
+    }
+    for s in &bundle.settings {
+        validate_setting_key(&s.key)?;
+    }
+
+    let tx = conn.transaction().map_err(|e| {
+        AppError::new("DB_TX_FAILED", "Failed to start settings import transaction")
+            .with_details(e.to_string())
+    })?;
+
+    let mut summary = SettingsImportSummary {
+        profiles_created: 0,
+        profiles_updated: 0,
+        settings_written: 0,
+    };
+
+    for p in &bundle.jira_mapping_profiles {
+        let mapping_json = serde_json::to_string(&p.mapping).map_err(|e| {
+            AppError::new("DB_ENCODE_FAILED", "Failed to encode Jira mapping profile JSON")
+                .with_details(e.to_string())
+        })?;
+        let updated = tx
+            .execute(
+                "UPDATE jira_mapping_profiles SET mapping_json = ?1 WHERE name = ?2",
+                rusqlite::params![mapping_json, p.name],
+            )
+            .map_err(|e| {
+                AppError::new("DB_WRITE_FAILED", "Failed to update Jira mapping profile")
+                    .with_details(format!("name={}; err={}", p.name, e))
+            })?;
+        if updated > 0 {
+            summary.profiles_updated += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO jira_mapping_profiles(name, mapping_json, created_at) VALUES (?1, ?2, strftime('%Y-%m-%dT%H:%M:%fZ','now'))",
+            rusqlite::params![p.name, mapping_json],
+        )
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to create Jira mapping profile")
+                .with_details(format!("name={}; err={}", p.name, e))
+        })?;
+        summary.profiles_created += 1;
+    }
+
+    for s in &bundle.settings {
+        super::set_setting(&tx, &s.key, &s.value)?;
+        summary.settings_written += 1;
+    }
+
+    tx.commit().map_err(|e| {
+        AppError::new("DB_TX_FAILED", "Failed to commit settings import")
+            .with_details(e.to_string())
+    })?;
+    Ok(summary)
+}
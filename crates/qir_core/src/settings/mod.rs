@@ -0,0 +1,146 @@
+use rusqlite::{Connection, OptionalExtension};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+mod bundle;
+pub use bundle::{
+    build_settings_bundle, export_settings_bundle, import_settings_bundle, read_settings_bundle,
+    verify_settings_bundle, SettingsBundle, SettingsBundleEntry, SettingsBundleProfile,
+    SettingsBundleSignature, SettingsExportResult, SettingsImportSummary,
+    SETTINGS_BUNDLE_VERSION,
+};
+
+/// One workspace setting row. Values are arbitrary JSON; typed accessors live with the
+/// feature that owns the key (e.g. dashboard tuning under `dashboard.*`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkspaceSetting {
+    pub key: String,
+    pub value: serde_json::Value,
+    pub updated_at: String,
+}
+
+/// Keys are lowercase dotted identifiers (`namespace.name`), so bundles stay portable and
+/// diffs stay readable.
+pub fn validate_setting_key(key: &str) -> Result<(), AppError> {
+    let valid_chars = key
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.');
+    let has_namespace = key
+        .split_once('.')
+        .map(|(ns, name)| !ns.is_empty() && !name.is_empty())
+        .unwrap_or(false);
+    if key.is_empty() || !valid_chars || !has_namespace || key.ends_with('.') {
+        return Err(AppError::new(
+            "VALIDATION_SETTING_KEY_INVALID",
+            "Setting keys must be lowercase dotted identifiers (namespace.name)",
+        )
+        .with_details(format!("key={key}")));
+    }
+    Ok(())
+}
+
+pub fn list_settings(conn: &Connection) -> Result<Vec<WorkspaceSetting>, AppError> {
+    let mut stmt = conn
+        .prepare("SELECT key, value_json, updated_at FROM workspace_settings ORDER BY key ASC")
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare workspace settings query")
+                .with_details(e.to_string())
+        })?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query workspace settings")
+                .with_details(e.to_string())
+        })?;
+
+    let mut out = Vec::new();
+    for r in rows {
+        let (key, value_json, updated_at) = r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode workspace setting row")
+                .with_details(e.to_string())
+        })?;
+        let value = serde_json::from_str(&value_json).map_err(|e| {
+            AppError::new("DB_DECODE_FAILED", "Failed to decode workspace setting JSON")
+                .with_details(format!("key={key}; err={e}"))
+        })?;
+        out.push(WorkspaceSetting {
+            key,
+            value,
+            updated_at,
+        });
+    }
+    Ok(out)
+}
+
+pub fn get_setting(conn: &Connection, key: &str) -> Result<Option<serde_json::Value>, AppError> {
+    let value_json: Option<String> = conn
+        .query_row(
+            "SELECT value_json FROM workspace_settings WHERE key = ?1",
+            [key],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query workspace setting")
+                .with_details(format!("key={key}; err={e}"))
+        })?;
+    match value_json {
+        None => Ok(None),
+        Some(s) => serde_json::from_str(&s).map(Some).map_err(|e| {
+            AppError::new("DB_DECODE_FAILED", "Failed to decode workspace setting JSON")
+                .with_details(format!("key={key}; err={e}"))
+        }),
+    }
+}
+
+/// Typed read of a setting. A stored value that does not match `T` is an error, never a
+/// silent fallback to defaults.
+pub fn get_setting_as<T: DeserializeOwned>(conn: &Connection, key: &str) -> Result<Option<T>, AppError> {
+    match get_setting(conn, key)? {
+        None => Ok(None),
+        Some(v) => serde_json::from_value(v).map(Some).map_err(|e| {
+            AppError::new("VALIDATION_SETTING_INVALID", "Workspace setting has an unexpected shape")
+                .with_details(format!("key={key}; err={e}"))
+        }),
+    }
+}
+
+pub fn set_setting(conn: &Connection, key: &str, value: &serde_json::Value) -> Result<(), AppError> {
+    validate_setting_key(key)?;
+    let value_json = serde_json::to_string(value).map_err(|e| {
+        AppError::new("DB_ENCODE_FAILED", "Failed to encode workspace setting JSON")
+            .with_details(format!("key={key}; err={e}"))
+    })?;
+    conn.execute(
+        "INSERT INTO workspace_settings(key, value_json, updated_at) VALUES (?1, ?2, strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+         ON CONFLICT(key) DO UPDATE SET value_json = excluded.value_json, updated_at = excluded.updated_at",
+        rusqlite::params![key, value_json],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to write workspace setting")
+            .with_details(format!("key={key}; err={e}"))
+    })?;
+    Ok(())
+}
+
+pub fn delete_setting(conn: &Connection, key: &str) -> Result<(), AppError> {
+    let changed = conn
+        .execute("DELETE FROM workspace_settings WHERE key = ?1", [key])
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to delete workspace setting")
+                .with_details(format!("key={key}; err={e}"))
+        })?;
+    if changed == 0 {
+        return Err(AppError::new("DB_NOT_FOUND", "Workspace setting not found")
+            .with_details(format!("key={key}")));
+    }
+    Ok(())
+}
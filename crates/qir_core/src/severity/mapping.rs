@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SeverityMapping {
+    pub source_value: String,
+    pub severity: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SeverityMappingInput {
+    pub source_value: String,
+    pub severity: String,
+}
+
+fn map_row(row: &rusqlite::Row<'_>) -> Result<SeverityMapping, rusqlite::Error> {
+    Ok(SeverityMapping {
+        source_value: row.get(0)?,
+        severity: row.get(1)?,
+        created_at: row.get(2)?,
+        updated_at: row.get(3)?,
+    })
+}
+
+fn get_severity_mapping(conn: &Connection, source_value: &str) -> Result<Option<SeverityMapping>, AppError> {
+    conn.query_row(
+        "SELECT source_value, severity, created_at, updated_at FROM severity_mappings WHERE source_value = ?1",
+        [source_value],
+        map_row,
+    )
+    .optional()
+    .map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query severity mapping")
+            .with_details(format!("source_value={source_value}; err={e}"))
+    })
+}
+
+/// Mappings grouped by target severity.
+pub fn list_severity_mappings(conn: &Connection) -> Result<Vec<SeverityMapping>, AppError> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT source_value, severity, created_at, updated_at FROM severity_mappings
+             ORDER BY severity ASC, source_value ASC",
+        )
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare severity mapping query").with_details(e.to_string())
+        })?;
+    let rows = stmt.query_map([], map_row).map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query severity mappings").with_details(e.to_string())
+    })?;
+    let mut out = Vec::new();
+    for r in rows {
+        out.push(r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode severity mapping row").with_details(e.to_string())
+        })?);
+    }
+    Ok(out)
+}
+
+/// Creates or retargets the mapping for `source_value`. Mappings are one step: a target may
+/// not itself be mapped elsewhere, and a value other mappings target may not be remapped.
+/// Already-imported incidents keep their stored severity.
+pub fn upsert_severity_mapping(conn: &Connection, input: &SeverityMappingInput) -> Result<SeverityMapping, AppError> {
+    let source_value = input.source_value.trim();
+    let severity = input.severity.trim();
+    let invalid = |message: &str| {
+        AppError::new("VALIDATION_SEVERITY_MAPPING_INVALID", message)
+            .with_details(format!("source_value={source_value}; severity={severity}"))
+    };
+    if source_value.is_empty() || severity.is_empty() {
+        return Err(invalid("Severity mappings need a source value and a severity"));
+    }
+    if !source_value.eq_ignore_ascii_case(severity) {
+        if let Some(chained) = get_severity_mapping(conn, severity)?.filter(|m| !m.severity.eq_ignore_ascii_case(severity)) {
+            return Err(invalid(&format!("Severity {} is itself mapped to {}", chained.source_value, chained.severity)));
+        }
+    }
+    let targeted: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM severity_mappings WHERE severity = ?1 COLLATE NOCASE AND source_value <> ?1",
+            [source_value],
+            |row| row.get(0),
+        )
+        .map_err(|e| AppError::new("DB_QUERY_FAILED", "Failed to check severity mappings").with_details(e.to_string()))?;
+    if targeted > 0 && !source_value.eq_ignore_ascii_case(severity) {
+        return Err(invalid("Other values are mapped to this source value; map them to the new severity instead"));
+    }
+    conn.execute(
+        "INSERT INTO severity_mappings(source_value, severity, created_at, updated_at)
+         VALUES (?1, ?2, strftime('%Y-%m-%dT%H:%M:%fZ','now'), strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+         ON CONFLICT(source_value) DO UPDATE SET
+           source_value = excluded.source_value,
+           severity = excluded.severity,
+           updated_at = excluded.updated_at",
+        params![source_value, severity],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to store severity mapping")
+            .with_details(format!("source_value={source_value}; err={e}"))
+    })?;
+    get_severity_mapping(conn, source_value)?.ok_or_else(|| {
+        AppError::new("DB_NOT_FOUND", "Severity mapping missing after write")
+            .with_details(format!("source_value={source_value}"))
+    })
+}
+
+pub fn delete_severity_mapping(conn: &Connection, source_value: &str) -> Result<(), AppError> {
+    let source_value = source_value.trim();
+    let deleted = conn
+        .execute("DELETE FROM severity_mappings WHERE source_value = ?1", [source_value])
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to delete severity mapping")
+                .with_details(format!("source_value={source_value}; err={e}"))
+        })?;
+    if deleted == 0 {
+        return Err(AppError::new("DB_NOT_FOUND", "Severity mapping not found")
+            .with_details(format!("source_value={source_value}")));
+    }
+    Ok(())
+}
+
+/// The mapping table loaded for one import. With no mappings every value passes through
+/// untouched; otherwise a value is either a mapped source, a known target severity (which
+/// adopts the target's spelling), or unmapped.
+#[derive(Debug, Clone, Default)]
+pub struct SeverityNormalizer {
+    by_key: HashMap<String, String>,
+}
+
+impl SeverityNormalizer {
+    pub fn load(conn: &Connection) -> Result<Self, AppError> {
+        let mut by_key = HashMap::new();
+        for m in list_severity_mappings(conn)? {
+            by_key.entry(m.severity.to_lowercase()).or_insert_with(|| m.severity.clone());
+            by_key.insert(m.source_value.to_lowercase(), m.severity);
+        }
+        Ok(Self { by_key })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_key.is_empty()
+    }
+
+    /// Ok(stored severity), or Err(trimmed raw value) when mappings exist but none covers it.
+    pub fn normalize(&self, raw: &str) -> Result<String, String> {
+        let trimmed = raw.trim();
+        if self.is_empty() || trimmed.is_empty() {
+            return Ok(raw.to_string());
+        }
+        self.by_key
+            .get(&trimmed.to_lowercase())
+            .cloned()
+            .ok_or_else(|| trimmed.to_string())
+    }
+}
@@ -0,0 +1,213 @@
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+pub mod mapping;
+pub use mapping::{
+    delete_severity_mapping, list_severity_mappings, upsert_severity_mapping, SeverityMapping, SeverityMappingInput,
+    SeverityNormalizer,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SeverityGradeSource {
+    Ingest,
+    Manual,
+    /// Not stored: the first severity recorded in incident field history.
+    History,
+}
+
+impl SeverityGradeSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ingest => "ingest",
+            Self::Manual => "manual",
+            Self::History => "history",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "ingest" => Some(Self::Ingest),
+            "manual" => Some(Self::Manual),
+            "history" => Some(Self::History),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SeverityGrade {
+    pub incident_id: i64,
+    pub initial_severity: String,
+    pub source: SeverityGradeSource,
+    pub recorded_at: String,
+}
+
+/// Numeric rank for common severity spellings (SEV1, Sev-2, S3, P1, 2); lower is more
+/// severe. None when the label does not carry a level.
+pub fn severity_rank(severity: &str) -> Option<u32> {
+    let s = severity.trim().to_ascii_uppercase();
+    let digits = ["SEV-", "SEV ", "SEV", "S", "P"]
+        .iter()
+        .find_map(|p| s.strip_prefix(p))
+        .unwrap_or(&s);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn invalid(message: &str, details: String) -> AppError {
+    AppError::new("VALIDATION_SEVERITY_GRADE_INVALID", message).with_details(details)
+}
+
+pub fn get_severity_grade(conn: &Connection, incident_id: i64) -> Result<Option<SeverityGrade>, AppError> {
+    let row: Option<(String, String, String)> = conn
+        .query_row(
+            "SELECT initial_severity, source, recorded_at FROM incident_severity_grades WHERE incident_id = ?1",
+            [incident_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query severity grade")
+                .with_details(format!("incident_id={incident_id}; err={e}"))
+        })?;
+    row.map(|(initial_severity, source, recorded_at)| {
+        let source = SeverityGradeSource::parse(&source).ok_or_else(|| {
+            AppError::new("DB_QUERY_FAILED", "Unknown severity grade source")
+                .with_details(format!("incident_id={incident_id}; source={source}"))
+        })?;
+        Ok(SeverityGrade {
+            incident_id,
+            initial_severity,
+            source,
+            recorded_at,
+        })
+    })
+    .transpose()
+}
+
+/// Stores the initial grade captured by an import. Returns false (and leaves the row alone)
+/// when a manual grade already exists.
+pub(crate) fn record_ingest_severity(conn: &Connection, incident_id: i64, severity: &str) -> Result<bool, AppError> {
+    let severity = severity.trim();
+    if severity.is_empty() {
+        return Ok(false);
+    }
+    let changed = conn
+        .execute(
+            "INSERT INTO incident_severity_grades(incident_id, initial_severity, source, recorded_at)
+             VALUES (?1, ?2, 'ingest', strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+             ON CONFLICT(incident_id) DO UPDATE SET
+               initial_severity = excluded.initial_severity,
+               recorded_at = excluded.recorded_at
+             WHERE incident_severity_grades.source = 'ingest'
+               AND incident_severity_grades.initial_severity IS NOT excluded.initial_severity",
+            params![incident_id, severity],
+        )
+        .map_err(|e| {
+            AppError::new("DB_WRITE_FAILED", "Failed to store initial severity")
+                .with_details(format!("incident_id={incident_id}; err={e}"))
+        })?;
+    Ok(changed > 0)
+}
+
+/// Sets (or with None, clears) the hand-entered initial severity for an incident.
+pub fn set_initial_severity(
+    conn: &Connection,
+    incident_id: i64,
+    initial_severity: Option<&str>,
+) -> Result<Option<SeverityGrade>, AppError> {
+    let exists: Option<i64> = conn
+        .query_row("SELECT id FROM incidents WHERE id = ?1", [incident_id], |row| row.get(0))
+        .optional()
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to check incident exists").with_details(e.to_string())
+        })?;
+    if exists.is_none() {
+        return Err(AppError::new("DB_NOT_FOUND", "Incident not found for severity grade")
+            .with_details(format!("incident_id={incident_id}")));
+    }
+    crate::freeze::ensure_incident_mutable(conn, incident_id)?;
+
+    let Some(raw) = initial_severity else {
+        conn.execute("DELETE FROM incident_severity_grades WHERE incident_id = ?1", [incident_id])
+            .map_err(|e| {
+                AppError::new("DB_WRITE_FAILED", "Failed to clear initial severity")
+                    .with_details(format!("incident_id={incident_id}; err={e}"))
+            })?;
+        return Ok(None);
+    };
+    let severity = raw.trim();
+    if severity.is_empty() {
+        return Err(invalid("Initial severity cannot be blank", format!("incident_id={incident_id}")));
+    }
+    conn.execute(
+        "INSERT INTO incident_severity_grades(incident_id, initial_severity, source, recorded_at)
+         VALUES (?1, ?2, 'manual', strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+         ON CONFLICT(incident_id) DO UPDATE SET
+           initial_severity = excluded.initial_severity,
+           source = 'manual',
+           recorded_at = excluded.recorded_at",
+        params![incident_id, severity],
+    )
+    .map_err(|e| {
+        AppError::new("DB_WRITE_FAILED", "Failed to store initial severity")
+            .with_details(format!("incident_id={incident_id}; err={e}"))
+    })?;
+    get_severity_grade(conn, incident_id)
+}
+
+/// Initial grade per incident id: the stored grade when present, otherwise the first
+/// severity incident field history saw for the incident.
+pub fn initial_severities(conn: &Connection) -> Result<Vec<SeverityGrade>, AppError> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT incident_id, initial_severity, source, recorded_at FROM incident_severity_grades
+             UNION ALL
+             SELECT h.incident_id,
+                    CASE h.change_kind WHEN 'insert' THEN h.new_value ELSE h.old_value END,
+                    'history', h.effective_at
+               FROM incident_field_history h
+              WHERE h.field = 'severity'
+                AND h.incident_id NOT IN (SELECT incident_id FROM incident_severity_grades)
+                AND h.id = (SELECT h2.id FROM incident_field_history h2
+                             WHERE h2.incident_id = h.incident_id AND h2.field = 'severity'
+                             ORDER BY h2.effective_at ASC, h2.id ASC LIMIT 1)
+             ORDER BY 1",
+        )
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to prepare initial severity query").with_details(e.to_string())
+        })?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })
+        .map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to query initial severities").with_details(e.to_string())
+        })?;
+    let mut out = Vec::new();
+    for r in rows {
+        let (incident_id, initial, source, recorded_at) = r.map_err(|e| {
+            AppError::new("DB_QUERY_FAILED", "Failed to decode initial severity row").with_details(e.to_string())
+        })?;
+        let (Some(initial_severity), Some(source)) = (initial, SeverityGradeSource::parse(&source)) else {
+            continue;
+        };
+        out.push(SeverityGrade {
+            incident_id,
+            initial_severity,
+            source,
+            recorded_at,
+        });
+    }
+    Ok(out)
+}
@@ -0,0 +1,61 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+/// Set to `1` to rewrite snapshots from the current output instead of comparing
+/// (`scripts/golden.sh --bless`).
+pub const BLESS_ENV: &str = "QIR_BLESS";
+
+/// `fixtures/golden/` at the repository root.
+pub fn golden_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/golden")
+}
+
+fn blessing() -> bool {
+    std::env::var(BLESS_ENV).is_ok_and(|v| v == "1")
+}
+
+/// Compares `actual` with `fixtures/golden/<name>`. A missing snapshot or a mismatch panics
+/// (the latter with the first differing line) unless blessing is enabled, in which case the
+/// snapshot is written.
+pub fn assert_golden(name: &str, actual: &str) {
+    let path = golden_dir().join(name);
+    let expected = fs::read_to_string(&path).ok().map(|s| s.replace("\r\n", "\n"));
+    if expected.as_deref() == Some(actual) {
+        return;
+    }
+    if blessing() {
+        fs::write(&path, actual).unwrap_or_else(|e| panic!("failed to write {}: {e}", path.display()));
+        eprintln!("golden snapshot written: {}", path.display());
+        return;
+    }
+
+    let Some(expected) = expected else {
+        panic!(
+            "golden snapshot {name} is missing ({})\n\
+             Record it with {BLESS_ENV}=1 (scripts/golden.sh --bless) and commit the file.",
+            path.display()
+        );
+    };
+    let want: Vec<&str> = expected.lines().collect();
+    let got: Vec<&str> = actual.lines().collect();
+    // Equal line lists mean the only difference is the trailing newline.
+    let idx = (0..want.len().max(got.len()))
+        .find(|&i| want.get(i) != got.get(i))
+        .unwrap_or(want.len());
+    let line = idx + 1;
+    let want = want.get(idx).copied().unwrap_or("<end of file>");
+    let got = got.get(idx).copied().unwrap_or("<end of file>");
+    panic!(
+        "golden snapshot {name} differs at line {line}\n  expected: {want}\n  actual:   {got}\n\
+         If the change is intended, rerun with {BLESS_ENV}=1 (scripts/golden.sh --bless) and review the diff."
+    );
+}
+
+/// `assert_golden` over pretty-printed JSON with a trailing newline.
+pub fn assert_golden_json<T: Serialize>(name: &str, value: &T) {
+    let mut json = serde_json::to_string_pretty(value).expect("serialize golden value");
+    json.push('\n');
+    assert_golden(name, &json);
+}
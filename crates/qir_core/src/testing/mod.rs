@@ -0,0 +1,202 @@
+// Test support for qir_core and downstream crates (qir_ai, the Tauri layer).
+// Compiled only with `--features testing`; never shipped in the app.
+
+mod golden;
+
+pub use golden::{assert_golden, assert_golden_json, golden_dir, BLESS_ENV};
+
+use rusqlite::Connection;
+
+use crate::domain::Incident;
+use crate::error::AppError;
+use crate::ingest::jira_csv::{import_jira_csv, JiraCsvMapping};
+
+/// Two clean incidents (INC-101, INC-102) with start/ack/resolve timestamps.
+pub const JIRA_SAMPLE_CSV: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures/demo/jira_sample.csv"));
+/// Incidents with every column populated (detection source, vendor, service, full timeline).
+pub const JIRA_STORY_CSV: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures/demo/jira_story.csv"));
+/// One incident whose timestamps are not RFC3339 (kept as raw values with warnings).
+pub const JIRA_NON_RFC3339_CSV: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures/demo/jira_non_rfc3339.csv"));
+/// Two rows sharing an external id; the second is reported as a conflict.
+pub const JIRA_DUPLICATE_EXTERNAL_ID_CSV: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../fixtures/demo/jira_duplicate_external_id.csv"
+));
+pub const SLACK_SAMPLE_TXT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures/demo/slack_sample.txt"));
+
+/// Mapping for the header names used by every bundled fixture and the demo dataset.
+/// Columns absent from a given CSV simply import as unknown.
+pub fn fixture_mapping() -> JiraCsvMapping {
+    JiraCsvMapping {
+        external_id: Some("Key".to_string()),
+        title: "Summary".to_string(),
+        description: Some("Description".to_string()),
+        severity: Some("Severity".to_string()),
+        initial_severity: Some("InitialSeverity".to_string()),
+        detection_source: Some("DetectionSource".to_string()),
+        vendor: Some("Vendor".to_string()),
+        service: Some("Service".to_string()),
+        impact_pct: Some("ImpactPct".to_string()),
+        service_health_pct: Some("ServiceHealthPct".to_string()),
+        start_ts: Some("StartTs".to_string()),
+        first_observed_ts: Some("FirstObservedTs".to_string()),
+        it_awareness_ts: Some("ItAwarenessTs".to_string()),
+        ack_ts: Some("AckTs".to_string()),
+        mitigate_ts: Some("MitigateTs".to_string()),
+        resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
+    }
+}
+
+/// Migrated, empty in-memory workspace.
+pub fn empty_db() -> Result<Connection, AppError> {
+    let mut conn = crate::db::open_in_memory()?;
+    crate::db::migrate(&mut conn)?;
+    Ok(conn)
+}
+
+/// In-memory workspace seeded with the 40-incident demo dataset.
+pub fn demo_db() -> Result<Connection, AppError> {
+    let mut conn = empty_db()?;
+    crate::demo::seed_demo_dataset(&mut conn)?;
+    Ok(conn)
+}
+
+/// In-memory workspace with `csv_text` imported through `fixture_mapping()`.
+pub fn db_from_csv(csv_text: &str) -> Result<Connection, AppError> {
+    let mut conn = empty_db()?;
+    import_jira_csv(&mut conn, csv_text, &fixture_mapping())?;
+    Ok(conn)
+}
+
+/// In-memory workspace containing exactly `incidents`, ids preserved.
+pub fn db_with_incidents(incidents: &[Incident]) -> Result<Connection, AppError> {
+    let conn = crate::history::materialize_incidents(incidents)?;
+    crate::quarters::refresh_quarter_assignments(&conn)?;
+    Ok(conn)
+}
+
+/// Builds an `Incident` with only the fields a test cares about. Everything else is unknown,
+/// and the fingerprint defaults to `fp-<id>`.
+#[derive(Debug, Clone)]
+pub struct IncidentBuilder {
+    incident: Incident,
+}
+
+impl IncidentBuilder {
+    pub fn new(id: i64, title: impl Into<String>) -> Self {
+        Self {
+            incident: Incident {
+                id,
+                external_id: None,
+                fingerprint: format!("fp-{id}"),
+                title: title.into(),
+                description: None,
+                severity: None,
+                detection_source: None,
+                vendor: None,
+                service: None,
+                impact_pct: None,
+                service_health_pct: None,
+                start_ts: None,
+                first_observed_ts: None,
+                it_awareness_ts: None,
+                ack_ts: None,
+                mitigate_ts: None,
+                resolve_ts: None,
+                start_ts_raw: None,
+                first_observed_ts_raw: None,
+                it_awareness_ts_raw: None,
+                ack_ts_raw: None,
+                mitigate_ts_raw: None,
+                resolve_ts_raw: None,
+            },
+        }
+    }
+
+    pub fn external_id(mut self, v: impl Into<String>) -> Self {
+        self.incident.external_id = Some(v.into());
+        self
+    }
+
+    pub fn fingerprint(mut self, v: impl Into<String>) -> Self {
+        self.incident.fingerprint = v.into();
+        self
+    }
+
+    pub fn description(mut self, v: impl Into<String>) -> Self {
+        self.incident.description = Some(v.into());
+        self
+    }
+
+    pub fn severity(mut self, v: impl Into<String>) -> Self {
+        self.incident.severity = Some(v.into());
+        self
+    }
+
+    pub fn detection_source(mut self, v: impl Into<String>) -> Self {
+        self.incident.detection_source = Some(v.into());
+        self
+    }
+
+    pub fn vendor(mut self, v: impl Into<String>) -> Self {
+        self.incident.vendor = Some(v.into());
+        self
+    }
+
+    pub fn service(mut self, v: impl Into<String>) -> Self {
+        self.incident.service = Some(v.into());
+        self
+    }
+
+    pub fn impact(mut self, impact_pct: i64, service_health_pct: i64) -> Self {
+        self.incident.impact_pct = Some(impact_pct);
+        self.incident.service_health_pct = Some(service_health_pct);
+        self
+    }
+
+    pub fn start(mut self, ts: impl Into<String>) -> Self {
+        self.incident.start_ts = Some(ts.into());
+        self
+    }
+
+    pub fn first_observed(mut self, ts: impl Into<String>) -> Self {
+        self.incident.first_observed_ts = Some(ts.into());
+        self
+    }
+
+    pub fn it_awareness(mut self, ts: impl Into<String>) -> Self {
+        self.incident.it_awareness_ts = Some(ts.into());
+        self
+    }
+
+    pub fn ack(mut self, ts: impl Into<String>) -> Self {
+        self.incident.ack_ts = Some(ts.into());
+        self
+    }
+
+    pub fn mitigate(mut self, ts: impl Into<String>) -> Self {
+        self.incident.mitigate_ts = Some(ts.into());
+        self
+    }
+
+    pub fn resolve(mut self, ts: impl Into<String>) -> Self {
+        self.incident.resolve_ts = Some(ts.into());
+        self
+    }
+
+    /// Stores `raw` as the non-canonical start (canonical stays unknown), as ingest does for
+    /// unparseable values.
+    pub fn start_raw(mut self, raw: impl Into<String>) -> Self {
+        self.incident.start_ts = None;
+        self.incident.start_ts_raw = Some(raw.into());
+        self
+    }
+
+    pub fn build(self) -> Incident {
+        self.incident
+    }
+}
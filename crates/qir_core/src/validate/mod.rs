@@ -7,6 +7,8 @@ use crate::error::AppError;
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
 
+pub mod overlap;
+
 fn parse_ts(
     field: &str,
     canonical: &Option<String>,
@@ -165,10 +167,12 @@ pub fn validate_all_incidents(
     conn: &Connection,
 ) -> Result<Vec<IncidentValidationReportItem>, AppError> {
     let incidents = crate::repo::list_incidents(conn)?;
+    let overlaps = overlap::find_overlapping_incidents(&incidents);
     let mut out = Vec::new();
 
     for inc in incidents {
-        let warnings = validate_incident(&inc);
+        let mut warnings = validate_incident(&inc);
+        warnings.extend(overlap::overlap_warnings(&overlaps, inc.id));
         out.push(IncidentValidationReportItem {
             id: inc.id,
             external_id: inc.external_id,
@@ -0,0 +1,132 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::domain::{Incident, ValidationWarning};
+use crate::error::AppError;
+
+/// Share of the shorter incident's window that must overlap the other before the pair is
+/// flagged. Brief touches at the edges (one incident resolving as the next starts) are normal.
+pub const MIN_OVERLAP_RATIO: f64 = 0.5;
+
+/// Pairs whose starts are this close and that overlap almost entirely are likely the same
+/// incident filed twice rather than a cascade.
+const DUPLICATE_START_WINDOW_SECONDS: i64 = 15 * 60;
+const DUPLICATE_OVERLAP_RATIO: f64 = 0.9;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlapKind {
+    PossibleDuplicate,
+    PossibleCascade,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IncidentOverlap {
+    /// The earlier-starting incident.
+    pub incident_id: i64,
+    pub other_incident_id: i64,
+    pub service: String,
+    pub overlap_seconds: i64,
+    /// Overlap divided by the shorter window, rounded to two decimals.
+    pub overlap_ratio: f64,
+    pub kind: OverlapKind,
+}
+
+struct Window<'a> {
+    incident: &'a Incident,
+    service: String,
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+}
+
+fn parse(ts: &Option<String>) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(ts.as_deref()?, &Rfc3339).ok()
+}
+
+/// Impact window: start (or first observed) to resolve (or mitigate). Incidents without both
+/// ends are not compared.
+fn window(incident: &Incident) -> Option<Window<'_>> {
+    let service = incident.service.as_deref()?.trim().to_lowercase();
+    if service.is_empty() {
+        return None;
+    }
+    let start = parse(&incident.start_ts).or_else(|| parse(&incident.first_observed_ts))?;
+    let end = parse(&incident.resolve_ts).or_else(|| parse(&incident.mitigate_ts))?;
+    (end > start).then_some(Window {
+        incident,
+        service,
+        start,
+        end,
+    })
+}
+
+/// Pairs of incidents on the same service whose windows overlap by at least
+/// `MIN_OVERLAP_RATIO`, ordered by service then start.
+pub fn find_overlapping_incidents(incidents: &[Incident]) -> Vec<IncidentOverlap> {
+    let mut windows: Vec<Window<'_>> = incidents.iter().filter_map(window).collect();
+    windows.sort_by(|a, b| (&a.service, a.start, a.incident.id).cmp(&(&b.service, b.start, b.incident.id)));
+
+    let mut out = Vec::new();
+    for (i, a) in windows.iter().enumerate() {
+        for b in &windows[i + 1..] {
+            // Sorted by service then start: nothing after `b` can overlap `a` either.
+            if b.service != a.service || b.start >= a.end {
+                break;
+            }
+            let overlap = (a.end.min(b.end) - b.start).whole_seconds();
+            let shorter = (a.end - a.start).min(b.end - b.start).whole_seconds().max(1);
+            let ratio = overlap as f64 / shorter as f64;
+            if ratio < MIN_OVERLAP_RATIO {
+                continue;
+            }
+            let kind = if ratio >= DUPLICATE_OVERLAP_RATIO
+                && (b.start - a.start).whole_seconds() <= DUPLICATE_START_WINDOW_SECONDS
+            {
+                OverlapKind::PossibleDuplicate
+            } else {
+                OverlapKind::PossibleCascade
+            };
+            out.push(IncidentOverlap {
+                incident_id: a.incident.id,
+                other_incident_id: b.incident.id,
+                service: a.incident.service.clone().unwrap_or_default(),
+                overlap_seconds: overlap,
+                overlap_ratio: (ratio * 100.0).round() / 100.0,
+                kind,
+            });
+        }
+    }
+    out
+}
+
+pub fn list_incident_overlaps(conn: &Connection) -> Result<Vec<IncidentOverlap>, AppError> {
+    Ok(find_overlapping_incidents(&crate::repo::list_incidents(conn)?))
+}
+
+/// One warning per incident per overlapping partner, for the validation report.
+pub(crate) fn overlap_warnings(overlaps: &[IncidentOverlap], incident_id: i64) -> Vec<ValidationWarning> {
+    overlaps
+        .iter()
+        .filter_map(|o| {
+            let other = if o.incident_id == incident_id {
+                o.other_incident_id
+            } else if o.other_incident_id == incident_id {
+                o.incident_id
+            } else {
+                return None;
+            };
+            let message = match o.kind {
+                OverlapKind::PossibleDuplicate => "Overlaps another incident on the same service; possible duplicate",
+                OverlapKind::PossibleCascade => "Overlaps another incident on the same service; possible cascade",
+            };
+            Some(
+                ValidationWarning::new("VALIDATION_INCIDENT_OVERLAP", message).with_details(format!(
+                    "other_incident_id={other}; service={}; overlap_ratio={:.2}",
+                    o.service, o.overlap_ratio
+                )),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,156 @@
+use std::fs;
+use std::path::Path;
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+use super::{is_empty_conn, validate_db_path};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WorkspaceLineage {
+    pub derived_from_db_path: String,
+    pub branch_label: String,
+    pub branched_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WorkspaceBranchResult {
+    pub db_path: String,
+    pub is_empty: bool,
+    pub lineage: WorkspaceLineage,
+}
+
+pub fn get_workspace_lineage(conn: &Connection) -> Result<Option<WorkspaceLineage>, AppError> {
+    conn.query_row(
+        "SELECT derived_from_db_path, branch_label, branched_at FROM workspace_lineage WHERE id = 1",
+        [],
+        |row| {
+            Ok(WorkspaceLineage {
+                derived_from_db_path: row.get(0)?,
+                branch_label: row.get(1)?,
+                branched_at: row.get(2)?,
+            })
+        },
+    )
+    .optional()
+    .map_err(|e| {
+        AppError::new("DB_QUERY_FAILED", "Failed to query workspace lineage")
+            .with_details(e.to_string())
+    })
+}
+
+/// Clones the source workspace (via the SQLite backup API, so the snapshot is consistent even
+/// while the source is open) into a new scratch workspace and marks it as derived-from.
+/// The source workspace is never modified. The new file is staged under a temporary name and
+/// renamed into place only after the lineage row is written.
+pub fn snapshot_branch_workspace(
+    source_conn: &Connection,
+    source_db_path: &Path,
+    dest_db_path: &Path,
+    branch_label: &str,
+    branched_at: &str,
+) -> Result<WorkspaceBranchResult, AppError> {
+    validate_db_path(dest_db_path)?;
+    let branch_label = branch_label.trim();
+    if branch_label.is_empty() {
+        return Err(AppError::new(
+            "WORKSPACE_BRANCH_LABEL_REQUIRED",
+            "Workspace branch label is required",
+        ));
+    }
+    if dest_db_path == source_db_path {
+        return Err(AppError::new(
+            "WORKSPACE_BRANCH_FAILED",
+            "Branch destination must differ from the source workspace",
+        )
+        .with_details(dest_db_path.display().to_string()));
+    }
+    if dest_db_path.exists() {
+        return Err(AppError::new(
+            "WORKSPACE_BRANCH_FAILED",
+            "Branch destination file already exists",
+        )
+        .with_details(dest_db_path.display().to_string()));
+    }
+    let parent = dest_db_path.parent().ok_or_else(|| {
+        AppError::new(
+            "WORKSPACE_INVALID_PATH",
+            "Workspace DB path must have a parent directory",
+        )
+        .with_details(dest_db_path.display().to_string())
+    })?;
+    fs::create_dir_all(parent).map_err(|e| {
+        AppError::new("WORKSPACE_BRANCH_FAILED", "Failed to create branch directory")
+            .with_details(format!("path={}; err={}", parent.display(), e))
+    })?;
+
+    let tmp_path = dest_db_path.with_extension("branch_tmp");
+    if tmp_path.exists() {
+        fs::remove_file(&tmp_path).map_err(|e| {
+            AppError::new("WORKSPACE_BRANCH_FAILED", "Failed to remove stale branch staging file")
+                .with_details(format!("path={}; err={}", tmp_path.display(), e))
+        })?;
+    }
+
+    let stage = || -> Result<(), AppError> {
+        let mut dest_conn = Connection::open(&tmp_path).map_err(|e| {
+            AppError::new("WORKSPACE_BRANCH_FAILED", "Failed to create branch database")
+                .with_details(format!("path={}; err={}", tmp_path.display(), e))
+        })?;
+        {
+            let backup = rusqlite::backup::Backup::new(source_conn, &mut dest_conn).map_err(|e| {
+                AppError::new("WORKSPACE_BRANCH_FAILED", "Failed to start workspace snapshot")
+                    .with_details(e.to_string())
+            })?;
+            backup
+                .run_to_completion(5, std::time::Duration::from_millis(50), None)
+                .map_err(|e| {
+                    AppError::new("WORKSPACE_BRANCH_FAILED", "Failed to complete workspace snapshot")
+                        .with_details(e.to_string())
+                })?;
+        }
+        crate::db::migrate(&mut dest_conn).map_err(|e| {
+            let details = e.details.clone().unwrap_or_else(|| e.to_string());
+            AppError::new("WORKSPACE_MIGRATION_FAILED", "Failed to migrate branch workspace")
+                .with_details(details)
+        })?;
+        dest_conn
+            .execute(
+                "INSERT OR REPLACE INTO workspace_lineage(id, derived_from_db_path, branch_label, branched_at) VALUES (1, ?1, ?2, ?3)",
+                rusqlite::params![source_db_path.to_string_lossy().to_string(), branch_label, branched_at],
+            )
+            .map_err(|e| {
+                AppError::new("WORKSPACE_BRANCH_FAILED", "Failed to record branch lineage")
+                    .with_details(e.to_string())
+            })?;
+        Ok(())
+    };
+
+    if let Err(e) = stage() {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(e);
+    }
+
+    fs::rename(&tmp_path, dest_db_path).map_err(|e| {
+        AppError::new("WORKSPACE_BRANCH_FAILED", "Failed to finalize branch workspace")
+            .with_details(format!(
+                "tmp={}; dest={}; err={}",
+                tmp_path.display(),
+                dest_db_path.display(),
+                e
+            ))
+    })?;
+
+    let conn = super::open_workspace_connection(dest_db_path)?;
+    let lineage = get_workspace_lineage(&conn)?.ok_or_else(|| {
+        AppError::new("WORKSPACE_BRANCH_FAILED", "Branch lineage missing after snapshot")
+            .with_details(dest_db_path.display().to_string())
+    })?;
+    Ok(WorkspaceBranchResult {
+        db_path: dest_db_path.to_string_lossy().to_string(),
+        is_empty: is_empty_conn(&conn)?,
+        lineage,
+    })
+}
@@ -4,6 +4,16 @@ use rusqlite::Connection;
 
 use crate::error::AppError;
 
+mod branch;
+mod paths;
+mod repair;
+pub use branch::{get_workspace_lineage, snapshot_branch_workspace, WorkspaceBranchResult, WorkspaceLineage};
+pub use paths::{
+    init_portable_layout, is_portable_workspace, relocate_data_dir, resolve_workspace_data_paths, set_workspace_portable, validate_data_dir,
+    RelocateSummary, WorkspaceDataPaths, AI_STORE_DIRNAME, ARTIFACTS_DIRNAME, PORTABLE_MARKER_FILENAME,
+};
+pub use repair::{plan_workspace_config_repair, WorkspaceConfigRepair};
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct WorkspaceMetadata {
     pub db_path: String,
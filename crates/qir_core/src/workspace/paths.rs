@@ -0,0 +1,280 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::AppError;
+
+pub const ARTIFACTS_DIRNAME: &str = "artifacts";
+pub const AI_STORE_DIRNAME: &str = "ai";
+/// Present next to the workspace DB when the folder uses the portable layout.
+pub const PORTABLE_MARKER_FILENAME: &str = "incidentreview.portable";
+
+/// Where a workspace's artifacts and AI store live. Portable workspaces keep both next to the
+/// DB file, so moving the folder moves everything; otherwise the machine-level paths apply.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct WorkspaceDataPaths {
+    pub portable: bool,
+    pub artifacts_dir: String,
+    pub ai_store_root: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct RelocateSummary {
+    pub from: String,
+    pub to: String,
+    /// False when there was nothing to move (source missing or same path).
+    pub moved: bool,
+    pub file_count: u64,
+    pub byte_count: u64,
+}
+
+fn invalid(message: &str, path: &Path) -> AppError {
+    AppError::new("WORKSPACE_PATHS_INVALID", message).with_details(path.display().to_string())
+}
+
+fn relocate_failed(message: &str, details: String) -> AppError {
+    AppError::new("WORKSPACE_PATHS_RELOCATE_FAILED", message).with_details(details)
+}
+
+/// A data directory override must be absolute and must not point at a file.
+pub fn validate_data_dir(path: &Path) -> Result<(), AppError> {
+    if path.as_os_str().is_empty() || !path.is_absolute() {
+        return Err(invalid("Data directory must be an absolute path", path));
+    }
+    if path.exists() && !path.is_dir() {
+        return Err(invalid("Data directory must be a folder (not a file)", path));
+    }
+    Ok(())
+}
+
+fn tree_totals(dir: &Path) -> Result<(u64, u64), AppError> {
+    let mut files = 0u64;
+    let mut bytes = 0u64;
+    let entries = fs::read_dir(dir).map_err(|e| {
+        relocate_failed("Failed to read data directory", format!("path={}; err={}", dir.display(), e))
+    })?;
+    for entry in entries {
+        let entry = entry.map_err(|e| {
+            relocate_failed("Failed to read data directory", format!("path={}; err={}", dir.display(), e))
+        })?;
+        let path = entry.path();
+        if path.is_dir() {
+            let (f, b) = tree_totals(&path)?;
+            files += f;
+            bytes += b;
+        } else {
+            let meta = entry.metadata().map_err(|e| {
+                relocate_failed("Failed to stat data file", format!("path={}; err={}", path.display(), e))
+            })?;
+            files += 1;
+            bytes += meta.len();
+        }
+    }
+    Ok((files, bytes))
+}
+
+fn copy_tree(from: &Path, to: &Path) -> Result<(), AppError> {
+    fs::create_dir_all(to).map_err(|e| {
+        relocate_failed("Failed to create data directory", format!("path={}; err={}", to.display(), e))
+    })?;
+    let entries = fs::read_dir(from).map_err(|e| {
+        relocate_failed("Failed to read data directory", format!("path={}; err={}", from.display(), e))
+    })?;
+    for entry in entries {
+        let entry = entry.map_err(|e| {
+            relocate_failed("Failed to read data directory", format!("path={}; err={}", from.display(), e))
+        })?;
+        let src = entry.path();
+        let dest = to.join(entry.file_name());
+        if src.is_dir() {
+            copy_tree(&src, &dest)?;
+        } else {
+            fs::copy(&src, &dest).map_err(|e| {
+                relocate_failed(
+                    "Failed to copy data file",
+                    format!("from={}; to={}; err={}", src.display(), dest.display(), e),
+                )
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Moves the contents of data directory `from` to `to`.
+///
+/// `to` must be empty or missing; existing content is never merged or overwritten. A plain
+/// rename is tried first; across filesystems the tree is copied, verified by file count and
+/// total size, and only then removed from `from`. On any failure `from` is left intact.
+pub fn relocate_data_dir(from: &Path, to: &Path) -> Result<RelocateSummary, AppError> {
+    validate_data_dir(to)?;
+    let summary = |moved: bool, file_count: u64, byte_count: u64| RelocateSummary {
+        from: from.display().to_string(),
+        to: to.display().to_string(),
+        moved,
+        file_count,
+        byte_count,
+    };
+
+    if from == to {
+        return Ok(summary(false, 0, 0));
+    }
+    if to.starts_with(from) || from.starts_with(to) {
+        return Err(invalid("Data directory cannot be moved into or out of itself", to));
+    }
+    if !from.is_dir() {
+        fs::create_dir_all(to).map_err(|e| {
+            relocate_failed("Failed to create data directory", format!("path={}; err={}", to.display(), e))
+        })?;
+        return Ok(summary(false, 0, 0));
+    }
+    if to.exists() {
+        let non_empty = fs::read_dir(to)
+            .map(|mut it| it.next().is_some())
+            .unwrap_or(true);
+        if non_empty {
+            return Err(invalid("Destination data directory must be empty", to));
+        }
+        fs::remove_dir(to).map_err(|e| {
+            relocate_failed("Failed to prepare destination", format!("path={}; err={}", to.display(), e))
+        })?;
+    }
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            relocate_failed("Failed to create destination parent", format!("path={}; err={}", parent.display(), e))
+        })?;
+    }
+
+    let (file_count, byte_count) = tree_totals(from)?;
+    if fs::rename(from, to).is_ok() {
+        return Ok(summary(true, file_count, byte_count));
+    }
+
+    // Rename fails across filesystems: copy, verify, then drop the source.
+    if let Err(e) = copy_tree(from, to) {
+        let _ = fs::remove_dir_all(to);
+        return Err(e);
+    }
+    let copied = tree_totals(to)?;
+    if copied != (file_count, byte_count) {
+        let _ = fs::remove_dir_all(to);
+        return Err(relocate_failed(
+            "Copied data directory does not match the source",
+            format!(
+                "files={}/{}; bytes={}/{}",
+                copied.0, file_count, copied.1, byte_count
+            ),
+        ));
+    }
+    fs::remove_dir_all(from).map_err(|e| {
+        relocate_failed(
+            "Data was copied but the old directory could not be removed",
+            format!("path={}; err={}", from.display(), e),
+        )
+    })?;
+    Ok(summary(true, file_count, byte_count))
+}
+
+fn workspace_folder(db_path: &Path) -> Result<&Path, AppError> {
+    db_path
+        .parent()
+        .filter(|p| p.is_absolute())
+        .ok_or_else(|| invalid("Workspace DB path must be absolute", db_path))
+}
+
+pub fn is_portable_workspace(db_path: &Path) -> bool {
+    db_path
+        .parent()
+        .is_some_and(|dir| dir.join(PORTABLE_MARKER_FILENAME).is_file())
+}
+
+/// Resolves data paths for `db_path`, falling back to the machine-level directories for
+/// workspaces without the portable marker.
+pub fn resolve_workspace_data_paths(
+    db_path: &Path,
+    machine_artifacts_dir: &Path,
+    machine_ai_store_root: &Path,
+) -> Result<WorkspaceDataPaths, AppError> {
+    let portable = is_portable_workspace(db_path);
+    let (artifacts, ai) = if portable {
+        let dir = workspace_folder(db_path)?;
+        (dir.join(ARTIFACTS_DIRNAME), dir.join(AI_STORE_DIRNAME))
+    } else {
+        (machine_artifacts_dir.to_path_buf(), machine_ai_store_root.to_path_buf())
+    };
+    Ok(WorkspaceDataPaths {
+        portable,
+        artifacts_dir: artifacts.display().to_string(),
+        ai_store_root: ai.display().to_string(),
+    })
+}
+
+/// Starts a fresh workspace in the portable layout without moving any existing data (used for
+/// new workspaces such as the guided tour). The data folders must be empty or missing.
+pub fn init_portable_layout(db_path: &Path) -> Result<WorkspaceDataPaths, AppError> {
+    let dir = workspace_folder(db_path)?;
+    for name in [ARTIFACTS_DIRNAME, AI_STORE_DIRNAME] {
+        let data_dir = dir.join(name);
+        let occupied = fs::read_dir(&data_dir).map(|mut it| it.next().is_some()).unwrap_or(false);
+        if occupied && !is_portable_workspace(db_path) {
+            return Err(invalid("Workspace folder already contains app data", &data_dir));
+        }
+        fs::create_dir_all(&data_dir).map_err(|e| {
+            relocate_failed("Failed to create data directory", format!("path={}; err={}", data_dir.display(), e))
+        })?;
+    }
+    write_portable_marker(dir)?;
+    resolve_workspace_data_paths(db_path, &dir.join(ARTIFACTS_DIRNAME), &dir.join(AI_STORE_DIRNAME))
+}
+
+fn write_portable_marker(dir: &Path) -> Result<(), AppError> {
+    let marker = dir.join(PORTABLE_MARKER_FILENAME);
+    fs::write(&marker, b"artifacts/ and ai/ in this folder belong to the workspace DB next to them.\n").map_err(|e| {
+        relocate_failed(
+            "Failed to write the portable marker",
+            format!("path={}; err={}", marker.display(), e),
+        )
+    })
+}
+
+/// Switches the workspace folder to (or from) the portable layout, moving artifacts and the
+/// AI store with `relocate_data_dir`. The marker is only written or removed once both moves
+/// succeed; if the second move fails the first is moved back.
+pub fn set_workspace_portable(
+    db_path: &Path,
+    enabled: bool,
+    machine_artifacts_dir: &Path,
+    machine_ai_store_root: &Path,
+) -> Result<WorkspaceDataPaths, AppError> {
+    if !db_path.is_file() {
+        return Err(AppError::new("WORKSPACE_DB_NOT_FOUND", "Workspace database file not found")
+            .with_details(db_path.display().to_string()));
+    }
+    let dir = workspace_folder(db_path)?;
+    if is_portable_workspace(db_path) == enabled {
+        return resolve_workspace_data_paths(db_path, machine_artifacts_dir, machine_ai_store_root);
+    }
+
+    let portable = (dir.join(ARTIFACTS_DIRNAME), dir.join(AI_STORE_DIRNAME));
+    let machine = (machine_artifacts_dir.to_path_buf(), machine_ai_store_root.to_path_buf());
+    let (from, to): ((PathBuf, PathBuf), (PathBuf, PathBuf)) =
+        if enabled { (machine, portable) } else { (portable, machine) };
+
+    relocate_data_dir(&from.0, &to.0)?;
+    if let Err(e) = relocate_data_dir(&from.1, &to.1) {
+        let _ = relocate_data_dir(&to.0, &from.0);
+        return Err(e);
+    }
+
+    if enabled {
+        write_portable_marker(dir)?;
+    } else {
+        let marker = dir.join(PORTABLE_MARKER_FILENAME);
+        fs::remove_file(&marker).map_err(|e| {
+            relocate_failed(
+                "Data was moved but the portable marker could not be removed",
+                format!("path={}; err={}", marker.display(), e),
+            )
+        })?;
+    }
+
+    resolve_workspace_data_paths(db_path, machine_artifacts_dir, machine_ai_store_root)
+}
@@ -0,0 +1,71 @@
+use std::path::{Path, PathBuf};
+
+/// Outcome of checking the persisted workspace selection against the filesystem.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct WorkspaceConfigRepair {
+    /// The configured last workspace when its DB file no longer exists.
+    pub missing_db_path: Option<String>,
+    /// Existing DB files the missing workspace could be remapped to, most likely first.
+    pub candidates: Vec<String>,
+    /// Recent entries whose DB file is gone.
+    pub pruned_recent_db_paths: Vec<String>,
+    /// Recent entries that still exist, order preserved.
+    pub recent_db_paths: Vec<String>,
+}
+
+impl WorkspaceConfigRepair {
+    pub fn needs_repair(&self) -> bool {
+        self.missing_db_path.is_some() || !self.pruned_recent_db_paths.is_empty()
+    }
+}
+
+fn push_unique(out: &mut Vec<String>, path: &Path) {
+    let s = path.to_string_lossy().to_string();
+    if !out.contains(&s) {
+        out.push(s);
+    }
+}
+
+/// Detects a stale `last_db_path` and dead recents. `fallbacks` are known DB locations (the
+/// default app-data DB, a DB next to the config file); each fallback's folder is also searched
+/// for a file named like the missing DB, which covers app data copied to a new machine.
+pub fn plan_workspace_config_repair(
+    last_db_path: Option<&Path>,
+    recent_db_paths: &[PathBuf],
+    fallbacks: &[PathBuf],
+) -> WorkspaceConfigRepair {
+    let mut repair = WorkspaceConfigRepair::default();
+    for p in recent_db_paths {
+        if p.is_file() {
+            push_unique(&mut repair.recent_db_paths, p);
+        } else {
+            push_unique(&mut repair.pruned_recent_db_paths, p);
+        }
+    }
+
+    let Some(missing) = last_db_path.filter(|p| !p.is_file()) else {
+        return repair;
+    };
+    repair.missing_db_path = Some(missing.to_string_lossy().to_string());
+
+    let file_name = missing.file_name();
+    let mut candidates = Vec::new();
+    for fallback in fallbacks {
+        if let (Some(dir), Some(name)) = (fallback.parent(), file_name) {
+            let same_name = dir.join(name);
+            if same_name.is_file() {
+                push_unique(&mut candidates, &same_name);
+            }
+        }
+    }
+    for fallback in fallbacks {
+        if fallback.is_file() {
+            push_unique(&mut candidates, fallback);
+        }
+    }
+    for p in &repair.recent_db_paths {
+        push_unique(&mut candidates, Path::new(p));
+    }
+    repair.candidates = candidates;
+    repair
+}
@@ -0,0 +1,117 @@
+use qir_core::analytics::{
+    build_dashboard_payload_v2, save_business_hours, save_dashboard_config, BusinessHoursConfig,
+    DashboardConfig, DashboardPayloadV2, HoursClass,
+};
+use qir_core::cache::compute_dashboard_v2_hash;
+use qir_core::db;
+use rusqlite::Connection;
+
+fn insert(conn: &Connection, id: i64, vendor: &str, start: Option<&str>, resolve: Option<&str>) {
+    conn.execute(
+        "INSERT INTO incidents(id, external_id, fingerprint, title, vendor, impact_pct, service_health_pct,
+                               start_ts, resolve_ts, ingested_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, 50, 80, ?6, ?7, '2026-07-01T00:00:00Z')",
+        rusqlite::params![id, format!("INC-{id}"), format!("fp-{id}"), format!("Incident {id}"), vendor, start, resolve],
+    )
+    .expect("insert");
+}
+
+/// One-hour incidents: Tuesday morning, Tuesday evening, Saturday morning, and one without a start.
+fn setup() -> Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    insert(&conn, 1, "A", Some("2026-01-06T10:00:00Z"), Some("2026-01-06T11:00:00Z"));
+    insert(&conn, 2, "B", Some("2026-01-06T20:00:00Z"), Some("2026-01-06T21:00:00Z"));
+    insert(&conn, 3, "C", Some("2026-01-10T10:00:00Z"), Some("2026-01-10T11:00:00Z"));
+    insert(&conn, 4, "D", None, None);
+    conn
+}
+
+fn hours_buckets(dash: &DashboardPayloadV2) -> Vec<(String, Vec<i64>)> {
+    dash.response_story
+        .start_hours_buckets
+        .iter()
+        .map(|b| (b.key.clone(), b.incident_ids.clone()))
+        .collect()
+}
+
+fn vendor_pain(dash: &DashboardPayloadV2, vendor: &str) -> i64 {
+    dash.vendor_service_story
+        .top_vendors_by_pain
+        .iter()
+        .find(|b| b.label == vendor)
+        .expect("vendor bucket")
+        .pain_sum
+}
+
+#[test]
+fn incident_starts_are_bucketed_by_business_hours() {
+    let conn = setup();
+    let dash = build_dashboard_payload_v2(&conn).expect("dash");
+    assert_eq!(
+        hours_buckets(&dash),
+        vec![
+            ("start_hours:business_hours".to_string(), vec![1]),
+            ("start_hours:after_hours".to_string(), vec![2]),
+            ("start_hours:weekend".to_string(), vec![3]),
+            ("start_hours:unknown".to_string(), vec![4]),
+        ]
+    );
+    let classes: Vec<Option<HoursClass>> = dash.incidents.iter().map(|i| i.start_hours_class).collect();
+    assert_eq!(
+        classes,
+        vec![
+            Some(HoursClass::BusinessHours),
+            Some(HoursClass::AfterHours),
+            Some(HoursClass::Weekend),
+            None
+        ]
+    );
+
+    // At UTC-10:00 the Tuesday 20:00Z start is 10:00 local and the 10:00Z start is midnight.
+    let before = compute_dashboard_v2_hash(&conn).expect("hash");
+    save_business_hours(
+        &conn,
+        &BusinessHoursConfig {
+            utc_offset_minutes: -600,
+            ..BusinessHoursConfig::default()
+        },
+    )
+    .expect("save hours");
+    assert_ne!(compute_dashboard_v2_hash(&conn).expect("hash"), before);
+    let shifted = build_dashboard_payload_v2(&conn).expect("dash");
+    assert_eq!(hours_buckets(&shifted)[0].1, vec![2]);
+    assert_eq!(hours_buckets(&shifted)[1].1, vec![1]);
+}
+
+#[test]
+fn after_hours_pain_multiplier_weights_pain() {
+    let conn = setup();
+    let dash = build_dashboard_payload_v2(&conn).expect("dash");
+    // 50% impact × 20% degradation × 3600s.
+    assert_eq!(vendor_pain(&dash, "A"), 3_600_000);
+    assert_eq!(vendor_pain(&dash, "B"), 3_600_000);
+
+    save_dashboard_config(
+        &conn,
+        &DashboardConfig {
+            after_hours_pain_multiplier_pct: 150,
+            ..DashboardConfig::default()
+        },
+    )
+    .expect("save");
+    let weighted = build_dashboard_payload_v2(&conn).expect("dash");
+    assert_eq!(vendor_pain(&weighted, "A"), 3_600_000);
+    assert_eq!(vendor_pain(&weighted, "B"), 5_400_000);
+    assert_eq!(vendor_pain(&weighted, "C"), 5_400_000);
+
+    let err = save_dashboard_config(
+        &conn,
+        &DashboardConfig {
+            after_hours_pain_multiplier_pct: 50,
+            ..DashboardConfig::default()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "VALIDATION_DASHBOARD_CONFIG_INVALID");
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "testing")]
+
+use qir_core::ai_drafts::policy::{
+    approve_ai_draft, ensure_draft_exportable, get_ai_draft_approval, load_ai_usage_policy, revoke_ai_draft_approval,
+    save_ai_usage_policy, AiUsagePolicy,
+};
+use qir_core::ai_drafts::{create_ai_draft, AiDraftArtifact, AiDraftSectionType, CreateAiDraftInput};
+use qir_core::report::{generate_qir_report, QirReportOptions};
+use qir_core::testing::empty_db;
+use rusqlite::Connection;
+
+fn setup() -> (Connection, AiDraftArtifact) {
+    let conn = empty_db().expect("db");
+    let draft = create_ai_draft(
+        &conn,
+        CreateAiDraftInput {
+            quarter_label: "Q1 2026".to_string(),
+            section_type: AiDraftSectionType::ExecSummary,
+            draft_text: "Vendor outages dominated [[chunk:a]]".to_string(),
+            citation_chunk_ids: vec!["a".to_string()],
+            model_name: "llama3.2:latest".to_string(),
+            model_params_hash: "params_hash".to_string(),
+            prompt_template_version: "exec_summary_v1".to_string(),
+            created_at: "2026-02-10T00:00:00Z".to_string(),
+            parent_draft_id: None,
+            revision_notes: None,
+            branch_label: None,
+        },
+    )
+    .expect("draft");
+    (conn, draft)
+}
+
+#[test]
+fn default_policy_allows_everything() {
+    let (conn, draft) = setup();
+    let policy = load_ai_usage_policy(&conn).expect("load");
+    assert_eq!(policy, AiUsagePolicy::default());
+    policy.check_draft(&AiDraftSectionType::ThemeAnalysis, 500).expect("no limits");
+    ensure_draft_exportable(&conn, &policy, &draft).expect("no approval needed");
+}
+
+#[test]
+fn policy_limits_sections_and_chunks() {
+    let (conn, _) = setup();
+    let policy = AiUsagePolicy {
+        max_chunks_per_draft: Some(3),
+        allowed_section_types: Some(vec![AiDraftSectionType::ExecSummary]),
+        require_approval_before_export: false,
+        disclaimer: Some("  Generated with AI assistance; verify against cited evidence.  ".to_string()),
+    };
+    save_ai_usage_policy(&conn, &policy).expect("save");
+    let loaded = load_ai_usage_policy(&conn).expect("load");
+    assert_eq!(loaded, policy);
+    assert_eq!(
+        loaded.disclaimer_text(),
+        Some("Generated with AI assistance; verify against cited evidence.")
+    );
+
+    loaded.check_draft(&AiDraftSectionType::ExecSummary, 3).expect("within limits");
+    let err = loaded.check_draft(&AiDraftSectionType::ExecSummary, 4).unwrap_err();
+    assert_eq!(err.code, "AI_POLICY_VIOLATION");
+    let err = loaded.check_draft(&AiDraftSectionType::ThemeAnalysis, 1).unwrap_err();
+    assert_eq!(err.code, "AI_POLICY_VIOLATION");
+
+    let invalid = AiUsagePolicy {
+        allowed_section_types: Some(Vec::new()),
+        ..AiUsagePolicy::default()
+    };
+    let err = save_ai_usage_policy(&conn, &invalid).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_AI_USAGE_POLICY_INVALID");
+    let invalid = AiUsagePolicy {
+        max_chunks_per_draft: Some(0),
+        ..AiUsagePolicy::default()
+    };
+    assert!(save_ai_usage_policy(&conn, &invalid).is_err());
+}
+
+#[test]
+fn approval_gates_export_and_report_inclusion() {
+    let (conn, draft) = setup();
+    let policy = AiUsagePolicy {
+        require_approval_before_export: true,
+        disclaimer: Some("AI-assisted draft".to_string()),
+        ..AiUsagePolicy::default()
+    };
+    save_ai_usage_policy(&conn, &policy).expect("save");
+    let opts = QirReportOptions {
+        draft_ids: vec![draft.id],
+        ..QirReportOptions::default()
+    };
+
+    let err = ensure_draft_exportable(&conn, &policy, &draft).unwrap_err();
+    assert_eq!(err.code, "AI_POLICY_APPROVAL_REQUIRED");
+    let err = generate_qir_report(&conn, &opts, "2026-03-01T00:00:00Z", "test").unwrap_err();
+    assert_eq!(err.code, "AI_POLICY_APPROVAL_REQUIRED");
+
+    let approval = approve_ai_draft(&conn, draft.id, " lead ", "2026-02-11T00:00:00Z").expect("approve");
+    assert_eq!(approval.approved_by, "lead");
+    assert_eq!(get_ai_draft_approval(&conn, draft.id).unwrap(), Some(approval));
+    ensure_draft_exportable(&conn, &policy, &draft).expect("approved");
+    let md = generate_qir_report(&conn, &opts, "2026-03-01T00:00:00Z", "test")
+        .expect("report")
+        .markdown;
+    assert!(md.contains("Vendor outages dominated [[chunk:a]]\n\n_AI-assisted draft_\n"));
+
+    revoke_ai_draft_approval(&conn, draft.id).expect("revoke");
+    assert!(get_ai_draft_approval(&conn, draft.id).unwrap().is_none());
+    assert_eq!(approve_ai_draft(&conn, 999, "lead", "2026-02-11T00:00:00Z").unwrap_err().code, "DB_NOT_FOUND");
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::analytics::export_bucket_csv;
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+/// MTTRs: 1 and 3 over 24h (Q1 and Q2), 2 under an hour.
+fn setup() -> Connection {
+    let incident = |id: i64, title: &str, sev: &str, start: &str, resolve: &str| {
+        IncidentBuilder::new(id, title)
+            .external_id(format!("INC-{id}"))
+            .severity(sev)
+            .start(start)
+            .resolve(resolve)
+            .build()
+    };
+    db_with_incidents(&[
+        incident(1, "Ledger drift, region \"eu\"", "SEV1", "2026-02-01T00:00:00Z", "2026-02-03T00:00:00Z"),
+        incident(2, "Search latency", "SEV2", "2026-02-05T10:00:00Z", "2026-02-05T10:30:00Z"),
+        incident(3, "Batch stuck", "SEV2", "2026-05-01T00:00:00Z", "2026-05-02T06:00:00Z"),
+    ])
+    .expect("db")
+}
+
+#[test]
+fn writes_the_bucket_incidents_as_csv() {
+    let conn = setup();
+    let dir = tempfile::tempdir().expect("dir");
+    let result = export_bucket_csv(&conn, "time_to_resolve:gt_24h", None, dir.path()).expect("export");
+    assert_eq!(result.bucket_label, ">24h");
+    assert_eq!(result.incident_count, 2);
+    assert!(result.csv_path.ends_with("bucket_time_to_resolve_gt_24h.csv"));
+
+    let text = std::fs::read_to_string(&result.csv_path).expect("read");
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "id,external_id,title,severity,vendor,service,start_ts,mtta_seconds,time_to_mitigation_seconds,mttr_seconds,pain_units,warning_count",
+            "1,INC-1,\"Ledger drift, region \"\"eu\"\"\",SEV1,,,2026-02-01T00:00:00Z,,,172800,,0",
+            "3,INC-3,Batch stuck,SEV2,,,2026-05-01T00:00:00Z,,,108000,,0",
+        ]
+    );
+
+    // Severity counts are addressable too; an existing file is never overwritten.
+    let sev = export_bucket_csv(&conn, "severity:SEV2", None, dir.path()).expect("severity");
+    assert_eq!(sev.incident_count, 2);
+    let err = export_bucket_csv(&conn, "severity:SEV2", None, dir.path()).unwrap_err();
+    assert_eq!(err.code, "EXPORT_BUCKET_DEST_EXISTS");
+}
+
+#[test]
+fn quarter_scope_narrows_the_bucket_and_unknown_keys_fail() {
+    let conn = setup();
+    let dir = tempfile::tempdir().expect("dir");
+    let q1 = export_bucket_csv(&conn, "time_to_resolve:gt_24h", Some("Q1 2026"), dir.path()).expect("q1");
+    assert_eq!(q1.incident_count, 1);
+    assert!(q1.csv_path.ends_with("bucket_time_to_resolve_gt_24h_Q1_2026.csv"));
+
+    // Q2 has no short incident, so the bucket does not exist there.
+    let err = export_bucket_csv(&conn, "time_to_resolve:le_1h", Some("Q2 2026"), dir.path()).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_BUCKET_KEY_UNKNOWN");
+
+    let err = export_bucket_csv(&conn, "vendor:Acme", None, &dir.path().join("missing")).unwrap_err();
+    assert_eq!(err.code, "EXPORT_BUCKET_DEST_NOT_DIR");
+}
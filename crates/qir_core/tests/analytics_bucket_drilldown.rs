@@ -0,0 +1,65 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::analytics::{bucket_drilldown, build_dashboard_payload_v2};
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+fn setup() -> Connection {
+    db_with_incidents(&[
+        IncidentBuilder::new(1, "Checkout errors")
+            .external_id("INC-2")
+            .severity("SEV1")
+            .vendor("Acme")
+            .impact(50, 80)
+            .start("2026-01-06T10:00:00Z")
+            .resolve("2026-01-06T11:00:00Z")
+            .build(),
+        IncidentBuilder::new(2, "Search latency")
+            .external_id("INC-1")
+            .severity("SEV2")
+            .vendor("Acme")
+            .start("2026-01-07T10:00:00Z")
+            .resolve("2026-01-07T09:00:00Z")
+            .build(),
+        IncidentBuilder::new(3, "Queue backlog").vendor("Other Co").build(),
+    ])
+    .expect("db")
+}
+
+#[test]
+fn drilldown_summarizes_a_dashboard_bucket_in_dashboard_order() {
+    let conn = setup();
+    let dash = build_dashboard_payload_v2(&conn).expect("dash");
+    let acme = dash
+        .vendor_service_story
+        .top_vendors_by_pain
+        .iter()
+        .find(|b| b.label == "Acme")
+        .expect("acme bucket");
+
+    let drill = bucket_drilldown(&conn, &acme.incident_ids).expect("drill");
+    let ids: Vec<(i64, Option<&str>)> = drill.incidents.iter().map(|i| (i.id, i.external_id.as_deref())).collect();
+    assert_eq!(ids, vec![(2, Some("INC-1")), (1, Some("INC-2"))]);
+    // INC-1 resolved before it started, so it has no MTTR and a warning.
+    let metrics: Vec<(Option<i64>, Option<i64>)> = drill.incidents.iter().map(|i| (i.mttr_seconds, i.pain_units)).collect();
+    assert_eq!(metrics, vec![(None, None), (Some(3600), Some(acme.pain_sum))]);
+    assert!(drill.incidents[0].warning_count > 0);
+    assert_eq!(drill.incidents[1].warning_count, 0);
+    assert!(drill.missing_ids.is_empty());
+}
+
+#[test]
+fn drilldown_is_independent_of_request_order_and_reports_missing_ids() {
+    let conn = setup();
+    let a = bucket_drilldown(&conn, &[3, 99, 1, 3, 42]).expect("a");
+    let b = bucket_drilldown(&conn, &[42, 1, 99, 3]).expect("b");
+    assert_eq!(a, b);
+    // No external_id sorts first.
+    assert_eq!(a.incidents.iter().map(|i| i.id).collect::<Vec<_>>(), vec![3, 1]);
+    assert_eq!(a.missing_ids, vec![42, 99]);
+
+    let empty = bucket_drilldown(&conn, &[]).expect("empty");
+    assert!(empty.incidents.is_empty() && empty.missing_ids.is_empty());
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "testing")]
+
+use std::collections::BTreeMap;
+
+use pretty_assertions::assert_eq;
+
+use qir_core::analytics::{
+    build_dashboard_payload_v2, save_pain_formula, DashboardPayloadV2, PainFormulaConfig, PainScheme,
+    PAIN_FORMULA_DEFAULT_VERSION,
+};
+use qir_core::cache::compute_dashboard_v2_hash;
+use qir_core::report::generate_qir_markdown;
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+/// A: SEV1, 50% impact at 80% health for 2h spanning 16:00-18:00 on a Tuesday (1h in business
+/// hours). B: SEV3, 100% impact at 100% health for 1h.
+fn setup() -> Connection {
+    db_with_incidents(&[
+        IncidentBuilder::new(1, "Gateway errors")
+            .external_id("INC-1")
+            .severity("SEV1")
+            .vendor("A")
+            .impact(50, 80)
+            .start("2026-01-06T16:00:00Z")
+            .resolve("2026-01-06T18:00:00Z")
+            .build(),
+        IncidentBuilder::new(2, "Full outage, health unknown")
+            .external_id("INC-2")
+            .severity("SEV3")
+            .vendor("B")
+            .impact(100, 100)
+            .start("2026-01-07T10:00:00Z")
+            .resolve("2026-01-07T11:00:00Z")
+            .build(),
+    ])
+    .expect("db")
+}
+
+fn vendor_pain(dash: &DashboardPayloadV2) -> Vec<(String, i64)> {
+    let mut out: Vec<(String, i64)> = dash
+        .vendor_service_story
+        .top_vendors_by_pain
+        .iter()
+        .map(|b| (b.label.clone(), b.pain_sum))
+        .collect();
+    out.sort();
+    out
+}
+
+#[test]
+fn default_formula_is_unchanged_and_versioned_v1() {
+    let conn = setup();
+    let dash = build_dashboard_payload_v2(&conn).expect("dash");
+    // 50 x 20 x 7200 and 100 x 0 x 3600.
+    assert_eq!(vendor_pain(&dash), vec![("A".to_string(), 7_200_000), ("B".to_string(), 0)]);
+    assert_eq!(dash.pain_formula_version, PAIN_FORMULA_DEFAULT_VERSION);
+
+    let md = generate_qir_markdown(&conn).expect("report");
+    assert!(md.contains("_Pain formula v1: impact × degradation × duration._"));
+    assert!(md.contains("### Top vendors by weighted pain (impact × degradation × duration)"));
+}
+
+#[test]
+fn custom_formula_reweights_caps_and_changes_the_version() {
+    let conn = setup();
+    let before = compute_dashboard_v2_hash(&conn).expect("hash");
+    let formula = PainFormulaConfig {
+        scheme: PainScheme::WeightedSum,
+        impact_weight_pct: 300,
+        degradation_weight_pct: 100,
+        severity_multipliers_pct: BTreeMap::from([("SEV1".to_string(), 200)]),
+        cap_per_incident: Some(20_000_000),
+        business_hours_only_duration: true,
+    };
+    save_pain_formula(&conn, &formula).expect("save");
+    assert_ne!(compute_dashboard_v2_hash(&conn).expect("hash"), before);
+
+    let dash = build_dashboard_payload_v2(&conn).expect("dash");
+    // A: (3x50 + 1x20)/4 x 100 x 3600 business seconds x 2 = 30_600_000, capped.
+    // B: (3x100 + 1x0)/4 x 100 x 3600 = 27_000_000, capped.
+    assert_eq!(vendor_pain(&dash), vec![("A".to_string(), 20_000_000), ("B".to_string(), 20_000_000)]);
+    assert!(dash.pain_formula_version.starts_with("v2-") && dash.pain_formula_version.len() == 11);
+
+    let md = generate_qir_markdown(&conn).expect("report");
+    assert!(md.contains(&format!(
+        "_Pain formula {}: (impact × 300% + degradation × 100%) × duration; business-hours duration only; \
+         severity weights SEV1 ×2.00; capped at 20000000 per incident._",
+        dash.pain_formula_version
+    )));
+
+    let err = save_pain_formula(
+        &conn,
+        &PainFormulaConfig {
+            impact_weight_pct: 0,
+            degradation_weight_pct: 0,
+            ..PainFormulaConfig::default()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "VALIDATION_PAIN_FORMULA_INVALID");
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::analytics::{build_dashboard_payload_v2, load_dashboard_config, save_dashboard_config, DashboardConfig};
+use qir_core::report::generate_qir_markdown;
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+/// Vendors A×3, B×2, C×1 and Legacy×1; every incident is on service "web".
+fn setup() -> Connection {
+    let incidents: Vec<_> = [(1, "A"), (2, "A"), (3, "A"), (4, "B"), (5, "B"), (6, "C"), (7, "Legacy")]
+        .into_iter()
+        .map(|(id, vendor)| {
+            IncidentBuilder::new(id, format!("Incident {id}"))
+                .external_id(format!("INC-{id}"))
+                .vendor(vendor)
+                .service("web")
+                .impact(50, 80)
+                .start("2026-01-06T10:00:00Z")
+                .resolve("2026-01-06T11:00:00Z")
+                .build()
+        })
+        .collect();
+    db_with_incidents(&incidents).expect("db")
+}
+
+fn labels_and_counts<'a>(buckets: impl IntoIterator<Item = (&'a String, i64)>) -> Vec<(String, i64)> {
+    buckets.into_iter().map(|(l, c)| (l.clone(), c)).collect()
+}
+
+#[test]
+fn pinned_names_keep_their_bucket_and_the_threshold_feeds_other() {
+    let conn = setup();
+    save_dashboard_config(
+        &conn,
+        &DashboardConfig {
+            top_n: 2,
+            pinned_vendors: vec!["Legacy".to_string(), "Retired".to_string()],
+            other_min_count: 3,
+            ..DashboardConfig::default()
+        },
+    )
+    .expect("save");
+    assert_eq!(load_dashboard_config(&conn).expect("load").pinned_vendors, vec!["Legacy", "Retired"]);
+
+    let dash = build_dashboard_payload_v2(&conn).expect("dash");
+    let story = &dash.vendor_service_story;
+    // B ranks second but is under the threshold; Legacy ranks last but is pinned; Retired has
+    // no incidents and gets no bucket.
+    let expected = vec![("A".to_string(), 3), ("Legacy".to_string(), 1), ("OTHER".to_string(), 3)];
+    assert_eq!(
+        labels_and_counts(story.top_vendors_by_count.iter().map(|b| (&b.label, b.count))),
+        expected
+    );
+    assert_eq!(
+        labels_and_counts(story.top_vendors_by_pain.iter().map(|b| (&b.label, b.count))),
+        expected
+    );
+    let other = story.top_vendors_by_count.last().expect("other");
+    assert_eq!(other.incident_ids, vec![4, 5, 6]);
+    // Pins are per dimension: the single service still clears the threshold on its own.
+    assert_eq!(
+        labels_and_counts(story.top_services_by_count.iter().map(|b| (&b.label, b.count))),
+        vec![("web".to_string(), 7)]
+    );
+
+    let md = generate_qir_markdown(&conn).expect("report");
+    assert!(md.contains("_Always listed vendors: Legacy, Retired._"));
+    assert!(md.contains("_Unpinned vendors/services with fewer than 3 incidents are grouped under OTHER._"));
+    assert!(md.contains("- Legacy: 1\n"));
+}
+
+#[test]
+fn default_config_reports_no_bucket_rules_and_rejects_bad_pins() {
+    let conn = setup();
+    let md = generate_qir_markdown(&conn).expect("report");
+    assert!(!md.contains("_Always listed"));
+    assert!(!md.contains("grouped under OTHER"));
+
+    for bad in [
+        DashboardConfig {
+            pinned_services: vec!["api".to_string(), "api".to_string()],
+            ..DashboardConfig::default()
+        },
+        DashboardConfig {
+            pinned_vendors: vec![" A".to_string()],
+            ..DashboardConfig::default()
+        },
+        DashboardConfig {
+            other_min_count: 0,
+            ..DashboardConfig::default()
+        },
+    ] {
+        let err = save_dashboard_config(&conn, &bad).unwrap_err();
+        assert_eq!(err.code, "VALIDATION_DASHBOARD_CONFIG_INVALID");
+    }
+    assert_eq!(load_dashboard_config(&conn).expect("load"), DashboardConfig::default());
+}
@@ -1,6 +1,9 @@
 use std::collections::BTreeSet;
 
-use qir_core::analytics::build_dashboard_payload_v2;
+use qir_core::analytics::{
+    build_dashboard_payload_v2, load_dashboard_config, save_dashboard_config, DashboardConfig,
+};
+use qir_core::demo::seed_demo_dataset;
 use qir_core::db;
 use qir_core::ingest::jira_csv::{ingest_jira_csv, JiraCsvMapping};
 
@@ -10,6 +13,7 @@ fn story_mapping() -> JiraCsvMapping {
         title: "Summary".to_string(),
         description: Some("Description".to_string()),
         severity: Some("Severity".to_string()),
+        initial_severity: None,
         detection_source: Some("DetectionSource".to_string()),
         vendor: Some("Vendor".to_string()),
         service: Some("Service".to_string()),
@@ -21,6 +25,9 @@ fn story_mapping() -> JiraCsvMapping {
         ack_ts: Some("AckTs".to_string()),
         mitigate_ts: Some("MitigateTs".to_string()),
         resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
     }
 }
 
@@ -129,3 +136,68 @@ fn dashboard_v2_buckets_reconcile_and_support_drilldown() {
         dash.incident_count,
     );
 }
+
+#[test]
+fn dashboard_v2_respects_workspace_top_n_and_bucket_settings() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    seed_demo_dataset(&mut conn).expect("seed");
+
+    assert_eq!(load_dashboard_config(&conn).expect("cfg"), DashboardConfig::default());
+    let default_dash = build_dashboard_payload_v2(&conn).expect("dash");
+    let default_labels: Vec<&str> = default_dash
+        .response_story
+        .time_to_resolve_buckets
+        .iter()
+        .map(|b| b.label.as_str())
+        .collect();
+    assert!(default_labels.iter().all(|l| ["0-5m", "5-15m", "15m-1h", "1h-4h", "4h-24h", ">24h"]
+        .contains(l)
+        || l.starts_with("UNKNOWN")));
+
+    save_dashboard_config(
+        &conn,
+        &DashboardConfig {
+            top_n: 2,
+            duration_bucket_bounds_seconds: vec![30, 90 * 60],
+            ..DashboardConfig::default()
+        },
+    )
+    .expect("save");
+    let dash = build_dashboard_payload_v2(&conn).expect("dash");
+
+    let vendors = &dash.vendor_service_story.top_vendors_by_count;
+    assert!(vendors.len() <= 3, "top 2 plus OTHER");
+    assert_reconciles_to_total(
+        vendors.iter().map(|b| (b.count, b.incident_ids.clone())).collect(),
+        dash.incident_count,
+    );
+
+    for b in &dash.response_story.time_to_resolve_buckets {
+        assert!(
+            ["time_to_resolve:le_30s", "time_to_resolve:le_90m", "time_to_resolve:gt_90m", "time_to_resolve:unknown"]
+                .contains(&b.key.as_str()),
+            "unexpected key {}",
+            b.key
+        );
+    }
+    assert_reconciles_to_total(
+        dash.response_story
+            .time_to_resolve_buckets
+            .iter()
+            .map(|b| (b.count, b.incident_ids.clone()))
+            .collect(),
+        dash.incident_count,
+    );
+
+    let err = save_dashboard_config(
+        &conn,
+        &DashboardConfig {
+            top_n: 8,
+            duration_bucket_bounds_seconds: vec![600, 300],
+            ..DashboardConfig::default()
+        },
+    )
+    .expect_err("non-increasing bounds");
+    assert_eq!(err.code, "VALIDATION_DASHBOARD_CONFIG_INVALID");
+}
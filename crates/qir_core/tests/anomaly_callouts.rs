@@ -0,0 +1,78 @@
+use qir_core::analytics::build_anomaly_summary;
+use qir_core::db;
+use qir_core::report::{generate_qir_report, QirReportOptions};
+use rusqlite::Connection;
+
+fn insert(conn: &Connection, id: i64, vendor: &str, start: &str, resolve: &str) {
+    conn.execute(
+        "INSERT INTO incidents(id, external_id, fingerprint, title, vendor, service, start_ts, first_observed_ts,
+                               resolve_ts, ingested_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, 'cdn', ?6, ?6, ?7, '2026-07-01T00:00:00Z')",
+        rusqlite::params![id, format!("INC-{id}"), format!("fp-{id}"), format!("Incident {id}"), vendor, start, resolve],
+    )
+    .expect("insert");
+}
+
+/// Q1 2026 has three Akamai incidents. Q2 2026 has four 30-minute incidents plus a 4h
+/// Akamai incident and a 5h incident on Fastly, which is new this quarter.
+fn setup() -> Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    for id in 1..=3 {
+        insert(&conn, id, "Akamai", "2026-02-01T10:00:00Z", "2026-02-01T10:30:00Z");
+    }
+    for id in 10..=13 {
+        insert(&conn, id, "Akamai", "2026-05-01T10:00:00Z", "2026-05-01T10:30:00Z");
+    }
+    insert(&conn, 14, "Akamai", "2026-05-02T10:00:00Z", "2026-05-02T14:00:00Z");
+    insert(&conn, 15, "Fastly", "2026-05-03T10:00:00Z", "2026-05-03T15:00:00Z");
+    conn
+}
+
+#[test]
+fn quarter_callouts_cover_tails_new_vendors_and_data_gaps() {
+    let conn = setup();
+    let summary = build_anomaly_summary(&conn, Some("Q2 2026")).expect("summary");
+    let kinds: Vec<&str> = summary.callouts.iter().map(|c| c.kind.as_str()).collect();
+    assert_eq!(kinds, vec!["metric_tail", "new_vendor", "data_gap"]);
+
+    let tail = &summary.callouts[0];
+    assert_eq!(
+        tail.message,
+        "MTTR P90 (4h 0m) driven by 2 incidents: INC-15 (5h 0m), INC-14 (4h 0m)"
+    );
+    assert_eq!(tail.incident_ids, vec![14, 15]);
+
+    let vendor = &summary.callouts[1];
+    assert_eq!(vendor.message, "Vendor Fastly appeared for the first time this quarter (1 incident)");
+    assert_eq!(vendor.incident_ids, vec![15]);
+
+    assert_eq!(
+        summary.callouts[2].message,
+        "Timeline capture breaks down at IT aware (it_awareness_ts): 6 of 6 incidents lose a complete timeline at this stage"
+    );
+}
+
+#[test]
+fn first_appearance_rules_need_earlier_history() {
+    let conn = setup();
+    let q1 = build_anomaly_summary(&conn, Some("Q1 2026")).expect("q1");
+    assert!(q1.callouts.iter().all(|c| c.kind != "new_vendor"));
+
+    let workspace = build_anomaly_summary(&conn, None).expect("all");
+    assert!(workspace.quarter_label.is_none());
+    assert!(workspace.callouts.iter().all(|c| c.kind != "new_vendor"));
+}
+
+#[test]
+fn callouts_land_in_the_executive_summary() {
+    let conn = setup();
+    let opts = QirReportOptions {
+        quarter_label: Some("Q2 2026".to_string()),
+        ..QirReportOptions::default()
+    };
+    let md = generate_qir_report(&conn, &opts, "2026-07-01T00:00:00Z", "test").expect("report").markdown;
+    let summary = &md[md.find("## Executive summary").expect("summary")..md.find("## Metrics summary").expect("metrics")];
+    assert!(summary.contains("- MTTR P90 (4h 0m) driven by 2 incidents: INC-15 (5h 0m), INC-14 (4h 0m)\n"));
+    assert!(summary.contains("- Vendor Fastly appeared for the first time this quarter (1 incident)\n"));
+}
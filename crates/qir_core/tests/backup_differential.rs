@@ -0,0 +1,146 @@
+use std::fs;
+use std::path::Path;
+
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+use qir_core::backup::{
+    create_backup, create_differential_backup_with_progress, read_manifest, restore_from_backup, BackupCreateResult,
+};
+use qir_core::db;
+use qir_core::progress::Progress;
+use rusqlite::Connection;
+
+fn write(path: &Path, text: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, text).unwrap();
+}
+
+fn differential(conn: &Connection, db_path: &Path, artifacts: &Path, base: &str, at: &str) -> BackupCreateResult {
+    create_differential_backup_with_progress(
+        conn,
+        db_path,
+        Some(artifacts),
+        Path::new(base),
+        at,
+        "0.1.0-test",
+        &mut Progress::noop("backup_create_differential"),
+    )
+    .expect("differential backup")
+}
+
+fn stored_in(backup_dir: &str) -> Vec<(String, Option<String>)> {
+    read_manifest(Path::new(backup_dir))
+        .expect("manifest")
+        .artifacts
+        .files
+        .into_iter()
+        .map(|f| (f.rel_path, f.stored_in))
+        .collect()
+}
+
+fn folder_name(backup_dir: &str) -> String {
+    Path::new(backup_dir).file_name().unwrap().to_string_lossy().to_string()
+}
+
+/// Full backup, then two differentials chained on top of it, each changing some artifacts.
+fn chain(root: &Path) -> (Connection, std::path::PathBuf, Vec<BackupCreateResult>) {
+    let db_path = root.join("source.sqlite");
+    let mut conn = db::open(&db_path).expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    let artifacts = root.join("artifacts");
+    write(&artifacts.join("a.txt"), "alpha");
+    write(&artifacts.join("reports/b.md"), "bravo v1");
+    let backups = root.join("backups");
+    fs::create_dir_all(&backups).unwrap();
+
+    let full = create_backup(&conn, &db_path, Some(&artifacts), &backups, "2026-10-01T00:00:00Z", "0.1.0-test")
+        .expect("full backup");
+
+    write(&artifacts.join("reports/b.md"), "bravo v2");
+    write(&artifacts.join("c.txt"), "charlie v1");
+    let first = differential(&conn, &db_path, &artifacts, &full.backup_dir, "2026-10-02T00:00:00Z");
+
+    write(&artifacts.join("c.txt"), "charlie v2");
+    let second = differential(&conn, &db_path, &artifacts, &first.backup_dir, "2026-10-03T00:00:00Z");
+    (conn, db_path, vec![full, first, second])
+}
+
+#[test]
+fn differential_backups_copy_only_changed_artifacts_and_restore_through_the_chain() {
+    let tmp = tempdir().unwrap();
+    let (_conn, _db_path, backups) = chain(tmp.path());
+    let [full, first, second] = &backups[..] else { panic!("three backups") };
+    let full_name = folder_name(&full.backup_dir);
+    let first_name = folder_name(&first.backup_dir);
+
+    let manifest = read_manifest(Path::new(&first.backup_dir)).expect("manifest");
+    assert_eq!(manifest.manifest_version, 2);
+    assert_eq!(manifest.base_backup.as_deref(), Some(full_name.as_str()));
+    assert_eq!(
+        stored_in(&first.backup_dir),
+        vec![
+            ("a.txt".to_string(), Some(full_name.clone())),
+            ("c.txt".to_string(), None),
+            ("reports/b.md".to_string(), None),
+        ]
+    );
+    assert!(!Path::new(&first.backup_dir).join("artifacts/a.txt").exists());
+
+    // References point at the backup holding the bytes, not at the intermediate base.
+    assert_eq!(
+        stored_in(&second.backup_dir),
+        vec![
+            ("a.txt".to_string(), Some(full_name.clone())),
+            ("c.txt".to_string(), None),
+            ("reports/b.md".to_string(), Some(first_name)),
+        ]
+    );
+    assert_eq!(read_manifest(Path::new(&full.backup_dir)).expect("full").manifest_version, 1);
+
+    let target = tmp.path().join("restored");
+    fs::create_dir_all(&target).unwrap();
+    let restore = restore_from_backup(
+        Path::new(&second.backup_dir),
+        &target.join("restored.sqlite"),
+        Some(&target.join("artifacts")),
+        false,
+    )
+    .expect("restore");
+    assert!(restore.ok);
+    let read = |rel: &str| fs::read_to_string(target.join("artifacts").join(rel)).unwrap();
+    assert_eq!(read("a.txt"), "alpha");
+    assert_eq!(read("reports/b.md"), "bravo v2");
+    assert_eq!(read("c.txt"), "charlie v2");
+}
+
+#[test]
+fn broken_chains_fail_with_stable_codes() {
+    let tmp = tempdir().unwrap();
+    let (conn, db_path, backups) = chain(tmp.path());
+
+    let err = create_differential_backup_with_progress(
+        &conn,
+        &db_path,
+        None,
+        &tmp.path().join("backups/IncidentReviewBackup_missing"),
+        "2026-10-04T00:00:00Z",
+        "0.1.0-test",
+        &mut Progress::noop("backup_create_differential"),
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "DB_BACKUP_BASE_INVALID");
+
+    fs::remove_dir_all(&backups[0].backup_dir).unwrap();
+    let target = tmp.path().join("restored");
+    fs::create_dir_all(&target).unwrap();
+    let err = restore_from_backup(
+        Path::new(&backups[2].backup_dir),
+        &target.join("restored.sqlite"),
+        Some(&target.join("artifacts")),
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "DB_RESTORE_BASE_MISSING");
+    assert!(!target.join("restored.sqlite").exists());
+}
@@ -4,7 +4,7 @@ use pretty_assertions::assert_eq;
 use rusqlite::Connection;
 use tempfile::tempdir;
 
-use qir_core::backup::{create_backup, read_manifest, restore_from_backup};
+use qir_core::backup::{create_backup, read_manifest, restore_as_new_workspace, restore_from_backup};
 use qir_core::db;
 use qir_core::ingest::jira_csv::{import_jira_csv, JiraCsvMapping};
 
@@ -22,6 +22,7 @@ fn seed_db(db_path: &Path) -> Connection {
         title: "Summary".to_string(),
         description: Some("Description".to_string()),
         severity: Some("Severity".to_string()),
+        initial_severity: None,
         detection_source: Some("DetectionSource".to_string()),
         vendor: Some("Vendor".to_string()),
         service: Some("Service".to_string()),
@@ -33,6 +34,9 @@ fn seed_db(db_path: &Path) -> Connection {
         ack_ts: Some("AckTs".to_string()),
         mitigate_ts: Some("MitigateTs".to_string()),
         resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
     };
 
     import_jira_csv(&mut conn, csv_text, &mapping).expect("import");
@@ -108,6 +112,7 @@ fn restore_rehydrates_db_when_overwrite_is_allowed() {
             title: "Summary".to_string(),
             description: None,
             severity: None,
+            initial_severity: None,
             detection_source: None,
             vendor: None,
             service: None,
@@ -119,6 +124,9 @@ fn restore_rehydrates_db_when_overwrite_is_allowed() {
             ack_ts: None,
             mitigate_ts: None,
             resolve_ts: None,
+            conflict_strategy: Default::default(),
+            assumed_utc_offset_minutes: None,
+            timestamp_formats: Default::default(),
         };
         import_jira_csv(&mut c, csv_text, &mapping).expect("seed target");
     }
@@ -137,3 +145,57 @@ fn restore_rehydrates_db_when_overwrite_is_allowed() {
         .unwrap();
     assert_eq!(count, qir_core::backup::read_manifest(Path::new(&backup.backup_dir)).unwrap().counts.incidents);
 }
+
+#[test]
+fn restore_as_new_workspace_leaves_the_source_alone_and_never_overwrites() {
+    let tmp = tempdir().unwrap();
+    let source_db_path = tmp.path().join("source.sqlite");
+    let conn = seed_db(&source_db_path);
+    let source_artifacts = tmp.path().join("artifacts");
+    std::fs::create_dir_all(source_artifacts.join("IR-1")).unwrap();
+    std::fs::write(source_artifacts.join("IR-1").join("notes.txt"), b"bridge notes").unwrap();
+
+    let dest_root = tmp.path().join("backups");
+    std::fs::create_dir_all(&dest_root).unwrap();
+    let backup = create_backup(
+        &conn,
+        &source_db_path,
+        Some(&source_artifacts),
+        &dest_root,
+        "2026-02-10T03:00:00Z",
+        "0.1.0-test",
+    )
+    .expect("backup");
+    let backup_dir = Path::new(&backup.backup_dir);
+
+    let restored_db_path = tmp.path().join("inspect").join("restored.sqlite");
+    let res = restore_as_new_workspace(backup_dir, &restored_db_path).expect("restore as new");
+    assert!(res.restore.restored_artifacts);
+    assert!(res.data_paths.portable);
+    assert!(!res.workspace.is_empty);
+    let restored_artifacts = tmp.path().join("inspect").join("artifacts");
+    assert_eq!(res.data_paths.artifacts_dir, restored_artifacts.display().to_string());
+    assert_eq!(
+        std::fs::read(restored_artifacts.join("IR-1").join("notes.txt")).unwrap(),
+        b"bridge notes"
+    );
+
+    let restored = db::open(&restored_db_path).expect("open restored");
+    let count: i64 = restored.query_row("SELECT COUNT(*) FROM incidents", [], |row| row.get(0)).unwrap();
+    assert_eq!(count, read_manifest(backup_dir).unwrap().counts.incidents);
+    // The source workspace and its artifacts are untouched.
+    assert!(!source_db_path.with_extension("pre_restore").exists());
+    assert!(source_artifacts.join("IR-1").join("notes.txt").is_file());
+
+    let err = restore_as_new_workspace(backup_dir, &restored_db_path).unwrap_err();
+    assert_eq!(err.code, "DB_RESTORE_DEST_EXISTS");
+    let err = restore_as_new_workspace(backup_dir, &tmp.path().join("inspect").join("other.sqlite")).unwrap_err();
+    assert_eq!(err.code, "DB_RESTORE_DEST_EXISTS");
+
+    // A corrupt backup leaves nothing behind at the destination.
+    std::fs::write(backup_dir.join(&read_manifest(backup_dir).unwrap().db.filename), b"tampered").unwrap();
+    let failed_db_path = tmp.path().join("failed").join("restored.sqlite");
+    let err = restore_as_new_workspace(backup_dir, &failed_db_path).unwrap_err();
+    assert_eq!(err.code, "DB_RESTORE_DB_HASH_MISMATCH");
+    assert_eq!(std::fs::read_dir(tmp.path().join("failed")).unwrap().count(), 0);
+}
@@ -0,0 +1,106 @@
+use qir_core::analytics::build_dashboard_payload_v2;
+use qir_core::cache::compute_dashboard_v2_hash;
+use qir_core::columnar::export_parquet_dataset;
+use qir_core::db;
+use qir_core::metrics::{
+    load_computed_metric_definitions, save_computed_metric_definitions, ComputedMetricDefinition,
+};
+use qir_core::report::generate_qir_markdown;
+use rusqlite::Connection;
+
+fn insert(conn: &Connection, id: i64, observed: Option<&str>, ack: Option<&str>) {
+    conn.execute(
+        "INSERT INTO incidents(id, fingerprint, title, start_ts, first_observed_ts, ack_ts, ingested_at)
+         VALUES (?1, ?2, 'Incident', '2026-01-05T09:00:00Z', ?3, ?4, '2026-02-01T00:00:00Z')",
+        rusqlite::params![id, format!("fp-{id}"), observed, ack],
+    )
+    .expect("insert");
+}
+
+fn observed_to_ack() -> ComputedMetricDefinition {
+    ComputedMetricDefinition {
+        key: "detection_to_ack".to_string(),
+        label: "Detection to ack".to_string(),
+        from_field: "first_observed_ts".to_string(),
+        to_field: "ack_ts".to_string(),
+    }
+}
+
+/// Observed -> ack of 3m, 10m, 2h; one incident without ack; one acked before observed.
+fn setup() -> Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    insert(&conn, 1, Some("2026-01-05T10:00:00Z"), Some("2026-01-05T10:03:00Z"));
+    insert(&conn, 2, Some("2026-01-05T10:00:00Z"), Some("2026-01-05T10:10:00Z"));
+    insert(&conn, 3, Some("2026-01-05T10:00:00Z"), Some("2026-01-05T12:00:00Z"));
+    insert(&conn, 4, Some("2026-01-05T10:00:00Z"), None);
+    insert(&conn, 5, Some("2026-01-05T10:00:00Z"), Some("2026-01-05T09:30:00Z"));
+    conn
+}
+
+#[test]
+fn definitions_are_validated_and_round_trip() {
+    let conn = setup();
+    assert!(load_computed_metric_definitions(&conn).expect("empty").is_empty());
+
+    let bad = [
+        ComputedMetricDefinition { key: "Bad Key".to_string(), ..observed_to_ack() },
+        ComputedMetricDefinition { key: "mttr".to_string(), ..observed_to_ack() },
+        ComputedMetricDefinition { to_field: "first_comms_ts".to_string(), ..observed_to_ack() },
+        ComputedMetricDefinition { to_field: "first_observed_ts".to_string(), ..observed_to_ack() },
+        ComputedMetricDefinition { label: " ".to_string(), ..observed_to_ack() },
+    ];
+    for def in bad {
+        let err = save_computed_metric_definitions(&conn, &[def]).expect_err("invalid");
+        assert_eq!(err.code, "VALIDATION_COMPUTED_METRIC_INVALID");
+    }
+    let err = save_computed_metric_definitions(&conn, &[observed_to_ack(), observed_to_ack()]).expect_err("dup");
+    assert_eq!(err.code, "VALIDATION_COMPUTED_METRIC_INVALID");
+
+    save_computed_metric_definitions(&conn, &[observed_to_ack()]).expect("save");
+    assert_eq!(load_computed_metric_definitions(&conn).expect("load"), vec![observed_to_ack()]);
+}
+
+#[test]
+fn computed_metrics_feed_dashboard_distributions_and_cache_hash() {
+    let conn = setup();
+    let before = compute_dashboard_v2_hash(&conn).expect("hash");
+    assert!(build_dashboard_payload_v2(&conn).expect("dash").computed_metrics.is_empty());
+
+    save_computed_metric_definitions(&conn, &[observed_to_ack()]).expect("save");
+    assert_ne!(compute_dashboard_v2_hash(&conn).expect("hash"), before);
+
+    let dash = build_dashboard_payload_v2(&conn).expect("dash");
+    assert_eq!(dash.computed_metrics.len(), 1);
+    let dist = &dash.computed_metrics[0];
+    assert_eq!(dist.key, "detection_to_ack");
+    let buckets: Vec<(&str, i64)> = dist.buckets.iter().map(|b| (b.key.as_str(), b.count)).collect();
+    assert_eq!(
+        buckets,
+        vec![
+            ("computed:detection_to_ack:le_5m", 1),
+            ("computed:detection_to_ack:le_15m", 1),
+            ("computed:detection_to_ack:le_4h", 1),
+            ("computed:detection_to_ack:unknown", 2),
+        ]
+    );
+    // Computed metric order warnings are not counted against the incident.
+    let inc5 = dash.incidents.iter().find(|i| i.id == 5).expect("inc5");
+    assert_eq!(inc5.warning_count, 0);
+}
+
+#[test]
+fn computed_metrics_appear_in_report_and_parquet_export() {
+    let conn = setup();
+    save_computed_metric_definitions(&conn, &[observed_to_ack()]).expect("save");
+
+    let md = generate_qir_markdown(&conn).expect("report");
+    assert!(md.contains("| Detection to ack (first_observed_ts → ack_ts) | 3/5 | 10m 0s | 10m 0s |\n"));
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let res = export_parquet_dataset(&conn, dir.path(), "2026-02-01T00:00:00Z", "0.1.0").expect("export");
+    let names: Vec<&str> = res.descriptor.tables[0].columns.iter().map(|c| c.name.as_str()).collect();
+    let at = names.iter().position(|n| *n == "computed_detection_to_ack_seconds").expect("column");
+    assert_eq!(names[at - 1], "mttr_seconds");
+    assert_eq!(names.last(), Some(&"warning_count"));
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::csv_dialect::{decode_csv_bytes, detect_delimiter, CsvDelimiter, CsvEncoding};
+use qir_core::ingest::jira_csv::{import_jira_csv, preview_jira_csv};
+use qir_core::testing::{empty_db, fixture_mapping};
+
+#[test]
+fn detects_delimiters_including_quoted_newlines() {
+    assert_eq!(detect_delimiter("a,b,c\n1,2,3\n"), CsvDelimiter::Comma);
+    assert_eq!(detect_delimiter("Key;Summary;Impact\nA-1;Checkout;12,5\n"), CsvDelimiter::Semicolon);
+    assert_eq!(detect_delimiter("Key\tSummary\nA-1\tCheckout, again\n"), CsvDelimiter::Tab);
+    assert_eq!(detect_delimiter("Key|Summary\n\"A-1\"|\"multi\nline\"\n"), CsvDelimiter::Pipe);
+    assert_eq!(detect_delimiter("single column\nvalue\n"), CsvDelimiter::Comma);
+}
+
+#[test]
+fn decodes_boms_utf16_and_windows_1252() {
+    let bom = decode_csv_bytes(b"\xEF\xBB\xBFKey,Summary\n").expect("utf8 bom");
+    assert_eq!((bom.text.as_str(), bom.encoding, bom.had_bom), ("Key,Summary\n", CsvEncoding::Utf8, true));
+    assert!(bom.warnings.is_empty());
+
+    let utf16: Vec<u8> = [0xFF, 0xFE]
+        .into_iter()
+        .chain("Key\tSummary\n".encode_utf16().flat_map(u16::to_le_bytes))
+        .collect();
+    let decoded = decode_csv_bytes(&utf16).expect("utf16");
+    assert_eq!((decoded.text.as_str(), decoded.encoding), ("Key\tSummary\n", CsvEncoding::Utf16Le));
+
+    // "Caf\xe9 \x93down\x94" in Windows-1252.
+    let cp1252 = decode_csv_bytes(b"Summary\nCaf\xE9 \x93down\x94\n").expect("cp1252");
+    assert_eq!(cp1252.encoding, CsvEncoding::Windows1252);
+    assert_eq!(cp1252.text, "Summary\nCaf\u{e9} \u{201c}down\u{201d}\n");
+    assert_eq!(cp1252.warnings[0].code, "INGEST_CSV_TRANSCODED");
+
+    let err = decode_csv_bytes(&[0xFF, 0xFE, 0x41]).unwrap_err();
+    assert_eq!(err.code, "INGEST_CSV_DECODE_FAILED");
+}
+
+#[test]
+fn imports_semicolon_csv_with_bom_and_quoted_newline() {
+    let csv = "\u{feff}Key;Summary;Description;StartTs\n\
+A-1;Checkout errors;\"Line one\nline two; still quoted\";2026-01-05T14:00:00Z\n";
+    let preview = preview_jira_csv(csv, 5).expect("preview");
+    assert_eq!(preview.headers, vec!["Key", "Summary", "Description", "StartTs"]);
+    assert_eq!(preview.delimiter, CsvDelimiter::Semicolon);
+
+    let mut conn = empty_db().expect("db");
+    let summary = import_jira_csv(&mut conn, csv, &fixture_mapping()).expect("import");
+    assert_eq!(summary.inserted, 1);
+    let (title, start): (String, String) = conn
+        .query_row("SELECT title, start_ts FROM incidents WHERE external_id = 'A-1'", [], |r| {
+            Ok((r.get(0)?, r.get(1)?))
+        })
+        .unwrap();
+    assert_eq!((title.as_str(), start.as_str()), ("Checkout errors", "2026-01-05T14:00:00Z"));
+}
@@ -0,0 +1,56 @@
+use pretty_assertions::assert_eq;
+
+use qir_core::analytics::{build_dashboard_payload_v2, dashboard_delta_v2};
+use qir_core::cache::DashboardCache;
+use qir_core::db;
+use qir_core::demo::seed_demo_dataset;
+
+fn seeded() -> rusqlite::Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    seed_demo_dataset(&mut conn).expect("seed");
+    conn
+}
+
+#[test]
+fn delta_is_full_then_unchanged_then_patch() {
+    let conn = seeded();
+    let cache = DashboardCache::new();
+
+    let first = dashboard_delta_v2(&conn, &cache, None).expect("first");
+    assert_eq!(first.kind, "full");
+    assert_eq!(first.full.as_ref().unwrap(), &build_dashboard_payload_v2(&conn).unwrap());
+
+    let same = dashboard_delta_v2(&conn, &cache, Some(&first.hash)).expect("same");
+    assert_eq!(same.kind, "unchanged");
+    assert_eq!(same.hash, first.hash);
+    assert!(same.patch.is_none() && same.full.is_none());
+
+    conn.execute(
+        "UPDATE incidents SET severity = 'SEV3' WHERE external_id = 'IR-001'",
+        [],
+    )
+    .unwrap();
+    let changed = dashboard_delta_v2(&conn, &cache, Some(&first.hash)).expect("changed");
+    assert_eq!(changed.kind, "patch");
+    assert_ne!(changed.hash, first.hash);
+
+    let patch = changed.patch.expect("patch");
+    assert_eq!(patch.upserted_incidents.len(), 1);
+    assert_eq!(patch.upserted_incidents[0].external_id.as_deref(), Some("IR-001"));
+    assert!(patch.removed_incident_ids.is_empty());
+    assert!(patch.incident_order.is_none());
+    assert!(patch.severity_counts.is_some());
+    // Severity is not part of the detection/response stories, so those lists are untouched.
+    assert!(patch.detection_source_mix.is_none());
+    assert!(patch.time_to_resolve_buckets.is_none());
+}
+
+#[test]
+fn unknown_since_hash_returns_full_payload() {
+    let conn = seeded();
+    let cache = DashboardCache::new();
+    let res = dashboard_delta_v2(&conn, &cache, Some("not-a-known-hash")).expect("delta");
+    assert_eq!(res.kind, "full");
+    assert!(res.full.is_some());
+}
@@ -0,0 +1,96 @@
+use std::fs;
+
+use tempfile::tempdir;
+
+use qir_core::workspace::{
+    is_portable_workspace, relocate_data_dir, resolve_workspace_data_paths, set_workspace_portable, validate_data_dir,
+};
+
+fn populate(dir: &std::path::Path) {
+    fs::create_dir_all(dir.join("chunks")).unwrap();
+    fs::write(dir.join("sources.json"), b"[]").unwrap();
+    fs::write(dir.join("chunks").join("a.json"), b"{\"text\":\"hello\"}").unwrap();
+}
+
+#[test]
+fn moves_contents_and_reports_totals() {
+    let tmp = tempdir().unwrap();
+    let from = tmp.path().join("ai");
+    let to = tmp.path().join("big-drive").join("ai");
+    populate(&from);
+
+    let summary = relocate_data_dir(&from, &to).expect("relocate");
+    assert!(summary.moved);
+    assert_eq!((summary.file_count, summary.byte_count), (2, 18));
+    assert!(!from.exists());
+    assert_eq!(fs::read(to.join("chunks").join("a.json")).unwrap(), b"{\"text\":\"hello\"}");
+}
+
+#[test]
+fn missing_source_just_creates_destination() {
+    let tmp = tempdir().unwrap();
+    let to = tmp.path().join("artifacts");
+    let summary = relocate_data_dir(&tmp.path().join("never-created"), &to).expect("relocate");
+    assert!(!summary.moved);
+    assert!(to.is_dir());
+
+    let same = relocate_data_dir(&to, &to).expect("same path");
+    assert!(!same.moved);
+}
+
+#[test]
+fn refuses_unsafe_destinations_and_keeps_source() {
+    let tmp = tempdir().unwrap();
+    let from = tmp.path().join("ai");
+    populate(&from);
+
+    let occupied = tmp.path().join("occupied");
+    fs::create_dir_all(&occupied).unwrap();
+    fs::write(occupied.join("keep.txt"), b"x").unwrap();
+
+    for to in [occupied.clone(), from.join("nested"), "relative/ai".into()] {
+        let err = relocate_data_dir(&from, &to).expect_err("unsafe destination");
+        assert_eq!(err.code, "WORKSPACE_PATHS_INVALID", "{}", to.display());
+    }
+    assert!(from.join("sources.json").exists());
+    assert_eq!(fs::read(occupied.join("keep.txt")).unwrap(), b"x");
+
+    let file = tmp.path().join("file.txt");
+    fs::write(&file, b"x").unwrap();
+    assert_eq!(validate_data_dir(&file).expect_err("file").code, "WORKSPACE_PATHS_INVALID");
+}
+
+#[test]
+fn portable_layout_moves_data_next_to_the_db_and_back() {
+    let tmp = tempdir().unwrap();
+    let usb = tmp.path().join("usb");
+    fs::create_dir_all(&usb).unwrap();
+    let db_path = usb.join("incidentreview.sqlite");
+    fs::write(&db_path, b"").unwrap();
+    let machine_artifacts = tmp.path().join("appdata").join("artifacts");
+    let machine_ai = tmp.path().join("appdata").join("ai");
+    populate(&machine_ai);
+
+    let paths = resolve_workspace_data_paths(&db_path, &machine_artifacts, &machine_ai).unwrap();
+    assert!(!paths.portable);
+    assert_eq!(paths.ai_store_root, machine_ai.display().to_string());
+
+    let paths = set_workspace_portable(&db_path, true, &machine_artifacts, &machine_ai).expect("enable");
+    assert!(paths.portable && is_portable_workspace(&db_path));
+    assert_eq!(paths.ai_store_root, usb.join("ai").display().to_string());
+    assert!(usb.join("ai").join("sources.json").exists());
+    assert!(usb.join("artifacts").is_dir());
+    assert!(!machine_ai.exists());
+
+    // Moving the folder moves everything with it.
+    let moved = tmp.path().join("usb-moved");
+    fs::rename(&usb, &moved).unwrap();
+    let moved_db = moved.join("incidentreview.sqlite");
+    let paths = resolve_workspace_data_paths(&moved_db, &machine_artifacts, &machine_ai).unwrap();
+    assert_eq!(paths.ai_store_root, moved.join("ai").display().to_string());
+
+    let paths = set_workspace_portable(&moved_db, false, &machine_artifacts, &machine_ai).expect("disable");
+    assert!(!paths.portable);
+    assert!(machine_ai.join("chunks").join("a.json").exists());
+    assert!(!moved.join("ai").exists());
+}
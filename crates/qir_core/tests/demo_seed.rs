@@ -1,7 +1,7 @@
 use tempfile::tempdir;
 
 use qir_core::db;
-use qir_core::demo::seed_demo_dataset;
+use qir_core::demo::{seed_demo_dataset, seed_guided_tour_dataset};
 
 #[test]
 fn seeds_demo_dataset_with_enough_incidents_for_dashboards() {
@@ -18,3 +18,25 @@ fn seeds_demo_dataset_with_enough_incidents_for_dashboards() {
     );
 }
 
+
+#[test]
+fn guided_tour_adds_slack_timelines_and_evidence_notes() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+
+    let tour = seed_guided_tour_dataset(&mut conn).expect("seed");
+    assert_eq!(tour.import.inserted, 40);
+    assert_eq!(tour.timeline_events, 20);
+    assert_eq!(tour.evidence_notes.len(), 8);
+    assert_eq!(tour.evidence_notes[1].label, "IR-001 post-incident review");
+
+    let events: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM timeline_events e JOIN incidents i ON i.id = e.incident_id
+             WHERE i.external_id = 'IR-003'",
+            [],
+            |r| r.get(0),
+        )
+        .expect("count");
+    assert_eq!(events, 5);
+}
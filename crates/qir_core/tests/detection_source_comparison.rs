@@ -0,0 +1,86 @@
+use qir_core::analytics::build_detection_source_comparison;
+use qir_core::db;
+use rusqlite::Connection;
+
+fn insert(conn: &Connection, id: i64, source: Option<&str>, start: &str, ack: Option<&str>, resolve: &str) {
+    conn.execute(
+        "INSERT INTO incidents(id, fingerprint, title, detection_source, start_ts, it_awareness_ts, ack_ts,
+                               resolve_ts, ingested_at)
+         VALUES (?1, ?2, 'Incident', ?3, ?4, ?4, ?5, ?6, '2026-07-01T00:00:00Z')",
+        rusqlite::params![id, format!("fp-{id}"), source, start, ack, resolve],
+    )
+    .expect("insert");
+}
+
+/// Five monitoring-detected incidents (5m ack, 30m resolve), two customer-reported
+/// (20m ack, 2h resolve) and one without a source in Q2 (1h resolve, no ack).
+fn setup() -> Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    for id in 1..=5 {
+        let day = format!("2026-01-0{id}");
+        insert(
+            &conn,
+            id,
+            Some("monitoring"),
+            &format!("{day}T10:00:00Z"),
+            Some(&format!("{day}T10:05:00Z")),
+            &format!("{day}T10:30:00Z"),
+        );
+    }
+    for id in 6..=7 {
+        let day = format!("2026-02-0{id}");
+        insert(
+            &conn,
+            id,
+            Some("customer"),
+            &format!("{day}T10:00:00Z"),
+            Some(&format!("{day}T10:20:00Z")),
+            &format!("{day}T12:00:00Z"),
+        );
+    }
+    insert(&conn, 8, None, "2026-04-01T10:00:00Z", None, "2026-04-01T11:00:00Z");
+    conn
+}
+
+#[test]
+fn compares_response_metrics_by_source() {
+    let conn = setup();
+    let cmp = build_detection_source_comparison(&conn, None).expect("cmp");
+    let sources: Vec<&str> = cmp.sources.iter().map(|s| s.detection_source.as_str()).collect();
+    assert_eq!(sources, vec!["monitoring", "customer", "UNKNOWN"]);
+    assert_eq!(cmp.overall.incident_count, 8);
+    assert_eq!(cmp.overall.mttr.p50_seconds, Some(1800));
+
+    let monitoring = &cmp.sources[0];
+    assert_eq!(monitoring.mtta.p50_seconds, Some(300));
+    assert_eq!((monitoring.mttr.known_count, monitoring.mttr.small_sample), (5, false));
+    assert_eq!(monitoring.mttr_p50_delta_seconds, Some(0));
+
+    let customer = &cmp.sources[1];
+    assert_eq!(customer.mtta.p50_seconds, Some(1200));
+    assert_eq!(customer.mttr.p50_seconds, Some(7200));
+    assert_eq!(customer.mttr_p50_delta_seconds, Some(5400));
+    assert!(customer.mttr.small_sample);
+
+    let unknown = &cmp.sources[2];
+    assert_eq!((unknown.mtta.known_count, unknown.mtta.p50_seconds), (0, None));
+    assert_eq!(unknown.incident_ids, vec![8]);
+
+    assert!(cmp.caveats[0].starts_with("Fewer than 5 known MTTR values for: customer, UNKNOWN;"));
+}
+
+#[test]
+fn quarter_scope_and_single_source_caveat() {
+    let conn = setup();
+    let q1 = build_detection_source_comparison(&conn, Some("Q1 2026")).expect("q1");
+    assert_eq!(q1.quarter_label.as_deref(), Some("Q1 2026"));
+    assert_eq!(q1.overall.incident_count, 7);
+    assert_eq!(q1.sources.len(), 2);
+
+    let q2 = build_detection_source_comparison(&conn, Some("Q2 2026")).expect("q2");
+    assert_eq!(q2.sources.len(), 1);
+    assert!(q2
+        .caveats
+        .contains(&"Only one detection source in scope; nothing to compare.".to_string()));
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::report::{
+    build_weekly_digest, export_digest_eml, render_digest_eml, save_display_timezone, DigestEmailOptions,
+    DisplayTimezone,
+};
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+fn setup() -> Connection {
+    let incident = |id: i64, title: &str, sev: &str, start: &str| {
+        IncidentBuilder::new(id, title).external_id(format!("INC-{id}")).severity(sev).start(start)
+    };
+    db_with_incidents(&[
+        incident(1, "Checkout <errors> & retries", "SEV1", "2026-10-12T08:00:00Z").resolve("2026-10-12T10:00:00Z").build(),
+        incident(2, "Search latency", "SEV3", "2026-10-14T09:00:00Z").build(),
+        // Started the week before, resolved in the window.
+        incident(3, "Stale cache", "SEV2", "2026-10-05T00:00:00Z").resolve("2026-10-10T00:00:00Z").build(),
+        incident(4, "Outside the window", "SEV2", "2026-10-16T00:00:00Z").build(),
+    ])
+    .expect("db")
+}
+
+fn opts() -> DigestEmailOptions {
+    DigestEmailOptions {
+        from: "qir@example.com".to_string(),
+        to: vec!["sre-leads@example.com".to_string(), "ops@example.com".to_string()],
+        week_ending: "2026-10-16T00:00:00Z".to_string(),
+    }
+}
+
+#[test]
+fn digest_covers_the_seven_days_before_week_ending() {
+    let conn = setup();
+    let d = build_weekly_digest(&conn, "2026-10-16T00:00:00Z").expect("digest");
+    assert_eq!(d.week_start, "2026-10-09T00:00:00Z");
+    let ids: Vec<_> = d.new_incidents.iter().map(|i| i.external_id.clone().unwrap()).collect();
+    assert_eq!(ids, vec!["INC-1", "INC-2"]);
+    assert_eq!(d.still_open_count, 1);
+    assert_eq!(d.resolved_count, 2);
+    assert!(d.resolved_mttr_p50_seconds.is_some());
+}
+
+#[test]
+fn eml_has_plain_text_and_html_alternatives() {
+    let conn = setup();
+    save_display_timezone(
+        &conn,
+        Some(&DisplayTimezone {
+            label: "New York office".to_string(),
+            utc_offset_minutes: -4 * 60,
+        }),
+    )
+    .expect("tz");
+    let (eml, _) = render_digest_eml(&conn, &opts(), "2026-10-16T09:30:00Z").expect("eml");
+    let (again, _) = render_digest_eml(&conn, &opts(), "2026-10-16T09:30:00Z").expect("eml");
+    assert_eq!(eml, again);
+
+    assert!(eml.starts_with("From: qir@example.com\r\nTo: sre-leads@example.com, ops@example.com\r\n"));
+    assert!(eml.contains("Subject: Incident digest: week ending 2026-10-16 (2 new, 2 resolved)\r\n"));
+    assert!(eml.contains("Date: Fri, 16 Oct 2026 09:30:00 +0000\r\n"));
+    assert!(eml.contains("Content-Type: multipart/alternative; boundary=\"qir-digest-"));
+    assert!(eml.contains("Content-Type: text/plain; charset=utf-8\r\n"));
+    assert!(eml.contains("Content-Type: text/html; charset=utf-8\r\n"));
+    assert!(!eml.replace("\r\n", "").contains('\n'));
+
+    assert!(eml.contains("- INC-1 [SEV1] Checkout <errors> & retries\r\n"));
+    assert!(eml.contains("<td>Checkout &lt;errors&gt; &amp; retries</td>"));
+    assert!(eml.contains("2026-10-14T09:00:00Z (2026-10-14 05:00 UTC-04:00 New York office)"));
+    assert!(!eml.contains("INC-4"));
+
+    conn.execute("DELETE FROM incidents", []).expect("clear");
+    let (empty, digest) = render_digest_eml(&conn, &opts(), "2026-10-16T09:30:00Z").expect("eml");
+    assert!(digest.new_incidents.is_empty());
+    assert!(empty.contains("<p>None.</p>"));
+}
+
+#[test]
+fn rejects_header_injection_and_missing_recipients() {
+    let conn = setup();
+    let mut bad = opts();
+    bad.from = "qir@example.com\r\nBcc: everyone@example.com".to_string();
+    let err = render_digest_eml(&conn, &bad, "2026-10-16T09:30:00Z").unwrap_err();
+    assert_eq!(err.code, "VALIDATION_DIGEST_INVALID");
+
+    let mut bad = opts();
+    bad.to.clear();
+    let err = render_digest_eml(&conn, &bad, "2026-10-16T09:30:00Z").unwrap_err();
+    assert_eq!(err.code, "VALIDATION_DIGEST_INVALID");
+
+    let mut bad = opts();
+    bad.week_ending = "last friday".to_string();
+    let err = render_digest_eml(&conn, &bad, "2026-10-16T09:30:00Z").unwrap_err();
+    assert_eq!(err.code, "VALIDATION_DIGEST_INVALID");
+}
+
+#[test]
+fn export_writes_the_file_once() {
+    let conn = setup();
+    let dir = tempfile::tempdir().expect("tempdir");
+    let res = export_digest_eml(&conn, &opts(), dir.path(), "2026-10-16T09:30:00Z").expect("export");
+    assert!(res.eml_path.ends_with("QIR_digest_2026_10_16T00_00_00Z.eml"));
+    assert_eq!(res.new_incident_count, 2);
+    let written = std::fs::read_to_string(&res.eml_path).expect("read");
+    assert_eq!(written.len() as u64, res.eml_bytes);
+    assert!(written.contains(&format!("Subject: {}\r\n", res.subject)));
+
+    let err = export_digest_eml(&conn, &opts(), dir.path(), "2026-10-16T09:30:00Z").unwrap_err();
+    assert_eq!(err.code, "REPORT_EXPORT_DEST_EXISTS");
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::metrics::compute_incident_metrics;
+use qir_core::report::{
+    generate_qir_markdown, generate_qir_report, load_display_timezone, save_display_timezone, DisplayTimezone,
+    QirReportOptions, DISPLAY_TIMEZONE_SETTING,
+};
+use qir_core::settings::set_setting;
+use qir_core::testing::{db_with_incidents, empty_db, IncidentBuilder};
+
+fn new_york() -> DisplayTimezone {
+    DisplayTimezone {
+        label: "New York office".to_string(),
+        utc_offset_minutes: -4 * 60,
+    }
+}
+
+#[test]
+fn renders_local_times_next_to_utc() {
+    let tz = new_york();
+    assert_eq!(tz.offset_label(), "UTC-04:00");
+    assert_eq!(tz.local("2026-04-01T02:00:00Z").as_deref(), Some("2026-03-31 22:00 UTC-04:00 New York office"));
+    assert_eq!(tz.local("not a time"), None);
+
+    let err = save_display_timezone(
+        &empty_db().expect("db"),
+        Some(&DisplayTimezone {
+            label: " ".to_string(),
+            utc_offset_minutes: 0,
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "VALIDATION_DISPLAY_TIMEZONE_INVALID");
+}
+
+#[test]
+fn reports_show_both_times_only_when_configured() {
+    let conn = db_with_incidents(&[IncidentBuilder::new(1, "Checkout errors")
+        .external_id("INC-1")
+        .start("2026-04-01T02:00:00Z")
+        .resolve("2026-04-01T05:00:00Z")
+        .build()])
+    .expect("db");
+    let utc_only = generate_qir_markdown(&conn).expect("report");
+    assert!(!utc_only.contains("| Started |"));
+    let incident = qir_core::repo::list_incidents(&conn).expect("list").remove(0);
+    let metrics_before = compute_incident_metrics(&incident).0;
+
+    save_display_timezone(&conn, Some(&new_york())).expect("save");
+    assert_eq!(load_display_timezone(&conn).expect("load"), Some(new_york()));
+
+    let md = generate_qir_markdown(&conn).expect("report");
+    assert!(md.contains("local times in parentheses are New York office (UTC-04:00)"));
+    assert!(md.contains("| INC-1 | Checkout errors | 2026-04-01T02:00:00Z (2026-03-31 22:00 UTC-04:00 New York office) |"));
+
+    // Quarter assignment stays UTC: the incident is in Q2 although it started on the evening
+    // of March 31 in New York.
+    let opts = QirReportOptions {
+        quarter_label: Some("Q2 2026".to_string()),
+        as_of: None,
+        draft_ids: vec![],
+        on_call: None,
+    };
+    let scoped = generate_qir_report(&conn, &opts, "2026-07-01T00:00:00Z", "test").expect("scoped");
+    assert!(scoped.markdown.contains("| INC-1 | Checkout errors | 2026-04-01T02:00:00Z (2026-03-31 22:00 UTC-04:00 New York office) |"));
+    let stored: String = conn
+        .query_row("SELECT start_ts FROM incidents WHERE id = 1", [], |r| r.get(0))
+        .unwrap();
+    assert_eq!(stored, "2026-04-01T02:00:00Z");
+    assert_eq!(compute_incident_metrics(&incident).0, metrics_before);
+
+    save_display_timezone(&conn, None).expect("clear");
+    assert_eq!(load_display_timezone(&conn).expect("load"), None);
+    assert_eq!(generate_qir_markdown(&conn).expect("report"), utc_only);
+}
+
+#[test]
+fn clearing_removes_an_unreadable_setting() {
+    let conn = empty_db().expect("db");
+    save_display_timezone(&conn, None).expect("clearing an unset timezone");
+
+    let corrupt = serde_json::json!({"label": "", "utc_offset_minutes": 9000});
+    set_setting(&conn, DISPLAY_TIMEZONE_SETTING, &corrupt).expect("seed");
+    assert_eq!(load_display_timezone(&conn).unwrap_err().code, "VALIDATION_DISPLAY_TIMEZONE_INVALID");
+    set_setting(&conn, DISPLAY_TIMEZONE_SETTING, &serde_json::json!("New York")).expect("seed");
+    assert!(load_display_timezone(&conn).is_err());
+
+    save_display_timezone(&conn, None).expect("clear");
+    assert_eq!(load_display_timezone(&conn).expect("load"), None);
+}
@@ -0,0 +1,95 @@
+use qir_core::ai_drafts::comments::{
+    create_draft_comment, delete_draft_comment, list_draft_comments, set_draft_comment_resolved,
+    update_draft_comment_text, CreateDraftCommentInput,
+};
+use qir_core::ai_drafts::{create_ai_draft, AiDraftSectionType, CreateAiDraftInput};
+use qir_core::db;
+use rusqlite::Connection;
+
+fn setup() -> (Connection, i64) {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    let draft = create_ai_draft(
+        &conn,
+        CreateAiDraftInput {
+            quarter_label: "Q1 2026".to_string(),
+            section_type: AiDraftSectionType::QuarterNarrativeRecap,
+            draft_text: "First paragraph [[chunk:a]]\n\nSecond paragraph [[chunk:b]]".to_string(),
+            citation_chunk_ids: vec!["a".to_string(), "b".to_string()],
+            model_name: "llama3.2:latest".to_string(),
+            model_params_hash: "params_hash".to_string(),
+            prompt_template_version: "quarter_narrative_recap_v1".to_string(),
+            created_at: "2026-02-10T00:00:00Z".to_string(),
+            parent_draft_id: None,
+            revision_notes: None,
+            branch_label: None,
+        },
+    )
+    .expect("draft");
+    (conn, draft.id)
+}
+
+fn comment(draft_id: i64, anchor_paragraph: u32, text: &str) -> CreateDraftCommentInput {
+    CreateDraftCommentInput {
+        draft_id,
+        anchor_paragraph,
+        author: "manager".to_string(),
+        text: text.to_string(),
+    }
+}
+
+#[test]
+fn comments_round_trip_in_document_order() {
+    let (conn, draft_id) = setup();
+
+    let late = create_draft_comment(&conn, comment(draft_id, 1, "Cite the vendor ticket")).expect("add");
+    let early = create_draft_comment(&conn, comment(draft_id, 0, " Too long ")).expect("add");
+    assert_eq!(early.text, "Too long");
+    assert!(!early.resolved);
+
+    let listed = list_draft_comments(&conn, draft_id, true).expect("list");
+    assert_eq!(listed.iter().map(|c| c.id).collect::<Vec<_>>(), vec![early.id, late.id]);
+
+    let edited = update_draft_comment_text(&conn, early.id, "Trim to two sentences").expect("edit");
+    assert_eq!(edited.text, "Trim to two sentences");
+
+    let resolved = set_draft_comment_resolved(&conn, early.id, true).expect("resolve");
+    assert!(resolved.resolved);
+    assert!(resolved.resolved_at.is_some());
+    let open = list_draft_comments(&conn, draft_id, false).expect("list open");
+    assert_eq!(open.iter().map(|c| c.id).collect::<Vec<_>>(), vec![late.id]);
+
+    let reopened = set_draft_comment_resolved(&conn, early.id, false).expect("reopen");
+    assert!(!reopened.resolved);
+    assert!(reopened.resolved_at.is_none());
+
+    delete_draft_comment(&conn, late.id).expect("delete");
+    assert_eq!(delete_draft_comment(&conn, late.id).unwrap_err().code, "DB_NOT_FOUND");
+    assert_eq!(list_draft_comments(&conn, draft_id, true).expect("list").len(), 1);
+}
+
+#[test]
+fn rejects_invalid_comments() {
+    let (conn, draft_id) = setup();
+
+    let err = create_draft_comment(&conn, comment(draft_id, 2, "past the end")).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_DRAFT_COMMENT_INVALID");
+    let err = create_draft_comment(&conn, comment(draft_id, 0, "   ")).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_DRAFT_COMMENT_INVALID");
+    let err = create_draft_comment(&conn, comment(draft_id + 100, 0, "x")).unwrap_err();
+    assert_eq!(err.code, "DB_NOT_FOUND");
+    let err = update_draft_comment_text(&conn, 999, "x").unwrap_err();
+    assert_eq!(err.code, "DB_NOT_FOUND");
+}
+
+#[test]
+fn deleting_a_draft_removes_its_comments() {
+    let (conn, draft_id) = setup();
+    create_draft_comment(&conn, comment(draft_id, 0, "note")).expect("add");
+
+    conn.execute("DELETE FROM ai_drafts WHERE id = ?1", [draft_id]).expect("delete draft");
+    let remaining: i64 = conn
+        .query_row("SELECT COUNT(*) FROM draft_comments", [], |r| r.get(0))
+        .expect("count");
+    assert_eq!(remaining, 0);
+}
@@ -0,0 +1,95 @@
+use qir_core::ai_drafts::staleness::list_ai_drafts_with_staleness;
+use qir_core::ai_drafts::{create_ai_draft, AiDraftSectionType, CreateAiDraftInput};
+use qir_core::db;
+use qir_core::freeze::incident_quarter_label;
+use qir_core::repo;
+use rusqlite::Connection;
+
+fn draft_input(quarter_label: &str) -> CreateAiDraftInput {
+    CreateAiDraftInput {
+        quarter_label: quarter_label.to_string(),
+        section_type: AiDraftSectionType::ExecSummary,
+        draft_text: "Summary [[chunk:a]]".to_string(),
+        citation_chunk_ids: vec!["a".to_string()],
+        model_name: "llama3.2:latest".to_string(),
+        model_params_hash: "params_hash".to_string(),
+        prompt_template_version: "exec_summary_v1".to_string(),
+        created_at: "2026-02-10T00:00:00Z".to_string(),
+        parent_draft_id: None,
+        revision_notes: None,
+        branch_label: None,
+    }
+}
+
+/// Seeded workspace plus the quarter holding the most demo incidents and its incident ids.
+fn setup() -> (Connection, String, Vec<i64>) {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    qir_core::demo::seed_demo_dataset(&mut conn).expect("seed");
+
+    let mut by_quarter: std::collections::BTreeMap<String, Vec<i64>> = Default::default();
+    for inc in repo::list_incidents(&conn).expect("incidents") {
+        if let Some(q) = incident_quarter_label(inc.start_ts.as_deref(), inc.first_observed_ts.as_deref()) {
+            by_quarter.entry(q).or_default().push(inc.id);
+        }
+    }
+    let (quarter, ids) = by_quarter
+        .into_iter()
+        .max_by_key(|(_, ids)| ids.len())
+        .expect("demo has quarters");
+    (conn, quarter, ids)
+}
+
+#[test]
+fn fresh_draft_is_not_stale() {
+    let (conn, quarter, ids) = setup();
+    create_ai_draft(&conn, draft_input(&quarter)).expect("draft");
+
+    let items = list_ai_drafts_with_staleness(&conn, Some(&quarter)).expect("list");
+    assert_eq!(items.len(), 1);
+    assert!(!items[0].stale);
+    assert!(items[0].changes.tracked);
+    assert_eq!(items[0].changes.incident_count_at_draft as usize, ids.len());
+    assert_eq!(items[0].changes.incident_count_now as usize, ids.len());
+}
+
+#[test]
+fn edits_new_and_removed_incidents_mark_draft_stale() {
+    let (conn, quarter, ids) = setup();
+    let draft = create_ai_draft(&conn, draft_input(&quarter)).expect("draft");
+    let anchor = repo::get_incident(&conn, ids[0]).expect("incident");
+
+    conn.execute("UPDATE incidents SET title = 'Renamed' WHERE id = ?1", [ids[0]])
+        .expect("edit");
+    conn.execute("DELETE FROM incidents WHERE id = ?1", [ids[1]]).expect("delete");
+    conn.execute(
+        "INSERT INTO incidents(id, fingerprint, title, start_ts, ingested_at) VALUES (9001, 'fp-new', 'New one', ?1, '2026-02-11T00:00:00Z')",
+        [anchor.start_ts.as_deref().or(anchor.first_observed_ts.as_deref())],
+    )
+    .expect("insert");
+
+    let items = list_ai_drafts_with_staleness(&conn, None).expect("list");
+    let item = items.iter().find(|i| i.draft.id == draft.id).expect("draft listed");
+    assert!(item.stale);
+    assert_eq!(item.changes.changed_incident_ids, vec![ids[0]]);
+    assert_eq!(item.changes.removed_incident_ids, vec![ids[1]]);
+    assert_eq!(item.changes.new_incident_ids, vec![9001]);
+    assert_eq!(item.changes.incident_count_now, item.changes.incident_count_at_draft);
+}
+
+#[test]
+fn legacy_drafts_without_snapshot_are_untracked() {
+    let (conn, quarter, _) = setup();
+    let draft = create_ai_draft(&conn, draft_input(&quarter)).expect("draft");
+    conn.execute("UPDATE ai_drafts SET quarter_snapshot_json = NULL WHERE id = ?1", [draft.id])
+        .expect("clear");
+    conn.execute("UPDATE incidents SET title = title || '!'", []).expect("edit all");
+
+    let items = list_ai_drafts_with_staleness(&conn, Some(&quarter)).expect("list");
+    assert!(!items[0].stale);
+    assert!(!items[0].changes.tracked);
+
+    let json = serde_json::to_value(&items[0]).expect("json");
+    assert_eq!(json["id"], draft.id);
+    assert_eq!(json["stale"], false);
+}
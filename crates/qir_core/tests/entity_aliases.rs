@@ -0,0 +1,116 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::aliases::{delete_entity_alias, list_entity_aliases, upsert_entity_alias, AliasKind, EntityAliasInput};
+use qir_core::analytics::{bucket_drilldown, build_dashboard_payload_v2};
+use qir_core::cache::compute_dashboard_v2_hash;
+use qir_core::error::AppError;
+use qir_core::ingest::jira_csv::import_jira_csv;
+use qir_core::testing::{empty_db, fixture_mapping};
+use rusqlite::Connection;
+
+fn alias(conn: &Connection, kind: AliasKind, alias: &str, canonical: &str) -> Result<(), AppError> {
+    upsert_entity_alias(
+        conn,
+        &EntityAliasInput {
+            kind,
+            alias: alias.to_string(),
+            canonical: canonical.to_string(),
+        },
+    )
+    .map(|_| ())
+}
+
+#[test]
+fn aliases_are_per_kind_and_one_step() {
+    let conn = empty_db().expect("db");
+    alias(&conn, AliasKind::Vendor, "AWS", "Amazon Web Services").expect("aws");
+    alias(&conn, AliasKind::Vendor, " amazon ", "Amazon Web Services").expect("amazon");
+    // The same spelling can mean something else as a service.
+    alias(&conn, AliasKind::Service, "aws", "Hosting").expect("service");
+
+    let vendors: Vec<(String, String)> = list_entity_aliases(&conn, Some(AliasKind::Vendor))
+        .expect("list")
+        .into_iter()
+        .map(|a| (a.alias, a.canonical))
+        .collect();
+    assert_eq!(
+        vendors,
+        vec![
+            ("amazon".to_string(), "Amazon Web Services".to_string()),
+            ("AWS".to_string(), "Amazon Web Services".to_string()),
+        ]
+    );
+    assert_eq!(list_entity_aliases(&conn, None).expect("all").len(), 3);
+
+    let code = |r: Result<(), AppError>| r.unwrap_err().code;
+    assert_eq!(code(alias(&conn, AliasKind::Vendor, "Amazon", "amazon")), "VALIDATION_ENTITY_ALIAS_INVALID");
+    assert_eq!(code(alias(&conn, AliasKind::Vendor, "EC2", "aws")), "VALIDATION_ENTITY_ALIAS_INVALID");
+    assert_eq!(
+        code(alias(&conn, AliasKind::Vendor, "Amazon Web Services", "Amazon")),
+        "VALIDATION_ENTITY_ALIAS_INVALID"
+    );
+
+    delete_entity_alias(&conn, AliasKind::Vendor, "AMAZON").expect("delete");
+    assert_eq!(delete_entity_alias(&conn, AliasKind::Vendor, "amazon").unwrap_err().code, "DB_NOT_FOUND");
+    assert_eq!(list_entity_aliases(&conn, Some(AliasKind::Service)).expect("services").len(), 1);
+}
+
+#[test]
+fn ingest_and_dashboard_rollups_resolve_aliases() {
+    let mut conn = empty_db().expect("db");
+    // Imported before any alias existed: stored verbatim.
+    import_jira_csv(&mut conn, "Key,Summary,Vendor,Service\nA-1,Bucket errors,AWS,s3\n", &fixture_mapping())
+        .expect("first");
+
+    let before = compute_dashboard_v2_hash(&conn).expect("hash");
+    alias(&conn, AliasKind::Vendor, "AWS", "Amazon Web Services").expect("aws");
+    alias(&conn, AliasKind::Service, "s3", "Object storage").expect("s3");
+    assert_ne!(compute_dashboard_v2_hash(&conn).expect("hash"), before);
+
+    import_jira_csv(
+        &mut conn,
+        "Key,Summary,Vendor,Service\nA-2,Queue lag,amazon web services,S3\nA-3,DNS,Cloudflare,dns\n",
+        &fixture_mapping(),
+    )
+    .expect("second");
+    let stored: Vec<(String, String)> = conn
+        .prepare("SELECT vendor, service FROM incidents ORDER BY external_id")
+        .expect("prepare")
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?)))
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows");
+    assert_eq!(
+        stored,
+        vec![
+            ("AWS".to_string(), "s3".to_string()),
+            ("Amazon Web Services".to_string(), "Object storage".to_string()),
+            ("Cloudflare".to_string(), "dns".to_string()),
+        ]
+    );
+
+    // Rollups resolve the older verbatim row too, so one vendor and one service hold both.
+    let dash = build_dashboard_payload_v2(&conn).expect("dash");
+    let vendors: Vec<(String, Vec<i64>)> = dash
+        .vendor_service_story
+        .top_vendors_by_count
+        .iter()
+        .map(|b| (b.label.clone(), b.incident_ids.clone()))
+        .collect();
+    assert_eq!(
+        vendors,
+        vec![("Amazon Web Services".to_string(), vec![1, 2]), ("Cloudflare".to_string(), vec![3])]
+    );
+    let services: Vec<String> = dash
+        .vendor_service_story
+        .top_services_by_count
+        .iter()
+        .map(|b| b.label.clone())
+        .collect();
+    assert_eq!(services, vec!["Object storage".to_string(), "dns".to_string()]);
+
+    let drill = bucket_drilldown(&conn, &[1]).expect("drilldown");
+    assert_eq!(drill.incidents[0].vendor.as_deref(), Some("Amazon Web Services"));
+}
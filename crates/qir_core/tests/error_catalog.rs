@@ -0,0 +1,66 @@
+use qir_core::error::{error_catalog, lookup_error, AppError, ErrorCategory, ERROR_CATALOG};
+
+#[test]
+fn catalog_is_sorted_unique_and_complete() {
+    let codes: Vec<&str> = ERROR_CATALOG.iter().map(|s| s.code).collect();
+    let mut sorted = codes.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(codes, sorted, "catalog must be sorted by code without duplicates");
+
+    for spec in ERROR_CATALOG {
+        assert!(
+            spec.code
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'),
+            "code={}",
+            spec.code
+        );
+        assert!(!spec.remediation.trim().is_empty(), "code={}", spec.code);
+    }
+    assert_eq!(error_catalog().len(), ERROR_CATALOG.len());
+}
+
+#[test]
+fn categories_follow_code_families() {
+    for spec in ERROR_CATALOG {
+        let expected = match spec.code {
+            c if c.starts_with("AI_") || c.starts_with("DB_AI_") => ErrorCategory::Ai,
+            c if c.starts_with("DB_BACKUP_") || c.starts_with("DB_RESTORE_") => ErrorCategory::Backup,
+            c if c.starts_with("DB_") => ErrorCategory::Database,
+            c if c.starts_with("EXPORT_") => ErrorCategory::Export,
+            c if c.starts_with("INGEST_") => ErrorCategory::Ingest,
+            c if c.starts_with("QUARTER_") => ErrorCategory::Quarter,
+            c if c.starts_with("QUERY_") => ErrorCategory::Query,
+            c if c.starts_with("REPORT_") => ErrorCategory::Report,
+            c if c.starts_with("SETTINGS_") => ErrorCategory::Settings,
+            c if c.starts_with("WORKSPACE_") => ErrorCategory::Workspace,
+            _ => ErrorCategory::Validation,
+        };
+        assert_eq!(spec.category, expected, "code={}", spec.code);
+    }
+}
+
+#[test]
+fn app_error_takes_retryable_default_from_catalog() {
+    assert!(lookup_error("WORKSPACE_OPEN_FAILED").unwrap().retryable);
+    assert!(AppError::new("WORKSPACE_OPEN_FAILED", "x").retryable);
+    assert!(!AppError::new("WORKSPACE_OPEN_FAILED", "x").with_retryable(false).retryable);
+
+    let err = AppError::new("QUERY_PREPARE_FAILED", "x");
+    assert!(!err.retryable);
+    assert_eq!(err.spec().map(|s| s.category), Some(ErrorCategory::Query));
+
+    let unknown = AppError::new("NOT_A_REGISTERED_CODE", "x");
+    assert!(!unknown.retryable);
+    assert!(unknown.spec().is_none());
+}
+
+#[test]
+fn catalog_serializes_for_the_ui() {
+    let json = serde_json::to_value(lookup_error("QUARTER_FROZEN").unwrap()).unwrap();
+    assert_eq!(json["code"], "QUARTER_FROZEN");
+    assert_eq!(json["category"], "quarter");
+    assert_eq!(json["retryable"], false);
+    assert!(json["remediation"].as_str().unwrap().contains("Unfreeze"));
+}
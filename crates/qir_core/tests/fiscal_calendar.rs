@@ -0,0 +1,115 @@
+use qir_core::db;
+use qir_core::quarters::{
+    create_quarter, default_quarter_input, load_fiscal_calendar, resolve_quarter_scope,
+    save_fiscal_calendar, FiscalCalendarConfig,
+};
+use qir_core::sampling::{sample_incidents, IncidentSampleFilter};
+
+fn february() -> FiscalCalendarConfig {
+    FiscalCalendarConfig {
+        fiscal_year_start_month: 2,
+    }
+}
+
+#[test]
+fn labels_follow_the_fiscal_year_start() {
+    let cfg = february();
+    assert_eq!(cfg.quarter_label("2026-02-01T00:00:00Z").as_deref(), Some("FY2027 Q1"));
+    assert_eq!(cfg.quarter_label("2026-04-30T23:59:59Z").as_deref(), Some("FY2027 Q1"));
+    assert_eq!(cfg.quarter_label("2026-05-01T00:00:00Z").as_deref(), Some("FY2027 Q2"));
+    assert_eq!(cfg.quarter_label("2027-01-31T12:00:00Z").as_deref(), Some("FY2027 Q4"));
+    assert_eq!(cfg.quarter_label("2026-01-15T00:00:00Z").as_deref(), Some("FY2026 Q4"));
+    assert_eq!(cfg.quarter_label("nope"), None);
+
+    assert_eq!(
+        cfg.quarter_bounds("FY2027 Q4"),
+        Some(("2026-11-01T00:00:00Z".to_string(), "2027-02-01T00:00:00Z".to_string()))
+    );
+    assert_eq!(cfg.quarter_bounds("FY27 Q1"), None);
+    assert_eq!(
+        cfg.incident_quarter_label(Some("garbage"), Some("2026-03-01T00:00:00Z")).as_deref(),
+        Some("FY2027 Q1")
+    );
+
+    let calendar = FiscalCalendarConfig::default();
+    assert_eq!(calendar.quarter_label("2026-02-01T00:00:00Z").as_deref(), Some("Q1 2026"));
+    assert_eq!(calendar.quarter_bounds("FY2027 Q1"), None);
+}
+
+#[test]
+fn config_round_trips_through_settings_and_drives_scope_resolution() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+
+    assert_eq!(load_fiscal_calendar(&conn).expect("load"), FiscalCalendarConfig::default());
+    assert_eq!(resolve_quarter_scope(&conn, "FY2027 Q1").unwrap_err().code, "QUARTER_NOT_FOUND");
+
+    let bad = FiscalCalendarConfig {
+        fiscal_year_start_month: 13,
+    };
+    assert_eq!(
+        save_fiscal_calendar(&conn, &bad).unwrap_err().code,
+        "VALIDATION_FISCAL_CALENDAR_INVALID"
+    );
+
+    save_fiscal_calendar(&conn, &february()).expect("save");
+    assert_eq!(load_fiscal_calendar(&conn).expect("load"), february());
+
+    let scope = resolve_quarter_scope(&conn, "FY2027 Q1").expect("fiscal scope");
+    assert!(!scope.defined);
+    assert_eq!(scope.start_ts, "2026-02-01T00:00:00Z");
+    assert_eq!(scope.end_ts, "2026-05-01T00:00:00Z");
+    // Calendar labels keep resolving alongside fiscal ones.
+    assert!(resolve_quarter_scope(&conn, "Q1 2026").is_ok());
+}
+
+#[test]
+fn quarters_table_defaults_use_the_fiscal_calendar() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    save_fiscal_calendar(&conn, &february()).expect("save");
+
+    let input = default_quarter_input(&conn, "FY2027 Q2").expect("defaults");
+    assert_eq!(input.start_ts, "2026-05-01T00:00:00Z");
+    assert_eq!(input.end_ts, "2026-08-01T00:00:00Z");
+    assert_eq!(input.fiscal_offset_months, 1);
+    let q = create_quarter(&conn, &input).expect("create from defaults");
+    assert_eq!(q.fiscal_offset_months, 1);
+
+    assert_eq!(default_quarter_input(&conn, "Q3 2026").expect("calendar").fiscal_offset_months, 0);
+    assert_eq!(default_quarter_input(&conn, "H1 2026").unwrap_err().code, "QUARTER_NOT_FOUND");
+}
+
+#[test]
+fn quarter_scoped_sampling_uses_fiscal_labels() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    qir_core::demo::seed_demo_dataset(&mut conn).expect("seed");
+    save_fiscal_calendar(&conn, &february()).expect("save");
+    let cfg = load_fiscal_calendar(&conn).expect("load");
+
+    let incidents = qir_core::repo::list_incidents(&conn).expect("incidents");
+    let label = incidents
+        .iter()
+        .find_map(|i| cfg.incident_quarter_label(i.start_ts.as_deref(), i.first_observed_ts.as_deref()))
+        .expect("demo incidents have timestamps");
+    let mut expected: Vec<i64> = incidents
+        .iter()
+        .filter(|i| {
+            cfg.incident_quarter_label(i.start_ts.as_deref(), i.first_observed_ts.as_deref()).as_deref()
+                == Some(label.as_str())
+        })
+        .map(|i| i.id)
+        .collect();
+    expected.sort();
+
+    let filter = IncidentSampleFilter {
+        quarter_label: Some(label),
+        ..IncidentSampleFilter::default()
+    };
+    let sample = sample_incidents(&conn, 1000, "seed", &filter, "2026-02-10T00:00:00Z").expect("sample");
+    let mut got = sample.incident_ids.clone();
+    got.sort();
+    assert_eq!(got, expected);
+    assert_eq!(sample.population_count as usize, expected.len());
+}
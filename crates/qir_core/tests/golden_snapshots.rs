@@ -0,0 +1,22 @@
+#![cfg(feature = "testing")]
+
+// Snapshots of published numbers for the demo dataset. Refresh intended changes with
+// `scripts/golden.sh --bless` and review the fixture diff like any other code change.
+
+use qir_core::analytics::build_dashboard_payload_v2;
+use qir_core::report::generate_qir_markdown;
+use qir_core::testing::{assert_golden, assert_golden_json, demo_db};
+
+#[test]
+fn demo_report_matches_snapshot() {
+    let conn = demo_db().expect("demo");
+    let md = generate_qir_markdown(&conn).expect("report");
+    assert_golden("qir_report_demo_dataset.md", &md);
+}
+
+#[test]
+fn demo_dashboard_matches_snapshot() {
+    let conn = demo_db().expect("demo");
+    let dash = build_dashboard_payload_v2(&conn).expect("dash");
+    assert_golden_json("dashboard_v2_demo_dataset.json", &dash);
+}
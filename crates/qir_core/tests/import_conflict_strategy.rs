@@ -0,0 +1,159 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::generic_csv::{import_generic_csv, CsvDescriptionFormat, CsvFieldChange, GenericCsvMapping};
+use qir_core::ingest::jira_csv::{import_jira_csv, JiraCsvMapping};
+use qir_core::ingest::merge::ConflictStrategy;
+use qir_core::profiles::jira::{get_profile, upsert_profile, JiraMappingProfileUpsert};
+use qir_core::testing::{empty_db, fixture_mapping};
+use rusqlite::Connection;
+
+const FIRST: &str = "Key,Summary,Severity,Vendor,DetectionSource,StartTs\n\
+OPS-1,Checkout errors,SEV2,Acme,,2026-01-05T14:00:00Z\n\
+OPS-2,Search latency,SEV3,,,2026-01-06T09:00:00Z\n";
+
+// OPS-1 disagrees on severity and leaves vendor empty; OPS-2 only fills empty fields.
+const SECOND: &str = "Key,Summary,Severity,Vendor,DetectionSource,StartTs\n\
+OPS-1,Checkout errors,SEV1,,Monitoring,2026-01-05T14:00:00Z\n\
+OPS-2,Search latency,SEV3,Globex,Customer,2026-01-06T09:00:00Z\n";
+
+fn mapping(strategy: ConflictStrategy) -> GenericCsvMapping {
+    GenericCsvMapping {
+        description_format: CsvDescriptionFormat::Plain,
+        conflict_strategy: strategy,
+        ..GenericCsvMapping::from(&fixture_mapping())
+    }
+}
+
+type Row = (String, Option<String>, Option<String>, Option<String>);
+
+fn rows(conn: &Connection) -> Vec<Row> {
+    let mut stmt = conn
+        .prepare("SELECT external_id, severity, vendor, detection_source FROM incidents ORDER BY external_id")
+        .unwrap();
+    stmt.query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+fn row(ext: &str, sev: &str, vendor: Option<&str>, detection: Option<&str>) -> Row {
+    (
+        ext.to_string(),
+        Some(sev.to_string()),
+        vendor.map(str::to_string),
+        detection.map(str::to_string),
+    )
+}
+
+fn reimport(strategy: ConflictStrategy) -> (Connection, qir_core::ingest::generic_csv::CsvImportSummary) {
+    let mut conn = empty_db().expect("db");
+    import_generic_csv(&mut conn, FIRST, &mapping(ConflictStrategy::default())).expect("seed");
+    let summary = import_generic_csv(&mut conn, SECOND, &mapping(strategy)).expect("reimport");
+    (conn, summary)
+}
+
+fn severity_conflict() -> Vec<CsvFieldChange> {
+    vec![CsvFieldChange {
+        field: "severity".to_string(),
+        before: Some("SEV2".to_string()),
+        after: Some("SEV1".to_string()),
+    }]
+}
+
+#[test]
+fn merge_preserve_on_empty_is_the_default() {
+    let (conn, summary) = reimport(ConflictStrategy::MergePreserveOnEmpty);
+    assert_eq!(summary.updated, 2);
+    assert_eq!(
+        rows(&conn),
+        vec![
+            row("OPS-1", "SEV1", Some("Acme"), Some("Monitoring")),
+            row("OPS-2", "SEV3", Some("Globex"), Some("Customer")),
+        ]
+    );
+    assert!(summary.conflicts.is_empty());
+    assert_eq!(summary.value_conflicts.len(), 1);
+    assert_eq!(summary.value_conflicts[0].row, 0);
+    assert_eq!(summary.value_conflicts[0].strategy, ConflictStrategy::MergePreserveOnEmpty);
+    assert_eq!(summary.value_conflicts[0].fields, severity_conflict());
+}
+
+#[test]
+fn prefer_incoming_clears_mapped_fields_left_empty() {
+    let (conn, summary) = reimport(ConflictStrategy::PreferIncoming);
+    assert_eq!(summary.updated, 2);
+    assert_eq!(
+        rows(&conn),
+        vec![
+            row("OPS-1", "SEV1", None, Some("Monitoring")),
+            row("OPS-2", "SEV3", Some("Globex"), Some("Customer")),
+        ]
+    );
+    assert_eq!(summary.value_conflicts[0].fields, severity_conflict());
+}
+
+#[test]
+fn prefer_existing_only_fills_empty_fields() {
+    let (conn, summary) = reimport(ConflictStrategy::PreferExisting);
+    assert_eq!(summary.updated, 2);
+    assert_eq!(
+        rows(&conn),
+        vec![
+            row("OPS-1", "SEV2", Some("Acme"), Some("Monitoring")),
+            row("OPS-2", "SEV3", Some("Globex"), Some("Customer")),
+        ]
+    );
+    assert_eq!(summary.value_conflicts[0].strategy, ConflictStrategy::PreferExisting);
+    assert_eq!(summary.value_conflicts[0].fields, severity_conflict());
+}
+
+#[test]
+fn skip_conflicts_leaves_disagreeing_rows_alone() {
+    let (conn, summary) = reimport(ConflictStrategy::SkipConflicts);
+    assert_eq!((summary.updated, summary.skipped), (1, 1));
+    assert_eq!(
+        rows(&conn),
+        vec![
+            row("OPS-1", "SEV2", Some("Acme"), None),
+            row("OPS-2", "SEV3", Some("Globex"), Some("Customer")),
+        ]
+    );
+    assert_eq!(summary.conflicts.len(), 1);
+    assert_eq!(summary.conflicts[0].row, 0);
+    assert_eq!(
+        summary.conflicts[0].reason,
+        "Incoming values conflict with stored severity; row skipped"
+    );
+    assert_eq!(summary.value_conflicts[0].fields, severity_conflict());
+}
+
+#[test]
+fn profiles_save_their_strategy() {
+    let mut conn = empty_db().expect("db");
+    let jira = JiraCsvMapping {
+        conflict_strategy: ConflictStrategy::SkipConflicts,
+        ..fixture_mapping()
+    };
+    let profile = upsert_profile(
+        &mut conn,
+        JiraMappingProfileUpsert {
+            id: None,
+            name: "Ops strict".to_string(),
+            mapping: jira,
+        },
+    )
+    .expect("profile");
+    let profile = get_profile(&conn, profile.id).expect("get");
+    assert_eq!(profile.mapping.conflict_strategy, ConflictStrategy::SkipConflicts);
+
+    import_jira_csv(&mut conn, FIRST, &profile.mapping).expect("seed");
+    let summary = import_jira_csv(&mut conn, SECOND, &profile.mapping).expect("reimport");
+    assert_eq!(summary.conflicts.len(), 1);
+    assert_eq!(rows(&conn)[0], row("OPS-1", "SEV2", Some("Acme"), None));
+
+    // Profiles saved before strategies existed keep merging preserve-on-empty.
+    let legacy: JiraCsvMapping = serde_json::from_str(r#"{"title":"Incident Name"}"#).expect("legacy mapping");
+    assert_eq!(legacy.conflict_strategy, ConflictStrategy::MergePreserveOnEmpty);
+}
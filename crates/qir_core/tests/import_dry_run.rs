@@ -0,0 +1,67 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::generic_csv::{
+    dry_run_generic_csv_import, import_generic_csv, CsvFieldChange, CsvRowAction, GenericCsvMapping,
+};
+use qir_core::progress::Progress;
+use qir_core::testing::{empty_db, fixture_mapping};
+
+const FIRST: &str = "Key,Summary,Severity,StartTs\n\
+OPS-1,Checkout errors,SEV2,2026-01-05T14:00:00Z\n\
+OPS-2,Search latency,SEV3,2026-01-06T09:00:00Z\n";
+
+const SECOND: &str = "Key,Summary,Severity,StartTs\n\
+OPS-1,Checkout errors,SEV1,2026-01-05T14:00:00Z\n\
+OPS-2,Search latency,SEV3,2026-01-06T09:00:00Z\n\
+OPS-3,Login outage,SEV2,2026-01-07T10:00:00Z\n\
+OPS-3,Login outage again,SEV2,2026-01-07T11:00:00Z\n";
+
+fn snapshot(conn: &rusqlite::Connection) -> Vec<(String, Option<String>)> {
+    let mut stmt = conn
+        .prepare("SELECT external_id, severity FROM incidents ORDER BY external_id")
+        .unwrap();
+    stmt.query_map([], |r| Ok((r.get(0)?, r.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+#[test]
+fn dry_run_reports_row_diffs_without_writing() {
+    let mut conn = empty_db().expect("db");
+    let mapping = GenericCsvMapping::from(&fixture_mapping());
+    import_generic_csv(&mut conn, FIRST, &mapping).expect("seed import");
+    let before = snapshot(&conn);
+
+    let dry = dry_run_generic_csv_import(&mut conn, SECOND, &mapping, &mut Progress::noop("dry_run"))
+        .expect("dry run");
+    assert!(dry.dry_run);
+    assert_eq!((dry.inserted, dry.updated, dry.skipped, dry.conflicts.len()), (1, 1, 2, 1));
+    assert_eq!(snapshot(&conn), before);
+
+    let actions: Vec<(usize, CsvRowAction)> = dry.row_diffs.iter().map(|d| (d.row, d.action)).collect();
+    assert_eq!(
+        actions,
+        vec![(0, CsvRowAction::Update), (1, CsvRowAction::Unchanged), (2, CsvRowAction::Insert)]
+    );
+    assert_eq!(
+        dry.row_diffs[0].changes,
+        vec![CsvFieldChange {
+            field: "severity".to_string(),
+            before: Some("SEV2".to_string()),
+            after: Some("SEV1".to_string()),
+        }]
+    );
+    let inserted_fields: Vec<&str> = dry.row_diffs[2].changes.iter().map(|c| c.field.as_str()).collect();
+    assert_eq!(inserted_fields, vec!["external_id", "title", "severity", "start_ts"]);
+
+    // The real import lands exactly what the dry run predicted.
+    let real = import_generic_csv(&mut conn, SECOND, &mapping).expect("import");
+    assert!(!real.dry_run && real.row_diffs.is_empty());
+    assert_eq!(
+        (real.inserted, real.updated, real.skipped, real.conflicts),
+        (dry.inserted, dry.updated, dry.skipped, dry.conflicts)
+    );
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::freeze::freeze_quarter;
+use qir_core::ingest::generic_csv::{import_generic_csv, CsvDescriptionFormat, GenericCsvMapping};
+use qir_core::ingest::import_batches::{list_import_batches, undo_import_batch};
+use qir_core::testing::{empty_db, fixture_mapping};
+use rusqlite::Connection;
+
+const FIRST: &str = "Key,Summary,Severity,StartTs\n\
+OPS-1,Checkout errors,SEV2,2026-01-05T14:00:00Z\n";
+
+const SECOND: &str = "Key,Summary,Severity,StartTs\n\
+OPS-1,Checkout errors,SEV1,2026-01-05T14:00:00Z\n\
+OPS-2,Search latency,SEV3,2026-01-06T09:00:00Z\n";
+
+/// The fixture mapping as a plain CSV import, reading the initial grade from the current one.
+fn mapping() -> GenericCsvMapping {
+    GenericCsvMapping {
+        initial_severity: Some("Severity".to_string()),
+        description_format: CsvDescriptionFormat::Plain,
+        ..GenericCsvMapping::from(&fixture_mapping())
+    }
+}
+
+fn state(conn: &Connection) -> Vec<(String, Option<String>, Option<String>)> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT i.external_id, i.severity, g.initial_severity FROM incidents i
+             LEFT JOIN incident_severity_grades g ON g.incident_id = i.id ORDER BY i.external_id",
+        )
+        .unwrap();
+    stmt.query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+#[test]
+fn undo_deletes_inserts_and_restores_updates() {
+    let mut conn = empty_db().expect("db");
+    import_generic_csv(&mut conn, FIRST, &mapping()).expect("first");
+    let before = state(&conn);
+    let second = import_generic_csv(&mut conn, SECOND, &mapping()).expect("second");
+    let batch_id = second.batch_id.expect("batch recorded");
+
+    let batches = list_import_batches(&conn, 10).expect("list");
+    assert_eq!(batches[0].id, batch_id);
+    assert_eq!((batches[0].source.as_str(), batches[0].row_count), ("csv", 2));
+    assert_eq!(batches[0].incident_ids.len(), 2);
+    assert_eq!(batches[0].undone_at, None);
+
+    let summary = undo_import_batch(&mut conn, batch_id).expect("undo");
+    assert_eq!((summary.deleted, summary.restored), (1, 1));
+    assert_eq!(state(&conn), before);
+
+    let err = undo_import_batch(&mut conn, batch_id).unwrap_err();
+    assert_eq!(err.code, "INGEST_IMPORT_BATCH_ALREADY_UNDONE");
+    assert_eq!(undo_import_batch(&mut conn, 999).unwrap_err().code, "INGEST_IMPORT_BATCH_NOT_FOUND");
+}
+
+#[test]
+fn undo_refuses_when_incidents_changed_since() {
+    let mut conn = empty_db().expect("db");
+    let first = import_generic_csv(&mut conn, FIRST, &mapping()).expect("first");
+    import_generic_csv(&mut conn, SECOND, &mapping()).expect("second");
+
+    // The second import updated OPS-1, so undoing the first would clobber it.
+    let err = undo_import_batch(&mut conn, first.batch_id.unwrap()).unwrap_err();
+    assert_eq!(err.code, "INGEST_IMPORT_UNDO_CONFLICT");
+    assert_eq!(state(&conn).len(), 2);
+
+    let third = import_generic_csv(&mut conn, "Key,Summary,StartTs\nOPS-3,DNS,2026-02-01T00:00:00Z\n", &mapping())
+        .expect("third");
+    freeze_quarter(&mut conn, "Q1 2026", Some("reviewer"), None).expect("freeze");
+    let err = undo_import_batch(&mut conn, third.batch_id.unwrap()).unwrap_err();
+    assert_eq!(err.code, "INGEST_IMPORT_UNDO_CONFLICT");
+    assert!(err.details.unwrap_or_default().ends_with("changed_since=[]; frozen=[3]"));
+}
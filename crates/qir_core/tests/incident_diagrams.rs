@@ -0,0 +1,153 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::diagrams::{
+    attach_incident_diagram, clear_incident_diagram, get_incident_diagram, set_incident_diagram, MAX_DIAGRAM_BYTES,
+};
+use qir_core::progress::Progress;
+use qir_core::report::{export_static_site, StaticSiteOptions};
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+const TS: &str = "2026-04-02T09:00:00Z";
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR-not-a-real-image";
+
+fn setup() -> Connection {
+    db_with_incidents(&[
+        IncidentBuilder::new(1, "Checkout errors").external_id("INC-1").start("2026-01-05T14:00:00Z").build(),
+        IncidentBuilder::new(2, "Search latency").external_id("INC-2").start("2026-02-10T09:00:00Z").build(),
+    ])
+    .expect("db")
+}
+
+fn write_png(dir: &std::path::Path, name: &str) -> std::path::PathBuf {
+    let path = dir.join(name);
+    std::fs::write(&path, PNG).expect("write png");
+    path
+}
+
+#[test]
+fn attach_copies_by_hash_and_replaces_the_previous_diagram() {
+    let conn = setup();
+    let src = tempfile::tempdir().expect("src");
+    let artifacts = tempfile::tempdir().expect("artifacts");
+    let png = write_png(src.path(), "arch.png");
+
+    let d = attach_incident_diagram(&conn, artifacts.path(), 1, &png, Some("  Checkout path  "), TS).expect("attach");
+    assert_eq!(d.mime_type, "image/png");
+    assert_eq!(d.filename.as_deref(), Some("arch.png"));
+    assert_eq!(d.caption.as_deref(), Some("Checkout path"));
+    assert_eq!(d.byte_len, PNG.len() as i64);
+    assert_eq!(std::fs::read(artifacts.path().join(d.rel_path())).expect("stored"), PNG);
+
+    // The same image on another incident reuses the artifact row.
+    let other = attach_incident_diagram(&conn, artifacts.path(), 2, &png, None, TS).expect("attach again");
+    assert_eq!(other.artifact_id, d.artifact_id);
+
+    let gif = src.path().join("graph.gif");
+    std::fs::write(&gif, b"GIF89a-graph").expect("write gif");
+    let replaced = attach_incident_diagram(&conn, artifacts.path(), 1, &gif, None, TS).expect("replace");
+    assert_eq!(replaced.mime_type, "image/gif");
+    assert_eq!(get_incident_diagram(&conn, 1).expect("get"), Some(replaced.clone()));
+
+    // Marking the earlier artifact again brings it back without another copy.
+    let back = set_incident_diagram(&conn, artifacts.path(), 1, d.artifact_id, None, TS).expect("set");
+    assert_eq!(back.sha256, d.sha256);
+
+    clear_incident_diagram(&conn, 1).expect("clear");
+    assert_eq!(get_incident_diagram(&conn, 1).expect("get"), None);
+}
+
+#[test]
+fn rejects_non_images_oversized_files_and_text_artifacts() {
+    let conn = setup();
+    let src = tempfile::tempdir().expect("src");
+    let artifacts = tempfile::tempdir().expect("artifacts");
+
+    let txt = src.path().join("notes.png");
+    std::fs::write(&txt, "not an image").expect("write");
+    let err = attach_incident_diagram(&conn, artifacts.path(), 1, &txt, None, TS).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_DIAGRAM_INVALID");
+
+    let big = src.path().join("big.png");
+    let f = std::fs::File::create(&big).expect("create");
+    f.set_len(MAX_DIAGRAM_BYTES + 1).expect("set_len");
+    let err = attach_incident_diagram(&conn, artifacts.path(), 1, &big, None, TS).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_DIAGRAM_INVALID");
+    assert!(err.details.unwrap_or_default().contains("max_bytes=5242880"));
+
+    conn.execute(
+        "INSERT INTO artifacts(incident_id, kind, sha256, filename, mime_type, text, created_at)
+         VALUES (1, 'email_message', 'abc', 'm.eml', 'message/rfc822', 'hi', ?1)",
+        [TS],
+    )
+    .expect("artifact");
+    let id = conn.last_insert_rowid();
+    let err = set_incident_diagram(&conn, artifacts.path(), 1, id, None, TS).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_DIAGRAM_INVALID");
+
+    let err = attach_incident_diagram(&conn, artifacts.path(), 99, &txt, None, TS).unwrap_err();
+    assert_eq!(err.code, "DB_NOT_FOUND");
+}
+
+#[test]
+fn static_site_embeds_hash_checked_diagrams() {
+    let conn = setup();
+    let src = tempfile::tempdir().expect("src");
+    let artifacts = tempfile::tempdir().expect("artifacts");
+    let d = attach_incident_diagram(&conn, artifacts.path(), 1, &write_png(src.path(), "arch.png"), Some("Checkout <path>"), TS)
+        .expect("attach");
+
+    let dest = tempfile::tempdir().expect("dest");
+    let res = export_static_site(
+        &conn,
+        &StaticSiteOptions::default(),
+        Some(artifacts.path()),
+        dest.path(),
+        TS,
+        &mut Progress::noop("export_static_site"),
+    )
+    .expect("export");
+    assert!(res.files.contains(&d.rel_path()));
+    let site = std::path::PathBuf::from(&res.site_dir);
+    assert_eq!(std::fs::read(site.join(d.rel_path())).expect("copied"), PNG);
+
+    let incident = std::fs::read_to_string(site.join("incidents/incident-1.html")).expect("page");
+    assert!(incident.contains(&format!(
+        "<figure><img src=\"../{}\" alt=\"Diagram for Checkout errors\"><figcaption>Checkout &lt;path&gt;</figcaption></figure>",
+        d.rel_path()
+    )));
+    let index = std::fs::read_to_string(site.join("index.html")).expect("index");
+    assert!(index.contains("<h2>Highlights</h2>"));
+    assert!(index.contains(&format!("<img src=\"{}\"", d.rel_path())));
+    assert!(!std::fs::read_to_string(site.join("incidents/incident-2.html"))
+        .expect("page")
+        .contains("<figure>"));
+}
+
+#[test]
+fn static_site_refuses_a_tampered_diagram() {
+    let conn = setup();
+    let src = tempfile::tempdir().expect("src");
+    let artifacts = tempfile::tempdir().expect("artifacts");
+    let d = attach_incident_diagram(&conn, artifacts.path(), 1, &write_png(src.path(), "arch.png"), None, TS)
+        .expect("attach");
+    let mut swapped = PNG.to_vec();
+    swapped[PNG.len() - 1] ^= 1;
+    std::fs::write(artifacts.path().join(d.rel_path()), swapped).expect("tamper");
+
+    let dest = tempfile::tempdir().expect("dest");
+    let err = export_static_site(
+        &conn,
+        &StaticSiteOptions::default(),
+        Some(artifacts.path()),
+        dest.path(),
+        TS,
+        &mut Progress::noop("export_static_site"),
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "EXPORT_DIAGRAM_INVALID");
+    assert!(err.details.unwrap_or_default().contains("expected_sha256="));
+    assert_eq!(std::fs::read_dir(dest.path()).expect("dest").count(), 0);
+}
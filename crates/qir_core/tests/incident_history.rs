@@ -0,0 +1,89 @@
+use pretty_assertions::assert_eq;
+
+use qir_core::db;
+use qir_core::demo::seed_demo_dataset;
+use qir_core::history::{incident_history, incidents_as_of, normalize_as_of};
+use qir_core::report::{generate_qir_markdown, generate_qir_markdown_as_of};
+
+fn seeded_with_backdated_history() -> rusqlite::Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    seed_demo_dataset(&mut conn).expect("seed");
+    // Pin the initial import to a known effective time so as-of queries are deterministic.
+    conn.execute(
+        "UPDATE incident_field_history SET effective_at = '2026-02-01T00:00:00.000Z'",
+        [],
+    )
+    .unwrap();
+    conn
+}
+
+fn incident_id(conn: &rusqlite::Connection, key: &str) -> i64 {
+    conn.query_row("SELECT id FROM incidents WHERE external_id = ?1", [key], |r| r.get(0))
+        .unwrap()
+}
+
+#[test]
+fn history_records_field_level_changes() {
+    let conn = seeded_with_backdated_history();
+    let id = incident_id(&conn, "IR-001");
+
+    conn.execute("UPDATE incidents SET severity = 'SEV3', impact_pct = 5 WHERE id = ?1", [id])
+        .unwrap();
+
+    let history = incident_history(&conn, id).expect("history");
+    let updates: Vec<(&str, Option<&str>, Option<&str>)> = history
+        .iter()
+        .filter(|c| c.change_kind == "update")
+        .map(|c| (c.field.as_str(), c.old_value.as_deref(), c.new_value.as_deref()))
+        .collect();
+    assert_eq!(
+        updates,
+        vec![
+            ("severity", Some("SEV0"), Some("SEV3")),
+            ("impact_pct", Some("80"), Some("5")),
+        ]
+    );
+}
+
+#[test]
+fn as_of_reconstruction_and_report_reflect_prior_beliefs() {
+    let conn = seeded_with_backdated_history();
+    let review_time = "2026-02-15T00:00:00Z";
+    let report_at_review = generate_qir_markdown(&conn).expect("report");
+
+    let id = incident_id(&conn, "IR-002");
+    conn.execute("UPDATE incidents SET severity = 'SEV0' WHERE id = ?1", [id]).unwrap();
+    conn.execute("DELETE FROM incidents WHERE external_id = 'IR-003'", []).unwrap();
+    conn.execute(
+        "UPDATE incident_field_history SET effective_at = '2026-03-01T00:00:00.000Z' WHERE change_kind != 'insert'",
+        [],
+    )
+    .unwrap();
+
+    let then = incidents_as_of(&conn, review_time).expect("as of");
+    assert_eq!(then.len(), 40);
+    let ir2 = then.iter().find(|i| i.id == id).unwrap();
+    assert_eq!(ir2.severity.as_deref(), Some("SEV1"));
+
+    let before_import = incidents_as_of(&conn, "2026-01-01T00:00:00Z").expect("before");
+    assert!(before_import.is_empty());
+
+    let as_of_md = generate_qir_markdown_as_of(&conn, review_time).expect("as of report");
+    assert!(as_of_md.contains("As of: **2026-02-15T00:00:00.000Z**"));
+    let without_header = as_of_md.replacen(
+        "As of: **2026-02-15T00:00:00.000Z** (reconstructed from incident field history)\n\n",
+        "",
+        1,
+    );
+    assert_eq!(without_header, report_at_review);
+}
+
+#[test]
+fn as_of_timestamps_are_normalized_to_millisecond_utc() {
+    assert_eq!(
+        normalize_as_of("2026-02-15T02:00:00+02:00").unwrap(),
+        "2026-02-15T00:00:00.000Z"
+    );
+    assert_eq!(normalize_as_of("yesterday").unwrap_err().code, "VALIDATION_AS_OF_INVALID");
+}
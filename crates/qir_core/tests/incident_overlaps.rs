@@ -0,0 +1,79 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::domain::Incident;
+use qir_core::ingest::jira_csv::import_jira_csv;
+use qir_core::testing::{empty_db, fixture_mapping, IncidentBuilder};
+use qir_core::validate::overlap::{find_overlapping_incidents, OverlapKind};
+use qir_core::validate::validate_all_incidents;
+
+fn incident(id: i64, service: Option<&str>, start: &str, resolve: &str) -> Incident {
+    let builder = IncidentBuilder::new(id, format!("Incident {id}"))
+        .external_id(format!("INC-{id}"))
+        .start(start)
+        .resolve(resolve);
+    match service {
+        Some(service) => builder.service(service),
+        None => builder,
+    }
+    .build()
+}
+
+#[test]
+fn flags_substantial_same_service_overlaps_only() {
+    let incidents = vec![
+        incident(1, Some("payments"), "2026-01-05T14:00:00Z", "2026-01-05T15:00:00Z"),
+        // Same outage filed twice: starts 5 minutes later, nearly identical window.
+        incident(2, Some("Payments "), "2026-01-05T14:05:00Z", "2026-01-05T15:00:00Z"),
+        // Downstream fallout: starts mid-incident and runs long.
+        incident(3, Some("payments"), "2026-01-05T14:30:00Z", "2026-01-05T16:00:00Z"),
+        // Touches the edge of incident 1 only briefly.
+        incident(4, Some("payments"), "2026-01-05T14:55:00Z", "2026-01-05T18:00:00Z"),
+        // Same time, different service.
+        incident(5, Some("search"), "2026-01-05T14:00:00Z", "2026-01-05T15:00:00Z"),
+        incident(6, None, "2026-01-05T14:00:00Z", "2026-01-05T15:00:00Z"),
+    ];
+
+    let overlaps = find_overlapping_incidents(&incidents);
+    let pairs: Vec<(i64, i64, OverlapKind)> = overlaps
+        .iter()
+        .map(|o| (o.incident_id, o.other_incident_id, o.kind))
+        .collect();
+    assert_eq!(
+        pairs,
+        vec![
+            (1, 2, OverlapKind::PossibleDuplicate),
+            (1, 3, OverlapKind::PossibleCascade),
+            (2, 3, OverlapKind::PossibleCascade),
+            (3, 4, OverlapKind::PossibleCascade),
+        ]
+    );
+    assert_eq!(overlaps[0].overlap_seconds, 55 * 60);
+    assert_eq!(overlaps[0].overlap_ratio, 1.0);
+}
+
+#[test]
+fn validation_report_carries_overlap_warnings() {
+    let mut conn = empty_db().expect("db");
+    let csv = "Key,Summary,Service,StartTs,ResolveTs\n\
+A-1,Checkout errors,payments,2026-01-05T14:00:00Z,2026-01-05T15:00:00Z\n\
+B-7,Card declines,payments,2026-01-05T14:02:00Z,2026-01-05T14:58:00Z\n\
+C-3,Search latency,search,2026-01-05T14:00:00Z,2026-01-05T15:00:00Z\n";
+    import_jira_csv(&mut conn, csv, &fixture_mapping()).expect("import");
+
+    let report = validate_all_incidents(&conn).expect("validate");
+    let overlap_codes = |ext: &str| -> usize {
+        report
+            .iter()
+            .find(|i| i.external_id.as_deref() == Some(ext))
+            .unwrap()
+            .warnings
+            .iter()
+            .filter(|w| w.code == "VALIDATION_INCIDENT_OVERLAP")
+            .count()
+    };
+    assert_eq!(overlap_codes("A-1"), 1);
+    assert_eq!(overlap_codes("B-7"), 1);
+    assert_eq!(overlap_codes("C-3"), 0);
+}
@@ -0,0 +1,64 @@
+use pretty_assertions::assert_eq;
+
+use qir_core::db;
+use qir_core::demo::seed_demo_dataset;
+use qir_core::sampling::{
+    list_incident_samples, replay_incident_sample, sample_incidents, IncidentSampleFilter,
+};
+
+fn seeded() -> rusqlite::Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    seed_demo_dataset(&mut conn).expect("seed");
+    conn
+}
+
+#[test]
+fn same_seed_and_filter_reproduce_the_same_sample() {
+    let conn = seeded();
+    let filter = IncidentSampleFilter {
+        severity: Some("SEV2".to_string()),
+        ..Default::default()
+    };
+
+    let a = sample_incidents(&conn, 5, "qir-2026-q1", &filter, "2026-02-10T03:00:00Z").expect("a");
+    let b = sample_incidents(&conn, 5, "qir-2026-q1", &filter, "2026-02-10T03:00:00Z").expect("b");
+    assert_eq!(a.population_count, 10);
+    assert_eq!(a.incident_ids.len(), 5);
+    assert_eq!(a.incident_ids, b.incident_ids);
+
+    let sev: Vec<String> = a
+        .incident_ids
+        .iter()
+        .map(|id| {
+            conn.query_row("SELECT severity FROM incidents WHERE id = ?1", [id], |r| r.get(0))
+                .unwrap()
+        })
+        .collect();
+    assert!(sev.iter().all(|s| s == "SEV2"));
+
+    let other = sample_incidents(&conn, 5, "another-seed", &filter, "2026-02-10T03:00:00Z").expect("c");
+    assert_ne!(a.incident_ids, other.incident_ids);
+
+    assert_eq!(list_incident_samples(&conn).unwrap().len(), 3);
+    let replay = replay_incident_sample(&conn, a.id).expect("replay");
+    assert!(replay.matches_recorded);
+}
+
+#[test]
+fn replay_detects_population_drift_and_sizes_are_validated() {
+    let conn = seeded();
+    let all = IncidentSampleFilter::default();
+    let s = sample_incidents(&conn, 40, "seed", &all, "2026-02-10T03:00:00Z").expect("sample");
+    assert_eq!(s.incident_ids.len(), 40);
+
+    conn.execute("DELETE FROM incidents WHERE external_id = 'IR-001'", []).unwrap();
+    let replay = replay_incident_sample(&conn, s.id).expect("replay");
+    assert!(!replay.matches_recorded);
+    assert_eq!(replay.current_population_count, 39);
+
+    let err = sample_incidents(&conn, 0, "seed", &all, "2026-02-10T03:00:00Z").expect_err("n=0");
+    assert_eq!(err.code, "VALIDATION_SAMPLE_SIZE_INVALID");
+    let err = sample_incidents(&conn, 1, " ", &all, "2026-02-10T03:00:00Z").expect_err("seed");
+    assert_eq!(err.code, "VALIDATION_SAMPLE_SEED_REQUIRED");
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::artifacts_dir::{ingest_artifacts_dir, FILE_ARTIFACT_KIND};
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+const TS: &str = "2026-04-02T09:00:00Z";
+
+fn setup() -> Connection {
+    db_with_incidents(&[
+        IncidentBuilder::new(1, "Checkout errors").external_id("INC-1").build(),
+        IncidentBuilder::new(2, "Search latency").external_id("INC-2").build(),
+    ])
+    .expect("db")
+}
+
+fn write(dir: &std::path::Path, rel: &str, bytes: &[u8]) {
+    let path = dir.join(rel);
+    std::fs::create_dir_all(path.parent().expect("parent")).expect("mkdir");
+    std::fs::write(path, bytes).expect("write");
+}
+
+#[test]
+fn walks_the_folder_copies_by_hash_and_links_rows() {
+    let mut conn = setup();
+    let src = tempfile::tempdir().expect("src");
+    let artifacts = tempfile::tempdir().expect("artifacts");
+    write(src.path(), "screens/01-dashboard.PNG", b"\x89PNG\r\n\x1a\nfake");
+    write(src.path(), "logs/api.log", b"12:00 ERROR upstream timeout\n");
+    write(src.path(), "logs/api-copy.log", b"12:00 ERROR upstream timeout\n");
+    write(src.path(), ".DS_Store", b"junk");
+
+    let summary = ingest_artifacts_dir(&mut conn, artifacts.path(), 1, src.path(), TS).expect("ingest");
+    let listed: Vec<(&str, &str, bool)> = summary
+        .artifacts
+        .iter()
+        .map(|a| (a.source_rel_path.as_str(), a.mime_type.as_str(), a.duplicate))
+        .collect();
+    assert_eq!(
+        listed,
+        vec![
+            ("logs/api-copy.log", "text/plain", false),
+            ("logs/api.log", "text/plain", true),
+            ("screens/01-dashboard.PNG", "image/png", false),
+        ]
+    );
+    assert_eq!((summary.inserted, summary.duplicates, summary.skipped), (2, 1, 0));
+
+    let png = &summary.artifacts[2];
+    assert!(png.stored_rel_path.ends_with(".png"));
+    assert_eq!(
+        std::fs::read(artifacts.path().join(&png.stored_rel_path)).expect("stored"),
+        b"\x89PNG\r\n\x1a\nfake"
+    );
+    let rows: Vec<(i64, String, String)> = conn
+        .prepare("SELECT incident_id, kind, filename FROM artifacts ORDER BY id")
+        .expect("prepare")
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows");
+    assert_eq!(
+        rows,
+        vec![
+            (1, FILE_ARTIFACT_KIND.to_string(), "api-copy.log".to_string()),
+            (1, FILE_ARTIFACT_KIND.to_string(), "01-dashboard.PNG".to_string()),
+        ]
+    );
+
+    // Ingesting the same folder for another incident stores nothing new.
+    let again = ingest_artifacts_dir(&mut conn, artifacts.path(), 2, src.path(), TS).expect("again");
+    assert_eq!((again.inserted, again.duplicates), (0, 3));
+}
+
+#[test]
+fn rejects_non_folders_and_unknown_incidents() {
+    let mut conn = setup();
+    let src = tempfile::tempdir().expect("src");
+    let artifacts = tempfile::tempdir().expect("artifacts");
+    write(src.path(), "note.txt", b"hello");
+
+    let err = ingest_artifacts_dir(&mut conn, artifacts.path(), 1, &src.path().join("note.txt"), TS).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_ARTIFACTS_DIR_INVALID");
+
+    let err = ingest_artifacts_dir(&mut conn, artifacts.path(), 99, src.path(), TS).unwrap_err();
+    assert_eq!(err.code, "DB_NOT_FOUND");
+
+    let empty = tempfile::tempdir().expect("empty");
+    let summary = ingest_artifacts_dir(&mut conn, artifacts.path(), 1, empty.path(), TS).expect("empty ingest");
+    assert!(summary.artifacts.is_empty());
+}
@@ -0,0 +1,157 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::confluence::{ingest_confluence_page, parse_confluence_page, ConfluenceActionItem};
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+const TS: &str = "2026-05-04T12:00:00Z";
+
+const HTML_EXPORT: &str = r#"<!DOCTYPE html>
+<html><head><title>Postmortem: Checkout 503s</title><style>p { color: red; }</style></head>
+<body>
+<h1>Postmortem: Checkout 503s</h1>
+<h2>Summary</h2>
+<p>Checkout returned 503s for 40 minutes after a bad config push&nbsp;to the payments gateway.</p>
+<h2>Timeline</h2>
+<table>
+  <tr><th>Time (UTC)</th><th>Event</th><th>Who</th></tr>
+  <tr><td>23:40</td><td>Config push &amp; rollout start</td><td>deploy-bot</td></tr>
+  <tr><td>23:52<td>First 503 alerts fire<td>
+  <tr><td>00:20</td><td>Rollback complete</td><td>Alice</td></tr>
+</table>
+<h2>Action items</h2>
+<table>
+  <tr><th>Action</th><th>Owner</th><th>Status</th></tr>
+  <tr><td>Add canary stage to config pushes</td><td>Bob</td><td>Open</td></tr>
+  <tr><td>Alert on gateway 5xx ratio</td><td>Alice</td><td>Done</td></tr>
+</table>
+</body></html>"#;
+
+const STORAGE_FORMAT: &str = r#"<ac:structured-macro ac:name="info"><ac:parameter ac:name="title">Status</ac:parameter>
+<ac:rich-text-body><p>Draft</p></ac:rich-text-body></ac:structured-macro>
+<h2>Incident overview</h2>
+<p>DNS resolution failed for internal services.</p>
+<h2>Chronology</h2>
+<table><tbody>
+<tr><th><p>When</p></th><th><p>What happened</p></th></tr>
+<tr><td><p>2026-03-02 10:00</p></td><td><p>Resolver errors spike</p></td></tr>
+<tr><td><p>10:15</p></td><td><p>Failover to secondary resolvers</p></td></tr>
+</tbody></table>
+<h2>Follow-ups</h2>
+<ac:task-list>
+<ac:task><ac:task-id>1</ac:task-id><ac:task-status>complete</ac:task-status><ac:task-body>Document resolver failover <ac:link><ri:user ri:username="carol" /></ac:link></ac:task-body></ac:task>
+<ac:task><ac:task-id>2</ac:task-id><ac:task-status>incomplete</ac:task-status><ac:task-body>Add resolver health checks</ac:task-body></ac:task>
+</ac:task-list>"#;
+
+fn setup() -> Connection {
+    db_with_incidents(&[IncidentBuilder::new(1, "Checkout errors")
+        .external_id("INC-1")
+        .start("2026-05-03T23:45:00Z")
+        .build()])
+    .expect("db")
+}
+
+#[test]
+fn parses_html_and_storage_format_pages() {
+    let html = parse_confluence_page(HTML_EXPORT).expect("html");
+    assert_eq!(html.format, "html");
+    assert_eq!(html.title.as_deref(), Some("Postmortem: Checkout 503s"));
+    assert_eq!(
+        html.summary.as_deref(),
+        Some("Checkout returned 503s for 40 minutes after a bad config push to the payments gateway.")
+    );
+    let timeline: Vec<(&str, &str, Option<&str>)> =
+        html.timeline.iter().map(|r| (r.time.as_str(), r.text.as_str(), r.author.as_deref())).collect();
+    assert_eq!(
+        timeline,
+        vec![
+            ("23:40", "Config push & rollout start", Some("deploy-bot")),
+            ("23:52", "First 503 alerts fire", None),
+            ("00:20", "Rollback complete", Some("Alice")),
+        ]
+    );
+    assert_eq!(
+        html.action_items[1],
+        ConfluenceActionItem {
+            title: "Alert on gateway 5xx ratio".to_string(),
+            owner: Some("Alice".to_string()),
+            done: true,
+        }
+    );
+
+    let storage = parse_confluence_page(STORAGE_FORMAT).expect("storage");
+    assert_eq!(storage.format, "storage");
+    assert_eq!(storage.summary.as_deref(), Some("DNS resolution failed for internal services."));
+    assert_eq!(storage.timeline.len(), 2);
+    let tasks: Vec<(&str, bool)> = storage.action_items.iter().map(|a| (a.title.as_str(), a.done)).collect();
+    assert_eq!(
+        tasks,
+        vec![("Document resolver failover @carol", true), ("Add resolver health checks", false)]
+    );
+
+    let err = parse_confluence_page("<html><body><p>Nothing to see</p></body></html>").unwrap_err();
+    assert_eq!(err.code, "INGEST_CONFLUENCE_EMPTY");
+}
+
+#[test]
+fn ingest_anchors_times_to_the_incident_and_is_idempotent() {
+    let mut conn = setup();
+    let summary = ingest_confluence_page(&mut conn, HTML_EXPORT, Some(1), None, TS).expect("ingest");
+    assert!(summary.description_filled);
+    assert_eq!((summary.inserted_events, summary.inserted_action_items), (3, 2));
+
+    let events: Vec<(Option<String>, String)> = conn
+        .prepare("SELECT ts, text FROM timeline_events WHERE incident_id = 1 AND source = 'confluence' ORDER BY id")
+        .expect("prepare")
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?)))
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows");
+    // 23:40 predates the recorded start but stays on its day; 00:20 rolls past midnight.
+    let ts: Vec<Option<&str>> = events.iter().map(|(t, _)| t.as_deref()).collect();
+    assert_eq!(
+        ts,
+        vec![Some("2026-05-03T23:40:00Z"), Some("2026-05-03T23:52:00Z"), Some("2026-05-04T00:20:00Z")]
+    );
+    let closed: Vec<(String, i64, Option<String>)> = conn
+        .prepare("SELECT title, done, closed_at FROM action_items WHERE incident_id = 1 ORDER BY id")
+        .expect("prepare")
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows");
+    assert_eq!(closed[1], ("Alert on gateway 5xx ratio".to_string(), 1, Some(TS.to_string())));
+
+    let again = ingest_confluence_page(&mut conn, HTML_EXPORT, Some(1), None, TS).expect("again");
+    assert!(!again.description_filled);
+    assert_eq!((again.inserted_events, again.inserted_action_items, again.skipped_duplicates), (0, 0, 5));
+}
+
+#[test]
+fn new_incident_takes_the_page_title_and_warns_on_undated_times() {
+    let mut conn = setup();
+    let page = "<h1>Queue backlog</h1><h2>Timeline</h2><table><tr><th>Time</th><th>Event</th></tr>
+        <tr><td>09:10</td><td>Backlog alert</td></tr></table>";
+    let summary = ingest_confluence_page(&mut conn, page, None, None, TS).expect("ingest");
+    assert!(summary.incident_created);
+    let title: String = conn
+        .query_row("SELECT title FROM incidents WHERE id = ?1", [summary.incident_id], |r| r.get(0))
+        .expect("title");
+    assert_eq!(title, "Queue backlog");
+    assert_eq!(summary.inserted_events, 1);
+    let codes: Vec<&str> = summary.warnings.iter().map(|w| w.code.as_str()).collect();
+    assert_eq!(codes, vec!["INGEST_CONFLUENCE_TS_NO_DATE"]);
+}
+
+#[test]
+fn script_bodies_with_non_ascii_text_are_skipped_exactly() {
+    // "İ" lowercases to three bytes, so a search on a fully lowercased copy lands past the close tag.
+    let page = format!(
+        "<h2>Summary</h2><SCRIPT>var city = \"{}\";</SCRIPT><p>Payments were down in İzmir.</p>",
+        "İ".repeat(20)
+    );
+    let parsed = parse_confluence_page(&page).expect("page");
+    assert_eq!(parsed.summary.as_deref(), Some("Payments were down in İzmir."));
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "testing")]
+
+use std::fs;
+
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+use qir_core::ingest::email::{ingest_email_source, parse_email_message, preview_email_source};
+use qir_core::testing::empty_db;
+
+const PLAIN: &str = "From: Alice <alice@example.com>\r\nSubject: Payments degraded\r\nDate: Mon, 05 Jan 2026 09:00:00 -0500 (EST)\r\nMessage-ID: <a1@example.com>\r\n\r\nWe are seeing elevated 500s.\r\n\r\nOn Mon, Bob wrote:\r\n> earlier text\r\n";
+
+const MULTIPART: &str = "From: =?utf-8?Q?Bj=C3=B6rn?= <bjorn@example.com>\nSubject: Re: Payments degraded\nDate: Mon, 05 Jan 2026 14:30:00 +0000\nContent-Type: multipart/alternative; boundary=\"b1\"\n\n--b1\nContent-Type: text/plain; charset=utf-8\nContent-Transfer-Encoding: quoted-printable\n\nRollback complete, error rate back to =\nnormal.\n-- \nBj=C3=B6rn\n--b1\nContent-Type: text/html\n\n<p>Rollback complete</p>\n--b1--\n";
+
+#[test]
+fn parses_headers_multipart_and_strips_quotes() {
+    let a = parse_email_message(PLAIN);
+    assert_eq!(a.from.as_deref(), Some("Alice <alice@example.com>"));
+    assert_eq!(a.ts.as_deref(), Some("2026-01-05T14:00:00Z"));
+    assert_eq!(a.message_id.as_deref(), Some("<a1@example.com>"));
+    assert_eq!(a.body, "We are seeing elevated 500s.");
+
+    let b = parse_email_message(MULTIPART);
+    assert_eq!(b.from.as_deref(), Some("Björn <bjorn@example.com>"));
+    assert_eq!(b.body, "Rollback complete, error rate back to normal.");
+}
+
+#[test]
+fn ingests_mbox_into_timeline_and_artifacts() {
+    let tmp = tempdir().unwrap();
+    let mbox = tmp.path().join("bridge.mbox");
+    let text = format!(
+        "From alice@example.com Mon Jan  5 14:00:00 2026\n{}\nFrom bjorn@example.com Mon Jan  5 14:30:00 2026\n{}",
+        PLAIN.replace("\r\n", "\n"),
+        MULTIPART
+    );
+    fs::write(&mbox, text).unwrap();
+
+    let preview = preview_email_source(&mbox).expect("preview");
+    assert_eq!(preview.message_count, 2);
+    assert_eq!(preview.first_ts.as_deref(), Some("2026-01-05T14:00:00Z"));
+
+    let mut conn = empty_db().expect("db");
+    let summary = ingest_email_source(&mut conn, &mbox, None, Some("Payments email thread")).expect("ingest");
+    assert!(summary.incident_created);
+    assert_eq!(summary.inserted_events, 2);
+    assert_eq!(summary.inserted_artifacts, 2);
+
+    let first: String = conn
+        .query_row(
+            "SELECT text FROM timeline_events WHERE source = 'email' ORDER BY ts LIMIT 1",
+            [],
+            |r| r.get(0),
+        )
+        .unwrap();
+    assert_eq!(first, "Payments degraded\n\nWe are seeing elevated 500s.");
+    let artifacts: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM artifacts WHERE kind = 'email_message' AND incident_id = ?1",
+            [summary.incident_id],
+            |r| r.get(0),
+        )
+        .unwrap();
+    assert_eq!(artifacts, 2);
+
+    let again = ingest_email_source(&mut conn, &mbox, Some(summary.incident_id), None).expect("reingest");
+    assert_eq!(again.inserted_events, 0);
+    assert_eq!(again.skipped_duplicates, 2);
+}
+
+#[test]
+fn empty_eml_folder_is_rejected() {
+    let tmp = tempdir().unwrap();
+    let err = preview_email_source(tmp.path()).unwrap_err();
+    assert_eq!(err.code, "INGEST_EMAIL_EMPTY");
+}
+
+#[test]
+fn undecodable_base64_body_warns_with_the_message_origin() {
+    let tmp = tempdir().unwrap();
+    let eml = tmp.path().join("broken.eml");
+    let raw = "From: Alice <alice@example.com>\nSubject: Status update\nDate: Mon, 05 Jan 2026 15:00:00 +0000\nContent-Type: text/plain\nContent-Transfer-Encoding: base64\n\n%%% not base64 %%%\n";
+    fs::write(&eml, raw).unwrap();
+
+    let parsed = parse_email_message(raw);
+    assert!(parsed.body_undecodable);
+    assert_eq!(parsed.body, "");
+
+    let preview = preview_email_source(&eml).expect("preview");
+    let warnings: Vec<(&str, Option<&str>)> =
+        preview.warnings.iter().map(|w| (w.code.as_str(), w.details.as_deref())).collect();
+    assert_eq!(warnings, vec![("INGEST_EMAIL_BODY_UNDECODABLE", Some("message=broken.eml"))]);
+
+    let mut conn = empty_db().expect("db");
+    let summary = ingest_email_source(&mut conn, &eml, None, Some("Broken body")).expect("ingest");
+    assert_eq!(summary.inserted_events, 1);
+    let codes: Vec<&str> = summary.warnings.iter().map(|w| w.code.as_str()).collect();
+    assert_eq!(codes, vec!["INGEST_EMAIL_BODY_UNDECODABLE"]);
+}
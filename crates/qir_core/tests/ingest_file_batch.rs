@@ -0,0 +1,110 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::file_batch::{detect_file_batch_format, import_file_batch, FileBatchFormat};
+use qir_core::progress::Progress;
+use qir_core::repo::count_incidents;
+use qir_core::testing::empty_db;
+
+const PAGERDUTY: &str = "\
+id,incident_number,description,status,urgency,priority_name,service_name,created_on,resolved_on
+Q1ABC,1042,Checkout API returning 503s,resolved,high,,payments-api,2026-01-05T10:00:00Z,2026-01-05T11:30:00Z
+";
+
+const SERVICENOW: &str = "\
+number,sys_id,short_description,priority,impact,urgency,state,business_service,opened_at,resolved_at,closed_at
+INC0010001,a1b2c3,Queue backlog on billing workers,1 - Critical,,,Resolved,billing,2026-01-07 08:00:00,2026-01-07 09:00:00,
+";
+
+const JIRA_CSV: &str = "\
+Issue key,Summary,Priority,Created,Resolved
+OPS-7,Search latency above SLO,P2,2026-01-06T08:00:00Z,2026-01-06T09:00:00Z
+";
+
+const FIREHYDRANT: &str = r#"{"data": [{"number": 77, "name": "Login outage", "milestones": [
+  {"type": "started", "occurred_at": "2026-02-01T10:00:00Z"}
+]}]}"#;
+
+const INCIDENT_IO: &str = r#"{"incidents": [{"reference": "INC-41", "name": "DNS failures",
+  "severity": {"name": "Major"}, "created_at": "2026-03-02T10:00:00Z",
+  "incident_role_assignments": [{"role": {"name": "Incident Lead"}, "assignee": {"name": "Alice"}}]}]}"#;
+
+#[test]
+fn detects_each_source_from_its_content() {
+    let cases = [
+        ("pd.csv", PAGERDUTY, FileBatchFormat::PagerdutyCsv),
+        ("sn.csv", SERVICENOW, FileBatchFormat::ServicenowCsv),
+        ("jira.csv", JIRA_CSV, FileBatchFormat::JiraCsv),
+        ("fh.json", FIREHYDRANT, FileBatchFormat::RetroTool),
+        ("iio.json", INCIDENT_IO, FileBatchFormat::IncidentIo),
+        ("jira.json", r#"{"issues": [{"key": "OPS-1", "fields": {}}]}"#, FileBatchFormat::JiraJson),
+        ("rootly.txt", r#"[{"id": "b6c2", "attributes": {"title": "x"}}]"#, FileBatchFormat::RetroTool),
+    ];
+    for (name, text, expected) in cases {
+        assert_eq!(detect_file_batch_format(name, text, None).expect(name), expected, "{name}");
+    }
+
+    let err = detect_file_batch_format("notes.json", r#"{"pages": []}"#, None).unwrap_err();
+    assert_eq!(err.code, "INGEST_FILE_BATCH_FORMAT_UNKNOWN");
+}
+
+#[test]
+fn imports_a_mixed_batch_and_reports_per_file() {
+    let mut conn = empty_db().expect("db");
+    let dir = tempfile::tempdir().expect("dir");
+    let mut paths = Vec::new();
+    for (name, text) in [
+        ("pagerduty.csv", PAGERDUTY),
+        ("servicenow.csv", SERVICENOW),
+        ("jira.csv", JIRA_CSV),
+        ("firehydrant.json", FIREHYDRANT),
+        ("incident_io.json", INCIDENT_IO),
+        ("notes.csv", "a,b\n1,2\n"),
+    ] {
+        let path = dir.path().join(name);
+        std::fs::write(&path, text).expect("write");
+        paths.push(path);
+    }
+    paths.push(dir.path().join("missing.json"));
+
+    let summary =
+        import_file_batch(&mut conn, &paths, None, &mut Progress::noop("import_file_batch")).expect("batch");
+    assert_eq!((summary.inserted, summary.updated, summary.failed), (5, 0, 2));
+    assert_eq!(count_incidents(&conn).expect("count"), 5);
+
+    let names: Vec<&str> = summary.files.keys().map(String::as_str).collect();
+    assert_eq!(
+        names,
+        vec!["firehydrant.json", "incident_io.json", "jira.csv", "missing.json", "notes.csv", "pagerduty.csv", "servicenow.csv"]
+    );
+    let pd = &summary.files["pagerduty.csv"];
+    assert_eq!(pd.format, Some(FileBatchFormat::PagerdutyCsv));
+    assert_eq!(pd.summary.as_ref().map(|s| s.inserted), Some(1));
+    assert_eq!(summary.files["incident_io.json"].responders_added, 1);
+    assert_eq!(
+        summary.files["notes.csv"].error.as_ref().map(|e| e.code.as_str()),
+        Some("INGEST_FILE_BATCH_FORMAT_UNKNOWN")
+    );
+    let missing = &summary.files["missing.json"];
+    assert_eq!(missing.format, None);
+    assert_eq!(missing.error.as_ref().map(|e| e.code.as_str()), Some("INGEST_FILE_BATCH_READ_FAILED"));
+
+    // Re-running the batch dedupes against what the first run wrote.
+    let again =
+        import_file_batch(&mut conn, &paths[..5], None, &mut Progress::noop("import_file_batch")).expect("again");
+    assert_eq!((again.inserted, again.failed), (0, 0));
+    assert_eq!(count_incidents(&conn).expect("count"), 5);
+}
+
+#[test]
+fn rejects_empty_batches_and_clashing_file_names() {
+    let mut conn = empty_db().expect("db");
+    let err = import_file_batch(&mut conn, &[], None, &mut Progress::noop("import_file_batch")).unwrap_err();
+    assert_eq!(err.code, "INGEST_FILE_BATCH_EMPTY");
+
+    let paths = vec!["/a/export.csv".into(), "/b/export.csv".into()];
+    let err = import_file_batch(&mut conn, &paths, None, &mut Progress::noop("import_file_batch")).unwrap_err();
+    assert_eq!(err.code, "INGEST_FILE_BATCH_DUPLICATE_FILE");
+    assert_eq!(err.details.as_deref(), Some("file=export.csv"));
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::fingerprint::{
+    save_fingerprint_strategy, FingerprintComponent, FingerprintInput, FingerprintStrategy,
+};
+use qir_core::ingest::generic_csv::{import_generic_csv, CsvDescriptionFormat, GenericCsvMapping};
+use qir_core::testing::{empty_db, fixture_mapping};
+use rusqlite::Connection;
+
+/// Rows carry no external id, so matching relies on the fingerprint alone.
+fn mapping() -> GenericCsvMapping {
+    GenericCsvMapping {
+        external_id: None,
+        description_format: CsvDescriptionFormat::Plain,
+        ..GenericCsvMapping::from(&fixture_mapping())
+    }
+}
+
+fn csv(rows: &[(&str, &str, &str, &str)]) -> String {
+    let mut out = "Summary,Service,StartTs,ResolveTs\n".to_string();
+    for (name, service, began, ended) in rows {
+        out.push_str(&format!("{name},{service},{began},{ended}\n"));
+    }
+    out
+}
+
+fn stored(conn: &Connection) -> Vec<(i64, Option<String>, Option<String>)> {
+    conn.prepare("SELECT id, fingerprint_strategy, resolve_ts FROM incidents ORDER BY id")
+        .expect("prepare")
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows")
+}
+
+#[test]
+fn versions_round_trip_and_default_keeps_the_original_fingerprint() {
+    let default = FingerprintStrategy::default();
+    assert_eq!(default.version(), "v1");
+    let custom = FingerprintStrategy {
+        components: vec![FingerprintComponent::Title, FingerprintComponent::Service, FingerprintComponent::StartDate],
+    };
+    assert_eq!(custom.version(), "v2:title+service+start_date");
+    assert_eq!(FingerprintStrategy::from_version(&custom.version()), Some(custom.clone()));
+    assert_eq!(FingerprintStrategy::from_version("v9"), None);
+
+    // The v1 payload is unchanged from before strategies existed.
+    let input = FingerprintInput {
+        title: "  Checkout   Errors ",
+        start_ts: Some("2026-01-05T14:00:00Z"),
+        ..Default::default()
+    };
+    assert_eq!(
+        default.fingerprint(&input),
+        ("f37d4280d64b7f68e4f3146fac2e3317ef72fe52a581ffed7ca140858e3c3a44".to_string(), "v1".to_string())
+    );
+
+    // A custom strategy with nothing to hash falls back to v1 rather than colliding.
+    let by_id = FingerprintStrategy {
+        components: vec![FingerprintComponent::ExternalId],
+    };
+    assert_eq!(by_id.fingerprint(&input), default.fingerprint(&input));
+
+    let conn = empty_db().expect("db");
+    let err = save_fingerprint_strategy(
+        &conn,
+        &FingerprintStrategy {
+            components: vec![FingerprintComponent::Title, FingerprintComponent::Title],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "VALIDATION_FINGERPRINT_STRATEGY_INVALID");
+}
+
+#[test]
+fn reimports_match_incidents_under_the_strategy_they_were_stored_with() {
+    let mut conn = empty_db().expect("db");
+
+    // Default strategy: the fingerprint covers resolve_ts, so only an identical row matches.
+    let v1_row = ("Checkout errors", "payments", "2026-01-05T14:00:00Z", "2026-01-05T15:00:00Z");
+    import_generic_csv(&mut conn, &csv(&[v1_row]), &mapping()).expect("v1 import");
+
+    save_fingerprint_strategy(
+        &conn,
+        &FingerprintStrategy {
+            components: vec![FingerprintComponent::Title, FingerprintComponent::Service, FingerprintComponent::StartDate],
+        },
+    )
+    .expect("save");
+    let v2_row = ("Search latency", "search", "2026-01-06T09:00:00Z", "2026-01-06T10:00:00Z");
+    let summary = import_generic_csv(&mut conn, &csv(&[v1_row, v2_row]), &mapping()).expect("v2 import");
+    assert_eq!((summary.inserted, summary.updated), (1, 0));
+
+    // A later resolve time still lands on the same incident under title+service+date.
+    let resolved_later = ("Search latency", "search", "2026-01-06T09:00:00Z", "2026-01-06T12:00:00Z");
+    let summary = import_generic_csv(&mut conn, &csv(&[v1_row, resolved_later]), &mapping()).expect("reimport");
+    assert_eq!((summary.inserted, summary.updated), (0, 1));
+    assert_eq!(
+        stored(&conn),
+        vec![
+            (1, Some("v1".to_string()), Some("2026-01-05T15:00:00Z".to_string())),
+            (2, Some("v2:title+service+start_date".to_string()), Some("2026-01-06T12:00:00Z".to_string())),
+        ]
+    );
+
+    // Back on the default, the incident stored under v2 is still found.
+    save_fingerprint_strategy(&conn, &FingerprintStrategy::default()).expect("reset");
+    let summary = import_generic_csv(&mut conn, &csv(&[resolved_later]), &mapping()).expect("after reset");
+    assert_eq!((summary.inserted, summary.updated, summary.skipped), (0, 0, 1));
+}
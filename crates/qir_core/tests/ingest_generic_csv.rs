@@ -0,0 +1,100 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::generic_csv::{import_generic_csv, preview_generic_csv, CsvDescriptionFormat, GenericCsvMapping};
+use qir_core::ingest::import_batches::list_import_batches;
+use qir_core::ingest::jira_csv::{import_jira_csv, JiraCsvMapping};
+use qir_core::testing::{empty_db, fixture_mapping};
+
+const CSV: &str = "Ticket,Incident Name,Notes,Began,Ended\n\
+OPS-1,Checkout errors,*Card* declines spiked,2026-01-05T14:00:00Z,2026-01-05T15:00:00Z\n\
+OPS-2,Search latency,,2026-01-06T09:00:00Z,\n";
+
+/// The fixture mapping pointed at `CSV`'s headers; its other columns are absent and import as unknown.
+fn jira_mapping() -> JiraCsvMapping {
+    JiraCsvMapping {
+        external_id: Some("Ticket".to_string()),
+        title: "Incident Name".to_string(),
+        description: Some("Notes".to_string()),
+        start_ts: Some("Began".to_string()),
+        resolve_ts: Some("Ended".to_string()),
+        ..fixture_mapping()
+    }
+}
+
+fn mapping() -> GenericCsvMapping {
+    GenericCsvMapping {
+        description_format: CsvDescriptionFormat::Plain,
+        ..GenericCsvMapping::from(&jira_mapping())
+    }
+}
+
+fn description(conn: &rusqlite::Connection, external_id: &str) -> (Option<String>, Option<String>) {
+    conn.query_row(
+        "SELECT description, description_raw FROM incidents WHERE external_id = ?1",
+        [external_id],
+        |r| Ok((r.get(0)?, r.get(1)?)),
+    )
+    .unwrap()
+}
+
+#[test]
+fn imports_arbitrary_headers_and_keeps_plain_descriptions() {
+    let preview = preview_generic_csv(CSV, 1).expect("preview");
+    assert_eq!(preview.headers, vec!["Ticket", "Incident Name", "Notes", "Began", "Ended"]);
+    assert_eq!(preview.rows.len(), 1);
+
+    let mut conn = empty_db().expect("db");
+    let summary = import_generic_csv(&mut conn, CSV, &mapping()).expect("import");
+    assert_eq!(summary.inserted, 2);
+    assert!(summary.conflicts.is_empty());
+
+    let (start, resolve): (String, Option<String>) = conn
+        .query_row(
+            "SELECT start_ts, resolve_ts FROM incidents WHERE external_id = 'OPS-2'",
+            [],
+            |r| Ok((r.get(0)?, r.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(start, "2026-01-06T09:00:00Z");
+    assert_eq!(resolve, None);
+    assert_eq!(description(&conn, "OPS-1"), (Some("*Card* declines spiked".to_string()), None));
+
+    let again = import_generic_csv(&mut conn, CSV, &mapping()).expect("reimport");
+    assert_eq!(again.inserted, 0);
+}
+
+#[test]
+fn jira_wrapper_normalizes_markup() {
+    let mut conn = empty_db().expect("db");
+    import_jira_csv(&mut conn, CSV, &jira_mapping()).expect("import");
+
+    let (text, raw) = description(&conn, "OPS-1");
+    assert_eq!(raw.as_deref(), Some("*Card* declines spiked"));
+    assert_ne!(text.as_deref(), raw.as_deref());
+}
+
+#[test]
+fn rows_without_the_mapped_title_are_skipped() {
+    let mut conn = empty_db().expect("db");
+    let summary = import_generic_csv(&mut conn, "Ticket,Began\nOPS-1,2026-01-05T14:00:00Z\n", &mapping()).expect("import");
+    assert_eq!(summary.inserted, 0);
+    assert_eq!(summary.skipped, 1);
+    assert_eq!(summary.warnings[0].code, "INGEST_MISSING_TITLE");
+}
+
+#[test]
+fn batches_are_recorded_under_the_mapping_source() {
+    let mut conn = empty_db().expect("db");
+    import_generic_csv(&mut conn, CSV, &mapping()).expect("plain import");
+    let labeled = GenericCsvMapping {
+        source: Some("ops_tracker".to_string()),
+        ..mapping()
+    };
+    import_generic_csv(&mut conn, CSV, &labeled).expect("labeled import");
+    import_jira_csv(&mut conn, CSV, &jira_mapping()).expect("jira import");
+
+    let sources: Vec<String> = list_import_batches(&conn, 10).expect("batches").into_iter().map(|b| b.source).collect();
+    assert_eq!(sources, vec!["jira_csv", "ops_tracker", "csv"]);
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "testing")]
+
+use std::collections::BTreeMap;
+
+use pretty_assertions::assert_eq;
+
+use qir_core::freeze::freeze_quarter;
+use qir_core::ingest::import_batches::list_import_batches;
+use qir_core::ingest::incident_io::{import_incident_io, preview_incident_io, IncidentIoMapping};
+use qir_core::responders::list_responders;
+use qir_core::testing::empty_db;
+
+const CSV: &str = "Reference,Name,Summary,Severity,Reported at,Impact started at,Accepted at,Stabilised at,Resolved at,Incident Lead,Reporter\n\
+INC-41,Checkout errors,Elevated 500s on checkout,Major,2026-01-05T14:05:00Z,2026-01-05T14:00:00Z,2026-01-05T14:07:00Z,2026-01-05T14:40:00Z,2026-01-05T15:10:00Z,Alice,Bob\n\
+INC-42,Search latency,,P0 - Sev Zero,,,,,,\"Carol, Dan\",\n";
+
+const JSON: &str = r#"{
+  "incidents": [
+    {
+      "id": "01H",
+      "reference": "INC-41",
+      "name": "Checkout errors",
+      "summary": "Elevated 500s on checkout",
+      "severity": {"id": "s2", "name": "Major"},
+      "created_at": "2026-01-05T14:05:00Z",
+      "incident_role_assignments": [
+        {"role": {"name": "Incident Lead", "role_type": "lead"}, "assignee": {"name": "Alice", "email": "alice@example.com"}},
+        {"role": {"name": "Scribe"}, "assignee": {"email": "erin@example.com"}},
+        {"role": {"name": "Reporter"}}
+      ],
+      "incident_timestamp_values": [
+        {"incident_timestamp": {"name": "Impact started"}, "value": {"value": "2026-01-05T14:00:00Z"}},
+        {"incident_timestamp": {"name": "Resolved at"}, "value": {"value": "2026-01-05T15:10:00Z"}}
+      ]
+    }
+  ]
+}"#;
+
+#[test]
+fn preview_lists_raw_severities_and_roles() {
+    let csv = preview_incident_io(CSV, None).expect("csv preview");
+    assert_eq!(csv.format, "csv");
+    assert_eq!(csv.incident_count, 2);
+    assert_eq!(csv.severities, vec!["Major", "P0 - Sev Zero"]);
+    assert_eq!(csv.roles, vec!["Incident Lead", "Reporter"]);
+
+    let json = preview_incident_io(JSON, None).expect("json preview");
+    assert_eq!(json.format, "json");
+    assert_eq!(json.roles, vec!["Incident Lead", "Scribe"]);
+
+    let err = preview_incident_io("{\"data\": 1}", None).unwrap_err();
+    assert_eq!(err.code, "INGEST_INCIDENT_IO_PARSE_FAILED");
+}
+
+#[test]
+fn csv_import_maps_custom_severities_and_roles_to_responders() {
+    let mut conn = empty_db().expect("db");
+
+    let mapping = IncidentIoMapping {
+        severity_map: BTreeMap::from([("major".to_string(), "SEV2".to_string())]),
+        ..IncidentIoMapping::default()
+    };
+    let summary = import_incident_io(&mut conn, CSV, &mapping).expect("import");
+    assert_eq!(summary.incidents.inserted, 2);
+    assert_eq!(summary.responders_added, 2);
+    let codes: Vec<&str> = summary.warnings.iter().map(|w| w.code.as_str()).collect();
+    assert_eq!(codes, vec!["INGEST_INCIDENT_IO_SEVERITY_UNMAPPED", "INGEST_INCIDENT_IO_ROLES_NO_START"]);
+
+    let (severity, start, mitigate): (String, String, String) = conn
+        .query_row(
+            "SELECT severity, start_ts, mitigate_ts FROM incidents WHERE external_id = 'IIO-INC-41'",
+            [],
+            |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(severity, "SEV2");
+    assert_eq!(start, "2026-01-05T14:00:00Z");
+    assert_eq!(mitigate, "2026-01-05T14:40:00Z");
+
+    let responders = list_responders(&conn, None).expect("responders");
+    let people: Vec<(&str, Option<&str>)> = responders.iter().map(|r| (r.person.as_str(), r.role.as_deref())).collect();
+    assert_eq!(people, vec![("Alice", Some("Incident Lead")), ("Bob", Some("Reporter"))]);
+    assert_eq!(responders[0].engaged_at, "2026-01-05T14:00:00Z");
+}
+
+#[test]
+fn json_reimport_updates_without_duplicating_responders() {
+    let mut conn = empty_db().expect("db");
+    let mapping = IncidentIoMapping::default();
+
+    let first = import_incident_io(&mut conn, JSON, &mapping).expect("import");
+    assert_eq!(first.incidents.inserted, 1);
+    assert_eq!(first.responders_added, 2);
+
+    let again = import_incident_io(&mut conn, JSON, &mapping).expect("reimport");
+    assert_eq!(again.incidents.inserted, 0);
+    assert_eq!(again.responders_added, 0);
+
+    let responders = list_responders(&conn, None).expect("responders");
+    let people: Vec<&str> = responders.iter().map(|r| r.person.as_str()).collect();
+    assert_eq!(people, vec!["Alice", "erin@example.com"]);
+
+    // Frozen incidents keep their responders; the skip is reported.
+    freeze_quarter(&mut conn, "Q1 2026", None, None).expect("freeze");
+    let frozen = import_incident_io(&mut conn, JSON, &mapping).expect("frozen reimport");
+    let warnings: Vec<(&str, Option<&str>)> =
+        frozen.warnings.iter().map(|w| (w.code.as_str(), w.details.as_deref())).collect();
+    assert_eq!(
+        warnings,
+        vec![(
+            "INGEST_INCIDENT_IO_ROLES_FROZEN",
+            Some("external_id=IIO-INC-41; quarter_label=Q1 2026; incident_id=1")
+        )]
+    );
+}
+
+#[test]
+fn markdown_summaries_survive_import_and_the_batch_is_labeled_incident_io() {
+    let mut conn = empty_db().expect("db");
+
+    let summary = "## Impact\n\n- *Checkout* returned 500s\n- _Search_ was slow\n\n+ Rolled back -v2-";
+    let export = serde_json::json!([{"reference": "INC-50", "name": "Checkout errors", "summary": summary}]);
+    import_incident_io(&mut conn, &export.to_string(), &IncidentIoMapping::default()).expect("import");
+
+    let (description, raw): (Option<String>, Option<String>) = conn
+        .query_row("SELECT description, description_raw FROM incidents WHERE external_id = 'IIO-INC-50'", [], |r| {
+            Ok((r.get(0)?, r.get(1)?))
+        })
+        .expect("incident");
+    assert_eq!(description.as_deref(), Some(summary));
+    assert_eq!(raw, None);
+
+    let batches = list_import_batches(&conn, 1).expect("batches");
+    assert_eq!(batches[0].source, "incident_io");
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "testing")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread;
+
+use qir_core::ingest::jira_api::{
+    clear_jira_api_credentials, default_jira_api_mapping, fetch_jira_issues, import_jira_issues,
+    jira_api_credentials_status, load_jira_api_credentials, preview_jira_issues, save_jira_api_credentials,
+    JiraApiCredentials, JiraApiIssue,
+};
+use qir_core::testing::empty_db;
+
+fn issues() -> Vec<JiraApiIssue> {
+    serde_json::from_value(serde_json::json!([
+        {
+            "key": "INC-1",
+            "fields": {
+                "summary": "Checkout API returning 503s",
+                "description": "Elevated 5xx after deploy",
+                "priority": { "name": "High", "id": "2" },
+                "components": [{ "name": "payments-api" }, { "name": "edge" }],
+                "created": "2026-01-05T10:00:00.000+0000",
+                "resolutiondate": "2026-01-05T11:30:00.000-0100",
+                "customfield_10042": { "value": "Vendor" }
+            }
+        },
+        {
+            "key": "INC-2",
+            "fields": { "summary": "Search latency above SLO", "priority": null, "created": "2026-01-06T08:00:00.000+0000" }
+        }
+    ]))
+    .expect("issues")
+}
+
+#[test]
+fn credentials_round_trip_without_exposing_the_token() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("jira_api_credentials.json");
+    assert!(!jira_api_credentials_status(&path).unwrap().configured);
+
+    let status = save_jira_api_credentials(
+        &path,
+        &JiraApiCredentials {
+            base_url: " https://example.atlassian.net/ ".to_string(),
+            email: Some("ops@example.com".to_string()),
+            token: "secret-token".to_string(),
+        },
+    )
+    .expect("save");
+    assert!(status.configured);
+    assert_eq!(status.base_url.as_deref(), Some("https://example.atlassian.net"));
+    assert!(!serde_json::to_string(&status).unwrap().contains("secret-token"));
+    assert_eq!(load_jira_api_credentials(&path).unwrap().unwrap().token, "secret-token");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+
+    let insecure = JiraApiCredentials {
+        base_url: "http://jira.example.com".to_string(),
+        email: None,
+        token: "t".to_string(),
+    };
+    assert_eq!(
+        save_jira_api_credentials(&path, &insecure).unwrap_err().code,
+        "INGEST_JIRA_API_CONFIG_INVALID"
+    );
+
+    clear_jira_api_credentials(&path).expect("clear");
+    clear_jira_api_credentials(&path).expect("clearing twice is fine");
+    assert!(load_jira_api_credentials(&path).unwrap().is_none());
+}
+
+#[test]
+fn imports_issues_through_the_jira_pipeline() {
+    let mut conn = empty_db().expect("db");
+
+    let preview = preview_jira_issues(&issues(), 1);
+    assert_eq!(preview.headers[0], "key");
+    assert!(preview.headers.contains(&"customfield_10042".to_string()));
+    assert_eq!(preview.rows.len(), 1);
+
+    let mut mapping = default_jira_api_mapping();
+    mapping.vendor = Some("customfield_10042".to_string());
+    let summary = import_jira_issues(&mut conn, &issues(), &mapping).expect("import");
+    assert_eq!(summary.inserted, 2);
+
+    let [severity, service, vendor, start_ts, resolve_ts]: [Option<String>; 5] = conn
+        .query_row(
+            "SELECT severity, service, vendor, start_ts, resolve_ts FROM incidents WHERE external_id = 'INC-1'",
+            [],
+            |row| Ok([row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?]),
+        )
+        .expect("incident");
+    assert_eq!(severity.as_deref(), Some("High"));
+    assert_eq!(service.as_deref(), Some("payments-api; edge"));
+    assert_eq!(vendor.as_deref(), Some("Vendor"));
+    assert!(start_ts.is_some_and(|ts| ts.starts_with("2026-01-05T10:00:00")));
+    assert!(
+        resolve_ts.is_some_and(|ts| ts.starts_with("2026-01-05T12:30:00")),
+        "Jira's +hhmm offsets are normalized to UTC"
+    );
+
+    let again = import_jira_issues(&mut conn, &issues(), &mapping).expect("reimport");
+    assert_eq!((again.inserted, again.updated), (0, 0));
+}
+
+/// Minimal HTTP server: `/search/jql` is missing (404), legacy `/search` serves two pages.
+fn serve_legacy_jira(listener: TcpListener) -> thread::JoinHandle<Vec<String>> {
+    thread::spawn(move || {
+        let mut seen = Vec::new();
+        for _ in 0..3 {
+            let (stream, _) = listener.accept().expect("accept");
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut content_length = 0;
+            let mut auth = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let lower = line.to_ascii_lowercase();
+                if let Some(v) = lower.strip_prefix("content-length:") {
+                    content_length = v.trim().parse().unwrap();
+                }
+                if lower.starts_with("authorization:") {
+                    auth = line.trim().to_string();
+                }
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(auth, "Authorization: Bearer pat-123");
+            seen.push(request_line.split_whitespace().nth(1).unwrap().to_string());
+
+            let (status, payload) = if request_line.contains("/search/jql") {
+                ("404 Not Found", serde_json::json!({}))
+            } else if body["startAt"] == 0 {
+                let issue = serde_json::json!({ "key": "INC-1", "fields": { "summary": "a" } });
+                ("200 OK", serde_json::json!({ "issues": [issue], "total": 2 }))
+            } else {
+                let issue = serde_json::json!({ "key": "INC-2", "fields": { "summary": "b" } });
+                ("200 OK", serde_json::json!({ "issues": [issue], "total": 2 }))
+            };
+            let payload = payload.to_string();
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{payload}",
+                payload.len()
+            )
+            .unwrap();
+        }
+        seen
+    })
+}
+
+#[test]
+fn fetch_falls_back_to_legacy_search_pagination() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let base_url = format!("http://127.0.0.1:{}", listener.local_addr().unwrap().port());
+    let server = serve_legacy_jira(listener);
+
+    let creds = JiraApiCredentials {
+        base_url,
+        email: None,
+        token: "pat-123".to_string(),
+    };
+    let fetched = fetch_jira_issues(&creds, "project = INC", &["summary".to_string()], 10).expect("fetch");
+    assert_eq!(fetched.iter().map(|i| i.key.as_str()).collect::<Vec<_>>(), ["INC-1", "INC-2"]);
+    assert_eq!(
+        server.join().unwrap(),
+        ["/rest/api/2/search/jql", "/rest/api/2/search", "/rest/api/2/search"]
+    );
+}
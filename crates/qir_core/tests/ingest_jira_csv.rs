@@ -16,6 +16,7 @@ fn ingests_sample_jira_csv_into_db() {
         title: "Summary".to_string(),
         description: Some("Description".to_string()),
         severity: Some("Severity".to_string()),
+        initial_severity: None,
         detection_source: None,
         vendor: None,
         service: None,
@@ -27,6 +28,9 @@ fn ingests_sample_jira_csv_into_db() {
         ack_ts: Some("AckTs".to_string()),
         mitigate_ts: None,
         resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
     };
 
     let summary = ingest_jira_csv(&mut conn, csv_text, &mapping).expect("ingest");
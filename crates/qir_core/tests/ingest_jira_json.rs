@@ -0,0 +1,131 @@
+#![cfg(feature = "testing")]
+
+use qir_core::freeze::freeze_quarter;
+use qir_core::ingest::jira_api::default_jira_api_mapping;
+use qir_core::ingest::jira_json::{import_jira_json, parse_jira_json_export, preview_jira_json};
+use qir_core::testing::empty_db;
+
+fn export() -> String {
+    serde_json::json!({
+        "issues": [
+            {
+                "key": "INC-7",
+                "fields": {
+                    "summary": "Payments webhook backlog",
+                    "priority": { "name": "Highest" },
+                    "created": "2026-02-01T09:00:00.000+0000",
+                    "resolutiondate": null,
+                    "customfield_10100": { "value": "Stripe" }
+                },
+                "changelog": {
+                    "histories": [
+                        {
+                            "id": "2",
+                            "author": { "displayName": "Dana Ops" },
+                            "created": "2026-02-01T11:00:00.000+0000",
+                            "items": [{ "field": "status", "fromString": "Mitigated", "toString": "Resolved" }]
+                        },
+                        {
+                            "id": "1",
+                            "author": { "displayName": "Dana Ops" },
+                            "created": "2026-02-01T10:15:00.000+0000",
+                            "items": [
+                                { "field": "status", "fromString": "Open", "toString": "Mitigated" },
+                                { "field": "assignee", "fromString": null, "toString": "Sam" }
+                            ]
+                        }
+                    ]
+                }
+            },
+            { "key": "INC-8", "fields": { "summary": "Search latency above SLO" } }
+        ]
+    })
+    .to_string()
+}
+
+#[test]
+fn preview_exposes_custom_fields_and_changelog_transitions() {
+    let preview = preview_jira_json(&export(), 5).expect("preview");
+    assert_eq!(preview.headers[0], "key");
+    assert!(preview.headers.contains(&"customfield_10100".to_string()));
+    let col = preview
+        .headers
+        .iter()
+        .position(|h| h == "changelog:status:Mitigated")
+        .expect("transition column");
+    assert_eq!(preview.rows[0][col], "2026-02-01T10:15:00.000+00:00");
+    assert_eq!(preview.rows[1][col], "");
+
+    let bare = format!("[{}]", serde_json::json!({ "key": "INC-9", "fields": {} }));
+    assert_eq!(parse_jira_json_export(&bare).unwrap().len(), 1);
+    assert_eq!(
+        parse_jira_json_export("{\"nope\": 1}").unwrap_err().code,
+        "INGEST_JIRA_JSON_PARSE_FAILED"
+    );
+}
+
+#[test]
+fn imports_incidents_and_changelog_timeline_events_idempotently() {
+    let mut conn = empty_db().expect("db");
+
+    let mut mapping = default_jira_api_mapping();
+    mapping.vendor = Some("customfield_10100".to_string());
+    mapping.mitigate_ts = Some("changelog:status:Mitigated".to_string());
+    mapping.resolve_ts = Some("changelog:status:Resolved".to_string());
+    let summary = import_jira_json(&mut conn, &export(), &mapping).expect("import");
+    assert_eq!(summary.incidents.inserted, 2);
+    assert_eq!(summary.timeline_events_inserted, 3);
+
+    let (vendor, mitigate_ts, resolve_ts): (Option<String>, Option<String>, Option<String>) = conn
+        .query_row(
+            "SELECT vendor, mitigate_ts, resolve_ts FROM incidents WHERE external_id = 'INC-7'",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .expect("incident");
+    assert_eq!(vendor.as_deref(), Some("Stripe"));
+    assert!(mitigate_ts.is_some_and(|ts| ts.starts_with("2026-02-01T10:15:00")));
+    assert!(resolve_ts.is_some_and(|ts| ts.starts_with("2026-02-01T11:00:00")));
+
+    let events: Vec<(String, Option<String>, String)> = conn
+        .prepare(
+            "SELECT ts, author, text FROM timeline_events WHERE source = 'jira' AND kind = 'field_change' ORDER BY ts, id",
+        )
+        .unwrap()
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[0].1.as_deref(), Some("Dana Ops"));
+    assert_eq!(events[0].2, "status: Open → Mitigated");
+    assert_eq!(events[1].2, "assignee: Sam");
+    assert_eq!(events[2].2, "status: Mitigated → Resolved");
+
+    let again = import_jira_json(&mut conn, &export(), &mapping).expect("reimport");
+    assert_eq!((again.incidents.inserted, again.incidents.updated), (0, 0));
+    assert_eq!((again.timeline_events_inserted, again.timeline_events_skipped), (0, 3));
+}
+
+#[test]
+fn changelog_events_skip_incidents_in_frozen_quarters() {
+    let mut conn = empty_db().expect("db");
+    let mapping = default_jira_api_mapping();
+    import_jira_json(&mut conn, &export(), &mapping).expect("import");
+    conn.execute("DELETE FROM timeline_events", []).expect("reset events");
+    freeze_quarter(&mut conn, "Q1 2026", None, None).expect("freeze");
+
+    let summary = import_jira_json(&mut conn, &export(), &mapping).expect("reimport");
+    assert_eq!(summary.timeline_events_inserted, 0);
+    let warnings: Vec<(String, Option<String>)> =
+        summary.warnings.iter().map(|w| (w.code.clone(), w.details.clone())).collect();
+    assert_eq!(
+        warnings,
+        vec![(
+            "INGEST_JIRA_JSON_CHANGELOG_SKIPPED".to_string(),
+            Some("key=INC-7; quarter_label=Q1 2026; incident_id=1".to_string())
+        )]
+    );
+    let events: i64 = conn.query_row("SELECT COUNT(*) FROM timeline_events", [], |r| r.get(0)).expect("count");
+    assert_eq!(events, 0);
+}
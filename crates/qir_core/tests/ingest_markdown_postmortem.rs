@@ -0,0 +1,142 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::markdown_postmortem::{
+    ingest_markdown_postmortem, parse_markdown_postmortem, MarkdownActionItem, MARKDOWN_ARTIFACT_KIND,
+};
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+const TS: &str = "2026-05-04T12:00:00Z";
+
+const POSTMORTEM: &str = "---
+title: \"Checkout 503s\"
+owner: payments
+---
+
+# Postmortem
+
+## Summary
+
+Checkout returned 503s for **40 minutes** after a bad
+config push (see [PR 812](https://git.example/pr/812)).
+
+## Impact
+
+- ~12% of checkouts failed
+
+## Timeline
+
+- **23:40** - Config push starts
+- 23:52 UTC: First 503 alerts fire
+  - paged @alice
+- `00:20` Rollback complete
+- Customer support notified
+
+```
+## Not a heading
+- 01:00 - not an event
+```
+
+## Action items
+
+- [ ] Add canary stage to config pushes @bob
+- [x] Alert on gateway 5xx ratio (@alice)
+";
+
+fn setup() -> Connection {
+    db_with_incidents(&[IncidentBuilder::new(1, "Checkout errors")
+        .external_id("INC-1")
+        .start("2026-05-03T23:45:00Z")
+        .build()])
+    .expect("db")
+}
+
+#[test]
+fn parses_conventional_sections() {
+    let doc = parse_markdown_postmortem(POSTMORTEM).expect("parse");
+    assert_eq!(doc.title.as_deref(), Some("Checkout 503s"));
+    assert_eq!(
+        doc.summary.as_deref(),
+        Some("Checkout returned 503s for 40 minutes after a bad config push (see PR 812).")
+    );
+    assert_eq!(doc.impact.as_deref(), Some("- ~12% of checkouts failed"));
+    let timeline: Vec<(&str, &str)> = doc.timeline.iter().map(|e| (e.time.as_str(), e.text.as_str())).collect();
+    assert_eq!(
+        timeline,
+        vec![
+            ("23:40", "Config push starts"),
+            ("23:52 UTC", "First 503 alerts fire; paged @alice"),
+            ("00:20", "Rollback complete"),
+            ("", "Customer support notified"),
+        ]
+    );
+    assert_eq!(
+        doc.action_items,
+        vec![
+            MarkdownActionItem {
+                title: "Add canary stage to config pushes @bob".to_string(),
+                owner: Some("bob".to_string()),
+                done: false,
+            },
+            MarkdownActionItem {
+                title: "Alert on gateway 5xx ratio (@alice)".to_string(),
+                owner: Some("alice".to_string()),
+                done: true,
+            },
+        ]
+    );
+
+    let err = parse_markdown_postmortem("# Notes\n\nNothing structured here.\n").unwrap_err();
+    assert_eq!(err.code, "INGEST_MARKDOWN_EMPTY");
+}
+
+#[test]
+fn ingest_writes_incident_timeline_actions_and_artifact_once() {
+    let mut conn = setup();
+    let dir = tempfile::tempdir().expect("dir");
+    let path = dir.path().join("checkout-503s.md");
+    std::fs::write(&path, POSTMORTEM).expect("write");
+
+    let summary = ingest_markdown_postmortem(&mut conn, &path, Some(1), None, TS).expect("ingest");
+    assert!(summary.description_filled && summary.artifact_inserted);
+    assert_eq!((summary.inserted_events, summary.inserted_action_items), (4, 2));
+
+    let description: String = conn
+        .query_row("SELECT description FROM incidents WHERE id = 1", [], |r| r.get(0))
+        .expect("description");
+    assert!(description.ends_with("\n\nImpact: - ~12% of checkouts failed"));
+    let ts: Vec<Option<String>> = conn
+        .prepare("SELECT ts FROM timeline_events WHERE incident_id = 1 AND source = 'markdown_postmortem' ORDER BY id")
+        .expect("prepare")
+        .query_map([], |r| r.get(0))
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows");
+    assert_eq!(
+        ts,
+        vec![
+            Some("2026-05-03T23:40:00Z".to_string()),
+            Some("2026-05-03T23:52:00Z".to_string()),
+            Some("2026-05-04T00:20:00Z".to_string()),
+            None,
+        ]
+    );
+    let artifact: (i64, String, String) = conn
+        .query_row(
+            "SELECT incident_id, kind, filename FROM artifacts WHERE id = ?1",
+            [summary.artifact_id],
+            |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)),
+        )
+        .expect("artifact");
+    assert_eq!(artifact, (1, MARKDOWN_ARTIFACT_KIND.to_string(), "checkout-503s.md".to_string()));
+
+    let again = ingest_markdown_postmortem(&mut conn, &path, Some(1), None, TS).expect("again");
+    assert!(!again.description_filled && !again.artifact_inserted);
+    assert_eq!(again.artifact_id, summary.artifact_id);
+    assert_eq!((again.inserted_events, again.inserted_action_items, again.skipped_duplicates), (0, 0, 6));
+
+    let err = ingest_markdown_postmortem(&mut conn, &dir.path().join("missing.md"), Some(1), None, TS).unwrap_err();
+    assert_eq!(err.code, "INGEST_MARKDOWN_READ_FAILED");
+}
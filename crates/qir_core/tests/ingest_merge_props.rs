@@ -0,0 +1,202 @@
+use proptest::prelude::*;
+
+use qir_core::ingest::merge::{
+    merge_preserve_on_empty, IncidentRecord, TimestampUpdate, TimestampUpdates,
+};
+
+fn opt_text() -> impl Strategy<Value = Option<String>> {
+    prop::option::of("[a-zA-Z0-9 :T-]{0,12}")
+}
+
+fn opt_pct() -> impl Strategy<Value = Option<i64>> {
+    prop::option::of(0i64..=100)
+}
+
+fn record() -> impl Strategy<Value = IncidentRecord> {
+    (
+        (opt_text(), "[a-f0-9]{8}", "[A-Za-z ]{1,16}", opt_text(), opt_text()),
+        (opt_text(), opt_text(), opt_text(), opt_pct(), opt_pct()),
+        prop::collection::vec(opt_text(), 12),
+    )
+        .prop_map(
+            |(
+                (external_id, fingerprint, title, description, severity),
+                (detection_source, vendor, service, impact_pct, service_health_pct),
+                ts,
+            )| IncidentRecord {
+                external_id,
+                fingerprint,
+                title,
+                description,
+                severity,
+                detection_source,
+                vendor,
+                service,
+                impact_pct,
+                service_health_pct,
+                start_ts: ts[0].clone(),
+                first_observed_ts: ts[1].clone(),
+                it_awareness_ts: ts[2].clone(),
+                ack_ts: ts[3].clone(),
+                mitigate_ts: ts[4].clone(),
+                resolve_ts: ts[5].clone(),
+                start_ts_raw: ts[6].clone(),
+                first_observed_ts_raw: ts[7].clone(),
+                it_awareness_ts_raw: ts[8].clone(),
+                ack_ts_raw: ts[9].clone(),
+                mitigate_ts_raw: ts[10].clone(),
+                resolve_ts_raw: ts[11].clone(),
+            },
+        )
+}
+
+fn ts_update() -> impl Strategy<Value = TimestampUpdate> {
+    (any::<bool>(), opt_text(), opt_text()).prop_map(|(provided, canonical, raw)| {
+        if provided {
+            TimestampUpdate {
+                provided,
+                canonical,
+                raw,
+            }
+        } else {
+            TimestampUpdate::not_provided()
+        }
+    })
+}
+
+fn ts_updates() -> impl Strategy<Value = TimestampUpdates> {
+    prop::collection::vec(ts_update(), 6).prop_map(|mut v| TimestampUpdates {
+        resolve: v.pop().unwrap(),
+        mitigate: v.pop().unwrap(),
+        ack: v.pop().unwrap(),
+        it_awareness: v.pop().unwrap(),
+        first_observed: v.pop().unwrap(),
+        start: v.pop().unwrap(),
+    })
+}
+
+/// (canonical, raw) pairs in the same column order as `updates_in_order`.
+fn ts_columns(r: &IncidentRecord) -> [(Option<String>, Option<String>); 6] {
+    [
+        (r.start_ts.clone(), r.start_ts_raw.clone()),
+        (r.first_observed_ts.clone(), r.first_observed_ts_raw.clone()),
+        (r.it_awareness_ts.clone(), r.it_awareness_ts_raw.clone()),
+        (r.ack_ts.clone(), r.ack_ts_raw.clone()),
+        (r.mitigate_ts.clone(), r.mitigate_ts_raw.clone()),
+        (r.resolve_ts.clone(), r.resolve_ts_raw.clone()),
+    ]
+}
+
+fn updates_in_order(u: &TimestampUpdates) -> [TimestampUpdate; 6] {
+    [
+        u.start.clone(),
+        u.first_observed.clone(),
+        u.it_awareness.clone(),
+        u.ack.clone(),
+        u.mitigate.clone(),
+        u.resolve.clone(),
+    ]
+}
+
+fn non_ts_text_fields(r: &IncidentRecord) -> [Option<String>; 6] {
+    [
+        r.external_id.clone(),
+        r.description.clone(),
+        r.severity.clone(),
+        r.detection_source.clone(),
+        r.vendor.clone(),
+        r.service.clone(),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn unprovided_timestamps_never_change_stored_values(
+        existing in record(),
+        incoming in record(),
+        updates in ts_updates(),
+    ) {
+        let merged = merge_preserve_on_empty(&existing, incoming, updates.clone());
+        let before = ts_columns(&existing);
+        let after = ts_columns(&merged);
+        for (i, u) in updates_in_order(&updates).iter().enumerate() {
+            if u.provided {
+                prop_assert_eq!(&after[i], &(u.canonical.clone(), u.raw.clone()));
+            } else {
+                prop_assert_eq!(&after[i], &before[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn empty_incoming_fields_never_clear_stored_values(
+        existing in record(),
+        incoming in record(),
+        updates in ts_updates(),
+    ) {
+        let merged = merge_preserve_on_empty(&existing, incoming.clone(), updates);
+        let before = non_ts_text_fields(&existing);
+        let inc = non_ts_text_fields(&incoming);
+        let after = non_ts_text_fields(&merged);
+        for ((b, i), a) in before.iter().zip(inc.iter()).zip(after.iter()) {
+            prop_assert_eq!(a, &i.clone().or_else(|| b.clone()));
+            if b.is_some() {
+                prop_assert!(a.is_some());
+            }
+        }
+        prop_assert_eq!(merged.impact_pct, incoming.impact_pct.or(existing.impact_pct));
+        prop_assert_eq!(
+            merged.service_health_pct,
+            incoming.service_health_pct.or(existing.service_health_pct)
+        );
+        prop_assert_eq!(&merged.title, &incoming.title);
+    }
+
+    #[test]
+    fn all_unprovided_with_empty_incoming_is_identity(existing in record()) {
+        let incoming = IncidentRecord {
+            external_id: None,
+            fingerprint: existing.fingerprint.clone(),
+            title: existing.title.clone(),
+            description: None,
+            severity: None,
+            detection_source: None,
+            vendor: None,
+            service: None,
+            impact_pct: None,
+            service_health_pct: None,
+            start_ts: None,
+            first_observed_ts: None,
+            it_awareness_ts: None,
+            ack_ts: None,
+            mitigate_ts: None,
+            resolve_ts: None,
+            start_ts_raw: None,
+            first_observed_ts_raw: None,
+            it_awareness_ts_raw: None,
+            ack_ts_raw: None,
+            mitigate_ts_raw: None,
+            resolve_ts_raw: None,
+        };
+        let updates = TimestampUpdates {
+            start: TimestampUpdate::not_provided(),
+            first_observed: TimestampUpdate::not_provided(),
+            it_awareness: TimestampUpdate::not_provided(),
+            ack: TimestampUpdate::not_provided(),
+            mitigate: TimestampUpdate::not_provided(),
+            resolve: TimestampUpdate::not_provided(),
+        };
+        prop_assert_eq!(merge_preserve_on_empty(&existing, incoming, updates), existing);
+    }
+
+    #[test]
+    fn reapplying_the_same_import_is_idempotent(
+        existing in record(),
+        incoming in record(),
+        updates in ts_updates(),
+    ) {
+        let once = merge_preserve_on_empty(&existing, incoming.clone(), updates.clone());
+        let twice = merge_preserve_on_empty(&once, incoming, updates);
+        prop_assert_eq!(twice, once);
+    }
+}
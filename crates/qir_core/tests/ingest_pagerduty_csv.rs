@@ -0,0 +1,101 @@
+#![cfg(feature = "testing")]
+
+use qir_core::ingest::import_batches::list_import_batches;
+use qir_core::ingest::pagerduty_csv::{import_pagerduty_csv, preview_pagerduty_csv, PagerDutyCsvMapping};
+use qir_core::testing::empty_db;
+
+const CSV: &str = "\
+id,incident_number,description,status,urgency,priority_name,service_name,created_on,resolved_on
+Q1ABC,1042,Checkout API returning 503s,resolved,high,,payments-api,2026-01-05T10:00:00Z,2026-01-05T11:30:00Z
+Q1ABD,1043,Search latency above SLO,resolved,low,P1,search,2026-01-06T08:00:00Z,2026-01-06T09:00:00Z
+Q1ABE,1044,,resolved,high,,search,2026-01-07T08:00:00Z,
+";
+
+fn incident(conn: &rusqlite::Connection, external_id: &str) -> (String, Option<String>, Option<String>, Option<String>) {
+    conn.query_row(
+        "SELECT title, severity, service, resolve_ts FROM incidents WHERE external_id = ?1",
+        [external_id],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+    )
+    .expect("incident")
+}
+
+#[test]
+fn imports_stock_pagerduty_export_columns() {
+    let mut conn = empty_db().expect("db");
+
+    let preview = preview_pagerduty_csv(CSV, 1).expect("preview");
+    assert_eq!(preview.headers[1], "incident_number");
+    assert_eq!(preview.rows.len(), 1);
+
+    let summary = import_pagerduty_csv(&mut conn, CSV, &PagerDutyCsvMapping::default()).expect("import");
+    assert_eq!(summary.inserted, 2);
+    assert_eq!(summary.skipped, 1, "the row without a title is skipped");
+    assert!(summary.warnings.iter().any(|w| w.code == "INGEST_MISSING_TITLE"));
+
+    let (title, severity, service, resolve_ts) = incident(&conn, "PD-1042");
+    assert_eq!(title, "Checkout API returning 503s");
+    assert_eq!(severity.as_deref(), Some("SEV2"), "high urgency maps to the configured severity");
+    assert_eq!(service.as_deref(), Some("payments-api"));
+    assert!(resolve_ts.is_some_and(|ts| ts.starts_with("2026-01-05T11:30:00")));
+
+    let (_, severity, _, _) = incident(&conn, "PD-1043");
+    assert_eq!(severity.as_deref(), Some("P1"), "priority wins over urgency");
+
+    // Re-importing the same export is idempotent.
+    let again = import_pagerduty_csv(&mut conn, CSV, &PagerDutyCsvMapping::default()).expect("reimport");
+    assert_eq!((again.inserted, again.updated), (0, 0));
+}
+
+#[test]
+fn custom_mapping_and_urgency_table_are_honored() {
+    let mut conn = empty_db().expect("db");
+
+    let csv = "Number,Title,Urgency,Opened,Acked\n77,Disk full on db-3,HIGH,2026-02-01T00:00:00Z,2026-02-01T00:05:00Z\n";
+    let mut mapping = PagerDutyCsvMapping {
+        incident_number: Some("Number".to_string()),
+        title: "Title".to_string(),
+        priority: None,
+        urgency: Some("Urgency".to_string()),
+        service: None,
+        created_ts: Some("Opened".to_string()),
+        ack_ts: Some("Acked".to_string()),
+        resolved_ts: None,
+        ..PagerDutyCsvMapping::default()
+    };
+    mapping.urgency_severity.insert("high".to_string(), "SEV1".to_string());
+
+    let summary = import_pagerduty_csv(&mut conn, csv, &mapping).expect("import");
+    assert_eq!(summary.inserted, 1);
+    let (severity, ack_ts): (Option<String>, Option<String>) = conn
+        .query_row("SELECT severity, ack_ts FROM incidents WHERE external_id = 'PD-77'", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .expect("incident");
+    assert_eq!(severity.as_deref(), Some("SEV1"));
+    assert!(ack_ts.is_some());
+}
+
+#[test]
+fn markdown_descriptions_are_kept_and_the_batch_is_labeled_pagerduty() {
+    let mut conn = empty_db().expect("db");
+
+    let notes = "# Impact\n- *payments* and _search_ degraded\n+ retries -helped-";
+    let csv = format!("incident_number,description,notes\n88,Card declines,\"{notes}\"\n");
+    let mapping = PagerDutyCsvMapping {
+        description: Some("notes".to_string()),
+        ..PagerDutyCsvMapping::default()
+    };
+    import_pagerduty_csv(&mut conn, &csv, &mapping).expect("import");
+
+    let (description, raw): (Option<String>, Option<String>) = conn
+        .query_row("SELECT description, description_raw FROM incidents WHERE external_id = 'PD-88'", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .expect("incident");
+    assert_eq!(description.as_deref(), Some(notes));
+    assert_eq!(raw, None);
+
+    let batches = list_import_batches(&conn, 1).expect("batches");
+    assert_eq!(batches[0].source, "pagerduty_csv");
+}
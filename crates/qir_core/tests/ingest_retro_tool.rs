@@ -0,0 +1,131 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::import_batches::list_import_batches;
+use qir_core::ingest::retro_tool::{import_retro_tool_export, preview_retro_tool_export, RetroTool};
+use qir_core::testing::empty_db;
+
+const FIREHYDRANT: &str = r#"{
+  "data": [
+    {
+      "id": "8a1f",
+      "number": 77,
+      "name": "Checkout errors",
+      "description": "Elevated 500s on checkout",
+      "severity": "SEV2",
+      "services": [{"name": "payments"}],
+      "milestones": [
+        {"type": "started", "occurred_at": "2026-01-05T14:00:00Z"},
+        {"type": "detected", "occurred_at": "2026-01-05T14:03:00Z"},
+        {"type": "acknowledged", "occurred_at": "2026-01-05T14:07:00Z"},
+        {"type": "investigating", "occurred_at": "2026-01-05T14:08:00Z"},
+        {"type": "mitigated", "occurred_at": "2026-01-05T14:40:00Z"},
+        {"type": "resolved", "occurred_at": "2026-01-05T15:10:00Z"},
+        {"type": "resolved", "occurred_at": "2026-01-05T16:00:00Z"}
+      ]
+    }
+  ]
+}"#;
+
+const ROOTLY: &str = r#"{
+  "data": [
+    {
+      "id": "b6c2",
+      "type": "incidents",
+      "attributes": {
+        "sequential_id": 12,
+        "title": "Login outage",
+        "summary": "SSO provider timeouts",
+        "severity": {"data": {"attributes": {"name": "SEV1", "slug": "sev1"}}},
+        "started_at": "2026-02-01T10:00:00Z",
+        "detected_at": "2026-02-01T10:02:00Z",
+        "acknowledged_at": "2026-02-01T10:05:00Z",
+        "mitigated_at": "2026-02-01T10:45:00Z",
+        "resolved_at": null
+      }
+    }
+  ]
+}"#;
+
+type Timestamps = (String, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>);
+
+fn timestamps(conn: &rusqlite::Connection, external_id: &str) -> Timestamps {
+    conn.query_row(
+        "SELECT severity, start_ts, first_observed_ts, ack_ts, mitigate_ts, resolve_ts FROM incidents WHERE external_id = ?1",
+        [external_id],
+        |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?, r.get(4)?, r.get(5)?)),
+    )
+    .unwrap()
+}
+
+#[test]
+fn detects_tool_and_lists_milestones() {
+    let fh = preview_retro_tool_export(FIREHYDRANT, None).expect("preview");
+    assert_eq!(fh.tool, RetroTool::FireHydrant);
+    assert_eq!(fh.incident_count, 1);
+    assert!(fh.milestones.contains(&"investigating".to_string()));
+
+    let rl = preview_retro_tool_export(ROOTLY, None).expect("preview");
+    assert_eq!(rl.tool, RetroTool::Rootly);
+
+    let err = preview_retro_tool_export("{\"meta\": {}}", None).unwrap_err();
+    assert_eq!(err.code, "INGEST_RETRO_TOOL_PARSE_FAILED");
+}
+
+#[test]
+fn maps_milestones_onto_canonical_timestamps() {
+    let mut conn = empty_db().expect("db");
+
+    let fh = import_retro_tool_export(&mut conn, FIREHYDRANT, None).expect("firehydrant");
+    assert_eq!(fh.inserted, 1);
+    assert_eq!(fh.warnings[0].code, "INGEST_RETRO_TOOL_MILESTONE_UNMAPPED");
+    assert_eq!(fh.warnings[0].details.as_deref(), Some("milestone=investigating"));
+    assert_eq!(
+        timestamps(&conn, "FH-77"),
+        (
+            "SEV2".to_string(),
+            Some("2026-01-05T14:00:00Z".to_string()),
+            Some("2026-01-05T14:03:00Z".to_string()),
+            Some("2026-01-05T14:07:00Z".to_string()),
+            Some("2026-01-05T14:40:00Z".to_string()),
+            // A reopened incident keeps its first resolution.
+            Some("2026-01-05T15:10:00Z".to_string()),
+        )
+    );
+
+    let rl = import_retro_tool_export(&mut conn, ROOTLY, Some(RetroTool::Rootly)).expect("rootly");
+    assert_eq!(rl.inserted, 1);
+    assert!(rl.warnings.is_empty());
+    let (severity, start, first_observed, _, mitigate, resolve) = timestamps(&conn, "RL-12");
+    assert_eq!(severity, "SEV1");
+    assert_eq!(start.as_deref(), Some("2026-02-01T10:00:00Z"));
+    assert_eq!(first_observed.as_deref(), Some("2026-02-01T10:02:00Z"));
+    assert_eq!(mitigate.as_deref(), Some("2026-02-01T10:45:00Z"));
+    assert_eq!(resolve, None);
+}
+
+#[test]
+fn markdown_descriptions_are_kept_and_batches_carry_the_tool_name() {
+    let mut conn = empty_db().expect("db");
+
+    let notes = "# Timeline\n- *cache* evicted\n- _origin_ overloaded\n+ scaled -twice-";
+    let firehydrant = serde_json::json!([{"number": 90, "name": "Cache stampede", "description": notes}]);
+    let rootly = serde_json::json!([
+        {"id": "r1", "attributes": {"sequential_id": 91, "title": "Origin overload", "summary": notes}}
+    ]);
+    import_retro_tool_export(&mut conn, &firehydrant.to_string(), None).expect("firehydrant import");
+    import_retro_tool_export(&mut conn, &rootly.to_string(), None).expect("rootly import");
+
+    for external_id in ["FH-90", "RL-91"] {
+        let (description, raw): (Option<String>, Option<String>) = conn
+            .query_row("SELECT description, description_raw FROM incidents WHERE external_id = ?1", [external_id], |r| {
+                Ok((r.get(0)?, r.get(1)?))
+            })
+            .expect("incident");
+        assert_eq!((description.as_deref(), raw), (Some(notes), None), "{external_id}");
+    }
+
+    let sources: Vec<String> = list_import_batches(&conn, 10).expect("batches").into_iter().map(|b| b.source).collect();
+    assert_eq!(sources, vec!["rootly", "firehydrant"]);
+}
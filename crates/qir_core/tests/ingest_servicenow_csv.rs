@@ -0,0 +1,88 @@
+#![cfg(feature = "testing")]
+
+use qir_core::ingest::import_batches::list_import_batches;
+use qir_core::ingest::servicenow_csv::{import_servicenow_csv, preview_servicenow_csv, ServiceNowCsvMapping};
+use qir_core::testing::empty_db;
+
+const CSV: &str = "\
+number,sys_id,short_description,priority,impact,urgency,state,business_service,opened_at,resolved_at,closed_at
+INC0010001,a1b2c3,Checkout API returning 503s,1 - Critical,1 - High,1 - High,Resolved,payments-api,2026-01-05 10:00:00,2026-01-05 11:30:00,
+INC0010002,,Search latency above SLO,,2 - Medium,3 - Low,7,search,2026-01-06 08:00:00,,2026-01-06 09:00:00
+INC0010003,d4e5f6,Queue backlog on billing workers,3 - Moderate,,,In Progress,billing,2026-01-07 08:00:00,2026-01-07 08:10:00,
+";
+
+fn incident(conn: &rusqlite::Connection, external_id: &str) -> (Option<String>, Option<String>, Option<String>) {
+    conn.query_row(
+        "SELECT severity, service, resolve_ts FROM incidents WHERE external_id = ?1",
+        [external_id],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    )
+    .expect("incident")
+}
+
+#[test]
+fn imports_stock_servicenow_export_columns() {
+    let mut conn = empty_db().expect("db");
+
+    let preview = preview_servicenow_csv(CSV, 2).expect("preview");
+    assert_eq!(preview.headers[1], "sys_id");
+    assert_eq!(preview.rows.len(), 2);
+
+    let summary = import_servicenow_csv(&mut conn, CSV, &ServiceNowCsvMapping::default()).expect("import");
+    assert_eq!(summary.inserted, 3);
+
+    let (severity, service, resolve_ts) = incident(&conn, "SN-a1b2c3");
+    assert_eq!(severity.as_deref(), Some("SEV1"), "priority display value maps through the table");
+    assert_eq!(service.as_deref(), Some("payments-api"));
+    assert!(resolve_ts.is_some_and(|ts| ts.starts_with("2026-01-05T11:30:00")));
+
+    let (severity, _, resolve_ts) = incident(&conn, "INC0010002");
+    assert_eq!(severity.as_deref(), Some("SEV4"), "impact 2 + urgency 3 derives priority 4");
+    assert!(
+        resolve_ts.is_some_and(|ts| ts.starts_with("2026-01-06T09:00:00")),
+        "closed incidents fall back to closed_at"
+    );
+
+    let (severity, _, resolve_ts) = incident(&conn, "SN-d4e5f6");
+    assert_eq!(severity.as_deref(), Some("SEV3"));
+    assert_eq!(resolve_ts, None, "open incidents do not import a resolve time");
+
+    let again = import_servicenow_csv(&mut conn, CSV, &ServiceNowCsvMapping::default()).expect("reimport");
+    assert_eq!((again.inserted, again.updated), (0, 0));
+}
+
+#[test]
+fn reopened_incident_preserves_stored_resolve_time() {
+    let mut conn = empty_db().expect("db");
+
+    let header = "sys_id,short_description,priority,state,opened_at,resolved_at\n";
+    let resolved = format!("{header}z9,Disk full on db-3,2,Resolved,2026-02-01T00:00:00Z,2026-02-01T01:00:00Z\n");
+    import_servicenow_csv(&mut conn, &resolved, &ServiceNowCsvMapping::default()).expect("import");
+
+    let reopened = format!("{header}z9,Disk full on db-3,2,In Progress,2026-02-01T00:00:00Z,\n");
+    import_servicenow_csv(&mut conn, &reopened, &ServiceNowCsvMapping::default()).expect("reimport");
+
+    let (severity, _, resolve_ts) = incident(&conn, "SN-z9");
+    assert_eq!(severity.as_deref(), Some("SEV2"));
+    assert!(resolve_ts.is_some_and(|ts| ts.starts_with("2026-02-01T01:00:00")));
+}
+
+#[test]
+fn descriptions_are_kept_verbatim_and_the_batch_is_labeled_servicenow() {
+    let mut conn = empty_db().expect("db");
+
+    let notes = "# Work notes\n- *db-3* failed over\n- _replica_ lag -cleared-";
+    let csv = format!("sys_id,short_description,description\nz9y8,Database failover,\"{notes}\"\n");
+    import_servicenow_csv(&mut conn, &csv, &ServiceNowCsvMapping::default()).expect("import");
+
+    let (description, raw): (Option<String>, Option<String>) = conn
+        .query_row("SELECT description, description_raw FROM incidents WHERE external_id = 'SN-z9y8'", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .expect("incident");
+    assert_eq!(description.as_deref(), Some(notes));
+    assert_eq!(raw, None);
+
+    let batches = list_import_batches(&conn, 1).expect("batches");
+    assert_eq!(batches[0].source, "servicenow_csv");
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "testing")]
+
+use std::fs;
+use std::path::Path;
+
+use qir_core::ingest::slack_export::{ingest_slack_export, preview_slack_export};
+use qir_core::testing::empty_db;
+
+fn write_export(root: &Path) {
+    fs::write(
+        root.join("users.json"),
+        r#"[
+          {"id": "U1", "name": "alice", "profile": {"display_name": "Alice", "real_name": "Alice Example"}},
+          {"id": "U2", "name": "bob", "real_name": "Bob Builder", "profile": {"display_name": ""}}
+        ]"#,
+    )
+    .unwrap();
+    fs::create_dir(root.join("inc-payments")).unwrap();
+    fs::write(
+        root.join("inc-payments").join("2024-01-01.json"),
+        r#"[
+          {"type": "message", "subtype": "channel_join", "user": "U2", "text": "<@U2> has joined the channel", "ts": "1704067100.000100"},
+          {"type": "message", "user": "U1", "text": "Payments failing, paging <@U2>", "ts": "1704067200.000100", "thread_ts": "1704067200.000100", "reply_count": 1},
+          {"type": "message", "user": "U2", "text": "Rolling back now", "ts": "1704067260.000200", "thread_ts": "1704067200.000100", "parent_user_id": "U1"}
+        ]"#,
+    )
+    .unwrap();
+    fs::create_dir(root.join("random")).unwrap();
+    fs::write(
+        root.join("random").join("2024-01-01.json"),
+        r#"[{"type": "message", "user": "U9", "user_profile": {"real_name": "Guest"}, "text": "lunch?", "ts": "1704067300.000000"}]"#,
+    )
+    .unwrap();
+}
+
+#[test]
+fn previews_channels_and_user_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    write_export(dir.path());
+
+    let preview = preview_slack_export(dir.path()).expect("preview");
+    assert_eq!(preview.user_count, 2);
+    assert!(preview.warnings.is_empty());
+    let names: Vec<&str> = preview.channels.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["inc-payments", "random"]);
+    assert_eq!(preview.channels[0].message_count, 2);
+    assert_eq!(preview.channels[0].first_ts.as_deref(), Some("2024-01-01T00:00:00.0001Z"));
+}
+
+#[test]
+fn ingests_selected_channel_with_names_and_thread_metadata() {
+    let dir = tempfile::tempdir().unwrap();
+    write_export(dir.path());
+    let mut conn = empty_db().expect("db");
+
+    let summary = ingest_slack_export(
+        &mut conn,
+        dir.path(),
+        &["inc-payments".to_string()],
+        None,
+        Some("Payments outage"),
+    )
+    .expect("ingest");
+    assert!(summary.incident_created);
+    assert_eq!(summary.detected_format, "slack_workspace_export");
+    assert_eq!(summary.inserted_events, 2);
+
+    let rows: Vec<(String, String, String, String)> = {
+        let mut stmt = conn
+            .prepare("SELECT author, kind, text, raw_json FROM timeline_events WHERE incident_id = ?1 ORDER BY ts")
+            .unwrap();
+        stmt.query_map([summary.incident_id], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?)))
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+    };
+    assert_eq!(rows[0].0, "Alice");
+    assert_eq!(rows[0].1, "message");
+    assert_eq!(rows[0].2, "Payments failing, paging @Bob Builder");
+    assert_eq!(rows[1].0, "Bob Builder");
+    assert_eq!(rows[1].1, "thread_reply");
+    let raw: serde_json::Value = serde_json::from_str(&rows[1].3).unwrap();
+    assert_eq!(raw["channel"], "inc-payments");
+    assert_eq!(raw["thread_ts"], "1704067200.000100");
+    assert_eq!(raw["parent_user_id"], "U1");
+
+    // Re-importing into the same incident skips messages already stored.
+    let again = ingest_slack_export(&mut conn, dir.path(), &[], Some(summary.incident_id), None).expect("reingest");
+    assert_eq!(again.inserted_events, 1);
+}
+
+#[test]
+fn unknown_channel_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    write_export(dir.path());
+    let mut conn = empty_db().expect("db");
+
+    let err = ingest_slack_export(&mut conn, dir.path(), &["nope".to_string()], None, Some("X")).unwrap_err();
+    assert_eq!(err.code, "INGEST_SLACK_EXPORT_CHANNEL_NOT_FOUND");
+}
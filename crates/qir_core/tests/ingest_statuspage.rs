@@ -0,0 +1,98 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::statuspage::{ingest_statuspage_incident, preview_statuspage_history};
+use qir_core::quarters::assigned_incident_ids;
+use qir_core::testing::empty_db;
+
+const HISTORY: &str = r#"{
+  "page": {"id": "p1", "name": "Acme Status"},
+  "incidents": [
+    {
+      "id": "inc-1",
+      "name": "Elevated checkout errors",
+      "status": "resolved",
+      "impact": "major",
+      "shortlink": "https://stspg.io/abc",
+      "created_at": "2026-01-05T09:00:00.000-05:00",
+      "monitoring_at": "2026-01-05T14:40:00.000Z",
+      "resolved_at": "2026-01-05T15:10:00.000Z",
+      "incident_updates": [
+        {"id": "u3", "status": "resolved", "body": "This incident has been resolved.", "created_at": "2026-01-05T15:10:00.000Z", "display_at": "2026-01-05T15:10:00.000Z"},
+        {"id": "u1", "status": "investigating", "body": "We are investigating elevated errors.", "created_at": "2026-01-05T14:00:00.000Z", "display_at": "2026-01-05T14:00:00.000Z"},
+        {"id": "u2", "status": "monitoring", "body": "A fix has been deployed.", "created_at": "2026-01-05T14:40:00.000Z", "display_at": "2026-01-05T14:40:00.000Z"}
+      ]
+    },
+    {"id": "inc-2", "name": "Scheduled maintenance", "created_at": "2026-01-09T01:00:00Z", "incident_updates": []}
+  ]
+}"#;
+
+#[test]
+fn preview_lists_incidents_with_canonical_timestamps() {
+    let preview = preview_statuspage_history(HISTORY).expect("preview");
+    assert_eq!(preview.len(), 2);
+    assert_eq!(preview[0].id, "inc-1");
+    assert_eq!(preview[0].created_at.as_deref(), Some("2026-01-05T14:00:00Z"));
+    assert_eq!(preview[0].update_count, 3);
+
+    let err = preview_statuspage_history("{\"page\": {}}").unwrap_err();
+    assert_eq!(err.code, "INGEST_STATUSPAGE_PARSE_FAILED");
+}
+
+#[test]
+fn ingest_adds_updates_and_backfills_only_missing_timestamps() {
+    let mut conn = empty_db().expect("db");
+
+    let summary = ingest_statuspage_incident(&mut conn, HISTORY, "inc-1", None, None).expect("ingest");
+    assert!(summary.incident_created);
+    assert_eq!(summary.inserted_events, 3);
+    assert_eq!(summary.backfilled_fields, vec!["start_ts", "mitigate_ts", "resolve_ts"]);
+
+    let (title, start, resolve): (String, String, String) = conn
+        .query_row(
+            "SELECT title, start_ts, resolve_ts FROM incidents WHERE id = ?1",
+            [summary.incident_id],
+            |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(title, "Elevated checkout errors");
+    assert_eq!(start, "2026-01-05T14:00:00Z");
+    assert_eq!(resolve, "2026-01-05T15:10:00Z");
+
+    let kinds: Vec<String> = conn
+        .prepare("SELECT kind FROM timeline_events WHERE source = 'statuspage' ORDER BY ts")
+        .unwrap()
+        .query_map([], |r| r.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(kinds, vec!["investigating", "monitoring", "resolved"]);
+
+    // A reviewer-corrected start time must survive a re-import.
+    conn.execute(
+        "UPDATE incidents SET start_ts = '2026-01-05T13:52:00Z' WHERE id = ?1",
+        [summary.incident_id],
+    )
+    .unwrap();
+    let again = ingest_statuspage_incident(&mut conn, HISTORY, "inc-1", Some(summary.incident_id), None).expect("reingest");
+    assert_eq!(again.inserted_events, 0);
+    assert_eq!(again.skipped_duplicates, 3);
+    assert!(again.backfilled_fields.is_empty());
+    let start: String = conn
+        .query_row("SELECT start_ts FROM incidents WHERE id = ?1", [summary.incident_id], |r| r.get(0))
+        .unwrap();
+    assert_eq!(start, "2026-01-05T13:52:00Z");
+
+    let err = ingest_statuspage_incident(&mut conn, HISTORY, "inc-9", Some(summary.incident_id), None).unwrap_err();
+    assert_eq!(err.code, "INGEST_STATUSPAGE_INCIDENT_NOT_FOUND");
+}
+
+#[test]
+fn backfilled_anchor_assigns_the_incident_to_its_quarter() {
+    let mut conn = empty_db().expect("db");
+
+    let summary = ingest_statuspage_incident(&mut conn, HISTORY, "inc-2", None, None).expect("ingest");
+    assert_eq!(summary.backfilled_fields, vec!["start_ts"]);
+    assert_eq!(assigned_incident_ids(&conn, "Q1 2026").expect("assigned"), vec![summary.incident_id]);
+}
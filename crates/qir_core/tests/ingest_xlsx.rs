@@ -0,0 +1,107 @@
+#![cfg(feature = "testing")]
+
+use std::io::{Cursor, Write};
+
+use qir_core::ingest::xlsx::{import_xlsx, preview_xlsx};
+use qir_core::testing::{empty_db, fixture_mapping};
+
+fn cell(c: &str) -> String {
+    match c.strip_prefix('#') {
+        // `#<serial>` is a date-time cell (style 1 = built-in format 22).
+        Some(serial) => format!(r#"<c s="1"><v>{serial}</v></c>"#),
+        None if c.parse::<f64>().is_ok() => format!("<c><v>{c}</v></c>"),
+        None => format!(r#"<c t="inlineStr"><is><t>{c}</t></is></c>"#),
+    }
+}
+
+fn sheet_xml(rows: &[&[&str]]) -> String {
+    let rows: String = rows
+        .iter()
+        .map(|r| format!("<row>{}</row>", r.iter().map(|c| cell(c)).collect::<String>()))
+        .collect();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>{rows}</sheetData></worksheet>"#
+    )
+}
+
+/// Minimal two-sheet workbook: "Notes" first, then "Incidents".
+fn workbook() -> Vec<u8> {
+    let incidents = sheet_xml(&[
+        &["Key", "Summary", "Severity", "StartTs", "ResolveTs", "ImpactPct"],
+        &["INC-1", "Checkout API returning 503s", "SEV1", "#46027.4166666667", "#46027.5", "12"],
+        &[],
+        &["INC-2", "Search latency above SLO", "SEV3", "2026-01-06T08:00:00Z"],
+    ]);
+    let parts = [
+        (
+            "[Content_Types].xml",
+            r#"<?xml version="1.0" encoding="UTF-8"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/><Override PartName="/xl/worksheets/sheet2.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/><Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/></Types>"#.to_string(),
+        ),
+        (
+            "_rels/.rels",
+            r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#.to_string(),
+        ),
+        (
+            "xl/workbook.xml",
+            r#"<?xml version="1.0" encoding="UTF-8"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="Notes" sheetId="1" r:id="rId1"/><sheet name="Incidents" sheetId="2" r:id="rId2"/></sheets></workbook>"#.to_string(),
+        ),
+        (
+            "xl/_rels/workbook.xml.rels",
+            r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet2.xml"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/></Relationships>"#.to_string(),
+        ),
+        (
+            "xl/styles.xml",
+            r#"<?xml version="1.0" encoding="UTF-8"?><styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><cellXfs count="2"><xf numFmtId="0"/><xf numFmtId="22" applyNumberFormat="1"/></cellXfs></styleSheet>"#.to_string(),
+        ),
+        ("xl/worksheets/sheet1.xml", sheet_xml(&[&["Owner"], &["ops"]])),
+        ("xl/worksheets/sheet2.xml", incidents),
+    ];
+    let mut zw = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, xml) in parts {
+        zw.start_file(name, zip::write::SimpleFileOptions::default()).unwrap();
+        zw.write_all(xml.as_bytes()).unwrap();
+    }
+    zw.finish().unwrap().into_inner()
+}
+
+#[test]
+fn preview_lists_sheets_and_reads_the_selected_one() {
+    let bytes = workbook();
+
+    let first = preview_xlsx(&bytes, None, 5).expect("preview");
+    assert_eq!(first.sheets, ["Notes", "Incidents"]);
+    assert_eq!(first.sheet, "Notes");
+    assert_eq!(first.headers, ["Owner"]);
+
+    let preview = preview_xlsx(&bytes, Some("Incidents"), 5).expect("preview");
+    assert_eq!(preview.headers[0], "Key");
+    assert_eq!(preview.rows.len(), 2, "blank rows are dropped");
+    assert_eq!(preview.rows[0][3], "2026-01-05T10:00:00Z");
+    assert_eq!(preview.rows[0][5], "12");
+    assert_eq!(preview_xlsx(&bytes, Some("Incidents"), 1).unwrap().rows.len(), 1);
+
+    let err = preview_xlsx(&bytes, Some("Missing"), 5).unwrap_err();
+    assert_eq!(err.code, "INGEST_XLSX_SHEET_NOT_FOUND");
+    assert_eq!(preview_xlsx(b"not a workbook", None, 5).unwrap_err().code, "INGEST_XLSX_PARSE_FAILED");
+}
+
+#[test]
+fn imports_a_sheet_with_a_jira_mapping() {
+    let mut conn = empty_db().expect("db");
+
+    let summary = import_xlsx(&mut conn, &workbook(), Some("Incidents"), &fixture_mapping()).expect("import");
+    assert_eq!(summary.inserted, 2);
+
+    let (start_ts, resolve_ts): (Option<String>, Option<String>) = conn
+        .query_row(
+            "SELECT start_ts, resolve_ts FROM incidents WHERE external_id = 'INC-1'",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .expect("incident");
+    assert!(start_ts.is_some_and(|ts| ts.starts_with("2026-01-05T10:00:00")));
+    assert!(resolve_ts.is_some_and(|ts| ts.starts_with("2026-01-05T12:00:00")));
+
+    let again = import_xlsx(&mut conn, &workbook(), Some("Incidents"), &fixture_mapping()).expect("reimport");
+    assert_eq!((again.inserted, again.updated), (0, 0));
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::zoom_vtt::{ingest_zoom_vtt, parse_zoom_vtt, preview_zoom_vtt};
+use qir_core::testing::empty_db;
+
+const VTT: &str = "WEBVTT\r\n\r\n1\r\n00:00:05.120 --> 00:00:09.300\r\nAlice Smith: Payments API is throwing 500s\r\n\r\nNOTE recording resumed\r\n\r\n2\r\n00:01:02.000 --> 00:01:04.500 align:start\r\n<v Bob Lee>Rolling back the deploy\r\nnow</v>\r\n\r\n3\r\n00:01:10.000 --> 00:01:11.000\r\nthanks everyone\r\n";
+
+#[test]
+fn parses_zoom_and_voice_tag_speakers() {
+    let (cues, warnings) = parse_zoom_vtt(VTT).expect("parse");
+    assert_eq!(cues.len(), 3);
+    assert_eq!(cues[0].cue_id.as_deref(), Some("1"));
+    assert_eq!(cues[0].start_ms, 5_120);
+    assert_eq!(cues[0].speaker.as_deref(), Some("Alice Smith"));
+    assert_eq!(cues[1].speaker.as_deref(), Some("Bob Lee"));
+    assert_eq!(cues[1].text, "Rolling back the deploy now");
+    assert_eq!(cues[2].speaker, None);
+    assert!(warnings.iter().any(|w| w.code == "INGEST_ZOOM_VTT_SPEAKER_UNKNOWN"));
+
+    let preview = preview_zoom_vtt(VTT).expect("preview");
+    assert_eq!(preview.speakers, vec!["Alice Smith".to_string(), "Bob Lee".to_string()]);
+    assert_eq!(preview.duration_ms, 71_000);
+}
+
+#[test]
+fn ingests_cues_anchored_to_call_start_and_skips_duplicates() {
+    let mut conn = empty_db().expect("db");
+
+    let summary =
+        ingest_zoom_vtt(&mut conn, None, Some("Payments bridge"), "2026-01-05T09:00:00-05:00", VTT).expect("ingest");
+    assert!(summary.incident_created);
+    assert_eq!(summary.inserted_events, 3);
+
+    let rows: Vec<(String, Option<String>, String)> = {
+        let mut stmt = conn
+            .prepare("SELECT ts, author, kind FROM timeline_events WHERE source = 'zoom' ORDER BY ts")
+            .unwrap();
+        stmt.query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+    };
+    assert_eq!(rows[0], ("2026-01-05T14:00:05.12Z".to_string(), Some("Alice Smith".to_string()), "transcript".to_string()));
+    assert_eq!(rows[1].0, "2026-01-05T14:01:02Z");
+
+    let again = ingest_zoom_vtt(&mut conn, Some(summary.incident_id), None, "2026-01-05T09:00:00-05:00", VTT)
+        .expect("reingest");
+    assert_eq!(again.inserted_events, 0);
+    assert_eq!(again.skipped_duplicates, 3);
+}
+
+#[test]
+fn rejects_non_vtt_and_bad_call_start() {
+    let mut conn = empty_db().expect("db");
+
+    let err = parse_zoom_vtt("hello").unwrap_err();
+    assert_eq!(err.code, "INGEST_ZOOM_VTT_PARSE_FAILED");
+    let err = ingest_zoom_vtt(&mut conn, None, Some("X"), "yesterday", VTT).unwrap_err();
+    assert_eq!(err.code, "INGEST_ZOOM_VTT_CALL_START_INVALID");
+}
@@ -0,0 +1,218 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::aliases::{upsert_entity_alias, AliasKind, EntityAliasInput};
+use qir_core::freeze::freeze_quarter;
+use qir_core::interchange::{
+    build_interchange_document, export_interchange, import_interchange, interchange_capabilities,
+    negotiate_interchange_version, parse_interchange_document, read_interchange_document, INTERCHANGE_SCHEMA,
+};
+use qir_core::repo::{list_incidents, list_timeline_events};
+use qir_core::severity::{upsert_severity_mapping, SeverityMappingInput};
+use qir_core::testing::{db_with_incidents, empty_db, IncidentBuilder};
+use rusqlite::Connection;
+
+fn seeded() -> Connection {
+    let conn = db_with_incidents(&[
+        IncidentBuilder::new(1, "Checkout errors")
+            .external_id("INC-1")
+            .severity("SEV1")
+            .vendor("Acme")
+            .start("2026-01-05T14:00:00Z")
+            .ack("2026-01-05T14:10:00Z")
+            .resolve("2026-01-05T16:00:00Z")
+            .build(),
+        IncidentBuilder::new(2, "Search latency").severity("SEV3").start("2026-02-10T09:00:00Z").build(),
+    ])
+    .expect("db");
+    conn.execute(
+        "INSERT INTO timeline_events(incident_id, source, ts, author, kind, text, raw_json, created_at)
+         VALUES (1, 'slack', '2026-01-05T14:05:00Z', 'alice', 'message', 'Rolled back', '{\"ts\":\"1\"}', '2026-04-01T00:00:00Z')",
+        [],
+    )
+    .expect("seed");
+    conn
+}
+
+#[test]
+fn capabilities_and_negotiation() {
+    let caps = interchange_capabilities();
+    assert_eq!(caps.schema, INTERCHANGE_SCHEMA);
+    assert_eq!(caps.supported_versions, vec![1]);
+    assert_eq!(negotiate_interchange_version(&[]).unwrap(), 1);
+    assert_eq!(negotiate_interchange_version(&[3, 1]).unwrap(), 1);
+    let err = negotiate_interchange_version(&[2, 3]).unwrap_err();
+    assert_eq!(err.code, "EXPORT_INTERCHANGE_VERSION_UNSUPPORTED");
+}
+
+#[test]
+fn round_trips_incidents_timelines_and_metrics() {
+    let source = seeded();
+    let dir = tempfile::tempdir().expect("tempdir");
+    let res = export_interchange(&source, dir.path(), &[1], "2026-04-02T09:00:00Z", "0.1.0").expect("export");
+    assert!(res.path.ends_with("IncidentReviewInterchange_2026_04_02T09_00_00Z.json"));
+    assert_eq!((res.schema_version, res.incident_count, res.timeline_event_count), (1, 2, 1));
+
+    let doc = read_interchange_document(std::path::Path::new(&res.path)).expect("read");
+    assert_eq!(doc.incidents[0].metrics.as_ref().unwrap().mttr_seconds, Some(7200));
+    assert_eq!(doc.incidents[0].timeline[0].text, "Rolled back");
+
+    let mut target = empty_db().expect("db");
+    let summary = import_interchange(&mut target, &doc, "2026-04-03T00:00:00Z").expect("import");
+    assert_eq!((summary.inserted_incidents, summary.inserted_timeline_events), (2, 1));
+    assert!(summary.skipped_existing.is_empty());
+    assert!(summary.import_warnings.is_empty());
+
+    let strip = |mut v: Vec<qir_core::domain::Incident>| {
+        v.iter_mut().for_each(|i| i.id = 0);
+        v
+    };
+    assert_eq!(strip(list_incidents(&target).unwrap()), strip(list_incidents(&source).unwrap()));
+    let events = list_timeline_events(&target).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].raw_json, None);
+
+    // Re-importing the same document leaves existing incidents alone.
+    let again = import_interchange(&mut target, &doc, "2026-04-04T00:00:00Z").expect("reimport");
+    assert_eq!(again.inserted_incidents, 0);
+    assert_eq!(again.skipped_existing, vec!["INC-1".to_string(), "fp-2".to_string()]);
+
+    let err = export_interchange(&source, dir.path(), &[], "2026-04-02T09:00:00Z", "0.1.0").unwrap_err();
+    assert_eq!(err.code, "EXPORT_INTERCHANGE_DEST_EXISTS");
+}
+
+#[test]
+fn rejects_foreign_schemas_and_newer_versions() {
+    let err = parse_interchange_document(r#"{"schema":"other.tool","schema_version":1}"#).unwrap_err();
+    assert_eq!(err.code, "INGEST_INTERCHANGE_SCHEMA_MISMATCH");
+
+    // A newer producer's body may not decode as v1; the version check must fire first.
+    let err = parse_interchange_document(
+        r#"{"schema":"incidentreview.interchange","schema_version":2,"incidents":{"changed":true}}"#,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "INGEST_INTERCHANGE_VERSION_UNSUPPORTED");
+    assert_eq!(err.details.as_deref(), Some("schema_version=2; supported=[1]"));
+
+    let err = parse_interchange_document("not json").unwrap_err();
+    assert_eq!(err.code, "INGEST_INTERCHANGE_DECODE_FAILED");
+}
+
+#[test]
+fn imports_documents_from_other_tools() {
+    // Minimal producer: no fingerprint, no metrics, unknown extra fields.
+    let doc = parse_interchange_document(
+        r#"{
+          "schema": "incidentreview.interchange",
+          "schema_version": 1,
+          "producer": {"name": "pager-bot", "version": "7"},
+          "exported_at": "2026-04-02T00:00:00Z",
+          "incidents": [
+            {"external_id": "PB-1", "title": "Disk full", "severity": "SEV2",
+             "timestamps": {"start": "2026-03-01T10:00:00Z", "resolve": "2026-03-01T11:00:00Z"},
+             "metrics": {"mttd_seconds": null, "it_awareness_lag_seconds": null, "mtta_seconds": null,
+                         "time_to_mitigation_seconds": null, "mttr_seconds": 60},
+             "timeline": [{"source": "pager-bot", "ts": "2026-03-01T10:01:00Z", "text": "Paged"}],
+             "x_pager_bot_rank": 4}
+          ]
+        }"#,
+    )
+    .expect("parse");
+    let mut conn = empty_db().expect("db");
+    let summary = import_interchange(&mut conn, &doc, "2026-04-03T00:00:00Z").expect("import");
+    assert_eq!(summary.inserted_incidents, 1);
+    assert_eq!(summary.import_warnings.len(), 1);
+    assert_eq!(summary.import_warnings[0].code, "INGEST_INTERCHANGE_METRICS_MISMATCH");
+
+    let incident = &list_incidents(&conn).unwrap()[0];
+    assert_eq!(incident.fingerprint.len(), 64);
+    assert_eq!(incident.resolve_ts.as_deref(), Some("2026-03-01T11:00:00Z"));
+
+    let rebuilt = build_interchange_document(&conn, &[], "2026-04-03T00:00:00Z", "0.1.0").expect("build");
+    assert_eq!(rebuilt.incidents[0].metrics.as_ref().unwrap().mttr_seconds, Some(3600));
+}
+
+#[test]
+fn frozen_quarters_severity_mappings_and_aliases_apply_on_import() {
+    let doc = parse_interchange_document(
+        r#"{
+          "schema": "incidentreview.interchange",
+          "schema_version": 1,
+          "producer": {"name": "pager-bot", "version": "7"},
+          "exported_at": "2026-04-04T00:00:00Z",
+          "incidents": [
+            {"external_id": "PB-1", "title": "Disk full", "severity": "P1", "vendor": "AWS",
+             "timestamps": {"start": "2026-03-01T10:00:00Z"}, "timeline": [{"source": "pager-bot", "text": "Paged"}]},
+            {"external_id": "PB-2", "title": "Queue lag", "severity": "p1", "vendor": "aws",
+             "timestamps": {"start": "2026-04-02T10:00:00Z"}},
+            {"external_id": "PB-3", "title": "DNS", "severity": "Blocker",
+             "timestamps": {"start": "2026-04-03T10:00:00Z"}}
+          ]
+        }"#,
+    )
+    .expect("parse");
+    let mut conn = empty_db().expect("db");
+    freeze_quarter(&mut conn, "Q1 2026", None, None).expect("freeze");
+    upsert_severity_mapping(
+        &conn,
+        &SeverityMappingInput {
+            source_value: "P1".to_string(),
+            severity: "SEV1".to_string(),
+        },
+    )
+    .expect("mapping");
+    upsert_entity_alias(
+        &conn,
+        &EntityAliasInput {
+            kind: AliasKind::Vendor,
+            alias: "AWS".to_string(),
+            canonical: "Amazon Web Services".to_string(),
+        },
+    )
+    .expect("alias");
+
+    let summary = import_interchange(&mut conn, &doc, "2026-04-05T00:00:00Z").expect("import");
+    assert_eq!((summary.inserted_incidents, summary.inserted_timeline_events), (2, 0));
+    assert_eq!(summary.skipped_frozen, vec!["PB-1".to_string()]);
+    let warnings: Vec<(String, Option<String>)> =
+        summary.import_warnings.iter().map(|w| (w.code.clone(), w.details.clone())).collect();
+    assert_eq!(
+        warnings,
+        vec![
+            (
+                "INGEST_INTERCHANGE_QUARTER_FROZEN".to_string(),
+                Some("incident=PB-1; quarter_label=Q1 2026".to_string())
+            ),
+            ("INGEST_SEVERITY_UNMAPPED".to_string(), Some("severity=Blocker; incident=PB-3".to_string())),
+        ]
+    );
+
+    let stored: Vec<(Option<String>, Option<String>, Option<String>)> = list_incidents(&conn)
+        .unwrap()
+        .into_iter()
+        .map(|i| (i.external_id, i.severity, i.vendor))
+        .collect();
+    assert_eq!(
+        stored,
+        vec![
+            (Some("PB-2".to_string()), Some("SEV1".to_string()), Some("Amazon Web Services".to_string())),
+            (Some("PB-3".to_string()), None, None),
+        ]
+    );
+}
+
+#[test]
+fn invalid_documents_import_nothing() {
+    let mut doc = build_interchange_document(&seeded(), &[], "2026-04-02T00:00:00Z", "0.1.0").expect("build");
+    doc.incidents[1].timestamps.start = Some("Feb 10, 9am".to_string());
+    let mut conn = empty_db().expect("db");
+    let err = import_interchange(&mut conn, &doc, "2026-04-03T00:00:00Z").unwrap_err();
+    assert_eq!(err.code, "INGEST_INTERCHANGE_INVALID");
+    assert_eq!(err.details.as_deref(), Some("incident=1; field=start; value=Feb 10, 9am"));
+
+    doc.incidents[1] = doc.incidents[0].clone();
+    let err = import_interchange(&mut conn, &doc, "2026-04-03T00:00:00Z").unwrap_err();
+    assert_eq!(err.code, "INGEST_INTERCHANGE_INVALID");
+    assert!(list_incidents(&conn).unwrap().is_empty());
+}
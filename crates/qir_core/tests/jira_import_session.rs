@@ -0,0 +1,96 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::import_history::list_import_history;
+use qir_core::ingest::jira_session::{import_jira_csv_session, JiraImportSessionFile, JIRA_SESSION_IMPORT_SOURCE};
+use qir_core::profiles::jira::{upsert_profile, JiraMappingProfileUpsert};
+use qir_core::progress::Progress;
+use qir_core::repo::count_incidents;
+use qir_core::testing::{empty_db, fixture_mapping};
+
+fn file(name: &str, csv_text: &str) -> JiraImportSessionFile {
+    JiraImportSessionFile {
+        name: name.to_string(),
+        csv_text: csv_text.to_string(),
+    }
+}
+
+#[test]
+fn imports_all_files_with_combined_summary_and_one_history_entry() {
+    let mut conn = empty_db().expect("db");
+    let profile = upsert_profile(
+        &mut conn,
+        JiraMappingProfileUpsert {
+            id: None,
+            name: "Quarterly".to_string(),
+            mapping: fixture_mapping(),
+        },
+    )
+    .expect("profile");
+
+    let files = vec![
+        file(
+            "payments.csv",
+            "Key,Summary,Severity,StartTs,ResolveTs\nPAY-1,Checkout errors,SEV2,2026-01-05T14:00:00Z,2026-01-05T15:00:00Z\n",
+        ),
+        file(
+            "identity.csv",
+            "Key,Summary,Severity,StartTs,ResolveTs\nID-1,Login outage,SEV1,2026-02-01T10:00:00Z,2026-02-01T11:00:00Z\nID-1,Login outage again,SEV1,2026-02-01T10:00:00Z,\n",
+        ),
+        file(
+            "search.csv",
+            "Key,Summary,Severity,StartTs,ResolveTs,Labels\nSR-1,,SEV3,2026-03-01T10:00:00Z,,x\nSR-2,Slow search,SEV3,2026-03-02T10:00:00Z,,y\n",
+        ),
+    ];
+
+    let summary =
+        import_jira_csv_session(&mut conn, profile.id, &files, &mut Progress::noop("test")).expect("session");
+    assert_eq!(summary.inserted, 3);
+    assert_eq!(summary.skipped, 2);
+    assert_eq!(summary.files.len(), 3);
+    assert_eq!(summary.conflicts.len(), 1);
+    assert_eq!(summary.conflicts[0].file, "identity.csv");
+    assert_eq!(summary.conflicts[0].conflict.external_id.as_deref(), Some("ID-1"));
+
+    let codes: Vec<&str> = summary.warnings.iter().map(|w| w.code.as_str()).collect();
+    assert_eq!(codes, vec!["INGEST_JIRA_SESSION_HEADERS_DIFFER", "INGEST_MISSING_TITLE"]);
+    assert!(summary.warnings[1].details.as_deref().unwrap().starts_with("file=search.csv"));
+    assert_eq!(count_incidents(&conn).expect("count"), 3);
+
+    let history = list_import_history(&conn, 10).expect("history");
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].id, summary.import_history_id);
+    assert_eq!(history[0].source, JIRA_SESSION_IMPORT_SOURCE);
+    assert_eq!(history[0].profile_id, Some(profile.id));
+    assert_eq!(history[0].file_names, vec!["payments.csv", "identity.csv", "search.csv"]);
+    assert_eq!((history[0].inserted, history[0].skipped, history[0].conflicts), (3, 2, 1));
+}
+
+#[test]
+fn rejects_empty_and_duplicate_file_sessions() {
+    let mut conn = empty_db().expect("db");
+    let profile = upsert_profile(
+        &mut conn,
+        JiraMappingProfileUpsert {
+            id: None,
+            name: "Quarterly".to_string(),
+            mapping: fixture_mapping(),
+        },
+    )
+    .expect("profile");
+
+    let err = import_jira_csv_session(&mut conn, profile.id, &[], &mut Progress::noop("test")).unwrap_err();
+    assert_eq!(err.code, "INGEST_JIRA_SESSION_EMPTY");
+
+    let csv = "Key,Summary\nA-1,One\n";
+    let err = import_jira_csv_session(
+        &mut conn,
+        profile.id,
+        &[file("a.csv", csv), file("a.csv", csv)],
+        &mut Progress::noop("test"),
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "INGEST_JIRA_SESSION_DUPLICATE_FILE");
+    assert!(list_import_history(&conn, 10).expect("history").is_empty());
+}
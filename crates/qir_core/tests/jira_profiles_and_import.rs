@@ -11,6 +11,7 @@ fn demo_mapping() -> JiraCsvMapping {
         title: "Summary".to_string(),
         description: Some("Description".to_string()),
         severity: Some("Severity".to_string()),
+        initial_severity: None,
         detection_source: None,
         vendor: None,
         service: None,
@@ -22,6 +23,9 @@ fn demo_mapping() -> JiraCsvMapping {
         ack_ts: Some("AckTs".to_string()),
         mitigate_ts: None,
         resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
     }
 }
 
@@ -0,0 +1,67 @@
+use qir_core::analytics::build_incident_lifecycle_funnel;
+use qir_core::db;
+use rusqlite::Connection;
+
+const T: &str = "2026-01-06T10:00:00Z";
+
+/// `ts` is start, observed, aware, ack, mitigate, resolve.
+fn insert(conn: &Connection, id: i64, ts: [Option<&str>; 6], ack_raw: Option<&str>) {
+    conn.execute(
+        "INSERT INTO incidents(id, fingerprint, title, start_ts, first_observed_ts, it_awareness_ts, ack_ts,
+                               mitigate_ts, resolve_ts, ack_ts_raw, ingested_at)
+         VALUES (?1, ?2, 'Incident', ?3, ?4, ?5, ?6, ?7, ?8, ?9, '2026-07-01T00:00:00Z')",
+        rusqlite::params![id, format!("fp-{id}"), ts[0], ts[1], ts[2], ts[3], ts[4], ts[5], ack_raw],
+    )
+    .expect("insert");
+}
+
+fn setup() -> Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    let t = Some(T);
+    insert(&conn, 1, [t, t, t, t, t, t], None);
+    insert(&conn, 2, [t, t, t, None, t, t], Some("yesterday-ish"));
+    insert(&conn, 3, [t, t, None, None, t, t], None);
+    insert(&conn, 4, [t, None, t, None, None, t], None);
+    insert(&conn, 5, [Some("2026-04-07T10:00:00Z"), None, None, None, None, None], None);
+    conn
+}
+
+#[test]
+fn funnel_counts_populated_and_continuous_stages() {
+    let conn = setup();
+    let f = build_incident_lifecycle_funnel(&conn, None).expect("funnel");
+    assert_eq!(f.incident_count, 5);
+    let stages: Vec<&str> = f.stages.iter().map(|s| s.stage.as_str()).collect();
+    assert_eq!(
+        stages,
+        vec!["start_ts", "first_observed_ts", "it_awareness_ts", "ack_ts", "mitigate_ts", "resolve_ts"]
+    );
+
+    let populated: Vec<i64> = f.stages.iter().map(|s| s.populated_count).collect();
+    assert_eq!(populated, vec![5, 3, 3, 1, 3, 4]);
+    let continuous: Vec<i64> = f.stages.iter().map(|s| s.continuous_count).collect();
+    assert_eq!(continuous, vec![5, 3, 2, 1, 1, 1]);
+    let drops: Vec<i64> = f.stages.iter().map(|s| s.drop_off_count).collect();
+    assert_eq!(drops, vec![0, 2, 1, 1, 0, 0]);
+
+    let ack = &f.stages[3];
+    assert_eq!(ack.missing_incident_ids, vec![2, 3, 4, 5]);
+    assert_eq!((ack.missing_count, ack.unparseable_count), (4, 1));
+    assert_eq!(f.weakest_stage.as_deref(), Some("first_observed_ts"));
+}
+
+#[test]
+fn funnel_respects_quarter_scope() {
+    let conn = setup();
+    let q2 = build_incident_lifecycle_funnel(&conn, Some("Q2 2026")).expect("q2");
+    assert_eq!(q2.quarter_label.as_deref(), Some("Q2 2026"));
+    assert_eq!(q2.incident_count, 1);
+    assert_eq!(q2.stages[0].populated_count, 1);
+    assert_eq!(q2.stages[1].missing_incident_ids, vec![5]);
+    assert_eq!(q2.weakest_stage.as_deref(), Some("first_observed_ts"));
+
+    let empty = build_incident_lifecycle_funnel(&conn, Some("Q3 2026")).expect("q3");
+    assert_eq!(empty.incident_count, 0);
+    assert!(empty.weakest_stage.is_none());
+}
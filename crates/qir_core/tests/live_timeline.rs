@@ -0,0 +1,84 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::live::{quick_capture, reconcile_live_notes, timeline_append_now, QuickCaptureInput, LIVE_SOURCE};
+use qir_core::repo::list_timeline_events_for_incident;
+use qir_core::testing::empty_db;
+use time::format_description::well_known::Rfc3339;
+use time::{Duration, OffsetDateTime};
+
+fn shifted(ts: &str, seconds: i64) -> String {
+    (OffsetDateTime::parse(ts, &Rfc3339).unwrap() + Duration::seconds(seconds))
+        .format(&Rfc3339)
+        .unwrap()
+}
+
+fn insert_imported(conn: &rusqlite::Connection, incident_id: i64, source: &str, ts: &str, text: &str) -> i64 {
+    conn.execute(
+        "INSERT INTO timeline_events(incident_id, source, ts, author, kind, text, raw_json, created_at)
+         VALUES (?1, ?2, ?3, 'U123', 'message', ?4, NULL, '2026-01-01T00:00:00Z')",
+        rusqlite::params![incident_id, source, ts, text],
+    )
+    .unwrap();
+    conn.last_insert_rowid()
+}
+
+#[test]
+fn appends_notes_stamped_now_and_suppresses_duplicates_of_imports() {
+    let conn = empty_db().expect("db");
+    let incident = quick_capture(
+        &conn,
+        QuickCaptureInput {
+            title: "Checkout errors".to_string(),
+            severity: None,
+            service: None,
+            started_at: None,
+        },
+    )
+    .expect("capture");
+
+    let before = OffsetDateTime::now_utc() - Duration::seconds(1);
+    let rollback = timeline_append_now(&conn, incident.id, "  Rolling back deploy 412 ", Some("Decision")).expect("note");
+    assert_eq!(rollback.source, LIVE_SOURCE);
+    assert_eq!(rollback.kind.as_deref(), Some("decision"));
+    assert_eq!(rollback.text, "Rolling back deploy 412");
+    let ts = rollback.ts.clone().expect("ts");
+    assert!(OffsetDateTime::parse(&ts, &Rfc3339).unwrap() >= before);
+
+    let paged = timeline_append_now(&conn, incident.id, "Paged the database on-call", None).expect("note");
+    assert_eq!(paged.kind.as_deref(), Some("note"));
+
+    // Slack export imported afterwards: one message restates the rollback a minute later,
+    // another says the same words but an hour later and is a different moment.
+    let slack_rollback = insert_imported(&conn, incident.id, "slack", &shifted(&ts, 60), "rolling back deploy 412 now");
+    insert_imported(&conn, incident.id, "slack", &shifted(&ts, 3600), "Paged the database on-call");
+
+    let summary = reconcile_live_notes(&conn, incident.id).expect("reconcile");
+    assert_eq!(summary.checked, 2);
+    assert_eq!(summary.suppressed.len(), 1);
+    assert_eq!(summary.suppressed[0].live_event_id, rollback.id);
+    assert_eq!(summary.suppressed[0].matched_event_id, slack_rollback);
+    assert_eq!(summary.suppressed[0].matched_source, "slack");
+
+    let timeline: Vec<(String, String)> = list_timeline_events_for_incident(&conn, incident.id)
+        .expect("timeline")
+        .into_iter()
+        .map(|e| (e.source, e.text))
+        .collect();
+    assert_eq!(timeline.len(), 3);
+    assert!(!timeline.contains(&(LIVE_SOURCE.to_string(), "Rolling back deploy 412".to_string())));
+
+    let again = reconcile_live_notes(&conn, incident.id).expect("reconcile again");
+    assert_eq!((again.checked, again.suppressed.len()), (1, 0));
+}
+
+#[test]
+fn rejects_blank_notes_and_unknown_incidents() {
+    let conn = empty_db().expect("db");
+
+    let err = timeline_append_now(&conn, 42, "Something", None).unwrap_err();
+    assert_eq!(err.code, "DB_NOT_FOUND");
+    let err = timeline_append_now(&conn, 42, "   ", None).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_QUICK_CAPTURE_INVALID");
+}
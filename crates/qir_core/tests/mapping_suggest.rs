@@ -0,0 +1,41 @@
+use pretty_assertions::assert_eq;
+
+use qir_core::profiles::suggest::suggest_jira_mapping;
+
+#[test]
+fn suggests_jira_export_columns_from_synonyms() {
+    let csv = "Issue key,Summary,Priority,Original Priority,Created,Resolved,Components\n\
+INC-1,Checkout errors,P1,P2,2026-01-05T14:00:00Z,2026-01-05T15:00:00Z,payments\n";
+    let suggestion = suggest_jira_mapping(csv).expect("suggest");
+
+    let m = &suggestion.mapping;
+    assert_eq!(m.external_id.as_deref(), Some("Issue key"));
+    assert_eq!(m.title, "Summary");
+    assert_eq!(m.severity.as_deref(), Some("Priority"));
+    assert_eq!(m.initial_severity.as_deref(), Some("Original Priority"));
+    assert_eq!(m.first_observed_ts.as_deref(), Some("Created"));
+    assert_eq!(m.resolve_ts.as_deref(), Some("Resolved"));
+    assert_eq!(m.service.as_deref(), Some("Components"));
+    assert_eq!(m.start_ts, None);
+
+    let severity = suggestion.fields.iter().find(|f| f.field == "severity").unwrap();
+    assert_eq!(severity.confidence, 1.0);
+    let start = suggestion.fields.iter().find(|f| f.field == "start_ts").unwrap();
+    assert_eq!((start.column.as_deref(), start.confidence), (None, 0.0));
+}
+
+#[test]
+fn sampled_values_break_ties_that_header_names_cannot() {
+    let csv = "Ref,Incident Name,Tier,Closed At,Closed\n\
+A-1,Login outage,P2,2026-02-01 10:45,yes\n\
+A-2,Search latency,SEV-3,2026-02-02 09:00,no\n";
+    let suggestion = suggest_jira_mapping(csv).expect("suggest");
+
+    assert_eq!(suggestion.mapping.title, "Incident Name");
+    // "Tier" matches no synonym, but P2 / SEV-3 values read as severities.
+    let severity = suggestion.fields.iter().find(|f| f.field == "severity").unwrap();
+    assert_eq!(severity.column.as_deref(), Some("Tier"));
+    assert_eq!(severity.reason.as_deref(), Some("values"));
+    // A yes/no column is never offered as a timestamp.
+    assert_eq!(suggestion.mapping.resolve_ts.as_deref(), Some("Closed At"));
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "testing")]
+
+use qir_core::ingest::jira_csv::ingest_jira_csv;
+use qir_core::ingest::slack_transcript::ingest_slack_transcript_text;
+use qir_core::normalize::markup::{normalize_jira_description, normalize_slack_text};
+use qir_core::testing::{empty_db, fixture_mapping};
+
+#[test]
+fn jira_wiki_markup_becomes_commonmark() {
+    let wiki = "h1. Impact\n\
+                *Checkout* failed for _some_ users; see [runbook|https://wiki/rb] and {{payments-api}}.\n\
+                * first\n\
+                ** nested\n\
+                # step\n\
+                {code:sql}\n\
+                SELECT *_x_* FROM t;\n\
+                {code}";
+    let out = normalize_jira_description(wiki);
+    assert_eq!(
+        out.text,
+        "# Impact\n\
+         **Checkout** failed for *some* users; see [runbook](https://wiki/rb) and `payments-api`.\n\
+         - first\n  - nested\n1. step\n```sql\nSELECT *_x_* FROM t;\n```"
+    );
+    assert_eq!(out.raw.as_deref(), Some(wiki));
+}
+
+#[test]
+fn jira_adf_document_becomes_commonmark() {
+    let adf = r#"{"type":"doc","version":1,"content":[
+        {"type":"heading","attrs":{"level":2},"content":[{"type":"text","text":"Summary"}]},
+        {"type":"paragraph","content":[{"type":"text","text":"DB "},{"type":"text","text":"failover","marks":[{"type":"strong"}]}]},
+        {"type":"bulletList","content":[{"type":"listItem","content":[{"type":"paragraph","content":[{"type":"text","text":"page on-call"}]}]}]}
+    ]}"#;
+    let out = normalize_jira_description(adf);
+    assert_eq!(out.text, "## Summary\n\nDB **failover**\n\n- page on-call");
+    assert!(out.raw.is_some());
+}
+
+#[test]
+fn slack_mrkdwn_becomes_commonmark_and_plain_text_keeps_no_raw() {
+    let out = normalize_slack_text(
+        "*deploy* rolled back by <@U123|maria> &amp; _team_ — see <https://status.example.com|status page>\n• retry ~later~",
+    );
+    assert_eq!(
+        out.text,
+        "**deploy** rolled back by @maria & *team* — see [status page](https://status.example.com)\n- retry ~~later~~"
+    );
+
+    let plain = normalize_slack_text("all clear, snake_case_name stays");
+    assert_eq!(plain.text, "all clear, snake_case_name stays");
+    assert_eq!(plain.raw, None);
+}
+
+#[test]
+fn ingest_stores_normalized_text_and_preserves_raw_markup() {
+    let mut conn = empty_db().expect("db");
+
+    let csv = "Key,Summary,Description\n\
+               INC-1,Outage,\"h2. Impact\n*Checkout* down\"\n\
+               INC-2,Plain,Plain text\n";
+    let summary = ingest_jira_csv(&mut conn, csv, &fixture_mapping()).expect("ingest");
+    assert_eq!(summary.inserted, 2);
+
+    let row = |key: &str| -> (Option<String>, Option<String>) {
+        conn.query_row(
+            "SELECT description, description_raw FROM incidents WHERE external_id = ?1",
+            [key],
+            |r| Ok((r.get(0)?, r.get(1)?)),
+        )
+        .expect("incident")
+    };
+    assert_eq!(
+        row("INC-1"),
+        (
+            Some("## Impact\n**Checkout** down".to_string()),
+            Some("h2. Impact\n*Checkout* down".to_string())
+        )
+    );
+    assert_eq!(row("INC-2"), (Some("Plain text".to_string()), None));
+
+    let slack = ingest_slack_transcript_text(
+        &mut conn,
+        None,
+        Some("Slack Shell"),
+        "2026-01-05T12:00:00Z - alice: *rollback* started",
+    )
+    .expect("slack");
+    let (text, raw): (String, Option<String>) = conn
+        .query_row(
+            "SELECT text, text_raw FROM timeline_events WHERE incident_id = ?1",
+            [slack.incident_id],
+            |r| Ok((r.get(0)?, r.get(1)?)),
+        )
+        .expect("event");
+    assert_eq!(text, "**rollback** started");
+    assert_eq!(raw.as_deref(), Some("*rollback* started"));
+}
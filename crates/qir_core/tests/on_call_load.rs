@@ -0,0 +1,141 @@
+use qir_core::analytics::{build_on_call_load, save_business_hours, BusinessHoursConfig, HoursClass};
+use qir_core::db;
+use qir_core::report::{generate_qir_report, OnCallReportMode, QirReportOptions};
+use qir_core::responders::{add_responder, list_responders, remove_responder, AddResponderInput};
+use rusqlite::Connection;
+
+fn insert(conn: &Connection, id: i64, start: &str, resolve: Option<&str>) {
+    conn.execute(
+        "INSERT INTO incidents(id, external_id, fingerprint, title, start_ts, resolve_ts, ingested_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, '2026-07-01T00:00:00Z')",
+        rusqlite::params![id, format!("INC-{id}"), format!("fp-{id}"), format!("Incident {id}"), start, resolve],
+    )
+    .expect("insert");
+}
+
+fn engage(conn: &Connection, incident_id: i64, person: &str, engaged: &str, released: Option<&str>) -> i64 {
+    add_responder(
+        conn,
+        AddResponderInput {
+            incident_id,
+            person: person.to_string(),
+            role: None,
+            engaged_at: engaged.to_string(),
+            released_at: released.map(str::to_string),
+        },
+    )
+    .expect("responder")
+    .id
+}
+
+/// 1 and 2 are Q1 2026, 3 is Q2 2026 and still open.
+fn setup() -> Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    insert(&conn, 1, "2026-01-06T10:00:00Z", Some("2026-01-06T12:00:00Z"));
+    insert(&conn, 2, "2026-01-10T22:00:00Z", Some("2026-01-11T01:00:00Z"));
+    insert(&conn, 3, "2026-04-07T20:00:00Z", None);
+    // Tuesday business hours, released early.
+    engage(&conn, 1, "alice", "2026-01-06T10:00:00Z", Some("2026-01-06T11:00:00Z"));
+    engage(&conn, 1, "bob", "2026-01-06T10:30:00Z", None);
+    // Saturday night; both run to resolution.
+    engage(&conn, 2, "alice", "2026-01-10T22:00:00Z", None);
+    engage(&conn, 2, "alice", "2026-01-11T00:00:00Z", None);
+    // Tuesday evening on an open incident.
+    engage(&conn, 3, "bob", "2026-04-07T20:00:00Z", None);
+    conn
+}
+
+#[test]
+fn responders_round_trip_and_validate() {
+    let conn = setup();
+    let listed = list_responders(&conn, Some(1)).expect("list");
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed[0].person, "alice");
+
+    let id = engage(&conn, 1, " carol ", "2026-01-06T12:00:00+02:00", None);
+    let carol = list_responders(&conn, Some(1)).expect("list").remove(1);
+    assert_eq!((carol.id, carol.person.as_str()), (id, "carol"));
+    assert_eq!(carol.engaged_at, "2026-01-06T10:00:00Z");
+    remove_responder(&conn, id).expect("remove");
+    assert_eq!(remove_responder(&conn, id).unwrap_err().code, "DB_NOT_FOUND");
+
+    let bad = AddResponderInput {
+        incident_id: 1,
+        person: "dave".to_string(),
+        role: None,
+        engaged_at: "2026-01-06T10:00:00Z".to_string(),
+        released_at: Some("2026-01-06T09:00:00Z".to_string()),
+    };
+    assert_eq!(add_responder(&conn, bad.clone()).unwrap_err().code, "VALIDATION_RESPONDER_INVALID");
+    let missing = AddResponderInput { incident_id: 99, ..bad };
+    assert_eq!(add_responder(&conn, missing).unwrap_err().code, "DB_NOT_FOUND");
+}
+
+#[test]
+fn business_hours_classify_and_validate() {
+    let cfg = BusinessHoursConfig::default();
+    assert_eq!(cfg.classify("2026-01-06T10:00:00Z"), Some(HoursClass::BusinessHours));
+    assert_eq!(cfg.classify("2026-01-06T17:00:00Z"), Some(HoursClass::AfterHours));
+    assert_eq!(cfg.classify("2026-01-10T10:00:00Z"), Some(HoursClass::Weekend));
+    assert_eq!(cfg.classify("not a time"), None);
+
+    let shifted = BusinessHoursConfig {
+        utc_offset_minutes: -5 * 60,
+        ..BusinessHoursConfig::default()
+    };
+    // 2026-01-06T20:00Z is 15:00 at UTC-05:00.
+    assert_eq!(shifted.classify("2026-01-06T20:00:00Z"), Some(HoursClass::BusinessHours));
+
+    let conn = setup();
+    let bad = BusinessHoursConfig {
+        start_hour: 18,
+        ..BusinessHoursConfig::default()
+    };
+    assert_eq!(save_business_hours(&conn, &bad).unwrap_err().code, "VALIDATION_BUSINESS_HOURS_INVALID");
+}
+
+#[test]
+fn per_person_load_for_quarter() {
+    let conn = setup();
+    let all = build_on_call_load(&conn, None, false).expect("load");
+    let people: Vec<&str> = all.responders.iter().map(|r| r.person.as_str()).collect();
+    assert_eq!(people, vec!["alice", "bob"]);
+
+    let alice = &all.responders[0];
+    assert_eq!((alice.incident_count, alice.engagement_count), (2, 3));
+    // 1h on incident 1, then 3h and 1h until incident 2 resolved.
+    assert_eq!(alice.time_in_incident_seconds, 5 * 3600);
+    assert_eq!(
+        (alice.business_hours_engagements, alice.after_hours_engagements, alice.weekend_engagements),
+        (1, 0, 2)
+    );
+
+    let bob = &all.responders[1];
+    assert_eq!((bob.incident_count, bob.time_in_incident_seconds), (2, 90 * 60));
+    assert_eq!((bob.after_hours_engagements, bob.open_engagement_count), (1, 1));
+
+    let q2 = build_on_call_load(&conn, Some("Q2 2026"), true).expect("q2");
+    assert_eq!(q2.quarter_label.as_deref(), Some("Q2 2026"));
+    assert_eq!(q2.responders.len(), 1);
+    assert_eq!(q2.responders[0].person, "Responder 1");
+    assert_eq!(q2.responders[0].engagement_count, 1);
+}
+
+#[test]
+fn report_section_is_optional_and_anonymizable() {
+    let conn = setup();
+    let mut opts = QirReportOptions {
+        quarter_label: Some("Q1 2026".to_string()),
+        ..QirReportOptions::default()
+    };
+    let plain = generate_qir_report(&conn, &opts, "2026-07-01T00:00:00Z", "test").expect("report");
+    assert!(!plain.markdown.contains("## On-call load"));
+
+    opts.on_call = Some(OnCallReportMode::Anonymized);
+    let report = generate_qir_report(&conn, &opts, "2026-07-01T00:00:00Z", "test").expect("report");
+    assert!(report.markdown.contains("## On-call load"));
+    assert!(report.markdown.contains("| Responder 1 | 2 | 3 | 5h 0m | 0 | 2 | 0 |"));
+    assert!(!report.markdown.contains("alice"));
+    assert_eq!(report.manifest.on_call, Some(OnCallReportMode::Anonymized));
+}
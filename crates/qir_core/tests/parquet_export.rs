@@ -0,0 +1,63 @@
+use pretty_assertions::assert_eq;
+
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use qir_core::columnar::{export_parquet_dataset, ParquetExportDescriptor, PARQUET_SCHEMA_VERSION};
+use qir_core::db;
+use qir_core::demo::seed_demo_dataset;
+
+fn seeded() -> rusqlite::Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    seed_demo_dataset(&mut conn).expect("seed");
+    conn
+}
+
+fn parquet_row_count(path: &std::path::Path) -> usize {
+    let file = std::fs::File::open(path).expect("open parquet");
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+        .expect("reader builder")
+        .build()
+        .expect("reader");
+    reader.map(|b| b.expect("batch").num_rows()).sum()
+}
+
+#[test]
+fn parquet_export_writes_tables_and_descriptor() {
+    let conn = seeded();
+    let dir = tempfile::tempdir().expect("tempdir");
+
+    let res = export_parquet_dataset(&conn, dir.path(), "2026-02-01T00:00:00Z", "0.1.0").expect("export");
+    assert_eq!(res.incident_count, 40);
+
+    let export_dir = std::path::Path::new(&res.export_dir);
+    let descriptor: ParquetExportDescriptor = serde_json::from_slice(
+        &std::fs::read(export_dir.join("schema.json")).expect("read descriptor"),
+    )
+    .expect("decode descriptor");
+    assert_eq!(descriptor, res.descriptor);
+    assert_eq!(descriptor.schema_version, PARQUET_SCHEMA_VERSION);
+
+    let names: Vec<&str> = descriptor.tables.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["incidents", "warnings"]);
+
+    let incidents = &descriptor.tables[0];
+    assert!(incidents.columns.iter().any(|c| c.name == "mttr_seconds" && c.data_type == "int64"));
+    assert_eq!(parquet_row_count(&export_dir.join(&incidents.filename)), 40);
+
+    let warnings = &descriptor.tables[1];
+    assert_eq!(warnings.row_count, res.warning_count);
+    assert_eq!(
+        parquet_row_count(&export_dir.join(&warnings.filename)) as i64,
+        res.warning_count
+    );
+}
+
+#[test]
+fn parquet_export_refuses_existing_destination() {
+    let conn = seeded();
+    let dir = tempfile::tempdir().expect("tempdir");
+    export_parquet_dataset(&conn, dir.path(), "2026-02-01T00:00:00Z", "0.1.0").expect("first export");
+    let err = export_parquet_dataset(&conn, dir.path(), "2026-02-01T00:00:00Z", "0.1.0")
+        .expect_err("second export");
+    assert_eq!(err.code, "EXPORT_PARQUET_DEST_EXISTS");
+}
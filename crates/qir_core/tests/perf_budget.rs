@@ -0,0 +1,44 @@
+#![cfg(feature = "bench-data")]
+// Generous wall-clock budgets that catch order-of-magnitude regressions without flaking.
+// Budget checks are #[ignore]d (debug builds on shared CI vary too much); run them with:
+//   cargo test -p qir_core --release --features bench-data -- --ignored
+
+use std::time::{Duration, Instant};
+
+use pretty_assertions::assert_eq;
+
+use qir_core::analytics::build_dashboard_payload_v2;
+use qir_core::bench_data::{generate_jira_csv, seed_bench_dataset};
+use qir_core::report::generate_qir_markdown;
+use qir_core::testing::empty_db;
+
+fn seeded(n: usize) -> rusqlite::Connection {
+    let mut conn = empty_db().expect("db");
+    let summary = seed_bench_dataset(&mut conn, n, 42).expect("seed");
+    assert_eq!(summary.inserted, n);
+    conn
+}
+
+#[test]
+fn generator_is_deterministic() {
+    assert_eq!(generate_jira_csv(500, 7), generate_jira_csv(500, 7));
+    assert_ne!(generate_jira_csv(500, 7), generate_jira_csv(500, 8));
+    assert_eq!(generate_jira_csv(500, 7).lines().count(), 501);
+}
+
+#[test]
+#[ignore]
+fn ten_thousand_incidents_within_budget() {
+    let started = Instant::now();
+    let conn = seeded(10_000);
+    assert!(started.elapsed() < Duration::from_secs(30), "import took {:?}", started.elapsed());
+
+    let started = Instant::now();
+    let dash = build_dashboard_payload_v2(&conn).expect("dashboard");
+    assert_eq!(dash.incident_count, 10_000);
+    assert!(started.elapsed() < Duration::from_secs(5), "dashboard took {:?}", started.elapsed());
+
+    let started = Instant::now();
+    generate_qir_markdown(&conn).expect("report");
+    assert!(started.elapsed() < Duration::from_secs(10), "report took {:?}", started.elapsed());
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::profiles::check::{check_profile_against_csv, ProfileColumnStatus};
+use qir_core::profiles::jira::{upsert_profile, JiraMappingProfileUpsert};
+use qir_core::testing::{empty_db, fixture_mapping};
+
+#[test]
+fn reports_missing_and_renamed_columns_with_suggestions() {
+    let mut conn = empty_db().expect("db");
+    let profile = upsert_profile(
+        &mut conn,
+        JiraMappingProfileUpsert {
+            id: None,
+            name: "Jira 2025".to_string(),
+            mapping: fixture_mapping(),
+        },
+    )
+    .expect("profile");
+
+    let headers = "Key,Summary,Description,Severity,InitialSeverity,DetectionSource,Vendor,Service,ImpactPct,\
+                   ServiceHealthPct,StartTs,FirstObservedTs,ItAwarenessTs,AckTs,MitigateTs,ResolveTs\n";
+    let ok = check_profile_against_csv(&conn, profile.id, headers).expect("check");
+    assert!(ok.ok);
+
+    // The new export renamed three columns and dropped the resolution column.
+    let csv = "Issue key,Summary,Custom field (Severity),start ts,Labels\n";
+    let check = check_profile_against_csv(&conn, profile.id, csv).expect("check");
+    assert!(!check.ok);
+    let by_field = |f: &str| check.columns.iter().find(|c| c.field == f).unwrap();
+    assert_eq!(by_field("title").status, ProfileColumnStatus::Ok);
+    assert_eq!(by_field("start_ts").status, ProfileColumnStatus::Renamed);
+    assert_eq!(by_field("start_ts").suggestion.as_deref(), Some("start ts"));
+    assert_eq!(by_field("severity").status, ProfileColumnStatus::Missing);
+    assert_eq!(by_field("severity").suggestion.as_deref(), Some("Custom field (Severity)"));
+    assert_eq!(by_field("external_id").suggestion.as_deref(), Some("Issue key"));
+    assert_eq!(by_field("resolve_ts").suggestion, None);
+
+    assert_eq!(check.suggested_mapping.start_ts.as_deref(), Some("start ts"));
+    assert_eq!(check.suggested_mapping.severity.as_deref(), Some("Custom field (Severity)"));
+    assert_eq!(check.suggested_mapping.resolve_ts.as_deref(), Some("ResolveTs"));
+}
@@ -0,0 +1,183 @@
+use std::sync::Mutex;
+
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+use qir_core::backup::create_backup_with_progress;
+use qir_core::db;
+use qir_core::ingest::jira_csv::{import_jira_csv_with_progress, JiraCsvMapping};
+use qir_core::progress::{
+    CancelToken, Progress, ProgressCounts, ProgressEvent, STAGE_COMPLETED, STAGE_FAILED, STAGE_STARTED,
+};
+use qir_core::sanitize::export_sanitized_dataset_with_progress;
+
+fn mapping() -> JiraCsvMapping {
+    JiraCsvMapping {
+        external_id: Some("Key".to_string()),
+        title: "Summary".to_string(),
+        description: None,
+        severity: Some("Severity".to_string()),
+        initial_severity: None,
+        detection_source: None,
+        vendor: None,
+        service: None,
+        impact_pct: None,
+        service_health_pct: None,
+        start_ts: Some("StartTs".to_string()),
+        first_observed_ts: None,
+        it_awareness_ts: None,
+        ack_ts: None,
+        mitigate_ts: None,
+        resolve_ts: None,
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
+    }
+}
+
+fn assert_well_formed(events: &[ProgressEvent], operation: &str, last_stage: &str) {
+    assert!(!events.is_empty());
+    assert_eq!(events[0].stage, STAGE_STARTED);
+    assert_eq!(events.last().unwrap().stage, last_stage);
+    let mut prev = 0u8;
+    for ev in events {
+        assert_eq!(ev.operation, operation);
+        assert_eq!(ev.operation_id, "op-1");
+        if let Some(p) = ev.percent {
+            assert!(p >= prev, "percent went backwards: {events:?}");
+            prev = p;
+        }
+    }
+}
+
+#[test]
+fn import_reports_rows_and_warnings_in_order() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+
+    let csv = "Key,Summary,Severity,StartTs\n\
+               A-1,First,SEV1,2026-01-01T00:00:00Z\n\
+               A-2,,SEV2,2026-01-02T00:00:00Z\n\
+               A-3,Third,SEV3,2026-01-03T00:00:00Z\n";
+
+    let events = Mutex::new(Vec::new());
+    let sink = |ev: &ProgressEvent| events.lock().unwrap().push(ev.clone());
+    let mut progress = Progress::new(&sink, "op-1", "import_jira_csv");
+    let summary = import_jira_csv_with_progress(&mut conn, csv, &mapping(), &mut progress).expect("import");
+    assert_eq!(summary.inserted, 2);
+
+    let events = events.into_inner().unwrap();
+    assert_well_formed(&events, "import_jira_csv", STAGE_COMPLETED);
+    assert!(events.iter().any(|e| e.stage == "rows" && e.percent.is_some()));
+    let warned: Vec<&str> = events
+        .iter()
+        .filter_map(|e| e.warning.as_ref().map(|w| w.code.as_str()))
+        .collect();
+    assert_eq!(warned, vec!["INGEST_MISSING_TITLE"]);
+}
+
+fn large_csv(rows: usize) -> String {
+    let mut csv = String::from("Key,Summary,Severity,StartTs\n");
+    for i in 0..rows {
+        csv.push_str(&format!("A-{i},Incident {i},SEV3,2026-01-01T00:00:00Z\n"));
+    }
+    csv
+}
+
+#[test]
+fn import_streams_running_counts() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+
+    let events = Mutex::new(Vec::new());
+    let sink = |ev: &ProgressEvent| events.lock().unwrap().push(ev.clone());
+    let mut progress = Progress::new(&sink, "op-1", "import_jira_csv");
+    import_jira_csv_with_progress(&mut conn, &large_csv(1200), &mapping(), &mut progress).expect("import");
+
+    let events = events.into_inner().unwrap();
+    assert_well_formed(&events, "import_jira_csv", STAGE_COMPLETED);
+    let counts: Vec<ProgressCounts> = events.iter().filter_map(|e| e.counts).collect();
+    assert_eq!(counts.iter().map(|c| c.processed).collect::<Vec<_>>(), vec![500, 1000, 1200]);
+    assert_eq!(
+        counts.last().copied(),
+        Some(ProgressCounts {
+            processed: 1200,
+            inserted: 1200,
+            updated: 0,
+            skipped: 0,
+            conflicts: 0,
+        })
+    );
+}
+
+#[test]
+fn cancelled_import_rolls_back_every_row() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+
+    let token = CancelToken::new();
+    let cancel = token.clone();
+    // Cancel from the first running-count event, i.e. mid-import.
+    let sink = move |ev: &ProgressEvent| {
+        if ev.counts.is_some() {
+            cancel.cancel();
+        }
+    };
+    let mut progress = Progress::new(&sink, "op-1", "import_jira_csv").with_cancel(token);
+    let err = import_jira_csv_with_progress(&mut conn, &large_csv(1200), &mapping(), &mut progress)
+        .expect_err("cancelled");
+    assert_eq!(err.code, "INGEST_IMPORT_CANCELLED");
+
+    let n: i64 = conn.query_row("SELECT COUNT(*) FROM incidents", [], |r| r.get(0)).unwrap();
+    assert_eq!(n, 0);
+}
+
+#[test]
+fn failures_end_with_a_failed_event_carrying_the_error() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    let tmp = tempdir().unwrap();
+    let not_a_dir = tmp.path().join("file.txt");
+    std::fs::write(&not_a_dir, b"x").unwrap();
+
+    let events = Mutex::new(Vec::new());
+    let sink = |ev: &ProgressEvent| events.lock().unwrap().push(ev.clone());
+    let mut progress = Progress::new(&sink, "op-1", "backup_create");
+    let err = create_backup_with_progress(
+        &conn,
+        &tmp.path().join("ws.sqlite"),
+        None,
+        &not_a_dir,
+        "2026-01-01T00:00:00Z",
+        "test",
+        &mut progress,
+    )
+    .expect_err("dest is a file");
+
+    let events = events.into_inner().unwrap();
+    assert_well_formed(&events, "backup_create", STAGE_FAILED);
+    assert_eq!(events.last().unwrap().error.as_ref(), Some(&err));
+}
+
+#[test]
+fn sanitized_export_walks_through_its_stages() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    qir_core::demo::seed_demo_dataset(&mut conn).expect("seed");
+    let tmp = tempdir().unwrap();
+
+    let events = Mutex::new(Vec::new());
+    let sink = |ev: &ProgressEvent| events.lock().unwrap().push(ev.clone());
+    let mut progress = Progress::new(&sink, "op-1", "export_sanitized_dataset");
+    export_sanitized_dataset_with_progress(&conn, tmp.path(), "2026-01-01T00:00:00Z", "test", &mut progress)
+        .expect("export");
+
+    let events = events.into_inner().unwrap();
+    assert_well_formed(&events, "export_sanitized_dataset", STAGE_COMPLETED);
+    let mut stages: Vec<&str> = events.iter().map(|e| e.stage.as_str()).collect();
+    stages.dedup();
+    assert_eq!(
+        stages,
+        vec![STAGE_STARTED, "incidents", "validate", "metrics", "timeline", "write", "manifest", STAGE_COMPLETED]
+    );
+}
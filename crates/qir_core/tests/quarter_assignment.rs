@@ -0,0 +1,151 @@
+#![cfg(feature = "testing")]
+
+use qir_core::db;
+use qir_core::freeze::{ensure_incident_mutable, freeze_quarter};
+use qir_core::ingest::jira_csv::import_jira_csv;
+use qir_core::quarters::{
+    assigned_incident_ids, create_quarter, quarter_boundary_report, refresh_quarter_assignments,
+    save_fiscal_calendar, save_quarter_assignment_anchor, FiscalCalendarConfig,
+    QuarterAssignmentAnchor, QuarterInput,
+};
+use qir_core::report::{generate_qir_report, QirReportOptions};
+use qir_core::testing::fixture_mapping;
+use rusqlite::Connection;
+
+fn insert(conn: &Connection, id: i64, start: Option<&str>, resolve: Option<&str>) {
+    conn.execute(
+        "INSERT INTO incidents(id, external_id, fingerprint, title, start_ts, resolve_ts, ingested_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, '2026-07-01T00:00:00Z')",
+        rusqlite::params![id, format!("INC-{id}"), format!("fp-{id}"), format!("Incident {id}"), start, resolve],
+    )
+    .expect("insert");
+}
+
+/// 1: inside Q1; 2: starts in Q1, resolves in Q2; 3: starts in Q1, still open; 4: no timestamps.
+fn setup() -> Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    insert(&conn, 1, Some("2026-02-01T00:00:00Z"), Some("2026-02-01T04:00:00Z"));
+    insert(&conn, 2, Some("2026-03-31T22:00:00Z"), Some("2026-04-01T03:00:00Z"));
+    insert(&conn, 3, Some("2026-03-15T00:00:00Z"), None);
+    insert(&conn, 4, None, None);
+    conn
+}
+
+#[test]
+fn assignment_follows_the_configured_anchor() {
+    let conn = setup();
+    let summary = refresh_quarter_assignments(&conn).expect("refresh");
+    assert_eq!(summary.anchor, QuarterAssignmentAnchor::StartTs);
+    assert_eq!((summary.assigned, summary.unassigned, summary.changed), (3, 1, 3));
+    assert_eq!(assigned_incident_ids(&conn, "Q1 2026").expect("q1"), vec![1, 2, 3]);
+    assert_eq!(refresh_quarter_assignments(&conn).expect("again").changed, 0);
+
+    let summary = save_quarter_assignment_anchor(&conn, QuarterAssignmentAnchor::ResolveTs).expect("rule");
+    assert_eq!(summary.changed, 1);
+    assert_eq!(assigned_incident_ids(&conn, "Q1 2026").expect("q1"), vec![1, 3]);
+    assert_eq!(assigned_incident_ids(&conn, "Q2 2026").expect("q2"), vec![2]);
+}
+
+#[test]
+fn calendar_and_quarter_changes_rematerialize_assignments() {
+    let conn = setup();
+    refresh_quarter_assignments(&conn).expect("refresh");
+
+    save_fiscal_calendar(&conn, &FiscalCalendarConfig { fiscal_year_start_month: 2 }).expect("fiscal");
+    assert_eq!(assigned_incident_ids(&conn, "FY2027 Q1").expect("fy"), vec![1, 2, 3]);
+
+    create_quarter(
+        &conn,
+        &QuarterInput {
+            label: "Spring close".to_string(),
+            start_ts: "2026-03-01T00:00:00Z".to_string(),
+            end_ts: "2026-04-01T00:00:00Z".to_string(),
+            fiscal_offset_months: 1,
+        },
+    )
+    .expect("define");
+    assert_eq!(assigned_incident_ids(&conn, "Spring close").expect("defined"), vec![2, 3]);
+    assert_eq!(assigned_incident_ids(&conn, "FY2027 Q1").expect("fy"), vec![1]);
+}
+
+#[test]
+fn boundary_report_lists_straddling_incidents() {
+    let conn = setup();
+    let report = quarter_boundary_report(&conn, None).expect("report");
+    assert_eq!(report.incidents.len(), 1);
+    let inc = &report.incidents[0];
+    assert_eq!(inc.incident_id, 2);
+    assert_eq!(inc.start_quarter, "Q1 2026");
+    assert_eq!(inc.resolve_quarter, "Q2 2026");
+    assert_eq!(inc.assigned_quarter, "Q1 2026");
+
+    save_quarter_assignment_anchor(&conn, QuarterAssignmentAnchor::ResolveTs).expect("rule");
+    let report = quarter_boundary_report(&conn, Some("Q2 2026")).expect("report");
+    assert_eq!(report.incidents[0].assigned_quarter, "Q2 2026");
+    assert!(quarter_boundary_report(&conn, Some("Q3 2026")).expect("report").incidents.is_empty());
+}
+
+#[test]
+fn quarter_scoped_report_uses_the_rule_and_lists_boundary_incidents() {
+    let conn = setup();
+    save_quarter_assignment_anchor(&conn, QuarterAssignmentAnchor::ResolveTs).expect("rule");
+    let opts = QirReportOptions {
+        quarter_label: Some("Q2 2026".to_string()),
+        as_of: None,
+        draft_ids: vec![],
+        on_call: None,
+    };
+    let report = generate_qir_report(&conn, &opts, "2026-07-01T00:00:00Z", "test").expect("report");
+    assert!(report.markdown.contains("| INC-2 | Incident 2 |"));
+    assert!(!report.markdown.contains("| INC-1 |"));
+    assert!(report.markdown.contains("## Quarter boundary incidents"));
+    assert!(report.markdown.contains("assigned by `resolve_ts`"));
+}
+
+#[test]
+fn freeze_checks_use_the_assigned_quarter() {
+    let mut conn = setup();
+    save_quarter_assignment_anchor(&conn, QuarterAssignmentAnchor::ResolveTs).expect("rule");
+    freeze_quarter(&mut conn, "Q1 2026", None, None).expect("freeze");
+
+    // Incident 2 started in Q1 but is assigned to Q2 by its resolution.
+    ensure_incident_mutable(&conn, 2).expect("assigned to an open quarter");
+    assert_eq!(ensure_incident_mutable(&conn, 1).unwrap_err().code, "QUARTER_FROZEN");
+
+    let csv = "Key,Summary,StartTs,ResolveTs\n\
+INC-1,Renamed 1,2026-02-01T00:00:00Z,2026-02-01T04:00:00Z\n\
+INC-2,Renamed 2,2026-03-31T22:00:00Z,2026-04-01T03:00:00Z\n";
+    let summary = import_jira_csv(&mut conn, csv, &fixture_mapping()).expect("import");
+    assert_eq!(summary.updated, 1);
+    assert_eq!(summary.conflicts.len(), 1);
+    assert_eq!(summary.conflicts[0].external_id.as_deref(), Some("INC-1"));
+}
+
+#[test]
+fn fiscal_and_defined_quarters_can_be_frozen() {
+    let mut conn = setup();
+    let err = freeze_quarter(&mut conn, "FY2027 Q1", None, None).expect_err("no fiscal calendar yet");
+    assert_eq!(err.code, "VALIDATION_QUARTER_LABEL_INVALID");
+
+    save_fiscal_calendar(&conn, &FiscalCalendarConfig { fiscal_year_start_month: 2 }).expect("fiscal");
+    freeze_quarter(&mut conn, "FY2027 Q1", None, None).expect("freeze fiscal quarter");
+    assert_eq!(ensure_incident_mutable(&conn, 1).unwrap_err().code, "QUARTER_FROZEN");
+    ensure_incident_mutable(&conn, 4).expect("unassigned incidents stay editable");
+
+    create_quarter(
+        &conn,
+        &QuarterInput {
+            label: "Spring close".to_string(),
+            start_ts: "2026-03-01T00:00:00Z".to_string(),
+            end_ts: "2026-04-01T00:00:00Z".to_string(),
+            fiscal_offset_months: 1,
+        },
+    )
+    .expect("define");
+    freeze_quarter(&mut conn, "Spring close", None, None).expect("freeze defined quarter");
+    assert_eq!(ensure_incident_mutable(&conn, 3).unwrap_err().code, "QUARTER_FROZEN");
+
+    let err = freeze_quarter(&mut conn, "FY2027 Q5", None, None).expect_err("label");
+    assert_eq!(err.code, "VALIDATION_QUARTER_LABEL_INVALID");
+}
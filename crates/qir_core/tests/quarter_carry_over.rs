@@ -0,0 +1,129 @@
+use qir_core::action_items::{
+    create_action_item, delete_action_item, list_action_items, set_action_item_done,
+    CreateActionItemInput,
+};
+use qir_core::db;
+use qir_core::quarters::quarter_carry_over;
+use qir_core::report::{generate_qir_report, QirReportOptions};
+use rusqlite::Connection;
+
+fn insert(conn: &Connection, id: i64, start: &str, resolve: Option<&str>) {
+    conn.execute(
+        "INSERT INTO incidents(id, external_id, fingerprint, title, start_ts, resolve_ts, ingested_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, '2026-07-01T00:00:00Z')",
+        rusqlite::params![id, format!("INC-{id}"), format!("fp-{id}"), format!("Incident {id}"), start, resolve],
+    )
+    .expect("insert");
+}
+
+fn action(conn: &Connection, incident_id: i64, title: &str, created_at: &str) -> i64 {
+    create_action_item(
+        conn,
+        CreateActionItemInput {
+            incident_id,
+            title: title.to_string(),
+            owner: Some("netops".to_string()),
+            created_at: Some(created_at.to_string()),
+        },
+    )
+    .expect("action item")
+    .id
+}
+
+/// Q2 2026 view: 1 opened in Q1 and resolved in Q2; 2 opened in Q1 and still open;
+/// 3 opened and resolved inside Q2; 4 opened in Q2 and still open.
+fn setup() -> Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    insert(&conn, 1, "2026-03-30T00:00:00Z", Some("2026-04-04T00:00:00Z"));
+    insert(&conn, 2, "2026-02-01T00:00:00Z", None);
+    insert(&conn, 3, "2026-05-01T00:00:00Z", Some("2026-05-02T00:00:00Z"));
+    insert(&conn, 4, "2026-06-20T00:00:00Z", None);
+    conn
+}
+
+#[test]
+fn action_items_round_trip() {
+    let conn = setup();
+    let id = action(&conn, 1, " Add alert on queue depth ", "2026-03-31T10:00:00+02:00");
+    let item = list_action_items(&conn, Some(1), true).expect("list").remove(0);
+    assert_eq!(item.title, "Add alert on queue depth");
+    assert_eq!(item.created_at, "2026-03-31T08:00:00Z");
+    assert!(!item.done);
+
+    let err = set_action_item_done(&conn, id, true, Some("2026-03-01T00:00:00Z")).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_ACTION_ITEM_INVALID");
+    let closed = set_action_item_done(&conn, id, true, Some("2026-04-10T00:00:00Z")).expect("close");
+    assert_eq!(closed.closed_at.as_deref(), Some("2026-04-10T00:00:00Z"));
+    assert!(list_action_items(&conn, None, false).expect("open only").is_empty());
+    let reopened = set_action_item_done(&conn, id, false, None).expect("reopen");
+    assert!(reopened.closed_at.is_none());
+
+    let err = create_action_item(
+        &conn,
+        CreateActionItemInput {
+            incident_id: 99,
+            title: "x".to_string(),
+            owner: None,
+            created_at: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "DB_NOT_FOUND");
+    delete_action_item(&conn, id).expect("delete");
+    assert_eq!(delete_action_item(&conn, id).unwrap_err().code, "DB_NOT_FOUND");
+}
+
+#[test]
+fn incidents_open_at_boundaries_are_listed_oldest_first() {
+    let conn = setup();
+    let carry = quarter_carry_over(&conn, "Q2 2026").expect("carry");
+    assert_eq!(carry.start_ts, "2026-04-01T00:00:00Z");
+
+    let at_start: Vec<i64> = carry.open_at_start.iter().map(|i| i.incident_id).collect();
+    assert_eq!(at_start, vec![2, 1]);
+    assert_eq!(carry.open_at_start[0].origin_quarter.as_deref(), Some("Q1 2026"));
+    // Open since Feb 1 through the end of Q2 (Jul 1): 150 days.
+    assert_eq!(carry.open_at_start[0].age_days, 150);
+    // Resolved inside the quarter: age stops at resolution.
+    assert_eq!(carry.open_at_start[1].age_days, 5);
+
+    let at_end: Vec<i64> = carry.open_at_end.iter().map(|i| i.incident_id).collect();
+    assert_eq!(at_end, vec![2, 4]);
+}
+
+#[test]
+fn action_items_carried_from_earlier_quarters() {
+    let conn = setup();
+    let old_open = action(&conn, 2, "Replace load balancer", "2026-02-02T00:00:00Z");
+    let closed_in_q2 = action(&conn, 1, "Tune retry budget", "2026-03-31T00:00:00Z");
+    set_action_item_done(&conn, closed_in_q2, true, Some("2026-04-15T00:00:00Z")).expect("close");
+    let closed_in_q1 = action(&conn, 1, "Update runbook", "2026-03-30T12:00:00Z");
+    set_action_item_done(&conn, closed_in_q1, true, Some("2026-03-31T12:00:00Z")).expect("close");
+    action(&conn, 3, "Raised this quarter", "2026-05-02T00:00:00Z");
+
+    let carry = quarter_carry_over(&conn, "Q2 2026").expect("carry");
+    let ids: Vec<i64> = carry.carried_action_items.iter().map(|a| a.action_item_id).collect();
+    assert_eq!(ids, vec![old_open, closed_in_q2]);
+    assert!(carry.carried_action_items[0].open_at_quarter_end);
+    assert_eq!(carry.carried_action_items[0].age_days, 149);
+    assert!(!carry.carried_action_items[1].open_at_quarter_end);
+    assert_eq!(carry.carried_action_items[1].age_days, 15);
+    assert_eq!(carry.carried_action_items[1].origin_quarter.as_deref(), Some("Q1 2026"));
+}
+
+#[test]
+fn quarter_scoped_report_has_carry_over_subsection() {
+    let conn = setup();
+    action(&conn, 2, "Replace load balancer", "2026-02-02T00:00:00Z");
+    let opts = QirReportOptions {
+        quarter_label: Some("Q2 2026".to_string()),
+        as_of: None,
+        draft_ids: vec![],
+        on_call: None,
+    };
+    let report = generate_qir_report(&conn, &opts, "2026-07-01T00:00:00Z", "test").expect("report");
+    assert!(report.markdown.contains("## Carry-over"));
+    assert!(report.markdown.contains("### Action items carried over from previous quarters"));
+    assert!(report.markdown.contains("| Replace load balancer | netops | 2026-02-02T00:00:00Z | Q1 2026 | 149 | open |"));
+}
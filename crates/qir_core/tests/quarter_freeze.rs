@@ -0,0 +1,101 @@
+use pretty_assertions::assert_eq;
+
+use qir_core::db;
+use qir_core::freeze::{
+    calendar_quarter_label, freeze_quarter, list_quarter_freeze_audit, list_quarter_freezes,
+    unlock_quarter,
+};
+use qir_core::ingest::jira_csv::{import_jira_csv, JiraCsvMapping};
+use qir_core::ingest::slack_transcript::ingest_slack_transcript_text;
+
+fn mapping() -> JiraCsvMapping {
+    JiraCsvMapping {
+        external_id: Some("Key".to_string()),
+        title: "Summary".to_string(),
+        description: Some("Description".to_string()),
+        severity: Some("Severity".to_string()),
+        initial_severity: None,
+        detection_source: None,
+        vendor: None,
+        service: None,
+        impact_pct: Some("ImpactPct".to_string()),
+        service_health_pct: Some("ServiceHealthPct".to_string()),
+        start_ts: Some("StartTs".to_string()),
+        first_observed_ts: None,
+        it_awareness_ts: None,
+        ack_ts: Some("AckTs".to_string()),
+        mitigate_ts: None,
+        resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
+    }
+}
+
+const SAMPLE: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../fixtures/demo/jira_sample.csv"
+));
+
+#[test]
+fn calendar_quarter_labels_are_utc_based() {
+    assert_eq!(calendar_quarter_label("2026-01-05T12:00:00Z").as_deref(), Some("Q1 2026"));
+    assert_eq!(calendar_quarter_label("2026-04-01T00:00:00Z").as_deref(), Some("Q2 2026"));
+    assert_eq!(calendar_quarter_label("2026-12-31T23:59:59Z").as_deref(), Some("Q4 2026"));
+    // Local midnight in +02:00 is still the previous quarter in UTC.
+    assert_eq!(calendar_quarter_label("2026-04-01T01:00:00+02:00").as_deref(), Some("Q1 2026"));
+    assert_eq!(calendar_quarter_label("not a timestamp"), None);
+}
+
+#[test]
+fn frozen_quarter_blocks_jira_updates_and_slack_ingest_until_unlocked() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    import_jira_csv(&mut conn, SAMPLE, &mapping()).expect("import");
+
+    freeze_quarter(&mut conn, "Q1 2026", Some("reviewer"), Some("QIR published")).expect("freeze");
+    assert_eq!(list_quarter_freezes(&conn).unwrap().len(), 1);
+
+    let edited = SAMPLE.replace("SEV2", "SEV1");
+    let summary = import_jira_csv(&mut conn, &edited, &mapping()).expect("import edited");
+    assert_eq!(summary.updated, 0);
+    assert_eq!(summary.conflicts.len(), 1);
+    assert!(summary.conflicts[0].reason.contains("Q1 2026"));
+
+    let sev: String = conn
+        .query_row("SELECT severity FROM incidents WHERE external_id = 'INC-101'", [], |r| r.get(0))
+        .unwrap();
+    assert_eq!(sev, "SEV2");
+
+    let id: i64 = conn
+        .query_row("SELECT id FROM incidents WHERE external_id = 'INC-101'", [], |r| r.get(0))
+        .unwrap();
+    let err = ingest_slack_transcript_text(&mut conn, Some(id), None, "2026-01-05T12:01:00Z a: hi\n")
+        .expect_err("frozen");
+    assert_eq!(err.code, "QUARTER_FROZEN");
+
+    let err = unlock_quarter(&mut conn, "Q1 2026", None, "  ").expect_err("reason required");
+    assert_eq!(err.code, "VALIDATION_UNLOCK_REASON_REQUIRED");
+
+    unlock_quarter(&mut conn, "Q1 2026", Some("reviewer"), "Severity correction").expect("unlock");
+    let summary = import_jira_csv(&mut conn, &edited, &mapping()).expect("import after unlock");
+    assert_eq!(summary.updated, 1);
+
+    let audit = list_quarter_freeze_audit(&conn, Some("Q1 2026")).unwrap();
+    let actions: Vec<&str> = audit.iter().map(|a| a.action.as_str()).collect();
+    assert_eq!(actions, vec!["freeze", "unlock"]);
+    assert_eq!(audit[1].reason.as_deref(), Some("Severity correction"));
+}
+
+#[test]
+fn freeze_rejects_malformed_labels_and_double_freeze() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+
+    let err = freeze_quarter(&mut conn, "2026Q1", None, None).expect_err("label");
+    assert_eq!(err.code, "VALIDATION_QUARTER_LABEL_INVALID");
+
+    freeze_quarter(&mut conn, "Q3 2026", None, None).expect("freeze");
+    let err = freeze_quarter(&mut conn, "Q3 2026", None, None).expect_err("double");
+    assert_eq!(err.code, "QUARTER_ALREADY_FROZEN");
+}
@@ -0,0 +1,135 @@
+use qir_core::ai_drafts::{create_ai_draft, AiDraftSectionType, CreateAiDraftInput};
+use qir_core::db;
+use qir_core::freeze::freeze_quarter;
+use qir_core::quarters::{
+    calendar_quarter_bounds, create_quarter, delete_quarter, list_quarters, resolve_quarter_scope,
+    update_quarter, QuarterInput,
+};
+use qir_core::report::{generate_qir_report, QirReportOptions};
+
+fn fy_q1() -> QuarterInput {
+    QuarterInput {
+        label: "FY27 Q1".to_string(),
+        start_ts: "2026-02-01T00:00:00Z".to_string(),
+        end_ts: "2026-05-01T01:00:00+01:00".to_string(),
+        fiscal_offset_months: 1,
+    }
+}
+
+#[test]
+fn calendar_labels_resolve_without_a_row() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+
+    assert_eq!(
+        calendar_quarter_bounds("Q4 2026"),
+        Some(("2026-10-01T00:00:00Z".to_string(), "2027-01-01T00:00:00Z".to_string()))
+    );
+    let scope = resolve_quarter_scope(&conn, "Q1 2026").expect("calendar");
+    assert!(!scope.defined);
+    assert!(scope.contains("2026-03-31T23:59:59Z"));
+    assert!(!scope.contains("2026-04-01T00:00:00Z"));
+    assert!(scope.contains_incident(Some("not a timestamp"), Some("2026-01-02T00:00:00Z")));
+
+    let err = resolve_quarter_scope(&conn, "FY27 Q1").unwrap_err();
+    assert_eq!(err.code, "QUARTER_NOT_FOUND");
+}
+
+#[test]
+fn crud_canonicalizes_and_rejects_overlaps() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+
+    let q = create_quarter(&conn, &fy_q1()).expect("create");
+    assert_eq!(q.end_ts, "2026-05-01T00:00:00Z");
+    assert_eq!(create_quarter(&conn, &fy_q1()).unwrap_err().code, "QUARTER_ALREADY_EXISTS");
+
+    let overlapping = QuarterInput {
+        label: "FY27 Q2".to_string(),
+        start_ts: "2026-04-15T00:00:00Z".to_string(),
+        end_ts: "2026-08-01T00:00:00Z".to_string(),
+        fiscal_offset_months: 1,
+    };
+    assert_eq!(create_quarter(&conn, &overlapping).unwrap_err().code, "QUARTER_OVERLAP");
+    let adjacent = QuarterInput {
+        start_ts: "2026-05-01T00:00:00Z".to_string(),
+        ..overlapping
+    };
+    create_quarter(&conn, &adjacent).expect("adjacent quarters are fine");
+
+    let backwards = QuarterInput {
+        start_ts: "2026-06-01T00:00:00Z".to_string(),
+        end_ts: "2026-05-01T00:00:00Z".to_string(),
+        ..fy_q1()
+    };
+    assert_eq!(create_quarter(&conn, &backwards).unwrap_err().code, "VALIDATION_QUARTER_INVALID");
+
+    let shorter = QuarterInput {
+        end_ts: "2026-04-30T00:00:00Z".to_string(),
+        ..fy_q1()
+    };
+    assert_eq!(update_quarter(&conn, &shorter).expect("update").end_ts, "2026-04-30T00:00:00Z");
+    assert_eq!(
+        list_quarters(&conn).expect("list").iter().map(|q| q.label.as_str()).collect::<Vec<_>>(),
+        vec!["FY27 Q1", "FY27 Q2"]
+    );
+
+    delete_quarter(&conn, "FY27 Q2").expect("delete");
+    assert_eq!(delete_quarter(&conn, "FY27 Q2").unwrap_err().code, "QUARTER_NOT_FOUND");
+}
+
+#[test]
+fn defined_quarters_override_calendar_and_block_edits_when_frozen() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    let custom = QuarterInput {
+        label: "Q1 2026".to_string(),
+        ..fy_q1()
+    };
+    create_quarter(&conn, &custom).expect("create");
+
+    let scope = resolve_quarter_scope(&conn, "Q1 2026").expect("scope");
+    assert!(scope.defined);
+    assert!(!scope.contains("2026-01-15T00:00:00Z"));
+    assert!(scope.contains("2026-04-15T00:00:00Z"));
+
+    freeze_quarter(&mut conn, "Q1 2026", None, None).expect("freeze");
+    assert_eq!(update_quarter(&conn, &custom).unwrap_err().code, "QUARTER_FROZEN");
+    assert_eq!(delete_quarter(&conn, "Q1 2026").unwrap_err().code, "QUARTER_FROZEN");
+}
+
+#[test]
+fn drafts_and_reports_require_a_resolvable_quarter() {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    qir_core::demo::seed_demo_dataset(&mut conn).expect("seed");
+
+    let input = CreateAiDraftInput {
+        quarter_label: "FY27 Q1".to_string(),
+        section_type: AiDraftSectionType::ExecSummary,
+        draft_text: "Hello [[chunk:abc]]".to_string(),
+        citation_chunk_ids: vec!["abc".to_string()],
+        model_name: "llama3.2:latest".to_string(),
+        model_params_hash: "params_hash".to_string(),
+        prompt_template_version: "exec_summary_v1".to_string(),
+        created_at: "2026-02-10T00:00:00Z".to_string(),
+        parent_draft_id: None,
+        revision_notes: None,
+        branch_label: None,
+    };
+    assert_eq!(create_ai_draft(&conn, input.clone()).unwrap_err().code, "QUARTER_NOT_FOUND");
+
+    let opts = QirReportOptions {
+        quarter_label: Some("FY27 Q1".to_string()),
+        as_of: None,
+        draft_ids: vec![],
+        on_call: None,
+    };
+    let err = generate_qir_report(&conn, &opts, "2026-02-10T00:00:00Z", "test").unwrap_err();
+    assert_eq!(err.code, "QUARTER_NOT_FOUND");
+
+    create_quarter(&conn, &fy_q1()).expect("define");
+    create_ai_draft(&conn, input).expect("draft after definition");
+    let report = generate_qir_report(&conn, &opts, "2026-02-10T00:00:00Z", "test").expect("report");
+    assert!(report.markdown.contains("Quarter scope: **FY27 Q1**"));
+}
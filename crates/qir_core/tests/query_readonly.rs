@@ -0,0 +1,127 @@
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+use qir_core::demo::seed_demo_dataset;
+use qir_core::query::{open_readonly_query_connection, run_readonly_query, QueryLimits};
+use qir_core::workspace::create_workspace_connection;
+
+fn seeded_workspace() -> (tempfile::TempDir, std::path::PathBuf) {
+    let tmp = tempdir().unwrap();
+    let path = tmp.path().join("query ws.sqlite");
+    let mut conn = create_workspace_connection(&path).expect("create");
+    seed_demo_dataset(&mut conn).expect("seed");
+    (tmp, path)
+}
+
+#[test]
+fn select_returns_columns_and_rows() {
+    let (_tmp, path) = seeded_workspace();
+    let conn = open_readonly_query_connection(&path).expect("open ro");
+
+    let res = run_readonly_query(
+        &conn,
+        "SELECT severity, COUNT(*) AS n FROM incidents GROUP BY severity ORDER BY severity;",
+        &QueryLimits::default(),
+    )
+    .expect("query");
+    assert_eq!(res.columns, vec!["severity".to_string(), "n".to_string()]);
+    assert_eq!(res.row_count, 4);
+    assert_eq!(res.rows[0], vec![serde_json::json!("SEV0"), serde_json::json!(10)]);
+    assert!(!res.truncated);
+}
+
+#[test]
+fn row_and_byte_caps_truncate() {
+    let (_tmp, path) = seeded_workspace();
+    let conn = open_readonly_query_connection(&path).expect("open ro");
+
+    let by_rows = run_readonly_query(
+        &conn,
+        "SELECT id FROM incidents ORDER BY id",
+        &QueryLimits { max_rows: 5, ..Default::default() },
+    )
+    .expect("query");
+    assert_eq!(by_rows.row_count, 5);
+    assert!(by_rows.truncated);
+
+    let by_bytes = run_readonly_query(
+        &conn,
+        "SELECT title FROM incidents ORDER BY id",
+        &QueryLimits { max_bytes: 40, ..Default::default() },
+    )
+    .expect("query");
+    assert!(by_bytes.truncated);
+    assert!(by_bytes.bytes <= 40);
+}
+
+#[test]
+fn non_select_statements_are_rejected() {
+    let (_tmp, path) = seeded_workspace();
+    let conn = open_readonly_query_connection(&path).expect("open ro");
+
+    for sql in [
+        "DELETE FROM incidents",
+        "SELECT 1; DELETE FROM incidents",
+        "PRAGMA query_only = OFF",
+        "ATTACH DATABASE 'x.sqlite' AS x",
+        "WITH x AS (SELECT 1) DELETE FROM incidents",
+        "SELECT 1 -- trailing",
+    ] {
+        let err = run_readonly_query(&conn, sql, &QueryLimits::default()).expect_err(sql);
+        assert!(
+            err.code == "QUERY_STATEMENT_REJECTED" || err.code == "QUERY_PREPARE_FAILED",
+            "sql={sql}; code={}",
+            err.code
+        );
+    }
+
+    let count: i64 = run_readonly_query(&conn, "SELECT COUNT(*) FROM incidents", &QueryLimits::default())
+        .expect("count")
+        .rows[0][0]
+        .as_i64()
+        .unwrap();
+    assert_eq!(count, 40);
+}
+
+#[test]
+fn quoted_semicolons_and_comment_markers_are_allowed() {
+    let (_tmp, path) = seeded_workspace();
+    let conn = open_readonly_query_connection(&path).expect("open ro");
+
+    let res = run_readonly_query(
+        &conn,
+        "SELECT 'a; b -- c /* d' AS \"odd;name\", 'it''s' AS quoted FROM incidents LIMIT 1;",
+        &QueryLimits::default(),
+    )
+    .expect("query");
+    assert_eq!(res.columns, vec!["odd;name".to_string(), "quoted".to_string()]);
+    assert_eq!(res.rows[0], vec![serde_json::json!("a; b -- c /* d"), serde_json::json!("it's")]);
+
+    for sql in ["SELECT 'x'; DELETE FROM incidents", "SELECT 'x' -- trailing", "SELECT 'unterminated; x"] {
+        let err = run_readonly_query(&conn, sql, &QueryLimits::default()).expect_err(sql);
+        assert!(
+            err.code == "QUERY_STATEMENT_REJECTED" || err.code == "QUERY_PREPARE_FAILED",
+            "sql={sql}; code={}",
+            err.code
+        );
+    }
+}
+
+#[test]
+fn long_running_queries_time_out() {
+    let (_tmp, path) = seeded_workspace();
+    let conn = open_readonly_query_connection(&path).expect("open ro");
+
+    let endless = "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n) SELECT COUNT(*) FROM n";
+    let err = run_readonly_query(&conn, endless, &QueryLimits { timeout_ms: 50, ..Default::default() })
+        .expect_err("timeout");
+    assert_eq!(err.code, "QUERY_TIMEOUT");
+
+    // The handler is removed afterwards; the connection keeps working.
+    let res = run_readonly_query(&conn, "SELECT COUNT(*) FROM incidents", &QueryLimits::default()).expect("query");
+    assert_eq!(res.rows[0][0], serde_json::json!(40));
+
+    let err = run_readonly_query(&conn, "SELECT 1", &QueryLimits { timeout_ms: 0, ..Default::default() })
+        .expect_err("limit");
+    assert_eq!(err.code, "VALIDATION_QUERY_LIMIT_INVALID");
+}
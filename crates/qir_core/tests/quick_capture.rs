@@ -0,0 +1,54 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::freeze::freeze_quarter;
+use qir_core::live::{quick_capture, QuickCaptureInput};
+use qir_core::severity::get_severity_grade;
+use qir_core::testing::empty_db;
+
+fn input(title: &str, started_at: Option<&str>) -> QuickCaptureInput {
+    QuickCaptureInput {
+        title: title.to_string(),
+        severity: Some("SEV2".to_string()),
+        service: Some(" payments ".to_string()),
+        started_at: started_at.map(str::to_string),
+    }
+}
+
+#[test]
+fn captures_open_incident_with_initial_severity() {
+    let conn = empty_db().expect("db");
+
+    let incident = quick_capture(&conn, input("  Checkout errors ", Some("2026-05-04T10:15:30.250+02:00"))).expect("capture");
+    assert_eq!(incident.title, "Checkout errors");
+    assert_eq!(incident.severity.as_deref(), Some("SEV2"));
+    assert_eq!(incident.service.as_deref(), Some("payments"));
+    assert_eq!(incident.start_ts.as_deref(), Some("2026-05-04T08:15:30Z"));
+    assert_eq!(incident.resolve_ts, None);
+    assert_eq!(incident.external_id, None);
+
+    let grade = get_severity_grade(&conn, incident.id).expect("grade").expect("some grade");
+    assert_eq!(grade.initial_severity, "SEV2");
+
+    // A double-press returns the same incident instead of a second one.
+    let again = quick_capture(&conn, input("checkout errors", Some("2026-05-04T08:15:30Z"))).expect("again");
+    assert_eq!(again.id, incident.id);
+}
+
+#[test]
+fn defaults_start_to_now_and_rejects_bad_input() {
+    let mut conn = empty_db().expect("db");
+
+    let incident = quick_capture(&conn, input("DNS failures", None)).expect("capture");
+    assert!(incident.start_ts.is_some_and(|ts| ts.ends_with('Z')));
+
+    let err = quick_capture(&conn, input("   ", None)).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_QUICK_CAPTURE_INVALID");
+    let err = quick_capture(&conn, input("Bad time", Some("yesterday"))).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_QUICK_CAPTURE_INVALID");
+
+    freeze_quarter(&mut conn, "Q1 2026", None, None).expect("freeze");
+    let err = quick_capture(&conn, input("Late entry", Some("2026-02-01T00:00:00Z"))).unwrap_err();
+    assert_eq!(err.code, "QUARTER_FROZEN");
+}
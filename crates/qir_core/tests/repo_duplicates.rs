@@ -0,0 +1,109 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::repo::{find_probable_duplicates, get_incident, merge_incidents};
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+/// 1 and 2 are the same outage filed twice; 3 shares the title a week later; 4 overlaps 1 but
+/// is unrelated.
+fn setup() -> Connection {
+    let conn = db_with_incidents(&[
+        IncidentBuilder::new(1, "Checkout API 503 errors")
+            .external_id("INC-1")
+            .severity("SEV1")
+            .start("2026-03-02T10:00:00Z")
+            .ack("2026-03-02T10:20:00Z")
+            .resolve("2026-03-02T11:00:00Z")
+            .build(),
+        IncidentBuilder::new(2, "checkout api: 503 errors!")
+            .vendor("Acme")
+            .start("2026-03-02T09:50:00Z")
+            .ack("2026-03-02T10:05:00Z")
+            .resolve("2026-03-02T12:30:00Z")
+            .build(),
+        IncidentBuilder::new(3, "Checkout API 503 errors")
+            .external_id("INC-3")
+            .severity("SEV2")
+            .start("2026-03-09T10:00:00Z")
+            .resolve("2026-03-09T11:00:00Z")
+            .build(),
+        IncidentBuilder::new(4, "Warehouse sync lag")
+            .external_id("INC-4")
+            .severity("SEV3")
+            .start("2026-03-02T10:30:00Z")
+            .resolve("2026-03-02T10:45:00Z")
+            .build(),
+    ])
+    .expect("db");
+    conn.execute_batch(
+        "INSERT INTO timeline_events(id, incident_id, source, ts, author, kind, text, raw_json, created_at)
+         VALUES
+           (10, 1, 'slack', '2026-03-02T10:01:00Z', 'alice', 'message', 'Seeing 503s', '{}', '2026-03-03T00:00:00Z'),
+           (20, 2, 'slack', '2026-03-02T10:01:00Z', 'alice', 'message', 'Seeing 503s', '{}', '2026-03-03T00:00:00Z'),
+           (21, 2, 'slack', '2026-03-02T12:29:00Z', 'bob', 'message', 'All clear', '{}', '2026-03-03T00:00:00Z');
+         INSERT INTO artifacts(incident_id, kind, sha256, filename, created_at)
+         VALUES (2, 'screenshot', 'sha-2', 'errors.png', '2026-03-03T00:00:00Z');
+         INSERT INTO action_items(incident_id, title, created_at)
+         VALUES (2, 'Add canary stage', '2026-03-03T00:00:00Z');",
+    )
+    .expect("seed");
+    conn
+}
+
+#[test]
+fn pairs_similar_titles_with_close_windows_only() {
+    let conn = setup();
+    let candidates = find_probable_duplicates(&conn).expect("duplicates");
+    let pairs: Vec<(i64, i64, f64)> = candidates.iter().map(|c| (c.incident_id, c.other_incident_id, c.score)).collect();
+    // Normalization makes 1 and 2 identical titles with overlapping windows: a perfect score.
+    assert_eq!(pairs, vec![(2, 1, 1.0)]);
+    assert_eq!((candidates[0].title_similarity, candidates[0].window_gap_seconds), (1.0, 0));
+}
+
+#[test]
+fn merge_folds_fields_timestamps_and_children_into_the_kept_incident() {
+    let mut conn = setup();
+    let summary = merge_incidents(&mut conn, 1, 2).expect("merge");
+    assert_eq!(summary.filled_fields, vec!["vendor".to_string()]);
+    assert_eq!(summary.changed_timestamps, vec!["start_ts", "ack_ts", "resolve_ts"]);
+    assert_eq!(
+        (
+            summary.moved_timeline_events,
+            summary.dropped_duplicate_events,
+            summary.moved_artifacts,
+            summary.moved_action_items
+        ),
+        (1, 1, 1, 1)
+    );
+
+    let kept = get_incident(&conn, 1).expect("kept");
+    assert_eq!(kept.external_id.as_deref(), Some("INC-1"));
+    assert_eq!(kept.severity.as_deref(), Some("SEV1"));
+    assert_eq!(kept.vendor.as_deref(), Some("Acme"));
+    assert_eq!(kept.start_ts.as_deref(), Some("2026-03-02T09:50:00Z"));
+    assert_eq!(kept.resolve_ts.as_deref(), Some("2026-03-02T12:30:00Z"));
+    assert_eq!(get_incident(&conn, 2).unwrap_err().code, "DB_NOT_FOUND");
+
+    let events: Vec<i64> = conn
+        .prepare("SELECT id FROM timeline_events WHERE incident_id = 1 ORDER BY id")
+        .expect("prepare")
+        .query_map([], |r| r.get(0))
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows");
+    assert_eq!(events, vec![10, 21]);
+    let children: (i64, i64) = conn
+        .query_row(
+            "SELECT (SELECT COUNT(*) FROM artifacts WHERE incident_id = 1),
+                    (SELECT COUNT(*) FROM action_items WHERE incident_id = 1)",
+            [],
+            |r| Ok((r.get(0)?, r.get(1)?)),
+        )
+        .expect("children");
+    assert_eq!(children, (1, 1));
+
+    assert_eq!(merge_incidents(&mut conn, 1, 1).unwrap_err().code, "VALIDATION_MERGE_SAME_INCIDENT");
+    assert_eq!(merge_incidents(&mut conn, 1, 2).unwrap_err().code, "DB_NOT_FOUND");
+}
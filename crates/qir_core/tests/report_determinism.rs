@@ -0,0 +1,133 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::error::AppError;
+use qir_core::report::{
+    compare_outputs, verify_determinism, verify_determinism_with, DeterminismMismatch, IncidentIdMap,
+};
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+/// Every ranking in the dashboard and report has ties here: two vendors with equal counts and
+/// pain, two services with equal counts, identical start times and identical durations, so
+/// percentiles and top-N lists only stay stable if their tie-breaks do.
+fn setup_with_ties() -> Connection {
+    let incident = |id: i64, title: &str, sev: &str, detection: &str, vendor: &str, service: &str| {
+        IncidentBuilder::new(id, title)
+            .external_id(format!("INC-{id}"))
+            .severity(sev)
+            .detection_source(detection)
+            .vendor(vendor)
+            .service(service)
+    };
+    // Start (also first observed), IT awareness, ack, mitigate and resolve times on `day`.
+    let timed = |b: IncidentBuilder, day: &str, [start, aware, ack, mitigate, resolve]: [&str; 5]| {
+        let at = |t: &str| format!("{day}T{t}:00Z");
+        b.start(at(start))
+            .first_observed(at(start))
+            .it_awareness(at(aware))
+            .ack(at(ack))
+            .mitigate(at(mitigate))
+            .resolve(at(resolve))
+            .build()
+    };
+    let jan = |b: IncidentBuilder| timed(b, "2026-01-05", ["10:00", "10:05", "10:10", "10:40", "11:00"]);
+    let feb = |b: IncidentBuilder| timed(b, "2026-02-10", ["08:00", "08:30", "08:45", "09:00", "12:00"]);
+    let conn = db_with_incidents(&[
+        jan(incident(1, "Checkout errors", "SEV2", "monitoring", "Acme", "payments").impact(50, 50)),
+        jan(incident(2, "Search latency", "SEV2", "customer", "Globex", "search").impact(50, 50)),
+        feb(incident(3, "Login failures", "SEV1", "monitoring", "Acme", "search").impact(80, 20)),
+        feb(incident(4, "Login failures", "SEV1", "customer", "Globex", "payments").impact(80, 20)),
+        IncidentBuilder::new(5, "Queue backlog")
+            .external_id("INC-5")
+            .severity("SEV3")
+            .detection_source("unknown")
+            .start("2026-03-01T00:00:00Z")
+            .build(),
+    ])
+    .expect("db");
+    conn.execute_batch(
+        "INSERT INTO timeline_events(id, incident_id, source, ts, author, kind, text, created_at)
+         VALUES
+           (1, 1, 'slack', '2026-01-05T10:05:00Z', 'ana', 'message', 'paged', '2026-07-01T00:00:00Z'),
+           (2, 1, 'slack', '2026-01-05T10:05:00Z', 'ben', 'message', 'paged too', '2026-07-01T00:00:00Z'),
+           (3, 3, 'slack', NULL, 'ana', 'message', 'no timestamp', '2026-07-01T00:00:00Z');",
+    )
+    .expect("seed");
+    conn
+}
+
+#[test]
+fn dashboard_and_report_are_byte_identical_across_shuffled_copies() {
+    let conn = setup_with_ties();
+    let report = verify_determinism(&conn).expect("verify");
+    let outputs: Vec<&str> = report.checks.iter().map(|c| c.output.as_str()).collect();
+    assert_eq!(outputs, vec!["dashboard_v2", "report_markdown"]);
+    for check in &report.checks {
+        assert_eq!(check.first_difference, None, "{} differs", check.output);
+        assert_eq!(check.first_sha256, check.second_sha256);
+    }
+    assert!(report.deterministic);
+
+    // The check only reads the workspace.
+    let incidents: i64 = conn.query_row("SELECT COUNT(*) FROM incidents", [], |r| r.get(0)).expect("count");
+    assert_eq!(incidents, 5);
+}
+
+/// One output line per row of `sql` (first column an incident id, second a count), with ids
+/// mapped back to the workspace.
+fn incident_rows(conn: &Connection, ids: &IncidentIdMap, sql: &str) -> Result<String, AppError> {
+    let mut stmt = conn.prepare(sql).expect("prepare");
+    let rows = stmt
+        .query_map([], |r| Ok((r.get::<_, i64>(0)?, r.get::<_, i64>(1)?)))
+        .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+        .expect("query");
+    Ok(rows.iter().map(|(id, n)| format!("{} {n}\n", ids.workspace_id(*id))).collect())
+}
+
+#[test]
+fn storage_order_dependence_is_caught_by_the_shuffled_copy() {
+    let conn = setup_with_ties();
+
+    // An explicit ORDER BY is unaffected by reverse_unordered_selects: only renumbered, shuffled
+    // rows expose it.
+    let by_storage = verify_determinism_with(&conn, |c, ids| {
+        let sql = "SELECT id, 0 FROM incidents ORDER BY rowid";
+        Ok(vec![("storage_order".to_string(), incident_rows(c, ids, sql)?)])
+    })
+    .expect("verify");
+    assert!(!by_storage.deterministic);
+
+    // Ordered by content, with ids mapped back and references following the new ids.
+    let by_content = verify_determinism_with(&conn, |c, ids| {
+        let sql = "SELECT i.id, COUNT(t.id) FROM incidents i
+                   LEFT JOIN timeline_events t ON t.incident_id = i.id
+                   GROUP BY i.id ORDER BY i.external_id";
+        Ok(vec![("event_counts".to_string(), incident_rows(c, ids, sql)?)])
+    })
+    .expect("verify");
+    assert_eq!(by_content.checks[0].first_difference, None);
+    assert!(by_content.deterministic);
+}
+
+#[test]
+fn compare_outputs_reports_the_first_differing_line() {
+    let check = compare_outputs("report_markdown", "# QIR\n- Acme: 2\n- Globex: 2\n", "# QIR\n- Globex: 2\n- Acme: 2\n");
+    assert!(!check.identical);
+    assert_ne!(check.first_sha256, check.second_sha256);
+    assert_eq!(
+        check.first_difference,
+        Some(DeterminismMismatch {
+            line: 2,
+            first: "- Acme: 2".to_string(),
+            second: "- Globex: 2".to_string(),
+        })
+    );
+
+    // A missing trailing line is reported against the end of the shorter output.
+    let check = compare_outputs("dashboard_v2", "a\nb", "a");
+    assert_eq!(check.first_difference.map(|d| (d.line, d.second)), Some((2, "<end of output>".to_string())));
+
+    assert!(compare_outputs("dashboard_v2", "same", "same").identical);
+}
@@ -18,6 +18,7 @@ fn dashboard_payload_reconciles_to_incident_total() {
         title: "Summary".to_string(),
         description: Some("Description".to_string()),
         severity: Some("Severity".to_string()),
+        initial_severity: None,
         detection_source: None,
         vendor: None,
         service: None,
@@ -29,6 +30,9 @@ fn dashboard_payload_reconciles_to_incident_total() {
         ack_ts: Some("AckTs".to_string()),
         mitigate_ts: None,
         resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
     };
 
     ingest_jira_csv(&mut conn, csv_text, &mapping).expect("ingest");
@@ -121,6 +125,7 @@ fn report_matches_golden_fixture() {
         title: "Summary".to_string(),
         description: Some("Description".to_string()),
         severity: Some("Severity".to_string()),
+        initial_severity: None,
         detection_source: None,
         vendor: None,
         service: None,
@@ -132,6 +137,9 @@ fn report_matches_golden_fixture() {
         ack_ts: Some("AckTs".to_string()),
         mitigate_ts: None,
         resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
     };
 
     ingest_jira_csv(&mut conn, csv_text, &mapping).expect("ingest");
@@ -0,0 +1,90 @@
+use pretty_assertions::assert_eq;
+
+use qir_core::db;
+use qir_core::demo::seed_demo_dataset;
+use qir_core::report::{
+    export_qir_report, generate_qir_markdown, generate_qir_report, read_report_manifest,
+    verify_qir_report, QirReportOptions, REPORT_TEMPLATE_VERSION,
+};
+
+fn seeded() -> rusqlite::Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    seed_demo_dataset(&mut conn).expect("seed");
+    conn
+}
+
+#[test]
+fn manifest_is_deterministic_and_describes_inputs() {
+    let conn = seeded();
+    let opts = QirReportOptions::default();
+
+    let a = generate_qir_report(&conn, &opts, "2026-02-01T00:00:00Z", "0.1.0").expect("report a");
+    let b = generate_qir_report(&conn, &opts, "2026-02-01T00:00:00Z", "0.1.0").expect("report b");
+    assert_eq!(a, b);
+
+    assert_eq!(a.markdown, generate_qir_markdown(&conn).expect("md"));
+    assert_eq!(a.manifest.template_version, REPORT_TEMPLATE_VERSION);
+    assert_eq!(a.manifest.applied_migrations, db::applied_migration_names(&conn).unwrap());
+    assert_eq!(a.manifest.report_bytes, a.markdown.len() as u64);
+    assert!(a.manifest.quarter_scope.is_none());
+    assert!(a.manifest.draft_artifact_hashes.is_empty());
+}
+
+#[test]
+fn quarter_scope_is_recorded_and_rendered() {
+    let conn = seeded();
+    let opts = QirReportOptions {
+        quarter_label: Some("Q1 2026".to_string()),
+        ..Default::default()
+    };
+    let report = generate_qir_report(&conn, &opts, "2026-02-01T00:00:00Z", "0.1.0").expect("report");
+    assert!(report.markdown.contains("Quarter scope: **Q1 2026**"));
+    assert_eq!(report.manifest.quarter_scope.as_deref(), Some("Q1 2026"));
+}
+
+#[test]
+fn export_writes_sidecar_and_verify_detects_drift() {
+    let conn = seeded();
+    let dir = tempfile::tempdir().expect("tempdir");
+
+    let exported = export_qir_report(
+        &conn,
+        &QirReportOptions::default(),
+        dir.path(),
+        "2026-02-01T00:00:00Z",
+        "0.1.0",
+    )
+    .expect("export");
+    let md = std::fs::read_to_string(&exported.report_path).expect("read md");
+    assert_eq!(md, generate_qir_markdown(&conn).unwrap());
+
+    let manifest = read_report_manifest(std::path::Path::new(&exported.manifest_path)).expect("manifest");
+    assert_eq!(manifest, exported.manifest);
+
+    let ok = verify_qir_report(&conn, &manifest).expect("verify");
+    assert!(ok.reproducible);
+    assert!(ok.workspace_content_matches);
+    assert!(ok.migrations_match);
+    assert!(ok.notes.is_empty());
+
+    conn.execute(
+        "UPDATE incidents SET severity = 'SEV3' WHERE external_id = 'IR-001'",
+        [],
+    )
+    .unwrap();
+    let drifted = verify_qir_report(&conn, &manifest).expect("verify after edit");
+    assert!(!drifted.reproducible);
+    assert!(!drifted.workspace_content_matches);
+    assert_eq!(drifted.expected_report_sha256, manifest.report_sha256);
+
+    let again = export_qir_report(
+        &conn,
+        &QirReportOptions::default(),
+        dir.path(),
+        "2026-02-01T00:00:00Z",
+        "0.1.0",
+    )
+    .expect_err("existing files");
+    assert_eq!(again.code, "REPORT_EXPORT_DEST_EXISTS");
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "testing")]
+
+use std::path::PathBuf;
+
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+use qir_core::demo::{seed_demo_dataset, seed_guided_tour_dataset};
+use qir_core::sanitize::{aggregate_sanitized_benchmark, export_sanitized_dataset, render_benchmark_markdown};
+use qir_core::testing::empty_db;
+
+fn export(conn: &rusqlite::Connection, dest: &std::path::Path) -> PathBuf {
+    let res = export_sanitized_dataset(conn, dest, "2026-02-10T03:00:00Z", "0.1.0-test").expect("export");
+    PathBuf::from(res.export_dir)
+}
+
+#[test]
+fn aggregates_datasets_under_anonymized_labels() {
+    let dest_a = tempdir().unwrap();
+    let dest_b = tempdir().unwrap();
+
+    let mut conn = empty_db().expect("db");
+    seed_demo_dataset(&mut conn).expect("seed demo");
+    let dataset_a = export(&conn, dest_a.path());
+
+    let mut conn = empty_db().expect("db");
+    seed_guided_tour_dataset(&mut conn).expect("seed tour");
+    let dataset_b = export(&conn, dest_b.path());
+
+    let b = aggregate_sanitized_benchmark(&[dataset_a.clone(), dataset_b]).expect("aggregate");
+    let labels: Vec<&str> = b.datasets.iter().map(|d| d.label.as_str()).collect();
+    assert_eq!(labels, vec!["TEAM_A", "TEAM_B"]);
+    assert_eq!(
+        b.incident_count as i64,
+        b.datasets.iter().map(|d| d.incident_count).sum::<i64>()
+    );
+    assert_eq!(
+        b.mttr_by_severity.iter().map(|s| s.incident_count).sum::<usize>(),
+        b.incident_count
+    );
+    assert_eq!(
+        b.mttr_overall.count,
+        b.mttr_by_severity.iter().map(|s| s.mttr.count).sum::<usize>()
+    );
+    assert_eq!(b.detection_mix.len(), 2);
+
+    let md = render_benchmark_markdown(&b);
+    assert!(md.contains("| TEAM_A |"));
+    assert!(!md.contains(&dataset_a.to_string_lossy().to_string()));
+
+    let err = aggregate_sanitized_benchmark(&[dataset_a.clone(), dataset_a]).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_BENCHMARK_DATASETS_INVALID");
+    let err = aggregate_sanitized_benchmark(&[]).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_BENCHMARK_DATASETS_INVALID");
+}
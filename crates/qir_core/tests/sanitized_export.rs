@@ -66,3 +66,26 @@ fn sanitized_export_does_not_include_raw_slack_text_and_is_deterministic() {
     assert_eq!(warnings1, warnings2);
 }
 
+
+#[test]
+fn sanitized_export_stages_in_temp_dir_and_replaces_leftover_staging() {
+    let tmp = tempdir().unwrap();
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+
+    // A crashed earlier run left a half-written staging folder behind.
+    let staging = tmp.path().join("IncidentReviewSanitized_2026_01_02T00_00_00Z.export_tmp");
+    fs::create_dir_all(&staging).unwrap();
+    fs::write(staging.join("incidents.json"), b"partial").unwrap();
+
+    let res = export_sanitized_dataset(&conn, tmp.path(), "2026-01-02T00:00:00Z", "0.1.0").expect("export");
+    let export_dir = Path::new(&res.export_dir);
+    assert!(export_dir.join("sanitized_manifest.json").is_file());
+    assert!(!staging.exists(), "staging folder should be renamed into place");
+
+    let names: Vec<String> = fs::read_dir(tmp.path())
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(names, vec!["IncidentReviewSanitized_2026_01_02T00_00_00Z".to_string()]);
+}
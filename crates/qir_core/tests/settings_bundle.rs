@@ -0,0 +1,122 @@
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+use qir_core::db;
+use qir_core::ingest::jira_csv::JiraCsvMapping;
+use qir_core::profiles::jira::{list_profiles, upsert_profile, JiraMappingProfileUpsert};
+use qir_core::settings::{
+    build_settings_bundle, export_settings_bundle, get_setting, import_settings_bundle,
+    read_settings_bundle, set_setting,
+};
+
+fn demo_mapping() -> JiraCsvMapping {
+    JiraCsvMapping {
+        external_id: Some("Key".to_string()),
+        title: "Summary".to_string(),
+        description: None,
+        severity: Some("Severity".to_string()),
+        initial_severity: None,
+        detection_source: None,
+        vendor: None,
+        service: None,
+        impact_pct: None,
+        service_health_pct: None,
+        start_ts: Some("StartTs".to_string()),
+        first_observed_ts: None,
+        it_awareness_ts: None,
+        ack_ts: None,
+        mitigate_ts: None,
+        resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
+    }
+}
+
+fn seeded_conn() -> rusqlite::Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    upsert_profile(
+        &mut conn,
+        JiraMappingProfileUpsert {
+            id: None,
+            name: "Team Jira".to_string(),
+            mapping: demo_mapping(),
+        },
+    )
+    .expect("profile");
+    set_setting(&conn, "slo.mttr_seconds", &serde_json::json!({"SEV1": 3600})).expect("set");
+    set_setting(&conn, "taxonomy.detection_sources", &serde_json::json!(["Monitoring", "Customer"]))
+        .expect("set");
+    conn
+}
+
+#[test]
+fn settings_bundle_round_trips_into_empty_workspace() {
+    let src = seeded_conn();
+    let dest_dir = tempdir().unwrap();
+    let export_time = "2026-02-10T03:00:00Z";
+
+    let res = export_settings_bundle(&src, dest_dir.path(), export_time, "0.1.0-test", None)
+        .expect("export");
+    assert_eq!(res.profile_count, 1);
+    assert_eq!(res.setting_count, 2);
+
+    let bundle = read_settings_bundle(std::path::Path::new(&res.bundle_path)).expect("read");
+    let mut dst = db::open_in_memory().expect("open");
+    db::migrate(&mut dst).expect("migrate");
+    let summary = import_settings_bundle(&mut dst, &bundle, None).expect("import");
+    assert_eq!(summary.profiles_created, 1);
+    assert_eq!(summary.settings_written, 2);
+
+    let profiles = list_profiles(&dst).expect("list");
+    assert_eq!(profiles[0].mapping, demo_mapping());
+    assert_eq!(
+        get_setting(&dst, "slo.mttr_seconds").expect("get"),
+        Some(serde_json::json!({"SEV1": 3600}))
+    );
+
+    // Re-importing updates by profile name instead of duplicating.
+    let again = import_settings_bundle(&mut dst, &bundle, None).expect("import again");
+    assert_eq!(again.profiles_created, 0);
+    assert_eq!(again.profiles_updated, 1);
+    assert_eq!(list_profiles(&dst).expect("list").len(), 1);
+}
+
+#[test]
+fn settings_bundle_is_deterministic_and_contains_no_incident_data() {
+    let mut src = seeded_conn();
+    qir_core::demo::seed_demo_dataset(&mut src).expect("seed");
+
+    let a = build_settings_bundle(&src, "2026-02-10T03:00:00Z", "0.1.0-test", None).expect("a");
+    let b = build_settings_bundle(&src, "2026-02-10T03:00:00Z", "0.1.0-test", None).expect("b");
+    assert_eq!(a, b);
+
+    let json = serde_json::to_string(&a).unwrap();
+    assert!(!json.contains("IR-001"), "bundle must not contain incident data");
+}
+
+#[test]
+fn settings_bundle_rejects_tampering_and_wrong_key() {
+    let src = seeded_conn();
+    let mut dst = db::open_in_memory().expect("open");
+    db::migrate(&mut dst).expect("migrate");
+
+    let mut tampered = build_settings_bundle(&src, "2026-02-10T03:00:00Z", "0.1.0-test", None).unwrap();
+    tampered.settings[0].value = serde_json::json!({"SEV1": 1});
+    let err = import_settings_bundle(&mut dst, &tampered, None).expect_err("tampered");
+    assert_eq!(err.code, "SETTINGS_BUNDLE_SIGNATURE_INVALID");
+
+    let keyed = build_settings_bundle(&src, "2026-02-10T03:00:00Z", "0.1.0-test", Some("team-secret")).unwrap();
+    assert_eq!(keyed.signature.algorithm, "hmac-sha256");
+    let err = import_settings_bundle(&mut dst, &keyed, None).expect_err("missing key");
+    assert_eq!(err.code, "SETTINGS_BUNDLE_SIGNING_KEY_REQUIRED");
+    let err = import_settings_bundle(&mut dst, &keyed, Some("other")).expect_err("wrong key");
+    assert_eq!(err.code, "SETTINGS_BUNDLE_SIGNATURE_INVALID");
+
+    // Nothing was written by the failed imports.
+    assert!(list_profiles(&dst).unwrap().is_empty());
+
+    import_settings_bundle(&mut dst, &keyed, Some("team-secret")).expect("keyed import");
+    assert_eq!(list_profiles(&dst).unwrap().len(), 1);
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::jira_csv::import_jira_csv;
+use qir_core::severity::{
+    delete_severity_mapping, list_severity_mappings, upsert_severity_mapping, SeverityMappingInput,
+};
+use qir_core::testing::{empty_db, fixture_mapping};
+use rusqlite::Connection;
+
+fn map(conn: &Connection, source_value: &str, severity: &str) -> Result<(), qir_core::error::AppError> {
+    upsert_severity_mapping(
+        conn,
+        &SeverityMappingInput {
+            source_value: source_value.to_string(),
+            severity: severity.to_string(),
+        },
+    )
+    .map(|_| ())
+}
+
+#[test]
+fn mappings_are_one_step_and_case_insensitive() {
+    let conn = empty_db().expect("db");
+    map(&conn, "P1", "SEV1").expect("p1");
+    map(&conn, " sev-1 ", "SEV1").expect("sev-1");
+    map(&conn, "Critical", "SEV2").expect("critical");
+    // Retargeting keeps one row per source value.
+    map(&conn, "critical", "SEV1").expect("retarget");
+
+    let listed: Vec<(String, String)> = list_severity_mappings(&conn)
+        .expect("list")
+        .into_iter()
+        .map(|m| (m.source_value, m.severity))
+        .collect();
+    assert_eq!(
+        listed,
+        vec![
+            ("critical".to_string(), "SEV1".to_string()),
+            ("P1".to_string(), "SEV1".to_string()),
+            ("sev-1".to_string(), "SEV1".to_string()),
+        ]
+    );
+
+    // Chains are rejected in both directions.
+    assert_eq!(map(&conn, "Urgent", "p1").unwrap_err().code, "VALIDATION_SEVERITY_MAPPING_INVALID");
+    assert_eq!(map(&conn, "SEV1", "S1").unwrap_err().code, "VALIDATION_SEVERITY_MAPPING_INVALID");
+    assert_eq!(map(&conn, " ", "SEV1").unwrap_err().code, "VALIDATION_SEVERITY_MAPPING_INVALID");
+
+    delete_severity_mapping(&conn, "CRITICAL").expect("delete");
+    assert_eq!(delete_severity_mapping(&conn, "critical").unwrap_err().code, "DB_NOT_FOUND");
+    assert_eq!(list_severity_mappings(&conn).expect("list").len(), 2);
+}
+
+#[test]
+fn import_applies_mappings_and_warns_on_unmapped_values() {
+    let mut conn = empty_db().expect("db");
+    let csv = "Key,Summary,Severity,InitialSeverity\n\
+               A-1,Checkout errors,P1,critical\n\
+               A-2,Search latency,sev1,P1\n\
+               A-3,Login failures,Blocker,\n\
+               A-4,Queue backlog,blocker,P2\n";
+
+    // Without mappings values are stored as given.
+    let summary = import_jira_csv(&mut conn, csv, &fixture_mapping()).expect("import");
+    assert!(summary.warnings.is_empty());
+    conn.execute_batch("DELETE FROM incident_severity_grades; DELETE FROM incidents;").expect("reset");
+
+    map(&conn, "P1", "SEV1").expect("p1");
+    map(&conn, "Critical", "SEV1").expect("critical");
+    let summary = import_jira_csv(&mut conn, csv, &fixture_mapping()).expect("import");
+    assert_eq!(summary.inserted, 4);
+
+    let stored: Vec<(String, Option<String>, Option<String>)> = conn
+        .prepare(
+            "SELECT i.external_id, i.severity, g.initial_severity
+               FROM incidents i LEFT JOIN incident_severity_grades g ON g.incident_id = i.id
+              ORDER BY i.external_id",
+        )
+        .expect("prepare")
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows");
+    assert_eq!(
+        stored,
+        vec![
+            ("A-1".to_string(), Some("SEV1".to_string()), Some("SEV1".to_string())),
+            // A target severity is known and adopts its stored spelling.
+            ("A-2".to_string(), Some("SEV1".to_string()), Some("SEV1".to_string())),
+            ("A-3".to_string(), None, None),
+            ("A-4".to_string(), None, None),
+        ]
+    );
+
+    let warnings: Vec<(String, Option<String>)> = summary
+        .warnings
+        .iter()
+        .map(|w| (w.code.clone(), w.details.clone()))
+        .collect();
+    assert_eq!(
+        warnings,
+        vec![
+            ("INGEST_SEVERITY_UNMAPPED".to_string(), Some("severity=Blocker; rows=2; first_row=2".to_string())),
+            ("INGEST_SEVERITY_UNMAPPED".to_string(), Some("severity=P2; rows=1; first_row=3".to_string())),
+        ]
+    );
+}
@@ -0,0 +1,125 @@
+use qir_core::analytics::{build_severity_regrade_analysis, RegradeDirection};
+use qir_core::db;
+use qir_core::ingest::jira_csv::{import_jira_csv, JiraCsvMapping};
+use qir_core::severity::{get_severity_grade, set_initial_severity, severity_rank, SeverityGradeSource};
+use rusqlite::Connection;
+
+fn mapping() -> JiraCsvMapping {
+    JiraCsvMapping {
+        external_id: Some("Key".to_string()),
+        title: "Summary".to_string(),
+        description: None,
+        severity: Some("Severity".to_string()),
+        initial_severity: Some("InitialSeverity".to_string()),
+        detection_source: None,
+        vendor: None,
+        service: None,
+        impact_pct: None,
+        service_health_pct: None,
+        start_ts: Some("StartTs".to_string()),
+        first_observed_ts: None,
+        it_awareness_ts: None,
+        ack_ts: None,
+        mitigate_ts: None,
+        resolve_ts: None,
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
+    }
+}
+
+const CSV: &str = "Key,Summary,Severity,InitialSeverity,StartTs
+INC-1,Checkout down,SEV1,SEV3,2026-01-10T10:00:00Z
+INC-2,Slow search,SEV3,SEV2,2026-01-11T10:00:00Z
+INC-3,Login errors,SEV2,SEV2,2026-01-12T10:00:00Z
+INC-4,Batch delay,SEV3,SEV3,2026-04-02T10:00:00Z
+INC-5,Unknown blip,SEV4,,2026-04-03T10:00:00Z
+";
+
+fn setup() -> Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    import_jira_csv(&mut conn, CSV, &mapping()).expect("import");
+    conn
+}
+
+fn id_of(conn: &Connection, key: &str) -> i64 {
+    conn.query_row("SELECT id FROM incidents WHERE external_id = ?1", [key], |r| r.get(0))
+        .expect("id")
+}
+
+#[test]
+fn ranks_common_severity_spellings() {
+    assert_eq!(severity_rank("SEV1"), Some(1));
+    assert_eq!(severity_rank("Sev-2"), Some(2));
+    assert_eq!(severity_rank("sev 3"), Some(3));
+    assert_eq!(severity_rank("P0"), Some(0));
+    assert_eq!(severity_rank("S4"), Some(4));
+    assert_eq!(severity_rank("2"), Some(2));
+    assert_eq!(severity_rank("Critical"), None);
+    assert_eq!(severity_rank("SEV"), None);
+}
+
+#[test]
+fn counts_directions_and_transitions_from_ingest() {
+    let conn = setup();
+    let analysis = build_severity_regrade_analysis(&conn, None).expect("analysis");
+    assert_eq!(analysis.incident_count, 5);
+    assert_eq!(
+        (analysis.upgraded_count, analysis.downgraded_count, analysis.unchanged_count),
+        (1, 1, 3)
+    );
+    // INC-5 has no ingest grade, but field history still records its first severity.
+    assert_eq!(analysis.unknown_count, 0);
+    let inc5 = analysis
+        .incidents
+        .iter()
+        .find(|r| r.external_id.as_deref() == Some("INC-5"))
+        .expect("inc5");
+    assert_eq!(inc5.initial_source, Some(SeverityGradeSource::History));
+    assert_eq!(inc5.direction, RegradeDirection::Unchanged);
+    assert_eq!(analysis.regrade_rate_pct, Some(40));
+
+    let pairs: Vec<(&str, &str)> = analysis
+        .transitions
+        .iter()
+        .map(|t| (t.from.as_str(), t.to.as_str()))
+        .collect();
+    assert_eq!(pairs, vec![("SEV2", "SEV3"), ("SEV3", "SEV1")]);
+    assert_eq!(analysis.transitions[1].incident_ids, vec![id_of(&conn, "INC-1")]);
+}
+
+#[test]
+fn history_fallback_sees_mid_incident_upgrades() {
+    let conn = setup();
+    let id = id_of(&conn, "INC-5");
+    conn.execute("UPDATE incidents SET severity = 'SEV2' WHERE id = ?1", [id])
+        .expect("update");
+    let analysis = build_severity_regrade_analysis(&conn, Some("Q2 2026")).expect("q2");
+    assert_eq!(analysis.quarter_label.as_deref(), Some("Q2 2026"));
+    assert_eq!(analysis.incident_count, 2);
+    let inc5 = analysis.incidents.iter().find(|r| r.incident_id == id).expect("inc5");
+    assert_eq!(inc5.initial_severity.as_deref(), Some("SEV4"));
+    assert_eq!(inc5.direction, RegradeDirection::Upgraded);
+}
+
+#[test]
+fn manual_grade_wins_over_later_imports() {
+    let mut conn = setup();
+    let id = id_of(&conn, "INC-2");
+    let grade = set_initial_severity(&conn, id, Some(" SEV4 ")).expect("set").expect("grade");
+    assert_eq!((grade.initial_severity.as_str(), grade.source), ("SEV4", SeverityGradeSource::Manual));
+
+    import_jira_csv(&mut conn, CSV, &mapping()).expect("re-import");
+    let kept = get_severity_grade(&conn, id).expect("get").expect("grade");
+    assert_eq!((kept.initial_severity.as_str(), kept.source), ("SEV4", SeverityGradeSource::Manual));
+
+    let err = set_initial_severity(&conn, id, Some("  ")).expect_err("blank");
+    assert_eq!(err.code, "VALIDATION_SEVERITY_GRADE_INVALID");
+
+    assert!(set_initial_severity(&conn, id, None).expect("clear").is_none());
+    assert!(get_severity_grade(&conn, id).expect("get").is_none());
+
+    let err = set_initial_severity(&conn, 9999, Some("SEV1")).expect_err("missing");
+    assert_eq!(err.code, "DB_NOT_FOUND");
+}
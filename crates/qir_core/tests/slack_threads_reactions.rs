@@ -0,0 +1,86 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::slack_transcript::ingest_slack_transcript_text;
+use qir_core::testing::empty_db;
+use rusqlite::Connection;
+
+/// (id, kind, thread_parent_event_id, reactions) per stored event, in insertion order.
+fn events(conn: &Connection) -> Vec<(i64, String, Option<i64>, String)> {
+    conn.prepare(
+        "SELECT id, kind, json_extract(raw_json, '$.thread_parent_event_id'), json_extract(raw_json, '$.reactions')
+           FROM timeline_events ORDER BY id",
+    )
+    .expect("prepare")
+    .query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?)))
+    .expect("query")
+    .collect::<Result<_, _>>()
+    .expect("rows")
+}
+
+#[test]
+fn thread_replies_reference_their_parent_and_reactions_tag_the_kind() {
+    let mut conn = empty_db().expect("db");
+    // The second reply precedes its parent, as in hand-assembled transcripts.
+    let transcript = r#"[
+      {"type":"message","user":"U1","text":"Checkout errors are up","ts":"1700000000.000100","thread_ts":"1700000000.000100","reply_count":2},
+      {"type":"message","user":"U3","text":"Second thread","ts":"1700000300.000000","thread_ts":"1700000400.000000"},
+      {"type":"message","user":"U2","text":"Looking","ts":"1700000030.000000","thread_ts":"1700000000.000100","parent_user_id":"U1",
+       "reactions":[{"name":"eyes","users":["U1"],"count":1}]},
+      {"type":"message","user":"U2","text":"Rolled back the deploy","ts":"1700000200.000000","thread_ts":"1700000000.000100",
+       "reactions":[{"name":"+1::skin-tone-3","users":["U1"],"count":1},{"name":"white_check_mark","users":["U1"],"count":1}]},
+      {"type":"message","user":"U3","text":"Parent arrives late","ts":"1700000400.000000","thread_ts":"1700000400.000000"}
+    ]"#;
+
+    let summary = ingest_slack_transcript_text(&mut conn, None, Some("Threads"), transcript).expect("ingest");
+    assert_eq!(summary.inserted_events, 5);
+    assert!(summary.warnings.is_empty(), "{:?}", summary.warnings);
+
+    assert_eq!(
+        events(&conn),
+        vec![
+            (1, "message".to_string(), None, "[]".to_string()),
+            (2, "thread_reply".to_string(), Some(5), "[]".to_string()),
+            (3, "acknowledged".to_string(), Some(1), r#"["eyes"]"#.to_string()),
+            (4, "mitigated".to_string(), Some(1), r#"["+1","white_check_mark"]"#.to_string()),
+            (5, "message".to_string(), None, "[]".to_string()),
+        ]
+    );
+
+    // The original message is kept alongside the thread references.
+    let (user, reply): (String, bool) = conn
+        .query_row(
+            "SELECT json_extract(raw_json, '$.message.user'), json_extract(raw_json, '$.is_thread_reply')
+               FROM timeline_events WHERE id = 4",
+            [],
+            |r| Ok((r.get(0)?, r.get(1)?)),
+        )
+        .expect("raw_json");
+    assert_eq!((user.as_str(), reply), ("U2", true));
+}
+
+#[test]
+fn strongest_reaction_wins_and_orphan_replies_warn() {
+    let mut conn = empty_db().expect("db");
+    let transcript = r#"[
+      {"type":"message","user":"U1","text":"Paging the DB team","ts":"1700000000.000000",
+       "reactions":[{"name":"eyes","count":1},{"name":"rotating_light","count":2}]},
+      {"type":"message","user":"U2","text":"Replica lag is back to normal","ts":"1700000100.000000","thread_ts":"1699999000.000000",
+       "reactions":[{"name":"rotating_light","count":1},{"name":"heavy_check_mark","count":1}]}
+    ]"#;
+
+    let summary = ingest_slack_transcript_text(&mut conn, None, Some("Reactions"), transcript).expect("ingest");
+    let kinds: Vec<String> = events(&conn).into_iter().map(|(_, kind, _, _)| kind).collect();
+    assert_eq!(kinds, vec!["escalated".to_string(), "mitigated".to_string()]);
+
+    let warnings: Vec<(String, Option<String>)> =
+        summary.warnings.iter().map(|w| (w.code.clone(), w.details.clone())).collect();
+    assert_eq!(
+        warnings,
+        vec![(
+            "INGEST_SLACK_THREAD_PARENT_MISSING".to_string(),
+            Some("index=1; thread_ts=1699999000.000000".to_string())
+        )]
+    );
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::progress::Progress;
+use qir_core::report::{export_static_site, StaticSiteOptions};
+use qir_core::testing::{db_with_incidents, IncidentBuilder};
+use rusqlite::Connection;
+
+const GENERATED_AT: &str = "2026-04-02T09:00:00Z";
+
+fn setup() -> Connection {
+    let conn = db_with_incidents(&[
+        IncidentBuilder::new(1, "Checkout <errors>")
+            .external_id("INC-1")
+            .severity("SEV1")
+            .vendor("Acme")
+            .service("checkout")
+            .start("2026-01-05T14:00:00Z")
+            .resolve("2026-01-05T16:00:00Z")
+            .build(),
+        IncidentBuilder::new(2, "Search latency")
+            .external_id("INC-2")
+            .severity("SEV3")
+            .vendor("Acme")
+            .service("search")
+            .start("2026-02-10T09:00:00Z")
+            .build(),
+        IncidentBuilder::new(3, "Login outage").external_id("INC-3").severity("SEV2").start("2026-04-03T10:00:00Z").build(),
+    ])
+    .expect("db");
+    conn.execute(
+        "INSERT INTO timeline_events(incident_id, source, ts, author, kind, text, created_at)
+         VALUES (1, 'slack', '2026-01-05T14:05:00Z', 'alice', 'message', 'Rolled back & paged vendor', '2026-04-01T00:00:00Z')",
+        [],
+    )
+    .expect("seed");
+    conn
+}
+
+fn read(dir: &std::path::Path, rel: &str) -> String {
+    std::fs::read_to_string(dir.join(rel)).expect(rel)
+}
+
+#[test]
+fn writes_index_dashboard_and_incident_pages() {
+    let conn = setup();
+    let dest = tempfile::tempdir().expect("tempdir");
+    let res = export_static_site(
+        &conn,
+        &StaticSiteOptions::default(),
+        None,
+        dest.path(),
+        GENERATED_AT,
+        &mut Progress::noop("export_static_site"),
+    )
+    .expect("export");
+
+    assert_eq!(res.incident_count, 3);
+    assert_eq!(
+        res.files,
+        vec![
+            "dashboard.html",
+            "incidents/incident-1.html",
+            "incidents/incident-2.html",
+            "incidents/incident-3.html",
+            "index.html",
+            "style.css",
+        ]
+    );
+    let site = std::path::PathBuf::from(&res.site_dir);
+    assert!(site.ends_with("IncidentReviewSite_2026_04_02T09_00_00Z"));
+    assert!(!site.with_extension("export_tmp").exists());
+
+    let index = read(&site, "index.html");
+    assert!(index.contains("<a href=\"incidents/incident-1.html\">Checkout &lt;errors&gt;</a>"));
+    assert!(index.contains("href=\"style.css\""));
+
+    let dashboard = read(&site, "dashboard.html");
+    assert_eq!(dashboard.matches("<svg ").count(), 5);
+    assert!(dashboard.contains("aria-label=\"Incidents by severity\""));
+
+    let incident = read(&site, "incidents/incident-1.html");
+    assert!(incident.contains("href=\"../index.html\""));
+    assert!(incident.contains("Rolled back &amp; paged vendor"));
+    assert!(incident.contains("<tr><th>MTTR</th><td>2h 0m</td></tr>"));
+    assert!(read(&site, "incidents/incident-3.html").contains("No timeline events."));
+}
+
+#[test]
+fn quarter_scope_limits_pages() {
+    let conn = setup();
+    let dest = tempfile::tempdir().expect("tempdir");
+    let res = export_static_site(
+        &conn,
+        &StaticSiteOptions {
+            quarter_label: Some("Q1 2026".to_string()),
+        },
+        None,
+        dest.path(),
+        GENERATED_AT,
+        &mut Progress::noop("export_static_site"),
+    )
+    .expect("export");
+    assert_eq!(res.incident_count, 2);
+    assert!(!res.files.contains(&"incidents/incident-3.html".to_string()));
+    let index = read(std::path::Path::new(&res.site_dir), "index.html");
+    assert!(index.contains("Quarter scope: <strong>Q1 2026</strong>"));
+    assert!(!index.contains("Login outage"));
+}
+
+#[test]
+fn refuses_existing_site_folder() {
+    let conn = setup();
+    let dest = tempfile::tempdir().expect("tempdir");
+    let opts = StaticSiteOptions::default();
+    export_static_site(&conn, &opts, None, dest.path(), GENERATED_AT, &mut Progress::noop("export_static_site"))
+        .expect("first export");
+    let err = export_static_site(&conn, &opts, None, dest.path(), GENERATED_AT, &mut Progress::noop("export_static_site"))
+        .unwrap_err();
+    assert_eq!(err.code, "EXPORT_SITE_DEST_EXISTS");
+
+    let file = dest.path().join("not_a_dir.txt");
+    std::fs::write(&file, "x").expect("write");
+    let err = export_static_site(&conn, &opts, None, &file, GENERATED_AT, &mut Progress::noop("export_static_site"))
+        .unwrap_err();
+    assert_eq!(err.code, "EXPORT_SITE_DEST_NOT_DIR");
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "testing")]
+// Run with: cargo test -p qir_core --features testing
+
+use pretty_assertions::assert_eq;
+
+use qir_core::analytics::build_dashboard_payload_v2;
+use qir_core::metrics::compute_incident_metrics;
+use qir_core::repo::list_incidents;
+use qir_core::testing::{
+    db_from_csv, db_with_incidents, demo_db, empty_db, IncidentBuilder, JIRA_DUPLICATE_EXTERNAL_ID_CSV,
+    JIRA_SAMPLE_CSV, JIRA_STORY_CSV,
+};
+
+#[test]
+fn builder_fills_only_requested_fields() {
+    let inc = IncidentBuilder::new(7, "Checkout down")
+        .external_id("INC-7")
+        .severity("SEV1")
+        .start("2026-01-05T10:00:00Z")
+        .resolve("2026-01-05T11:00:00Z")
+        .build();
+    assert_eq!(inc.fingerprint, "fp-7");
+    assert_eq!(inc.vendor, None);
+    assert_eq!(compute_incident_metrics(&inc).0.mttr_seconds, Some(3600));
+
+    let raw = IncidentBuilder::new(8, "Bad clock").start_raw("01/05/2026 12:00").build();
+    assert_eq!((raw.start_ts, raw.start_ts_raw.as_deref()), (None, Some("01/05/2026 12:00")));
+}
+
+#[test]
+fn workspaces_from_incidents_csvs_and_demo() {
+    assert!(list_incidents(&empty_db().expect("empty")).expect("list").is_empty());
+
+    let incidents = vec![
+        IncidentBuilder::new(3, "A").vendor("AcmeCloud").start("2026-02-01T00:00:00Z").build(),
+        IncidentBuilder::new(9, "B").vendor("AcmeCloud").build(),
+    ];
+    let conn = db_with_incidents(&incidents).expect("db");
+    let mut listed = list_incidents(&conn).expect("list");
+    listed.sort_by_key(|i| i.id);
+    assert_eq!(listed, incidents);
+    assert_eq!(build_dashboard_payload_v2(&conn).expect("dash").incident_count, 2);
+
+    assert_eq!(list_incidents(&db_from_csv(JIRA_SAMPLE_CSV).expect("sample")).expect("list").len(), 2);
+    let story = list_incidents(&db_from_csv(JIRA_STORY_CSV).expect("story")).expect("list");
+    // Every story row names a vendor; not every row has a mitigation time (INC-703).
+    assert!(story.iter().all(|i| i.vendor.is_some()));
+    assert_eq!(
+        list_incidents(&db_from_csv(JIRA_DUPLICATE_EXTERNAL_ID_CSV).expect("dup")).expect("list").len(),
+        1
+    );
+
+    assert_eq!(list_incidents(&demo_db().expect("demo")).expect("list").len(), 40);
+}
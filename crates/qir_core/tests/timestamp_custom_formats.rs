@@ -0,0 +1,121 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::generic_csv::{CsvTimestampFormats, GenericCsvMapping};
+use qir_core::ingest::jira_csv::{import_jira_csv, JiraCsvMapping};
+use qir_core::normalize::timestamps::{normalize_timestamp_with_format, CustomTimestampFormat, NaiveTimestampPolicy};
+use qir_core::profiles::jira::{upsert_profile, JiraMappingProfileUpsert};
+use qir_core::testing::{empty_db, fixture_mapping};
+
+const CSV: &str = "Key,Summary,StartTs,ResolveTs\n\
+OPS-1,Checkout errors,05/Jan/26 2:00 PM,2026-01-05 16:30\n";
+
+fn mapping(formats: CsvTimestampFormats) -> JiraCsvMapping {
+    JiraCsvMapping {
+        timestamp_formats: formats,
+        ..fixture_mapping()
+    }
+}
+
+fn jira_created_format() -> CsvTimestampFormats {
+    CsvTimestampFormats {
+        start_ts: Some("%d/%b/%y %-I:%M %p".to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn profile_format_canonicalizes_jira_style_timestamps() {
+    let mut conn = empty_db().expect("db");
+    let summary = import_jira_csv(&mut conn, CSV, &mapping(jira_created_format())).expect("import");
+    let stored: (Option<String>, Option<String>, Option<String>) = conn
+        .query_row("SELECT start_ts, start_ts_raw, resolve_ts FROM incidents", [], |r| {
+            Ok((r.get(0)?, r.get(1)?, r.get(2)?))
+        })
+        .expect("row");
+    // Columns without a format keep using the built-in allowlist.
+    assert_eq!(
+        stored,
+        (
+            Some("2026-01-05T14:00:00Z".to_string()),
+            Some("05/Jan/26 2:00 PM".to_string()),
+            Some("2026-01-05T16:30:00Z".to_string()),
+        )
+    );
+    let assumed = summary
+        .warnings
+        .iter()
+        .find(|w| w.code == "INGEST_TS_TZ_ASSUMED_UTC" && w.message.ends_with("start_ts"))
+        .expect("assumed utc warning");
+    assert_eq!(assumed.details.as_deref(), Some("value=05/Jan/26 2:00 PM; fmt=%d/%b/%y %-I:%M %p"));
+    assert!(!summary.warnings.iter().any(|w| w.code == "INGEST_TS_UNPARSEABLE"));
+}
+
+#[test]
+fn without_a_format_the_same_cell_stays_raw_only() {
+    let mut conn = empty_db().expect("db");
+    let summary = import_jira_csv(&mut conn, CSV, &mapping(CsvTimestampFormats::default())).expect("import");
+    let stored: (Option<String>, Option<String>) = conn
+        .query_row("SELECT start_ts, start_ts_raw FROM incidents", [], |r| Ok((r.get(0)?, r.get(1)?)))
+        .expect("row");
+    assert_eq!(stored, (None, Some("05/Jan/26 2:00 PM".to_string())));
+    assert!(summary.warnings.iter().any(|w| w.code == "INGEST_TS_UNPARSEABLE"));
+}
+
+#[test]
+fn offsets_in_the_pattern_win_over_the_policy() {
+    let format = CustomTimestampFormat::from_strftime("ack_ts", "%d.%m.%Y %H:%M %z").expect("format");
+    let policy = NaiveTimestampPolicy::from_offset_minutes(Some(60)).expect("policy");
+    let mut warnings = Vec::new();
+    let norm = normalize_timestamp_with_format("ack_ts", "05.01.2026 14:00 -0500", Some(&format), policy, &mut warnings);
+    assert_eq!(norm.canonical_rfc3339_utc.as_deref(), Some("2026-01-05T19:00:00Z"));
+    assert!(!warnings.iter().any(|w| w.code.starts_with("INGEST_TS_TZ_ASSUMED")));
+
+    // Two-digit years pivot at 69.
+    let format = CustomTimestampFormat::from_strftime("ack_ts", "%y-%m-%d %H:%M").expect("format");
+    let norm = normalize_timestamp_with_format("ack_ts", "98-01-05 14:00", Some(&format), policy, &mut warnings);
+    assert_eq!(norm.canonical_rfc3339_utc.as_deref(), Some("1998-01-05T13:00:00Z"));
+}
+
+#[test]
+fn unsupported_patterns_are_rejected_on_save_and_import() {
+    let formats = CsvTimestampFormats {
+        resolve_ts: Some("%d/%b/%y %Q".to_string()),
+        ..Default::default()
+    };
+
+    let mut conn = empty_db().expect("db");
+    let err = upsert_profile(
+        &mut conn,
+        JiraMappingProfileUpsert {
+            id: None,
+            name: "Jira Cloud".to_string(),
+            mapping: mapping(formats.clone()),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "VALIDATION_TS_FORMAT_INVALID");
+    assert_eq!(err.details.as_deref(), Some("field=resolve_ts; format=%d/%b/%y %Q; unsupported=%Q"));
+
+    let err = import_jira_csv(&mut conn, CSV, &mapping(formats)).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_TS_FORMAT_INVALID");
+    let count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM incidents", [], |r| r.get(0))
+        .expect("count");
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn legacy_mappings_have_no_formats_and_jira_carries_them() {
+    let legacy: JiraCsvMapping = serde_json::from_str(r#"{"title":"Summary"}"#).expect("legacy mapping");
+    assert_eq!(legacy.timestamp_formats, CsvTimestampFormats::default());
+
+    let jira: JiraCsvMapping =
+        serde_json::from_str(r#"{"title":"Summary","timestamp_formats":{"ack_ts":"%d/%b/%y %I:%M %p"}}"#)
+            .expect("mapping");
+    assert_eq!(
+        GenericCsvMapping::from(&jira).timestamp_formats.ack_ts.as_deref(),
+        Some("%d/%b/%y %I:%M %p")
+    );
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "testing")]
+
+use pretty_assertions::assert_eq;
+
+use qir_core::ingest::generic_csv::{import_generic_csv, GenericCsvMapping};
+use qir_core::ingest::jira_csv::JiraCsvMapping;
+use qir_core::normalize::timestamps::{normalize_timestamp_with_policy, NaiveTimestampPolicy};
+use qir_core::testing::{empty_db, fixture_mapping};
+use rusqlite::Connection;
+
+const CSV: &str = "Key,Summary,StartTs,ResolveTs\n\
+OPS-1,Checkout errors,2026-01-05 14:00,2026-01-05T16:00:00Z\n";
+
+fn mapping(offset: Option<i32>) -> GenericCsvMapping {
+    GenericCsvMapping::from(&JiraCsvMapping {
+        assumed_utc_offset_minutes: offset,
+        ..fixture_mapping()
+    })
+}
+
+fn stored(conn: &Connection) -> (Option<String>, Option<String>, Option<String>, Option<String>) {
+    conn.query_row(
+        "SELECT start_ts, start_ts_raw, resolve_ts, resolve_ts_raw FROM incidents",
+        [],
+        |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?)),
+    )
+    .expect("row")
+}
+
+#[test]
+fn naive_timestamps_default_to_utc() {
+    let mut conn = empty_db().expect("db");
+    let summary = import_generic_csv(&mut conn, CSV, &mapping(None)).expect("import");
+    assert_eq!(
+        stored(&conn),
+        (
+            Some("2026-01-05T14:00:00Z".to_string()),
+            Some("2026-01-05 14:00".to_string()),
+            Some("2026-01-05T16:00:00Z".to_string()),
+            None,
+        )
+    );
+    assert!(summary.warnings.iter().any(|w| w.code == "INGEST_TS_TZ_ASSUMED_UTC"));
+    assert!(!summary.warnings.iter().any(|w| w.code == "INGEST_TS_TZ_ASSUMED_OFFSET"));
+}
+
+#[test]
+fn configured_offset_applies_to_naive_timestamps_only() {
+    let mut conn = empty_db().expect("db");
+    let summary = import_generic_csv(&mut conn, CSV, &mapping(Some(330))).expect("import");
+    // The RFC3339 resolve cell carries its own zone and is untouched.
+    assert_eq!(
+        stored(&conn),
+        (
+            Some("2026-01-05T08:30:00Z".to_string()),
+            Some("2026-01-05 14:00".to_string()),
+            Some("2026-01-05T16:00:00Z".to_string()),
+            None,
+        )
+    );
+    let assumed: Vec<_> = summary
+        .warnings
+        .iter()
+        .filter(|w| w.code == "INGEST_TS_TZ_ASSUMED_OFFSET")
+        .collect();
+    assert_eq!(assumed.len(), 1);
+    assert_eq!(assumed[0].message, "Assumed UTC+05:30 for start_ts");
+    assert_eq!(
+        assumed[0].details.as_deref(),
+        Some("value=2026-01-05 14:00; fmt=[year]-[month]-[day] [hour]:[minute]; offset=+05:30")
+    );
+    assert!(!summary.warnings.iter().any(|w| w.code == "INGEST_TS_TZ_ASSUMED_UTC"));
+}
+
+#[test]
+fn policy_is_deterministic_for_negative_and_zero_offsets() {
+    let mut warnings = Vec::new();
+    let policy = NaiveTimestampPolicy::from_offset_minutes(Some(-300)).expect("policy");
+    let a = normalize_timestamp_with_policy("start_ts", "2026-01-05T14:00", policy, &mut warnings);
+    let b = normalize_timestamp_with_policy("start_ts", "2026-01-05T14:00", policy, &mut warnings);
+    assert_eq!(a, b);
+    assert_eq!(a.canonical_rfc3339_utc.as_deref(), Some("2026-01-05T19:00:00Z"));
+    assert_eq!(a.raw.as_deref(), Some("2026-01-05T14:00"));
+    assert!(warnings[0].details.as_deref().unwrap().ends_with("offset=-05:00"));
+
+    // An explicit zero offset is still recorded, unlike the implicit UTC default.
+    let mut warnings = Vec::new();
+    let zero = NaiveTimestampPolicy::from_offset_minutes(Some(0)).expect("policy");
+    normalize_timestamp_with_policy("ack_ts", "2026-01-05 14:00:30", zero, &mut warnings);
+    assert_eq!(warnings[0].code, "INGEST_TS_TZ_ASSUMED_OFFSET");
+    assert_eq!(warnings[0].message, "Assumed UTC+00:00 for ack_ts");
+}
+
+#[test]
+fn rejects_out_of_range_offsets_before_importing() {
+    let mut conn = empty_db().expect("db");
+    let err = import_generic_csv(&mut conn, CSV, &mapping(Some(15 * 60))).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_UTC_OFFSET_INVALID");
+    assert_eq!(err.details.as_deref(), Some("assumed_utc_offset_minutes=900"));
+    let count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM incidents", [], |r| r.get(0))
+        .expect("count");
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn jira_mappings_carry_the_offset() {
+    let jira: JiraCsvMapping =
+        serde_json::from_str(r#"{"title":"Summary","assumed_utc_offset_minutes":-480}"#).expect("mapping");
+    assert_eq!(GenericCsvMapping::from(&jira).assumed_utc_offset_minutes, Some(-480));
+
+    let legacy: JiraCsvMapping = serde_json::from_str(r#"{"title":"Summary"}"#).expect("legacy mapping");
+    assert_eq!(legacy.assumed_utc_offset_minutes, None);
+}
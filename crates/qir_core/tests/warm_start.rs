@@ -0,0 +1,52 @@
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+use qir_core::analytics::build_dashboard_payload_v2;
+use qir_core::cache::{cached_dashboard_v2, warm_start_precompute, DashboardCache, DerivedSource};
+use qir_core::demo::seed_demo_dataset;
+use qir_core::validate::validate_all_incidents;
+use qir_core::workspace::{create_workspace_connection, open_workspace_connection};
+
+#[test]
+fn warm_start_persists_payloads_for_the_next_open() {
+    let tmp = tempdir().unwrap();
+    let path = tmp.path().join("warm.sqlite");
+    {
+        let mut conn = create_workspace_connection(&path).expect("create");
+        seed_demo_dataset(&mut conn).expect("seed");
+
+        let first = warm_start_precompute(&conn, &DashboardCache::new()).expect("warm start");
+        assert_eq!(first.dashboard_source, DerivedSource::Computed);
+        assert_eq!(first.validation_source, DerivedSource::Computed);
+        assert_eq!(first.incident_count, 40);
+    }
+
+    // Fresh process: empty memory cache, payloads come from the derived table.
+    let conn = open_workspace_connection(&path).expect("reopen");
+    let cache = DashboardCache::new();
+    let second = warm_start_precompute(&conn, &cache).expect("warm start again");
+    assert_eq!(second.dashboard_source, DerivedSource::Persisted);
+    assert_eq!(second.validation_source, DerivedSource::Persisted);
+
+    let (dash, _, source) = cached_dashboard_v2(&conn, &cache).expect("cached");
+    assert_eq!(source, DerivedSource::Memory);
+    assert_eq!(dash, build_dashboard_payload_v2(&conn).unwrap());
+
+    let (report, _, _) = qir_core::cache::cached_validation_report(&conn).expect("validation");
+    assert_eq!(report, validate_all_incidents(&conn).unwrap());
+}
+
+#[test]
+fn stale_persisted_payload_is_never_served() {
+    let tmp = tempdir().unwrap();
+    let path = tmp.path().join("stale.sqlite");
+    let mut conn = create_workspace_connection(&path).expect("create");
+    seed_demo_dataset(&mut conn).expect("seed");
+    warm_start_precompute(&conn, &DashboardCache::new()).expect("warm start");
+
+    conn.execute("UPDATE incidents SET severity = 'SEV3' WHERE external_id = 'IR-001'", [])
+        .unwrap();
+    let (dash, _, source) = cached_dashboard_v2(&conn, &DashboardCache::new()).expect("cached");
+    assert_eq!(source, DerivedSource::Computed);
+    assert_eq!(dash, build_dashboard_payload_v2(&conn).unwrap());
+}
@@ -0,0 +1,56 @@
+use qir_core::analytics::build_weekday_hour_heatmap;
+use qir_core::db;
+use rusqlite::Connection;
+
+fn insert(conn: &Connection, id: i64, start: Option<&str>, first_observed: Option<&str>) {
+    conn.execute(
+        "INSERT INTO incidents(id, fingerprint, title, start_ts, first_observed_ts, ingested_at)
+         VALUES (?1, ?2, 'Incident', ?3, ?4, '2026-07-01T00:00:00Z')",
+        rusqlite::params![id, format!("fp-{id}"), start, first_observed],
+    )
+    .expect("insert");
+}
+
+fn setup() -> Connection {
+    let mut conn = db::open_in_memory().expect("open");
+    db::migrate(&mut conn).expect("migrate");
+    // Fridays 2026-01-09 and 2026-04-10, both in the 16:00 UTC deploy window.
+    insert(&conn, 1, Some("2026-01-09T16:05:00Z"), Some("2026-01-09T16:20:00Z"));
+    insert(&conn, 2, Some("2026-04-10T16:40:00Z"), None);
+    // Sunday 23:30 UTC is Monday 00:30 at UTC+01:00.
+    insert(&conn, 3, Some("2026-01-11T23:30:00Z"), Some("2026-01-12T00:10:00Z"));
+    insert(&conn, 4, None, None);
+    conn
+}
+
+#[test]
+fn counts_starts_and_detections_by_weekday_and_hour() {
+    let conn = setup();
+    let h = build_weekday_hour_heatmap(&conn, None, 0).expect("heatmap");
+    assert_eq!(h.weekdays.len(), 7);
+    assert!(h.starts.counts.iter().all(|row| row.len() == 24));
+
+    assert_eq!(h.starts.counts[4][16], 2);
+    assert_eq!(h.starts.counts[6][23], 1);
+    assert_eq!((h.starts.total, h.starts.unknown_count), (3, 1));
+
+    assert_eq!(h.detections.counts[4][16], 1);
+    assert_eq!(h.detections.counts[0][0], 1);
+    assert_eq!((h.detections.total, h.detections.unknown_count), (2, 2));
+}
+
+#[test]
+fn offset_and_quarter_scope_are_applied() {
+    let conn = setup();
+    let shifted = build_weekday_hour_heatmap(&conn, None, 60).expect("heatmap");
+    assert_eq!(shifted.starts.counts[0][0], 1);
+    assert_eq!(shifted.starts.counts[4][17], 2);
+
+    let q1 = build_weekday_hour_heatmap(&conn, Some("Q1 2026"), 0).expect("q1");
+    assert_eq!(q1.quarter_label.as_deref(), Some("Q1 2026"));
+    assert_eq!(q1.starts.counts[4][16], 1);
+    assert_eq!((q1.starts.total, q1.starts.unknown_count), (2, 0));
+
+    let err = build_weekday_hour_heatmap(&conn, None, 15 * 60).unwrap_err();
+    assert_eq!(err.code, "VALIDATION_UTC_OFFSET_INVALID");
+}
@@ -0,0 +1,61 @@
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+use qir_core::demo::seed_demo_dataset;
+use qir_core::workspace::{
+    create_workspace_connection, get_workspace_lineage, open_workspace_connection,
+    snapshot_branch_workspace,
+};
+
+fn count_incidents(conn: &rusqlite::Connection) -> i64 {
+    conn.query_row("SELECT COUNT(*) FROM incidents", [], |row| row.get(0))
+        .unwrap()
+}
+
+#[test]
+fn branch_clones_data_and_records_lineage_without_touching_source() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source.sqlite");
+    let branch = tmp.path().join("scratch").join("what_if.sqlite");
+
+    let mut conn = create_workspace_connection(&source).expect("create");
+    seed_demo_dataset(&mut conn).expect("seed");
+    let source_count = count_incidents(&conn);
+
+    let res = snapshot_branch_workspace(&conn, &source, &branch, "reclassify vendors", "2026-02-10T03:00:00Z")
+        .expect("branch");
+    assert!(!res.is_empty);
+    assert_eq!(res.lineage.branch_label, "reclassify vendors");
+    assert_eq!(res.lineage.derived_from_db_path, source.to_string_lossy());
+
+    // Experiment in the branch.
+    let branch_conn = open_workspace_connection(&branch).expect("open branch");
+    assert_eq!(count_incidents(&branch_conn), source_count);
+    branch_conn
+        .execute("UPDATE incidents SET vendor = 'Reclassified'", [])
+        .unwrap();
+
+    // Source of truth is untouched and is not marked as a branch.
+    let source_conn = open_workspace_connection(&source).expect("open source");
+    let reclassified: i64 = source_conn
+        .query_row("SELECT COUNT(*) FROM incidents WHERE vendor = 'Reclassified'", [], |r| r.get(0))
+        .unwrap();
+    assert_eq!(reclassified, 0);
+    assert_eq!(get_workspace_lineage(&source_conn).unwrap(), None);
+    assert!(!branch.with_extension("branch_tmp").exists());
+}
+
+#[test]
+fn branch_refuses_existing_destination_and_empty_label() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source.sqlite");
+    let conn = create_workspace_connection(&source).expect("create");
+
+    let err = snapshot_branch_workspace(&conn, &source, &source, "x", "2026-02-10T03:00:00Z")
+        .expect_err("same path");
+    assert_eq!(err.code, "WORKSPACE_BRANCH_FAILED");
+
+    let err = snapshot_branch_workspace(&conn, &source, &tmp.path().join("b.sqlite"), "  ", "2026-02-10T03:00:00Z")
+        .expect_err("label");
+    assert_eq!(err.code, "WORKSPACE_BRANCH_LABEL_REQUIRED");
+}
@@ -0,0 +1,50 @@
+use std::fs;
+
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+use qir_core::workspace::plan_workspace_config_repair;
+
+#[test]
+fn healthy_config_needs_no_repair() {
+    let tmp = tempdir().unwrap();
+    let db = tmp.path().join("ws.sqlite");
+    fs::write(&db, b"").unwrap();
+
+    let repair = plan_workspace_config_repair(Some(&db), std::slice::from_ref(&db), &[]);
+    assert!(!repair.needs_repair());
+    assert_eq!(repair.recent_db_paths, vec![db.to_string_lossy().to_string()]);
+}
+
+#[test]
+fn missing_last_db_offers_fallbacks_and_prunes_dead_recents() {
+    let old = tempdir().unwrap();
+    let data = tempdir().unwrap();
+    let missing = old.path().join("team.sqlite");
+    let gone_recent = old.path().join("old.sqlite");
+    let live_recent = data.path().join("other.sqlite");
+    let default_db = data.path().join("incidentreview.sqlite");
+    let moved = data.path().join("team.sqlite");
+    for p in [&live_recent, &default_db, &moved] {
+        fs::write(p, b"").unwrap();
+    }
+
+    let repair = plan_workspace_config_repair(
+        Some(&missing),
+        &[gone_recent.clone(), live_recent.clone()],
+        &[default_db.clone(), old.path().join("incidentreview.sqlite")],
+    );
+    assert!(repair.needs_repair());
+    assert_eq!(repair.missing_db_path, Some(missing.to_string_lossy().to_string()));
+    assert_eq!(repair.pruned_recent_db_paths, vec![gone_recent.to_string_lossy().to_string()]);
+    assert_eq!(repair.recent_db_paths, vec![live_recent.to_string_lossy().to_string()]);
+    // A same-named DB in a fallback folder ranks first, then existing fallbacks, then recents.
+    assert_eq!(
+        repair.candidates,
+        vec![
+            moved.to_string_lossy().to_string(),
+            default_db.to_string_lossy().to_string(),
+            live_recent.to_string_lossy().to_string(),
+        ]
+    );
+}
@@ -0,0 +1,9 @@
+-- Workspace-scoped settings (dashboard tuning, taxonomies, report templates, SLOs,
+-- sanitization policy). Values are JSON documents keyed by a stable dotted key.
+-- Settings never contain incident data, so they can be shared via settings bundles.
+
+CREATE TABLE IF NOT EXISTS workspace_settings (
+  key TEXT PRIMARY KEY NOT NULL,
+  value_json TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
@@ -0,0 +1,21 @@
+-- Close-the-books mode: a frozen quarter locks its incidents against modification so
+-- published report numbers cannot silently drift. Unlocking is explicit and audited.
+-- An incident belongs to the quarter of its start_ts (fallback: first_observed_ts).
+
+CREATE TABLE IF NOT EXISTS quarter_freezes (
+  quarter_label TEXT PRIMARY KEY NOT NULL,
+  frozen_at TEXT NOT NULL,
+  frozen_by TEXT,
+  note TEXT
+);
+
+CREATE TABLE IF NOT EXISTS quarter_freeze_audit (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  quarter_label TEXT NOT NULL,
+  action TEXT NOT NULL CHECK (action IN ('freeze', 'unlock')),
+  actor TEXT,
+  reason TEXT,
+  created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_quarter_freeze_audit_label ON quarter_freeze_audit(quarter_label, id);
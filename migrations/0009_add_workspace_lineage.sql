@@ -0,0 +1,10 @@
+-- Snapshot-and-branch workspaces: a branched workspace records which workspace it was
+-- cloned from so what-if analysis is never mistaken for the source of truth.
+-- Single-row table (id = 1); absent row means the workspace is not a branch.
+
+CREATE TABLE IF NOT EXISTS workspace_lineage (
+  id INTEGER PRIMARY KEY NOT NULL CHECK (id = 1),
+  derived_from_db_path TEXT NOT NULL,
+  branch_label TEXT NOT NULL,
+  branched_at TEXT NOT NULL
+);
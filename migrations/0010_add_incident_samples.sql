@@ -0,0 +1,13 @@
+-- Deterministic audit samples. The seed, filter and selected incident ids are recorded so a
+-- sample can be reproduced (and checked for drift) later. Selection is a pure function of
+-- (seed, filter, incident population); no RNG state is persisted.
+
+CREATE TABLE IF NOT EXISTS incident_samples (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  seed TEXT NOT NULL,
+  requested_n INTEGER NOT NULL,
+  filter_json TEXT NOT NULL,
+  population_count INTEGER NOT NULL,
+  incident_ids_json TEXT NOT NULL,
+  created_at TEXT NOT NULL
+);
@@ -0,0 +1,210 @@
+-- Field-level temporal history for incidents ("what did we believe at review time?").
+-- Every insert/update/delete of an incident row records one history row per changed field
+-- with the effective timestamp, via triggers, so no mutating code path can bypass it.
+-- Values are stored as TEXT (integers are cast); NULL means "unknown" as in incidents.
+-- effective_at uses millisecond precision; ties are ordered by id.
+
+CREATE TABLE IF NOT EXISTS incident_field_history (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  incident_id INTEGER NOT NULL,
+  field TEXT NOT NULL,
+  change_kind TEXT NOT NULL CHECK (change_kind IN ('insert', 'update', 'delete')),
+  old_value TEXT,
+  new_value TEXT,
+  effective_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_incident_field_history_incident
+  ON incident_field_history(incident_id, field, effective_at, id);
+CREATE INDEX IF NOT EXISTS idx_incident_field_history_effective_at
+  ON incident_field_history(effective_at, id);
+
+-- Backfill: existing incidents are recorded as inserted at their ingested_at time.
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'external_id', 'insert', NULL, external_id, ingested_at FROM incidents WHERE external_id IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'fingerprint', 'insert', NULL, fingerprint, ingested_at FROM incidents;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'title', 'insert', NULL, title, ingested_at FROM incidents;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'description', 'insert', NULL, description, ingested_at FROM incidents WHERE description IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'severity', 'insert', NULL, severity, ingested_at FROM incidents WHERE severity IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'detection_source', 'insert', NULL, detection_source, ingested_at FROM incidents WHERE detection_source IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'vendor', 'insert', NULL, vendor, ingested_at FROM incidents WHERE vendor IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'service', 'insert', NULL, service, ingested_at FROM incidents WHERE service IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'impact_pct', 'insert', NULL, CAST(impact_pct AS TEXT), ingested_at FROM incidents WHERE impact_pct IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'service_health_pct', 'insert', NULL, CAST(service_health_pct AS TEXT), ingested_at FROM incidents WHERE service_health_pct IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'start_ts', 'insert', NULL, start_ts, ingested_at FROM incidents WHERE start_ts IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'first_observed_ts', 'insert', NULL, first_observed_ts, ingested_at FROM incidents WHERE first_observed_ts IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'it_awareness_ts', 'insert', NULL, it_awareness_ts, ingested_at FROM incidents WHERE it_awareness_ts IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'ack_ts', 'insert', NULL, ack_ts, ingested_at FROM incidents WHERE ack_ts IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'mitigate_ts', 'insert', NULL, mitigate_ts, ingested_at FROM incidents WHERE mitigate_ts IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'resolve_ts', 'insert', NULL, resolve_ts, ingested_at FROM incidents WHERE resolve_ts IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'start_ts_raw', 'insert', NULL, start_ts_raw, ingested_at FROM incidents WHERE start_ts_raw IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'first_observed_ts_raw', 'insert', NULL, first_observed_ts_raw, ingested_at FROM incidents WHERE first_observed_ts_raw IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'it_awareness_ts_raw', 'insert', NULL, it_awareness_ts_raw, ingested_at FROM incidents WHERE it_awareness_ts_raw IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'ack_ts_raw', 'insert', NULL, ack_ts_raw, ingested_at FROM incidents WHERE ack_ts_raw IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'mitigate_ts_raw', 'insert', NULL, mitigate_ts_raw, ingested_at FROM incidents WHERE mitigate_ts_raw IS NOT NULL;
+INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+  SELECT id, 'resolve_ts_raw', 'insert', NULL, resolve_ts_raw, ingested_at FROM incidents WHERE resolve_ts_raw IS NOT NULL;
+
+CREATE TRIGGER IF NOT EXISTS trg_incidents_history_insert AFTER INSERT ON incidents
+BEGIN
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'external_id', 'insert', NULL, NEW.external_id, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.external_id IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'fingerprint', 'insert', NULL, NEW.fingerprint, strftime('%Y-%m-%dT%H:%M:%fZ','now');
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'title', 'insert', NULL, NEW.title, strftime('%Y-%m-%dT%H:%M:%fZ','now');
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'description', 'insert', NULL, NEW.description, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.description IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'severity', 'insert', NULL, NEW.severity, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.severity IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'detection_source', 'insert', NULL, NEW.detection_source, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.detection_source IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'vendor', 'insert', NULL, NEW.vendor, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.vendor IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'service', 'insert', NULL, NEW.service, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.service IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'impact_pct', 'insert', NULL, CAST(NEW.impact_pct AS TEXT), strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.impact_pct IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'service_health_pct', 'insert', NULL, CAST(NEW.service_health_pct AS TEXT), strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.service_health_pct IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'start_ts', 'insert', NULL, NEW.start_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.start_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'first_observed_ts', 'insert', NULL, NEW.first_observed_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.first_observed_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'it_awareness_ts', 'insert', NULL, NEW.it_awareness_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.it_awareness_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'ack_ts', 'insert', NULL, NEW.ack_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.ack_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'mitigate_ts', 'insert', NULL, NEW.mitigate_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.mitigate_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'resolve_ts', 'insert', NULL, NEW.resolve_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.resolve_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'start_ts_raw', 'insert', NULL, NEW.start_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.start_ts_raw IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'first_observed_ts_raw', 'insert', NULL, NEW.first_observed_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.first_observed_ts_raw IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'it_awareness_ts_raw', 'insert', NULL, NEW.it_awareness_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.it_awareness_ts_raw IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'ack_ts_raw', 'insert', NULL, NEW.ack_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.ack_ts_raw IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'mitigate_ts_raw', 'insert', NULL, NEW.mitigate_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.mitigate_ts_raw IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'resolve_ts_raw', 'insert', NULL, NEW.resolve_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE NEW.resolve_ts_raw IS NOT NULL;
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_incidents_history_update AFTER UPDATE ON incidents
+BEGIN
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'external_id', 'update', OLD.external_id, NEW.external_id, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.external_id IS NOT NEW.external_id;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'fingerprint', 'update', OLD.fingerprint, NEW.fingerprint, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.fingerprint IS NOT NEW.fingerprint;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'title', 'update', OLD.title, NEW.title, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.title IS NOT NEW.title;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'description', 'update', OLD.description, NEW.description, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.description IS NOT NEW.description;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'severity', 'update', OLD.severity, NEW.severity, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.severity IS NOT NEW.severity;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'detection_source', 'update', OLD.detection_source, NEW.detection_source, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.detection_source IS NOT NEW.detection_source;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'vendor', 'update', OLD.vendor, NEW.vendor, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.vendor IS NOT NEW.vendor;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'service', 'update', OLD.service, NEW.service, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.service IS NOT NEW.service;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'impact_pct', 'update', CAST(OLD.impact_pct AS TEXT), CAST(NEW.impact_pct AS TEXT), strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.impact_pct IS NOT NEW.impact_pct;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'service_health_pct', 'update', CAST(OLD.service_health_pct AS TEXT), CAST(NEW.service_health_pct AS TEXT), strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.service_health_pct IS NOT NEW.service_health_pct;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'start_ts', 'update', OLD.start_ts, NEW.start_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.start_ts IS NOT NEW.start_ts;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'first_observed_ts', 'update', OLD.first_observed_ts, NEW.first_observed_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.first_observed_ts IS NOT NEW.first_observed_ts;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'it_awareness_ts', 'update', OLD.it_awareness_ts, NEW.it_awareness_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.it_awareness_ts IS NOT NEW.it_awareness_ts;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'ack_ts', 'update', OLD.ack_ts, NEW.ack_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.ack_ts IS NOT NEW.ack_ts;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'mitigate_ts', 'update', OLD.mitigate_ts, NEW.mitigate_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.mitigate_ts IS NOT NEW.mitigate_ts;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'resolve_ts', 'update', OLD.resolve_ts, NEW.resolve_ts, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.resolve_ts IS NOT NEW.resolve_ts;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'start_ts_raw', 'update', OLD.start_ts_raw, NEW.start_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.start_ts_raw IS NOT NEW.start_ts_raw;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'first_observed_ts_raw', 'update', OLD.first_observed_ts_raw, NEW.first_observed_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.first_observed_ts_raw IS NOT NEW.first_observed_ts_raw;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'it_awareness_ts_raw', 'update', OLD.it_awareness_ts_raw, NEW.it_awareness_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.it_awareness_ts_raw IS NOT NEW.it_awareness_ts_raw;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'ack_ts_raw', 'update', OLD.ack_ts_raw, NEW.ack_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.ack_ts_raw IS NOT NEW.ack_ts_raw;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'mitigate_ts_raw', 'update', OLD.mitigate_ts_raw, NEW.mitigate_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.mitigate_ts_raw IS NOT NEW.mitigate_ts_raw;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT NEW.id, 'resolve_ts_raw', 'update', OLD.resolve_ts_raw, NEW.resolve_ts_raw, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.resolve_ts_raw IS NOT NEW.resolve_ts_raw;
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_incidents_history_delete AFTER DELETE ON incidents
+BEGIN
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'external_id', 'delete', OLD.external_id, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.external_id IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'fingerprint', 'delete', OLD.fingerprint, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now');
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'title', 'delete', OLD.title, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now');
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'description', 'delete', OLD.description, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.description IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'severity', 'delete', OLD.severity, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.severity IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'detection_source', 'delete', OLD.detection_source, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.detection_source IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'vendor', 'delete', OLD.vendor, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.vendor IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'service', 'delete', OLD.service, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.service IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'impact_pct', 'delete', CAST(OLD.impact_pct AS TEXT), NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.impact_pct IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'service_health_pct', 'delete', CAST(OLD.service_health_pct AS TEXT), NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.service_health_pct IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'start_ts', 'delete', OLD.start_ts, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.start_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'first_observed_ts', 'delete', OLD.first_observed_ts, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.first_observed_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'it_awareness_ts', 'delete', OLD.it_awareness_ts, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.it_awareness_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'ack_ts', 'delete', OLD.ack_ts, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.ack_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'mitigate_ts', 'delete', OLD.mitigate_ts, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.mitigate_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'resolve_ts', 'delete', OLD.resolve_ts, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.resolve_ts IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'start_ts_raw', 'delete', OLD.start_ts_raw, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.start_ts_raw IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'first_observed_ts_raw', 'delete', OLD.first_observed_ts_raw, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.first_observed_ts_raw IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'it_awareness_ts_raw', 'delete', OLD.it_awareness_ts_raw, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.it_awareness_ts_raw IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'ack_ts_raw', 'delete', OLD.ack_ts_raw, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.ack_ts_raw IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'mitigate_ts_raw', 'delete', OLD.mitigate_ts_raw, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.mitigate_ts_raw IS NOT NULL;
+  INSERT INTO incident_field_history(incident_id, field, change_kind, old_value, new_value, effective_at)
+    SELECT OLD.id, 'resolve_ts_raw', 'delete', OLD.resolve_ts_raw, NULL, strftime('%Y-%m-%dT%H:%M:%fZ','now') WHERE OLD.resolve_ts_raw IS NOT NULL;
+END;
@@ -0,0 +1,10 @@
+-- Persisted derived payloads (dashboard V2, validation report) for warm starts.
+-- One row per kind, tagged with the content hash it was computed from; a row is served only
+-- when its hash matches the current data, so the table is always safe to clear.
+
+CREATE TABLE IF NOT EXISTS derived_cache (
+  kind TEXT PRIMARY KEY,
+  content_hash TEXT NOT NULL,
+  payload_json TEXT NOT NULL,
+  computed_at TEXT NOT NULL
+);
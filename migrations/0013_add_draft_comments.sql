@@ -0,0 +1,17 @@
+-- Inline review notes on AI drafts. A comment is anchored to a paragraph of the draft text
+-- (0-based index over blank-line separated paragraphs) and can be resolved instead of deleted
+-- so review history stays visible.
+
+CREATE TABLE IF NOT EXISTS draft_comments (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  draft_id INTEGER NOT NULL REFERENCES ai_drafts(id) ON DELETE CASCADE,
+  anchor_paragraph INTEGER NOT NULL CHECK (anchor_paragraph >= 0),
+  author TEXT NOT NULL,
+  text TEXT NOT NULL,
+  resolved INTEGER NOT NULL DEFAULT 0 CHECK (resolved IN (0, 1)),
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL,
+  resolved_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_draft_comments_draft ON draft_comments(draft_id, anchor_paragraph, id);
@@ -0,0 +1,6 @@
+-- Snapshot of the quarter's incident data at draft time, used to flag drafts as stale when
+-- incidents in that quarter are added, edited or removed afterwards.
+-- JSON: {"content_hash": "...", "incidents": {"<incident_id>": "<sha256 of incident row>"}}.
+-- NULL for drafts created before this migration; those are never flagged.
+
+ALTER TABLE ai_drafts ADD COLUMN quarter_snapshot_json TEXT NULL;
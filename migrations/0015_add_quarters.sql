@@ -0,0 +1,16 @@
+-- Explicit quarter definitions. A defined quarter overrides the implicit calendar quarter of
+-- the same label; calendar labels ("Q1 2026") keep working without a row.
+-- Bounds are canonical RFC3339 UTC: start_ts inclusive, end_ts exclusive. Defined quarters
+-- may not overlap so every incident maps to at most one of them.
+
+CREATE TABLE IF NOT EXISTS quarters (
+  label TEXT PRIMARY KEY NOT NULL,
+  start_ts TEXT NOT NULL,
+  end_ts TEXT NOT NULL,
+  fiscal_offset_months INTEGER NOT NULL DEFAULT 0 CHECK (fiscal_offset_months BETWEEN 0 AND 11),
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL,
+  CHECK (start_ts < end_ts)
+);
+
+CREATE INDEX IF NOT EXISTS idx_quarters_start ON quarters(start_ts);
@@ -0,0 +1,8 @@
+-- Quarter each incident is reviewed in, materialized by the quarter assignment rule
+-- (workspace setting calendar.quarter_assignment_anchor: start_ts or resolve_ts).
+-- Recomputed after imports and whenever quarters, the fiscal calendar or the rule change.
+-- NULL when the incident has no usable anchor timestamp.
+
+ALTER TABLE incidents ADD COLUMN assigned_quarter_label TEXT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_incidents_assigned_quarter ON incidents(assigned_quarter_label);
@@ -0,0 +1,14 @@
+-- Follow-up action items raised from incidents. Open until closed; created_at/closed_at may
+-- be backdated when items are recorded after the fact, so quarter carry-over ages are honest.
+
+CREATE TABLE IF NOT EXISTS action_items (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  incident_id INTEGER NOT NULL REFERENCES incidents(id) ON DELETE CASCADE,
+  title TEXT NOT NULL,
+  owner TEXT NULL,
+  done INTEGER NOT NULL DEFAULT 0 CHECK (done IN (0, 1)),
+  created_at TEXT NOT NULL,
+  closed_at TEXT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_action_items_incident ON action_items(incident_id, id);
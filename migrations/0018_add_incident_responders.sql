@@ -0,0 +1,15 @@
+-- People engaged on an incident (on-call, incident commander, SMEs), one row per engagement.
+-- released_at NULL means "until the incident resolved" for time-in-incident accounting.
+
+CREATE TABLE IF NOT EXISTS incident_responders (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  incident_id INTEGER NOT NULL REFERENCES incidents(id) ON DELETE CASCADE,
+  person TEXT NOT NULL,
+  role TEXT NULL,
+  engaged_at TEXT NOT NULL,
+  released_at TEXT NULL,
+  created_at TEXT NOT NULL,
+  UNIQUE (incident_id, person, engaged_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_incident_responders_person ON incident_responders(person, engaged_at);
@@ -0,0 +1,10 @@
+-- Severity an incident was first graded at, for re-grade (triage accuracy) analysis.
+-- incidents.severity stays the final grade. source records whether the initial grade came
+-- from an import column or was entered by hand; manual entries are never overwritten by import.
+
+CREATE TABLE IF NOT EXISTS incident_severity_grades (
+  incident_id INTEGER PRIMARY KEY REFERENCES incidents(id) ON DELETE CASCADE,
+  initial_severity TEXT NOT NULL,
+  source TEXT NOT NULL CHECK (source IN ('ingest', 'manual')),
+  recorded_at TEXT NOT NULL
+);
@@ -0,0 +1,5 @@
+-- Descriptions and Slack message text are normalized to CommonMark at ingest (Jira wiki/ADF
+-- and Slack mrkdwn). The original text is kept here only when normalization changed it.
+
+ALTER TABLE incidents ADD COLUMN description_raw TEXT NULL;
+ALTER TABLE timeline_events ADD COLUMN text_raw TEXT NULL;
@@ -0,0 +1,8 @@
+-- Sign-off on AI drafts. The workspace AI usage policy can require an approval before a draft
+-- is exported or included in a report; revoking deletes the row.
+
+CREATE TABLE IF NOT EXISTS ai_draft_approvals (
+  draft_id INTEGER PRIMARY KEY NOT NULL REFERENCES ai_drafts(id) ON DELETE CASCADE,
+  approved_by TEXT NOT NULL,
+  approved_at TEXT NOT NULL
+);
@@ -0,0 +1,18 @@
+-- One row per logical import operation. A multi-file session records a single row covering
+-- all of its files; per-file detail lives in summary_json.
+
+CREATE TABLE IF NOT EXISTS import_history (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  source TEXT NOT NULL,
+  profile_id INTEGER NULL REFERENCES jira_mapping_profiles(id) ON DELETE SET NULL,
+  file_names_json TEXT NOT NULL,
+  inserted INTEGER NOT NULL,
+  updated INTEGER NOT NULL,
+  skipped INTEGER NOT NULL,
+  conflicts INTEGER NOT NULL,
+  warnings INTEGER NOT NULL,
+  summary_json TEXT NOT NULL,
+  created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_import_history_created_at ON import_history(created_at);
@@ -0,0 +1,5 @@
+-- Live notes typed during an incident are reconciled against events imported afterwards
+-- (Slack, PagerDuty, Statuspage). A note that duplicates an imported event points at it here
+-- and is hidden from timelines; the row is kept so nothing typed live is lost.
+
+ALTER TABLE timeline_events ADD COLUMN superseded_by INTEGER NULL REFERENCES timeline_events(id) ON DELETE SET NULL;
@@ -0,0 +1,22 @@
+-- Every committed import is a batch that can be undone. Per incident the batch keeps the row
+-- as it was before (NULL for inserts) and as the import left it, so undo can refuse to clobber
+-- edits made since. incident_id has no foreign key: the record must outlive a deleted incident.
+
+CREATE TABLE IF NOT EXISTS import_batches (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  source TEXT NOT NULL,
+  row_count INTEGER NOT NULL,
+  created_at TEXT NOT NULL,
+  undone_at TEXT NULL
+);
+
+CREATE TABLE IF NOT EXISTS import_batch_incidents (
+  batch_id INTEGER NOT NULL REFERENCES import_batches(id) ON DELETE CASCADE,
+  incident_id INTEGER NOT NULL,
+  action TEXT NOT NULL CHECK (action IN ('insert', 'update')),
+  before_json TEXT NULL,
+  after_json TEXT NOT NULL,
+  PRIMARY KEY (batch_id, incident_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_import_batch_incidents_incident ON import_batch_incidents(incident_id);
@@ -0,0 +1,11 @@
+-- At most one artifact per incident is its diagram (architecture sketch, graph screenshot),
+-- embedded in HTML exports. The image bytes live under the workspace artifacts dir at
+-- diagrams/<sha256>.<ext>; byte_len and the artifact's sha256 are re-checked on export.
+
+CREATE TABLE IF NOT EXISTS incident_diagrams (
+  incident_id INTEGER PRIMARY KEY NOT NULL REFERENCES incidents(id) ON DELETE CASCADE,
+  artifact_id INTEGER NOT NULL REFERENCES artifacts(id) ON DELETE CASCADE,
+  caption TEXT NULL,
+  byte_len INTEGER NOT NULL,
+  set_at TEXT NOT NULL
+);
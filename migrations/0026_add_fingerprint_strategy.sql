@@ -0,0 +1,5 @@
+-- Fingerprints can be built from a workspace-configured set of fields. Each incident records
+-- the strategy version its fingerprint was computed with so re-imports match it under the same
+-- rules after the workspace strategy changes. NULL (rows from before this column) means v1.
+
+ALTER TABLE incidents ADD COLUMN fingerprint_strategy TEXT NULL;
@@ -0,0 +1,10 @@
+-- Workspace severity vocabulary. Imports rewrite a source value ("P1", "SEV-1", "Critical")
+-- to its mapped severity before storing it, so severity counts do not split one level across
+-- spellings. source_value is matched trimmed and case-insensitively.
+
+CREATE TABLE IF NOT EXISTS severity_mappings (
+  source_value TEXT PRIMARY KEY NOT NULL COLLATE NOCASE,
+  severity TEXT NOT NULL,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
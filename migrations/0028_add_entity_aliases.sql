@@ -0,0 +1,13 @@
+-- Vendor and service aliases ("AWS" == "Amazon Web Services"). Imports store the canonical
+-- name, and dashboard rollups resolve stored values through the same table so incidents
+-- imported before an alias existed are not split across spellings. alias is matched trimmed
+-- and case-insensitively within its kind.
+
+CREATE TABLE IF NOT EXISTS entity_aliases (
+  kind TEXT NOT NULL CHECK (kind IN ('vendor', 'service')),
+  alias TEXT NOT NULL COLLATE NOCASE,
+  canonical TEXT NOT NULL,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL,
+  PRIMARY KEY (kind, alias)
+);
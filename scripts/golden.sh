@@ -0,0 +1,17 @@
+#!/usr/bin/env bash
+# Runs the golden snapshot tests. `--bless` rewrites fixtures/golden/ from the current output.
+set -euo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+
+if [[ "${1:-}" == "--bless" ]]; then
+  export QIR_BLESS=1
+  shift
+fi
+
+cd "${ROOT_DIR}"
+cargo test -p qir_core --features testing --test golden_snapshots "$@"
+
+if [[ "${QIR_BLESS:-}" == "1" ]]; then
+  git status --short fixtures/golden
+fi
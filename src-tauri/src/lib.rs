@@ -1,51 +1,163 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
 use std::sync::Mutex;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use qir_ai::ollama::{OllamaClient, OllamaModelInfo};
 use qir_ai::evidence::{
-    BuildChunksResult as AiBuildChunksResult, EvidenceAddSourceInput as AiEvidenceAddSourceInput,
+    BuildChunksResult as AiBuildChunksResult, Citation as AiCitation, CopyRetryPolicy as AiCopyRetryPolicy,
+    EvidenceAddSourceInput as AiEvidenceAddSourceInput, EvidenceCopySourceInput as AiEvidenceCopySourceInput,
+    EvidenceCopySourceResult as AiEvidenceCopySourceResult,
     EvidenceChunk as AiEvidenceChunk, EvidenceChunkSummary as AiEvidenceChunkSummary,
-    EvidenceContextResponse as AiEvidenceContextResponse, EvidenceOrigin as AiEvidenceOrigin,
+    EvidenceContextResponse as AiEvidenceContextResponse, EvidenceFsckReport as AiEvidenceFsckReport,
+    EvidenceOrigin as AiEvidenceOrigin, IncidentEvidenceLinks as AiIncidentEvidenceLinks, IncidentLinks as AiIncidentLinks,
+    IncidentRef as AiIncidentRef,
     EvidenceQueryStore as AiEvidenceQueryStore, EvidenceSource as AiEvidenceSource,
     EvidenceSourceType as AiEvidenceSourceType, EvidenceStore as AiEvidenceStore,
     AiIndexBuildInput as AiIndexBuildInput, AiIndexStatus as AiIndexStatus, IndexStore as AiIndexStore,
+    AiRechunkResult, AiStoreSettings, ChunkingParams as AiChunkingParams, rechunk_source as ai_rechunk_source,
+    summarize_source as ai_summarize_source, SourceSummaries as AiSourceSummaries,
+    SummarizeSourceInput as AiSummarizeSourceInput,
 };
+use qir_ai::demo::seed_guided_tour_evidence;
 use qir_ai::embeddings::ollama_embed::OllamaEmbedder;
 use qir_ai::llm::ollama_llm::OllamaLlm;
-use qir_ai::retrieve::{query_with_embedder as ai_query_with_embedder, EvidenceQueryResponse as AiEvidenceQueryResponse};
-use qir_ai::draft::{draft_section_with_llm as ai_draft_with_llm, AiDraftResponse as AiDraftResponse, AiDraftSectionRequest as AiDraftSectionRequest, SectionId as AiSectionId};
-use qir_core::analytics::{DashboardPayloadV1, DashboardPayloadV2};
-use qir_core::backup::{BackupCreateResult, BackupManifest, RestoreResult};
-use qir_core::demo::seed_demo_dataset as core_seed_demo_dataset;
-use qir_core::error::AppError;
-use qir_core::repo::{PaginationParams, PaginationResult};
+use qir_ai::queue::{AiOperationQueue, AiQueueGuard, AiQueueSnapshot};
+use qir_ai::retrieve::{
+    boost_incident_linked_hits as ai_boost_incident_linked_hits, query_with_fallback as ai_query_with_fallback,
+    EvidenceQueryResponse as AiEvidenceQueryResponse,
+};
+use qir_ai::draft::appendix::{
+    export_evidence_appendix as ai_export_evidence_appendix, EvidenceAppendixResult as AiEvidenceAppendixResult,
+};
+use qir_ai::draft::export::{export_draft_with_disclaimer as ai_export_draft_with_disclaimer, DraftExportFormat as AiDraftExportFormat, DraftExportResult as AiDraftExportResult};
+use qir_ai::draft::preview::{resolve_citation_previews as ai_resolve_citation_previews, CitationPreview as AiCitationPreview};
+use qir_ai::draft::{draft_section_with_llm_progress as ai_draft_with_llm_progress, validate_draft_text as ai_validate_draft_text, AiDraftResponse as AiDraftResponse, AiDraftSectionRequest as AiDraftSectionRequest, SectionId as AiSectionId};
+use qir_core::analytics::{
+    AnomalySummary, BucketCsvExportResult, BucketDrilldown, BusinessHoursConfig, DashboardConfig, DashboardDeltaV2, DashboardPayloadV1,
+    DashboardPayloadV2, DetectionSourceComparison, IncidentLifecycleFunnel, OnCallLoad, PainFormulaConfig,
+    SeverityRegradeAnalysis, WeekdayHourHeatmap,
+};
+use qir_core::cache::{DashboardCache, WarmStartSummary};
+use qir_core::backup::{BackupCreateResult, BackupManifest, RestoreAsNewWorkspaceResult, RestoreResult};
+use qir_core::demo::{
+    seed_demo_dataset as core_seed_demo_dataset, seed_guided_tour_dataset, GUIDED_TOUR_QUARTER_LABEL,
+};
+use qir_core::error::{error_catalog, AppError, ErrorSpec};
+use qir_core::freeze::{QuarterFreeze, QuarterFreezeAuditEntry};
+use qir_core::quarters::{
+    FiscalCalendarConfig, Quarter, QuarterAssignmentAnchor, QuarterAssignmentSummary,
+    QuarterBoundaryReport, QuarterCarryOver, QuarterInput, QuarterScope,
+};
+use qir_core::action_items::{ActionItem, CreateActionItemInput};
+use qir_core::live::{LiveReconcileSummary, QuickCaptureInput};
+use qir_core::responders::{AddResponderInput, IncidentResponder};
+use qir_core::diagrams::IncidentDiagram;
+use qir_core::metrics::ComputedMetricDefinition;
+use qir_core::severity::{SeverityGrade, SeverityMapping, SeverityMappingInput};
+use qir_core::aliases::{AliasKind, EntityAlias, EntityAliasInput};
+use qir_core::repo::{
+    find_probable_duplicates, merge_incidents, DuplicateCandidate, MergeIncidentsSummary, PaginationParams,
+    PaginationResult,
+};
 use qir_core::ingest::jira_csv::{
-    import_jira_csv, preview_jira_csv, JiraCsvMapping, JiraCsvPreview, JiraImportSummary,
+    dry_run_jira_csv_import, import_jira_csv, import_jira_csv_with_progress, preview_jira_csv, JiraCsvMapping, JiraCsvPreview, JiraImportSummary,
+};
+use qir_core::ingest::csv_dialect::{decode_csv_bytes, DecodedCsv};
+use qir_core::ingest::fingerprint::{load_fingerprint_strategy, save_fingerprint_strategy, FingerprintStrategy};
+use qir_core::ingest::generic_csv::{
+    dry_run_generic_csv_import, import_generic_csv_with_progress, preview_generic_csv, CsvImportSummary, CsvPreview, GenericCsvMapping,
+};
+use qir_core::ingest::slack_export::{ingest_slack_export, preview_slack_export, SlackExportPreview};
+use qir_core::ingest::email::{ingest_email_source, preview_email_source, EmailIngestSummary, EmailPreview};
+use qir_core::ingest::import_batches::{list_import_batches, undo_import_batch, ImportBatch, ImportUndoSummary};
+use qir_core::ingest::import_history::{list_import_history, ImportHistoryEntry};
+use qir_core::ingest::merge::ConflictStrategy;
+use qir_core::interchange::{
+    export_interchange as core_export_interchange, import_interchange as core_import_interchange,
+    interchange_capabilities, read_interchange_document, InterchangeCapabilities, InterchangeExportResult,
+    InterchangeImportSummary,
 };
+use qir_core::ingest::incident_io::{
+    import_incident_io_with_progress, preview_incident_io, IncidentIoImportSummary, IncidentIoMapping, IncidentIoPreview,
+};
+use qir_core::ingest::artifacts_dir::{ingest_artifacts_dir, ArtifactsDirIngestSummary};
+use qir_core::ingest::file_batch::{import_file_batch, FileBatchImportSummary};
+use qir_core::ingest::markdown_postmortem::{
+    ingest_markdown_postmortem, preview_markdown_postmortem, MarkdownIngestSummary, MarkdownPostmortem,
+};
+use qir_core::ingest::confluence::{
+    ingest_confluence_page, preview_confluence_page, ConfluenceIngestSummary, ConfluencePostmortem,
+};
+use qir_core::ingest::jira_session::{import_jira_csv_session, JiraImportSessionFile, JiraImportSessionSummary};
+use qir_core::ingest::retro_tool::{
+    import_retro_tool_export_with_progress, preview_retro_tool_export, RetroTool, RetroToolPreview,
+};
+use qir_core::ingest::statuspage::{
+    ingest_statuspage_incident, preview_statuspage_history, StatuspageIncidentSummary, StatuspageIngestSummary,
+};
+use qir_core::ingest::zoom_vtt::{ingest_zoom_vtt, preview_zoom_vtt, ZoomVttIngestSummary, ZoomVttPreview};
 use qir_core::ingest::slack_transcript::{
     ingest_slack_transcript_text, preview_slack_transcript_text, SlackIngestSummary, SlackPreview,
 };
+use qir_core::progress::{CancelToken, Progress, ProgressEvent, ProgressSink};
+use qir_core::ingest::jira_api::{
+    clear_jira_api_credentials, default_jira_api_mapping, fetch_jira_issues, import_jira_issues_with_progress,
+    jira_api_credentials_status, load_jira_api_credentials, mapping_fields as jira_api_mapping_fields,
+    preview_jira_issues, save_jira_api_credentials, JiraApiCredentials, JiraApiCredentialsStatus,
+    DEFAULT_JIRA_API_MAX_ISSUES,
+};
+use qir_core::ingest::pagerduty_csv::{
+    import_pagerduty_csv_with_progress, preview_pagerduty_csv, PagerDutyCsvMapping,
+};
+use qir_core::ingest::jira_json::{import_jira_json_with_progress, preview_jira_json, JiraJsonImportSummary};
+use qir_core::ingest::xlsx::{import_xlsx_with_progress, preview_xlsx, read_xlsx_file, XlsxPreview};
+use qir_core::ingest::servicenow_csv::{
+    import_servicenow_csv_with_progress, preview_servicenow_csv, ServiceNowCsvMapping,
+};
+use qir_core::profiles::check::{check_profile_against_csv, JiraProfileCheck};
+use qir_core::profiles::suggest::{suggest_jira_mapping, JiraMappingSuggestion};
 use qir_core::profiles::jira::{
     delete_profile, list_profiles, upsert_profile, JiraMappingProfile, JiraMappingProfileUpsert,
 };
-use qir_core::report::generate_qir_markdown;
+use qir_core::columnar::{export_parquet_dataset as core_export_parquet_dataset, ParquetExportResult};
+use qir_core::history::IncidentFieldChange;
+use qir_core::query::{open_readonly_query_connection, run_readonly_query, QueryLimits, QueryResult};
+use qir_core::report::{
+    export_digest_eml, export_qir_report, export_static_site as core_export_static_site, generate_qir_report, read_report_manifest,
+    verify_determinism, verify_qir_report, DeterminismReport, DigestEmailOptions, DigestEmlExportResult, DisplayTimezone, GeneratedReport, StaticSiteExportResult, StaticSiteOptions, OnCallReportMode, QirReportOptions, ReportExportResult, ReportVerifyResult,
+};
+use qir_core::sampling::{IncidentSample, IncidentSampleFilter, IncidentSampleReplay};
 use qir_core::sanitize::{
-    export_sanitized_dataset as core_export_sanitized_dataset, import_sanitized_dataset as core_import_sanitized_dataset,
+    export_sanitized_dataset_with_progress as core_export_sanitized_dataset_with_progress, import_sanitized_dataset as core_import_sanitized_dataset,
     inspect_sanitized_dataset as core_inspect_sanitized_dataset, SanitizedExportManifest, SanitizedExportResult,
-    SanitizedImportSummary,
+    SanitizedImportSummary, aggregate_sanitized_benchmark, render_benchmark_markdown, SanitizedBenchmark,
+};
+use qir_core::settings::{
+    export_settings_bundle as core_export_settings_bundle, import_settings_bundle as core_import_settings_bundle,
+    read_settings_bundle as core_read_settings_bundle, SettingsExportResult, SettingsImportSummary,
+};
+use qir_core::validate::overlap::{list_incident_overlaps, IncidentOverlap};
+use qir_core::validate::IncidentValidationReportItem;
+use qir_core::workspace::{
+    WorkspaceBranchResult, WorkspaceConfigRepair, WorkspaceDataPaths, WorkspaceLineage, WorkspaceMetadata, AI_STORE_DIRNAME, ARTIFACTS_DIRNAME,
+};
+use qir_core::ai_drafts::comments::{CreateDraftCommentInput, DraftComment};
+use qir_core::ai_drafts::policy::{
+    approve_ai_draft, ensure_draft_exportable, get_ai_draft_approval, load_ai_usage_policy, revoke_ai_draft_approval,
+    save_ai_usage_policy, AiDraftApproval, AiUsagePolicy,
 };
-use qir_core::validate::{validate_all_incidents, IncidentValidationReportItem};
-use qir_core::workspace::WorkspaceMetadata;
+use qir_core::ai_drafts::staleness::AiDraftListItem;
 use qir_core::ai_drafts::{AiDraftArtifact, AiDraftSectionType, CreateAiDraftInput};
-use tauri::Manager;
+use tauri::{Emitter, Manager};
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 
 const WORKSPACE_CONFIG_FILE: &str = "workspace.json";
 const WORKSPACE_DEFAULT_DB_FILENAME: &str = "incidentreview.sqlite";
 const WORKSPACE_RECENT_LIMIT: usize = 8;
-const AI_STORE_DIRNAME: &str = "ai";
+const APP_PATHS_CONFIG_FILE: &str = "app_paths.json";
+const JIRA_API_CREDENTIALS_FILE: &str = "jira_api_credentials.json";
 
 #[derive(Debug, serde::Serialize)]
 pub struct InitDbResponse {
@@ -118,6 +230,16 @@ pub struct EvidenceAddSourceRequest {
     pub text: Option<String>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EvidenceCopySourceRequest {
+    #[serde(rename = "type")]
+    pub source_type: AiEvidenceSourceType,
+    pub origin_path: String,
+    pub label: String,
+    pub expected_sha256: Option<String>,
+    pub retry: Option<AiCopyRetryPolicy>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AiIndexBuildRequest {
@@ -131,6 +253,12 @@ pub struct AiEvidenceQueryRequest {
     pub query: String,
     pub top_k: u32,
     pub source_filter: Option<Vec<String>>,
+    /// "en" or "es"; mixed-language chunks match either.
+    #[serde(default)]
+    pub language_filter: Option<String>,
+    /// Boosts chunks linked to this incident.
+    #[serde(default)]
+    pub incident_id: Option<i64>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -143,6 +271,18 @@ pub struct AiDraftSectionRequestWire {
     pub model: String,
 }
 
+#[derive(Debug, serde::Serialize)]
+pub struct GuidedTourSummary {
+    pub workspace: WorkspaceMetadata,
+    pub data_paths: WorkspaceDataPaths,
+    pub quarter_label: String,
+    pub incidents_inserted: usize,
+    pub timeline_events: usize,
+    pub evidence_sources: usize,
+    pub evidence_chunks: usize,
+    pub draft_id: i64,
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct DeleteResponse {
     pub ok: bool,
@@ -160,6 +300,8 @@ pub struct WorkspaceInfo {
     pub current_db_path: String,
     pub recent_db_paths: Vec<String>,
     pub load_error: Option<AppError>,
+    /// Set when startup found the saved workspace missing or pruned dead recents.
+    pub config_repair: Option<WorkspaceConfigRepair>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -168,18 +310,73 @@ struct WorkspaceConfig {
     recent_db_paths: Vec<String>,
 }
 
+/// Machine-level overrides for where large app data lives; unset means under `app_data_dir()`.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct AppPathsConfig {
+    #[serde(default)]
+    artifacts_dir: Option<String>,
+    #[serde(default)]
+    ai_store_root: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AppPaths {
+    pub artifacts_dir: String,
+    pub artifacts_dir_overridden: bool,
+    pub ai_store_root: String,
+    pub ai_store_root_overridden: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WarmStartStatus {
+    pub db_path: String,
+    pub running: bool,
+    pub summary: Option<WarmStartSummary>,
+    pub error: Option<AppError>,
+}
+
 #[derive(Default)]
 struct WorkspaceState {
     current_db_path: Mutex<Option<PathBuf>>,
     recent_db_paths: Mutex<Vec<PathBuf>>,
     load_error: Mutex<Option<AppError>>,
+    config_repair: Mutex<Option<WorkspaceConfigRepair>>,
+    warm_start: Mutex<Option<WarmStartStatus>>,
+}
+
+/// Precomputes dashboard/validation payloads for `db_path` on a background thread so the
+/// first render after opening is served from cache. Failures are recorded, never fatal.
+fn spawn_warm_start(app: &tauri::AppHandle, db_path: PathBuf) {
+    let state = app.state::<WorkspaceState>();
+    *state.warm_start.lock().unwrap() = Some(WarmStartStatus {
+        db_path: db_path.to_string_lossy().to_string(),
+        running: true,
+        summary: None,
+        error: None,
+    });
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let result = qir_core::workspace::open_workspace_connection(&db_path).and_then(|conn| {
+            let cache = app.state::<DashboardCache>();
+            qir_core::cache::warm_start_precompute(&conn, &cache)
+        });
+        let state = app.state::<WorkspaceState>();
+        let mut slot = state.warm_start.lock().unwrap();
+        // Only record the outcome if the user has not switched workspaces meanwhile.
+        if let Some(status) = slot.as_mut() {
+            if status.db_path == db_path.to_string_lossy() {
+                status.running = false;
+                match result {
+                    Ok(summary) => status.summary = Some(summary),
+                    Err(e) => status.error = Some(e),
+                }
+            }
+        }
+    });
 }
 
 fn default_db_path(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
-    let dir = app.path().app_data_dir().map_err(|e| {
-        AppError::new("DB_PATH_FAILED", "Failed to resolve app data directory")
-            .with_details(e.to_string())
-    })?;
+    let dir = app_data_dir(app, "DB_PATH_FAILED")?;
 
     fs::create_dir_all(&dir).map_err(|e| {
         AppError::new("DB_PATH_FAILED", "Failed to create app data directory")
@@ -189,6 +386,19 @@ fn default_db_path(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
     Ok(dir.join("incidentreview.sqlite"))
 }
 
+/// Where a workspace DB usually ends up after app data is moved: the default app-data DB and
+/// a DB next to the config file.
+fn fallback_db_paths(app: &tauri::AppHandle) -> Vec<PathBuf> {
+    let mut out = Vec::new();
+    if let Ok(p) = default_db_path(app) {
+        out.push(p);
+    }
+    if let Some(dir) = config_path(app).ok().and_then(|p| p.parent().map(Path::to_path_buf)) {
+        out.push(dir.join("incidentreview.sqlite"));
+    }
+    out
+}
+
 fn config_path(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
     let dir = app.path().app_config_dir().map_err(|e| {
         AppError::new("WORKSPACE_PERSIST_FAILED", "Failed to resolve app config directory")
@@ -252,20 +462,83 @@ fn open_current_workspace_conn(
     qir_core::workspace::open_workspace_connection(&db_path)
 }
 
-fn default_artifacts_dir(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
-    let dir = app.path().app_data_dir().map_err(|e| {
-        AppError::new("DB_PATH_FAILED", "Failed to resolve app data directory")
-            .with_details(e.to_string())
+fn app_data_dir(app: &tauri::AppHandle, code: &str) -> Result<PathBuf, AppError> {
+    app.path().app_data_dir().map_err(|e| {
+        AppError::new(code, "Failed to resolve app data directory").with_details(e.to_string())
+    })
+}
+
+fn app_paths_config_path(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
+    Ok(config_path(app)?.with_file_name(APP_PATHS_CONFIG_FILE))
+}
+
+fn read_app_paths_config(app: &tauri::AppHandle) -> Result<AppPathsConfig, AppError> {
+    let path = app_paths_config_path(app)?;
+    if !path.exists() {
+        return Ok(AppPathsConfig::default());
+    }
+    let bytes = fs::read(&path).map_err(|e| {
+        AppError::new("WORKSPACE_PERSIST_FAILED", "Failed to read app paths config")
+            .with_details(format!("path={}; err={}", path.display(), e))
     })?;
-    Ok(dir.join("artifacts"))
+    serde_json::from_slice(&bytes).map_err(|e| {
+        AppError::new("WORKSPACE_PERSIST_FAILED", "Failed to decode app paths config")
+            .with_details(format!("path={}; err={}", path.display(), e))
+    })
 }
 
-fn ai_store_root(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
-    let dir = app.path().app_data_dir().map_err(|e| {
-        AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to resolve app data directory")
+fn write_app_paths_config(app: &tauri::AppHandle, cfg: &AppPathsConfig) -> Result<(), AppError> {
+    let path = app_paths_config_path(app)?;
+    let tmp = path.with_extension("tmp");
+    let json = serde_json::to_string_pretty(cfg).map_err(|e| {
+        AppError::new("WORKSPACE_PERSIST_FAILED", "Failed to encode app paths config")
             .with_details(e.to_string())
     })?;
-    let root = dir.join(AI_STORE_DIRNAME);
+    fs::write(&tmp, json.as_bytes()).map_err(|e| {
+        AppError::new("WORKSPACE_PERSIST_FAILED", "Failed to write app paths config")
+            .with_details(format!("path={}; err={}", tmp.display(), e))
+    })?;
+    fs::rename(&tmp, &path).map_err(|e| {
+        AppError::new("WORKSPACE_PERSIST_FAILED", "Failed to finalize app paths config write")
+            .with_details(format!("tmp={}; dest={}; err={}", tmp.display(), path.display(), e))
+    })?;
+    Ok(())
+}
+
+fn resolve_app_paths(app: &tauri::AppHandle) -> Result<AppPaths, AppError> {
+    let cfg = read_app_paths_config(app)?;
+    let data_dir = app_data_dir(app, "DB_PATH_FAILED")?;
+    Ok(AppPaths {
+        artifacts_dir_overridden: cfg.artifacts_dir.is_some(),
+        artifacts_dir: cfg
+            .artifacts_dir
+            .unwrap_or_else(|| data_dir.join(ARTIFACTS_DIRNAME).to_string_lossy().to_string()),
+        ai_store_root_overridden: cfg.ai_store_root.is_some(),
+        ai_store_root: cfg
+            .ai_store_root
+            .unwrap_or_else(|| data_dir.join(AI_STORE_DIRNAME).to_string_lossy().to_string()),
+    })
+}
+
+/// Data paths for the current workspace: next to the DB for portable workspaces, otherwise
+/// the machine-level `AppPaths`.
+fn workspace_data_paths(app: &tauri::AppHandle) -> Result<WorkspaceDataPaths, AppError> {
+    let machine = resolve_app_paths(app)?;
+    let state = app.state::<WorkspaceState>();
+    let db_path = resolve_current_db_path(app, &state)?;
+    qir_core::workspace::resolve_workspace_data_paths(
+        &db_path,
+        PathBuf::from(&machine.artifacts_dir).as_path(),
+        PathBuf::from(&machine.ai_store_root).as_path(),
+    )
+}
+
+fn artifacts_dir(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
+    Ok(PathBuf::from(workspace_data_paths(app)?.artifacts_dir))
+}
+
+fn ai_store_root(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
+    let root = PathBuf::from(workspace_data_paths(app)?.ai_store_root);
     fs::create_dir_all(&root).map_err(|e| {
         AppError::new("AI_EVIDENCE_STORE_FAILED", "Failed to create AI store directory")
             .with_details(format!("path={}; err={}", root.display(), e))
@@ -283,6 +556,71 @@ fn ai_llm() -> Result<OllamaLlm, AppError> {
     Ok(OllamaLlm::new(client))
 }
 
+const PROGRESS_EVENT: &str = "qir://progress";
+
+/// Forwards core progress events to the webview on `PROGRESS_EVENT`.
+fn progress_sink(app: &tauri::AppHandle) -> impl ProgressSink {
+    let app = app.clone();
+    move |ev: &ProgressEvent| {
+        // Best-effort: a closed window must not fail the operation itself.
+        let _ = app.emit(PROGRESS_EVENT, ev);
+    }
+}
+
+const AI_QUEUE_EVENT: &str = "qir://ai-queue";
+
+/// Waits for this operation's turn in the AI queue, emitting its position on `AI_QUEUE_EVENT`
+/// while another model holds the GPU. Drop the guard to let the next operation run.
+fn ai_queue_acquire<'a>(
+    app: &tauri::AppHandle,
+    queue: &'a AiOperationQueue,
+    operation_id: &str,
+    operation: &str,
+    model: &str,
+) -> AiQueueGuard<'a> {
+    queue.acquire(operation_id, operation, model, |pos| {
+        // Best-effort, like progress events.
+        let _ = app.emit(AI_QUEUE_EVENT, pos);
+    })
+}
+
+/// Cancel tokens of running cancellable operations, keyed by operation id.
+#[derive(Default)]
+struct OperationCancels(Mutex<HashMap<String, CancelToken>>);
+
+/// Unregisters the operation's cancel token when the command returns.
+struct CancelRegistration<'a> {
+    cancels: &'a OperationCancels,
+    operation_id: String,
+}
+
+impl OperationCancels {
+    fn register(&self, operation_id: &str) -> (CancelToken, CancelRegistration<'_>) {
+        let token = CancelToken::new();
+        self.0.lock().unwrap().insert(operation_id.to_string(), token.clone());
+        (
+            token,
+            CancelRegistration {
+                cancels: self,
+                operation_id: operation_id.to_string(),
+            },
+        )
+    }
+}
+
+impl Drop for CancelRegistration<'_> {
+    fn drop(&mut self) {
+        self.cancels.0.lock().unwrap().remove(&self.operation_id);
+    }
+}
+
+/// The UI passes its own id to correlate events with the call it made; otherwise derive one.
+fn operation_id_or_new(operation: &str, operation_id: Option<String>) -> String {
+    operation_id.unwrap_or_else(|| {
+        format!("{operation}-{}", OffsetDateTime::now_utc().unix_timestamp_nanos())
+    })
+}
+
 fn now_rfc3339_utc() -> Result<String, AppError> {
     OffsetDateTime::now_utc()
         .format(&Rfc3339)
@@ -332,6 +670,11 @@ fn app_info(app: tauri::AppHandle) -> Result<AppInfo, AppError> {
     })
 }
 
+#[tauri::command]
+fn errors_catalog() -> Result<Vec<ErrorSpec>, AppError> {
+    Ok(error_catalog())
+}
+
 #[tauri::command]
 fn workspace_migration_status(db_path: String) -> Result<WorkspaceMigrationStatus, AppError> {
     let db_path = PathBuf::from(db_path);
@@ -378,13 +721,55 @@ fn workspace_get_current(app: tauri::AppHandle) -> Result<WorkspaceInfo, AppErro
         .map(|p| p.to_string_lossy().to_string())
         .collect::<Vec<_>>();
     let load_error = state.load_error.lock().unwrap().clone();
+    let config_repair = state.config_repair.lock().unwrap().clone();
     Ok(WorkspaceInfo {
         current_db_path: current.to_string_lossy().to_string(),
         recent_db_paths: recent,
         load_error,
+        config_repair,
     })
 }
 
+/// Resolves a stale workspace selection: remaps to `db_path` (usually one of the startup
+/// candidates) or, when None, falls back to the default workspace. Dead recents are dropped.
+#[tauri::command]
+fn workspace_repair_config(app: tauri::AppHandle, db_path: Option<String>) -> Result<WorkspaceInfo, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let db_path = db_path.map(PathBuf::from);
+    if let Some(p) = db_path.as_deref() {
+        qir_core::workspace::open_workspace(p)?;
+    }
+
+    *state.current_db_path.lock().unwrap() = db_path.clone();
+    state.recent_db_paths.lock().unwrap().retain(|p| p.is_file());
+    if let Some(p) = db_path.as_ref() {
+        push_recent(&state, p);
+    }
+
+    let cfg = WorkspaceConfig {
+        last_db_path: db_path.as_ref().map(|p| p.to_string_lossy().to_string()),
+        recent_db_paths: state
+            .recent_db_paths
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect(),
+    };
+    write_workspace_config(&app, &cfg)?;
+
+    *state.config_repair.lock().unwrap() = None;
+    let mut load_error = state.load_error.lock().unwrap();
+    if load_error.as_ref().is_some_and(|e| e.code == "WORKSPACE_CONFIG_STALE") {
+        *load_error = None;
+    }
+    drop(load_error);
+    if let Some(p) = db_path {
+        spawn_warm_start(&app, p);
+    }
+    workspace_get_current(app)
+}
+
 #[tauri::command]
 fn workspace_open(app: tauri::AppHandle, db_path: String) -> Result<WorkspaceMetadata, AppError> {
     let state = app.state::<WorkspaceState>();
@@ -406,10 +791,77 @@ fn workspace_open(app: tauri::AppHandle, db_path: String) -> Result<WorkspaceMet
             .collect(),
     };
     write_workspace_config(&app, &cfg)?;
+    spawn_warm_start(&app, db_path);
 
     Ok(meta)
 }
 
+#[tauri::command]
+fn app_paths_get(app: tauri::AppHandle) -> Result<AppPaths, AppError> {
+    resolve_app_paths(&app)
+}
+
+/// Points the artifacts dir and/or AI store at a new folder (`None` restores the default),
+/// moving existing content first. Each override is persisted only after its move succeeds.
+#[tauri::command]
+fn app_paths_set(
+    app: tauri::AppHandle,
+    artifacts_dir: Option<String>,
+    ai_store_root: Option<String>,
+) -> Result<AppPaths, AppError> {
+    let mut cfg = read_app_paths_config(&app)?;
+    let current = resolve_app_paths(&app)?;
+    let data_dir = app_data_dir(&app, "DB_PATH_FAILED")?;
+    let normalize = |p: Option<String>| p.map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+    let artifacts_dir = normalize(artifacts_dir);
+    let ai_store_root = normalize(ai_store_root);
+
+    let target = artifacts_dir
+        .clone()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| data_dir.join(ARTIFACTS_DIRNAME));
+    qir_core::workspace::relocate_data_dir(PathBuf::from(&current.artifacts_dir).as_path(), &target)?;
+    cfg.artifacts_dir = artifacts_dir;
+    write_app_paths_config(&app, &cfg)?;
+
+    let target = ai_store_root
+        .clone()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| data_dir.join(AI_STORE_DIRNAME));
+    qir_core::workspace::relocate_data_dir(PathBuf::from(&current.ai_store_root).as_path(), &target)?;
+    cfg.ai_store_root = ai_store_root;
+    write_app_paths_config(&app, &cfg)?;
+
+    resolve_app_paths(&app)
+}
+
+#[tauri::command]
+fn workspace_portable_get(app: tauri::AppHandle) -> Result<WorkspaceDataPaths, AppError> {
+    workspace_data_paths(&app)
+}
+
+/// Moves the current workspace's artifacts and AI store next to its DB file (or back to the
+/// machine-level folders when `enabled` is false).
+#[tauri::command]
+fn workspace_portable_set(app: tauri::AppHandle, enabled: bool) -> Result<WorkspaceDataPaths, AppError> {
+    let machine = resolve_app_paths(&app)?;
+    let state = app.state::<WorkspaceState>();
+    let db_path = resolve_current_db_path(&app, &state)?;
+    qir_core::workspace::set_workspace_portable(
+        &db_path,
+        enabled,
+        PathBuf::from(&machine.artifacts_dir).as_path(),
+        PathBuf::from(&machine.ai_store_root).as_path(),
+    )
+}
+
+#[tauri::command]
+fn workspace_warm_start_status(app: tauri::AppHandle) -> Result<Option<WarmStartStatus>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let status = state.warm_start.lock().unwrap().clone();
+    Ok(status)
+}
+
 #[tauri::command]
 fn workspace_create(app: tauri::AppHandle, destination_dir: String, filename: Option<String>) -> Result<WorkspaceMetadata, AppError> {
     let state = app.state::<WorkspaceState>();
@@ -445,6 +897,44 @@ fn workspace_create(app: tauri::AppHandle, destination_dir: String, filename: Op
     Ok(meta)
 }
 
+/// Clones the current workspace into a scratch branch. The current workspace stays selected;
+/// the UI opens the branch explicitly via `workspace_open`.
+#[tauri::command]
+fn workspace_snapshot_branch(
+    app: tauri::AppHandle,
+    destination_dir: String,
+    filename: Option<String>,
+    branch_label: String,
+) -> Result<WorkspaceBranchResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let source_path = resolve_current_db_path(&app, &state)?;
+    let conn = qir_core::workspace::open_workspace_connection(&source_path)?;
+    let dir = PathBuf::from(destination_dir);
+    if !dir.is_dir() {
+        return Err(AppError::new(
+            "WORKSPACE_INVALID_PATH",
+            "Workspace destination must be an existing directory",
+        )
+        .with_details(dir.display().to_string()));
+    }
+    let branched_at = now_rfc3339_utc()?;
+    let name = filename.unwrap_or_else(|| {
+        let safe: String = branched_at
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        format!("incidentreview_branch_{safe}.sqlite")
+    });
+    qir_core::workspace::snapshot_branch_workspace(&conn, &source_path, &dir.join(name), &branch_label, &branched_at)
+}
+
+#[tauri::command]
+fn workspace_lineage(app: tauri::AppHandle) -> Result<Option<WorkspaceLineage>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::workspace::get_workspace_lineage(&conn)
+}
+
 #[tauri::command]
 fn seed_demo_jira(app: tauri::AppHandle) -> Result<JiraImportSummary, AppError> {
     let state = app.state::<WorkspaceState>();
@@ -461,6 +951,7 @@ fn seed_demo_jira(app: tauri::AppHandle) -> Result<JiraImportSummary, AppError>
         title: "Summary".to_string(),
         description: Some("Description".to_string()),
         severity: Some("Severity".to_string()),
+        initial_severity: None,
         detection_source: None,
         vendor: None,
         service: None,
@@ -472,6 +963,9 @@ fn seed_demo_jira(app: tauri::AppHandle) -> Result<JiraImportSummary, AppError>
         ack_ts: Some("AckTs".to_string()),
         mitigate_ts: None,
         resolve_ts: Some("ResolveTs".to_string()),
+        conflict_strategy: Default::default(),
+        assumed_utc_offset_minutes: None,
+        timestamp_formats: Default::default(),
     };
 
     import_jira_csv(&mut conn, csv_text, &mapping)
@@ -484,6 +978,86 @@ fn seed_demo_dataset(app: tauri::AppHandle) -> Result<JiraImportSummary, AppErro
     core_seed_demo_dataset(&mut conn)
 }
 
+/// Creates a new portable workspace at `destination_dir` holding the demo quarter with Slack
+/// timelines, evidence, an offline-built index and a saved draft, then switches to it.
+#[tauri::command]
+fn seed_guided_tour(
+    app: tauri::AppHandle,
+    destination_dir: String,
+    filename: Option<String>,
+) -> Result<GuidedTourSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let dir = PathBuf::from(destination_dir);
+    if !dir.is_dir() {
+        return Err(AppError::new(
+            "WORKSPACE_INVALID_PATH",
+            "Workspace destination must be an existing directory",
+        )
+        .with_details(dir.display().to_string()));
+    }
+    let db_path = dir.join(filename.unwrap_or_else(|| "guided-tour.sqlite".to_string()));
+
+    qir_core::workspace::create_workspace(&db_path)?;
+    let data_paths = qir_core::workspace::init_portable_layout(&db_path)?;
+    let mut conn = qir_core::workspace::open_workspace_connection(&db_path)?;
+    let dataset = seed_guided_tour_dataset(&mut conn)?;
+
+    let created_at = now_rfc3339_utc()?;
+    let notes: Vec<(String, String)> = dataset
+        .evidence_notes
+        .iter()
+        .map(|n| (n.label.clone(), n.text.clone()))
+        .collect();
+    let evidence = seed_guided_tour_evidence(
+        PathBuf::from(&data_paths.ai_store_root).as_path(),
+        &notes,
+        GUIDED_TOUR_QUARTER_LABEL,
+        &created_at,
+    )?;
+    let draft = qir_core::ai_drafts::create_ai_draft(
+        &conn,
+        CreateAiDraftInput {
+            quarter_label: GUIDED_TOUR_QUARTER_LABEL.to_string(),
+            section_type: AiDraftSectionType::ExecSummary,
+            draft_text: evidence.draft.markdown.clone(),
+            citation_chunk_ids: evidence.draft.citations.iter().map(|c| c.chunk_id.clone()).collect(),
+            model_name: evidence.draft.model_name.clone(),
+            model_params_hash: evidence.draft.model_params_hash.clone(),
+            prompt_template_version: evidence.draft.prompt_template_version.clone(),
+            created_at,
+            parent_draft_id: None,
+            revision_notes: Some("Seeded by the guided tour".to_string()),
+            branch_label: None,
+        },
+    )?;
+
+    *state.current_db_path.lock().unwrap() = Some(db_path.clone());
+    push_recent(&state, &db_path);
+    let cfg = WorkspaceConfig {
+        last_db_path: Some(db_path.to_string_lossy().to_string()),
+        recent_db_paths: state
+            .recent_db_paths
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect(),
+    };
+    write_workspace_config(&app, &cfg)?;
+    spawn_warm_start(&app, db_path.clone());
+
+    Ok(GuidedTourSummary {
+        workspace: qir_core::workspace::open_workspace(&db_path)?,
+        data_paths,
+        quarter_label: GUIDED_TOUR_QUARTER_LABEL.to_string(),
+        incidents_inserted: dataset.import.inserted,
+        timeline_events: dataset.timeline_events,
+        evidence_sources: evidence.sources.len(),
+        evidence_chunks: evidence.chunk_ids.len(),
+        draft_id: draft.id,
+    })
+}
+
 #[tauri::command]
 fn get_dashboard_v1(app: tauri::AppHandle) -> Result<DashboardPayloadV1, AppError> {
     let state = app.state::<WorkspaceState>();
@@ -494,414 +1068,2170 @@ fn get_dashboard_v1(app: tauri::AppHandle) -> Result<DashboardPayloadV1, AppErro
 #[tauri::command]
 fn get_dashboard_v2(app: tauri::AppHandle) -> Result<DashboardPayloadV2, AppError> {
     let state = app.state::<WorkspaceState>();
+    let cache = app.state::<DashboardCache>();
     let conn = open_current_workspace_conn(&app, &state)?;
-    qir_core::analytics::build_dashboard_payload_v2(&conn)
+    let (dash, _, _) = qir_core::cache::cached_dashboard_v2(&conn, &cache)?;
+    Ok(dash)
 }
 
 #[tauri::command]
-fn generate_report_md(app: tauri::AppHandle) -> Result<String, AppError> {
+fn get_dashboard_delta(app: tauri::AppHandle, since_hash: Option<String>) -> Result<DashboardDeltaV2, AppError> {
     let state = app.state::<WorkspaceState>();
+    let cache = app.state::<DashboardCache>();
     let conn = open_current_workspace_conn(&app, &state)?;
-    generate_qir_markdown(&conn)
+    qir_core::analytics::dashboard_delta_v2(&conn, &cache, since_hash.as_deref())
 }
 
+/// Summaries for the incident_ids of any dashboard bucket, in one round trip.
 #[tauri::command]
-fn jira_csv_preview(csv_text: String, max_rows: usize) -> Result<JiraCsvPreview, AppError> {
-    preview_jira_csv(&csv_text, max_rows)
+fn bucket_drilldown(app: tauri::AppHandle, incident_ids: Vec<i64>) -> Result<BucketDrilldown, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::analytics::bucket_drilldown(&conn, &incident_ids)
 }
 
+/// Writes one dashboard bucket's incidents as a CSV file, optionally scoped to a quarter.
 #[tauri::command]
-fn jira_profiles_list(app: tauri::AppHandle) -> Result<Vec<JiraMappingProfile>, AppError> {
+fn export_bucket_csv(
+    app: tauri::AppHandle,
+    bucket_key: String,
+    quarter_label: Option<String>,
+    destination_dir: String,
+) -> Result<BucketCsvExportResult, AppError> {
     let state = app.state::<WorkspaceState>();
     let conn = open_current_workspace_conn(&app, &state)?;
-    list_profiles(&conn)
+    qir_core::analytics::export_bucket_csv(
+        &conn,
+        &bucket_key,
+        quarter_label.as_deref(),
+        PathBuf::from(destination_dir).as_path(),
+    )
 }
 
 #[tauri::command]
-fn jira_profiles_upsert(
+fn get_weekday_hour_heatmap(
     app: tauri::AppHandle,
-    profile: JiraMappingProfileUpsert,
-) -> Result<JiraMappingProfile, AppError> {
+    quarter_label: Option<String>,
+    utc_offset_minutes: Option<i32>,
+) -> Result<WeekdayHourHeatmap, AppError> {
     let state = app.state::<WorkspaceState>();
-    let mut conn = open_current_workspace_conn(&app, &state)?;
-    upsert_profile(&mut conn, profile)
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::analytics::build_weekday_hour_heatmap(
+        &conn,
+        quarter_label.as_deref(),
+        utc_offset_minutes.unwrap_or(0),
+    )
 }
 
 #[tauri::command]
-fn jira_profiles_delete(app: tauri::AppHandle, id: i64) -> Result<DeleteResponse, AppError> {
+fn get_incident_lifecycle_funnel(
+    app: tauri::AppHandle,
+    quarter_label: Option<String>,
+) -> Result<IncidentLifecycleFunnel, AppError> {
     let state = app.state::<WorkspaceState>();
-    let mut conn = open_current_workspace_conn(&app, &state)?;
-    delete_profile(&mut conn, id)?;
-    Ok(DeleteResponse { ok: true })
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::analytics::build_incident_lifecycle_funnel(&conn, quarter_label.as_deref())
 }
 
 #[tauri::command]
-fn jira_import_using_profile(
+fn get_detection_source_comparison(
     app: tauri::AppHandle,
-    profile_id: i64,
-    csv_text: String,
-) -> Result<JiraImportSummary, AppError> {
+    quarter_label: Option<String>,
+) -> Result<DetectionSourceComparison, AppError> {
     let state = app.state::<WorkspaceState>();
-    let mut conn = open_current_workspace_conn(&app, &state)?;
-    let profile = qir_core::profiles::jira::get_profile(&conn, profile_id)?;
-    import_jira_csv(&mut conn, &csv_text, &profile.mapping)
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::analytics::build_detection_source_comparison(&conn, quarter_label.as_deref())
 }
 
 #[tauri::command]
-fn incidents_list(app: tauri::AppHandle) -> Result<Vec<IncidentListItem>, AppError> {
+fn get_severity_regrade_analysis(
+    app: tauri::AppHandle,
+    quarter_label: Option<String>,
+) -> Result<SeverityRegradeAnalysis, AppError> {
     let state = app.state::<WorkspaceState>();
     let conn = open_current_workspace_conn(&app, &state)?;
-    let incidents = qir_core::repo::list_incidents(&conn)?;
-
-    Ok(incidents
-        .into_iter()
-        .map(|i| IncidentListItem {
-            id: i.id,
-            external_id: i.external_id,
-            title: i.title,
-        })
-        .collect())
+    qir_core::analytics::build_severity_regrade_analysis(&conn, quarter_label.as_deref())
 }
 
 #[tauri::command]
-fn incident_detail(app: tauri::AppHandle, incident_id: i64) -> Result<qir_core::repo::IncidentDetail, AppError> {
+fn get_anomaly_summary(app: tauri::AppHandle, quarter_label: Option<String>) -> Result<AnomalySummary, AppError> {
     let state = app.state::<WorkspaceState>();
     let conn = open_current_workspace_conn(&app, &state)?;
-    qir_core::repo::get_incident_detail(&conn, incident_id)
+    qir_core::analytics::build_anomaly_summary(&conn, quarter_label.as_deref())
 }
 
 #[tauri::command]
-fn validation_report(app: tauri::AppHandle) -> Result<Vec<IncidentValidationReportItem>, AppError> {
+fn get_on_call_load(
+    app: tauri::AppHandle,
+    quarter_label: Option<String>,
+    anonymize: Option<bool>,
+) -> Result<OnCallLoad, AppError> {
     let state = app.state::<WorkspaceState>();
     let conn = open_current_workspace_conn(&app, &state)?;
-    validate_all_incidents(&conn)
+    qir_core::analytics::build_on_call_load(&conn, quarter_label.as_deref(), anonymize.unwrap_or(false))
 }
 
 #[tauri::command]
-fn slack_preview(transcript_text: String) -> Result<SlackPreview, AppError> {
+fn business_hours_get(app: tauri::AppHandle) -> Result<BusinessHoursConfig, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::analytics::load_business_hours(&conn)
+}
+
+#[tauri::command]
+fn business_hours_set(app: tauri::AppHandle, config: BusinessHoursConfig) -> Result<BusinessHoursConfig, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::analytics::save_business_hours(&conn, &config)?;
+    qir_core::analytics::load_business_hours(&conn)
+}
+
+#[tauri::command]
+fn display_timezone_get(app: tauri::AppHandle) -> Result<Option<DisplayTimezone>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::report::load_display_timezone(&conn)
+}
+
+/// None clears the display timezone so reports show UTC only.
+#[tauri::command]
+fn display_timezone_set(
+    app: tauri::AppHandle,
+    timezone: Option<DisplayTimezone>,
+) -> Result<Option<DisplayTimezone>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::report::save_display_timezone(&conn, timezone.as_ref())?;
+    qir_core::report::load_display_timezone(&conn)
+}
+
+#[tauri::command]
+fn dashboard_config_get(app: tauri::AppHandle) -> Result<DashboardConfig, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::analytics::load_dashboard_config(&conn)
+}
+
+#[tauri::command]
+fn dashboard_config_set(
+    app: tauri::AppHandle,
+    config: DashboardConfig,
+) -> Result<DashboardConfig, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::analytics::save_dashboard_config(&conn, &config)?;
+    qir_core::analytics::load_dashboard_config(&conn)
+}
+
+#[tauri::command]
+fn pain_formula_get(app: tauri::AppHandle) -> Result<PainFormulaConfig, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::analytics::load_pain_formula(&conn)
+}
+
+#[tauri::command]
+fn pain_formula_set(app: tauri::AppHandle, config: PainFormulaConfig) -> Result<PainFormulaConfig, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::analytics::save_pain_formula(&conn, &config)?;
+    qir_core::analytics::load_pain_formula(&conn)
+}
+
+#[tauri::command]
+fn fingerprint_strategy_get(app: tauri::AppHandle) -> Result<FingerprintStrategy, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    load_fingerprint_strategy(&conn)
+}
+
+/// Applies to incidents inserted from now on; existing incidents keep their stored strategy.
+#[tauri::command]
+fn fingerprint_strategy_set(app: tauri::AppHandle, strategy: FingerprintStrategy) -> Result<FingerprintStrategy, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    save_fingerprint_strategy(&conn, &strategy)?;
+    load_fingerprint_strategy(&conn)
+}
+
+#[tauri::command]
+fn computed_metrics_get(app: tauri::AppHandle) -> Result<Vec<ComputedMetricDefinition>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::metrics::load_computed_metric_definitions(&conn)
+}
+
+#[tauri::command]
+fn computed_metrics_set(
+    app: tauri::AppHandle,
+    definitions: Vec<ComputedMetricDefinition>,
+) -> Result<Vec<ComputedMetricDefinition>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::metrics::save_computed_metric_definitions(&conn, &definitions)?;
+    qir_core::metrics::load_computed_metric_definitions(&conn)
+}
+
+#[tauri::command]
+fn generate_report_md(app: tauri::AppHandle) -> Result<GeneratedReport, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let generated_at = now_rfc3339_utc()?;
+    generate_qir_report(&conn, &QirReportOptions::default(), &generated_at, env!("CARGO_PKG_VERSION"))
+}
+
+#[tauri::command]
+fn generate_report_md_as_of(app: tauri::AppHandle, as_of: String) -> Result<GeneratedReport, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let generated_at = now_rfc3339_utc()?;
+    let opts = QirReportOptions {
+        as_of: Some(as_of),
+        ..QirReportOptions::default()
+    };
+    generate_qir_report(&conn, &opts, &generated_at, env!("CARGO_PKG_VERSION"))
+}
+
+#[tauri::command]
+fn query_readonly(
+    app: tauri::AppHandle,
+    sql: String,
+    max_rows: Option<u32>,
+    max_bytes: Option<u64>,
+) -> Result<QueryResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let db_path = resolve_current_db_path(&app, &state)?;
+    let conn = open_readonly_query_connection(&db_path)?;
+    let defaults = QueryLimits::default();
+    let limits = QueryLimits {
+        max_rows: max_rows.unwrap_or(defaults.max_rows),
+        max_bytes: max_bytes.unwrap_or(defaults.max_bytes),
+        ..defaults
+    };
+    run_readonly_query(&conn, &sql, &limits)
+}
+
+/// Writes the weekly digest as an .eml file; nothing is sent.
+#[tauri::command]
+fn generate_digest_eml(
+    app: tauri::AppHandle,
+    destination_dir: String,
+    from: String,
+    to: Vec<String>,
+    week_ending: Option<String>,
+) -> Result<DigestEmlExportResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let generated_at = now_rfc3339_utc()?;
+    let opts = DigestEmailOptions {
+        from,
+        to,
+        week_ending: week_ending.unwrap_or_else(|| generated_at.clone()),
+    };
+    export_digest_eml(&conn, &opts, PathBuf::from(destination_dir).as_path(), &generated_at)
+}
+
+/// Writes the review as a static HTML site folder for hosting on a file share.
+#[tauri::command]
+fn export_static_site(
+    app: tauri::AppHandle,
+    destination_dir: String,
+    quarter_label: Option<String>,
+    operation_id: Option<String>,
+) -> Result<StaticSiteExportResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let artifacts_dir = artifacts_dir(&app)?;
+    let generated_at = now_rfc3339_utc()?;
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("export_static_site", operation_id),
+        "export_static_site",
+    );
+    core_export_static_site(
+        &conn,
+        &StaticSiteOptions { quarter_label },
+        Some(artifacts_dir.as_path()),
+        PathBuf::from(destination_dir).as_path(),
+        &generated_at,
+        &mut progress,
+    )
+}
+
+#[tauri::command]
+fn report_export(
+    app: tauri::AppHandle,
+    destination_dir: String,
+    quarter_label: Option<String>,
+    as_of: Option<String>,
+    draft_ids: Option<Vec<i64>>,
+    on_call: Option<OnCallReportMode>,
+) -> Result<ReportExportResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let generated_at = now_rfc3339_utc()?;
+    let opts = QirReportOptions {
+        quarter_label,
+        as_of,
+        draft_ids: draft_ids.unwrap_or_default(),
+        on_call,
+    };
+    export_qir_report(
+        &conn,
+        &opts,
+        PathBuf::from(destination_dir).as_path(),
+        &generated_at,
+        env!("CARGO_PKG_VERSION"),
+    )
+}
+
+#[tauri::command]
+fn report_verify(app: tauri::AppHandle, manifest_path: String) -> Result<ReportVerifyResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let manifest = read_report_manifest(PathBuf::from(manifest_path).as_path())?;
+    verify_qir_report(&conn, &manifest)
+}
+
+#[tauri::command]
+fn report_determinism_verify(app: tauri::AppHandle) -> Result<DeterminismReport, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    verify_determinism(&conn)
+}
+
+#[tauri::command]
+fn jira_csv_preview(csv_text: String, max_rows: usize) -> Result<JiraCsvPreview, AppError> {
+    preview_jira_csv(&csv_text, max_rows)
+}
+
+/// Suggested mapping for an unmapped CSV, scored from header synonyms and sample values.
+#[tauri::command]
+fn jira_csv_suggest_mapping(csv_text: String) -> Result<JiraMappingSuggestion, AppError> {
+    suggest_jira_mapping(&csv_text)
+}
+
+/// Decodes a CSV file's raw bytes (BOM, UTF-16, Windows-1252) before preview or import.
+#[tauri::command]
+fn csv_decode_file(bytes: Vec<u8>) -> Result<DecodedCsv, AppError> {
+    decode_csv_bytes(&bytes)
+}
+
+#[tauri::command]
+fn generic_csv_preview(csv_text: String, max_rows: usize) -> Result<CsvPreview, AppError> {
+    preview_generic_csv(&csv_text, max_rows)
+}
+
+/// Imports a CSV from any tool with an ad-hoc column mapping; no saved profile is involved.
+/// With `dry_run` the import is rolled back and the summary carries per-row diffs. Runs off the main thread so progress events reach the UI and `operation_cancel` can stop it.
+#[tauri::command(async)]
+fn generic_csv_import(
+    app: tauri::AppHandle,
+    csv_text: String,
+    mapping: GenericCsvMapping,
+    operation_id: Option<String>,
+    dry_run: Option<bool>,
+) -> Result<CsvImportSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let sink = progress_sink(&app);
+    let operation_id = operation_id_or_new("import_generic_csv", operation_id);
+    let cancels = app.state::<OperationCancels>();
+    let (token, _registration) = cancels.register(&operation_id);
+    let mut progress = Progress::new(&sink, operation_id, "import_generic_csv").with_cancel(token);
+    if dry_run.unwrap_or(false) {
+        return dry_run_generic_csv_import(&mut conn, &csv_text, &mapping, &mut progress);
+    }
+    import_generic_csv_with_progress(&mut conn, &csv_text, &mapping, &mut progress)
+}
+
+#[tauri::command]
+fn jira_profiles_list(app: tauri::AppHandle) -> Result<Vec<JiraMappingProfile>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    list_profiles(&conn)
+}
+
+#[tauri::command]
+fn jira_profiles_upsert(
+    app: tauri::AppHandle,
+    profile: JiraMappingProfileUpsert,
+) -> Result<JiraMappingProfile, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    upsert_profile(&mut conn, profile)
+}
+
+/// Dry-run check of a saved profile against a CSV's header row; nothing is imported.
+#[tauri::command]
+fn profile_check(app: tauri::AppHandle, profile_id: i64, csv_text: String) -> Result<JiraProfileCheck, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    check_profile_against_csv(&conn, profile_id, &csv_text)
+}
+
+#[tauri::command]
+fn jira_profiles_delete(app: tauri::AppHandle, id: i64) -> Result<DeleteResponse, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    delete_profile(&mut conn, id)?;
+    Ok(DeleteResponse { ok: true })
+}
+
+/// `conflict_strategy` and `assumed_utc_offset_minutes` override the profile's saved values
+/// for this import only.
+#[tauri::command(async)]
+fn jira_import_using_profile(
+    app: tauri::AppHandle,
+    profile_id: i64,
+    csv_text: String,
+    operation_id: Option<String>,
+    dry_run: Option<bool>,
+    conflict_strategy: Option<ConflictStrategy>,
+    assumed_utc_offset_minutes: Option<i32>,
+) -> Result<JiraImportSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let mut profile = qir_core::profiles::jira::get_profile(&conn, profile_id)?;
+    if let Some(strategy) = conflict_strategy {
+        profile.mapping.conflict_strategy = strategy;
+    }
+    if assumed_utc_offset_minutes.is_some() {
+        profile.mapping.assumed_utc_offset_minutes = assumed_utc_offset_minutes;
+    }
+    let sink = progress_sink(&app);
+    let operation_id = operation_id_or_new("import_jira_csv", operation_id);
+    let cancels = app.state::<OperationCancels>();
+    let (token, _registration) = cancels.register(&operation_id);
+    let mut progress = Progress::new(&sink, operation_id, "import_jira_csv").with_cancel(token);
+    if dry_run.unwrap_or(false) {
+        return dry_run_jira_csv_import(&mut conn, &csv_text, &profile.mapping, &mut progress);
+    }
+    import_jira_csv_with_progress(&mut conn, &csv_text, &profile.mapping, &mut progress)
+}
+
+/// Asks a running cancellable operation to stop; it rolls back and fails with a `*_CANCELLED`
+/// error. Unknown or finished ids are ignored, since the operation may have just completed.
+#[tauri::command]
+fn operation_cancel(app: tauri::AppHandle, operation_id: String) -> bool {
+    let cancels = app.state::<OperationCancels>();
+    let token = cancels.0.lock().unwrap().get(&operation_id).cloned();
+    match token {
+        Some(token) => {
+            token.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Imports several CSVs with one profile as a single operation with one import-history entry.
+#[tauri::command]
+fn jira_import_session(
+    app: tauri::AppHandle,
+    profile_id: i64,
+    files: Vec<JiraImportSessionFile>,
+    operation_id: Option<String>,
+) -> Result<JiraImportSessionSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("import_jira_csv_session", operation_id),
+        "import_jira_csv_session",
+    );
+    import_jira_csv_session(&mut conn, profile_id, &files, &mut progress)
+}
+
+/// Imports a mix of CSV and JSON export files, picking the importer per file; Jira CSVs use
+/// the given profile's mapping. Results are keyed by file name.
+#[tauri::command]
+fn import_files_batch(
+    app: tauri::AppHandle,
+    paths: Vec<String>,
+    jira_profile_id: Option<i64>,
+    operation_id: Option<String>,
+) -> Result<FileBatchImportSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let jira_mapping = match jira_profile_id {
+        Some(id) => Some(qir_core::profiles::jira::get_profile(&conn, id)?.mapping),
+        None => None,
+    };
+    let paths: Vec<PathBuf> = paths.into_iter().map(PathBuf::from).collect();
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("import_file_batch", operation_id),
+        "import_file_batch",
+    );
+    import_file_batch(&mut conn, &paths, jira_mapping.as_ref(), &mut progress)
+}
+
+#[tauri::command]
+fn import_history_list(app: tauri::AppHandle, limit: Option<usize>) -> Result<Vec<ImportHistoryEntry>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    list_import_history(&conn, limit.unwrap_or(50))
+}
+
+#[tauri::command]
+fn import_batches_list(app: tauri::AppHandle, limit: Option<usize>) -> Result<Vec<ImportBatch>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    list_import_batches(&conn, limit.unwrap_or(50))
+}
+
+/// Reverts one import batch; refused as a whole when its incidents changed since.
+#[tauri::command]
+fn import_undo(app: tauri::AppHandle, batch_id: i64) -> Result<ImportUndoSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    undo_import_batch(&mut conn, batch_id)
+}
+
+fn jira_api_credentials_path(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
+    Ok(config_path(app)?.with_file_name(JIRA_API_CREDENTIALS_FILE))
+}
+
+fn require_jira_api_credentials(app: &tauri::AppHandle) -> Result<JiraApiCredentials, AppError> {
+    load_jira_api_credentials(&jira_api_credentials_path(app)?)?.ok_or_else(|| {
+        AppError::new(
+            "INGEST_JIRA_API_CONFIG_INVALID",
+            "No Jira connection is configured; save a base URL and token first",
+        )
+    })
+}
+
+#[tauri::command]
+fn jira_api_credentials_get(app: tauri::AppHandle) -> Result<JiraApiCredentialsStatus, AppError> {
+    jira_api_credentials_status(&jira_api_credentials_path(&app)?)
+}
+
+#[tauri::command]
+fn jira_api_credentials_set(
+    app: tauri::AppHandle,
+    credentials: JiraApiCredentials,
+) -> Result<JiraApiCredentialsStatus, AppError> {
+    save_jira_api_credentials(&jira_api_credentials_path(&app)?, &credentials)
+}
+
+#[tauri::command]
+fn jira_api_credentials_clear(app: tauri::AppHandle) -> Result<(), AppError> {
+    clear_jira_api_credentials(&jira_api_credentials_path(&app)?)
+}
+
+/// Fetches the first `max_rows` issues matching `jql` with all navigable fields, tabulated
+/// like a CSV preview so field ids can be picked for the mapping.
+#[tauri::command(async)]
+fn jira_api_preview(app: tauri::AppHandle, jql: String, max_rows: usize) -> Result<JiraCsvPreview, AppError> {
+    let creds = require_jira_api_credentials(&app)?;
+    let max_rows = max_rows.clamp(1, 50);
+    let issues = fetch_jira_issues(&creds, &jql, &["*navigable".to_string()], max_rows as u32)?;
+    Ok(preview_jira_issues(&issues, max_rows))
+}
+
+/// Pulls issues matching `jql` and imports them; `mapping` names Jira field ids (defaults to
+/// the stock fields).
+#[tauri::command(async)]
+fn jira_api_import(
+    app: tauri::AppHandle,
+    jql: String,
+    mapping: Option<JiraCsvMapping>,
+    max_issues: Option<u32>,
+    operation_id: Option<String>,
+) -> Result<JiraImportSummary, AppError> {
+    let creds = require_jira_api_credentials(&app)?;
+    let mapping = mapping.unwrap_or_else(default_jira_api_mapping);
+    let issues = fetch_jira_issues(
+        &creds,
+        &jql,
+        &jira_api_mapping_fields(&mapping),
+        max_issues.unwrap_or(DEFAULT_JIRA_API_MAX_ISSUES),
+    )?;
+
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("import_jira_api", operation_id),
+        "import_jira_api",
+    );
+    import_jira_issues_with_progress(&mut conn, &issues, &mapping, &mut progress)
+}
+
+/// Tabulates a Jira bulk JSON export; changelog transitions appear as `changelog:<field>:<to>` columns.
+#[tauri::command]
+fn jira_json_preview(json_text: String, max_rows: usize) -> Result<JiraCsvPreview, AppError> {
+    preview_jira_json(&json_text, max_rows)
+}
+
+/// Imports a Jira bulk JSON export; `mapping` names field ids (defaults to the stock fields)
+/// and changelog entries become timeline events.
+#[tauri::command]
+fn jira_json_import(
+    app: tauri::AppHandle,
+    json_text: String,
+    mapping: Option<JiraCsvMapping>,
+    operation_id: Option<String>,
+) -> Result<JiraJsonImportSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("import_jira_json", operation_id),
+        "import_jira_json",
+    );
+    let mapping = mapping.unwrap_or_else(default_jira_api_mapping);
+    import_jira_json_with_progress(&mut conn, &json_text, &mapping, &mut progress)
+}
+
+#[tauri::command]
+fn pagerduty_csv_preview(csv_text: String, max_rows: usize) -> Result<JiraCsvPreview, AppError> {
+    preview_pagerduty_csv(&csv_text, max_rows)
+}
+
+/// Imports a PagerDuty incidents export; without a mapping the stock export columns are used.
+#[tauri::command]
+fn pagerduty_csv_import(
+    app: tauri::AppHandle,
+    csv_text: String,
+    mapping: Option<PagerDutyCsvMapping>,
+    operation_id: Option<String>,
+) -> Result<JiraImportSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("import_pagerduty_csv", operation_id),
+        "import_pagerduty_csv",
+    );
+    import_pagerduty_csv_with_progress(&mut conn, &csv_text, &mapping.unwrap_or_default(), &mut progress)
+}
+
+#[tauri::command]
+fn servicenow_csv_preview(csv_text: String, max_rows: usize) -> Result<JiraCsvPreview, AppError> {
+    preview_servicenow_csv(&csv_text, max_rows)
+}
+
+/// Imports a ServiceNow incident list export; without a mapping the stock export columns are used.
+#[tauri::command]
+fn servicenow_csv_import(
+    app: tauri::AppHandle,
+    csv_text: String,
+    mapping: Option<ServiceNowCsvMapping>,
+    operation_id: Option<String>,
+) -> Result<JiraImportSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("import_servicenow_csv", operation_id),
+        "import_servicenow_csv",
+    );
+    import_servicenow_csv_with_progress(&mut conn, &csv_text, &mapping.unwrap_or_default(), &mut progress)
+}
+
+#[tauri::command]
+fn incident_io_preview(text: String, mapping: Option<IncidentIoMapping>) -> Result<IncidentIoPreview, AppError> {
+    preview_incident_io(&text, mapping.as_ref())
+}
+
+/// Imports an incident.io CSV or JSON export; without a mapping the stock export columns and
+/// Critical/Major/Minor severities are used.
+#[tauri::command]
+fn incident_io_import(
+    app: tauri::AppHandle,
+    text: String,
+    mapping: Option<IncidentIoMapping>,
+    operation_id: Option<String>,
+) -> Result<IncidentIoImportSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("import_incident_io", operation_id),
+        "import_incident_io",
+    );
+    import_incident_io_with_progress(&mut conn, &text, &mapping.unwrap_or_default(), &mut progress)
+}
+
+#[tauri::command]
+fn retro_tool_preview(json_text: String, tool: Option<RetroTool>) -> Result<RetroToolPreview, AppError> {
+    preview_retro_tool_export(&json_text, tool)
+}
+
+/// Imports a FireHydrant or Rootly incident export; the tool is detected when not given.
+#[tauri::command]
+fn retro_tool_import(
+    app: tauri::AppHandle,
+    json_text: String,
+    tool: Option<RetroTool>,
+    operation_id: Option<String>,
+) -> Result<JiraImportSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("import_retro_tool", operation_id),
+        "import_retro_tool",
+    );
+    import_retro_tool_export_with_progress(&mut conn, &json_text, tool, &mut progress)
+}
+
+/// First `max_rows` rows of one worksheet (the first sheet when `sheet` is None).
+#[tauri::command]
+fn xlsx_preview(path: String, sheet: Option<String>, max_rows: usize) -> Result<XlsxPreview, AppError> {
+    let bytes = read_xlsx_file(PathBuf::from(path).as_path())?;
+    preview_xlsx(&bytes, sheet.as_deref(), max_rows)
+}
+
+/// Imports one worksheet; `mapping` names header cells exactly like a Jira CSV mapping.
+#[tauri::command]
+fn xlsx_import(
+    app: tauri::AppHandle,
+    path: String,
+    sheet: Option<String>,
+    mapping: JiraCsvMapping,
+    operation_id: Option<String>,
+) -> Result<JiraImportSummary, AppError> {
+    let bytes = read_xlsx_file(PathBuf::from(path).as_path())?;
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(&sink, operation_id_or_new("import_xlsx", operation_id), "import_xlsx");
+    import_xlsx_with_progress(&mut conn, &bytes, sheet.as_deref(), &mapping, &mut progress)
+}
+
+#[tauri::command]
+fn incidents_list(app: tauri::AppHandle) -> Result<Vec<IncidentListItem>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let incidents = qir_core::repo::list_incidents(&conn)?;
+
+    Ok(incidents
+        .into_iter()
+        .map(|i| IncidentListItem {
+            id: i.id,
+            external_id: i.external_id,
+            title: i.title,
+        })
+        .collect())
+}
+
+#[tauri::command]
+fn incident_detail(app: tauri::AppHandle, incident_id: i64) -> Result<qir_core::repo::IncidentDetail, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::repo::get_incident_detail(&conn, incident_id)
+}
+
+/// Opens a live incident in one call (title, start=now, optional severity/service) so the
+/// timeline can be annotated while the incident is still running.
+#[tauri::command]
+fn quick_capture(app: tauri::AppHandle, req: QuickCaptureInput) -> Result<qir_core::domain::Incident, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::live::quick_capture(&conn, req)
+}
+
+/// Appends a note to a live incident's timeline stamped with the current UTC time.
+#[tauri::command]
+fn timeline_append_now(
+    app: tauri::AppHandle,
+    incident_id: i64,
+    text: String,
+    kind: Option<String>,
+) -> Result<qir_core::repo::TimelineEvent, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::live::timeline_append_now(&conn, incident_id, &text, kind.as_deref())
+}
+
+#[tauri::command]
+fn live_reconcile(app: tauri::AppHandle, incident_id: i64) -> Result<LiveReconcileSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::live::reconcile_live_notes(&conn, incident_id)
+}
+
+#[tauri::command]
+fn incident_history(app: tauri::AppHandle, incident_id: i64) -> Result<Vec<IncidentFieldChange>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::history::incident_history(&conn, incident_id)
+}
+
+#[tauri::command]
+fn incidents_sample(
+    app: tauri::AppHandle,
+    n: u32,
+    seed: String,
+    filter: Option<IncidentSampleFilter>,
+) -> Result<IncidentSample, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let created_at = now_rfc3339_utc()?;
+    qir_core::sampling::sample_incidents(&conn, n, &seed, &filter.unwrap_or_default(), &created_at)
+}
+
+#[tauri::command]
+fn incidents_sample_list(app: tauri::AppHandle) -> Result<Vec<IncidentSample>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::sampling::list_incident_samples(&conn)
+}
+
+#[tauri::command]
+fn incidents_sample_replay(app: tauri::AppHandle, sample_id: i64) -> Result<IncidentSampleReplay, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::sampling::replay_incident_sample(&conn, sample_id)
+}
+
+#[tauri::command]
+fn validation_report(app: tauri::AppHandle) -> Result<Vec<IncidentValidationReportItem>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let (report, _, _) = qir_core::cache::cached_validation_report(&conn)?;
+    Ok(report)
+}
+
+/// Same-service incidents with substantially overlapping windows, for duplicate/cascade review.
+#[tauri::command]
+fn incident_overlaps_list(app: tauri::AppHandle) -> Result<Vec<IncidentOverlap>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    list_incident_overlaps(&conn)
+}
+
+/// Similar-titled incidents with close windows, best match first.
+#[tauri::command]
+fn incident_duplicates_list(app: tauri::AppHandle) -> Result<Vec<DuplicateCandidate>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    find_probable_duplicates(&conn)
+}
+
+#[tauri::command]
+fn incidents_merge(
+    app: tauri::AppHandle,
+    keep_incident_id: i64,
+    merge_incident_id: i64,
+) -> Result<MergeIncidentsSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    merge_incidents(&mut conn, keep_incident_id, merge_incident_id)
+}
+
+#[tauri::command]
+fn slack_preview(transcript_text: String) -> Result<SlackPreview, AppError> {
     Ok(preview_slack_transcript_text(&transcript_text))
 }
 
 #[tauri::command]
-fn slack_ingest(
+fn slack_ingest(
+    app: tauri::AppHandle,
+    incident_id: Option<i64>,
+    new_incident_title: Option<String>,
+    transcript_text: String,
+) -> Result<SlackIngestSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    ingest_slack_transcript_text(
+        &mut conn,
+        incident_id,
+        new_incident_title.as_deref(),
+        &transcript_text,
+    )
+}
+
+#[tauri::command]
+fn slack_export_preview(path: String) -> Result<SlackExportPreview, AppError> {
+    preview_slack_export(PathBuf::from(path).as_path())
+}
+
+/// `path` is the export .zip or its extracted folder; an empty `channels` list imports all.
+#[tauri::command]
+fn slack_export_ingest(
+    app: tauri::AppHandle,
+    path: String,
+    channels: Vec<String>,
+    incident_id: Option<i64>,
+    new_incident_title: Option<String>,
+) -> Result<SlackIngestSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    ingest_slack_export(
+        &mut conn,
+        PathBuf::from(path).as_path(),
+        &channels,
+        incident_id,
+        new_incident_title.as_deref(),
+    )
+}
+
+#[tauri::command]
+fn zoom_vtt_preview(vtt_text: String) -> Result<ZoomVttPreview, AppError> {
+    preview_zoom_vtt(&vtt_text)
+}
+
+/// Cue offsets are anchored to `call_start` (RFC3339), the moment the recording began.
+#[tauri::command]
+fn zoom_vtt_ingest(
+    app: tauri::AppHandle,
+    incident_id: Option<i64>,
+    new_incident_title: Option<String>,
+    call_start: String,
+    vtt_text: String,
+) -> Result<ZoomVttIngestSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    ingest_zoom_vtt(&mut conn, incident_id, new_incident_title.as_deref(), &call_start, &vtt_text)
+}
+
+#[tauri::command]
+fn email_preview(path: String) -> Result<EmailPreview, AppError> {
+    preview_email_source(PathBuf::from(path).as_path())
+}
+
+/// `path` is an mbox file, a single .eml file, or a folder of .eml files.
+#[tauri::command]
+fn email_ingest(
+    app: tauri::AppHandle,
+    path: String,
+    incident_id: Option<i64>,
+    new_incident_title: Option<String>,
+) -> Result<EmailIngestSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    ingest_email_source(&mut conn, PathBuf::from(path).as_path(), incident_id, new_incident_title.as_deref())
+}
+
+#[tauri::command]
+fn statuspage_preview(json_text: String) -> Result<Vec<StatuspageIncidentSummary>, AppError> {
+    preview_statuspage_history(&json_text)
+}
+
+/// Imports one incident from a Statuspage history export; empty incident timestamps are
+/// backfilled from its created/monitoring/resolved times.
+#[tauri::command]
+fn statuspage_ingest(
+    app: tauri::AppHandle,
+    json_text: String,
+    statuspage_incident_id: String,
+    incident_id: Option<i64>,
+    new_incident_title: Option<String>,
+) -> Result<StatuspageIngestSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    ingest_statuspage_incident(
+        &mut conn,
+        &json_text,
+        &statuspage_incident_id,
+        incident_id,
+        new_incident_title.as_deref(),
+    )
+}
+
+#[tauri::command]
+fn confluence_preview(text: String) -> Result<ConfluencePostmortem, AppError> {
+    preview_confluence_page(&text)
+}
+
+/// Imports an exported Confluence postmortem page: the summary fills an empty description and
+/// the timeline table and action items are added to the incident.
+#[tauri::command]
+fn confluence_ingest(
+    app: tauri::AppHandle,
+    text: String,
+    incident_id: Option<i64>,
+    new_incident_title: Option<String>,
+) -> Result<ConfluenceIngestSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let ingested_at = now_rfc3339_utc()?;
+    ingest_confluence_page(&mut conn, &text, incident_id, new_incident_title.as_deref(), &ingested_at)
+}
+
+#[tauri::command]
+fn markdown_postmortem_preview(path: String) -> Result<MarkdownPostmortem, AppError> {
+    preview_markdown_postmortem(Path::new(&path))
+}
+
+/// Imports a Markdown postmortem file into an incident and keeps the file as an artifact.
+#[tauri::command]
+fn markdown_postmortem_ingest(
+    app: tauri::AppHandle,
+    path: String,
+    incident_id: Option<i64>,
+    new_incident_title: Option<String>,
+) -> Result<MarkdownIngestSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let ingested_at = now_rfc3339_utc()?;
+    ingest_markdown_postmortem(
+        &mut conn,
+        Path::new(&path),
+        incident_id,
+        new_incident_title.as_deref(),
+        &ingested_at,
+    )
+}
+
+#[tauri::command]
+fn ai_health_check() -> Result<AiHealthStatus, AppError> {
+    let client = OllamaClient::new("http://127.0.0.1:11434")?;
+    client.health_check()?;
+    Ok(AiHealthStatus {
+        ok: true,
+        message: "Ollama reachable on 127.0.0.1".to_string(),
+    })
+}
+
+#[tauri::command]
+fn ai_models_list() -> Result<Vec<AiModelInfo>, AppError> {
+    let client = OllamaClient::new("http://127.0.0.1:11434")?;
+    let mut models = client.list_models()?;
+    models.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(models
+        .into_iter()
+        .map(|m: OllamaModelInfo| AiModelInfo {
+            name: m.name,
+            size: m.size,
+            digest: m.digest,
+            modified_at: m.modified_at,
+        })
+        .collect())
+}
+
+#[tauri::command]
+fn ai_evidence_add_source(
+    app: tauri::AppHandle,
+    req: EvidenceAddSourceRequest,
+) -> Result<AiEvidenceSource, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    let created_at = now_rfc3339_utc()?;
+    store.add_source(AiEvidenceAddSourceInput {
+        source_type: req.source_type,
+        origin: req.origin,
+        label: req.label,
+        created_at,
+        text: req.text,
+    })
+}
+
+/// Copies a file (e.g. from a network share) into the evidence store with checksum
+/// verification and retry; the source keeps the share path as its origin.
+#[tauri::command(async)]
+fn ai_evidence_copy_source(
+    app: tauri::AppHandle,
+    req: EvidenceCopySourceRequest,
+) -> Result<AiEvidenceCopySourceResult, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    let created_at = now_rfc3339_utc()?;
+    store.copy_file_source(AiEvidenceCopySourceInput {
+        source_type: req.source_type,
+        origin_path: req.origin_path,
+        label: req.label,
+        created_at,
+        expected_sha256: req.expected_sha256,
+        retry: req.retry.unwrap_or_default(),
+    })
+}
+
+#[tauri::command]
+fn ai_evidence_list_sources(app: tauri::AppHandle) -> Result<Vec<AiEvidenceSource>, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    store.list_sources()
+}
+
+/// Re-runs the incident-to-evidence linking pass against the current workspace incidents.
+fn ai_relink_incidents(app: &tauri::AppHandle, store: &AiEvidenceStore) -> Result<AiIncidentLinks, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(app, &state)?;
+    let incidents: Vec<AiIncidentRef> = qir_core::repo::list_incidents(&conn)?
+        .into_iter()
+        .map(|i| AiIncidentRef {
+            incident_id: i.id,
+            external_id: i.external_id,
+            title: i.title,
+        })
+        .collect();
+    store.rebuild_incident_links(&incidents, &now_rfc3339_utc()?)
+}
+
+#[tauri::command]
+fn ai_evidence_build_chunks(
+    app: tauri::AppHandle,
+    source_id: Option<String>,
+) -> Result<AiBuildChunksResult, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    let updated_at = now_rfc3339_utc()?;
+    let res = store.build_chunks(source_id, &updated_at)?;
+    ai_relink_incidents(&app, &store)?;
+    Ok(res)
+}
+
+#[tauri::command]
+fn ai_incident_links_rebuild(app: tauri::AppHandle) -> Result<AiIncidentLinks, AppError> {
+    let root = ai_store_root(&app)?;
+    ai_relink_incidents(&app, &AiEvidenceStore::open(root))
+}
+
+#[tauri::command]
+fn ai_incident_evidence_links(app: tauri::AppHandle, incident_id: i64) -> Result<AiIncidentEvidenceLinks, AppError> {
+    let root = ai_store_root(&app)?;
+    AiEvidenceStore::open(root).incident_evidence_links(incident_id)
+}
+
+#[tauri::command]
+fn ai_chunking_get(app: tauri::AppHandle) -> Result<AiChunkingParams, AppError> {
+    let root = ai_store_root(&app)?;
+    Ok(AiEvidenceStore::open(root).read_settings()?.chunking)
+}
+
+#[tauri::command]
+fn ai_chunking_set(app: tauri::AppHandle, params: AiChunkingParams) -> Result<AiChunkingParams, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    let mut settings: AiStoreSettings = store.read_settings()?;
+    settings.chunking = params;
+    store.write_settings(&settings)?;
+    Ok(store.read_settings()?.chunking)
+}
+
+#[tauri::command]
+fn ai_embed_batch_size_get(app: tauri::AppHandle) -> Result<u32, AppError> {
+    let root = ai_store_root(&app)?;
+    Ok(AiEvidenceStore::open(root).read_settings()?.embed_batch_size)
+}
+
+#[tauri::command]
+fn ai_embed_batch_size_set(app: tauri::AppHandle, batch_size: u32) -> Result<u32, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    let mut settings: AiStoreSettings = store.read_settings()?;
+    settings.embed_batch_size = batch_size;
+    store.write_settings(&settings)?;
+    Ok(store.read_settings()?.embed_batch_size)
+}
+
+#[tauri::command]
+fn ai_evidence_rechunk(app: tauri::AppHandle, source_id: String) -> Result<AiRechunkResult, AppError> {
+    let root = ai_store_root(&app)?;
+    let evidence = AiEvidenceStore::open(root.clone());
+    let index = AiIndexStore::open(root);
+    let updated_at = now_rfc3339_utc()?;
+    let res = ai_rechunk_source(&evidence, &index, &source_id, &updated_at)?;
+    ai_relink_incidents(&app, &evidence)?;
+    Ok(res)
+}
+
+/// Summarize-then-embed for a long source; the next index build embeds the section summaries.
+#[tauri::command(async)]
+fn ai_evidence_summarize(
+    app: tauri::AppHandle,
+    source_id: String,
+    model: String,
+    section_chunks: Option<u32>,
+    operation_id: Option<String>,
+) -> Result<AiSourceSummaries, AppError> {
+    let root = ai_store_root(&app)?;
+    let evidence = AiEvidenceStore::open(root);
+    let llm = ai_llm()?;
+    let updated_at = now_rfc3339_utc()?;
+    let queue = app.state::<AiOperationQueue>();
+    let operation_id = operation_id_or_new("ai_evidence_summarize", operation_id);
+    let _turn = ai_queue_acquire(&app, &queue, &operation_id, "ai_evidence_summarize", &model);
+    ai_summarize_source(
+        &evidence,
+        &llm,
+        AiSummarizeSourceInput {
+            source_id,
+            model,
+            section_chunks,
+            updated_at,
+        },
+    )
+}
+
+#[tauri::command]
+fn ai_evidence_list_chunks(
+    app: tauri::AppHandle,
+    source_id: Option<String>,
+) -> Result<Vec<AiEvidenceChunkSummary>, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    store.list_chunks(AiEvidenceQueryStore {
+        include_text: false,
+        source_id,
+    })
+}
+
+#[tauri::command]
+fn ai_evidence_list_chunks_paginated(
+    app: tauri::AppHandle,
+    source_id: Option<String>,
+    limit: u32,
+    offset: u32,
+) -> Result<PaginationResult<AiEvidenceChunkSummary>, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    let pagination = PaginationParams {
+        limit,
+        offset,
+        sort_by: None,
+        sort_order: None,
+    };
+    store.list_chunks_paginated(
+        AiEvidenceQueryStore {
+            include_text: false,
+            source_id,
+        },
+        pagination,
+    )
+}
+
+#[tauri::command]
+fn ai_evidence_get_chunk(app: tauri::AppHandle, chunk_id: String) -> Result<AiEvidenceChunk, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    store.get_chunk(&chunk_id)
+}
+
+#[tauri::command]
+fn ai_evidence_get_context(
+    app: tauri::AppHandle,
+    req: AiEvidenceContextRequest,
+) -> Result<AiEvidenceContextResponse, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    store.get_context(&req.chunk_id, req.window)
+}
+
+#[tauri::command]
+fn ai_store_fsck(app: tauri::AppHandle, repair: Option<bool>) -> Result<AiEvidenceFsckReport, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    store.fsck(repair.unwrap_or(false))
+}
+
+#[tauri::command]
+fn ai_index_status(app: tauri::AppHandle) -> Result<AiIndexStatus, AppError> {
+    let root = ai_store_root(&app)?;
+    let index = AiIndexStore::open(root);
+    index.status()
+}
+
+#[tauri::command(async)]
+fn ai_index_build(
+    app: tauri::AppHandle,
+    req: AiIndexBuildRequest,
+    operation_id: Option<String>,
+) -> Result<AiIndexStatus, AppError> {
+    let root = ai_store_root(&app)?;
+    let evidence = AiEvidenceStore::open(root.clone());
+    let index = AiIndexStore::open(root);
+    let embedder = ai_embedder()?;
+    let updated_at = now_rfc3339_utc()?;
+    let operation_id = operation_id_or_new("ai_index_build", operation_id);
+    let queue = app.state::<AiOperationQueue>();
+    let _turn = ai_queue_acquire(&app, &queue, &operation_id, "ai_index_build", &req.model);
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(&sink, operation_id, "ai_index_build");
+    index.build_with_embedder_progress(
+        &evidence,
+        &embedder,
+        AiIndexBuildInput {
+            model: req.model,
+            source_id: req.source_id,
+            updated_at,
+        },
+        &mut progress,
+    )
+}
+
+#[tauri::command(async)]
+fn ai_evidence_query(
+    app: tauri::AppHandle,
+    req: AiEvidenceQueryRequest,
+) -> Result<AiEvidenceQueryResponse, AppError> {
+    let root = ai_store_root(&app)?;
+    let evidence = AiEvidenceStore::open(root.clone());
+    let index = AiIndexStore::open(root);
+    // Without a usable embedder or index the query falls back to keyword search.
+    let embedder = ai_embedder().ok();
+    let queue = app.state::<AiOperationQueue>();
+    let status = index.status()?;
+    let _turn = match (status.ready, status.model, embedder.is_some()) {
+        (true, Some(model), true) => {
+            let operation_id = operation_id_or_new("ai_evidence_query", None);
+            Some(ai_queue_acquire(&app, &queue, &operation_id, "ai_evidence_query", &model))
+        }
+        _ => None,
+    };
+    // With an incident in focus, over-fetch so linked chunks just below the cut can be boosted in.
+    let fetch_k = if req.incident_id.is_some() { req.top_k.saturating_mul(2) } else { req.top_k };
+    let mut res = ai_query_with_fallback(
+        &evidence,
+        &index,
+        embedder.as_ref().map(|e| e as &dyn qir_ai::embeddings::Embedder),
+        &req.query,
+        fetch_k,
+        req.source_filter.as_deref(),
+        req.language_filter.as_deref(),
+    )?;
+    if let Some(incident_id) = req.incident_id {
+        let linked: BTreeSet<String> = evidence
+            .incident_evidence_links(incident_id)?
+            .links
+            .into_iter()
+            .map(|l| l.chunk_id)
+            .collect();
+        ai_boost_incident_linked_hits(&mut res, &linked, req.top_k);
+    }
+    Ok(res)
+}
+
+#[tauri::command(async)]
+fn ai_draft_section(
+    app: tauri::AppHandle,
+    req: AiDraftSectionRequestWire,
+    operation_id: Option<String>,
+) -> Result<AiDraftResponse, AppError> {
+    {
+        let state = app.state::<WorkspaceState>();
+        let conn = open_current_workspace_conn(&app, &state)?;
+        load_ai_usage_policy(&conn)?.check_draft(&req.section_id.section_type(), req.citation_chunk_ids.len())?;
+    }
+    let root = ai_store_root(&app)?;
+    let evidence = AiEvidenceStore::open(root);
+    let llm = ai_llm()?;
+    let operation_id = operation_id_or_new("ai_draft_section", operation_id);
+    let queue = app.state::<AiOperationQueue>();
+    let _turn = ai_queue_acquire(&app, &queue, &operation_id, "ai_draft_section", &req.model);
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(&sink, operation_id, "ai_draft_section");
+
+    ai_draft_with_llm_progress(
+        &evidence,
+        &llm,
+        &req.model,
+        AiDraftSectionRequest {
+            section_id: req.section_id,
+            quarter_label: req.quarter_label,
+            prompt: req.prompt,
+            citation_chunk_ids: req.citation_chunk_ids,
+        },
+        &mut progress,
+    )
+}
+
+#[tauri::command]
+fn ai_queue_status(app: tauri::AppHandle) -> AiQueueSnapshot {
+    app.state::<AiOperationQueue>().snapshot()
+}
+
+/// Guardrail check for the draft editor's save path; returns the citations the edited text keeps.
+#[tauri::command]
+fn ai_draft_validate_text(
+    app: tauri::AppHandle,
+    text: String,
+    allowed_chunk_ids: Vec<String>,
+) -> Result<Vec<AiCitation>, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    ai_validate_draft_text(&store, &text, &allowed_chunk_ids)
+}
+
+#[tauri::command]
+fn ai_citation_previews(
+    app: tauri::AppHandle,
+    markdown: String,
+) -> Result<BTreeMap<String, AiCitationPreview>, AppError> {
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    ai_resolve_citation_previews(&store, &markdown)
+}
+
+#[tauri::command]
+fn ai_drafts_create(app: tauri::AppHandle, req: AiDraftCreateRequest) -> Result<AiDraftArtifact, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let created_at = now_rfc3339_utc()?;
+    let section_type = AiDraftSectionType::from_str(&req.section_type).ok_or_else(|| {
+        AppError::new("DB_AI_DRAFT_INVALID", "Invalid section_type for AI draft")
+            .with_details(req.section_type.clone())
+    })?;
+    load_ai_usage_policy(&conn)?.check_draft(&section_type, req.citation_chunk_ids.len())?;
+
+    qir_core::ai_drafts::create_ai_draft(
+        &conn,
+        CreateAiDraftInput {
+            quarter_label: req.quarter_label,
+            section_type,
+            draft_text: req.draft_text,
+            citation_chunk_ids: req.citation_chunk_ids,
+            model_name: req.model_name,
+            model_params_hash: req.model_params_hash,
+            prompt_template_version: req.prompt_template_version,
+            created_at,
+            parent_draft_id: None,
+            revision_notes: None,
+            branch_label: None,
+        },
+    )
+}
+
+#[tauri::command]
+fn ai_drafts_list(app: tauri::AppHandle, req: AiDraftListRequest) -> Result<Vec<AiDraftListItem>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::ai_drafts::staleness::list_ai_drafts_with_staleness(&conn, req.quarter_label.as_deref())
+}
+
+#[tauri::command]
+fn ai_drafts_get(app: tauri::AppHandle, id: i64) -> Result<Option<AiDraftArtifact>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::ai_drafts::get_ai_draft(&conn, id)
+}
+
+#[tauri::command]
+fn ai_draft_comment_add(app: tauri::AppHandle, req: CreateDraftCommentInput) -> Result<DraftComment, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::ai_drafts::comments::create_draft_comment(&conn, req)
+}
+
+#[tauri::command]
+fn ai_draft_comments_list(
+    app: tauri::AppHandle,
+    draft_id: i64,
+    include_resolved: Option<bool>,
+) -> Result<Vec<DraftComment>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::ai_drafts::comments::list_draft_comments(&conn, draft_id, include_resolved.unwrap_or(true))
+}
+
+#[tauri::command]
+fn ai_draft_comment_update(app: tauri::AppHandle, id: i64, text: String) -> Result<DraftComment, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::ai_drafts::comments::update_draft_comment_text(&conn, id, &text)
+}
+
+#[tauri::command]
+fn ai_draft_comment_set_resolved(app: tauri::AppHandle, id: i64, resolved: bool) -> Result<DraftComment, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::ai_drafts::comments::set_draft_comment_resolved(&conn, id, resolved)
+}
+
+#[tauri::command]
+fn ai_draft_comment_delete(app: tauri::AppHandle, id: i64) -> Result<(), AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::ai_drafts::comments::delete_draft_comment(&conn, id)
+}
+
+#[tauri::command]
+fn ai_draft_export(
+    app: tauri::AppHandle,
+    draft_id: i64,
+    format: AiDraftExportFormat,
+    destination_dir: String,
+) -> Result<AiDraftExportResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let draft = qir_core::ai_drafts::get_ai_draft(&conn, draft_id)?.ok_or_else(|| {
+        AppError::new("DB_NOT_FOUND", "AI draft not found").with_details(format!("draft_id={draft_id}"))
+    })?;
+    let policy = load_ai_usage_policy(&conn)?;
+    ensure_draft_exportable(&conn, &policy, &draft)?;
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    ai_export_draft_with_disclaimer(
+        &store,
+        &draft,
+        format,
+        PathBuf::from(destination_dir).as_path(),
+        policy.disclaimer_text(),
+    )
+}
+
+#[tauri::command]
+fn ai_usage_policy_get(app: tauri::AppHandle) -> Result<AiUsagePolicy, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    load_ai_usage_policy(&conn)
+}
+
+#[tauri::command]
+fn ai_usage_policy_set(app: tauri::AppHandle, policy: AiUsagePolicy) -> Result<AiUsagePolicy, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    save_ai_usage_policy(&conn, &policy)?;
+    load_ai_usage_policy(&conn)
+}
+
+#[tauri::command]
+fn ai_draft_approval_get(app: tauri::AppHandle, draft_id: i64) -> Result<Option<AiDraftApproval>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    get_ai_draft_approval(&conn, draft_id)
+}
+
+#[tauri::command]
+fn ai_draft_approve(app: tauri::AppHandle, draft_id: i64, approved_by: String) -> Result<AiDraftApproval, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    approve_ai_draft(&conn, draft_id, &approved_by, &now_rfc3339_utc()?)
+}
+
+#[tauri::command]
+fn ai_draft_approval_revoke(app: tauri::AppHandle, draft_id: i64) -> Result<(), AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    revoke_ai_draft_approval(&conn, draft_id)
+}
+
+/// Exports the chunks cited by a published report's drafts (via its manifest) and/or by the
+/// given drafts, so reviewers without the app can audit the claims.
+#[tauri::command]
+fn ai_evidence_appendix_export(
     app: tauri::AppHandle,
-    incident_id: Option<i64>,
-    new_incident_title: Option<String>,
-    transcript_text: String,
-) -> Result<SlackIngestSummary, AppError> {
+    report_manifest_path: Option<String>,
+    draft_ids: Vec<i64>,
+    destination_dir: String,
+) -> Result<AiEvidenceAppendixResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let manifest = report_manifest_path
+        .map(|p| read_report_manifest(PathBuf::from(p).as_path()))
+        .transpose()?;
+
+    let mut ids: Vec<i64> = manifest.as_ref().map(|m| m.draft_ids.clone()).unwrap_or_default();
+    for id in draft_ids {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    let mut drafts = Vec::with_capacity(ids.len());
+    for id in ids {
+        drafts.push(qir_core::ai_drafts::get_ai_draft(&conn, id)?.ok_or_else(|| {
+            AppError::new("DB_NOT_FOUND", "AI draft not found").with_details(format!("draft_id={id}"))
+        })?);
+    }
+
+    let root = ai_store_root(&app)?;
+    let store = AiEvidenceStore::open(root);
+    ai_export_evidence_appendix(
+        &store,
+        &drafts,
+        manifest.as_ref().map(|m| m.report_sha256.as_str()),
+        PathBuf::from(destination_dir).as_path(),
+    )
+}
+
+#[tauri::command]
+fn backup_create(
+    app: tauri::AppHandle,
+    destination_dir: String,
+    operation_id: Option<String>,
+) -> Result<BackupCreateResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let db_path = resolve_current_db_path(&app, &state)?;
+    let conn = qir_core::workspace::open_workspace_connection(&db_path)?;
+    let artifacts_dir = artifacts_dir(&app)?;
+    let export_time = now_rfc3339_utc()?;
+    let dest_root = PathBuf::from(destination_dir);
+
+    let artifacts_opt = if artifacts_dir.is_dir() {
+        Some(artifacts_dir.as_path())
+    } else {
+        None
+    };
+
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("backup_create", operation_id),
+        "backup_create",
+    );
+    qir_core::backup::create_backup_with_progress(
+        &conn,
+        &db_path,
+        artifacts_opt,
+        dest_root.as_path(),
+        &export_time,
+        env!("CARGO_PKG_VERSION"),
+        &mut progress,
+    )
+}
+
+#[tauri::command]
+fn backup_create_differential(
+    app: tauri::AppHandle,
+    base_backup_dir: String,
+    operation_id: Option<String>,
+) -> Result<BackupCreateResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let db_path = resolve_current_db_path(&app, &state)?;
+    let conn = qir_core::workspace::open_workspace_connection(&db_path)?;
+    let artifacts_dir = artifacts_dir(&app)?;
+    let export_time = now_rfc3339_utc()?;
+    let base_root = PathBuf::from(base_backup_dir);
+
+    let artifacts_opt = if artifacts_dir.is_dir() {
+        Some(artifacts_dir.as_path())
+    } else {
+        None
+    };
+
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("backup_create_differential", operation_id),
+        "backup_create_differential",
+    );
+    qir_core::backup::create_differential_backup_with_progress(
+        &conn,
+        &db_path,
+        artifacts_opt,
+        base_root.as_path(),
+        &export_time,
+        env!("CARGO_PKG_VERSION"),
+        &mut progress,
+    )
+}
+
+#[tauri::command]
+fn backup_inspect(backup_dir: String) -> Result<BackupManifest, AppError> {
+    qir_core::backup::read_manifest(PathBuf::from(backup_dir).as_path())
+}
+
+#[tauri::command]
+fn restore_from_backup(
+    app: tauri::AppHandle,
+    backup_dir: String,
+    allow_overwrite: bool,
+) -> Result<RestoreResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let db_path = resolve_current_db_path(&app, &state)?;
+    let artifacts_dir = artifacts_dir(&app)?;
+    let artifacts_opt = Some(artifacts_dir.as_path());
+
+    qir_core::backup::restore_from_backup(
+        PathBuf::from(backup_dir).as_path(),
+        &db_path,
+        artifacts_opt,
+        allow_overwrite,
+    )
+}
+
+/// Restores a backup into a new workspace at `dest_path` and adds it to recents. The current
+/// workspace stays selected; the UI opens the restored one explicitly via `workspace_open`.
+#[tauri::command]
+fn restore_as_new_workspace(
+    app: tauri::AppHandle,
+    backup_dir: String,
+    dest_path: String,
+) -> Result<RestoreAsNewWorkspaceResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let dest_path = PathBuf::from(dest_path);
+    let res = qir_core::backup::restore_as_new_workspace(PathBuf::from(backup_dir).as_path(), &dest_path)?;
+
+    push_recent(&state, &dest_path);
+    let cfg = WorkspaceConfig {
+        last_db_path: state
+            .current_db_path
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|p| p.to_string_lossy().to_string()),
+        recent_db_paths: state
+            .recent_db_paths
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect(),
+    };
+    write_workspace_config(&app, &cfg)?;
+
+    Ok(res)
+}
+
+#[tauri::command]
+fn export_sanitized_dataset(
+    app: tauri::AppHandle,
+    destination_dir: String,
+    operation_id: Option<String>,
+) -> Result<SanitizedExportResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let export_time = now_rfc3339_utc()?;
+    let dest_root = PathBuf::from(destination_dir);
+    let sink = progress_sink(&app);
+    let mut progress = Progress::new(
+        &sink,
+        operation_id_or_new("export_sanitized_dataset", operation_id),
+        "export_sanitized_dataset",
+    );
+    core_export_sanitized_dataset_with_progress(
+        &conn,
+        dest_root.as_path(),
+        &export_time,
+        env!("CARGO_PKG_VERSION"),
+        &mut progress,
+    )
+}
+
+#[tauri::command]
+fn export_parquet(app: tauri::AppHandle, destination_dir: String) -> Result<ParquetExportResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let export_time = now_rfc3339_utc()?;
+    core_export_parquet_dataset(
+        &conn,
+        PathBuf::from(destination_dir).as_path(),
+        &export_time,
+        env!("CARGO_PKG_VERSION"),
+    )
+}
+
+#[tauri::command]
+fn inspect_sanitized_dataset(dataset_dir: String) -> Result<SanitizedExportManifest, AppError> {
+    core_inspect_sanitized_dataset(PathBuf::from(dataset_dir).as_path())
+}
+
+#[tauri::command]
+fn import_sanitized_dataset(
+    app: tauri::AppHandle,
+    dataset_dir: String,
+) -> Result<SanitizedImportSummary, AppError> {
     let state = app.state::<WorkspaceState>();
     let mut conn = open_current_workspace_conn(&app, &state)?;
-    ingest_slack_transcript_text(
-        &mut conn,
-        incident_id,
-        new_incident_title.as_deref(),
-        &transcript_text,
+    core_import_sanitized_dataset(&mut conn, PathBuf::from(dataset_dir).as_path())
+}
+
+/// Read-only: datasets are loaded in memory and never written to the current workspace.
+#[tauri::command]
+fn sanitized_benchmark_aggregate(dataset_dirs: Vec<String>) -> Result<SanitizedBenchmark, AppError> {
+    let dirs = dataset_dirs.into_iter().map(PathBuf::from).collect::<Vec<_>>();
+    aggregate_sanitized_benchmark(&dirs)
+}
+
+#[tauri::command]
+fn sanitized_benchmark_report_md(dataset_dirs: Vec<String>) -> Result<String, AppError> {
+    let dirs = dataset_dirs.into_iter().map(PathBuf::from).collect::<Vec<_>>();
+    Ok(render_benchmark_markdown(&aggregate_sanitized_benchmark(&dirs)?))
+}
+
+#[tauri::command]
+fn settings_export(
+    app: tauri::AppHandle,
+    destination_dir: String,
+    signing_key: Option<String>,
+) -> Result<SettingsExportResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let export_time = now_rfc3339_utc()?;
+    core_export_settings_bundle(
+        &conn,
+        PathBuf::from(destination_dir).as_path(),
+        &export_time,
+        env!("CARGO_PKG_VERSION"),
+        signing_key.as_deref(),
     )
 }
 
 #[tauri::command]
-fn ai_health_check() -> Result<AiHealthStatus, AppError> {
-    let client = OllamaClient::new("http://127.0.0.1:11434")?;
-    client.health_check()?;
-    Ok(AiHealthStatus {
-        ok: true,
-        message: "Ollama reachable on 127.0.0.1".to_string(),
-    })
+fn settings_import(
+    app: tauri::AppHandle,
+    bundle_path: String,
+    signing_key: Option<String>,
+) -> Result<SettingsImportSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let bundle = core_read_settings_bundle(PathBuf::from(bundle_path).as_path())?;
+    core_import_settings_bundle(&mut conn, &bundle, signing_key.as_deref())
+}
+
+#[tauri::command]
+fn get_interchange_capabilities() -> InterchangeCapabilities {
+    interchange_capabilities()
+}
+
+#[tauri::command]
+fn export_interchange(
+    app: tauri::AppHandle,
+    destination_dir: String,
+    accept_versions: Option<Vec<u32>>,
+) -> Result<InterchangeExportResult, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let exported_at = now_rfc3339_utc()?;
+    core_export_interchange(
+        &conn,
+        PathBuf::from(destination_dir).as_path(),
+        &accept_versions.unwrap_or_default(),
+        &exported_at,
+        env!("CARGO_PKG_VERSION"),
+    )
+}
+
+#[tauri::command]
+fn import_interchange(app: tauri::AppHandle, document_path: String) -> Result<InterchangeImportSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let doc = read_interchange_document(PathBuf::from(document_path).as_path())?;
+    let imported_at = now_rfc3339_utc()?;
+    core_import_interchange(&mut conn, &doc, &imported_at)
+}
+
+#[tauri::command]
+fn quarters_list(app: tauri::AppHandle) -> Result<Vec<Quarter>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::list_quarters(&conn)
+}
+
+#[tauri::command]
+fn quarter_create(app: tauri::AppHandle, req: QuarterInput) -> Result<Quarter, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::create_quarter(&conn, &req)
+}
+
+#[tauri::command]
+fn quarter_update(app: tauri::AppHandle, req: QuarterInput) -> Result<Quarter, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::update_quarter(&conn, &req)
+}
+
+#[tauri::command]
+fn quarter_delete(app: tauri::AppHandle, label: String) -> Result<(), AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::delete_quarter(&conn, &label)
+}
+
+#[tauri::command]
+fn quarter_resolve_scope(app: tauri::AppHandle, label: String) -> Result<QuarterScope, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::resolve_quarter_scope(&conn, &label)
+}
+
+#[tauri::command]
+fn quarter_defaults(app: tauri::AppHandle, label: String) -> Result<QuarterInput, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::default_quarter_input(&conn, &label)
+}
+
+#[tauri::command]
+fn fiscal_calendar_get(app: tauri::AppHandle) -> Result<FiscalCalendarConfig, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::load_fiscal_calendar(&conn)
+}
+
+#[tauri::command]
+fn fiscal_calendar_set(
+    app: tauri::AppHandle,
+    config: FiscalCalendarConfig,
+) -> Result<FiscalCalendarConfig, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::save_fiscal_calendar(&conn, &config)?;
+    qir_core::quarters::load_fiscal_calendar(&conn)
+}
+
+#[tauri::command]
+fn quarter_assignment_rule_get(app: tauri::AppHandle) -> Result<QuarterAssignmentAnchor, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::load_quarter_assignment_anchor(&conn)
+}
+
+#[tauri::command]
+fn quarter_assignment_rule_set(
+    app: tauri::AppHandle,
+    anchor: QuarterAssignmentAnchor,
+) -> Result<QuarterAssignmentSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::save_quarter_assignment_anchor(&conn, anchor)
+}
+
+#[tauri::command]
+fn quarter_assignments_refresh(app: tauri::AppHandle) -> Result<QuarterAssignmentSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::refresh_quarter_assignments(&conn)
 }
 
 #[tauri::command]
-fn ai_models_list() -> Result<Vec<AiModelInfo>, AppError> {
-    let client = OllamaClient::new("http://127.0.0.1:11434")?;
-    let mut models = client.list_models()?;
-    models.sort_by(|a, b| a.name.cmp(&b.name));
-    Ok(models
-        .into_iter()
-        .map(|m: OllamaModelInfo| AiModelInfo {
-            name: m.name,
-            size: m.size,
-            digest: m.digest,
-            modified_at: m.modified_at,
-        })
-        .collect())
+fn quarter_boundary_report(
+    app: tauri::AppHandle,
+    quarter_label: Option<String>,
+) -> Result<QuarterBoundaryReport, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::quarter_boundary_report(&conn, quarter_label.as_deref())
 }
 
 #[tauri::command]
-fn ai_evidence_add_source(
-    app: tauri::AppHandle,
-    req: EvidenceAddSourceRequest,
-) -> Result<AiEvidenceSource, AppError> {
-    let root = ai_store_root(&app)?;
-    let store = AiEvidenceStore::open(root);
-    let created_at = now_rfc3339_utc()?;
-    store.add_source(AiEvidenceAddSourceInput {
-        source_type: req.source_type,
-        origin: req.origin,
-        label: req.label,
-        created_at,
-        text: req.text,
-    })
+fn quarter_carry_over(app: tauri::AppHandle, quarter_label: String) -> Result<QuarterCarryOver, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::quarters::quarter_carry_over(&conn, &quarter_label)
 }
 
 #[tauri::command]
-fn ai_evidence_list_sources(app: tauri::AppHandle) -> Result<Vec<AiEvidenceSource>, AppError> {
-    let root = ai_store_root(&app)?;
-    let store = AiEvidenceStore::open(root);
-    store.list_sources()
+fn action_item_create(app: tauri::AppHandle, req: CreateActionItemInput) -> Result<ActionItem, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::action_items::create_action_item(&conn, req)
 }
 
 #[tauri::command]
-fn ai_evidence_build_chunks(
+fn action_items_list(
     app: tauri::AppHandle,
-    source_id: Option<String>,
-) -> Result<AiBuildChunksResult, AppError> {
-    let root = ai_store_root(&app)?;
-    let store = AiEvidenceStore::open(root);
-    let updated_at = now_rfc3339_utc()?;
-    store.build_chunks(source_id, &updated_at)
+    incident_id: Option<i64>,
+    include_done: Option<bool>,
+) -> Result<Vec<ActionItem>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::action_items::list_action_items(&conn, incident_id, include_done.unwrap_or(true))
 }
 
 #[tauri::command]
-fn ai_evidence_list_chunks(
+fn action_item_set_done(
     app: tauri::AppHandle,
-    source_id: Option<String>,
-) -> Result<Vec<AiEvidenceChunkSummary>, AppError> {
-    let root = ai_store_root(&app)?;
-    let store = AiEvidenceStore::open(root);
-    store.list_chunks(AiEvidenceQueryStore {
-        include_text: false,
-        source_id,
-    })
+    id: i64,
+    done: bool,
+    closed_at: Option<String>,
+) -> Result<ActionItem, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::action_items::set_action_item_done(&conn, id, done, closed_at.as_deref())
 }
 
 #[tauri::command]
-fn ai_evidence_list_chunks_paginated(
-    app: tauri::AppHandle,
-    source_id: Option<String>,
-    limit: u32,
-    offset: u32,
-) -> Result<PaginationResult<AiEvidenceChunkSummary>, AppError> {
-    let root = ai_store_root(&app)?;
-    let store = AiEvidenceStore::open(root);
-    let pagination = PaginationParams {
-        limit,
-        offset,
-        sort_by: None,
-        sort_order: None,
-    };
-    store.list_chunks_paginated(
-        AiEvidenceQueryStore {
-            include_text: false,
-            source_id,
-        },
-        pagination,
-    )
+fn action_item_delete(app: tauri::AppHandle, id: i64) -> Result<(), AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::action_items::delete_action_item(&conn, id)
 }
 
 #[tauri::command]
-fn ai_evidence_get_chunk(app: tauri::AppHandle, chunk_id: String) -> Result<AiEvidenceChunk, AppError> {
-    let root = ai_store_root(&app)?;
-    let store = AiEvidenceStore::open(root);
-    store.get_chunk(&chunk_id)
+fn responder_add(app: tauri::AppHandle, input: AddResponderInput) -> Result<IncidentResponder, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::responders::add_responder(&conn, input)
 }
 
 #[tauri::command]
-fn ai_evidence_get_context(
-    app: tauri::AppHandle,
-    req: AiEvidenceContextRequest,
-) -> Result<AiEvidenceContextResponse, AppError> {
-    let root = ai_store_root(&app)?;
-    let store = AiEvidenceStore::open(root);
-    store.get_context(&req.chunk_id, req.window)
+fn responders_list(app: tauri::AppHandle, incident_id: Option<i64>) -> Result<Vec<IncidentResponder>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::responders::list_responders(&conn, incident_id)
 }
 
 #[tauri::command]
-fn ai_index_status(app: tauri::AppHandle) -> Result<AiIndexStatus, AppError> {
-    let root = ai_store_root(&app)?;
-    let index = AiIndexStore::open(root);
-    index.status()
+fn responder_remove(app: tauri::AppHandle, id: i64) -> Result<(), AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::responders::remove_responder(&conn, id)
 }
 
 #[tauri::command]
-fn ai_index_build(app: tauri::AppHandle, req: AiIndexBuildRequest) -> Result<AiIndexStatus, AppError> {
-    let root = ai_store_root(&app)?;
-    let evidence = AiEvidenceStore::open(root.clone());
-    let index = AiIndexStore::open(root);
-    let embedder = ai_embedder()?;
-    let updated_at = now_rfc3339_utc()?;
-    index.build_with_embedder(
-        &evidence,
-        &embedder,
-        AiIndexBuildInput {
-            model: req.model,
-            source_id: req.source_id,
-            updated_at,
-        },
-    )
+fn incident_diagram_get(app: tauri::AppHandle, incident_id: i64) -> Result<Option<IncidentDiagram>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::diagrams::get_incident_diagram(&conn, incident_id)
 }
 
+/// Copies an image into the workspace artifacts folder and makes it the incident's diagram.
 #[tauri::command]
-fn ai_evidence_query(
+fn incident_diagram_attach(
     app: tauri::AppHandle,
-    req: AiEvidenceQueryRequest,
-) -> Result<AiEvidenceQueryResponse, AppError> {
-    let root = ai_store_root(&app)?;
-    let evidence = AiEvidenceStore::open(root.clone());
-    let index = AiIndexStore::open(root);
-    let embedder = ai_embedder()?;
-    ai_query_with_embedder(
-        &evidence,
-        &index,
-        &embedder,
-        &req.query,
-        req.top_k,
-        req.source_filter.as_deref(),
+    incident_id: i64,
+    path: String,
+    caption: Option<String>,
+) -> Result<IncidentDiagram, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let artifacts_dir = artifacts_dir(&app)?;
+    let created_at = now_rfc3339_utc()?;
+    qir_core::diagrams::attach_incident_diagram(
+        &conn,
+        &artifacts_dir,
+        incident_id,
+        PathBuf::from(path).as_path(),
+        caption.as_deref(),
+        &created_at,
     )
 }
 
+/// Marks an image artifact already attached to the workspace as the incident's diagram.
 #[tauri::command]
-fn ai_draft_section(
+fn incident_diagram_set(
     app: tauri::AppHandle,
-    req: AiDraftSectionRequestWire,
-) -> Result<AiDraftResponse, AppError> {
-    let root = ai_store_root(&app)?;
-    let evidence = AiEvidenceStore::open(root);
-    let llm = ai_llm()?;
-
-    ai_draft_with_llm(
-        &evidence,
-        &llm,
-        &req.model,
-        AiDraftSectionRequest {
-            section_id: req.section_id,
-            quarter_label: req.quarter_label,
-            prompt: req.prompt,
-            citation_chunk_ids: req.citation_chunk_ids,
-        },
-    )
+    incident_id: i64,
+    artifact_id: i64,
+    caption: Option<String>,
+) -> Result<IncidentDiagram, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    let artifacts_dir = artifacts_dir(&app)?;
+    let set_at = now_rfc3339_utc()?;
+    qir_core::diagrams::set_incident_diagram(&conn, &artifacts_dir, incident_id, artifact_id, caption.as_deref(), &set_at)
 }
 
 #[tauri::command]
-fn ai_drafts_create(app: tauri::AppHandle, req: AiDraftCreateRequest) -> Result<AiDraftArtifact, AppError> {
+fn incident_diagram_clear(app: tauri::AppHandle, incident_id: i64) -> Result<(), AppError> {
     let state = app.state::<WorkspaceState>();
     let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::diagrams::clear_incident_diagram(&conn, incident_id)
+}
+
+/// Copies every file in a folder (screenshots, logs) into the artifacts dir and links them to
+/// the incident.
+#[tauri::command]
+fn artifacts_ingest_dir(
+    app: tauri::AppHandle,
+    incident_id: i64,
+    dir: String,
+) -> Result<ArtifactsDirIngestSummary, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    let artifacts_dir = artifacts_dir(&app)?;
     let created_at = now_rfc3339_utc()?;
-    let section_type = AiDraftSectionType::from_str(&req.section_type).ok_or_else(|| {
-        AppError::new("DB_AI_DRAFT_INVALID", "Invalid section_type for AI draft")
-            .with_details(req.section_type.clone())
-    })?;
+    ingest_artifacts_dir(&mut conn, &artifacts_dir, incident_id, Path::new(&dir), &created_at)
+}
 
-    qir_core::ai_drafts::create_ai_draft(
-        &conn,
-        CreateAiDraftInput {
-            quarter_label: req.quarter_label,
-            section_type,
-            draft_text: req.draft_text,
-            citation_chunk_ids: req.citation_chunk_ids,
-            model_name: req.model_name,
-            model_params_hash: req.model_params_hash,
-            prompt_template_version: req.prompt_template_version,
-            created_at,
-            parent_draft_id: None,
-            revision_notes: None,
-            branch_label: None,
-        },
-    )
+#[tauri::command]
+fn severity_grade_set(
+    app: tauri::AppHandle,
+    incident_id: i64,
+    initial_severity: Option<String>,
+) -> Result<Option<SeverityGrade>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::severity::set_initial_severity(&conn, incident_id, initial_severity.as_deref())
 }
 
 #[tauri::command]
-fn ai_drafts_list(app: tauri::AppHandle, req: AiDraftListRequest) -> Result<Vec<AiDraftArtifact>, AppError> {
+fn severity_mappings_list(app: tauri::AppHandle) -> Result<Vec<SeverityMapping>, AppError> {
     let state = app.state::<WorkspaceState>();
     let conn = open_current_workspace_conn(&app, &state)?;
-    qir_core::ai_drafts::list_ai_drafts(&conn, req.quarter_label.as_deref())
+    qir_core::severity::list_severity_mappings(&conn)
 }
 
 #[tauri::command]
-fn ai_drafts_get(app: tauri::AppHandle, id: i64) -> Result<Option<AiDraftArtifact>, AppError> {
+fn severity_mapping_upsert(app: tauri::AppHandle, req: SeverityMappingInput) -> Result<SeverityMapping, AppError> {
     let state = app.state::<WorkspaceState>();
     let conn = open_current_workspace_conn(&app, &state)?;
-    qir_core::ai_drafts::get_ai_draft(&conn, id)
+    qir_core::severity::upsert_severity_mapping(&conn, &req)
 }
 
 #[tauri::command]
-fn backup_create(app: tauri::AppHandle, destination_dir: String) -> Result<BackupCreateResult, AppError> {
+fn severity_mapping_delete(app: tauri::AppHandle, source_value: String) -> Result<(), AppError> {
     let state = app.state::<WorkspaceState>();
-    let db_path = resolve_current_db_path(&app, &state)?;
-    let conn = qir_core::workspace::open_workspace_connection(&db_path)?;
-    let artifacts_dir = default_artifacts_dir(&app)?;
-    let export_time = now_rfc3339_utc()?;
-    let dest_root = PathBuf::from(destination_dir);
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::severity::delete_severity_mapping(&conn, &source_value)
+}
 
-    let artifacts_opt = if artifacts_dir.is_dir() {
-        Some(artifacts_dir.as_path())
-    } else {
-        None
-    };
+#[tauri::command]
+fn entity_aliases_list(app: tauri::AppHandle, kind: Option<AliasKind>) -> Result<Vec<EntityAlias>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::aliases::list_entity_aliases(&conn, kind)
+}
 
-    qir_core::backup::create_backup(
-        &conn,
-        &db_path,
-        artifacts_opt,
-        dest_root.as_path(),
-        &export_time,
-        env!("CARGO_PKG_VERSION"),
-    )
+#[tauri::command]
+fn entity_alias_upsert(app: tauri::AppHandle, req: EntityAliasInput) -> Result<EntityAlias, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::aliases::upsert_entity_alias(&conn, &req)
 }
 
 #[tauri::command]
-fn backup_inspect(backup_dir: String) -> Result<BackupManifest, AppError> {
-    qir_core::backup::read_manifest(PathBuf::from(backup_dir).as_path())
+fn entity_alias_delete(app: tauri::AppHandle, kind: AliasKind, alias: String) -> Result<(), AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::aliases::delete_entity_alias(&conn, kind, &alias)
 }
 
 #[tauri::command]
-fn restore_from_backup(
+fn quarter_freeze(
     app: tauri::AppHandle,
-    backup_dir: String,
-    allow_overwrite: bool,
-) -> Result<RestoreResult, AppError> {
+    quarter_label: String,
+    actor: Option<String>,
+    note: Option<String>,
+) -> Result<QuarterFreeze, AppError> {
     let state = app.state::<WorkspaceState>();
-    let db_path = resolve_current_db_path(&app, &state)?;
-    let artifacts_dir = default_artifacts_dir(&app)?;
-    let artifacts_opt = Some(artifacts_dir.as_path());
-
-    qir_core::backup::restore_from_backup(
-        PathBuf::from(backup_dir).as_path(),
-        &db_path,
-        artifacts_opt,
-        allow_overwrite,
-    )
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::freeze::freeze_quarter(&mut conn, &quarter_label, actor.as_deref(), note.as_deref())
 }
 
 #[tauri::command]
-fn export_sanitized_dataset(
+fn quarter_unlock(
     app: tauri::AppHandle,
-    destination_dir: String,
-) -> Result<SanitizedExportResult, AppError> {
+    quarter_label: String,
+    actor: Option<String>,
+    reason: String,
+) -> Result<DeleteResponse, AppError> {
     let state = app.state::<WorkspaceState>();
-    let conn = open_current_workspace_conn(&app, &state)?;
-    let export_time = now_rfc3339_utc()?;
-    let dest_root = PathBuf::from(destination_dir);
-    core_export_sanitized_dataset(&conn, dest_root.as_path(), &export_time, env!("CARGO_PKG_VERSION"))
+    let mut conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::freeze::unlock_quarter(&mut conn, &quarter_label, actor.as_deref(), &reason)?;
+    Ok(DeleteResponse { ok: true })
 }
 
 #[tauri::command]
-fn inspect_sanitized_dataset(dataset_dir: String) -> Result<SanitizedExportManifest, AppError> {
-    core_inspect_sanitized_dataset(PathBuf::from(dataset_dir).as_path())
+fn quarter_freeze_list(app: tauri::AppHandle) -> Result<Vec<QuarterFreeze>, AppError> {
+    let state = app.state::<WorkspaceState>();
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::freeze::list_quarter_freezes(&conn)
 }
 
 #[tauri::command]
-fn import_sanitized_dataset(
+fn quarter_freeze_audit(
     app: tauri::AppHandle,
-    dataset_dir: String,
-) -> Result<SanitizedImportSummary, AppError> {
+    quarter_label: Option<String>,
+) -> Result<Vec<QuarterFreezeAuditEntry>, AppError> {
     let state = app.state::<WorkspaceState>();
-    let mut conn = open_current_workspace_conn(&app, &state)?;
-    core_import_sanitized_dataset(&mut conn, PathBuf::from(dataset_dir).as_path())
+    let conn = open_current_workspace_conn(&app, &state)?;
+    qir_core::freeze::list_quarter_freeze_audit(&conn, quarter_label.as_deref())
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .manage(WorkspaceState::default())
+        .manage(DashboardCache::new())
+        .manage(AiOperationQueue::new())
+        .manage(OperationCancels::default())
         .setup(|app| {
             let handle = app.handle();
             let state = app.state::<WorkspaceState>();
@@ -916,15 +3246,47 @@ pub fn run() {
                 }
             };
 
-            if let Some(p) = cfg.last_db_path.as_deref() {
-                *state.current_db_path.lock().unwrap() = Some(PathBuf::from(p));
-            }
             let recent = cfg
                 .recent_db_paths
                 .iter()
                 .map(PathBuf::from)
                 .collect::<Vec<_>>();
-            *state.recent_db_paths.lock().unwrap() = recent;
+            let repair = qir_core::workspace::plan_workspace_config_repair(
+                cfg.last_db_path.as_deref().map(Path::new),
+                &recent,
+                &fallback_db_paths(&handle),
+            );
+
+            match (cfg.last_db_path.as_deref(), repair.missing_db_path.as_deref()) {
+                (Some(p), None) => *state.current_db_path.lock().unwrap() = Some(PathBuf::from(p)),
+                // Keep the stale path in the config until the user picks a replacement, so a
+                // drive that is merely unmounted still works on the next launch.
+                (_, Some(missing)) => {
+                    let mut load_error = state.load_error.lock().unwrap();
+                    if load_error.is_none() {
+                        *load_error = Some(
+                            AppError::new("WORKSPACE_CONFIG_STALE", "Last workspace DB no longer exists")
+                                .with_details(format!("path={missing}; candidates={:?}", repair.candidates)),
+                        );
+                    }
+                }
+                (None, None) => {}
+            }
+            *state.recent_db_paths.lock().unwrap() =
+                repair.recent_db_paths.iter().map(PathBuf::from).collect();
+
+            if !repair.pruned_recent_db_paths.is_empty() {
+                let pruned = WorkspaceConfig {
+                    last_db_path: cfg.last_db_path.clone(),
+                    recent_db_paths: repair.recent_db_paths.clone(),
+                };
+                if let Err(e) = write_workspace_config(&handle, &pruned) {
+                    state.load_error.lock().unwrap().get_or_insert(e);
+                }
+            }
+            if repair.needs_repair() {
+                *state.config_repair.lock().unwrap() = Some(repair);
+            }
             Ok(())
         })
         .plugin(tauri_plugin_dialog::init())
@@ -932,46 +3294,205 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             init_db,
             app_info,
+            app_paths_get,
+            app_paths_set,
+            errors_catalog,
             workspace_migration_status,
             workspace_get_current,
             workspace_open,
+            workspace_repair_config,
+            workspace_warm_start_status,
+            workspace_portable_get,
+            workspace_portable_set,
             workspace_create,
+            workspace_snapshot_branch,
+            workspace_lineage,
             seed_demo_jira,
             seed_demo_dataset,
+            seed_guided_tour,
             get_dashboard_v1,
             get_dashboard_v2,
+            get_dashboard_delta,
+            bucket_drilldown,
+            export_bucket_csv,
+            get_weekday_hour_heatmap,
+            get_incident_lifecycle_funnel,
+            get_anomaly_summary,
+            get_detection_source_comparison,
+            get_severity_regrade_analysis,
+            get_on_call_load,
+            business_hours_get,
+            business_hours_set,
+            display_timezone_get,
+            display_timezone_set,
+            dashboard_config_get,
+            dashboard_config_set,
+            pain_formula_get,
+            pain_formula_set,
+            fingerprint_strategy_get,
+            fingerprint_strategy_set,
+            computed_metrics_get,
+            computed_metrics_set,
             generate_report_md,
+            generate_report_md_as_of,
+            query_readonly,
+            report_export,
+            generate_digest_eml,
+            export_static_site,
+            report_verify,
+            report_determinism_verify,
             jira_csv_preview,
+            jira_csv_suggest_mapping,
+            csv_decode_file,
+            generic_csv_preview,
+            generic_csv_import,
+            operation_cancel,
             jira_profiles_list,
             jira_profiles_upsert,
             jira_profiles_delete,
+            profile_check,
             jira_import_using_profile,
+            jira_import_session,
+            import_files_batch,
+            import_history_list,
+            import_batches_list,
+            import_undo,
+            jira_api_credentials_get,
+            jira_api_credentials_set,
+            jira_api_credentials_clear,
+            jira_api_preview,
+            jira_api_import,
+            jira_json_preview,
+            jira_json_import,
+            pagerduty_csv_preview,
+            pagerduty_csv_import,
+            servicenow_csv_preview,
+            servicenow_csv_import,
+            incident_io_preview,
+            incident_io_import,
+            retro_tool_preview,
+            retro_tool_import,
+            xlsx_preview,
+            xlsx_import,
             incidents_list,
             incident_detail,
+            quick_capture,
+            timeline_append_now,
+            live_reconcile,
+            incident_history,
+            incidents_sample,
+            incidents_sample_list,
+            incidents_sample_replay,
             validation_report,
+            incident_overlaps_list,
+            incident_duplicates_list,
+            incidents_merge,
             slack_preview,
             slack_ingest,
+            slack_export_preview,
+            slack_export_ingest,
+            zoom_vtt_preview,
+            zoom_vtt_ingest,
+            email_preview,
+            email_ingest,
+            statuspage_preview,
+            statuspage_ingest,
+            confluence_preview,
+            confluence_ingest,
+            markdown_postmortem_preview,
+            markdown_postmortem_ingest,
             ai_models_list,
             ai_health_check,
             ai_evidence_add_source,
+            ai_evidence_copy_source,
             ai_evidence_list_sources,
             ai_evidence_build_chunks,
+            ai_incident_links_rebuild,
+            ai_incident_evidence_links,
+            ai_chunking_get,
+            ai_chunking_set,
+            ai_embed_batch_size_get,
+            ai_embed_batch_size_set,
+            ai_evidence_rechunk,
+            ai_evidence_summarize,
             ai_evidence_list_chunks,
             ai_evidence_get_chunk,
             ai_evidence_get_context,
             ai_index_status,
+            ai_store_fsck,
             ai_index_build,
             ai_evidence_query,
             ai_draft_section,
+            ai_queue_status,
+            ai_draft_validate_text,
+            ai_citation_previews,
             ai_drafts_create,
             ai_drafts_list,
             ai_drafts_get,
+            ai_draft_export,
+            ai_usage_policy_get,
+            ai_usage_policy_set,
+            ai_draft_approval_get,
+            ai_draft_approve,
+            ai_draft_approval_revoke,
+            ai_evidence_appendix_export,
+            ai_draft_comment_add,
+            ai_draft_comments_list,
+            ai_draft_comment_update,
+            ai_draft_comment_set_resolved,
+            ai_draft_comment_delete,
             backup_create,
+            backup_create_differential,
             backup_inspect,
             restore_from_backup,
+            restore_as_new_workspace,
             export_sanitized_dataset,
+            export_parquet,
             inspect_sanitized_dataset,
-            import_sanitized_dataset
+            import_sanitized_dataset,
+            sanitized_benchmark_aggregate,
+            sanitized_benchmark_report_md,
+            settings_export,
+            settings_import,
+            get_interchange_capabilities,
+            export_interchange,
+            import_interchange,
+            quarters_list,
+            quarter_create,
+            quarter_update,
+            quarter_delete,
+            quarter_resolve_scope,
+            quarter_defaults,
+            fiscal_calendar_get,
+            fiscal_calendar_set,
+            quarter_assignment_rule_get,
+            quarter_assignment_rule_set,
+            quarter_assignments_refresh,
+            quarter_boundary_report,
+            quarter_carry_over,
+            action_item_create,
+            action_items_list,
+            action_item_set_done,
+            action_item_delete,
+            responder_add,
+            responders_list,
+            responder_remove,
+            incident_diagram_get,
+            incident_diagram_attach,
+            incident_diagram_set,
+            incident_diagram_clear,
+            artifacts_ingest_dir,
+            severity_grade_set,
+            severity_mappings_list,
+            severity_mapping_upsert,
+            severity_mapping_delete,
+            entity_aliases_list,
+            entity_alias_upsert,
+            entity_alias_delete,
+            quarter_freeze,
+            quarter_unlock,
+            quarter_freeze_list,
+            quarter_freeze_audit
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
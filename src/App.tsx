@@ -7,16 +7,26 @@ import { guidanceForWorkspaceErrorCode } from "./lib/workspace_guidance";
 import { pickDbFile, pickDirectory } from "./lib/pickers";
 import {
   DashboardPayloadV2Schema,
+  GeneratedReportSchema,
   InitDbResponseSchema,
   DeleteResponseSchema,
   JiraCsvPreviewSchema,
+  DecodedCsvSchema,
   JiraImportSummarySchema,
+  type ConflictStrategy,
+  ImportUndoSummarySchema,
   JiraMappingProfileListSchema,
+  JiraProfileCheckSchema,
+  JiraMappingSuggestionSchema,
   JiraMappingProfileSchema,
   JiraMappingProfileUpsertSchema,
   AiHealthStatusSchema,
   IncidentListSchema,
   IncidentDetailSchema,
+  IncidentSchema,
+  TimelineEventSchema,
+  LiveReconcileSummarySchema,
+  IncidentEvidenceLinksSchema,
   BackupCreateResultSchema,
   BackupManifestSchema,
   RestoreResultSchema,
@@ -26,6 +36,7 @@ import {
   SlackPreviewSchema,
   SlackIngestSummarySchema,
   ValidationReportSchema,
+  IncidentOverlapListSchema,
   WorkspaceInfoSchema,
   WorkspaceMetadataSchema,
   WorkspaceMigrationStatusSchema,
@@ -35,15 +46,27 @@ import { Modal } from "./ui/Modal";
 import { ToastHost } from "./ui/ToastHost";
 import { useToasts } from "./ui/useToasts";
 import { WorkspaceSection } from "./features/workspace/WorkspaceSection";
-import { JiraImportSection } from "./features/import_jira/JiraImportSection";
+import {
+  JiraImportSection,
+  type ImportProgress,
+  type JiraImportSummary,
+  type TimestampFormats,
+} from "./features/import_jira/JiraImportSection";
+import { listenProgress } from "./lib/progress";
 import { SlackImportSection } from "./features/import_slack/SlackImportSection";
 import { SanitizedImportSection } from "./features/import_sanitized/SanitizedImportSection";
 import { BackupRestoreSection } from "./features/backup_restore/BackupRestoreSection";
-import { ValidationSection } from "./features/validation/ValidationSection";
+import { ValidationSection, type IncidentOverlapItem } from "./features/validation/ValidationSection";
 import { ReportSection } from "./features/report/ReportSection";
 import { DashboardsSection } from "./features/dashboards/DashboardsSection";
 import { IncidentDetailDrawer } from "./features/dashboards/IncidentDetailDrawer";
 import { AiSection } from "./features/ai/AiSection";
+import {
+  LiveCaptureSection,
+  type CapturedIncident,
+  type LiveNoteKind,
+  type QuickCaptureRequest,
+} from "./features/live/LiveCaptureSection";
 import { AboutSection } from "./features/about/AboutSection";
 
 export default function App() {
@@ -77,6 +100,7 @@ export default function App() {
   const [selectedProfileId, setSelectedProfileId] = useState<number | null>(null);
   const [profileName, setProfileName] = useState<string>("");
   const [csvText, setCsvText] = useState<string>("");
+  const [lastCaptured, setLastCaptured] = useState<CapturedIncident | null>(null);
   const [csvFileName, setCsvFileName] = useState<string>("");
   const [csvPreview, setCsvPreview] = useState<null | { headers: string[]; rows: string[][] }>(null);
   const [mapping, setMapping] = useState<{
@@ -95,6 +119,9 @@ export default function App() {
     ack_ts: string | null;
     mitigate_ts: string | null;
     resolve_ts: string | null;
+    conflict_strategy: ConflictStrategy;
+    assumed_utc_offset_minutes: number | null;
+    timestamp_formats: TimestampFormats;
   }>({
     external_id: null,
     title: "",
@@ -111,14 +138,13 @@ export default function App() {
     ack_ts: null,
     mitigate_ts: null,
     resolve_ts: null,
+    conflict_strategy: "merge_preserve_on_empty",
+    assumed_utc_offset_minutes: null,
+    timestamp_formats: {},
   });
-  const [importSummary, setImportSummary] = useState<null | {
-    inserted: number;
-    updated: number;
-    skipped: number;
-    conflicts: Array<{ row: number; reason: string; external_id?: string | null; fingerprint?: string | null }>;
-    warnings: Array<{ code: string; message: string; details?: string | null }>;
-  }>(null);
+  const [importSummary, setImportSummary] = useState<JiraImportSummary | null>(null);
+  const [importProgress, setImportProgress] = useState<ImportProgress | null>(null);
+  const [importOperationId, setImportOperationId] = useState<string | null>(null);
   const [dashboard, setDashboard] = useState<null | {
     version: number;
     incident_count: number;
@@ -287,6 +313,7 @@ export default function App() {
       created_at: string;
     }>;
   }>(null);
+  const [incidentEvidenceChunkCount, setIncidentEvidenceChunkCount] = useState<number | null>(null);
 
   const [incidentOptions, setIncidentOptions] = useState<
     Array<{ id: number; external_id: string | null; title: string }>
@@ -311,6 +338,7 @@ export default function App() {
     warnings: Array<{ code: string; message: string; details?: string | null }>;
   }>(null);
 
+  const [incidentOverlaps, setIncidentOverlaps] = useState<IncidentOverlapItem[] | null>(null);
   const [validationReport, setValidationReport] = useState<
     null | Array<{ id: number; external_id: string | null; title: string; warnings: Array<{ code: string; message: string; details?: string | null }> }>
   >(null);
@@ -417,6 +445,7 @@ export default function App() {
     setIncidentFilterLabel("");
     setReportMd("");
     setValidationReport(null);
+    setIncidentOverlaps(null);
     setIncidentOptions([]);
     setIncidentDetailOpen(false);
     setIncidentDetail(null);
@@ -463,33 +492,42 @@ export default function App() {
     }
   }
 
-  function applyCommonJiraDefaults() {
-    if (!csvPreview) return;
-    const headers = csvPreview.headers;
-    const pick = (candidates: string[]) => {
-      for (const c of candidates) {
-        if (headers.includes(c)) return c;
-      }
-      return null;
-    };
-    setMapping((m) => ({
-      ...m,
-      external_id: pick(["Key", "Issue key", "Issue Key"]),
-      title: pick(["Summary", "Title"]) ?? m.title,
-      description: pick(["Description"]),
-      severity: pick(["Severity", "Priority"]),
-      detection_source: pick(["DetectionSource", "Detection Source"]),
-      vendor: pick(["Vendor", "Provider"]),
-      service: pick(["Service", "Component"]),
-      impact_pct: pick(["ImpactPct", "Impact %"]),
-      service_health_pct: pick(["ServiceHealthPct", "Service Health %"]),
-      start_ts: pick(["StartTs", "Start", "Start TS"]),
-      first_observed_ts: pick(["FirstObservedTs", "First Observed", "First Observed TS"]),
-      it_awareness_ts: pick(["ItAwarenessTs", "IT Awareness", "IT Awareness TS"]),
-      ack_ts: pick(["AckTs", "Ack", "Ack TS"]),
-      mitigate_ts: pick(["MitigateTs", "Mitigate", "Mitigate TS"]),
-      resolve_ts: pick(["ResolveTs", "Resolve", "Resolve TS"]),
-    }));
+  async function applyCommonJiraDefaults() {
+    if (!csvPreview || !csvText) return;
+    try {
+      const suggestion = await invokeValidated(
+        "jira_csv_suggest_mapping",
+        { csvText },
+        JiraMappingSuggestionSchema
+      );
+      const m = suggestion.mapping;
+      setMapping((prev) => ({
+        ...prev,
+        external_id: m.external_id ?? null,
+        title: m.title || prev.title,
+        description: m.description ?? null,
+        severity: m.severity ?? null,
+        detection_source: m.detection_source ?? null,
+        vendor: m.vendor ?? null,
+        service: m.service ?? null,
+        impact_pct: m.impact_pct ?? null,
+        service_health_pct: m.service_health_pct ?? null,
+        start_ts: m.start_ts ?? null,
+        first_observed_ts: m.first_observed_ts ?? null,
+        it_awareness_ts: m.it_awareness_ts ?? null,
+        ack_ts: m.ack_ts ?? null,
+        mitigate_ts: m.mitigate_ts ?? null,
+        resolve_ts: m.resolve_ts ?? null,
+      }));
+      const unsure = suggestion.fields.filter((f) => f.column && f.confidence < 0.9).map((f) => f.field);
+      pushToast({
+        kind: "success",
+        title: "Mapping suggested",
+        message: unsure.length ? `Check low-confidence fields: ${unsure.join(", ")}` : "All suggestions are exact matches.",
+      });
+    } catch (e) {
+      pushToast({ kind: "error", title: "Mapping suggestion failed", message: String(e) });
+    }
   }
 
   async function onSaveProfile() {
@@ -529,13 +567,54 @@ export default function App() {
     }
   }
 
+  async function onQuickCapture(req: QuickCaptureRequest): Promise<boolean> {
+    try {
+      const incident = await invokeValidated("quick_capture", { req }, IncidentSchema);
+      setLastCaptured(incident);
+      pushToast({ kind: "success", title: "Incident captured", message: `#${incident.id} ${incident.title}` });
+      return true;
+    } catch (e) {
+      pushToast({ kind: "error", title: "Quick capture failed", message: String(e) });
+      return false;
+    }
+  }
+
+  async function onAppendLiveNote(incidentId: number, text: string, kind: LiveNoteKind): Promise<boolean> {
+    try {
+      await invokeValidated("timeline_append_now", { incidentId, text, kind }, TimelineEventSchema);
+      return true;
+    } catch (e) {
+      pushToast({ kind: "error", title: "Add note failed", message: String(e) });
+      return false;
+    }
+  }
+
+  async function onReconcileLiveNotes(incidentId: number) {
+    try {
+      const res = await invokeValidated("live_reconcile", { incidentId }, LiveReconcileSummarySchema);
+      pushToast({
+        kind: "success",
+        title: "Live notes reconciled",
+        message: `${res.suppressed.length} of ${res.checked} notes duplicated imported events`,
+      });
+    } catch (e) {
+      pushToast({ kind: "error", title: "Reconcile failed", message: String(e) });
+    }
+  }
+
   async function onPickCsvFile(file: File | null) {
     setImportSummary(null);
     if (!file) return;
-    const text = await file.text();
     setCsvFileName(file.name);
-    setCsvText(text);
     try {
+      // Decoded in the backend so BOMs, UTF-16 and Windows-1252 exports survive intact.
+      const bytes = Array.from(new Uint8Array(await file.arrayBuffer()));
+      const decoded = await invokeValidated("csv_decode_file", { bytes }, DecodedCsvSchema);
+      const text = decoded.text;
+      setCsvText(text);
+      for (const w of decoded.warnings) {
+        pushToast({ kind: "warning", title: "CSV transcoded", message: w.message });
+      }
       const prev = await invokeValidated("jira_csv_preview", { csvText: text, maxRows: 5 }, JiraCsvPreviewSchema);
       setCsvPreview(prev);
       pushToast({ kind: "success", title: "CSV loaded", message: `${prev.headers.length} columns` });
@@ -544,7 +623,7 @@ export default function App() {
     }
   }
 
-  async function onImportCsv() {
+  async function onImportCsv(dryRun = false) {
     try {
       if (!csvText) {
         pushToast({ kind: "error", title: "No CSV selected", message: "Choose a CSV file first." });
@@ -558,23 +637,91 @@ export default function App() {
         });
         return;
       }
-      const res = await invokeValidated(
-        "jira_import_using_profile",
+      // Stop before a doomed import when the profile references columns this CSV lacks.
+      const check = await invokeValidated(
+        "profile_check",
         { profileId: selectedProfileId, csvText },
-        JiraImportSummarySchema
+        JiraProfileCheckSchema
       );
-      setImportSummary(res);
-      const kind = res.conflicts.length > 0 ? "error" : res.warnings.length > 0 ? "warning" : "success";
-      pushToast({
-        kind,
-        title: "Import complete",
-        message: `inserted=${res.inserted}, updated=${res.updated}, skipped=${res.skipped}, conflicts=${res.conflicts.length}, warnings=${res.warnings.length}`,
+      if (!check.ok) {
+        const problems = check.columns
+          .filter((c) => c.status !== "ok")
+          .map((c) => `${c.field}: "${c.column}" ${c.suggestion ? `→ "${c.suggestion}"?` : "not found"}`);
+        pushToast({
+          kind: "error",
+          title: "Profile does not match this CSV",
+          message: `Update the mapping before importing.\n${problems.join("\n")}`,
+        });
+        return;
+      }
+      const operationId = `import_jira_csv-${Date.now()}`;
+      setImportOperationId(operationId);
+      setImportProgress({ percent: 0, processed: 0, inserted: 0, updated: 0, skipped: 0, conflicts: 0 });
+      const unlisten = await listenProgress((ev) => {
+        if (ev.operation_id !== operationId) return;
+        setImportProgress((prev) => ({
+          ...(prev ?? { processed: 0, inserted: 0, updated: 0, skipped: 0, conflicts: 0 }),
+          ...(ev.counts ?? {}),
+          percent: ev.percent ?? prev?.percent ?? null,
+        }));
       });
+      try {
+        const res = await invokeValidated(
+          "jira_import_using_profile",
+          {
+            profileId: selectedProfileId,
+            csvText,
+            operationId,
+            dryRun,
+            conflictStrategy: mapping.conflict_strategy,
+            assumedUtcOffsetMinutes: mapping.assumed_utc_offset_minutes,
+          },
+          JiraImportSummarySchema
+        );
+        setImportSummary(res);
+        const kind = res.conflicts.length > 0 ? "error" : res.warnings.length > 0 ? "warning" : "success";
+        pushToast({
+          kind,
+          title: dryRun ? "Dry run complete (nothing written)" : "Import complete",
+          message: `inserted=${res.inserted}, updated=${res.updated}, skipped=${res.skipped}, conflicts=${res.conflicts.length}, warnings=${res.warnings.length}`,
+        });
+      } finally {
+        unlisten();
+        setImportProgress(null);
+        setImportOperationId(null);
+      }
     } catch (e) {
+      if (extractAppError(e)?.code === "INGEST_IMPORT_CANCELLED") {
+        pushToast({ kind: "warning", title: "Import cancelled", message: "Nothing was imported." });
+        return;
+      }
       pushToast({ kind: "error", title: "Import failed", message: String(e) });
     }
   }
 
+  async function onUndoImport(batchId: number) {
+    try {
+      const res = await invokeValidated("import_undo", { batchId }, ImportUndoSummarySchema);
+      setImportSummary(null);
+      pushToast({
+        kind: "success",
+        title: "Import undone",
+        message: `deleted=${res.deleted}, restored=${res.restored}`,
+      });
+    } catch (e) {
+      pushToast({ kind: "error", title: "Undo failed", message: String(e) });
+    }
+  }
+
+  async function onCancelImport() {
+    if (!importOperationId) return;
+    try {
+      await invokeValidated<boolean>("operation_cancel", { operationId: importOperationId }, null);
+    } catch (e) {
+      pushToast({ kind: "error", title: "Cancel failed", message: String(e) });
+    }
+  }
+
   async function onLoadDashboard() {
     try {
       const res = await invokeValidated("get_dashboard_v2", undefined, DashboardPayloadV2Schema);
@@ -590,7 +737,8 @@ export default function App() {
 
   async function onGenerateReport() {
     try {
-      const md = await invokeValidated<string>("generate_report_md", undefined, null);
+      const report = await invokeValidated("generate_report_md", undefined, GeneratedReportSchema);
+      const md = report.markdown;
       setReportMd(md);
       pushToast({ kind: "success", title: "Report generated", message: `${md.length} chars` });
     } catch (e) {
@@ -617,6 +765,7 @@ export default function App() {
       { label: "Backup/Restore", href: "#data" },
       { label: "Report", href: "#report" },
       { label: "AI (Phase 5)", href: "#ai" },
+      { label: "Live Incident", href: "#live" },
       { label: "About", href: "#about" },
     ],
     []
@@ -717,6 +866,30 @@ export default function App() {
     }
   }
 
+  async function onWorkspaceRepairConfig(file: string | null) {
+    try {
+      if (file) {
+        const okToMigrate = await preflightWorkspaceMigrations(file, "open_workspace");
+        if (!okToMigrate) return;
+      }
+      clearWorkspaceScopedState();
+      const info = await invokeValidated("workspace_repair_config", { dbPath: file }, WorkspaceInfoSchema);
+      setWorkspaceInfo(info);
+      setDbPath(info.current_db_path);
+      setWorkspaceRecentPick(info.recent_db_paths[0] ?? "");
+      pushToast({ kind: "success", title: "Workspace config repaired", message: info.current_db_path });
+      await ensureDbInitialized({ toastOnSuccess: false, skipPreflight: true });
+      await refreshAllViewsAfterWorkspaceChange();
+    } catch (e) {
+      const appErr = extractAppError(e);
+      pushToast({
+        kind: "error",
+        title: "Workspace repair failed",
+        message: appErr ? `${appErr.code}: ${appErr.message}` : String(e),
+      });
+    }
+  }
+
   async function onBackupCreate() {
     try {
       const dest = await pickDirectory();
@@ -945,6 +1118,7 @@ export default function App() {
     try {
       const res = await invokeValidated("validation_report", undefined, ValidationReportSchema);
       setValidationReport(res);
+      setIncidentOverlaps(await invokeValidated("incident_overlaps_list", undefined, IncidentOverlapListSchema));
       const withIssues = res.filter((i) => i.warnings.length > 0).length;
       pushToast({
         kind: withIssues > 0 ? "warning" : "success",
@@ -974,6 +1148,13 @@ export default function App() {
       setIncidentDetailLoading(true);
       const res = await invokeValidated("incident_detail", { incidentId: id }, IncidentDetailSchema);
       setIncidentDetail(res);
+      setIncidentEvidenceChunkCount(null);
+      try {
+        const links = await invokeValidated("ai_incident_evidence_links", { incidentId: id }, IncidentEvidenceLinksSchema);
+        setIncidentEvidenceChunkCount(links.chunk_count);
+      } catch (e) {
+        pushToast({ kind: "warning", title: "Evidence links unavailable", message: String(e) });
+      }
     } catch (e) {
       pushToast({ kind: "error", title: "Incident detail failed", message: String(e) });
       setIncidentDetailOpen(false);
@@ -1049,9 +1230,11 @@ export default function App() {
         open={incidentDetailOpen}
         loading={incidentDetailLoading}
         detail={incidentDetail}
+        evidenceChunkCount={incidentEvidenceChunkCount}
         onClose={() => {
           setIncidentDetailOpen(false);
           setIncidentDetail(null);
+          setIncidentEvidenceChunkCount(null);
         }}
       />
 
@@ -1110,6 +1293,7 @@ export default function App() {
         onOpenWorkspace={onWorkspaceOpen}
         onCreateWorkspace={onWorkspaceCreate}
         onSwitchToRecent={onWorkspaceSwitchToRecent}
+        onRepairConfig={onWorkspaceRepairConfig}
       />
 
       <BackupRestoreSection
@@ -1135,10 +1319,14 @@ export default function App() {
         mapping={mapping}
         setMapping={setMapping as never}
         importSummary={importSummary}
+        importProgress={importProgress}
         onRefreshProfiles={onRefreshProfiles}
         onPickCsvFile={onPickCsvFile}
         applyCommonJiraDefaults={applyCommonJiraDefaults}
-        onImportCsv={onImportCsv}
+        onImportCsv={() => onImportCsv()}
+        onDryRunCsv={() => onImportCsv(true)}
+        onCancelImport={onCancelImport}
+        onUndoImport={onUndoImport}
         onSaveProfile={onSaveProfile}
         onDeleteProfile={onDeleteProfile}
       />
@@ -1176,6 +1364,7 @@ export default function App() {
 
       <ValidationSection
         validationReport={validationReport}
+        overlaps={incidentOverlaps}
         dashboardLoaded={!!dashboard}
         hasIncidentFilter={!!(incidentFilterIds && incidentFilterIds.length > 0)}
         onRefreshValidation={onRefreshValidationReport}
@@ -1185,6 +1374,10 @@ export default function App() {
           setIncidentFilterLabel("");
         }}
         onFilterIncidentFromValidation={onFilterIncidentFromValidation}
+        onFilterIncidentsFromOverlap={(ids, label) => {
+          setIncidentFilterIds(ids);
+          setIncidentFilterLabel(label);
+        }}
       />
 
       <DashboardsSection
@@ -1206,6 +1399,13 @@ export default function App() {
         }}
       />
 
+      <LiveCaptureSection
+        lastCaptured={lastCaptured}
+        onQuickCapture={onQuickCapture}
+        onAppendNote={onAppendLiveNote}
+        onReconcile={(id) => void onReconcileLiveNotes(id)}
+      />
+
       <AboutSection />
     </main>
   );
@@ -8,7 +8,12 @@ import {
   AiHealthStatusSchema,
   AiIndexStatusSchema,
   AiModelInfoListSchema,
+  AiRechunkResultSchema,
+  AiUsagePolicySchema,
   BuildChunksResultSchema,
+  ChunkingParamsSchema,
+  EmbedBatchSizeSchema,
+  SourceSummariesSchema,
   EvidenceQueryResponseSchema,
   EvidenceChunkSchema,
   EvidenceContextResponseSchema,
@@ -17,6 +22,7 @@ import {
 } from "../../lib/schemas";
 import { pickDirectory, pickTextFile } from "../../lib/pickers";
 import { guidanceForAiErrorCode } from "../../lib/ai_guidance";
+import { listenAiQueue, listenProgress } from "../../lib/progress";
 import { computeAiGate } from "./ai_gating";
 
 type EvidenceSourceType = "sanitized_export" | "slack_transcript" | "incident_report_md" | "freeform_text";
@@ -68,6 +74,11 @@ export function AiSection(props: { onToast: (t: { kind: "success" | "error"; tit
   const [draftModel, setDraftModel] = useState<string>("llama3.2:latest");
   const [searchQuery, setSearchQuery] = useState<string>("");
   const [searchTopK, setSearchTopK] = useState<number>(8);
+  const [chunkTargetTokens, setChunkTargetTokens] = useState<number>(400);
+  const [chunkOverlapTokens, setChunkOverlapTokens] = useState<number>(0);
+  const [embedBatchSize, setEmbedBatchSize] = useState<number>(32);
+  const [aiActivity, setAiActivity] = useState<string | null>(null);
+  const [searchLanguage, setSearchLanguage] = useState<"" | "en" | "es">("");
   const [searchHits, setSearchHits] = useState<
     Array<{
       chunk_id: string;
@@ -77,6 +88,7 @@ export function AiSection(props: { onToast: (t: { kind: "success" | "error"; tit
       citation: { chunk_id: string; locator: { source_id: string; ordinal: number; text_sha256: string; char_range?: [number, number] | null } };
     }>
   >([]);
+  const [searchFallbackReason, setSearchFallbackReason] = useState<string | null>(null);
   const [viewerChunk, setViewerChunk] = useState<null | {
     chunk_id: string;
     source_id: string;
@@ -98,6 +110,12 @@ export function AiSection(props: { onToast: (t: { kind: "success" | "error"; tit
     }>;
   }>(null);
   const [selectedCitationChunkIds, setSelectedCitationChunkIds] = useState<string[]>([]);
+  const [usagePolicy, setUsagePolicy] = useState<null | {
+    max_chunks_per_draft?: number | null;
+    allowed_section_types?: string[] | null;
+    require_approval_before_export: boolean;
+    disclaimer?: string | null;
+  }>(null);
   const [draftSectionId, setDraftSectionId] = useState<
     "exec_summary" | "incident_highlights_top_n" | "theme_analysis" | "action_plan_next_quarter" | "quarter_narrative_recap"
   >("exec_summary");
@@ -136,6 +154,55 @@ export function AiSection(props: { onToast: (t: { kind: "success" | "error"; tit
     // eslint-disable-next-line react-hooks/exhaustive-deps
   }, []);
 
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+    let cancelled = false;
+    void listenProgress((ev) => {
+      if (ev.operation !== "ai_draft_section" && ev.operation !== "ai_index_build") return;
+      if (ev.stage === "started") setAiActivity(`${ev.operation}: started`);
+      else if (ev.stage === "model_loading") setAiActivity(`${ev.operation}: Ollama is loading the model (first run can take a while)…`);
+      else if (ev.stage === "generating" || ev.stage === "embed") setAiActivity(`${ev.operation}: generating (${ev.percent ?? 0}%)`);
+      else if (ev.stage === "completed" || ev.stage === "failed") setAiActivity(null);
+    }).then((fn) => {
+      if (cancelled) fn();
+      else unlisten = fn;
+    });
+    let unlistenQueue: (() => void) | null = null;
+    void listenAiQueue((pos) => {
+      const busy = pos.running_model ? ` while ${pos.running_model} is in use` : "";
+      setAiActivity(`${pos.operation}: queued for ${pos.model} (position ${pos.position})${busy}…`);
+    }).then((fn) => {
+      if (cancelled) fn();
+      else unlistenQueue = fn;
+    });
+    return () => {
+      cancelled = true;
+      unlisten?.();
+      unlistenQueue?.();
+    };
+  }, []);
+
+  useEffect(() => {
+    invokeValidated("ai_chunking_get", undefined, ChunkingParamsSchema)
+      .then((p) => {
+        setChunkTargetTokens(p.target_tokens);
+        setChunkOverlapTokens(p.overlap_tokens);
+      })
+      .catch(() => {
+        // Defaults stay in place; saving surfaces any store error.
+      });
+    invokeValidated("ai_embed_batch_size_get", undefined, EmbedBatchSizeSchema)
+      .then(setEmbedBatchSize)
+      .catch(() => {
+        // Same as above.
+      });
+    invokeValidated("ai_usage_policy_get", undefined, AiUsagePolicySchema)
+      .then(setUsagePolicy)
+      .catch(() => {
+        // No banner; drafting and export still enforce the policy server-side.
+      });
+  }, []);
+
   useEffect(() => {
     if (typeof window === "undefined") return;
     try {
@@ -388,6 +455,93 @@ export function AiSection(props: { onToast: (t: { kind: "success" | "error"; tit
     }
   }
 
+  async function onSaveChunking() {
+    try {
+      const p = await invokeValidated(
+        "ai_chunking_set",
+        { params: { target_tokens: chunkTargetTokens | 0, overlap_tokens: chunkOverlapTokens | 0 } },
+        ChunkingParamsSchema
+      );
+      setChunkTargetTokens(p.target_tokens);
+      setChunkOverlapTokens(p.overlap_tokens);
+      onToast({
+        kind: "success",
+        title: "Chunking saved",
+        message: `target_tokens=${p.target_tokens}; overlap_tokens=${p.overlap_tokens}. Re-chunk sources to apply.`,
+      });
+    } catch (e) {
+      const appErr = extractAppError(e);
+      const guidance = appErr ? guidanceForAiErrorCode(appErr.code) : null;
+      onToast({
+        kind: "error",
+        title: "Save chunking failed",
+        message: appErr && guidance ? `${appErr.code}: ${appErr.message}\n\n${guidance}` : appErr ? `${appErr.code}: ${appErr.message}` : String(e),
+      });
+    }
+  }
+
+  async function onSaveEmbedBatchSize() {
+    try {
+      const n = await invokeValidated("ai_embed_batch_size_set", { batchSize: embedBatchSize | 0 }, EmbedBatchSizeSchema);
+      setEmbedBatchSize(n);
+      onToast({ kind: "success", title: "Batch size saved", message: `embed_batch_size=${n}` });
+    } catch (e) {
+      const appErr = extractAppError(e);
+      const guidance = appErr ? guidanceForAiErrorCode(appErr.code) : null;
+      onToast({
+        kind: "error",
+        title: "Save batch size failed",
+        message: appErr && guidance ? `${appErr.code}: ${appErr.message}\n\n${guidance}` : appErr ? `${appErr.code}: ${appErr.message}` : String(e),
+      });
+    }
+  }
+
+  async function onRechunk() {
+    if (!selectedSourceId) return;
+    try {
+      const res = await invokeValidated("ai_evidence_rechunk", { sourceId: selectedSourceId }, AiRechunkResultSchema);
+      onToast({
+        kind: "success",
+        title: "Source re-chunked",
+        message: `chunk_count=${res.rechunk.chunk_count}; removed=${res.rechunk.removed_chunk_ids.length}; invalidated_vectors=${res.invalidated_vectors}. Rebuild the index to embed new chunks.`,
+      });
+      setIndexStatus(res.index);
+      await refreshChunks(selectedSourceId);
+    } catch (e) {
+      const appErr = extractAppError(e);
+      const guidance = appErr ? guidanceForAiErrorCode(appErr.code) : null;
+      onToast({
+        kind: "error",
+        title: "Re-chunk failed",
+        message: appErr && guidance ? `${appErr.code}: ${appErr.message}\n\n${guidance}` : appErr ? `${appErr.code}: ${appErr.message}` : String(e),
+      });
+    }
+  }
+
+  async function onSummarizeSource() {
+    if (!selectedSourceId) return;
+    try {
+      const res = await invokeValidated(
+        "ai_evidence_summarize",
+        { sourceId: selectedSourceId, model: draftModel, sectionChunks: null },
+        SourceSummariesSchema
+      );
+      onToast({
+        kind: "success",
+        title: "Source summarized",
+        message: `sections=${res.sections.length}; model=${res.model}. Rebuild the index to embed the summaries; citations still point at the original chunks.`,
+      });
+    } catch (e) {
+      const appErr = extractAppError(e);
+      const guidance = appErr ? guidanceForAiErrorCode(appErr.code) : null;
+      onToast({
+        kind: "error",
+        title: "Summarize failed",
+        message: appErr && guidance ? `${appErr.code}: ${appErr.message}\n\n${guidance}` : appErr ? `${appErr.code}: ${appErr.message}` : String(e),
+      });
+    }
+  }
+
   async function refreshIndexStatus() {
     const st = await invokeValidated("ai_index_status", undefined, AiIndexStatusSchema);
     setIndexStatus(st);
@@ -426,12 +580,18 @@ export function AiSection(props: { onToast: (t: { kind: "success" | "error"; tit
             query: searchQuery,
             topK: Math.max(1, Math.min(50, searchTopK | 0)),
             sourceFilter: selectedSourceId ? [selectedSourceId] : null,
+            languageFilter: searchLanguage || null,
           },
         },
         EvidenceQueryResponseSchema
       );
       setSearchHits(res.hits);
-      onToast({ kind: "success", title: "Search complete", message: `${res.hits.length} hits` });
+      setSearchFallbackReason(res.mode === "keyword" ? (res.fallback_reason ?? "AI_INDEX_NOT_READY") : null);
+      onToast({
+        kind: "success",
+        title: res.mode === "keyword" ? "Keyword search complete" : "Search complete",
+        message: `${res.hits.length} hits${res.mode === "keyword" ? " (keyword match, not semantic)" : ""}`,
+      });
     } catch (e) {
       const appErr = extractAppError(e);
       const guidance = appErr ? guidanceForAiErrorCode(appErr.code) : null;
@@ -584,6 +744,24 @@ export function AiSection(props: { onToast: (t: { kind: "success" | "error"; tit
         Security: keep Ollama bound to <span className="mono">127.0.0.1</span> only. IncidentReview hard-rejects remote
         endpoints; do not expose Ollama to the network.
       </p>
+      {usagePolicy &&
+      (usagePolicy.disclaimer ||
+        usagePolicy.max_chunks_per_draft ||
+        usagePolicy.allowed_section_types ||
+        usagePolicy.require_approval_before_export) ? (
+        <p className="hint" role="note">
+          Workspace AI policy:
+          {usagePolicy.max_chunks_per_draft ? ` at most ${usagePolicy.max_chunks_per_draft} cited chunks per draft;` : ""}
+          {usagePolicy.allowed_section_types ? ` sections: ${usagePolicy.allowed_section_types.join(", ")};` : ""}
+          {usagePolicy.require_approval_before_export ? " drafts need approval before export;" : ""}
+          {usagePolicy.disclaimer ? ` exports carry the disclaimer "${usagePolicy.disclaimer}"` : ""}
+        </p>
+      ) : null}
+      {aiActivity ? (
+        <p className="hint" role="status">
+          {aiActivity}
+        </p>
+      ) : null}
 
       <div className="card card--sub">
         <h3>Preflight / Gating</h3>
@@ -708,6 +886,38 @@ export function AiSection(props: { onToast: (t: { kind: "success" | "error"; tit
           <button className="btn" type="button" onClick={onListChunks} disabled={!selectedSourceId}>
             List Chunks (selected)
           </button>
+          <button className="btn" type="button" onClick={onRechunk} disabled={!selectedSourceId}>
+            Re-chunk (selected)
+          </button>
+          <button className="btn" type="button" onClick={onSummarizeSource} disabled={!selectedSourceId}>
+            Summarize for search (long sources)
+          </button>
+        </div>
+        <div className="grid">
+          <label>
+            Target tokens
+            <input
+              type="number"
+              value={chunkTargetTokens}
+              onChange={(e) => setChunkTargetTokens(parseInt(e.target.value || "400", 10))}
+              min={50}
+              max={8000}
+            />
+          </label>
+          <label>
+            Overlap tokens
+            <input
+              type="number"
+              value={chunkOverlapTokens}
+              onChange={(e) => setChunkOverlapTokens(parseInt(e.target.value || "0", 10))}
+              min={0}
+            />
+          </label>
+        </div>
+        <div className="actions">
+          <button className="btn" type="button" onClick={onSaveChunking}>
+            Save chunking
+          </button>
         </div>
         {chunks.length === 0 ? (
           <p className="hint">No chunks loaded.</p>
@@ -751,8 +961,21 @@ export function AiSection(props: { onToast: (t: { kind: "success" | "error"; tit
               <input value={indexModel} onChange={(e) => setIndexModel(e.target.value)} placeholder="e.g. nomic-embed-text:latest" />
             )}
           </label>
+          <label>
+            Embed batch size
+            <input
+              type="number"
+              value={embedBatchSize}
+              onChange={(e) => setEmbedBatchSize(parseInt(e.target.value || "32", 10))}
+              min={1}
+              max={512}
+            />
+          </label>
         </div>
         <div className="actions">
+          <button className="btn" type="button" onClick={onSaveEmbedBatchSize}>
+            Save batch size
+          </button>
           <button className="btn" type="button" onClick={refreshIndexStatus}>
             Refresh Status
           </button>
@@ -791,9 +1014,17 @@ export function AiSection(props: { onToast: (t: { kind: "success" | "error"; tit
               max={50}
             />
           </label>
+          <label>
+            Language
+            <select value={searchLanguage} onChange={(e) => setSearchLanguage(e.target.value as "" | "en" | "es")}>
+              <option value="">any</option>
+              <option value="en">en (+ mixed)</option>
+              <option value="es">es (+ mixed)</option>
+            </select>
+          </label>
         </div>
         <div className="actions">
-          <button className="btn btn--accent" type="button" onClick={onSearchEvidence} disabled={!gate.canSearch}>
+          <button className="btn btn--accent" type="button" onClick={onSearchEvidence} disabled={!gate.canSearch && !gate.canKeywordSearch}>
             Search (selected source)
           </button>
           <button
@@ -848,6 +1079,11 @@ export function AiSection(props: { onToast: (t: { kind: "success" | "error"; tit
         )}
 
         <h4>Search Results</h4>
+        {searchFallbackReason ? (
+          <p className="hint" role="status">
+            Keyword-only results (not semantic): {searchFallbackReason}. Scores are BM25 word-match scores.
+          </p>
+        ) : null}
         {searchHits.length === 0 ? (
           <p className="hint">No hits.</p>
         ) : (
@@ -33,5 +33,12 @@ describe("computeAiGate", () => {
     expect(g.canDraft).toBe(false);
     expect(g.reasonCode).toBe("AI_CITATION_REQUIRED");
   });
-});
 
+  it("keeps keyword search available without Ollama or an index", () => {
+    const down = computeAiGate({ healthOk: false, sourcesCount: 1, chunksCount: 3, indexReady: false, selectedCitationsCount: 0 });
+    expect(down.canSearch).toBe(false);
+    expect(down.canKeywordSearch).toBe(true);
+    const noChunks = computeAiGate({ healthOk: false, sourcesCount: 1, chunksCount: 0, indexReady: false, selectedCitationsCount: 0 });
+    expect(noChunks.canKeywordSearch).toBe(false);
+  });
+});
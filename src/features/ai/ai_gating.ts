@@ -1,5 +1,7 @@
 export type AiGate = {
   canSearch: boolean;
+  /** Keyword (non-semantic) search only needs chunks; it works without Ollama or an index. */
+  canKeywordSearch: boolean;
   canDraft: boolean;
   reasonCode: string | null;
   reasonMessage: string | null;
@@ -12,9 +14,12 @@ export function computeAiGate(input: {
   indexReady: boolean | null;
   selectedCitationsCount: number;
 }): AiGate {
+  const canKeywordSearch = input.sourcesCount > 0 && input.chunksCount > 0;
+
   if (input.healthOk === false) {
     return {
       canSearch: false,
+      canKeywordSearch,
       canDraft: false,
       reasonCode: "AI_OLLAMA_UNHEALTHY",
       reasonMessage: "Ollama is not reachable on 127.0.0.1.",
@@ -24,6 +29,7 @@ export function computeAiGate(input: {
   if (input.sourcesCount === 0) {
     return {
       canSearch: false,
+      canKeywordSearch,
       canDraft: false,
       reasonCode: "AI_EVIDENCE_EMPTY",
       reasonMessage: "Add at least one evidence source.",
@@ -33,6 +39,7 @@ export function computeAiGate(input: {
   if (input.chunksCount === 0) {
     return {
       canSearch: false,
+      canKeywordSearch,
       canDraft: false,
       reasonCode: "AI_INDEX_NOT_READY",
       reasonMessage: "Build evidence chunks before indexing/search.",
@@ -42,6 +49,7 @@ export function computeAiGate(input: {
   if (input.indexReady === false) {
     return {
       canSearch: false,
+      canKeywordSearch,
       canDraft: false,
       reasonCode: "AI_INDEX_NOT_READY",
       reasonMessage: "Build the embeddings index before searching/drafting.",
@@ -54,6 +62,7 @@ export function computeAiGate(input: {
   if (!canDraft) {
     return {
       canSearch,
+      canKeywordSearch,
       canDraft: false,
       reasonCode: "AI_CITATION_REQUIRED",
       reasonMessage: "Select at least one citation chunk before drafting.",
@@ -62,6 +71,7 @@ export function computeAiGate(input: {
 
   return {
     canSearch: true,
+    canKeywordSearch,
     canDraft: true,
     reasonCode: null,
     reasonMessage: null,
@@ -19,6 +19,7 @@ describe("IncidentDetailDrawer", () => {
         open={true}
         loading={false}
         onClose={onClose}
+        evidenceChunkCount={3}
         detail={{
           incident: {
             id: 1,
@@ -76,6 +77,7 @@ describe("IncidentDetailDrawer", () => {
     expect(screen.getByText("No warnings.")).toBeInTheDocument();
     expect(screen.getByText("(redacted)")).toBeInTheDocument();
     expect(screen.getByText(/events.log/)).toBeInTheDocument();
+    expect(screen.getByText("3 evidence chunks reference this incident")).toBeInTheDocument();
 
     fireEvent.click(screen.getByRole("button", { name: "Close" }));
     fireEvent.click(screen.getByRole("button", { name: "Close incident detail drawer" }));
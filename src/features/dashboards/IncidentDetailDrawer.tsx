@@ -61,6 +61,8 @@ export function IncidentDetailDrawer(props: {
   open: boolean;
   loading: boolean;
   detail: IncidentDetailPayload | null;
+  /** Evidence chunks linked to this incident by the linking pass; null when unknown. */
+  evidenceChunkCount?: number | null;
   onClose: () => void;
 }) {
   if (!props.open) return null;
@@ -151,6 +153,17 @@ export function IncidentDetailDrawer(props: {
               )}
             </section>
 
+            {props.evidenceChunkCount != null ? (
+              <section className="drawerSection">
+                <h3>Evidence</h3>
+                <p className={props.evidenceChunkCount === 0 ? "muted" : undefined}>
+                  {props.evidenceChunkCount === 1
+                    ? "1 evidence chunk references this incident"
+                    : `${props.evidenceChunkCount} evidence chunks reference this incident`}
+                </p>
+              </section>
+            ) : null}
+
             <section className="drawerSection">
               <h3>Artifacts</h3>
               {props.detail.artifacts.length === 0 ? (
@@ -29,13 +29,20 @@ function makeProps() {
       ack_ts: null,
       mitigate_ts: null,
       resolve_ts: null,
+      conflict_strategy: "merge_preserve_on_empty" as const,
+      assumed_utc_offset_minutes: null as number | null,
+      timestamp_formats: {},
     },
     setMapping: vi.fn(),
     importSummary: null,
+    importProgress: null,
     onRefreshProfiles: vi.fn(),
     onPickCsvFile: vi.fn(),
     applyCommonJiraDefaults: vi.fn(),
     onImportCsv: vi.fn(),
+    onDryRunCsv: vi.fn(),
+    onCancelImport: vi.fn(),
+    onUndoImport: vi.fn(),
     onSaveProfile: vi.fn(),
     onDeleteProfile: vi.fn(),
   };
@@ -65,4 +72,119 @@ describe("JiraImportSection", () => {
     fireEvent.click(screen.getByRole("button", { name: "Apply common Jira defaults" }));
     expect(props.applyCommonJiraDefaults).toHaveBeenCalledTimes(1);
   });
+
+  it("shows running counts and cancels an import in progress", () => {
+    const props = makeProps();
+    render(
+      <JiraImportSection
+        {...props}
+        importProgress={{ percent: 40, processed: 2000, inserted: 1990, updated: 0, skipped: 10, conflicts: 3 }}
+      />
+    );
+
+    expect(screen.getByRole("status")).toHaveTextContent("rows=2000, inserted=1990");
+    expect(screen.getByRole("button", { name: "Import CSV" })).toBeDisabled();
+    fireEvent.click(screen.getByRole("button", { name: "Cancel import" }));
+    expect(props.onCancelImport).toHaveBeenCalledTimes(1);
+  });
+
+  it("labels dry runs and lists per-row field changes", () => {
+    const props = makeProps();
+    render(
+      <JiraImportSection
+        {...props}
+        importSummary={{
+          inserted: 0,
+          updated: 1,
+          skipped: 0,
+          conflicts: [],
+          warnings: [],
+          dry_run: true,
+          row_diffs: [
+            {
+              row: 0,
+              action: "update",
+              incident_id: 7,
+              external_id: "INC-1",
+              changes: [{ field: "severity", before: "SEV2", after: "SEV1" }],
+            },
+          ],
+        }}
+      />
+    );
+
+    expect(screen.getByText("Dry Run Result (nothing written)")).toBeInTheDocument();
+    expect(screen.getByText("severity: SEV2 → SEV1")).toBeInTheDocument();
+    fireEvent.click(screen.getByRole("button", { name: "Dry run" }));
+    expect(props.onDryRunCsv).toHaveBeenCalledTimes(1);
+  });
+
+  it("offers undo for a committed import batch", () => {
+    const props = makeProps();
+    render(
+      <JiraImportSection
+        {...props}
+        importSummary={{ inserted: 2, updated: 0, skipped: 0, conflicts: [], warnings: [], batch_id: 12 }}
+      />
+    );
+
+    fireEvent.click(screen.getByRole("button", { name: "Undo this import" }));
+    expect(props.onUndoImport).toHaveBeenCalledWith(12);
+  });
+
+  it("switches the conflict strategy and lists value conflicts", () => {
+    const props = makeProps();
+    render(
+      <JiraImportSection
+        {...props}
+        importSummary={{
+          inserted: 0,
+          updated: 0,
+          skipped: 1,
+          conflicts: [{ row: 0, reason: "Incoming values conflict with stored severity; row skipped" }],
+          warnings: [],
+          value_conflicts: [
+            {
+              row: 0,
+              incident_id: 3,
+              external_id: "INC-3",
+              strategy: "skip_conflicts",
+              fields: [{ field: "severity", before: "SEV2", after: "SEV1" }],
+            },
+          ],
+        }}
+      />
+    );
+
+    fireEvent.change(screen.getByLabelText("Conflict strategy"), { target: { value: "prefer_existing" } });
+    expect(props.setMapping).toHaveBeenCalledWith({ ...props.mapping, conflict_strategy: "prefer_existing" });
+    expect(screen.getByText("Value conflicts")).toBeInTheDocument();
+    expect(screen.getByText("severity: stored SEV2, incoming SEV1")).toBeInTheDocument();
+  });
+
+  it("sets and clears the assumed UTC offset for naive timestamps", () => {
+    const props = makeProps();
+    const { rerender } = render(<JiraImportSection {...props} />);
+
+    fireEvent.change(screen.getByLabelText("Assumed UTC offset"), { target: { value: "330" } });
+    expect(props.setMapping).toHaveBeenCalledWith({ ...props.mapping, assumed_utc_offset_minutes: 330 });
+
+    rerender(<JiraImportSection {...props} mapping={{ ...props.mapping, assumed_utc_offset_minutes: 330 }} />);
+    fireEvent.change(screen.getByLabelText("Assumed UTC offset"), { target: { value: "" } });
+    expect(props.setMapping).toHaveBeenLastCalledWith({ ...props.mapping, assumed_utc_offset_minutes: null });
+  });
+
+  it("edits a per-column timestamp format next to the timestamp mappings", () => {
+    const props = { ...makeProps(), csvPreview: { headers: ["Key", "Created"], rows: [["OPS-1", "05/Jan/26 2:00 PM"]] } };
+    render(<JiraImportSection {...props} />);
+
+    expect(screen.queryByLabelText("Timestamp format for Title (required)")).not.toBeInTheDocument();
+    fireEvent.change(screen.getByLabelText("Timestamp format for Start TS (optional)"), {
+      target: { value: "%d/%b/%y %I:%M %p" },
+    });
+    expect(props.setMapping).toHaveBeenCalledWith({
+      ...props.mapping,
+      timestamp_formats: { start_ts: "%d/%b/%y %I:%M %p" },
+    });
+  });
 });
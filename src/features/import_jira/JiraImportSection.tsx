@@ -17,11 +17,37 @@ export type JiraProfile = {
     ack_ts?: string | null;
     mitigate_ts?: string | null;
     resolve_ts?: string | null;
+    conflict_strategy?: ConflictStrategy;
+    assumed_utc_offset_minutes?: number | null;
+    timestamp_formats?: TimestampFormats;
   };
 };
 
+type TimestampKey = "start_ts" | "first_observed_ts" | "it_awareness_ts" | "ack_ts" | "mitigate_ts" | "resolve_ts";
+
+/** strftime-style pattern per timestamp field, e.g. "%d/%b/%y %I:%M %p". */
+export type TimestampFormats = Partial<Record<TimestampKey, string | null>>;
+
+const TIMESTAMP_KEYS: ReadonlySet<string> = new Set<TimestampKey>([
+  "start_ts",
+  "first_observed_ts",
+  "it_awareness_ts",
+  "ack_ts",
+  "mitigate_ts",
+  "resolve_ts",
+]);
+
 export type CsvPreview = { headers: string[]; rows: string[][] };
 
+export type ConflictStrategy = "prefer_existing" | "prefer_incoming" | "merge_preserve_on_empty" | "skip_conflicts";
+
+const CONFLICT_STRATEGIES: Array<{ value: ConflictStrategy; label: string }> = [
+  { value: "merge_preserve_on_empty", label: "Merge (empty cells keep stored values)" },
+  { value: "prefer_incoming", label: "Prefer incoming (empty cells clear mapped fields)" },
+  { value: "prefer_existing", label: "Prefer existing (only fill empty fields)" },
+  { value: "skip_conflicts", label: "Skip rows that conflict" },
+];
+
 export type JiraMappingState = {
   external_id: string | null;
   title: string;
@@ -38,6 +64,9 @@ export type JiraMappingState = {
   ack_ts: string | null;
   mitigate_ts: string | null;
   resolve_ts: string | null;
+  conflict_strategy: ConflictStrategy;
+  assumed_utc_offset_minutes: number | null;
+  timestamp_formats: TimestampFormats;
 };
 
 export type JiraImportSummary = {
@@ -46,6 +75,31 @@ export type JiraImportSummary = {
   skipped: number;
   conflicts: Array<{ row: number; reason: string; external_id?: string | null; fingerprint?: string | null }>;
   warnings: Array<{ code: string; message: string; details?: string | null }>;
+  dry_run?: boolean;
+  batch_id?: number | null;
+  row_diffs?: Array<{
+    row: number;
+    action: "insert" | "update" | "unchanged";
+    incident_id: number | null;
+    external_id: string | null;
+    changes: Array<{ field: string; before: string | null; after: string | null }>;
+  }>;
+  value_conflicts?: Array<{
+    row: number;
+    incident_id: number;
+    external_id: string | null;
+    strategy: ConflictStrategy;
+    fields: Array<{ field: string; before: string | null; after: string | null }>;
+  }>;
+};
+
+export type ImportProgress = {
+  percent: number | null;
+  processed: number;
+  inserted: number;
+  updated: number;
+  skipped: number;
+  conflicts: number;
 };
 
 export function JiraImportSection(props: {
@@ -59,14 +113,20 @@ export function JiraImportSection(props: {
   mapping: JiraMappingState;
   setMapping: (next: JiraMappingState) => void;
   importSummary: JiraImportSummary | null;
+  /** Set while an import runs; null otherwise. */
+  importProgress: ImportProgress | null;
   onRefreshProfiles: () => void | Promise<void>;
   onPickCsvFile: (file: File | null) => void | Promise<void>;
   applyCommonJiraDefaults: () => void;
   onImportCsv: () => void | Promise<void>;
+  onDryRunCsv: () => void | Promise<void>;
+  onCancelImport: () => void | Promise<void>;
+  onUndoImport: (batchId: number) => void | Promise<void>;
   onSaveProfile: () => void | Promise<void>;
   onDeleteProfile: () => void | Promise<void>;
 }) {
   const csvPreview = props.csvPreview;
+  const importProgress = props.importProgress;
 
   return (
     <section className="card" id="jira">
@@ -87,10 +147,35 @@ export function JiraImportSection(props: {
         <button className="btn" type="button" onClick={props.applyCommonJiraDefaults} disabled={!csvPreview}>
           Apply common Jira defaults
         </button>
-        <button className="btn btn--accent" type="button" onClick={() => void props.onImportCsv()}>
+        <button
+          className="btn btn--accent"
+          type="button"
+          onClick={() => void props.onImportCsv()}
+          disabled={importProgress !== null}
+        >
           Import CSV
         </button>
+        <button
+          className="btn"
+          type="button"
+          onClick={() => void props.onDryRunCsv()}
+          disabled={importProgress !== null}
+        >
+          Dry run
+        </button>
+        {importProgress ? (
+          <button className="btn" type="button" onClick={() => void props.onCancelImport()}>
+            Cancel import
+          </button>
+        ) : null}
       </div>
+      {importProgress ? (
+        <p className="hint" role="status">
+          Importing{importProgress.percent !== null ? ` (${importProgress.percent}%)` : ""}: rows=
+          {importProgress.processed}, inserted={importProgress.inserted}, updated={importProgress.updated}, skipped=
+          {importProgress.skipped}, conflicts={importProgress.conflicts}
+        </p>
+      ) : null}
       <p className="hint">
         Selected file: <span className="mono">{props.csvFileName || "none"}</span>
       </p>
@@ -126,6 +211,9 @@ export function JiraImportSection(props: {
                     ack_ts: prof.mapping.ack_ts ?? null,
                     mitigate_ts: prof.mapping.mitigate_ts ?? null,
                     resolve_ts: prof.mapping.resolve_ts ?? null,
+                    conflict_strategy: prof.mapping.conflict_strategy ?? "merge_preserve_on_empty",
+                    assumed_utc_offset_minutes: prof.mapping.assumed_utc_offset_minutes ?? null,
+                    timestamp_formats: prof.mapping.timestamp_formats ?? {},
                   });
                 }
               }}
@@ -235,18 +323,87 @@ export function JiraImportSection(props: {
                     </option>
                   ))}
                 </select>
+                {TIMESTAMP_KEYS.has(f.key) ? (
+                  <input
+                    className="textInput mono"
+                    type="text"
+                    aria-label={`Timestamp format for ${f.label}`}
+                    placeholder="Format, e.g. %d/%b/%y %I:%M %p"
+                    value={props.mapping.timestamp_formats[f.key as TimestampKey] ?? ""}
+                    onChange={(e) => {
+                      const v = e.currentTarget.value;
+                      props.setMapping({
+                        ...props.mapping,
+                        timestamp_formats: { ...props.mapping.timestamp_formats, [f.key]: v === "" ? null : v },
+                      });
+                    }}
+                  />
+                ) : null}
               </div>
             ))}
           </div>
         )}
+        <div className="mappingRow">
+          <div className="mappingRow__label">When a row disagrees with a stored incident</div>
+          <select
+            className="select"
+            aria-label="Conflict strategy"
+            value={props.mapping.conflict_strategy}
+            onChange={(e) =>
+              props.setMapping({ ...props.mapping, conflict_strategy: e.currentTarget.value as ConflictStrategy })
+            }
+          >
+            {CONFLICT_STRATEGIES.map((s) => (
+              <option key={s.value} value={s.value}>
+                {s.label}
+              </option>
+            ))}
+          </select>
+        </div>
         <p className="hint">
-          Timestamp normalization is deterministic: canonical incident timestamps are stored as RFC3339 UTC; non-RFC3339 inputs are preserved as raw strings and surfaced as warnings (no fuzzy parsing, no guessing).
+          The strategy is saved with the profile and applies to the next import. Disagreements are listed in the result
+          whichever strategy wins.
+        </p>
+        <div className="mappingRow">
+          <div className="mappingRow__label">UTC offset assumed for timestamps without a timezone (minutes)</div>
+          <input
+            className="textInput"
+            type="number"
+            min={-840}
+            max={840}
+            step={15}
+            aria-label="Assumed UTC offset"
+            placeholder="0 (UTC)"
+            value={props.mapping.assumed_utc_offset_minutes ?? ""}
+            onChange={(e) => {
+              const v = e.currentTarget.value;
+              props.setMapping({ ...props.mapping, assumed_utc_offset_minutes: v === "" ? null : Number(v) });
+            }}
+          />
+        </div>
+        <p className="hint">
+          Timestamp normalization is deterministic: canonical incident timestamps are stored as RFC3339 UTC; non-RFC3339 inputs are preserved as raw strings and surfaced as warnings (no fuzzy parsing, no guessing). Values like "2026-01-05 14:00" are read at the offset above (UTC when empty) and the assumed offset is listed in the warnings. A timestamp column's format, such as %d/%b/%y %I:%M %p for "05/Jan/26 2:00 PM", is saved with the profile and tried before the built-in formats.
         </p>
       </section>
 
       {props.importSummary && (
         <section className="card">
-          <h2>Import Result</h2>
+          <h2>{props.importSummary.dry_run ? "Dry Run Result (nothing written)" : "Import Result"}</h2>
+          {!props.importSummary.dry_run && props.importSummary.batch_id != null ? (
+            <div className="actions">
+              <button
+                className="btn"
+                type="button"
+                onClick={() => void props.onUndoImport(props.importSummary?.batch_id as number)}
+              >
+                Undo this import
+              </button>
+              <span className="hint">
+                Batch <span className="mono">#{props.importSummary.batch_id}</span>: deletes inserted incidents and restores
+                updated ones.
+              </span>
+            </div>
+          ) : null}
           <div className="kpiRow">
             <div className="kpi">
               <div className="kpi__label">Inserted</div>
@@ -270,6 +427,32 @@ export function JiraImportSection(props: {
             </div>
           </div>
 
+          {props.importSummary.dry_run && (props.importSummary.row_diffs?.length ?? 0) > 0 && (
+            <>
+              <h3 className="subhead">Row changes</h3>
+              <ul className="list">
+                {props.importSummary.row_diffs?.map((d) => (
+                  <li key={d.row}>
+                    <span className="mono">
+                      row={d.row} {d.action}
+                      {d.external_id ? ` ${d.external_id}` : ""}
+                      {d.incident_id !== null ? ` (incident ${d.incident_id})` : ""}
+                    </span>
+                    {d.changes.length > 0 && (
+                      <ul className="list">
+                        {d.changes.map((c) => (
+                          <li key={c.field} className="mono">
+                            {c.field}: {c.before ?? "∅"} → {c.after ?? "∅"}
+                          </li>
+                        ))}
+                      </ul>
+                    )}
+                  </li>
+                ))}
+              </ul>
+            </>
+          )}
+
           {props.importSummary.conflicts.length > 0 && (
             <>
               <h3 className="subhead">Conflicts</h3>
@@ -283,6 +466,29 @@ export function JiraImportSection(props: {
             </>
           )}
 
+          {(props.importSummary.value_conflicts?.length ?? 0) > 0 && (
+            <>
+              <h3 className="subhead">Value conflicts</h3>
+              <ul className="list">
+                {props.importSummary.value_conflicts?.map((c) => (
+                  <li key={c.row}>
+                    <span className="mono">
+                      row={c.row} incident {c.incident_id}
+                      {c.external_id ? ` ${c.external_id}` : ""} ({c.strategy})
+                    </span>
+                    <ul className="list">
+                      {c.fields.map((f) => (
+                        <li key={f.field} className="mono">
+                          {f.field}: stored {f.before ?? "∅"}, incoming {f.after ?? "∅"}
+                        </li>
+                      ))}
+                    </ul>
+                  </li>
+                ))}
+              </ul>
+            </>
+          )}
+
           {props.importSummary.warnings.length > 0 && (
             <>
               <h3 className="subhead">Warnings</h3>
@@ -0,0 +1,55 @@
+// @vitest-environment jsdom
+import { fireEvent, render, screen, waitFor } from "@testing-library/react";
+import { describe, expect, it, vi } from "vitest";
+
+import { isQuickCaptureShortcut, LiveCaptureSection } from "./LiveCaptureSection";
+
+describe("LiveCaptureSection", () => {
+  it("captures on Enter and clears the title once the incident exists", async () => {
+    const onQuickCapture = vi.fn().mockResolvedValue(true);
+    render(<LiveCaptureSection lastCaptured={null} onQuickCapture={onQuickCapture} onAppendNote={vi.fn()} onReconcile={vi.fn()} />);
+
+    const title = screen.getByLabelText("Quick capture title");
+    fireEvent.change(title, { target: { value: " Checkout errors " } });
+    fireEvent.change(screen.getByLabelText("Quick capture severity"), { target: { value: "SEV2" } });
+    fireEvent.keyDown(title, { key: "Enter" });
+
+    await waitFor(() =>
+      expect(onQuickCapture).toHaveBeenCalledWith({ title: "Checkout errors", severity: "SEV2", service: null })
+    );
+    await waitFor(() => expect(title).toHaveValue(""));
+  });
+
+  it("focuses the title on the global shortcut", () => {
+    render(<LiveCaptureSection lastCaptured={null} onQuickCapture={vi.fn()} onAppendNote={vi.fn()} onReconcile={vi.fn()} />);
+    fireEvent.keyDown(window, { key: "K", ctrlKey: true, shiftKey: true });
+    expect(screen.getByLabelText("Quick capture title")).toHaveFocus();
+
+    expect(isQuickCaptureShortcut({ key: "k", ctrlKey: false, metaKey: false, shiftKey: true })).toBe(false);
+  });
+
+  it("appends notes to the last captured incident and reconciles it", async () => {
+    const onAppendNote = vi.fn().mockResolvedValue(true);
+    const onReconcile = vi.fn();
+    render(
+      <LiveCaptureSection
+        lastCaptured={{ id: 7, title: "Checkout errors", severity: "SEV2", start_ts: "2026-01-05T14:00:00Z" }}
+        onQuickCapture={vi.fn()}
+        onAppendNote={onAppendNote}
+        onReconcile={onReconcile}
+      />
+    );
+    expect(screen.getByText("#7")).toBeInTheDocument();
+    expect(screen.getByText("2026-01-05T14:00:00Z")).toBeInTheDocument();
+
+    fireEvent.change(screen.getByLabelText("Note kind"), { target: { value: "decision" } });
+    const note = screen.getByLabelText("Live note");
+    fireEvent.change(note, { target: { value: "Rolling back deploy 412" } });
+    fireEvent.keyDown(note, { key: "Enter" });
+    await waitFor(() => expect(onAppendNote).toHaveBeenCalledWith(7, "Rolling back deploy 412", "decision"));
+    await waitFor(() => expect(note).toHaveValue(""));
+
+    fireEvent.click(screen.getByRole("button", { name: "Reconcile with imports" }));
+    expect(onReconcile).toHaveBeenCalledWith(7);
+  });
+});
@@ -0,0 +1,138 @@
+import { useEffect, useRef, useState } from "react";
+
+export type QuickCaptureRequest = { title: string; severity: string | null; service: string | null };
+export type CapturedIncident = { id: number; title: string; severity: string | null; start_ts: string | null };
+
+/** Ctrl+Shift+K (Cmd+Shift+K on macOS) focuses the capture title from anywhere in the app. */
+export function isQuickCaptureShortcut(e: Pick<KeyboardEvent, "key" | "ctrlKey" | "metaKey" | "shiftKey">): boolean {
+  return (e.ctrlKey || e.metaKey) && e.shiftKey && e.key.toLowerCase() === "k";
+}
+
+export type LiveNoteKind = "note" | "action" | "decision" | "status";
+
+type Props = {
+  lastCaptured: CapturedIncident | null;
+  onQuickCapture: (req: QuickCaptureRequest) => Promise<boolean>;
+  onAppendNote: (incidentId: number, text: string, kind: LiveNoteKind) => Promise<boolean>;
+  onReconcile: (incidentId: number) => void;
+};
+
+export function LiveCaptureSection(props: Props) {
+  const { lastCaptured, onQuickCapture, onAppendNote, onReconcile } = props;
+  const [title, setTitle] = useState("");
+  const [severity, setSeverity] = useState("");
+  const [service, setService] = useState("");
+  const [busy, setBusy] = useState(false);
+  const [note, setNote] = useState("");
+  const [noteKind, setNoteKind] = useState<LiveNoteKind>("note");
+  const titleRef = useRef<HTMLInputElement | null>(null);
+
+  useEffect(() => {
+    function onKeyDown(e: KeyboardEvent) {
+      if (!isQuickCaptureShortcut(e)) return;
+      e.preventDefault();
+      titleRef.current?.focus();
+    }
+    window.addEventListener("keydown", onKeyDown);
+    return () => window.removeEventListener("keydown", onKeyDown);
+  }, []);
+
+  async function submit() {
+    if (!title.trim() || busy) return;
+    setBusy(true);
+    try {
+      const ok = await onQuickCapture({
+        title: title.trim(),
+        severity: severity.trim() || null,
+        service: service.trim() || null,
+      });
+      if (ok) setTitle("");
+    } finally {
+      setBusy(false);
+    }
+  }
+
+  async function appendNote() {
+    if (!lastCaptured || !note.trim()) return;
+    if (await onAppendNote(lastCaptured.id, note.trim(), noteKind)) setNote("");
+  }
+
+  return (
+    <section className="card" id="live">
+      <h2>Live Incident</h2>
+      <p className="hint">
+        Open an incident while it is happening; the start time is now. Shortcut: <span className="mono">Ctrl/Cmd+Shift+K</span>,
+        then Enter.
+      </p>
+      <div className="actions">
+        <input
+          ref={titleRef}
+          className="textInput"
+          placeholder="What is broken?"
+          aria-label="Quick capture title"
+          value={title}
+          onChange={(e) => setTitle(e.target.value)}
+          onKeyDown={(e) => {
+            if (e.key === "Enter") void submit();
+          }}
+        />
+        <input
+          className="textInput"
+          placeholder="Severity (e.g. SEV2)"
+          aria-label="Quick capture severity"
+          value={severity}
+          onChange={(e) => setSeverity(e.target.value)}
+        />
+        <input
+          className="textInput"
+          placeholder="Service"
+          aria-label="Quick capture service"
+          value={service}
+          onChange={(e) => setService(e.target.value)}
+        />
+        <button className="btn" type="button" onClick={() => void submit()} disabled={!title.trim() || busy}>
+          Capture
+        </button>
+      </div>
+      {lastCaptured ? (
+        <p className="hint">
+          Captured <span className="mono">#{lastCaptured.id}</span> {lastCaptured.title}
+          {lastCaptured.severity ? ` (${lastCaptured.severity})` : ""}, started{" "}
+          <span className="mono">{lastCaptured.start_ts ?? "NULL"}</span>.
+        </p>
+      ) : null}
+      {lastCaptured ? (
+        <>
+          <div className="subhead">Timeline notes (stamped with the current UTC time)</div>
+          <div className="actions">
+            <select aria-label="Note kind" value={noteKind} onChange={(e) => setNoteKind(e.target.value as LiveNoteKind)}>
+              <option value="note">note</option>
+              <option value="action">action</option>
+              <option value="decision">decision</option>
+              <option value="status">status</option>
+            </select>
+            <input
+              className="textInput"
+              placeholder="What just happened?"
+              aria-label="Live note"
+              value={note}
+              onChange={(e) => setNote(e.target.value)}
+              onKeyDown={(e) => {
+                if (e.key === "Enter") void appendNote();
+              }}
+            />
+            <button className="btn" type="button" onClick={() => void appendNote()} disabled={!note.trim()}>
+              Add note
+            </button>
+            <button className="btn" type="button" onClick={() => onReconcile(lastCaptured.id)}>
+              Reconcile with imports
+            </button>
+          </div>
+          <p className="hint">
+            After importing Slack or PagerDuty data, reconcile to hide notes that duplicate imported events.
+          </p>
+        </>
+      ) : null}
+    </section>
+  );
+}
@@ -57,4 +57,34 @@ describe("ValidationSection", () => {
     fireEvent.click(screen.getByRole("button", { name: "Clear incident filter" }));
     expect(onClearIncidentFilter).toHaveBeenCalledTimes(1);
   });
+
+  it("lists overlapping incident pairs and filters to both", () => {
+    const onFilterIncidentsFromOverlap = vi.fn();
+    render(
+      <ValidationSection
+        validationReport={[]}
+        overlaps={[
+          {
+            incident_id: 3,
+            other_incident_id: 7,
+            service: "payments",
+            overlap_seconds: 3300,
+            overlap_ratio: 1,
+            kind: "possible_duplicate",
+          },
+        ]}
+        dashboardLoaded={true}
+        hasIncidentFilter={false}
+        onRefreshValidation={vi.fn()}
+        onRefreshIncidents={vi.fn()}
+        onClearIncidentFilter={vi.fn()}
+        onFilterIncidentFromValidation={vi.fn()}
+        onFilterIncidentsFromOverlap={onFilterIncidentsFromOverlap}
+      />
+    );
+
+    expect(screen.getByText(/possible duplicate overlap=100%/)).toBeInTheDocument();
+    fireEvent.click(screen.getByRole("button", { name: "Filter incidents table" }));
+    expect(onFilterIncidentsFromOverlap).toHaveBeenCalledWith([3, 7], "overlap:3+7");
+  });
 });
@@ -5,14 +5,25 @@ export type ValidationReportItem = {
   warnings: Array<{ code: string; message: string; details?: string | null }>;
 };
 
+export type IncidentOverlapItem = {
+  incident_id: number;
+  other_incident_id: number;
+  service: string;
+  overlap_seconds: number;
+  overlap_ratio: number;
+  kind: "possible_duplicate" | "possible_cascade";
+};
+
 export function ValidationSection(props: {
   validationReport: ValidationReportItem[] | null;
+  overlaps?: IncidentOverlapItem[] | null;
   dashboardLoaded: boolean;
   hasIncidentFilter: boolean;
   onRefreshValidation: () => void | Promise<void>;
   onRefreshIncidents: () => void | Promise<void>;
   onClearIncidentFilter: () => void;
   onFilterIncidentFromValidation: (incidentId: number, label: string) => void;
+  onFilterIncidentsFromOverlap?: (incidentIds: number[], label: string) => void;
 }) {
   return (
     <section className="card" id="validation">
@@ -73,6 +84,42 @@ export function ValidationSection(props: {
               ))}
           </ul>
           {props.validationReport.filter((i) => i.warnings.length > 0).length === 0 && <p className="muted">No validation warnings found.</p>}
+          {props.overlaps && props.overlaps.length > 0 ? (
+            <>
+              <h3>Possible duplicates / cascades</h3>
+              <p className="hint">Same-service incidents whose impact windows overlap substantially. Review before merging or linking.</p>
+              <ul className="list">
+                {props.overlaps.map((o) => (
+                  <li key={`${o.incident_id}-${o.other_incident_id}`}>
+                    <div className="actions">
+                      <span className="mono">
+                        id={o.incident_id} / id={o.other_incident_id}
+                      </span>
+                      <span>{o.service}</span>
+                      <span className="mono">
+                        {o.kind === "possible_duplicate" ? "possible duplicate" : "possible cascade"} overlap=
+                        {Math.round(o.overlap_ratio * 100)}%
+                      </span>
+                      {props.onFilterIncidentsFromOverlap ? (
+                        <button
+                          className="linkBtn"
+                          type="button"
+                          onClick={() =>
+                            props.onFilterIncidentsFromOverlap?.(
+                              [o.incident_id, o.other_incident_id],
+                              `overlap:${o.incident_id}+${o.other_incident_id}`
+                            )
+                          }
+                        >
+                          Filter incidents table
+                        </button>
+                      ) : null}
+                    </div>
+                  </li>
+                ))}
+              </ul>
+            </>
+          ) : null}
         </>
       )}
     </section>
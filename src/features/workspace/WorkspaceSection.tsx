@@ -2,6 +2,12 @@ export type WorkspaceInfo = {
   current_db_path: string;
   recent_db_paths: string[];
   load_error?: { code: string; message: string; details?: string | null; retryable: boolean } | null;
+  config_repair?: {
+    missing_db_path: string | null;
+    candidates: string[];
+    pruned_recent_db_paths: string[];
+    recent_db_paths: string[];
+  } | null;
 };
 
 export type WorkspaceMetadata = { db_path: string; is_empty: boolean };
@@ -17,7 +23,9 @@ export function WorkspaceSection(props: {
   onOpenWorkspace: () => void | Promise<void>;
   onCreateWorkspace: () => void | Promise<void>;
   onSwitchToRecent: () => void | Promise<void>;
+  onRepairConfig: (dbPath: string | null) => void | Promise<void>;
 }) {
+  const repair = props.workspaceInfo?.config_repair ?? null;
   return (
     <section className="card" id="workspace">
       <h2>Workspace (Create / Open / Switch)</h2>
@@ -33,6 +41,30 @@ export function WorkspaceSection(props: {
         </p>
       ) : null}
 
+      {repair?.missing_db_path ? (
+        <div className="card card--sub">
+          <p className="hint">
+            The last workspace was not found at <span className="mono">{repair.missing_db_path}</span>. It may have moved with
+            your app data.
+          </p>
+          <div className="actions">
+            {repair.candidates.map((p) => (
+              <button key={p} className="btn" type="button" onClick={() => void props.onRepairConfig(p)}>
+                Use {p}
+              </button>
+            ))}
+            <button className="btn" type="button" onClick={() => void props.onRepairConfig(null)}>
+              Continue With Default Workspace
+            </button>
+          </div>
+        </div>
+      ) : null}
+      {repair && repair.pruned_recent_db_paths.length > 0 ? (
+        <p className="hint">
+          Removed {repair.pruned_recent_db_paths.length} recent workspace(s) whose DB file no longer exists.
+        </p>
+      ) : null}
+
       <div className="actions">
         <button className="btn" type="button" onClick={() => void props.onOpenWorkspace()}>
           Open Workspace DB...
@@ -10,6 +10,14 @@ export function guidanceForAiErrorCode(code: string): string | null {
       return "No evidence sources are available. Add at least one evidence source (sanitized export, Slack transcript, report MD, or freeform text).";
     case "AI_EVIDENCE_SOURCE_INVALID":
       return "The evidence source is invalid. Check the selected path exists and matches the chosen source type (file vs directory vs paste).";
+    case "AI_EVIDENCE_COPY_FAILED":
+      return "The file could not be read from its share after several attempts. Check the share is reachable and the file is not locked, then retry.";
+    case "AI_EVIDENCE_COPY_HASH_MISMATCH":
+      return "The copied file does not match its checksum. It may still be changing on the share; confirm the file and copy it again.";
+    case "AI_CHUNKING_INVALID":
+      return "Chunking settings are out of range. Use 50-8000 target tokens and an overlap of at most half the target.";
+    case "AI_EMBED_BATCH_INVALID":
+      return "Embedding batch size is out of range. Use 1-512 inputs per request.";
     case "AI_INDEX_BUILD_FAILED":
       return "Index build failed. Confirm evidence chunks exist, and that your local environment can write to the app data directory.";
     case "AI_EMBEDDINGS_FAILED":
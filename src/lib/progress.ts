@@ -0,0 +1,35 @@
+import { listen } from "@tauri-apps/api/event";
+
+import { AiQueuePositionSchema, ProgressEventSchema, type AiQueuePosition, type ProgressEvent } from "./schemas";
+
+/** Matches `PROGRESS_EVENT` in the Tauri layer. */
+export const PROGRESS_EVENT = "qir://progress";
+/** Matches `AI_QUEUE_EVENT` in the Tauri layer. */
+export const AI_QUEUE_EVENT = "qir://ai-queue";
+
+/**
+ * Subscribes to backend progress events. Resolves to an unsubscribe function; outside the
+ * Tauri runtime (tests, plain browser) it resolves to a no-op.
+ */
+export async function listenProgress(onEvent: (ev: ProgressEvent) => void): Promise<() => void> {
+  try {
+    return await listen(PROGRESS_EVENT, (e) => {
+      const parsed = ProgressEventSchema.safeParse(e.payload);
+      if (parsed.success) onEvent(parsed.data);
+    });
+  } catch {
+    return () => {};
+  }
+}
+
+/** Subscribes to AI queue position updates; same fallback behaviour as `listenProgress`. */
+export async function listenAiQueue(onPosition: (pos: AiQueuePosition) => void): Promise<() => void> {
+  try {
+    return await listen(AI_QUEUE_EVENT, (e) => {
+      const parsed = AiQueuePositionSchema.safeParse(e.payload);
+      if (parsed.success) onPosition(parsed.data);
+    });
+  } catch {
+    return () => {};
+  }
+}
@@ -13,11 +13,64 @@ export const ValidationWarningSchema = z.object({
   details: z.string().nullable().optional(),
 });
 
+export const ProgressCountsSchema = z.object({
+  processed: z.number().int().nonnegative(),
+  inserted: z.number().int().nonnegative(),
+  updated: z.number().int().nonnegative(),
+  skipped: z.number().int().nonnegative(),
+  conflicts: z.number().int().nonnegative(),
+});
+
+export type ProgressCounts = z.infer<typeof ProgressCountsSchema>;
+
+export const ProgressEventSchema = z.object({
+  operation_id: z.string(),
+  operation: z.string(),
+  stage: z.string(),
+  percent: z.number().int().min(0).max(100).nullable(),
+  warning: ValidationWarningSchema.optional(),
+  error: AppErrorSchema.optional(),
+  counts: ProgressCountsSchema.optional(),
+});
+
+export type ProgressEvent = z.infer<typeof ProgressEventSchema>;
+
+export const AiQueuePositionSchema = z.object({
+  operation_id: z.string(),
+  operation: z.string(),
+  model: z.string(),
+  position: z.number().int().positive(),
+  running_model: z.string().nullable(),
+});
+
+export type AiQueuePosition = z.infer<typeof AiQueuePositionSchema>;
+
+export const ConflictStrategySchema = z.enum([
+  "prefer_existing",
+  "prefer_incoming",
+  "merge_preserve_on_empty",
+  "skip_conflicts",
+]);
+
+export type ConflictStrategy = z.infer<typeof ConflictStrategySchema>;
+
+export const CsvTimestampFormatsSchema = z.object({
+  start_ts: z.string().nullable().optional(),
+  first_observed_ts: z.string().nullable().optional(),
+  it_awareness_ts: z.string().nullable().optional(),
+  ack_ts: z.string().nullable().optional(),
+  mitigate_ts: z.string().nullable().optional(),
+  resolve_ts: z.string().nullable().optional(),
+});
+
+export type CsvTimestampFormats = z.infer<typeof CsvTimestampFormatsSchema>;
+
 export const JiraCsvMappingSchema = z.object({
   external_id: z.string().nullable().optional(),
   title: z.string(),
   description: z.string().nullable().optional(),
   severity: z.string().nullable().optional(),
+  initial_severity: z.string().nullable().optional(),
   detection_source: z.string().nullable().optional(),
   vendor: z.string().nullable().optional(),
   service: z.string().nullable().optional(),
@@ -29,8 +82,21 @@ export const JiraCsvMappingSchema = z.object({
   ack_ts: z.string().nullable().optional(),
   mitigate_ts: z.string().nullable().optional(),
   resolve_ts: z.string().nullable().optional(),
+  conflict_strategy: ConflictStrategySchema.optional(),
+  assumed_utc_offset_minutes: z.number().int().nullable().optional(),
+  timestamp_formats: CsvTimestampFormatsSchema.optional(),
+});
+
+export const CsvDelimiterSchema = z.enum(["comma", "semicolon", "tab", "pipe"]);
+
+export const GenericCsvMappingSchema = JiraCsvMappingSchema.extend({
+  description_format: z.enum(["plain", "jira_markup"]).optional(),
+  source: z.string().nullable().optional(),
+  delimiter: CsvDelimiterSchema.nullable().optional(),
 });
 
+export type GenericCsvMapping = z.infer<typeof GenericCsvMappingSchema>;
+
 export const JiraMappingProfileSchema = z.object({
   id: z.number().int(),
   name: z.string(),
@@ -45,9 +111,54 @@ export const JiraMappingProfileUpsertSchema = z.object({
   mapping: JiraCsvMappingSchema,
 });
 
+export const JiraProfileCheckSchema = z.object({
+  profile_id: z.number().int(),
+  profile_name: z.string(),
+  headers: z.array(z.string()),
+  ok: z.boolean(),
+  columns: z.array(
+    z.object({
+      field: z.string(),
+      column: z.string(),
+      status: z.enum(["ok", "renamed", "missing"]),
+      suggestion: z.string().nullable(),
+      score: z.number().nullable(),
+    })
+  ),
+  suggested_mapping: JiraCsvMappingSchema,
+});
+
 export const JiraCsvPreviewSchema = z.object({
   headers: z.array(z.string()),
   rows: z.array(z.array(z.string())),
+  delimiter: CsvDelimiterSchema.optional(),
+});
+
+export const DecodedCsvSchema = z.object({
+  text: z.string(),
+  encoding: z.enum(["utf8", "utf16_le", "utf16_be", "windows1252"]),
+  had_bom: z.boolean(),
+  warnings: z.array(ValidationWarningSchema),
+});
+
+export const JiraMappingSuggestionSchema = z.object({
+  headers: z.array(z.string()),
+  mapping: JiraCsvMappingSchema,
+  fields: z.array(
+    z.object({
+      field: z.string(),
+      column: z.string().nullable(),
+      confidence: z.number(),
+      reason: z.string().nullable(),
+    })
+  ),
+});
+
+export const XlsxPreviewSchema = z.object({
+  sheets: z.array(z.string()),
+  sheet: z.string(),
+  headers: z.array(z.string()),
+  rows: z.array(z.array(z.string())),
 });
 
 export const JiraImportConflictSchema = z.object({
@@ -57,12 +168,117 @@ export const JiraImportConflictSchema = z.object({
   fingerprint: z.string().nullable().optional(),
 });
 
+export const CsvRowDiffSchema = z.object({
+  row: z.number().int().nonnegative(),
+  action: z.enum(["insert", "update", "unchanged"]),
+  incident_id: z.number().int().nullable(),
+  external_id: z.string().nullable(),
+  changes: z.array(
+    z.object({
+      field: z.string(),
+      before: z.string().nullable(),
+      after: z.string().nullable(),
+    })
+  ),
+});
+
+export type CsvRowDiff = z.infer<typeof CsvRowDiffSchema>;
+
+export const CsvValueConflictSchema = z.object({
+  row: z.number().int().nonnegative(),
+  incident_id: z.number().int(),
+  external_id: z.string().nullable(),
+  strategy: ConflictStrategySchema,
+  fields: z.array(
+    z.object({
+      field: z.string(),
+      before: z.string().nullable(),
+      after: z.string().nullable(),
+    })
+  ),
+});
+
+export type CsvValueConflict = z.infer<typeof CsvValueConflictSchema>;
+
 export const JiraImportSummarySchema = z.object({
   inserted: z.number().int().nonnegative(),
   updated: z.number().int().nonnegative(),
   skipped: z.number().int().nonnegative(),
   conflicts: z.array(JiraImportConflictSchema),
   warnings: z.array(ValidationWarningSchema),
+  dry_run: z.boolean().default(false),
+  row_diffs: z.array(CsvRowDiffSchema).default([]),
+  value_conflicts: z.array(CsvValueConflictSchema).default([]),
+  batch_id: z.number().int().nullable().default(null),
+});
+
+export const ImportBatchSchema = z.object({
+  id: z.number().int(),
+  source: z.string(),
+  row_count: z.number().int().nonnegative(),
+  incident_ids: z.array(z.number().int()),
+  created_at: z.string(),
+  undone_at: z.string().nullable(),
+});
+
+export type ImportBatch = z.infer<typeof ImportBatchSchema>;
+
+export const ImportUndoSummarySchema = z.object({
+  batch_id: z.number().int(),
+  deleted: z.number().int().nonnegative(),
+  restored: z.number().int().nonnegative(),
+});
+
+export const JiraImportSessionSummarySchema = z.object({
+  import_history_id: z.number().int(),
+  import_batch_id: z.number().int().nullable().default(null),
+  profile_id: z.number().int(),
+  inserted: z.number().int().nonnegative(),
+  updated: z.number().int().nonnegative(),
+  skipped: z.number().int().nonnegative(),
+  conflicts: z.array(JiraImportConflictSchema.extend({ file: z.string() })),
+  warnings: z.array(ValidationWarningSchema),
+  files: z.array(z.object({ name: z.string(), summary: JiraImportSummarySchema })),
+});
+
+export const ImportHistoryEntrySchema = z.object({
+  id: z.number().int(),
+  source: z.string(),
+  profile_id: z.number().int().nullable(),
+  file_names: z.array(z.string()),
+  inserted: z.number().int().nonnegative(),
+  updated: z.number().int().nonnegative(),
+  skipped: z.number().int().nonnegative(),
+  conflicts: z.number().int().nonnegative(),
+  warnings: z.number().int().nonnegative(),
+  summary_json: z.string(),
+  created_at: z.string(),
+});
+
+export const IncidentIoPreviewSchema = z.object({
+  format: z.enum(["csv", "json"]),
+  incident_count: z.number().int().nonnegative(),
+  severities: z.array(z.string()),
+  roles: z.array(z.string()),
+});
+
+export const IncidentIoImportSummarySchema = z.object({
+  incidents: JiraImportSummarySchema,
+  responders_added: z.number().int().nonnegative(),
+  warnings: z.array(ValidationWarningSchema),
+});
+
+export const RetroToolPreviewSchema = z.object({
+  tool: z.enum(["fire_hydrant", "rootly"]),
+  incident_count: z.number().int().nonnegative(),
+  milestones: z.array(z.string()),
+});
+
+export const JiraJsonImportSummarySchema = z.object({
+  incidents: JiraImportSummarySchema,
+  timeline_events_inserted: z.number().int().nonnegative(),
+  timeline_events_skipped: z.number().int().nonnegative(),
+  warnings: z.array(ValidationWarningSchema),
 });
 
 export const InitDbResponseSchema = z.object({
@@ -190,6 +406,19 @@ export const AiModelInfoSchema = z.object({
 
 export const AiModelInfoListSchema = z.array(AiModelInfoSchema);
 
+export const AiUsagePolicySchema = z.object({
+  max_chunks_per_draft: z.number().int().positive().nullable().optional(),
+  allowed_section_types: z.array(z.string()).nullable().optional(),
+  require_approval_before_export: z.boolean(),
+  disclaimer: z.string().nullable().optional(),
+});
+
+export const AiDraftApprovalSchema = z.object({
+  draft_id: z.number().int(),
+  approved_by: z.string(),
+  approved_at: z.string(),
+});
+
 export const AiDraftArtifactSchema = z.object({
   id: z.number().int(),
   quarter_label: z.string(),
@@ -232,10 +461,19 @@ export const EvidenceTimeRangeSchema = z.object({
   end_ts: z.string().nullable().optional(),
 });
 
+export const ChunkingParamsSchema = z.object({
+  target_tokens: z.number().int().positive(),
+  overlap_tokens: z.number().int().nonnegative(),
+});
+
+export const EmbedBatchSizeSchema = z.number().int().min(1).max(512);
+
 export const EvidenceChunkMetaSchema = z.object({
   kind: z.string(),
   incident_keys: z.array(z.string()).nullable().optional(),
   time_range: EvidenceTimeRangeSchema.nullable().optional(),
+  language: z.string().nullable().optional(),
+  chunking: ChunkingParamsSchema.nullable().optional(),
 });
 
 export const EvidenceChunkSummarySchema = z.object({
@@ -276,16 +514,33 @@ export const CitationSchema = z.object({
   locator: CitationLocatorSchema,
 });
 
+export const IncidentEvidenceLinksSchema = z.object({
+  incident_id: z.number().int(),
+  chunk_count: z.number().int().nonnegative(),
+  source_count: z.number().int().nonnegative(),
+  links: z.array(
+    z.object({
+      incident_id: z.number().int(),
+      chunk_id: z.string(),
+      source_id: z.string(),
+      matched_on: z.enum(["external_id", "title"]),
+    })
+  ),
+});
+
 export const EvidenceQueryHitSchema = z.object({
   chunk_id: z.string(),
   source_id: z.string(),
   score: z.number(),
   snippet: z.string(),
   citation: CitationSchema,
+  via_section: z.string().nullable().optional(),
 });
 
 export const EvidenceQueryResponseSchema = z.object({
   hits: z.array(EvidenceQueryHitSchema),
+  mode: z.enum(["semantic", "keyword"]).default("semantic"),
+  fallback_reason: z.string().nullable().optional(),
 });
 
 export const AiSectionIdSchema = z.enum([
@@ -305,6 +560,8 @@ export const AiDraftResponseSchema = z.object({
   prompt_template_version: z.string(),
 });
 
+export const AiDraftTextValidationSchema = z.array(CitationSchema);
+
 export const AiIndexStatusSchema = z.object({
   ready: z.boolean(),
   model: z.string().nullable().optional(),
@@ -315,6 +572,34 @@ export const AiIndexStatusSchema = z.object({
   updated_at: z.string().nullable().optional(),
 });
 
+export const SourceSummariesSchema = z.object({
+  source_id: z.string(),
+  model: z.string(),
+  prompt_version: z.string(),
+  section_chunks: z.number().int().positive(),
+  sections: z.array(
+    z.object({
+      ordinal: z.number().int().nonnegative(),
+      chunk_ids: z.array(z.string()),
+      summary: z.string(),
+      summary_sha256: z.string(),
+    })
+  ),
+  updated_at: z.string(),
+});
+
+export const AiRechunkResultSchema = z.object({
+  rechunk: z.object({
+    source_id: z.string(),
+    params: ChunkingParamsSchema,
+    chunk_count: z.number().int().nonnegative(),
+    removed_chunk_ids: z.array(z.string()),
+    updated_at: z.string(),
+  }),
+  invalidated_vectors: z.number().int().nonnegative(),
+  index: AiIndexStatusSchema,
+});
+
 export const DeleteResponseSchema = z.object({
   ok: z.boolean(),
 });
@@ -396,6 +681,35 @@ export const SanitizedImportSummarySchema = z.object({
   import_warnings: z.array(ValidationWarningSchema),
 });
 
+const BenchmarkDurationStatsSchema = z.object({
+  count: z.number().int().nonnegative(),
+  p50_seconds: z.number().int().nullable(),
+  p90_seconds: z.number().int().nullable(),
+});
+
+export const SanitizedBenchmarkSchema = z.object({
+  datasets: z.array(z.object({ label: z.string(), export_time: z.string(), incident_count: z.number().int() })),
+  incident_count: z.number().int().nonnegative(),
+  mttr_overall: BenchmarkDurationStatsSchema,
+  mttr_by_severity: z.array(
+    z.object({
+      severity: z.string(),
+      incident_count: z.number().int().nonnegative(),
+      mttr: BenchmarkDurationStatsSchema,
+      by_dataset: z.array(z.object({ label: z.string(), mttr: BenchmarkDurationStatsSchema })),
+    })
+  ),
+  detection_mix: z.array(
+    z.object({
+      label: z.string(),
+      incident_count: z.number().int().nonnegative(),
+      detection_known_pct: z.number(),
+      distinct_sources: z.number().int().nonnegative(),
+      top_source_pct: z.number(),
+    })
+  ),
+});
+
 export const WorkspaceMetadataSchema = z.object({
   db_path: z.string(),
   is_empty: z.boolean(),
@@ -405,6 +719,15 @@ export const WorkspaceInfoSchema = z.object({
   current_db_path: z.string(),
   recent_db_paths: z.array(z.string()),
   load_error: AppErrorSchema.nullable().optional(),
+  config_repair: z
+    .object({
+      missing_db_path: z.string().nullable(),
+      candidates: z.array(z.string()),
+      pruned_recent_db_paths: z.array(z.string()),
+      recent_db_paths: z.array(z.string()),
+    })
+    .nullable()
+    .optional(),
 });
 
 export const SlackPreviewSchema = z.object({
@@ -422,6 +745,72 @@ export const SlackIngestSummarySchema = z.object({
   warnings: z.array(ValidationWarningSchema),
 });
 
+export const SlackExportPreviewSchema = z.object({
+  user_count: z.number().int().nonnegative(),
+  channels: z.array(
+    z.object({
+      name: z.string(),
+      day_files: z.number().int().nonnegative(),
+      message_count: z.number().int().nonnegative(),
+      first_ts: z.string().nullable(),
+      last_ts: z.string().nullable(),
+    })
+  ),
+  warnings: z.array(ValidationWarningSchema),
+});
+
+export const ZoomVttPreviewSchema = z.object({
+  cue_count: z.number().int().nonnegative(),
+  speakers: z.array(z.string()),
+  duration_ms: z.number().int().nonnegative(),
+  warnings: z.array(ValidationWarningSchema),
+});
+
+export const ZoomVttIngestSummarySchema = z.object({
+  incident_id: z.number().int(),
+  incident_created: z.boolean(),
+  inserted_events: z.number().int().nonnegative(),
+  skipped_duplicates: z.number().int().nonnegative(),
+  warnings: z.array(ValidationWarningSchema),
+});
+
+export const EmailPreviewSchema = z.object({
+  message_count: z.number().int().nonnegative(),
+  senders: z.array(z.string()),
+  first_ts: z.string().nullable(),
+  last_ts: z.string().nullable(),
+  warnings: z.array(ValidationWarningSchema),
+});
+
+export const EmailIngestSummarySchema = z.object({
+  incident_id: z.number().int(),
+  incident_created: z.boolean(),
+  inserted_events: z.number().int().nonnegative(),
+  inserted_artifacts: z.number().int().nonnegative(),
+  skipped_duplicates: z.number().int().nonnegative(),
+  warnings: z.array(ValidationWarningSchema),
+});
+
+export const StatuspageIncidentSummarySchema = z.object({
+  id: z.string(),
+  name: z.string().nullable(),
+  impact: z.string().nullable(),
+  created_at: z.string().nullable(),
+  resolved_at: z.string().nullable(),
+  update_count: z.number().int().nonnegative(),
+});
+
+export const StatuspageHistoryPreviewSchema = z.array(StatuspageIncidentSummarySchema);
+
+export const StatuspageIngestSummarySchema = z.object({
+  incident_id: z.number().int(),
+  incident_created: z.boolean(),
+  inserted_events: z.number().int().nonnegative(),
+  skipped_duplicates: z.number().int().nonnegative(),
+  backfilled_fields: z.array(z.string()),
+  warnings: z.array(ValidationWarningSchema),
+});
+
 export const IncidentValidationReportItemSchema = z.object({
   id: z.number().int(),
   external_id: z.string().nullable(),
@@ -431,6 +820,39 @@ export const IncidentValidationReportItemSchema = z.object({
 
 export const ValidationReportSchema = z.array(IncidentValidationReportItemSchema);
 
+export const ReportManifestSchema = z.object({
+  manifest_version: z.number().int(),
+  report_kind: z.string(),
+  template_version: z.string(),
+  app_version: z.string(),
+  generated_at: z.string(),
+  workspace_content_sha256: z.string(),
+  applied_migrations: z.array(z.string()),
+  quarter_scope: z.string().nullable(),
+  as_of: z.string().nullable(),
+  draft_ids: z.array(z.number().int()),
+  draft_artifact_hashes: z.array(z.string()),
+  on_call: z.enum(["named", "anonymized"]).nullable().optional(),
+  report_sha256: z.string(),
+  report_bytes: z.number().int().nonnegative(),
+});
+
+export const GeneratedReportSchema = z.object({
+  markdown: z.string(),
+  manifest: ReportManifestSchema,
+});
+
+export const IncidentOverlapSchema = z.object({
+  incident_id: z.number().int(),
+  other_incident_id: z.number().int(),
+  service: z.string(),
+  overlap_seconds: z.number().int().nonnegative(),
+  overlap_ratio: z.number(),
+  kind: z.enum(["possible_duplicate", "possible_cascade"]),
+});
+
+export const IncidentOverlapListSchema = z.array(IncidentOverlapSchema);
+
 export const IncidentSchema = z.object({
   id: z.number().int(),
   external_id: z.string().nullable(),
@@ -488,6 +910,18 @@ export const TimelineEventSchema = z.object({
   created_at: z.string(),
 });
 
+export const LiveReconcileSummarySchema = z.object({
+  incident_id: z.number().int(),
+  checked: z.number().int().nonnegative(),
+  suppressed: z.array(
+    z.object({
+      live_event_id: z.number().int(),
+      matched_event_id: z.number().int(),
+      matched_source: z.string(),
+    })
+  ),
+});
+
 export const IncidentDetailSchema = z.object({
   incident: IncidentSchema,
   metrics: IncidentMetricsSchema,
@@ -18,7 +18,25 @@ describe("workspaceOpenAndLoadAll", () => {
         case "validation_report":
           return [];
         case "generate_report_md":
-          return "# Quarterly Incident Review (QIR)\n";
+          return {
+            markdown: "# Quarterly Incident Review (QIR)\n",
+            manifest: {
+              manifest_version: 1,
+              report_kind: "qir_markdown",
+              template_version: "qir_md_v5",
+              app_version: "0.1.0",
+              generated_at: "2026-01-01T00:00:00Z",
+              workspace_content_sha256: "abc",
+              applied_migrations: [],
+              quarter_scope: null,
+              as_of: null,
+              draft_ids: [],
+              draft_artifact_hashes: [],
+              on_call: null,
+              report_sha256: "def",
+              report_bytes: 34,
+            },
+          };
         default:
           throw new Error(`unexpected command ${command}`);
       }
@@ -3,6 +3,7 @@ import type { ZodType } from "zod";
 import { invokeValidated } from "./tauri";
 import {
   DashboardPayloadV2Schema,
+  GeneratedReportSchema,
   IncidentListSchema,
   ValidationReportSchema,
   WorkspaceMetadataSchema,
@@ -23,7 +24,8 @@ export async function workspaceOpenAndLoadAll(dbPath: string, invoker: Invoker =
   const incidents = await invoker("incidents_list", undefined, IncidentListSchema);
   const dashboard = await invoker("get_dashboard_v2", undefined, DashboardPayloadV2Schema);
   const validation = await invoker("validation_report", undefined, ValidationReportSchema);
-  const report_md = await invoker<string>("generate_report_md", undefined, null);
+  const report = await invoker("generate_report_md", undefined, GeneratedReportSchema);
+  const report_md = report.markdown;
 
   return { incidents, dashboard, validation, report_md };
 }